@@ -0,0 +1,392 @@
+//! Failure injection for resilience testing.
+//!
+//! `spoq --inject-faults` (or `SPOQ_INJECT_FAULTS=1`) makes the adapters layer
+//! misbehave at random: WebSocket frames are dropped, SSE streams disconnect
+//! mid-response, conductor requests fail with 500s and some responses are
+//! delayed. Retries, stream resume and stalled-stream detection can then be
+//! exercised and demoed without a broken network.
+//!
+//! Rates can be tuned with a spec, for example
+//! `--inject-faults=ws-drop=0.2,sse-disconnect=0.05,http-500=0,slow=0.3,slow-ms=4000`.
+//! Faults not named in the spec keep their default rate.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::Stream;
+use futures_util::StreamExt;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::traits::{Headers, HttpClient, HttpError, Response};
+
+/// Environment variable that enables failure injection (same spec as the flag)
+pub const FAULTS_ENV_VAR: &str = "SPOQ_INJECT_FAULTS";
+
+/// Status returned by injected HTTP failures
+pub const INJECTED_STATUS: u16 = 500;
+
+/// Body of injected HTTP failures, so they're recognizable in logs
+pub const INJECTED_MESSAGE: &str = "Injected fault (--inject-faults)";
+
+/// Chance (0.0 - 1.0) of each kind of injected failure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaultConfig {
+    /// Chance an incoming or outgoing WebSocket frame is dropped
+    pub ws_drop_rate: f64,
+    /// Chance an SSE stream disconnects after each chunk
+    pub sse_disconnect_rate: f64,
+    /// Chance a conductor request fails with a 500
+    pub http_error_rate: f64,
+    /// Chance a request or SSE chunk is delayed by `slow_delay`
+    pub slow_rate: f64,
+    /// How long slowed requests and chunks are held back
+    pub slow_delay: Duration,
+}
+
+impl Default for FaultConfig {
+    fn default() -> Self {
+        Self {
+            ws_drop_rate: 0.05,
+            sse_disconnect_rate: 0.02,
+            http_error_rate: 0.1,
+            slow_rate: 0.1,
+            slow_delay: Duration::from_secs(3),
+        }
+    }
+}
+
+impl FaultConfig {
+    /// Parse a spec like `ws-drop=0.2,http-500=0.1,slow-ms=2000`.
+    ///
+    /// An empty spec, "1" or "true" means the default rates.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        let spec = spec.trim();
+        if spec.is_empty() || spec == "1" || spec.eq_ignore_ascii_case("true") {
+            return Ok(config);
+        }
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (key, value) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("expected key=value, got '{}'", entry))?;
+            let value = value.trim();
+            if key.trim() == "slow-ms" {
+                let millis: u64 = value.parse().map_err(|_| {
+                    format!("slow-ms must be a number of milliseconds, got '{}'", value)
+                })?;
+                config.slow_delay = Duration::from_millis(millis);
+                continue;
+            }
+
+            let rate: f64 = value
+                .parse()
+                .ok()
+                .filter(|rate| (0.0..=1.0).contains(rate))
+                .ok_or_else(|| {
+                    format!("{} must be a rate between 0 and 1, got '{}'", key, value)
+                })?;
+            match key.trim() {
+                "ws-drop" => config.ws_drop_rate = rate,
+                "sse-disconnect" => config.sse_disconnect_rate = rate,
+                "http-500" => config.http_error_rate = rate,
+                "slow" => config.slow_rate = rate,
+                other => return Err(format!("unknown fault '{}'", other)),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Whether to drop this WebSocket frame
+    pub fn drop_ws_frame(&self) -> bool {
+        roll(self.ws_drop_rate)
+    }
+
+    /// Whether to cut the SSE stream off after this chunk
+    pub fn disconnect_sse(&self) -> bool {
+        roll(self.sse_disconnect_rate)
+    }
+
+    /// Whether this request should fail with [`INJECTED_STATUS`]
+    pub fn fail_request(&self) -> bool {
+        roll(self.http_error_rate)
+    }
+
+    /// Delay to add before this response or chunk, if any
+    pub fn delay(&self) -> Option<Duration> {
+        roll(self.slow_rate).then_some(self.slow_delay)
+    }
+}
+
+/// Process-wide configuration, set once at startup
+static FAULTS: OnceLock<FaultConfig> = OnceLock::new();
+
+/// Enable failure injection for the rest of the process.
+///
+/// Only the first call takes effect.
+pub fn enable(config: FaultConfig) {
+    tracing::warn!("Failure injection enabled: {:?}", config);
+    let _ = FAULTS.set(config);
+}
+
+/// The active failure injection config, if enabled
+pub fn active() -> Option<&'static FaultConfig> {
+    FAULTS.get()
+}
+
+/// Whether failure injection is enabled
+pub fn is_enabled() -> bool {
+    FAULTS.get().is_some()
+}
+
+/// Apply stream faults to a response body.
+///
+/// The stream may stall once for `slow_delay`, and after each chunk may end
+/// early as if the connection dropped.
+pub fn inject_stream_faults<S, T>(stream: S, config: FaultConfig) -> impl Stream<Item = T> + Send
+where
+    S: Stream<Item = T> + Send + Unpin,
+    T: Send,
+{
+    futures::stream::unfold(
+        (stream, config, false, false),
+        |(mut stream, config, mut stalled, disconnected)| async move {
+            if disconnected {
+                return None;
+            }
+            let item = stream.next().await?;
+            if !stalled {
+                if let Some(delay) = config.delay() {
+                    tracing::debug!("Injected fault: stalling stream for {:?}", delay);
+                    stalled = true;
+                    tokio::time::sleep(delay).await;
+                }
+            }
+            let disconnect = config.disconnect_sse();
+            if disconnect {
+                tracing::debug!("Injected fault: disconnecting stream");
+            }
+            Some((item, (stream, config, stalled, disconnect)))
+        },
+    )
+}
+
+/// True with probability `rate`
+fn roll(rate: f64) -> bool {
+    rate > 0.0 && next_random() < rate
+}
+
+/// xorshift state; statistical quality doesn't matter for fault rolls
+static RNG_STATE: AtomicU64 = AtomicU64::new(0);
+
+/// Uniform random number in [0, 1)
+fn next_random() -> f64 {
+    let mut x = RNG_STATE.load(Ordering::Relaxed);
+    if x == 0 {
+        x = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0x9E37_79B9_7F4A_7C15)
+            | 1;
+    }
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    RNG_STATE.store(x, Ordering::Relaxed);
+    (x >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// HTTP client adapter that injects failures into another [`HttpClient`].
+///
+/// Requests may be delayed or answered with a 500 without reaching the
+/// server, and streams may end early as if the connection dropped.
+pub struct FaultInjectingHttpClient {
+    inner: Arc<dyn HttpClient>,
+    config: FaultConfig,
+}
+
+impl FaultInjectingHttpClient {
+    /// Wrap `inner`, injecting failures at the rates in `config`
+    pub fn new(inner: Arc<dyn HttpClient>, config: FaultConfig) -> Self {
+        Self { inner, config }
+    }
+
+    /// Wrap `inner` if failure injection is enabled, otherwise return it as-is
+    pub fn wrap_if_enabled(inner: Arc<dyn HttpClient>) -> Arc<dyn HttpClient> {
+        match active() {
+            Some(config) => Arc::new(Self::new(inner, config.clone())),
+            None => inner,
+        }
+    }
+
+    /// Apply the delay and failure rolls shared by every request
+    async fn before_request(&self, url: &str) -> Result<(), Response> {
+        if let Some(delay) = self.config.delay() {
+            tracing::debug!("Injected fault: delaying {} by {:?}", url, delay);
+            tokio::time::sleep(delay).await;
+        }
+        if self.config.fail_request() {
+            tracing::debug!("Injected fault: {} for {}", INJECTED_STATUS, url);
+            return Err(Response::new(
+                INJECTED_STATUS,
+                Bytes::from_static(INJECTED_MESSAGE.as_bytes()),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HttpClient for FaultInjectingHttpClient {
+    async fn get(&self, url: &str, headers: &Headers) -> Result<Response, HttpError> {
+        if let Err(response) = self.before_request(url).await {
+            return Ok(response);
+        }
+        self.inner.get(url, headers).await
+    }
+
+    async fn post(&self, url: &str, body: &str, headers: &Headers) -> Result<Response, HttpError> {
+        if let Err(response) = self.before_request(url).await {
+            return Ok(response);
+        }
+        self.inner.post(url, body, headers).await
+    }
+
+    async fn post_stream(
+        &self,
+        url: &str,
+        body: &str,
+        headers: &Headers,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, HttpError>> + Send>>, HttpError> {
+        if self.before_request(url).await.is_err() {
+            return Err(HttpError::ServerError {
+                status: INJECTED_STATUS,
+                message: INJECTED_MESSAGE.to_string(),
+            });
+        }
+        let stream = self.inner.post_stream(url, body, headers).await?;
+        let stream = inject_stream_faults(stream, self.config.clone());
+        Ok(Box::pin(stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::mock::http::MockResponse;
+    use crate::adapters::mock::MockHttpClient;
+
+    fn never() -> FaultConfig {
+        FaultConfig {
+            ws_drop_rate: 0.0,
+            sse_disconnect_rate: 0.0,
+            http_error_rate: 0.0,
+            slow_rate: 0.0,
+            slow_delay: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        assert_eq!(FaultConfig::parse("").unwrap(), FaultConfig::default());
+        assert_eq!(FaultConfig::parse("1").unwrap(), FaultConfig::default());
+    }
+
+    #[test]
+    fn test_parse_spec() {
+        let config = FaultConfig::parse("ws-drop=0.5, http-500=0,slow-ms=250").unwrap();
+
+        assert_eq!(config.ws_drop_rate, 0.5);
+        assert_eq!(config.http_error_rate, 0.0);
+        assert_eq!(config.slow_delay, Duration::from_millis(250));
+        // Unlisted faults keep their defaults
+        assert_eq!(
+            config.sse_disconnect_rate,
+            FaultConfig::default().sse_disconnect_rate
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_specs() {
+        assert!(FaultConfig::parse("ws-drop").is_err());
+        assert!(FaultConfig::parse("ws-drop=2").is_err());
+        assert!(FaultConfig::parse("packet-loss=0.1").is_err());
+        assert!(FaultConfig::parse("slow-ms=soon").is_err());
+    }
+
+    #[test]
+    fn test_rolls_respect_zero_and_one() {
+        let config = never();
+        assert!((0..100).all(|_| !config.drop_ws_frame() && !config.fail_request()));
+
+        let config = FaultConfig {
+            ws_drop_rate: 1.0,
+            http_error_rate: 1.0,
+            ..never()
+        };
+        assert!((0..100).all(|_| config.drop_ws_frame() && config.fail_request()));
+    }
+
+    #[tokio::test]
+    async fn test_http_client_injects_500() {
+        let mock = Arc::new(MockHttpClient::new());
+        mock.set_response(
+            "http://test/v1/threads",
+            MockResponse::Success(Response::new(200, Bytes::from("[]"))),
+        );
+        let config = FaultConfig {
+            http_error_rate: 1.0,
+            ..never()
+        };
+        let client = FaultInjectingHttpClient::new(mock.clone(), config);
+
+        let response = client
+            .get("http://test/v1/threads", &Headers::new())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, INJECTED_STATUS);
+        assert!(mock.get_requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_http_client_passes_through_when_quiet() {
+        let mock = Arc::new(MockHttpClient::new());
+        mock.set_response(
+            "http://test/v1/threads",
+            MockResponse::Success(Response::new(200, Bytes::from("[]"))),
+        );
+        let client = FaultInjectingHttpClient::new(mock, never());
+
+        let response = client
+            .get("http://test/v1/threads", &Headers::new())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, 200);
+    }
+
+    #[tokio::test]
+    async fn test_http_client_disconnects_stream() {
+        let mock = Arc::new(MockHttpClient::new());
+        mock.set_response(
+            "http://test/v1/stream",
+            MockResponse::Stream(vec![Bytes::from("a"), Bytes::from("b"), Bytes::from("c")]),
+        );
+        let config = FaultConfig {
+            sse_disconnect_rate: 1.0,
+            ..never()
+        };
+        let client = FaultInjectingHttpClient::new(mock, config);
+
+        let stream = client
+            .post_stream("http://test/v1/stream", "{}", &Headers::new())
+            .await
+            .unwrap();
+        let chunks: Vec<_> = stream.collect().await;
+
+        assert_eq!(chunks.len(), 1);
+    }
+}
@@ -0,0 +1,86 @@
+//! In-memory clipboard for testing.
+//!
+//! Records copied text instead of touching the system clipboard, and can
+//! pretend no clipboard is reachable (as in a headless SSH session).
+
+use std::sync::{Arc, Mutex};
+
+use crate::clipboard::ClipboardTextError;
+use crate::traits::ClipboardProvider;
+
+/// In-memory clipboard provider for testing.
+///
+/// Clones share the copied text, so a test can keep one to inspect after
+/// handing the other to the App.
+#[derive(Debug, Clone, Default)]
+pub struct MockClipboard {
+    /// Text written so far, oldest first
+    writes: Arc<Mutex<Vec<String>>>,
+    /// Whether writes fail as if no clipboard were available
+    unavailable: Arc<Mutex<bool>>,
+}
+
+impl MockClipboard {
+    /// Create a working in-memory clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a clipboard whose writes fail with `Unavailable`.
+    pub fn unavailable() -> Self {
+        let clipboard = Self::new();
+        clipboard.set_unavailable(true);
+        clipboard
+    }
+
+    /// Configure whether writes fail.
+    pub fn set_unavailable(&self, unavailable: bool) {
+        *self.unavailable.lock().unwrap() = unavailable;
+    }
+
+    /// Text written so far, oldest first.
+    pub fn writes(&self) -> Vec<String> {
+        self.writes.lock().unwrap().clone()
+    }
+
+    /// The most recently written text.
+    pub fn contents(&self) -> Option<String> {
+        self.writes.lock().unwrap().last().cloned()
+    }
+}
+
+impl ClipboardProvider for MockClipboard {
+    fn write_text(&mut self, text: &str) -> Result<(), ClipboardTextError> {
+        if *self.unavailable.lock().unwrap() {
+            return Err(ClipboardTextError::Unavailable(
+                "Mock clipboard unavailable".to_string(),
+            ));
+        }
+        self.writes.lock().unwrap().push(text.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clipboard_records_writes() {
+        let mut clipboard = MockClipboard::new();
+        let observer = clipboard.clone();
+
+        clipboard.write_text("one").unwrap();
+        clipboard.write_text("two").unwrap();
+
+        assert_eq!(observer.writes(), vec!["one", "two"]);
+        assert_eq!(observer.contents().as_deref(), Some("two"));
+    }
+
+    #[test]
+    fn test_unavailable_mock_clipboard_fails() {
+        let mut clipboard = MockClipboard::unavailable();
+        assert!(clipboard.write_text("one").is_err());
+        assert!(clipboard.writes().is_empty());
+    }
+}
@@ -8,11 +8,14 @@
 //! - [`MockHttpClient`] - HTTP client with configurable responses
 //! - [`MockWebSocket`] - WebSocket with message injection
 //! - [`InMemoryCredentials`] - In-memory credential storage
+//! - [`MockClipboard`] - In-memory clipboard
 
+pub mod clipboard;
 pub mod credentials;
 pub mod http;
 pub mod websocket;
 
+pub use clipboard::MockClipboard;
 pub use credentials::InMemoryCredentials;
 pub use http::MockHttpClient;
 pub use websocket::MockWebSocket;
@@ -10,6 +10,9 @@
 //! - [`TungsteniteWsConnection`] - WebSocket using tokio-tungstenite
 //! - [`FileCredentialsProvider`] - File-based credentials storage
 //! - [`DefaultSseParser`] - SSE parser wrapping the existing implementation
+//! - [`SystemClipboard`] - System clipboard via arboard
+//! - [`FaultInjectingHttpClient`] - Random failures for resilience testing
+//!   (see [`fault_injection`])
 //!
 //! # Mock Implementations
 //!
@@ -17,15 +20,20 @@
 //! - [`mock::MockHttpClient`] - Configurable HTTP responses
 //! - [`mock::MockWebSocket`] - Message injection for testing
 //! - [`mock::InMemoryCredentials`] - In-memory credential storage
+//! - [`mock::MockClipboard`] - In-memory clipboard
 
 pub mod default_sse;
+pub mod fault_injection;
 pub mod file_credentials;
 pub mod mock;
 pub mod reqwest_http;
+pub mod system_clipboard;
 pub mod tungstenite_ws;
 
 pub use default_sse::DefaultSseParser;
+pub use fault_injection::{FaultConfig, FaultInjectingHttpClient};
 pub use file_credentials::FileCredentialsProvider;
-pub use mock::{InMemoryCredentials, MockHttpClient, MockWebSocket};
+pub use mock::{InMemoryCredentials, MockClipboard, MockHttpClient, MockWebSocket};
 pub use reqwest_http::ReqwestHttpClient;
+pub use system_clipboard::SystemClipboard;
 pub use tungstenite_ws::TungsteniteWsConnection;
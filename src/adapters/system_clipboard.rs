@@ -0,0 +1,17 @@
+//! System clipboard adapter.
+//!
+//! This module provides a clipboard provider that wraps the existing
+//! [`try_write_clipboard_text`] (arboard).
+
+use crate::clipboard::{try_write_clipboard_text, ClipboardTextError};
+use crate::traits::ClipboardProvider;
+
+/// System clipboard provider (NSPasteboard on macOS, X11/Wayland on Linux).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClipboard;
+
+impl ClipboardProvider for SystemClipboard {
+    fn write_text(&mut self, text: &str) -> Result<(), ClipboardTextError> {
+        try_write_clipboard_text(text)
+    }
+}
@@ -176,6 +176,8 @@ mod tests {
             max_backoff_secs: 1,
             auth_token: None,
             use_tls: false,
+            ack_timeout_secs: 5,
+            ack_max_attempts: 5,
         };
 
         let result = TungsteniteWsConnection::connect(config).await;
@@ -15,6 +15,7 @@ use crate::websocket::{
     WsOutgoingMessage, WsPermissionData, WsPlanApprovalResponse,
 };
 
+use super::setup_wizard::authorize_device;
 use super::App;
 
 impl App {
@@ -35,7 +36,7 @@ impl App {
     ///   "result": { "status": "success", "data": { "allowed": true } }
     /// }
     /// ```
-    pub fn send_permission_response_for_thread(&self, request_id: &str, allowed: bool) -> bool {
+    pub fn send_permission_response_for_thread(&mut self, request_id: &str, allowed: bool) -> bool {
         self.send_permission_response_with_message(request_id, allowed, None)
     }
 
@@ -43,25 +44,13 @@ impl App {
     ///
     /// This is the general form that supports passing a message (e.g., plan feedback).
     /// `send_permission_response_for_thread` delegates to this with `message: None`.
+    /// While the connection is down the response is queued and counts as sent.
     pub fn send_permission_response_with_message(
-        &self,
+        &mut self,
         request_id: &str,
         allowed: bool,
         message: Option<String>,
     ) -> bool {
-        let sender = match &self.ws_sender {
-            Some(s) => s,
-            None => {
-                warn!("No WebSocket sender available for permission response");
-                return false;
-            }
-        };
-
-        if self.ws_connection_state != WsConnectionState::Connected {
-            warn!("WebSocket not connected for permission response");
-            return false;
-        }
-
         let has_message = message.is_some();
         let response = WsCommandResponse {
             type_: "command_response".to_string(),
@@ -75,6 +64,23 @@ impl App {
             },
         };
 
+        if self.queues_ws_messages() {
+            info!(
+                "WebSocket not connected, queueing permission response {}",
+                request_id
+            );
+            self.queue_ws_message(WsOutgoingMessage::CommandResponse(response));
+            return true;
+        }
+
+        let sender = match &self.ws_sender {
+            Some(s) => s,
+            None => {
+                warn!("No WebSocket sender available for permission response");
+                return false;
+            }
+        };
+
         match sender.try_send(WsOutgoingMessage::CommandResponse(response)) {
             Ok(()) => {
                 info!(
@@ -106,17 +112,34 @@ impl App {
     ///   "approved": true
     /// }
     /// ```
-    pub fn send_plan_approval_response(&self, request_id: &str, approved: bool) -> bool {
+    pub fn send_plan_approval_response(&mut self, request_id: &str, approved: bool) -> bool {
         self.send_plan_approval_response_with_message(request_id, approved, None)
     }
 
     /// Send a plan approval response with an optional feedback message via WebSocket.
+    ///
+    /// While the connection is down the response is queued and counts as sent.
     pub fn send_plan_approval_response_with_message(
-        &self,
+        &mut self,
         request_id: &str,
         approved: bool,
         message: Option<String>,
     ) -> bool {
+        let response = WsPlanApprovalResponse::with_message(
+            request_id.to_string(),
+            approved,
+            message,
+        );
+
+        if self.queues_ws_messages() {
+            info!(
+                "WebSocket not connected, queueing plan approval response {}",
+                request_id
+            );
+            self.queue_ws_message(WsOutgoingMessage::PlanApprovalResponse(response));
+            return true;
+        }
+
         let sender = match &self.ws_sender {
             Some(s) => s,
             None => {
@@ -125,17 +148,6 @@ impl App {
             }
         };
 
-        if self.ws_connection_state != WsConnectionState::Connected {
-            warn!("WebSocket not connected for plan approval response");
-            return false;
-        }
-
-        let response = WsPlanApprovalResponse::with_message(
-            request_id.to_string(),
-            approved,
-            message,
-        );
-
         match sender.try_send(WsOutgoingMessage::PlanApprovalResponse(response)) {
             Ok(()) => {
                 info!(
@@ -270,6 +282,7 @@ impl App {
                 let sent = self.send_permission_response_for_thread(&request_id, true);
                 if sent {
                     self.dashboard.clear_waiting_for(thread_id);
+                    self.update_thread_metrics(thread_id, |m| m.record_permission(true));
                 }
                 sent
             }
@@ -318,6 +331,7 @@ impl App {
                 let sent = self.send_permission_response_for_thread(&request_id, false);
                 if sent {
                     self.dashboard.clear_waiting_for(thread_id);
+                    self.update_thread_metrics(thread_id, |m| m.record_permission(false));
                 }
                 sent
             }
@@ -577,61 +591,19 @@ impl App {
                     let _ = open::that(&verification_url);
 
                     // Step 2: Poll for authorization
-                    let interval = std::time::Duration::from_secs(
-                        device_response.interval.max(5) as u64,
-                    );
-                    let timeout = std::time::Duration::from_secs(300); // 5 min timeout
-                    let start = std::time::Instant::now();
-
-                    loop {
-                        if start.elapsed() > timeout {
-                            let _ = tx.send(AppMessage::VpsConfigFailed {
-                                error: "Authentication timed out. Try again.".to_string(),
-                                is_auth_error: true,
-                            });
-                            return;
-                        }
-
-                        tokio::time::sleep(interval).await;
-
-                        match api.poll_device_token(&device_response.device_code).await {
-                            Ok(token_response) => {
-                                // Success - send tokens back
-                                let _ = tx.send(AppMessage::VpsAuthComplete {
-                                    access_token: token_response.access_token,
-                                    refresh_token: token_response.refresh_token,
-                                    expires_in: token_response.expires_in,
-                                    user_id: token_response.user_id,
-                                });
-                                return;
-                            }
-                            Err(crate::auth::central_api::CentralApiError::AuthorizationPending) => {
-                                // Keep polling
-                                continue;
-                            }
-                            Err(crate::auth::central_api::CentralApiError::AuthorizationExpired) => {
-                                let _ = tx.send(AppMessage::VpsConfigFailed {
-                                    error: "Authorization expired. Try again.".to_string(),
-                                    is_auth_error: true,
-                                });
-                                return;
-                            }
-                            Err(crate::auth::central_api::CentralApiError::AccessDenied) => {
-                                let _ = tx.send(AppMessage::VpsConfigFailed {
-                                    error: "Access denied.".to_string(),
-                                    is_auth_error: true,
-                                });
-                                return;
-                            }
-                            Err(e) => {
-                                let _ = tx.send(AppMessage::VpsConfigFailed {
-                                    error: format!("Auth error: {}", e),
-                                    is_auth_error: true,
-                                });
-                                return;
-                            }
-                        }
-                    }
+                    let message = match authorize_device(&api, &device_response).await {
+                        Ok(token_response) => AppMessage::VpsAuthComplete {
+                            access_token: token_response.access_token,
+                            refresh_token: token_response.refresh_token,
+                            expires_in: token_response.expires_in,
+                            user_id: token_response.user_id,
+                        },
+                        Err(error) => AppMessage::VpsConfigFailed {
+                            error,
+                            is_auth_error: true,
+                        },
+                    };
+                    let _ = tx.send(message);
                 }
                 Err(e) => {
                     let _ = tx.send(AppMessage::VpsConfigFailed {
@@ -731,51 +703,30 @@ impl App {
     /// This should be called after a successful VPS swap to establish
     /// a connection to the new conductor.
     pub fn reconnect_websocket(&mut self) {
-        use crate::websocket::{WsClientConfig};
-
         // Drop the old sender to disconnect
         self.ws_sender = None;
+        self.ws_control = None;
         self.ws_connection_state = WsConnectionState::Disconnected;
         info!("WebSocket disconnected, ready for reconnection to new VPS");
 
         // Spawn a new WebSocket connection to the current vps_url
-        let vps_url = match &self.vps_url {
-            Some(url) => url.clone(),
-            None => {
-                info!("No VPS URL set, skipping WebSocket reconnection");
-                return;
-            }
-        };
+        if self.vps_url.is_none() {
+            info!("No VPS URL set, skipping WebSocket reconnection");
+            return;
+        }
 
-        let auth_token = self.credentials.access_token.clone();
+        let ws_config = self.ws_client_config();
         let tx = self.message_tx.clone();
 
         tokio::spawn(async move {
-            // Build config from the current VPS URL
-            let mut ws_config = WsClientConfig::default();
-
-            let (host, use_tls) = if vps_url.starts_with("https://") {
-                (vps_url.strip_prefix("https://").unwrap(), true)
-            } else if vps_url.starts_with("http://") {
-                (vps_url.strip_prefix("http://").unwrap(), false)
-            } else {
-                let is_ip = vps_url.split(':').next().map_or(false, |h| {
-                    h.parse::<std::net::Ipv4Addr>().is_ok()
-                        || h.parse::<std::net::Ipv6Addr>().is_ok()
-                });
-                (vps_url.as_str(), !is_ip)
-            };
-            ws_config = ws_config.with_host(host).with_tls(use_tls);
-
-            if let Some(ref token) = auth_token {
-                ws_config = ws_config.with_auth(token);
-            }
-
-            info!("Reconnecting WebSocket to {} (tls={})", host, use_tls);
+            info!(
+                "Reconnecting WebSocket to {} (tls={})",
+                ws_config.host, ws_config.use_tls
+            );
 
             match crate::app::start_websocket_with_config(tx.clone(), ws_config).await {
-                Ok(sender) => {
-                    let _ = tx.send(crate::app::AppMessage::WsReconnected { sender });
+                Ok(handle) => {
+                    let _ = tx.send(crate::app::AppMessage::WsReconnected { handle });
                 }
                 Err(e) => {
                     tracing::error!("Failed to reconnect WebSocket: {}", e);
@@ -809,7 +760,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_send_permission_response_for_thread_success() {
-        let (app, mut rx) = create_test_app_with_ws();
+        let (mut app, mut rx) = create_test_app_with_ws();
 
         let result = app.send_permission_response_for_thread("perm-123", true);
         assert!(result);
@@ -828,7 +779,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_send_permission_response_for_thread_denied() {
-        let (app, mut rx) = create_test_app_with_ws();
+        let (mut app, mut rx) = create_test_app_with_ws();
 
         let result = app.send_permission_response_for_thread("perm-456", false);
         assert!(result);
@@ -845,7 +796,7 @@ mod tests {
 
     #[test]
     fn test_send_permission_response_no_sender() {
-        let app = App::default();
+        let mut app = App::default();
         let result = app.send_permission_response_for_thread("perm-789", true);
         assert!(!result);
     }
@@ -858,14 +809,15 @@ mod tests {
         app.ws_connection_state = WsConnectionState::Disconnected;
 
         let result = app.send_permission_response_for_thread("perm-abc", true);
-        assert!(!result);
+        assert!(result);
+        assert_eq!(app.ws_queued_messages.len(), 1);
     }
 
     // -------------------- Plan Approval Response Tests --------------------
 
     #[tokio::test]
     async fn test_send_plan_approval_response_approved() {
-        let (app, mut rx) = create_test_app_with_ws();
+        let (mut app, mut rx) = create_test_app_with_ws();
 
         let result = app.send_plan_approval_response("plan-123", true);
         assert!(result);
@@ -883,7 +835,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_send_plan_approval_response_rejected() {
-        let (app, mut rx) = create_test_app_with_ws();
+        let (mut app, mut rx) = create_test_app_with_ws();
 
         let result = app.send_plan_approval_response("plan-456", false);
         assert!(result);
@@ -900,7 +852,7 @@ mod tests {
 
     #[test]
     fn test_send_plan_approval_no_sender() {
-        let app = App::default();
+        let mut app = App::default();
         let result = app.send_plan_approval_response("plan-789", true);
         assert!(!result);
     }
@@ -909,7 +861,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_permission_response_wire_format() {
-        let (app, mut rx) = create_test_app_with_ws();
+        let (mut app, mut rx) = create_test_app_with_ws();
 
         app.send_permission_response_for_thread("req-format", true);
 
@@ -927,7 +879,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_plan_approval_response_wire_format() {
-        let (app, mut rx) = create_test_app_with_ws();
+        let (mut app, mut rx) = create_test_app_with_ws();
 
         app.send_plan_approval_response("plan-format", true);
 
@@ -966,6 +918,11 @@ mod tests {
             status: Some(ThreadStatus::Waiting),
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         app.dashboard
@@ -1020,6 +977,11 @@ mod tests {
             status: Some(ThreadStatus::Waiting),
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         app.dashboard
@@ -1085,6 +1047,11 @@ mod tests {
             status: Some(ThreadStatus::Waiting),
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         app.dashboard
@@ -1142,6 +1109,11 @@ mod tests {
             status: Some(ThreadStatus::Done),
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         app.dashboard
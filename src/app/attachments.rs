@@ -0,0 +1,217 @@
+//! Image attachments for the App.
+//!
+//! Images come from the clipboard (Ctrl+V or a paste), dragged-in file paths,
+//! or png/jpg files picked with `@`. They show as chips above the input until
+//! submit, when each one is uploaded with `ConductorClient::upload_attachment`
+//! and the stream request references them by ID. The newest image also shows
+//! a thumbnail with its dimensions and size. Backspace with the cursor at the
+//! start of the input, or Ctrl+X, removes the last chip. A failed upload becomes an
+//! inline thread error and gives the typed text and images back.
+
+use std::sync::Arc;
+
+use crate::clipboard::{self, ImageAttachment};
+use crate::models::{ImageAttachmentPayload, StreamRequest};
+
+use super::{App, AppMessage};
+
+/// File extensions the `@` picker attaches as images instead of mentioning
+const ATTACHABLE_EXTENSIONS: &[&str] = &[".png", ".jpg", ".jpeg"];
+
+impl App {
+    /// Attach an image unless the per-message limit is reached.
+    ///
+    /// Returns false if the image was not attached.
+    pub fn attach_image(&mut self, attachment: ImageAttachment) -> bool {
+        if self.pending_images.len() >= clipboard::MAX_PENDING_IMAGES {
+            return false;
+        }
+        self.pending_images.push(attachment);
+        self.mark_dirty();
+        true
+    }
+
+    /// Whether Backspace should remove the last image chip (cursor at the very
+    /// start of the input, no folder chips in front of it)
+    pub fn should_remove_image_on_backspace(&self) -> bool {
+        !self.pending_images.is_empty()
+            && self.selected_folders.is_empty()
+            && self.textarea.cursor() == (0, 0)
+    }
+
+    /// Discard the most recently attached image
+    pub fn remove_last_image(&mut self) {
+        if self.pending_images.pop().is_some() {
+            self.mark_dirty();
+        }
+    }
+
+    /// Whether a picked file should be attached as an image
+    pub fn is_attachable_image(path: &str) -> bool {
+        let lower = path.to_lowercase();
+        ATTACHABLE_EXTENSIONS.iter().any(|ext| lower.ends_with(ext))
+    }
+
+    /// Attach a png/jpg picked in the `@` file picker.
+    ///
+    /// Returns false if the file can't be read from this machine (e.g. it
+    /// lives on a remote VPS) or the image limit is reached; the caller then
+    /// falls back to an `@path` mention.
+    pub fn attach_image_file(&mut self, path: &str) -> bool {
+        if self.pending_images.len() >= clipboard::MAX_PENDING_IMAGES {
+            return false;
+        }
+        match clipboard::try_read_image_file(path) {
+            Ok(attachment) => self.attach_image(attachment),
+            Err(e) => {
+                tracing::debug!("Could not attach {} as an image: {:?}", path, e);
+                false
+            }
+        }
+    }
+
+    /// Upload images, then send the stream request referencing them.
+    ///
+    /// A failed upload is reported as `AttachmentUploadFailed` and the stream
+    /// request is not sent.
+    pub(super) fn spawn_upload_and_stream(
+        &mut self,
+        request: StreamRequest,
+        thread_id: String,
+        images: Vec<ImageAttachment>,
+    ) {
+        let client = Arc::clone(&self.client);
+        let message_tx = self.message_tx.clone();
+        let debug_tx = self.debug_tx.clone();
+        let task_thread_id = thread_id.clone();
+        let transport = self.stream_transport();
+
+        self.cache.begin_stream_generation(&thread_id);
+
+        let handle = tokio::spawn(async move {
+            let mut attachment_ids = Vec::with_capacity(images.len());
+            for image in &images {
+                let payload = ImageAttachmentPayload {
+                    hash: image.hash.clone(),
+                    data: image.base64_png.clone(),
+                };
+                match client.upload_attachment(&payload).await {
+                    Ok(id) => attachment_ids.push(id),
+                    Err(e) => {
+                        let _ = message_tx.send(AppMessage::AttachmentUploadFailed {
+                            thread_id,
+                            content: request.prompt,
+                            images,
+                            error: e.to_string(),
+                        });
+                        return;
+                    }
+                }
+            }
+
+            let request = request.with_attachments(attachment_ids);
+            Self::run_stream_request(client, message_tx, debug_tx, request, thread_id, transport)
+                .await;
+        });
+        self.track_stream_task(task_thread_id, handle);
+    }
+
+    /// Turn a failed upload into an inline error, restoring the input
+    pub(super) fn handle_attachment_upload_failed(
+        &mut self,
+        thread_id: &str,
+        content: String,
+        images: Vec<ImageAttachment>,
+        error: &str,
+    ) {
+        tracing::warn!(
+            "Attachment upload failed for thread {}: {}",
+            thread_id,
+            error
+        );
+        // Give the message back so it can be resent; don't clobber new typing.
+        // A restored draft replaces the unsent turn so a resend doesn't duplicate it.
+        if self.textarea.is_empty() && self.active_thread_id.as_deref() == Some(thread_id) {
+            self.cache.discard_unsent_turn(thread_id);
+            self.textarea.set_content(&content);
+            self.pending_images = images;
+        } else {
+            self.cache.cancel_streaming_message(thread_id);
+        }
+        self.cache.add_error_simple(
+            thread_id,
+            "attachment_upload_failed".to_string(),
+            format!("Image upload failed: {}", error),
+        );
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ThreadType;
+
+    fn image(hash: &str) -> ImageAttachment {
+        ImageAttachment {
+            hash: hash.to_string(),
+            base64_png: String::new(),
+            byte_size: 1024,
+            width: 0,
+            height: 0,
+            preview: None,
+        }
+    }
+
+    #[test]
+    fn test_attach_respects_limit_and_backspace_removes_last() {
+        let mut app = App::default();
+        for i in 0..clipboard::MAX_PENDING_IMAGES {
+            assert!(app.attach_image(image(&format!("hash{}", i))));
+        }
+        assert!(!app.attach_image(image("extra")));
+
+        assert!(app.should_remove_image_on_backspace());
+        app.remove_last_image();
+        assert_eq!(app.pending_images.len(), clipboard::MAX_PENDING_IMAGES - 1);
+
+        app.textarea.insert_char('x');
+        assert!(!app.should_remove_image_on_backspace());
+    }
+
+    #[test]
+    fn test_is_attachable_image() {
+        assert!(App::is_attachable_image("shots/Screen.PNG"));
+        assert!(App::is_attachable_image("photo.jpeg"));
+        assert!(!App::is_attachable_image("anim.gif"));
+        assert!(!App::is_attachable_image("src/main.rs"));
+    }
+
+    #[tokio::test]
+    async fn test_upload_failure_restores_input_and_adds_inline_error() {
+        let mut app = App::default();
+        for c in "look at this".chars() {
+            app.textarea.insert_char(c);
+        }
+        app.attach_image(image("a3f2b1c0"));
+        app.submit_input(ThreadType::Conversation);
+        let thread_id = app.active_thread_id.clone().unwrap();
+        assert!(app.pending_images.is_empty());
+        assert!(app.textarea.is_empty());
+
+        app.handle_message(AppMessage::AttachmentUploadFailed {
+            thread_id: thread_id.clone(),
+            content: "look at this".to_string(),
+            images: vec![image("a3f2b1c0")],
+            error: "HTTP 413".to_string(),
+        });
+
+        assert_eq!(app.textarea.content(), "look at this");
+        assert_eq!(app.pending_images.len(), 1);
+        assert!(!app.cache.is_thread_streaming(&thread_id));
+        // The unsent turn is gone so resending doesn't duplicate it
+        let messages = app.cache.get_messages(&thread_id).unwrap();
+        assert!(messages.iter().all(|m| m.content != "look at this"));
+        assert!(messages.iter().all(|m| !m.content.contains("[Cancelled]")));
+    }
+}
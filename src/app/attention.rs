@@ -0,0 +1,97 @@
+//! Terminal attention for the App.
+//!
+//! While the terminal is in the background, a permission request or a
+//! finished response rings the terminal bell (plus an OSC 777 notification)
+//! so spoq behind other windows still gets noticed. The terminal is asked
+//! once per unfocused stretch; the flag clears when focus returns.
+
+use std::time::Instant;
+
+use super::App;
+
+impl App {
+    /// Record a focus change reported by the terminal
+    pub fn set_terminal_focus(&mut self, focused: bool) {
+        let was_focused = self.is_focused;
+        self.focus_supported = true;
+        self.is_focused = focused;
+        if focused {
+            self.attention_raised = false;
+            self.pending_attention = None;
+            if !was_focused {
+                self.on_focus_gained();
+            }
+        } else if was_focused {
+            self.focus_lost_at = Some(Instant::now());
+        }
+    }
+
+    /// Ask for the user's attention about a thread if the terminal is
+    /// unfocused and the thread isn't muted.
+    ///
+    /// Terminals that never report focus are left alone, since we can't tell
+    /// whether the user is already looking.
+    pub fn request_attention(&mut self, thread_id: &str, reason: &str) {
+        if !self.focus_supported
+            || self.is_focused
+            || self.attention_raised
+            || self.is_thread_muted(thread_id)
+        {
+            return;
+        }
+        let body = match self.cache.get_thread(thread_id) {
+            Some(thread) if !thread.title.is_empty() => format!("{}: {}", thread.title, reason),
+            _ => reason.to_string(),
+        };
+        self.attention_raised = true;
+        self.pending_attention = Some(body);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    #[test]
+    fn test_request_attention_only_when_unfocused() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+
+        // No focus events yet: focus state is unknown
+        app.request_attention("thread-001", "Response finished");
+        assert!(app.pending_attention.is_none());
+
+        app.set_terminal_focus(true);
+        app.request_attention("thread-001", "Response finished");
+        assert!(app.pending_attention.is_none());
+
+        app.set_terminal_focus(false);
+        app.request_attention("thread-001", "Needs permission: Bash");
+        let title = app.cache.get_thread("thread-001").unwrap().title.clone();
+        assert_eq!(
+            app.pending_attention.as_deref(),
+            Some(format!("{}: Needs permission: Bash", title).as_str())
+        );
+    }
+
+    #[test]
+    fn test_attention_raised_once_until_focus_returns() {
+        let mut app = App::default();
+        app.set_terminal_focus(false);
+
+        app.request_attention("missing", "Response finished");
+        assert_eq!(
+            app.pending_attention.take().as_deref(),
+            Some("Response finished")
+        );
+        app.request_attention("missing", "Response finished");
+        assert!(app.pending_attention.is_none());
+
+        app.set_terminal_focus(true);
+        assert!(!app.attention_raised);
+        app.set_terminal_focus(false);
+        app.request_attention("missing", "Response finished");
+        assert!(app.pending_attention.is_some());
+    }
+}
@@ -0,0 +1,391 @@
+//! Combined threads-and-repos mode of BrowseList for the App.
+//!
+//! Ctrl+T cycles BrowseList through threads, repos and both together. In the
+//! combined mode typing searches threads with the debounced API search and
+//! filters repos locally right away; the results are listed in a section
+//! per source under one cursor. The section with the best match goes first.
+//! With no query, recent threads sit above the repos most recently worked in.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset};
+
+use crate::models::picker::{RepoEntry, ThreadEntry};
+
+use super::{App, AppMessage, BrowseListMode, BrowseListState};
+
+/// A section of the combined list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowseListSection {
+    Threads,
+    Repos,
+}
+
+impl BrowseListSection {
+    /// Header shown above the section
+    pub fn label(self) -> &'static str {
+        match self {
+            BrowseListSection::Threads => "threads",
+            BrowseListSection::Repos => "repos",
+        }
+    }
+}
+
+/// A selectable BrowseList item: an index into `threads` or `repos`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowseListItem {
+    Thread(usize),
+    Repo(usize),
+}
+
+impl BrowseListItem {
+    /// The section the item is listed in
+    pub fn section(self) -> BrowseListSection {
+        match self {
+            BrowseListItem::Thread(_) => BrowseListSection::Threads,
+            BrowseListItem::Repo(_) => BrowseListSection::Repos,
+        }
+    }
+}
+
+/// How well `text` matches a lowercase `query`: exact (4), prefix (3),
+/// start of a word (2), anywhere (1) or not at all (0)
+fn match_score(text: &str, query: &str) -> u8 {
+    let text = text.to_lowercase();
+    if text == query {
+        4
+    } else if text.starts_with(query) {
+        3
+    } else if text
+        .match_indices(query)
+        .any(|(i, _)| text[..i].ends_with(|c: char| !c.is_alphanumeric()))
+    {
+        2
+    } else if text.contains(query) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Last component of a path or `owner/name`
+fn base_name(path: &str) -> &str {
+    path.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(path)
+}
+
+/// Best match of a thread's title or directory
+fn thread_score(thread: &ThreadEntry, query: &str) -> u8 {
+    let title = thread.title.as_deref().unwrap_or("");
+    let dir = thread.working_directory.as_deref().unwrap_or("");
+    [title, dir, base_name(dir)]
+        .into_iter()
+        .filter(|text| !text.is_empty())
+        .map(|text| match_score(text, query))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Best match of a repo's name, path or URL
+fn repo_score(repo: &RepoEntry, query: &str) -> u8 {
+    let path = repo.local_path.as_deref().unwrap_or("");
+    [
+        repo.name_with_owner.as_str(),
+        base_name(&repo.name_with_owner),
+        path,
+        repo.url.as_str(),
+    ]
+    .into_iter()
+    .filter(|text| !text.is_empty())
+    .map(|text| match_score(text, query))
+    .max()
+    .unwrap_or(0)
+}
+
+/// Latest thread activity in each working directory
+fn last_used(threads: &[ThreadEntry]) -> HashMap<&str, DateTime<FixedOffset>> {
+    let mut last_used: HashMap<&str, DateTime<FixedOffset>> = HashMap::new();
+    for thread in threads {
+        let (Some(dir), Some(at)) = (
+            thread.working_directory.as_deref(),
+            thread
+                .last_activity
+                .as_deref()
+                .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok()),
+        ) else {
+            continue;
+        };
+        let entry = last_used.entry(dir.trim_end_matches('/')).or_insert(at);
+        if at > *entry {
+            *entry = at;
+        }
+    }
+    last_used
+}
+
+impl BrowseListState {
+    /// Number of selectable items in the current mode
+    pub fn item_count(&self) -> usize {
+        match self.mode {
+            BrowseListMode::Threads => self.threads.len(),
+            BrowseListMode::Repos => self.repos.len(),
+            BrowseListMode::All => self.threads.len() + self.repos.len(),
+        }
+    }
+
+    /// The item under the cursor
+    pub fn selected_item(&self) -> Option<BrowseListItem> {
+        let index = self.selected_index;
+        match self.mode {
+            BrowseListMode::Threads => {
+                (index < self.threads.len()).then_some(BrowseListItem::Thread(index))
+            }
+            BrowseListMode::Repos => {
+                (index < self.repos.len()).then_some(BrowseListItem::Repo(index))
+            }
+            BrowseListMode::All => self.combined_items().get(index).copied(),
+        }
+    }
+
+    /// Items of the combined mode in display order, a section at a time.
+    ///
+    /// Threads keep the order the search returned them in. Repos are ordered
+    /// by how well they match, or with no query by the latest thread activity
+    /// in them.
+    pub fn combined_items(&self) -> Vec<BrowseListItem> {
+        let query = self.search_query.trim().to_lowercase();
+        let mut repos: Vec<usize> = (0..self.repos.len()).collect();
+
+        let threads_first = if query.is_empty() {
+            let last_used = last_used(&self.threads);
+            repos.sort_by_key(|&i| {
+                Reverse(
+                    self.repos[i]
+                        .local_path
+                        .as_deref()
+                        .and_then(|path| last_used.get(path.trim_end_matches('/'))),
+                )
+            });
+            true
+        } else {
+            repos.sort_by_key(|&i| Reverse(repo_score(&self.repos[i], &query)));
+            let best_thread = self
+                .threads
+                .iter()
+                .map(|thread| thread_score(thread, &query))
+                .max()
+                .unwrap_or(0);
+            let best_repo = repos
+                .first()
+                .map(|&i| repo_score(&self.repos[i], &query))
+                .unwrap_or(0);
+            best_thread >= best_repo
+        };
+
+        let threads = (0..self.threads.len()).map(BrowseListItem::Thread);
+        let repos = repos.into_iter().map(BrowseListItem::Repo);
+        if threads_first {
+            threads.chain(repos).collect()
+        } else {
+            repos.chain(threads).collect()
+        }
+    }
+}
+
+impl App {
+    /// Switch BrowseList to the next mode (threads, repos, both), keeping
+    /// the search query.
+    pub fn browse_list_cycle_mode(&mut self) {
+        if self.browse_list.cloning {
+            return;
+        }
+        let next = match self.browse_list.mode {
+            BrowseListMode::Threads => BrowseListMode::Repos,
+            BrowseListMode::Repos => BrowseListMode::All,
+            BrowseListMode::All => BrowseListMode::Threads,
+        };
+        let query = self.browse_list.search_query.clone();
+        self.reset_browse_list(next, query);
+    }
+
+    /// Type a character in the combined search: repos are filtered now,
+    /// threads once the debounce fires.
+    /// Returns the query for scheduling debounced search.
+    pub fn browse_list_combined_type_char(&mut self, c: char) -> String {
+        let query = self.browse_list_type_char(c);
+        self.browse_list_filter_repos_local(&query);
+        query
+    }
+
+    /// Backspace in the combined search.
+    /// Returns Some(query) if search should be scheduled, None if nothing was removed.
+    pub fn browse_list_combined_backspace(&mut self) -> Option<String> {
+        let query = self.browse_list_backspace()?;
+        self.browse_list_filter_repos_local(&query);
+        Some(query)
+    }
+
+    /// Load the repos to filter when the session cache doesn't have them yet
+    pub(super) fn load_browse_list_repos(&mut self) {
+        use crate::ui::MAX_ITEMS;
+
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            match client.search_repos("", MAX_ITEMS).await {
+                Ok(response) => {
+                    let _ = tx.send(AppMessage::BrowseListReposLoaded {
+                        repos: response.repos,
+                        offset: 0,
+                        has_more: false,
+                    });
+                }
+                Err(e) => {
+                    let _ = tx.send(AppMessage::BrowseListError(e.to_string()));
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::BrowseListSelectAction;
+
+    fn thread(id: &str, title: &str, dir: &str, last_activity: &str) -> ThreadEntry {
+        ThreadEntry {
+            id: id.to_string(),
+            title: Some(title.to_string()),
+            working_directory: Some(dir.to_string()),
+            thread_type: None,
+            status: None,
+            mode: None,
+            description: None,
+            last_activity: Some(last_activity.to_string()),
+            message_count: None,
+            archived: false,
+        }
+    }
+
+    fn repo(name: &str, local_path: Option<&str>) -> RepoEntry {
+        RepoEntry {
+            name_with_owner: name.to_string(),
+            url: format!("https://github.com/{}", name),
+            local_path: local_path.map(str::to_string),
+            description: None,
+            is_private: None,
+            pushed_at: None,
+            is_fork: None,
+        }
+    }
+
+    fn combined_state() -> BrowseListState {
+        BrowseListState {
+            mode: BrowseListMode::All,
+            threads: vec![
+                thread("t-1", "Fix login", "/home/me/web", "2026-10-14T10:00:00Z"),
+                thread("t-2", "Add parser", "/home/me/cli", "2026-10-15T10:00:00Z"),
+            ],
+            repos: vec![
+                repo("me/api", None),
+                repo("me/web", Some("/home/me/web")),
+                repo("me/cli", Some("/home/me/cli")),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_empty_query_lists_threads_then_recently_used_repos() {
+        let state = combined_state();
+        assert_eq!(
+            state.combined_items(),
+            vec![
+                BrowseListItem::Thread(0),
+                BrowseListItem::Thread(1),
+                BrowseListItem::Repo(2),
+                BrowseListItem::Repo(1),
+                BrowseListItem::Repo(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_best_matching_section_goes_first() {
+        let mut state = combined_state();
+        state.search_query = "cli".to_string();
+        state.threads.truncate(1);
+        state.repos = vec![repo("me/cli", Some("/home/me/cli"))];
+        // The repo name matches exactly, the thread only by content
+        assert_eq!(
+            state.combined_items(),
+            vec![BrowseListItem::Repo(0), BrowseListItem::Thread(0)]
+        );
+
+        state.search_query = "fix login".to_string();
+        assert_eq!(state.combined_items()[0], BrowseListItem::Thread(0));
+    }
+
+    #[test]
+    fn test_select_acts_per_item() {
+        let mut app = App::default();
+        app.browse_list = combined_state();
+
+        app.browse_list.selected_index = 1;
+        assert!(matches!(
+            app.browse_list_select(),
+            BrowseListSelectAction::OpenThread { ref id, .. } if id == "t-2"
+        ));
+        app.browse_list.selected_index = 2;
+        assert!(matches!(
+            app.browse_list_select(),
+            BrowseListSelectAction::SetWorkingDirectory { ref name, .. } if name == "me/cli"
+        ));
+        app.browse_list.selected_index = 4;
+        assert!(matches!(
+            app.browse_list_select(),
+            BrowseListSelectAction::CloneRepo { ref name, .. } if name == "me/api"
+        ));
+        app.browse_list.selected_index = 5;
+        assert!(matches!(
+            app.browse_list_select(),
+            BrowseListSelectAction::None
+        ));
+    }
+
+    #[test]
+    fn test_typing_filters_repos_and_schedules_thread_search() {
+        let mut app = App::default();
+        app.browse_list = combined_state();
+        app.browse_list.all_repos = app.browse_list.repos.clone();
+
+        let query = app.browse_list_combined_type_char('w');
+        assert_eq!(query, "w");
+        assert_eq!(app.browse_list.pending_search.as_deref(), Some("w"));
+        let names: Vec<&str> = app
+            .browse_list
+            .repos
+            .iter()
+            .map(|r| r.name_with_owner.as_str())
+            .collect();
+        assert_eq!(names, vec!["me/web"]);
+
+        assert_eq!(app.browse_list_combined_backspace().as_deref(), Some(""));
+        assert_eq!(app.browse_list.repos.len(), 3);
+        assert_eq!(app.browse_list_combined_backspace(), None);
+    }
+
+    #[test]
+    fn test_match_score() {
+        assert_eq!(match_score("spoq-tui", "spoq-tui"), 4);
+        assert_eq!(match_score("Spoq-TUI", "spoq"), 3);
+        assert_eq!(match_score("awlsen/spoq-tui", "tui"), 2);
+        assert_eq!(match_score("spoqtui", "tui"), 1);
+        assert_eq!(match_score("spoq", "tui"), 0);
+    }
+}
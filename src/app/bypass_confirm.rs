@@ -0,0 +1,341 @@
+//! Confirmation before Execute (bypass permissions) mode.
+//!
+//! Execute mode runs every tool call without a prompt, and Shift+Tab reaches
+//! it in two keystrokes. So cycling into it opens a confirmation that says
+//! what the mode does and which thread it applies to: `Y` switches, any
+//! other key skips past it to Default.
+//!
+//! `bypass_allowed_dirs` in `~/.spoq/config.json` limits the mode to threads
+//! working in those directories (or below them), and
+//! `bypass_thread_allowed_dirs` gives single threads their own list. On any
+//! other thread the confirmation shows why instead, and every key skips.
+//! Sending checks again: a turn on a thread where the mode isn't allowed
+//! goes out in Default mode.
+
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+use crate::models::PermissionMode;
+
+use super::App;
+
+/// Open Execute mode confirmation
+#[derive(Debug, Clone, PartialEq)]
+pub struct BypassConfirm {
+    /// Thread title, or "new threads" on the CommandDeck
+    pub target: String,
+    /// Working directory the mode would apply to
+    pub working_directory: Option<String>,
+    /// Why the mode isn't allowed here (None when it can be confirmed)
+    pub refusal: Option<String>,
+}
+
+/// Whether `dir` is one of `allowed` or below one of them (empty allows all)
+pub fn dir_allows_bypass(dir: &str, allowed: &[String]) -> bool {
+    if allowed.is_empty() {
+        return true;
+    }
+    let dir = normalize_dir(dir);
+    allowed
+        .iter()
+        .any(|entry| dir.starts_with(normalize_dir(entry)))
+}
+
+/// `path` with `~`, `.` and `..` resolved, and symlinks in the part that exists
+fn normalize_dir(path: &str) -> PathBuf {
+    let mut lexical = PathBuf::new();
+    for component in Path::new(&expand_home(path)).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                lexical.pop();
+            }
+            other => lexical.push(other),
+        }
+    }
+
+    let mut existing = lexical.as_path();
+    let mut rest = Vec::new();
+    loop {
+        if let Ok(canonical) = std::fs::canonicalize(existing) {
+            return rest
+                .iter()
+                .rev()
+                .fold(canonical, |path, name| path.join(name));
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                rest.push(name);
+                existing = parent;
+            }
+            _ => return lexical,
+        }
+    }
+}
+
+/// Expand a leading `~` to the home directory
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix('~'), dirs::home_dir()) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+            format!("{}{}", home.display(), rest)
+        }
+        _ => path.to_string(),
+    }
+}
+
+impl App {
+    /// Switch to `mode` and sync it to the active thread
+    pub(super) fn set_permission_mode(&mut self, mode: PermissionMode) {
+        self.permission_mode = mode;
+        self.mark_dirty();
+
+        if let Some(thread_id) = self.active_thread_id.clone() {
+            self.thread_mode_sync.request_mode_change(thread_id, mode);
+        }
+    }
+
+    /// Ask before switching to Execute mode
+    pub fn request_bypass(&mut self) {
+        let (thread_id, target, working_directory) = match self
+            .active_thread_id
+            .as_deref()
+            .and_then(|id| self.cache.get_thread(id))
+        {
+            Some(thread) => (
+                Some(thread.id.clone()),
+                thread.title.clone(),
+                thread.working_directory.clone(),
+            ),
+            None => (
+                None,
+                "new threads".to_string(),
+                self.selected_folders.first().map(|f| f.path.clone()),
+            ),
+        };
+        let refusal = self.bypass_refusal(thread_id.as_deref(), working_directory.as_deref());
+        self.bypass_confirm = Some(BypassConfirm {
+            target,
+            working_directory,
+            refusal,
+        });
+        self.mark_dirty();
+    }
+
+    /// Why Execute mode isn't allowed on `thread_id` in `working_directory`, if it isn't
+    pub fn bypass_refusal(
+        &self,
+        thread_id: Option<&str>,
+        working_directory: Option<&str>,
+    ) -> Option<String> {
+        let (setting, allowed) = match thread_id.and_then(|id| {
+            self.bypass_thread_allowed_dirs
+                .get(self.cache.resolve_thread_id(id))
+        }) {
+            Some(dirs) => ("this thread's bypass_thread_allowed_dirs", dirs),
+            None => ("bypass_allowed_dirs", &self.bypass_allowed_dirs),
+        };
+        if allowed.is_empty() {
+            return None;
+        }
+        match working_directory {
+            None => Some(format!("No working directory, and {} is set", setting)),
+            Some(dir) if !dir_allows_bypass(dir, allowed) => Some(format!(
+                "{} isn't in {} ({})",
+                dir,
+                setting,
+                allowed.join(", ")
+            )),
+            Some(_) => None,
+        }
+    }
+
+    /// The mode to send a turn on `thread_id` with: Execute mode falls back
+    /// to Default (and the app leaves it) where it isn't allowed, e.g. after
+    /// opening another thread since confirming it
+    pub(super) fn enforce_bypass_allowed(
+        &mut self,
+        thread_id: &str,
+        working_directory: Option<&str>,
+        mode: PermissionMode,
+    ) -> PermissionMode {
+        if mode != PermissionMode::Execution {
+            return mode;
+        }
+        let Some(refusal) = self.bypass_refusal(Some(thread_id), working_directory) else {
+            return mode;
+        };
+        if self.permission_mode == PermissionMode::Execution {
+            self.set_permission_mode(PermissionMode::Default);
+        }
+        self.set_timed_error(
+            format!(
+                "Sent in Default mode. Execute mode not allowed: {}",
+                refusal
+            ),
+            Duration::from_secs(5),
+        );
+        PermissionMode::Default
+    }
+
+    /// `Y` in the confirmation: switch to Execute mode (skips if refused)
+    pub fn confirm_bypass(&mut self) {
+        match self.bypass_confirm.take() {
+            Some(confirm) if confirm.refusal.is_none() => {
+                self.set_permission_mode(PermissionMode::Execution)
+            }
+            Some(_) => self.set_permission_mode(PermissionMode::Default),
+            None => {}
+        }
+    }
+
+    /// Any other key: skip Execute mode and go on to Default
+    pub fn skip_bypass(&mut self) {
+        if self.bypass_confirm.take().is_some() {
+            self.set_permission_mode(PermissionMode::Default);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Thread, ThreadMode, ThreadType};
+
+    fn app_on_thread(working_directory: Option<&str>) -> App {
+        let mut app = App {
+            permission_mode: PermissionMode::Plan,
+            ..Default::default()
+        };
+        app.cache.upsert_thread(Thread {
+            id: "t1".to_string(),
+            title: "Refactor auth".to_string(),
+            description: None,
+            preview: String::new(),
+            updated_at: chrono::Utc::now(),
+            thread_type: ThreadType::Programming,
+            mode: ThreadMode::default(),
+            model: None,
+            permission_mode: None,
+            message_count: 0,
+            created_at: chrono::Utc::now(),
+            working_directory: working_directory.map(str::to_string),
+            status: None,
+            verified: None,
+            verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
+        });
+        app.active_thread_id = Some("t1".to_string());
+        app
+    }
+
+    #[test]
+    fn test_dir_allows_bypass() {
+        let allowed = vec!["/home/dev/sandbox".to_string()];
+        assert!(dir_allows_bypass("/anywhere", &[]));
+        assert!(dir_allows_bypass("/home/dev/sandbox", &allowed));
+        assert!(dir_allows_bypass("/home/dev/sandbox/api", &allowed));
+        assert!(!dir_allows_bypass("/home/dev/sandbox-prod", &allowed));
+        assert!(!dir_allows_bypass("/home/dev", &allowed));
+        assert!(dir_allows_bypass("/home/dev/sandbox/./api", &allowed));
+        assert!(!dir_allows_bypass("/home/dev/sandbox/../prod", &allowed));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_dir_allows_bypass_resolves_symlinks() {
+        let root = tempfile::tempdir().unwrap();
+        let sandbox = root.path().join("sandbox");
+        let prod = root.path().join("prod");
+        std::fs::create_dir_all(&sandbox).unwrap();
+        std::fs::create_dir_all(&prod).unwrap();
+        std::os::unix::fs::symlink(&prod, sandbox.join("link")).unwrap();
+        let allowed = vec![sandbox.display().to_string()];
+
+        assert!(dir_allows_bypass(
+            &sandbox.join("new/api").display().to_string(),
+            &allowed
+        ));
+        assert!(!dir_allows_bypass(
+            &sandbox.join("link/api").display().to_string(),
+            &allowed
+        ));
+    }
+
+    #[test]
+    fn test_cycling_into_bypass_asks_first() {
+        let mut app = app_on_thread(Some("/home/dev/api"));
+
+        app.cycle_permission_mode();
+        assert_eq!(app.permission_mode, PermissionMode::Plan);
+        let confirm = app.bypass_confirm.clone().unwrap();
+        assert_eq!(confirm.target, "Refactor auth");
+        assert_eq!(confirm.refusal, None);
+
+        app.confirm_bypass();
+        assert!(app.bypass_confirm.is_none());
+        assert_eq!(app.permission_mode, PermissionMode::Execution);
+    }
+
+    #[test]
+    fn test_other_key_skips_to_default() {
+        let mut app = app_on_thread(Some("/home/dev/api"));
+
+        app.cycle_permission_mode();
+        app.skip_bypass();
+
+        assert!(app.bypass_confirm.is_none());
+        assert_eq!(app.permission_mode, PermissionMode::Default);
+    }
+
+    #[test]
+    fn test_bypass_refused_outside_allowed_dirs() {
+        let mut app = app_on_thread(Some("/srv/prod"));
+        app.bypass_allowed_dirs = vec!["/home/dev".to_string()];
+
+        app.cycle_permission_mode();
+        let refusal = app.bypass_confirm.as_ref().unwrap().refusal.clone();
+        assert!(refusal.unwrap().contains("/srv/prod"));
+
+        // Even Y skips when refused
+        app.confirm_bypass();
+        assert_eq!(app.permission_mode, PermissionMode::Default);
+    }
+
+    #[test]
+    fn test_thread_allowed_dirs_replace_the_global_list() {
+        let mut app = app_on_thread(Some("/srv/prod"));
+        app.bypass_allowed_dirs = vec!["/home/dev".to_string()];
+        app.bypass_thread_allowed_dirs
+            .insert("t1".to_string(), vec!["/srv".to_string()]);
+        assert_eq!(app.bypass_refusal(Some("t1"), Some("/srv/prod")), None);
+
+        // Other threads keep the global list
+        assert!(app.bypass_refusal(Some("t2"), Some("/srv/prod")).is_some());
+
+        // A thread's own list restricts it even with no global list
+        app.bypass_allowed_dirs.clear();
+        let refusal = app.bypass_refusal(Some("t1"), Some("/home/dev")).unwrap();
+        assert!(refusal.contains("bypass_thread_allowed_dirs"));
+    }
+
+    #[test]
+    fn test_sending_falls_back_to_default_where_refused() {
+        // Execute mode confirmed elsewhere, then this thread was opened
+        let mut app = app_on_thread(Some("/srv/prod"));
+        app.permission_mode = PermissionMode::Execution;
+        app.bypass_allowed_dirs = vec!["/home/dev".to_string()];
+
+        let mode = app.enforce_bypass_allowed("t1", Some("/srv/prod"), PermissionMode::Execution);
+        assert_eq!(mode, PermissionMode::Default);
+        assert_eq!(app.permission_mode, PermissionMode::Default);
+        assert!(app.stream_error.as_deref().unwrap().contains("/srv/prod"));
+
+        let mode =
+            app.enforce_bypass_allowed("t1", Some("/home/dev/api"), PermissionMode::Execution);
+        assert_eq!(mode, PermissionMode::Execution);
+    }
+}
@@ -1,28 +1,28 @@
 //! Stream cancellation functionality for the App.
 //!
-//! This module provides methods to cancel an active streaming response
-//! by calling the backend's `/v1/cancel` endpoint.
+//! Ctrl+C stops a streaming response on both ends: the local stream task is
+//! aborted and the partial response is finalized right away (marked
+//! "Cancelled by user"), and the backend is told to stop with a
+//! `cancel_stream` WebSocket message, falling back to the `/v1/cancel`
+//! endpoint when the WebSocket is down. Events of the cancelled stream that
+//! were already in flight are dropped (see `ThreadCache::cancel_stream_by_user`).
+
+use tokio::task::JoinHandle;
 
 use super::{emit_debug, App, AppMessage};
 use crate::debug::{DebugEventKind, ErrorData, ErrorSource, StreamLifecycleData, StreamPhase};
+use crate::websocket::{WsCancelStream, WsConnectionState, WsOutgoingMessage};
 
 impl App {
     /// Cancel the active stream if one is running.
     ///
-    /// This sends a cancel request to the backend, which will SIGTERM the
-    /// Claude CLI process. The stream will then emit a `cancelled` event
-    /// that triggers `StreamCancelled` handling.
+    /// The thread is idle again when this returns, so the next prompt can be
+    /// submitted (and queued prompts go out) without waiting for the backend.
     ///
     /// Guards:
-    /// - Does nothing if `cancel_in_progress` is already true
     /// - Does nothing if no stream is active (`is_streaming()` returns false)
     /// - Does nothing if there's no active thread
     pub fn cancel_active_stream(&mut self) {
-        // Guard: prevent double-cancel
-        if self.cancel_in_progress {
-            return;
-        }
-
         // Guard: only cancel if actually streaming
         if !self.is_streaming() {
             return;
@@ -33,8 +33,6 @@ impl App {
             return;
         };
 
-        self.cancel_in_progress = true;
-
         // Emit debug event
         emit_debug(
             &self.debug_tx,
@@ -45,14 +43,68 @@ impl App {
             Some(&thread_id),
         );
 
+        // Keep the text received so far, then stop reading the stream
+        self.reveal_all_pending();
+        self.flush_stream_tokens();
+        if let Some(task) = self.stream_tasks.remove(&thread_id) {
+            task.abort();
+        }
+        self.cache.cancel_stream_by_user(&thread_id);
+        self.cache.clear_stream_resume(&thread_id);
+
+        if let Some(ref qs) = self.queued_steering {
+            if qs.thread_id == thread_id {
+                self.queued_steering = None;
+            }
+        }
+        self.stream_start_time = None;
+        self.last_event_time = None;
+        self.cumulative_token_count = 0;
+        self.tool_tracker.clear();
+
+        if !self.send_cancel_stream(&thread_id) {
+            self.spawn_cancel_request(thread_id.clone());
+        }
+
+        self.reset_scroll();
+        self.mark_dirty();
+
+        // Prompts kept through Ctrl+C go out now
+        self.submit_next_queued(&thread_id);
+    }
+
+    /// Ask the backend to stop a thread's stream over the WebSocket.
+    ///
+    /// # Returns
+    /// `false` if the WebSocket isn't connected or the send failed
+    fn send_cancel_stream(&self, thread_id: &str) -> bool {
+        let Some(sender) = &self.ws_sender else {
+            return false;
+        };
+        if self.ws_connection_state != WsConnectionState::Connected {
+            return false;
+        }
+
+        let message = WsCancelStream::new(thread_id.to_string());
+        match sender.try_send(WsOutgoingMessage::CancelStream(message)) {
+            Ok(()) => {
+                tracing::info!("Sent cancel_stream via WebSocket: {}", thread_id);
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to send cancel_stream via WebSocket: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Ask the backend to stop a thread's stream over HTTP (`/v1/cancel`)
+    fn spawn_cancel_request(&self, thread_id: String) {
         let client = self.client.clone();
-        let message_tx = self.message_tx.clone();
         let debug_tx = self.debug_tx.clone();
-        let thread_id_for_task = thread_id.clone();
 
-        // Spawn async task to call cancel endpoint
         tokio::spawn(async move {
-            match client.cancel_stream(&thread_id_for_task).await {
+            match client.cancel_stream(&thread_id).await {
                 Ok(response) => {
                     if response.is_cancelled() {
                         tracing::info!("Stream cancelled: {}", response.message);
@@ -63,28 +115,42 @@ impl App {
                             response.message
                         );
                     }
-                    // The SSE stream will emit a Cancelled event which triggers StreamCancelled
                 }
                 Err(e) => {
+                    tracing::warn!("Cancel request failed: {}", e);
                     emit_debug(
                         &debug_tx,
                         DebugEventKind::Error(ErrorData::new(
                             ErrorSource::ConductorApi,
                             format!("Cancel request failed: {}", e),
                         )),
-                        Some(&thread_id_for_task),
+                        Some(&thread_id),
                     );
-                    // Send error so UI can display it and reset state
-                    let _ = message_tx.send(AppMessage::StreamError {
-                        thread_id: thread_id_for_task,
-                        error: format!("Cancel failed: {}", e),
-                        error_code: None,
-                    });
                 }
             }
         });
     }
 
+    /// Remember a stream task so Ctrl+C can abort it
+    pub(super) fn track_stream_task(&mut self, thread_id: String, handle: JoinHandle<()>) {
+        self.stream_tasks.insert(thread_id, handle.abort_handle());
+    }
+
+    /// Whether `msg` belongs to a stream the user cancelled and should be dropped
+    pub(super) fn is_cancelled_stream_event(&self, msg: &AppMessage) -> bool {
+        let thread_id = match msg {
+            AppMessage::StreamToken { thread_id, .. }
+            | AppMessage::ReasoningToken { thread_id, .. }
+            | AppMessage::StreamComplete { thread_id, .. }
+            | AppMessage::StreamError { thread_id, .. }
+            | AppMessage::StreamCancelled { thread_id, .. }
+            | AppMessage::StreamProgress { thread_id, .. }
+            | AppMessage::StreamInterrupted { thread_id, .. } => thread_id,
+            _ => return false,
+        };
+        self.cache.is_stream_cancelled(thread_id)
+    }
+
     /// Reset the cancel state.
     ///
     /// This should be called when:
@@ -98,7 +164,79 @@ impl App {
 
 #[cfg(test)]
 mod tests {
-    // Note: Full integration tests require mocking the ConductorClient
-    // which is beyond the scope of this unit test module.
-    // The cancel functionality is tested via manual testing.
+    use super::*;
+    use crate::cache::MSG_ID_USER_CANCELLED;
+    use crate::models::ThreadType;
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.textarea.insert_char(c);
+        }
+    }
+
+    /// Submit a prompt and receive some of the response
+    fn streaming_app() -> (App, String) {
+        let mut app = App::default();
+        app.reveal.set_enabled(false);
+        type_text(&mut app, "Explain lifetimes");
+        app.submit_input(ThreadType::Conversation);
+        let thread_id = app.active_thread_id.clone().unwrap();
+        app.handle_message(AppMessage::StreamToken {
+            thread_id: thread_id.clone(),
+            token: "Lifetimes are".to_string(),
+        });
+        (app, thread_id)
+    }
+
+    #[tokio::test]
+    async fn test_cancel_finalizes_partial_response() {
+        let (mut app, thread_id) = streaming_app();
+
+        app.cancel_active_stream();
+
+        assert!(!app.is_streaming());
+        assert!(app.stream_tasks.is_empty());
+        let last = app.cache.get_messages(&thread_id).unwrap().last().unwrap();
+        assert_eq!(last.id, MSG_ID_USER_CANCELLED);
+        assert!(last.content.starts_with("Lifetimes are"));
+    }
+
+    #[tokio::test]
+    async fn test_late_events_of_cancelled_stream_are_dropped() {
+        let (mut app, thread_id) = streaming_app();
+        app.cancel_active_stream();
+
+        app.handle_message(AppMessage::StreamToken {
+            thread_id: thread_id.clone(),
+            token: " a way".to_string(),
+        });
+        app.handle_message(AppMessage::StreamComplete {
+            thread_id: thread_id.clone(),
+            message_id: 42,
+        });
+
+        let last = app.cache.get_messages(&thread_id).unwrap().last().unwrap();
+        assert_eq!(last.id, MSG_ID_USER_CANCELLED);
+        assert!(!last.content.contains("a way"));
+    }
+
+    #[tokio::test]
+    async fn test_submit_after_cancel_starts_new_stream() {
+        let (mut app, thread_id) = streaming_app();
+        app.cancel_active_stream();
+
+        type_text(&mut app, "Go on");
+        app.submit_input(ThreadType::Conversation);
+
+        assert!(app.cache.is_thread_streaming(&thread_id));
+        assert!(!app.cache.is_stream_cancelled(&thread_id));
+        app.handle_message(AppMessage::StreamToken {
+            thread_id: thread_id.clone(),
+            token: "Sure".to_string(),
+        });
+        // Tokens coalesce until the next frame
+        app.flush_stream_tokens();
+        let last = app.cache.get_messages(&thread_id).unwrap().last().unwrap();
+        assert_eq!(last.partial_content, "Sure");
+    }
 }
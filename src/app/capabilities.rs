@@ -0,0 +1,45 @@
+//! Terminal capability downgrades for the App.
+//!
+//! Capabilities come from the setup probe (see `spoq setup`). Features the
+//! terminal lacks are switched off up front: links render as plain text,
+//! OSC 52 copies are refused, and the renderer maps colors to the 256-color
+//! palette when truecolor is unavailable.
+
+use crate::terminal::TerminalCapabilities;
+
+use super::App;
+
+impl App {
+    /// Apply probed terminal capabilities.
+    pub fn apply_terminal_capabilities(&mut self, capabilities: TerminalCapabilities) {
+        crate::markdown::set_hyperlinks_enabled(capabilities.hyperlinks);
+        if capabilities.hyperlinks != self.terminal_capabilities.hyperlinks {
+            // Cached renders still carry (or lack) OSC 8 sequences
+            self.markdown_cache.clear();
+            self.rendered_lines_cache.clear();
+        }
+        self.terminal_capabilities = capabilities;
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_terminal_capabilities_stores_and_redraws() {
+        let mut app = App::default();
+        app.needs_redraw = false;
+        let capabilities = TerminalCapabilities {
+            truecolor: false,
+            synchronized_output: false,
+            ..Default::default()
+        };
+
+        app.apply_terminal_capabilities(capabilities);
+
+        assert_eq!(app.terminal_capabilities, capabilities);
+        assert!(app.needs_redraw);
+    }
+}
@@ -0,0 +1,170 @@
+//! Manual context compaction for the App (`/compact`).
+//!
+//! The backend compacts a thread's context on its own when it fills up
+//! (reported as `ContextCompacted`); `/compact` asks for it right away. The
+//! conversation's bottom row shows a spinner while it runs. When it's done
+//! the token counts are updated and a system message records the savings;
+//! failures land in the thread's inline errors.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::models::{CompactResponse, MessageRole};
+use crate::ui::format_token_count;
+
+use super::{App, AppMessage};
+
+/// How long the "can't compact now" hints stay in the status line
+const HINT_DURATION: Duration = Duration::from_secs(3);
+
+/// Error code of failed compactions in the thread's inline errors
+const COMPACT_ERROR_CODE: &str = "compact_failed";
+
+impl App {
+    /// Compact the active thread's context (`/compact`)
+    pub fn compact_context(&mut self) {
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            self.set_status_notice("Open a thread to compact it".to_string(), HINT_DURATION);
+            return;
+        };
+        if self.cache.is_thread_streaming(&thread_id) {
+            self.set_status_notice(
+                "Can't compact while a response is streaming".to_string(),
+                HINT_DURATION,
+            );
+            return;
+        }
+        if self.compacting.is_some() {
+            self.set_status_notice("Already compacting".to_string(), HINT_DURATION);
+            return;
+        }
+
+        self.compacting = Some(thread_id.clone());
+        let client = Arc::clone(&self.client);
+        let tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            let msg = match client.compact_context(&thread_id).await {
+                Ok(response) => AppMessage::ContextCompactFinished {
+                    thread_id,
+                    response,
+                },
+                Err(e) => AppMessage::ContextCompactFailed {
+                    thread_id,
+                    error: e.to_string(),
+                },
+            };
+            let _ = tx.send(msg);
+        });
+        self.mark_dirty();
+    }
+
+    /// Whether the thread's context is being compacted
+    pub fn is_compacting(&self, thread_id: &str) -> bool {
+        self.compacting.as_deref() == Some(thread_id)
+    }
+
+    /// Record a finished compaction
+    pub(super) fn finish_compaction(&mut self, thread_id: &str, response: CompactResponse) {
+        self.compacting = None;
+        if self.active_thread_id.as_deref() == Some(thread_id) {
+            self.session_state.set_context_tokens(response.tokens_after);
+            if let Some(limit) = response.token_limit {
+                self.session_state.set_context_token_limit(limit);
+            }
+            self.session_state.record_compaction(chrono::Utc::now());
+        }
+        self.cache.add_message_simple(
+            thread_id,
+            MessageRole::System,
+            compaction_summary(&response),
+        );
+        self.mark_dirty();
+    }
+
+    /// Report a failed compaction in the thread's inline errors
+    pub(super) fn fail_compaction(&mut self, thread_id: &str, error: String) {
+        self.compacting = None;
+        self.cache.add_error_simple(
+            thread_id,
+            COMPACT_ERROR_CODE.to_string(),
+            format!("Couldn't compact context: {}", error),
+        );
+        self.mark_dirty();
+    }
+}
+
+/// "Context compacted: 82k → 31k tokens"
+fn compaction_summary(response: &CompactResponse) -> String {
+    format!(
+        "Context compacted: {} \u{2192} {} tokens",
+        format_token_count(response.tokens_before.into()),
+        format_token_count(response.tokens_after.into())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn app() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.active_thread_id = Some("thread-001".to_string());
+        app
+    }
+
+    #[test]
+    fn test_compact_rejected_while_streaming() {
+        let mut app = app();
+        app.cache
+            .add_streaming_message("thread-001", "Hi".to_string(), Vec::new());
+        app.compact_context();
+        assert!(!app.is_compacting("thread-001"));
+        assert_eq!(
+            app.status_notice.as_deref(),
+            Some("Can't compact while a response is streaming")
+        );
+    }
+
+    #[test]
+    fn test_finished_compaction_updates_usage_and_adds_message() {
+        let mut app = app();
+        app.compacting = Some("thread-001".to_string());
+        app.handle_message(AppMessage::ContextCompactFinished {
+            thread_id: "thread-001".to_string(),
+            response: CompactResponse {
+                tokens_before: 82_000,
+                tokens_after: 31_000,
+                token_limit: Some(200_000),
+            },
+        });
+
+        assert!(!app.is_compacting("thread-001"));
+        assert_eq!(app.session_state.context_tokens_used, Some(31_000));
+        assert_eq!(app.session_state.context_token_limit, Some(200_000));
+        assert!(app.session_state.last_compaction_at.is_some());
+        let last = app
+            .cache
+            .get_messages("thread-001")
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_eq!(last.role, MessageRole::System);
+        assert_eq!(last.content, "Context compacted: 82k \u{2192} 31k tokens");
+    }
+
+    #[test]
+    fn test_failed_compaction_is_an_inline_error() {
+        let mut app = app();
+        app.compacting = Some("thread-001".to_string());
+        app.handle_message(AppMessage::ContextCompactFailed {
+            thread_id: "thread-001".to_string(),
+            error: "Server error (500): boom".to_string(),
+        });
+
+        assert!(!app.is_compacting("thread-001"));
+        let errors = app.cache.get_errors("thread-001").unwrap();
+        assert_eq!(errors.last().unwrap().error_code, COMPACT_ERROR_CODE);
+    }
+}
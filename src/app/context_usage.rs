@@ -0,0 +1,129 @@
+//! Context window usage for the App.
+//!
+//! The conversation header gauge reads `session_state` directly. This module
+//! adds the `/context` breakdown and a one-time hint when usage crosses
+//! `CONTEXT_HINT_PERCENT`, checked whenever new usage numbers arrive.
+
+use std::time::Duration;
+
+use crate::state::SessionState;
+use crate::ui::format_token_count;
+
+use super::App;
+
+/// Usage (percent) at which the "consider compacting" hint is shown
+pub const CONTEXT_HINT_PERCENT: u32 = 90;
+
+/// How long the `/context` breakdown stays in the status line
+const BREAKDOWN_DURATION: Duration = Duration::from_secs(8);
+
+/// How long the compaction hint stays in the status line
+const HINT_DURATION: Duration = Duration::from_secs(10);
+
+impl App {
+    /// Show the context breakdown in the status line (`/context`)
+    pub fn show_context_usage(&mut self) {
+        let breakdown = context_breakdown(&self.session_state);
+        self.set_status_notice(breakdown, BREAKDOWN_DURATION);
+    }
+
+    /// Suggest compacting the first time usage crosses `CONTEXT_HINT_PERCENT`
+    pub(crate) fn check_context_usage_hint(&mut self) {
+        if self.session_state.context_hint_shown {
+            return;
+        }
+        let Some(percent) = self.session_state.context_usage_percent() else {
+            return;
+        };
+        if percent >= CONTEXT_HINT_PERCENT {
+            self.session_state.context_hint_shown = true;
+            self.set_status_notice(
+                format!(
+                    "Context {}% full - consider compacting or starting a new thread",
+                    percent
+                ),
+                HINT_DURATION,
+            );
+        }
+    }
+}
+
+/// One-line summary: used / limit, percentage and last compaction time
+fn context_breakdown(state: &SessionState) -> String {
+    let (used, limit) = (state.context_tokens_used, state.context_token_limit);
+    if used.is_none() && limit.is_none() {
+        return "Context usage not reported yet".to_string();
+    }
+
+    let format = |tokens: Option<u32>| {
+        tokens.map_or("--".to_string(), |tokens| format_token_count(tokens.into()))
+    };
+    let mut line = format!("Context: {} / {} tokens", format(used), format(limit));
+    if let Some(percent) = state.context_usage_percent() {
+        line.push_str(&format!(" ({}%)", percent));
+    }
+    match state.last_compaction_at {
+        Some(at) => line.push_str(&format!(
+            " | last compacted {}",
+            at.with_timezone(&chrono::Local).format("%H:%M")
+        )),
+        None => line.push_str(" | not compacted yet"),
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppMessage;
+
+    #[test]
+    fn test_context_breakdown() {
+        let mut state = SessionState::new();
+        assert_eq!(context_breakdown(&state), "Context usage not reported yet");
+
+        state.set_context_token_limit(200_000);
+        assert_eq!(
+            context_breakdown(&state),
+            "Context: -- / 200k tokens | not compacted yet"
+        );
+
+        state.set_context_tokens(45_230);
+        state.record_compaction(chrono::Utc::now());
+        let breakdown = context_breakdown(&state);
+        assert!(breakdown.starts_with("Context: 45.2k / 200k tokens (23%) | last compacted "));
+    }
+
+    #[test]
+    fn test_context_hint_shown_once_above_threshold() {
+        let mut app = App::default();
+        app.handle_message(AppMessage::ContextCompacted {
+            tokens_used: Some(50_000),
+            token_limit: Some(100_000),
+        });
+        assert!(app.status_notice.is_none());
+        assert!(app.session_state.last_compaction_at.is_some());
+
+        app.handle_message(AppMessage::UsageReceived {
+            thread_id: "thread-1".to_string(),
+            context_used: 91_000,
+            context_limit: 100_000,
+            input_tokens: None,
+            output_tokens: None,
+        });
+        assert!(app
+            .status_notice
+            .as_deref()
+            .is_some_and(|n| n.starts_with("Context 91% full")));
+
+        app.status_notice = None;
+        app.handle_message(AppMessage::UsageReceived {
+            thread_id: "thread-1".to_string(),
+            context_used: 95_000,
+            context_limit: 100_000,
+            input_tokens: None,
+            output_tokens: None,
+        });
+        assert!(app.status_notice.is_none());
+    }
+}
@@ -0,0 +1,240 @@
+//! Copy-to-clipboard for conversation messages.
+//!
+//! `y` copies the focused (current search match) or last assistant message as
+//! raw markdown; `Y` copies only its code blocks. Copies go to the system
+//! clipboard (`App::clipboard`), falling back to OSC 52 through the terminal
+//! when no clipboard is reachable (e.g., headless SSH sessions).
+
+use std::time::Duration;
+
+use crate::markdown::extract_code_blocks;
+use crate::models::MessageRole;
+
+use super::App;
+
+/// How long the "Copied N chars" confirmation stays visible
+const COPY_NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+impl App {
+    /// Raw markdown of the focused or last assistant message in the active thread.
+    ///
+    /// The focused message is the current search match, if it is an
+    /// assistant message.
+    pub fn copy_target_content(&self) -> Option<String> {
+        let thread_id = self.active_thread_id.as_ref()?;
+        let messages = self.cache.get_messages(thread_id)?;
+
+        let focused = self
+            .message_search
+            .active
+            .then(|| self.message_search.current_match())
+            .flatten()
+            .and_then(|m| messages.get(m.message_index))
+            .filter(|m| m.role == MessageRole::Assistant);
+
+        let message = focused.or_else(|| {
+            messages
+                .iter()
+                .rev()
+                .find(|m| m.role == MessageRole::Assistant)
+        })?;
+
+        let content = if message.is_streaming {
+            &message.partial_content
+        } else {
+            &message.content
+        };
+        (!content.is_empty()).then(|| content.clone())
+    }
+
+    /// Copy the focused or last assistant message as raw markdown
+    pub fn copy_assistant_message(&mut self) {
+        match self.copy_target_content() {
+            Some(content) => self.copy_text(content),
+            None => self.set_timed_error(
+                "No assistant message to copy".to_string(),
+                COPY_NOTICE_DURATION,
+            ),
+        }
+    }
+
+    /// Copy the code blocks of the focused or last assistant message.
+    ///
+    /// Multiple blocks are concatenated, separated by a blank line.
+    pub fn copy_assistant_code_blocks(&mut self) {
+        let code = self.copy_target_content().and_then(|content| {
+            let blocks = extract_code_blocks(&content);
+            (!blocks.is_empty()).then(|| {
+                blocks
+                    .iter()
+                    .map(|b| b.code.trim_end_matches('\n'))
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            })
+        });
+
+        match code {
+            Some(code) => self.copy_text(code),
+            None => self.set_timed_error(
+                "No code blocks to copy".to_string(),
+                COPY_NOTICE_DURATION,
+            ),
+        }
+    }
+
+    /// Copy text to the system clipboard, or queue it for OSC 52 if the
+    /// terminal supports it.
+    pub(super) fn copy_text(&mut self, text: String) {
+        let notice = format!("Copied {} chars", text.chars().count());
+        self.copy_text_with_notice(text, notice, COPY_NOTICE_DURATION);
+    }
+
+    /// Copy text like [`App::copy_text`], confirming with `notice`.
+    ///
+    /// Returns false if the text couldn't be copied.
+    pub(super) fn copy_text_with_notice(
+        &mut self,
+        text: String,
+        notice: String,
+        duration: Duration,
+    ) -> bool {
+        match self.clipboard.write_text(&text) {
+            Ok(()) => {
+                self.set_status_notice(notice, duration);
+                true
+            }
+            Err(e) if !self.terminal_capabilities.osc52 => {
+                tracing::debug!("System clipboard unavailable, OSC 52 unsupported: {}", e);
+                self.set_timed_error(
+                    "Clipboard unavailable in this terminal".to_string(),
+                    COPY_NOTICE_DURATION,
+                );
+                false
+            }
+            Err(e) => {
+                tracing::debug!("System clipboard unavailable, using OSC 52: {}", e);
+                self.pending_osc52_copy = Some(text);
+                self.set_status_notice(format!("{} (via terminal)", notice), duration);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::MockClipboard;
+    use crate::app::Screen;
+    use crate::cache::{MessageMatch, ThreadCache};
+
+    fn app_in_thread() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.clipboard = Box::new(MockClipboard::new());
+        app
+    }
+
+    fn set_last_assistant_content(app: &mut App, content: &str) {
+        let messages = app.cache.get_messages_mut("thread-001").unwrap();
+        let last = messages
+            .iter_mut()
+            .rev()
+            .find(|m| m.role == MessageRole::Assistant)
+            .unwrap();
+        last.content = content.to_string();
+    }
+
+    #[test]
+    fn test_copy_target_is_last_assistant_message() {
+        let mut app = app_in_thread();
+        set_last_assistant_content(&mut app, "Final **answer**");
+
+        assert_eq!(app.copy_target_content().as_deref(), Some("Final **answer**"));
+    }
+
+    #[test]
+    fn test_copy_target_prefers_focused_search_match() {
+        let mut app = app_in_thread();
+        let messages = app.cache.get_messages("thread-001").unwrap();
+        let first_assistant = messages
+            .iter()
+            .position(|m| m.role == MessageRole::Assistant)
+            .unwrap();
+        let expected = messages[first_assistant].content.clone();
+
+        app.message_search.active = true;
+        app.message_search.matches = vec![MessageMatch {
+            message_index: first_assistant,
+            start: 0,
+            end: 1,
+        }];
+
+        assert_eq!(app.copy_target_content(), Some(expected));
+    }
+
+    #[test]
+    fn test_copy_without_active_thread_shows_error() {
+        let mut app = App::default();
+        app.copy_assistant_message();
+        assert_eq!(app.stream_error.as_deref(), Some("No assistant message to copy"));
+    }
+
+    #[test]
+    fn test_copy_code_blocks_without_blocks_shows_error() {
+        let mut app = app_in_thread();
+        set_last_assistant_content(&mut app, "No code here");
+
+        app.copy_assistant_code_blocks();
+
+        assert_eq!(app.stream_error.as_deref(), Some("No code blocks to copy"));
+    }
+
+    #[test]
+    fn test_copy_reports_char_count() {
+        let mut app = app_in_thread();
+        let clipboard = MockClipboard::new();
+        app.clipboard = Box::new(clipboard.clone());
+        set_last_assistant_content(&mut app, "```sh\nls\n```\n\n```sh\npwd\n```");
+
+        app.copy_assistant_code_blocks();
+
+        assert_eq!(clipboard.contents().as_deref(), Some("ls\n\npwd"));
+        assert_eq!(app.status_notice.as_deref(), Some("Copied 7 chars"));
+        assert!(app.pending_osc52_copy.is_none());
+    }
+
+    #[test]
+    fn test_copy_falls_back_to_osc52_without_clipboard() {
+        let mut app = app_in_thread();
+        app.clipboard = Box::new(MockClipboard::unavailable());
+        app.terminal_capabilities.osc52 = true;
+        set_last_assistant_content(&mut app, "plain text");
+
+        app.copy_assistant_message();
+
+        assert_eq!(app.pending_osc52_copy.as_deref(), Some("plain text"));
+        assert_eq!(
+            app.status_notice.as_deref(),
+            Some("Copied 10 chars (via terminal)")
+        );
+    }
+
+    #[test]
+    fn test_copy_never_queues_osc52_when_unsupported() {
+        let mut app = app_in_thread();
+        app.clipboard = Box::new(MockClipboard::unavailable());
+        app.terminal_capabilities.osc52 = false;
+        set_last_assistant_content(&mut app, "plain text");
+
+        app.copy_assistant_message();
+
+        assert!(app.pending_osc52_copy.is_none());
+        assert_eq!(
+            app.stream_error.as_deref(),
+            Some("Clipboard unavailable in this terminal")
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Crash recovery for the App.
+//!
+//! After each frame `record_crash_state` hands the panic hook what a crash
+//! would lose (see `terminal::crash`) together with a disconnect that cancels
+//! the active stream on the backend, over the WebSocket or else `/v1/cancel`.
+//! The snapshot is only rebuilt when its inputs change, which a hash of the
+//! thread, draft, stream length and prompt tells cheaply.
+//! On the next start the preflight offers the saved snapshot, and
+//! `restore_crash_snapshot` puts the draft back into the input and reopens
+//! the thread.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::terminal::{CrashDisconnect, CrashSnapshot};
+use crate::websocket::{WsCancelStream, WsConnectionState, WsOutgoingMessage};
+
+use super::{App, Screen};
+
+/// How long the panic hook waits for the `/v1/cancel` fallback
+const CRASH_CANCEL_TIMEOUT: Duration = Duration::from_millis(800);
+
+/// Time given to the WebSocket writer to send the cancel before the process
+/// goes down
+const CRASH_WS_FLUSH: Duration = Duration::from_millis(150);
+
+impl App {
+    /// What the panic hook would save right now
+    pub fn crash_snapshot(&self) -> CrashSnapshot {
+        let thread_id = match self.screen {
+            Screen::Conversation => self.active_thread_id.clone(),
+            _ => None,
+        };
+        let partial_content = thread_id
+            .as_deref()
+            .and_then(|id| self.cache.get_messages(id))
+            .and_then(|messages| messages.last())
+            .filter(|message| message.is_streaming)
+            .map(|message| message.partial_content.clone());
+        let pending_permission = thread_id
+            .as_deref()
+            .and_then(|id| self.dashboard.get_pending_permission(id))
+            .cloned();
+        let question_other_texts = if pending_permission
+            .as_ref()
+            .is_some_and(|perm| perm.tool_name == "AskUserQuestion")
+        {
+            self.question_state.other_texts.clone()
+        } else {
+            Vec::new()
+        };
+
+        CrashSnapshot {
+            thread_id,
+            draft: self.textarea.content_expanded(),
+            partial_content,
+            pending_permission,
+            question_other_texts,
+            crashed_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Hand the panic hook the current snapshot and how to cancel the stream,
+    /// unless nothing it is built from changed since the last call
+    pub fn record_crash_state(&mut self) {
+        let key = self.crash_state_key();
+        if self.crash_state_key == Some(key) {
+            return;
+        }
+        self.crash_state_key = Some(key);
+
+        let snapshot = self.crash_snapshot();
+        let disconnect = snapshot
+            .partial_content
+            .is_some()
+            .then(|| self.crash_disconnect());
+        crate::terminal::record_crash_state(snapshot, disconnect);
+    }
+
+    /// Hash of what `crash_snapshot` and `crash_disconnect` read, without
+    /// copying the draft or the streamed text (which only grows)
+    fn crash_state_key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        let thread_id = match self.screen {
+            Screen::Conversation => self.active_thread_id.as_deref(),
+            _ => None,
+        };
+        thread_id.hash(&mut hasher);
+        self.textarea.lines().hash(&mut hasher);
+        if let Some(id) = thread_id {
+            self.cache
+                .get_messages(id)
+                .and_then(|messages| messages.last())
+                .filter(|message| message.is_streaming)
+                .map(|message| (message.id, message.partial_content.len()))
+                .hash(&mut hasher);
+            self.dashboard
+                .get_pending_permission(id)
+                .map(|perm| perm.permission_id.as_str())
+                .hash(&mut hasher);
+        }
+        self.question_state.other_texts.hash(&mut hasher);
+        (self.ws_connection_state == WsConnectionState::Connected).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Cancel the snapshot's stream: over the WebSocket when connected, else
+    /// with `/v1/cancel`, waiting for it at most `CRASH_CANCEL_TIMEOUT`
+    fn crash_disconnect(&self) -> CrashDisconnect {
+        let ws_sender = (self.ws_connection_state == WsConnectionState::Connected)
+            .then(|| self.ws_sender.clone())
+            .flatten();
+        let client = self.client.clone();
+        let runtime = tokio::runtime::Handle::try_current().ok();
+
+        Box::new(move |snapshot: &CrashSnapshot| {
+            let Some(thread_id) = snapshot.thread_id.clone() else {
+                return;
+            };
+            let cancel = WsOutgoingMessage::CancelStream(WsCancelStream::new(thread_id.clone()));
+            if ws_sender
+                .as_ref()
+                .is_some_and(|sender| sender.try_send(cancel).is_ok())
+            {
+                std::thread::sleep(CRASH_WS_FLUSH);
+                return;
+            }
+            let Some(runtime) = runtime.as_ref() else {
+                return;
+            };
+            let client = client.clone();
+            let (done_tx, done_rx) = mpsc::channel();
+            runtime.spawn(async move {
+                let _ = client.cancel_stream(&thread_id).await;
+                let _ = done_tx.send(());
+            });
+            let _ = done_rx.recv_timeout(CRASH_CANCEL_TIMEOUT);
+        })
+    }
+
+    /// Restore a crash snapshot the user accepted at startup: reopen its
+    /// thread if it still exists and put the draft back into the input.
+    /// Call after `initialize()` so the thread list is loaded.
+    pub fn restore_crash_snapshot(&mut self, snapshot: CrashSnapshot) {
+        if let Some(thread_id) = snapshot.thread_id {
+            if self.cache.get_thread(&thread_id).is_some() {
+                self.open_thread(thread_id);
+            } else {
+                tracing::info!(
+                    "Not reopening crashed thread {}: no longer exists",
+                    thread_id
+                );
+            }
+        }
+        if !snapshot.draft.trim().is_empty() {
+            self.textarea.set_content(&snapshot.draft);
+        }
+        self.set_status_notice(
+            "Restored from the last crash".to_string(),
+            Duration::from_secs(5),
+        );
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_holds_draft_and_streaming_response() {
+        let mut app = App::default();
+        let thread_id = app
+            .cache
+            .create_streaming_thread("Explain lifetimes".to_string());
+        app.cache.append_to_message(&thread_id, "Lifetimes are");
+        app.active_thread_id = Some(thread_id.clone());
+        app.screen = Screen::Conversation;
+        app.textarea.set_content("and what about 'static?");
+
+        let snapshot = app.crash_snapshot();
+        assert_eq!(snapshot.thread_id.as_deref(), Some(thread_id.as_str()));
+        assert_eq!(snapshot.draft, "and what about 'static?");
+        assert_eq!(snapshot.partial_content.as_deref(), Some("Lifetimes are"));
+        assert!(snapshot.pending_permission.is_none());
+    }
+
+    #[test]
+    fn test_crash_state_is_recorded_only_when_it_changes() {
+        let mut app = App::default();
+        let thread_id = app
+            .cache
+            .create_streaming_thread("Explain lifetimes".to_string());
+        app.active_thread_id = Some(thread_id.clone());
+        app.screen = Screen::Conversation;
+
+        app.record_crash_state();
+        let recorded = app.crash_state_key;
+        assert!(recorded.is_some());
+        app.record_crash_state();
+        assert_eq!(app.crash_state_key, recorded);
+
+        // Streamed text, the draft and the thread each change it
+        app.cache.append_to_message(&thread_id, "Lifetimes are");
+        assert_ne!(Some(app.crash_state_key()), recorded);
+        app.record_crash_state();
+        let recorded = app.crash_state_key;
+
+        app.textarea.set_content("and 'static?");
+        assert_ne!(Some(app.crash_state_key()), recorded);
+        app.record_crash_state();
+        let recorded = app.crash_state_key;
+
+        app.screen = Screen::CommandDeck;
+        assert_ne!(Some(app.crash_state_key()), recorded);
+    }
+
+    #[test]
+    fn test_restore_puts_draft_back_and_reopens_thread() {
+        let mut app = App::default();
+        app.cache = crate::cache::ThreadCache::with_stub_data();
+        let thread_id = app.cache.threads()[0].id.clone();
+
+        app.restore_crash_snapshot(CrashSnapshot {
+            thread_id: Some(thread_id.clone()),
+            draft: "unsent follow-up".to_string(),
+            ..Default::default()
+        });
+
+        assert_eq!(app.screen, Screen::Conversation);
+        assert_eq!(app.active_thread_id.as_deref(), Some(thread_id.as_str()));
+        assert_eq!(app.textarea.content(), "unsent follow-up");
+    }
+}
@@ -3,6 +3,12 @@
 //! This module provides a [`CursorBlinkState`] struct that encapsulates all
 //! cursor blink logic with proper blinkwait behavior, similar to Vim's
 //! blinkwait/blinkon/blinkoff settings.
+//!
+//! Every visibility change costs a full frame, so [`CursorBlinkState::update`]
+//! only reports changes that are actually drawn: never for the solid caret
+//! (the default; `cursor_blink` in config turns blinking on), and not once a
+//! blinking cursor has been idle past `idle_limit_ticks`, where it settles
+//! visible.
 
 /// Manages cursor blink state with blinkwait behavior.
 ///
@@ -12,8 +18,11 @@
 /// Default timing at 60fps:
 /// - blinkwait: 31 ticks (~500ms) - cursor stays visible after activity
 /// - blink_half_cycle: 16 ticks (~250ms) - duration of each visible/hidden phase
+/// - idle limit: 600 ticks (~10s) - blinking stops after this much inactivity
 #[derive(Debug, Clone)]
 pub struct CursorBlinkState {
+    /// Whether the cursor blinks at all (false = solid caret)
+    blink_enabled: bool,
     /// Tick count when cursor last had activity (moved, typed, etc.)
     last_activity_tick: u64,
     /// Current visibility state of the cursor
@@ -22,6 +31,9 @@ pub struct CursorBlinkState {
     blinkwait_ticks: u64,
     /// Number of ticks per blink phase (on or off) (default: 16 ticks = ~250ms at 60fps)
     blink_half_cycle_ticks: u64,
+    /// Ticks of inactivity after which blinking stops with the cursor visible
+    /// (default: 600 ticks = ~10s at 60fps)
+    idle_limit_ticks: u64,
 }
 
 impl Default for CursorBlinkState {
@@ -33,15 +45,28 @@ impl Default for CursorBlinkState {
 impl CursorBlinkState {
     /// Create a new CursorBlinkState with default timing.
     ///
+    /// The cursor is a solid caret until blinking is enabled.
+    ///
     /// Defaults:
     /// - blinkwait: 31 ticks (~500ms at 60fps)
     /// - blink_half_cycle: 16 ticks (~250ms at 60fps)
+    /// - idle limit: 600 ticks (~10s at 60fps)
     pub fn new() -> Self {
         Self {
+            blink_enabled: false,
             last_activity_tick: 0,
             is_visible: true,
             blinkwait_ticks: 31,        // ~500ms at 60fps
             blink_half_cycle_ticks: 16, // ~250ms at 60fps
+            idle_limit_ticks: 600,      // ~10s at 60fps
+        }
+    }
+
+    /// Create a CursorBlinkState whose cursor blinks (`cursor_blink` in config).
+    pub fn blinking() -> Self {
+        Self {
+            blink_enabled: true,
+            ..Self::new()
         }
     }
 
@@ -57,19 +82,27 @@ impl CursorBlinkState {
 
     /// Update the blink state based on the current tick.
     ///
-    /// Returns `true` if the visibility state changed, `false` otherwise.
+    /// Returns `true` if the drawn visibility changed, `false` otherwise.
     /// Use this to determine if a redraw is needed.
     ///
     /// # Algorithm
     ///
+    /// 0. Solid caret: always visible, never changes
     /// 1. During blinkwait period: cursor stays visible
     /// 2. After blinkwait: cursor blinks based on blink_half_cycle_ticks
     ///    - First half of each full cycle: visible
     ///    - Second half of each full cycle: hidden
+    /// 3. Idle past idle_limit_ticks: cursor stays visible
     pub fn update(&mut self, current_tick: u64) -> bool {
+        if !self.blink_enabled {
+            return false;
+        }
+
         let ticks_since_activity = current_tick.saturating_sub(self.last_activity_tick);
 
-        let new_visibility = if ticks_since_activity < self.blinkwait_ticks {
+        let new_visibility = if ticks_since_activity < self.blinkwait_ticks
+            || ticks_since_activity >= self.idle_limit_ticks
+        {
             // Still in blinkwait period - cursor stays visible
             true
         } else {
@@ -90,7 +123,7 @@ impl CursorBlinkState {
     /// Get the current visibility state of the cursor.
     /// Always returns true for solid caret (no blinking).
     pub fn is_visible(&self) -> bool {
-        true
+        !self.blink_enabled || self.is_visible
     }
 }
 
@@ -176,20 +209,45 @@ mod tests {
 
     #[test]
     fn test_update_returns_change_status() {
-        // Internal state still tracks blink cycles, but is_visible() always returns true
-        let mut state = CursorBlinkState::new();
+        let mut state = CursorBlinkState::blinking();
         state.reset(0);
 
         // No change during blinkwait
         let changed = state.update(10);
         assert!(!changed, "Should return false when visibility unchanged");
 
-        // Internal state changes when entering hidden phase
+        // Entering the hidden phase is a visible change
         let changed = state.update(47);
-        assert!(changed, "Should return true when internal state changes");
+        assert!(changed, "Should return true when visibility changes");
+        assert!(!state.is_visible());
+    }
+
+    #[test]
+    fn test_solid_caret_never_requests_redraw() {
+        let mut state = CursorBlinkState::new();
+        state.reset(0);
+
+        for tick in 0..2000 {
+            assert!(!state.update(tick), "Solid caret changed at tick {}", tick);
+        }
+        assert!(state.is_visible());
+    }
+
+    #[test]
+    fn test_blinking_stops_when_idle() {
+        let mut state = CursorBlinkState::blinking();
+        state.reset(0);
+
+        let redraws = (0..5000).filter(|&tick| state.update(tick)).count();
+
+        // Blinks until the idle limit, then settles visible with no further redraws
+        assert!(redraws > 0);
+        assert!(state.is_visible());
+        assert!((601..5000).all(|tick| !state.update(tick)));
 
-        // Cursor is still visible externally
-        assert!(state.is_visible(), "External visibility always true");
+        // Activity restarts blinking
+        state.reset(5000);
+        assert!((5000..5100).any(|tick| state.update(tick)));
     }
 
     #[test]
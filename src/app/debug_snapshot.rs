@@ -0,0 +1,388 @@
+//! Point-in-time JSON dumps of App state for bug reports.
+//!
+//! The debug event stream shows how state changed; a snapshot shows what it
+//! is right now: screen and focus, scroll and height cache values, streaming
+//! flags, pending permissions and questions, WebSocket state, which pickers
+//! and overlays are open, and cache stats. The debug server serves the latest
+//! one at `/snapshot` and `/debug snapshot` writes one to a temp file.
+//!
+//! Credentials and message bodies are never included. The only free text is
+//! the stream error, cut to `ERROR_PREVIEW_CHARS`.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::{App, SyncStatus};
+use crate::cli::VERSION;
+use crate::view_state::OverlayState;
+
+/// Characters of the stream error kept in a snapshot
+const ERROR_PREVIEW_CHARS: usize = 120;
+
+/// Ticks between snapshots published to the debug server (~1s at 16ms/tick)
+pub(super) const SNAPSHOT_PUBLISH_TICKS: u64 = 60;
+
+/// How long the "snapshot written" notice stays up
+const SNAPSHOT_NOTICE_DURATION: Duration = Duration::from_secs(10);
+
+/// Everything `App::debug_snapshot` reports
+#[derive(Debug, Serialize)]
+struct DebugSnapshot {
+    taken_at: chrono::DateTime<chrono::Utc>,
+    version: &'static str,
+    screen: String,
+    focus: String,
+    active_thread_id: Option<String>,
+    terminal_width: u16,
+    terminal_height: u16,
+    scroll: ScrollSnapshot,
+    streaming: StreamingSnapshot,
+    pending: PendingSnapshot,
+    connection: ConnectionSnapshot,
+    overlays: OverlaySnapshot,
+    cache: CacheSnapshot,
+}
+
+#[derive(Debug, Serialize)]
+struct ScrollSnapshot {
+    unified_scroll: u16,
+    max_scroll: u16,
+    user_has_scrolled: bool,
+    total_content_lines: usize,
+    /// Thread the height cache was measured for
+    height_cache_thread: Option<String>,
+    height_cache_messages: usize,
+    height_cache_total_lines: usize,
+    height_cache_width: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct StreamingSnapshot {
+    active_thread_streaming: bool,
+    streaming_threads: Vec<String>,
+    cancel_in_progress: bool,
+    compacting: Option<String>,
+    stream_error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PendingSnapshot {
+    permissions: usize,
+    questions: usize,
+    /// Tool of the active thread's pending permission
+    active_permission_tool: Option<String>,
+    active_question: bool,
+    question_tab: usize,
+    queued_messages: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct ConnectionSnapshot {
+    ws_state: String,
+    ws_reconnect_attempts: u32,
+    ws_unacked_messages: usize,
+    ws_queued_messages: usize,
+    polling_mode: bool,
+    connected: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct OverlaySnapshot {
+    /// Dashboard overlay kind (question, plan, VPS config, ...)
+    dashboard_overlay: Option<&'static str>,
+    thread_switcher: bool,
+    unified_picker: bool,
+    file_picker: bool,
+    folder_picker: bool,
+    slash_autocomplete: bool,
+    model_picker: bool,
+    help_dialog: bool,
+    keys_dialog: bool,
+    link_editor: bool,
+    share_dialog: bool,
+    thread_rename: bool,
+    thread_delete_confirm: bool,
+    thread_archive_confirm: bool,
+    quit_confirm: bool,
+    bypass_confirm: bool,
+    rate_limit_modal: bool,
+    notifications_panel: bool,
+    event_log: bool,
+    log_view: bool,
+    status_view: bool,
+    usage_view: bool,
+    permission_detail: bool,
+    whats_new: bool,
+    sync_dialog: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct CacheSnapshot {
+    thread_count: usize,
+    total_messages: usize,
+    /// Loaded messages per thread ID
+    messages_per_thread: BTreeMap<String, usize>,
+}
+
+/// Name of a dashboard overlay without its contents
+fn overlay_kind(overlay: &OverlayState) -> &'static str {
+    match overlay {
+        OverlayState::Question { .. } => "question",
+        OverlayState::FreeForm { .. } => "free_form",
+        OverlayState::Plan { .. } => "plan",
+        OverlayState::ClaudeLogin { .. } => "claude_login",
+        OverlayState::ClaudeAccounts { .. } => "claude_accounts",
+        OverlayState::VpsConfig { .. } => "vps_config",
+    }
+}
+
+impl App {
+    /// Dump the state that matters for rendering bugs as JSON.
+    ///
+    /// Contains no credentials or message bodies, so it can be attached to
+    /// bug reports as is.
+    pub fn debug_snapshot(&self) -> serde_json::Value {
+        let active = self.active_thread_id.as_deref();
+
+        let (
+            height_cache_thread,
+            height_cache_messages,
+            height_cache_total_lines,
+            height_cache_width,
+        ) = match &self.height_cache {
+            Some(cache) => (
+                Some(cache.thread_id.to_string()),
+                cache.heights.len(),
+                cache.total_lines,
+                cache.viewport_width,
+            ),
+            None => (None, 0, 0, 0),
+        };
+
+        let messages_per_thread: BTreeMap<String, usize> = self
+            .cache
+            .messages
+            .iter()
+            .map(|(id, messages)| (id.clone(), messages.len()))
+            .collect();
+        let streaming_threads: Vec<String> = messages_per_thread
+            .keys()
+            .filter(|id| self.cache.is_thread_streaming(id))
+            .cloned()
+            .collect();
+
+        let snapshot = DebugSnapshot {
+            taken_at: chrono::Utc::now(),
+            version: VERSION,
+            screen: format!("{:?}", self.screen),
+            focus: format!("{:?}", self.focus),
+            active_thread_id: self.active_thread_id.clone(),
+            terminal_width: self.terminal_width,
+            terminal_height: self.terminal_height,
+            scroll: ScrollSnapshot {
+                unified_scroll: self.unified_scroll,
+                max_scroll: self.max_scroll,
+                user_has_scrolled: self.user_has_scrolled,
+                total_content_lines: self.total_content_lines,
+                height_cache_thread,
+                height_cache_messages,
+                height_cache_total_lines,
+                height_cache_width,
+            },
+            streaming: StreamingSnapshot {
+                active_thread_streaming: self.is_streaming(),
+                streaming_threads,
+                cancel_in_progress: self.cancel_in_progress,
+                compacting: self.compacting.clone(),
+                stream_error: self
+                    .stream_error
+                    .as_ref()
+                    .map(|e| e.chars().take(ERROR_PREVIEW_CHARS).collect()),
+            },
+            pending: PendingSnapshot {
+                permissions: self.dashboard.pending_permissions_iter().count(),
+                questions: self.dashboard.pending_question_count(),
+                active_permission_tool: active
+                    .and_then(|id| self.dashboard.get_pending_permission(id))
+                    .map(|p| p.tool_name.clone()),
+                active_question: active
+                    .is_some_and(|id| self.dashboard.get_pending_question(id).is_some()),
+                question_tab: self.question_state.tab_index,
+                queued_messages: self.outgoing_queue.values().map(|q| q.len()).sum(),
+            },
+            connection: ConnectionSnapshot {
+                ws_state: format!("{:?}", self.ws_connection_state),
+                ws_reconnect_attempts: self.ws_reconnect_attempts,
+                ws_unacked_messages: self.ws_unacked_messages,
+                ws_queued_messages: self.ws_queued_messages.len(),
+                polling_mode: self.polling_mode,
+                connected: self.connection_status,
+            },
+            overlays: OverlaySnapshot {
+                dashboard_overlay: self.dashboard.overlay().map(overlay_kind),
+                thread_switcher: self.thread_switcher.visible,
+                unified_picker: self.unified_picker.visible,
+                file_picker: self.file_picker.visible,
+                folder_picker: self.folder_picker_visible,
+                slash_autocomplete: self.slash_autocomplete_visible,
+                model_picker: self.model_picker.is_some(),
+                help_dialog: self.help_dialog_visible,
+                keys_dialog: self.keys_dialog_visible,
+                link_editor: self.link_editor.is_some(),
+                share_dialog: self.share_dialog.is_some(),
+                thread_rename: self.thread_rename.is_some(),
+                thread_delete_confirm: self.thread_delete_confirm.is_some(),
+                thread_archive_confirm: self.thread_archive_confirm.is_some(),
+                quit_confirm: self.quit_confirm.is_some(),
+                bypass_confirm: self.bypass_confirm.is_some(),
+                rate_limit_modal: self.rate_limit_modal.is_some(),
+                notifications_panel: self.notifications_panel.is_some(),
+                event_log: self.event_log_view.is_some(),
+                log_view: self.log_view.is_some(),
+                status_view: self.status_view.is_some(),
+                usage_view: self.usage_view.is_some(),
+                permission_detail: self.permission_detail.is_some(),
+                whats_new: self.whats_new.is_some(),
+                sync_dialog: self.sync_status != SyncStatus::Idle,
+            },
+            cache: CacheSnapshot {
+                thread_count: self.cache.thread_count(),
+                total_messages: messages_per_thread.values().sum(),
+                messages_per_thread,
+            },
+        };
+
+        serde_json::to_value(snapshot).unwrap_or(serde_json::Value::Null)
+    }
+
+    /// Refresh the state served by the debug server.
+    ///
+    /// Skipped when the debug server isn't running or a request is reading
+    /// the previous snapshot right now.
+    pub(super) fn publish_debug_snapshot(&self) {
+        let Some(shared) = &self.debug_state_snapshot else {
+            return;
+        };
+        let Ok(mut state) = shared.try_write() else {
+            return;
+        };
+        let app = self.debug_snapshot();
+        state.threads_count = self.cache.thread_count();
+        state.messages_count = app["cache"]["total_messages"].as_u64().unwrap_or(0) as usize;
+        state.is_streaming = self.is_streaming();
+        state.thread_id = self.active_thread_id.clone();
+        state.active_subagents = self.subagent_tracker.active_count() as u32;
+        state.app = Some(app);
+    }
+
+    /// Handle `/debug <argument>`: `snapshot` (the default) writes a snapshot
+    /// to a temp file and shows its path.
+    pub fn debug_command(&mut self, argument: &str) {
+        match argument.trim() {
+            "" | "snapshot" => self.write_debug_snapshot(),
+            other => self.set_timed_error(
+                format!("Unknown /debug option '{}' (try /debug snapshot)", other),
+                SNAPSHOT_NOTICE_DURATION,
+            ),
+        }
+    }
+
+    fn write_debug_snapshot(&mut self) {
+        let path: PathBuf = std::env::temp_dir().join(format!(
+            "spoq-snapshot-{}.json",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let result = serde_json::to_string_pretty(&self.debug_snapshot())
+            .map_err(std::io::Error::other)
+            .and_then(|json| std::fs::write(&path, json));
+
+        match result {
+            Ok(()) => {
+                tracing::info!("Wrote debug snapshot to {}", path.display());
+                self.set_status_notice(
+                    format!("Snapshot written to {}", path.display()),
+                    SNAPSHOT_NOTICE_DURATION,
+                );
+            }
+            Err(e) => self.set_timed_error(
+                format!("Couldn't write snapshot: {}", e),
+                SNAPSHOT_NOTICE_DURATION,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    #[test]
+    fn test_debug_snapshot_reports_state_without_bodies() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.active_thread_id = Some("thread-001".to_string());
+        app.unified_scroll = 7;
+        app.max_scroll = 40;
+        app.thread_switcher.visible = true;
+        app.credentials.access_token = Some("secret-token".to_string());
+
+        let snapshot = app.debug_snapshot();
+
+        assert_eq!(snapshot["active_thread_id"], "thread-001");
+        assert_eq!(snapshot["scroll"]["unified_scroll"], 7);
+        assert_eq!(snapshot["scroll"]["max_scroll"], 40);
+        assert_eq!(snapshot["overlays"]["thread_switcher"], true);
+        assert_eq!(snapshot["overlays"]["sync_dialog"], false);
+        assert!(snapshot["cache"]["thread_count"].as_u64().unwrap() > 0);
+
+        let json = snapshot.to_string();
+        assert!(!json.contains("secret-token"));
+        for messages in app.cache.messages.values() {
+            for message in messages {
+                if message.content.len() > 8 {
+                    assert!(!json.contains(message.content.as_str()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_debug_snapshot_truncates_stream_error() {
+        let mut app = App::default();
+        app.stream_error = Some("x".repeat(500));
+
+        let snapshot = app.debug_snapshot();
+
+        let error = snapshot["streaming"]["stream_error"].as_str().unwrap();
+        assert_eq!(error.chars().count(), ERROR_PREVIEW_CHARS);
+    }
+
+    #[test]
+    fn test_publish_debug_snapshot_fills_shared_state() {
+        let shared = std::sync::Arc::new(tokio::sync::RwLock::new(
+            crate::debug::StateSnapshot::default(),
+        ));
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.debug_state_snapshot = Some(shared.clone());
+
+        app.publish_debug_snapshot();
+
+        let state = shared.try_read().unwrap();
+        assert_eq!(state.threads_count, app.cache.thread_count());
+        assert!(state.app.is_some());
+    }
+
+    #[test]
+    fn test_debug_command_rejects_unknown_option() {
+        let mut app = App::default();
+
+        app.debug_command("bogus");
+
+        assert!(app.stream_error.as_deref().unwrap().contains("bogus"));
+    }
+}
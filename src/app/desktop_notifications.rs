@@ -0,0 +1,223 @@
+//! Desktop notifications for the App.
+//!
+//! Finished responses, permission prompts, questions, failures and
+//! downloaded updates send a native notification while the terminal is in
+//! the background. Each category can be switched off in `notifications` in
+//! `~/.spoq/config.json` or with `/notify`; quiet hours hold back everything
+//! but failures, and muted threads send none. Notifications can't bring the
+//! TUI to the front, so the body names the thread the event belongs to.
+
+use std::time::Duration;
+
+use chrono::{Local, NaiveTime};
+
+use crate::notifications::{notify, NotificationKind, NotificationSettings};
+
+use super::App;
+
+/// How long `/notify` confirmations stay visible
+const NOTIFY_NOTICE_DURATION: Duration = Duration::from_secs(4);
+
+impl App {
+    /// Send a desktop notification about an event, if the terminal is in
+    /// the background and the settings allow it.
+    ///
+    /// Terminals that never report focus always notify, since we can't tell
+    /// whether the user is looking.
+    pub fn notify_desktop(&self, kind: NotificationKind, thread_id: Option<&str>, detail: &str) {
+        if let Some(body) =
+            self.desktop_notification_body(kind, thread_id, detail, Local::now().time())
+        {
+            notify(kind, body);
+        }
+    }
+
+    /// Body of the notification to send at local time `now`, or None if it
+    /// should not be sent
+    pub(crate) fn desktop_notification_body(
+        &self,
+        kind: NotificationKind,
+        thread_id: Option<&str>,
+        detail: &str,
+        now: NaiveTime,
+    ) -> Option<String> {
+        if self.focus_supported && self.is_focused {
+            return None;
+        }
+        if !self.notification_settings.allows(kind, now) {
+            return None;
+        }
+        if thread_id.is_some_and(|id| self.is_thread_muted(id)) {
+            return None;
+        }
+        let title = thread_id
+            .and_then(|id| self.cache.get_thread(id))
+            .map(|thread| thread.title.as_str())
+            .filter(|title| !title.is_empty());
+        Some(match title {
+            Some(title) => format!("{}: {}", title, detail),
+            None => detail.to_string(),
+        })
+    }
+
+    /// `/notify [on|off|<category>]`.
+    ///
+    /// No argument shows the settings; `on`/`off` flips the master switch and
+    /// a category name (complete, permission, question, failure, update)
+    /// toggles that category.
+    pub fn notify_command(&mut self, argument: &str) {
+        let argument = argument.trim();
+        match argument.to_lowercase().as_str() {
+            "" => {}
+            "on" => self.notification_settings.enabled = true,
+            "off" => self.notification_settings.enabled = false,
+            name => {
+                let Some(kind) = NotificationKind::from_name(name) else {
+                    self.set_timed_error(
+                        "Usage: /notify [on | off | complete | permission | question | failure | update]"
+                            .to_string(),
+                        Duration::from_secs(4),
+                    );
+                    return;
+                };
+                let on = !self.notification_settings.category(kind);
+                self.notification_settings.set_category(kind, on);
+            }
+        }
+        if !argument.is_empty() {
+            self.save_notification_settings();
+        }
+        let summary = notification_summary(&self.notification_settings);
+        self.set_status_notice(summary, NOTIFY_NOTICE_DURATION);
+    }
+
+    /// Mirror the notification settings to the local config
+    fn save_notification_settings(&mut self) {
+        self.config.notifications = self.notification_settings.clone();
+        self.save_config();
+    }
+}
+
+/// One-line description of the settings for the status line
+fn notification_summary(settings: &NotificationSettings) -> String {
+    if !settings.enabled {
+        return "Notifications off (/notify on)".to_string();
+    }
+    let on: Vec<&str> = NotificationKind::ALL
+        .into_iter()
+        .filter(|kind| settings.category(*kind))
+        .map(NotificationKind::name)
+        .collect();
+    let mut summary = if on.is_empty() {
+        "Notifications: all categories off".to_string()
+    } else {
+        format!("Notifications: {}", on.join(", "))
+    };
+    if let Some(quiet) = settings.quiet_hours {
+        summary.push_str(&format!(
+            " · quiet {}–{}",
+            quiet.start.format("%H:%M"),
+            quiet.end.format("%H:%M")
+        ));
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+    use crate::notifications::QuietHours;
+
+    fn at(h: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_notification_body_names_thread_and_respects_focus() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.notification_settings = NotificationSettings::default();
+        let title = app.cache.get_thread("thread-001").unwrap().title.clone();
+
+        app.set_terminal_focus(true);
+        let kind = NotificationKind::StreamComplete;
+        assert!(app
+            .desktop_notification_body(kind, Some("thread-001"), "Response finished", at(12))
+            .is_none());
+
+        app.set_terminal_focus(false);
+        assert_eq!(
+            app.desktop_notification_body(kind, Some("thread-001"), "Response finished", at(12)),
+            Some(format!("{}: Response finished", title))
+        );
+        assert_eq!(
+            app.desktop_notification_body(kind, None, "Update ready", at(12)),
+            Some("Update ready".to_string())
+        );
+    }
+
+    #[test]
+    fn test_quiet_hours_only_let_failures_through() {
+        let mut app = App::default();
+        app.set_terminal_focus(false);
+        app.notification_settings = NotificationSettings {
+            quiet_hours: Some(QuietHours {
+                start: at(22),
+                end: at(8),
+            }),
+            ..Default::default()
+        };
+
+        let complete = NotificationKind::StreamComplete;
+        assert!(app
+            .desktop_notification_body(complete, None, "done", at(23))
+            .is_none());
+        assert!(app
+            .desktop_notification_body(NotificationKind::TaskFailed, None, "failed", at(23))
+            .is_some());
+    }
+
+    #[test]
+    fn test_notification_summary() {
+        let mut settings = NotificationSettings {
+            question_asked: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            notification_summary(&settings),
+            "Notifications: complete, permission, failure, update"
+        );
+        settings.enabled = false;
+        assert_eq!(
+            notification_summary(&settings),
+            "Notifications off (/notify on)"
+        );
+    }
+
+    #[test]
+    fn test_notify_command_saves_config() {
+        use crate::startup::config::SpoqConfig;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        let mut app = App::default();
+        app.config_path = Some(path.clone());
+        app.config.input_max_rows = 3;
+
+        app.notify_command("question");
+        let saved = SpoqConfig::load_from(&path);
+        assert!(!saved.notifications.question_asked);
+        assert_eq!(saved.notifications, app.notification_settings);
+        // The rest of the in-memory config is written with it
+        assert_eq!(saved.input_max_rows, 3);
+
+        app.notify_command("off");
+        assert!(!SpoqConfig::load_from(&path).notifications.enabled);
+
+        // Showing the settings doesn't write
+        std::fs::remove_file(&path).unwrap();
+        app.notify_command("");
+        assert!(!path.exists());
+    }
+}
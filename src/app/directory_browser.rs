@@ -0,0 +1,224 @@
+//! Directory browsing in the unified @ picker.
+//!
+//! The folders section normally shows indexed folders from the backend. On a
+//! fresh host there are none, so the picker falls back to listing
+//! directories (starting from home); a path-like query (`/srv/`, `~/src`)
+//! lists that directory instead. Enter opens the highlighted subdirectory,
+//! Backspace on an empty query goes up, and picking the listed directory
+//! itself works like any other folder pick.
+
+use std::sync::Arc;
+
+use crate::conductor::ConductorError;
+use crate::state::is_path_query;
+
+use super::{App, AppMessage};
+
+impl App {
+    /// Start, move or stop browsing to match the picker's query and folders.
+    ///
+    /// Called whenever either changes.
+    pub(crate) fn sync_directory_browse(&mut self) {
+        let wanted = self.unified_picker.wanted_browse_dir();
+        let current = self
+            .unified_picker
+            .browse
+            .as_ref()
+            .map(|b| b.requested.as_str());
+        match wanted {
+            Some(dir) if current != Some(dir.as_str()) => self.browse_directory(dir),
+            Some(_) => {}
+            None => self.unified_picker.stop_browse(),
+        }
+    }
+
+    /// Open the highlighted subdirectory (Enter while browsing).
+    ///
+    /// # Returns
+    /// `false` if the selection isn't a subdirectory, so Enter picks it as usual
+    pub fn unified_picker_descend(&mut self) -> bool {
+        let Some(path) = self.unified_picker.descend_target() else {
+            return false;
+        };
+        if is_path_query(&self.unified_picker.query) {
+            // The query names the directory: extend it
+            self.replace_unified_picker_query(format!("{}/", path));
+        } else {
+            self.replace_unified_picker_query(String::new());
+            self.browse_directory(path);
+        }
+        true
+    }
+
+    /// Go up a directory (Backspace on an empty query while browsing).
+    ///
+    /// # Returns
+    /// `false` if there is nowhere to go, so the picker closes as usual
+    pub fn unified_picker_ascend(&mut self) -> bool {
+        if !self.unified_picker.query.is_empty() {
+            return false;
+        }
+        let Some(parent) = self.unified_picker.browse_parent() else {
+            return false;
+        };
+        self.browse_directory(parent);
+        true
+    }
+
+    /// Replace the picker query, keeping the `@query` in the input in sync
+    /// (`/cd` doesn't mirror it)
+    fn replace_unified_picker_query(&mut self, query: String) {
+        if !self.unified_picker.changing_directory {
+            self.remove_unified_picker_query_from_input();
+            self.textarea.insert_char('@');
+            for ch in query.chars() {
+                self.textarea.insert_char(ch);
+            }
+        }
+        self.unified_picker.set_query(query);
+        self.sync_directory_browse();
+        self.mark_dirty();
+    }
+
+    /// List `dir` on the backend and show it in the folders section
+    fn browse_directory(&mut self, dir: String) {
+        self.unified_picker.start_browse(dir.clone());
+        self.mark_dirty();
+
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let message = match client.list_directory(&dir).await {
+                Ok(listing) => AppMessage::UnifiedPickerDirectoryLoaded {
+                    requested: dir,
+                    listing,
+                },
+                Err(e) => AppMessage::UnifiedPickerDirectoryFailed {
+                    error: listing_error(&dir, &e),
+                    requested: dir,
+                },
+            };
+            let _ = tx.send(message);
+        });
+    }
+}
+
+/// Inline error for a failed listing ("Permission denied: /root")
+fn listing_error(dir: &str, error: &ConductorError) -> String {
+    match error {
+        ConductorError::ServerError { status: 403, .. } => format!("Permission denied: {}", dir),
+        ConductorError::ServerError { status: 404, .. } => format!("No such directory: {}", dir),
+        ConductorError::ServerError { message, .. } if !message.is_empty() => message.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::picker::{DirectoryListing, FolderEntry, PickerSection};
+
+    fn listing(path: &str, parent: Option<&str>, names: &[&str]) -> DirectoryListing {
+        DirectoryListing {
+            path: path.to_string(),
+            parent: parent.map(str::to_string),
+            entries: names
+                .iter()
+                .map(|name| FolderEntry {
+                    name: name.to_string(),
+                    path: format!("{}/{}", path, name),
+                    is_dir: true,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_listing_error() {
+        let forbidden = ConductorError::ServerError {
+            status: 403,
+            message: "EACCES".to_string(),
+        };
+        assert_eq!(
+            listing_error("/root", &forbidden),
+            "Permission denied: /root"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_empty_folders_fall_back_to_browsing_home() {
+        let mut app = App::default();
+        app.unified_picker.open();
+        app.handle_message(AppMessage::UnifiedPickerFoldersLoaded(vec![]));
+
+        let browse = app.unified_picker.browse.as_ref().unwrap();
+        assert_eq!(browse.requested, "~");
+        assert!(browse.loading);
+
+        app.handle_message(AppMessage::UnifiedPickerDirectoryLoaded {
+            requested: "~".to_string(),
+            listing: listing("/home/me", Some("/home"), &["src"]),
+        });
+        assert_eq!(app.unified_picker.folders.items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_descend_and_ascend() {
+        let mut app = App::default();
+        app.unified_picker.open();
+        app.handle_message(AppMessage::UnifiedPickerFoldersLoaded(vec![]));
+        app.handle_message(AppMessage::UnifiedPickerDirectoryLoaded {
+            requested: "~".to_string(),
+            listing: listing("/home/me", Some("/home"), &["src"]),
+        });
+        app.unified_picker.selected_section = PickerSection::Folders;
+        app.unified_picker.selected_index = 1;
+
+        assert!(app.unified_picker_descend());
+        assert_eq!(
+            app.unified_picker.browse.as_ref().unwrap().requested,
+            "/home/me/src"
+        );
+
+        app.handle_message(AppMessage::UnifiedPickerDirectoryFailed {
+            requested: "/home/me/src".to_string(),
+            error: "Permission denied: /home/me/src".to_string(),
+        });
+        assert!(app.unified_picker.visible);
+
+        assert!(app.unified_picker_ascend());
+        assert_eq!(
+            app.unified_picker.browse.as_ref().unwrap().requested,
+            "/home/me"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_path_query_descends_by_extending_query() {
+        let mut app = App::default();
+        app.unified_picker.open();
+        app.textarea.insert_char('@');
+        for c in "/srv/".chars() {
+            app.unified_picker_type_char(c);
+            app.textarea.insert_char(c);
+        }
+        assert_eq!(
+            app.unified_picker.browse.as_ref().unwrap().requested,
+            "/srv/"
+        );
+
+        app.handle_message(AppMessage::UnifiedPickerDirectoryLoaded {
+            requested: "/srv/".to_string(),
+            listing: listing("/srv", Some("/"), &["app"]),
+        });
+        app.unified_picker.selected_index = 1;
+        assert!(app.unified_picker_descend());
+
+        assert_eq!(app.unified_picker.query, "/srv/app/");
+        assert_eq!(app.textarea.content(), "@/srv/app/");
+        assert_eq!(
+            app.unified_picker.browse.as_ref().unwrap().requested,
+            "/srv/app/"
+        );
+    }
+}
@@ -0,0 +1,320 @@
+//! Opening finished answers in `$EDITOR`.
+//!
+//! `/editor answer` (or `/editor code`) sets up the active thread so that
+//! each response, once it finishes streaming, is written to a scratch file in
+//! `~/.spoq/scratch/` — the whole answer as Markdown, or only its last code
+//! block — and opened in `$VISUAL`/`$EDITOR`. `/editor off` turns it off and
+//! a bare `/editor` shows the current setting. The setting is per thread and
+//! mirrored to `editor_handoff` in `~/.spoq/config.json`.
+//!
+//! The editor only opens for the thread on screen. Answers finishing in
+//! other threads are still written; the path is shown as a notice.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache::write_export;
+use crate::markdown::extract_code_blocks;
+
+use super::{App, Screen};
+
+/// How long handoff notices stay visible
+const HANDOFF_NOTICE_DURATION: Duration = Duration::from_secs(4);
+
+/// What a thread hands to the editor when a response finishes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EditorHandoff {
+    /// The whole answer, as Markdown
+    Answer,
+    /// The last code block of the answer
+    Code,
+}
+
+impl EditorHandoff {
+    /// Argument of `/editor` selecting this mode
+    pub fn name(self) -> &'static str {
+        match self {
+            EditorHandoff::Answer => "answer",
+            EditorHandoff::Code => "code",
+        }
+    }
+}
+
+/// Directory scratch files are written to (`~/.spoq/scratch`)
+pub fn default_scratch_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".spoq").join("scratch"))
+}
+
+/// Contents of the scratch file for a finished answer, with its file
+/// extension. None if there's nothing to hand off (no code block in `Code`
+/// mode).
+pub fn handoff_contents(mode: EditorHandoff, answer: &str) -> Option<(String, &'static str)> {
+    match mode {
+        EditorHandoff::Answer => (!answer.trim().is_empty()).then(|| (answer.to_string(), "md")),
+        EditorHandoff::Code => extract_code_blocks(answer)
+            .pop()
+            .map(|block| (block.code, extension_for(block.language.as_deref()))),
+    }
+}
+
+/// File extension for a code block language, so the editor picks the right
+/// syntax highlighting
+fn extension_for(language: Option<&str>) -> &'static str {
+    match language.map(str::to_lowercase).as_deref() {
+        Some("rust" | "rs") => "rs",
+        Some("python" | "py") => "py",
+        Some("javascript" | "js") => "js",
+        Some("typescript" | "ts") => "ts",
+        Some("tsx") => "tsx",
+        Some("jsx") => "jsx",
+        Some("go" | "golang") => "go",
+        Some("bash" | "sh" | "shell" | "zsh") => "sh",
+        Some("json") => "json",
+        Some("yaml" | "yml") => "yaml",
+        Some("toml") => "toml",
+        Some("html") => "html",
+        Some("css") => "css",
+        Some("sql") => "sql",
+        Some("c") => "c",
+        Some("cpp" | "c++") => "cpp",
+        Some("java") => "java",
+        Some("ruby" | "rb") => "rb",
+        Some("markdown" | "md") => "md",
+        Some("diff" | "patch") => "diff",
+        _ => "txt",
+    }
+}
+
+impl App {
+    /// `/editor <answer|code|off>` for the active thread
+    pub fn set_editor_handoff_command(&mut self, argument: &str) {
+        let mode = match argument.to_lowercase().as_str() {
+            "answer" => Some(EditorHandoff::Answer),
+            "code" => Some(EditorHandoff::Code),
+            "off" => None,
+            _ => {
+                self.set_timed_error(
+                    "Usage: /editor answer | code | off".to_string(),
+                    Duration::from_secs(3),
+                );
+                return;
+            }
+        };
+        let Some(thread_id) = self.active_handoff_target() else {
+            return;
+        };
+        self.set_editor_handoff(&thread_id, mode);
+
+        let notice = match mode {
+            Some(EditorHandoff::Answer) => "Finished answers will open in $EDITOR",
+            Some(EditorHandoff::Code) => "The last code block of each answer will open in $EDITOR",
+            None => "Editor handoff off for this thread",
+        };
+        self.set_status_notice(notice.to_string(), HANDOFF_NOTICE_DURATION);
+    }
+
+    /// Bare `/editor`: show the active thread's setting
+    pub fn show_editor_handoff(&mut self) {
+        let Some(thread_id) = self.active_handoff_target() else {
+            return;
+        };
+        let notice = match self.editor_handoff.get(&thread_id) {
+            Some(mode) => format!("Editor handoff: {} (/editor off to stop)", mode.name()),
+            None => "Editor handoff off (/editor answer or /editor code)".to_string(),
+        };
+        self.set_status_notice(notice, HANDOFF_NOTICE_DURATION);
+    }
+
+    /// Set or clear a thread's editor handoff and save it to the local config
+    pub fn set_editor_handoff(&mut self, thread_id: &str, mode: Option<EditorHandoff>) {
+        match mode {
+            Some(mode) => self.editor_handoff.insert(thread_id.to_string(), mode),
+            None => self.editor_handoff.remove(thread_id),
+        };
+
+        self.config.editor_handoff = self.editor_handoff.clone();
+        self.save_config();
+    }
+
+    /// Hand a finished answer off to the editor, if its thread asks for it.
+    ///
+    /// Called when a response completes.
+    pub(super) fn hand_off_finished_answer(&mut self, thread_id: &str, message_id: i64) {
+        let Some(mode) = self.editor_handoff.get(thread_id).copied() else {
+            return;
+        };
+        let Some(dir) = default_scratch_dir() else {
+            return;
+        };
+        self.hand_off_answer_to(thread_id, message_id, mode, &dir);
+    }
+
+    /// Write a finished answer to a scratch file in `dir`, queueing it to
+    /// open in the editor if its thread is on screen.
+    ///
+    /// Returns the path written, if any.
+    pub(crate) fn hand_off_answer_to(
+        &mut self,
+        thread_id: &str,
+        message_id: i64,
+        mode: EditorHandoff,
+        dir: &Path,
+    ) -> Option<PathBuf> {
+        let answer = self
+            .cache
+            .get_messages(thread_id)?
+            .iter()
+            .find(|m| m.id == message_id)
+            .map(|m| m.content.clone())?;
+
+        let Some((contents, extension)) = handoff_contents(mode, &answer) else {
+            if mode == EditorHandoff::Code {
+                self.set_status_notice(
+                    "No code block in the answer to open".to_string(),
+                    HANDOFF_NOTICE_DURATION,
+                );
+            }
+            return None;
+        };
+
+        let short_id: String = thread_id
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .take(12)
+            .collect();
+        let path = dir.join(format!("spoq-{}-{}.{}", short_id, message_id, extension));
+        if let Err(e) = write_export(&path, &contents) {
+            self.add_error_to_active_thread(
+                "editor_handoff_failed".to_string(),
+                format!("Writing {} failed: {}", path.display(), e),
+            );
+            return None;
+        }
+
+        let on_screen = self.screen == Screen::Conversation
+            && self.active_thread_id.as_deref() == Some(thread_id);
+        if on_screen {
+            self.pending_editor_open = Some(path.clone());
+        } else {
+            self.set_status_notice(
+                format!("Answer saved to {}", path.display()),
+                HANDOFF_NOTICE_DURATION,
+            );
+        }
+        Some(path)
+    }
+
+    /// The thread `/editor` applies to, if one is open
+    fn active_handoff_target(&mut self) -> Option<String> {
+        let thread_id = self
+            .active_thread_id
+            .clone()
+            .filter(|_| self.screen == Screen::Conversation);
+        if thread_id.is_none() {
+            self.set_timed_error(
+                "Open a thread to set its editor handoff".to_string(),
+                Duration::from_secs(3),
+            );
+        }
+        thread_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+    use crate::models::MessageRole;
+
+    const ANSWER: &str = "Try this:\n\n```rust\nfn a() {}\n```\n\nOr:\n\n```python\nb()\n```\n";
+
+    fn app_with_answer() -> (App, i64) {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.cache
+            .add_message_simple("thread-001", MessageRole::Assistant, ANSWER.to_string());
+        let id = app
+            .cache
+            .get_messages("thread-001")
+            .unwrap()
+            .last()
+            .unwrap()
+            .id;
+        (app, id)
+    }
+
+    #[test]
+    fn test_handoff_contents() {
+        assert_eq!(
+            handoff_contents(EditorHandoff::Answer, ANSWER),
+            Some((ANSWER.to_string(), "md"))
+        );
+        assert_eq!(
+            handoff_contents(EditorHandoff::Code, ANSWER),
+            Some(("b()\n".to_string(), "py"))
+        );
+        assert_eq!(handoff_contents(EditorHandoff::Code, "no code"), None);
+        assert_eq!(handoff_contents(EditorHandoff::Answer, "  \n"), None);
+    }
+
+    #[test]
+    fn test_hand_off_opens_editor_for_active_thread() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut app, id) = app_with_answer();
+
+        let path = app
+            .hand_off_answer_to("thread-001", id, EditorHandoff::Code, dir.path())
+            .unwrap();
+
+        assert_eq!(path.extension().unwrap(), "py");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "b()\n");
+        assert_eq!(app.pending_editor_open.as_ref(), Some(&path));
+    }
+
+    #[test]
+    fn test_hand_off_in_background_only_writes_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut app, id) = app_with_answer();
+        app.active_thread_id = Some("thread-002".to_string());
+
+        let path = app
+            .hand_off_answer_to("thread-001", id, EditorHandoff::Answer, dir.path())
+            .unwrap();
+
+        assert!(path.exists());
+        assert!(app.pending_editor_open.is_none());
+        assert!(app
+            .status_notice
+            .as_deref()
+            .unwrap()
+            .contains("Answer saved"));
+    }
+
+    #[test]
+    fn test_set_editor_handoff_saves_config() {
+        use crate::startup::config::SpoqConfig;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        let mut app = App::default();
+        app.config_path = Some(path.clone());
+        app.config.vim_navigation = true;
+
+        app.set_editor_handoff("thread-001", Some(EditorHandoff::Code));
+        let saved = SpoqConfig::load_from(&path);
+        assert_eq!(
+            saved.editor_handoff.get("thread-001"),
+            Some(&EditorHandoff::Code)
+        );
+        // The rest of the in-memory config is written with it
+        assert!(saved.vim_navigation);
+
+        app.set_editor_handoff("thread-001", None);
+        assert!(SpoqConfig::load_from(&path).editor_handoff.is_empty());
+    }
+}
@@ -0,0 +1,282 @@
+//! Raw event log for the App.
+//!
+//! Every SSE event of a thread's stream and every WebSocket message naming a
+//! thread is recorded (type, arrival time, payload size and the start of the
+//! payload) in a bounded per-thread log, for debugging what the agent
+//! actually sent. Recording happens in the message pipeline: streams are
+//! wrapped by `record_sse_events` before `process_stream` consumes them, and
+//! the WebSocket task records each message before routing it. `/events` lists
+//! the active thread's log, newest first.
+
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures_util::{Stream, StreamExt};
+use tokio::sync::mpsc;
+
+use crate::conductor::ConductorError;
+use crate::events::SseEvent;
+use crate::websocket::WsIncomingMessage;
+
+use super::{truncate_for_debug, App, AppMessage, EventLogViewState, Screen};
+
+/// Events kept per thread (oldest dropped first)
+pub const MAX_EVENTS_PER_THREAD: usize = 500;
+
+/// Threads with a log; the least recently active one is dropped beyond this
+const MAX_LOGGED_THREADS: usize = 50;
+
+/// Characters of the payload kept per event
+pub const EVENT_PAYLOAD_EXCERPT_LEN: usize = 200;
+
+/// Stream of events as returned by the conductor client
+type SseEventStream = Pin<Box<dyn Stream<Item = Result<SseEvent, ConductorError>> + Send>>;
+
+/// Transport an event arrived on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventSource {
+    Sse,
+    Ws,
+}
+
+impl EventSource {
+    /// Short label shown in the log
+    pub fn label(&self) -> &'static str {
+        match self {
+            EventSource::Sse => "sse",
+            EventSource::Ws => "ws",
+        }
+    }
+}
+
+/// One received event
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventRecord {
+    pub source: EventSource,
+    /// Event type as sent on the wire (`tool_result`, `thread_updated`, ...)
+    pub event_type: String,
+    pub received_at: DateTime<Utc>,
+    /// Size of the full payload in bytes
+    pub payload_size: usize,
+    /// Start of the payload (`EVENT_PAYLOAD_EXCERPT_LEN` characters)
+    pub payload: String,
+}
+
+impl EventRecord {
+    /// Record an event received now
+    pub fn new(source: EventSource, event_type: impl Into<String>, payload: &str) -> Self {
+        Self {
+            source,
+            event_type: event_type.into(),
+            received_at: Utc::now(),
+            payload_size: payload.len(),
+            payload: truncate_for_debug(payload, EVENT_PAYLOAD_EXCERPT_LEN),
+        }
+    }
+}
+
+/// Bounded per-thread event log
+#[derive(Debug, Default)]
+pub struct EventLog {
+    threads: HashMap<String, VecDeque<EventRecord>>,
+}
+
+impl EventLog {
+    /// Append an event to a thread's log
+    pub fn record(&mut self, thread_id: &str, record: EventRecord) {
+        if !self.threads.contains_key(thread_id) && self.threads.len() >= MAX_LOGGED_THREADS {
+            self.evict_least_recent();
+        }
+        let events = self.threads.entry(thread_id.to_string()).or_default();
+        if events.len() >= MAX_EVENTS_PER_THREAD {
+            events.pop_front();
+        }
+        events.push_back(record);
+    }
+
+    /// A thread's events, oldest first
+    pub fn events(&self, thread_id: &str) -> impl DoubleEndedIterator<Item = &EventRecord> {
+        self.threads.get(thread_id).into_iter().flatten()
+    }
+
+    /// Number of events logged for a thread
+    pub fn len(&self, thread_id: &str) -> usize {
+        self.threads.get(thread_id).map_or(0, VecDeque::len)
+    }
+
+    /// Drop the log of the thread whose last event is oldest
+    fn evict_least_recent(&mut self) {
+        let oldest = self
+            .threads
+            .iter()
+            .min_by_key(|(_, events)| events.back().map(|e| e.received_at))
+            .map(|(thread_id, _)| thread_id.clone());
+        if let Some(thread_id) = oldest {
+            self.threads.remove(&thread_id);
+        }
+    }
+}
+
+/// Wrap a thread's stream so each event is reported to the event log
+/// (`AppMessage::EventReceived`) as it passes through.
+pub(super) fn record_sse_events(
+    stream: SseEventStream,
+    message_tx: mpsc::UnboundedSender<AppMessage>,
+    thread_id: String,
+) -> SseEventStream {
+    Box::pin(stream.inspect(move |result| {
+        if let Ok(event) = result {
+            let record = EventRecord::new(
+                EventSource::Sse,
+                event.event_type_name(),
+                &format!("{:?}", event),
+            );
+            let _ = message_tx.send(AppMessage::EventReceived {
+                thread_id: thread_id.clone(),
+                record,
+            });
+        }
+    }))
+}
+
+/// Event log entry for a WebSocket message, None if it names no thread
+pub(super) fn ws_event_record(msg: &WsIncomingMessage) -> Option<(String, EventRecord)> {
+    let value = serde_json::to_value(msg).ok()?;
+    let thread_id = value.get("thread_id")?.as_str()?.to_string();
+    let event_type = value
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or("unknown");
+    let record = EventRecord::new(EventSource::Ws, event_type, &value.to_string());
+    Some((thread_id, record))
+}
+
+impl App {
+    /// Open the active thread's event log (`/events`)
+    pub fn open_event_log(&mut self) {
+        let thread_id = match (self.screen, self.active_thread_id.as_ref()) {
+            (Screen::Conversation, Some(thread_id)) => thread_id.clone(),
+            _ => {
+                self.set_timed_error(
+                    "Open a thread to see its event log".to_string(),
+                    Duration::from_secs(4),
+                );
+                return;
+            }
+        };
+        self.event_log_view = Some(EventLogViewState {
+            thread_id,
+            scroll: 0,
+        });
+        self.mark_dirty();
+    }
+
+    /// Close the event log
+    pub fn close_event_log(&mut self) {
+        self.event_log_view = None;
+        self.mark_dirty();
+    }
+
+    /// Scroll the event log by `delta` entries (positive = older)
+    pub fn scroll_event_log(&mut self, delta: isize) {
+        let Some(view) = self.event_log_view.as_mut() else {
+            return;
+        };
+        let count = self.event_log.len(&view.thread_id);
+        view.scroll = view
+            .scroll
+            .saturating_add_signed(delta)
+            .min(count.saturating_sub(1));
+        self.mark_dirty();
+    }
+
+    /// Append a received event to the log, redrawing if it's on screen
+    pub(super) fn record_event(&mut self, thread_id: &str, record: EventRecord) {
+        self.event_log.record(thread_id, record);
+        if self
+            .event_log_view
+            .as_ref()
+            .is_some_and(|view| view.thread_id == thread_id)
+        {
+            self.mark_dirty();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_log_is_bounded_per_thread() {
+        let mut log = EventLog::default();
+        for i in 0..MAX_EVENTS_PER_THREAD + 5 {
+            log.record(
+                "t1",
+                EventRecord::new(EventSource::Sse, "content", &i.to_string()),
+            );
+        }
+        assert_eq!(log.len("t1"), MAX_EVENTS_PER_THREAD);
+        assert_eq!(log.events("t1").next().unwrap().payload, "5");
+        assert_eq!(log.len("other"), 0);
+
+        let long = "x".repeat(1000);
+        let record = EventRecord::new(EventSource::Sse, "tool_result", &long);
+        assert_eq!(record.payload_size, 1000);
+        assert_eq!(record.payload.len(), EVENT_PAYLOAD_EXCERPT_LEN);
+    }
+
+    #[tokio::test]
+    async fn test_record_sse_events_passes_events_through() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let events: Vec<Result<SseEvent, ConductorError>> =
+            vec![Ok(SseEvent::Done(crate::events::DoneEvent {
+                message_id: "4".to_string(),
+            }))];
+        let stream: SseEventStream = Box::pin(futures_util::stream::iter(events));
+
+        let mut recorded = record_sse_events(stream, tx, "t1".to_string());
+        assert!(matches!(recorded.next().await, Some(Ok(SseEvent::Done(_)))));
+
+        match rx.try_recv() {
+            Ok(AppMessage::EventReceived { thread_id, record }) => {
+                assert_eq!(thread_id, "t1");
+                assert_eq!(record.source, EventSource::Sse);
+                assert_eq!(record.event_type, "done");
+            }
+            other => panic!("Expected EventReceived, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ws_event_record_needs_thread_id() {
+        let msg = WsIncomingMessage::ThreadDeleted(crate::websocket::messages::WsThreadDeleted {
+            thread_id: "t1".to_string(),
+            timestamp: 0,
+        });
+        let (thread_id, record) = ws_event_record(&msg).unwrap();
+        assert_eq!(thread_id, "t1");
+        assert_eq!(record.source, EventSource::Ws);
+        assert_eq!(record.event_type, "thread_deleted");
+
+        assert!(ws_event_record(&WsIncomingMessage::PendingAcks(2)).is_none());
+    }
+
+    #[test]
+    fn test_open_event_log_requires_thread() {
+        let mut app = App::default();
+        app.open_event_log();
+        assert!(app.event_log_view.is_none());
+
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("t1".to_string());
+        for _ in 0..3 {
+            app.record_event("t1", EventRecord::new(EventSource::Ws, "ping", "{}"));
+        }
+        app.open_event_log();
+        app.scroll_event_log(10);
+        assert_eq!(app.event_log_view.as_ref().unwrap().scroll, 2);
+    }
+}
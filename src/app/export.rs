@@ -0,0 +1,117 @@
+//! `/export` for the active thread.
+//!
+//! Writes the thread as Markdown to `~/.spoq/exports/`. Success shows the
+//! output path as a status notice; write failures are shown inline in the
+//! conversation.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::cache::{default_export_dir, default_export_file_name, write_export, ExportFormat};
+
+use super::App;
+
+/// How long the "Exported to ..." confirmation stays visible
+const EXPORT_NOTICE_DURATION: Duration = Duration::from_secs(5);
+
+impl App {
+    /// Export the active thread to the default export directory.
+    ///
+    /// Returns the path written, if any.
+    pub fn export_active_thread(&mut self) -> Option<PathBuf> {
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            self.set_timed_error(
+                "Open a thread to export it".to_string(),
+                Duration::from_secs(3),
+            );
+            return None;
+        };
+        let dir = default_export_dir()?;
+        let file_name = default_export_file_name(&thread_id, ExportFormat::Markdown);
+        self.export_thread_to(&thread_id, dir.join(file_name))
+    }
+
+    /// Export a cached thread as Markdown to `path`.
+    pub(crate) fn export_thread_to(&mut self, thread_id: &str, path: PathBuf) -> Option<PathBuf> {
+        let Some(contents) = self.cache.export_thread(thread_id, ExportFormat::Markdown) else {
+            self.set_timed_error(
+                "Nothing to export yet — thread has no messages".to_string(),
+                Duration::from_secs(3),
+            );
+            return None;
+        };
+
+        match write_export(&path, &contents) {
+            Ok(()) => {
+                self.set_status_notice(
+                    format!("Exported to {}", path.display()),
+                    EXPORT_NOTICE_DURATION,
+                );
+                Some(path)
+            }
+            Err(e) => {
+                self.add_error_to_active_thread(
+                    "export_failed".to_string(),
+                    format!("Export to {} failed: {}", path.display(), e),
+                );
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn app_with_thread() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.active_thread_id = Some("thread-001".to_string());
+        app.cache.add_message_simple(
+            "thread-001",
+            crate::models::MessageRole::User,
+            "Hello".to_string(),
+        );
+        app
+    }
+
+    #[test]
+    fn test_export_writes_markdown_and_shows_path() {
+        let mut app = app_with_thread();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("thread.md");
+
+        let written = app.export_thread_to("thread-001", path.clone());
+
+        assert_eq!(written.as_ref(), Some(&path));
+        assert!(std::fs::read_to_string(&path).unwrap().starts_with("# "));
+        assert!(app
+            .status_notice
+            .as_deref()
+            .is_some_and(|n| n.contains("thread.md")));
+    }
+
+    #[test]
+    fn test_export_write_failure_is_inline_error() {
+        let mut app = app_with_thread();
+        let dir = tempfile::tempdir().unwrap();
+        // A file where a directory is expected makes the write fail
+        let blocker = dir.path().join("blocker");
+        std::fs::write(&blocker, "").unwrap();
+        let errors_before = app.cache.error_count("thread-001");
+
+        let written = app.export_thread_to("thread-001", blocker.join("thread.md"));
+
+        assert!(written.is_none());
+        assert_eq!(app.cache.error_count("thread-001"), errors_before + 1);
+    }
+
+    #[test]
+    fn test_export_without_thread() {
+        let mut app = App::default();
+        assert!(app.export_active_thread().is_none());
+        assert!(app.stream_error.is_some());
+    }
+}
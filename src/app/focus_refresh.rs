@@ -0,0 +1,177 @@
+//! Catching up after the terminal regains focus.
+//!
+//! While the terminal is unfocused, streamed tokens still go into the cache
+//! but don't trigger redraws, and messages finishing in threads the user
+//! can't see add to per-thread unread counts ("● 12 new" on thread rows).
+//! When focus returns after a while away, the thread list and the pending
+//! permissions are re-fetched, and loaded conversations the backend has
+//! newer messages for are reloaded.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::models::Thread;
+
+use super::{App, AppMessage, Screen};
+
+/// Time away after which regaining focus refreshes from the backend
+const FOCUS_REFRESH_AFTER: Duration = Duration::from_secs(30);
+
+/// Whether focus returned after long enough away to refresh
+fn refreshes_after(away: Duration) -> bool {
+    away >= FOCUS_REFRESH_AFTER
+}
+
+impl App {
+    /// Redraw and catch up with the backend when focus returns
+    pub(super) fn on_focus_gained(&mut self) {
+        let away = self.focus_lost_at.take().map(|at| at.elapsed());
+        if let Some(thread_id) = self.visible_thread_id().map(str::to_string) {
+            self.unread_counts.remove(&thread_id);
+        }
+        self.mark_dirty();
+
+        if away.is_some_and(refreshes_after) {
+            self.refresh_after_focus();
+        }
+    }
+
+    /// Thread the user is looking at, if any
+    fn visible_thread_id(&self) -> Option<&str> {
+        if !self.is_focused || self.screen != Screen::Conversation {
+            return None;
+        }
+        self.active_thread_id.as_deref()
+    }
+
+    /// Count a message that arrived in a thread, unless it's in view
+    pub(super) fn note_new_message(&mut self, thread_id: &str) {
+        if self.visible_thread_id() != Some(thread_id) {
+            *self.unread_counts.entry(thread_id.to_string()).or_default() += 1;
+        }
+    }
+
+    /// Messages that arrived in a thread while it wasn't visible
+    pub fn unread_count(&self, thread_id: &str) -> usize {
+        self.unread_counts.get(thread_id).copied().unwrap_or(0)
+    }
+
+    /// Re-fetch the thread list and the pending permissions
+    fn refresh_after_focus(&mut self) {
+        tracing::info!("Terminal focused again, refreshing threads");
+        let client = Arc::clone(&self.client);
+        let message_tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            match client.fetch_threads().await {
+                Ok(threads) => {
+                    let _ = message_tx.send(AppMessage::DashboardDataRefreshed { threads });
+                }
+                Err(e) => tracing::warn!("Failed to refresh threads on focus: {:?}", e),
+            }
+        });
+
+        if self.dashboard.has_pending_permission() && !self.permission_recheck_in_flight {
+            self.recheck_pending_permissions();
+        }
+    }
+
+    /// Compare refreshed threads with the local copies: threads with new
+    /// messages get unread counts, and loaded conversations are reloaded
+    /// (unless a response is streaming into them).
+    ///
+    /// Called before the refreshed threads replace the local ones.
+    pub(super) fn note_thread_updates(&mut self, threads: &[Thread]) {
+        let mut stale = Vec::new();
+        for thread in threads {
+            let Some(local) = self.cache.get_thread(&thread.id) else {
+                continue;
+            };
+            if thread.updated_at <= local.updated_at {
+                continue;
+            }
+            let new_messages = thread
+                .message_count
+                .saturating_sub(local.message_count)
+                .max(0) as usize;
+            if new_messages > 0 && self.visible_thread_id() != Some(thread.id.as_str()) {
+                // Messages counted as they streamed in may be among them
+                let unread = self.unread_counts.entry(thread.id.clone()).or_default();
+                *unread = (*unread).max(new_messages);
+            }
+            if self.cache.get_messages(&thread.id).is_some()
+                && !self.cache.is_thread_streaming(&thread.id)
+            {
+                stale.push(thread.id.clone());
+            }
+        }
+
+        for thread_id in stale {
+            tracing::info!("Reloading messages of updated thread {}", thread_id);
+            self.fetch_latest_messages(&thread_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn app() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app
+    }
+
+    #[test]
+    fn test_unread_counts_skip_the_visible_thread() {
+        let mut app = app();
+        app.active_thread_id = Some("thread-001".to_string());
+        app.screen = Screen::Conversation;
+
+        app.note_new_message("thread-001");
+        app.note_new_message("thread-002");
+        assert_eq!(app.unread_count("thread-001"), 0);
+        assert_eq!(app.unread_count("thread-002"), 1);
+
+        // Unfocused, the open thread isn't visible either
+        app.set_terminal_focus(false);
+        app.note_new_message("thread-001");
+        app.note_new_message("thread-001");
+        assert_eq!(app.unread_count("thread-001"), 2);
+
+        // Coming back to it reads it
+        app.set_terminal_focus(true);
+        assert_eq!(app.unread_count("thread-001"), 0);
+        assert_eq!(app.unread_count("thread-002"), 1);
+    }
+
+    #[test]
+    fn test_focus_gained_redraws_and_refreshes_after_a_while() {
+        let mut app = app();
+        app.set_terminal_focus(false);
+        app.needs_redraw = false;
+        // A quick Alt-Tab only redraws
+        app.set_terminal_focus(true);
+        assert!(app.needs_redraw);
+        assert!(app.focus_lost_at.is_none());
+
+        assert!(!refreshes_after(Duration::from_secs(5)));
+        assert!(refreshes_after(Duration::from_secs(45 * 60)));
+    }
+
+    #[tokio::test]
+    async fn test_refreshed_threads_with_new_messages_count_as_unread() {
+        let mut app = app();
+        let mut updated = app.cache.get_thread("thread-002").unwrap().clone();
+        updated.updated_at += chrono::Duration::minutes(5);
+        updated.message_count += 3;
+        let mut unchanged = app.cache.get_thread("thread-003").unwrap().clone();
+        unchanged.message_count += 3;
+
+        app.note_new_message("thread-002");
+        app.note_thread_updates(&[updated, unchanged]);
+        assert_eq!(app.unread_count("thread-002"), 3);
+        assert_eq!(app.unread_count("thread-003"), 0);
+    }
+}
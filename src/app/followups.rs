@@ -0,0 +1,507 @@
+//! TODO/FIXME follow-ups for the App.
+//!
+//! When a response finishes, the file edits it made (Edit, MultiEdit, Write)
+//! and any diffs its tools printed are scanned for TODO, FIXME, XXX and HACK
+//! markers the agent added. They are listed under the conversation as a
+//! collapsed "follow-ups" row; `f` expands it and moves a selection through
+//! the list, `t` keeps the selected one as a tracked todo (alongside the
+//! agent's own todos) and `Enter` puts a follow-up prompt about it in the
+//! input.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crossterm::event::KeyCode;
+use serde_json::Value;
+
+use crate::models::{Message, MessageSegment, ToolEvent};
+use crate::state::{Todo, TodoStatus};
+
+use super::{App, Focus, Screen};
+
+/// Markers that flag unfinished work
+const MARKERS: [&str; 4] = ["TODO", "FIXME", "XXX", "HACK"];
+
+/// How long follow-up notices stay visible
+const FOLLOWUP_NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+/// A TODO-style marker added during a turn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FollowUp {
+    /// The marker (`TODO`, `FIXME`, ...)
+    pub marker: &'static str,
+    /// Text after the marker
+    pub text: String,
+    /// File the marker was added to, if known
+    pub path: Option<String>,
+    /// 1-based line in that file, if known
+    pub line: Option<usize>,
+}
+
+impl FollowUp {
+    /// `path:line`, or whatever part of it is known
+    pub fn location(&self) -> Option<String> {
+        let path = self.path.as_deref()?;
+        Some(match self.line {
+            Some(line) => format!("{}:{}", path, line),
+            None => path.to_string(),
+        })
+    }
+
+    /// One-line description (`FIXME src/lib.rs:12: handle overflow`)
+    pub fn summary(&self) -> String {
+        match (self.location(), self.text.is_empty()) {
+            (Some(location), false) => format!("{} {}: {}", self.marker, location, self.text),
+            (Some(location), true) => format!("{} {}", self.marker, location),
+            (None, _) => format!("{}: {}", self.marker, self.text),
+        }
+    }
+}
+
+/// Follow-ups of a thread's latest turn
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TurnFollowUps {
+    /// Assistant message the markers came from
+    pub message_id: i64,
+    pub items: Vec<FollowUp>,
+    /// Whether the list is expanded (and takes the follow-up keys)
+    pub expanded: bool,
+    /// Highlighted item while expanded
+    pub selected: usize,
+    /// Items already kept as tracked todos
+    pub tracked: HashSet<usize>,
+}
+
+/// Marker in a line of code and the text after it.
+///
+/// Markers must be upper case and stand alone, so `TODOS` or `todo_list`
+/// don't count.
+pub fn find_marker(line: &str) -> Option<(&'static str, String)> {
+    MARKERS.into_iter().find_map(|marker| {
+        line.match_indices(marker).find_map(|(start, _)| {
+            let before = line[..start].chars().next_back();
+            let after = line[start + marker.len()..].chars().next();
+            let standalone = !before.is_some_and(|c| c.is_alphanumeric() || c == '_')
+                && !after.is_some_and(|c| c.is_alphanumeric() || c == '_');
+            standalone.then(|| (marker, marker_text(&line[start + marker.len()..])))
+        })
+    })
+}
+
+/// Text following a marker, without the `(owner):` punctuation and comment
+/// closers around it
+fn marker_text(rest: &str) -> String {
+    let rest = rest.trim_start();
+    let rest = match rest.strip_prefix('(') {
+        Some(owned) => owned.split_once(')').map_or(owned, |(_, after)| after),
+        None => rest,
+    };
+    rest.trim_start_matches([':', '-', ' ', '!'])
+        .trim_end()
+        .trim_end_matches("*/")
+        .trim_end_matches("-->")
+        .trim_end()
+        .to_string()
+}
+
+/// Follow-ups added by a finished message's tool calls
+pub fn find_followups(message: &Message) -> Vec<FollowUp> {
+    let mut found: Vec<FollowUp> = Vec::new();
+    for segment in &message.segments {
+        if let MessageSegment::ToolEvent(event) = segment {
+            if event.result_is_error {
+                continue;
+            }
+            for followup in edit_followups(event)
+                .into_iter()
+                .chain(diff_followups(event))
+            {
+                let duplicate = found
+                    .iter()
+                    .any(|f| f.path == followup.path && f.text == followup.text);
+                if !duplicate {
+                    found.push(followup);
+                }
+            }
+        }
+    }
+    found
+}
+
+/// A string field of a tool input ("" when missing)
+fn field<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(Value::as_str).unwrap_or("")
+}
+
+/// Markers an Edit/MultiEdit/Write call added to a file
+fn edit_followups(event: &ToolEvent) -> Vec<FollowUp> {
+    let Ok(input) = serde_json::from_str::<Value>(&event.args_json) else {
+        return Vec::new();
+    };
+    let path = input
+        .get("file_path")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+
+    match event.function_name.as_str() {
+        "Write" => field(&input, "content")
+            .lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let (marker, text) = find_marker(line)?;
+                Some(FollowUp {
+                    marker,
+                    text,
+                    path: path.clone(),
+                    line: Some(i + 1),
+                })
+            })
+            .collect(),
+        "Edit" => added_markers(field(&input, "old_string"), field(&input, "new_string"))
+            .into_iter()
+            .map(|(marker, text)| FollowUp {
+                marker,
+                text,
+                path: path.clone(),
+                line: None,
+            })
+            .collect(),
+        "MultiEdit" => input
+            .get("edits")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .flat_map(|edit| added_markers(field(edit, "old_string"), field(edit, "new_string")))
+            .map(|(marker, text)| FollowUp {
+                marker,
+                text,
+                path: path.clone(),
+                line: None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Markers in `new` that `old` didn't have
+fn added_markers(old: &str, new: &str) -> Vec<(&'static str, String)> {
+    let mut existing: Vec<(&'static str, String)> = old.lines().filter_map(find_marker).collect();
+    new.lines()
+        .filter_map(find_marker)
+        .filter(|marker| match existing.iter().position(|m| m == marker) {
+            Some(index) => {
+                existing.remove(index);
+                false
+            }
+            None => true,
+        })
+        .collect()
+}
+
+/// Markers on added lines of a unified diff in a tool's output (e.g. from
+/// `git diff`)
+fn diff_followups(event: &ToolEvent) -> Vec<FollowUp> {
+    let Some(output) = event.output.as_deref() else {
+        return Vec::new();
+    };
+    if !output.contains("\n@@ ") && !output.starts_with("@@ ") {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+    let mut path: Option<String> = None;
+    let mut line: Option<usize> = None;
+    for row in output.lines() {
+        if let Some(file) = row.strip_prefix("+++ ") {
+            path = Some(file.trim_start_matches("b/").to_string());
+            line = None;
+        } else if let Some(hunk) = row.strip_prefix("@@ ") {
+            // @@ -12,3 +14,5 @@
+            line = hunk
+                .split_whitespace()
+                .find_map(|range| range.strip_prefix('+'))
+                .and_then(|range| range.split(',').next())
+                .and_then(|start| start.parse().ok());
+        } else if let Some(added) = row.strip_prefix('+') {
+            if let Some((marker, text)) = find_marker(added) {
+                found.push(FollowUp {
+                    marker,
+                    text,
+                    path: path.clone(),
+                    line,
+                });
+            }
+            line = line.map(|l| l + 1);
+        } else if !row.starts_with('-') {
+            line = line.map(|l| l + 1);
+        }
+    }
+    found
+}
+
+impl App {
+    /// Collect the follow-ups of a finished response. Called when a response
+    /// completes.
+    pub(super) fn collect_followups(&mut self, thread_id: &str, message_id: i64) {
+        let items = self
+            .cache
+            .get_messages(thread_id)
+            .and_then(|messages| messages.iter().find(|m| m.id == message_id))
+            .map(find_followups)
+            .unwrap_or_default();
+        if items.is_empty() {
+            self.turn_followups.remove(thread_id);
+        } else {
+            self.turn_followups.insert(
+                thread_id.to_string(),
+                TurnFollowUps {
+                    message_id,
+                    items,
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    /// Follow-ups of the thread on screen
+    pub fn active_followups(&self) -> Option<&TurnFollowUps> {
+        if self.screen != Screen::Conversation {
+            return None;
+        }
+        self.turn_followups.get(self.active_thread_id.as_deref()?)
+    }
+
+    fn active_followups_mut(&mut self) -> Option<&mut TurnFollowUps> {
+        if self.screen != Screen::Conversation {
+            return None;
+        }
+        let thread_id = self.active_thread_id.as_deref()?;
+        self.turn_followups.get_mut(thread_id)
+    }
+
+    /// Whether the follow-up list is expanded and takes the follow-up keys
+    pub fn followups_expanded(&self) -> bool {
+        self.active_followups().is_some_and(|f| f.expanded)
+    }
+
+    /// Expand or collapse the follow-up list.
+    ///
+    /// Returns false if the thread on screen has no follow-ups.
+    pub fn toggle_followups(&mut self) -> bool {
+        let Some(followups) = self.active_followups_mut() else {
+            return false;
+        };
+        followups.expanded = !followups.expanded;
+        self.mark_dirty();
+        true
+    }
+
+    /// Move the follow-up selection by `delta`
+    pub fn move_followup_selection(&mut self, delta: i32) {
+        if let Some(followups) = self.active_followups_mut() {
+            let last = followups.items.len().saturating_sub(1) as i32;
+            followups.selected = (followups.selected as i32 + delta).clamp(0, last) as usize;
+        }
+        self.mark_dirty();
+    }
+
+    /// Keep the selected follow-up as a tracked todo
+    pub fn track_selected_followup(&mut self) {
+        let Some(followups) = self.active_followups_mut() else {
+            return;
+        };
+        let index = followups.selected;
+        let Some(item) = followups.items.get(index).cloned() else {
+            return;
+        };
+        if !followups.tracked.insert(index) {
+            self.set_status_notice("Already tracked".to_string(), FOLLOWUP_NOTICE_DURATION);
+            return;
+        }
+
+        let todo = Todo {
+            content: item.summary(),
+            active_form: item.summary(),
+            status: TodoStatus::Pending,
+        };
+        self.tracked_todos.push(todo.clone());
+        self.todos.push(todo);
+        self.set_status_notice("Added to todos".to_string(), FOLLOWUP_NOTICE_DURATION);
+    }
+
+    /// Put a prompt about the selected follow-up in the input
+    pub fn prompt_selected_followup(&mut self) {
+        let Some(followups) = self.active_followups_mut() else {
+            return;
+        };
+        let Some(item) = followups.items.get(followups.selected).cloned() else {
+            return;
+        };
+        followups.expanded = false;
+
+        let prompt = match (item.location(), item.text.is_empty()) {
+            (Some(location), false) => format!(
+                "Follow up on the {} you left in {}: {}",
+                item.marker, location, item.text
+            ),
+            (Some(location), true) => {
+                format!("Follow up on the {} you left in {}", item.marker, location)
+            }
+            (None, _) => format!("Follow up on the {} you left: {}", item.marker, item.text),
+        };
+        self.textarea.set_content(&prompt);
+        self.focus = Focus::Input;
+        self.mark_dirty();
+    }
+
+    /// Keys of the expanded follow-up list (input not focused): j/k or the
+    /// arrows move, `t` tracks, Enter or `p` prompts, Esc collapses.
+    ///
+    /// Checked before a printable key moves focus to the input. Returns
+    /// whether the key was handled.
+    pub fn handle_followup_key(&mut self, code: KeyCode) -> bool {
+        if self.focus == Focus::Input || !self.followups_expanded() {
+            return false;
+        }
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => self.move_followup_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_followup_selection(1),
+            KeyCode::Char('t') => self.track_selected_followup(),
+            KeyCode::Enter | KeyCode::Char('p') => self.prompt_selected_followup(),
+            KeyCode::Esc => return self.toggle_followups(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit_event(name: &str, args: Value) -> ToolEvent {
+        let mut event = ToolEvent::new("tool-1".to_string(), name.to_string());
+        event.args_json = args.to_string();
+        event
+    }
+
+    #[test]
+    fn test_find_marker() {
+        assert_eq!(
+            find_marker("    // TODO(alice): handle overflow */"),
+            Some(("TODO", "handle overflow".to_string()))
+        );
+        assert_eq!(
+            find_marker("# FIXME - retry on 503"),
+            Some(("FIXME", "retry on 503".to_string()))
+        );
+        assert_eq!(find_marker("let TODOS = todo_list();"), None);
+        assert_eq!(find_marker("// todo: lower case"), None);
+    }
+
+    #[test]
+    fn test_edit_reports_only_new_markers() {
+        let event = edit_event(
+            "Edit",
+            serde_json::json!({
+                "file_path": "src/lib.rs",
+                "old_string": "// TODO: old one\nfn a() {}",
+                "new_string": "// TODO: old one\n// FIXME: new one\nfn a() {}",
+            }),
+        );
+        assert_eq!(
+            edit_followups(&event),
+            vec![FollowUp {
+                marker: "FIXME",
+                text: "new one".to_string(),
+                path: Some("src/lib.rs".to_string()),
+                line: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_write_and_diff_markers_have_line_numbers() {
+        let event = edit_event(
+            "Write",
+            serde_json::json!({
+                "file_path": "app.py",
+                "content": "import os\n\n# TODO: read config\n",
+            }),
+        );
+        assert_eq!(edit_followups(&event)[0].line, Some(3));
+
+        let mut bash = ToolEvent::new("tool-2".to_string(), "Bash".to_string());
+        bash.output = Some(
+            "diff --git a/src/x.rs b/src/x.rs\n--- a/src/x.rs\n+++ b/src/x.rs\n@@ -10,2 +10,3 @@\n fn x() {\n-    old();\n+    new(); // XXX: temporary\n"
+                .to_string(),
+        );
+        let found = diff_followups(&bash);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].summary(), "XXX src/x.rs:11: temporary");
+    }
+
+    /// Conversation with one follow-up from the last turn
+    fn app_with_followup() -> App {
+        let mut app = App::default();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.turn_followups.insert(
+            "thread-001".to_string(),
+            TurnFollowUps {
+                message_id: 2,
+                items: vec![FollowUp {
+                    marker: "TODO",
+                    text: "handle overflow".to_string(),
+                    path: Some("src/lib.rs".to_string()),
+                    line: None,
+                }],
+                ..Default::default()
+            },
+        );
+        app
+    }
+
+    #[test]
+    fn test_track_and_prompt_selected_followup() {
+        let mut app = app_with_followup();
+
+        assert!(app.toggle_followups());
+        assert!(app.followups_expanded());
+        app.track_selected_followup();
+        app.track_selected_followup();
+        assert_eq!(app.tracked_todos.len(), 1);
+        assert_eq!(
+            app.todos.last().unwrap().content,
+            "TODO src/lib.rs: handle overflow"
+        );
+
+        app.prompt_selected_followup();
+        assert!(!app.followups_expanded());
+        assert_eq!(
+            app.textarea.content(),
+            "Follow up on the TODO you left in src/lib.rs: handle overflow"
+        );
+    }
+
+    #[test]
+    fn test_followup_keys_reach_the_list() {
+        use crate::input::keymap::Action;
+
+        let mut app = app_with_followup();
+        app.focus = Focus::Threads;
+
+        assert!(app.handle_panel_action(Action::ToggleFollowUps));
+        assert!(app.followups_expanded());
+        assert!(app.handle_followup_key(KeyCode::Char('j')));
+        assert!(app.handle_followup_key(KeyCode::Char('t')));
+        assert_eq!(app.tracked_todos.len(), 1);
+
+        assert!(app.handle_followup_key(KeyCode::Char('p')));
+        assert!(!app.followups_expanded());
+        assert_eq!(app.focus, Focus::Input);
+        // Typing again: the keys are text
+        assert!(!app.handle_followup_key(KeyCode::Char('t')));
+        assert!(!app.handle_panel_action(Action::ToggleFollowUps));
+    }
+}
@@ -5,18 +5,54 @@ use crate::credential_watcher::{
 };
 use crate::debug::{DebugEventKind, ErrorData, ErrorSource, StateChangeData, StateType};
 use crate::models::ThreadMode;
+use crate::notifications::NotificationKind;
 use crate::state::dashboard::PhaseProgressData;
 use crate::state::session::AskUserQuestionData;
+use crate::state::PickerDataKind;
 use tracing::info;
 
-use super::{emit_debug, log_thread_update, truncate_for_debug, App, AppMessage};
+use super::reveal::deferred_thread_id;
+use super::{emit_debug, log_thread_update, truncate_for_debug, App, AppMessage, TurnOutcome};
 
 impl App {
     /// Handle an incoming async message
-    /// All message handlers mark the app as dirty since they update visible state.
+    ///
+    /// Stream text may be held back by the reveal pacer (simulated typing)
+    /// and applied later from `tick()` or on keypress. Otherwise tokens are
+    /// coalesced into one append per frame (see `token_coalesce`).
     pub fn handle_message(&mut self, msg: AppMessage) {
-        // All messages result in state changes that require a redraw
-        self.mark_dirty();
+        if self.is_cancelled_stream_event(&msg) {
+            return;
+        }
+        match self.pace_message(msg) {
+            Some(AppMessage::StreamToken { thread_id, token }) => {
+                self.coalesce_stream_token(thread_id, token);
+            }
+            Some(msg) => {
+                if deferred_thread_id(&msg).is_some() {
+                    self.flush_stream_tokens();
+                }
+                self.dispatch_message(msg);
+            }
+            None => {}
+        }
+    }
+
+    /// Apply a message to app state.
+    /// All message handlers mark the app as dirty since they update visible state
+    /// (streamed tokens only while the terminal is focused).
+    pub(crate) fn dispatch_message(&mut self, msg: AppMessage) {
+        // All messages result in state changes that require a redraw, except
+        // streamed tokens while the terminal is unfocused: they only update
+        // the cache until something else (or focus returning) redraws
+        let token = matches!(
+            msg,
+            AppMessage::StreamToken { .. } | AppMessage::ReasoningToken { .. }
+        );
+        if self.is_focused || !token {
+            self.mark_dirty();
+        }
+        self.observers.notify(&msg);
         match msg {
             AppMessage::StreamToken { thread_id, token } => {
                 // Initialize stream start time if this is the first token
@@ -34,6 +70,7 @@ impl App {
                 // Estimate token count (rough approximation: 4 chars per token)
                 let estimated_tokens = (token.len() as f64 / 4.0).ceil() as u64;
                 self.cumulative_token_count += estimated_tokens;
+                self.cache.add_streamed_tokens(&thread_id, estimated_tokens);
 
                 // Calculate tokens per second
                 let tokens_per_second = if let Some(start) = self.stream_start_time {
@@ -73,8 +110,9 @@ impl App {
                     )),
                     Some(&thread_id),
                 );
-                // Auto-scroll to bottom when new content arrives, but only for the active thread
-                if self.active_thread_id.as_ref() == Some(&thread_id) {
+                // Auto-scroll to bottom when new content arrives, but only for the active
+                // thread and only if the user hasn't scrolled up to read
+                if self.active_thread_id.as_ref() == Some(&thread_id) && !self.user_has_scrolled {
                     self.reset_scroll();
                 }
             }
@@ -90,8 +128,9 @@ impl App {
                     )),
                     Some(&thread_id),
                 );
-                // Auto-scroll to bottom when new reasoning content arrives, but only for the active thread
-                if self.active_thread_id.as_ref() == Some(&thread_id) {
+                // Auto-scroll to bottom when new reasoning content arrives, but only for the
+                // active thread and only if the user hasn't scrolled up to read
+                if self.active_thread_id.as_ref() == Some(&thread_id) && !self.user_has_scrolled {
                     self.reset_scroll();
                 }
             }
@@ -100,6 +139,9 @@ impl App {
                 message_id,
             } => {
                 self.cache.finalize_message(&thread_id, message_id);
+                self.cache.clear_stream_resume(&thread_id);
+                self.stream_failures.remove(&thread_id);
+                self.note_new_message(&thread_id);
 
                 // Reset stream statistics
                 self.stream_start_time = None;
@@ -135,21 +177,28 @@ impl App {
                     self.reset_scroll();
                 }
 
-                // Fire native OS notification when the TUI is not focused.
-                // If the terminal doesn't support focus reporting (focus_supported=false),
-                // we always notify since we can't detect focus state.
-                let should_notify = !self.focus_supported || !self.is_focused;
-                tracing::debug!(
-                    "StreamComplete: is_focused={}, focus_supported={}, should_notify={}, thread_id={}",
-                    self.is_focused,
-                    self.focus_supported,
-                    should_notify,
-                    thread_id
+                // Native OS notification while the terminal is in the background
+                self.notify_desktop(
+                    NotificationKind::StreamComplete,
+                    Some(&thread_id),
+                    "Response finished",
                 );
-                if should_notify {
-                    let title = self.cache.get_thread(&thread_id).map(|t| t.title.as_str());
-                    crate::notifications::notify_task_complete(title);
-                }
+                self.request_attention(&thread_id, "Response finished");
+                self.push_thread_notification(
+                    &thread_id,
+                    Some(message_id),
+                    "Response finished".to_string(),
+                );
+
+                // Write the answer to a scratch file for $EDITOR if the thread asks
+                self.hand_off_finished_answer(&thread_id, message_id);
+
+                // List TODO/FIXME markers the turn left behind
+                self.collect_followups(&thread_id, message_id);
+                self.record_turn_outcome(&thread_id, TurnOutcome::Completed);
+
+                // Send the next prompt queued while this response streamed
+                self.submit_next_queued(&thread_id);
             }
             AppMessage::StreamError {
                 thread_id,
@@ -173,6 +222,8 @@ impl App {
 
                 // Cancel the streaming message so spinner stops
                 self.cache.cancel_streaming_message(&thread_id);
+                self.cache.clear_stream_resume(&thread_id);
+                *self.stream_failures.entry(thread_id.clone()).or_default() += 1;
 
                 // Add error as inline content in the thread's cache
                 self.cache.add_error_simple(
@@ -180,6 +231,11 @@ impl App {
                     error_code.as_deref().unwrap_or("error").to_string(),
                     error.clone(),
                 );
+                self.notify_desktop(
+                    NotificationKind::TaskFailed,
+                    Some(&thread_id),
+                    &format!("Failed: {}", error),
+                );
 
                 // Emit Error debug event
                 emit_debug(
@@ -187,7 +243,13 @@ impl App {
                     DebugEventKind::Error(ErrorData::new(ErrorSource::AppState, &error)),
                     None,
                 );
+                self.push_thread_notification(&thread_id, None, format!("Error: {}", error));
                 self.stream_error = Some(error);
+                self.update_thread_metrics(&thread_id, |m| m.errors += 1);
+                self.record_turn_outcome(&thread_id, TurnOutcome::Failed);
+
+                // Send the next prompt queued while this response streamed
+                self.submit_next_queued(&thread_id);
             }
             AppMessage::StreamCancelled { thread_id, reason } => {
                 // Clear queued steering on cancel
@@ -199,6 +261,8 @@ impl App {
 
                 // Mark message as no longer streaming
                 self.cache.cancel_streaming_message(&thread_id);
+                self.cache.clear_stream_resume(&thread_id);
+                self.record_turn_outcome(&thread_id, TurnOutcome::Cancelled);
 
                 // Reset stream statistics
                 self.stream_start_time = None;
@@ -226,6 +290,65 @@ impl App {
                 if self.active_thread_id.as_ref() == Some(&thread_id) {
                     self.reset_scroll();
                 }
+
+                // Prompts kept through Ctrl+C go out now
+                self.submit_next_queued(&thread_id);
+            }
+            AppMessage::StreamProgress { thread_id, seq } => {
+                self.cache.record_stream_seq(&thread_id, seq);
+            }
+            AppMessage::EventReceived { thread_id, record } => {
+                self.record_event(&thread_id, record);
+            }
+            AppMessage::StreamInterrupted { thread_id, error } => {
+                // Only streams with a message still in flight can be resumed
+                if !self.cache.mark_stream_interrupted(&thread_id) {
+                    return;
+                }
+                tracing::warn!("Stream interrupted: thread_id={}, error={}", thread_id, error);
+
+                // Reset stream statistics; they restart when the resume connects
+                self.stream_start_time = None;
+                self.last_event_time = None;
+                self.cumulative_token_count = 0;
+
+                emit_debug(
+                    &self.debug_tx,
+                    DebugEventKind::StateChange(StateChangeData::new(
+                        StateType::MessageCache,
+                        "Stream interrupted",
+                        format!("thread: {}, error: {}", thread_id, error),
+                    )),
+                    Some(&thread_id),
+                );
+
+                // Try right away; if the network is down, the next
+                // ConnectionStatus(true) / WsConnected retries
+                self.resume_interrupted_streams();
+            }
+            AppMessage::StreamResumeFailed {
+                thread_id,
+                error,
+                retryable,
+            } => {
+                emit_debug(
+                    &self.debug_tx,
+                    DebugEventKind::Error(ErrorData::new(ErrorSource::ConductorApi, &error)),
+                    Some(&thread_id),
+                );
+                if !self.cache.is_thread_streaming(&thread_id) {
+                    // Message was finalized or cancelled meanwhile
+                    self.cache.clear_stream_resume(&thread_id);
+                } else if retryable {
+                    // Wait for connectivity to come back before retrying
+                    self.cache.mark_stream_interrupted(&thread_id);
+                    self.connection_status = false;
+                } else {
+                    self.abandon_stream_resume(
+                        &thread_id,
+                        format!("Response could not be resumed: {}", error),
+                    );
+                }
             }
             AppMessage::RateLimited {
                 thread_id,
@@ -257,6 +380,7 @@ impl App {
 
                 // Mark message as no longer streaming
                 self.cache.cancel_streaming_message(&thread_id);
+                self.cache.clear_stream_resume(&thread_id);
 
                 // Reset stream statistics
                 self.stream_start_time = None;
@@ -269,6 +393,9 @@ impl App {
                 // Clear tool tracker when stream is interrupted
                 self.tool_tracker.clear();
             }
+            AppMessage::PollingModeChanged(polling) => {
+                self.set_polling_mode(polling);
+            }
             AppMessage::ConnectionStatus(connected) => {
                 // Emit StateChange for connection status
                 emit_debug(
@@ -284,6 +411,8 @@ impl App {
                 if connected {
                     // Clear any previous error when reconnected
                     self.stream_error = None;
+                    // Pick up streams that dropped while offline
+                    self.resume_interrupted_streams();
                 }
             }
             AppMessage::ThreadCreated {
@@ -313,6 +442,7 @@ impl App {
             AppMessage::MessagesLoaded {
                 thread_id,
                 messages,
+                older_count,
             } => {
                 let count = messages.len();
                 log_thread_update(&format!(
@@ -320,6 +450,7 @@ impl App {
                     thread_id, count
                 ));
                 self.cache.set_messages(thread_id.clone(), messages);
+                self.cache.set_older_message_count(&thread_id, older_count);
                 log_thread_update(&format!(
                     "HANDLER: Messages stored in cache for {}",
                     thread_id
@@ -348,9 +479,19 @@ impl App {
                 );
                 self.stream_error = Some(error);
             }
+            AppMessage::OlderMessagesLoaded {
+                thread_id,
+                messages,
+            } => {
+                self.prepend_older_messages(thread_id, messages);
+            }
+            AppMessage::OlderMessagesLoadFailed { thread_id, error } => {
+                self.older_messages_failed(thread_id, &error);
+            }
             AppMessage::TodosUpdated { todos } => {
                 let count = todos.len();
                 self.todos = todos;
+                self.todos.extend(self.tracked_todos.iter().cloned());
                 // Emit StateChange for todos update
                 emit_debug(
                     &self.debug_tx,
@@ -420,6 +561,12 @@ impl App {
 
                     // Compute thread views to ensure needs_action is set
                     self.dashboard.compute_thread_views();
+                    self.request_attention(&effective_thread_id, "Plan ready for approval");
+                    self.notify_desktop(
+                        NotificationKind::PermissionRequested,
+                        Some(&effective_thread_id),
+                        "Plan ready for approval",
+                    );
 
                     // Emit StateChange for plan approval
                     emit_debug(
@@ -506,6 +653,21 @@ impl App {
                     // Compute thread views to ensure needs_action is set
                     self.dashboard.compute_thread_views();
 
+                    let (notice, kind) = if tool_name == "AskUserQuestion" {
+                        (
+                            "Has a question for you".to_string(),
+                            NotificationKind::QuestionAsked,
+                        )
+                    } else {
+                        (
+                            format!("Needs permission: {}", tool_name),
+                            NotificationKind::PermissionRequested,
+                        )
+                    };
+                    self.request_attention(&effective_thread_id, &notice);
+                    self.notify_desktop(kind, Some(&effective_thread_id), &notice);
+                    self.push_thread_notification(&effective_thread_id, None, notice);
+
                     // Emit StateChange for pending permission
                     emit_debug(
                         &self.debug_tx,
@@ -578,6 +740,9 @@ impl App {
                 summary,
                 result,
             } => {
+                if !success {
+                    self.update_thread_metrics(&thread_id, |m| m.tool_errors += 1);
+                }
                 // Mark tool as completed with summary for fade display
                 self.tool_tracker.complete_tool_with_summary(
                     &tool_call_id,
@@ -671,6 +836,8 @@ impl App {
                 if let Some(limit) = token_limit {
                     self.session_state.set_context_token_limit(limit);
                 }
+                self.session_state.record_compaction(chrono::Utc::now());
+                self.check_context_usage_hint();
                 // Emit StateChange for context compaction
                 emit_debug(
                     &self.debug_tx,
@@ -682,6 +849,15 @@ impl App {
                     None,
                 );
             }
+            AppMessage::ContextCompactFinished {
+                thread_id,
+                response,
+            } => {
+                self.finish_compaction(&thread_id, response);
+            }
+            AppMessage::ContextCompactFailed { thread_id, error } => {
+                self.fail_compaction(&thread_id, error);
+            }
             AppMessage::ThreadMetadataUpdated {
                 thread_id,
                 title,
@@ -691,6 +867,8 @@ impl App {
                     "Updating cache: id={}, title={:?}, description={:?}",
                     thread_id, title, description
                 ));
+                // A title the user picked wins over generated (possibly stale) ones
+                let title = title.filter(|_| !self.cache.is_thread_renamed(&thread_id));
                 let updated = self.cache.update_thread_metadata(
                     &thread_id,
                     title.clone(),
@@ -854,12 +1032,18 @@ impl App {
                 );
             }
             AppMessage::UsageReceived {
+                thread_id,
                 context_used,
                 context_limit,
+                input_tokens,
+                output_tokens,
             } => {
                 // Update context tracking in session state
                 self.session_state.set_context_tokens(context_used);
                 self.session_state.set_context_token_limit(context_limit);
+                self.check_context_usage_hint();
+                self.cache
+                    .record_turn_usage(&thread_id, context_used, input_tokens, output_tokens);
                 // Emit StateChange for usage update
                 emit_debug(
                     &self.debug_tx,
@@ -885,12 +1069,30 @@ impl App {
                     )),
                     None,
                 );
+                // Hide the connection banner and send the queued answers
+                self.note_ws_up();
+                // Streaming works again once the WebSocket gets through
+                self.set_polling_mode(false);
+                // Pick up streams that dropped while offline
+                self.resume_interrupted_streams();
+                // Re-announce the open thread to other clients
+                if self.screen == crate::app::Screen::Conversation {
+                    if let Some(thread_id) = self.active_thread_id.clone() {
+                        self.send_presence(&thread_id, crate::websocket::WsPresenceState::Viewing);
+                    }
+                }
             }
-            AppMessage::WsReconnected { sender } => {
+            AppMessage::WsReconnected { handle } => {
                 use crate::websocket::WsConnectionState;
                 tracing::info!("WebSocket reconnected to new VPS");
-                self.ws_sender = Some(sender);
+                self.ws_sender = Some(handle.sender);
+                self.ws_control = Some(handle.control);
                 self.ws_connection_state = WsConnectionState::Connected;
+                // The old connection's unacknowledged messages went with it
+                self.ws_unacked_messages = 0;
+                // Answers queued for the old conductor don't apply to the new one
+                self.ws_queued_messages.clear();
+                self.note_ws_up();
 
                 // Clear stale dashboard data from old conductor
                 self.dashboard
@@ -919,11 +1121,20 @@ impl App {
                 self.mark_dirty();
             }
             AppMessage::DashboardDataRefreshed { threads } => {
+                self.note_thread_updates(&threads);
                 let total = threads.len();
-                let threads: Vec<_> = threads
+                let mut threads: Vec<_> = threads
                     .into_iter()
                     .take(crate::app::MAX_DASHBOARD_THREADS)
                     .collect();
+                super::thread_pins::merge_local_pins(
+                    &mut threads,
+                    &self.thread_state.pinned_threads,
+                );
+                super::thread_mute::merge_local_mutes(
+                    &mut threads,
+                    &self.thread_state.muted_threads,
+                );
                 tracing::info!(
                     "Dashboard refreshed: {} threads (limited to {})",
                     total,
@@ -943,6 +1154,9 @@ impl App {
                 use crate::websocket::WsConnectionState;
                 tracing::info!("WebSocket disconnected");
                 self.ws_connection_state = WsConnectionState::Disconnected;
+                self.note_ws_down();
+                // Presence events are missed while offline
+                self.thread_presence.clear();
                 // Emit StateChange for WebSocket disconnection
                 emit_debug(
                     &self.debug_tx,
@@ -958,6 +1172,8 @@ impl App {
                 use crate::websocket::WsConnectionState;
                 tracing::info!("WebSocket reconnecting (attempt {})", attempt);
                 self.ws_connection_state = WsConnectionState::Reconnecting { attempt };
+                self.ws_reconnect_attempts += 1;
+                self.note_ws_down();
                 // Emit StateChange for WebSocket reconnection attempt
                 emit_debug(
                     &self.debug_tx,
@@ -994,6 +1210,84 @@ impl App {
                     None,
                 );
             }
+            AppMessage::WsClientStarted { handle } => {
+                tracing::info!("WebSocket client started by manual reconnect");
+                self.adopt_ws_client(handle);
+            }
+            AppMessage::WsClientStartFailed { error } => {
+                use crate::websocket::WsConnectionState;
+                tracing::warn!("Manual reconnect failed: {}", error);
+                self.ws_connection_state = WsConnectionState::Disconnected;
+                self.set_status_notice(
+                    "Couldn't reconnect - press R to try again".to_string(),
+                    std::time::Duration::from_secs(5),
+                );
+            }
+            AppMessage::TaskUpdated { task } => {
+                self.apply_task_update(task);
+            }
+            AppMessage::TaskActionFailed {
+                task_id,
+                previous,
+                error,
+            } => {
+                tracing::warn!("Task action failed for {}: {}", task_id, error);
+                self.rollback_task_action(&task_id, previous, error);
+            }
+            AppMessage::WsConnectionLost { reason } => {
+                tracing::info!("WebSocket connection lost: {}", reason);
+                self.ws_last_disconnect = Some(super::WsDisconnect {
+                    reason,
+                    at: chrono::Utc::now(),
+                });
+            }
+            AppMessage::WsPendingAcks(count) => {
+                self.ws_unacked_messages = count;
+                self.mark_dirty();
+            }
+            AppMessage::WsRetransmitted {
+                message_id,
+                kind,
+                attempt,
+            } => {
+                emit_debug(
+                    &self.debug_tx,
+                    DebugEventKind::StateChange(StateChangeData::new(
+                        StateType::WebSocket,
+                        "WS_RETRANSMIT",
+                        format!("{} {} (attempt {})", kind, message_id, attempt),
+                    )),
+                    None,
+                );
+            }
+            AppMessage::WsUndelivered { message_id, kind } => {
+                emit_debug(
+                    &self.debug_tx,
+                    DebugEventKind::Error(ErrorData::new(
+                        ErrorSource::WebSocket,
+                        format!("No ack for {} {}, giving up", kind, message_id),
+                    )),
+                    None,
+                );
+                self.set_timed_error(
+                    format!("Couldn't deliver {} to the server", kind.replace('_', " ")),
+                    std::time::Duration::from_secs(6),
+                );
+            }
+            AppMessage::ThreadsLoaded { threads, tasks } => {
+                self.apply_loaded_threads(threads, tasks);
+            }
+            AppMessage::ThreadsLoadFailed(error) => {
+                self.apply_threads_load_failed(&error);
+            }
+            AppMessage::WsStarted { handle } => {
+                tracing::info!("WebSocket connected at startup");
+                self.apply_ws_started(handle);
+            }
+            AppMessage::WsStartFailed { error } => {
+                tracing::warn!("WebSocket connection failed at startup: {}", error);
+                self.apply_ws_start_failed(&error);
+            }
             AppMessage::FoldersLoaded(folders) => {
                 let count = folders.len();
                 self.folders = folders;
@@ -1020,6 +1314,27 @@ impl App {
                     None,
                 );
             }
+            AppMessage::ModelsLoaded(models) => {
+                self.models = models;
+                self.models_loading = false;
+                self.models_error = None;
+                // Preselect the current model now that the list is known
+                let current = self.selected_model().map(str::to_string);
+                if let (Some(picker), Some(current)) = (self.model_picker.as_mut(), current) {
+                    if let Some(index) = self.models.iter().position(|m| m.id == current) {
+                        picker.selected_index = index;
+                    }
+                }
+            }
+            AppMessage::ModelsLoadFailed(error) => {
+                self.models_loading = false;
+                self.models_error = Some(error.clone());
+                emit_debug(
+                    &self.debug_tx,
+                    DebugEventKind::Error(ErrorData::new(ErrorSource::AppState, &error)),
+                    None,
+                );
+            }
             AppMessage::ReposLoaded(repos) => {
                 let count = repos.len();
                 self.repos = repos;
@@ -1128,7 +1443,7 @@ impl App {
             }
             AppMessage::FolderSelected(folder) => {
                 let folder_name = folder.name.clone();
-                self.selected_folder = Some(folder);
+                self.attach_folder(folder);
                 self.folder_picker_visible = false;
                 self.folder_picker_filter.clear();
                 self.folder_picker_cursor = 0;
@@ -1143,18 +1458,20 @@ impl App {
                     None,
                 );
             }
-            AppMessage::FolderCleared => {
-                self.selected_folder = None;
-                // Emit StateChange for folder cleared
-                emit_debug(
-                    &self.debug_tx,
-                    DebugEventKind::StateChange(StateChangeData::new(
-                        StateType::SessionState,
-                        "Folder cleared",
-                        "none",
-                    )),
-                    None,
-                );
+            AppMessage::FolderRemoved(index) => {
+                if index < self.selected_folders.len() {
+                    let folder = self.selected_folders.remove(index);
+                    // Emit StateChange for folder removed
+                    emit_debug(
+                        &self.debug_tx,
+                        DebugEventKind::StateChange(StateChangeData::new(
+                            StateType::SessionState,
+                            "Folder removed",
+                            truncate_for_debug(&folder.name, 30),
+                        )),
+                        None,
+                    );
+                }
             }
             AppMessage::SystemStatsUpdate(stats) => {
                 // Update system stats for dashboard header display
@@ -1181,8 +1498,14 @@ impl App {
                 waiting_for,
             } => {
                 // Update dashboard state with thread status
+                let run_elapsed = self.dashboard.run_elapsed(&thread_id);
+                let previous_status = self.dashboard.thread_status(&thread_id);
                 self.dashboard
                     .update_thread_status(&thread_id, status, waiting_for.clone());
+                self.maybe_request_thread_summary(&thread_id, status, run_elapsed);
+                if previous_status != Some(status) {
+                    self.dispatch_status_webhooks(&thread_id, status, waiting_for.as_ref());
+                }
                 // Emit StateChange for thread status update
                 emit_debug(
                     &self.debug_tx,
@@ -1194,6 +1517,20 @@ impl App {
                     Some(&thread_id),
                 );
             }
+            AppMessage::ThreadSummaryReady { thread_id, summary } => {
+                self.apply_thread_summary(&thread_id, summary);
+            }
+            AppMessage::WebhookFailed {
+                thread_id,
+                host,
+                error,
+            } => {
+                tracing::warn!("Webhook to {} failed for {}: {}", host, thread_id, error);
+                self.set_timed_error(
+                    format!("Webhook to {} failed: {}", host, error),
+                    std::time::Duration::from_secs(5),
+                );
+            }
             AppMessage::AgentStatusUpdate {
                 thread_id,
                 state,
@@ -1251,7 +1588,7 @@ impl App {
                     Some(&thread_id),
                 );
             }
-            AppMessage::WsThreadCreated { thread } => {
+            AppMessage::WsThreadCreated { mut thread } => {
                 // Log for terminal debugging with detailed thread information
                 tracing::info!(
                     "WS_THREAD_CREATED: thread_id={}, title={:?}, mode={:?}, status={:?}, verified={:?}",
@@ -1261,6 +1598,10 @@ impl App {
                     thread.status,
                     thread.verified
                 );
+                // Keep the local title of a thread started here until it's named
+                if let Some(title) = self.cache.provisional_title_over(&thread.id, &thread.title) {
+                    thread.title = title;
+                }
                 // Add newly created thread to dashboard state
                 let thread_id = thread.id.clone();
                 self.dashboard.add_thread(thread);
@@ -1358,6 +1699,8 @@ impl App {
                 thread_id,
                 verified_at,
             } => {
+                self.update_thread_metrics(&thread_id, |m| m.record_verified());
+                self.save_thread_metrics();
                 // Log for terminal debugging
                 tracing::info!(
                     "THREAD_VERIFIED: thread_id={}, verified_at={}",
@@ -1428,34 +1771,43 @@ impl App {
             // Unified Picker Messages
             // =========================================================================
             AppMessage::UnifiedPickerFoldersLoaded(items) => {
-                // Cache for session
+                // Cache with TTL (refreshed in the background when stale)
                 self.picker_cache.set_folders(items.clone());
                 // Update picker if visible
                 if self.unified_picker.visible {
                     self.unified_picker.folders.set_items(items);
-                    self.unified_picker.validate_selection();
+                    self.unified_picker.apply_query();
+                    self.sync_picker_freshness();
+                    // No indexed folders: fall back to browsing
+                    self.sync_directory_browse();
                 }
                 self.mark_dirty();
             }
             AppMessage::UnifiedPickerFoldersFailed(error) => {
+                self.picker_cache.finish_refresh(PickerDataKind::Folders);
                 if self.unified_picker.visible {
                     self.unified_picker.folders.set_error(error);
+                    self.sync_picker_freshness();
+                    self.sync_directory_browse();
                 }
                 self.mark_dirty();
             }
             AppMessage::UnifiedPickerReposLoaded(items) => {
-                // Cache for entire session (repos rarely change)
+                // Cache with TTL (repos rarely change)
                 self.picker_cache.set_repos(items.clone());
                 // Update picker if visible
                 if self.unified_picker.visible {
                     self.unified_picker.repos.set_items(items);
-                    self.unified_picker.validate_selection();
+                    self.unified_picker.apply_query();
+                    self.sync_picker_freshness();
                 }
                 self.mark_dirty();
             }
             AppMessage::UnifiedPickerReposFailed(error) => {
+                self.picker_cache.finish_refresh(PickerDataKind::Repos);
                 if self.unified_picker.visible {
                     self.unified_picker.repos.set_error(error);
+                    self.sync_picker_freshness();
                 }
                 self.mark_dirty();
             }
@@ -1465,13 +1817,16 @@ impl App {
                 // Update picker if visible
                 if self.unified_picker.visible {
                     self.unified_picker.threads.set_items(items);
-                    self.unified_picker.validate_selection();
+                    self.unified_picker.apply_query();
+                    self.sync_picker_freshness();
                 }
                 self.mark_dirty();
             }
             AppMessage::UnifiedPickerThreadsFailed(error) => {
+                self.picker_cache.finish_refresh(PickerDataKind::Threads);
                 if self.unified_picker.visible {
                     self.unified_picker.threads.set_error(error);
+                    self.sync_picker_freshness();
                 }
                 self.mark_dirty();
             }
@@ -1481,7 +1836,7 @@ impl App {
                     name,
                     path: local_path,
                 };
-                self.selected_folder = Some(folder);
+                self.attach_folder(folder);
 
                 // Close the picker
                 self.unified_picker.finish_clone();
@@ -1499,11 +1854,24 @@ impl App {
             AppMessage::UnifiedPickerCloneFailed { error } => {
                 self.unified_picker_clone_failed(error);
             }
+            AppMessage::UnifiedPickerDirectoryLoaded { requested, listing } => {
+                if self.unified_picker.visible {
+                    self.unified_picker
+                        .set_directory_listing(&requested, listing);
+                }
+                self.mark_dirty();
+            }
+            AppMessage::UnifiedPickerDirectoryFailed { requested, error } => {
+                if self.unified_picker.visible {
+                    self.unified_picker.set_directory_error(&requested, error);
+                }
+                self.mark_dirty();
+            }
 
             // =========================================================================
             // Sync Messages
             // =========================================================================
-            AppMessage::TriggerSync => {
+            AppMessage::TriggerSync { changed_files } => {
                 // Set status to starting and spawn async sync task
                 use crate::app::SyncStatus;
                 use crate::conductor::ConductorClient;
@@ -1520,6 +1888,7 @@ impl App {
 
                 tracing::info!("Spawning sync task with base_url: {}", base_url);
                 tokio::spawn(async move {
+                    use crate::credential_watcher::SyncReport;
                     tracing::info!("Sync task started");
 
                     // Send started message
@@ -1546,7 +1915,7 @@ impl App {
                             tracing::info!("Sync succeeded: {:?}", result.success);
 
                             // Extract verification results from sync response
-                            let github_cli = if let Some(v) = result.verification {
+                            let github_cli = if let Some(v) = &result.verification {
                                 tracing::info!("Using embedded verification from sync response");
                                 v.github_cli_works.unwrap_or(false)
                             } else {
@@ -1569,15 +1938,15 @@ impl App {
                                 }
                             };
 
-                            let _ = tx.send(AppMessage::SyncComplete {
-                                github_cli,
-                            });
+                            let report =
+                                SyncReport::from_response(changed_files, &result, github_cli);
+                            let _ = tx.send(AppMessage::SyncComplete { github_cli, report });
                         }
                         Err(e) => {
                             tracing::error!("Sync failed: {}", e);
-                            let _ = tx.send(AppMessage::SyncFailed {
-                                error: e.to_string(),
-                            });
+                            let error = e.to_string();
+                            let report = SyncReport::failed(changed_files, &error);
+                            let _ = tx.send(AppMessage::SyncFailed { error, report });
                         }
                     }
                 });
@@ -1631,11 +2000,13 @@ impl App {
             }
             AppMessage::SyncComplete {
                 github_cli,
+                report,
             } => {
                 use crate::app::SyncStatus;
                 tracing::info!("SyncComplete received: github_cli={}", github_cli);
                 self.sync_status = SyncStatus::Complete {
                     github_cli,
+                    report,
                 };
                 // Reset credential watcher backoff on success
                 handle_sync_complete(&mut self.credential_watch_state);
@@ -1651,11 +2022,12 @@ impl App {
                     None,
                 );
             }
-            AppMessage::SyncFailed { error } => {
+            AppMessage::SyncFailed { error, report } => {
                 use crate::app::SyncStatus;
                 tracing::error!("SyncFailed received: {}", error);
                 self.sync_status = SyncStatus::Failed {
                     error: error.clone(),
+                    report,
                 };
                 // Record failure in credential watcher backoff
                 handle_sync_failed(&mut self.credential_watch_state, &error);
@@ -1679,21 +2051,30 @@ impl App {
                 offset,
                 has_more,
             } => {
-                // Only update if we're still on the BrowseList screen in Threads mode
+                // Only update if we're still on the BrowseList screen showing threads
                 if self.screen == crate::app::Screen::BrowseList
-                    && self.browse_list.mode == crate::app::BrowseListMode::Threads
+                    && matches!(
+                        self.browse_list.mode,
+                        crate::app::BrowseListMode::Threads | crate::app::BrowseListMode::All
+                    )
                 {
+                    let threads = self.merge_local_archive_state(threads);
                     if offset == 0 {
                         // Initial load - replace items
-                        self.browse_list.threads = threads;
+                        self.browse_list.all_threads = threads;
                     } else {
                         // Pagination - append items
-                        self.browse_list.threads.extend(threads);
+                        self.browse_list.all_threads.extend(threads);
                     }
+                    self.browse_list.apply_archive_filter();
                     self.browse_list.total_count = self.browse_list.threads.len();
                     self.browse_list.has_more = has_more;
                     self.browse_list.loading = false;
                     self.browse_list.searching = false;
+                    // Repos may have been moved onto while the threads were loading
+                    let last = self.browse_list.item_count().saturating_sub(1);
+                    self.browse_list.selected_index = self.browse_list.selected_index.min(last);
+                    self.browse_list.scroll_offset = self.browse_list.scroll_offset.min(last);
                     self.mark_dirty();
                 }
             }
@@ -1702,8 +2083,15 @@ impl App {
                 offset,
                 has_more,
             } => {
-                // Only update if we're still on the BrowseList screen in Repos mode
+                // Only update if we're still on the BrowseList screen showing repos
                 if self.screen == crate::app::Screen::BrowseList
+                    && self.browse_list.mode == crate::app::BrowseListMode::All
+                {
+                    // Combined mode: everything to filter locally as the query is typed
+                    let query = self.browse_list.search_query.clone();
+                    self.browse_list.all_repos = repos;
+                    self.browse_list_filter_repos_local(&query);
+                } else if self.screen == crate::app::Screen::BrowseList
                     && self.browse_list.mode == crate::app::BrowseListMode::Repos
                 {
                     if offset == 0 {
@@ -2282,20 +2670,16 @@ impl App {
                     );
                 }
                 // Persist conductor mode to ~/.spoq/config.json
-                let spoq_config = if vps_url.contains("localhost") || vps_url.contains("127.0.0.1") {
-                    crate::startup::config::SpoqConfig {
-                        conductor_mode: "local".to_string(),
-                        conductor_url: Some(vps_url.clone()),
-                    }
+                if vps_url.contains("localhost") || vps_url.contains("127.0.0.1") {
+                    self.config.conductor_mode = "local".to_string();
+                    self.config.conductor_url = Some(vps_url.clone());
                 } else {
-                    crate::startup::config::SpoqConfig {
-                        conductor_mode: "remote".to_string(),
-                        conductor_url: None,
-                    }
-                };
-                if let Err(e) = spoq_config.save() {
-                    tracing::warn!("Failed to save conductor config: {}", e);
+                    self.config.conductor_mode = "remote".to_string();
+                    self.config.conductor_url = None;
                 }
+                self.save_config();
+                // Completes the setup wizard's VPS step if it opened the dialog
+                self.setup_vps_ready(vps_url, hostname);
                 self.mark_dirty();
             }
             AppMessage::VpsConfigFailed { error, is_auth_error } => {
@@ -2341,51 +2725,200 @@ impl App {
                 }
             }
             AppMessage::VpsAuthComplete { access_token, refresh_token, expires_in, user_id } => {
-                // Update credentials
-                self.credentials.access_token = Some(access_token.clone());
-                if let Some(rt) = refresh_token {
-                    self.credentials.refresh_token = Some(rt);
-                }
-                // Compute and store expiration so startup doesn't consider token expired
-                let expires_secs = expires_in
-                    .or_else(|| crate::auth::central_api::get_jwt_expires_in(&access_token))
-                    .unwrap_or(900);
-                self.credentials.expires_at = Some(
-                    chrono::Utc::now().timestamp() + i64::from(expires_secs),
-                );
-                if let Some(uid) = user_id {
-                    self.credentials.user_id = Some(uid);
-                }
-                // Save to disk
-                if let Some(ref manager) = self.credentials_manager {
-                    let _ = manager.save(&self.credentials);
-                }
-                // Recreate CentralApiClient with new tokens
-                let mut new_central = crate::auth::central_api::CentralApiClient::new()
-                    .with_auth(&access_token);
-                if let Some(ref rt) = self.credentials.refresh_token {
-                    new_central = new_central.with_refresh_token(rt);
-                }
-                self.central_api = Some(std::sync::Arc::new(new_central));
+                // Update credentials, on disk and in the Central API client
+                self.store_auth_tokens(access_token, refresh_token, expires_in, user_id);
                 // Auto-retry VPS replace with stored credentials
                 if let Some((ip, _username, password)) = self.dashboard.take_vps_pending_credentials() {
                     self.start_vps_replace(ip, password);
                 }
                 self.mark_dirty();
             }
+            AppMessage::SetupAuthStarted { verification_url, user_code } => {
+                self.setup_auth_started(verification_url, user_code);
+            }
+            AppMessage::SetupAuthComplete { access_token, refresh_token, expires_in, user_id } => {
+                self.setup_auth_complete(access_token, refresh_token, expires_in, user_id);
+            }
+            AppMessage::SetupVpsProgress { status } => {
+                self.setup_vps_progress(status);
+            }
+            AppMessage::SetupVpsReady { vps_url, hostname } => {
+                self.setup_vps_ready(vps_url, hostname);
+            }
+            AppMessage::SetupVpsMissing => {
+                self.setup_vps_missing();
+            }
+            AppMessage::SetupCredentialsDetected { checks } => {
+                self.setup_credentials_detected(checks);
+            }
+            AppMessage::SetupStepFailed { step, error } => {
+                self.setup_step_failed(step, error);
+            }
             AppMessage::ThreadDeleted { thread_id } => {
-                // Thread was successfully deleted from backend
-                // (Cache already cleared, navigation already happened in /discard handler)
+                // Deleted on the backend, by us or by another client (WebSocket).
+                // Local removal is a no-op if we already removed it optimistically.
                 tracing::info!("Thread {} deleted from backend", thread_id);
+                self.deleted_threads.remove(&thread_id);
+                self.remove_thread_locally(&thread_id);
+                if self.thread_metrics.remove(&thread_id).is_some() {
+                    self.save_thread_metrics();
+                }
+            }
+            AppMessage::EditsReverted { thread_id, count } => {
+                self.update_thread_metrics(&thread_id, |m| m.reverted_edits += count);
+                self.save_thread_metrics();
             }
             AppMessage::ThreadDeleteFailed { thread_id, error } => {
-                // Backend delete failed - show timed error
+                // Backend delete failed - put the thread back and show timed error
                 tracing::warn!("Failed to delete thread {}: {}", thread_id, error);
+                self.restore_deleted_thread(&thread_id);
                 self.set_timed_error(
                     format!("Failed to delete thread: {}", error),
                     std::time::Duration::from_secs(4),
                 );
             }
+            AppMessage::ThreadArchiveFailed {
+                thread_id,
+                previous,
+                error,
+            } => {
+                tracing::warn!(
+                    "Failed to save archive state of thread {}: {}",
+                    thread_id,
+                    error
+                );
+                self.apply_thread_archive(&thread_id, previous);
+                self.set_timed_error(
+                    format!("Failed to archive thread: {}", error),
+                    std::time::Duration::from_secs(4),
+                );
+            }
+            AppMessage::ThreadLinksUpdateFailed {
+                thread_id,
+                previous,
+                error,
+            } => {
+                tracing::warn!("Failed to save links for thread {}: {}", thread_id, error);
+                self.apply_thread_links(&thread_id, previous);
+                self.set_timed_error(
+                    format!("Failed to save thread links: {}", error),
+                    std::time::Duration::from_secs(4),
+                );
+            }
+            AppMessage::ThreadPinUpdateFailed {
+                thread_id,
+                previous_pinned,
+                previous_pin_order,
+                error,
+            } => {
+                tracing::warn!("Failed to save pin for thread {}: {}", thread_id, error);
+                self.apply_thread_pin(&thread_id, previous_pinned, previous_pin_order);
+                self.save_local_pins();
+                self.set_timed_error(
+                    format!("Failed to save thread pin: {}", error),
+                    std::time::Duration::from_secs(4),
+                );
+            }
+            AppMessage::ThreadRenameFailed {
+                thread_id,
+                title,
+                previous_title,
+                error,
+            } => {
+                tracing::warn!("Failed to rename thread {}: {}", thread_id, error);
+                self.revert_thread_rename(&thread_id, &title, previous_title);
+                self.set_timed_error(
+                    format!("Failed to rename thread: {}", error),
+                    std::time::Duration::from_secs(4),
+                );
+            }
+            AppMessage::ThreadModelUpdateFailed {
+                thread_id,
+                previous,
+                error,
+            } => {
+                tracing::warn!("Failed to save model for thread {}: {}", thread_id, error);
+                self.cache.set_thread_model(&thread_id, previous);
+                self.set_timed_error(
+                    format!("Failed to save thread model: {}", error),
+                    std::time::Duration::from_secs(4),
+                );
+            }
+            AppMessage::ThreadWorkingDirectoryChanged { thread_id, path } => {
+                self.apply_working_directory_change(&thread_id, &path);
+            }
+            AppMessage::ThreadWorkingDirectoryUpdateFailed {
+                thread_id,
+                path,
+                error,
+            } => {
+                tracing::warn!(
+                    "Failed to change working directory of thread {}: {}",
+                    thread_id,
+                    error
+                );
+                self.fail_working_directory_change(&path, &error);
+            }
+            AppMessage::ThreadShared { thread_id, share } => {
+                self.apply_thread_share(&thread_id, share);
+            }
+            AppMessage::ThreadShareFailed { thread_id, error } => {
+                tracing::warn!("Failed to share thread {}: {}", thread_id, error);
+                self.fail_thread_share(&thread_id, format!("Couldn't share thread: {}", error));
+            }
+            AppMessage::ThreadUnshared { thread_id } => {
+                self.apply_thread_unshare(&thread_id);
+            }
+            AppMessage::ThreadUnshareFailed { thread_id, error } => {
+                tracing::warn!("Failed to revoke share of thread {}: {}", thread_id, error);
+                self.fail_thread_share(
+                    &thread_id,
+                    format!("Couldn't revoke share link: {}", error),
+                );
+            }
+            AppMessage::AttachmentUploadFailed {
+                thread_id,
+                content,
+                images,
+                error,
+            } => {
+                self.handle_attachment_upload_failed(&thread_id, content, images, &error);
+            }
+            AppMessage::PermissionResolved {
+                permission_id,
+                allowed,
+            } => {
+                self.handle_permission_resolved(&permission_id, allowed);
+            }
+            AppMessage::PendingPermissionsFetched {
+                permission_ids,
+                requested_at,
+            } => {
+                self.reconcile_pending_permissions(&permission_ids, requested_at);
+            }
+            AppMessage::PendingPermissionsFetchFailed { error } => {
+                tracing::debug!("Failed to re-query pending permissions: {}", error);
+                self.permission_recheck_in_flight = false;
+            }
+            AppMessage::PresenceUpdated {
+                thread_id,
+                client_id,
+                client_kind,
+                state,
+            } => {
+                self.apply_presence_update(thread_id, client_id, client_kind, state);
+            }
+            AppMessage::NotificationDismissed { notification_ids } => {
+                self.send_notifications_dismissed(notification_ids);
+            }
+            AppMessage::UpdateReady { version } => {
+                let detail = format!("Update {} downloaded; restart to apply", version);
+                self.notify_desktop(NotificationKind::UpdateAvailable, None, &detail);
+                self.set_status_notice(detail, std::time::Duration::from_secs(8));
+            }
+            AppMessage::SetLogLevel(level) => {
+                self.apply_log_level(level);
+            }
         }
     }
 }
@@ -0,0 +1,149 @@
+//! Input history search methods for the App.
+//!
+//! Ctrl+R while the input is focused opens a reverse search over
+//! `InputHistory`. Typing refines the query (fuzzy, see
+//! `InputHistory::search`), Up/Down move through the candidates, Enter puts
+//! the highlighted entry in the input and Esc closes the search. The input
+//! isn't touched until Enter, so cancelling leaves whatever was typed.
+
+use super::{App, Focus};
+
+/// Maximum number of candidates shown in the overlay
+pub const HISTORY_SEARCH_LIMIT: usize = 8;
+
+impl App {
+    /// Open history search.
+    ///
+    /// Returns false if the input isn't focused or there's no history.
+    pub fn open_history_search(&mut self) -> bool {
+        if self.focus != Focus::Input || self.input_history.is_empty() {
+            return false;
+        }
+        self.history_search = Default::default();
+        self.history_search.active = true;
+        self.run_history_search();
+        true
+    }
+
+    /// Close history search, leaving the input as it was
+    pub fn close_history_search(&mut self) {
+        self.history_search = Default::default();
+        self.mark_dirty();
+    }
+
+    /// Replace the input with the highlighted entry and close search
+    pub fn accept_history_search(&mut self) {
+        if let Some(entry) = self.history_search.selected_match() {
+            let entry = entry.to_string();
+            self.textarea.set_content(&entry);
+            self.input_history.reset_navigation();
+        }
+        self.close_history_search();
+    }
+
+    /// Append a character to the query
+    pub fn history_search_type_char(&mut self, c: char) {
+        self.history_search.query.push(c);
+        self.run_history_search();
+    }
+
+    /// Remove the last character from the query
+    pub fn history_search_backspace(&mut self) {
+        if self.history_search.query.pop().is_some() {
+            self.run_history_search();
+        }
+    }
+
+    /// Highlight the next (older or worse) candidate, wrapping around
+    pub fn history_search_next(&mut self) {
+        let count = self.history_search.matches.len();
+        if count > 0 {
+            self.history_search.selected = (self.history_search.selected + 1) % count;
+            self.mark_dirty();
+        }
+    }
+
+    /// Highlight the previous candidate, wrapping around
+    pub fn history_search_prev(&mut self) {
+        let count = self.history_search.matches.len();
+        if count > 0 {
+            self.history_search.selected = (self.history_search.selected + count - 1) % count;
+            self.mark_dirty();
+        }
+    }
+
+    /// Re-run the search for the current query and highlight the best match
+    fn run_history_search(&mut self) {
+        self.history_search.matches = self
+            .input_history
+            .search(&self.history_search.query, HISTORY_SEARCH_LIMIT)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        self.history_search.selected = 0;
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input_history::InputHistory;
+
+    fn app_with_history(entries: &[&str]) -> App {
+        let mut app = App::default();
+        app.focus = Focus::Input;
+        app.input_history = InputHistory::new();
+        for entry in entries {
+            app.input_history.add(entry.to_string());
+        }
+        app
+    }
+
+    #[test]
+    fn test_open_requires_input_focus_and_history() {
+        let mut app = app_with_history(&[]);
+        assert!(!app.open_history_search());
+
+        let mut app = app_with_history(&["hello"]);
+        app.focus = Focus::Threads;
+        assert!(!app.open_history_search());
+
+        app.focus = Focus::Input;
+        assert!(app.open_history_search());
+        assert_eq!(app.history_search.matches, vec!["hello"]);
+    }
+
+    #[test]
+    fn test_accept_inserts_selected_match() {
+        let mut app = app_with_history(&["cargo build", "git status", "cargo test"]);
+        app.textarea.set_content("draft");
+        app.open_history_search();
+
+        for c in "cargo".chars() {
+            app.history_search_type_char(c);
+        }
+        assert_eq!(
+            app.history_search.matches,
+            vec!["cargo test", "cargo build"]
+        );
+
+        app.history_search_next();
+        app.accept_history_search();
+        assert_eq!(app.textarea.content(), "cargo build");
+        assert!(!app.history_search.active);
+    }
+
+    #[test]
+    fn test_cancel_keeps_typed_input() {
+        let mut app = app_with_history(&["cargo build"]);
+        app.textarea.set_content("draft");
+        app.open_history_search();
+        app.history_search_type_char('c');
+        app.history_search_prev();
+
+        app.close_history_search();
+        assert_eq!(app.textarea.content(), "draft");
+        assert!(app.history_search.matches.is_empty());
+    }
+}
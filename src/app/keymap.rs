@@ -0,0 +1,190 @@
+//! Custom keymap application for the App.
+//!
+//! Problems in `~/.config/spoq/keymap.toml` don't stop startup: the usable
+//! lines apply and the offending ones are listed in an in-app warning. `/keys`
+//! shows the effective bindings.
+
+use std::time::Duration;
+
+use crate::input::keymap::{Action, Keymap, KeymapWarning};
+use crate::startup::load_keymap;
+
+use super::{App, Focus, Screen};
+
+/// How long keymap warnings stay visible
+const KEYMAP_WARNING_DURATION: Duration = Duration::from_secs(10);
+
+impl App {
+    /// Load `~/.config/spoq/keymap.toml`, if present, and apply it.
+    pub fn load_keymap(&mut self) {
+        let (keymap, warnings) = load_keymap();
+        self.apply_keymap(keymap, warnings);
+    }
+
+    /// Use a keymap, warning about the lines that were skipped.
+    pub fn apply_keymap(&mut self, keymap: Keymap, warnings: Vec<KeymapWarning>) {
+        self.keymap = keymap;
+        self.keymap_warnings = warnings;
+        if self.keymap_warnings.is_empty() {
+            return;
+        }
+
+        for warning in &self.keymap_warnings {
+            tracing::warn!("keymap.toml: {}", warning);
+        }
+        let lines = self
+            .keymap_warnings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ");
+        self.set_timed_error(
+            format!("keymap.toml: {} — see /keys", lines),
+            KEYMAP_WARNING_DURATION,
+        );
+    }
+
+    /// Show the effective key bindings (`/keys`)
+    pub fn show_keys_dialog(&mut self) {
+        self.keys_dialog_visible = true;
+        self.mark_dirty();
+    }
+
+    /// Run a rebindable action while the input isn't focused: reconnecting
+    /// while the connection banner is up, and the open conversation's actions.
+    ///
+    /// Checked before a printable key moves focus to the input, which would
+    /// otherwise type these keys instead. Returns whether it was handled.
+    pub fn handle_panel_action(&mut self, action: Action) -> bool {
+        if self.focus == Focus::Input {
+            return false;
+        }
+        match action {
+            Action::ReconnectNow if self.connection_banner().is_some() => self.reconnect_now(),
+            _ if self.screen != Screen::Conversation => return false,
+            Action::CycleZoom => self.cycle_zoom(),
+            Action::ToggleToolOutput => {
+                self.toggle_tool_output();
+            }
+            Action::CopyToolOutput => self.copy_tool_output(),
+            Action::ToggleSubagents => {
+                self.toggle_subagents();
+            }
+            Action::ToggleFollowUps => {
+                self.toggle_followups();
+            }
+            Action::ToggleTimestamps => self.toggle_timestamps(),
+            Action::UndoDismissError => {
+                self.undo_dismiss_error();
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::ZoomLevel;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    /// Conversation open with the thread list focused, as after Esc
+    fn app_in_conversation() -> App {
+        App {
+            screen: Screen::Conversation,
+            focus: Focus::Threads,
+            active_thread_id: Some("t1".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn action(app: &App, code: KeyCode) -> Action {
+        let modifiers = match code {
+            KeyCode::Char(c) if c.is_ascii_uppercase() => KeyModifiers::SHIFT,
+            _ => KeyModifiers::NONE,
+        };
+        app.keymap
+            .action_for(&KeyEvent::new(code, modifiers))
+            .expect("default binding")
+    }
+
+    #[test]
+    fn test_zoom_key_reaches_the_conversation() {
+        let mut app = app_in_conversation();
+
+        assert!(app.handle_panel_action(action(&app, KeyCode::Char('z'))));
+        assert_ne!(app.zoom_level(), ZoomLevel::Full);
+
+        // While typing, `z` is text
+        app.focus = Focus::Input;
+        assert!(!app.handle_panel_action(action(&app, KeyCode::Char('z'))));
+    }
+
+    #[test]
+    fn test_tool_output_keys_reach_the_conversation() {
+        let mut app = app_in_conversation();
+
+        assert!(app.handle_panel_action(action(&app, KeyCode::Char('o'))));
+        assert!(app.handle_panel_action(action(&app, KeyCode::Char('O'))));
+        assert_eq!(app.stream_error.as_deref(), Some("No tool output to copy"));
+
+        app.focus = Focus::Input;
+        assert!(!app.handle_panel_action(action(&app, KeyCode::Char('o'))));
+        assert!(!app.handle_panel_action(action(&app, KeyCode::Char('O'))));
+    }
+
+    #[test]
+    fn test_subagents_key_reaches_the_conversation() {
+        let mut app = app_in_conversation();
+        assert!(app.handle_panel_action(action(&app, KeyCode::Char('s'))));
+
+        app.focus = Focus::Input;
+        assert!(!app.handle_panel_action(action(&app, KeyCode::Char('s'))));
+    }
+
+    #[test]
+    fn test_timestamps_key_reaches_the_conversation() {
+        let mut app = app_in_conversation();
+        let shown = app.show_timestamps;
+
+        assert!(app.handle_panel_action(action(&app, KeyCode::Char('T'))));
+        assert_ne!(app.show_timestamps, shown);
+
+        app.focus = Focus::Input;
+        assert!(!app.handle_panel_action(action(&app, KeyCode::Char('T'))));
+    }
+
+    #[test]
+    fn test_undo_dismiss_key_reaches_the_conversation() {
+        let mut app = app_in_conversation();
+        app.cache.add_error_simple(
+            "t1",
+            "stream_error".to_string(),
+            "Connection lost".to_string(),
+        );
+        assert!(app.dismiss_focused_error());
+
+        assert!(app.handle_panel_action(action(&app, KeyCode::Char('u'))));
+        assert_eq!(app.cache.error_count("t1"), 1);
+
+        app.focus = Focus::Input;
+        assert!(!app.handle_panel_action(action(&app, KeyCode::Char('u'))));
+    }
+
+    #[test]
+    fn test_keymap_warnings_list_offending_lines() {
+        let mut app = App::default();
+        let (keymap, warnings) = Keymap::parse("Quit = \"ctrl+q\"\nFly = \"f\"\n");
+
+        app.apply_keymap(keymap, warnings);
+
+        let warning = app.stream_error.as_deref().unwrap();
+        assert!(
+            warning.contains("line 2: unknown action `Fly`"),
+            "{}",
+            warning
+        );
+        assert_eq!(app.keymap_warnings.len(), 1);
+    }
+}
@@ -0,0 +1,249 @@
+//! Link hint mode for the conversation viewport.
+//!
+//! Ctrl+O (`LinkHints` in the keymap) numbers every distinct link of the
+//! messages in view; typing a number opens its link in the browser. The
+//! links come from `MarkdownCache::links`, so a message is only scanned again
+//! when its text changes. Links in code are left out unless
+//! `link_hints_include_code` is set in `~/.spoq/config.json`.
+
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{App, Screen};
+
+/// How long a failure to open the browser is shown
+const BROWSER_ERROR_DURATION: Duration = Duration::from_secs(5);
+
+/// Link hint mode state
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkHints {
+    /// Links in view; the link at index `i` has label `i + 1`
+    pub urls: Vec<String>,
+    /// Digits typed so far
+    pub typed: String,
+}
+
+impl LinkHints {
+    /// Label of `url` (links to the same URL share it)
+    pub fn label_for(&self, url: &str) -> Option<usize> {
+        self.urls.iter().position(|u| u == url).map(|i| i + 1)
+    }
+
+    /// Whether `label` can still be picked given the digits typed so far
+    pub fn is_candidate(&self, label: usize) -> bool {
+        label.to_string().starts_with(&self.typed)
+    }
+}
+
+impl App {
+    /// IDs of the messages in the conversation viewport in the last frame
+    fn visible_message_ids(&self, thread_id: &str) -> Vec<i64> {
+        let Some(cache) = self
+            .height_cache
+            .as_ref()
+            .filter(|cache| cache.thread_id.as_str() == thread_id)
+        else {
+            return Vec::new();
+        };
+        let top = self.message_viewport.top;
+        let bottom = top + self.message_viewport.height;
+        cache
+            .heights
+            .iter()
+            .filter(|h| h.cumulative_offset < bottom && h.cumulative_offset + h.visual_lines > top)
+            .map(|h| h.message_id)
+            .collect()
+    }
+
+    /// Distinct URLs linked from the messages in view, in reading order
+    pub fn visible_link_urls(&mut self) -> Vec<String> {
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return Vec::new();
+        };
+        let visible = self.visible_message_ids(&thread_id);
+        let Some(messages) = self.cache.get_messages(&thread_id) else {
+            return Vec::new();
+        };
+
+        let mut urls: Vec<String> = Vec::new();
+        for message in messages.iter().filter(|m| visible.contains(&m.id)) {
+            let content = if message.is_streaming {
+                &message.partial_content
+            } else {
+                &message.content
+            };
+            let links =
+                self.markdown_cache
+                    .links(message.id, content, self.link_hints_include_code);
+            for link in links.iter() {
+                if !urls.contains(&link.url) {
+                    urls.push(link.url.clone());
+                }
+            }
+        }
+        urls
+    }
+
+    /// Label the links in view (Ctrl+O).
+    ///
+    /// Returns false, with a notice, when no link is in view.
+    pub fn start_link_hints(&mut self) -> bool {
+        if self.screen != Screen::Conversation {
+            return false;
+        }
+        let urls = self.visible_link_urls();
+        if urls.is_empty() {
+            self.set_status_notice("No links in view".to_string(), Duration::from_secs(2));
+            return false;
+        }
+        self.link_hints = Some(LinkHints {
+            urls,
+            typed: String::new(),
+        });
+        self.mark_dirty();
+        true
+    }
+
+    /// Handle a key while link hints are shown.
+    ///
+    /// Digits pick a label, opening its link as soon as no longer label
+    /// starts with them (or on Enter); Backspace takes back a digit and any
+    /// other key closes the hints.
+    pub fn handle_link_hint_key(&mut self, key: KeyEvent) {
+        let Some(hints) = self.link_hints.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(c @ '0'..='9') => {
+                hints.typed.push(c);
+                let label: usize = hints.typed.parse().unwrap_or(0);
+                if label == 0 || label > hints.urls.len() {
+                    // No such label: start over
+                    hints.typed.clear();
+                } else if label * 10 > hints.urls.len() {
+                    let url = hints.urls[label - 1].clone();
+                    self.link_hints = None;
+                    self.open_link(&url);
+                }
+            }
+            KeyCode::Enter => {
+                let url = hints
+                    .typed
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|label| hints.urls.get(label.wrapping_sub(1)))
+                    .cloned();
+                self.link_hints = None;
+                if let Some(url) = url {
+                    self.open_link(&url);
+                }
+            }
+            KeyCode::Backspace => {
+                hints.typed.pop();
+            }
+            _ => self.link_hints = None,
+        }
+        self.mark_dirty();
+    }
+
+    /// Open a link in the browser
+    fn open_link(&mut self, url: &str) {
+        match open::that(url) {
+            Ok(()) => self.set_status_notice(format!("Opened {}", url), Duration::from_secs(2)),
+            Err(e) => self.set_timed_error(
+                format!("Couldn't open browser: {} ({})", e, url),
+                BROWSER_ERROR_DURATION,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{CachedHeights, MessageViewport};
+    use crate::models::MessageRole;
+    use crossterm::event::KeyModifiers;
+    use std::sync::Arc;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    /// Three 10-line messages, the viewport showing the last 15 lines
+    fn app_with_links(contents: [&str; 3]) -> App {
+        let mut app = App::default();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-1".to_string());
+        for content in contents {
+            app.cache
+                .add_message_simple("thread-1", MessageRole::Assistant, content.to_string());
+        }
+        let mut heights = CachedHeights::new(Arc::new("thread-1".to_string()), 80);
+        for message in app.cache.get_messages("thread-1").unwrap() {
+            heights.append(message.id, 0, 10);
+        }
+        app.height_cache = Some(heights);
+        app.message_viewport = MessageViewport {
+            top: 15,
+            height: 15,
+        };
+        app
+    }
+
+    #[test]
+    fn test_only_links_in_view_are_labelled() {
+        let mut app = app_with_links([
+            "Off screen: https://hidden.test",
+            "See [docs](https://docs.test) and https://crates.test",
+            "Again https://docs.test and `https://code.test`",
+        ]);
+        assert!(app.start_link_hints());
+        let hints = app.link_hints.as_ref().unwrap();
+        assert_eq!(hints.urls, vec!["https://docs.test", "https://crates.test"]);
+        assert_eq!(hints.label_for("https://docs.test"), Some(1));
+        assert_eq!(hints.label_for("https://hidden.test"), None);
+    }
+
+    #[test]
+    fn test_no_links_in_view_shows_notice() {
+        let mut app = app_with_links(["a", "b", "c"]);
+        assert!(!app.start_link_hints());
+        assert!(app.link_hints.is_none());
+        assert_eq!(app.status_notice.as_deref(), Some("No links in view"));
+    }
+
+    #[test]
+    fn test_digits_wait_while_longer_labels_match() {
+        let mut app = App::default();
+        app.link_hints = Some(LinkHints {
+            urls: (1..=12).map(|i| format!("https://{}.test", i)).collect(),
+            typed: String::new(),
+        });
+
+        app.handle_link_hint_key(key(KeyCode::Char('1')));
+        let hints = app.link_hints.as_ref().unwrap();
+        assert_eq!(hints.typed, "1");
+        assert!(hints.is_candidate(12));
+        assert!(!hints.is_candidate(2));
+
+        app.handle_link_hint_key(key(KeyCode::Backspace));
+        assert_eq!(app.link_hints.as_ref().unwrap().typed, "");
+
+        // No label 0: the digit is dropped
+        app.handle_link_hint_key(key(KeyCode::Char('0')));
+        assert_eq!(app.link_hints.as_ref().unwrap().typed, "");
+    }
+
+    #[test]
+    fn test_other_keys_close_hints() {
+        let mut app = App::default();
+        app.link_hints = Some(LinkHints {
+            urls: vec!["https://one.test".to_string()],
+            typed: String::new(),
+        });
+        app.handle_link_hint_key(key(KeyCode::Esc));
+        assert!(app.link_hints.is_none());
+    }
+}
@@ -0,0 +1,281 @@
+//! Log viewer (`/log`) and runtime log level switching for the App.
+//!
+//! The overlay shows the last `LOG_TAIL_LINES` lines of the log file, newest
+//! at the bottom. In follow mode it rereads the file every
+//! `LOG_REFRESH_INTERVAL` from the tick and stays on the newest line;
+//! scrolling up stops following so the view holds still. `e`/`w`/`i`/`d`
+//! hide lines below that level. `/log <level>` changes what gets logged.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use tracing::info;
+
+use crate::logging::{self, LogLevel};
+
+use super::{App, AppMessage};
+
+/// Lines read from the end of the log file
+pub const LOG_TAIL_LINES: usize = 1000;
+
+/// How often the file is reread while following
+const LOG_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long level change notices stay in the status line
+const NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+/// Open `/log` overlay
+#[derive(Debug, Clone)]
+pub struct LogView {
+    pub path: PathBuf,
+    /// Last lines of the file, oldest first
+    pub lines: Vec<String>,
+    /// Why the file couldn't be read
+    pub error: Option<String>,
+    /// Least severe level shown
+    pub min_level: LogLevel,
+    /// Reread the file as it grows and stay on the newest line
+    pub follow: bool,
+    /// Lines scrolled up from the newest one
+    pub scroll: usize,
+    /// When the file was last read
+    pub refreshed_at: Instant,
+}
+
+impl LogView {
+    /// Lines at or above `min_level`, oldest first.
+    ///
+    /// Lines without a level (continuations of a multi-line message) go with
+    /// the line before them.
+    pub fn visible_lines(&self) -> Vec<&str> {
+        let mut level = LogLevel::Info;
+        self.lines
+            .iter()
+            .filter(|line| {
+                if let Some(line_level) = LogLevel::of_line(line) {
+                    level = line_level;
+                }
+                level >= self.min_level
+            })
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Reread the end of the file
+    fn reload(&mut self) {
+        match logging::read_tail(&self.path, LOG_TAIL_LINES) {
+            Ok(lines) => {
+                self.lines = lines;
+                self.error = None;
+            }
+            Err(e) => {
+                self.lines.clear();
+                self.error = Some(e.to_string());
+            }
+        }
+        self.refreshed_at = Instant::now();
+    }
+}
+
+impl App {
+    /// Open the `/log` overlay, following the log file
+    pub fn open_log_view(&mut self) {
+        let mut view = LogView {
+            path: logging::log_path(),
+            lines: Vec::new(),
+            error: None,
+            min_level: LogLevel::Trace,
+            follow: true,
+            scroll: 0,
+            refreshed_at: Instant::now(),
+        };
+        view.reload();
+        self.log_view = Some(view);
+        self.mark_dirty();
+    }
+
+    /// Close the `/log` overlay
+    pub fn close_log_view(&mut self) {
+        self.log_view = None;
+        self.mark_dirty();
+    }
+
+    /// Scroll by `delta` lines (negative is up, towards older lines).
+    /// Scrolling up stops following.
+    pub fn scroll_log_view(&mut self, delta: isize) {
+        let Some(view) = self.log_view.as_mut() else {
+            return;
+        };
+        let count = view.visible_lines().len();
+        view.scroll = view
+            .scroll
+            .saturating_add_signed(-delta)
+            .min(count.saturating_sub(1));
+        if view.scroll > 0 {
+            view.follow = false;
+        }
+        self.mark_dirty();
+    }
+
+    /// Show only lines at or above `level`; the same level again shows all
+    pub fn set_log_view_level(&mut self, level: LogLevel) {
+        let Some(view) = self.log_view.as_mut() else {
+            return;
+        };
+        view.min_level = if view.min_level == level {
+            LogLevel::Trace
+        } else {
+            level
+        };
+        view.scroll = 0;
+        self.mark_dirty();
+    }
+
+    /// Turn follow mode on (jumping to the newest line) or off
+    pub fn toggle_log_follow(&mut self) {
+        let Some(view) = self.log_view.as_mut() else {
+            return;
+        };
+        view.follow = !view.follow;
+        if view.follow {
+            view.scroll = 0;
+            view.reload();
+        }
+        self.mark_dirty();
+    }
+
+    /// Reread the followed log file if it is due. Called every tick.
+    pub(super) fn refresh_log_view(&mut self) {
+        let Some(view) = self.log_view.as_mut() else {
+            return;
+        };
+        if view.follow && view.refreshed_at.elapsed() >= LOG_REFRESH_INTERVAL {
+            let before = view.lines.len();
+            let last = view.lines.last().cloned();
+            view.reload();
+            if view.lines.len() != before || view.lines.last() != last.as_ref() {
+                self.mark_dirty();
+            }
+        }
+    }
+
+    /// `/log <level>`: change what gets logged
+    pub fn log_level_command(&mut self, argument: &str) {
+        match LogLevel::parse(argument) {
+            Some(level) => {
+                let _ = self.message_tx.send(AppMessage::SetLogLevel(level));
+            }
+            None => self.set_timed_error(
+                format!(
+                    "Unknown log level '{}' (error, warn, info, debug or trace)",
+                    argument
+                ),
+                NOTICE_DURATION,
+            ),
+        }
+    }
+
+    /// Switch the log level of the running process
+    pub(super) fn apply_log_level(&mut self, level: LogLevel) {
+        match logging::set_level(level) {
+            Ok(()) => {
+                info!("Log level set to {}", level.as_str());
+                self.set_status_notice(
+                    format!("Log level set to {}", level.as_str()),
+                    NOTICE_DURATION,
+                );
+            }
+            Err(e) => {
+                self.set_status_notice(
+                    format!("Couldn't change the log level: {}", e),
+                    NOTICE_DURATION,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn app_with_log(dir: &TempDir, text: &str) -> App {
+        let path = dir.path().join("spoq.log");
+        std::fs::write(&path, text).unwrap();
+        let mut app = App::default();
+        app.open_log_view();
+        let view = app.log_view.as_mut().unwrap();
+        view.path = path;
+        view.reload();
+        app
+    }
+
+    const LOG: &str = "\
+2026-10-15T09:00:00.000000Z  INFO Starting
+2026-10-15T09:00:01.000000Z DEBUG Polling
+2026-10-15T09:00:02.000000Z  WARN Reconnecting
+2026-10-15T09:00:03.000000Z ERROR Request failed:
+  connection refused
+";
+
+    #[test]
+    fn test_level_filter_keeps_continuation_lines() {
+        let dir = TempDir::new().unwrap();
+        let mut app = app_with_log(&dir, LOG);
+        assert_eq!(app.log_view.as_ref().unwrap().visible_lines().len(), 5);
+
+        app.set_log_view_level(LogLevel::Warn);
+        let view = app.log_view.as_ref().unwrap();
+        let visible = view.visible_lines();
+        assert_eq!(visible.len(), 3);
+        assert!(visible[0].ends_with("Reconnecting"));
+        assert_eq!(visible[2], "  connection refused");
+
+        // Pressing the same level again shows everything
+        app.set_log_view_level(LogLevel::Warn);
+        assert_eq!(app.log_view.as_ref().unwrap().min_level, LogLevel::Trace);
+    }
+
+    #[test]
+    fn test_scrolling_up_stops_following() {
+        let dir = TempDir::new().unwrap();
+        let mut app = app_with_log(&dir, LOG);
+
+        app.scroll_log_view(-2);
+        let view = app.log_view.as_ref().unwrap();
+        assert_eq!(view.scroll, 2);
+        assert!(!view.follow);
+
+        // Clamped to the oldest line
+        app.scroll_log_view(-100);
+        assert_eq!(app.log_view.as_ref().unwrap().scroll, 4);
+
+        app.toggle_log_follow();
+        let view = app.log_view.as_ref().unwrap();
+        assert!(view.follow);
+        assert_eq!(view.scroll, 0);
+    }
+
+    #[test]
+    fn test_missing_file_shows_error() {
+        let dir = TempDir::new().unwrap();
+        let mut app = app_with_log(&dir, "");
+        let view = app.log_view.as_mut().unwrap();
+        view.path = dir.path().join("missing.log");
+        view.reload();
+        assert!(view.error.is_some());
+        assert!(view.lines.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_log_level_is_rejected() {
+        let mut app = App::default();
+        app.log_level_command("loud");
+        assert!(app
+            .stream_error
+            .as_deref()
+            .unwrap()
+            .starts_with("Unknown log level 'loud'"));
+    }
+}
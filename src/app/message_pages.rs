@@ -0,0 +1,252 @@
+//! Paginated message loading for long threads.
+//!
+//! Opening a thread that isn't cached fetches only its most recent page
+//! (`MESSAGE_PAGE_SIZE` messages), so rendering starts right away however long
+//! the thread is. When the reader scrolls near the top, the next older page is
+//! fetched and prepended to the cache; the message at the top of the viewport
+//! stays where it was. A "Loading earlier messages…" row shows at the top of
+//! the conversation while a page is in flight.
+//!
+//! Backends without the paginated endpoint (404) get the whole thread in one
+//! request, as before.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::conductor::ConductorError;
+use crate::models::{Message, MessagePageResponse};
+
+use super::{log_thread_update, App, AppMessage, Screen};
+
+/// Number of messages fetched per page
+pub const MESSAGE_PAGE_SIZE: usize = 100;
+
+/// Distance from the top of the conversation (in lines) at which the next
+/// older page is fetched
+const LOAD_OLDER_MARGIN: u16 = 20;
+
+/// How long to wait before retrying a failed page
+const OLDER_PAGE_RETRY: Duration = Duration::from_secs(10);
+
+impl App {
+    /// Fetch the most recent page of a thread's messages in the background.
+    ///
+    /// Sends `MessagesLoaded` (or `MessagesLoadError`) when done.
+    pub(super) fn fetch_latest_messages(&self, thread_id: &str) {
+        let client = Arc::clone(&self.client);
+        let message_tx = self.message_tx.clone();
+        let tid = thread_id.to_string();
+
+        tokio::spawn(async move {
+            log_thread_update(&format!("open_thread: fetch task started for {}", tid));
+            let page = match client.fetch_messages(&tid, MESSAGE_PAGE_SIZE, None).await {
+                Err(ConductorError::ServerError { status: 404, .. }) => client
+                    .fetch_thread_with_messages(&tid)
+                    .await
+                    .map(|response| MessagePageResponse {
+                        messages: response.messages,
+                        offset: 0,
+                    }),
+                result => result,
+            };
+            match page {
+                Ok(page) => {
+                    log_thread_update(&format!(
+                        "open_thread: fetch SUCCESS for {}, got {} messages ({} older)",
+                        tid,
+                        page.messages.len(),
+                        page.offset
+                    ));
+                    let older_count = page.offset;
+                    let messages = page.into_client_messages(&tid);
+                    let _ = message_tx.send(AppMessage::MessagesLoaded {
+                        thread_id: tid,
+                        messages,
+                        older_count,
+                    });
+                }
+                Err(e) => {
+                    log_thread_update(&format!("open_thread: fetch FAILED for {}: {:?}", tid, e));
+                    let _ = message_tx.send(AppMessage::MessagesLoadError {
+                        thread_id: tid,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Fetch the next older page of the open thread if the reader scrolled
+    /// near the top and there are messages left to load. Called every tick.
+    pub fn load_older_messages_if_needed(&mut self) {
+        if self.screen != Screen::Conversation {
+            return;
+        }
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return;
+        };
+        if !self.older_page_due(&thread_id) {
+            return;
+        }
+        if self.unified_scroll.saturating_add(LOAD_OLDER_MARGIN) < self.max_scroll {
+            return;
+        }
+
+        let before = self.cache.older_message_count(&thread_id);
+        self.older_page_requests.insert(thread_id.clone(), None);
+        self.mark_dirty();
+
+        let client = Arc::clone(&self.client);
+        let message_tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            match client
+                .fetch_messages(&thread_id, MESSAGE_PAGE_SIZE, Some(before))
+                .await
+            {
+                Ok(page) => {
+                    let messages = page.into_client_messages(&thread_id);
+                    let _ = message_tx.send(AppMessage::OlderMessagesLoaded {
+                        thread_id,
+                        messages,
+                    });
+                }
+                Err(e) => {
+                    let _ = message_tx.send(AppMessage::OlderMessagesLoadFailed {
+                        thread_id,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Whether an older page of a thread is being fetched
+    pub fn is_loading_older_messages(&self, thread_id: &str) -> bool {
+        matches!(self.older_page_requests.get(thread_id), Some(None))
+    }
+
+    /// Whether the next older page of a thread should be requested: there
+    /// are messages left, none in flight, and no recent failure
+    fn older_page_due(&self, thread_id: &str) -> bool {
+        if self.cache.older_message_count(thread_id) == 0 {
+            return false;
+        }
+        match self.older_page_requests.get(thread_id) {
+            None => true,
+            Some(None) => false,
+            Some(Some(failed_at)) => failed_at.elapsed() >= OLDER_PAGE_RETRY,
+        }
+    }
+
+    /// Prepend a page of older messages, keeping the viewport on the message
+    /// the reader was looking at
+    pub(super) fn prepend_older_messages(&mut self, thread_id: String, messages: Vec<Message>) {
+        self.older_page_requests.remove(&thread_id);
+        let count = self.cache.prepend_messages(&thread_id, messages);
+        if count > 0 && self.active_thread_id.as_deref() == Some(thread_id.as_str()) {
+            // The renderer scrolls this message back to the top of the viewport
+            self.pending_scroll_anchor = self.scroll_anchor;
+        }
+        self.mark_dirty();
+    }
+
+    /// Record a failed older page; it is retried after `OLDER_PAGE_RETRY`
+    pub(super) fn older_messages_failed(&mut self, thread_id: String, error: &str) {
+        tracing::warn!(
+            "Loading earlier messages of {} failed: {}",
+            thread_id,
+            error
+        );
+        self.older_page_requests
+            .insert(thread_id, Some(Instant::now()));
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::ScrollAnchor;
+    use crate::cache::ThreadCache;
+    use crate::models::MessageRole;
+
+    /// App showing messages 901..=1000 of a 1000-message thread
+    fn app_with_latest_page() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        // Drop the stub messages (set_messages would keep them as local ones)
+        app.cache.messages.remove("thread-001");
+        app.cache.set_older_message_count("thread-001", 900);
+        for i in 0..100 {
+            app.cache.add_message_simple(
+                "thread-001",
+                MessageRole::User,
+                format!("message {}", 901 + i),
+            );
+        }
+        app
+    }
+
+    /// Page of `count` older messages starting at `first_id`
+    fn older_page(app: &App, first_id: i64, count: i64) -> Vec<Message> {
+        let template = app.cache.get_messages("thread-001").unwrap()[0].clone();
+        (0..count)
+            .map(|i| Message {
+                id: first_id + i,
+                content: format!("message {}", first_id + i),
+                ..template.clone()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_latest_page_numbers_messages_by_position() {
+        let app = app_with_latest_page();
+        let messages = app.cache.get_messages("thread-001").unwrap();
+        assert_eq!(messages[0].id, 901);
+        assert_eq!(messages[99].id, 1000);
+        assert_eq!(app.cache.next_message_id("thread-001"), 1001);
+    }
+
+    #[test]
+    fn test_prepending_older_page_keeps_anchor_and_stops_loading() {
+        let mut app = app_with_latest_page();
+        app.older_page_requests
+            .insert("thread-001".to_string(), None);
+        assert!(app.is_loading_older_messages("thread-001"));
+        let anchor = ScrollAnchor {
+            message_id: 905,
+            fraction: 0.0,
+        };
+        app.scroll_anchor = Some(anchor);
+
+        let page = older_page(&app, 801, 100);
+        app.handle_message(AppMessage::OlderMessagesLoaded {
+            thread_id: "thread-001".to_string(),
+            messages: page,
+        });
+
+        let messages = app.cache.get_messages("thread-001").unwrap();
+        assert_eq!(messages.len(), 200);
+        assert_eq!(messages[0].id, 801);
+        assert_eq!(app.cache.older_message_count("thread-001"), 800);
+        assert!(!app.is_loading_older_messages("thread-001"));
+        assert_eq!(app.pending_scroll_anchor, Some(anchor));
+    }
+
+    #[test]
+    fn test_failed_page_waits_before_retrying() {
+        let mut app = app_with_latest_page();
+        assert!(app.older_page_due("thread-001"));
+
+        app.handle_message(AppMessage::OlderMessagesLoadFailed {
+            thread_id: "thread-001".to_string(),
+            error: "offline".to_string(),
+        });
+
+        assert!(!app.is_loading_older_messages("thread-001"));
+        assert!(!app.older_page_due("thread-001"));
+    }
+}
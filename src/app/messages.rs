@@ -1,10 +1,10 @@
 //! AppMessage enum for async communication within the application.
 
 use crate::models::dashboard::{PlanSummary, ThreadStatus, WaitingFor};
-use crate::models::picker::PickerItem;
-use crate::models::{Folder, GitHubRepo, Thread, ThreadMode};
+use crate::models::picker::{DirectoryListing, PickerItem};
+use crate::models::{Folder, GitHubRepo, ModelInfo, Thread, ThreadMode};
 use crate::state::session::AskUserQuestionData;
-use crate::state::Todo;
+use crate::state::{Task, TaskStatus, Todo};
 use crate::ui::dashboard::SystemStats;
 use crate::websocket::messages::PhaseStatus;
 
@@ -26,6 +26,22 @@ pub enum AppMessage {
     },
     /// Stream was cancelled by user request (Ctrl+C)
     StreamCancelled { thread_id: String, reason: String },
+    /// A content event with a sequence number was received (resume offset)
+    StreamProgress { thread_id: String, seq: u64 },
+    /// An SSE or WebSocket event for a thread was received (event log)
+    EventReceived {
+        thread_id: String,
+        record: super::EventRecord,
+    },
+    /// The stream connection dropped before a terminal event
+    StreamInterrupted { thread_id: String, error: String },
+    /// A resume request for an interrupted stream failed
+    StreamResumeFailed {
+        thread_id: String,
+        error: String,
+        /// True if the failure was transient and the resume can be retried on reconnect
+        retryable: bool,
+    },
     /// Rate limit hit - account is rate-limited
     RateLimited {
         thread_id: String,
@@ -36,19 +52,30 @@ pub enum AppMessage {
     },
     /// Connection status changed
     ConnectionStatus(bool),
+    /// Streams switched to (true) or away from the polling transport
+    PollingModeChanged(bool),
     /// Thread created on backend - reconcile pending ID with real ID
     ThreadCreated {
         pending_id: String,
         real_id: String,
         title: Option<String>,
     },
-    /// Messages loaded for a thread
+    /// Messages loaded for a thread (its most recent page)
     MessagesLoaded {
         thread_id: String,
         messages: Vec<crate::models::Message>,
+        /// Messages before the page, fetched as the user scrolls up
+        older_count: usize,
     },
     /// Error loading messages for a thread
     MessagesLoadError { thread_id: String, error: String },
+    /// A page of older messages loaded for a thread (oldest first)
+    OlderMessagesLoaded {
+        thread_id: String,
+        messages: Vec<crate::models::Message>,
+    },
+    /// Error loading older messages for a thread
+    OlderMessagesLoadFailed { thread_id: String, error: String },
     /// Todos updated from the assistant
     TodosUpdated { todos: Vec<Todo> },
     /// Permission request from the assistant - needs user approval
@@ -59,6 +86,32 @@ pub enum AppMessage {
         description: String,
         tool_input: Option<serde_json::Value>,
     },
+    /// A permission was answered elsewhere (web UI, another client) or timed out
+    PermissionResolved {
+        permission_id: String,
+        /// Whether it was allowed (None when cancelled or timed out)
+        allowed: Option<bool>,
+    },
+    /// Another client's presence on a thread changed (viewing, typing, sent)
+    PresenceUpdated {
+        thread_id: String,
+        client_id: String,
+        client_kind: String,
+        state: crate::websocket::WsPresenceState,
+    },
+    /// Pending permissions re-queried from the backend
+    PendingPermissionsFetched {
+        /// Permissions the backend is still waiting on
+        permission_ids: Vec<String>,
+        /// When the query was sent (prompts received after this are kept)
+        requested_at: std::time::Instant,
+    },
+    /// Re-querying pending permissions failed
+    PendingPermissionsFetchFailed { error: String },
+    /// Notifications were dismissed from the panel (synced to the backend)
+    NotificationDismissed { notification_ids: Vec<String> },
+    /// A newer version was downloaded in the background
+    UpdateReady { version: String },
     /// Tool call started
     ToolStarted {
         thread_id: String,
@@ -99,12 +152,28 @@ pub enum AppMessage {
         tokens_used: Option<u32>,
         token_limit: Option<u32>,
     },
+    /// A compaction requested with `/compact` finished
+    ContextCompactFinished {
+        thread_id: String,
+        response: crate::models::CompactResponse,
+    },
+    /// A compaction requested with `/compact` failed
+    ContextCompactFailed { thread_id: String, error: String },
     /// Thread metadata updated
     ThreadMetadataUpdated {
         thread_id: String,
         title: Option<String>,
         description: Option<String>,
     },
+    /// Outcome summary generated for a thread's latest run
+    ThreadSummaryReady { thread_id: String, summary: String },
+    /// A thread event could not be delivered to a webhook
+    WebhookFailed {
+        thread_id: String,
+        /// Webhook host (the full URL may contain secrets)
+        host: String,
+        error: String,
+    },
     /// Subagent task started
     SubagentStarted {
         task_id: String,
@@ -119,10 +188,14 @@ pub enum AppMessage {
         summary: String,
         tool_call_count: Option<u32>,
     },
-    /// Usage information received (context window usage)
+    /// Usage information received after a turn (context window usage, and
+    /// the turn's token counts when reported)
     UsageReceived {
+        thread_id: String,
         context_used: u32,
         context_limit: u32,
+        input_tokens: Option<u64>,
+        output_tokens: Option<u64>,
     },
     /// WebSocket connected successfully
     WsConnected,
@@ -130,10 +203,21 @@ pub enum AppMessage {
     WsDisconnected,
     /// WebSocket reconnecting
     WsReconnecting { attempt: u8 },
-    /// WebSocket reconnected with new sender (after VPS swap)
-    WsReconnected {
-        sender: tokio::sync::mpsc::Sender<crate::websocket::WsOutgoingMessage>,
+    /// A task's state changed (pushed by the backend, or returned by a
+    /// start/pause request)
+    TaskUpdated { task: Task },
+    /// Starting or pausing a task failed; its status goes back to `previous`
+    TaskActionFailed {
+        task_id: String,
+        previous: TaskStatus,
+        error: String,
     },
+    /// WebSocket reconnected with new channels (after VPS swap)
+    WsReconnected { handle: super::WsHandle },
+    /// WebSocket client started by a manual reconnect (`R`)
+    WsClientStarted { handle: super::WsHandle },
+    /// Manual reconnect (`R`) couldn't start a WebSocket client
+    WsClientStartFailed { error: String },
     /// Dashboard data refreshed from new conductor (after VPS swap)
     DashboardDataRefreshed {
         threads: Vec<Thread>,
@@ -142,10 +226,37 @@ pub enum AppMessage {
     WsRawMessage { message: String },
     /// WebSocket message parse error (for debugging)
     WsParseError { error: String, raw: String },
+    /// WebSocket connection lost, with why
+    WsConnectionLost { reason: String },
+    /// Number of outgoing WebSocket messages awaiting an ack changed
+    WsPendingAcks(usize),
+    /// Unacknowledged outgoing WebSocket message sent again
+    WsRetransmitted {
+        message_id: String,
+        kind: String,
+        attempt: u8,
+    },
+    /// Outgoing WebSocket message never acknowledged, given up on
+    WsUndelivered { message_id: String, kind: String },
+    /// Threads (and tasks) fetched at startup
+    ThreadsLoaded {
+        threads: Vec<Thread>,
+        tasks: Vec<Task>,
+    },
+    /// Startup thread fetch failed; the CommandDeck starts empty
+    ThreadsLoadFailed(String),
+    /// WebSocket client connected at startup
+    WsStarted { handle: super::WsHandle },
+    /// Startup WebSocket connection failed; the app continues over SSE
+    WsStartFailed { error: String },
     /// Folders loaded from API
     FoldersLoaded(Vec<Folder>),
     /// Failed to load folders from API
     FoldersLoadFailed(String),
+    /// Models loaded from API for the model picker
+    ModelsLoaded(Vec<ModelInfo>),
+    /// Failed to load models from API
+    ModelsLoadFailed(String),
     /// GitHub repos loaded from API
     ReposLoaded(Vec<GitHubRepo>),
     /// Failed to load repos from API
@@ -166,8 +277,8 @@ pub enum AppMessage {
     FolderPickerCursorDown,
     /// A folder was selected from the picker
     FolderSelected(Folder),
-    /// Clear the currently selected folder
-    FolderCleared,
+    /// Remove the attached folder at this index
+    FolderRemoved(usize),
     /// System stats update (CPU, RAM)
     SystemStatsUpdate(SystemStats),
     /// Thread status update from WebSocket (for dashboard)
@@ -243,6 +354,13 @@ pub enum AppMessage {
     },
     /// Clone operation failed
     UnifiedPickerCloneFailed { error: String },
+    /// Directory listing received while browsing in the unified picker
+    UnifiedPickerDirectoryLoaded {
+        requested: String,
+        listing: DirectoryListing,
+    },
+    /// Directory listing failed (shown inline in the picker)
+    UnifiedPickerDirectoryFailed { requested: String, error: String },
     // =========================================================================
     // Credential Auto-Sync Messages
     // =========================================================================
@@ -334,10 +452,40 @@ pub enum AppMessage {
         child: std::sync::Arc<tokio::sync::Mutex<Option<tokio::process::Child>>>,
     },
     // =========================================================================
+    // Setup Wizard Messages
+    // =========================================================================
+    /// Setup sign-in started - show the verification URL and code
+    SetupAuthStarted {
+        verification_url: String,
+        user_code: String,
+    },
+    /// Setup sign-in authorized
+    SetupAuthComplete {
+        access_token: String,
+        refresh_token: Option<String>,
+        expires_in: Option<u32>,
+        user_id: Option<String>,
+    },
+    /// The user's VPS is still starting
+    SetupVpsProgress { status: String },
+    /// The user's VPS is ready
+    SetupVpsReady { vps_url: String, hostname: String },
+    /// The user has no VPS yet
+    SetupVpsMissing,
+    /// Local credentials checked
+    SetupCredentialsDetected {
+        checks: crate::app::CredentialChecks,
+    },
+    /// A setup step failed (Enter retries it)
+    SetupStepFailed {
+        step: crate::app::SetupStep,
+        error: String,
+    },
+    // =========================================================================
     // Sync Messages
     // =========================================================================
-    /// Trigger token sync operation (from /sync command)
-    TriggerSync,
+    /// Trigger token sync operation (from /sync or the credential watcher)
+    TriggerSync { changed_files: Vec<String> },
     /// Sync operation started
     SyncStarted,
     /// Sync progress update
@@ -345,9 +493,13 @@ pub enum AppMessage {
     /// Sync completed successfully
     SyncComplete {
         github_cli: bool,
+        report: crate::credential_watcher::SyncReport,
     },
     /// Sync operation failed
-    SyncFailed { error: String },
+    SyncFailed {
+        error: String,
+        report: crate::credential_watcher::SyncReport,
+    },
     // =========================================================================
     // Browse List Messages (for /threads and /repos full-screen views)
     // =========================================================================
@@ -414,11 +566,82 @@ pub enum AppMessage {
     ThreadDeleted {
         thread_id: String,
     },
+    /// Edits made in a thread were reverted (reported by the backend)
+    EditsReverted { thread_id: String, count: u32 },
     /// Thread deletion failed
     ThreadDeleteFailed {
         thread_id: String,
         error: String,
     },
+    /// Archiving or unarchiving a thread on the backend failed
+    ThreadArchiveFailed {
+        thread_id: String,
+        /// Archive state before the failed update (restored locally)
+        previous: bool,
+        error: String,
+    },
+    /// Saving a thread's links on the backend failed
+    ThreadLinksUpdateFailed {
+        thread_id: String,
+        /// Links before the failed update (restored locally)
+        previous: Vec<crate::models::ThreadLink>,
+        error: String,
+    },
+    /// Saving a thread's pin on the backend failed
+    ThreadPinUpdateFailed {
+        thread_id: String,
+        /// Pin state before the failed update (restored locally)
+        previous_pinned: bool,
+        previous_pin_order: u32,
+        error: String,
+    },
+    /// Renaming a thread on the backend failed
+    ThreadRenameFailed {
+        thread_id: String,
+        /// Title the backend rejected
+        title: String,
+        /// Title before the failed rename (restored locally)
+        previous_title: String,
+        error: String,
+    },
+    /// Saving a thread's model on the backend failed
+    ThreadModelUpdateFailed {
+        thread_id: String,
+        /// Model before the failed update (restored locally)
+        previous: Option<String>,
+        error: String,
+    },
+    /// The backend accepted a thread's new working directory (`/cd`)
+    ThreadWorkingDirectoryChanged { thread_id: String, path: String },
+    /// Changing a thread's working directory on the backend failed
+    ThreadWorkingDirectoryUpdateFailed {
+        thread_id: String,
+        /// Directory the backend rejected
+        path: String,
+        error: String,
+    },
+    /// A thread was shared (`/share`)
+    ThreadShared {
+        thread_id: String,
+        share: crate::models::ThreadShare,
+    },
+    /// Sharing a thread failed
+    ThreadShareFailed { thread_id: String, error: String },
+    /// A thread's share link was revoked
+    ThreadUnshared { thread_id: String },
+    /// Revoking a thread's share link failed
+    ThreadUnshareFailed { thread_id: String, error: String },
+    /// Uploading a message's images failed (the message was not sent)
+    AttachmentUploadFailed {
+        thread_id: String,
+        /// Text of the unsent message (restored to the input)
+        content: String,
+        /// Images of the unsent message (restored as chips)
+        images: Vec<crate::clipboard::ImageAttachment>,
+        error: String,
+    },
+    /// Change the log level while running (`/log <level>`)
+    SetLogLevel(crate::logging::LogLevel),
 }
 
 #[cfg(test)]
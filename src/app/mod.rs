@@ -7,25 +7,127 @@
 //! - [`AppMessage`] - Messages for async communication
 
 mod actions;
+mod attachments;
+mod attention;
 pub mod backend_coordinator;
+mod browse_combined;
+mod bypass_confirm;
 mod cancel;
+mod capabilities;
+mod compaction;
+mod context_usage;
+mod copy;
+mod crash;
 pub mod cursor_blink;
+mod debug_snapshot;
+mod desktop_notifications;
+mod directory_browser;
+mod editor_handoff;
+mod event_log;
+mod export;
+mod focus_refresh;
+mod followups;
 mod handlers;
+mod history_search;
+mod keymap;
+mod link_hints;
+mod log_view;
+mod message_pages;
 mod messages;
+mod model_picker;
 mod navigation;
+mod needs_action;
+mod notifications;
+pub mod observe;
+mod outgoing_queue;
+mod paste_burst;
+mod permission_detail;
+mod permission_reconcile;
 mod permissions;
+mod picker_refresh;
+mod polling;
+mod presence;
+mod quit_confirm;
+mod read_only;
+mod reconnect;
+mod render_snapshots;
+mod reply;
+mod resize;
+pub mod reveal;
+mod retry;
+mod safe_mode;
+mod search;
+mod session;
+mod setup_wizard;
+mod sidebar;
+mod startup_load;
 mod state_methods;
+mod status_report;
 mod stream;
+mod stream_resume;
+mod tasks;
+mod theme;
+mod thread_archive;
+mod thread_delete;
+mod thread_links;
+mod thread_metrics;
+mod thread_mute;
+mod thread_pins;
+mod thread_previews;
+mod thread_rename;
+mod thread_share;
+mod thread_state;
+mod thread_summary;
+mod token_coalesce;
+mod tool_output;
 mod types;
+mod usage;
 mod utils;
 mod view;
+mod vim_navigation;
+mod webhooks;
 mod websocket;
+mod whats_new;
+mod working_directory;
+mod zoom;
 pub mod thread_mode_sync;
 
+pub use browse_combined::{BrowseListItem, BrowseListSection};
+pub use bypass_confirm::{dir_allows_bypass, BypassConfirm};
+pub use editor_handoff::EditorHandoff;
+pub use message_pages::MESSAGE_PAGE_SIZE;
+pub use outgoing_queue::QueuedPrompt;
+pub use resize::{PendingResize, ScrollAnchor, RESIZE_SETTLE};
+pub use thread_delete::DeletedThread;
+pub use event_log::{EventLog, EventRecord, EventSource};
+pub use followups::{FollowUp, TurnFollowUps};
+pub use link_hints::LinkHints;
+pub use log_view::LogView;
 pub use messages::AppMessage;
+pub use permission_detail::PermissionDetailView;
+pub use polling::StreamTransport;
+pub use quit_confirm::{BackgroundWork, QuitConfirm};
 pub use state_methods::{BrowseListSelectAction, UnifiedPickerAction};
-pub use types::{BrowseListMode, BrowseListState, Focus, Screen, ScrollBoundary, ThreadSwitcher};
-pub use websocket::{start_websocket, start_websocket_with_config};
+pub use types::{
+    BrowseListMode, BrowseListState, EventLogViewState, Focus, HistorySearchState, LinkEditorState,
+    MessageSearchState, ModelPickerState,
+    NotificationsPanelState, ShareDialogState, PeerPresence, PendingReply, Screen, ScrollBoundary, ThreadArchiveConfirm,
+    ThreadArchiveFilter, ThreadDeleteConfirm, ThreadRenameState, ThreadSwitcher, WhatsNewState,
+    ZoomLevel,
+};
+pub use render_snapshots::{RenderSnapshot, RestoredHeights};
+pub use reply::REPLY_EXCERPT_CHARS;
+pub use session::SavedSession;
+pub use setup_wizard::{CredentialChecks, SetupStep, SetupWizard, StepStatus};
+pub use status_report::{
+    load_update_state, StatusReport, StatusView, WebSocketStatus, WsDisconnect,
+};
+pub use thread_links::ThreadLinkChip;
+pub use thread_metrics::{MetricsSummary, ThreadMetrics, TurnOutcome};
+pub use thread_state::ThreadState;
+pub use usage::{TokenPrice, UsageRow, UsageSummary, UsageView, DEFAULT_PRICE_KEY};
+pub use vim_navigation::{MessageViewport, NavigateMode};
+pub use websocket::{start_websocket, start_websocket_with_config, WsHandle};
 
 use crate::auth::{
     central_api::get_jwt_expires_in, CentralApiClient, Credentials, CredentialsManager,
@@ -35,8 +137,9 @@ use crate::conductor::ConductorClient;
 use crate::credential_watcher::{CredentialWatchState, Debouncer};
 use crate::debug::DebugEventSender;
 use crate::input_history::InputHistory;
-use crate::markdown::MarkdownCache;
+use crate::markdown::{MarkdownCache, PreviewCache};
 use crate::models::{Folder, GitHubRepo, PermissionMode, QueuedSteeringMessage};
+use crate::startup::config::SpoqConfig;
 use crate::state::{
     AskUserQuestionState, DashboardState, FilePickerState, SessionState, SubagentTracker, Task,
     Thread, Todo, ToolTracker, UnifiedPickerState,
@@ -47,11 +150,15 @@ use crate::widgets::textarea_input::TextAreaInput;
 use chrono::Utc;
 use color_eyre::Result;
 use notify::RecommendedWatcher;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
 use cursor_blink::CursorBlinkState;
+use paste_burst::PasteBurst;
+use reveal::RevealPacer;
 use thread_mode_sync::ThreadModeSync;
+use token_coalesce::TokenCoalescer;
 
 /// Cached message height data for incremental updates.
 /// Stores precomputed heights with cumulative offsets to avoid recalculating
@@ -123,6 +230,12 @@ impl CachedHeights {
         self.total_lines += visual_lines;
     }
 
+    /// Insert entries at the front (older messages were prepended)
+    pub fn prepend(&mut self, entries: Vec<CachedMessageHeight>) {
+        self.heights.splice(0..0, entries);
+        self.recalculate_offsets_from(0);
+    }
+
     /// Truncate heights to given length (for when messages are removed)
     pub fn truncate(&mut self, new_len: usize) {
         if new_len < self.heights.len() {
@@ -162,9 +275,15 @@ pub enum SyncStatus {
     /// Sync in progress with status message
     InProgress { message: String },
     /// Sync completed successfully
-    Complete { github_cli: bool },
+    Complete {
+        github_cli: bool,
+        report: crate::credential_watcher::SyncReport,
+    },
     /// Sync failed with error
-    Failed { error: String },
+    Failed {
+        error: String,
+        report: crate::credential_watcher::SyncReport,
+    },
 }
 
 /// Maximum number of threads to display in the dashboard
@@ -184,6 +303,10 @@ pub struct App {
     /// If false, the terminal doesn't support focus reporting and we
     /// skip the focus check (always allow notifications).
     pub focus_supported: bool,
+    /// Whether the terminal was asked for attention since focus was lost
+    pub attention_raised: bool,
+    /// Attention request (bell + OSC 777 body) to write after the next frame
+    pub pending_attention: Option<String>,
     /// Current screen being displayed
     pub screen: Screen,
     /// ID of the active thread when in Conversation screen
@@ -192,6 +315,14 @@ pub struct App {
     pub focus: Focus,
     /// Selected index in threads panel
     pub threads_index: usize,
+    /// Mouse wheel scroll offsets for the CommandDeck thread list sections
+    pub thread_list_scroll: crate::view_state::ThreadListScroll,
+    /// Thread list section rects from the last CommandDeck render (mouse hit-testing)
+    pub thread_list_layout: crate::view_state::ThreadListLayout,
+    /// Whether the needs-action sidebar is on (Ctrl+B, Conversation screen)
+    pub sidebar_enabled: bool,
+    /// Sidebar entries from the last Conversation render (Alt+number, clicks)
+    pub sidebar_layout: crate::view_state::SidebarLayout,
     /// TextArea input (tui-textarea wrapper)
     pub textarea: TextAreaInput<'static>,
     /// Migration/indexing progress (0-100), None when complete
@@ -204,10 +335,15 @@ pub struct App {
     pub message_tx: mpsc::UnboundedSender<AppMessage>,
     /// Current connection status to the backend
     pub connection_status: bool,
+    /// Streams use the polling transport (streaming is blocked)
+    pub polling_mode: bool,
     /// Last stream error for display
     pub stream_error: Option<String>,
     /// Currently queued steering message awaiting promotion
     pub queued_steering: Option<QueuedSteeringMessage>,
+    /// Consecutive stream failures per thread (cleared when a stream completes;
+    /// `r` retries only threads listed here)
+    pub stream_failures: HashMap<String, u8>,
     /// Current sync operation status (for /sync dialog display)
     pub sync_status: SyncStatus,
     /// Thread mode synchronization coordinator (debounces and syncs mode changes)
@@ -226,6 +362,8 @@ pub struct App {
     pub input_section_start: usize,
     /// Total content lines from last render
     pub total_content_lines: usize,
+    /// Lines streamed in below the viewport while scrolled up ("↓ N new lines" badge)
+    pub new_content_lines: usize,
     /// Flag to batch scroll events (set on scroll, cleared in tick)
     pub scroll_changed: bool,
     /// Accumulates fractional scroll for smooth scrolling (moves line when >= 1.0)
@@ -241,6 +379,10 @@ pub struct App {
     pub subagent_tracker: SubagentTracker,
     /// Dashboard state for multi-thread view
     pub dashboard: DashboardState,
+    /// Dashboard colors (custom theme from `~/.spoq/theme.json`, if any)
+    pub theme: crate::view_state::Theme,
+    /// Webhooks that receive thread events (`~/.spoq/webhooks.json`)
+    pub webhooks: crate::webhooks::WebhookConfig,
     pub todos: Vec<Todo>,
     /// Debug event sender for emitting internal events to debug server
     pub debug_tx: Option<DebugEventSender>,
@@ -254,12 +396,18 @@ pub struct App {
     pub thread_switcher: ThreadSwitcher,
     /// Full-screen browse list state (for /threads and /repos commands)
     pub browse_list: BrowseListState,
+    /// In-conversation message search state (`/` when input is not focused)
+    pub message_search: MessageSearchState,
+    /// Input history search state (Ctrl+R when the input is focused)
+    pub history_search: HistorySearchState,
     /// Timestamp of last Tab press (for double-tap detection)
     pub last_tab_press: Option<std::time::Instant>,
     /// WebSocket sender for sending messages to the server
     pub ws_sender: Option<tokio::sync::mpsc::Sender<crate::websocket::WsOutgoingMessage>>,
     /// WebSocket connection state for UI status indicator
     pub ws_connection_state: WsConnectionState,
+    /// Outgoing WebSocket messages the server hasn't acknowledged yet
+    pub ws_unacked_messages: usize,
     /// State for AskUserQuestion prompt modal
     pub question_state: AskUserQuestionState,
     /// Scroll boundary hit state (for visual feedback)
@@ -279,6 +427,8 @@ pub struct App {
     /// Click detector for multi-click detection (single/double/triple click)
     /// Cache for parsed markdown (avoids re-parsing unchanged content)
     pub markdown_cache: MarkdownCache,
+    /// Plain-text thread previews for the CommandDeck, switcher and BrowseList
+    pub thread_previews: PreviewCache,
     /// Incremental height cache for virtualization (avoids recalculating all heights every frame)
     pub height_cache: Option<CachedHeights>,
     /// Dirty flag: when true, the UI needs to be redrawn.
@@ -295,10 +445,22 @@ pub struct App {
     pub folders_loading: bool,
     /// Error message if folder fetch failed
     pub folders_error: Option<String>,
-    /// Currently selected folder (displayed as chip in input)
-    pub selected_folder: Option<Folder>,
+    /// Cached model list from API for the model picker
+    pub models: Vec<crate::models::ModelInfo>,
+    /// True while fetching models from API
+    pub models_loading: bool,
+    /// Error message if model fetch failed
+    pub models_error: Option<String>,
+    /// Model picker state (`/model`)
+    pub model_picker: Option<ModelPickerState>,
+    /// Model for the next thread started from the CommandDeck
+    pub next_thread_model: Option<String>,
+    /// Folders attached to the next new thread (displayed as chips in input)
+    pub selected_folders: Vec<Folder>,
     /// Pending image attachments from clipboard paste or drag-drop (cleared on submit)
     pub pending_images: Vec<crate::clipboard::ImageAttachment>,
+    /// Earlier message the next submission replies to (cleared on submit)
+    pub pending_reply: Option<PendingReply>,
     /// Cached GitHub repos from API for empty state
     pub repos: Vec<GitHubRepo>,
     /// True while fetching repos from API
@@ -339,10 +501,14 @@ pub struct App {
     pub last_ctrl_c_time: Option<std::time::Instant>,
     /// Cursor blink state (manages visibility with blinkwait behavior)
     pub cursor_blink: CursorBlinkState,
+    /// Simulated typing: paces how fast streamed text is revealed
+    pub reveal: RevealPacer,
     /// Help dialog visible (shown via /help command)
     pub help_dialog_visible: bool,
     /// Flag to prevent double-cancel requests (set when cancel is in progress)
     pub cancel_in_progress: bool,
+    /// Running stream tasks by thread ID, aborted on Ctrl+C
+    pub stream_tasks: HashMap<String, tokio::task::AbortHandle>,
 
     // =========================================================================
     // Credential Auto-Sync
@@ -357,8 +523,190 @@ pub struct App {
     pub claude_login_auto_close: Option<std::time::Instant>,
     /// Auto-dismiss timer for timed errors (e.g., from /discard on wrong screen)
     pub timed_error_dismiss: Option<std::time::Instant>,
+    /// Transient confirmation shown in the status line (e.g., "Copied 120 chars")
+    pub status_notice: Option<String>,
+    /// Auto-dismiss timer for the status notice
+    pub status_notice_dismiss: Option<std::time::Instant>,
+    /// System clipboard that copies are written to
+    pub clipboard: Box<dyn crate::traits::ClipboardProvider>,
+    /// Text to copy via OSC 52 on the next frame (system clipboard unavailable)
+    pub pending_osc52_copy: Option<String>,
+    /// Terminal features detected by the setup probe; unsupported ones are downgraded
+    pub terminal_capabilities: crate::terminal::TerminalCapabilities,
     /// Rate limit modal state (shown when account hits rate limit)
     pub rate_limit_modal: Option<RateLimitModalState>,
+    /// Thread deletion confirmation dialog state
+    pub thread_delete_confirm: Option<ThreadDeleteConfirm>,
+    /// Threads removed locally whose backend delete is still in flight
+    pub deleted_threads: HashMap<String, DeletedThread>,
+    /// Thread link editor state (`/link`)
+    pub link_editor: Option<LinkEditorState>,
+    /// Inline thread title editor state (`/rename`)
+    pub thread_rename: Option<ThreadRenameState>,
+    /// State files that failed to parse at startup; non-empty means read-only mode
+    pub corrupted_files: Vec<crate::startup::CorruptedFile>,
+    /// Started with `--safe-mode`: no customizations, preloads or file watchers
+    pub safe_mode: bool,
+    /// Prompts submitted while a thread was streaming, per thread (oldest first)
+    pub outgoing_queue: HashMap<String, VecDeque<QueuedPrompt>>,
+    /// Thread whose stream Ctrl+C would cancel while prompts are queued
+    /// (asks whether to drop the queue too)
+    pub queue_cancel_confirm: Option<String>,
+    /// Embedder subscriptions to high-level events (see `app::observe`)
+    pub observers: observe::Observers,
+    /// Whether a pending-permissions re-query is in flight
+    pub permission_recheck_in_flight: bool,
+    /// When pending permissions were last re-queried from the backend
+    pub last_permission_recheck: Option<std::time::Instant>,
+    /// Key chords for rebindable actions (`~/.config/spoq/keymap.toml`)
+    pub keymap: crate::input::Keymap,
+    /// Lines of the keymap file that were skipped at startup
+    pub keymap_warnings: Vec<crate::input::KeymapWarning>,
+    /// Whether the `/keys` dialog is visible
+    pub keys_dialog_visible: bool,
+    /// Notifications panel state (`/notifications`)
+    pub notifications_panel: Option<NotificationsPanelState>,
+    /// Message to scroll to once the active thread renders (opened from a notification)
+    pub pending_message_jump: Option<i64>,
+    /// Where the session is saved for resume on restart (None disables saving)
+    pub session_path: Option<std::path::PathBuf>,
+    /// Restored scroll position, applied once the thread's heights are built
+    pub pending_scroll_restore: Option<u16>,
+    /// Recent SSE/WS events per thread (`/events`)
+    pub event_log: EventLog,
+    /// Event log view state (`/events`)
+    pub event_log_view: Option<EventLogViewState>,
+    /// ID identifying this TUI instance in presence events
+    pub client_id: String,
+    /// Other clients present on each thread, keyed by thread ID then client ID
+    pub thread_presence: HashMap<String, HashMap<String, PeerPresence>>,
+    /// When we last told other clients we're typing (throttles typing events)
+    pub last_typing_sent: Option<std::time::Instant>,
+    /// When the crossed-turn warning was shown (a second submit sends anyway)
+    pub crossed_turn_warned_at: Option<std::time::Instant>,
+    /// Zoom level per thread (threads not listed show everything)
+    pub thread_zoom: HashMap<String, ZoomLevel>,
+    /// "What's new" overlay shown once after an update installed
+    pub whats_new: Option<WhatsNewState>,
+    /// What each thread opens in `$EDITOR` when a response finishes (`/editor`)
+    pub editor_handoff: HashMap<String, EditorHandoff>,
+    /// Scratch file to open in `$EDITOR` after the next frame
+    pub pending_editor_open: Option<std::path::PathBuf>,
+    /// Desktop notification preferences (`/notify`)
+    pub notification_settings: crate::notifications::NotificationSettings,
+    /// Resize waiting for the terminal size to settle
+    pub pending_resize: Option<PendingResize>,
+    /// Message at the top of the viewport in the last frame, while scrolled up
+    pub scroll_anchor: Option<ScrollAnchor>,
+    /// Anchor to scroll back to in the first frame after a resize
+    pub pending_scroll_anchor: Option<ScrollAnchor>,
+    /// Older-page requests per thread: None while in flight, or when the last
+    /// one failed
+    pub older_page_requests: HashMap<String, Option<std::time::Instant>>,
+    /// WebSocket reconnect attempts since startup
+    pub ws_reconnect_attempts: u32,
+    /// Why the WebSocket connection last dropped
+    pub ws_last_disconnect: Option<WsDisconnect>,
+    /// Connection diagnostics overlay (`/status`)
+    pub status_view: Option<StatusView>,
+    /// TODO/FIXME markers added by each thread's latest turn
+    pub turn_followups: HashMap<String, TurnFollowUps>,
+    /// Follow-ups kept as todos, shown after the agent's own todos
+    pub tracked_todos: Vec<Todo>,
+    /// Where render cache snapshots are kept (None disables them)
+    pub render_snapshot_dir: Option<std::path::PathBuf>,
+    /// Snapshot of the thread being opened, applied on the next render
+    pub pending_render_snapshot: Option<RenderSnapshot>,
+    /// Measured heights from the active thread's snapshot
+    pub restored_heights: RestoredHeights,
+    /// Relative times and day header rows on messages (`T`)
+    pub show_timestamps: bool,
+    /// Outcome metrics per thread ID
+    pub thread_metrics: HashMap<String, ThreadMetrics>,
+    /// Where the metrics are saved (None keeps them in memory)
+    pub thread_metrics_path: Option<std::path::PathBuf>,
+    /// Thread shown in the metrics pane (`/metrics`)
+    pub thread_metrics_view: Option<String>,
+    /// Local per-thread state (pins and mutes)
+    pub thread_state: ThreadState,
+    /// Where the thread state is saved (None keeps it in memory)
+    pub thread_state_path: Option<std::path::PathBuf>,
+    /// Quit confirmation listing the work in flight
+    pub quit_confirm: Option<QuitConfirm>,
+    /// Quit once the streaming responses finish
+    pub quit_when_idle: bool,
+    /// When the terminal last lost focus (None while focused)
+    pub focus_lost_at: Option<std::time::Instant>,
+    /// Messages per thread ID that arrived while it wasn't visible
+    pub unread_counts: HashMap<String, usize>,
+    /// Thread whose context is being compacted (`/compact`)
+    pub compacting: Option<String>,
+    /// Control channel of the WebSocket client ("reconnect now")
+    pub ws_control: Option<tokio::sync::mpsc::Sender<crate::websocket::WsControl>>,
+    /// When the WebSocket connection went down (None while connected)
+    pub ws_disconnected_at: Option<std::time::Instant>,
+    /// Seconds shown by the connection banner, to redraw it as they change
+    pub ws_banner_secs: u64,
+    /// Answers waiting for the WebSocket connection to come back
+    pub ws_queued_messages: Vec<crate::websocket::WsOutgoingMessage>,
+    /// Selected position in `tasks`
+    pub tasks_index: usize,
+    /// Rows of text the input grows to before it scrolls
+    pub input_max_rows: u16,
+    /// Log file viewer overlay (`/log`)
+    pub log_view: Option<LogView>,
+    /// Full tool input of a pending permission (`e` on the prompt)
+    pub permission_detail: Option<PermissionDetailView>,
+    /// Token usage summary overlay (`/usage`)
+    pub usage_view: Option<UsageView>,
+    /// Prices for estimating token costs, by model (`token_prices` in config)
+    pub token_prices: HashMap<String, TokenPrice>,
+    /// Esc in an empty input enters navigate mode (`vim_navigation` in config)
+    pub vim_navigation: bool,
+    /// Vim-style navigate mode, while active
+    pub navigate_mode: Option<NavigateMode>,
+    /// Message lines in the conversation viewport in the last frame
+    pub message_viewport: MessageViewport,
+    /// Pending thread archive confirmation (`a` on the Threads panel)
+    pub thread_archive_confirm: Option<ThreadArchiveConfirm>,
+    /// First-run setup wizard, while on `Screen::Setup`
+    pub setup_wizard: Option<SetupWizard>,
+    /// State served by the debug server (None when it isn't running)
+    pub debug_state_snapshot: Option<Arc<tokio::sync::RwLock<crate::debug::StateSnapshot>>>,
+    /// How long a dismissed inline error can be restored with `u`
+    pub dismissed_error_ttl: std::time::Duration,
+    /// Numbered link labels (Ctrl+O), while shown
+    pub link_hints: Option<LinkHints>,
+    /// Links in code are labelled too (`link_hints_include_code` in config)
+    pub link_hints_include_code: bool,
+    /// Streamed tokens waiting to be appended together (one append per frame)
+    pub token_coalescer: TokenCoalescer,
+    /// Thread share dialog (`/share`), while open
+    pub share_dialog: Option<ShareDialogState>,
+    /// Links of threads shared this session, by thread ID
+    pub thread_shares: HashMap<String, crate::models::ThreadShare>,
+    /// Key presses that may be a paste (terminals without bracketed paste)
+    pub paste_burst: PasteBurst,
+    /// True while fetching threads at startup
+    pub threads_loading: bool,
+    /// Session to resume once the startup thread fetch finishes
+    pub pending_session: Option<SavedSession>,
+    /// Crash snapshot to restore once the startup thread fetch finishes
+    pub pending_crash_snapshot: Option<crate::terminal::CrashSnapshot>,
+    /// Hash of the inputs of the crash snapshot last handed to the panic hook
+    pub crash_state_key: Option<u64>,
+    /// Open Execute mode confirmation (Shift+Tab into bypass permissions)
+    pub bypass_confirm: Option<BypassConfirm>,
+    /// Directories Execute mode is limited to (`bypass_allowed_dirs` in
+    /// config; empty allows any)
+    pub bypass_allowed_dirs: Vec<String>,
+    /// Per-thread directory lists that replace `bypass_allowed_dirs`
+    /// (`bypass_thread_allowed_dirs` in config)
+    pub bypass_thread_allowed_dirs: HashMap<String, Vec<String>>,
+    /// User config (`~/.spoq/config.json`), read once when the App is created
+    pub config: SpoqConfig,
+    /// Where `config` is saved (`None` keeps changes in memory)
+    pub config_path: Option<std::path::PathBuf>,
 }
 
 /// State for rate limit confirmation modal
@@ -404,6 +752,7 @@ impl App {
             debug_tx,
             credentials,
             vps_url,
+            SpoqConfig::load(),
         )
     }
 
@@ -414,6 +763,21 @@ impl App {
     pub fn with_debug_and_vps(
         debug_tx: Option<DebugEventSender>,
         vps_url: Option<String>,
+    ) -> Result<Self> {
+        Self::with_debug_vps_and_config(debug_tx, vps_url, SpoqConfig::load())
+    }
+
+    /// Create a new App instance with the given user config instead of
+    /// `~/.spoq/config.json` (tests pass `SpoqConfig::default()`)
+    pub fn with_config(config: SpoqConfig) -> Result<Self> {
+        Self::with_debug_vps_and_config(None, None, config)
+    }
+
+    /// Create a new App instance from loaded credentials and the given config
+    fn with_debug_vps_and_config(
+        debug_tx: Option<DebugEventSender>,
+        vps_url: Option<String>,
+        config: SpoqConfig,
     ) -> Result<Self> {
         // Load credentials to get the auth tokens
         let credentials_manager = CredentialsManager::new();
@@ -434,6 +798,7 @@ impl App {
             debug_tx,
             credentials,
             vps_url,
+            config,
         )
     }
 
@@ -453,15 +818,22 @@ impl App {
             .as_ref()
             .map(|cm| cm.load())
             .unwrap_or_default();
-        Self::with_client_and_debug_and_credentials(client, debug_tx, credentials, None)
+        Self::with_client_and_debug_and_credentials(
+            client,
+            debug_tx,
+            credentials,
+            None,
+            SpoqConfig::load(),
+        )
     }
 
-    /// Create a new App instance with pre-loaded credentials and VPS URL
+    /// Create a new App instance with pre-loaded credentials, VPS URL and user config
     fn with_client_and_debug_and_credentials(
         client: Arc<ConductorClient>,
         debug_tx: Option<DebugEventSender>,
         credentials: Credentials,
         vps_url: Option<String>,
+        config: SpoqConfig,
     ) -> Result<Self> {
         // Initialize empty cache - will be populated by initialize()
         let cache = ThreadCache::new();
@@ -491,18 +863,26 @@ impl App {
             should_quit: false,
             is_focused: true,
             focus_supported: false,
+            attention_raised: false,
+            pending_attention: None,
             screen: Screen::CommandDeck,
             active_thread_id: None,
             focus: Focus::default(),
             threads_index: 0,
+            thread_list_scroll: Default::default(),
+            thread_list_layout: Default::default(),
+            sidebar_enabled: false,
+            sidebar_layout: Default::default(),
             textarea: TextAreaInput::new(),
             migration_progress: Some(0),
             cache,
             message_rx: Some(message_rx),
             message_tx,
             connection_status: false,
+            polling_mode: false,
             stream_error: None,
             queued_steering: None,
+            stream_failures: HashMap::new(),
             sync_status: SyncStatus::default(),
             client: client.clone(),
             thread_mode_sync: ThreadModeSync::new(client),
@@ -512,6 +892,7 @@ impl App {
             user_has_scrolled: false,
             input_section_start: 0,
             total_content_lines: 0,
+            new_content_lines: 0,
             scroll_changed: false,
             scroll_accumulator: 0.0,
             permission_mode: PermissionMode::default(),
@@ -519,6 +900,8 @@ impl App {
             tool_tracker: ToolTracker::new(),
             subagent_tracker: SubagentTracker::new(),
             dashboard: DashboardState::new(),
+            theme: crate::view_state::Theme::default(),
+            webhooks: crate::webhooks::WebhookConfig::default(),
             todos: Vec::new(),
             debug_tx,
             stream_start_time: None,
@@ -526,9 +909,12 @@ impl App {
             cumulative_token_count: 0,
             thread_switcher: ThreadSwitcher::default(),
             browse_list: BrowseListState::default(),
+            message_search: MessageSearchState::default(),
+            history_search: HistorySearchState::default(),
             last_tab_press: None,
             ws_sender: None,
             ws_connection_state: WsConnectionState::Disconnected,
+            ws_unacked_messages: 0,
             question_state: AskUserQuestionState::default(),
             scroll_boundary_hit: None,
             boundary_hit_tick: 0,
@@ -538,6 +924,7 @@ impl App {
             terminal_height: 24, // Default, will be updated on first render
             rendered_lines_cache: crate::rendered_lines_cache::RenderedLinesCache::new(),
             markdown_cache: MarkdownCache::new(),
+            thread_previews: PreviewCache::new(),
             height_cache: None,
             needs_redraw: true, // Start with redraw needed
             has_visible_links: false,
@@ -545,8 +932,14 @@ impl App {
             folders: Vec::new(),
             folders_loading: false,
             folders_error: None,
-            selected_folder: None,
+            models: Vec::new(),
+            models_loading: false,
+            models_error: None,
+            model_picker: None,
+            next_thread_model: None,
+            selected_folders: Vec::new(),
             pending_images: Vec::new(),
+            pending_reply: None,
             repos: Vec::new(),
             repos_loading: false,
             repos_error: None,
@@ -566,19 +959,124 @@ impl App {
             local_conductor: None,
             system_stats: SystemStats::default(),
             last_ctrl_c_time: None,
-            cursor_blink: CursorBlinkState::default(),
+            cursor_blink: if config.cursor_blink {
+                CursorBlinkState::blinking()
+            } else {
+                CursorBlinkState::default()
+            },
+            reveal: RevealPacer::from_env(),
             help_dialog_visible: false,
             cancel_in_progress: false,
+            stream_tasks: HashMap::new(),
             // Credential auto-sync
             credential_watch_state: CredentialWatchState::new(),
             credential_debouncer: Debouncer::new(),
             credential_file_watcher: None,
             claude_login_auto_close: None,
             timed_error_dismiss: None,
+            status_notice: None,
+            status_notice_dismiss: None,
+            clipboard: Box::new(crate::adapters::SystemClipboard),
+            pending_osc52_copy: None,
+            terminal_capabilities: crate::terminal::TerminalCapabilities::default(),
             rate_limit_modal: None,
+            thread_delete_confirm: None,
+            deleted_threads: HashMap::new(),
+            link_editor: None,
+            thread_rename: None,
+            corrupted_files: Vec::new(),
+            safe_mode: false,
+            outgoing_queue: HashMap::new(),
+            queue_cancel_confirm: None,
+            observers: observe::Observers::default(),
+            permission_recheck_in_flight: false,
+            last_permission_recheck: None,
+            keymap: crate::input::Keymap::default(),
+            keymap_warnings: Vec::new(),
+            keys_dialog_visible: false,
+            notifications_panel: None,
+            pending_message_jump: None,
+            session_path: None,
+            pending_scroll_restore: None,
+            event_log: EventLog::default(),
+            event_log_view: None,
+            client_id: uuid::Uuid::new_v4().to_string(),
+            thread_presence: HashMap::new(),
+            last_typing_sent: None,
+            crossed_turn_warned_at: None,
+            thread_zoom: HashMap::new(),
+            whats_new: None,
+            editor_handoff: config.editor_handoff.clone(),
+            pending_editor_open: None,
+            notification_settings: config.notifications.clone(),
+            pending_resize: None,
+            scroll_anchor: None,
+            pending_scroll_anchor: None,
+            older_page_requests: HashMap::new(),
+            ws_reconnect_attempts: 0,
+            ws_last_disconnect: None,
+            status_view: None,
+            turn_followups: HashMap::new(),
+            tracked_todos: Vec::new(),
+            render_snapshot_dir: None,
+            pending_render_snapshot: None,
+            restored_heights: RestoredHeights::default(),
+            show_timestamps: false,
+            thread_metrics: HashMap::new(),
+            thread_metrics_path: None,
+            thread_metrics_view: None,
+            thread_state: ThreadState::default(),
+            thread_state_path: None,
+            quit_confirm: None,
+            quit_when_idle: false,
+            focus_lost_at: None,
+            unread_counts: HashMap::new(),
+            compacting: None,
+            ws_control: None,
+            ws_disconnected_at: None,
+            ws_banner_secs: 0,
+            ws_queued_messages: Vec::new(),
+            tasks_index: 0,
+            input_max_rows: config.input_max_rows,
+            log_view: None,
+            permission_detail: None,
+            usage_view: None,
+            token_prices: config.token_prices.clone(),
+            vim_navigation: config.vim_navigation,
+            navigate_mode: None,
+            message_viewport: MessageViewport::default(),
+            thread_archive_confirm: None,
+            setup_wizard: None,
+            debug_state_snapshot: None,
+            dismissed_error_ttl: std::time::Duration::from_secs(config.dismissed_error_ttl_secs),
+            link_hints: None,
+            link_hints_include_code: config.link_hints_include_code,
+            token_coalescer: TokenCoalescer::default(),
+            share_dialog: None,
+            thread_shares: HashMap::new(),
+            paste_burst: PasteBurst::default(),
+            threads_loading: false,
+            pending_session: None,
+            pending_crash_snapshot: None,
+            crash_state_key: None,
+            bypass_confirm: None,
+            bypass_allowed_dirs: config.bypass_allowed_dirs.clone(),
+            bypass_thread_allowed_dirs: config.bypass_thread_allowed_dirs.clone(),
+            config,
+            config_path: None,
         })
     }
 
+    /// Save `config` to `config_path` (nothing to do without one)
+    pub(crate) fn save_config(&self) {
+        let Some(path) = self.config_path.as_deref() else {
+            return;
+        };
+        if let Err(e) = self.config.save_to(path) {
+            tracing::warn!("Failed to save config: {}", e);
+        }
+    }
+
     /// Set the credential file watcher handle.
     ///
     /// The watcher must be kept alive - dropping it stops watching.
@@ -760,53 +1258,20 @@ impl App {
     /// Fetches threads and tasks from the server. If the server is unreachable
     /// or returns an error, the app starts with empty state and sets connection
     /// status to false.
+    ///
+    /// Startup uses `load_threads` instead, which doesn't block the first
+    /// frame (see `startup_load`).
     pub async fn initialize(&mut self) {
         // Fetch threads from server
         match self.client.fetch_threads().await {
             Ok(threads) => {
-                // Limit to most recent threads for dashboard display
-                let total_threads = threads.len();
-                let threads = threads.into_iter().take(MAX_DASHBOARD_THREADS).collect::<Vec<_>>();
-
-                // Populate cache with threads from server
-                // Iterate in reverse because upsert_thread() inserts at front,
-                // so we process oldest first to end up with newest at front
-                log_thread_update(&format!(
-                    "fetch_threads SUCCESS: {} threads (limited to {} for dashboard)",
-                    total_threads, threads.len()
-                ));
-
-                // Also populate dashboard state for UI rendering
-                self.dashboard
-                    .set_threads(threads.clone(), &std::collections::HashMap::new());
-                // Compute thread views so they're ready for rendering
-                self.dashboard.compute_thread_views();
-
-                for thread in threads.into_iter().rev() {
-                    self.cache.upsert_thread(thread);
-                }
-                self.connection_status = true;
-                self.system_stats.connected = true;
+                // Failed to fetch tasks - continue with empty tasks
+                let tasks = self.client.fetch_tasks().await.unwrap_or_default();
+                self.apply_loaded_threads(threads, tasks);
             }
             Err(e) => {
                 // Server unreachable - start with empty state
-                // Log the error for debugging
-                log_thread_update(&format!("fetch_threads FAILED: {:?}", e));
-                self.connection_status = false;
-                self.system_stats.connected = false;
-            }
-        }
-
-        // Fetch tasks from server (only if connected)
-        if self.connection_status {
-            match self.client.fetch_tasks().await {
-                Ok(tasks) => {
-                    self.tasks = tasks;
-                }
-                Err(_) => {
-                    // Failed to fetch tasks - continue with empty tasks
-                    self.tasks = Vec::new();
-                }
+                self.apply_threads_load_failed(&format!("{:?}", e));
             }
         }
     }
@@ -855,6 +1320,7 @@ impl App {
             Screen::CommandDeck => "CommandDeck",
             Screen::Conversation => "Conversation",
             Screen::BrowseList => "BrowseList",
+            Screen::Setup => "Setup",
         };
         emit_debug(
             &self.debug_tx,
@@ -869,8 +1335,10 @@ impl App {
 }
 
 impl Default for App {
+    /// An App with the default user config, so it doesn't depend on the
+    /// developer's `~/.spoq/config.json`
     fn default() -> Self {
-        Self::new().expect("Failed to create default App")
+        Self::with_config(SpoqConfig::default()).expect("Failed to create default App")
     }
 }
 
@@ -939,6 +1407,50 @@ mod tests {
         assert!(app.active_thread_id.is_none());
     }
 
+    #[test]
+    fn test_with_config_reads_settings_from_the_given_config() {
+        let config = SpoqConfig {
+            resume_session: false,
+            bypass_allowed_dirs: vec!["/srv/app".to_string()],
+            link_hints_include_code: true,
+            dismissed_error_ttl_secs: 60,
+            vim_navigation: true,
+            token_prices: HashMap::from([(
+                DEFAULT_PRICE_KEY.to_string(),
+                TokenPrice {
+                    prompt_per_million: 3.0,
+                    completion_per_million: 15.0,
+                },
+            )]),
+            input_max_rows: 3,
+            notifications: crate::notifications::NotificationSettings {
+                enabled: false,
+                ..Default::default()
+            },
+            editor_handoff: HashMap::from([("t-1".to_string(), EditorHandoff::Answer)]),
+            cursor_blink: true,
+            ..SpoqConfig::default()
+        };
+
+        let app = App::with_config(config).unwrap();
+
+        assert!(!app.config.resume_session);
+        assert_eq!(app.bypass_allowed_dirs, vec!["/srv/app".to_string()]);
+        assert!(app.link_hints_include_code);
+        assert_eq!(app.dismissed_error_ttl, std::time::Duration::from_secs(60));
+        assert!(app.vim_navigation);
+        assert_eq!(app.token_prices["default"].prompt_per_million, 3.0);
+        assert_eq!(app.input_max_rows, 3);
+        assert!(!app.notification_settings.enabled);
+        assert_eq!(app.editor_handoff.get("t-1"), Some(&EditorHandoff::Answer));
+        let mut blink = app.cursor_blink.clone();
+        blink.reset(0);
+        assert!((0..100).any(|tick| {
+            blink.update(tick);
+            !blink.is_visible()
+        }));
+    }
+
     #[test]
     fn test_submit_input_with_empty_input_does_nothing() {
         use crate::models::ThreadType;
@@ -1113,6 +1625,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         });
         app.cache.add_message_simple(
             &existing_id,
@@ -1401,6 +1918,90 @@ mod tests {
         assert!(!app.connection_status);
     }
 
+    fn app_with_streaming_thread() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.cache
+            .add_streaming_message("thread-001", "hello".to_string(), Vec::new());
+        app.handle_message(AppMessage::StreamToken {
+            thread_id: "thread-001".to_string(),
+            token: "partial".to_string(),
+        });
+        app
+    }
+
+    #[test]
+    fn test_handle_message_stream_progress_records_seq() {
+        let mut app = app_with_streaming_thread();
+        app.handle_message(AppMessage::StreamProgress {
+            thread_id: "thread-001".to_string(),
+            seq: 42,
+        });
+        assert_eq!(app.cache.last_stream_seq("thread-001"), Some(42));
+    }
+
+    #[test]
+    fn test_handle_message_stream_interrupted_ignored_without_streaming_message() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+
+        app.handle_message(AppMessage::StreamInterrupted {
+            thread_id: "thread-001".to_string(),
+            error: "connection reset".to_string(),
+        });
+
+        assert!(!app.cache.is_stream_interrupted("thread-001"));
+    }
+
+    #[test]
+    fn test_handle_message_stream_resume_failed_retryable_keeps_streaming() {
+        let mut app = app_with_streaming_thread();
+        app.connection_status = true;
+
+        app.handle_message(AppMessage::StreamResumeFailed {
+            thread_id: "thread-001".to_string(),
+            error: "HTTP error".to_string(),
+            retryable: true,
+        });
+
+        assert!(app.cache.is_thread_streaming("thread-001"));
+        assert!(app.cache.is_stream_interrupted("thread-001"));
+        assert!(!app.connection_status);
+    }
+
+    #[test]
+    fn test_handle_message_stream_resume_failed_finalizes_partial_message() {
+        let mut app = app_with_streaming_thread();
+        app.active_thread_id = Some("thread-001".to_string());
+
+        app.handle_message(AppMessage::StreamResumeFailed {
+            thread_id: "thread-001".to_string(),
+            error: "Server error (410): gone".to_string(),
+            retryable: false,
+        });
+
+        assert!(!app.cache.is_thread_streaming("thread-001"));
+        let last = app.cache.get_messages("thread-001").unwrap().last().unwrap();
+        assert_eq!(last.content, "partial");
+        assert_eq!(app.cache.error_count("thread-001"), 1);
+    }
+
+    #[test]
+    fn test_handle_message_stream_complete_clears_resume_state() {
+        let mut app = app_with_streaming_thread();
+        app.handle_message(AppMessage::StreamProgress {
+            thread_id: "thread-001".to_string(),
+            seq: 3,
+        });
+
+        app.handle_message(AppMessage::StreamComplete {
+            thread_id: "thread-001".to_string(),
+            message_id: 99,
+        });
+
+        assert_eq!(app.cache.last_stream_seq("thread-001"), None);
+    }
+
     #[test]
     fn test_message_sender_returns_clone() {
         let app = App::default();
@@ -1597,9 +2198,9 @@ mod tests {
             .create_streaming_thread("Active thread".to_string());
         app.active_thread_id = Some(thread_id.clone());
 
-        // Set unified scroll to a non-zero value
+        // Set unified scroll to a non-zero value without the user scrolling
         app.unified_scroll = 10;
-        app.user_has_scrolled = true;
+        app.user_has_scrolled = false;
 
         // Receive token for the active thread
         app.handle_message(AppMessage::StreamToken {
@@ -1611,6 +2212,45 @@ mod tests {
         assert_eq!(app.unified_scroll, 0);
     }
 
+    #[test]
+    fn test_stream_token_keeps_scroll_when_user_scrolled_up() {
+        let mut app = App::default();
+        let thread_id = app
+            .cache
+            .create_streaming_thread("Active thread".to_string());
+        app.active_thread_id = Some(thread_id.clone());
+        app.unified_scroll = 10;
+        app.user_has_scrolled = true;
+
+        app.handle_message(AppMessage::StreamToken {
+            thread_id: thread_id.clone(),
+            token: "Hello".to_string(),
+        });
+        app.handle_message(AppMessage::ReasoningToken {
+            thread_id,
+            token: "Thinking".to_string(),
+        });
+
+        assert_eq!(app.unified_scroll, 10);
+        assert!(app.user_has_scrolled);
+    }
+
+    #[test]
+    fn test_scroll_to_top_and_bottom() {
+        let mut app = App::default();
+        app.max_scroll = 40;
+        app.new_content_lines = 7;
+
+        app.scroll_to_top();
+        assert_eq!(app.unified_scroll, 40);
+        assert!(app.user_has_scrolled);
+
+        app.scroll_to_bottom();
+        assert_eq!(app.unified_scroll, 0);
+        assert!(!app.user_has_scrolled);
+        assert_eq!(app.new_content_lines, 0);
+    }
+
     // ============= PermissionMode Tests =============
 
     #[test]
@@ -1661,7 +2301,10 @@ mod tests {
         };
 
         app.cycle_permission_mode();
+        assert_eq!(app.permission_mode, PermissionMode::Plan);
+        assert!(app.bypass_confirm.is_some());
 
+        app.confirm_bypass();
         assert_eq!(app.permission_mode, PermissionMode::Execution);
     }
 
@@ -1688,8 +2331,9 @@ mod tests {
         app.cycle_permission_mode();
         assert_eq!(app.permission_mode, PermissionMode::Plan);
 
-        // Cycle: Plan → Execution
+        // Cycle: Plan → Execution (after the confirmation)
         app.cycle_permission_mode();
+        app.confirm_bypass();
         assert_eq!(app.permission_mode, PermissionMode::Execution);
 
         // Cycle: Execution → Default
@@ -1711,6 +2355,7 @@ mod tests {
             assert_eq!(app.permission_mode, PermissionMode::Plan);
 
             app.cycle_permission_mode(); // Plan → Execution
+            app.confirm_bypass();
             assert_eq!(app.permission_mode, PermissionMode::Execution);
 
             app.cycle_permission_mode(); // Execution → Default
@@ -1748,6 +2393,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.active_thread_id = Some("thread-conv".to_string());
@@ -1776,6 +2426,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.active_thread_id = Some("thread-prog".to_string());
@@ -1814,6 +2469,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.active_thread_id = Some("thread-1".to_string());
@@ -1837,6 +2497,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
 
@@ -1867,6 +2532,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.cache
@@ -1915,6 +2585,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.cache
@@ -2025,6 +2700,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.active_thread_id = Some("prog-thread".to_string());
@@ -2056,6 +2736,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.active_thread_id = Some("conv-thread".to_string());
@@ -2098,6 +2783,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.active_thread_id = Some("prog-thread".to_string());
@@ -2136,6 +2826,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.active_thread_id = Some("conv-thread".to_string());
@@ -2172,6 +2867,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread);
         app.active_thread_id = Some("prog-thread".to_string());
@@ -2210,6 +2910,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread1);
         // Pre-populate messages to avoid lazy fetch triggering tokio::spawn
@@ -2236,6 +2941,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread2);
         // Pre-populate messages to avoid lazy fetch triggering tokio::spawn
@@ -2667,6 +3377,41 @@ mod tests {
         assert!(!app.has_errors());
     }
 
+    #[test]
+    fn test_undo_dismiss_error_restores_and_shows_hint() {
+        let mut app = App::default();
+        let thread_id = app.cache.create_streaming_thread("Hello".to_string());
+        app.active_thread_id = Some(thread_id.clone());
+        app.cache
+            .add_error_simple(&thread_id, "error1".to_string(), "First".to_string());
+
+        assert!(app.dismiss_focused_error());
+        assert_eq!(
+            app.status_notice.as_deref(),
+            Some("Error dismissed — press u to undo")
+        );
+
+        assert!(app.undo_dismiss_error());
+        assert_eq!(app.cache.error_count(&thread_id), 1);
+        assert!(app.status_notice.is_none());
+        assert!(!app.undo_dismiss_error());
+    }
+
+    #[test]
+    fn test_leaving_thread_forgets_dismissed_errors() {
+        let mut app = App::default();
+        let thread_id = app.cache.create_streaming_thread("Hello".to_string());
+        app.active_thread_id = Some(thread_id.clone());
+        app.cache
+            .add_error_simple(&thread_id, "error1".to_string(), "First".to_string());
+        app.dismiss_focused_error();
+
+        app.navigate_to_command_deck();
+        app.active_thread_id = Some(thread_id);
+
+        assert!(!app.undo_dismiss_error());
+    }
+
     #[test]
     fn test_error_persists_across_navigate_to_command_deck() {
         let mut app = App::default();
@@ -2841,6 +3586,56 @@ mod tests {
         assert_eq!(thread.description, Some("Just a description".to_string()));
     }
 
+    #[test]
+    fn test_thread_metadata_updated_keeps_renamed_title() {
+        let mut app = App::default();
+
+        let thread_id = app
+            .cache
+            .create_streaming_thread("Original Title".to_string());
+        app.cache.rename_thread(&thread_id, "My Title".to_string());
+
+        // A generated title arriving after the rename must not clobber it
+        app.handle_message(AppMessage::ThreadMetadataUpdated {
+            thread_id: thread_id.clone(),
+            title: Some("Generated Title".to_string()),
+            description: Some("New Description".to_string()),
+        });
+
+        let thread = app.cache.get_thread(&thread_id).unwrap();
+        assert_eq!(thread.title, "My Title");
+        assert_eq!(thread.description, Some("New Description".to_string()));
+    }
+
+    #[test]
+    fn test_ws_thread_created_keeps_provisional_title() {
+        let mut app = App::default();
+
+        let prompt = "Look into the flaky login test. It fails on CI only.";
+        let thread_id = app.cache.create_streaming_thread(prompt.to_string());
+        let mut thread = app.cache.get_thread(&thread_id).unwrap().clone();
+        thread.title = prompt.to_string();
+
+        // The backend echoes the prompt as the title: keep the local one
+        app.handle_message(AppMessage::WsThreadCreated { thread });
+        assert_eq!(
+            app.dashboard.get_thread(&thread_id).unwrap().title,
+            "Look into the flaky login test"
+        );
+
+        // A generated title replaces it everywhere
+        app.handle_message(AppMessage::ThreadMetadataUpdated {
+            thread_id: thread_id.clone(),
+            title: Some("Flaky CI login test".to_string()),
+            description: None,
+        });
+        assert_eq!(
+            app.dashboard.get_thread(&thread_id).unwrap().title,
+            "Flaky CI login test"
+        );
+        assert!(!app.cache.is_title_provisional(&thread_id));
+    }
+
     #[test]
     fn test_thread_metadata_updated_nonexistent_thread() {
         let mut app = App::default();
@@ -3352,6 +4147,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         });
 
         // Set as active thread
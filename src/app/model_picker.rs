@@ -0,0 +1,243 @@
+//! Per-thread model selection for the App.
+//!
+//! `/model` opens a picker over the models the backend offers (fetched on
+//! first use and cached like folders). Enter sets the model of the active
+//! thread, saving it on the backend in the background (a failed save restores
+//! the previous model), or, on the CommandDeck, the model the next new thread
+//! starts with. The model is sent with every stream request.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{App, AppMessage, ModelPickerState, Screen};
+
+/// How long the "Model set to ..." confirmation stays visible
+const MODEL_NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+impl App {
+    /// Open the model picker (`/model`), fetching models if none are cached
+    pub fn open_model_picker(&mut self) {
+        let current = self.selected_model();
+        let selected_index = current
+            .and_then(|model| self.models.iter().position(|m| m.id == model))
+            .unwrap_or(0);
+        self.model_picker = Some(ModelPickerState { selected_index });
+
+        if self.models.is_empty() && !self.models_loading {
+            self.load_models();
+        }
+        self.mark_dirty();
+    }
+
+    /// Close the model picker
+    pub fn close_model_picker(&mut self) {
+        self.model_picker = None;
+        self.mark_dirty();
+    }
+
+    /// Load models from the backend API.
+    ///
+    /// Sets models_loading = true and spawns an async task to fetch models.
+    /// On success, sends ModelsLoaded message with the model list.
+    /// On error, sends ModelsLoadFailed message with the error description.
+    pub fn load_models(&mut self) {
+        self.models_loading = true;
+        self.models_error = None;
+        self.mark_dirty();
+
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+
+        tokio::spawn(async move {
+            match client.list_models().await {
+                Ok(models) => {
+                    let _ = tx.send(AppMessage::ModelsLoaded(models));
+                }
+                Err(e) => {
+                    let error_msg = format!("Failed to load models: {}", e);
+                    let _ = tx.send(AppMessage::ModelsLoadFailed(error_msg));
+                }
+            }
+        });
+    }
+
+    /// Move the model picker selection by `delta` (clamped)
+    pub fn model_picker_move(&mut self, delta: isize) {
+        let last = self.models.len().saturating_sub(1);
+        if let Some(picker) = self.model_picker.as_mut() {
+            picker.selected_index = picker.selected_index.saturating_add_signed(delta).min(last);
+        }
+        self.mark_dirty();
+    }
+
+    /// Use the model selected in the picker.
+    ///
+    /// In a conversation this sets the active thread's model; on the
+    /// CommandDeck it sets the model for the next new thread.
+    pub fn confirm_model_selection(&mut self) {
+        let Some(picker) = self.model_picker.take() else {
+            return;
+        };
+        let Some(model) = self.models.get(picker.selected_index).cloned() else {
+            self.mark_dirty();
+            return;
+        };
+
+        match self
+            .active_thread_id
+            .clone()
+            .filter(|_| self.screen == Screen::Conversation)
+        {
+            Some(thread_id) => {
+                let previous = self
+                    .cache
+                    .get_thread(&thread_id)
+                    .and_then(|t| t.model.clone());
+                self.cache
+                    .set_thread_model(&thread_id, Some(model.id.clone()));
+                self.spawn_backend_model_update(thread_id, model.id.clone(), previous);
+                self.set_status_notice(
+                    format!("Model set to {}", model.label()),
+                    MODEL_NOTICE_DURATION,
+                );
+            }
+            None => {
+                self.next_thread_model = Some(model.id.clone());
+                self.set_status_notice(
+                    format!("New threads will use {}", model.label()),
+                    MODEL_NOTICE_DURATION,
+                );
+            }
+        }
+        self.mark_dirty();
+    }
+
+    /// Model the picker applies to: the active thread's in a conversation,
+    /// otherwise the one chosen for the next new thread
+    pub fn selected_model(&self) -> Option<&str> {
+        match self.active_thread_id.as_deref() {
+            Some(thread_id) if self.screen == Screen::Conversation => self
+                .cache
+                .get_thread(thread_id)
+                .and_then(|t| t.model.as_deref()),
+            _ => self.next_thread_model.as_deref(),
+        }
+    }
+
+    /// Save a thread's model on the backend, reporting failures as a message
+    fn spawn_backend_model_update(
+        &self,
+        thread_id: String,
+        model: String,
+        previous: Option<String>,
+    ) {
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            if let Err(e) = client.update_thread_model(&thread_id, &model).await {
+                let _ = tx.send(AppMessage::ThreadModelUpdateFailed {
+                    thread_id,
+                    previous,
+                    error: e.to_string(),
+                });
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelInfo;
+
+    fn model(id: &str) -> ModelInfo {
+        ModelInfo {
+            id: id.to_string(),
+            display_name: None,
+        }
+    }
+
+    fn app_with_models() -> App {
+        let mut app = App::default();
+        app.models = vec![model("claude-sonnet-4-5"), model("claude-opus-4-5")];
+        app
+    }
+
+    #[tokio::test]
+    async fn test_model_selection_sets_active_thread_model() {
+        let mut app = app_with_models();
+        app.cache = crate::cache::ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+
+        app.open_model_picker();
+        app.model_picker_move(1);
+        app.model_picker_move(1);
+        app.confirm_model_selection();
+
+        assert!(app.model_picker.is_none());
+        assert_eq!(app.selected_model(), Some("claude-opus-4-5"));
+        assert!(app.next_thread_model.is_none());
+
+        // Reopening preselects the thread's model
+        app.open_model_picker();
+        assert_eq!(app.model_picker.as_ref().unwrap().selected_index, 1);
+    }
+
+    #[test]
+    fn test_model_selection_on_command_deck_applies_to_next_thread() {
+        let mut app = app_with_models();
+
+        app.open_model_picker();
+        app.confirm_model_selection();
+
+        assert_eq!(app.next_thread_model.as_deref(), Some("claude-sonnet-4-5"));
+    }
+
+    #[tokio::test]
+    async fn test_next_thread_model_applies_to_new_thread_once() {
+        let mut app = app_with_models();
+        app.next_thread_model = Some("claude-opus-4-5".to_string());
+        app.textarea.insert_char('H');
+        app.textarea.insert_char('i');
+
+        app.submit_input(crate::models::ThreadType::Conversation);
+
+        let thread_id = app.active_thread_id.clone().unwrap();
+        assert_eq!(
+            app.cache.get_thread(&thread_id).unwrap().model.as_deref(),
+            Some("claude-opus-4-5")
+        );
+        assert!(app.next_thread_model.is_none());
+    }
+
+    #[test]
+    fn test_failed_model_save_restores_previous() {
+        let mut app = App::default();
+        app.cache = crate::cache::ThreadCache::with_stub_data();
+        app.cache
+            .set_thread_model("thread-001", Some("claude-opus-4-5".to_string()));
+
+        app.handle_message(AppMessage::ThreadModelUpdateFailed {
+            thread_id: "thread-001".to_string(),
+            previous: None,
+            error: "boom".to_string(),
+        });
+
+        assert!(app.cache.get_thread("thread-001").unwrap().model.is_none());
+        assert!(app.stream_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_open_model_picker_fetches_models() {
+        let mut app = App::default();
+
+        app.open_model_picker();
+
+        assert!(app.models_loading);
+
+        app.handle_message(AppMessage::ModelsLoaded(vec![model("claude-haiku-4-5")]));
+        assert!(!app.models_loading);
+        assert_eq!(app.models.len(), 1);
+    }
+}
@@ -1,8 +1,9 @@
 //! Navigation methods for the App.
 
-use std::sync::Arc;
+use crate::input_history::match_rank;
+use crate::websocket::WsPresenceState;
 
-use super::{App, Focus, Screen};
+use super::{App, Focus, Screen, ScrollBoundary, ThreadSwitcher};
 
 impl App {
     /// Cycle focus between Threads and Input
@@ -15,22 +16,15 @@ impl App {
     }
 
     /// Cycle through permission modes: Default -> Plan -> Execution -> Default
+    ///
+    /// Execution opens a confirmation first (see `bypass_confirm`).
     pub fn cycle_permission_mode(&mut self) {
         use crate::models::PermissionMode;
-        // 1. Local update (existing behavior)
-        self.permission_mode = match self.permission_mode {
-            PermissionMode::Default => PermissionMode::Plan,
-            PermissionMode::Plan => PermissionMode::Execution,
-            PermissionMode::Execution => PermissionMode::Default,
-        };
-        self.mark_dirty();
-
-        // 2. Sync to backend if thread exists
-        if let Some(thread_id) = &self.active_thread_id.clone() {
-            self.thread_mode_sync.request_mode_change(
-                thread_id.clone(),
-                self.permission_mode,
-            );
+        match self.permission_mode {
+            PermissionMode::Default => self.set_permission_mode(PermissionMode::Plan),
+            // Execution skips tool prompts, so ask first
+            PermissionMode::Plan => self.request_bypass(),
+            PermissionMode::Execution => self.set_permission_mode(PermissionMode::Default),
         }
     }
 
@@ -70,6 +64,44 @@ impl App {
         }
     }
 
+    /// Scroll the CommandDeck thread list section under the mouse cursor.
+    ///
+    /// `delta` is in rows (negative scrolls up). Sections come from the last
+    /// render; the wheel does nothing outside them. Scrolling past either end
+    /// clamps and flags the boundary hit, like the conversation view.
+    /// `threads_index` indexes `cache.threads()`, not the section rows, so it
+    /// is only kept within the cached thread list.
+    pub fn scroll_thread_list(&mut self, column: u16, row: u16, delta: isize) {
+        let layout = self.thread_list_layout;
+        let (panel, offset, is_threads) = if let Some(panel) =
+            layout.need_action.filter(|p| p.contains(column, row))
+        {
+            (panel, &mut self.thread_list_scroll.need_action, false)
+        } else if let Some(panel) = layout.threads.filter(|p| p.contains(column, row)) {
+            (panel, &mut self.thread_list_scroll.threads, true)
+        } else {
+            return;
+        };
+
+        let current = (*offset).min(panel.max_offset());
+        let target = current.saturating_add_signed(delta).min(panel.max_offset());
+        if target == current {
+            self.scroll_boundary_hit = Some(if delta < 0 {
+                ScrollBoundary::Top
+            } else {
+                ScrollBoundary::Bottom
+            });
+            self.boundary_hit_tick = self.tick_count;
+        }
+        *offset = target;
+
+        if is_threads {
+            let max_index = self.cache.threads().len().saturating_sub(1);
+            self.threads_index = self.threads_index.min(max_index);
+        }
+        self.mark_dirty();
+    }
+
     /// Create a new thread placeholder
     pub fn create_new_thread(&mut self) {
         use crate::state::Thread;
@@ -89,40 +121,72 @@ impl App {
 
     /// Mark the app to quit
     pub fn quit(&mut self) {
+        self.save_render_snapshot();
+        self.save_thread_metrics();
         self.should_quit = true;
         self.mark_dirty();
     }
 
     /// Navigate back to the CommandDeck screen
     pub fn navigate_to_command_deck(&mut self) {
+        self.save_render_snapshot();
+        if let Some(thread_id) = self.active_thread_id.as_deref() {
+            self.send_presence(thread_id, WsPresenceState::Left);
+            self.cache.forget_dismissed_errors(thread_id);
+        }
         self.screen = Screen::CommandDeck;
         self.active_thread_id = None; // Clear so next submit creates new thread
         self.textarea.clear(); // Clear any partial input
         self.pending_images.clear(); // Clear attached images
+        self.message_search = Default::default(); // Search is per-conversation
+        self.pending_scroll_restore = None;
+        self.navigate_mode = None;
         self.mark_dirty();
+        self.save_session();
     }
 
     /// Open a specific thread by ID for conversation
     pub fn open_thread(&mut self, thread_id: String) {
-        use super::{log_thread_update, AppMessage};
+        use super::log_thread_update;
 
         log_thread_update(&format!("open_thread called with thread_id: {}", thread_id));
 
         // Touch thread to update LRU (prevents eviction and moves to front)
         self.cache.touch_thread(&thread_id);
+        self.dashboard.mark_thread_notifications_read(&thread_id);
+        self.unread_counts.remove(&thread_id);
+        self.pending_message_jump = None;
+        self.pending_scroll_anchor = None;
+        self.navigate_mode = None;
 
-        // Clear selected folder when navigating to an existing thread
+        // Clear selected folders when navigating to an existing thread
         // (folder context is only for new thread creation from CommandDeck)
-        if self.selected_folder.is_some() {
-            self.selected_folder = None;
+        self.selected_folders.clear();
+
+        // Move our presence from the previous thread to this one
+        if let Some(previous) = self.active_thread_id.as_deref() {
+            if previous != thread_id {
+                self.send_presence(previous, WsPresenceState::Left);
+                self.cache.forget_dismissed_errors(previous);
+            }
         }
 
+        // Keep the previous thread's layout and pick up this one's
+        if self.active_thread_id.as_deref() != Some(thread_id.as_str()) {
+            self.save_render_snapshot();
+            self.load_render_snapshot(&thread_id);
+        }
+        self.send_presence(&thread_id, WsPresenceState::Viewing);
+
         // Set active thread and navigate (existing logic)
         self.active_thread_id = Some(thread_id.clone());
         self.screen = Screen::Conversation;
         self.textarea.clear();
+        self.message_search = Default::default();
         self.reset_scroll();
+        self.pending_scroll_restore = None;
         self.mark_dirty();
+        self.save_session();
 
         // Check if messages need to be fetched
         let has_cached = self.cache.get_messages(&thread_id).is_some();
@@ -133,44 +197,12 @@ impl App {
                 "open_thread: spawning fetch task for {}",
                 thread_id
             ));
-            // Spawn async fetch task
-            let client = Arc::clone(&self.client);
-            let message_tx = self.message_tx.clone();
-            let tid = thread_id.clone();
-
-            tokio::spawn(async move {
-                log_thread_update(&format!("open_thread: fetch task started for {}", tid));
-                match client.fetch_thread_with_messages(&tid).await {
-                    Ok(response) => {
-                        log_thread_update(&format!(
-                            "open_thread: fetch SUCCESS for {}, got {} messages",
-                            tid,
-                            response.messages.len()
-                        ));
-                        let messages: Vec<crate::models::Message> = response
-                            .messages
-                            .into_iter()
-                            .enumerate()
-                            .map(|(i, m)| m.to_client_message(&tid, i as i64 + 1))
-                            .collect();
-                        let _ = message_tx.send(AppMessage::MessagesLoaded {
-                            thread_id: tid,
-                            messages,
-                        });
-                    }
-                    Err(e) => {
-                        log_thread_update(&format!(
-                            "open_thread: fetch FAILED for {}: {:?}",
-                            tid, e
-                        ));
-                        let _ = message_tx.send(AppMessage::MessagesLoadError {
-                            thread_id: tid,
-                            error: e.to_string(),
-                        });
-                    }
-                }
-            });
+            // Only the latest page; older ones load as the user scrolls up
+            self.fetch_latest_messages(&thread_id);
         }
+
+        // Resume a queue that was paused while the thread wasn't open
+        self.submit_next_queued(&thread_id);
     }
 
     /// Open the currently selected thread from the threads panel
@@ -217,8 +249,8 @@ impl App {
     }
 
     /// Open the thread switcher dialog and set selection based on current screen:
-    /// - CommandDeck (Dashboard): starts at index 0 (most recent thread)
-    /// - Conversation (Thread view): starts at index 1 (second most recent)
+    /// - CommandDeck (Dashboard): starts at index 0 (first pinned or most recent thread)
+    /// - Conversation (Thread view): starts at the first thread other than the open one
     pub fn open_switcher(&mut self) {
         let thread_count = self.cache.threads().len();
         if thread_count < 2 {
@@ -229,11 +261,17 @@ impl App {
         self.thread_switcher.visible = true;
         // Set initial selection based on current screen:
         // - Dashboard (CommandDeck): start at 0 (most recent)
-        // - Thread view (Conversation): start at 1 (second most recent, allows immediate switch)
+        // - Thread view (Conversation): start past the open thread (allows immediate
+        //   switch). Pinned threads lead the list, so it isn't always at 0.
         self.thread_switcher.selected_index = match self.screen {
             Screen::CommandDeck => 0,
-            Screen::Conversation => 1,
-            Screen::BrowseList => 0, // Start at most recent when coming from browse list
+            Screen::Conversation => self
+                .cache
+                .threads()
+                .iter()
+                .position(|t| self.active_thread_id.as_deref() != Some(t.id.as_str()))
+                .unwrap_or(1),
+            Screen::BrowseList | Screen::Setup => 0, // Start at most recent when coming from browse list
         };
         self.thread_switcher.scroll_offset = 0;
         self.thread_switcher.last_nav_time = Some(std::time::Instant::now());
@@ -242,49 +280,110 @@ impl App {
 
     /// Close the thread switcher dialog without switching
     pub fn close_switcher(&mut self) {
-        self.thread_switcher.visible = false;
-        self.thread_switcher.selected_index = 0;
-        self.thread_switcher.scroll_offset = 0;
-        self.thread_switcher.last_nav_time = None;
+        self.thread_switcher = ThreadSwitcher::default();
         self.mark_dirty();
     }
 
+    /// Thread list indices shown in the switcher, in display order: every
+    /// thread, or the matches of the typed filter
+    pub fn switcher_entries(&self) -> Vec<usize> {
+        if self.thread_switcher.query.is_empty() {
+            (0..self.cache.threads().len()).collect()
+        } else {
+            self.thread_switcher.matches.clone()
+        }
+    }
+
     /// Cycle the thread switcher selection forward (toward older threads)
     pub fn cycle_switcher_forward(&mut self) {
-        let thread_count = self.cache.threads().len();
-        if thread_count == 0 {
+        let entry_count = self.switcher_entries().len();
+        if entry_count == 0 {
             return;
         }
 
         self.thread_switcher.selected_index =
-            (self.thread_switcher.selected_index + 1) % thread_count;
-        self.adjust_switcher_scroll(thread_count);
-        self.thread_switcher.last_nav_time = Some(std::time::Instant::now());
+            (self.thread_switcher.selected_index + 1) % entry_count;
+        self.adjust_switcher_scroll(entry_count);
+        self.touch_switcher_nav_time();
         self.mark_dirty();
     }
 
     /// Cycle the thread switcher selection backward (toward newer threads)
     pub fn cycle_switcher_backward(&mut self) {
-        let thread_count = self.cache.threads().len();
-        if thread_count == 0 {
+        let entry_count = self.switcher_entries().len();
+        if entry_count == 0 {
             return;
         }
 
         if self.thread_switcher.selected_index == 0 {
-            self.thread_switcher.selected_index = thread_count - 1;
+            self.thread_switcher.selected_index = entry_count - 1;
         } else {
             self.thread_switcher.selected_index -= 1;
         }
-        self.adjust_switcher_scroll(thread_count);
-        self.thread_switcher.last_nav_time = Some(std::time::Instant::now());
+        self.adjust_switcher_scroll(entry_count);
+        self.touch_switcher_nav_time();
+        self.mark_dirty();
+    }
+
+    /// Restart the auto-confirm timer after navigating, unless a filter was
+    /// typed (then only Enter confirms)
+    fn touch_switcher_nav_time(&mut self) {
+        if self.thread_switcher.query.is_empty() {
+            self.thread_switcher.last_nav_time = Some(std::time::Instant::now());
+        }
+    }
+
+    /// Type a character into the switcher filter.
+    ///
+    /// Cancels the auto-confirm timeout so the dialog stays open while typing.
+    pub fn switcher_type_char(&mut self, c: char) {
+        self.thread_switcher.query.push(c);
+        self.apply_switcher_filter();
+    }
+
+    /// Delete the last character of the switcher filter
+    pub fn switcher_backspace(&mut self) {
+        if self.thread_switcher.query.pop().is_some() {
+            self.apply_switcher_filter();
+        }
+    }
+
+    /// Match threads against the switcher filter by title and preview.
+    ///
+    /// Uses the input history's fuzzy ranking (prefix, then substring, then
+    /// scattered characters); title matches rank above preview matches and
+    /// the MRU order is kept within a rank.
+    fn apply_switcher_filter(&mut self) {
+        let query = self.thread_switcher.query.to_lowercase();
+        let mut ranked: Vec<(u8, usize)> = self
+            .cache
+            .threads()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, thread)| {
+                let preview = self
+                    .thread_previews
+                    .get(&thread.id)
+                    .unwrap_or(&thread.preview);
+                let title_rank = match_rank(&thread.title.to_lowercase(), &query);
+                let preview_rank = match_rank(&preview.to_lowercase(), &query).map(|r| r + 3);
+                title_rank.or(preview_rank).map(|rank| (rank, index))
+            })
+            .collect();
+        ranked.sort_by_key(|(rank, _)| *rank);
+
+        self.thread_switcher.matches = ranked.into_iter().map(|(_, index)| index).collect();
+        self.thread_switcher.selected_index = 0;
+        self.thread_switcher.scroll_offset = 0;
+        self.thread_switcher.last_nav_time = None;
         self.mark_dirty();
     }
 
     /// Adjust scroll offset to keep selected item visible
-    fn adjust_switcher_scroll(&mut self, thread_count: usize) {
+    fn adjust_switcher_scroll(&mut self, entry_count: usize) {
         let selected = self.thread_switcher.selected_index;
         let offset = self.thread_switcher.scroll_offset;
-        let visible = Self::MAX_VISIBLE_THREADS.min(thread_count);
+        let visible = Self::MAX_VISIBLE_THREADS.min(entry_count);
 
         // If selected is above visible area, scroll up
         if selected < offset {
@@ -299,6 +398,8 @@ impl App {
     /// Check if the thread switcher should auto-confirm due to navigation timeout
     /// Returns true if auto-confirm happened
     ///
+    /// Typing a filter clears the timer, so a filtered switcher waits for Enter.
+    ///
     /// NOTE: Alternative approach if auto-confirm doesn't work well:
     /// - Use Tab/Arrow keys just for navigation (no auto-confirm)
     /// - Require explicit Enter to confirm selection
@@ -320,18 +421,27 @@ impl App {
         false
     }
 
-    /// Confirm the thread switcher selection and switch to the selected thread
+    /// Confirm the thread switcher selection and switch to the selected thread.
+    ///
+    /// With a filter that matches nothing the switcher stays open.
     pub fn confirm_switcher_selection(&mut self) {
-        let threads = self.cache.threads();
-        let idx = self.thread_switcher.selected_index;
+        let entries = self.switcher_entries();
+        if entries.is_empty() && !self.thread_switcher.query.is_empty() {
+            return;
+        }
+
+        let thread_id = entries
+            .get(self.thread_switcher.selected_index)
+            .and_then(|&index| {
+                self.cache
+                    .threads()
+                    .get(index)
+                    .map(|thread| thread.id.clone())
+            });
 
-        if idx < threads.len() {
-            let thread_id = threads[idx].id.clone();
+        if let Some(thread_id) = thread_id {
             // Close switcher first
-            self.thread_switcher.visible = false;
-            self.thread_switcher.selected_index = 0;
-            self.thread_switcher.scroll_offset = 0;
-            self.thread_switcher.last_nav_time = None;
+            self.thread_switcher = ThreadSwitcher::default();
             // Open the selected thread (mark_dirty is called in open_thread)
             self.open_thread(thread_id);
         } else {
@@ -340,3 +450,134 @@ impl App {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view_state::{ThreadListLayout, ThreadListPanel};
+    use ratatui::layout::Rect;
+
+    fn app_with_panels() -> App {
+        let mut app = App::default();
+        app.thread_list_layout = ThreadListLayout {
+            need_action: Some(ThreadListPanel {
+                area: Rect::new(0, 0, 80, 16),
+                total: 8,
+                visible: 5,
+            }),
+            threads: Some(ThreadListPanel {
+                area: Rect::new(0, 17, 80, 9),
+                total: 10,
+                visible: 3,
+            }),
+        };
+        app
+    }
+
+    #[tokio::test]
+    async fn test_switcher_filter_stops_auto_confirm() {
+        let mut app = App::default();
+        app.cache = crate::cache::ThreadCache::with_stub_data();
+        app.open_switcher();
+        assert!(app.thread_switcher.last_nav_time.is_some());
+
+        for c in "Tui".chars() {
+            app.switcher_type_char(c);
+        }
+        assert!(app.thread_switcher.last_nav_time.is_none());
+        assert!(!app.check_switcher_timeout());
+        let entries = app.switcher_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(app.cache.threads()[entries[0]].id, "thread-002");
+
+        // Navigating a filtered list doesn't restart the timer
+        app.cycle_switcher_forward();
+        assert!(app.thread_switcher.last_nav_time.is_none());
+
+        // Nothing matches: Enter keeps the switcher open
+        app.switcher_type_char('z');
+        assert!(app.switcher_entries().is_empty());
+        app.confirm_switcher_selection();
+        assert!(app.thread_switcher.visible);
+
+        app.switcher_backspace();
+        app.confirm_switcher_selection();
+        assert!(!app.thread_switcher.visible);
+        assert!(app.thread_switcher.query.is_empty());
+        assert_eq!(app.active_thread_id.as_deref(), Some("thread-002"));
+    }
+
+    #[test]
+    fn test_switcher_filter_matches_preview_after_titles() {
+        let mut app = App::default();
+        app.cache = crate::cache::ThreadCache::with_stub_data();
+        app.open_switcher();
+
+        for c in "reqwest".chars() {
+            app.switcher_type_char(c);
+        }
+        let entries = app.switcher_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(app.cache.threads()[entries[0]].id, "thread-003");
+
+        app.close_switcher();
+        assert!(app.thread_switcher.query.is_empty());
+        assert!(app.thread_switcher.matches.is_empty());
+    }
+
+    #[test]
+    fn test_scroll_thread_list_targets_panel_under_cursor() {
+        let mut app = app_with_panels();
+
+        app.scroll_thread_list(10, 2, 1);
+        assert_eq!(app.thread_list_scroll.need_action, 1);
+        assert_eq!(app.thread_list_scroll.threads, 0);
+
+        app.scroll_thread_list(10, 20, 2);
+        assert_eq!(app.thread_list_scroll.need_action, 1);
+        assert_eq!(app.thread_list_scroll.threads, 2);
+    }
+
+    #[test]
+    fn test_scroll_thread_list_outside_panels_is_ignored() {
+        let mut app = app_with_panels();
+        app.needs_redraw = false;
+
+        app.scroll_thread_list(10, 16, 1);
+
+        assert_eq!(app.thread_list_scroll, Default::default());
+        assert!(!app.needs_redraw);
+    }
+
+    #[test]
+    fn test_scroll_thread_list_clamps_with_boundary_feedback() {
+        let mut app = app_with_panels();
+
+        app.scroll_thread_list(10, 2, -1);
+        assert_eq!(app.thread_list_scroll.need_action, 0);
+        assert_eq!(app.scroll_boundary_hit, Some(ScrollBoundary::Top));
+
+        app.scroll_thread_list(10, 20, 100);
+        assert_eq!(app.thread_list_scroll.threads, 7);
+        app.scroll_boundary_hit = None;
+        app.scroll_thread_list(10, 20, 1);
+        assert_eq!(app.thread_list_scroll.threads, 7);
+        assert_eq!(app.scroll_boundary_hit, Some(ScrollBoundary::Bottom));
+    }
+
+    #[test]
+    fn test_scroll_thread_list_keeps_selection_within_cached_threads() {
+        let mut app = app_with_panels();
+        app.cache = crate::cache::ThreadCache::with_stub_data();
+        let thread_count = app.cache.threads().len();
+
+        // Section offsets don't move a selection that is already in range
+        app.threads_index = 0;
+        app.scroll_thread_list(10, 20, 5);
+        assert_eq!(app.threads_index, 0);
+
+        app.threads_index = thread_count + 3;
+        app.scroll_thread_list(10, 20, 1);
+        assert_eq!(app.threads_index, thread_count - 1);
+    }
+}
@@ -0,0 +1,141 @@
+//! Needs-action selection on the CommandDeck.
+//!
+//! With several threads waiting, Up/Down (empty input, no overlay) move a
+//! cursor through the needs-action rows; y/a/n answer the selected thread's
+//! prompt and Enter opens it. The selection starts on the top entry, stays
+//! on its thread while the list reorders, and passes to the entry below once
+//! that thread is dealt with. Elsewhere the top entry is still the target.
+
+use crate::models::dashboard::WaitingFor;
+
+use super::{App, Screen};
+
+impl App {
+    /// The needs-action thread that permission, question and plan keys act
+    /// on: the selected one on the CommandDeck, the open thread if it is
+    /// muted and waiting, the top one elsewhere
+    pub fn needs_action_target(&self) -> Option<(String, WaitingFor)> {
+        if self.screen == Screen::CommandDeck {
+            self.dashboard.get_selected_needs_action_thread()
+        } else {
+            self.muted_active_thread_waiting()
+                .or_else(|| self.dashboard.get_top_needs_action_thread())
+        }
+    }
+
+    /// Whether the CommandDeck has several waiting threads to choose from
+    pub fn needs_action_selectable(&self) -> bool {
+        self.screen == Screen::CommandDeck && self.dashboard.needs_action_threads().len() > 1
+    }
+
+    /// Move the needs-action selection by `delta` rows, scrolling the
+    /// need-action section to keep it visible
+    pub fn move_needs_action_selection(&mut self, delta: isize) {
+        if !self.dashboard.move_needs_action_selection(delta) {
+            return;
+        }
+
+        // Pinned threads are listed in their own section above
+        let pinned = self
+            .dashboard
+            .compute_thread_views()
+            .iter()
+            .filter(|v| v.needs_action && v.pinned)
+            .count();
+        let position = self
+            .dashboard
+            .selected_needs_action_index()
+            .checked_sub(pinned);
+        let visible = self.thread_list_layout.need_action.map_or(0, |p| p.visible);
+        if let Some(position) = position.filter(|_| visible > 0) {
+            let offset = &mut self.thread_list_scroll.need_action;
+            *offset = (*offset).clamp((position + 1).saturating_sub(visible), position);
+        }
+        self.mark_dirty();
+    }
+
+    /// Open the selected needs-action thread.
+    ///
+    /// Returns false if no thread needs action.
+    pub fn open_selected_needs_action_thread(&mut self) -> bool {
+        match self.dashboard.get_selected_needs_action_thread() {
+            Some((thread_id, _)) => {
+                self.open_thread(thread_id);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::dashboard::ThreadStatus;
+    use crate::models::{Thread, ThreadMode, ThreadType};
+    use chrono::Utc;
+
+    /// CommandDeck with three threads waiting on permissions, newest first:
+    /// t3, t2, t1
+    fn app_with_three_waiting() -> App {
+        let mut app = App::default();
+        for (i, id) in ["t1", "t2", "t3"].iter().enumerate() {
+            app.dashboard.add_thread(Thread {
+                id: id.to_string(),
+                title: format!("Thread {}", id),
+                description: None,
+                preview: String::new(),
+                updated_at: Utc::now() + chrono::Duration::seconds(i as i64),
+                thread_type: ThreadType::Conversation,
+                mode: ThreadMode::Normal,
+                model: None,
+                permission_mode: None,
+                message_count: 0,
+                created_at: Utc::now(),
+                working_directory: None,
+                status: Some(ThreadStatus::Waiting),
+                verified: None,
+                verified_at: None,
+                links: Vec::new(),
+                pinned: false,
+                pin_order: 0,
+                archived: false,
+                muted: false,
+            });
+            app.dashboard.update_thread_status(
+                id,
+                ThreadStatus::Waiting,
+                Some(WaitingFor::Permission {
+                    request_id: format!("perm-{}", id),
+                    tool_name: "Bash".to_string(),
+                }),
+            );
+        }
+        let _ = app.dashboard.compute_thread_views();
+        app
+    }
+
+    #[test]
+    fn test_selection_targets_entry_on_command_deck_only() {
+        let mut app = app_with_three_waiting();
+        assert!(app.needs_action_selectable());
+        assert_eq!(app.needs_action_target().unwrap().0, "t3");
+
+        app.move_needs_action_selection(1);
+        assert_eq!(app.needs_action_target().unwrap().0, "t2");
+
+        app.screen = Screen::Conversation;
+        assert!(!app.needs_action_selectable());
+        assert_eq!(app.needs_action_target().unwrap().0, "t3");
+    }
+
+    #[tokio::test]
+    async fn test_enter_opens_selected_thread() {
+        let mut app = app_with_three_waiting();
+        app.move_needs_action_selection(5);
+
+        assert!(app.open_selected_needs_action_thread());
+        assert_eq!(app.active_thread_id.as_deref(), Some("t1"));
+        assert_eq!(app.screen, Screen::Conversation);
+    }
+}
@@ -0,0 +1,252 @@
+//! Notifications panel for the App.
+//!
+//! Thread events the user wasn't looking at (a response finishing, a
+//! permission prompt, an error) are collected in the dashboard's notification
+//! feed. `/notifications` opens the panel: Enter jumps to the source thread
+//! (and message), `d` dismisses the selected entry and `D` clears them all.
+//! Dismissals are sent to the backend over the WebSocket.
+
+use tracing::{error, info, warn};
+
+use crate::state::Notification;
+use crate::websocket::{WsConnectionState, WsNotificationDismissed, WsOutgoingMessage};
+
+use super::{App, AppMessage, NotificationsPanelState, Screen};
+
+impl App {
+    /// Record a notification for a thread event.
+    ///
+    /// Skipped when the thread is already open in front of the user.
+    pub fn push_thread_notification(
+        &mut self,
+        thread_id: &str,
+        message_id: Option<i64>,
+        message: String,
+    ) {
+        if self.screen == Screen::Conversation
+            && self.active_thread_id.as_deref() == Some(thread_id)
+        {
+            return;
+        }
+
+        self.dashboard.push_notification(Notification {
+            id: uuid::Uuid::new_v4().to_string(),
+            thread_id: thread_id.to_string(),
+            message_id,
+            timestamp: chrono::Utc::now(),
+            message,
+            read: false,
+        });
+        self.mark_dirty();
+    }
+
+    /// Open the notifications panel (`/notifications`)
+    pub fn open_notifications_panel(&mut self) {
+        self.notifications_panel = Some(NotificationsPanelState::default());
+        self.mark_dirty();
+    }
+
+    /// Close the notifications panel
+    pub fn close_notifications_panel(&mut self) {
+        self.notifications_panel = None;
+        self.mark_dirty();
+    }
+
+    /// Move the panel selection up (-1) or down (+1)
+    pub fn notifications_panel_move(&mut self, delta: isize) {
+        let count = self.dashboard.notifications().len();
+        if let Some(panel) = self.notifications_panel.as_mut() {
+            if count > 0 {
+                panel.selected_index = panel
+                    .selected_index
+                    .saturating_add_signed(delta)
+                    .min(count - 1);
+            }
+        }
+        self.mark_dirty();
+    }
+
+    /// Open the thread behind the selected notification (Enter)
+    ///
+    /// Scrolls to the notification's message once the thread renders.
+    pub fn open_selected_notification(&mut self) {
+        let Some(notification) = self.selected_notification().cloned() else {
+            return;
+        };
+        self.dashboard.mark_notification_read(&notification.id);
+        self.notifications_panel = None;
+        self.open_thread(notification.thread_id);
+        self.pending_message_jump = notification.message_id;
+    }
+
+    /// Dismiss the selected notification (`d`)
+    ///
+    /// A permission prompt the thread is waiting on stays pending.
+    pub fn dismiss_selected_notification(&mut self) {
+        let Some(id) = self.selected_notification().map(|n| n.id.clone()) else {
+            return;
+        };
+        self.dashboard.dismiss_notification(&id);
+
+        let count = self.dashboard.notifications().len();
+        if let Some(panel) = self.notifications_panel.as_mut() {
+            panel.selected_index = panel.selected_index.min(count.saturating_sub(1));
+        }
+        let _ = self.message_tx.send(AppMessage::NotificationDismissed {
+            notification_ids: vec![id],
+        });
+        self.mark_dirty();
+    }
+
+    /// Dismiss every notification (`D`)
+    pub fn clear_all_notifications(&mut self) {
+        let ids = self.dashboard.clear_notifications();
+        if let Some(panel) = self.notifications_panel.as_mut() {
+            panel.selected_index = 0;
+        }
+        if !ids.is_empty() {
+            let _ = self.message_tx.send(AppMessage::NotificationDismissed {
+                notification_ids: ids,
+            });
+        }
+        self.mark_dirty();
+    }
+
+    /// Tell the backend which notifications were dismissed.
+    ///
+    /// Wire format:
+    /// ```json
+    /// {
+    ///   "type": "notification_dismissed",
+    ///   "notification_ids": ["..."]
+    /// }
+    /// ```
+    pub fn send_notifications_dismissed(&self, notification_ids: Vec<String>) -> bool {
+        let sender = match &self.ws_sender {
+            Some(s) => s,
+            None => {
+                warn!("No WebSocket sender available for notification dismissal");
+                return false;
+            }
+        };
+
+        if self.ws_connection_state != WsConnectionState::Connected {
+            warn!("WebSocket not connected for notification dismissal");
+            return false;
+        }
+
+        let count = notification_ids.len();
+        let message = WsNotificationDismissed::new(notification_ids);
+        match sender.try_send(WsOutgoingMessage::NotificationDismissed(message)) {
+            Ok(()) => {
+                info!("Sent {} notification dismissal(s) via WebSocket", count);
+                true
+            }
+            Err(e) => {
+                error!("Failed to send notification dismissal: {}", e);
+                false
+            }
+        }
+    }
+
+    fn selected_notification(&self) -> Option<&Notification> {
+        let panel = self.notifications_panel.as_ref()?;
+        self.dashboard.notifications().get(panel.selected_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::PermissionRequest;
+    use tokio::sync::mpsc;
+
+    fn app_with_notifications() -> App {
+        let mut app = App::default();
+        app.push_thread_notification("thread-1", Some(7), "Response finished".to_string());
+        app.push_thread_notification("thread-2", None, "Needs permission: Bash".to_string());
+        app.open_notifications_panel();
+        app
+    }
+
+    #[tokio::test]
+    async fn test_open_selected_notification_jumps_to_message() {
+        let mut app = app_with_notifications();
+        app.notifications_panel_move(1);
+
+        app.open_selected_notification();
+
+        assert_eq!(app.screen, Screen::Conversation);
+        assert_eq!(app.active_thread_id.as_deref(), Some("thread-1"));
+        assert_eq!(app.pending_message_jump, Some(7));
+        assert!(app.notifications_panel.is_none());
+        assert_eq!(app.dashboard.unread_notification_count(), 1);
+    }
+
+    #[test]
+    fn test_dismiss_keeps_permission_and_syncs() {
+        let mut app = app_with_notifications();
+        let mut rx = app.message_rx.take().unwrap();
+        app.dashboard.set_pending_permission(
+            "thread-2",
+            PermissionRequest {
+                permission_id: "perm-1".to_string(),
+                thread_id: Some("thread-2".to_string()),
+                tool_name: "Bash".to_string(),
+                description: "Run a command".to_string(),
+                context: None,
+                tool_input: None,
+                received_at: std::time::Instant::now(),
+            },
+        );
+        let dismissed_id = app.dashboard.notifications()[0].id.clone();
+
+        app.dismiss_selected_notification();
+
+        assert_eq!(app.dashboard.unread_notification_count(), 1);
+        assert!(app.dashboard.get_pending_permission("thread-2").is_some());
+        match rx.try_recv().unwrap() {
+            AppMessage::NotificationDismissed { notification_ids } => {
+                assert_eq!(notification_ids, vec![dismissed_id]);
+            }
+            other => panic!("Expected NotificationDismissed, got {:?}", other),
+        }
+
+        app.clear_all_notifications();
+        assert!(app.dashboard.notifications().is_empty());
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            AppMessage::NotificationDismissed { notification_ids } if notification_ids.len() == 1
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_notification_dismissed_is_sent_over_websocket() {
+        let mut app = App::default();
+        let (tx, mut rx) = mpsc::channel(10);
+        app.ws_sender = Some(tx);
+        app.ws_connection_state = WsConnectionState::Connected;
+
+        app.handle_message(AppMessage::NotificationDismissed {
+            notification_ids: vec!["n1".to_string()],
+        });
+
+        match rx.recv().await.unwrap() {
+            WsOutgoingMessage::NotificationDismissed(message) => {
+                assert_eq!(message.notification_ids, vec!["n1".to_string()]);
+            }
+            _ => panic!("Expected NotificationDismissed"),
+        }
+    }
+
+    #[test]
+    fn test_no_notification_for_open_thread() {
+        let mut app = App::default();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-1".to_string());
+
+        app.push_thread_notification("thread-1", Some(1), "Response finished".to_string());
+
+        assert!(app.dashboard.notifications().is_empty());
+    }
+}
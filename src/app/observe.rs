@@ -0,0 +1,236 @@
+//! Observer API for embedders.
+//!
+//! Frontends and automation built on the spoq core can subscribe to
+//! high-level events (a thread was created, a response finished, a tool is
+//! waiting for permission) without depending on the TUI. Subscribers are
+//! either callbacks or channels and are notified as the App applies each
+//! incoming message, including the `EmbedEvent`s a host feeds in.
+//!
+//! Embedders subscribe through the stable facade, which re-exports
+//! [`AppEvent`] and [`ObserverId`]:
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use spoq::adapters::MockHttpClient;
+//! use spoq::embed::{AppEvent, EmbeddedApp};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut embedded = EmbeddedApp::builder()
+//!     .http_client(Arc::new(MockHttpClient::new()))
+//!     .build()?;
+//!
+//! let mut events = embedded.subscribe_channel();
+//! embedded.subscribe(|event| {
+//!     if let AppEvent::PermissionRequested { tool_name, .. } = event {
+//!         println!("approval needed for {}", tool_name);
+//!     }
+//! });
+//! # let _ = events.try_recv();
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+
+use tokio::sync::mpsc;
+
+use super::AppMessage;
+
+/// High-level event reported to observers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppEvent {
+    /// A thread exists on the backend (reported once per thread)
+    ThreadCreated {
+        thread_id: String,
+        title: Option<String>,
+    },
+    /// An assistant response finished streaming
+    MessageFinalized { thread_id: String, message_id: i64 },
+    /// A tool call is waiting for the user's permission
+    PermissionRequested {
+        permission_id: String,
+        thread_id: Option<String>,
+        tool_name: String,
+        description: String,
+    },
+}
+
+impl AppEvent {
+    /// The observer event for an App message, if it has one
+    pub fn from_message(msg: &AppMessage) -> Option<Self> {
+        match msg {
+            AppMessage::ThreadCreated { real_id, title, .. } => Some(AppEvent::ThreadCreated {
+                thread_id: real_id.clone(),
+                title: title.clone(),
+            }),
+            AppMessage::WsThreadCreated { thread } => Some(AppEvent::ThreadCreated {
+                thread_id: thread.id.clone(),
+                title: Some(thread.title.clone()).filter(|t| !t.is_empty()),
+            }),
+            AppMessage::StreamComplete {
+                thread_id,
+                message_id,
+            } => Some(AppEvent::MessageFinalized {
+                thread_id: thread_id.clone(),
+                message_id: *message_id,
+            }),
+            AppMessage::PermissionRequested {
+                permission_id,
+                thread_id,
+                tool_name,
+                description,
+                ..
+            } => Some(AppEvent::PermissionRequested {
+                permission_id: permission_id.clone(),
+                thread_id: thread_id.clone(),
+                tool_name: tool_name.clone(),
+                description: description.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Handle returned by `Observers::subscribe`, used to unsubscribe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObserverId(u64);
+
+type Callback = Box<dyn FnMut(&AppEvent) + Send>;
+
+/// Registered event subscribers
+#[derive(Default)]
+pub struct Observers {
+    callbacks: Vec<(ObserverId, Callback)>,
+    channels: Vec<mpsc::UnboundedSender<AppEvent>>,
+    next_id: u64,
+    /// Threads already reported as created (the stream and the WebSocket
+    /// both announce threads this client creates)
+    announced_threads: HashSet<String>,
+}
+
+impl fmt::Debug for Observers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Observers")
+            .field("callbacks", &self.callbacks.len())
+            .field("channels", &self.channels.len())
+            .finish()
+    }
+}
+
+impl Observers {
+    /// Call `callback` for every event
+    pub fn subscribe(&mut self, callback: impl FnMut(&AppEvent) + Send + 'static) -> ObserverId {
+        let id = ObserverId(self.next_id);
+        self.next_id += 1;
+        self.callbacks.push((id, Box::new(callback)));
+        id
+    }
+
+    /// Receive every event on a channel; dropping the receiver unsubscribes
+    pub fn subscribe_channel(&mut self) -> mpsc::UnboundedReceiver<AppEvent> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.channels.push(tx);
+        rx
+    }
+
+    /// Remove a callback. Returns false if it wasn't registered.
+    pub fn unsubscribe(&mut self, id: ObserverId) -> bool {
+        let before = self.callbacks.len();
+        self.callbacks.retain(|(existing, _)| *existing != id);
+        self.callbacks.len() != before
+    }
+
+    /// Whether anyone is subscribed
+    pub fn is_empty(&self) -> bool {
+        self.callbacks.is_empty() && self.channels.is_empty()
+    }
+
+    /// Report an event to every subscriber
+    pub fn emit(&mut self, event: AppEvent) {
+        if let AppEvent::ThreadCreated { thread_id, .. } = &event {
+            if !self.announced_threads.insert(thread_id.clone()) {
+                return;
+            }
+        }
+        for (_, callback) in self.callbacks.iter_mut() {
+            callback(&event);
+        }
+        self.channels.retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
+    /// Report the event for an App message, if it has one
+    pub(crate) fn notify(&mut self, msg: &AppMessage) {
+        if self.is_empty() {
+            return;
+        }
+        if let Some(event) = AppEvent::from_message(msg) {
+            self.emit(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_callbacks_and_channels_receive_events() {
+        let mut app = App::default();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_by_callback = Arc::clone(&seen);
+        let id = app
+            .observers
+            .subscribe(move |event| seen_by_callback.lock().unwrap().push(event.clone()));
+        let mut rx = app.observers.subscribe_channel();
+
+        app.handle_message(AppMessage::StreamComplete {
+            thread_id: "thread-001".to_string(),
+            message_id: 7,
+        });
+
+        let expected = AppEvent::MessageFinalized {
+            thread_id: "thread-001".to_string(),
+            message_id: 7,
+        };
+        assert_eq!(*seen.lock().unwrap(), vec![expected.clone()]);
+        assert_eq!(rx.try_recv().unwrap(), expected);
+
+        assert!(app.observers.unsubscribe(id));
+        assert!(!app.observers.unsubscribe(id));
+    }
+
+    #[test]
+    fn test_thread_created_reported_once() {
+        let mut observers = Observers::default();
+        let mut rx = observers.subscribe_channel();
+        let created = AppEvent::ThreadCreated {
+            thread_id: "t1".to_string(),
+            title: None,
+        };
+
+        observers.emit(created.clone());
+        observers.emit(created);
+
+        assert!(rx.try_recv().is_ok());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_closed_channels_are_dropped() {
+        let mut observers = Observers::default();
+        drop(observers.subscribe_channel());
+        observers.emit(AppEvent::MessageFinalized {
+            thread_id: "t1".to_string(),
+            message_id: 1,
+        });
+        assert!(observers.is_empty());
+    }
+
+    #[test]
+    fn test_unrelated_messages_have_no_event() {
+        assert!(AppEvent::from_message(&AppMessage::ConnectionStatus(true)).is_none());
+    }
+}
@@ -0,0 +1,267 @@
+//! Outgoing message queue for the App.
+//!
+//! Enter while the active thread is still streaming steers the response.
+//! `QueuePrompt` (Alt+Q) queues the input per thread instead, with any
+//! attached images. Queued prompts render dimmed below the streaming
+//! response and are sent one at a time as each stream finishes.
+//! Auto-submission only happens while the thread is open: leaving the thread
+//! keeps its queue but pauses it until the user returns.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::clipboard::ImageAttachment;
+
+use super::{App, Screen};
+
+/// A prompt waiting for its thread's response to finish
+#[derive(Debug, Clone)]
+pub struct QueuedPrompt {
+    pub content: String,
+    /// Images attached when it was queued, uploaded when it is sent
+    pub images: Vec<ImageAttachment>,
+}
+
+impl QueuedPrompt {
+    /// A prompt without images (e.g. restored from a saved session)
+    pub fn text(content: String) -> Self {
+        Self {
+            content,
+            images: Vec::new(),
+        }
+    }
+}
+
+impl App {
+    /// Queue the input behind the open thread's streaming response.
+    ///
+    /// Returns false (leaving the input alone) unless the open thread is
+    /// streaming and there is something to send.
+    pub fn queue_input(&mut self) -> bool {
+        if self.screen != Screen::Conversation {
+            return false;
+        }
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return false;
+        };
+        let content = self.textarea.content_expanded();
+        if !self.cache.is_thread_streaming(&thread_id)
+            || (content.trim().is_empty() && self.pending_images.is_empty())
+        {
+            return false;
+        }
+
+        let images = self.pending_images.drain(..).collect();
+        self.enqueue_outgoing(
+            &thread_id,
+            QueuedPrompt {
+                content: content.clone(),
+                images,
+            },
+        );
+        self.input_history.add(content);
+        self.input_history.reset_navigation();
+        self.textarea.clear();
+        self.textarea.clear_paste_tokens();
+        true
+    }
+
+    /// Queue a prompt for a thread that is still streaming
+    pub fn enqueue_outgoing(&mut self, thread_id: &str, prompt: QueuedPrompt) {
+        let queue = self
+            .outgoing_queue
+            .entry(thread_id.to_string())
+            .or_default();
+        queue.push_back(prompt);
+        let notice = format!("Queued ({} waiting)", queue.len());
+        self.set_status_notice(notice, Duration::from_secs(2));
+        self.mark_dirty();
+    }
+
+    /// Prompts queued for a thread, oldest first
+    pub fn queued_outgoing(&self, thread_id: &str) -> Option<&VecDeque<QueuedPrompt>> {
+        self.outgoing_queue.get(thread_id).filter(|q| !q.is_empty())
+    }
+
+    /// Send the next queued prompt for `thread_id`.
+    ///
+    /// Does nothing (returning false) unless the thread is open, idle and has
+    /// something queued.
+    pub fn submit_next_queued(&mut self, thread_id: &str) -> bool {
+        let is_open = self.screen == Screen::Conversation
+            && self.active_thread_id.as_deref() == Some(thread_id);
+        if !is_open
+            || self.cache.is_thread_streaming(thread_id)
+            || self.queue_cancel_confirm.is_some()
+        {
+            return false;
+        }
+
+        let Some(queue) = self.outgoing_queue.get_mut(thread_id) else {
+            return false;
+        };
+        let Some(prompt) = queue.pop_front() else {
+            return false;
+        };
+        if queue.is_empty() {
+            self.outgoing_queue.remove(thread_id);
+        }
+
+        let image_hashes = prompt.images.iter().map(|img| img.hash.clone()).collect();
+        if !self
+            .cache
+            .add_streaming_message(thread_id, prompt.content.clone(), image_hashes)
+        {
+            self.stream_error = Some("Thread no longer exists.".to_string());
+            self.outgoing_queue.remove(thread_id);
+            return false;
+        }
+        // A reply reference left pending while it was queued applies now
+        let reply_to = self.take_pending_reply(thread_id);
+
+        let request = self
+            .stream_request_for(
+                thread_id,
+                prompt.content,
+                self.permission_mode,
+                self.selected_directories(),
+            )
+            .with_reply_to(reply_to);
+
+        if prompt.images.is_empty() {
+            self.spawn_stream_request(request, thread_id.to_string());
+        } else {
+            self.spawn_upload_and_stream(request, thread_id.to_string(), prompt.images);
+        }
+        self.mark_dirty();
+        true
+    }
+
+    /// Ctrl+C while streaming: cancel, asking first whether to drop queued prompts
+    pub fn request_stream_cancel(&mut self) {
+        let queued = self
+            .active_thread_id
+            .clone()
+            .filter(|id| self.queued_outgoing(id).is_some());
+        match queued {
+            Some(thread_id) => {
+                self.queue_cancel_confirm = Some(thread_id);
+                self.mark_dirty();
+            }
+            None => self.cancel_active_stream(),
+        }
+    }
+
+    /// Answer the cancel prompt: cancel the stream, optionally dropping the queue
+    pub fn confirm_queue_cancel(&mut self, drop_queue: bool) {
+        let Some(thread_id) = self.queue_cancel_confirm.take() else {
+            return;
+        };
+        if drop_queue {
+            self.outgoing_queue.remove(&thread_id);
+        }
+        self.cancel_active_stream();
+        self.mark_dirty();
+    }
+
+    /// Close the cancel prompt without cancelling
+    pub fn dismiss_queue_cancel(&mut self) {
+        self.queue_cancel_confirm = None;
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ThreadType;
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.textarea.insert_char(c);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queue_input_while_streaming_sends_after_complete() {
+        let mut app = App::default();
+        type_text(&mut app, "First");
+        app.submit_input(ThreadType::Conversation);
+        let thread_id = app.active_thread_id.clone().unwrap();
+
+        type_text(&mut app, "Second");
+        assert!(app.queue_input());
+
+        assert!(app.textarea.is_empty());
+        let queued: Vec<&str> = app
+            .queued_outgoing(&thread_id)
+            .unwrap()
+            .iter()
+            .map(|p| p.content.as_str())
+            .collect();
+        assert_eq!(queued, vec!["Second"]);
+
+        app.cache.finalize_message(&thread_id, 1);
+        assert!(!app.cache.is_thread_streaming(&thread_id));
+        assert!(app.submit_next_queued(&thread_id));
+
+        assert!(app.queued_outgoing(&thread_id).is_none());
+        assert!(app.cache.is_thread_streaming(&thread_id));
+        let messages = app.cache.get_messages(&thread_id).unwrap();
+        assert_eq!(messages[messages.len() - 2].content, "Second");
+    }
+
+    #[tokio::test]
+    async fn test_queue_input_needs_a_streaming_thread() {
+        let mut app = App::default();
+        type_text(&mut app, "First");
+        app.submit_input(ThreadType::Conversation);
+        let thread_id = app.active_thread_id.clone().unwrap();
+        app.cache.finalize_message(&thread_id, 1);
+
+        type_text(&mut app, "Second");
+        assert!(!app.queue_input());
+        assert_eq!(app.textarea.content(), "Second");
+        assert!(app.queued_outgoing(&thread_id).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_queue_pauses_while_thread_not_open() {
+        let mut app = App::default();
+        type_text(&mut app, "First");
+        app.submit_input(ThreadType::Conversation);
+        let thread_id = app.active_thread_id.clone().unwrap();
+        app.enqueue_outgoing(&thread_id, QueuedPrompt::text("Later".to_string()));
+        app.cache.finalize_message(&thread_id, 1);
+
+        app.screen = Screen::CommandDeck;
+        assert!(!app.submit_next_queued(&thread_id));
+        assert!(app.queued_outgoing(&thread_id).is_some());
+
+        app.open_thread(thread_id.clone());
+        assert!(app.queued_outgoing(&thread_id).is_none());
+        assert!(app.cache.is_thread_streaming(&thread_id));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_with_queue_asks_first() {
+        let mut app = App::default();
+        type_text(&mut app, "First");
+        app.submit_input(ThreadType::Conversation);
+        let thread_id = app.active_thread_id.clone().unwrap();
+        app.enqueue_outgoing(&thread_id, QueuedPrompt::text("Later".to_string()));
+
+        app.request_stream_cancel();
+        assert_eq!(
+            app.queue_cancel_confirm.as_deref(),
+            Some(thread_id.as_str())
+        );
+        assert!(!app.cancel_in_progress);
+
+        app.confirm_queue_cancel(true);
+        assert!(app.queue_cancel_confirm.is_none());
+        assert!(app.queued_outgoing(&thread_id).is_none());
+        assert!(!app.cache.is_thread_streaming(&thread_id));
+        assert!(app.cache.is_stream_cancelled(&thread_id));
+    }
+}
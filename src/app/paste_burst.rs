@@ -0,0 +1,254 @@
+//! Paste detection for terminals without bracketed paste.
+//!
+//! Without bracketed paste (older terminals, mosh) a paste arrives as a flood
+//! of key presses, each opening the @ picker or slash autocomplete again.
+//! The first printable key of a run is handled as typed; keys that follow
+//! within [`PASTE_BURST_INTERVAL`] of the previous one are held back. Once the
+//! keys stop (checked on ticks, and when a key breaks the run), a run of at
+//! least [`PASTE_BURST_MIN_CHARS`] is inserted as one paste, summarized like
+//! a bracketed paste, with whatever its first key opened closed again.
+//! Shorter runs are inserted as typed text.
+
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{App, Focus, Screen};
+
+/// Longest gap between two keys of a paste (people don't type this fast)
+pub const PASTE_BURST_INTERVAL: Duration = Duration::from_millis(8);
+
+/// Shortest run of keys treated as a paste
+pub const PASTE_BURST_MIN_CHARS: usize = 16;
+
+/// A run of keys that may be a paste
+#[derive(Debug, Clone, Default)]
+pub struct PasteBurst {
+    /// First key of the run, already handled as typed (None if it didn't go
+    /// to the input, so the run isn't held back)
+    first: Option<char>,
+    /// Keys held back after the first
+    held: String,
+    /// When the last key of the run arrived
+    last_key: Option<Instant>,
+}
+
+impl PasteBurst {
+    /// Hold `c` back if it continues the run at `now`
+    pub fn hold(&mut self, c: char, now: Instant) -> bool {
+        let continues = self.first.is_some()
+            && self
+                .last_key
+                .is_some_and(|last| now.duration_since(last) <= PASTE_BURST_INTERVAL);
+        if continues {
+            self.held.push(c);
+            self.last_key = Some(now);
+        }
+        continues
+    }
+
+    /// Start a new run with a key handled as typed
+    pub fn start(&mut self, first: Option<char>, now: Instant) {
+        self.first = first;
+        self.held.clear();
+        self.last_key = Some(now);
+    }
+
+    /// Whether the run is over at `now`
+    pub fn is_idle(&self, now: Instant) -> bool {
+        self.last_key
+            .is_none_or(|last| now.duration_since(last) > PASTE_BURST_INTERVAL)
+    }
+
+    /// Whether any keys are held back
+    pub fn has_held(&self) -> bool {
+        !self.held.is_empty()
+    }
+
+    /// End the run, returning its first key and the keys held back
+    fn take(&mut self) -> (Option<char>, String) {
+        self.last_key = None;
+        (self.first.take(), std::mem::take(&mut self.held))
+    }
+}
+
+/// Text a key press would insert, if it can be part of a paste
+fn burst_char(key: &KeyEvent) -> Option<char> {
+    match key.code {
+        KeyCode::Char(c)
+            if !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER) =>
+        {
+            Some(c)
+        }
+        KeyCode::Enter if key.modifiers.is_empty() => Some('\n'),
+        _ => None,
+    }
+}
+
+impl App {
+    /// Route a key press through paste detection.
+    ///
+    /// Returns true if the key was held back as part of a possible paste;
+    /// otherwise any held keys are inserted first and the key is handled as
+    /// usual.
+    pub fn paste_burst_key(&mut self, key: &KeyEvent) -> bool {
+        let now = Instant::now();
+        let c = burst_char(key);
+        if c.is_some_and(|c| self.paste_burst.hold(c, now)) {
+            return true;
+        }
+        self.flush_paste_burst();
+
+        // Enter starts no run: it submits
+        let first = c.filter(|c| *c != '\n' && self.paste_burst_target());
+        self.paste_burst.start(first, now);
+        false
+    }
+
+    /// Insert held keys once no key has come for a while (on ticks)
+    pub fn flush_idle_paste_burst(&mut self) {
+        if self.paste_burst.has_held() && self.paste_burst.is_idle(Instant::now()) {
+            self.flush_paste_burst();
+        }
+    }
+
+    /// Insert the held keys, as a paste if the run was long enough
+    fn flush_paste_burst(&mut self) {
+        let (first, held) = self.paste_burst.take();
+        let Some(first) = first.filter(|_| !held.is_empty()) else {
+            return;
+        };
+
+        if held.chars().count() + 1 < PASTE_BURST_MIN_CHARS {
+            self.textarea.insert_text(&held);
+        } else {
+            // The first key was typed: close what it opened and paste it
+            // with the rest
+            if first == '@' || first == '/' {
+                self.close_file_picker();
+                self.unified_picker.close();
+                self.slash_autocomplete_visible = false;
+            }
+            self.textarea.backspace();
+            self.insert_pasted_text(format!("{}{}", first, held));
+        }
+        self.reset_cursor_blink();
+        self.mark_dirty();
+    }
+
+    /// Whether typed keys go to the input (no modal or prompt takes them)
+    fn paste_burst_target(&self) -> bool {
+        self.focus == Focus::Input
+            && matches!(self.screen, Screen::Conversation | Screen::CommandDeck)
+            && self.dashboard.overlay().is_none()
+            && self.needs_action_target().is_none()
+            && !self.unified_picker.visible
+            && !self.file_picker.visible
+            && !self.folder_picker_visible
+            && !self.thread_switcher.visible
+            && !self.help_dialog_visible
+            && !self.keys_dialog_visible
+            && !self.history_search.active
+            && !self.message_search.active
+            && self.model_picker.is_none()
+            && self.link_editor.is_none()
+            && self.share_dialog.is_none()
+            && self.thread_rename.is_none()
+            && self.thread_delete_confirm.is_none()
+            && self.thread_archive_confirm.is_none()
+            && self.quit_confirm.is_none()
+            && self.bypass_confirm.is_none()
+            && self.rate_limit_modal.is_none()
+            && self.notifications_panel.is_none()
+            && self.permission_detail.is_none()
+            && self.link_hints.is_none()
+            && self.navigate_mode.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    fn input_app() -> App {
+        let mut app = App::default();
+        app.screen = Screen::CommandDeck;
+        app.focus = Focus::Input;
+        app
+    }
+
+    /// Feed keys as the event loop would, typing the ones not held back
+    fn feed(app: &mut App, text: &str) {
+        for c in text.chars() {
+            if !app.paste_burst_key(&key(c)) {
+                app.textarea.insert_char(c);
+            }
+        }
+    }
+
+    #[test]
+    fn test_keys_far_apart_are_not_held() {
+        let mut burst = PasteBurst::default();
+        let start = Instant::now();
+        burst.start(Some('a'), start);
+        assert!(burst.hold('b', start + Duration::from_millis(2)));
+        assert!(!burst.hold('c', start + Duration::from_millis(40)));
+        assert!(burst.is_idle(start + Duration::from_millis(40)));
+
+        // A run that didn't start in the input holds nothing
+        burst.start(None, start);
+        assert!(!burst.hold('x', start));
+    }
+
+    #[test]
+    fn test_long_burst_becomes_one_paste() {
+        let mut app = input_app();
+        let text = "line one of the pasted text\nline two\nline three\nline four";
+        feed(&mut app, text);
+        assert!(app.paste_burst.has_held());
+
+        app.flush_paste_burst();
+        assert!(!app.paste_burst.has_held());
+        // More than three lines: summarized into a paste token
+        assert_eq!(app.textarea.content_expanded(), text);
+        assert_ne!(app.textarea.content(), text);
+    }
+
+    #[test]
+    fn test_burst_starting_with_slash_skips_autocomplete() {
+        let mut app = input_app();
+        app.textarea.insert_char('/');
+        app.slash_autocomplete_visible = true;
+        app.paste_burst.start(Some('/'), Instant::now());
+        for c in "usr/local/bin/spoq --help".chars() {
+            assert!(app.paste_burst_key(&key(c)));
+        }
+
+        app.flush_paste_burst();
+        assert!(!app.slash_autocomplete_visible);
+        assert_eq!(app.textarea.content(), "/usr/local/bin/spoq --help");
+    }
+
+    #[test]
+    fn test_short_run_is_typed() {
+        let mut app = input_app();
+        feed(&mut app, "ok");
+        app.flush_paste_burst();
+        assert_eq!(app.textarea.content(), "ok");
+    }
+
+    #[test]
+    fn test_modal_keys_are_not_held() {
+        let mut app = input_app();
+        app.help_dialog_visible = true;
+        assert!(!app.paste_burst_key(&key('a')));
+        assert!(!app.paste_burst_key(&key('b')));
+        assert!(!app.paste_burst.has_held());
+    }
+}
@@ -0,0 +1,142 @@
+//! Full tool input of a pending permission for the App.
+//!
+//! The permission prompt shows at most `MAX_PROMPT_INPUT_ROWS` rows of what
+//! the tool would do; `e` opens all of it (the whole command, every diff
+//! line) in a scrollable overlay. The request can be answered from there,
+//! and the overlay closes once the request is no longer pending.
+
+use crate::state::session::PermissionRequest;
+use crate::ui::messages::permission_input_rows;
+use crate::ui::permission_detail_width;
+
+use super::App;
+
+/// Open full tool input overlay
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionDetailView {
+    pub permission_id: String,
+    /// Rows scrolled past the top
+    pub scroll: usize,
+}
+
+impl App {
+    /// Show the full tool input of a pending permission
+    pub fn open_permission_detail(&mut self, permission_id: &str) {
+        self.permission_detail = Some(PermissionDetailView {
+            permission_id: permission_id.to_string(),
+            scroll: 0,
+        });
+        self.mark_dirty();
+    }
+
+    /// Close the overlay
+    pub fn close_permission_detail(&mut self) {
+        self.permission_detail = None;
+        self.mark_dirty();
+    }
+
+    /// The request shown, while it's still pending
+    pub fn permission_detail_request(&self) -> Option<&PermissionRequest> {
+        let view = self.permission_detail.as_ref()?;
+        self.dashboard
+            .find_permission_by_id(&view.permission_id)
+            .map(|(_, perm)| perm)
+    }
+
+    /// Scroll the overlay by `delta` rows
+    pub fn scroll_permission_detail(&mut self, delta: isize) {
+        let width = permission_detail_width(self.terminal_width);
+        let Some(rows) = self
+            .permission_detail_request()
+            .map(|perm| permission_input_rows(perm, width).len())
+        else {
+            return;
+        };
+        if let Some(view) = self.permission_detail.as_mut() {
+            view.scroll = view
+                .scroll
+                .saturating_add_signed(delta)
+                .min(rows.saturating_sub(1));
+        }
+        self.mark_dirty();
+    }
+
+    /// Answer the shown request with `y`, `a` or `n`, closing the overlay
+    pub fn answer_permission_detail(&mut self, key: char) {
+        let Some((permission_id, tool_name)) = self
+            .permission_detail_request()
+            .map(|perm| (perm.permission_id.clone(), perm.tool_name.clone()))
+        else {
+            self.close_permission_detail();
+            return;
+        };
+        self.close_permission_detail();
+        match key {
+            'y' | 'Y' => self.approve_permission(&permission_id),
+            'a' | 'A' => self.allow_tool_always(&tool_name, &permission_id),
+            'n' | 'N' => self.deny_permission(&permission_id),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn permission(id: &str) -> PermissionRequest {
+        let command: Vec<String> = (0..40).map(|i| format!("echo {}", i)).collect();
+        PermissionRequest {
+            permission_id: id.to_string(),
+            thread_id: Some("thread-1".to_string()),
+            tool_name: "Bash".to_string(),
+            description: "Run a command".to_string(),
+            context: None,
+            tool_input: Some(serde_json::json!({"command": command.join("\n")})),
+            received_at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn test_e_opens_full_input_of_prompted_permission() {
+        let mut app = App::default();
+        app.dashboard
+            .set_pending_permission("thread-1", permission("perm-1"));
+
+        assert!(app.handle_permission_key('e'));
+        assert_eq!(
+            app.permission_detail_request()
+                .map(|p| p.permission_id.as_str()),
+            Some("perm-1")
+        );
+        // Still pending: `e` doesn't answer
+        assert!(app.dashboard.get_pending_permission("thread-1").is_some());
+    }
+
+    #[test]
+    fn test_scroll_is_clamped_to_rows() {
+        let mut app = App::default();
+        app.terminal_width = 100;
+        app.dashboard
+            .set_pending_permission("thread-1", permission("perm-1"));
+        app.open_permission_detail("perm-1");
+
+        app.scroll_permission_detail(-5);
+        assert_eq!(app.permission_detail.as_ref().unwrap().scroll, 0);
+        app.scroll_permission_detail(1000);
+        // 40 command lines plus the "runs 40 commands" note
+        assert_eq!(app.permission_detail.as_ref().unwrap().scroll, 40);
+    }
+
+    #[test]
+    fn test_resolved_permission_is_not_shown() {
+        let mut app = App::default();
+        app.dashboard
+            .set_pending_permission("thread-1", permission("perm-1"));
+        app.open_permission_detail("perm-1");
+
+        app.dashboard.clear_permission_by_id("perm-1");
+        assert!(app.permission_detail_request().is_none());
+    }
+}
@@ -0,0 +1,253 @@
+//! Reconciliation of pending permission prompts with the backend.
+//!
+//! A permission can be answered somewhere else (the web UI, another TUI). The
+//! backend then sends `permission_resolved` over the WebSocket and the prompt
+//! is dropped here. In case that event was missed (e.g. while reconnecting),
+//! prompts left unanswered for a while trigger a re-query of the backend's
+//! pending permissions, and anything it no longer lists is dropped. Prompts
+//! older than the server's own timeout are dropped the same way, only once
+//! the backend confirms they're gone.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::models::dashboard::ThreadStatus;
+
+use super::{App, AppMessage};
+
+/// Unanswered prompts older than this are re-checked with the backend
+const STALE_PERMISSION_SECS: u64 = 60;
+
+/// Minimum time between pending-permission re-queries
+const PERMISSION_RECHECK_INTERVAL_SECS: u64 = 30;
+
+/// The server gives up on a permission after this long
+const PERMISSION_EXPIRY_SECS: u64 = 300;
+
+impl App {
+    /// Drop a prompt that was answered outside this client
+    pub fn handle_permission_resolved(&mut self, permission_id: &str, allowed: Option<bool>) {
+        // Our own answers are echoed back after the prompt is already gone
+        let Some(thread_id) = self.drop_pending_permission(permission_id) else {
+            return;
+        };
+        tracing::info!(
+            "Permission {} for thread {} resolved elsewhere (allowed: {:?})",
+            permission_id,
+            thread_id,
+            allowed
+        );
+        if let Some(allowed) = allowed {
+            self.update_thread_metrics(&thread_id, |m| m.record_permission(allowed));
+        }
+
+        if self.active_thread_id.as_deref() == Some(thread_id.as_str()) {
+            let notice = match allowed {
+                Some(true) => "Permission approved from another client",
+                Some(false) => "Permission denied from another client",
+                None => "Permission request was cancelled",
+            };
+            self.set_status_notice(notice.to_string(), Duration::from_secs(3));
+        }
+    }
+
+    /// Re-query the backend about stale (or timed-out) prompts.
+    ///
+    /// Called on every tick; cheap when nothing is pending.
+    pub fn check_stale_permissions(&mut self) {
+        if !self.dashboard.has_pending_permission() {
+            return;
+        }
+
+        let recently_checked = self
+            .last_permission_recheck
+            .is_some_and(|at| at.elapsed().as_secs() < PERMISSION_RECHECK_INTERVAL_SECS);
+        if self.permission_recheck_in_flight || recently_checked {
+            return;
+        }
+        let has_stale = self
+            .dashboard
+            .pending_permissions_iter()
+            .any(|(_, perm)| perm.received_at.elapsed().as_secs() >= STALE_PERMISSION_SECS);
+        if has_stale {
+            self.recheck_pending_permissions();
+        }
+    }
+
+    /// Re-query the backend's pending permissions in the background.
+    ///
+    /// Sends `PendingPermissionsFetched` (or `PendingPermissionsFetchFailed`).
+    pub(super) fn recheck_pending_permissions(&mut self) {
+        let requested_at = Instant::now();
+        self.permission_recheck_in_flight = true;
+        self.last_permission_recheck = Some(requested_at);
+
+        let client = Arc::clone(&self.client);
+        let message_tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            let msg = match client.fetch_pending_permissions().await {
+                Ok(pending) => AppMessage::PendingPermissionsFetched {
+                    permission_ids: pending.into_iter().map(|p| p.permission_id).collect(),
+                    requested_at,
+                },
+                Err(e) => AppMessage::PendingPermissionsFetchFailed {
+                    error: e.to_string(),
+                },
+            };
+            let _ = message_tx.send(msg);
+        });
+    }
+
+    /// Drop prompts the backend no longer lists as pending.
+    ///
+    /// Prompts received after `requested_at` are kept: the backend response
+    /// can't know about them yet.
+    pub fn reconcile_pending_permissions(
+        &mut self,
+        permission_ids: &[String],
+        requested_at: Instant,
+    ) {
+        self.permission_recheck_in_flight = false;
+
+        let still_pending: HashSet<&str> = permission_ids.iter().map(String::as_str).collect();
+        let resolved: Vec<(String, bool)> = self
+            .dashboard
+            .pending_permissions_iter()
+            .filter(|(_, perm)| {
+                perm.received_at <= requested_at
+                    && !still_pending.contains(perm.permission_id.as_str())
+            })
+            .map(|(_, perm)| {
+                let expired = perm.received_at.elapsed().as_secs() >= PERMISSION_EXPIRY_SECS;
+                (perm.permission_id.clone(), expired)
+            })
+            .collect();
+
+        for (permission_id, _) in &resolved {
+            tracing::info!("Clearing stale permission {}", permission_id);
+            self.drop_pending_permission(permission_id);
+        }
+        if !resolved.is_empty() {
+            let notice = if resolved.iter().all(|(_, expired)| *expired) {
+                "Permission request timed out"
+            } else {
+                "Cleared permission prompts answered elsewhere"
+            };
+            self.set_status_notice(notice.to_string(), Duration::from_secs(3));
+        }
+    }
+
+    /// Remove a pending prompt and the UI state attached to it.
+    ///
+    /// Returns the thread it belonged to, or None if it wasn't pending.
+    fn drop_pending_permission(&mut self, permission_id: &str) -> Option<String> {
+        let thread_id = self.dashboard.clear_permission_by_id(permission_id)?;
+
+        self.dashboard.clear_pending_question(&thread_id);
+        self.dashboard
+            .update_thread_status(&thread_id, ThreadStatus::Running, None);
+        if self
+            .dashboard
+            .overlay()
+            .is_some_and(|overlay| overlay.thread_id() == thread_id)
+        {
+            self.dashboard.collapse_overlay();
+        }
+        if self.active_thread_id.as_deref() == Some(thread_id.as_str()) {
+            self.question_state.reset();
+        }
+        self.mark_dirty();
+        Some(thread_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::PermissionRequest;
+
+    fn permission(permission_id: &str, thread_id: &str, age_secs: u64) -> PermissionRequest {
+        PermissionRequest {
+            permission_id: permission_id.to_string(),
+            thread_id: Some(thread_id.to_string()),
+            tool_name: "Bash".to_string(),
+            description: "Run a command".to_string(),
+            context: None,
+            tool_input: None,
+            received_at: Instant::now() - Duration::from_secs(age_secs),
+        }
+    }
+
+    #[test]
+    fn test_resolved_elsewhere_clears_prompt() {
+        let mut app = App::default();
+        app.dashboard
+            .set_pending_permission("thread-1", permission("perm-1", "thread-1", 0));
+
+        app.handle_message(AppMessage::PermissionResolved {
+            permission_id: "perm-1".to_string(),
+            allowed: Some(true),
+        });
+
+        assert!(app.dashboard.get_pending_permission("thread-1").is_none());
+
+        // Unknown IDs (e.g. our own answer echoed back) are ignored
+        app.handle_permission_resolved("perm-1", Some(true));
+    }
+
+    #[test]
+    fn test_reconcile_keeps_pending_and_newer_prompts() {
+        let mut app = App::default();
+        app.dashboard
+            .set_pending_permission("thread-1", permission("perm-1", "thread-1", 90));
+        app.dashboard
+            .set_pending_permission("thread-2", permission("perm-2", "thread-2", 90));
+        let requested_at = Instant::now();
+        app.dashboard
+            .set_pending_permission("thread-3", permission("perm-3", "thread-3", 0));
+        app.permission_recheck_in_flight = true;
+
+        app.handle_message(AppMessage::PendingPermissionsFetched {
+            permission_ids: vec!["perm-2".to_string()],
+            requested_at,
+        });
+
+        assert!(!app.permission_recheck_in_flight);
+        assert!(app.dashboard.get_pending_permission("thread-1").is_none());
+        assert!(app.dashboard.get_pending_permission("thread-2").is_some());
+        assert!(app.dashboard.get_pending_permission("thread-3").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_check_stale_permissions_rechecks_before_expiring() {
+        let mut app = App::default();
+        app.dashboard.set_pending_permission(
+            "thread-1",
+            permission("perm-1", "thread-1", PERMISSION_EXPIRY_SECS),
+        );
+        app.dashboard
+            .set_pending_permission("thread-2", permission("perm-2", "thread-2", 90));
+
+        app.check_stale_permissions();
+
+        // Nothing is dropped until the backend answers
+        assert!(app.dashboard.get_pending_permission("thread-1").is_some());
+        assert!(app.dashboard.get_pending_permission("thread-2").is_some());
+        assert!(app.permission_recheck_in_flight);
+        assert!(app.last_permission_recheck.is_some());
+
+        // A failed re-query keeps them
+        app.handle_message(AppMessage::PendingPermissionsFetchFailed {
+            error: "offline".to_string(),
+        });
+        assert!(app.dashboard.get_pending_permission("thread-1").is_some());
+
+        app.handle_message(AppMessage::PendingPermissionsFetched {
+            permission_ids: vec!["perm-2".to_string()],
+            requested_at: Instant::now(),
+        });
+        assert!(app.dashboard.get_pending_permission("thread-1").is_none());
+        assert!(app.dashboard.get_pending_permission("thread-2").is_some());
+    }
+}
@@ -28,12 +28,24 @@ pub enum PermissionResponseResult {
     SentViaWebSocket,
     /// Sent via HTTP fallback
     SentViaHttpFallback,
+    /// Queued until the WebSocket connection is back
+    Queued,
     /// Permission expired before sending
     Expired,
     /// Failed to send (connection lost and no HTTP fallback)
     Failed(String),
 }
 
+impl PermissionResponseResult {
+    /// Whether the response went out (or is being retried in the background)
+    pub fn was_sent(&self) -> bool {
+        matches!(
+            self,
+            Self::SentViaWebSocket | Self::SentViaHttpFallback | Self::Queued
+        )
+    }
+}
+
 impl App {
     /// Check if a pending permission has expired
     ///
@@ -90,9 +102,10 @@ impl App {
     ///
     /// This method:
     /// 1. Checks if permission has expired (>50s elapsed)
-    /// 2. Tries to send via WebSocket
-    /// 3. If WS fails, retries once after 500ms
-    /// 4. If still fails, falls back to HTTP if available
+    /// 2. Queues the response while the WebSocket is reconnecting
+    /// 3. Tries to send via WebSocket
+    /// 4. If WS fails, retries once after 500ms
+    /// 5. If still fails, falls back to HTTP if available
     fn send_permission_response(
         &mut self,
         permission_id: &str,
@@ -107,6 +120,22 @@ impl App {
             return PermissionResponseResult::Expired;
         }
 
+        // Hold the response until the connection is back
+        if self.queues_ws_messages() {
+            self.queue_ws_message(WsOutgoingMessage::CommandResponse(WsCommandResponse {
+                type_: "command_response".to_string(),
+                request_id: permission_id.to_string(),
+                result: WsCommandResult {
+                    status: "success".to_string(),
+                    data: WsPermissionData {
+                        allowed,
+                        message: None,
+                    },
+                },
+            }));
+            return PermissionResponseResult::Queued;
+        }
+
         // Try WebSocket first
         match self.send_ws_permission_response(permission_id, allowed) {
             Ok(()) => return PermissionResponseResult::SentViaWebSocket,
@@ -173,6 +202,9 @@ impl App {
     /// Approve a pending permission (user pressed 'y')
     pub fn approve_permission(&mut self, permission_id: &str) {
         let result = self.send_permission_response(permission_id, true);
+        if result.was_sent() {
+            self.record_permission_answer(permission_id, true);
+        }
 
         match result {
             PermissionResponseResult::SentViaWebSocket => {
@@ -184,6 +216,12 @@ impl App {
                     permission_id
                 );
             }
+            PermissionResponseResult::Queued => {
+                debug!(
+                    "Permission {} approval queued until reconnect",
+                    permission_id
+                );
+            }
             PermissionResponseResult::Expired => {
                 warn!("Permission {} expired - could not approve", permission_id);
                 // Could set an error notification here if needed
@@ -199,6 +237,9 @@ impl App {
     /// Deny a pending permission (user pressed 'n')
     pub fn deny_permission(&mut self, permission_id: &str) {
         let result = self.send_permission_response(permission_id, false);
+        if result.was_sent() {
+            self.record_permission_answer(permission_id, false);
+        }
 
         match result {
             PermissionResponseResult::SentViaWebSocket => {
@@ -207,6 +248,9 @@ impl App {
             PermissionResponseResult::SentViaHttpFallback => {
                 debug!("Permission {} denial sent via HTTP fallback", permission_id);
             }
+            PermissionResponseResult::Queued => {
+                debug!("Permission {} denial queued until reconnect", permission_id);
+            }
             PermissionResponseResult::Expired => {
                 warn!("Permission {} expired - could not deny", permission_id);
             }
@@ -268,21 +312,22 @@ impl App {
     pub fn handle_permission_key(&mut self, key: char) -> bool {
         info!("handle_permission_key called with key: '{}'", key);
 
-        // Check top thread type - if UserInput, Y/N/A should do nothing
+        // Check target thread type - if UserInput, Y/N/A should do nothing
         // (A key will be used to open dialog instead)
         if matches!(key, 'y' | 'Y' | 'n' | 'N' | 'a' | 'A') {
-            if let Some((_, wf)) = self.dashboard.get_top_needs_action_thread() {
+            if let Some((_, wf)) = self.needs_action_target() {
                 if matches!(wf, WaitingFor::UserInput) {
-                    info!("Ignoring Y/N/A key because top thread is UserInput");
-                    return false; // Ignore Y/N/A when top thread is UserInput
+                    info!("Ignoring Y/N/A key because target thread is UserInput");
+                    return false; // Ignore Y/N/A when target thread is UserInput
                 }
             }
         }
 
-        // Find a pending permission from the top thread needing action,
-        // or fall back to searching all threads
-        let perm_info = if let Some((thread_id, _)) = self.dashboard.get_top_needs_action_thread() {
-            // Try to get permission for the top thread
+        // Find a pending permission from the targeted thread needing action
+        // (selected on the CommandDeck, else the top one), or fall back to
+        // searching all threads
+        let perm_info = if let Some((thread_id, _)) = self.needs_action_target() {
+            // Try to get permission for the target thread
             self.dashboard
                 .get_pending_permission(&thread_id)
                 .map(|p| (p.permission_id.clone(), p.tool_name.clone()))
@@ -323,6 +368,11 @@ impl App {
                     self.deny_permission(&permission_id);
                     true
                 }
+                'e' | 'E' => {
+                    info!("User pressed 'e' - showing the full tool input");
+                    self.open_permission_detail(&permission_id);
+                    true
+                }
                 _ => {
                     // Consume all non-permission keys when permission modal is active.
                     // This prevents any fallback handling that might insert chars into
@@ -482,12 +532,12 @@ impl App {
     // AskUserQuestion Navigation Methods
     // ========================================================================
 
-    /// Find an AskUserQuestion permission from the top needs-action thread
+    /// Find an AskUserQuestion permission from the targeted needs-action thread
     ///
     /// Returns the permission if found, None otherwise.
     fn find_ask_user_question_permission(&self) -> Option<&crate::state::PermissionRequest> {
-        // Only check the TOP needs-action thread
-        if let Some((thread_id, _)) = self.dashboard.get_top_needs_action_thread() {
+        // Only check the targeted needs-action thread
+        if let Some((thread_id, _)) = self.needs_action_target() {
             if let Some(perm) = self.dashboard.get_pending_permission(&thread_id) {
                 if perm.tool_name == "AskUserQuestion" {
                     return Some(perm);
@@ -497,10 +547,11 @@ impl App {
         None
     }
 
-    /// Check if there is a pending AskUserQuestion permission on the top needs-action thread
+    /// Check if there is a pending AskUserQuestion permission on the targeted
+    /// needs-action thread
     pub fn is_ask_user_question_pending(&self) -> bool {
-        // Only check the TOP needs-action thread
-        if let Some((thread_id, _)) = self.dashboard.get_top_needs_action_thread() {
+        // Only check the targeted needs-action thread
+        if let Some((thread_id, _)) = self.needs_action_target() {
             if let Some(perm) = self.dashboard.get_pending_permission(&thread_id) {
                 if perm.tool_name == "AskUserQuestion" {
                     if let Some(ref tool_input) = perm.tool_input {
@@ -581,13 +632,17 @@ impl App {
     /// Move to the previous option in the current question
     pub fn question_prev_option(&mut self) {
         let option_count = self.get_current_option_count();
+        let allow_other = self.question_state.current_constraints().allow_other;
 
         if let Some(current) = self.question_state.current_selection() {
             if current > 0 {
                 self.question_state.set_current_selection(Some(current - 1));
-            } else {
+            } else if allow_other {
                 // Wrap to "Other" (None)
                 self.question_state.set_current_selection(None);
+            } else if option_count > 0 {
+                self.question_state
+                    .set_current_selection(Some(option_count - 1));
             }
         } else {
             // Currently on "Other", move to last option
@@ -606,13 +661,16 @@ impl App {
     /// Move to the next option in the current question
     pub fn question_next_option(&mut self) {
         let option_count = self.get_current_option_count();
+        let allow_other = self.question_state.current_constraints().allow_other;
 
         if let Some(current) = self.question_state.current_selection() {
-            if current < option_count - 1 {
+            if current + 1 < option_count {
                 self.question_state.set_current_selection(Some(current + 1));
-            } else {
+            } else if allow_other {
                 // Wrap to "Other" (None)
                 self.question_state.set_current_selection(None);
+            } else {
+                self.question_state.set_current_selection(Some(0));
             }
         } else {
             // Currently on "Other", wrap to first option
@@ -636,18 +694,54 @@ impl App {
         }
     }
 
+    /// Pick option `number` (1-based) of the current question by digit key
+    ///
+    /// Selects it, toggling it on multi-select questions. Returns false if
+    /// there's no such option.
+    pub fn question_select_number(&mut self, number: usize) -> bool {
+        let option_count = self.get_current_option_count();
+        let multi_select = self.is_current_question_multi_select();
+        let selected = self
+            .question_state
+            .select_number(number, option_count, multi_select);
+        if selected {
+            self.mark_dirty();
+            debug!("Picked option {} by number", number);
+        }
+        selected
+    }
+
     /// Handle Enter key in question UI
     ///
     /// For single questions: submits immediately
     /// For multiple questions: marks current as answered and advances to next tab
-    ///                         Only submits when all questions are answered
+    ///                         Only submits when all questions are answered,
+    ///                         or at once while the defaults are untouched
+    ///
+    /// Answers that don't meet the question's constraints (selection count,
+    /// "Other" length) are refused with a hint in `question_state`.
     ///
     /// Returns true if a response was sent
     pub fn question_confirm(&mut self) -> bool {
         let num_questions = self.get_question_count();
 
+        // Enter on an untouched prompt with defaults submits them as they stand
+        if num_questions > 1 && self.question_state.submits_defaults() {
+            if let Some((index, error)) = self.first_invalid_question_answer() {
+                self.question_state.tab_index = index;
+                self.question_state.validation_error = Some(error);
+                self.mark_dirty();
+                return false;
+            }
+            debug!("Submitting question defaults");
+            return self.submit_question_answer();
+        }
+
         // Check if "Other" is selected and not in text input mode
-        if self.question_state.current_selection().is_none() && !self.question_state.other_active {
+        if self.question_state.current_selection().is_none()
+            && !self.question_state.other_active
+            && self.question_state.current_constraints().allow_other
+        {
             // Activate "Other" text input mode
             self.question_state.other_active = true;
             self.mark_dirty();
@@ -662,10 +756,19 @@ impl App {
                 // Don't submit/advance with empty "Other" text
                 return false;
             }
-            // Deactivate "Other" mode as we're confirming this answer
-            self.question_state.other_active = false;
         }
 
+        let tab_index = self.question_state.tab_index;
+        let multi_select = self.is_current_question_multi_select();
+        if let Some(error) = self.question_state.answer_error(tab_index, multi_select) {
+            debug!("Refusing answer to question {}: {}", tab_index, error);
+            self.question_state.validation_error = Some(error);
+            self.mark_dirty();
+            return false;
+        }
+        // Deactivate "Other" mode as we're confirming this answer
+        self.question_state.other_active = false;
+
         // For single question, submit immediately
         if num_questions == 1 {
             return self.submit_question_answer();
@@ -680,6 +783,13 @@ impl App {
 
         // Check if all questions are now answered
         if self.question_state.all_answered() {
+            // Answers confirmed earlier may have changed since
+            if let Some((index, error)) = self.first_invalid_question_answer() {
+                self.question_state.tab_index = index;
+                self.question_state.validation_error = Some(error);
+                self.mark_dirty();
+                return false;
+            }
             debug!("All questions answered, submitting");
             return self.submit_question_answer();
         }
@@ -695,6 +805,20 @@ impl App {
         false
     }
 
+    /// First question (index and reason) whose answer doesn't meet its
+    /// constraints
+    fn first_invalid_question_answer(&self) -> Option<(usize, String)> {
+        let data = self
+            .find_ask_user_question_permission()
+            .and_then(|perm| perm.tool_input.as_ref())
+            .and_then(parse_ask_user_question)?;
+        data.questions.iter().enumerate().find_map(|(i, question)| {
+            self.question_state
+                .answer_error(i, question.multi_select)
+                .map(|error| (i, error))
+        })
+    }
+
     /// Cancel "Other" text input mode
     pub fn question_cancel_other(&mut self) {
         if self.question_state.other_active {
@@ -856,25 +980,13 @@ impl App {
         );
     }
 
-    /// Send question answers via WebSocket
+    /// Send question answers via WebSocket, queueing them while the
+    /// connection is down
     fn send_question_response(
-        &self,
+        &mut self,
         request_id: &str,
         answers: std::collections::HashMap<String, String>,
     ) {
-        let sender = match &self.ws_sender {
-            Some(s) => s,
-            None => {
-                warn!("No WebSocket sender for question response");
-                return;
-            }
-        };
-
-        if self.ws_connection_state != WsConnectionState::Connected {
-            warn!("WebSocket not connected for question response");
-            return;
-        }
-
         // Convert answers to JSON Value
         let answers_value = serde_json::to_value(&answers).unwrap_or_default();
 
@@ -890,6 +1002,23 @@ impl App {
             },
         };
 
+        if self.queues_ws_messages() {
+            info!(
+                "WebSocket not connected, queueing question response {}",
+                request_id
+            );
+            self.queue_ws_message(WsOutgoingMessage::CommandResponse(response));
+            return;
+        }
+
+        let sender = match &self.ws_sender {
+            Some(s) => s,
+            None => {
+                warn!("No WebSocket sender for question response");
+                return;
+            }
+        };
+
         if let Err(e) = sender.try_send(WsOutgoingMessage::CommandResponse(response)) {
             error!("Failed to send question response: {}", e);
         } else {
@@ -901,17 +1030,22 @@ impl App {
     // Dialog Opening Helpers
     // ========================================================================
 
-    /// Open the AskUserQuestion dialog for the first user input thread
+    /// Open the AskUserQuestion dialog for the selected user input thread
     ///
-    /// This method finds the first thread waiting for user input and expands it.
+    /// This method expands the selected needs-action thread if it waits for
+    /// user input, else the first thread that does.
     /// Only opens if no overlay is currently active.
     ///
     /// Returns true if a dialog was opened, false otherwise.
     pub fn open_ask_user_question_dialog(&mut self) -> bool {
         // Check if an overlay is NOT already open
         if self.dashboard.overlay().is_none() {
-            // Find first thread waiting for user input
-            if let Some(thread_id) = self.dashboard.find_first_user_input_thread() {
+            // The selected thread if it waits for user input, else the first one
+            let thread_id = match self.needs_action_target() {
+                Some((thread_id, WaitingFor::UserInput)) => Some(thread_id),
+                _ => self.dashboard.find_first_user_input_thread(),
+            };
+            if let Some(thread_id) = thread_id {
                 // Use a reasonable anchor_y for keyboard-triggered overlay
                 // (middle of screen is typical for non-click interactions)
                 let computed_anchor_y = self.terminal_height / 2;
@@ -961,6 +1095,7 @@ mod tests {
         match msg {
             WsOutgoingMessage::CommandResponse(resp) => resp,
             WsOutgoingMessage::CancelPermission(_) => panic!("Expected CommandResponse"),
+            WsOutgoingMessage::CancelStream(_) => panic!("Expected CommandResponse"),
             WsOutgoingMessage::PlanApprovalResponse(_) => panic!("Expected CommandResponse"),
             WsOutgoingMessage::ClaudeLoginResponse(_) => panic!("Expected CommandResponse"),
             WsOutgoingMessage::ClaudeAuthTokenResponse(_) => panic!("Expected CommandResponse"),
@@ -969,6 +1104,8 @@ mod tests {
             WsOutgoingMessage::ClaudeAccountAddRequest(_) => panic!("Expected CommandResponse"),
             WsOutgoingMessage::ClaudeAccountRemoveRequest(_) => panic!("Expected CommandResponse"),
             WsOutgoingMessage::ClaudeAccountSelectRequest(_) => panic!("Expected CommandResponse"),
+            WsOutgoingMessage::NotificationDismissed(_) => panic!("Expected CommandResponse"),
+            WsOutgoingMessage::Presence(_) => panic!("Expected CommandResponse"),
         }
     }
 
@@ -996,6 +1133,11 @@ mod tests {
             status: Some(ThreadStatus::Waiting),
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.dashboard.add_thread(thread);
 
@@ -1022,6 +1164,7 @@ mod tests {
         let results = vec![
             PermissionResponseResult::SentViaWebSocket,
             PermissionResponseResult::SentViaHttpFallback,
+            PermissionResponseResult::Queued,
             PermissionResponseResult::Expired,
             PermissionResponseResult::Failed("test error".to_string()),
         ];
@@ -1088,6 +1231,21 @@ mod tests {
         assert!(result.unwrap_err().contains("not connected"));
     }
 
+    #[test]
+    fn test_send_permission_response_queued_while_reconnecting() {
+        let mut app = App::default();
+        let (tx, _rx) = mpsc::channel(10);
+        app.ws_sender = Some(tx);
+        app.ws_connection_state = WsConnectionState::Reconnecting { attempt: 1 };
+        app.dashboard
+            .set_pending_permission(TEST_THREAD_ID, create_test_permission("perm-123"));
+
+        let result = app.send_permission_response("perm-123", true);
+        assert!(matches!(result, PermissionResponseResult::Queued));
+        assert!(result.was_sent());
+        assert_eq!(app.ws_queued_messages.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_send_ws_permission_response_success() {
         let (app, mut rx) = create_test_app_with_ws();
@@ -1226,6 +1384,30 @@ mod tests {
         assert!(msg.result.data.allowed);
     }
 
+    #[tokio::test]
+    async fn test_handle_permission_key_answers_selected_thread() {
+        let (mut app, mut rx) = create_test_app_with_ws();
+        for id in ["t-1", "t-2", "t-3"] {
+            let mut perm = create_test_permission(&format!("perm-{}", id));
+            perm.thread_id = Some(id.to_string());
+            setup_thread_with_permission(&mut app, id, perm);
+        }
+        let waiting = app.dashboard.needs_action_threads();
+        assert_eq!(waiting.len(), 3);
+
+        app.move_needs_action_selection(1);
+        let selected = waiting[1].0.clone();
+        assert!(app.handle_permission_key('n'));
+
+        let msg = extract_command_response(rx.recv().await.unwrap());
+        assert_eq!(msg.request_id, format!("perm-{}", selected));
+        assert!(!msg.result.data.allowed);
+        assert!(app.dashboard.get_pending_permission(&selected).is_none());
+        for (thread_id, _) in [&waiting[0], &waiting[2]] {
+            assert!(app.dashboard.get_pending_permission(thread_id).is_some());
+        }
+    }
+
     #[test]
     fn test_handle_permission_key_no_pending() {
         let mut app = App::default();
@@ -1860,6 +2042,49 @@ mod tests {
         assert_eq!(app.question_state.tab_index, 1); // Back to unanswered question
     }
 
+    #[test]
+    fn test_question_confirm_enforces_constraints() {
+        let (mut app, _rx) = create_test_app_with_ws();
+        let mut perm = create_ask_user_question_permission("perm-constrained");
+        perm.tool_input = Some(serde_json::json!({
+            "questions": [
+                {
+                    "question": "Pick files",
+                    "header": "Files",
+                    "options": [
+                        {"label": "a.rs", "description": ""},
+                        {"label": "b.rs", "description": ""}
+                    ],
+                    "multiSelect": true,
+                    "minSelections": 1,
+                    "allowOther": false,
+                    "otherMaxLength": 4
+                }
+            ],
+            "answers": {}
+        }));
+        setup_thread_with_permission(&mut app, TEST_THREAD_ID, perm);
+        app.init_question_state();
+
+        // Nothing toggled: refused with a hint
+        assert!(!app.question_confirm());
+        assert_eq!(
+            app.question_state.validation_error.as_deref(),
+            Some("Select at least 1 option")
+        );
+        assert!(app.dashboard.get_pending_permission(TEST_THREAD_ID).is_some());
+
+        // "Other" is skipped when not allowed
+        app.question_next_option();
+        app.question_next_option();
+        assert_eq!(app.question_state.current_selection(), Some(0));
+
+        // Toggling clears the hint and the answer goes through
+        app.question_toggle_option();
+        assert!(app.question_state.validation_error.is_none());
+        assert!(app.question_confirm());
+    }
+
     // ============= Dashboard Question Submission Tests =============
 
     #[tokio::test]
@@ -1878,6 +2103,8 @@ mod tests {
                     description: "First option".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -1932,6 +2159,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.dashboard.add_thread(thread);
 
@@ -1944,6 +2176,8 @@ mod tests {
                     description: "Option A".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -0,0 +1,146 @@
+//! Background refresh of the picker data.
+//!
+//! Repos, threads and folders are preloaded at startup and cached in
+//! `picker_cache` (see `crate::state::cache` for the TTLs). Once a kind is
+//! older than its TTL, `refresh_stale_picker_data` (called every tick) fetches
+//! it again in the background. The @ picker opens with whatever is cached,
+//! stale or not, and its section headers show how old the items are while a
+//! refresh is pending ("updated 2h ago – refreshing…"). Ctrl+R or F5 in the
+//! picker refreshes everything at once.
+
+use crate::models::picker::PickerSection;
+use crate::state::PickerDataKind;
+
+use super::App;
+
+/// Picker section showing a kind of data
+fn section_for(kind: PickerDataKind) -> PickerSection {
+    match kind {
+        PickerDataKind::Repos => PickerSection::Repos,
+        PickerDataKind::Threads => PickerSection::Threads,
+        PickerDataKind::Folders => PickerSection::Folders,
+    }
+}
+
+impl App {
+    /// Start background refreshes for picker data past its TTL.
+    ///
+    /// Does nothing until the startup preload has run (safe mode skips it).
+    pub fn refresh_stale_picker_data(&mut self) {
+        if !self.picker_cache.preload_started {
+            return;
+        }
+        let mut started = false;
+        for kind in PickerDataKind::ALL {
+            if self.picker_cache.refresh_due(kind) {
+                started |= self.request_picker_data(kind);
+            }
+        }
+        if started && self.unified_picker.visible {
+            self.sync_picker_freshness();
+            self.mark_dirty();
+        }
+    }
+
+    /// Refresh all picker data now, regardless of age (Ctrl+R / F5 in the
+    /// @ picker)
+    pub fn refresh_picker_data(&mut self) {
+        for kind in PickerDataKind::ALL {
+            self.request_picker_data(kind);
+        }
+        self.sync_picker_freshness();
+        self.mark_dirty();
+    }
+
+    /// Fetch one kind of picker data in the background.
+    ///
+    /// Returns false if a request for it is already in flight.
+    pub(super) fn request_picker_data(&mut self, kind: PickerDataKind) -> bool {
+        if !self.picker_cache.start_refresh(kind) {
+            return false;
+        }
+        match kind {
+            PickerDataKind::Repos => self.load_picker_repos(),
+            PickerDataKind::Threads => self.load_picker_threads(),
+            PickerDataKind::Folders => self.load_picker_folders(),
+        }
+        true
+    }
+
+    /// Copy each kind's age and refresh state to its picker section
+    pub(super) fn sync_picker_freshness(&mut self) {
+        for kind in PickerDataKind::ALL {
+            let updated_at = self.picker_cache.entry(kind).map(|c| c.cached_at);
+            let stale = updated_at.is_some() && self.picker_cache.is_stale(kind);
+            let refreshing = self.picker_cache.is_refreshing(kind);
+
+            let section = self.unified_picker.section_state_mut(section_for(kind));
+            section.updated_at = updated_at;
+            section.stale = stale;
+            section.refreshing = refreshing;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppMessage;
+    use crate::models::picker::PickerItem;
+
+    fn folder(name: &str) -> PickerItem {
+        PickerItem::Folder {
+            name: name.to_string(),
+            path: format!("/srv/{}", name),
+        }
+    }
+
+    #[test]
+    fn test_refresh_result_keeps_query_and_freshness() {
+        let mut app = App::default();
+        app.unified_picker.open();
+        app.unified_picker.set_query("api".to_string());
+        app.picker_cache.start_refresh(PickerDataKind::Folders);
+        app.sync_picker_freshness();
+        assert!(app.unified_picker.folders.refreshing);
+
+        app.handle_message(AppMessage::UnifiedPickerFoldersLoaded(vec![
+            folder("api"),
+            folder("web"),
+        ]));
+
+        let folders = &app.unified_picker.folders;
+        assert_eq!(folders.items, vec![folder("api")]);
+        assert!(!folders.refreshing);
+        assert!(!folders.stale);
+        assert!(folders.updated_at.is_some());
+    }
+
+    #[test]
+    fn test_failed_refresh_keeps_cached_items() {
+        let mut app = App::default();
+        app.picker_cache.set_repos(vec![PickerItem::Repo {
+            name: "acme/api".to_string(),
+            local_path: None,
+            url: "https://github.com/acme/api".to_string(),
+        }]);
+        app.unified_picker.open();
+        app.unified_picker
+            .repos
+            .set_items(app.picker_cache.get_repos().unwrap().clone());
+        app.picker_cache.start_refresh(PickerDataKind::Repos);
+
+        app.handle_message(AppMessage::UnifiedPickerReposFailed("offline".to_string()));
+
+        assert!(!app.picker_cache.is_refreshing(PickerDataKind::Repos));
+        assert_eq!(app.unified_picker.repos.items.len(), 1);
+        assert_eq!(app.unified_picker.repos.error.as_deref(), Some("offline"));
+    }
+
+    #[test]
+    fn test_no_background_refresh_before_preload() {
+        let mut app = App::default();
+        app.refresh_stale_picker_data();
+        assert!(!app.picker_cache.is_refreshing(PickerDataKind::Repos));
+    }
+}
@@ -0,0 +1,78 @@
+//! Polling mode for the App.
+//!
+//! When a stream request fails at the transport level while the WebSocket is
+//! down too, `run_stream_request` retries it over the polling transport
+//! (`ConductorClient::stream_polling`) and reports `PollingModeChanged(true)`.
+//! From then on new streams go straight to polling and the conversation
+//! header shows "polling mode". A WebSocket connection ends polling mode.
+
+use std::time::Duration;
+
+use crate::websocket::WsConnectionState;
+
+use super::App;
+
+/// How a stream request reaches the backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamTransport {
+    /// SSE only
+    Sse,
+    /// SSE, falling back to polling on a transport error
+    SseOrPolling,
+    /// Polling only
+    Polling,
+}
+
+impl App {
+    /// Transport for the next stream request
+    pub fn stream_transport(&self) -> StreamTransport {
+        if self.polling_mode {
+            StreamTransport::Polling
+        } else if self.ws_connection_state != WsConnectionState::Connected {
+            StreamTransport::SseOrPolling
+        } else {
+            StreamTransport::Sse
+        }
+    }
+
+    /// Enter or leave polling mode, with a status notice on change
+    pub fn set_polling_mode(&mut self, polling: bool) {
+        if self.polling_mode == polling {
+            return;
+        }
+        self.polling_mode = polling;
+        let notice = if polling {
+            tracing::warn!("Streaming unavailable, switching to polling mode");
+            "Streaming is blocked on this network: using polling mode (slower updates)"
+        } else {
+            tracing::info!("Leaving polling mode");
+            "Connection restored: streaming again"
+        };
+        self.set_status_notice(notice.to_string(), Duration::from_secs(6));
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::AppMessage;
+
+    #[test]
+    fn test_transport_follows_websocket_and_polling_mode() {
+        let mut app = App::default();
+        app.ws_connection_state = WsConnectionState::Connected;
+        assert_eq!(app.stream_transport(), StreamTransport::Sse);
+
+        app.ws_connection_state = WsConnectionState::Disconnected;
+        assert_eq!(app.stream_transport(), StreamTransport::SseOrPolling);
+
+        app.handle_message(AppMessage::PollingModeChanged(true));
+        assert!(app.polling_mode);
+        assert_eq!(app.stream_transport(), StreamTransport::Polling);
+        assert!(app
+            .status_notice
+            .as_deref()
+            .is_some_and(|n| n.contains("polling mode")));
+    }
+}
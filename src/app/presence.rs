@@ -0,0 +1,292 @@
+//! Multi-client presence for the App.
+//!
+//! When a thread is also open in the web UI or another TUI, the backend relays
+//! lightweight `presence_update` events between the clients. The conversation
+//! header shows who else has the thread open and who is typing, and we
+//! announce our own presence the same way (viewing, typing, sent, left).
+//! Submitting right after another client sent a message warns first so the
+//! two turns don't cross; pressing Enter again sends anyway.
+
+use std::time::{Duration, Instant};
+
+use tracing::{debug, warn};
+
+use crate::websocket::{WsConnectionState, WsOutgoingMessage, WsPresence, WsPresenceState};
+
+use super::{App, PeerPresence, Screen};
+
+/// How long a typing indicator lasts without a fresh typing event
+const TYPING_INDICATOR_DURATION: Duration = Duration::from_secs(5);
+
+/// Minimum gap between our own typing events
+const TYPING_SEND_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Another client's message this recent makes a submit warn first
+const CROSSED_TURN_WINDOW: Duration = Duration::from_secs(10);
+
+/// How long a second Enter counts as confirming the crossed-turn warning
+const CROSSED_TURN_CONFIRM_WINDOW: Duration = Duration::from_secs(5);
+
+impl App {
+    /// Apply a presence event from another client
+    pub fn apply_presence_update(
+        &mut self,
+        thread_id: String,
+        client_id: String,
+        client_kind: String,
+        state: WsPresenceState,
+    ) {
+        // The backend may echo our own events back
+        if client_id == self.client_id {
+            return;
+        }
+
+        if state == WsPresenceState::Left {
+            if let Some(peers) = self.thread_presence.get_mut(&thread_id) {
+                peers.remove(&client_id);
+                if peers.is_empty() {
+                    self.thread_presence.remove(&thread_id);
+                }
+            }
+            self.mark_dirty();
+            return;
+        }
+
+        let peer = self
+            .thread_presence
+            .entry(thread_id)
+            .or_default()
+            .entry(client_id)
+            .or_insert_with(|| PeerPresence {
+                client_kind: client_kind.clone(),
+                typing_until: None,
+                last_sent_at: None,
+            });
+        if !client_kind.is_empty() {
+            peer.client_kind = client_kind;
+        }
+        match state {
+            WsPresenceState::Typing => {
+                peer.typing_until = Some(Instant::now() + TYPING_INDICATOR_DURATION);
+            }
+            WsPresenceState::MessageSent => {
+                peer.typing_until = None;
+                peer.last_sent_at = Some(Instant::now());
+            }
+            WsPresenceState::Viewing | WsPresenceState::Left => {
+                peer.typing_until = None;
+            }
+        }
+        self.mark_dirty();
+    }
+
+    /// Kinds of the other clients with a thread open (e.g. ["web"]), deduplicated
+    pub fn present_client_kinds(&self, thread_id: &str) -> Vec<&str> {
+        self.peer_kinds(thread_id, |_| true)
+    }
+
+    /// Kinds of the other clients currently typing in a thread
+    pub fn typing_client_kinds(&self, thread_id: &str) -> Vec<&str> {
+        let now = Instant::now();
+        self.peer_kinds(thread_id, |peer| {
+            peer.typing_until.is_some_and(|until| until > now)
+        })
+    }
+
+    /// Clear typing indicators that weren't refreshed in time.
+    ///
+    /// Called on every tick; cheap when nobody is typing.
+    pub fn check_presence_expiry(&mut self) {
+        let now = Instant::now();
+        let mut expired = false;
+        for peer in self
+            .thread_presence
+            .values_mut()
+            .flat_map(|p| p.values_mut())
+        {
+            if peer.typing_until.is_some_and(|until| until <= now) {
+                peer.typing_until = None;
+                expired = true;
+            }
+        }
+        if expired {
+            self.mark_dirty();
+        }
+    }
+
+    /// Tell other clients we're typing in the active thread (throttled)
+    pub fn note_local_typing(&mut self) {
+        if self.screen != Screen::Conversation {
+            return;
+        }
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return;
+        };
+        if self
+            .last_typing_sent
+            .is_some_and(|at| at.elapsed() < TYPING_SEND_INTERVAL)
+        {
+            return;
+        }
+        if self.send_presence(&thread_id, WsPresenceState::Typing) {
+            self.last_typing_sent = Some(Instant::now());
+        }
+    }
+
+    /// Whether to hold a submit because another client just sent a message.
+    ///
+    /// The first submit shows a warning and returns true; a second submit
+    /// within a few seconds goes through.
+    pub fn should_warn_crossed_turn(&mut self, thread_id: &str) -> bool {
+        let recent_sender = self.thread_presence.get(thread_id).and_then(|peers| {
+            peers
+                .values()
+                .find(|peer| {
+                    peer.last_sent_at
+                        .is_some_and(|at| at.elapsed() < CROSSED_TURN_WINDOW)
+                })
+                .map(|peer| peer.client_kind.clone())
+        });
+        let Some(kind) = recent_sender else {
+            return false;
+        };
+
+        if self
+            .crossed_turn_warned_at
+            .take()
+            .is_some_and(|at| at.elapsed() < CROSSED_TURN_CONFIRM_WINDOW)
+        {
+            return false;
+        }
+
+        self.crossed_turn_warned_at = Some(Instant::now());
+        self.set_status_notice(
+            format!(
+                "{} just sent a message to this thread. Press Enter again to send anyway",
+                client_label(&kind)
+            ),
+            CROSSED_TURN_CONFIRM_WINDOW,
+        );
+        true
+    }
+
+    /// Send our presence on a thread to other clients via WebSocket.
+    ///
+    /// Returns false (and does nothing) when the WebSocket isn't connected.
+    pub fn send_presence(&self, thread_id: &str, state: WsPresenceState) -> bool {
+        let Some(sender) = &self.ws_sender else {
+            return false;
+        };
+        if self.ws_connection_state != WsConnectionState::Connected {
+            return false;
+        }
+
+        let message = WsPresence::new(thread_id.to_string(), self.client_id.clone(), state);
+        match sender.try_send(WsOutgoingMessage::Presence(message)) {
+            Ok(()) => {
+                debug!("Sent presence {:?} for thread {}", state, thread_id);
+                true
+            }
+            Err(e) => {
+                warn!("Failed to send presence: {}", e);
+                false
+            }
+        }
+    }
+
+    fn peer_kinds(&self, thread_id: &str, filter: impl Fn(&PeerPresence) -> bool) -> Vec<&str> {
+        let mut kinds: Vec<&str> = self
+            .thread_presence
+            .get(thread_id)
+            .map(|peers| {
+                peers
+                    .values()
+                    .filter(|peer| filter(peer))
+                    .map(|peer| peer.client_kind.as_str())
+                    .collect()
+            })
+            .unwrap_or_default();
+        kinds.sort_unstable();
+        kinds.dedup();
+        kinds
+    }
+}
+
+/// Human-readable name for a client kind ("web" -> "The web UI")
+fn client_label(kind: &str) -> String {
+    match kind {
+        "web" => "The web UI".to_string(),
+        "tui" => "Another terminal".to_string(),
+        "" => "Another client".to_string(),
+        other => format!("Another client ({})", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn presence(app: &mut App, client_id: &str, kind: &str, state: WsPresenceState) {
+        app.apply_presence_update(
+            "thread-1".to_string(),
+            client_id.to_string(),
+            kind.to_string(),
+            state,
+        );
+    }
+
+    #[test]
+    fn test_presence_tracks_viewers_and_typing() {
+        let mut app = App::default();
+
+        presence(&mut app, "web-1", "web", WsPresenceState::Viewing);
+        presence(&mut app, "tui-2", "tui", WsPresenceState::Typing);
+
+        assert_eq!(app.present_client_kinds("thread-1"), vec!["tui", "web"]);
+        assert_eq!(app.typing_client_kinds("thread-1"), vec!["tui"]);
+
+        presence(&mut app, "tui-2", "tui", WsPresenceState::Left);
+        assert_eq!(app.present_client_kinds("thread-1"), vec!["web"]);
+        assert!(app.typing_client_kinds("thread-1").is_empty());
+    }
+
+    #[test]
+    fn test_presence_ignores_own_echo() {
+        let mut app = App::default();
+        let own_id = app.client_id.clone();
+
+        presence(&mut app, &own_id, "tui", WsPresenceState::Viewing);
+
+        assert!(app.present_client_kinds("thread-1").is_empty());
+    }
+
+    #[test]
+    fn test_typing_indicator_expires() {
+        let mut app = App::default();
+        presence(&mut app, "web-1", "web", WsPresenceState::Typing);
+
+        let peer = app
+            .thread_presence
+            .get_mut("thread-1")
+            .and_then(|peers| peers.get_mut("web-1"))
+            .unwrap();
+        peer.typing_until = Some(Instant::now() - Duration::from_millis(1));
+        app.check_presence_expiry();
+
+        assert!(app.typing_client_kinds("thread-1").is_empty());
+        assert_eq!(app.present_client_kinds("thread-1"), vec!["web"]);
+    }
+
+    #[test]
+    fn test_crossed_turn_warns_once_then_allows() {
+        let mut app = App::default();
+        assert!(!app.should_warn_crossed_turn("thread-1"));
+
+        presence(&mut app, "web-1", "web", WsPresenceState::MessageSent);
+
+        assert!(app.should_warn_crossed_turn("thread-1"));
+        assert!(app.status_notice.is_some());
+        // Second submit goes through
+        assert!(!app.should_warn_crossed_turn("thread-1"));
+    }
+}
@@ -0,0 +1,320 @@
+//! Two-stage quit for the App.
+//!
+//! Quitting while work is still in flight — responses streaming, prompts
+//! queued behind them, permission prompts waiting for an answer — opens a
+//! confirmation listing it instead of dropping it silently. From there the
+//! user can quit anyway, wait (spoq quits by itself once the streams finish)
+//! or jump to the busy thread. Quitting anyway keeps the queued prompts and
+//! the streaming threads in the saved session: the next launch restores the
+//! queues and says which threads were still working.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::{App, QueuedPrompt};
+
+/// How long the restored-work notice stays visible
+const RESTORED_NOTICE_DURATION: Duration = Duration::from_secs(6);
+
+/// Work that quitting would abandon
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BackgroundWork {
+    /// Threads with a streaming response
+    pub streaming: Vec<String>,
+    /// Threads with queued prompts, and how many
+    pub queued: Vec<(String, usize)>,
+    /// Threads waiting on a permission answer
+    pub permissions: Vec<String>,
+}
+
+impl BackgroundWork {
+    pub fn is_empty(&self) -> bool {
+        self.streaming.is_empty() && self.queued.is_empty() && self.permissions.is_empty()
+    }
+
+    /// Thread to jump to: one waiting on a permission, then one streaming,
+    /// then one with queued prompts
+    pub fn busy_thread(&self) -> Option<&str> {
+        self.permissions
+            .first()
+            .or(self.streaming.first())
+            .or(self.queued.first().map(|(thread_id, _)| thread_id))
+            .map(String::as_str)
+    }
+}
+
+/// Open quit confirmation
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuitConfirm {
+    pub work: BackgroundWork,
+}
+
+/// `count` followed by `noun`, pluralized
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+impl App {
+    /// Streams, queued prompts and permission prompts in flight
+    pub fn background_work(&self) -> BackgroundWork {
+        let streaming = self
+            .cache
+            .threads()
+            .into_iter()
+            .filter(|thread| self.cache.is_thread_streaming(&thread.id))
+            .map(|thread| thread.id.clone())
+            .collect();
+        let mut queued: Vec<(String, usize)> = self
+            .outgoing_queue
+            .iter()
+            .filter(|(_, queue)| !queue.is_empty())
+            .map(|(thread_id, queue)| (thread_id.clone(), queue.len()))
+            .collect();
+        queued.sort();
+        let mut permissions: Vec<String> = self
+            .dashboard
+            .pending_permissions_iter()
+            .map(|(thread_id, _)| thread_id.clone())
+            .collect();
+        permissions.sort();
+        BackgroundWork {
+            streaming,
+            queued,
+            permissions,
+        }
+    }
+
+    /// One line per kind of work in flight, naming the threads
+    pub fn background_work_lines(&self, work: &BackgroundWork) -> Vec<String> {
+        let title = |thread_id: &str| {
+            self.cache
+                .get_thread(thread_id)
+                .map(|thread| thread.title.clone())
+                .unwrap_or_else(|| thread_id.to_string())
+        };
+        let mut lines = Vec::new();
+        if !work.streaming.is_empty() {
+            let titles: Vec<String> = work.streaming.iter().map(|id| title(id)).collect();
+            lines.push(format!(
+                "{} streaming: {}",
+                plural(work.streaming.len(), "response"),
+                titles.join(", ")
+            ));
+        }
+        if !work.queued.is_empty() {
+            let total: usize = work.queued.iter().map(|(_, count)| count).sum();
+            let titles: Vec<String> = work
+                .queued
+                .iter()
+                .map(|(id, count)| format!("{} ({})", title(id), count))
+                .collect();
+            lines.push(format!(
+                "{} queued: {}",
+                plural(total, "prompt"),
+                titles.join(", ")
+            ));
+        }
+        if !work.permissions.is_empty() {
+            let titles: Vec<String> = work.permissions.iter().map(|id| title(id)).collect();
+            lines.push(format!(
+                "{} waiting: {}",
+                plural(work.permissions.len(), "permission"),
+                titles.join(", ")
+            ));
+        }
+        lines
+    }
+
+    /// Quit, or ask first if that would abandon work in flight
+    pub fn request_quit(&mut self) {
+        let work = self.background_work();
+        if work.is_empty() {
+            self.quit();
+        } else {
+            self.quit_confirm = Some(QuitConfirm { work });
+            self.mark_dirty();
+        }
+    }
+
+    /// Stay (Esc in the confirmation, or while waiting to quit)
+    pub fn cancel_quit(&mut self) {
+        self.quit_confirm = None;
+        self.quit_when_idle = false;
+        self.mark_dirty();
+    }
+
+    /// Quit once no response is streaming anymore
+    pub fn quit_when_streams_finish(&mut self) {
+        self.quit_confirm = None;
+        let streams = self.background_work().streaming.len();
+        if streams == 0 {
+            self.quit();
+            return;
+        }
+        self.quit_when_idle = true;
+        self.set_status_notice(
+            format!(
+                "Quitting when {} finish{}",
+                plural(streams, "response"),
+                if streams == 1 { "es" } else { "" }
+            ),
+            Duration::from_secs(4),
+        );
+        self.mark_dirty();
+    }
+
+    /// Open the busy thread from the confirmation
+    pub fn jump_to_busy_thread(&mut self) {
+        let Some(confirm) = self.quit_confirm.take() else {
+            return;
+        };
+        if let Some(thread_id) = confirm.work.busy_thread() {
+            self.open_thread(thread_id.to_string());
+        }
+        self.mark_dirty();
+    }
+
+    /// Quit if waiting for streams to finish and none is left. Called every
+    /// tick.
+    pub(super) fn check_quit_when_idle(&mut self) {
+        if self.quit_when_idle && self.background_work().streaming.is_empty() {
+            self.quit();
+        }
+    }
+
+    /// Prompts queued per thread, as saved in the session
+    pub(super) fn saved_queues(&self) -> HashMap<String, Vec<String>> {
+        self.outgoing_queue
+            .iter()
+            .filter(|(_, queue)| !queue.is_empty())
+            .map(|(thread_id, queue)| {
+                let prompts = queue.iter().map(|prompt| prompt.content.clone()).collect();
+                (thread_id.clone(), prompts)
+            })
+            .collect()
+    }
+
+    /// Restore the queues and report the threads that were still working
+    /// when the last session quit
+    pub(super) fn restore_background_work(
+        &mut self,
+        queued_prompts: HashMap<String, Vec<String>>,
+        streaming_threads: &[String],
+    ) {
+        for (thread_id, prompts) in queued_prompts {
+            if self.cache.get_thread(&thread_id).is_some() && !prompts.is_empty() {
+                self.outgoing_queue
+                    .entry(thread_id)
+                    .or_default()
+                    .extend(prompts.into_iter().map(QueuedPrompt::text));
+            }
+        }
+
+        let streaming: Vec<&String> = streaming_threads
+            .iter()
+            .filter(|thread_id| self.cache.get_thread(thread_id).is_some())
+            .collect();
+        let queued: usize = self.outgoing_queue.values().map(|queue| queue.len()).sum();
+        let mut parts = Vec::new();
+        if !streaming.is_empty() {
+            parts.push(format!(
+                "{} still working at quit",
+                plural(streaming.len(), "thread")
+            ));
+        }
+        if queued > 0 {
+            parts.push(format!("{} restored", plural(queued, "queued prompt")));
+        }
+        if !parts.is_empty() {
+            self.set_status_notice(parts.join(", "), RESTORED_NOTICE_DURATION);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn app() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app
+    }
+
+    #[test]
+    fn test_quit_without_work_is_immediate() {
+        let mut app = app();
+        app.request_quit();
+        assert!(app.should_quit);
+        assert!(app.quit_confirm.is_none());
+    }
+
+    #[test]
+    fn test_quit_with_work_asks_first() {
+        let mut app = app();
+        app.cache
+            .add_streaming_message("thread-001", "Hi".to_string(), Vec::new());
+        app.outgoing_queue
+            .entry("thread-002".to_string())
+            .or_default()
+            .extend(["one", "two"].map(|p| QueuedPrompt::text(p.to_string())));
+
+        app.request_quit();
+        assert!(!app.should_quit);
+        let work = app.quit_confirm.as_ref().unwrap().work.clone();
+        assert_eq!(work.streaming, vec!["thread-001".to_string()]);
+        assert_eq!(work.queued, vec![("thread-002".to_string(), 2)]);
+        assert_eq!(work.busy_thread(), Some("thread-001"));
+
+        let lines = app.background_work_lines(&work);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("1 response streaming: "));
+        assert!(lines[1].starts_with("2 prompts queued: "));
+        assert!(lines[1].ends_with(" (2)"));
+
+        app.cancel_quit();
+        assert!(app.quit_confirm.is_none());
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_wait_quits_when_streams_finish() {
+        let mut app = app();
+        app.cache
+            .add_streaming_message("thread-001", "Hi".to_string(), Vec::new());
+        app.request_quit();
+        app.quit_when_streams_finish();
+        assert!(app.quit_when_idle);
+
+        app.check_quit_when_idle();
+        assert!(!app.should_quit);
+
+        app.cache.finalize_message("thread-001", 42);
+        app.check_quit_when_idle();
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_restore_background_work() {
+        let mut app = app();
+        let mut queues = HashMap::new();
+        queues.insert("thread-001".to_string(), vec!["next".to_string()]);
+        queues.insert("thread-gone".to_string(), vec!["lost".to_string()]);
+
+        app.restore_background_work(queues, &["thread-002".to_string()]);
+        assert_eq!(
+            app.queued_outgoing("thread-001")
+                .unwrap()
+                .front()
+                .map(|prompt| prompt.content.as_str()),
+            Some("next")
+        );
+        assert!(app.queued_outgoing("thread-gone").is_none());
+        assert_eq!(app.saved_queues().len(), 1);
+    }
+}
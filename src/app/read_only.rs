@@ -0,0 +1,108 @@
+//! Read-only degraded mode for the App.
+//!
+//! When a state file fails to parse at startup (see
+//! `startup::integrity`), nothing is written to disk and a banner names the
+//! corrupted file. Conversations keep working; `/regenerate` moves the
+//! corrupted files aside and writes defaults, leaving read-only mode once
+//! every file is fixed.
+
+use std::time::Duration;
+
+use crate::startup::integrity::{self, CorruptedFile};
+
+use super::App;
+
+impl App {
+    /// Enter read-only mode for the given corrupted files (no-op if empty)
+    pub fn enter_read_only_mode(&mut self, corrupted: Vec<CorruptedFile>) {
+        for file in &corrupted {
+            tracing::warn!("Read-only mode: {}", file);
+        }
+        integrity::set_read_only(!corrupted.is_empty());
+        self.corrupted_files = corrupted;
+        self.mark_dirty();
+    }
+
+    /// Banner text while in read-only mode
+    pub fn read_only_banner(&self) -> Option<String> {
+        let first = self.corrupted_files.first()?;
+        let others = match self.corrupted_files.len() - 1 {
+            0 => String::new(),
+            n => format!(" (+{} more)", n),
+        };
+        Some(format!(
+            "Read-only: {}{} - /regenerate to back up and reset to defaults",
+            first, others
+        ))
+    }
+
+    /// Back up each corrupted file and regenerate its defaults.
+    ///
+    /// Files that can't be regenerated stay listed, keeping read-only mode on.
+    pub fn regenerate_corrupted_files(&mut self) {
+        if self.corrupted_files.is_empty() {
+            self.set_status_notice(
+                "No corrupted state files".to_string(),
+                Duration::from_secs(2),
+            );
+            return;
+        }
+
+        let mut backups = Vec::new();
+        let mut failed = Vec::new();
+        for file in std::mem::take(&mut self.corrupted_files) {
+            match integrity::backup_and_regenerate(&file) {
+                Ok(backup) => backups.push(backup),
+                Err(e) => {
+                    tracing::error!("Failed to regenerate {}: {}", file.path.display(), e);
+                    failed.push(file);
+                }
+            }
+        }
+
+        if failed.is_empty() {
+            let notice = match backups.as_slice() {
+                [backup] => format!("Regenerated defaults; backup at {}", backup.display()),
+                _ => format!(
+                    "Regenerated {} files; backups saved alongside",
+                    backups.len()
+                ),
+            };
+            self.set_status_notice(notice, Duration::from_secs(5));
+        } else {
+            self.set_timed_error(
+                format!("Could not regenerate {}", failed[0].path.display()),
+                Duration::from_secs(5),
+            );
+        }
+        self.enter_read_only_mode(failed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::startup::integrity::{check_state_files_in, StateFileKind};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_regenerate_corrupted_files_leaves_read_only_mode() {
+        let home = TempDir::new().unwrap();
+        let path = StateFileKind::Config.path_in(home.path());
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{ broken").unwrap();
+
+        // Set the files directly: the global read-only flag would make other
+        // tests' saves fail while this one runs
+        let mut app = App::default();
+        app.corrupted_files = check_state_files_in(home.path());
+        assert!(app.read_only_banner().unwrap().contains("config.json"));
+
+        app.regenerate_corrupted_files();
+
+        assert!(app.corrupted_files.is_empty());
+        assert!(app.read_only_banner().is_none());
+        assert!(check_state_files_in(home.path()).is_empty());
+        assert!(app.status_notice.is_some());
+    }
+}
@@ -0,0 +1,350 @@
+//! Connection banner and manual reconnect for the App.
+//!
+//! While the WebSocket is down a banner across the top of both screens says
+//! for how long, and which reconnect attempt is running. `R` skips the rest of
+//! the backoff and retries right away, or starts a new client if none is
+//! running. Permission and question answers given meanwhile are queued and
+//! sent as soon as the connection is back.
+
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc::error::TrySendError;
+use tracing::{info, warn};
+
+use crate::websocket::{WsClientConfig, WsConnectionState, WsControl, WsOutgoingMessage};
+
+use super::{start_websocket_with_config, App, AppMessage, WsHandle};
+
+/// How long the reconnect notices stay in the status line
+const NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+impl App {
+    /// Banner text while the WebSocket connection is down
+    pub fn connection_banner(&self) -> Option<String> {
+        let since = self.ws_disconnected_at?;
+        if self.ws_connection_state == WsConnectionState::Connected {
+            return None;
+        }
+        Some(banner_text(
+            &self.ws_connection_state,
+            since.elapsed(),
+            self.ws_queued_messages.len(),
+        ))
+    }
+
+    /// Redraw the banner as its elapsed time changes
+    pub(super) fn tick_connection_banner(&mut self) {
+        let Some(since) = self.ws_disconnected_at else {
+            return;
+        };
+        let secs = since.elapsed().as_secs();
+        if secs != self.ws_banner_secs {
+            self.ws_banner_secs = secs;
+            self.mark_dirty();
+        }
+    }
+
+    /// Record that the connection went down (keeps the earliest time)
+    pub fn note_ws_down(&mut self) {
+        if self.ws_disconnected_at.is_none() {
+            self.ws_disconnected_at = Some(Instant::now());
+            self.ws_banner_secs = 0;
+        }
+        self.mark_dirty();
+    }
+
+    /// Record that the connection is back, sending the queued answers
+    pub(super) fn note_ws_up(&mut self) {
+        self.ws_disconnected_at = None;
+        self.flush_queued_ws_messages();
+        self.mark_dirty();
+    }
+
+    /// Whether messages for the server are held until the connection is back.
+    ///
+    /// True while a client is reconnecting, or after the connection was lost
+    /// and `R` can start a new one.
+    pub(super) fn queues_ws_messages(&self) -> bool {
+        self.ws_connection_state != WsConnectionState::Connected
+            && (self.ws_sender.is_some() || self.ws_disconnected_at.is_some())
+    }
+
+    /// Hold a message until the connection is back
+    pub(super) fn queue_ws_message(&mut self, message: WsOutgoingMessage) {
+        self.ws_queued_messages.push(message);
+        self.set_status_notice(
+            "Not connected - answer will be sent once reconnected".to_string(),
+            NOTICE_DURATION,
+        );
+        self.mark_dirty();
+    }
+
+    /// Send the queued messages, oldest first, if the connection is up
+    fn flush_queued_ws_messages(&mut self) {
+        if self.ws_connection_state != WsConnectionState::Connected
+            || self.ws_queued_messages.is_empty()
+        {
+            return;
+        }
+        let Some(sender) = self.ws_sender.clone() else {
+            return;
+        };
+
+        let mut queued = std::mem::take(&mut self.ws_queued_messages).into_iter();
+        let mut sent = 0;
+        while let Some(message) = queued.next() {
+            if let Err(e) = sender.try_send(message) {
+                warn!("Failed to send queued WebSocket message: {}", e);
+                self.ws_queued_messages.push(e.into_inner());
+                self.ws_queued_messages.extend(queued);
+                break;
+            }
+            sent += 1;
+        }
+        info!("Sent {} queued WebSocket message(s)", sent);
+    }
+
+    /// Reconnect right away instead of waiting out the backoff (`R`)
+    pub fn reconnect_now(&mut self) {
+        if self.ws_connection_state == WsConnectionState::Connected {
+            self.set_status_notice("Already connected".to_string(), NOTICE_DURATION);
+            return;
+        }
+
+        if let Some(control) = &self.ws_control {
+            match control.try_send(WsControl::ReconnectNow) {
+                Ok(()) | Err(TrySendError::Full(_)) => {
+                    self.set_status_notice("Reconnecting...".to_string(), NOTICE_DURATION);
+                    return;
+                }
+                Err(TrySendError::Closed(_)) => {
+                    // The client is gone; start a new one below
+                    self.ws_control = None;
+                    self.ws_sender = None;
+                }
+            }
+        } else if matches!(
+            self.ws_connection_state,
+            WsConnectionState::Reconnecting { .. }
+        ) {
+            // A client is already being started
+            return;
+        }
+
+        info!("Starting a new WebSocket client");
+        self.ws_connection_state = WsConnectionState::Reconnecting { attempt: 1 };
+        self.note_ws_down();
+        self.set_status_notice("Reconnecting...".to_string(), NOTICE_DURATION);
+        let config = self.ws_client_config();
+        let tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            let msg = match start_websocket_with_config(tx.clone(), config).await {
+                Ok(handle) => AppMessage::WsClientStarted { handle },
+                Err(error) => AppMessage::WsClientStartFailed { error },
+            };
+            let _ = tx.send(msg);
+        });
+    }
+
+    /// Use a WebSocket client started by `reconnect_now`
+    pub(super) fn adopt_ws_client(&mut self, handle: WsHandle) {
+        self.ws_sender = Some(handle.sender);
+        self.ws_control = Some(handle.control);
+        // The client reports WsConnected before it's handed over
+        self.flush_queued_ws_messages();
+        self.mark_dirty();
+    }
+
+    /// WebSocket client settings for the current VPS and credentials.
+    ///
+    /// Uses wss:// for `https://` URLs and ws:// for `http://` ones. Without a
+    /// protocol, domains (likely behind Cloudflare Tunnel) get TLS and IPs
+    /// don't.
+    pub fn ws_client_config(&self) -> WsClientConfig {
+        let mut config = WsClientConfig::default();
+        if let Some(ref url) = self.vps_url {
+            let (host, use_tls) = if let Some(host) = url.strip_prefix("https://") {
+                (host, true)
+            } else if let Some(host) = url.strip_prefix("http://") {
+                (host, false)
+            } else {
+                let is_ip = url.split(':').next().is_some_and(|h| {
+                    h.parse::<std::net::Ipv4Addr>().is_ok()
+                        || h.parse::<std::net::Ipv6Addr>().is_ok()
+                });
+                (url.as_str(), !is_ip)
+            };
+            config = config.with_host(host).with_tls(use_tls);
+        }
+        if let Some(ref token) = self.credentials.access_token {
+            config = config.with_auth(token);
+        }
+        config
+    }
+}
+
+/// "Connection lost 2m 05s ago · reconnecting (attempt 3) · R to reconnect now"
+fn banner_text(state: &WsConnectionState, elapsed: Duration, queued: usize) -> String {
+    let mut text = format!("Connection lost {} ago", format_elapsed(elapsed));
+    if let WsConnectionState::Reconnecting { attempt } = state {
+        text.push_str(&format!(" \u{00b7} reconnecting (attempt {})", attempt));
+    }
+    match queued {
+        0 => {}
+        1 => text.push_str(" \u{00b7} 1 answer queued"),
+        n => text.push_str(&format!(" \u{00b7} {} answers queued", n)),
+    }
+    text.push_str(" \u{00b7} R to reconnect now");
+    text
+}
+
+/// "12s", "2m 05s", "1h 02m"
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::websocket::{WsCommandResponse, WsCommandResult, WsPermissionData};
+    use tokio::sync::mpsc;
+
+    fn response(request_id: &str) -> WsOutgoingMessage {
+        WsOutgoingMessage::CommandResponse(WsCommandResponse {
+            type_: "command_response".to_string(),
+            request_id: request_id.to_string(),
+            result: WsCommandResult {
+                status: "success".to_string(),
+                data: WsPermissionData {
+                    allowed: true,
+                    message: None,
+                },
+            },
+        })
+    }
+
+    #[test]
+    fn test_banner_text() {
+        assert_eq!(
+            banner_text(
+                &WsConnectionState::Reconnecting { attempt: 3 },
+                Duration::from_secs(125),
+                0
+            ),
+            "Connection lost 2m 05s ago \u{00b7} reconnecting (attempt 3) \u{00b7} R to reconnect now"
+        );
+        assert_eq!(
+            banner_text(&WsConnectionState::Disconnected, Duration::from_secs(9), 2),
+            "Connection lost 9s ago \u{00b7} 2 answers queued \u{00b7} R to reconnect now"
+        );
+    }
+
+    #[test]
+    fn test_banner_shown_until_connected() {
+        let mut app = App::default();
+        assert!(app.connection_banner().is_none());
+
+        app.handle_message(AppMessage::WsDisconnected);
+        assert!(app.connection_banner().is_some());
+        app.handle_message(AppMessage::WsReconnecting { attempt: 2 });
+        assert!(app
+            .connection_banner()
+            .unwrap()
+            .contains("reconnecting (attempt 2)"));
+
+        app.handle_message(AppMessage::WsConnected);
+        assert!(app.connection_banner().is_none());
+        assert!(app.ws_disconnected_at.is_none());
+    }
+
+    #[test]
+    fn test_reconnect_now_skips_backoff() {
+        let mut app = App::default();
+        let (control_tx, mut control_rx) = mpsc::channel(4);
+        app.ws_control = Some(control_tx);
+        app.ws_connection_state = WsConnectionState::Reconnecting { attempt: 4 };
+
+        app.reconnect_now();
+        assert_eq!(control_rx.try_recv().unwrap(), WsControl::ReconnectNow);
+    }
+
+    #[test]
+    fn test_reconnect_key_works_while_the_banner_is_up() {
+        use crate::app::{Focus, Screen};
+        use crate::input::keymap::Action;
+
+        let mut app = App {
+            focus: Focus::Threads,
+            ..Default::default()
+        };
+        let (control_tx, mut control_rx) = mpsc::channel(4);
+        app.ws_control = Some(control_tx);
+        assert!(!app.handle_panel_action(Action::ReconnectNow));
+
+        app.handle_message(AppMessage::WsReconnecting { attempt: 2 });
+        for screen in [Screen::CommandDeck, Screen::Conversation] {
+            app.screen = screen;
+            assert!(app.handle_panel_action(Action::ReconnectNow));
+            assert_eq!(control_rx.try_recv().unwrap(), WsControl::ReconnectNow);
+        }
+
+        // While typing, `R` is text
+        app.focus = Focus::Input;
+        assert!(!app.handle_panel_action(Action::ReconnectNow));
+    }
+
+    #[test]
+    fn test_reconnect_now_while_connected_does_nothing() {
+        let mut app = App::default();
+        let (control_tx, mut control_rx) = mpsc::channel(4);
+        app.ws_control = Some(control_tx);
+        app.ws_connection_state = WsConnectionState::Connected;
+
+        app.reconnect_now();
+        assert!(control_rx.try_recv().is_err());
+        assert_eq!(app.status_notice.as_deref(), Some("Already connected"));
+    }
+
+    #[test]
+    fn test_queued_messages_sent_on_reconnect() {
+        let mut app = App::default();
+        let (tx, mut rx) = mpsc::channel(10);
+        app.ws_sender = Some(tx);
+        app.handle_message(AppMessage::WsDisconnected);
+        assert!(app.queues_ws_messages());
+
+        app.queue_ws_message(response("perm-1"));
+        app.queue_ws_message(response("perm-2"));
+        assert!(rx.try_recv().is_err());
+
+        app.handle_message(AppMessage::WsConnected);
+        assert!(app.ws_queued_messages.is_empty());
+        for expected in ["perm-1", "perm-2"] {
+            match rx.try_recv().unwrap() {
+                WsOutgoingMessage::CommandResponse(r) => assert_eq!(r.request_id, expected),
+                other => panic!("Expected CommandResponse, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_ws_client_config_from_vps_url() {
+        let mut app = App::default();
+        app.vps_url = Some("https://vps.example.com".to_string());
+        let config = app.ws_client_config();
+        assert_eq!(config.host, "vps.example.com");
+        assert!(config.use_tls);
+
+        app.vps_url = Some("10.0.0.5:8000".to_string());
+        let config = app.ws_client_config();
+        assert_eq!(config.host, "10.0.0.5:8000");
+        assert!(!config.use_tls);
+    }
+}
@@ -0,0 +1,519 @@
+//! Render cache snapshots for the App.
+//!
+//! Opening a long thread pays for markdown parsing, wrapping and height
+//! measurement before the first frame. When the user leaves a thread (or
+//! quits), its rendered lines and measured heights are written to
+//! `~/.spoq/render-cache/<thread>.json`; opening the thread again loads the
+//! snapshot and seeds `rendered_lines_cache` and the height cache from it, so
+//! messages seen before render without being laid out again.
+//!
+//! Entries are keyed by message ID and `render_version` like the in-memory
+//! caches, and a snapshot is only used at the terminal width, zoom level,
+//! sidebar, hyperlink and timestamp settings and spoq version it was taken
+//! with.
+//! Snapshots of the `MAX_SNAPSHOTS` most recently left threads are kept.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use ratatui::{
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::cli::VERSION;
+
+use super::{App, Screen};
+
+/// Directory of the snapshots inside `~/.spoq`
+const SNAPSHOT_DIR: &str = "render-cache";
+
+/// Threads whose snapshots are kept
+const MAX_SNAPSHOTS: usize = 20;
+
+/// Rendered lines kept per snapshot (newest messages first)
+const MAX_SNAPSHOT_LINES: usize = 5_000;
+
+/// Rendered lines and heights of a thread's messages
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderSnapshot {
+    /// spoq version that rendered the lines
+    pub version: String,
+    pub thread_id: String,
+    /// Terminal width of the rendered lines
+    pub width: u16,
+    pub sidebar: bool,
+    pub hyperlinks: bool,
+    /// Zoom level (see `ZoomLevel::level`)
+    pub zoom: u8,
+    /// Whether the heights include day header rows
+    #[serde(default)]
+    pub timestamps: bool,
+    /// Message area width of the heights
+    pub height_width: usize,
+    pub heights: Vec<SnapshotHeight>,
+    pub messages: Vec<SnapshotMessage>,
+}
+
+/// Measured height of a message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotHeight {
+    pub id: i64,
+    pub render_version: u64,
+    pub visual_lines: usize,
+}
+
+/// Rendered lines of a message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotMessage {
+    pub id: i64,
+    pub render_version: u64,
+    pub lines: Vec<SnapshotLine>,
+}
+
+/// A rendered line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotLine {
+    #[serde(default, skip_serializing_if = "SnapshotStyle::is_plain")]
+    pub style: SnapshotStyle,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alignment: Option<String>,
+    pub spans: Vec<SnapshotSpan>,
+}
+
+/// A styled piece of a rendered line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotSpan {
+    pub text: String,
+    #[serde(default, skip_serializing_if = "SnapshotStyle::is_plain")]
+    pub style: SnapshotStyle,
+}
+
+/// A `Style` with colors by name (`Red`, `#04B575`, `240`) and modifier bits
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotStyle {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fg: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bg: Option<String>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub add: u16,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub sub: u16,
+}
+
+fn is_zero(bits: &u16) -> bool {
+    *bits == 0
+}
+
+impl SnapshotStyle {
+    fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn from_style(style: Style) -> Self {
+        Self {
+            fg: style.fg.map(|color| color.to_string()),
+            bg: style.bg.map(|color| color.to_string()),
+            add: style.add_modifier.bits(),
+            sub: style.sub_modifier.bits(),
+        }
+    }
+
+    fn to_style(&self) -> Style {
+        let color = |name: &Option<String>| name.as_deref().and_then(|n| Color::from_str(n).ok());
+        Style {
+            fg: color(&self.fg),
+            bg: color(&self.bg),
+            add_modifier: Modifier::from_bits_truncate(self.add),
+            sub_modifier: Modifier::from_bits_truncate(self.sub),
+            ..Style::default()
+        }
+    }
+}
+
+impl SnapshotLine {
+    pub fn from_line(line: &Line<'static>) -> Self {
+        Self {
+            style: SnapshotStyle::from_style(line.style),
+            alignment: line.alignment.map(|alignment| {
+                match alignment {
+                    Alignment::Left => "left",
+                    Alignment::Center => "center",
+                    Alignment::Right => "right",
+                }
+                .to_string()
+            }),
+            spans: line
+                .spans
+                .iter()
+                .map(|span| SnapshotSpan {
+                    text: span.content.to_string(),
+                    style: SnapshotStyle::from_style(span.style),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_line(&self) -> Line<'static> {
+        let mut line = Line::from(
+            self.spans
+                .iter()
+                .map(|span| Span::styled(span.text.clone(), span.style.to_style()))
+                .collect::<Vec<_>>(),
+        )
+        .style(self.style.to_style());
+        line.alignment = match self.alignment.as_deref() {
+            Some("left") => Some(Alignment::Left),
+            Some("center") => Some(Alignment::Center),
+            Some("right") => Some(Alignment::Right),
+            _ => None,
+        };
+        line
+    }
+}
+
+impl RenderSnapshot {
+    /// Directory of the snapshots (`~/.spoq/render-cache`)
+    pub fn dir() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".spoq").join(SNAPSHOT_DIR))
+    }
+
+    /// Snapshot file of a thread
+    fn path(dir: &Path, thread_id: &str) -> PathBuf {
+        let name: String = thread_id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        dir.join(format!("{}.json", name))
+    }
+
+    /// Load a thread's snapshot; missing or invalid files yield None
+    pub fn load(dir: &Path, thread_id: &str) -> Option<Self> {
+        let path = Self::path(dir, thread_id);
+        let contents = std::fs::read_to_string(&path).ok()?;
+        serde_json::from_str::<Self>(&contents)
+            .map_err(|e| tracing::warn!("Ignoring invalid {}: {}", path.display(), e))
+            .ok()
+            .filter(|snapshot| snapshot.thread_id == thread_id)
+    }
+
+    /// Write the snapshot and drop the oldest beyond `MAX_SNAPSHOTS`
+    pub fn save(&self, dir: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(dir)?;
+        let json = serde_json::to_string(self).map_err(std::io::Error::other)?;
+        std::fs::write(Self::path(dir, &self.thread_id), json)?;
+        prune_snapshots(dir, MAX_SNAPSHOTS);
+        Ok(())
+    }
+}
+
+/// Remove all but the `keep` most recently written snapshots
+fn prune_snapshots(dir: &Path, keep: usize) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .collect();
+    files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+    for (_, path) in files.into_iter().skip(keep) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Heights restored from a snapshot, used in place of estimates when the
+/// height cache is built
+#[derive(Debug, Clone, Default)]
+pub struct RestoredHeights {
+    pub thread_id: String,
+    /// Message area width of the heights
+    pub width: usize,
+    pub heights: HashMap<(i64, u64), usize>,
+}
+
+impl RestoredHeights {
+    /// Height of a message, if it was measured at this width
+    pub fn get(
+        &self,
+        thread_id: &str,
+        width: usize,
+        message_id: i64,
+        render_version: u64,
+    ) -> Option<usize> {
+        if self.thread_id != thread_id || self.width != width {
+            return None;
+        }
+        self.heights.get(&(message_id, render_version)).copied()
+    }
+}
+
+impl App {
+    /// Snapshot the active thread's render caches
+    fn render_snapshot(&self) -> Option<RenderSnapshot> {
+        if self.screen != Screen::Conversation {
+            return None;
+        }
+        let thread_id = self.active_thread_id.as_deref()?;
+        let width = self.rendered_lines_cache.viewport_width()?;
+
+        // Placeholder IDs (cancelled, interrupted) aren't unique
+        let mut entries: Vec<_> = self
+            .rendered_lines_cache
+            .thread_entries(thread_id)
+            .into_iter()
+            .filter(|(id, _, _)| *id > 0)
+            .collect();
+        entries.sort_by_key(|(id, _, _)| std::cmp::Reverse(*id));
+        let mut budget = MAX_SNAPSHOT_LINES;
+        let messages: Vec<SnapshotMessage> = entries
+            .into_iter()
+            .take_while(|(_, _, lines)| {
+                let fits = lines.len() <= budget;
+                budget = budget.saturating_sub(lines.len());
+                fits
+            })
+            .map(|(id, render_version, lines)| SnapshotMessage {
+                id,
+                render_version,
+                lines: lines.iter().map(SnapshotLine::from_line).collect(),
+            })
+            .collect();
+
+        let (height_width, heights) = match &self.height_cache {
+            Some(cache) if cache.thread_id.as_str() == thread_id => (
+                cache.viewport_width,
+                cache
+                    .heights
+                    .iter()
+                    .filter(|h| h.message_id > 0)
+                    .map(|h| SnapshotHeight {
+                        id: h.message_id,
+                        render_version: h.render_version,
+                        visual_lines: h.visual_lines,
+                    })
+                    .collect(),
+            ),
+            _ => (0, Vec::new()),
+        };
+        if messages.is_empty() && heights.is_empty() {
+            return None;
+        }
+
+        Some(RenderSnapshot {
+            version: VERSION.to_string(),
+            thread_id: thread_id.to_string(),
+            width,
+            sidebar: self.sidebar_visible(),
+            hyperlinks: self.terminal_capabilities.hyperlinks,
+            zoom: self.zoom_level().level(),
+            timestamps: self.show_timestamps,
+            height_width,
+            heights,
+            messages,
+        })
+    }
+
+    /// Write the active thread's render caches to disk. Called when leaving
+    /// a thread and on quit.
+    pub(super) fn save_render_snapshot(&self) {
+        let Some(dir) = self.render_snapshot_dir.as_deref() else {
+            return;
+        };
+        if crate::startup::integrity::is_read_only() {
+            return;
+        }
+        if let Some(snapshot) = self.render_snapshot() {
+            if let Err(e) = snapshot.save(dir) {
+                tracing::warn!("Failed to save render snapshot: {}", e);
+            }
+        }
+    }
+
+    /// Load the snapshot of a thread being opened; it is applied on the next
+    /// render, once the viewport width is known
+    pub(super) fn load_render_snapshot(&mut self, thread_id: &str) {
+        self.pending_render_snapshot = self
+            .render_snapshot_dir
+            .as_deref()
+            .and_then(|dir| RenderSnapshot::load(dir, thread_id));
+    }
+
+    /// Seed the render caches from the loaded snapshot if it matches the
+    /// current layout. Called from `prepare_render`.
+    pub fn apply_render_snapshot(&mut self) {
+        let Some(snapshot) = self.pending_render_snapshot.take() else {
+            return;
+        };
+        let matches = self.active_thread_id.as_deref() == Some(snapshot.thread_id.as_str())
+            && snapshot.version == VERSION
+            && self.rendered_lines_cache.viewport_width() == Some(snapshot.width)
+            && snapshot.sidebar == self.sidebar_visible()
+            && snapshot.hyperlinks == self.terminal_capabilities.hyperlinks
+            && snapshot.zoom == self.zoom_level().level()
+            && snapshot.timestamps == self.show_timestamps;
+        if !matches {
+            return;
+        }
+
+        // Oldest first, so the newest messages are the last to be evicted
+        for message in snapshot.messages.iter().rev() {
+            if !self.rendered_lines_cache.contains(
+                &snapshot.thread_id,
+                message.id,
+                message.render_version,
+            ) {
+                let lines = message.lines.iter().map(SnapshotLine::to_line).collect();
+                self.rendered_lines_cache.insert(
+                    &snapshot.thread_id,
+                    message.id,
+                    message.render_version,
+                    lines,
+                );
+            }
+        }
+        self.restored_heights = RestoredHeights {
+            heights: snapshot
+                .heights
+                .iter()
+                .map(|h| ((h.id, h.render_version), h.visual_lines))
+                .collect(),
+            thread_id: snapshot.thread_id,
+            width: snapshot.height_width,
+        };
+        // Rebuild the height cache from the restored heights
+        self.height_cache = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styled_line() -> Line<'static> {
+        Line::from(vec![
+            Span::styled("\u{2502} ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                "bold",
+                Style::default()
+                    .fg(Color::Rgb(4, 181, 117))
+                    .bg(Color::Indexed(236))
+                    .add_modifier(Modifier::BOLD | Modifier::ITALIC),
+            ),
+            Span::raw(" plain"),
+        ])
+    }
+
+    #[test]
+    fn test_lines_round_trip() {
+        let line = styled_line();
+        let snapshot = SnapshotLine::from_line(&line);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: SnapshotLine = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.to_line(), line);
+    }
+
+    #[test]
+    fn test_snapshot_save_load_and_prune() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot = RenderSnapshot {
+            version: VERSION.to_string(),
+            thread_id: "thread/001".to_string(),
+            width: 80,
+            sidebar: false,
+            hyperlinks: false,
+            zoom: 2,
+            timestamps: false,
+            height_width: 78,
+            heights: vec![SnapshotHeight {
+                id: 1,
+                render_version: 0,
+                visual_lines: 3,
+            }],
+            messages: vec![SnapshotMessage {
+                id: 1,
+                render_version: 0,
+                lines: vec![SnapshotLine::from_line(&styled_line())],
+            }],
+        };
+        snapshot.save(dir.path()).unwrap();
+        assert_eq!(
+            RenderSnapshot::load(dir.path(), "thread/001"),
+            Some(snapshot.clone())
+        );
+        assert_eq!(RenderSnapshot::load(dir.path(), "thread-002"), None);
+
+        for i in 0..MAX_SNAPSHOTS + 2 {
+            let mut other = snapshot.clone();
+            other.thread_id = format!("thread-{}", i);
+            other.save(dir.path()).unwrap();
+        }
+        let count = std::fs::read_dir(dir.path()).unwrap().count();
+        assert_eq!(count, MAX_SNAPSHOTS);
+    }
+
+    #[test]
+    fn test_leaving_and_reopening_thread_restores_render_caches() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = App::default();
+        app.render_snapshot_dir = Some(dir.path().to_path_buf());
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.rendered_lines_cache.invalidate_if_width_changed(80);
+        app.rendered_lines_cache
+            .insert("thread-001", 7, 2, vec![styled_line()]);
+        app.height_cache = Some(crate::app::CachedHeights::new(
+            std::sync::Arc::new("thread-001".to_string()),
+            78,
+        ));
+        app.height_cache.as_mut().unwrap().append(7, 2, 4);
+
+        app.save_render_snapshot();
+
+        // A fresh start at the same width
+        let mut app = App::default();
+        app.render_snapshot_dir = Some(dir.path().to_path_buf());
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.load_render_snapshot("thread-001");
+        app.rendered_lines_cache.invalidate_if_width_changed(80);
+        app.apply_render_snapshot();
+
+        assert_eq!(
+            app.rendered_lines_cache.get("thread-001", 7, 2),
+            Some(&vec![styled_line()])
+        );
+        assert_eq!(app.restored_heights.get("thread-001", 78, 7, 2), Some(4));
+        assert_eq!(app.restored_heights.get("thread-001", 100, 7, 2), None);
+    }
+
+    #[test]
+    fn test_snapshot_for_other_width_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = App::default();
+        app.render_snapshot_dir = Some(dir.path().to_path_buf());
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.rendered_lines_cache.invalidate_if_width_changed(80);
+        app.rendered_lines_cache
+            .insert("thread-001", 7, 2, vec![styled_line()]);
+        app.save_render_snapshot();
+
+        app.rendered_lines_cache.invalidate_if_width_changed(120);
+        app.load_render_snapshot("thread-001");
+        app.apply_render_snapshot();
+        assert!(app.rendered_lines_cache.is_empty());
+        assert!(app.pending_render_snapshot.is_none());
+    }
+}
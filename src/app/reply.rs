@@ -0,0 +1,158 @@
+//! Replying to a specific earlier message.
+//!
+//! With a search match focused in the conversation, `r` marks that message as
+//! the one the next submission replies to. A quoted chip shows the reference
+//! above the input until the message is sent; the request carries the
+//! message's ID in `reply_to` and the sent message shows the quote above it.
+//! Ctrl+C clears the reference along with the input.
+
+use std::time::Duration;
+
+use crate::models::MessageRole;
+
+use super::{App, Focus, PendingReply};
+
+/// Most characters of the replied-to message shown in a chip
+pub const REPLY_EXCERPT_CHARS: usize = 60;
+
+/// How long the "Replying to ..." confirmation stays visible
+const REPLY_NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+impl App {
+    /// Reply to the message of the focused search match.
+    ///
+    /// Returns false (leaving `r` to retry) when no match is focused or the
+    /// message has no backend ID yet.
+    pub fn reply_to_focused_message(&mut self) -> bool {
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return false;
+        };
+        let Some(message) = self
+            .message_search
+            .active
+            .then(|| self.message_search.current_match())
+            .flatten()
+            .and_then(|m| self.cache.get_messages(&thread_id)?.get(m.message_index))
+            .filter(|m| m.id != 0 && m.role != MessageRole::System)
+        else {
+            return false;
+        };
+
+        let reply = PendingReply {
+            thread_id,
+            message_id: message.id,
+            excerpt: message.excerpt(REPLY_EXCERPT_CHARS),
+        };
+        self.set_status_notice(
+            format!("Replying to \u{201c}{}\u{201d}", reply.excerpt),
+            REPLY_NOTICE_DURATION,
+        );
+        self.pending_reply = Some(reply);
+        self.close_message_search();
+        self.focus = Focus::Input;
+        self.mark_dirty();
+        true
+    }
+
+    /// Reply reference to show above the input of the active thread
+    pub fn active_pending_reply(&self) -> Option<&PendingReply> {
+        self.pending_reply
+            .as_ref()
+            .filter(|r| self.active_thread_id.as_deref() == Some(r.thread_id.as_str()))
+    }
+
+    /// Drop the reply reference
+    pub fn cancel_reply(&mut self) {
+        if self.pending_reply.take().is_some() {
+            self.mark_dirty();
+        }
+    }
+
+    /// Take the reply reference for a submission to `thread_id`.
+    ///
+    /// Marks the thread's newest user message (the one being sent) as the
+    /// reply and returns the replied-to message ID for the request.
+    pub(super) fn take_pending_reply(&mut self, thread_id: &str) -> Option<i64> {
+        let reply = self.pending_reply.take_if(|r| r.thread_id == thread_id)?;
+        if let Some(user_message) = self
+            .cache
+            .get_messages_mut(thread_id)
+            .and_then(|messages| messages.iter_mut().rfind(|m| m.role == MessageRole::User))
+        {
+            user_message.reply_to = Some(reply.message_id);
+        }
+        Some(reply.message_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Screen;
+    use crate::cache::{MessageMatch, ThreadCache};
+
+    fn app_with_focused_match() -> (App, usize) {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        let messages = app.cache.get_messages("thread-001").unwrap();
+        let index = messages
+            .iter()
+            .position(|m| m.role == MessageRole::Assistant)
+            .unwrap();
+        app.message_search.active = true;
+        app.message_search.matches = vec![MessageMatch {
+            message_index: index,
+            start: 0,
+            end: 1,
+        }];
+        (app, index)
+    }
+
+    #[test]
+    fn test_reply_to_focused_message_sets_pending_reply() {
+        let (mut app, index) = app_with_focused_match();
+        let expected_id = app.cache.get_messages("thread-001").unwrap()[index].id;
+
+        assert!(app.reply_to_focused_message());
+
+        let reply = app.active_pending_reply().unwrap();
+        assert_eq!(reply.message_id, expected_id);
+        assert!(!reply.excerpt.is_empty());
+        assert!(!app.message_search.active);
+        assert_eq!(app.focus, Focus::Input);
+    }
+
+    #[test]
+    fn test_reply_without_focused_match_falls_through() {
+        let (mut app, _) = app_with_focused_match();
+        app.message_search.active = false;
+        assert!(!app.reply_to_focused_message());
+        assert!(app.pending_reply.is_none());
+    }
+
+    #[test]
+    fn test_take_pending_reply_marks_sent_message() {
+        let (mut app, index) = app_with_focused_match();
+        let expected_id = app.cache.get_messages("thread-001").unwrap()[index].id;
+        app.reply_to_focused_message();
+
+        // Another thread's submission leaves the reference alone
+        assert_eq!(app.take_pending_reply("thread-002"), None);
+        assert!(app.pending_reply.is_some());
+
+        app.cache
+            .add_streaming_message("thread-001", "Why this one?".to_string(), Vec::new());
+        assert_eq!(app.take_pending_reply("thread-001"), Some(expected_id));
+        assert!(app.pending_reply.is_none());
+
+        let messages = app.cache.get_messages("thread-001").unwrap();
+        let sent = messages
+            .iter()
+            .rfind(|m| m.role == MessageRole::User)
+            .unwrap();
+        assert_eq!(sent.content, "Why this one?");
+        assert_eq!(sent.reply_to, Some(expected_id));
+    }
+}
@@ -0,0 +1,314 @@
+//! Debounced terminal resizes.
+//!
+//! Dragging the window between monitors sends a burst of resize events, and
+//! every width change throws away all cached message layouts. Instead of
+//! laying out again for each event, the new size is held until it has been
+//! stable for `RESIZE_SETTLE` and then applied once; drawing is skipped in
+//! the meantime, so the terminal keeps showing the last frame.
+//!
+//! A reader who scrolled up keeps their place. `unified_scroll` and
+//! `max_scroll` count lines at the old width, so when the size changes the
+//! message at the top of the viewport (and how far into it) is looked up in
+//! the old heights, the heights are rebuilt for the new width and the anchor
+//! is translated back into a scroll offset right away, keeping scrolling
+//! consistent until the next frame. That frame then scrolls the anchor to
+//! the top again with the exact heights. A reader following the stream stays
+//! at the bottom.
+
+use std::time::{Duration, Instant};
+
+use super::{App, MessageViewport, Screen};
+
+/// How long the terminal size must stay unchanged before it is applied
+pub const RESIZE_SETTLE: Duration = Duration::from_millis(120);
+
+/// The message at the top of the viewport and how far into it the viewport
+/// starts (0.0 = its first line)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollAnchor {
+    /// Message at the top of the viewport
+    pub message_id: i64,
+    /// Fraction of the message's height scrolled past
+    pub fraction: f32,
+}
+
+/// A resize waiting for the size to settle
+#[derive(Debug, Clone, Copy)]
+pub struct PendingResize {
+    pub width: u16,
+    pub height: u16,
+    /// When the last resize event arrived
+    pub at: Instant,
+}
+
+impl App {
+    /// Record a resize event; it is applied by `apply_settled_resize` once
+    /// no further resizes arrive for `RESIZE_SETTLE`
+    pub fn queue_resize(&mut self, width: u16, height: u16) {
+        self.pending_resize = Some(PendingResize {
+            width,
+            height,
+            at: Instant::now(),
+        });
+    }
+
+    /// Whether a resize is waiting to settle (drawing is skipped until then)
+    pub fn resize_pending(&self) -> bool {
+        self.pending_resize.is_some()
+    }
+
+    /// Apply a queued resize if the size has settled. Called every tick.
+    ///
+    /// Returns true if the resize was applied.
+    pub fn apply_settled_resize(&mut self) -> bool {
+        self.apply_settled_resize_at(Instant::now())
+    }
+
+    pub(crate) fn apply_settled_resize_at(&mut self, now: Instant) -> bool {
+        let Some(resize) = self.pending_resize else {
+            return false;
+        };
+        if now.saturating_duration_since(resize.at) < RESIZE_SETTLE {
+            return false;
+        }
+        self.pending_resize = None;
+
+        if (resize.width, resize.height) != (self.terminal_width, self.terminal_height) {
+            // Restore the reader's place once the new layout is known
+            self.pending_scroll_anchor = self.scroll_anchor;
+            self.update_terminal_dimensions(resize.width, resize.height);
+        }
+        // The terminal may have been redrawn at an intermediate size
+        self.mark_dirty();
+        true
+    }
+
+    /// Keep the conversation's scroll position across a size change from
+    /// `old_width` x `old_height` to the current size
+    pub(super) fn reanchor_scroll_after_resize(&mut self, old_width: u16, old_height: u16) {
+        if self.screen != Screen::Conversation {
+            return;
+        }
+        // Following the stream: stay pinned to the bottom
+        if !self.user_has_scrolled || self.unified_scroll == 0 {
+            self.unified_scroll = 0;
+            self.scroll_position = 0.0;
+            self.scroll_velocity = 0.0;
+            return;
+        }
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return;
+        };
+        // Heights are laid out 1 cell inside the frame on each side
+        let old_message_width = old_width.saturating_sub(2) as usize;
+        let Some(old) = self
+            .height_cache
+            .as_ref()
+            .filter(|cache| cache.is_valid_for(&thread_id, old_message_width))
+        else {
+            return;
+        };
+
+        // Where the viewport was, in old lines
+        let viewport = self.message_viewport;
+        let max_scroll = self.max_scroll as usize;
+        let scroll_from_top = max_scroll.saturating_sub(self.unified_scroll as usize);
+        let pinned_to_top = self.unified_scroll as usize >= max_scroll;
+        let header = scroll_from_top.saturating_sub(viewport.top);
+        // Lines above and below the messages (header rows, input, banners)
+        let around = (max_scroll + viewport.height).saturating_sub(old.total_lines);
+        let Some(index) = old
+            .heights
+            .iter()
+            .rposition(|h| h.cumulative_offset <= viewport.top)
+        else {
+            return;
+        };
+        let top_height = &old.heights[index];
+        let anchor = ScrollAnchor {
+            message_id: top_height.message_id,
+            fraction: (viewport.top - top_height.cumulative_offset) as f32
+                / top_height.visual_lines.max(1) as f32,
+        };
+
+        let message_width = self.terminal_width.saturating_sub(2) as usize;
+        crate::ui::prepare::prepare_message_heights(self, message_width);
+        let Some(new) = self.height_cache.as_ref() else {
+            return;
+        };
+        let Some(anchored) = new
+            .heights
+            .iter()
+            .find(|h| h.message_id == anchor.message_id)
+        else {
+            return;
+        };
+
+        // The same message and fraction, in new lines
+        let viewport_height = (viewport.height as isize + self.terminal_height as isize
+            - old_height as isize)
+            .max(1) as usize;
+        let max_scroll = (new.total_lines + around).saturating_sub(viewport_height);
+        let top =
+            anchored.cumulative_offset + (anchor.fraction * anchored.visual_lines as f32) as usize;
+        let unified_scroll = if pinned_to_top {
+            max_scroll
+        } else {
+            max_scroll - (header + top).min(max_scroll)
+        };
+
+        self.max_scroll = max_scroll.min(u16::MAX as usize) as u16;
+        self.unified_scroll = unified_scroll.min(u16::MAX as usize) as u16;
+        self.scroll_position = self.unified_scroll as f32;
+        self.scroll_velocity = 0.0;
+        self.message_viewport = MessageViewport {
+            top,
+            height: viewport_height,
+        };
+        // The next frame refines the position with the exact heights
+        if !pinned_to_top {
+            self.pending_scroll_anchor = Some(anchor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MessageRole;
+
+    const THREAD: &str = "resize-thread";
+    /// Rows below the messages (input and hints) in the simulated frames
+    const FOOTER: usize = 4;
+    const VIEWPORT: usize = 30;
+
+    /// A long thread at 120x40, laid out and scrolled so message line `top`
+    /// is at the top of the viewport, as the renderer would leave it
+    fn scrolled_app(top: usize) -> App {
+        let mut app = App::default();
+        app.update_terminal_dimensions(120, 40);
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some(THREAD.to_string());
+        for i in 0..300 {
+            let role = if i % 2 == 0 {
+                MessageRole::User
+            } else {
+                MessageRole::Assistant
+            };
+            let text = format!("Message {} {}", i, "wrapping words ".repeat(i % 37));
+            app.cache.add_message_simple(THREAD, role, text);
+        }
+        crate::ui::prepare::prepare_render(&mut app, 120);
+
+        let total = app.height_cache.as_ref().unwrap().total_lines;
+        app.max_scroll = (total + FOOTER - VIEWPORT) as u16;
+        app.unified_scroll = app.max_scroll - top as u16;
+        app.user_has_scrolled = true;
+        app.message_viewport = MessageViewport {
+            top,
+            height: VIEWPORT,
+        };
+        app
+    }
+
+    /// ID of the message holding message line `top`
+    fn message_at(app: &App, top: usize) -> i64 {
+        let heights = &app.height_cache.as_ref().unwrap().heights;
+        let index = heights
+            .iter()
+            .rposition(|h| h.cumulative_offset <= top)
+            .unwrap();
+        heights[index].message_id
+    }
+
+    #[test]
+    fn test_resize_storm_applies_last_size_once_settled() {
+        let mut app = App::default();
+        app.update_terminal_dimensions(80, 24);
+
+        app.queue_resize(100, 30);
+        app.queue_resize(140, 40);
+        let last = app.pending_resize.unwrap().at;
+
+        assert!(!app.apply_settled_resize_at(last + RESIZE_SETTLE / 2));
+        assert_eq!(app.terminal_width, 80);
+        assert!(app.resize_pending());
+
+        assert!(app.apply_settled_resize_at(last + RESIZE_SETTLE));
+        assert_eq!((app.terminal_width, app.terminal_height), (140, 40));
+        assert!(!app.resize_pending());
+        assert!(!app.apply_settled_resize_at(last + RESIZE_SETTLE * 2));
+    }
+
+    #[test]
+    fn test_width_change_keeps_top_message_at_several_positions() {
+        let total = scrolled_app(0).height_cache.unwrap().total_lines;
+        for top in [0, total / 5, total / 2, total * 4 / 5, total - VIEWPORT] {
+            let mut app = scrolled_app(top);
+            let before = message_at(&app, top);
+
+            app.update_terminal_dimensions(60, 40);
+
+            let cache = app.height_cache.as_ref().unwrap();
+            assert_eq!(cache.viewport_width, 58);
+            // The last lines stay reachable at the new width
+            assert_eq!(
+                app.max_scroll as usize,
+                cache.total_lines + FOOTER - VIEWPORT
+            );
+            let new_top = (app.max_scroll - app.unified_scroll) as usize;
+            assert_eq!(message_at(&app, new_top), before, "top line {}", top);
+            // At the very top the scroll stays pinned there, no anchor needed
+            let expected_anchor = (top > 0).then_some(before);
+            assert_eq!(
+                app.pending_scroll_anchor.map(|a| a.message_id),
+                expected_anchor
+            );
+
+            // And back again lands on the same message
+            app.update_terminal_dimensions(120, 40);
+            let new_top = (app.max_scroll - app.unified_scroll) as usize;
+            assert_eq!(message_at(&app, new_top), before, "top line {}", top);
+        }
+    }
+
+    #[test]
+    fn test_resize_keeps_following_and_top_pinned() {
+        let mut following = scrolled_app(0);
+        following.unified_scroll = 0;
+        following.user_has_scrolled = false;
+        following.update_terminal_dimensions(60, 30);
+        assert_eq!(following.unified_scroll, 0);
+        assert!(!following.user_has_scrolled);
+        assert!(following.pending_scroll_anchor.is_none());
+
+        let mut at_top = scrolled_app(0);
+        at_top.unified_scroll = at_top.max_scroll;
+        at_top.update_terminal_dimensions(60, 40);
+        assert!(at_top.max_scroll > 0);
+        assert_eq!(at_top.unified_scroll, at_top.max_scroll);
+    }
+
+    #[test]
+    fn test_settled_resize_restores_scroll_anchor() {
+        let mut app = App::default();
+        app.update_terminal_dimensions(80, 24);
+        let anchor = ScrollAnchor {
+            message_id: 3,
+            fraction: 0.5,
+        };
+        app.scroll_anchor = Some(anchor);
+
+        app.queue_resize(120, 24);
+        let at = app.pending_resize.unwrap().at;
+        app.apply_settled_resize_at(at + RESIZE_SETTLE);
+        assert_eq!(app.pending_scroll_anchor, Some(anchor));
+
+        // Settling back on the same size keeps the current layout
+        app.pending_scroll_anchor = None;
+        app.queue_resize(120, 24);
+        let at = app.pending_resize.unwrap().at;
+        app.apply_settled_resize_at(at + RESIZE_SETTLE);
+        assert_eq!(app.pending_scroll_anchor, None);
+    }
+}
@@ -0,0 +1,186 @@
+//! Retrying the last message after a stream error.
+//!
+//! `r` in the Conversation screen re-sends the last user message of the
+//! active thread after its stream failed: the failed or partial response is
+//! dropped and a new stream is issued with the thread's type and permission
+//! mode. A response that completed isn't re-sent. Consecutive failures are
+//! counted per thread; once `MAX_RETRY_FAILURES` is reached the next retry
+//! shows an inline error instead of sending again.
+
+use std::time::Duration;
+
+use crate::models::PermissionMode;
+
+use super::App;
+
+/// Consecutive stream failures before retrying asks the user to check connectivity
+pub(crate) const MAX_RETRY_FAILURES: u8 = 3;
+
+impl App {
+    /// Re-send the last user message on the active thread.
+    ///
+    /// Attached images are not re-sent; only the message text is.
+    /// Returns true if a new stream was started.
+    pub fn retry_last_message(&mut self) -> bool {
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return false;
+        };
+
+        if self.is_streaming() {
+            self.set_timed_error(
+                "Cannot retry while streaming — wait for response to finish".to_string(),
+                Duration::from_secs(3),
+            );
+            return false;
+        }
+
+        let Some(failures) = self.stream_failures.get(&thread_id).copied() else {
+            self.set_timed_error(
+                "No failed response to retry".to_string(),
+                Duration::from_secs(3),
+            );
+            return false;
+        };
+        if failures >= MAX_RETRY_FAILURES {
+            // Surface the pattern once, then let the next retry go through
+            self.stream_failures.insert(thread_id.clone(), 0);
+            self.add_error_to_active_thread(
+                "retry_failed".to_string(),
+                format!(
+                    "Failed {} times in a row. Check your network connection, then press r to retry.",
+                    failures
+                ),
+            );
+            return false;
+        }
+
+        let Some(content) = self.cache.begin_retry(&thread_id) else {
+            self.set_timed_error("No message to retry".to_string(), Duration::from_secs(3));
+            return false;
+        };
+
+        let permission_mode = self
+            .cache
+            .get_thread(&thread_id)
+            .and_then(|t| t.permission_mode.as_deref())
+            .and_then(parse_permission_mode)
+            .unwrap_or(self.permission_mode);
+        self.stream_error = None;
+        let request =
+            self.stream_request_for(&thread_id, content, permission_mode, (None, Vec::new()));
+        self.mark_dirty();
+        self.spawn_stream_request(request, thread_id);
+        true
+    }
+}
+
+/// Parse a thread's stored permission mode (e.g. "plan", "bypassPermissions")
+fn parse_permission_mode(mode: &str) -> Option<PermissionMode> {
+    serde_json::from_value(serde_json::Value::String(mode.to_string())).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{AppMessage, Screen};
+    use crate::cache::ThreadCache;
+
+    fn app_with_failed_stream() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.cache
+            .add_streaming_message("thread-001", "Fix the build".to_string(), Vec::new());
+        app.cache.append_to_message("thread-001", "Looking at");
+        app.handle_message(AppMessage::StreamError {
+            thread_id: "thread-001".to_string(),
+            error: "Server error".to_string(),
+            error_code: None,
+        });
+        app
+    }
+
+    #[test]
+    fn test_parse_permission_mode() {
+        assert_eq!(parse_permission_mode("plan"), Some(PermissionMode::Plan));
+        assert_eq!(
+            parse_permission_mode("bypassPermissions"),
+            Some(PermissionMode::Execution)
+        );
+        assert_eq!(parse_permission_mode("unknown"), None);
+    }
+
+    #[test]
+    fn test_stream_errors_are_counted() {
+        let mut app = app_with_failed_stream();
+        assert_eq!(app.stream_failures.get("thread-001"), Some(&1));
+
+        app.handle_message(AppMessage::StreamComplete {
+            thread_id: "thread-001".to_string(),
+            message_id: 10,
+        });
+        assert!(app.stream_failures.get("thread-001").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_retry_resends_last_user_message() {
+        let mut app = app_with_failed_stream();
+
+        assert!(app.retry_last_message());
+
+        let messages = app.cache.get_messages("thread-001").unwrap();
+        let last = messages.last().unwrap();
+        assert!(last.is_streaming);
+        assert!(last.partial_content.is_empty());
+        assert_eq!(messages[messages.len() - 2].content, "Fix the build");
+        assert!(app.stream_error.is_none());
+    }
+
+    #[test]
+    fn test_retry_blocked_while_streaming() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.active_thread_id = Some("thread-001".to_string());
+        app.cache
+            .add_streaming_message("thread-001", "hi".to_string(), Vec::new());
+
+        assert!(!app.retry_last_message());
+        assert!(app.stream_error.is_some());
+    }
+
+    #[test]
+    fn test_retry_after_repeated_failures_shows_inline_error() {
+        let mut app = app_with_failed_stream();
+        app.stream_failures
+            .insert("thread-001".to_string(), MAX_RETRY_FAILURES);
+        let errors_before = app.cache.error_count("thread-001");
+        let messages_before = app.cache.get_messages("thread-001").unwrap().len();
+
+        assert!(!app.retry_last_message());
+
+        assert_eq!(app.cache.error_count("thread-001"), errors_before + 1);
+        assert_eq!(
+            app.cache.get_messages("thread-001").unwrap().len(),
+            messages_before
+        );
+        // The next retry is allowed again
+        assert_eq!(app.stream_failures.get("thread-001"), Some(&0));
+    }
+
+    #[test]
+    fn test_retry_needs_a_failed_stream() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        let messages_before = app.cache.get_messages("thread-001").unwrap().len();
+
+        assert!(!app.retry_last_message());
+        assert_eq!(
+            app.cache.get_messages("thread-001").unwrap().len(),
+            messages_before
+        );
+        assert!(app.stream_error.is_some());
+    }
+}
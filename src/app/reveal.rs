@@ -0,0 +1,479 @@
+//! Simulated typing (progressive reveal) for streamed responses.
+//!
+//! The backend sometimes delivers large chunks at once. When pacing is
+//! enabled, [`RevealPacer`] buffers streamed text per thread and releases it
+//! at a fixed rate on each tick, independent of network arrival. Stream
+//! events that must stay ordered after the text (tool events, completion,
+//! errors) are queued behind it. Any keypress reveals everything instantly.
+//!
+//! Pacing is off by default. Set `SPOQ_REVEAL_RATE` (characters per second)
+//! to enable it at startup, or toggle it with `/typing`.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use super::{App, AppMessage};
+
+/// Environment variable holding the reveal rate in characters per second
+pub const REVEAL_RATE_ENV: &str = "SPOQ_REVEAL_RATE";
+
+/// Rate used when pacing is toggled on without a configured rate
+pub const DEFAULT_REVEAL_RATE: u32 = 300;
+
+/// An item waiting to be revealed for a thread
+#[derive(Debug, Clone)]
+enum RevealItem {
+    /// Streamed text not yet appended to the message
+    Text(String),
+    /// A stream event held back until the text before it is revealed
+    Message(Box<AppMessage>),
+}
+
+/// A step released by the pacer, to be applied in order
+#[derive(Debug, Clone)]
+pub enum RevealStep {
+    /// Append text to the thread's streaming message
+    Text { thread_id: String, text: String },
+    /// Handle a deferred message
+    Message(AppMessage),
+}
+
+/// Buffers streamed text and releases it at a fixed character rate.
+#[derive(Debug, Clone, Default)]
+pub struct RevealPacer {
+    /// Reveal rate in characters per second (None = pacing disabled)
+    rate: Option<u32>,
+    /// Rate to use when pacing is toggled back on
+    configured_rate: u32,
+    /// Pending items per thread, oldest first
+    pending: HashMap<String, VecDeque<RevealItem>>,
+    /// Fractional character budget carried between ticks
+    carry: f64,
+    /// When characters were last released
+    last_drain: Option<Instant>,
+}
+
+impl RevealPacer {
+    /// Create a pacer revealing `rate` characters per second (None = disabled)
+    pub fn new(rate: Option<u32>) -> Self {
+        let rate = rate.filter(|r| *r > 0);
+        Self {
+            rate,
+            configured_rate: rate.unwrap_or(DEFAULT_REVEAL_RATE),
+            ..Default::default()
+        }
+    }
+
+    /// Create a pacer from the `SPOQ_REVEAL_RATE` environment variable
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var(REVEAL_RATE_ENV)
+                .ok()
+                .and_then(|v| v.trim().parse::<u32>().ok()),
+        )
+    }
+
+    /// Whether pacing is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.rate.is_some()
+    }
+
+    /// Current reveal rate in characters per second, if enabled
+    pub fn rate(&self) -> Option<u32> {
+        self.rate
+    }
+
+    /// Enable pacing at the configured rate, or disable it.
+    ///
+    /// Disabling does not release buffered items; callers should flush them.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.rate = enabled.then_some(self.configured_rate);
+        self.carry = 0.0;
+        self.last_drain = None;
+    }
+
+    /// Whether any thread has items waiting to be revealed
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Whether a thread has items waiting to be revealed
+    pub fn has_pending_for(&self, thread_id: &str) -> bool {
+        self.pending.contains_key(thread_id)
+    }
+
+    /// Buffer streamed text for a thread.
+    ///
+    /// Returns the text back if pacing is disabled and nothing is pending,
+    /// meaning it should be applied immediately.
+    pub fn push_text(&mut self, thread_id: &str, text: String) -> Option<String> {
+        if !self.is_enabled() && !self.has_pending_for(thread_id) {
+            return Some(text);
+        }
+        if self.pending.is_empty() {
+            // Start the clock at the first buffered token so the first tick
+            // does not release a backlog accumulated while idle
+            self.last_drain = Some(Instant::now());
+            self.carry = 0.0;
+        }
+        let queue = self.pending.entry(thread_id.to_string()).or_default();
+        match queue.back_mut() {
+            Some(RevealItem::Text(buffered)) => buffered.push_str(&text),
+            _ => queue.push_back(RevealItem::Text(text)),
+        }
+        None
+    }
+
+    /// Queue a message behind a thread's buffered text.
+    ///
+    /// Returns the message back if the thread has nothing pending, meaning it
+    /// should be handled immediately.
+    pub fn defer_message(&mut self, thread_id: &str, msg: AppMessage) -> Option<AppMessage> {
+        match self.pending.get_mut(thread_id) {
+            Some(queue) => {
+                queue.push_back(RevealItem::Message(Box::new(msg)));
+                None
+            }
+            None => Some(msg),
+        }
+    }
+
+    /// Release characters for the time elapsed since the last drain.
+    pub fn drain_due(&mut self) -> Vec<RevealStep> {
+        let now = Instant::now();
+        let elapsed = self
+            .last_drain
+            .map_or(Duration::ZERO, |last| now.duration_since(last));
+        self.last_drain = Some(now);
+        self.drain(elapsed)
+    }
+
+    /// Release up to `elapsed * rate` characters from every thread.
+    ///
+    /// Deferred messages are released as soon as the text before them is.
+    pub fn drain(&mut self, elapsed: Duration) -> Vec<RevealStep> {
+        let Some(rate) = self.rate else {
+            return self.flush_all();
+        };
+
+        self.carry += elapsed.as_secs_f64() * f64::from(rate);
+        let budget = self.carry.floor() as usize;
+        self.carry -= budget as f64;
+
+        let mut thread_ids: Vec<String> = self.pending.keys().cloned().collect();
+        thread_ids.sort();
+
+        let mut steps = Vec::new();
+        for thread_id in thread_ids {
+            let mut remaining = budget;
+            let Some(queue) = self.pending.get_mut(&thread_id) else {
+                continue;
+            };
+            while let Some(item) = queue.front_mut() {
+                match item {
+                    RevealItem::Message(_) => {
+                        if let Some(RevealItem::Message(msg)) = queue.pop_front() {
+                            steps.push(RevealStep::Message(*msg));
+                        }
+                    }
+                    RevealItem::Text(text) => {
+                        if remaining == 0 {
+                            break;
+                        }
+                        let split = text
+                            .char_indices()
+                            .nth(remaining)
+                            .map_or(text.len(), |(idx, _)| idx);
+                        let revealed: String = text.drain(..split).collect();
+                        remaining -= revealed.chars().count();
+                        if text.is_empty() {
+                            queue.pop_front();
+                        }
+                        steps.push(RevealStep::Text {
+                            thread_id: thread_id.clone(),
+                            text: revealed,
+                        });
+                    }
+                }
+            }
+            if queue.is_empty() {
+                self.pending.remove(&thread_id);
+            }
+        }
+        steps
+    }
+
+    /// Release everything pending for one thread
+    pub fn flush(&mut self, thread_id: &str) -> Vec<RevealStep> {
+        self.pending
+            .remove(thread_id)
+            .map(|queue| Self::into_steps(thread_id, queue))
+            .unwrap_or_default()
+    }
+
+    /// Release everything pending for all threads
+    pub fn flush_all(&mut self) -> Vec<RevealStep> {
+        let mut pending: Vec<_> = self.pending.drain().collect();
+        pending.sort_by(|a, b| a.0.cmp(&b.0));
+        pending
+            .into_iter()
+            .flat_map(|(thread_id, queue)| Self::into_steps(&thread_id, queue))
+            .collect()
+    }
+
+    /// Drop everything pending for a thread without revealing it
+    pub fn discard(&mut self, thread_id: &str) {
+        self.pending.remove(thread_id);
+    }
+
+    fn into_steps(thread_id: &str, queue: VecDeque<RevealItem>) -> Vec<RevealStep> {
+        queue
+            .into_iter()
+            .map(|item| match item {
+                RevealItem::Text(text) => RevealStep::Text {
+                    thread_id: thread_id.to_string(),
+                    text,
+                },
+                RevealItem::Message(msg) => RevealStep::Message(*msg),
+            })
+            .collect()
+    }
+}
+
+/// Thread whose buffered text a message must stay ordered behind, if any
+pub(super) fn deferred_thread_id(msg: &AppMessage) -> Option<&str> {
+    match msg {
+        AppMessage::StreamComplete { thread_id, .. }
+        | AppMessage::StreamError { thread_id, .. }
+        | AppMessage::StreamCancelled { thread_id, .. }
+        | AppMessage::StreamInterrupted { thread_id, .. }
+        | AppMessage::StreamResumeFailed { thread_id, .. }
+        | AppMessage::RateLimited { thread_id, .. }
+        | AppMessage::ToolStarted { thread_id, .. }
+        | AppMessage::ToolExecuting { thread_id, .. }
+        | AppMessage::ToolCompleted { thread_id, .. }
+        | AppMessage::ToolArgumentChunk { thread_id, .. } => Some(thread_id),
+        _ => None,
+    }
+}
+
+impl App {
+    /// Route a message through the reveal pacer.
+    ///
+    /// Returns the message if it should be handled now, or None if it was
+    /// buffered (stream text) or queued behind buffered text.
+    pub(crate) fn pace_message(&mut self, msg: AppMessage) -> Option<AppMessage> {
+        if let AppMessage::StreamToken { thread_id, token } = msg {
+            return self
+                .reveal
+                .push_text(&thread_id, token)
+                .map(|token| AppMessage::StreamToken { thread_id, token });
+        }
+        if !self.reveal.has_pending() {
+            return Some(msg);
+        }
+        match deferred_thread_id(&msg).map(str::to_string) {
+            Some(thread_id) => self.reveal.defer_message(&thread_id, msg),
+            None => Some(msg),
+        }
+    }
+
+    /// Reveal buffered text due since the last tick
+    pub(crate) fn tick_reveal(&mut self) {
+        if self.reveal.has_pending() {
+            let steps = self.reveal.drain_due();
+            self.apply_reveal_steps(steps);
+        }
+    }
+
+    /// Reveal all buffered text immediately (e.g. on keypress)
+    pub fn reveal_all_pending(&mut self) {
+        if self.reveal.has_pending() {
+            let steps = self.reveal.flush_all();
+            self.apply_reveal_steps(steps);
+        }
+    }
+
+    /// Toggle simulated typing, revealing anything buffered when turning it off
+    pub fn toggle_reveal_pacing(&mut self) {
+        self.flush_stream_tokens();
+        let enable = !self.reveal.is_enabled();
+        if !enable {
+            self.reveal_all_pending();
+        }
+        self.reveal.set_enabled(enable);
+        self.mark_dirty();
+    }
+
+    fn apply_reveal_steps(&mut self, steps: Vec<RevealStep>) {
+        if steps.is_empty() {
+            return;
+        }
+        for step in steps {
+            match step {
+                RevealStep::Text { thread_id, text } => {
+                    self.dispatch_message(AppMessage::StreamToken {
+                        thread_id,
+                        token: text,
+                    });
+                }
+                RevealStep::Message(msg) => self.dispatch_message(msg),
+            }
+        }
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn paced_app() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.reveal = RevealPacer::new(Some(100));
+        app.cache
+            .add_streaming_message("thread-001", "hi".to_string(), Vec::new());
+        app
+    }
+
+    fn streaming_content(app: &App) -> String {
+        app.cache
+            .get_messages("thread-001")
+            .unwrap()
+            .last()
+            .unwrap()
+            .partial_content
+            .clone()
+    }
+
+    #[test]
+    fn test_disabled_pacer_passes_text_through() {
+        let mut pacer = RevealPacer::new(None);
+        assert!(!pacer.is_enabled());
+        assert_eq!(pacer.push_text("t", "abc".to_string()), Some("abc".to_string()));
+        assert!(!pacer.has_pending());
+    }
+
+    #[test]
+    fn test_zero_rate_disables_pacing() {
+        assert!(!RevealPacer::new(Some(0)).is_enabled());
+    }
+
+    #[test]
+    fn test_drain_releases_chars_at_rate() {
+        let mut pacer = RevealPacer::new(Some(10));
+        assert!(pacer.push_text("t", "hello ".to_string()).is_none());
+        assert!(pacer.push_text("t", "wörld".to_string()).is_none());
+
+        let steps = pacer.drain(Duration::from_millis(800));
+        match steps.as_slice() {
+            [RevealStep::Text { text, .. }] => assert_eq!(text, "hello wö"),
+            other => panic!("unexpected steps: {:?}", other),
+        }
+
+        let steps = pacer.drain(Duration::from_secs(1));
+        match steps.as_slice() {
+            [RevealStep::Text { text, .. }] => assert_eq!(text, "rld"),
+            other => panic!("unexpected steps: {:?}", other),
+        }
+        assert!(!pacer.has_pending());
+    }
+
+    #[test]
+    fn test_drain_carries_fractional_budget() {
+        let mut pacer = RevealPacer::new(Some(10));
+        pacer.push_text("t", "abc".to_string());
+
+        // 50ms at 10 chars/sec = 0.5 chars, nothing yet
+        assert!(pacer.drain(Duration::from_millis(50)).is_empty());
+        // Another 0.5 chars completes one character
+        assert_eq!(pacer.drain(Duration::from_millis(50)).len(), 1);
+    }
+
+    #[test]
+    fn test_deferred_message_waits_for_text() {
+        let mut pacer = RevealPacer::new(Some(10));
+        pacer.push_text("t", "abcdef".to_string());
+        let msg = AppMessage::StreamComplete {
+            thread_id: "t".to_string(),
+            message_id: 1,
+        };
+        assert!(pacer.defer_message("t", msg).is_none());
+
+        let steps = pacer.drain(Duration::from_millis(300));
+        assert_eq!(steps.len(), 1);
+
+        let steps = pacer.drain(Duration::from_secs(1));
+        assert!(matches!(steps[0], RevealStep::Text { .. }));
+        assert!(matches!(
+            steps[1],
+            RevealStep::Message(AppMessage::StreamComplete { .. })
+        ));
+        assert!(!pacer.has_pending());
+    }
+
+    #[test]
+    fn test_defer_message_without_pending_returns_it() {
+        let mut pacer = RevealPacer::new(Some(10));
+        let msg = AppMessage::StreamCancelled {
+            thread_id: "t".to_string(),
+            reason: "user".to_string(),
+        };
+        assert!(pacer.defer_message("t", msg).is_some());
+    }
+
+    #[test]
+    fn test_tokens_are_buffered_until_revealed() {
+        let mut app = paced_app();
+
+        app.handle_message(AppMessage::StreamToken {
+            thread_id: "thread-001".to_string(),
+            token: "Hello world".to_string(),
+        });
+        assert_eq!(streaming_content(&app), "");
+
+        app.reveal_all_pending();
+        assert_eq!(streaming_content(&app), "Hello world");
+    }
+
+    #[test]
+    fn test_completion_is_ordered_after_buffered_text() {
+        let mut app = paced_app();
+        app.handle_message(AppMessage::StreamToken {
+            thread_id: "thread-001".to_string(),
+            token: "Full answer".to_string(),
+        });
+        app.handle_message(AppMessage::StreamComplete {
+            thread_id: "thread-001".to_string(),
+            message_id: 42,
+        });
+
+        // Still streaming: completion is queued behind the text
+        assert!(app.cache.is_thread_streaming("thread-001"));
+
+        app.reveal_all_pending();
+
+        assert!(!app.cache.is_thread_streaming("thread-001"));
+        let last = app.cache.get_messages("thread-001").unwrap().last().unwrap();
+        assert_eq!(last.content, "Full answer");
+    }
+
+    #[test]
+    fn test_toggle_off_reveals_pending() {
+        let mut app = paced_app();
+        app.handle_message(AppMessage::StreamToken {
+            thread_id: "thread-001".to_string(),
+            token: "abc".to_string(),
+        });
+
+        app.toggle_reveal_pacing();
+
+        assert!(!app.reveal.is_enabled());
+        assert_eq!(streaming_content(&app), "abc");
+
+        app.toggle_reveal_pacing();
+        assert_eq!(app.reveal.rate(), Some(100));
+    }
+}
@@ -0,0 +1,61 @@
+//! Safe mode for the App (`spoq --safe-mode`).
+//!
+//! For recovering when a bad customization or a corrupted cache makes normal
+//! startup unusable. The app starts with the built-in theme and keymap, sends
+//! no webhooks, skips the background picker/folder/repo preloads (the pickers
+//! load on demand instead) and doesn't start the credential file watcher.
+//! `main` checks `safe_mode` before each of those startup steps.
+
+use std::time::Duration;
+
+use crate::input::Keymap;
+use crate::view_state::Theme;
+use crate::webhooks::WebhookConfig;
+
+use super::App;
+
+/// How long the safe mode notice stays in the status line
+const NOTICE_DURATION: Duration = Duration::from_secs(10);
+
+impl App {
+    /// Start in safe mode: built-in defaults and no optional startup work
+    pub fn enter_safe_mode(&mut self) {
+        tracing::info!("Starting in safe mode");
+        self.safe_mode = true;
+        self.theme = Theme::default();
+        self.keymap = Keymap::default();
+        self.keymap_warnings.clear();
+        self.webhooks = WebhookConfig::default();
+        self.set_status_notice(
+            "Safe mode: default theme and keymap, no webhooks, preloads or file watchers"
+                .to_string(),
+            NOTICE_DURATION,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Action;
+
+    #[test]
+    fn test_enter_safe_mode_resets_customizations() {
+        let mut app = App::default();
+        let (keymap, warnings) = Keymap::parse("NewThread = \"alt+n\"\nBogus = \"x\"\n");
+        app.apply_keymap(keymap, warnings);
+        app.webhooks =
+            serde_json::from_str(r#"{"global": [{"url": "https://example.com/hook"}]}"#).unwrap();
+
+        app.enter_safe_mode();
+
+        assert!(app.safe_mode);
+        assert!(app.keymap_warnings.is_empty());
+        assert_eq!(app.keymap.chords_for(Action::NewThread).len(), 2);
+        assert!(app.webhooks.is_empty());
+        assert!(app
+            .status_notice
+            .as_deref()
+            .is_some_and(|n| n.starts_with("Safe mode")));
+    }
+}
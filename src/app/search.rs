@@ -0,0 +1,270 @@
+//! Message search methods for the App.
+//!
+//! Search mode is opened with `/` on the Conversation screen when the input
+//! is not focused. Typing refines the query and Enter confirms it; once
+//! confirmed, `n`/`N` cycle through matches. Esc closes search and clears
+//! the highlight. Scrolling to the current match happens at render time,
+//! where message line offsets are known. Matches are recomputed there too
+//! when the thread's messages changed (streamed tokens, loaded history).
+
+use super::{App, Screen};
+
+impl App {
+    /// Open message search for the active conversation.
+    ///
+    /// Returns false if there is no active conversation to search.
+    pub fn open_message_search(&mut self) -> bool {
+        if self.screen != Screen::Conversation || self.active_thread_id.is_none() {
+            return false;
+        }
+        self.message_search = Default::default();
+        self.message_search.active = true;
+        self.message_search.editing = true;
+        self.mark_dirty();
+        true
+    }
+
+    /// Finish typing the query so `n`/`N` navigate between matches.
+    ///
+    /// Confirming an empty query closes search.
+    pub fn confirm_message_search(&mut self) {
+        if self.message_search.query.is_empty() {
+            self.close_message_search();
+            return;
+        }
+        self.message_search.editing = false;
+        self.mark_dirty();
+    }
+
+    /// Close message search and clear match highlighting
+    pub fn close_message_search(&mut self) {
+        self.message_search = Default::default();
+        self.mark_dirty();
+    }
+
+    /// Append a character to the search query and jump to the first match
+    pub fn message_search_type_char(&mut self, c: char) {
+        self.message_search.query.push(c);
+        self.run_message_search();
+    }
+
+    /// Remove the last character from the search query
+    pub fn message_search_backspace(&mut self) {
+        if self.message_search.query.pop().is_some() {
+            self.run_message_search();
+        }
+    }
+
+    /// Jump to the next match (wraps around)
+    pub fn message_search_next(&mut self) {
+        let count = self.message_search.matches.len();
+        if count == 0 {
+            return;
+        }
+        self.message_search.current = (self.message_search.current + 1) % count;
+        self.message_search.pending_jump = true;
+        self.mark_dirty();
+    }
+
+    /// Jump to the previous match (wraps around)
+    pub fn message_search_prev(&mut self) {
+        let count = self.message_search.matches.len();
+        if count == 0 {
+            return;
+        }
+        self.message_search.current = (self.message_search.current + count - 1) % count;
+        self.message_search.pending_jump = true;
+        self.mark_dirty();
+    }
+
+    /// Recompute matches for the current query and select the first one
+    fn run_message_search(&mut self) {
+        self.search_active_thread();
+        self.message_search.pending_jump = !self.message_search.matches.is_empty();
+        self.message_search.current = 0;
+        self.mark_dirty();
+    }
+
+    /// Recompute matches if the active thread's messages changed since the
+    /// last search, keeping the current match selected if it still exists
+    pub fn refresh_message_search(&mut self) {
+        let Some(thread_id) = self.active_thread_id.as_deref() else {
+            return;
+        };
+        if !self.message_search.is_highlighting()
+            || self.cache.content_signature(thread_id) == self.message_search.content_signature
+        {
+            return;
+        }
+
+        let current = self.message_search.current_match().copied();
+        self.search_active_thread();
+        let matches = &self.message_search.matches;
+        self.message_search.current = current
+            .and_then(|c| matches.iter().position(|m| *m == c))
+            .unwrap_or_else(|| {
+                self.message_search
+                    .current
+                    .min(matches.len().saturating_sub(1))
+            });
+    }
+
+    /// Search the active thread for the query, recording its content signature
+    fn search_active_thread(&mut self) {
+        let (matches, signature) = match self.active_thread_id.as_deref() {
+            Some(thread_id) => (
+                self.cache
+                    .search_messages(thread_id, &self.message_search.query),
+                self.cache.content_signature(thread_id),
+            ),
+            None => (Vec::new(), 0),
+        };
+        self.message_search.matches = matches;
+        self.message_search.content_signature = signature;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn app_with_stub_conversation() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app
+    }
+
+    #[test]
+    fn test_open_message_search_requires_conversation() {
+        let mut app = App::default();
+        assert!(!app.open_message_search());
+        assert!(!app.message_search.active);
+
+        let mut app = app_with_stub_conversation();
+        assert!(app.open_message_search());
+        assert!(app.message_search.active);
+    }
+
+    #[test]
+    fn test_typing_filters_matches_and_requests_jump() {
+        let mut app = app_with_stub_conversation();
+        app.open_message_search();
+        for c in "TOKIO".chars() {
+            app.message_search_type_char(c);
+        }
+
+        assert!(!app.message_search.matches.is_empty());
+        assert_eq!(app.message_search.current, 0);
+        assert!(app.message_search.pending_jump);
+    }
+
+    #[test]
+    fn test_matches_follow_streamed_text() {
+        let mut app = app_with_stub_conversation();
+        app.open_message_search();
+        for c in "needle".chars() {
+            app.message_search_type_char(c);
+        }
+        assert!(app.message_search.matches.is_empty());
+        app.message_search.pending_jump = false;
+
+        app.cache
+            .add_streaming_message("thread-001", "hi".to_string(), Vec::new());
+        app.cache
+            .append_to_message("thread-001", "a needle, another Needle");
+        app.refresh_message_search();
+
+        assert_eq!(app.message_search.matches.len(), 2);
+        assert_eq!(app.message_search.current, 0);
+        // New matches don't move the viewport
+        assert!(!app.message_search.pending_jump);
+
+        // The selected match stays selected as more text arrives
+        app.message_search_next();
+        let selected = *app.message_search.current_match().unwrap();
+        app.cache
+            .append_to_message("thread-001", " and one more needle");
+        app.refresh_message_search();
+        assert_eq!(app.message_search.matches.len(), 3);
+        assert_eq!(app.message_search.current_match(), Some(&selected));
+    }
+
+    #[test]
+    fn test_no_matches_does_not_jump() {
+        let mut app = app_with_stub_conversation();
+        app.open_message_search();
+        for c in "zzzz-not-present".chars() {
+            app.message_search_type_char(c);
+        }
+
+        assert!(app.message_search.matches.is_empty());
+        assert!(!app.message_search.pending_jump);
+        assert!(app.message_search.current_match().is_none());
+    }
+
+    #[test]
+    fn test_next_and_prev_wrap_around() {
+        let mut app = app_with_stub_conversation();
+        app.open_message_search();
+        app.message_search_type_char('a');
+        let count = app.message_search.matches.len();
+        assert!(count > 1);
+
+        app.message_search_prev();
+        assert_eq!(app.message_search.current, count - 1);
+        app.message_search_next();
+        assert_eq!(app.message_search.current, 0);
+        app.message_search_next();
+        assert_eq!(app.message_search.current, 1);
+    }
+
+    #[test]
+    fn test_backspace_refines_query() {
+        let mut app = app_with_stub_conversation();
+        app.open_message_search();
+        app.message_search_type_char('t');
+        app.message_search_type_char('x');
+        app.message_search_backspace();
+
+        assert_eq!(app.message_search.query, "t");
+        assert!(!app.message_search.matches.is_empty());
+    }
+
+    #[test]
+    fn test_confirm_message_search_stops_editing() {
+        let mut app = app_with_stub_conversation();
+        app.open_message_search();
+        assert!(app.message_search.editing);
+        app.message_search_type_char('t');
+
+        app.confirm_message_search();
+        assert!(app.message_search.active);
+        assert!(!app.message_search.editing);
+    }
+
+    #[test]
+    fn test_confirm_empty_message_search_closes() {
+        let mut app = app_with_stub_conversation();
+        app.open_message_search();
+
+        app.confirm_message_search();
+        assert!(!app.message_search.active);
+    }
+
+    #[test]
+    fn test_close_message_search_clears_highlight() {
+        let mut app = app_with_stub_conversation();
+        app.open_message_search();
+        app.message_search_type_char('t');
+        assert!(app.message_search.is_highlighting());
+
+        app.close_message_search();
+        assert!(!app.message_search.active);
+        assert!(app.message_search.query.is_empty());
+        assert!(app.message_search.matches.is_empty());
+        assert!(!app.message_search.is_highlighting());
+    }
+}
@@ -0,0 +1,230 @@
+//! Session resume for the App.
+//!
+//! The last viewed thread, its scroll position, the selected folder and the
+//! permission mode are saved to `~/.spoq/session.json` on every thread switch
+//! and on quit. On the next start `main` hands the saved session to
+//! `restore_session` after `initialize()`: if the thread still exists it is
+//! reopened, and the scroll position is applied once the height cache for
+//! the viewport has been built (see `apply_pending_scroll_restore`). A thread
+//! deleted in the meantime leaves the app on the CommandDeck.
+//!
+//! Prompts still queued at quit and the threads that were streaming are saved
+//! too, so the queues come back and the user hears which threads were still
+//! working (see `quit_confirm`).
+//!
+//! `spoq --fresh`, safe mode and `"resume_session": false` in
+//! `~/.spoq/config.json` skip the restore.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Folder, PermissionMode};
+
+use super::{App, Screen};
+
+/// File name of the saved session inside `~/.spoq`
+const SESSION_FILE: &str = "session.json";
+
+/// What is restored on the next start
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SavedSession {
+    /// Thread open in the Conversation screen, None on the CommandDeck
+    #[serde(default)]
+    pub active_thread_id: Option<String>,
+    /// Lines scrolled up from the bottom of the conversation
+    #[serde(default)]
+    pub unified_scroll: u16,
+    /// Folders attached to the next new thread
+    #[serde(default)]
+    pub selected_folders: Vec<Folder>,
+    #[serde(default)]
+    pub permission_mode: PermissionMode,
+    /// Prompts queued per thread, not sent yet
+    #[serde(default)]
+    pub queued_prompts: HashMap<String, Vec<String>>,
+    /// Threads whose responses were still streaming
+    #[serde(default)]
+    pub streaming_threads: Vec<String>,
+}
+
+impl SavedSession {
+    /// Path of the session file (`~/.spoq/session.json`)
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".spoq").join(SESSION_FILE))
+    }
+
+    /// Load a saved session; missing or invalid files yield None
+    pub fn load_from(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents)
+            .map_err(|e| tracing::warn!("Ignoring invalid {}: {}", path.display(), e))
+            .ok()
+    }
+
+    /// Write the session file
+    pub fn save_to(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+}
+
+impl App {
+    /// The session as it would be restored right now
+    pub fn saved_session(&self) -> SavedSession {
+        let active_thread_id = match self.screen {
+            Screen::Conversation => self.active_thread_id.clone(),
+            _ => None,
+        };
+        SavedSession {
+            unified_scroll: if active_thread_id.is_some() {
+                self.unified_scroll
+            } else {
+                0
+            },
+            active_thread_id,
+            selected_folders: self.selected_folders.clone(),
+            permission_mode: self.permission_mode,
+            queued_prompts: self.saved_queues(),
+            streaming_threads: self.background_work().streaming,
+        }
+    }
+
+    /// Save the session to `session_path` (nothing to do without one)
+    pub fn save_session(&self) {
+        let Some(path) = self.session_path.as_deref() else {
+            return;
+        };
+        if crate::startup::integrity::is_read_only() {
+            return;
+        }
+        if let Err(e) = self.saved_session().save_to(path) {
+            tracing::warn!("Failed to save session: {}", e);
+        }
+    }
+
+    /// Load the session saved by the previous run
+    pub fn load_session(&self) -> Option<SavedSession> {
+        SavedSession::load_from(self.session_path.as_deref()?)
+    }
+
+    /// Restore a saved session. Call after `initialize()` so the thread list
+    /// is loaded.
+    ///
+    /// Returns false (and stays on the CommandDeck) if the saved thread no
+    /// longer exists.
+    pub fn restore_session(&mut self, session: SavedSession) -> bool {
+        self.permission_mode = session.permission_mode;
+        self.restore_background_work(session.queued_prompts, &session.streaming_threads);
+
+        let Some(thread_id) = session.active_thread_id else {
+            self.selected_folders = session.selected_folders;
+            self.mark_dirty();
+            return true;
+        };
+        if self.cache.get_thread(&thread_id).is_none() {
+            tracing::info!("Not resuming thread {}: no longer exists", thread_id);
+            self.selected_folders = session.selected_folders;
+            self.mark_dirty();
+            return false;
+        }
+
+        self.open_thread(thread_id);
+        self.pending_scroll_restore =
+            (session.unified_scroll > 0).then_some(session.unified_scroll);
+        true
+    }
+
+    /// Apply the restored scroll position once the active thread's heights
+    /// are known. Called from `prepare_render`.
+    pub fn apply_pending_scroll_restore(&mut self) {
+        let Some(scroll) = self.pending_scroll_restore else {
+            return;
+        };
+        let Some(thread_id) = self.active_thread_id.as_deref() else {
+            self.pending_scroll_restore = None;
+            return;
+        };
+        let heights_ready = self.height_cache.as_ref().is_some_and(|cache| {
+            cache.thread_id.as_str() == thread_id && !cache.heights.is_empty()
+        });
+        if !heights_ready {
+            return;
+        }
+
+        self.pending_scroll_restore = None;
+        self.unified_scroll = scroll;
+        self.scroll_position = scroll as f32;
+        self.user_has_scrolled = true;
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    #[test]
+    fn test_session_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SESSION_FILE);
+        let session = SavedSession {
+            active_thread_id: Some("thread-001".to_string()),
+            unified_scroll: 12,
+            selected_folders: Vec::new(),
+            permission_mode: PermissionMode::Plan,
+            queued_prompts: HashMap::from([("thread-001".to_string(), vec!["next".to_string()])]),
+            streaming_threads: vec!["thread-002".to_string()],
+        };
+        session.save_to(&path).unwrap();
+        assert_eq!(SavedSession::load_from(&path), Some(session));
+
+        std::fs::write(&path, "not json").unwrap();
+        assert_eq!(SavedSession::load_from(&path), None);
+    }
+
+    #[test]
+    fn test_restore_reopens_existing_thread() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        // Pre-populate messages to avoid lazy fetch triggering tokio::spawn
+        app.cache.set_messages("thread-001".to_string(), vec![]);
+        let session = SavedSession {
+            active_thread_id: Some("thread-001".to_string()),
+            unified_scroll: 7,
+            ..Default::default()
+        };
+
+        assert!(app.restore_session(session));
+        assert_eq!(app.screen, Screen::Conversation);
+        assert_eq!(app.active_thread_id.as_deref(), Some("thread-001"));
+        assert_eq!(app.pending_scroll_restore, Some(7));
+        assert_eq!(
+            app.saved_session().active_thread_id.as_deref(),
+            Some("thread-001")
+        );
+    }
+
+    #[test]
+    fn test_restore_deleted_thread_stays_on_command_deck() {
+        let mut app = App::default();
+        app.cache = ThreadCache::new();
+        let session = SavedSession {
+            active_thread_id: Some("gone".to_string()),
+            unified_scroll: 7,
+            permission_mode: PermissionMode::Plan,
+            ..Default::default()
+        };
+
+        assert!(!app.restore_session(session));
+        assert_eq!(app.screen, Screen::CommandDeck);
+        assert!(app.active_thread_id.is_none());
+        assert!(app.pending_scroll_restore.is_none());
+        assert_eq!(app.permission_mode, PermissionMode::Plan);
+    }
+}
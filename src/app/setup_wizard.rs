@@ -0,0 +1,722 @@
+//! First-run setup wizard.
+//!
+//! When startup finds no login or no VPS, the TUI opens on `Screen::Setup`
+//! instead of exiting: sign in with the device flow, find (or set up) a VPS,
+//! check the local Claude and GitHub CLI credentials, then a summary. Enter
+//! moves on (or retries a failed step in place) and Esc goes back. Finishing
+//! points the conductor client at the VPS and lands on the CommandDeck
+//! without a restart.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::auth::central_api::{CentralApiClient, CentralApiError};
+use crate::conductor::ConductorClient;
+
+use super::{App, AppMessage, Screen};
+
+/// How long the "Couldn't open browser" error stays visible
+const BROWSER_ERROR_DURATION: Duration = Duration::from_secs(5);
+
+/// How often the VPS step re-checks a VPS that is still starting
+const VPS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long the VPS step waits for a VPS to become ready
+const VPS_READY_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// A step of the setup wizard, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SetupStep {
+    #[default]
+    Auth,
+    Vps,
+    Credentials,
+    Summary,
+}
+
+impl SetupStep {
+    /// All steps, in order
+    pub const ALL: [SetupStep; 4] = [
+        SetupStep::Auth,
+        SetupStep::Vps,
+        SetupStep::Credentials,
+        SetupStep::Summary,
+    ];
+
+    /// Name shown in the wizard's step bar
+    pub fn label(self) -> &'static str {
+        match self {
+            SetupStep::Auth => "Sign in",
+            SetupStep::Vps => "VPS",
+            SetupStep::Credentials => "Credentials",
+            SetupStep::Summary => "Summary",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn next(self) -> Option<Self> {
+        Self::ALL.get(self.index() + 1).copied()
+    }
+
+    fn prev(self) -> Option<Self> {
+        self.index().checked_sub(1).map(|i| Self::ALL[i])
+    }
+}
+
+/// Progress of one setup step
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum StepStatus {
+    #[default]
+    Pending,
+    Running,
+    Done,
+    /// Failed with an error; Enter retries
+    Failed(String),
+}
+
+/// Local credentials found by the credentials step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CredentialChecks {
+    /// `~/.claude.json` exists
+    pub claude_json: bool,
+    /// `~/.config/gh/hosts.yml` exists
+    pub gh_hosts: bool,
+    /// The `gh` CLI is on the PATH
+    pub gh_installed: bool,
+    /// `gh auth status` succeeds
+    pub gh_authenticated: bool,
+}
+
+impl CredentialChecks {
+    /// Detect the local credentials (runs `gh`, so call off the UI thread)
+    pub fn detect() -> Result<Self, String> {
+        use crate::setup::gh_auth::{is_gh_authenticated, is_gh_installed};
+
+        let gh_hosts = crate::auth::token_migration::detect_tokens()?.github_cli;
+        let claude_json = dirs::home_dir()
+            .map(|home| home.join(".claude.json"))
+            .as_deref()
+            .is_some_and(std::path::Path::exists);
+        let gh_installed = is_gh_installed();
+        Ok(Self {
+            claude_json,
+            gh_hosts,
+            gh_installed,
+            gh_authenticated: gh_installed && is_gh_authenticated(),
+        })
+    }
+}
+
+/// State of the first-run setup wizard (`Screen::Setup`)
+#[derive(Debug, Clone, Default)]
+pub struct SetupWizard {
+    /// Step being shown
+    pub step: SetupStep,
+    statuses: [StepStatus; 4],
+    /// Device flow URL to open, while signing in
+    pub verification_url: Option<String>,
+    /// Device flow code to enter at the URL
+    pub user_code: Option<String>,
+    /// Latest status of a VPS that is still starting
+    pub vps_progress: Option<String>,
+    /// No VPS exists yet; Enter opens the VPS config dialog
+    pub vps_missing: bool,
+    /// Conductor URL of the VPS found or set up
+    pub vps_url: Option<String>,
+    /// Hostname of the VPS found or set up
+    pub vps_hostname: Option<String>,
+    /// Results of the credentials step
+    pub credentials: Option<CredentialChecks>,
+}
+
+impl SetupWizard {
+    /// Progress of `step`
+    pub fn status(&self, step: SetupStep) -> &StepStatus {
+        &self.statuses[step.index()]
+    }
+
+    pub(crate) fn set_status(&mut self, step: SetupStep, status: StepStatus) {
+        self.statuses[step.index()] = status;
+    }
+
+    /// Whether the current step is waiting on a background task
+    pub fn is_running(&self) -> bool {
+        *self.status(self.step) == StepStatus::Running
+    }
+
+    /// Whether `step` is the current step and still running, i.e. a result
+    /// for it isn't stale
+    fn awaits(&self, step: SetupStep) -> bool {
+        self.step == step && self.is_running()
+    }
+}
+
+/// Wait for the user to authorize a device code.
+///
+/// Polls at the server's interval for up to five minutes; errors are
+/// ready to show.
+pub(crate) async fn authorize_device(
+    api: &CentralApiClient,
+    device: &crate::auth::central_api::DeviceCodeResponse,
+) -> Result<crate::auth::central_api::TokenResponse, String> {
+    let interval = Duration::from_secs(device.interval.max(5) as u64);
+    let timeout = Duration::from_secs(300);
+    let start = std::time::Instant::now();
+
+    loop {
+        if start.elapsed() > timeout {
+            return Err("Authentication timed out. Try again.".to_string());
+        }
+
+        tokio::time::sleep(interval).await;
+
+        match api.poll_device_token(&device.device_code).await {
+            Ok(token_response) => return Ok(token_response),
+            Err(CentralApiError::AuthorizationPending) => continue,
+            Err(CentralApiError::AuthorizationExpired) => {
+                return Err("Authorization expired. Try again.".to_string());
+            }
+            Err(CentralApiError::AccessDenied) => return Err("Access denied.".to_string()),
+            Err(e) => return Err(format!("Auth error: {}", e)),
+        }
+    }
+}
+
+impl App {
+    /// Open the setup wizard in place of the CommandDeck.
+    ///
+    /// Sign-in is skipped when credentials are already stored.
+    pub fn start_setup_wizard(&mut self) {
+        let mut wizard = SetupWizard::default();
+        if self.credentials.access_token.is_some() {
+            wizard.set_status(SetupStep::Auth, StepStatus::Done);
+            wizard.step = SetupStep::Vps;
+        }
+        self.setup_wizard = Some(wizard);
+        self.screen = Screen::Setup;
+        self.run_setup_step();
+    }
+
+    /// Enter: continue past a finished step, retry a failed one, or finish
+    pub fn setup_wizard_enter(&mut self) {
+        let Some(wizard) = self.setup_wizard.as_mut() else {
+            return;
+        };
+        match wizard.status(wizard.step).clone() {
+            StepStatus::Running => {}
+            StepStatus::Failed(_) if wizard.vps_missing => {
+                self.dashboard.show_vps_config();
+                self.mark_dirty();
+            }
+            StepStatus::Pending | StepStatus::Failed(_) => self.run_setup_step(),
+            StepStatus::Done => match wizard.step.next() {
+                Some(next) => {
+                    wizard.step = next;
+                    // Credentials are re-checked on every visit, so fixes
+                    // made after going back show up
+                    let rerun =
+                        *wizard.status(next) != StepStatus::Done || next == SetupStep::Credentials;
+                    if rerun {
+                        self.run_setup_step();
+                    }
+                    self.mark_dirty();
+                }
+                None => self.finish_setup(),
+            },
+        }
+    }
+
+    /// Esc: go back a step (not while one is running)
+    pub fn setup_wizard_back(&mut self) {
+        let Some(wizard) = self.setup_wizard.as_mut() else {
+            return;
+        };
+        if wizard.is_running() {
+            return;
+        }
+        if let Some(prev) = wizard.step.prev() {
+            wizard.step = prev;
+            self.mark_dirty();
+        }
+    }
+
+    /// Open the sign-in URL in the browser (`o` on the sign-in step)
+    pub fn open_setup_auth_url(&mut self) {
+        let Some(url) = self
+            .setup_wizard
+            .as_ref()
+            .filter(|w| w.step == SetupStep::Auth)
+            .and_then(|w| w.verification_url.clone())
+        else {
+            return;
+        };
+        if let Err(e) = open::that(&url) {
+            self.set_timed_error(
+                format!("Couldn't open browser: {} ({})", e, url),
+                BROWSER_ERROR_DURATION,
+            );
+        }
+    }
+
+    /// Start (or retry) the current step
+    fn run_setup_step(&mut self) {
+        let Some(wizard) = self.setup_wizard.as_mut() else {
+            return;
+        };
+        let step = wizard.step;
+        wizard.set_status(step, StepStatus::Running);
+
+        match step {
+            SetupStep::Auth => {
+                wizard.verification_url = None;
+                wizard.user_code = None;
+                self.spawn_setup_auth();
+            }
+            SetupStep::Vps => {
+                wizard.vps_missing = false;
+                wizard.vps_progress = None;
+                if crate::startup::config::SpoqConfig::load().is_local() {
+                    // The VPS config dialog shows the local conductor's progress
+                    self.dashboard.show_vps_config();
+                    self.start_local_conductor();
+                } else {
+                    self.spawn_setup_vps();
+                }
+            }
+            SetupStep::Credentials => {
+                let tx = self.message_tx.clone();
+                tokio::spawn(async move {
+                    let message = match tokio::task::spawn_blocking(CredentialChecks::detect).await
+                    {
+                        Ok(Ok(checks)) => AppMessage::SetupCredentialsDetected { checks },
+                        Ok(Err(error)) => AppMessage::SetupStepFailed {
+                            step: SetupStep::Credentials,
+                            error,
+                        },
+                        Err(e) => AppMessage::SetupStepFailed {
+                            step: SetupStep::Credentials,
+                            error: e.to_string(),
+                        },
+                    };
+                    let _ = tx.send(message);
+                });
+            }
+            SetupStep::Summary => wizard.set_status(step, StepStatus::Done),
+        }
+        self.mark_dirty();
+    }
+
+    /// Run the device flow for the sign-in step
+    fn spawn_setup_auth(&mut self) {
+        let tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            let api = CentralApiClient::new();
+            let device = match api.request_device_code().await {
+                Ok(device) => device,
+                Err(e) => {
+                    let _ = tx.send(AppMessage::SetupStepFailed {
+                        step: SetupStep::Auth,
+                        error: format!("Could not start login: {}", e),
+                    });
+                    return;
+                }
+            };
+            let _ = tx.send(AppMessage::SetupAuthStarted {
+                verification_url: device.verification_uri.clone(),
+                user_code: device.user_code.clone().unwrap_or_default(),
+            });
+
+            let message = match authorize_device(&api, &device).await {
+                Ok(token) => AppMessage::SetupAuthComplete {
+                    access_token: token.access_token,
+                    refresh_token: token.refresh_token,
+                    expires_in: token.expires_in,
+                    user_id: token.user_id,
+                },
+                Err(error) => AppMessage::SetupStepFailed {
+                    step: SetupStep::Auth,
+                    error,
+                },
+            };
+            let _ = tx.send(message);
+        });
+    }
+
+    /// Look up the user's VPS for the VPS step, starting a stopped one and
+    /// waiting for one that is still provisioning
+    fn spawn_setup_vps(&mut self) {
+        let tx = self.message_tx.clone();
+        let mut api = CentralApiClient::new();
+        if let Some(ref token) = self.credentials.access_token {
+            api = api.with_auth(token);
+        }
+        if let Some(ref token) = self.credentials.refresh_token {
+            api = api.with_refresh_token(token);
+        }
+
+        tokio::spawn(async move {
+            let fail = |error: String| AppMessage::SetupStepFailed {
+                step: SetupStep::Vps,
+                error,
+            };
+            let start = std::time::Instant::now();
+            let mut start_requested = false;
+
+            loop {
+                if start.elapsed() > VPS_READY_TIMEOUT {
+                    let _ = tx.send(fail(
+                        "Timeout waiting for the VPS to become ready".to_string(),
+                    ));
+                    return;
+                }
+
+                let vps = match api.fetch_user_vps().await {
+                    Ok(Some(vps)) => vps,
+                    Ok(None) => {
+                        let _ = tx.send(AppMessage::SetupVpsMissing);
+                        return;
+                    }
+                    Err(e) => {
+                        let _ = tx.send(fail(format!("Couldn't check your VPS: {}", e)));
+                        return;
+                    }
+                };
+
+                match vps.status.as_str() {
+                    "ready" | "running" | "active" => {
+                        let message = match crate::startup::vps::build_vps_url(&vps) {
+                            Some(vps_url) => AppMessage::SetupVpsReady {
+                                hostname: vps
+                                    .hostname
+                                    .clone()
+                                    .or_else(|| vps.ip.clone())
+                                    .unwrap_or_else(|| vps_url.clone()),
+                                vps_url,
+                            },
+                            None => fail("VPS has no hostname, url, or IP address".to_string()),
+                        };
+                        let _ = tx.send(message);
+                        return;
+                    }
+                    "stopped" if !start_requested => {
+                        start_requested = true;
+                        if let Err(e) = api.start_vps().await {
+                            let _ = tx.send(fail(format!("Couldn't start your VPS: {}", e)));
+                            return;
+                        }
+                        let _ = tx.send(AppMessage::SetupVpsProgress {
+                            status: "Starting VPS...".to_string(),
+                        });
+                    }
+                    "failed" | "terminated" => {
+                        let _ = tx.send(fail(format!(
+                            "VPS is in {} state. Please contact support@spoq.dev for assistance.",
+                            vps.status
+                        )));
+                        return;
+                    }
+                    status => {
+                        let _ = tx.send(AppMessage::SetupVpsProgress {
+                            status: format!("VPS is {}...", status),
+                        });
+                    }
+                }
+
+                tokio::time::sleep(VPS_POLL_INTERVAL).await;
+            }
+        });
+    }
+
+    /// Store tokens from a completed device flow, on disk and in the
+    /// Central API client
+    pub(crate) fn store_auth_tokens(
+        &mut self,
+        access_token: String,
+        refresh_token: Option<String>,
+        expires_in: Option<u32>,
+        user_id: Option<String>,
+    ) {
+        if let Some(rt) = refresh_token {
+            self.credentials.refresh_token = Some(rt);
+        }
+        // Compute and store expiration so startup doesn't consider token expired
+        let expires_secs = expires_in
+            .or_else(|| crate::auth::central_api::get_jwt_expires_in(&access_token))
+            .unwrap_or(900);
+        self.credentials.expires_at =
+            Some(chrono::Utc::now().timestamp() + i64::from(expires_secs));
+        if let Some(uid) = user_id {
+            self.credentials.user_id = Some(uid);
+        }
+        // Recreate CentralApiClient with new tokens
+        let mut new_central = CentralApiClient::new().with_auth(&access_token);
+        if let Some(ref rt) = self.credentials.refresh_token {
+            new_central = new_central.with_refresh_token(rt);
+        }
+        self.central_api = Some(Arc::new(new_central));
+        self.credentials.access_token = Some(access_token);
+        // Save to disk
+        if let Some(ref manager) = self.credentials_manager {
+            let _ = manager.save(&self.credentials);
+        }
+    }
+
+    /// The sign-in step's device flow is waiting for the user
+    pub(crate) fn setup_auth_started(&mut self, verification_url: String, user_code: String) {
+        if let Some(wizard) = self
+            .setup_wizard
+            .as_mut()
+            .filter(|w| w.awaits(SetupStep::Auth))
+        {
+            wizard.verification_url = Some(verification_url);
+            wizard.user_code = Some(user_code);
+            self.mark_dirty();
+        }
+    }
+
+    /// The sign-in step's device flow was authorized
+    pub(crate) fn setup_auth_complete(
+        &mut self,
+        access_token: String,
+        refresh_token: Option<String>,
+        expires_in: Option<u32>,
+        user_id: Option<String>,
+    ) {
+        if !self
+            .setup_wizard
+            .as_ref()
+            .is_some_and(|w| w.awaits(SetupStep::Auth))
+        {
+            return;
+        }
+        self.store_auth_tokens(access_token, refresh_token, expires_in, user_id);
+        if let Some(wizard) = self.setup_wizard.as_mut() {
+            wizard.verification_url = None;
+            wizard.user_code = None;
+            wizard.set_status(SetupStep::Auth, StepStatus::Done);
+        }
+        self.mark_dirty();
+    }
+
+    /// The VPS step's VPS is still starting
+    pub(crate) fn setup_vps_progress(&mut self, status: String) {
+        if let Some(wizard) = self
+            .setup_wizard
+            .as_mut()
+            .filter(|w| w.awaits(SetupStep::Vps))
+        {
+            wizard.vps_progress = Some(status);
+            self.mark_dirty();
+        }
+    }
+
+    /// A VPS is ready, found by the VPS step or set up in the VPS config
+    /// dialog it opened
+    pub(crate) fn setup_vps_ready(&mut self, vps_url: String, hostname: String) {
+        if let Some(wizard) = self
+            .setup_wizard
+            .as_mut()
+            .filter(|w| w.step == SetupStep::Vps)
+        {
+            wizard.vps_missing = false;
+            wizard.vps_progress = None;
+            wizard.vps_url = Some(vps_url);
+            wizard.vps_hostname = Some(hostname);
+            wizard.set_status(SetupStep::Vps, StepStatus::Done);
+            self.mark_dirty();
+        }
+    }
+
+    /// The user has no VPS yet; Enter opens the VPS config dialog
+    pub(crate) fn setup_vps_missing(&mut self) {
+        if let Some(wizard) = self
+            .setup_wizard
+            .as_mut()
+            .filter(|w| w.awaits(SetupStep::Vps))
+        {
+            wizard.vps_missing = true;
+            wizard.set_status(
+                SetupStep::Vps,
+                StepStatus::Failed("No VPS is set up yet".to_string()),
+            );
+            self.mark_dirty();
+        }
+    }
+
+    /// The credentials step finished checking
+    pub(crate) fn setup_credentials_detected(&mut self, checks: CredentialChecks) {
+        if let Some(wizard) = self
+            .setup_wizard
+            .as_mut()
+            .filter(|w| w.awaits(SetupStep::Credentials))
+        {
+            wizard.credentials = Some(checks);
+            wizard.set_status(SetupStep::Credentials, StepStatus::Done);
+            self.mark_dirty();
+        }
+    }
+
+    /// A step failed; it stays current so Enter can retry it
+    pub(crate) fn setup_step_failed(&mut self, step: SetupStep, error: String) {
+        if let Some(wizard) = self.setup_wizard.as_mut().filter(|w| w.awaits(step)) {
+            wizard.set_status(step, StepStatus::Failed(error));
+            self.mark_dirty();
+        }
+    }
+
+    /// Leave the wizard: connect to the VPS and open the CommandDeck
+    fn finish_setup(&mut self) {
+        let Some(wizard) = self.setup_wizard.take() else {
+            return;
+        };
+        if wizard.vps_url.is_some() {
+            self.vps_url = wizard.vps_url;
+        }
+        if let (Some(url), Some(token)) = (&self.vps_url, &self.credentials.access_token) {
+            self.client = Arc::new(ConductorClient::with_url(url).with_auth(token));
+        }
+        self.screen = Screen::CommandDeck;
+
+        // Same loading as a normal start: threads, WebSocket and pickers
+        let client = self.client.clone();
+        let tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            match client.fetch_threads().await {
+                Ok(threads) => {
+                    let _ = tx.send(AppMessage::DashboardDataRefreshed { threads });
+                }
+                Err(e) => tracing::error!("Failed to fetch threads after setup: {:?}", e),
+            }
+        });
+        self.reconnect_websocket();
+        if !self.safe_mode {
+            self.load_folders();
+            self.load_repos();
+            self.preload_picker_data();
+        }
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_in_setup(step: SetupStep) -> App {
+        let mut app = App::default();
+        let mut wizard = SetupWizard::default();
+        for done in SetupStep::ALL.iter().take_while(|s| **s != step) {
+            wizard.set_status(*done, StepStatus::Done);
+        }
+        wizard.step = step;
+        wizard.set_status(step, StepStatus::Running);
+        app.setup_wizard = Some(wizard);
+        app.screen = Screen::Setup;
+        app
+    }
+
+    fn wizard(app: &App) -> &SetupWizard {
+        app.setup_wizard.as_ref().unwrap()
+    }
+
+    #[test]
+    fn test_step_order() {
+        assert_eq!(SetupStep::Auth.next(), Some(SetupStep::Vps));
+        assert_eq!(SetupStep::Summary.next(), None);
+        assert_eq!(SetupStep::Auth.prev(), None);
+        assert_eq!(SetupStep::Summary.prev(), Some(SetupStep::Credentials));
+    }
+
+    #[test]
+    fn test_enter_and_esc_ignored_while_running() {
+        let mut app = app_in_setup(SetupStep::Vps);
+
+        app.setup_wizard_enter();
+        app.setup_wizard_back();
+
+        assert_eq!(wizard(&app).step, SetupStep::Vps);
+        assert!(wizard(&app).is_running());
+    }
+
+    #[tokio::test]
+    async fn test_failed_step_retries_in_place() {
+        let mut app = app_in_setup(SetupStep::Credentials);
+        app.setup_step_failed(SetupStep::Credentials, "HOME not set".to_string());
+        assert_eq!(
+            *wizard(&app).status(SetupStep::Credentials),
+            StepStatus::Failed("HOME not set".to_string())
+        );
+
+        app.setup_wizard_enter();
+
+        assert_eq!(wizard(&app).step, SetupStep::Credentials);
+        assert!(wizard(&app).is_running());
+    }
+
+    #[test]
+    fn test_missing_vps_opens_vps_config() {
+        let mut app = app_in_setup(SetupStep::Vps);
+        app.setup_vps_missing();
+        assert!(wizard(&app).vps_missing);
+
+        app.setup_wizard_enter();
+
+        assert!(matches!(
+            app.dashboard.overlay(),
+            Some(crate::view_state::OverlayState::VpsConfig { .. })
+        ));
+        assert_eq!(wizard(&app).step, SetupStep::Vps);
+    }
+
+    #[tokio::test]
+    async fn test_done_step_advances_and_esc_goes_back() {
+        let mut app = app_in_setup(SetupStep::Vps);
+        app.setup_vps_ready(
+            "https://vps.spoq.dev".to_string(),
+            "vps.spoq.dev".to_string(),
+        );
+
+        app.setup_wizard_enter();
+        assert_eq!(wizard(&app).step, SetupStep::Credentials);
+        assert!(wizard(&app).is_running());
+
+        app.setup_credentials_detected(CredentialChecks::default());
+        app.setup_wizard_back();
+        assert_eq!(wizard(&app).step, SetupStep::Vps);
+        assert_eq!(*wizard(&app).status(SetupStep::Vps), StepStatus::Done);
+    }
+
+    #[test]
+    fn test_stale_results_are_ignored() {
+        let mut app = app_in_setup(SetupStep::Credentials);
+
+        app.setup_step_failed(SetupStep::Vps, "late".to_string());
+        app.setup_vps_progress("VPS is provisioning...".to_string());
+
+        assert_eq!(*wizard(&app).status(SetupStep::Vps), StepStatus::Done);
+        assert!(wizard(&app).vps_progress.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_finishing_opens_command_deck_with_vps() {
+        let mut app = app_in_setup(SetupStep::Vps);
+        app.credentials.access_token = Some("token".to_string());
+        app.setup_vps_ready(
+            "https://vps.spoq.dev".to_string(),
+            "vps.spoq.dev".to_string(),
+        );
+        app.setup_wizard_enter();
+        app.setup_credentials_detected(CredentialChecks::default());
+        app.setup_wizard_enter();
+        assert_eq!(wizard(&app).step, SetupStep::Summary);
+
+        app.setup_wizard_enter();
+
+        assert!(app.setup_wizard.is_none());
+        assert_eq!(app.screen, Screen::CommandDeck);
+        assert_eq!(app.vps_url.as_deref(), Some("https://vps.spoq.dev"));
+    }
+}
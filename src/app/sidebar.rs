@@ -0,0 +1,122 @@
+//! Needs-action sidebar for the Conversation screen.
+//!
+//! On wide terminals Ctrl+B shows the dashboard's needs-action threads next
+//! to the conversation, so a permission prompt on another thread isn't missed
+//! while reading this one. Alt+number or a click opens an entry. The main
+//! column narrows while the sidebar is shown (see `LayoutContext::main_column`).
+
+use std::time::Duration;
+
+use crate::ui::{breakpoints::SIDEBAR_MIN_WIDTH, LayoutContext};
+
+use super::App;
+
+impl App {
+    /// Turn the sidebar on or off (Ctrl+B)
+    pub fn toggle_sidebar(&mut self) {
+        self.sidebar_enabled = !self.sidebar_enabled;
+        if self.sidebar_enabled && !self.sidebar_visible() {
+            self.set_status_notice(
+                format!(
+                    "Sidebar needs a terminal at least {} columns wide",
+                    SIDEBAR_MIN_WIDTH
+                ),
+                Duration::from_secs(4),
+            );
+        }
+        self.mark_dirty();
+    }
+
+    /// Whether the sidebar is shown: on, and the terminal is wide enough
+    pub fn sidebar_visible(&self) -> bool {
+        self.sidebar_enabled
+            && LayoutContext::new(self.terminal_width, self.terminal_height).fits_sidebar()
+    }
+
+    /// Open the `number`th sidebar entry (Alt+number).
+    ///
+    /// # Returns
+    /// `false` if the sidebar is hidden or has no such entry
+    pub fn open_sidebar_entry(&mut self, number: usize) -> bool {
+        if !self.sidebar_visible() {
+            return false;
+        }
+        match self.sidebar_layout.thread_for_number(number) {
+            Some(thread_id) => {
+                let thread_id = thread_id.to_string();
+                self.open_thread(thread_id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Open the sidebar entry under a mouse click
+    ///
+    /// # Returns
+    /// `false` if the click wasn't on an entry
+    pub fn click_sidebar(&mut self, column: u16, row: u16) -> bool {
+        if !self.sidebar_visible() {
+            return false;
+        }
+        match self.sidebar_layout.thread_at(column, row) {
+            Some(thread_id) => {
+                let thread_id = thread_id.to_string();
+                self.open_thread(thread_id);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view_state::SidebarLayout;
+    use ratatui::layout::Rect;
+
+    fn wide_app() -> App {
+        let mut app = App::default();
+        app.terminal_width = 160;
+        app.sidebar_layout = SidebarLayout {
+            entries: vec![
+                (Rect::new(124, 2, 36, 2), "thread-a".to_string()),
+                (Rect::new(124, 5, 36, 2), "thread-b".to_string()),
+            ],
+        };
+        app
+    }
+
+    #[test]
+    fn test_toggle_sidebar_warns_when_too_narrow() {
+        let mut app = App::default();
+        app.terminal_width = 100;
+
+        app.toggle_sidebar();
+        assert!(app.sidebar_enabled);
+        assert!(!app.sidebar_visible());
+        assert!(app
+            .status_notice
+            .as_deref()
+            .is_some_and(|n| n.contains("140 columns")));
+
+        app.terminal_width = 160;
+        assert!(app.sidebar_visible());
+    }
+
+    #[tokio::test]
+    async fn test_open_sidebar_entry_by_number_and_click() {
+        let mut app = wide_app();
+        assert!(!app.open_sidebar_entry(1)); // sidebar off
+
+        app.sidebar_enabled = true;
+        assert!(app.open_sidebar_entry(2));
+        assert_eq!(app.active_thread_id.as_deref(), Some("thread-b"));
+        assert!(!app.open_sidebar_entry(3));
+
+        assert!(app.click_sidebar(130, 3));
+        assert_eq!(app.active_thread_id.as_deref(), Some("thread-a"));
+        assert!(!app.click_sidebar(10, 3));
+    }
+}
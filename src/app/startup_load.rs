@@ -0,0 +1,283 @@
+//! Startup loading for the App.
+//!
+//! `start_startup_loads` starts the thread fetch, the folder, repo and @
+//! picker preloads and the WebSocket connection together, each in its own
+//! task, so the main loop (and the CommandDeck) comes up right away. Each
+//! loader reports back with an `AppMessage`; a failing one leaves only its
+//! own section empty. Until they report, `section_loading` feeds the
+//! CommandDeck's loading hints.
+//!
+//! The resumed session and a restored crash snapshot need the thread list,
+//! so they wait in `pending_session` / `pending_crash_snapshot` until the
+//! thread fetch finishes.
+
+use std::sync::Arc;
+
+use crate::debug::{DebugEventKind, StateChangeData, StateType};
+use crate::models::Thread;
+use crate::state::{PickerDataKind, Task};
+use crate::ui::dashboard::SectionLoading;
+use crate::websocket::messages::WsClaudeAccountsListRequest;
+use crate::websocket::WsOutgoingMessage;
+
+use super::{
+    emit_debug, log_thread_update, start_websocket_with_config, thread_mute, thread_pins, App,
+    AppMessage, Screen, WsHandle, MAX_DASHBOARD_THREADS,
+};
+
+impl App {
+    /// Start every startup loader without waiting for any of them.
+    ///
+    /// `preload` is false in safe mode, where the pickers load on demand.
+    pub fn start_startup_loads(&mut self, preload: bool) {
+        self.load_threads();
+        if preload {
+            // Folder picker, empty-state repos and the @ picker cache
+            self.load_folders();
+            self.load_repos();
+            self.preload_picker_data();
+        }
+        self.connect_websocket();
+    }
+
+    /// Fetch threads and tasks from the backend in the background.
+    ///
+    /// Sends `ThreadsLoaded`, or `ThreadsLoadFailed` if the server is
+    /// unreachable.
+    pub fn load_threads(&mut self) {
+        self.threads_loading = true;
+        self.mark_dirty();
+
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let msg = match client.fetch_threads().await {
+                Ok(threads) => {
+                    // Failed to fetch tasks - continue with empty tasks
+                    let tasks = client.fetch_tasks().await.unwrap_or_default();
+                    AppMessage::ThreadsLoaded { threads, tasks }
+                }
+                Err(e) => AppMessage::ThreadsLoadFailed(format!("{:?}", e)),
+            };
+            let _ = tx.send(msg);
+        });
+    }
+
+    /// Show threads fetched from the backend, then apply the session and
+    /// crash snapshot waiting for them
+    pub(super) fn apply_loaded_threads(&mut self, threads: Vec<Thread>, tasks: Vec<Task>) {
+        // Limit to most recent threads for dashboard display
+        let total_threads = threads.len();
+        let mut threads = threads
+            .into_iter()
+            .take(MAX_DASHBOARD_THREADS)
+            .collect::<Vec<_>>();
+        thread_pins::merge_local_pins(&mut threads, &self.thread_state.pinned_threads);
+        thread_mute::merge_local_mutes(&mut threads, &self.thread_state.muted_threads);
+
+        log_thread_update(&format!(
+            "fetch_threads SUCCESS: {} threads (limited to {} for dashboard)",
+            total_threads,
+            threads.len()
+        ));
+
+        // Also populate dashboard state for UI rendering
+        self.dashboard
+            .set_threads(threads.clone(), &std::collections::HashMap::new());
+        // Compute thread views so they're ready for rendering
+        self.dashboard.compute_thread_views();
+
+        // Iterate in reverse because upsert_thread() inserts at front,
+        // so we process oldest first to end up with newest at front
+        for thread in threads.into_iter().rev() {
+            self.cache.upsert_thread(thread);
+        }
+        self.connection_status = true;
+        self.system_stats.connected = true;
+        self.threads_loading = false;
+        self.set_tasks(tasks);
+
+        self.apply_pending_restores();
+        self.mark_dirty();
+    }
+
+    /// Start with an empty CommandDeck after the thread fetch failed
+    pub(super) fn apply_threads_load_failed(&mut self, error: &str) {
+        log_thread_update(&format!("fetch_threads FAILED: {}", error));
+        self.connection_status = false;
+        self.system_stats.connected = false;
+        self.threads_loading = false;
+
+        self.apply_pending_restores();
+        self.mark_dirty();
+    }
+
+    /// Reopen the saved session and crash snapshot, unless the user has
+    /// already left the CommandDeck or started typing while threads loaded
+    fn apply_pending_restores(&mut self) {
+        let session = self.pending_session.take();
+        let snapshot = self.pending_crash_snapshot.take();
+        if self.screen != Screen::CommandDeck || !self.textarea.is_empty() {
+            if session.is_some() || snapshot.is_some() {
+                tracing::info!("Not restoring the last session: already in use");
+            }
+            return;
+        }
+
+        // Reopen the last viewed thread (CommandDeck if it was deleted)
+        if let Some(session) = session {
+            self.restore_session(session);
+        }
+        // Draft and thread of a crashed run, if the user chose to restore them
+        if let Some(snapshot) = snapshot {
+            self.restore_crash_snapshot(snapshot);
+        }
+    }
+
+    /// Connect the WebSocket in the background.
+    ///
+    /// Sends `WsStarted`, or `WsStartFailed`, after which the app continues
+    /// in SSE-only mode.
+    pub fn connect_websocket(&mut self) {
+        let ws_config = self.ws_client_config();
+        emit_debug(
+            &self.debug_tx,
+            DebugEventKind::StateChange(StateChangeData::new(
+                StateType::WebSocket,
+                "WS_CONNECTING",
+                format!(
+                    "Connecting to {} (use_tls={}, has_token={})",
+                    ws_config.host,
+                    ws_config.use_tls,
+                    ws_config.auth_token.is_some()
+                ),
+            )),
+            None,
+        );
+
+        let tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            let msg = match start_websocket_with_config(tx.clone(), ws_config).await {
+                Ok(handle) => AppMessage::WsStarted { handle },
+                Err(error) => AppMessage::WsStartFailed { error },
+            };
+            let _ = tx.send(msg);
+        });
+    }
+
+    /// Use the WebSocket client connected at startup
+    pub(super) fn apply_ws_started(&mut self, handle: WsHandle) {
+        emit_debug(
+            &self.debug_tx,
+            DebugEventKind::StateChange(StateChangeData::new(
+                StateType::WebSocket,
+                "WS_INIT",
+                "WebSocket connected successfully",
+            )),
+            None,
+        );
+        let sender = handle.sender.clone();
+        self.adopt_ws_client(handle);
+
+        // Request accounts list on connect to populate header badge
+        let msg = WsOutgoingMessage::ClaudeAccountsListRequest(WsClaudeAccountsListRequest::new(
+            uuid::Uuid::new_v4().to_string(),
+        ));
+        let _ = sender.try_send(msg);
+    }
+
+    /// Continue without a WebSocket after the startup connection failed
+    pub(super) fn apply_ws_start_failed(&mut self, error: &str) {
+        emit_debug(
+            &self.debug_tx,
+            DebugEventKind::StateChange(StateChangeData::new(
+                StateType::WebSocket,
+                "WS_INIT_FAILED",
+                format!("WebSocket connection failed: {}", error),
+            )),
+            None,
+        );
+        self.ws_sender = None;
+        // Show the connection banner so `R` can retry
+        self.note_ws_down();
+    }
+
+    /// CommandDeck sections still waiting for their startup data
+    pub fn section_loading(&self) -> SectionLoading {
+        SectionLoading {
+            threads: self.threads_loading,
+            repos: self.repos_loading,
+            folders: self.folders_loading,
+            picker: PickerDataKind::ALL
+                .iter()
+                .any(|&kind| self.picker_cache.is_refreshing(kind)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::SavedSession;
+    use crate::cache::ThreadCache;
+    use crate::terminal::CrashSnapshot;
+
+    fn stub_threads() -> Vec<Thread> {
+        ThreadCache::with_stub_data()
+            .threads()
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_loaded_threads_apply_the_waiting_session() {
+        let mut app = App::default();
+        app.threads_loading = true;
+        app.pending_session = Some(SavedSession {
+            active_thread_id: Some("thread-002".to_string()),
+            ..Default::default()
+        });
+
+        app.apply_loaded_threads(stub_threads(), Vec::new());
+
+        assert!(!app.threads_loading);
+        assert!(app.connection_status);
+        assert!(app.cache.get_thread("thread-001").is_some());
+        assert!(app.pending_session.is_none());
+        assert_eq!(app.screen, Screen::Conversation);
+        assert_eq!(app.active_thread_id.as_deref(), Some("thread-002"));
+    }
+
+    #[test]
+    fn test_restores_skipped_once_the_user_is_typing() {
+        let mut app = App::default();
+        app.threads_loading = true;
+        app.textarea.insert_char('h');
+        app.pending_crash_snapshot = Some(CrashSnapshot {
+            draft: "old draft".to_string(),
+            ..Default::default()
+        });
+
+        app.apply_threads_load_failed("connection refused");
+
+        assert!(!app.threads_loading);
+        assert!(!app.connection_status);
+        assert!(app.pending_crash_snapshot.is_none());
+        assert_eq!(app.textarea.content(), "h");
+    }
+
+    #[test]
+    fn test_section_loading_tracks_each_loader() {
+        let mut app = App::default();
+        app.threads_loading = true;
+        app.folders_loading = true;
+
+        let loading = app.section_loading();
+        assert!(loading.threads);
+        assert!(loading.folders);
+        assert!(!loading.repos);
+        assert!(!loading.picker);
+        assert!(loading.any());
+    }
+}
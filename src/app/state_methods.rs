@@ -6,6 +6,7 @@ use tokio::sync::mpsc;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use super::{App, AppMessage, ScrollBoundary};
+use crate::state::PickerDataKind;
 
 impl App {
     /// Mark the UI as needing a redraw.
@@ -49,6 +50,15 @@ impl App {
         self.mark_dirty();
     }
 
+    /// Show a transient confirmation in the status line.
+    ///
+    /// Auto-dismisses after the given duration.
+    pub fn set_status_notice(&mut self, message: String, duration: std::time::Duration) {
+        self.status_notice = Some(message);
+        self.status_notice_dismiss = Some(std::time::Instant::now() + duration);
+        self.mark_dirty();
+    }
+
     /// Reset cursor blink timer - call on any input activity
     /// This makes cursor solid immediately and restarts blinkwait countdown
     pub fn reset_cursor_blink(&mut self) {
@@ -62,9 +72,24 @@ impl App {
         self.scroll_position = 0.0;
         self.scroll_velocity = 0.0;
         self.user_has_scrolled = false;
+        self.new_content_lines = 0;
+        self.mark_dirty();
+    }
+
+    /// Jump to the very top of the conversation
+    pub fn scroll_to_top(&mut self) {
+        self.unified_scroll = self.max_scroll;
+        self.scroll_position = self.max_scroll as f32;
+        self.scroll_velocity = 0.0;
+        self.user_has_scrolled = self.max_scroll > 0;
         self.mark_dirty();
     }
 
+    /// Jump to the bottom of the conversation and resume auto-scroll
+    pub fn scroll_to_bottom(&mut self) {
+        self.reset_scroll();
+    }
+
     /// Increment the tick counter for animations and update smooth scrolling
     pub fn tick(&mut self) {
         self.tick_count = self.tick_count.wrapping_add(1);
@@ -75,6 +100,12 @@ impl App {
             self.mark_dirty();
         }
 
+        // Reveal paced stream text (simulated typing)
+        self.tick_reveal();
+
+        // Append tokens coalesced since the last frame
+        self.flush_stream_tokens();
+
         // Update cursor blink state and mark dirty if visibility changed
         let cursor_visibility_changed = self.cursor_blink.update(self.tick_count);
         if cursor_visibility_changed {
@@ -93,9 +124,13 @@ impl App {
 
         // Mark dirty if there are active animations:
         // - Scroll momentum (velocity > 0)
-        // - Streaming (spinner animation)
+        // - Streaming, `/compact` or a running setup step (spinner animation),
+        //   while the terminal is focused
         // - Boundary hit indicator (fades after a few ticks)
-        if has_velocity || self.is_streaming() || self.scroll_boundary_hit.is_some() {
+        let setup_running = self.setup_wizard.as_ref().is_some_and(|w| w.is_running());
+        let spinning =
+            (self.is_streaming() || self.compacting.is_some() || setup_running) && self.is_focused;
+        if has_velocity || spinning || self.scroll_boundary_hit.is_some() {
             self.mark_dirty();
         }
 
@@ -108,6 +143,26 @@ impl App {
             }
         }
 
+        // Keep the /status overlay live
+        self.refresh_status_view();
+
+        // Follow the log file in the /log overlay
+        self.refresh_log_view();
+
+        // Keep the connection banner's elapsed time current
+        self.tick_connection_banner();
+
+        // Keep the debug server's snapshot fresh
+        if self
+            .tick_count
+            .is_multiple_of(super::debug_snapshot::SNAPSHOT_PUBLISH_TICKS)
+        {
+            self.publish_debug_snapshot();
+        }
+
+        // Quit once the streams being waited for have finished
+        self.check_quit_when_idle();
+
         // Reset Ctrl+C state after 2 seconds
         if let Some(last_time) = self.last_ctrl_c_time {
             if last_time.elapsed().as_secs() >= 2 {
@@ -137,6 +192,15 @@ impl App {
                 self.mark_dirty();
             }
         }
+
+        // Check auto-dismiss timer for the status notice
+        if let Some(dismiss_at) = self.status_notice_dismiss {
+            if std::time::Instant::now() >= dismiss_at {
+                self.status_notice = None;
+                self.status_notice_dismiss = None;
+                self.mark_dirty();
+            }
+        }
     }
 
     /// Update smooth scroll position with velocity and friction
@@ -221,12 +285,57 @@ impl App {
         false
     }
 
+    /// Expand or collapse the subagent tree of the last finished message
+    /// with several subagents.
+    /// Returns true if a tree was toggled
+    pub fn toggle_subagents(&mut self) -> bool {
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return false;
+        };
+        let Some(idx) = self.cache.find_last_subagent_tree_message_index(&thread_id) else {
+            return false;
+        };
+        let toggled = self.cache.toggle_message_subagents(&thread_id, idx);
+        if toggled {
+            self.mark_dirty();
+        }
+        toggled
+    }
+
+    /// Show or hide message timestamps (`T` or `/timestamps`).
+    ///
+    /// Day header rows change message heights, so the rendered lines and
+    /// the height cache are rebuilt for the new layout.
+    pub fn toggle_timestamps(&mut self) {
+        self.show_timestamps = !self.show_timestamps;
+        self.rendered_lines_cache.clear();
+        self.height_cache = None;
+        let notice = if self.show_timestamps {
+            "Timestamps shown"
+        } else {
+            "Timestamps hidden"
+        };
+        self.set_status_notice(notice.to_string(), std::time::Duration::from_secs(2));
+        self.mark_dirty();
+    }
+
     /// Dismiss the currently focused error for the active thread
     /// Returns true if an error was dismissed
     pub fn dismiss_focused_error(&mut self) -> bool {
         if let Some(thread_id) = &self.active_thread_id {
             let dismissed = self.cache.dismiss_focused_error(thread_id);
             if dismissed {
+                let key = self
+                    .keymap
+                    .chords_for(crate::input::keymap::Action::UndoDismissError)
+                    .into_iter()
+                    .next();
+                if let Some(key) = key {
+                    self.set_status_notice(
+                        format!("Error dismissed — press {} to undo", key),
+                        std::time::Duration::from_secs(5),
+                    );
+                }
                 self.mark_dirty();
             }
             dismissed
@@ -235,6 +344,23 @@ impl App {
         }
     }
 
+    /// Restore the most recently dismissed error of the active thread
+    /// Returns true if an error was restored
+    pub fn undo_dismiss_error(&mut self) -> bool {
+        let Some(thread_id) = &self.active_thread_id else {
+            return false;
+        };
+        let restored = self
+            .cache
+            .restore_dismissed_error(thread_id, self.dismissed_error_ttl);
+        if restored {
+            self.status_notice = None;
+            self.status_notice_dismiss = None;
+            self.mark_dirty();
+        }
+        restored
+    }
+
     /// Check if the active thread has any errors
     pub fn has_errors(&self) -> bool {
         if let Some(thread_id) = &self.active_thread_id {
@@ -255,11 +381,14 @@ impl App {
     /// Update terminal dimensions
     ///
     /// Called when the terminal is resized or on initial setup.
-    /// Updates both width and height in a single call.
+    /// Updates both width and height in a single call, keeping the
+    /// conversation scrolled to the same place.
     pub fn update_terminal_dimensions(&mut self, width: u16, height: u16) {
         if self.terminal_width != width || self.terminal_height != height {
+            let (old_width, old_height) = (self.terminal_width, self.terminal_height);
             self.terminal_width = width;
             self.terminal_height = height;
+            self.reanchor_scroll_after_resize(old_width, old_height);
             self.mark_dirty();
         }
     }
@@ -295,6 +424,18 @@ impl App {
         line_count > 3 || char_count > 150
     }
 
+    /// Insert pasted text into the input, as a paste token if it is long
+    pub fn insert_pasted_text(&mut self, text: String) {
+        if self.should_summarize_paste(&text) {
+            // Insert as atomic token
+            self.textarea.insert_paste_token(text);
+        } else {
+            // Insert normally character by character
+            self.textarea.insert_text(&text);
+        }
+        self.reset_cursor_blink();
+    }
+
     /// Load folders from the backend API.
     ///
     /// Sets folders_loading = true and spawns an async task to fetch folders.
@@ -583,8 +724,22 @@ impl App {
         // Remove the @ and query from input (we'll add properly formatted @paths)
         self.remove_at_and_filter_from_input_file_picker();
 
+        // Attach readable png/jpg files as images; mention everything else
+        let base_path = self.file_picker.base_path.clone();
+        let mentions: Vec<String> = selected
+            .into_iter()
+            .filter(|path| {
+                !(Self::is_attachable_image(path)
+                    && self.attach_image_file(&base_path.join(path).to_string_lossy()))
+            })
+            .collect();
+        if mentions.is_empty() {
+            self.close_file_picker();
+            return;
+        }
+
         // Insert @path references for each selected file
-        for (i, path) in selected.iter().enumerate() {
+        for (i, path) in mentions.iter().enumerate() {
             if i > 0 {
                 self.textarea.insert_char(' ');
             }
@@ -831,11 +986,11 @@ impl App {
     ///
     /// This is called when the user presses Enter on a highlighted folder
     /// in the folder picker. It:
-    /// 1. Sets the selected folder
+    /// 1. Attaches the folder (next to any already attached)
     /// 2. Closes the folder picker
     /// 3. Clears the @ and filter text from the textarea
     pub fn select_folder(&mut self, folder: crate::models::Folder) {
-        self.selected_folder = Some(folder.clone());
+        self.attach_folder(folder);
         self.folder_picker_visible = false;
         self.folder_picker_filter.clear();
         self.folder_picker_cursor = 0;
@@ -846,29 +1001,58 @@ impl App {
         self.mark_dirty();
     }
 
-    /// Clear the currently selected folder.
+    /// Attach a folder as a chip in the input area.
     ///
-    /// Called when the user presses backspace at cursor position 0
-    /// while a folder chip is displayed.
-    pub fn clear_folder(&mut self) {
-        if self.selected_folder.is_some() {
-            self.selected_folder = None;
-            self.handle_message(super::AppMessage::FolderCleared);
+    /// Returns false (and attaches nothing) if the folder is already attached.
+    pub fn attach_folder(&mut self, folder: crate::models::Folder) -> bool {
+        if self.is_folder_attached(&folder.path) {
+            return false;
+        }
+        self.selected_folders.push(folder);
+        self.mark_dirty();
+        true
+    }
+
+    /// Whether a folder with this path is attached
+    pub fn is_folder_attached(&self, path: &str) -> bool {
+        self.selected_folders.iter().any(|f| f.path == path)
+    }
+
+    /// Remove the folder chip at `index`.
+    ///
+    /// Called when the user presses backspace while the cursor touches
+    /// that chip.
+    pub fn remove_folder(&mut self, index: usize) {
+        if index < self.selected_folders.len() {
+            self.handle_message(super::AppMessage::FolderRemoved(index));
             self.mark_dirty();
         }
     }
 
-    /// Check if backspace at the current cursor position should clear the folder chip.
+    /// Remove all folder chips.
+    pub fn clear_folders(&mut self) {
+        if !self.selected_folders.is_empty() {
+            self.selected_folders.clear();
+            self.mark_dirty();
+        }
+    }
+
+    /// Paths of the attached folders: the first is the working directory,
+    /// the others are additional directories
+    pub fn selected_directories(&self) -> (Option<String>, Vec<String>) {
+        let mut paths = self.selected_folders.iter().map(|f| f.path.clone());
+        (paths.next(), paths.collect())
+    }
+
+    /// The folder chip the cursor touches, which backspace removes.
     ///
-    /// Returns true if:
-    /// - A folder is selected (chip is displayed)
-    /// - Cursor is at position (0, 0) - start of input
-    pub fn should_clear_folder_on_backspace(&self) -> bool {
-        if self.selected_folder.is_none() {
-            return false;
+    /// Chips sit in front of the input, so the cursor touches the last one
+    /// when it is at position (0, 0) - the start of input.
+    pub fn folder_chip_at_cursor(&self) -> Option<usize> {
+        if self.textarea.cursor() != (0, 0) {
+            return None;
         }
-        let (row, col) = self.textarea.cursor();
-        row == 0 && col == 0
+        self.selected_folders.len().checked_sub(1)
     }
 
     // =========================================================================
@@ -907,9 +1091,18 @@ impl App {
 
         match cmd {
             SlashCommand::Sync => {
-                // Trigger sync via the message channel (unbounded sender - no await needed)
-                tracing::info!("SlashCommand::Sync executed - sending TriggerSync message");
-                let _ = self.message_tx.send(crate::app::AppMessage::TriggerSync);
+                // Go through the credential watcher so auto-sync can't overlap
+                tracing::info!("SlashCommand::Sync executed");
+                if !crate::credential_watcher::handle_manual_sync(
+                    &mut self.credential_watch_state,
+                    &mut self.credential_debouncer,
+                    &self.message_tx,
+                ) {
+                    self.set_status_notice(
+                        "Sync already in progress".to_string(),
+                        std::time::Duration::from_secs(3),
+                    );
+                }
             }
             SlashCommand::Manage => {
                 // Open billing portal in browser
@@ -925,7 +1118,7 @@ impl App {
                 // Navigate to command deck (new chat)
                 self.screen = crate::app::Screen::CommandDeck;
                 self.active_thread_id = None;
-                self.selected_folder = None;
+                self.selected_folders.clear();
             }
             SlashCommand::Help => {
                 // Show help dialog with contact information
@@ -970,36 +1163,88 @@ impl App {
 
                 let thread_id = self.active_thread_id.clone().unwrap();
 
-                // Clean up dashboard state for this thread
-                self.dashboard.remove_plan_request(&thread_id);
-                self.dashboard.set_thread_planning(&thread_id, false);
                 self.dashboard.collapse_overlay();
 
-                // Optimistic local removal from cache
-                self.cache.remove_thread(&thread_id);
-
-                // Navigate back to command deck
-                self.navigate_to_command_deck();
+                // Optimistic local removal, including dashboard state
+                // (navigates back to command deck)
+                self.remove_thread_locally(&thread_id);
 
                 // Fire-and-forget backend delete
-                let tx = self.message_tx.clone();
-                let client = std::sync::Arc::clone(&self.client);
-                let tid = thread_id.clone();
-                tokio::spawn(async move {
-                    match client.delete_thread(&tid).await {
-                        Ok(_) => {
-                            let _ = tx.send(crate::app::AppMessage::ThreadDeleted {
-                                thread_id: tid,
-                            });
-                        }
-                        Err(e) => {
-                            let _ = tx.send(crate::app::AppMessage::ThreadDeleteFailed {
-                                thread_id: tid,
-                                error: e.to_string(),
-                            });
-                        }
-                    }
-                });
+                self.spawn_backend_thread_delete(thread_id);
+            }
+            SlashCommand::Typing => {
+                self.toggle_reveal_pacing();
+                let status = match self.reveal.rate() {
+                    Some(rate) => format!("Simulated typing on ({} chars/s)", rate),
+                    None => "Simulated typing off".to_string(),
+                };
+                self.set_status_notice(status, std::time::Duration::from_secs(2));
+            }
+            SlashCommand::Export => {
+                self.export_active_thread();
+            }
+            SlashCommand::Link => {
+                self.open_link_editor();
+            }
+            SlashCommand::Regenerate => {
+                self.regenerate_corrupted_files();
+            }
+            SlashCommand::Keys => {
+                self.show_keys_dialog();
+            }
+            SlashCommand::Notifications => {
+                self.open_notifications_panel();
+            }
+            SlashCommand::Model => {
+                self.open_model_picker();
+            }
+            SlashCommand::Rename => {
+                self.open_active_thread_rename();
+            }
+            SlashCommand::Context => {
+                self.show_context_usage();
+            }
+            SlashCommand::Events => {
+                self.open_event_log();
+            }
+            SlashCommand::Editor => {
+                self.show_editor_handoff();
+            }
+            SlashCommand::Notify => {
+                self.notify_command("");
+            }
+            SlashCommand::Status => {
+                self.open_status_view();
+            }
+            SlashCommand::Timestamps => {
+                self.toggle_timestamps();
+            }
+            SlashCommand::Metrics => {
+                self.open_thread_metrics();
+            }
+            SlashCommand::Compact => {
+                self.compact_context();
+            }
+            SlashCommand::Log => {
+                self.open_log_view();
+            }
+            SlashCommand::Usage => {
+                self.open_usage_view();
+            }
+            SlashCommand::Archive => {
+                self.archive_active_thread();
+            }
+            SlashCommand::Debug => {
+                self.debug_command("");
+            }
+            SlashCommand::Cd => {
+                self.open_directory_picker();
+            }
+            SlashCommand::Share => {
+                self.open_active_thread_share();
+            }
+            SlashCommand::Mute => {
+                self.toggle_active_thread_mute();
             }
         }
         self.mark_dirty();
@@ -1011,33 +1256,39 @@ impl App {
 
     /// Open the unified @ picker overlay.
     ///
-    /// Initializes the picker state and uses cached data for instant display.
-    /// Only fetches fresh data for threads if cache is stale (>5 min).
+    /// Initializes the picker state and uses cached data for instant display,
+    /// even if stale. Stale or missing data is fetched in the background.
     pub fn open_unified_picker(&mut self) {
         self.unified_picker.open();
+        self.unified_picker.attached_paths =
+            self.selected_folders.iter().map(|f| f.path.clone()).collect();
         self.mark_dirty();
 
         // Use cached repos (loaded at startup)
         if let Some(items) = self.picker_cache.get_repos() {
             self.unified_picker.repos.set_items(items.clone());
-        } else {
-            // Fallback: load if not cached yet
-            self.load_picker_repos();
         }
 
-        // Use cached folders (session-level)
+        // Use cached folders
         if let Some(items) = self.picker_cache.get_folders() {
             self.unified_picker.folders.set_items(items.clone());
-        } else {
-            self.load_picker_folders();
         }
 
-        // Threads: use cache if fresh, otherwise refresh
-        if let Some(items) = self.picker_cache.get_fresh_threads() {
-            self.unified_picker.threads.set_items(items.clone());
-        } else {
-            self.load_picker_threads();
+        // Use cached threads
+        if let Some(cached) = self.picker_cache.entry(PickerDataKind::Threads) {
+            self.unified_picker.threads.set_items(cached.items.clone());
+        }
+
+        // Refresh whatever is missing or past its TTL
+        for kind in PickerDataKind::ALL {
+            if self.picker_cache.is_stale(kind) {
+                self.request_picker_data(kind);
+            }
         }
+        self.sync_picker_freshness();
+
+        // No indexed folders cached: browse directories instead
+        self.sync_directory_browse();
     }
 
     /// Preload picker data at app startup (background, non-blocking).
@@ -1048,16 +1299,15 @@ impl App {
         }
         self.picker_cache.mark_preload_started();
 
-        // Preload repos (slow, cache for session)
-        self.load_picker_repos();
-        // Preload folders (fast, but cache anyway)
-        self.load_picker_folders();
-        // Preload threads (medium, will refresh on picker open if stale)
-        self.load_picker_threads();
+        // Preload repos, threads and folders; each is refreshed in the
+        // background once past its TTL (see `refresh_stale_picker_data`)
+        for kind in PickerDataKind::ALL {
+            self.request_picker_data(kind);
+        }
     }
 
     /// Load repos from API and cache them.
-    fn load_picker_repos(&mut self) {
+    pub(super) fn load_picker_repos(&mut self) {
         const CACHE_LIMIT: usize = 50;
         let tx = self.message_tx.clone();
         let client = Arc::clone(&self.client);
@@ -1083,7 +1333,7 @@ impl App {
     }
 
     /// Load folders from API and cache them.
-    fn load_picker_folders(&mut self) {
+    pub(super) fn load_picker_folders(&mut self) {
         const CACHE_LIMIT: usize = 50;
         let tx = self.message_tx.clone();
         let client = Arc::clone(&self.client);
@@ -1108,7 +1358,7 @@ impl App {
     }
 
     /// Load threads from API and cache them.
-    fn load_picker_threads(&mut self) {
+    pub(super) fn load_picker_threads(&mut self) {
         const CACHE_LIMIT: usize = 50;
         let tx = self.message_tx.clone();
         let client = Arc::clone(&self.client);
@@ -1242,6 +1492,7 @@ impl App {
     /// Update the unified picker query and trigger debounced search.
     pub fn unified_picker_set_query(&mut self, query: String) {
         self.unified_picker.set_query(query);
+        self.sync_directory_browse();
         self.mark_dirty();
     }
 
@@ -1250,6 +1501,7 @@ impl App {
         let mut query = self.unified_picker.query.clone();
         query.push(c);
         self.unified_picker.set_query(query);
+        self.sync_directory_browse();
         self.mark_dirty();
     }
 
@@ -1264,6 +1516,7 @@ impl App {
             let mut query = self.unified_picker.query.clone();
             query.pop();
             self.unified_picker.set_query(query);
+            self.sync_directory_browse();
             self.mark_dirty();
             false
         }
@@ -1378,7 +1631,7 @@ impl App {
             name,
             path: local_path,
         };
-        self.selected_folder = Some(folder);
+        self.attach_folder(folder);
         self.unified_picker.finish_clone();
         self.unified_picker.close();
         self.remove_unified_picker_query_from_input();
@@ -1405,6 +1658,38 @@ impl App {
         }
     }
 
+    /// Attach the highlighted folder or local repo as a chip and close the
+    /// picker, so more folders can be mentioned before the message is sent.
+    ///
+    /// # Returns
+    /// `false` (leaving the picker open) if the highlighted item isn't a
+    /// local folder.
+    pub fn unified_picker_attach_selected(&mut self) -> bool {
+        use crate::models::picker::PickerItem;
+
+        let folder = match self.unified_picker.selected_item().cloned() {
+            Some(PickerItem::Folder { path, name })
+            | Some(PickerItem::Repo {
+                local_path: Some(path),
+                name,
+                ..
+            }) => crate::models::Folder { name, path },
+            _ => return false,
+        };
+
+        self.remove_unified_picker_query_from_input();
+        self.unified_picker.close();
+        let name = folder.name.clone();
+        if !self.attach_folder(folder) {
+            self.set_status_notice(
+                format!("{} is already attached", name),
+                std::time::Duration::from_secs(2),
+            );
+        }
+        self.mark_dirty();
+        true
+    }
+
     // =========================================================================
     // Browse List Methods (for /threads and /repos commands)
     // =========================================================================
@@ -1412,20 +1697,33 @@ impl App {
     /// Open the full-screen browse list view.
     ///
     /// Navigates to the BrowseList screen and loads initial data.
+    pub fn open_browse_list(&mut self, mode: crate::app::BrowseListMode) {
+        // Navigate to BrowseList screen
+        self.screen = crate::app::Screen::BrowseList;
+        self.reset_browse_list(mode, String::new());
+    }
+
+    /// Reset the browse list to `mode`, searching for `query`, and load its items.
+    ///
     /// For Repos: uses session cache (same as @ picker) for instant display.
     /// For Threads: loads from API with debounced search.
-    pub fn open_browse_list(&mut self, mode: crate::app::BrowseListMode) {
+    /// For All: both of the above.
+    pub(super) fn reset_browse_list(&mut self, mode: crate::app::BrowseListMode, query: String) {
+        use crate::app::BrowseListMode;
         use crate::ui::MAX_ITEMS;
 
         // Reset state for the new view
         self.browse_list = crate::app::BrowseListState {
             mode,
-            search_query: String::new(),
+            search_query: query.clone(),
             search_focused: false,
             selected_index: 0,
             scroll_offset: 0,
             total_count: 0,
             threads: Vec::new(),
+            all_threads: Vec::new(),
+            // The archive filter sticks across modes and reopening
+            archive_filter: self.browse_list.archive_filter,
             repos: Vec::new(),
             all_repos: Vec::new(),
             loading: true,
@@ -1437,46 +1735,48 @@ impl App {
             cloning: false,
             clone_message: None,
         };
-
-        // Navigate to BrowseList screen
-        self.screen = crate::app::Screen::BrowseList;
         self.mark_dirty();
 
-        match mode {
-            crate::app::BrowseListMode::Threads => {
-                // Threads: load from API
-                self.load_browse_list_data(String::new(), MAX_ITEMS);
-            }
-            crate::app::BrowseListMode::Repos => {
-                // Repos: use session cache (same data as @ picker)
-                if let Some(items) = self.picker_cache.get_repos() {
-                    // Convert PickerItem::Repo to RepoEntry
-                    let repos: Vec<crate::models::picker::RepoEntry> = items
-                        .iter()
-                        .filter_map(|item| {
-                            if let crate::models::picker::PickerItem::Repo { name, local_path, url } = item {
-                                Some(crate::models::picker::RepoEntry {
-                                    name_with_owner: name.clone(),
-                                    url: url.clone(),
-                                    local_path: local_path.clone(),
-                                    description: None,
-                                    is_private: None,
-                                    pushed_at: None,
-                                    is_fork: None,
-                                })
-                            } else {
-                                None
-                            }
-                        })
-                        .collect();
-                    self.browse_list.all_repos = repos.clone();
-                    self.browse_list.repos = repos;
+        if mode != BrowseListMode::Repos {
+            // Threads: load from API
+            self.load_browse_list_data(query.clone(), MAX_ITEMS);
+        }
+        if mode != BrowseListMode::Threads {
+            // Repos: use session cache (same data as @ picker)
+            if let Some(items) = self.picker_cache.get_repos() {
+                // Convert PickerItem::Repo to RepoEntry
+                let repos: Vec<crate::models::picker::RepoEntry> = items
+                    .iter()
+                    .filter_map(|item| {
+                        if let crate::models::picker::PickerItem::Repo { name, local_path, url } = item {
+                            Some(crate::models::picker::RepoEntry {
+                                name_with_owner: name.clone(),
+                                url: url.clone(),
+                                local_path: local_path.clone(),
+                                description: None,
+                                is_private: None,
+                                pushed_at: None,
+                                is_fork: None,
+                            })
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                self.browse_list.all_repos = repos.clone();
+                self.browse_list.repos = repos;
+                if mode == BrowseListMode::Repos {
                     self.browse_list.loading = false;
-                    self.mark_dirty();
-                } else {
-                    // Fallback: load from API if not cached yet
-                    self.load_browse_list_data(String::new(), MAX_ITEMS);
                 }
+                if !query.is_empty() {
+                    self.browse_list_filter_repos_local(&query);
+                }
+                self.mark_dirty();
+            } else if mode == BrowseListMode::Repos {
+                // Fallback: load from API if not cached yet
+                self.load_browse_list_data(query, MAX_ITEMS);
+            } else {
+                self.load_browse_list_repos();
             }
         }
     }
@@ -1499,7 +1799,7 @@ impl App {
 
         tokio::spawn(async move {
             match mode {
-                crate::app::BrowseListMode::Threads => {
+                crate::app::BrowseListMode::Threads | crate::app::BrowseListMode::All => {
                     match client.search_threads(&query, limit).await {
                         Ok(response) => {
                             let threads = response.threads;
@@ -1549,10 +1849,7 @@ impl App {
 
     /// Navigate down in the browse list.
     pub fn browse_list_move_down(&mut self) {
-        let max_index = match self.browse_list.mode {
-            crate::app::BrowseListMode::Threads => self.browse_list.threads.len().saturating_sub(1),
-            crate::app::BrowseListMode::Repos => self.browse_list.repos.len().saturating_sub(1),
-        };
+        let max_index = self.browse_list.item_count().saturating_sub(1);
 
         if self.browse_list.selected_index < max_index {
             self.browse_list.selected_index += 1;
@@ -1560,7 +1857,11 @@ impl App {
             // Adjust scroll to keep selection visible
             // Each item takes 3 lines (name, path, blank line)
             const LINES_PER_ITEM: usize = 3;
-            let visible_rows = (self.terminal_height as usize).saturating_sub(8).max(5);
+            let mut visible_rows = (self.terminal_height as usize).saturating_sub(8).max(5);
+            if self.browse_list.mode == crate::app::BrowseListMode::All {
+                // Room for the two section headers
+                visible_rows = visible_rows.saturating_sub(2).max(LINES_PER_ITEM);
+            }
             let visible_items = visible_rows / LINES_PER_ITEM;
             if self.browse_list.selected_index >= self.browse_list.scroll_offset + visible_items {
                 self.browse_list.scroll_offset = self.browse_list.selected_index.saturating_sub(visible_items - 1);
@@ -1582,6 +1883,7 @@ impl App {
 
         // Clear existing data and reload
         self.browse_list.threads.clear();
+        self.browse_list.all_threads.clear();
         self.browse_list.repos.clear();
 
         self.load_browse_list_data(query, MAX_ITEMS);
@@ -1624,7 +1926,10 @@ impl App {
             self.browse_list.pending_search = None;
             self.browse_list.searching = true;
             self.browse_list.threads.clear();
-            self.browse_list.repos.clear();
+            self.browse_list.all_threads.clear();
+            if self.browse_list.mode != crate::app::BrowseListMode::All {
+                self.browse_list.repos.clear();
+            }
             self.load_browse_list_data(query, MAX_ITEMS);
         }
     }
@@ -1633,31 +1938,29 @@ impl App {
     ///
     /// Returns the action to take (navigate to thread, set working directory, etc.)
     pub fn browse_list_select(&mut self) -> BrowseListSelectAction {
-        match self.browse_list.mode {
-            crate::app::BrowseListMode::Threads => {
-                if let Some(thread) = self.browse_list.threads.get(self.browse_list.selected_index) {
-                    let id = thread.id.clone();
-                    let title = thread.title.clone().unwrap_or_else(|| "Untitled".to_string());
-                    return BrowseListSelectAction::OpenThread { id, title };
-                }
+        match self.browse_list.selected_item() {
+            Some(crate::app::BrowseListItem::Thread(index)) => {
+                let thread = &self.browse_list.threads[index];
+                let id = thread.id.clone();
+                let title = thread.title.clone().unwrap_or_else(|| "Untitled".to_string());
+                BrowseListSelectAction::OpenThread { id, title }
             }
-            crate::app::BrowseListMode::Repos => {
-                if let Some(repo) = self.browse_list.repos.get(self.browse_list.selected_index) {
-                    if let Some(ref local_path) = repo.local_path {
-                        return BrowseListSelectAction::SetWorkingDirectory {
-                            path: local_path.clone(),
-                            name: repo.name_with_owner.clone(),
-                        };
-                    } else {
-                        return BrowseListSelectAction::CloneRepo {
-                            name: repo.name_with_owner.clone(),
-                            url: repo.url.clone(),
-                        };
+            Some(crate::app::BrowseListItem::Repo(index)) => {
+                let repo = &self.browse_list.repos[index];
+                if let Some(ref local_path) = repo.local_path {
+                    BrowseListSelectAction::SetWorkingDirectory {
+                        path: local_path.clone(),
+                        name: repo.name_with_owner.clone(),
+                    }
+                } else {
+                    BrowseListSelectAction::CloneRepo {
+                        name: repo.name_with_owner.clone(),
+                        url: repo.url.clone(),
                     }
                 }
             }
+            None => BrowseListSelectAction::None,
         }
-        BrowseListSelectAction::None
     }
 
     /// Toggle search focus in browse list.
@@ -1685,6 +1988,10 @@ impl App {
                 self.browse_list.repos = self.browse_list.all_repos.clone();
                 self.mark_dirty();
             }
+            crate::app::BrowseListMode::All => {
+                self.load_browse_list_data(String::new(), MAX_ITEMS);
+                self.browse_list.repos = self.browse_list.all_repos.clone();
+            }
         }
     }
 
@@ -1741,8 +2048,8 @@ impl App {
         self.browse_list.cloning = false;
         self.browse_list.clone_message = None;
 
-        // Set cloned repo as working directory
-        self.selected_folder = Some(crate::models::Folder {
+        // Attach cloned repo as a working directory
+        self.attach_folder(crate::models::Folder {
             name,
             path: local_path,
         });
@@ -1787,10 +2094,17 @@ impl App {
         let debug_tx = self.debug_tx.clone();
         let thread_id_for_task = thread_id.clone();
 
+        self.cache.begin_stream_generation(&thread_id);
+
         // Spawn async task to send resume request
-        tokio::spawn(async move {
+        let handle = tokio::spawn(async move {
             match client.stream(&request).await {
-                Ok(mut stream) => {
+                Ok(stream) => {
+                    let mut stream = super::event_log::record_sse_events(
+                        stream,
+                        message_tx.clone(),
+                        thread_id_for_task.clone(),
+                    );
                     // Update connection status
                     let _ = message_tx.send(AppMessage::ConnectionStatus(true));
                     App::process_stream(
@@ -1811,6 +2125,7 @@ impl App {
                 }
             }
         });
+        self.track_stream_task(thread_id, handle);
 
         self.mark_dirty();
     }
@@ -2148,7 +2463,7 @@ mod tests {
     }
 
     // =========================================================================
-    // select_folder and clear_folder tests
+    // select_folder and remove_folder tests
     // =========================================================================
 
     #[test]
@@ -2158,8 +2473,20 @@ mod tests {
 
         app.select_folder(folder.clone());
 
-        assert!(app.selected_folder.is_some());
-        assert_eq!(app.selected_folder.as_ref().unwrap().name, "my-project");
+        assert_eq!(app.selected_folders.len(), 1);
+        assert_eq!(app.selected_folders[0].name, "my-project");
+    }
+
+    #[test]
+    fn test_select_folder_attaches_several_without_duplicates() {
+        let mut app = create_test_app();
+        app.select_folder(create_test_folder("frontend", "/home/user/frontend"));
+        app.select_folder(create_test_folder("backend", "/home/user/backend"));
+        assert!(!app.attach_folder(create_test_folder("frontend", "/home/user/frontend")));
+
+        let names: Vec<&str> = app.selected_folders.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["frontend", "backend"]);
+        assert!(app.is_folder_attached("/home/user/backend"));
     }
 
     #[test]
@@ -2178,73 +2505,81 @@ mod tests {
     }
 
     #[test]
-    fn test_clear_folder() {
+    fn test_remove_folder() {
         let mut app = create_test_app();
-        let folder = create_test_folder("my-project", "/home/user/my-project");
-        app.selected_folder = Some(folder);
+        app.selected_folders = vec![
+            create_test_folder("frontend", "/home/user/frontend"),
+            create_test_folder("backend", "/home/user/backend"),
+        ];
 
-        app.clear_folder();
+        app.remove_folder(0);
 
-        assert!(app.selected_folder.is_none());
+        assert_eq!(app.selected_folders.len(), 1);
+        assert_eq!(app.selected_folders[0].name, "backend");
     }
 
     #[test]
-    fn test_clear_folder_when_none_selected() {
+    fn test_remove_folder_when_none_selected() {
         let mut app = create_test_app();
-        app.selected_folder = None;
+        app.selected_folders.clear();
 
         // Should not panic when no folder is selected
-        app.clear_folder();
+        app.remove_folder(0);
 
-        assert!(app.selected_folder.is_none());
+        assert!(app.selected_folders.is_empty());
     }
 
     // =========================================================================
-    // should_clear_folder_on_backspace tests
+    // folder_chip_at_cursor tests
     // =========================================================================
 
     #[test]
-    fn test_should_clear_folder_on_backspace_at_start() {
+    fn test_folder_chip_at_cursor_at_start() {
         let mut app = create_test_app();
-        let folder = create_test_folder("my-project", "/home/user/my-project");
-        app.selected_folder = Some(folder);
+        app.selected_folders = vec![
+            create_test_folder("frontend", "/home/user/frontend"),
+            create_test_folder("backend", "/home/user/backend"),
+        ];
         // Cursor should be at (0, 0) by default for empty input
 
-        assert!(app.should_clear_folder_on_backspace());
+        // The cursor touches the last chip; removing it exposes the one before
+        assert_eq!(app.folder_chip_at_cursor(), Some(1));
+        app.remove_folder(1);
+        assert_eq!(app.folder_chip_at_cursor(), Some(0));
     }
 
     #[test]
-    fn test_should_not_clear_folder_when_cursor_not_at_start() {
+    fn test_no_folder_chip_at_cursor_when_cursor_not_at_start() {
         let mut app = create_test_app();
         let folder = create_test_folder("my-project", "/home/user/my-project");
-        app.selected_folder = Some(folder);
+        app.selected_folders = vec![folder];
         // Type something to move cursor away from start
         app.textarea.insert_char('h');
         app.textarea.insert_char('i');
 
-        assert!(!app.should_clear_folder_on_backspace());
+        assert_eq!(app.folder_chip_at_cursor(), None);
     }
 
     #[test]
-    fn test_should_not_clear_folder_when_no_folder_selected() {
+    fn test_no_folder_chip_at_cursor_when_no_folder_selected() {
         let mut app = create_test_app();
-        app.selected_folder = None;
+        app.selected_folders.clear();
         // Cursor at (0, 0)
 
-        assert!(!app.should_clear_folder_on_backspace());
+        assert_eq!(app.folder_chip_at_cursor(), None);
     }
 
     #[test]
-    fn test_should_not_clear_folder_when_on_second_line() {
+    fn test_no_folder_chip_at_cursor_when_on_second_line() {
         let mut app = create_test_app();
         let folder = create_test_folder("my-project", "/home/user/my-project");
-        app.selected_folder = Some(folder);
+        app.selected_folders = vec![folder];
         // Add content and a newline
         app.textarea.insert_char('x');
         app.textarea.insert_newline();
         // Cursor is now on line 1, column 0
 
-        assert!(!app.should_clear_folder_on_backspace());
+        assert_eq!(app.folder_chip_at_cursor(), None);
     }
 
     // =========================================================================
@@ -2402,23 +2737,26 @@ mod tests {
     #[test]
     fn test_working_directory_extracted_from_selected_folder() {
         let mut app = create_test_app();
-        let folder = create_test_folder("my-project", "/Users/dev/my-project");
-        app.selected_folder = Some(folder);
+        app.selected_folders = vec![
+            create_test_folder("my-project", "/Users/dev/my-project"),
+            create_test_folder("api", "/Users/dev/api"),
+        ];
 
-        // The working_directory extraction happens in stream.rs
-        // Here we just verify the selected_folder is accessible
-        let wd = app.selected_folder.as_ref().map(|f| f.path.clone());
+        // The first folder is the working directory, the rest come along
+        let (wd, additional) = app.selected_directories();
         assert_eq!(wd, Some("/Users/dev/my-project".to_string()));
+        assert_eq!(additional, vec!["/Users/dev/api".to_string()]);
     }
 
     #[test]
     fn test_no_working_directory_when_no_folder_selected() {
         let app = create_test_app();
         // No folder selected
-        assert!(app.selected_folder.is_none());
+        assert!(app.selected_folders.is_empty());
 
-        let wd = app.selected_folder.as_ref().map(|f| f.path.clone());
+        let (wd, additional) = app.selected_directories();
         assert!(wd.is_none());
+        assert!(additional.is_empty());
     }
 
     // =========================================================================
@@ -2554,4 +2892,27 @@ mod tests {
         // After clear, should trigger again
         assert!(app.is_slash_autocomplete_trigger());
     }
+
+    // =========================================================================
+    // toggle_timestamps tests
+    // =========================================================================
+
+    #[test]
+    fn test_toggle_timestamps_invalidates_layout_caches() {
+        let mut app = create_test_app();
+        app.height_cache = Some(crate::app::CachedHeights::new(
+            Arc::new("t".to_string()),
+            80,
+        ));
+        app.rendered_lines_cache
+            .insert("t", 1, 0, vec![ratatui::text::Line::from("x")]);
+
+        app.toggle_timestamps();
+        assert!(app.show_timestamps);
+        assert!(app.height_cache.is_none());
+        assert!(app.rendered_lines_cache.is_empty());
+
+        app.toggle_timestamps();
+        assert!(!app.show_timestamps);
+    }
 }
@@ -0,0 +1,356 @@
+//! Connection diagnostics (`/status`).
+//!
+//! The overlay shows what the TUI knows about its backend: the Conductor URL
+//! and when it last answered, the WebSocket state with reconnect attempts and
+//! why it last dropped, when the auth token expires, pending permissions, and
+//! the running version with any downloaded update. It is rebuilt every
+//! `STATUS_REFRESH_INTERVAL` from the tick while open, and `c` copies it as
+//! plain text for bug reports. `spoq status` prints the same report from the
+//! command line, with a health check in place of the live connection state.
+
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local, TimeZone, Utc};
+
+use crate::cli::VERSION;
+use crate::update::{UpdateState, UpdateStateManager};
+use crate::websocket::WsConnectionState;
+
+use super::App;
+
+/// How often the open overlay is rebuilt
+const STATUS_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Why and when the WebSocket connection last dropped
+#[derive(Debug, Clone, PartialEq)]
+pub struct WsDisconnect {
+    pub reason: String,
+    pub at: DateTime<Utc>,
+}
+
+/// WebSocket part of the report (the TUI only)
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebSocketStatus {
+    pub state: WsConnectionState,
+    /// Reconnect attempts since startup
+    pub reconnect_attempts: u32,
+    pub last_disconnect: Option<WsDisconnect>,
+    /// Outgoing messages waiting for the server's ack
+    pub unacked_messages: usize,
+}
+
+/// Snapshot of the backend, connection and auth state
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusReport {
+    pub version: String,
+    pub conductor_url: Option<String>,
+    /// Result of a health check, if one was made (`spoq status`)
+    pub healthy: Option<bool>,
+    /// When a Conductor request last succeeded
+    pub last_success: Option<DateTime<Utc>>,
+    pub websocket: Option<WebSocketStatus>,
+    /// Access token expiry (Unix seconds)
+    pub token_expires_at: Option<i64>,
+    pub has_token: bool,
+    pub pending_permissions: Option<usize>,
+    pub update: UpdateState,
+}
+
+impl StatusReport {
+    /// Labelled rows of the report as of `now`
+    pub fn rows(&self, now: DateTime<Utc>) -> Vec<(&'static str, String)> {
+        let mut rows = vec![(
+            "Conductor",
+            self.conductor_url
+                .clone()
+                .unwrap_or_else(|| "not configured".to_string()),
+        )];
+        if let Some(healthy) = self.healthy {
+            let health = if healthy { "healthy" } else { "unhealthy" };
+            rows.push(("Health", health.to_string()));
+        }
+        rows.push((
+            "Last success",
+            self.last_success
+                .map(|at| format_time(at, now))
+                .unwrap_or_else(|| "none yet".to_string()),
+        ));
+
+        if let Some(ws) = &self.websocket {
+            let state = match ws.state {
+                WsConnectionState::Connected => "connected".to_string(),
+                WsConnectionState::Reconnecting { attempt } => {
+                    format!("reconnecting (attempt {})", attempt)
+                }
+                WsConnectionState::Disconnected => "disconnected".to_string(),
+            };
+            rows.push(("WebSocket", state));
+            rows.push(("Reconnects", ws.reconnect_attempts.to_string()));
+            rows.push((
+                "Last drop",
+                ws.last_disconnect
+                    .as_ref()
+                    .map(|drop| format!("{}, {}", drop.reason, format_time(drop.at, now)))
+                    .unwrap_or_else(|| "none".to_string()),
+            ));
+            if ws.unacked_messages > 0 {
+                rows.push(("Unacked", ws.unacked_messages.to_string()));
+            }
+        }
+
+        rows.push(("Auth token", self.token_status(now)));
+        if let Some(count) = self.pending_permissions {
+            rows.push(("Permissions", format!("{} pending", count)));
+        }
+
+        rows.push(("Version", self.version.clone()));
+        let update = match (
+            &self.update.available_version,
+            self.update.has_pending_update(),
+        ) {
+            (Some(version), true) => format!("{} downloaded, restart to install", version),
+            (Some(version), false) => format!("{} available", version),
+            (None, _) => "up to date".to_string(),
+        };
+        rows.push(("Update", update));
+        if let Some(last_check) = self.update.last_check.and_then(from_unix) {
+            rows.push(("Last check", format_time(last_check, now)));
+        }
+        rows
+    }
+
+    /// The report as plain text, one `Label: value` line per row
+    pub fn to_text(&self, now: DateTime<Utc>) -> String {
+        let rows = self.rows(now);
+        let width = rows
+            .iter()
+            .map(|(label, _)| label.len() + 1)
+            .max()
+            .unwrap_or(0);
+        let mut text = format!("spoq status ({})\n", format_local(now));
+        for (label, value) in rows {
+            let label = format!("{}:", label);
+            text.push_str(&format!("{:<width$} {}\n", label, value, width = width));
+        }
+        text
+    }
+
+    /// Access token expiry relative to `now`
+    fn token_status(&self, now: DateTime<Utc>) -> String {
+        if !self.has_token {
+            return "not signed in".to_string();
+        }
+        match self.token_expires_at.and_then(from_unix) {
+            Some(expires) if expires <= now => {
+                format!("expired {} ago", format_span(now - expires))
+            }
+            Some(expires) => format!(
+                "expires in {} ({})",
+                format_span(expires - now),
+                format_local(expires)
+            ),
+            None => "no expiry recorded".to_string(),
+        }
+    }
+}
+
+/// Read the update state, or the default if there's no home directory
+pub fn load_update_state() -> UpdateState {
+    UpdateStateManager::new()
+        .map(|manager| manager.load())
+        .unwrap_or_default()
+}
+
+/// Timestamp from Unix seconds
+fn from_unix(secs: i64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(secs, 0).single()
+}
+
+/// Local wall-clock time of `at`
+fn format_local(at: DateTime<Utc>) -> String {
+    at.with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+/// Local time of `at` and how long before `now` it was
+fn format_time(at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    format!("{} ({} ago)", format_local(at), format_span(now - at))
+}
+
+/// Compact length of a span (`45s`, `12m`, `3h 5m`, `2d`)
+fn format_span(span: chrono::Duration) -> String {
+    let secs = span.num_seconds().max(0);
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else if secs < 86400 {
+        format!("{}h {}m", secs / 3600, secs % 3600 / 60)
+    } else {
+        format!("{}d", secs / 86400)
+    }
+}
+
+/// Open `/status` overlay
+#[derive(Debug, Clone)]
+pub struct StatusView {
+    pub report: StatusReport,
+    /// When the report was last rebuilt
+    pub refreshed_at: Instant,
+}
+
+impl App {
+    /// Snapshot the current diagnostics
+    pub fn status_report(&self) -> StatusReport {
+        StatusReport {
+            version: VERSION.to_string(),
+            conductor_url: Some(self.client.base_url.clone()),
+            healthy: None,
+            last_success: self.client.last_success(),
+            websocket: Some(WebSocketStatus {
+                state: self.ws_connection_state.clone(),
+                reconnect_attempts: self.ws_reconnect_attempts,
+                last_disconnect: self.ws_last_disconnect.clone(),
+                unacked_messages: self.ws_unacked_messages,
+            }),
+            token_expires_at: self.credentials.expires_at,
+            has_token: self.credentials.has_token(),
+            pending_permissions: Some(self.dashboard.pending_permissions_iter().count()),
+            update: load_update_state(),
+        }
+    }
+
+    /// Open the `/status` overlay
+    pub fn open_status_view(&mut self) {
+        self.status_view = Some(StatusView {
+            report: self.status_report(),
+            refreshed_at: Instant::now(),
+        });
+        self.mark_dirty();
+    }
+
+    /// Close the `/status` overlay
+    pub fn close_status_view(&mut self) {
+        self.status_view = None;
+        self.mark_dirty();
+    }
+
+    /// Rebuild the open overlay's report if it is due. Called every tick.
+    pub(super) fn refresh_status_view(&mut self) {
+        let due = self
+            .status_view
+            .as_ref()
+            .is_some_and(|view| view.refreshed_at.elapsed() >= STATUS_REFRESH_INTERVAL);
+        if due {
+            self.open_status_view();
+        }
+    }
+
+    /// Copy the open report to the clipboard as text
+    pub fn copy_status_report(&mut self) {
+        if let Some(view) = &self.status_view {
+            let text = view.report.to_text(Utc::now());
+            self.copy_text(text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> StatusReport {
+        StatusReport {
+            version: "1.2.3".to_string(),
+            conductor_url: Some("https://vps.example".to_string()),
+            healthy: None,
+            last_success: None,
+            websocket: Some(WebSocketStatus {
+                state: WsConnectionState::Reconnecting { attempt: 2 },
+                reconnect_attempts: 5,
+                last_disconnect: None,
+                unacked_messages: 0,
+            }),
+            token_expires_at: None,
+            has_token: true,
+            pending_permissions: Some(1),
+            update: UpdateState::default(),
+        }
+    }
+
+    /// Now, to the whole second (token expiry is stored in seconds)
+    fn now() -> DateTime<Utc> {
+        from_unix(Utc::now().timestamp()).unwrap()
+    }
+
+    fn row<'a>(rows: &'a [(&'static str, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| *l == label)
+            .map(|(_, value)| value.as_str())
+            .unwrap_or_else(|| panic!("no {} row", label))
+    }
+
+    #[test]
+    fn test_rows_describe_connection_and_auth() {
+        let now = now();
+        let mut report = report();
+        report.token_expires_at = Some((now + chrono::Duration::minutes(90)).timestamp());
+        report.last_success = Some(now - chrono::Duration::seconds(12));
+        report.websocket.as_mut().unwrap().last_disconnect = Some(WsDisconnect {
+            reason: "Connection dropped".to_string(),
+            at: now - chrono::Duration::minutes(3),
+        });
+        report.update.available_version = Some("1.3.0".to_string());
+        report.update.pending_update_path = Some("/tmp/spoq".to_string());
+
+        let rows = report.rows(now);
+        assert_eq!(row(&rows, "WebSocket"), "reconnecting (attempt 2)");
+        assert_eq!(row(&rows, "Reconnects"), "5");
+        assert!(row(&rows, "Last drop").starts_with("Connection dropped, "));
+        assert!(row(&rows, "Last drop").ends_with("(3m ago)"));
+        assert!(row(&rows, "Last success").ends_with("(12s ago)"));
+        assert!(row(&rows, "Auth token").starts_with("expires in 1h 30m"));
+        assert_eq!(row(&rows, "Permissions"), "1 pending");
+        assert_eq!(row(&rows, "Update"), "1.3.0 downloaded, restart to install");
+    }
+
+    #[test]
+    fn test_token_status() {
+        let now = now();
+        let mut report = report();
+        assert_eq!(report.token_status(now), "no expiry recorded");
+
+        report.token_expires_at = Some((now - chrono::Duration::hours(2)).timestamp());
+        assert_eq!(report.token_status(now), "expired 2h 0m ago");
+
+        report.has_token = false;
+        assert_eq!(report.token_status(now), "not signed in");
+    }
+
+    #[test]
+    fn test_text_report_has_every_row() {
+        let report = report();
+        let now = now();
+        let text = report.to_text(now);
+        assert!(text.starts_with("spoq status ("));
+        assert_eq!(text.lines().count(), report.rows(now).len() + 1);
+        assert!(text.contains("Conductor:    https://vps.example"));
+    }
+
+    #[test]
+    fn test_status_view_opens_and_closes() {
+        let mut app = App::default();
+        app.ws_reconnect_attempts = 3;
+        app.open_status_view();
+        let view = app.status_view.as_ref().unwrap();
+        assert_eq!(
+            view.report.websocket.as_ref().unwrap().reconnect_attempts,
+            3
+        );
+        assert_eq!(view.report.version, VERSION);
+
+        app.close_status_view();
+        assert!(app.status_view.is_none());
+    }
+}
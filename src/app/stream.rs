@@ -8,11 +8,15 @@ use tokio::sync::mpsc;
 use crate::debug::{
     DebugEventKind, ErrorData, ErrorSource, ProcessedEventData, StreamLifecycleData, StreamPhase,
 };
+use crate::conductor::ConductorError;
 use crate::events::SseEvent;
 use crate::models::{PermissionMode, StreamRequest, ThreadType};
 use crate::state::Todo;
+use crate::websocket::WsPresenceState;
 
-use super::{emit_debug, log_thread_update, truncate_for_debug, App, AppMessage, Screen};
+use super::{
+    emit_debug, log_thread_update, truncate_for_debug, App, AppMessage, Screen, StreamTransport,
+};
 use crate::debug::DebugEventSender;
 
 /// Format server-side tool result for display.
@@ -104,8 +108,8 @@ impl App {
     /// The unified stream endpoint routes based on thread_type parameter.
     /// The current permission_mode is sent with the request.
     ///
-    /// Edge case: If the thread has a streaming response in progress, we block submission
-    /// to prevent sending multiple messages before the current response completes.
+    /// Edge case: If the thread has a streaming response in progress, the input steers it
+    /// (see `queue_steering_message`). `queue_input` queues it behind the response instead.
     ///
     /// The `new_thread_type` parameter specifies what type of thread to create if this
     /// is a NEW conversation. It's ignored when continuing an existing thread.
@@ -120,12 +124,21 @@ impl App {
         // Conversation = continue the thread that was opened via open_thread()
         let is_command_deck = self.screen == Screen::CommandDeck;
 
-        // Extract working directory from selected folder (if any)
-        let working_directory = self.selected_folder.as_ref().map(|f| f.path.clone());
+        // Extract working directories from the attached folders (if any)
+        let (working_directory, additional_directories) = self.selected_directories();
 
         // Extract image hashes upfront (before drain) so all branches can use them
         let image_hashes: Vec<String> = self.pending_images.iter().map(|img| img.hash.clone()).collect();
 
+        // Another client just posted to this thread - warn before crossing turns
+        if !is_command_deck {
+            if let Some(existing_id) = self.active_thread_id.clone() {
+                if self.should_warn_crossed_turn(&existing_id) {
+                    return;
+                }
+            }
+        }
+
         // Determine thread_id based on screen
         let (thread_id, is_new_thread) = if is_command_deck {
             // NEW thread - create pending, will reconcile when backend responds
@@ -134,13 +147,14 @@ impl App {
                 new_thread_type,
                 working_directory.clone(),
                 image_hashes.clone(),
+                None,
             );
             self.active_thread_id = Some(pending_id.clone());
             self.screen = Screen::Conversation;
             // Reset scroll for new conversation
             self.reset_scroll();
-            // Clear selected folder after successful thread creation
-            self.selected_folder = None;
+            // Clear selected folders after successful thread creation
+            self.selected_folders.clear();
             (pending_id, true)
         } else if let Some(existing_id) = &self.active_thread_id {
             // CONTINUING existing thread (we're on Conversation screen)
@@ -169,14 +183,31 @@ impl App {
                 new_thread_type,
                 working_directory.clone(),
                 image_hashes.clone(),
+                None,
             );
             self.active_thread_id = Some(pending_id.clone());
             self.reset_scroll();
-            // Clear selected folder after successful thread creation
-            self.selected_folder = None;
+            // Clear selected folders after successful thread creation
+            self.selected_folders.clear();
             (pending_id, true)
         };
 
+        // A reply reference applies to the message being sent (queued prompts keep it pending)
+        let reply_to = self.take_pending_reply(&thread_id);
+
+        // New threads start with the model picked on the CommandDeck (if any)
+        if is_new_thread {
+            if let Some(model) = self.next_thread_model.take() {
+                self.cache.set_thread_model(&thread_id, Some(model));
+            }
+        }
+
+        // Let other clients on this thread know a turn is starting
+        if !is_new_thread {
+            self.send_presence(&thread_id, WsPresenceState::MessageSent);
+        }
+        self.last_typing_sent = None;
+
         // Add to input history before clearing
         self.input_history.add(content.clone());
 
@@ -198,80 +229,159 @@ impl App {
             Some(&thread_id),
         );
 
+        // Pending images are uploaded first and referenced by ID in the request
+        let images: Vec<crate::clipboard::ImageAttachment> =
+            self.pending_images.drain(..).collect();
+
+        // Always send thread_id - for new threads, we generate a UUID upfront
+        // The backend will use our client-generated UUID as the canonical thread_id
+        let request = self
+            .stream_request_for(
+                &thread_id,
+                content,
+                self.permission_mode,
+                (working_directory, additional_directories),
+            )
+            .with_reply_to(reply_to);
+
+        if images.is_empty() {
+            self.spawn_stream_request(request, thread_id);
+        } else {
+            self.spawn_upload_and_stream(request, thread_id, images);
+        }
+    }
+
+    /// Build the stream request for a turn on `thread_id`.
+    ///
+    /// Shared by `submit_input`, queued prompts and message retry, so every
+    /// turn carries the thread's type and model. The thread's own working
+    /// directory is used when no folder is attached, and Execute mode falls
+    /// back to Default where it isn't allowed.
+    pub(super) fn stream_request_for(
+        &mut self,
+        thread_id: &str,
+        content: String,
+        permission_mode: PermissionMode,
+        (working_directory, additional_directories): (Option<String>, Vec<String>),
+    ) -> StreamRequest {
+        let thread = self.cache.get_thread(thread_id);
+        let thread_type = thread.map(|t| t.thread_type).unwrap_or_default();
+        let model = thread.and_then(|t| t.model.clone());
+        let working_directory =
+            working_directory.or_else(|| thread.and_then(|t| t.working_directory.clone()));
+        let permission_mode =
+            self.enforce_bypass_allowed(thread_id, working_directory.as_deref(), permission_mode);
+        StreamRequest::with_thread(content, thread_id.to_string())
+            .with_type(thread_type)
+            .with_permission_mode(permission_mode)
+            .with_working_directory(working_directory)
+            .with_additional_directories(additional_directories)
+            .with_plan_mode(permission_mode == PermissionMode::Plan)
+            .with_model(model)
+    }
+
+    /// Send a stream request and forward its events as `AppMessage`s.
+    ///
+    /// Shared by `submit_input` and message retry. Starts a new stream
+    /// generation for the thread.
+    pub(super) fn spawn_stream_request(&mut self, request: StreamRequest, thread_id: String) {
         // Clone what we need for the async task
         let client = Arc::clone(&self.client);
         let message_tx = self.message_tx.clone();
-        let thread_id_for_task = thread_id.clone();
         let debug_tx = self.debug_tx.clone();
 
-        // Drain pending images into payloads for the request
-        let image_payloads: Vec<crate::models::ImageAttachmentPayload> = self
-            .pending_images
-            .drain(..)
-            .map(|img| crate::models::ImageAttachmentPayload {
-                hash: img.hash,
-                data: img.base64_png,
-            })
-            .collect();
-
-        // Build unified StreamRequest with thread_type
-        // Always send thread_id - for new threads, we generate a UUID upfront
-        // The backend will use our client-generated UUID as the canonical thread_id
-        let is_plan_mode = self.permission_mode == PermissionMode::Plan;
-        let request = StreamRequest::with_thread(content, thread_id)
-            .with_type(new_thread_type)
-            .with_permission_mode(self.permission_mode)
-            .with_working_directory(working_directory)
-            .with_plan_mode(is_plan_mode)
-            .with_images(image_payloads);
+        let transport = self.stream_transport();
+
+        self.cache.begin_stream_generation(&thread_id);
+
+        // Spawn async task for unified stream endpoint
+        let handle = tokio::spawn(Self::run_stream_request(
+            client,
+            message_tx,
+            debug_tx,
+            request,
+            thread_id.clone(),
+            transport,
+        ));
+        self.track_stream_task(thread_id, handle);
+    }
 
+    /// Send a stream request and forward its events until the stream ends.
+    ///
+    /// With `StreamTransport::SseOrPolling`, a transport error on the SSE
+    /// request is retried over polling (see `app/polling.rs`).
+    pub(super) async fn run_stream_request(
+        client: Arc<crate::conductor::ConductorClient>,
+        message_tx: mpsc::UnboundedSender<AppMessage>,
+        debug_tx: Option<DebugEventSender>,
+        request: StreamRequest,
+        thread_id: String,
+        transport: StreamTransport,
+    ) {
         // Emit debug event with full StreamRequest JSON
         if let Ok(json_string) = serde_json::to_string_pretty(&request) {
             emit_debug(
-                &self.debug_tx,
+                &debug_tx,
                 DebugEventKind::ProcessedEvent(ProcessedEventData::new(
                     "StreamRequest",
                     json_string,
                 )),
-                Some(&thread_id_for_task),
+                Some(&thread_id),
             );
         }
 
-        // Spawn async task for unified stream endpoint
-        tokio::spawn(async move {
-            match client.stream(&request).await {
-                Ok(mut stream) => {
-                    // Emit StreamLifecycle connected event
-                    emit_debug(
-                        &debug_tx,
-                        DebugEventKind::StreamLifecycle(StreamLifecycleData::new(
-                            StreamPhase::Connected,
-                        )),
-                        Some(&thread_id_for_task),
-                    );
-                    // Update connection status to connected since streaming works
-                    let _ = message_tx.send(AppMessage::ConnectionStatus(true));
-                    Self::process_stream(&mut stream, &message_tx, &thread_id_for_task, debug_tx)
-                        .await;
-                }
-                Err(e) => {
-                    // Emit error debug event
-                    emit_debug(
-                        &debug_tx,
-                        DebugEventKind::Error(ErrorData::new(
-                            ErrorSource::ConductorApi,
-                            e.to_string(),
-                        )),
-                        Some(&thread_id_for_task),
-                    );
-                    let _ = message_tx.send(AppMessage::StreamError {
-                        thread_id: thread_id_for_task,
-                        error: e.to_string(),
-                        error_code: None,
-                    });
+        let result = match transport {
+            StreamTransport::Polling => client.stream_polling(&request).await,
+            StreamTransport::Sse => client.stream(&request).await,
+            StreamTransport::SseOrPolling => match client.stream(&request).await {
+                Err(ConductorError::Http(e)) => {
+                    tracing::warn!("Streaming failed ({}), trying the polling transport", e);
+                    let polled = client.stream_polling(&request).await;
+                    if polled.is_ok() {
+                        let _ = message_tx.send(AppMessage::PollingModeChanged(true));
+                    }
+                    polled
                 }
+                other => other,
+            },
+        };
+
+        match result {
+            Ok(stream) => {
+                let mut stream = super::event_log::record_sse_events(
+                    stream,
+                    message_tx.clone(),
+                    thread_id.clone(),
+                );
+                // Emit StreamLifecycle connected event
+                emit_debug(
+                    &debug_tx,
+                    DebugEventKind::StreamLifecycle(StreamLifecycleData::new(
+                        StreamPhase::Connected,
+                    )),
+                    Some(&thread_id),
+                );
+                // Update connection status to connected since streaming works
+                let _ = message_tx.send(AppMessage::ConnectionStatus(true));
+                Self::process_stream(&mut stream, &message_tx, &thread_id, debug_tx).await;
             }
-        });
+            Err(e) => {
+                // Emit error debug event
+                emit_debug(
+                    &debug_tx,
+                    DebugEventKind::Error(ErrorData::new(
+                        ErrorSource::ConductorApi,
+                        e.to_string(),
+                    )),
+                    Some(&thread_id),
+                );
+                let _ = message_tx.send(AppMessage::StreamError {
+                    thread_id,
+                    error: e.to_string(),
+                    error_code: None,
+                });
+            }
+        }
     }
 
     /// Process a stream of SSE events and send messages to the app.
@@ -289,6 +399,9 @@ impl App {
         thread_id: &str,
         debug_tx: Option<DebugEventSender>,
     ) {
+        // Whether a terminal event (done, error, cancelled, rate limited) was
+        // received. A stream that ends without one was dropped mid-response.
+        let mut terminated = false;
         while let Some(result) = stream.next().await {
             match result {
                 Ok(event) => {
@@ -314,6 +427,13 @@ impl App {
                                 thread_id: thread_id.to_string(),
                                 token: content_event.text,
                             });
+                            // Track the resume offset in case the connection drops
+                            if let Some(seq) = content_event.meta.seq {
+                                let _ = message_tx.send(AppMessage::StreamProgress {
+                                    thread_id: thread_id.to_string(),
+                                    seq,
+                                });
+                            }
                         }
                         SseEvent::Done(done_event) => {
                             // Parse message_id from string to i64
@@ -338,6 +458,7 @@ impl App {
                                 Some(thread_id),
                             );
 
+                            terminated = true;
                             let _ = message_tx.send(AppMessage::StreamComplete {
                                 thread_id: thread_id.to_string(),
                                 message_id,
@@ -374,6 +495,7 @@ impl App {
                                 error: display_msg,
                                 error_code: error_event.code,
                             });
+                            terminated = true;
                             break;
                         }
                         SseEvent::UserMessageSaved(event) => {
@@ -708,8 +830,11 @@ impl App {
                                 Some(thread_id),
                             );
                             let _ = message_tx.send(AppMessage::UsageReceived {
+                                thread_id: thread_id.to_string(),
                                 context_used: usage_event.context_window_used,
                                 context_limit: usage_event.context_window_limit,
+                                input_tokens: usage_event.input_tokens,
+                                output_tokens: usage_event.output_tokens,
                             });
                         }
                         SseEvent::SystemInit(system_init_event) => {
@@ -744,6 +869,7 @@ impl App {
                                 thread_id: thread_id.to_string(),
                                 reason: cancelled_event.reason,
                             });
+                            terminated = true;
                             break; // Exit stream loop
                         }
                         SseEvent::RateLimited(rate_limited_event) => {
@@ -763,6 +889,7 @@ impl App {
                                 next_account_id: rate_limited_event.next_account_id,
                                 retry_after_secs: rate_limited_event.retry_after_secs,
                             });
+                            terminated = true;
                             break; // Exit stream loop
                         }
                     }
@@ -777,15 +904,39 @@ impl App {
                         )),
                         Some(thread_id),
                     );
-                    let _ = message_tx.send(AppMessage::StreamError {
-                        thread_id: thread_id.to_string(),
-                        error: e.to_string(),
-                        error_code: None,
-                    });
-                    break;
+                    // Transport failures (sleep, network change) can be resumed;
+                    // anything else is a hard error
+                    if matches!(e, crate::conductor::ConductorError::Http(_)) {
+                        let _ = message_tx.send(AppMessage::StreamInterrupted {
+                            thread_id: thread_id.to_string(),
+                            error: e.to_string(),
+                        });
+                    } else {
+                        let _ = message_tx.send(AppMessage::StreamError {
+                            thread_id: thread_id.to_string(),
+                            error: e.to_string(),
+                            error_code: None,
+                        });
+                    }
+                    return;
                 }
             }
         }
+
+        if !terminated {
+            emit_debug(
+                &debug_tx,
+                DebugEventKind::StreamLifecycle(StreamLifecycleData::with_details(
+                    StreamPhase::Disconnected,
+                    "stream ended before done",
+                )),
+                Some(thread_id),
+            );
+            let _ = message_tx.send(AppMessage::StreamInterrupted {
+                thread_id: thread_id.to_string(),
+                error: "Stream ended before the response completed".to_string(),
+            });
+        }
     }
 
     /// Queue a steering message to interrupt the current stream
@@ -860,11 +1011,7 @@ impl App {
         self.cache.interrupt_streaming_for_steering(thread_id);
 
         // Get next message ID
-        let next_id = self
-            .cache
-            .get_messages(thread_id)
-            .map(|m| m.len() as i64 + 1)
-            .unwrap_or(1);
+        let next_id = self.cache.next_message_id(thread_id);
 
         // Create user message for the steering instruction
         use crate::models::{Message, MessageRole, MessageSegment};
@@ -881,6 +1028,7 @@ impl App {
             segments: vec![MessageSegment::Text(qs.instruction.clone())],
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         self.cache.add_message(user_message);
 
@@ -898,6 +1046,7 @@ impl App {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         self.cache.add_message(assistant_message);
 
@@ -1196,6 +1345,60 @@ mod tests {
         assert!(rx.try_recv().is_err(), "Should not receive additional messages after cancelled");
     }
 
+    #[tokio::test]
+    async fn test_process_stream_reports_content_seq() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let thread_id = "test-thread-seq";
+
+        let events: Vec<Result<SseEvent, crate::conductor::ConductorError>> = vec![
+            Ok(SseEvent::Content(crate::events::ContentEvent {
+                text: "Hello".to_string(),
+                meta: crate::events::EventMeta {
+                    seq: Some(7),
+                    ..Default::default()
+                },
+            })),
+            Ok(SseEvent::Done(crate::events::DoneEvent {
+                message_id: "1".to_string(),
+            })),
+        ];
+        let mut pinned_stream = create_stream(events);
+
+        App::process_stream(&mut pinned_stream, &tx, thread_id, None).await;
+
+        assert!(matches!(rx.recv().await, Some(AppMessage::StreamToken { .. })));
+        match rx.recv().await {
+            Some(AppMessage::StreamProgress { seq, .. }) => assert_eq!(seq, 7),
+            other => panic!("Expected StreamProgress message, got {:?}", other),
+        }
+        assert!(matches!(rx.recv().await, Some(AppMessage::StreamComplete { .. })));
+        // Stream ended after done, so it is not treated as interrupted
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_process_stream_eof_before_done_is_interrupted() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let thread_id = "test-thread-eof";
+
+        let events: Vec<Result<SseEvent, crate::conductor::ConductorError>> =
+            vec![Ok(SseEvent::Content(crate::events::ContentEvent {
+                text: "partial".to_string(),
+                meta: crate::events::EventMeta::default(),
+            }))];
+        let mut pinned_stream = create_stream(events);
+
+        App::process_stream(&mut pinned_stream, &tx, thread_id, None).await;
+
+        assert!(matches!(rx.recv().await, Some(AppMessage::StreamToken { .. })));
+        match rx.recv().await {
+            Some(AppMessage::StreamInterrupted { thread_id: id, .. }) => {
+                assert_eq!(id, thread_id);
+            }
+            other => panic!("Expected StreamInterrupted message, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_process_stream_error_with_code() {
         let (tx, mut rx) = mpsc::unbounded_channel();
@@ -1301,4 +1504,42 @@ mod tests {
             _ => panic!("Expected StreamError message, got {:?}", msg),
         }
     }
+
+    #[test]
+    fn test_stream_request_for_carries_thread_settings() {
+        let mut app = App::default();
+        app.cache = crate::cache::ThreadCache::with_stub_data();
+        app.cache
+            .set_thread_model("thread-001", Some("claude-opus-4".to_string()));
+        app.cache
+            .set_thread_working_directory("thread-001", "/home/dev/api");
+
+        let request = app.stream_request_for(
+            "thread-001",
+            "Again".to_string(),
+            PermissionMode::Plan,
+            (None, Vec::new()),
+        );
+        assert_eq!(request.model.as_deref(), Some("claude-opus-4"));
+        assert_eq!(request.working_directory.as_deref(), Some("/home/dev/api"));
+        assert_eq!(request.permission_mode, Some(PermissionMode::Plan));
+        assert!(request.plan_mode);
+
+        // Attached folders win over the thread's own directory
+        let request = app.stream_request_for(
+            "thread-001",
+            "Again".to_string(),
+            PermissionMode::Default,
+            (
+                Some("/tmp/other".to_string()),
+                vec!["/tmp/extra".to_string()],
+            ),
+        );
+        assert_eq!(request.working_directory.as_deref(), Some("/tmp/other"));
+        assert_eq!(
+            request.additional_directories,
+            vec!["/tmp/extra".to_string()]
+        );
+        assert!(!request.plan_mode);
+    }
 }
@@ -0,0 +1,138 @@
+//! Resuming interrupted SSE streams for the App.
+//!
+//! A stream that drops before its terminal event leaves the assistant message
+//! streaming and the thread marked interrupted in the cache. Resume requests
+//! are issued immediately and again whenever connectivity is restored
+//! (`ConnectionStatus(true)` / `WsConnected`); replayed tokens are appended to
+//! the same message. If the backend cannot resume, or too many attempts fail,
+//! the partial message is finalized and an inline error is shown.
+
+use std::sync::Arc;
+
+use super::{App, AppMessage};
+
+/// Resume attempts allowed without receiving any new events
+pub(crate) const MAX_STREAM_RESUME_ATTEMPTS: u8 = 3;
+
+impl App {
+    /// Issue resume requests for every interrupted stream.
+    pub fn resume_interrupted_streams(&mut self) {
+        for thread_id in self.cache.interrupted_streams() {
+            if self.cache.stream_resume_attempts(&thread_id) >= MAX_STREAM_RESUME_ATTEMPTS {
+                self.abandon_stream_resume(
+                    &thread_id,
+                    "Connection lost and the response could not be resumed.".to_string(),
+                );
+                continue;
+            }
+
+            let last_event_id = self.cache.begin_stream_resume(&thread_id);
+            tracing::info!(
+                "Resuming interrupted stream: thread_id={}, last_event_id={:?}",
+                thread_id,
+                last_event_id
+            );
+
+            let client = Arc::clone(&self.client);
+            let message_tx = self.message_tx.clone();
+            let debug_tx = self.debug_tx.clone();
+            let task_thread_id = thread_id.clone();
+
+            let handle = tokio::spawn(async move {
+                match client.resume_stream(&thread_id, last_event_id).await {
+                    Ok(mut stream) => {
+                        let _ = message_tx.send(AppMessage::ConnectionStatus(true));
+                        App::process_stream(&mut stream, &message_tx, &thread_id, debug_tx)
+                            .await;
+                    }
+                    Err(e) => {
+                        let _ = message_tx.send(AppMessage::StreamResumeFailed {
+                            thread_id,
+                            error: e.to_string(),
+                            retryable: !e.is_resume_unavailable(),
+                        });
+                    }
+                }
+            });
+            self.track_stream_task(task_thread_id, handle);
+        }
+    }
+
+    /// Give up on resuming a stream: keep the partial response and show an error.
+    pub(crate) fn abandon_stream_resume(&mut self, thread_id: &str, error: String) {
+        self.cache.finalize_interrupted_message(thread_id);
+
+        if self.active_thread_id.as_deref() == Some(thread_id) {
+            self.add_error_to_active_thread("stream_interrupted".to_string(), error);
+        } else {
+            self.cache
+                .add_error_simple(thread_id, "stream_interrupted".to_string(), error);
+        }
+
+        self.reset_cancel_state();
+        self.tool_tracker.clear();
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Screen;
+    use crate::cache::ThreadCache;
+
+    fn app_with_stream() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.cache
+            .add_streaming_message("thread-001", "hello".to_string(), Vec::new());
+        app.cache.append_to_message("thread-001", "partial");
+        app
+    }
+
+    #[test]
+    fn test_abandon_stream_resume_finalizes_and_shows_error() {
+        let mut app = app_with_stream();
+        app.cache.mark_stream_interrupted("thread-001");
+
+        app.abandon_stream_resume("thread-001", "lost".to_string());
+
+        assert!(!app.cache.is_thread_streaming("thread-001"));
+        assert!(!app.cache.is_stream_interrupted("thread-001"));
+        let last = app.cache.get_messages("thread-001").unwrap().last().unwrap();
+        assert_eq!(last.content, "partial");
+        assert_eq!(app.cache.error_count("thread-001"), 1);
+    }
+
+    #[test]
+    fn test_resume_gives_up_after_max_attempts() {
+        let mut app = app_with_stream();
+        app.cache.mark_stream_interrupted("thread-001");
+        for _ in 0..MAX_STREAM_RESUME_ATTEMPTS {
+            app.cache.begin_stream_resume("thread-001");
+        }
+        app.cache.mark_stream_interrupted("thread-001");
+
+        // No resume task is spawned once the attempt budget is spent
+        app.resume_interrupted_streams();
+
+        assert!(!app.cache.is_thread_streaming("thread-001"));
+        assert_eq!(app.cache.error_count("thread-001"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resume_interrupted_streams_starts_attempt() {
+        let mut app = app_with_stream();
+        app.cache.record_stream_seq("thread-001", 9);
+        app.cache.mark_stream_interrupted("thread-001");
+
+        app.resume_interrupted_streams();
+
+        assert!(!app.cache.is_stream_interrupted("thread-001"));
+        assert_eq!(app.cache.stream_resume_attempts("thread-001"), 1);
+        // Message stays streaming while the resume is in flight
+        assert!(app.cache.is_thread_streaming("thread-001"));
+    }
+}
@@ -0,0 +1,237 @@
+//! Task actions for the App.
+//!
+//! Tasks can be started (or resumed) and paused. The new status shows right
+//! away and is rolled back if the request fails. Updates pushed by the
+//! backend replace the task in place. The selection follows the selected
+//! task, not its position, when a refresh reorders the list.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::state::{Task, TaskStatus};
+
+use super::{App, AppMessage};
+
+/// How long task action notices stay in the status line
+const NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+/// A request that changes a task's status
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TaskAction {
+    Start,
+    Pause,
+}
+
+impl TaskAction {
+    /// Status the task has once the action succeeds
+    fn target_status(self) -> TaskStatus {
+        match self {
+            TaskAction::Start => TaskStatus::InProgress,
+            TaskAction::Pause => TaskStatus::Paused,
+        }
+    }
+
+    /// Whether a task in `status` can take the action
+    fn applies_to(self, status: &TaskStatus) -> bool {
+        match self {
+            TaskAction::Start => matches!(status, TaskStatus::Pending | TaskStatus::Paused),
+            TaskAction::Pause => *status == TaskStatus::InProgress,
+        }
+    }
+
+    fn verb(self) -> &'static str {
+        match self {
+            TaskAction::Start => "start",
+            TaskAction::Pause => "pause",
+        }
+    }
+}
+
+impl App {
+    /// Replace the task list, keeping the same task selected
+    pub fn set_tasks(&mut self, tasks: Vec<Task>) {
+        let selected_id = self.selected_task().map(|task| task.id.clone());
+        self.tasks = tasks;
+        self.reselect_task(selected_id.as_deref());
+        self.mark_dirty();
+    }
+
+    /// The selected task
+    pub fn selected_task(&self) -> Option<&Task> {
+        self.tasks.get(self.tasks_index)
+    }
+
+    /// Start or resume the selected task
+    pub fn start_selected_task(&mut self) {
+        self.run_task_action(TaskAction::Start);
+    }
+
+    /// Pause the selected task
+    pub fn pause_selected_task(&mut self) {
+        self.run_task_action(TaskAction::Pause);
+    }
+
+    fn run_task_action(&mut self, action: TaskAction) {
+        let Some(task) = self.tasks.get_mut(self.tasks_index) else {
+            return;
+        };
+        if !action.applies_to(&task.status) {
+            let notice = format!("Can't {} a task that's {:?}", action.verb(), task.status);
+            self.set_status_notice(notice, NOTICE_DURATION);
+            return;
+        }
+
+        // Show the new status right away; a failure rolls it back
+        let previous = std::mem::replace(&mut task.status, action.target_status());
+        let task_id = task.id.clone();
+        self.mark_dirty();
+
+        let client = Arc::clone(&self.client);
+        let tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            let result = match action {
+                TaskAction::Start => client.start_task(&task_id).await,
+                TaskAction::Pause => client.pause_task(&task_id).await,
+            };
+            let msg = match result {
+                Ok(task) => AppMessage::TaskUpdated { task },
+                Err(e) => AppMessage::TaskActionFailed {
+                    task_id,
+                    previous,
+                    error: e.to_string(),
+                },
+            };
+            let _ = tx.send(msg);
+        });
+    }
+
+    /// Replace a task with its new state (added if it's new)
+    pub(super) fn apply_task_update(&mut self, task: Task) {
+        let selected_id = self.selected_task().map(|task| task.id.clone());
+        match self.tasks.iter_mut().find(|t| t.id == task.id) {
+            Some(existing) => *existing = task,
+            None => self.tasks.push(task),
+        }
+        self.reselect_task(selected_id.as_deref());
+        self.mark_dirty();
+    }
+
+    /// Undo the optimistic status of a failed start/pause
+    pub(super) fn rollback_task_action(
+        &mut self,
+        task_id: &str,
+        previous: TaskStatus,
+        error: String,
+    ) {
+        if let Some(task) = self.tasks.iter_mut().find(|t| t.id == task_id) {
+            task.status = previous;
+        }
+        self.set_status_notice(format!("Task update failed: {}", error), NOTICE_DURATION);
+        self.mark_dirty();
+    }
+
+    /// Select the task with `id` if it's still listed, else clamp the index
+    fn reselect_task(&mut self, id: Option<&str>) {
+        let position = id.and_then(|id| self.tasks.iter().position(|t| t.id == id));
+        self.tasks_index = match position {
+            Some(index) => index,
+            None => self.tasks_index.min(self.tasks.len().saturating_sub(1)),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, status: TaskStatus) -> Task {
+        Task {
+            id: id.to_string(),
+            name: format!("Task {}", id),
+            status,
+            progress: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_selection_follows_task_across_reorder() {
+        let mut app = App::default();
+        app.set_tasks(vec![
+            task("a", TaskStatus::Pending),
+            task("b", TaskStatus::Pending),
+            task("c", TaskStatus::Pending),
+        ]);
+        app.tasks_index = 1;
+
+        app.set_tasks(vec![
+            task("c", TaskStatus::Pending),
+            task("a", TaskStatus::Pending),
+            task("b", TaskStatus::Pending),
+        ]);
+        assert_eq!(app.selected_task().unwrap().id, "b");
+
+        // The selected task is gone: keep the position, within bounds
+        app.set_tasks(vec![task("c", TaskStatus::Pending)]);
+        assert_eq!(app.tasks_index, 0);
+    }
+
+    #[test]
+    fn test_task_updated_replaces_entry() {
+        let mut app = App::default();
+        app.set_tasks(vec![
+            task("a", TaskStatus::Pending),
+            task("b", TaskStatus::InProgress),
+        ]);
+
+        app.handle_message(AppMessage::TaskUpdated {
+            task: task("b", TaskStatus::Completed),
+        });
+        assert_eq!(app.tasks.len(), 2);
+        assert_eq!(app.tasks[1].status, TaskStatus::Completed);
+
+        app.handle_message(AppMessage::TaskUpdated {
+            task: task("c", TaskStatus::Pending),
+        });
+        assert_eq!(app.tasks.len(), 3);
+    }
+
+    #[test]
+    fn test_failed_action_rolls_back_status() {
+        let mut app = App::default();
+        app.set_tasks(vec![task("a", TaskStatus::InProgress)]);
+
+        app.handle_message(AppMessage::TaskActionFailed {
+            task_id: "a".to_string(),
+            previous: TaskStatus::Paused,
+            error: "Server error (500): boom".to_string(),
+        });
+        assert_eq!(app.tasks[0].status, TaskStatus::Paused);
+        assert!(app
+            .status_notice
+            .as_deref()
+            .unwrap()
+            .starts_with("Task update failed"));
+    }
+
+    #[test]
+    fn test_action_rejected_for_wrong_status() {
+        let mut app = App::default();
+        app.set_tasks(vec![task("a", TaskStatus::Completed)]);
+
+        app.pause_selected_task();
+        assert_eq!(app.tasks[0].status, TaskStatus::Completed);
+        assert_eq!(
+            app.status_notice.as_deref(),
+            Some("Can't pause a task that's Completed")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_start_is_optimistic() {
+        let mut app = App::default();
+        app.set_tasks(vec![task("a", TaskStatus::Paused)]);
+
+        app.start_selected_task();
+        assert_eq!(app.tasks[0].status, TaskStatus::InProgress);
+    }
+}
@@ -0,0 +1,131 @@
+//! Custom theme application for the App.
+//!
+//! A custom theme is checked for minimum contrast when it is loaded. Failing
+//! colors are either adjusted (`auto_adjust_contrast`) or reported with an
+//! in-app warning pointing at `spoq doctor --theme`.
+
+use std::time::Duration;
+
+use crate::startup::{CustomTheme, ThemeLoadError};
+use crate::ui::contrast::{check_theme_contrast, enforce_theme_contrast};
+
+use super::App;
+
+/// How long theme warnings stay visible
+const THEME_WARNING_DURATION: Duration = Duration::from_secs(8);
+
+impl App {
+    /// Load `~/.spoq/theme.json`, if present, and apply it.
+    pub fn load_custom_theme(&mut self) {
+        self.apply_custom_theme_result(CustomTheme::load());
+    }
+
+    /// Apply a loaded custom theme, warning about low-contrast colors.
+    pub fn apply_custom_theme(&mut self, custom: CustomTheme) {
+        let CustomTheme {
+            mut theme,
+            background,
+            min_contrast,
+            auto_adjust_contrast,
+        } = custom;
+
+        let issues = if auto_adjust_contrast {
+            enforce_theme_contrast(&mut theme, background, min_contrast)
+        } else {
+            check_theme_contrast(&theme, background, min_contrast)
+        };
+        self.theme = theme;
+
+        if issues.is_empty() {
+            self.mark_dirty();
+            return;
+        }
+
+        let roles = issues
+            .iter()
+            .map(|issue| issue.role)
+            .collect::<Vec<_>>()
+            .join(", ");
+        if auto_adjust_contrast {
+            self.set_status_notice(
+                format!(
+                    "Theme: adjusted {} for {:.1}:1 contrast",
+                    roles, min_contrast
+                ),
+                THEME_WARNING_DURATION,
+            );
+        } else {
+            self.set_timed_error(
+                format!(
+                    "Theme: {} below {:.1}:1 contrast — run `spoq doctor --theme`",
+                    roles, min_contrast
+                ),
+                THEME_WARNING_DURATION,
+            );
+        }
+    }
+
+    fn apply_custom_theme_result(&mut self, result: Result<Option<CustomTheme>, ThemeLoadError>) {
+        match result {
+            Ok(Some(custom)) => self.apply_custom_theme(custom),
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!("Custom theme not loaded: {}", e);
+                self.set_timed_error(format!("Theme: {}", e), THEME_WARNING_DURATION);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_low_contrast_theme_warns() {
+        let mut app = App::default();
+        let custom = CustomTheme::parse(r##"{"dim": "#1e1e28"}"##).unwrap();
+
+        app.apply_custom_theme(custom);
+
+        assert_eq!(app.theme.dim, Color::Rgb(0x1e, 0x1e, 0x28));
+        let warning = app.stream_error.as_deref().unwrap();
+        assert!(warning.contains("dim"), "warning: {}", warning);
+        assert!(warning.contains("spoq doctor --theme"));
+    }
+
+    #[test]
+    fn test_auto_adjust_fixes_colors() {
+        let mut app = App::default();
+        let custom =
+            CustomTheme::parse(r##"{"dim": "#1e1e28", "auto_adjust_contrast": true}"##).unwrap();
+
+        app.apply_custom_theme(custom);
+
+        assert_ne!(app.theme.dim, Color::Rgb(0x1e, 0x1e, 0x28));
+        assert!(app.stream_error.is_none());
+        assert!(app.status_notice.as_deref().unwrap().contains("adjusted dim"));
+    }
+
+    #[test]
+    fn test_passing_theme_is_silent() {
+        let mut app = App::default();
+        let custom = CustomTheme::parse(r#"{"accent": "light-cyan"}"#).unwrap();
+
+        app.apply_custom_theme(custom);
+
+        assert_eq!(app.theme.accent, Color::LightCyan);
+        assert!(app.stream_error.is_none());
+        assert!(app.status_notice.is_none());
+    }
+
+    #[test]
+    fn test_invalid_theme_file_warns() {
+        let mut app = App::default();
+
+        app.apply_custom_theme_result(CustomTheme::parse("{").map(Some));
+
+        assert!(app.stream_error.as_deref().unwrap().starts_with("Theme:"));
+    }
+}
@@ -0,0 +1,374 @@
+//! Thread archiving for the App.
+//!
+//! `a` on the Threads panel asks to archive the selected thread, ahead of
+//! the "allow always"/"answer" of a waiting needs-action prompt; `/archive`
+//! archives the open one. Archived threads leave the CommandDeck and the
+//! thread switcher (`ThreadCache::threads()` skips them) but stay cached with
+//! their messages, and BrowseList lists them under its archive filter
+//! (Ctrl+A cycles All / Active / Archived). An archived thread shows a banner
+//! when opened, and `U` in the empty input unarchives it. Changes apply
+//! locally right away and are saved on the backend in the background; a
+//! failed save restores the previous state.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::models::picker::ThreadEntry;
+
+use super::{
+    App, AppMessage, BrowseListMode, BrowseListState, Focus, Screen, ThreadArchiveConfirm,
+    ThreadArchiveFilter,
+};
+
+/// How long the "Archived ..." confirmation stays visible
+const ARCHIVE_NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+impl BrowseListState {
+    /// Rebuild the listed threads from all loaded ones under the archive
+    /// filter, which only applies in Threads mode
+    pub fn apply_archive_filter(&mut self) {
+        let filter = match self.mode {
+            BrowseListMode::Threads => self.archive_filter,
+            _ => ThreadArchiveFilter::All,
+        };
+        self.threads = self
+            .all_threads
+            .iter()
+            .filter(|t| filter.matches(t.archived))
+            .cloned()
+            .collect();
+    }
+}
+
+impl App {
+    /// Whether the thread open in the Conversation screen is archived
+    pub fn active_thread_archived(&self) -> bool {
+        self.screen == Screen::Conversation
+            && self
+                .active_thread_id
+                .as_deref()
+                .and_then(|id| self.cache.get_thread(id))
+                .is_some_and(|t| t.archived)
+    }
+
+    /// Whether `c` is the Threads panel's archive key (`a` with the panel
+    /// focused on the CommandDeck and the input empty). It goes to the panel
+    /// even while a needs-action prompt waits.
+    pub fn is_threads_panel_archive_key(&self, c: char) -> bool {
+        c == 'a'
+            && self.screen == Screen::CommandDeck
+            && self.focus == Focus::Threads
+            && self.textarea.is_empty()
+    }
+
+    /// Ask to archive the thread selected on the Threads panel.
+    ///
+    /// Returns false if no thread is selected.
+    pub fn request_selected_thread_archive(&mut self) -> bool {
+        let selected = self
+            .cache
+            .threads()
+            .get(self.threads_index)
+            .map(|t| (t.id.clone(), t.title.clone()));
+
+        match selected {
+            Some((thread_id, title)) => {
+                self.thread_archive_confirm = Some(ThreadArchiveConfirm { thread_id, title });
+                self.mark_dirty();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Dismiss the archive confirmation dialog without archiving
+    pub fn cancel_thread_archive(&mut self) {
+        self.thread_archive_confirm = None;
+        self.mark_dirty();
+    }
+
+    /// Archive the thread awaiting confirmation
+    pub fn confirm_thread_archive(&mut self) {
+        let Some(confirm) = self.thread_archive_confirm.take() else {
+            return;
+        };
+        if self.set_thread_archived(&confirm.thread_id, true) {
+            self.set_status_notice(
+                format!("Archived {}", confirm.title),
+                ARCHIVE_NOTICE_DURATION,
+            );
+        }
+    }
+
+    /// Archive the thread open in the Conversation screen (`/archive`).
+    ///
+    /// The thread stays open, showing the unarchive banner.
+    pub fn archive_active_thread(&mut self) {
+        let Some(thread_id) = self
+            .active_thread_id
+            .clone()
+            .filter(|_| self.screen == Screen::Conversation)
+        else {
+            self.set_status_notice(
+                "Open a thread to archive it".to_string(),
+                ARCHIVE_NOTICE_DURATION,
+            );
+            return;
+        };
+        if self.active_thread_archived() {
+            self.set_status_notice(
+                "Thread is already archived".to_string(),
+                ARCHIVE_NOTICE_DURATION,
+            );
+            return;
+        }
+        if self.set_thread_archived(&thread_id, true) {
+            self.set_status_notice("Thread archived".to_string(), ARCHIVE_NOTICE_DURATION);
+        }
+    }
+
+    /// Unarchive the open thread (`U` on its banner).
+    ///
+    /// Returns false if no archived thread is open.
+    pub fn unarchive_active_thread(&mut self) -> bool {
+        if !self.active_thread_archived() {
+            return false;
+        }
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return false;
+        };
+        self.set_thread_archived(&thread_id, false);
+        self.set_status_notice("Thread unarchived".to_string(), ARCHIVE_NOTICE_DURATION);
+        true
+    }
+
+    /// Cycle the BrowseList archive filter (Threads mode only)
+    pub fn cycle_browse_list_archive_filter(&mut self) {
+        if self.browse_list.mode != BrowseListMode::Threads {
+            return;
+        }
+        self.browse_list.archive_filter = self.browse_list.archive_filter.next();
+        self.browse_list.apply_archive_filter();
+        self.browse_list.total_count = self.browse_list.threads.len();
+        self.browse_list.selected_index = 0;
+        self.browse_list.scroll_offset = 0;
+        self.mark_dirty();
+    }
+
+    /// Archive or unarchive a thread locally, then save it on the backend.
+    ///
+    /// Returns false if the thread is neither cached nor listed in BrowseList.
+    fn set_thread_archived(&mut self, thread_id: &str, archived: bool) -> bool {
+        let previous = self
+            .cache
+            .get_thread(thread_id)
+            .map(|t| t.archived)
+            .or_else(|| {
+                self.browse_list
+                    .all_threads
+                    .iter()
+                    .find(|t| t.id == thread_id)
+                    .map(|t| t.archived)
+            });
+        let Some(previous) = previous else {
+            return false;
+        };
+        self.apply_thread_archive(thread_id, archived);
+        self.spawn_backend_archive_update(thread_id.to_string(), archived, previous);
+        true
+    }
+
+    /// Set a thread's archive state in every local cache and view
+    pub(crate) fn apply_thread_archive(&mut self, thread_id: &str, archived: bool) {
+        self.cache.set_thread_archived(thread_id, archived);
+        self.dashboard.update_thread_archived(thread_id, archived);
+
+        let browse_list = &mut self.browse_list;
+        for entry in browse_list
+            .all_threads
+            .iter_mut()
+            .filter(|t| t.id == thread_id)
+        {
+            entry.archived = archived;
+        }
+        browse_list.apply_archive_filter();
+        browse_list.total_count = browse_list.threads.len();
+        browse_list.selected_index = browse_list
+            .selected_index
+            .min(browse_list.item_count().saturating_sub(1));
+
+        self.threads_index = self
+            .threads_index
+            .min(self.cache.threads().len().saturating_sub(1));
+        self.mark_dirty();
+    }
+
+    /// Take the archive state of cached threads over the one BrowseList loaded,
+    /// so threads archived here show up right before the backend reports it
+    pub(super) fn merge_local_archive_state(
+        &self,
+        mut threads: Vec<ThreadEntry>,
+    ) -> Vec<ThreadEntry> {
+        for entry in &mut threads {
+            if let Some(thread) = self.cache.get_thread(&entry.id) {
+                entry.archived = thread.archived;
+            }
+        }
+        threads
+    }
+
+    /// Save a thread's archive state on the backend, reporting failures as a
+    /// message
+    fn spawn_backend_archive_update(&self, thread_id: String, archived: bool, previous: bool) {
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            if let Err(e) = client.archive_thread(&thread_id, archived).await {
+                let _ = tx.send(AppMessage::ThreadArchiveFailed {
+                    thread_id,
+                    previous,
+                    error: e.to_string(),
+                });
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn app_with_stub_threads() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app
+    }
+
+    fn entry(id: &str, archived: bool) -> ThreadEntry {
+        ThreadEntry {
+            id: id.to_string(),
+            title: Some(id.to_string()),
+            working_directory: None,
+            thread_type: None,
+            status: None,
+            mode: None,
+            description: None,
+            last_activity: None,
+            message_count: None,
+            archived,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_confirmed_archive_hides_thread_and_keeps_messages() {
+        let mut app = app_with_stub_threads();
+        let count = app.cache.threads().len();
+        let thread_id = app.cache.threads()[0].id.clone();
+
+        assert!(app.request_selected_thread_archive());
+        // Nothing changes until confirmed
+        assert_eq!(app.cache.threads().len(), count);
+
+        app.confirm_thread_archive();
+        assert!(app.thread_archive_confirm.is_none());
+        assert_eq!(app.cache.threads().len(), count - 1);
+        assert!(app.cache.threads().iter().all(|t| t.id != thread_id));
+        assert!(app.cache.get_messages(&thread_id).is_some());
+    }
+
+    #[test]
+    fn test_a_on_threads_panel_archives_instead_of_allowing_always() {
+        use crate::state::session::PermissionRequest;
+
+        let mut app = app_with_stub_threads();
+        app.dashboard.set_pending_permission(
+            "thread-001",
+            PermissionRequest {
+                permission_id: "perm-1".to_string(),
+                thread_id: Some("thread-001".to_string()),
+                tool_name: "Bash".to_string(),
+                description: "Run tests".to_string(),
+                context: None,
+                tool_input: None,
+                received_at: std::time::Instant::now(),
+            },
+        );
+        app.focus = Focus::Threads;
+
+        // `a` skips the permission prompt and archives
+        assert!(app.is_threads_panel_archive_key('a'));
+        assert!(app.request_selected_thread_archive());
+        assert!(app.thread_archive_confirm.is_some());
+        assert!(app.dashboard.get_pending_permission("thread-001").is_some());
+        assert!(!app.session_state.is_tool_allowed("Bash"));
+
+        // Other keys still answer it, and so does `a` from the input
+        assert!(!app.is_threads_panel_archive_key('y'));
+        assert!(!app.is_threads_panel_archive_key('A'));
+        app.focus = Focus::Input;
+        assert!(!app.is_threads_panel_archive_key('a'));
+    }
+
+    #[tokio::test]
+    async fn test_unarchive_from_open_thread() {
+        let mut app = app_with_stub_threads();
+        let thread_id = app.cache.threads()[0].id.clone();
+        app.active_thread_id = Some(thread_id.clone());
+        app.screen = Screen::Conversation;
+
+        assert!(!app.unarchive_active_thread());
+        app.archive_active_thread();
+        assert!(app.active_thread_archived());
+
+        assert!(app.unarchive_active_thread());
+        assert!(!app.active_thread_archived());
+        assert_eq!(app.cache.threads()[0].id, thread_id);
+    }
+
+    #[test]
+    fn test_failed_save_restores_previous_state() {
+        let mut app = app_with_stub_threads();
+        let thread_id = app.cache.threads()[0].id.clone();
+        app.apply_thread_archive(&thread_id, true);
+
+        app.handle_message(AppMessage::ThreadArchiveFailed {
+            thread_id: thread_id.clone(),
+            previous: false,
+            error: "boom".to_string(),
+        });
+
+        assert!(app
+            .cache
+            .get_thread(&thread_id)
+            .is_some_and(|t| !t.archived));
+    }
+
+    #[test]
+    fn test_browse_list_filter_cycles_all_active_archived() {
+        let mut app = App::default();
+        app.browse_list.mode = BrowseListMode::Threads;
+        app.browse_list.all_threads = vec![entry("open", false), entry("done", true)];
+        app.browse_list.apply_archive_filter();
+        assert_eq!(app.browse_list.threads.len(), 2);
+
+        app.cycle_browse_list_archive_filter();
+        assert_eq!(app.browse_list.archive_filter, ThreadArchiveFilter::Active);
+        assert_eq!(app.browse_list.threads[0].id, "open");
+        assert_eq!(app.browse_list.threads.len(), 1);
+
+        app.cycle_browse_list_archive_filter();
+        assert_eq!(
+            app.browse_list.archive_filter,
+            ThreadArchiveFilter::Archived
+        );
+        assert_eq!(app.browse_list.threads[0].id, "done");
+
+        // Unarchiving moves it out of the Archived view
+        app.apply_thread_archive("done", false);
+        assert!(app.browse_list.threads.is_empty());
+
+        app.cycle_browse_list_archive_filter();
+        assert_eq!(app.browse_list.archive_filter, ThreadArchiveFilter::All);
+    }
+}
@@ -0,0 +1,451 @@
+//! Thread deletion for the App.
+//!
+//! `d`/`x` on the Threads panel or in BrowseList asks for confirmation; once
+//! confirmed the thread is removed locally right away and deleted on the
+//! backend in the background. `AppMessage::ThreadDeleted` (from our own
+//! delete or from another client over WebSocket) removes it again, which is
+//! a no-op if it is already gone. If the backend delete fails the thread is
+//! put back where it was.
+
+use std::sync::Arc;
+
+use crate::models::picker::ThreadEntry;
+use crate::models::{Message, Thread};
+
+use super::{App, AppMessage, BrowseListMode, ThreadDeleteConfirm};
+
+/// A BrowseList entry and its index in the list
+pub type BrowseEntry = (usize, ThreadEntry);
+
+/// A thread removed locally while its backend delete is in flight
+#[derive(Debug, Clone)]
+pub struct DeletedThread {
+    /// The cached thread
+    pub thread: Thread,
+    /// Its cached messages, if they were loaded
+    pub messages: Option<Vec<Message>>,
+    /// Position in the cache's thread order
+    pub position: usize,
+    /// Whether the dashboard listed it
+    pub on_dashboard: bool,
+    /// BrowseList entries with their indexes (all threads, filtered threads)
+    pub browse_entries: (Option<BrowseEntry>, Option<BrowseEntry>),
+}
+
+impl App {
+    /// Ask to delete the thread selected on the Threads panel.
+    ///
+    /// Returns false if no thread is selected.
+    pub fn request_selected_thread_delete(&mut self) -> bool {
+        let selected = self
+            .cache
+            .threads()
+            .get(self.threads_index)
+            .map(|t| (t.id.clone(), t.title.clone()));
+
+        match selected {
+            Some((thread_id, title)) => {
+                self.request_thread_delete(thread_id, title);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Ask to delete the thread selected in BrowseList.
+    ///
+    /// Returns false outside Threads mode or if nothing is selected.
+    pub fn request_browse_list_thread_delete(&mut self) -> bool {
+        if self.browse_list.mode != BrowseListMode::Threads {
+            return false;
+        }
+        let selected = self
+            .browse_list
+            .threads
+            .get(self.browse_list.selected_index)
+            .map(|t| {
+                let title = t.title.clone().unwrap_or_else(|| "Untitled".to_string());
+                (t.id.clone(), title)
+            });
+
+        match selected {
+            Some((thread_id, title)) => {
+                self.request_thread_delete(thread_id, title);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Show the deletion confirmation dialog for a thread
+    pub fn request_thread_delete(&mut self, thread_id: String, title: String) {
+        self.thread_delete_confirm = Some(ThreadDeleteConfirm { thread_id, title });
+        self.mark_dirty();
+    }
+
+    /// Dismiss the deletion confirmation dialog without deleting
+    pub fn cancel_thread_delete(&mut self) {
+        self.thread_delete_confirm = None;
+        self.mark_dirty();
+    }
+
+    /// Delete the thread awaiting confirmation.
+    ///
+    /// Removes it locally and deletes it on the backend in the background.
+    /// Refuses while the thread is streaming.
+    pub fn confirm_thread_delete(&mut self) {
+        let Some(confirm) = self.thread_delete_confirm.take() else {
+            return;
+        };
+        let thread_id = confirm.thread_id;
+
+        if self.cache.is_thread_streaming(&thread_id) {
+            self.set_timed_error(
+                "Cannot delete while streaming — wait for response to finish".into(),
+                std::time::Duration::from_secs(4),
+            );
+            return;
+        }
+
+        if let Some(deleted) = self.snapshot_thread(&thread_id) {
+            self.deleted_threads.insert(thread_id.clone(), deleted);
+        }
+        self.remove_thread_locally(&thread_id);
+        self.spawn_backend_thread_delete(thread_id);
+    }
+
+    /// What `remove_thread_locally` would drop, to put back if the delete fails
+    fn snapshot_thread(&self, thread_id: &str) -> Option<DeletedThread> {
+        let thread = self.cache.get_thread(thread_id)?.clone();
+        let browse_entry = |entries: &[ThreadEntry]| {
+            entries
+                .iter()
+                .position(|t| t.id == thread_id)
+                .map(|i| (i, entries[i].clone()))
+        };
+        Some(DeletedThread {
+            thread,
+            messages: self.cache.messages.get(thread_id).cloned(),
+            position: self
+                .cache
+                .thread_order
+                .iter()
+                .position(|id| id == thread_id)
+                .unwrap_or(0),
+            on_dashboard: self.dashboard.get_thread(thread_id).is_some(),
+            browse_entries: (
+                browse_entry(&self.browse_list.all_threads),
+                browse_entry(&self.browse_list.threads),
+            ),
+        })
+    }
+
+    /// The backend delete failed: put the thread back where it was.
+    ///
+    /// Returns false if nothing was removed for it.
+    pub(crate) fn restore_deleted_thread(&mut self, thread_id: &str) -> bool {
+        let Some(deleted) = self.deleted_threads.remove(thread_id) else {
+            return false;
+        };
+        if deleted.on_dashboard {
+            self.dashboard.add_thread(deleted.thread.clone());
+        }
+        self.cache
+            .restore_thread(deleted.thread, deleted.messages, deleted.position);
+
+        let (all_threads, threads) = deleted.browse_entries;
+        if let Some((index, entry)) = all_threads {
+            let index = index.min(self.browse_list.all_threads.len());
+            self.browse_list.all_threads.insert(index, entry);
+        }
+        if let Some((index, entry)) = threads {
+            let index = index.min(self.browse_list.threads.len());
+            self.browse_list.threads.insert(index, entry);
+            self.browse_list.total_count += 1;
+        }
+        self.mark_dirty();
+        true
+    }
+
+    /// Remove a thread from every local cache and view.
+    ///
+    /// If it is the thread being viewed, navigates back to CommandDeck.
+    /// Safe to call for threads that are not cached.
+    pub(crate) fn remove_thread_locally(&mut self, thread_id: &str) {
+        self.cache.remove_thread(thread_id);
+        self.reveal.discard(thread_id);
+        self.token_coalescer.discard(thread_id);
+        self.stream_failures.remove(thread_id);
+        self.dashboard.remove_thread(thread_id);
+        self.rendered_lines_cache.remove_thread(thread_id);
+        if self
+            .height_cache
+            .as_ref()
+            .is_some_and(|h| h.thread_id.as_str() == thread_id)
+        {
+            self.height_cache = None;
+        }
+
+        self.browse_list.all_threads.retain(|t| t.id != thread_id);
+        let before = self.browse_list.threads.len();
+        self.browse_list.threads.retain(|t| t.id != thread_id);
+        if self.browse_list.threads.len() < before {
+            self.browse_list.total_count = self.browse_list.total_count.saturating_sub(1);
+            self.browse_list.selected_index = self
+                .browse_list
+                .selected_index
+                .min(self.browse_list.threads.len().saturating_sub(1));
+        }
+
+        if self
+            .thread_delete_confirm
+            .as_ref()
+            .is_some_and(|c| c.thread_id == thread_id)
+        {
+            self.thread_delete_confirm = None;
+        }
+        if self
+            .thread_archive_confirm
+            .as_ref()
+            .is_some_and(|c| c.thread_id == thread_id)
+        {
+            self.thread_archive_confirm = None;
+        }
+
+        self.threads_index = self
+            .threads_index
+            .min(self.cache.thread_count().saturating_sub(1));
+
+        if self.active_thread_id.as_deref() == Some(thread_id) {
+            self.navigate_to_command_deck();
+        }
+        self.mark_dirty();
+    }
+
+    /// Delete a thread on the backend, reporting the result as a message
+    pub(crate) fn spawn_backend_thread_delete(&self, thread_id: String) {
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            match client.delete_thread(&thread_id).await {
+                Ok(_) => {
+                    let _ = tx.send(AppMessage::ThreadDeleted { thread_id });
+                }
+                Err(e) => {
+                    let _ = tx.send(AppMessage::ThreadDeleteFailed {
+                        thread_id,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Screen;
+    use crate::cache::ThreadCache;
+
+    fn app_with_stub_threads() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app
+    }
+
+    fn thread_entry(id: &str, title: &str) -> ThreadEntry {
+        ThreadEntry {
+            id: id.to_string(),
+            title: Some(title.to_string()),
+            working_directory: None,
+            thread_type: None,
+            status: None,
+            mode: None,
+            description: None,
+            last_activity: None,
+            message_count: None,
+            archived: false,
+        }
+    }
+
+    #[test]
+    fn test_request_selected_thread_delete_opens_confirmation() {
+        let mut app = app_with_stub_threads();
+        app.threads_index = 1;
+        let expected_id = app.cache.threads()[1].id.clone();
+
+        assert!(app.request_selected_thread_delete());
+
+        let confirm = app.thread_delete_confirm.as_ref().unwrap();
+        assert_eq!(confirm.thread_id, expected_id);
+        // Nothing is removed until confirmed
+        assert!(app.cache.get_thread(&expected_id).is_some());
+    }
+
+    #[test]
+    fn test_request_selected_thread_delete_without_threads() {
+        let mut app = App::default();
+        assert!(!app.request_selected_thread_delete());
+        assert!(app.thread_delete_confirm.is_none());
+    }
+
+    #[test]
+    fn test_cancel_thread_delete_keeps_thread() {
+        let mut app = app_with_stub_threads();
+        app.request_selected_thread_delete();
+
+        app.cancel_thread_delete();
+
+        assert!(app.thread_delete_confirm.is_none());
+        assert_eq!(app.cache.thread_count(), 3);
+    }
+
+    #[test]
+    fn test_request_browse_list_thread_delete() {
+        let mut app = App::default();
+        app.browse_list.mode = BrowseListMode::Threads;
+        app.browse_list.threads = vec![thread_entry("t-1", "First"), thread_entry("t-2", "Second")];
+        app.browse_list.selected_index = 1;
+
+        assert!(app.request_browse_list_thread_delete());
+        assert_eq!(
+            app.thread_delete_confirm,
+            Some(ThreadDeleteConfirm {
+                thread_id: "t-2".to_string(),
+                title: "Second".to_string(),
+            })
+        );
+
+        app.browse_list.mode = BrowseListMode::Repos;
+        app.thread_delete_confirm = None;
+        assert!(!app.request_browse_list_thread_delete());
+    }
+
+    #[test]
+    fn test_remove_active_thread_returns_to_command_deck() {
+        let mut app = app_with_stub_threads();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.rendered_lines_cache
+            .insert("thread-001", 1, 0, vec![ratatui::text::Line::from("x")]);
+        app.rendered_lines_cache
+            .insert("thread-002", 1, 0, vec![ratatui::text::Line::from("y")]);
+        app.height_cache = Some(crate::app::CachedHeights {
+            thread_id: Arc::new("thread-001".to_string()),
+            heights: Vec::new(),
+            total_lines: 0,
+            viewport_width: 80,
+        });
+
+        app.remove_thread_locally("thread-001");
+
+        assert_eq!(app.screen, Screen::CommandDeck);
+        assert!(app.active_thread_id.is_none());
+        assert!(app.height_cache.is_none());
+        assert!(!app.rendered_lines_cache.contains("thread-001", 1, 0));
+        assert!(app.rendered_lines_cache.contains("thread-002", 1, 0));
+        assert!(app.cache.get_thread("thread-001").is_none());
+        assert!(app.cache.get_messages("thread-001").is_none());
+    }
+
+    #[test]
+    fn test_remove_inactive_thread_keeps_conversation_open() {
+        let mut app = app_with_stub_threads();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.threads_index = 2;
+
+        app.remove_thread_locally("thread-003");
+
+        assert_eq!(app.screen, Screen::Conversation);
+        assert_eq!(app.active_thread_id.as_deref(), Some("thread-001"));
+        assert_eq!(app.threads_index, 1);
+    }
+
+    #[test]
+    fn test_remove_thread_updates_browse_list() {
+        let mut app = App::default();
+        app.browse_list.threads = vec![thread_entry("t-1", "First"), thread_entry("t-2", "Second")];
+        app.browse_list.total_count = 2;
+        app.browse_list.selected_index = 1;
+
+        app.remove_thread_locally("t-2");
+
+        assert_eq!(app.browse_list.threads.len(), 1);
+        assert_eq!(app.browse_list.total_count, 1);
+        assert_eq!(app.browse_list.selected_index, 0);
+    }
+
+    #[test]
+    fn test_confirm_thread_delete_refuses_while_streaming() {
+        let mut app = app_with_stub_threads();
+        app.cache
+            .add_streaming_message("thread-001", "hi".to_string(), Vec::new());
+        app.request_thread_delete("thread-001".to_string(), "Busy".to_string());
+
+        app.confirm_thread_delete();
+
+        assert!(app.thread_delete_confirm.is_none());
+        assert!(app.cache.get_thread("thread-001").is_some());
+        assert!(app.stream_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_confirm_thread_delete_removes_thread() {
+        let mut app = app_with_stub_threads();
+        app.request_thread_delete("thread-002".to_string(), "Old".to_string());
+
+        app.confirm_thread_delete();
+
+        assert!(app.thread_delete_confirm.is_none());
+        assert!(app.cache.get_thread("thread-002").is_none());
+        assert_eq!(app.cache.thread_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_delete_puts_thread_back() {
+        let mut app = app_with_stub_threads();
+        app.browse_list.threads = vec![
+            thread_entry("thread-001", "First"),
+            thread_entry("thread-002", "Second"),
+        ];
+        app.browse_list.total_count = 2;
+        let order: Vec<String> = app.cache.threads().iter().map(|t| t.id.clone()).collect();
+        let messages = app.cache.get_messages("thread-002").map(|m| m.len());
+        app.request_thread_delete("thread-002".to_string(), "Old".to_string());
+        app.confirm_thread_delete();
+        assert!(app.cache.get_thread("thread-002").is_none());
+
+        app.handle_message(AppMessage::ThreadDeleteFailed {
+            thread_id: "thread-002".to_string(),
+            error: "500".to_string(),
+        });
+
+        let restored: Vec<String> = app.cache.threads().iter().map(|t| t.id.clone()).collect();
+        assert_eq!(restored, order);
+        assert_eq!(
+            app.cache.get_messages("thread-002").map(|m| m.len()),
+            messages
+        );
+        assert_eq!(app.browse_list.threads[1].id, "thread-002");
+        assert_eq!(app.browse_list.total_count, 2);
+        assert!(app.deleted_threads.is_empty());
+        assert!(app.stream_error.as_deref().unwrap().contains("500"));
+    }
+
+    #[tokio::test]
+    async fn test_confirmed_delete_forgets_the_thread() {
+        let mut app = app_with_stub_threads();
+        app.request_thread_delete("thread-002".to_string(), "Old".to_string());
+        app.confirm_thread_delete();
+        assert!(app.deleted_threads.contains_key("thread-002"));
+
+        app.handle_message(AppMessage::ThreadDeleted {
+            thread_id: "thread-002".to_string(),
+        });
+
+        assert!(app.deleted_threads.is_empty());
+        assert!(!app.restore_deleted_thread("thread-002"));
+    }
+}
@@ -0,0 +1,315 @@
+//! Thread links ("follows", "blocked by") for the App.
+//!
+//! `/link` opens an editor listing the other threads; Enter links the
+//! selected one with the chosen relation (or unlinks it if already linked).
+//! Links are applied locally right away and saved on the backend in the
+//! background; a failed save restores the previous links. Links show up as
+//! numbered chips in the conversation header (1-9 jumps to them) and as
+//! grouping hints on CommandDeck rows.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::models::{ThreadLink, ThreadLinkKind};
+
+use super::{App, AppMessage, LinkEditorState, Screen};
+
+/// How long the "Linked ..." confirmation stays visible
+const LINK_NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+/// A link shown as a chip in the conversation header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadLinkChip {
+    /// Thread the chip navigates to
+    pub thread_id: String,
+    /// Relation as seen from the viewed thread ("blocked by", "blocks", ...)
+    pub label: &'static str,
+    /// Relation icon
+    pub icon: &'static str,
+    /// Title of the linked thread (short ID if it isn't cached)
+    pub title: String,
+}
+
+impl App {
+    /// Open the link editor for the active thread
+    pub fn open_link_editor(&mut self) {
+        let Some(thread_id) = self
+            .active_thread_id
+            .clone()
+            .filter(|_| self.screen == Screen::Conversation)
+        else {
+            self.set_timed_error(
+                "Open a thread to link it".to_string(),
+                Duration::from_secs(3),
+            );
+            return;
+        };
+        if self.link_candidates(&thread_id).is_empty() {
+            self.set_timed_error(
+                "No other threads to link to".to_string(),
+                Duration::from_secs(3),
+            );
+            return;
+        }
+
+        self.link_editor = Some(LinkEditorState {
+            thread_id,
+            kind: ThreadLinkKind::FollowUpOf,
+            selected_index: 0,
+        });
+        self.mark_dirty();
+    }
+
+    /// Close the link editor
+    pub fn close_link_editor(&mut self) {
+        self.link_editor = None;
+        self.mark_dirty();
+    }
+
+    /// Threads that `thread_id` can link to (every other cached thread, MRU order)
+    pub fn link_candidates(&self, thread_id: &str) -> Vec<&crate::models::Thread> {
+        self.cache
+            .threads()
+            .into_iter()
+            .filter(|t| t.id != thread_id)
+            .collect()
+    }
+
+    /// Move the link editor selection by `delta` (clamped)
+    pub fn link_editor_move(&mut self, delta: isize) {
+        let Some(thread_id) = self.link_editor.as_ref().map(|e| e.thread_id.clone()) else {
+            return;
+        };
+        let last = self.link_candidates(&thread_id).len().saturating_sub(1);
+        if let Some(editor) = self.link_editor.as_mut() {
+            editor.selected_index = editor.selected_index.saturating_add_signed(delta).min(last);
+        }
+        self.mark_dirty();
+    }
+
+    /// Switch the relation used for new links
+    pub fn link_editor_cycle_kind(&mut self) {
+        if let Some(editor) = self.link_editor.as_mut() {
+            editor.kind = editor.kind.next();
+            self.mark_dirty();
+        }
+    }
+
+    /// Link or unlink the thread selected in the link editor.
+    ///
+    /// Linking an already linked thread with the same relation removes the
+    /// link; with a different relation it changes the relation.
+    pub fn toggle_selected_link(&mut self) {
+        let Some(editor) = self.link_editor.clone() else {
+            return;
+        };
+        let Some((target_id, target_title)) = self
+            .link_candidates(&editor.thread_id)
+            .get(editor.selected_index)
+            .map(|t| (t.id.clone(), t.title.clone()))
+        else {
+            return;
+        };
+        let Some(previous) = self
+            .cache
+            .get_thread(&editor.thread_id)
+            .map(|t| t.links.clone())
+        else {
+            return;
+        };
+
+        let mut links = previous.clone();
+        let existing = links.iter().position(|l| l.thread_id == target_id);
+        let notice = match existing {
+            Some(index) if links[index].kind == editor.kind => {
+                links.remove(index);
+                format!("Unlinked {}", target_title)
+            }
+            Some(index) => {
+                links[index].kind = editor.kind;
+                format!("Now {} {}", editor.kind.label(), target_title)
+            }
+            None => {
+                links.push(ThreadLink::new(editor.kind, target_id));
+                format!("Linked: {} {}", editor.kind.label(), target_title)
+            }
+        };
+
+        self.apply_thread_links(&editor.thread_id, links.clone());
+        self.spawn_backend_links_update(editor.thread_id, links, previous);
+        self.set_status_notice(notice, LINK_NOTICE_DURATION);
+    }
+
+    /// Replace a thread's links in every local cache and view
+    pub(crate) fn apply_thread_links(&mut self, thread_id: &str, links: Vec<ThreadLink>) {
+        self.cache.set_thread_links(thread_id, links.clone());
+        self.dashboard.update_thread_links(thread_id, links);
+        self.mark_dirty();
+    }
+
+    /// Save a thread's links on the backend, reporting failures as a message
+    fn spawn_backend_links_update(
+        &self,
+        thread_id: String,
+        links: Vec<ThreadLink>,
+        previous: Vec<ThreadLink>,
+    ) {
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            if let Err(e) = client.update_thread_links(&thread_id, &links).await {
+                let _ = tx.send(AppMessage::ThreadLinksUpdateFailed {
+                    thread_id,
+                    previous,
+                    error: e.to_string(),
+                });
+            }
+        });
+    }
+
+    /// Header chips for a thread: its own links, then threads linking to it
+    pub fn thread_link_chips(&self, thread_id: &str) -> Vec<ThreadLinkChip> {
+        let title = |id: &str| {
+            self.cache
+                .get_thread(id)
+                .map(|t| t.title.clone())
+                .unwrap_or_else(|| id.chars().take(8).collect())
+        };
+
+        let outgoing = self
+            .cache
+            .get_thread(thread_id)
+            .map(|t| t.links.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|link| ThreadLinkChip {
+                label: link.kind.label(),
+                icon: link.kind.icon(),
+                title: title(&link.thread_id),
+                thread_id: link.thread_id,
+            });
+        let inbound =
+            self.cache
+                .inbound_thread_links(thread_id)
+                .into_iter()
+                .map(|(other_id, kind)| ThreadLinkChip {
+                    label: kind.inverse_label(),
+                    icon: kind.icon(),
+                    title: title(&other_id),
+                    thread_id: other_id,
+                });
+
+        outgoing.chain(inbound).collect()
+    }
+
+    /// Open the thread behind the `index`-th header chip of the active thread.
+    ///
+    /// Returns false if there is no such chip.
+    pub fn follow_thread_link(&mut self, index: usize) -> bool {
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return false;
+        };
+        match self.thread_link_chips(&thread_id).into_iter().nth(index) {
+            Some(chip) => {
+                self.open_thread(chip.thread_id);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn app_in_thread(thread_id: &str) -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some(thread_id.to_string());
+        app
+    }
+
+    fn select(app: &mut App, target_id: &str) {
+        let thread_id = app.link_editor.as_ref().unwrap().thread_id.clone();
+        let index = app
+            .link_candidates(&thread_id)
+            .iter()
+            .position(|t| t.id == target_id)
+            .unwrap();
+        app.link_editor.as_mut().unwrap().selected_index = index;
+    }
+
+    #[test]
+    fn test_open_link_editor_requires_conversation() {
+        let mut app = App::default();
+        app.open_link_editor();
+        assert!(app.link_editor.is_none());
+        assert!(app.stream_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_toggle_selected_link_adds_changes_and_removes() {
+        let mut app = app_in_thread("thread-002");
+        app.open_link_editor();
+        select(&mut app, "thread-001");
+
+        app.toggle_selected_link();
+        assert_eq!(
+            app.cache.get_thread("thread-002").unwrap().links,
+            vec![ThreadLink::new(ThreadLinkKind::FollowUpOf, "thread-001")]
+        );
+
+        app.link_editor_cycle_kind();
+        app.toggle_selected_link();
+        assert_eq!(
+            app.cache.get_thread("thread-002").unwrap().links,
+            vec![ThreadLink::new(ThreadLinkKind::BlockedBy, "thread-001")]
+        );
+
+        app.toggle_selected_link();
+        assert!(app.cache.get_thread("thread-002").unwrap().links.is_empty());
+    }
+
+    #[test]
+    fn test_failed_save_restores_previous_links() {
+        let mut app = app_in_thread("thread-002");
+        app.apply_thread_links(
+            "thread-002",
+            vec![ThreadLink::new(ThreadLinkKind::BlockedBy, "thread-001")],
+        );
+
+        app.handle_message(AppMessage::ThreadLinksUpdateFailed {
+            thread_id: "thread-002".to_string(),
+            previous: Vec::new(),
+            error: "boom".to_string(),
+        });
+
+        assert!(app.cache.get_thread("thread-002").unwrap().links.is_empty());
+        assert!(app.stream_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_thread_link_chips_and_follow() {
+        let mut app = app_in_thread("thread-002");
+        app.apply_thread_links(
+            "thread-002",
+            vec![ThreadLink::new(ThreadLinkKind::BlockedBy, "thread-001")],
+        );
+
+        let chips = app.thread_link_chips("thread-002");
+        assert_eq!(chips.len(), 1);
+        assert_eq!(chips[0].label, "blocked by");
+        assert_eq!(chips[0].title, "Rust async patterns");
+
+        let inbound = app.thread_link_chips("thread-001");
+        assert_eq!(inbound[0].label, "blocks");
+        assert_eq!(inbound[0].thread_id, "thread-002");
+
+        assert!(!app.follow_thread_link(1));
+        assert!(app.follow_thread_link(0));
+        assert_eq!(app.active_thread_id.as_deref(), Some("thread-001"));
+    }
+}
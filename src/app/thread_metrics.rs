@@ -0,0 +1,388 @@
+//! Per-thread outcome metrics (`/metrics`).
+//!
+//! For every thread the app counts how its turns ended (finished, failed or
+//! cancelled), the permissions allowed and denied, stream and tool errors,
+//! and the edits the backend reports as reverted (`edit_reverted`). When the
+//! backend first verifies a thread, the turns taken so far are kept as its
+//! turns to completion. `/metrics` shows the active thread's numbers with its
+//! model and permission mode, next to the averages over all threads and over
+//! threads on the same model, so prompts and settings can be compared.
+//!
+//! Metrics are kept in `~/.spoq/thread-metrics.json`, written after each turn
+//! and on quit.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::App;
+
+/// File name of the metrics inside `~/.spoq`
+const METRICS_FILE: &str = "thread-metrics.json";
+
+/// How a turn ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnOutcome {
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+/// Outcome counts of one thread
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThreadMetrics {
+    pub completed_turns: u32,
+    pub failed_turns: u32,
+    pub cancelled_turns: u32,
+    /// Turns taken when the backend first verified the thread
+    pub turns_to_completion: Option<u32>,
+    pub permissions_allowed: u32,
+    pub permissions_denied: u32,
+    /// Stream errors
+    pub errors: u32,
+    /// Tool calls that failed
+    pub tool_errors: u32,
+    pub reverted_edits: u32,
+}
+
+impl ThreadMetrics {
+    /// Turns that ended, however they ended
+    pub fn turns(&self) -> u32 {
+        self.completed_turns + self.failed_turns + self.cancelled_turns
+    }
+
+    /// Count a finished turn
+    pub fn record_turn(&mut self, outcome: TurnOutcome) {
+        match outcome {
+            TurnOutcome::Completed => self.completed_turns += 1,
+            TurnOutcome::Failed => self.failed_turns += 1,
+            TurnOutcome::Cancelled => self.cancelled_turns += 1,
+        }
+    }
+
+    /// Count an answered permission
+    pub fn record_permission(&mut self, allowed: bool) {
+        if allowed {
+            self.permissions_allowed += 1;
+        } else {
+            self.permissions_denied += 1;
+        }
+    }
+
+    /// Keep the turns taken so far, the first time the thread is verified
+    pub fn record_verified(&mut self) {
+        if self.turns_to_completion.is_none() {
+            self.turns_to_completion = Some(self.turns());
+        }
+    }
+
+    /// Labelled rows for the metrics pane
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        let mut outcomes = vec![format!("{} completed", self.completed_turns)];
+        if self.failed_turns > 0 {
+            outcomes.push(format!("{} failed", self.failed_turns));
+        }
+        if self.cancelled_turns > 0 {
+            outcomes.push(format!("{} cancelled", self.cancelled_turns));
+        }
+        vec![
+            (
+                "Turns",
+                format!("{} ({})", self.turns(), outcomes.join(", ")),
+            ),
+            (
+                "To completion",
+                self.turns_to_completion
+                    .map(|turns| format!("{} turns", turns))
+                    .unwrap_or_else(|| "not verified yet".to_string()),
+            ),
+            (
+                "Permissions",
+                format!(
+                    "{} allowed, {} denied",
+                    self.permissions_allowed, self.permissions_denied
+                ),
+            ),
+            (
+                "Errors",
+                format!("{} stream, {} tool", self.errors, self.tool_errors),
+            ),
+            ("Reverted edits", self.reverted_edits.to_string()),
+        ]
+    }
+
+    /// Path of the metrics file (`~/.spoq/thread-metrics.json`)
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".spoq").join(METRICS_FILE))
+    }
+
+    /// Load every thread's saved metrics; a missing or invalid file yields none
+    pub fn load_all(path: &Path) -> HashMap<String, ThreadMetrics> {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return HashMap::new();
+        };
+        serde_json::from_str(&contents)
+            .map_err(|e| tracing::warn!("Ignoring invalid {}: {}", path.display(), e))
+            .unwrap_or_default()
+    }
+
+    /// Write every thread's metrics
+    pub fn save_all(path: &Path, metrics: &HashMap<String, ThreadMetrics>) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string(metrics).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+}
+
+/// Averages over several threads' metrics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSummary {
+    pub threads: usize,
+    /// Mean turns to completion of the verified threads, and how many
+    pub turns_to_completion: Option<(f64, usize)>,
+    /// Share of answered permissions that were denied
+    pub denial_rate: Option<f64>,
+    /// Reverted edits per finished turn
+    pub reverts_per_turn: Option<f64>,
+}
+
+impl MetricsSummary {
+    pub fn of<'a>(metrics: impl IntoIterator<Item = &'a ThreadMetrics>) -> Self {
+        let mut summary = Self::default();
+        let (mut verified, mut verified_turns) = (0usize, 0u32);
+        let (mut answered, mut denied, mut turns, mut reverts) = (0u32, 0u32, 0u32, 0u32);
+        for m in metrics {
+            summary.threads += 1;
+            if let Some(turns) = m.turns_to_completion {
+                verified += 1;
+                verified_turns += turns;
+            }
+            answered += m.permissions_allowed + m.permissions_denied;
+            denied += m.permissions_denied;
+            turns += m.turns();
+            reverts += m.reverted_edits;
+        }
+        summary.turns_to_completion =
+            (verified > 0).then(|| (f64::from(verified_turns) / verified as f64, verified));
+        summary.denial_rate = (answered > 0).then(|| f64::from(denied) / f64::from(answered));
+        summary.reverts_per_turn = (turns > 0).then(|| f64::from(reverts) / f64::from(turns));
+        summary
+    }
+
+    /// One line, e.g. "4 threads: 3.5 turns to completion (2 verified), 10% denied"
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        match self.turns_to_completion {
+            Some((mean, threads)) => parts.push(format!(
+                "{:.1} turns to completion ({} verified)",
+                mean, threads
+            )),
+            None => parts.push("none verified".to_string()),
+        }
+        if let Some(rate) = self.denial_rate {
+            parts.push(format!("{:.0}% denied", rate * 100.0));
+        }
+        if let Some(reverts) = self.reverts_per_turn {
+            parts.push(format!("{:.1} reverts/turn", reverts));
+        }
+        let plural = if self.threads == 1 { "" } else { "s" };
+        format!("{} thread{}: {}", self.threads, plural, parts.join(", "))
+    }
+}
+
+impl App {
+    /// Update a thread's metrics
+    pub(super) fn update_thread_metrics(
+        &mut self,
+        thread_id: &str,
+        update: impl FnOnce(&mut ThreadMetrics),
+    ) {
+        update(
+            self.thread_metrics
+                .entry(thread_id.to_string())
+                .or_default(),
+        );
+    }
+
+    /// Count a finished turn and save the metrics
+    pub(super) fn record_turn_outcome(&mut self, thread_id: &str, outcome: TurnOutcome) {
+        self.update_thread_metrics(thread_id, |m| m.record_turn(outcome));
+        self.save_thread_metrics();
+    }
+
+    /// Count the answer to a pending permission, looked up by its ID
+    pub(super) fn record_permission_answer(&mut self, permission_id: &str, allowed: bool) {
+        let thread_id = self
+            .dashboard
+            .pending_permissions_iter()
+            .find(|(_, perm)| perm.permission_id == permission_id)
+            .map(|(thread_id, _)| thread_id.clone());
+        if let Some(thread_id) = thread_id {
+            self.update_thread_metrics(&thread_id, |m| m.record_permission(allowed));
+        }
+    }
+
+    /// Save the metrics to `thread_metrics_path` (nothing to do without one)
+    pub fn save_thread_metrics(&self) {
+        let Some(path) = self.thread_metrics_path.as_deref() else {
+            return;
+        };
+        if crate::startup::integrity::is_read_only() {
+            return;
+        }
+        if let Err(e) = ThreadMetrics::save_all(path, &self.thread_metrics) {
+            tracing::warn!("Failed to save thread metrics: {}", e);
+        }
+    }
+
+    /// Rows of the metrics pane for a thread: its metrics, settings and how
+    /// they compare with the other threads
+    pub fn thread_metrics_rows(&self, thread_id: &str) -> Vec<(&'static str, String)> {
+        let metrics = self
+            .thread_metrics
+            .get(thread_id)
+            .cloned()
+            .unwrap_or_default();
+        let mut rows = metrics.rows();
+
+        let thread = self.cache.get_thread(thread_id);
+        let model = thread.and_then(|t| t.model.clone());
+        rows.push((
+            "Model",
+            model.clone().unwrap_or_else(|| "default".to_string()),
+        ));
+        rows.push((
+            "Mode",
+            thread
+                .and_then(|t| t.permission_mode.clone())
+                .unwrap_or_else(|| "default".to_string()),
+        ));
+
+        rows.push((
+            "All threads",
+            MetricsSummary::of(self.thread_metrics.values()).describe(),
+        ));
+        if let Some(model) = model {
+            let same_model = self.thread_metrics.iter().filter(|(id, _)| {
+                self.cache
+                    .get_thread(id)
+                    .is_some_and(|t| t.model.as_deref() == Some(model.as_str()))
+            });
+            rows.push((
+                "Same model",
+                MetricsSummary::of(same_model.map(|(_, m)| m)).describe(),
+            ));
+        }
+        rows
+    }
+
+    /// Open the metrics pane for the active thread (`/metrics`)
+    pub fn open_thread_metrics(&mut self) {
+        match self.active_thread_id.clone() {
+            Some(thread_id) => {
+                self.thread_metrics_view = Some(thread_id);
+                self.mark_dirty();
+            }
+            None => self.set_status_notice(
+                "Open a thread to see its metrics".to_string(),
+                std::time::Duration::from_secs(3),
+            ),
+        }
+    }
+
+    /// Close the metrics pane
+    pub fn close_thread_metrics(&mut self) {
+        self.thread_metrics_view = None;
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns_to_completion_is_kept_from_first_verification() {
+        let mut metrics = ThreadMetrics::default();
+        metrics.record_turn(TurnOutcome::Completed);
+        metrics.record_turn(TurnOutcome::Failed);
+        metrics.record_verified();
+        metrics.record_turn(TurnOutcome::Completed);
+        metrics.record_verified();
+
+        assert_eq!(metrics.turns(), 3);
+        assert_eq!(metrics.turns_to_completion, Some(2));
+        assert_eq!(metrics.rows()[0].1, "3 (2 completed, 1 failed)");
+    }
+
+    #[test]
+    fn test_summary_averages_threads() {
+        let a = ThreadMetrics {
+            completed_turns: 4,
+            turns_to_completion: Some(3),
+            permissions_allowed: 3,
+            permissions_denied: 1,
+            reverted_edits: 2,
+            ..Default::default()
+        };
+        let b = ThreadMetrics {
+            completed_turns: 6,
+            turns_to_completion: Some(6),
+            ..Default::default()
+        };
+        let c = ThreadMetrics::default();
+
+        let summary = MetricsSummary::of([&a, &b, &c]);
+        assert_eq!(summary.threads, 3);
+        assert_eq!(summary.turns_to_completion, Some((4.5, 2)));
+        assert_eq!(summary.denial_rate, Some(0.25));
+        assert_eq!(summary.reverts_per_turn, Some(0.2));
+        assert_eq!(
+            summary.describe(),
+            "3 threads: 4.5 turns to completion (2 verified), 25% denied, 0.2 reverts/turn"
+        );
+        assert_eq!(
+            MetricsSummary::of([&c]).describe(),
+            "1 thread: none verified"
+        );
+    }
+
+    #[test]
+    fn test_metrics_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(METRICS_FILE);
+        let mut metrics = HashMap::new();
+        metrics.insert(
+            "thread-1".to_string(),
+            ThreadMetrics {
+                completed_turns: 2,
+                tool_errors: 1,
+                ..Default::default()
+            },
+        );
+
+        ThreadMetrics::save_all(&path, &metrics).unwrap();
+        assert_eq!(ThreadMetrics::load_all(&path), metrics);
+        assert!(ThreadMetrics::load_all(&dir.path().join("missing.json")).is_empty());
+    }
+
+    #[test]
+    fn test_metrics_rows_include_settings_and_comparison() {
+        let mut app = App::default();
+        app.update_thread_metrics("thread-1", |m| m.record_permission(false));
+        let rows = app.thread_metrics_rows("thread-1");
+        let row = |label: &str| {
+            rows.iter()
+                .find(|(l, _)| *l == label)
+                .map(|(_, value)| value.clone())
+                .unwrap()
+        };
+        assert_eq!(row("Permissions"), "0 allowed, 1 denied");
+        assert_eq!(row("Model"), "default");
+        assert!(row("All threads").contains("100% denied"));
+    }
+}
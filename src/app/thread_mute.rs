@@ -0,0 +1,266 @@
+//! Thread muting for the App.
+//!
+//! `m` on the Threads panel mutes the selected thread, or unmutes it if it is
+//! already muted; `/mute` does the same for the open thread. Muted threads
+//! keep streaming and collecting prompts, but send no desktop notifications
+//! or terminal attention and never become the needs-action target: they are
+//! listed dimmed with the other threads instead of in the needs-action
+//! section. Opening one shows a banner, and its prompts can be answered
+//! there. Mutes are local only, kept in `muted_threads` in
+//! `~/.spoq/thread_state.json`.
+
+use std::time::Duration;
+
+use crate::models::dashboard::WaitingFor;
+use crate::models::Thread;
+
+use super::{App, Screen};
+
+/// How long the "Muted ..." confirmation stays visible
+const MUTE_NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+/// Mute the threads the local store lists
+pub(crate) fn merge_local_mutes(threads: &mut [Thread], muted_ids: &[String]) {
+    for thread in threads.iter_mut() {
+        if muted_ids.contains(&thread.id) {
+            thread.muted = true;
+        }
+    }
+}
+
+impl App {
+    /// Whether a thread is muted
+    pub fn is_thread_muted(&self, thread_id: &str) -> bool {
+        self.cache.get_thread(thread_id).is_some_and(|t| t.muted)
+    }
+
+    /// Whether the open thread is muted (shown as a banner)
+    pub fn active_thread_muted(&self) -> bool {
+        self.screen == Screen::Conversation
+            && self
+                .active_thread_id
+                .as_deref()
+                .is_some_and(|id| self.is_thread_muted(id))
+    }
+
+    /// The open thread's prompt, if it is muted and waiting.
+    ///
+    /// Muted threads are never the top needs-action thread, so their prompts
+    /// are answered from the conversation.
+    pub(crate) fn muted_active_thread_waiting(&self) -> Option<(String, WaitingFor)> {
+        let thread_id = self
+            .active_thread_id
+            .as_deref()
+            .filter(|_| self.active_thread_muted())?;
+        let waiting_for = self.dashboard.get_waiting_for(thread_id)?;
+        Some((thread_id.to_string(), waiting_for.clone()))
+    }
+
+    /// Mute the thread selected on the Threads panel, or unmute it if muted
+    pub fn toggle_selected_thread_mute(&mut self) {
+        let Some(thread_id) = self
+            .cache
+            .threads()
+            .get(self.threads_index)
+            .map(|t| t.id.clone())
+        else {
+            return;
+        };
+        self.toggle_thread_mute(&thread_id);
+    }
+
+    /// `/mute`: mute the open thread, or unmute it if muted
+    pub fn toggle_active_thread_mute(&mut self) {
+        let Some(thread_id) = self
+            .active_thread_id
+            .clone()
+            .filter(|_| self.screen == Screen::Conversation)
+        else {
+            self.set_timed_error(
+                "Open a thread to mute it".to_string(),
+                Duration::from_secs(3),
+            );
+            return;
+        };
+        self.toggle_thread_mute(&thread_id);
+    }
+
+    /// Mute or unmute a thread and save the mutes locally
+    fn toggle_thread_mute(&mut self, thread_id: &str) {
+        let Some(thread) = self.cache.get_thread(thread_id) else {
+            return;
+        };
+        let muted = !thread.muted;
+        let notice = if muted {
+            format!("Muted {}", thread.title)
+        } else {
+            format!("Unmuted {}", thread.title)
+        };
+
+        self.apply_thread_mute(thread_id, muted);
+        self.save_local_mutes();
+        self.set_status_notice(notice, MUTE_NOTICE_DURATION);
+    }
+
+    /// Set a thread's mute in every local cache and view
+    pub(crate) fn apply_thread_mute(&mut self, thread_id: &str, muted: bool) {
+        self.cache.set_thread_muted(thread_id, muted);
+        self.dashboard.update_thread_muted(thread_id, muted);
+        self.mark_dirty();
+    }
+
+    /// Mirror the muted threads to the local thread state and save it.
+    ///
+    /// Mutes of threads that aren't cached right now are kept.
+    fn save_local_mutes(&mut self) {
+        let mut muted: Vec<String> = self
+            .cache
+            .threads()
+            .into_iter()
+            .filter(|t| t.muted)
+            .map(|t| t.id.clone())
+            .collect();
+        let uncached: Vec<String> = self
+            .thread_state
+            .muted_threads
+            .drain(..)
+            .filter(|id| self.cache.get_thread(id).is_none())
+            .collect();
+        muted.extend(uncached);
+        self.thread_state.muted_threads = muted;
+        self.save_thread_state();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+    use crate::models::dashboard::ThreadStatus;
+    use crate::notifications::NotificationKind;
+    use chrono::NaiveTime;
+
+    /// CommandDeck with thread-001 and thread-002 waiting on permissions
+    fn app_with_waiting_threads() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        for thread in app.cache.threads().into_iter().cloned().collect::<Vec<_>>() {
+            app.dashboard.add_thread(thread);
+        }
+        for id in ["thread-001", "thread-002"] {
+            app.dashboard.update_thread_status(
+                id,
+                ThreadStatus::Waiting,
+                Some(WaitingFor::Permission {
+                    request_id: format!("perm-{}", id),
+                    tool_name: "Bash".to_string(),
+                }),
+            );
+        }
+        let _ = app.dashboard.compute_thread_views();
+        app
+    }
+
+    fn needs_action_ids(app: &mut App) -> Vec<String> {
+        let _ = app.dashboard.compute_thread_views();
+        app.dashboard
+            .needs_action_threads()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_local_mutes() {
+        let cache = ThreadCache::with_stub_data();
+        let mut threads: Vec<Thread> = cache.threads().into_iter().cloned().collect();
+        merge_local_mutes(&mut threads, &["thread-002".to_string()]);
+
+        let muted: Vec<&str> = threads
+            .iter()
+            .filter(|t| t.muted)
+            .map(|t| t.id.as_str())
+            .collect();
+        assert_eq!(muted, vec!["thread-002"]);
+    }
+
+    #[test]
+    fn test_muted_thread_never_needs_action() {
+        let mut app = app_with_waiting_threads();
+        assert_eq!(needs_action_ids(&mut app).len(), 2);
+
+        app.apply_thread_mute("thread-001", true);
+        assert_eq!(needs_action_ids(&mut app), vec!["thread-002"]);
+
+        app.apply_thread_mute("thread-002", true);
+        assert!(needs_action_ids(&mut app).is_empty());
+        assert!(app.needs_action_target().is_none());
+
+        // Still waiting: the prompt can be answered from its conversation
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        assert!(app.active_thread_muted());
+        assert_eq!(app.needs_action_target().unwrap().0, "thread-001");
+    }
+
+    #[test]
+    fn test_muted_thread_sends_no_desktop_notification() {
+        let mut app = app_with_waiting_threads();
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let body = |app: &App| {
+            app.desktop_notification_body(
+                NotificationKind::PermissionRequested,
+                Some("thread-001"),
+                "Permission needed",
+                noon,
+            )
+        };
+        assert!(body(&app).is_some());
+
+        app.apply_thread_mute("thread-001", true);
+        assert!(body(&app).is_none());
+
+        app.focus_supported = true;
+        app.is_focused = false;
+        app.request_attention("thread-001", "Permission needed");
+        assert!(app.pending_attention.is_none());
+    }
+
+    #[test]
+    fn test_mute_survives_thread_updates() {
+        let mut app = app_with_waiting_threads();
+        app.apply_thread_mute("thread-001", true);
+
+        let mut fresh = app.cache.get_thread("thread-001").unwrap().clone();
+        fresh.muted = false;
+        app.cache.upsert_thread(fresh.clone());
+        app.dashboard.add_thread(fresh);
+
+        assert!(app.is_thread_muted("thread-001"));
+        assert!(!needs_action_ids(&mut app).contains(&"thread-001".to_string()));
+    }
+
+    #[test]
+    fn test_toggle_mute_saves_thread_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("thread_state.json");
+        let mut app = app_with_waiting_threads();
+        app.thread_state_path = Some(path.clone());
+        // Muted earlier, not in the cache right now
+        app.thread_state.muted_threads = vec!["thread-gone".to_string()];
+        app.threads_index = app
+            .cache
+            .threads()
+            .iter()
+            .position(|t| t.id == "thread-002")
+            .unwrap();
+
+        app.toggle_selected_thread_mute();
+        let saved = crate::app::ThreadState::load(&path);
+        assert_eq!(saved.muted_threads, vec!["thread-002", "thread-gone"]);
+
+        app.toggle_selected_thread_mute();
+        let saved = crate::app::ThreadState::load(&path);
+        assert_eq!(saved.muted_threads, vec!["thread-gone"]);
+    }
+}
@@ -0,0 +1,298 @@
+//! Thread pinning for the App.
+//!
+//! `p` on the Threads panel pins the selected thread, or unpins it if it is
+//! already pinned. Pinned threads lead `ThreadCache::threads()`, so they come
+//! first on the CommandDeck and in the thread switcher; Shift+Up/Down moves
+//! the selected pinned thread within them. Pins are applied locally right
+//! away, mirrored to `pinned_threads` in `~/.spoq/thread_state.json` and
+//! saved on the backend in the background; a failed save restores the
+//! previous pin.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::models::Thread;
+
+use super::{App, AppMessage};
+
+/// How long the "Pinned ..." confirmation stays visible
+const PIN_NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+/// Pin state of one thread
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ThreadPin {
+    thread_id: String,
+    pinned: bool,
+    pin_order: u32,
+}
+
+/// Pin threads the local store lists but the backend didn't report as pinned.
+///
+/// Keeps pins across restarts when the backend doesn't store them.
+pub(crate) fn merge_local_pins(threads: &mut [Thread], pinned_ids: &[String]) {
+    for thread in threads.iter_mut().filter(|t| !t.pinned) {
+        if let Some(position) = pinned_ids.iter().position(|id| *id == thread.id) {
+            thread.pinned = true;
+            thread.pin_order = position as u32;
+        }
+    }
+}
+
+/// Pins that move `pinned[index]` by `delta` and renumber the pinned threads.
+///
+/// `pinned` holds (thread ID, pin order) in pin order. Only threads whose
+/// order changes are returned; nothing if the move leaves the pinned section.
+fn reorder_pins(pinned: &[(String, u32)], index: usize, delta: isize) -> Vec<ThreadPin> {
+    let Some(target) = index
+        .checked_add_signed(delta)
+        .filter(|target| *target < pinned.len() && index < pinned.len())
+    else {
+        return Vec::new();
+    };
+
+    let mut ordered: Vec<&(String, u32)> = pinned.iter().collect();
+    ordered.swap(index, target);
+    ordered
+        .into_iter()
+        .enumerate()
+        .filter(|(order, (_, current))| *current != *order as u32)
+        .map(|(order, (thread_id, _))| ThreadPin {
+            thread_id: thread_id.clone(),
+            pinned: true,
+            pin_order: order as u32,
+        })
+        .collect()
+}
+
+impl App {
+    /// IDs of the pinned threads, in pin order
+    pub fn pinned_thread_ids(&self) -> Vec<String> {
+        self.cache
+            .threads()
+            .into_iter()
+            .filter(|t| t.pinned)
+            .map(|t| t.id.clone())
+            .collect()
+    }
+
+    /// Pin the thread selected on the Threads panel, or unpin it if pinned.
+    ///
+    /// New pins go to the end of the pinned section.
+    pub fn toggle_selected_thread_pin(&mut self) {
+        let threads = self.cache.threads();
+        let Some(thread) = threads.get(self.threads_index) else {
+            return;
+        };
+        let thread_id = thread.id.clone();
+        let (pin, notice) = if thread.pinned {
+            let pin = ThreadPin {
+                thread_id: thread_id.clone(),
+                pinned: false,
+                pin_order: 0,
+            };
+            (pin, format!("Unpinned {}", thread.title))
+        } else {
+            let pin_order = threads
+                .iter()
+                .filter(|t| t.pinned)
+                .map(|t| t.pin_order + 1)
+                .max()
+                .unwrap_or(0);
+            let pin = ThreadPin {
+                thread_id: thread_id.clone(),
+                pinned: true,
+                pin_order,
+            };
+            (pin, format!("Pinned {}", thread.title))
+        };
+
+        self.update_thread_pins(vec![pin]);
+        self.select_thread_in_list(&thread_id);
+        self.set_status_notice(notice, PIN_NOTICE_DURATION);
+    }
+
+    /// Move the selected pinned thread up (`delta < 0`) or down among the pins
+    pub fn move_selected_pinned_thread(&mut self, delta: isize) {
+        let pinned: Vec<(String, u32)> = self
+            .cache
+            .threads()
+            .into_iter()
+            .filter(|t| t.pinned)
+            .map(|t| (t.id.clone(), t.pin_order))
+            .collect();
+        // Pinned threads lead the list, so the selection indexes them directly
+        let Some(thread_id) = pinned.get(self.threads_index).map(|(id, _)| id.clone()) else {
+            return;
+        };
+
+        let pins = reorder_pins(&pinned, self.threads_index, delta);
+        if pins.is_empty() {
+            return;
+        }
+        self.update_thread_pins(pins);
+        self.select_thread_in_list(&thread_id);
+    }
+
+    /// Point the Threads panel selection at a thread (if it is listed)
+    fn select_thread_in_list(&mut self, thread_id: &str) {
+        if let Some(index) = self.cache.threads().iter().position(|t| t.id == thread_id) {
+            self.threads_index = index;
+        }
+    }
+
+    /// Apply pins locally, then save them to the local store and the backend
+    fn update_thread_pins(&mut self, pins: Vec<ThreadPin>) {
+        for pin in pins {
+            let Some(previous) = self.cache.get_thread(&pin.thread_id).map(|t| ThreadPin {
+                thread_id: pin.thread_id.clone(),
+                pinned: t.pinned,
+                pin_order: t.pin_order,
+            }) else {
+                continue;
+            };
+            self.apply_thread_pin(&pin.thread_id, pin.pinned, pin.pin_order);
+            self.spawn_backend_pin_update(pin, previous);
+        }
+        self.save_local_pins();
+    }
+
+    /// Set a thread's pin in every local cache and view
+    pub(crate) fn apply_thread_pin(&mut self, thread_id: &str, pinned: bool, pin_order: u32) {
+        self.cache.set_thread_pin(thread_id, pinned, pin_order);
+        self.dashboard
+            .update_thread_pin(thread_id, pinned, pin_order);
+        self.mark_dirty();
+    }
+
+    /// Mirror the pinned threads to the local thread state and save it.
+    ///
+    /// Pins of threads that aren't cached right now are kept.
+    pub(crate) fn save_local_pins(&mut self) {
+        let mut pinned = self.pinned_thread_ids();
+        let uncached: Vec<String> = self
+            .thread_state
+            .pinned_threads
+            .drain(..)
+            .filter(|id| self.cache.get_thread(id).is_none())
+            .collect();
+        pinned.extend(uncached);
+        self.thread_state.pinned_threads = pinned;
+        self.save_thread_state();
+    }
+
+    /// Save a thread's pin on the backend, reporting failures as a message
+    fn spawn_backend_pin_update(&self, pin: ThreadPin, previous: ThreadPin) {
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            if let Err(e) = client
+                .update_thread_pin(&pin.thread_id, pin.pinned, pin.pin_order)
+                .await
+            {
+                let _ = tx.send(AppMessage::ThreadPinUpdateFailed {
+                    thread_id: previous.thread_id,
+                    previous_pinned: previous.pinned,
+                    previous_pin_order: previous.pin_order,
+                    error: e.to_string(),
+                });
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn pinned(ids: &[(&str, u32)]) -> Vec<(String, u32)> {
+        ids.iter()
+            .map(|(id, order)| (id.to_string(), *order))
+            .collect()
+    }
+
+    #[test]
+    fn test_reorder_pins_swaps_and_renumbers() {
+        let pins = pinned(&[("a", 0), ("b", 1), ("c", 2)]);
+
+        assert_eq!(
+            reorder_pins(&pins, 2, -1),
+            vec![
+                ThreadPin {
+                    thread_id: "c".to_string(),
+                    pinned: true,
+                    pin_order: 1,
+                },
+                ThreadPin {
+                    thread_id: "b".to_string(),
+                    pinned: true,
+                    pin_order: 2,
+                },
+            ]
+        );
+        assert!(reorder_pins(&pins, 0, -1).is_empty());
+        assert!(reorder_pins(&pins, 2, 1).is_empty());
+
+        // Orders from the backend may collide; moving still renumbers them
+        let colliding = pinned(&[("a", 0), ("b", 0)]);
+        assert_eq!(
+            reorder_pins(&colliding, 0, 1),
+            vec![ThreadPin {
+                thread_id: "a".to_string(),
+                pinned: true,
+                pin_order: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_merge_local_pins_keeps_backend_pins() {
+        let mut cache = ThreadCache::with_stub_data();
+        cache.set_thread_pin("thread-001", true, 5);
+        let mut threads: Vec<Thread> = cache.threads().into_iter().cloned().collect();
+
+        merge_local_pins(
+            &mut threads,
+            &["thread-003".to_string(), "thread-001".to_string()],
+        );
+
+        let by_id = |id: &str| threads.iter().find(|t| t.id == id).unwrap();
+        assert!(by_id("thread-003").pinned);
+        assert_eq!(by_id("thread-003").pin_order, 0);
+        assert_eq!(by_id("thread-001").pin_order, 5);
+        assert!(!by_id("thread-002").pinned);
+    }
+
+    #[test]
+    fn test_apply_thread_pin_orders_threads_list() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+
+        app.apply_thread_pin("thread-003", true, 1);
+        app.apply_thread_pin("thread-002", true, 0);
+
+        assert_eq!(app.pinned_thread_ids(), vec!["thread-002", "thread-003"]);
+        assert_eq!(app.cache.threads()[2].id, "thread-001");
+
+        app.apply_thread_pin("thread-002", false, 0);
+        assert_eq!(app.pinned_thread_ids(), vec!["thread-003"]);
+    }
+
+    #[test]
+    fn test_save_local_pins_writes_thread_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("thread_state.json");
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.thread_state_path = Some(path.clone());
+        // Pinned earlier, not in the cache right now
+        app.thread_state.pinned_threads = vec!["thread-gone".to_string()];
+
+        app.apply_thread_pin("thread-002", true, 0);
+        app.save_local_pins();
+
+        let saved = crate::app::ThreadState::load(&path);
+        assert_eq!(saved.pinned_threads, vec!["thread-002", "thread-gone"]);
+        assert_eq!(saved, app.thread_state);
+    }
+}
@@ -0,0 +1,98 @@
+//! Thread previews for the App.
+//!
+//! The CommandDeck rows, the thread switcher and BrowseList show one line of
+//! a thread's latest message. `refresh_thread_previews` runs in the prepare
+//! phase and keeps `thread_previews` in step with the cache; the previews are
+//! only rebuilt when a thread gets a new message.
+
+use crate::models::MessageRole;
+
+use super::{App, Screen};
+
+impl App {
+    /// Whether the current screen lists threads with previews
+    fn shows_thread_previews(&self) -> bool {
+        matches!(self.screen, Screen::CommandDeck | Screen::BrowseList)
+            || self.thread_switcher.visible
+    }
+
+    /// Bring the thread previews up to date with the cache.
+    ///
+    /// A thread's preview comes from its latest completed message if its
+    /// messages are loaded, otherwise from the thread's preview text; threads
+    /// only listed in BrowseList use their description.
+    pub fn refresh_thread_previews(&mut self) {
+        if !self.shows_thread_previews() {
+            return;
+        }
+
+        for thread in self.cache.threads() {
+            let latest = self.cache.get_messages(&thread.id).and_then(|messages| {
+                messages.iter().rev().find(|m| {
+                    m.id != 0
+                        && !m.is_streaming
+                        && m.role != MessageRole::System
+                        && !m.content.trim().is_empty()
+                })
+            });
+            match latest {
+                Some(message) => {
+                    self.thread_previews
+                        .update(&thread.id, message.id, &message.content)
+                }
+                None => self.thread_previews.update(&thread.id, 0, &thread.preview),
+            }
+        }
+
+        for entry in &self.browse_list.threads {
+            if self.cache.get_thread(&entry.id).is_some() {
+                continue;
+            }
+            if let Some(ref description) = entry.description {
+                self.thread_previews.update(&entry.id, 0, description);
+            }
+        }
+
+        let cache = &self.cache;
+        let browse_list = &self.browse_list;
+        self.thread_previews.retain_threads(|id| {
+            cache.get_thread(id).is_some() || browse_list.threads.iter().any(|t| t.id == id)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    #[test]
+    fn test_refresh_thread_previews_uses_latest_message() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        // Drop the stub messages (set_messages would keep them as local ones)
+        app.cache.messages.remove("thread-002");
+        app.cache
+            .set_thread_preview("thread-002", "**Bold** preview".to_string());
+
+        app.refresh_thread_previews();
+
+        // thread-001 has loaded messages; thread-002 falls back to its preview text
+        assert_eq!(
+            app.thread_previews.get("thread-001"),
+            Some("Here's how you can use tokio for async operations in Rust...")
+        );
+        assert_eq!(app.thread_previews.get("thread-002"), Some("Bold preview"));
+    }
+
+    #[test]
+    fn test_refresh_thread_previews_skips_other_screens() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+
+        app.refresh_thread_previews();
+
+        assert!(app.thread_previews.is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+//! Thread renaming for the App.
+//!
+//! `/rename New title` renames the active thread directly; `/rename` alone, or
+//! `r` on a thread card on the CommandDeck, opens a one-line editor prefilled
+//! with the current title. Renames are applied locally right away and saved on
+//! the backend in the background; a failed save restores the previous title.
+//! Renamed threads ignore later generated titles from `ThreadMetadataUpdated`.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{App, AppMessage, Screen, ThreadRenameState};
+
+/// Longest title the editor accepts, in characters
+pub const MAX_THREAD_TITLE_CHARS: usize = 120;
+
+/// How long the "Renamed ..." confirmation stays visible
+const RENAME_NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+impl App {
+    /// Rename the active thread (`/rename New title`)
+    pub fn rename_active_thread(&mut self, title: &str) {
+        let Some(thread_id) = self.active_rename_target() else {
+            return;
+        };
+        self.rename_thread(&thread_id, title);
+    }
+
+    /// Open the title editor for the active thread (`/rename`)
+    pub fn open_active_thread_rename(&mut self) {
+        if let Some(thread_id) = self.active_rename_target() {
+            self.open_thread_rename(&thread_id);
+        }
+    }
+
+    /// Open the title editor for the thread selected on the Threads panel
+    pub fn open_selected_thread_rename(&mut self) {
+        let Some(thread_id) = self
+            .cache
+            .threads()
+            .get(self.threads_index)
+            .map(|t| t.id.clone())
+        else {
+            return;
+        };
+        self.open_thread_rename(&thread_id);
+    }
+
+    /// Open the title editor for a thread, prefilled with its title
+    pub fn open_thread_rename(&mut self, thread_id: &str) {
+        let Some(thread) = self.cache.get_thread(thread_id) else {
+            return;
+        };
+        self.thread_rename = Some(ThreadRenameState {
+            thread_id: thread.id.clone(),
+            title: thread.title.clone(),
+        });
+        self.mark_dirty();
+    }
+
+    /// Type a character into the title editor
+    pub fn thread_rename_type_char(&mut self, c: char) {
+        if let Some(editor) = self.thread_rename.as_mut() {
+            if editor.title.chars().count() < MAX_THREAD_TITLE_CHARS {
+                editor.title.push(c);
+            }
+        }
+        self.mark_dirty();
+    }
+
+    /// Delete the last character in the title editor
+    pub fn thread_rename_backspace(&mut self) {
+        if let Some(editor) = self.thread_rename.as_mut() {
+            editor.title.pop();
+        }
+        self.mark_dirty();
+    }
+
+    /// Save the title being edited and close the editor
+    pub fn confirm_thread_rename(&mut self) {
+        let Some(editor) = self.thread_rename.take() else {
+            return;
+        };
+        self.rename_thread(&editor.thread_id, &editor.title);
+        self.mark_dirty();
+    }
+
+    /// Close the title editor without renaming
+    pub fn close_thread_rename(&mut self) {
+        self.thread_rename = None;
+        self.mark_dirty();
+    }
+
+    /// Rename a thread locally, then save the title on the backend.
+    ///
+    /// Surrounding whitespace is trimmed; empty or unchanged titles are ignored.
+    pub fn rename_thread(&mut self, thread_id: &str, title: &str) {
+        let title = title.trim();
+        if title.is_empty() {
+            self.set_timed_error(
+                "Thread title can't be empty".to_string(),
+                Duration::from_secs(3),
+            );
+            return;
+        }
+        if self
+            .cache
+            .get_thread(thread_id)
+            .is_some_and(|t| t.title == title)
+        {
+            return;
+        }
+        let Some(previous_title) = self.cache.rename_thread(thread_id, title.to_string()) else {
+            return;
+        };
+        self.dashboard
+            .update_thread_metadata(thread_id, Some(title.to_string()), None);
+        self.set_status_notice(format!("Renamed to {}", title), RENAME_NOTICE_DURATION);
+        self.spawn_backend_rename(thread_id.to_string(), title.to_string(), previous_title);
+    }
+
+    /// Restore the title of a thread whose rename the backend rejected
+    pub(crate) fn revert_thread_rename(
+        &mut self,
+        thread_id: &str,
+        title: &str,
+        previous_title: String,
+    ) {
+        if self
+            .cache
+            .revert_thread_rename(thread_id, title, previous_title.clone())
+        {
+            self.dashboard
+                .update_thread_metadata(thread_id, Some(previous_title), None);
+        }
+        self.mark_dirty();
+    }
+
+    /// The thread `/rename` applies to, reporting an error if there is none
+    fn active_rename_target(&mut self) -> Option<String> {
+        let thread_id = self
+            .active_thread_id
+            .clone()
+            .filter(|_| self.screen == Screen::Conversation);
+        if thread_id.is_none() {
+            self.set_timed_error(
+                "Open a thread to rename it".to_string(),
+                Duration::from_secs(3),
+            );
+        }
+        thread_id
+    }
+
+    /// Save a thread's title on the backend, reporting failures as a message
+    fn spawn_backend_rename(&self, thread_id: String, title: String, previous_title: String) {
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            if let Err(e) = client.rename_thread(&thread_id, &title).await {
+                let _ = tx.send(AppMessage::ThreadRenameFailed {
+                    thread_id,
+                    title,
+                    previous_title,
+                    error: e.to_string(),
+                });
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    #[test]
+    fn test_thread_rename_editor_edits_and_limits_title() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.open_thread_rename("thread-001");
+        let original = app.cache.get_thread("thread-001").unwrap().title.clone();
+        assert_eq!(app.thread_rename.as_ref().unwrap().title, original);
+
+        for _ in 0..original.chars().count() {
+            app.thread_rename_backspace();
+        }
+        for c in "x".repeat(MAX_THREAD_TITLE_CHARS + 5).chars() {
+            app.thread_rename_type_char(c);
+        }
+        assert_eq!(
+            app.thread_rename.as_ref().unwrap().title.chars().count(),
+            MAX_THREAD_TITLE_CHARS
+        );
+
+        app.close_thread_rename();
+        assert!(app.thread_rename.is_none());
+        assert_eq!(app.cache.get_thread("thread-001").unwrap().title, original);
+    }
+
+    #[tokio::test]
+    async fn test_rename_failure_restores_title() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        let original = app.cache.get_thread("thread-001").unwrap().title.clone();
+
+        app.rename_thread("thread-001", "  Fix the login flow ");
+        assert_eq!(
+            app.cache.get_thread("thread-001").unwrap().title,
+            "Fix the login flow"
+        );
+
+        app.handle_message(AppMessage::ThreadRenameFailed {
+            thread_id: "thread-001".to_string(),
+            title: "Fix the login flow".to_string(),
+            previous_title: original.clone(),
+            error: "Server error (500)".to_string(),
+        });
+        assert_eq!(app.cache.get_thread("thread-001").unwrap().title, original);
+        assert!(app
+            .stream_error
+            .as_deref()
+            .is_some_and(|e| e.contains("Failed to rename thread")));
+    }
+
+    #[test]
+    fn test_rename_rejects_empty_title() {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        let original = app.cache.get_thread("thread-001").unwrap().title.clone();
+
+        app.rename_thread("thread-001", "   ");
+
+        assert_eq!(app.cache.get_thread("thread-001").unwrap().title, original);
+        assert!(!app.cache.is_thread_renamed("thread-001"));
+    }
+}
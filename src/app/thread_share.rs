@@ -0,0 +1,309 @@
+//! Sharing threads as read-only links (`/share`).
+//!
+//! `/share` in a conversation, or `s` on a thread card, opens a dialog with
+//! the share options: who can open the link, and whether tool outputs and
+//! reasoning blocks are included. Enter shares the thread on the backend;
+//! the returned link is copied to the clipboard (OSC 52 when no system
+//! clipboard is reachable) and shown in the status line. Opening the dialog
+//! again for a thread shared this session shows its link, which can be
+//! copied again or revoked. Failures land in the thread's inline errors.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::models::ThreadShare;
+
+use super::{App, AppMessage, Screen, ShareDialogState};
+
+/// How long the copied link stays in the status line
+const SHARE_NOTICE_DURATION: Duration = Duration::from_secs(6);
+
+/// Error code of failed shares and revokes in the thread's inline errors
+const SHARE_ERROR_CODE: &str = "share_failed";
+
+/// Option rows of the dialog: visibility, tool outputs, reasoning
+const SHARE_OPTION_ROWS: usize = 3;
+
+impl App {
+    /// `/share`: open the share dialog for the active thread
+    pub fn open_active_thread_share(&mut self) {
+        let Some(thread_id) = self
+            .active_thread_id
+            .clone()
+            .filter(|_| self.screen == Screen::Conversation)
+        else {
+            self.set_timed_error(
+                "Open a thread to share it".to_string(),
+                Duration::from_secs(3),
+            );
+            return;
+        };
+        self.open_share_dialog(&thread_id);
+    }
+
+    /// `s` on the Threads panel: open the share dialog for the selected thread
+    pub fn open_selected_thread_share(&mut self) {
+        let Some(thread_id) = self
+            .cache
+            .threads()
+            .get(self.threads_index)
+            .map(|t| t.id.clone())
+        else {
+            return;
+        };
+        self.open_share_dialog(&thread_id);
+    }
+
+    /// Open the share dialog, showing the current link if already shared
+    pub fn open_share_dialog(&mut self, thread_id: &str) {
+        self.share_dialog = Some(ShareDialogState {
+            thread_id: thread_id.to_string(),
+            options: Default::default(),
+            selected_row: 0,
+            existing: self.thread_shares.get(thread_id).cloned(),
+            in_progress: false,
+        });
+        self.mark_dirty();
+    }
+
+    /// Close the share dialog
+    pub fn close_share_dialog(&mut self) {
+        self.share_dialog = None;
+        self.mark_dirty();
+    }
+
+    /// Handle a key while the share dialog is open.
+    ///
+    /// With no share yet: Up/Down pick an option, Space or Tab changes it and
+    /// Enter shares. For a shared thread: Enter or `y` copies the link again
+    /// and `r` revokes it. Esc closes the dialog either way; other keys wait
+    /// while a request is in flight.
+    pub fn handle_share_dialog_key(&mut self, key: KeyEvent) {
+        let Some(dialog) = self.share_dialog.as_mut() else {
+            return;
+        };
+        if key.code == KeyCode::Esc {
+            self.close_share_dialog();
+            return;
+        }
+        if dialog.in_progress {
+            return;
+        }
+
+        match (&dialog.existing, key.code) {
+            (Some(share), KeyCode::Enter | KeyCode::Char('y')) => {
+                let url = share.url.clone();
+                self.share_dialog = None;
+                self.copy_share_link(url);
+            }
+            (Some(_), KeyCode::Char('r')) => self.revoke_share(),
+            (Some(_), _) => {}
+            (None, KeyCode::Up | KeyCode::Char('k')) => {
+                dialog.selected_row = dialog.selected_row.saturating_sub(1);
+            }
+            (None, KeyCode::Down | KeyCode::Char('j')) => {
+                dialog.selected_row = (dialog.selected_row + 1).min(SHARE_OPTION_ROWS - 1);
+            }
+            (None, KeyCode::Char(' ') | KeyCode::Tab | KeyCode::Left | KeyCode::Right) => {
+                let options = &mut dialog.options;
+                match dialog.selected_row {
+                    0 => options.visibility = options.visibility.toggled(),
+                    1 => options.include_tool_outputs = !options.include_tool_outputs,
+                    _ => options.include_reasoning = !options.include_reasoning,
+                }
+            }
+            (None, KeyCode::Enter) => self.confirm_share(),
+            (None, _) => {}
+        }
+        self.mark_dirty();
+    }
+
+    /// Share the dialog's thread with the chosen options
+    fn confirm_share(&mut self) {
+        let Some(dialog) = self.share_dialog.as_mut() else {
+            return;
+        };
+        dialog.in_progress = true;
+        let thread_id = dialog.thread_id.clone();
+        let options = dialog.options.clone();
+
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let message = match client.share_thread(&thread_id, &options).await {
+                Ok(share) => AppMessage::ThreadShared { thread_id, share },
+                Err(e) => AppMessage::ThreadShareFailed {
+                    thread_id,
+                    error: e.to_string(),
+                },
+            };
+            let _ = tx.send(message);
+        });
+    }
+
+    /// Revoke the share link of the dialog's thread
+    fn revoke_share(&mut self) {
+        let Some(dialog) = self.share_dialog.as_mut() else {
+            return;
+        };
+        dialog.in_progress = true;
+        let thread_id = dialog.thread_id.clone();
+
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let message = match client.unshare_thread(&thread_id).await {
+                Ok(()) => AppMessage::ThreadUnshared { thread_id },
+                Err(e) => AppMessage::ThreadUnshareFailed {
+                    thread_id,
+                    error: e.to_string(),
+                },
+            };
+            let _ = tx.send(message);
+        });
+    }
+
+    /// Remember a new share and copy its link
+    pub(super) fn apply_thread_share(&mut self, thread_id: &str, share: ThreadShare) {
+        let url = share.url.clone();
+        self.thread_shares.insert(thread_id.to_string(), share);
+        self.close_dialog_for(thread_id);
+        self.copy_share_link(url);
+    }
+
+    /// Forget a revoked share
+    pub(super) fn apply_thread_unshare(&mut self, thread_id: &str) {
+        self.thread_shares.remove(thread_id);
+        self.close_dialog_for(thread_id);
+        self.set_status_notice("Link revoked".to_string(), SHARE_NOTICE_DURATION);
+    }
+
+    /// Report a failed share or revoke in the thread's inline errors
+    pub(super) fn fail_thread_share(&mut self, thread_id: &str, message: String) {
+        self.close_dialog_for(thread_id);
+        self.cache
+            .add_error_simple(thread_id, SHARE_ERROR_CODE.to_string(), message);
+        self.mark_dirty();
+    }
+
+    /// Copy a share link, still showing it if no clipboard is reachable
+    fn copy_share_link(&mut self, url: String) {
+        let notice = format!("Link copied: {}", url);
+        if !self.copy_text_with_notice(url.clone(), notice, SHARE_NOTICE_DURATION) {
+            self.set_status_notice(format!("Shared: {}", url), SHARE_NOTICE_DURATION);
+        }
+    }
+
+    /// Close the share dialog if it is open for `thread_id`
+    fn close_dialog_for(&mut self, thread_id: &str) {
+        if self
+            .share_dialog
+            .as_ref()
+            .is_some_and(|d| d.thread_id == thread_id)
+        {
+            self.close_share_dialog();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+    use crate::models::ShareVisibility;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn app_in_thread() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app
+    }
+
+    fn share(url: &str) -> ThreadShare {
+        ThreadShare {
+            url: url.to_string(),
+            visibility: ShareVisibility::LinkOnly,
+        }
+    }
+
+    #[test]
+    fn test_options_toggle_per_row() {
+        let mut app = app_in_thread();
+        app.open_active_thread_share();
+
+        app.handle_share_dialog_key(key(KeyCode::Char(' ')));
+        app.handle_share_dialog_key(key(KeyCode::Down));
+        app.handle_share_dialog_key(key(KeyCode::Down));
+        app.handle_share_dialog_key(key(KeyCode::Down));
+        app.handle_share_dialog_key(key(KeyCode::Tab));
+
+        let dialog = app.share_dialog.as_ref().unwrap();
+        assert_eq!(dialog.selected_row, 2);
+        assert_eq!(dialog.options.visibility, ShareVisibility::Org);
+        assert!(dialog.options.include_tool_outputs);
+        assert!(dialog.options.include_reasoning);
+    }
+
+    #[test]
+    fn test_shared_link_is_copied_and_shown_next_time() {
+        let mut app = app_in_thread();
+        app.open_active_thread_share();
+        app.handle_message(AppMessage::ThreadShared {
+            thread_id: "thread-001".to_string(),
+            share: share("https://spoq.dev/s/abc"),
+        });
+
+        assert!(app.share_dialog.is_none());
+        // Either the system clipboard or the OSC 52 fallback is used
+        let notice = app.status_notice.clone().unwrap();
+        assert!(
+            notice.contains("https://spoq.dev/s/abc"),
+            "notice: {}",
+            notice
+        );
+
+        app.open_active_thread_share();
+        let dialog = app.share_dialog.as_ref().unwrap();
+        assert_eq!(dialog.existing, Some(share("https://spoq.dev/s/abc")));
+    }
+
+    #[test]
+    fn test_failed_share_adds_inline_error() {
+        let mut app = app_in_thread();
+        app.open_active_thread_share();
+        app.handle_message(AppMessage::ThreadShareFailed {
+            thread_id: "thread-001".to_string(),
+            error: "Sharing is disabled".to_string(),
+        });
+
+        assert!(app.share_dialog.is_none());
+        let errors = app.cache.get_errors("thread-001").unwrap();
+        assert_eq!(errors.last().unwrap().error_code, SHARE_ERROR_CODE);
+        assert!(errors
+            .last()
+            .unwrap()
+            .message
+            .contains("Sharing is disabled"));
+    }
+
+    #[test]
+    fn test_revoked_share_is_forgotten() {
+        let mut app = app_in_thread();
+        app.thread_shares
+            .insert("thread-001".to_string(), share("https://spoq.dev/s/abc"));
+        app.handle_message(AppMessage::ThreadUnshared {
+            thread_id: "thread-001".to_string(),
+        });
+
+        assert!(app.thread_shares.is_empty());
+        assert_eq!(app.status_notice.as_deref(), Some("Link revoked"));
+    }
+}
@@ -0,0 +1,95 @@
+//! Local per-thread state kept in `~/.spoq/thread_state.json`.
+//!
+//! Pins are mirrored here so they survive restarts when the backend doesn't
+//! store them, and mutes, which are local only, live here. The App keeps the
+//! state in memory and writes the whole file after each change; it is its
+//! own file so saving it never rewrites `~/.spoq/config.json`.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::App;
+
+/// File name of the thread state inside `~/.spoq`
+const THREAD_STATE_FILE: &str = "thread_state.json";
+
+/// Local state of the user's threads
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThreadState {
+    /// IDs of pinned threads in pin order (local copy of the backend pins)
+    pub pinned_threads: Vec<String>,
+    /// IDs of muted threads (mutes aren't stored on the backend)
+    pub muted_threads: Vec<String>,
+}
+
+impl ThreadState {
+    /// Path of the thread state file (`~/.spoq/thread_state.json`)
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".spoq").join(THREAD_STATE_FILE))
+    }
+
+    /// Load the saved state; a missing or invalid file yields the default
+    pub fn load(path: &Path) -> Self {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        serde_json::from_str(&contents)
+            .map_err(|e| tracing::warn!("Ignoring invalid {}: {}", path.display(), e))
+            .unwrap_or_default()
+    }
+
+    /// Write the state
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+}
+
+impl App {
+    /// Save the thread state to `thread_state_path` (nothing to do without one)
+    pub(crate) fn save_thread_state(&self) {
+        let Some(path) = self.thread_state_path.as_deref() else {
+            return;
+        };
+        if crate::startup::integrity::is_read_only() {
+            return;
+        }
+        if let Err(e) = self.thread_state.save(path) {
+            tracing::warn!("Failed to save thread state: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thread_state_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(THREAD_STATE_FILE);
+        let state = ThreadState {
+            pinned_threads: vec!["t-2".to_string(), "t-1".to_string()],
+            muted_threads: vec!["t-3".to_string()],
+        };
+
+        state.save(&path).unwrap();
+
+        assert_eq!(ThreadState::load(&path), state);
+    }
+
+    #[test]
+    fn test_thread_state_missing_or_invalid_file_is_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(THREAD_STATE_FILE);
+        assert_eq!(ThreadState::load(&path), ThreadState::default());
+
+        std::fs::write(&path, "{ not json").unwrap();
+        assert_eq!(ThreadState::load(&path), ThreadState::default());
+    }
+}
@@ -0,0 +1,139 @@
+//! Outcome summaries for thread cards.
+//!
+//! When a thread finishes a long run that the user is not watching, a
+//! one-line outcome summary ("✅ 14 tests fixed, PR opened") is requested
+//! from the backend. It replaces the thread's `preview` in the cache and is
+//! shown on its CommandDeck card until the thread runs again.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::models::dashboard::ThreadStatus;
+
+use super::{App, AppMessage, Screen};
+
+/// Minimum run length before an outcome summary is requested
+pub(crate) const SUMMARY_MIN_RUN: Duration = Duration::from_secs(120);
+
+impl App {
+    /// Request an outcome summary if a thread just finished a long run.
+    ///
+    /// `run_elapsed` is how long the run had been going before this status
+    /// update. Returns true if a summary was requested.
+    pub(crate) fn maybe_request_thread_summary(
+        &mut self,
+        thread_id: &str,
+        status: ThreadStatus,
+        run_elapsed: Option<Duration>,
+    ) -> bool {
+        if status != ThreadStatus::Done {
+            return false;
+        }
+        if run_elapsed.is_none_or(|elapsed| elapsed < SUMMARY_MIN_RUN) {
+            return false;
+        }
+        // The user watched this run; the conversation already shows the outcome
+        if self.screen == Screen::Conversation
+            && self.active_thread_id.as_deref() == Some(thread_id)
+        {
+            return false;
+        }
+
+        self.spawn_thread_summary_request(thread_id.to_string());
+        true
+    }
+
+    /// Store a thread's outcome summary as its preview
+    pub(crate) fn apply_thread_summary(&mut self, thread_id: &str, summary: String) {
+        self.cache.set_thread_preview(thread_id, summary.clone());
+        self.dashboard.set_run_summary(thread_id, summary);
+        self.mark_dirty();
+    }
+
+    fn spawn_thread_summary_request(&self, thread_id: String) {
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            match client.summarize_thread(&thread_id).await {
+                Ok(Some(summary)) => {
+                    let _ = tx.send(AppMessage::ThreadSummaryReady { thread_id, summary });
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    // Summaries are best-effort; keep the existing preview
+                    tracing::debug!("Thread summary unavailable for {}: {}", thread_id, e);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn app_with_stub_threads() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app
+    }
+
+    #[test]
+    fn test_short_or_unfinished_runs_are_not_summarized() {
+        let mut app = app_with_stub_threads();
+        let long = Some(SUMMARY_MIN_RUN);
+
+        assert!(!app.maybe_request_thread_summary(
+            "thread-001",
+            ThreadStatus::Done,
+            Some(Duration::from_secs(5))
+        ));
+        assert!(!app.maybe_request_thread_summary("thread-001", ThreadStatus::Done, None));
+        assert!(!app.maybe_request_thread_summary("thread-001", ThreadStatus::Error, long));
+        assert!(!app.maybe_request_thread_summary("thread-001", ThreadStatus::Waiting, long));
+    }
+
+    #[test]
+    fn test_watched_thread_is_not_summarized() {
+        let mut app = app_with_stub_threads();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+
+        assert!(!app.maybe_request_thread_summary(
+            "thread-001",
+            ThreadStatus::Done,
+            Some(SUMMARY_MIN_RUN)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_long_background_run_requests_summary() {
+        let mut app = app_with_stub_threads();
+
+        assert!(app.maybe_request_thread_summary(
+            "thread-002",
+            ThreadStatus::Done,
+            Some(SUMMARY_MIN_RUN + Duration::from_secs(1))
+        ));
+    }
+
+    #[test]
+    fn test_summary_ready_updates_preview() {
+        let mut app = app_with_stub_threads();
+
+        app.handle_message(AppMessage::ThreadSummaryReady {
+            thread_id: "thread-001".to_string(),
+            summary: "✅ 14 tests fixed, PR opened".to_string(),
+        });
+
+        assert_eq!(
+            app.cache.get_thread("thread-001").unwrap().preview,
+            "✅ 14 tests fixed, PR opened"
+        );
+        assert_eq!(
+            app.dashboard.get_run_summary("thread-001"),
+            Some("✅ 14 tests fixed, PR opened")
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Coalescing of streamed tokens.
+//!
+//! Fast models send many tokens per frame. Applying each one appends to the
+//! message cache and marks the app dirty, so the TUI would redraw per token.
+//! [`TokenCoalescer`] buffers tokens that arrive within a frame (16ms) of the
+//! last append and appends them together: with the next token once the frame
+//! is over, on the next tick, before a draw, or before a stream event that
+//! must stay ordered after the text (tool events, completion, errors). The
+//! first token after a quiet spell is applied right away.
+
+use std::time::{Duration, Instant};
+
+use crate::debug::{DebugEventKind, ProcessedEventData};
+
+use super::{emit_debug, App, AppMessage};
+
+/// Shortest time between two appends while tokens keep arriving
+pub const COALESCE_WINDOW: Duration = Duration::from_millis(16);
+
+/// Text buffered for one thread
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenBatch {
+    pub thread_id: String,
+    pub text: String,
+    /// Number of tokens in the batch
+    pub tokens: usize,
+}
+
+/// Buffers streamed tokens per thread until the current frame is over.
+#[derive(Debug, Clone, Default)]
+pub struct TokenCoalescer {
+    /// Buffered text per thread, in order of first arrival
+    pending: Vec<TokenBatch>,
+    /// When text was last appended (None after a quiet spell or a flush)
+    last_append: Option<Instant>,
+    /// Batches appended so far
+    batches: u64,
+}
+
+impl TokenCoalescer {
+    /// Buffer a token for a thread
+    pub fn push(&mut self, thread_id: &str, token: &str) {
+        match self.pending.iter_mut().find(|b| b.thread_id == thread_id) {
+            Some(batch) => {
+                batch.text.push_str(token);
+                batch.tokens += 1;
+            }
+            None => self.pending.push(TokenBatch {
+                thread_id: thread_id.to_string(),
+                text: token.to_string(),
+                tokens: 1,
+            }),
+        }
+    }
+
+    /// Whether buffered text should be appended at `now`
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.last_append
+            .is_none_or(|last| now.duration_since(last) >= COALESCE_WINDOW)
+    }
+
+    /// Whether any text is buffered
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Take the buffered batches, counting them as appended.
+    ///
+    /// `appended_at` starts a new frame; None lets the next token through
+    /// right away.
+    pub fn take(&mut self, appended_at: Option<Instant>) -> Vec<TokenBatch> {
+        self.last_append = appended_at;
+        self.batches += self.pending.len() as u64;
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Drop the buffered text of a thread without appending it
+    pub fn discard(&mut self, thread_id: &str) {
+        self.pending.retain(|b| b.thread_id != thread_id);
+    }
+
+    /// Number of batches appended so far
+    pub fn batches(&self) -> u64 {
+        self.batches
+    }
+}
+
+impl App {
+    /// Buffer a streamed token, appending the buffered text once the frame
+    /// since the last append is over
+    pub(crate) fn coalesce_stream_token(&mut self, thread_id: String, token: String) {
+        let now = Instant::now();
+        self.token_coalescer.push(&thread_id, &token);
+        if self.token_coalescer.is_due(now) {
+            let batches = self.token_coalescer.take(Some(now));
+            self.apply_token_batches(batches);
+        }
+    }
+
+    /// Append all buffered tokens now (before drawing, on a tick, or before
+    /// an event ordered after the text)
+    pub fn flush_stream_tokens(&mut self) {
+        if self.token_coalescer.has_pending() {
+            let batches = self.token_coalescer.take(None);
+            self.apply_token_batches(batches);
+        }
+    }
+
+    fn apply_token_batches(&mut self, batches: Vec<TokenBatch>) {
+        for batch in batches {
+            emit_debug(
+                &self.debug_tx,
+                DebugEventKind::ProcessedEvent(ProcessedEventData::new(
+                    "StreamTokenBatch",
+                    format!(
+                        "batch {}: {} tokens, {} bytes",
+                        self.token_coalescer.batches(),
+                        batch.tokens,
+                        batch.text.len()
+                    ),
+                )),
+                Some(&batch.thread_id),
+            );
+            self.dispatch_message(AppMessage::StreamToken {
+                thread_id: batch.thread_id,
+                token: batch.text,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn streaming_app() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.reveal.set_enabled(false);
+        app.cache
+            .add_streaming_message("thread-001", "hi".to_string(), Vec::new());
+        app
+    }
+
+    fn streaming_content(app: &App) -> String {
+        let messages = app.cache.get_messages("thread-001").unwrap();
+        messages.last().unwrap().partial_content.clone()
+    }
+
+    fn token(text: &str) -> AppMessage {
+        AppMessage::StreamToken {
+            thread_id: "thread-001".to_string(),
+            token: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_coalescer_batches_per_thread() {
+        let mut coalescer = TokenCoalescer::default();
+        let start = Instant::now();
+        assert!(coalescer.is_due(start));
+
+        coalescer.push("a", "Hel");
+        coalescer.push("b", "x");
+        coalescer.push("a", "lo");
+        let batches = coalescer.take(Some(start));
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].text, "Hello");
+        assert_eq!(batches[0].tokens, 2);
+        assert_eq!(coalescer.batches(), 2);
+
+        assert!(!coalescer.is_due(start + Duration::from_millis(5)));
+        assert!(coalescer.is_due(start + COALESCE_WINDOW));
+    }
+
+    #[test]
+    fn test_first_token_applies_at_once_and_rest_waits_for_flush() {
+        let mut app = streaming_app();
+
+        app.handle_message(token("Hello"));
+        assert_eq!(streaming_content(&app), "Hello");
+
+        app.handle_message(token(" world"));
+        assert_eq!(streaming_content(&app), "Hello");
+
+        app.tick();
+        assert_eq!(streaming_content(&app), "Hello world");
+    }
+
+    #[test]
+    fn test_completion_flushes_buffered_text() {
+        let mut app = streaming_app();
+        app.handle_message(token("Full"));
+        app.handle_message(token(" answer"));
+
+        app.handle_message(AppMessage::StreamComplete {
+            thread_id: "thread-001".to_string(),
+            message_id: 42,
+        });
+
+        let last = app
+            .cache
+            .get_messages("thread-001")
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_eq!(last.content, "Full answer");
+        assert!(!app.token_coalescer.has_pending());
+    }
+
+    #[test]
+    fn test_rapid_tokens_append_and_redraw_per_frame() {
+        let mut app = streaming_app();
+        app.needs_redraw = false;
+
+        let start = Instant::now();
+        let mut redraws = 0u128;
+        for _ in 0..10_000 {
+            app.handle_message(token("x"));
+            if app.needs_redraw {
+                redraws += 1;
+                app.needs_redraw = false;
+            }
+        }
+        let frames = start.elapsed().as_millis() / COALESCE_WINDOW.as_millis() + 1;
+        let appends = app.token_coalescer.batches() as u128;
+
+        assert!(
+            appends <= frames,
+            "{} appends in {} frames",
+            appends,
+            frames
+        );
+        assert!(
+            redraws <= frames,
+            "{} redraws in {} frames",
+            redraws,
+            frames
+        );
+
+        app.flush_stream_tokens();
+        assert_eq!(streaming_content(&app).len(), 10_000);
+    }
+}
@@ -0,0 +1,142 @@
+//! Tool output blocks for the App.
+//!
+//! Each tool event keeps the full output of its call. `o` expands or collapses
+//! the output blocks of the focused (current search match) or last message
+//! with tool output; `O` copies that output to the clipboard.
+
+use std::time::Duration;
+
+use crate::models::MessageSegment;
+
+use super::App;
+
+/// How long the "No tool output" error stays visible
+const TOOL_OUTPUT_NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+impl App {
+    /// Index of the focused or last message with tool output in the active thread
+    fn tool_output_target_index(&self) -> Option<usize> {
+        let thread_id = self.active_thread_id.as_ref()?;
+        let messages = self.cache.get_messages(thread_id)?;
+
+        self.message_search
+            .active
+            .then(|| self.message_search.current_match())
+            .flatten()
+            .map(|m| m.message_index)
+            .filter(|idx| messages.get(*idx).is_some_and(|m| m.has_tool_output()))
+            .or_else(|| self.cache.find_last_tool_output_message_index(thread_id))
+    }
+
+    /// Expand or collapse the tool output of the focused or last message.
+    ///
+    /// Returns true if a message had tool output to toggle.
+    pub fn toggle_tool_output(&mut self) -> bool {
+        let Some(idx) = self.tool_output_target_index() else {
+            return false;
+        };
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return false;
+        };
+        let toggled = self.cache.toggle_message_tool_output(&thread_id, idx);
+        if toggled {
+            self.mark_dirty();
+        }
+        toggled
+    }
+
+    /// Full tool output of the focused or last message with tool output.
+    ///
+    /// Outputs of several tools are separated by a blank line.
+    pub fn tool_output_content(&self) -> Option<String> {
+        let idx = self.tool_output_target_index()?;
+        let thread_id = self.active_thread_id.as_ref()?;
+        let message = self.cache.get_messages(thread_id)?.get(idx)?;
+
+        let outputs: Vec<&str> = message
+            .segments
+            .iter()
+            .filter_map(|s| match s {
+                MessageSegment::ToolEvent(event) if event.has_output() => {
+                    event.output.as_deref().map(|o| o.trim_end_matches('\n'))
+                }
+                _ => None,
+            })
+            .collect();
+        (!outputs.is_empty()).then(|| outputs.join("\n\n"))
+    }
+
+    /// Copy the full tool output of the focused or last message
+    pub fn copy_tool_output(&mut self) {
+        match self.tool_output_content() {
+            Some(output) => self.copy_text(output),
+            None => self.set_timed_error(
+                "No tool output to copy".to_string(),
+                TOOL_OUTPUT_NOTICE_DURATION,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Screen;
+    use crate::cache::ThreadCache;
+
+    fn app_with_tool_output() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::new();
+        let thread_id = app.cache.create_streaming_thread("Build it".to_string());
+        app.cache
+            .start_tool_in_message(&thread_id, "tool-1".to_string(), "Bash".to_string());
+        app.cache
+            .start_tool_in_message(&thread_id, "tool-2".to_string(), "Bash".to_string());
+        app.cache
+            .set_tool_result(&thread_id, "tool-1", "error: linker failed\n", true);
+        app.cache.set_tool_result(&thread_id, "tool-2", "ok", false);
+        app.cache.finalize_message(&thread_id, 100);
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some(thread_id);
+        app
+    }
+
+    #[test]
+    fn test_toggle_tool_output_expands_last_message() {
+        let mut app = app_with_tool_output();
+
+        assert!(app.toggle_tool_output());
+
+        let thread_id = app.active_thread_id.clone().unwrap();
+        let messages = app.cache.get_messages(&thread_id).unwrap();
+        assert!(
+            messages[1]
+                .get_tool_event("tool-1")
+                .unwrap()
+                .output_expanded
+        );
+        assert!(
+            messages[1]
+                .get_tool_event("tool-2")
+                .unwrap()
+                .output_expanded
+        );
+    }
+
+    #[test]
+    fn test_tool_output_content_joins_outputs() {
+        let app = app_with_tool_output();
+        assert_eq!(
+            app.tool_output_content().as_deref(),
+            Some("error: linker failed\n\nok")
+        );
+    }
+
+    #[test]
+    fn test_copy_tool_output_without_output_shows_error() {
+        let mut app = App::default();
+        assert!(!app.toggle_tool_output());
+        app.copy_tool_output();
+        assert_eq!(app.stream_error.as_deref(), Some("No tool output to copy"));
+    }
+}
@@ -6,7 +6,17 @@
 //! - [`ScrollBoundary`] - Scroll boundary hit state
 //! - [`ThreadSwitcher`] - Thread switcher dialog state
 //! - [`BrowseListState`] - Full-screen browse list state (threads/repos)
+//! - [`MessageSearchState`] - In-conversation message search state
+//! - [`HistorySearchState`] - Input history search state (Ctrl+R)
+//! - [`LinkEditorState`] - Thread link editor state (`/link`)
+//! - [`ThreadRenameState`] - Inline thread title editor state (`/rename`)
+//! - [`ShareDialogState`] - Thread share dialog state (`/share`)
+//! - [`PeerPresence`] - Another client's presence on a thread
+//! - [`ZoomLevel`] - Conversation content zoom level
+//! - [`PendingReply`] - Earlier message the next submission replies to
 
+use crate::cache::MessageMatch;
+use crate::models::{ShareOptions, ThreadLinkKind, ThreadShare};
 use crate::models::picker::{RepoEntry, ThreadEntry};
 
 /// Represents which screen is currently active
@@ -17,6 +27,8 @@ pub enum Screen {
     Conversation,
     /// Full-screen browse list (threads or repos)
     BrowseList,
+    /// First-run setup wizard (no login or VPS yet)
+    Setup,
 }
 
 /// What type of content the browse list is showing
@@ -25,6 +37,47 @@ pub enum BrowseListMode {
     #[default]
     Threads,
     Repos,
+    /// Threads and repos together, in a section each
+    All,
+}
+
+/// Which threads the browse list shows by archive state (Ctrl+A cycles)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThreadArchiveFilter {
+    #[default]
+    All,
+    /// Threads that aren't archived
+    Active,
+    Archived,
+}
+
+impl ThreadArchiveFilter {
+    /// The filter Ctrl+A switches to
+    pub fn next(self) -> Self {
+        match self {
+            ThreadArchiveFilter::All => ThreadArchiveFilter::Active,
+            ThreadArchiveFilter::Active => ThreadArchiveFilter::Archived,
+            ThreadArchiveFilter::Archived => ThreadArchiveFilter::All,
+        }
+    }
+
+    /// Label shown in the browse list header
+    pub fn label(self) -> &'static str {
+        match self {
+            ThreadArchiveFilter::All => "All",
+            ThreadArchiveFilter::Active => "Active",
+            ThreadArchiveFilter::Archived => "Archived",
+        }
+    }
+
+    /// Whether a thread with this archive state is shown
+    pub fn matches(self, archived: bool) -> bool {
+        match self {
+            ThreadArchiveFilter::All => true,
+            ThreadArchiveFilter::Active => !archived,
+            ThreadArchiveFilter::Archived => archived,
+        }
+    }
 }
 
 /// State for the full-screen browse list view
@@ -42,9 +95,13 @@ pub struct BrowseListState {
     pub scroll_offset: usize,
     /// Total count from server (for "X total" display)
     pub total_count: usize,
-    /// Thread items (when mode is Threads)
+    /// Thread items (when mode is Threads or All) - filtered view
     pub threads: Vec<ThreadEntry>,
-    /// Repo items (when mode is Repos) - filtered view
+    /// All loaded threads (unfiltered, for the archive filter)
+    pub all_threads: Vec<ThreadEntry>,
+    /// Archive state of the threads shown
+    pub archive_filter: ThreadArchiveFilter,
+    /// Repo items (when mode is Repos or All) - filtered view
     pub repos: Vec<RepoEntry>,
     /// All repos (unfiltered, for local filtering)
     pub all_repos: Vec<RepoEntry>,
@@ -79,12 +136,17 @@ pub enum Focus {
 pub struct ThreadSwitcher {
     /// Whether the thread switcher dialog is visible
     pub visible: bool,
-    /// Currently selected index in the thread list (MRU order)
+    /// Currently selected position among the listed threads (MRU order, or
+    /// match order while filtering)
     pub selected_index: usize,
-    /// Scroll offset for the thread list (first visible thread index)
+    /// Scroll offset for the thread list (first visible position)
     pub scroll_offset: usize,
     /// Timestamp of last navigation key press (for auto-confirm on release)
     pub last_nav_time: Option<std::time::Instant>,
+    /// Filter typed while the switcher is open (empty lists every thread)
+    pub query: String,
+    /// Thread list indices matching `query`, best matches first
+    pub matches: Vec<usize>,
 }
 
 /// Represents which scroll boundary was hit (for visual feedback)
@@ -93,3 +155,204 @@ pub enum ScrollBoundary {
     Top,
     Bottom,
 }
+
+/// In-conversation message search state (`/` in Conversation to open)
+#[derive(Debug, Clone, Default)]
+pub struct MessageSearchState {
+    /// Whether search mode is active (search bar shown, matches highlighted)
+    pub active: bool,
+    /// Whether the query is being typed (keys go to the query, not n/N)
+    pub editing: bool,
+    /// Current search query
+    pub query: String,
+    /// Matches for the current query in the active thread
+    pub matches: Vec<MessageMatch>,
+    /// Index into `matches` of the currently selected match
+    pub current: usize,
+    /// Set when the viewport should scroll to the current match on next render
+    pub pending_jump: bool,
+    /// `ThreadCache::content_signature` of the thread when `matches` were found
+    pub content_signature: u64,
+}
+
+impl MessageSearchState {
+    /// Get the currently selected match, if any
+    pub fn current_match(&self) -> Option<&MessageMatch> {
+        self.matches.get(self.current)
+    }
+
+    /// Whether matches should be highlighted in the messages area
+    pub fn is_highlighting(&self) -> bool {
+        self.active && !self.query.is_empty()
+    }
+}
+
+/// Input history search state (Ctrl+R while the input is focused)
+#[derive(Debug, Clone, Default)]
+pub struct HistorySearchState {
+    /// Whether the search overlay is open
+    pub active: bool,
+    /// Current search query
+    pub query: String,
+    /// Matching history entries, best first
+    pub matches: Vec<String>,
+    /// Index into `matches` of the highlighted entry
+    pub selected: usize,
+}
+
+impl HistorySearchState {
+    /// Get the highlighted entry, if any
+    pub fn selected_match(&self) -> Option<&str> {
+        self.matches.get(self.selected).map(String::as_str)
+    }
+}
+
+/// Thread deletion awaiting confirmation (`d`/`x` on a thread list)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadDeleteConfirm {
+    /// ID of the thread to delete
+    pub thread_id: String,
+    /// Thread title shown in the confirmation dialog
+    pub title: String,
+}
+
+/// Thread archiving awaiting confirmation (`a` on the Threads panel)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadArchiveConfirm {
+    /// ID of the thread to archive
+    pub thread_id: String,
+    /// Thread title shown in the confirmation dialog
+    pub title: String,
+}
+
+/// Model picker state (`/model`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelPickerState {
+    /// Selected index into the cached model list
+    pub selected_index: usize,
+}
+
+/// Earlier message the next submission replies to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingReply {
+    /// Thread the replied-to message belongs to
+    pub thread_id: String,
+    /// ID of the replied-to message
+    pub message_id: i64,
+    /// Start of the replied-to message, shown in the chip above the input
+    pub excerpt: String,
+}
+
+/// "What's new" overlay state, shown on the first launch after an update
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WhatsNewState {
+    /// Version the release notes describe
+    pub version: String,
+    /// Release notes (markdown)
+    pub notes: String,
+    /// Lines scrolled past the top
+    pub scroll: u16,
+}
+
+/// Notifications panel state (`/notifications` on the CommandDeck)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NotificationsPanelState {
+    /// Selected index into the dashboard's notifications (newest first)
+    pub selected_index: usize,
+}
+
+/// Raw event log view state (`/events` in a conversation)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventLogViewState {
+    /// Thread whose events are listed
+    pub thread_id: String,
+    /// Entries scrolled past the top (newest first)
+    pub scroll: usize,
+}
+
+/// How much of a conversation is shown (cycled per thread)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ZoomLevel {
+    /// Level 0: user prompts and answer text only
+    Answers,
+    /// Level 1: adds one-line tool and subagent summaries
+    ToolSummaries,
+    /// Level 2: everything, including reasoning and subagent progress
+    #[default]
+    Full,
+}
+
+impl ZoomLevel {
+    /// The next level in the cycle (full -> summaries -> answers -> full)
+    pub fn next(self) -> Self {
+        match self {
+            ZoomLevel::Full => ZoomLevel::ToolSummaries,
+            ZoomLevel::ToolSummaries => ZoomLevel::Answers,
+            ZoomLevel::Answers => ZoomLevel::Full,
+        }
+    }
+
+    /// Numeric level (0 = least detail)
+    pub fn level(self) -> u8 {
+        match self {
+            ZoomLevel::Answers => 0,
+            ZoomLevel::ToolSummaries => 1,
+            ZoomLevel::Full => 2,
+        }
+    }
+
+    /// Short description for notices and the header
+    pub fn label(self) -> &'static str {
+        match self {
+            ZoomLevel::Answers => "answers only",
+            ZoomLevel::ToolSummaries => "tool summaries",
+            ZoomLevel::Full => "everything",
+        }
+    }
+}
+
+/// Another client's presence on a thread (from `presence_update` events)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerPresence {
+    /// Kind of client ("web", "tui", ...)
+    pub client_kind: String,
+    /// Typing indicator shows until this instant (refreshed by typing events)
+    pub typing_until: Option<std::time::Instant>,
+    /// When the client last sent a message to the thread
+    pub last_sent_at: Option<std::time::Instant>,
+}
+
+/// Thread link editor state (`/link` in a conversation)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkEditorState {
+    /// Thread whose links are being edited
+    pub thread_id: String,
+    /// Relation used when linking the selected thread
+    pub kind: ThreadLinkKind,
+    /// Selected index into the link candidates
+    pub selected_index: usize,
+}
+
+/// Thread share dialog state (`/share`, or `s` on a thread card)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareDialogState {
+    /// Thread being shared
+    pub thread_id: String,
+    /// Options for a new share
+    pub options: ShareOptions,
+    /// Selected option row (visibility, tool outputs, reasoning)
+    pub selected_row: usize,
+    /// Current share of the thread, if it is already shared
+    pub existing: Option<ThreadShare>,
+    /// A share or revoke request is in flight
+    pub in_progress: bool,
+}
+
+/// Inline thread title editor state (`/rename`, or `r` on a thread card)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadRenameState {
+    /// Thread being renamed
+    pub thread_id: String,
+    /// Title being typed (starts as the current title)
+    pub title: String,
+}
@@ -0,0 +1,218 @@
+//! Token usage summary (`/usage`) for the App.
+//!
+//! ThreadCache counts each thread's prompt and completion tokens for the
+//! session; thread cards and the conversation header show a thread's total.
+//! `/usage` lists every thread with any, most used first, above the session
+//! totals. With a `token_prices` table in `~/.spoq/config.json` it also
+//! estimates the cost, priced by each thread's model or the `default` entry.
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache::TokenUsage;
+
+use super::App;
+
+/// `token_prices` entry used for threads whose model has no price
+pub const DEFAULT_PRICE_KEY: &str = "default";
+
+/// Price of a model, in dollars per million tokens
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TokenPrice {
+    pub prompt_per_million: f64,
+    pub completion_per_million: f64,
+}
+
+impl TokenPrice {
+    /// Estimated cost of `usage`
+    pub fn cost(&self, usage: &TokenUsage) -> f64 {
+        (usage.prompt_tokens as f64 * self.prompt_per_million
+            + usage.completion() as f64 * self.completion_per_million)
+            / 1_000_000.0
+    }
+}
+
+/// A thread's line in `/usage`
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageRow {
+    pub title: String,
+    pub usage: TokenUsage,
+    /// None when no price applies to the thread's model
+    pub cost: Option<f64>,
+}
+
+/// What `/usage` shows
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageSummary {
+    /// Most used thread first
+    pub rows: Vec<UsageRow>,
+    pub total: TokenUsage,
+    /// Sum of the priced threads, None without a price table
+    pub total_cost: Option<f64>,
+}
+
+/// Open `/usage` overlay
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageView {
+    /// Rows scrolled past the top
+    pub scroll: usize,
+}
+
+impl App {
+    /// Total tokens of a thread this session, if it used any
+    pub fn thread_token_total(&self, thread_id: &str) -> Option<u64> {
+        self.cache
+            .thread_usage(thread_id)
+            .map(|usage| usage.total())
+            .filter(|total| *total > 0)
+    }
+
+    /// Price applying to a thread: its model's, or the default one
+    fn thread_price(&self, thread_id: &str) -> Option<&TokenPrice> {
+        self.cache
+            .get_thread(thread_id)
+            .and_then(|thread| thread.model.as_deref())
+            .and_then(|model| self.token_prices.get(model))
+            .or_else(|| self.token_prices.get(DEFAULT_PRICE_KEY))
+    }
+
+    /// Per-thread usage and session totals for `/usage`
+    pub fn usage_summary(&self) -> UsageSummary {
+        let mut rows: Vec<UsageRow> = self
+            .cache
+            .usage_by_thread()
+            .iter()
+            .filter(|(_, usage)| usage.total() > 0)
+            .map(|(thread_id, usage)| UsageRow {
+                title: self
+                    .cache
+                    .get_thread(thread_id)
+                    .map(|thread| thread.title.clone())
+                    .unwrap_or_else(|| format!("thread {}", thread_id)),
+                usage: *usage,
+                cost: self.thread_price(thread_id).map(|price| price.cost(usage)),
+            })
+            .collect();
+        rows.sort_by(|a, b| {
+            b.usage
+                .total()
+                .cmp(&a.usage.total())
+                .then_with(|| a.title.cmp(&b.title))
+        });
+
+        let mut total = TokenUsage::default();
+        for row in &rows {
+            total.prompt_tokens += row.usage.prompt_tokens;
+            total.completion_tokens += row.usage.completion_tokens;
+            total.streamed_estimate += row.usage.streamed_estimate;
+            total.turns += row.usage.turns;
+        }
+        let total_cost =
+            (!self.token_prices.is_empty()).then(|| rows.iter().filter_map(|row| row.cost).sum());
+
+        UsageSummary {
+            rows,
+            total,
+            total_cost,
+        }
+    }
+
+    /// Open the `/usage` overlay
+    pub fn open_usage_view(&mut self) {
+        self.usage_view = Some(UsageView::default());
+        self.mark_dirty();
+    }
+
+    /// Close the `/usage` overlay
+    pub fn close_usage_view(&mut self) {
+        self.usage_view = None;
+        self.mark_dirty();
+    }
+
+    /// Scroll the thread rows by `delta`
+    pub fn scroll_usage_view(&mut self, delta: isize) {
+        let rows = self.usage_summary().rows.len();
+        if let Some(view) = self.usage_view.as_mut() {
+            view.scroll = view
+                .scroll
+                .saturating_add_signed(delta)
+                .min(rows.saturating_sub(1));
+            self.mark_dirty();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_with_usage() -> App {
+        let mut app = App::default();
+        app.cache = crate::cache::ThreadCache::with_stub_data();
+        let ids: Vec<String> = app
+            .cache
+            .threads()
+            .iter()
+            .take(2)
+            .map(|t| t.id.clone())
+            .collect();
+        app.cache.record_turn_usage(&ids[0], 1_000, None, Some(200));
+        app.cache
+            .record_turn_usage(&ids[1], 40_000, None, Some(2_000));
+        app.cache
+            .set_thread_model(&ids[1], Some("opus".to_string()));
+        app
+    }
+
+    #[test]
+    fn test_summary_orders_threads_by_total() {
+        let mut app = app_with_usage();
+        app.token_prices.clear();
+        let summary = app.usage_summary();
+
+        assert_eq!(summary.rows.len(), 2);
+        assert_eq!(summary.rows[0].usage.total(), 42_000);
+        assert_eq!(summary.rows[1].usage.total(), 1_200);
+        assert_eq!(summary.total.prompt_tokens, 41_000);
+        assert_eq!(summary.total.completion(), 2_200);
+        assert_eq!(summary.total_cost, None);
+        assert!(summary.rows.iter().all(|row| row.cost.is_none()));
+    }
+
+    #[test]
+    fn test_cost_uses_model_price_then_default() {
+        let mut app = app_with_usage();
+        app.token_prices = [
+            (
+                "opus".to_string(),
+                TokenPrice {
+                    prompt_per_million: 15.0,
+                    completion_per_million: 75.0,
+                },
+            ),
+            (
+                DEFAULT_PRICE_KEY.to_string(),
+                TokenPrice {
+                    prompt_per_million: 3.0,
+                    completion_per_million: 15.0,
+                },
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let summary = app.usage_summary();
+
+        // 40k prompt at $15/M + 2k completion at $75/M
+        let opus = summary.rows[0].cost.unwrap();
+        assert!((opus - 0.75).abs() < 1e-9);
+        // 1k prompt at $3/M + 200 completion at $15/M
+        let default = summary.rows[1].cost.unwrap();
+        assert!((default - 0.006).abs() < 1e-9);
+        assert!((summary.total_cost.unwrap() - 0.756).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_thread_token_total_hidden_without_usage() {
+        let app = app_with_usage();
+        assert_eq!(app.thread_token_total("no-such-thread"), None);
+    }
+}
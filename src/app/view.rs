@@ -127,7 +127,7 @@ impl App {
 
             // Folder picker
             folders: &self.folders,
-            selected_folder: self.selected_folder.as_ref(),
+            selected_folders: &self.selected_folders,
             folder_picker_visible: self.folder_picker_visible,
             folder_picker_filter: &self.folder_picker_filter,
             folder_picker_cursor: self.folder_picker_cursor,
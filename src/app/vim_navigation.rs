@@ -0,0 +1,260 @@
+//! Vim-style navigate mode for the conversation viewport.
+//!
+//! Opt-in with `vim_navigation` in `~/.spoq/config.json`. Esc in an empty
+//! input then drops into navigate mode instead of leaving the thread: `j`/`k`
+//! scroll a line, Ctrl+D/Ctrl+U half a page, `gg`/`G` jump to the top or
+//! bottom and `{`/`}` to the previous or next message start, found from the
+//! cumulative offsets in `CachedHeights`. `i` or any other character goes
+//! back to the input (the character is typed); Esc leaves the thread as it
+//! does from the input.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{App, Focus, Screen, ScrollAnchor, ScrollBoundary};
+
+/// Navigate mode state
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NavigateMode {
+    /// `g` was pressed; a second one jumps to the top
+    pub pending_g: bool,
+}
+
+/// Lines of the messages in the viewport in the last frame
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MessageViewport {
+    /// Message line at the top of the viewport (0 = first line of the first
+    /// message)
+    pub top: usize,
+    /// Rows the viewport shows
+    pub height: usize,
+}
+
+impl App {
+    /// Whether keys go to navigate mode
+    pub fn is_navigating(&self) -> bool {
+        self.navigate_mode.is_some() && self.screen == Screen::Conversation
+    }
+
+    /// Esc in an empty input: switch to navigate mode.
+    ///
+    /// Returns false (leaving Esc to its usual meaning) when vim navigation
+    /// is off, the input has text, or no conversation is open.
+    pub fn enter_navigate_mode(&mut self) -> bool {
+        if !self.vim_navigation || self.screen != Screen::Conversation || !self.textarea.is_empty()
+        {
+            return false;
+        }
+        self.navigate_mode = Some(NavigateMode::default());
+        self.focus = Focus::Threads;
+        self.mark_dirty();
+        true
+    }
+
+    /// Back to typing in the input
+    pub fn exit_navigate_mode(&mut self) {
+        self.navigate_mode = None;
+        self.focus = Focus::Input;
+        self.mark_dirty();
+    }
+
+    /// Handle a key in navigate mode.
+    ///
+    /// Returns false for keys it leaves to the usual handling: the
+    /// conversation actions, any other character (after switching back to the
+    /// input, so it gets typed) and keys without a navigate mode meaning.
+    pub fn handle_navigate_key(&mut self, key: KeyEvent) -> bool {
+        let Some(mode) = self.navigate_mode.as_mut() else {
+            return false;
+        };
+        let pending_g = std::mem::take(&mut mode.pending_g);
+        let half_page = (self.message_viewport.height / 2).max(1) as isize;
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        match key.code {
+            KeyCode::Char('d') if ctrl => self.scroll_conversation(-half_page),
+            KeyCode::Char('u') if ctrl => self.scroll_conversation(half_page),
+            _ if ctrl || key.modifiers.contains(KeyModifiers::ALT) => return false,
+            KeyCode::Char('j') => self.scroll_conversation(-1),
+            KeyCode::Char('k') => self.scroll_conversation(1),
+            KeyCode::Char('g') if pending_g => self.scroll_to_top(),
+            KeyCode::Char('g') => {
+                if let Some(mode) = self.navigate_mode.as_mut() {
+                    mode.pending_g = true;
+                }
+            }
+            KeyCode::Char('G') => self.scroll_to_bottom(),
+            KeyCode::Char('{') => self.jump_to_message_boundary(false),
+            KeyCode::Char('}') => self.jump_to_message_boundary(true),
+            KeyCode::Char('i') => self.exit_navigate_mode(),
+            // Conversation actions (copy, reply) work here as they do
+            // without focus on the input
+            KeyCode::Char('y' | 'Y' | 'r') => return false,
+            KeyCode::Char(_) => {
+                self.exit_navigate_mode();
+                return false;
+            }
+            KeyCode::Esc => {
+                self.navigate_mode = None;
+                self.focus = Focus::Input;
+                self.navigate_to_command_deck();
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Scroll the conversation by `lines` (positive = up, to older content)
+    fn scroll_conversation(&mut self, lines: isize) {
+        self.scroll_velocity = 0.0;
+        let scroll =
+            (self.unified_scroll as isize + lines).clamp(0, self.max_scroll as isize) as u16;
+        if scroll == self.unified_scroll {
+            if lines < 0 {
+                self.scroll_boundary_hit = Some(ScrollBoundary::Bottom);
+            } else if self.max_scroll > 0 {
+                self.scroll_boundary_hit = Some(ScrollBoundary::Top);
+            }
+            self.boundary_hit_tick = self.tick_count;
+        } else {
+            self.unified_scroll = scroll;
+            self.scroll_position = scroll as f32;
+            self.user_has_scrolled = scroll > 0;
+        }
+        self.mark_dirty();
+    }
+
+    /// Bring the start of the next (or previous) message to the top of the
+    /// viewport, or go to the bottom (top) past the last (first) one
+    fn jump_to_message_boundary(&mut self, forward: bool) {
+        let top = self.message_viewport.top;
+        let target = self.height_cache.as_ref().and_then(|cache| {
+            let mut starts = cache.heights.iter();
+            if forward {
+                starts.find(|h| h.cumulative_offset > top)
+            } else {
+                starts.rev().find(|h| h.cumulative_offset < top)
+            }
+            .map(|h| h.message_id)
+        });
+        match target {
+            // Positioned by the renderer from the message's offset
+            Some(message_id) => {
+                self.pending_scroll_anchor = Some(ScrollAnchor {
+                    message_id,
+                    fraction: 0.0,
+                });
+                self.scroll_velocity = 0.0;
+                self.user_has_scrolled = true;
+                self.mark_dirty();
+            }
+            None if forward => self.scroll_to_bottom(),
+            None => self.scroll_to_top(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::CachedHeights;
+    use std::sync::Arc;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn navigating_app() -> App {
+        let mut app = App::default();
+        app.vim_navigation = true;
+        app.screen = Screen::Conversation;
+        app.focus = Focus::Input;
+        app.max_scroll = 100;
+        app.message_viewport = MessageViewport {
+            top: 100,
+            height: 20,
+        };
+        let mut cache = CachedHeights::new(Arc::new("thread-1".to_string()), 80);
+        cache.append(1, 0, 30);
+        cache.append(2, 0, 50);
+        cache.append(3, 0, 40);
+        app.height_cache = Some(cache);
+        assert!(app.enter_navigate_mode());
+        app
+    }
+
+    #[test]
+    fn test_esc_enters_only_when_enabled_and_input_empty() {
+        let mut app = App::default();
+        app.screen = Screen::Conversation;
+        app.vim_navigation = false;
+        assert!(!app.enter_navigate_mode());
+
+        app.vim_navigation = true;
+        app.textarea.insert_char('x');
+        assert!(!app.enter_navigate_mode());
+
+        app.textarea.clear();
+        assert!(app.enter_navigate_mode());
+        assert!(app.is_navigating());
+        assert_eq!(app.focus, Focus::Threads);
+    }
+
+    #[test]
+    fn test_line_half_page_and_end_jumps() {
+        let mut app = navigating_app();
+
+        assert!(app.handle_navigate_key(key(KeyCode::Char('k'))));
+        assert_eq!(app.unified_scroll, 1);
+        assert!(app.handle_navigate_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL)));
+        assert_eq!(app.unified_scroll, 11);
+        assert!(app.handle_navigate_key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)));
+        assert!(app.handle_navigate_key(key(KeyCode::Char('j'))));
+        assert_eq!(app.unified_scroll, 0);
+        assert!(!app.user_has_scrolled);
+
+        // A single g waits for the second
+        app.handle_navigate_key(key(KeyCode::Char('g')));
+        assert_eq!(app.unified_scroll, 0);
+        app.handle_navigate_key(key(KeyCode::Char('g')));
+        assert_eq!(app.unified_scroll, 100);
+        app.handle_navigate_key(key(KeyCode::Char('G')));
+        assert_eq!(app.unified_scroll, 0);
+    }
+
+    #[test]
+    fn test_braces_jump_between_message_starts() {
+        let mut app = navigating_app();
+
+        // Viewport top is inside message 3 (starts at line 80)
+        app.handle_navigate_key(key(KeyCode::Char('{')));
+        assert_eq!(app.pending_scroll_anchor.map(|a| a.message_id), Some(3));
+
+        app.message_viewport.top = 80;
+        app.handle_navigate_key(key(KeyCode::Char('{')));
+        assert_eq!(app.pending_scroll_anchor.map(|a| a.message_id), Some(2));
+
+        app.message_viewport.top = 10;
+        app.handle_navigate_key(key(KeyCode::Char('}')));
+        assert_eq!(app.pending_scroll_anchor.map(|a| a.message_id), Some(2));
+
+        // Past the last message start: the bottom
+        app.unified_scroll = 30;
+        app.message_viewport.top = 85;
+        app.handle_navigate_key(key(KeyCode::Char('}')));
+        assert_eq!(app.unified_scroll, 0);
+    }
+
+    #[test]
+    fn test_typing_returns_to_input() {
+        let mut app = navigating_app();
+        assert!(app.handle_navigate_key(key(KeyCode::Char('i'))));
+        assert!(!app.is_navigating());
+        assert_eq!(app.focus, Focus::Input);
+
+        app.enter_navigate_mode();
+        // Not consumed, so the character is typed
+        assert!(!app.handle_navigate_key(key(KeyCode::Char('x'))));
+        assert!(!app.is_navigating());
+        assert_eq!(app.focus, Focus::Input);
+    }
+}
@@ -0,0 +1,146 @@
+//! Thread event routing to webhooks for the App.
+//!
+//! Thread status transitions are mapped to webhook events (Done → completed,
+//! Waiting → needs action, Error → failed) and delivered in background tasks.
+//! Deliveries that still fail after retrying are reported as
+//! `AppMessage::WebhookFailed`.
+
+use crate::models::dashboard::{ThreadStatus, WaitingFor};
+use crate::webhooks::{build_payload, deliver_with_retry, display_host, WebhookEvent};
+
+use super::{App, AppMessage};
+
+impl App {
+    /// Send webhooks for a thread status transition.
+    ///
+    /// Returns the number of webhooks notified.
+    pub(crate) fn dispatch_status_webhooks(
+        &self,
+        thread_id: &str,
+        status: ThreadStatus,
+        waiting_for: Option<&WaitingFor>,
+    ) -> usize {
+        let (event, detail) = match status {
+            ThreadStatus::Done => (WebhookEvent::Completed, None),
+            ThreadStatus::Waiting => (
+                WebhookEvent::NeedsAction,
+                waiting_for.map(|wf| wf.description()),
+            ),
+            ThreadStatus::Error => (WebhookEvent::Failed, None),
+            ThreadStatus::Running => return 0,
+        };
+        self.dispatch_thread_webhooks(thread_id, event, detail)
+    }
+
+    /// Deliver a thread event to every matching webhook in the background.
+    ///
+    /// Returns the number of webhooks notified.
+    pub(crate) fn dispatch_thread_webhooks(
+        &self,
+        thread_id: &str,
+        event: WebhookEvent,
+        detail: Option<String>,
+    ) -> usize {
+        let targets = self.webhooks.targets_for(thread_id, event);
+        if targets.is_empty() {
+            return 0;
+        }
+
+        let title = self
+            .cache
+            .get_thread(thread_id)
+            .map(|t| t.title.clone())
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| "Untitled thread".to_string());
+        let client = reqwest::Client::new();
+
+        for target in &targets {
+            let payload =
+                build_payload(target.format, event, thread_id, &title, detail.as_deref());
+            let url = target.url.clone();
+            let client = client.clone();
+            let tx = self.message_tx.clone();
+            let thread_id = thread_id.to_string();
+
+            tokio::spawn(async move {
+                if let Err(e) = deliver_with_retry(&client, &url, &payload).await {
+                    let _ = tx.send(AppMessage::WebhookFailed {
+                        thread_id,
+                        host: display_host(&url).to_string(),
+                        error: e.to_string(),
+                    });
+                }
+            });
+        }
+
+        targets.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+    use crate::webhooks::{WebhookConfig, WebhookFormat, WebhookTarget};
+
+    fn app_with_webhook(events: Option<Vec<WebhookEvent>>) -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.webhooks = WebhookConfig {
+            global: vec![WebhookTarget {
+                url: "http://127.0.0.1:9/hook".to_string(),
+                format: WebhookFormat::Slack,
+                events,
+            }],
+            ..WebhookConfig::default()
+        };
+        app
+    }
+
+    #[test]
+    fn test_no_webhooks_configured() {
+        let app = App::default();
+        assert_eq!(
+            app.dispatch_status_webhooks("thread-001", ThreadStatus::Done, None),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_transitions_map_to_events() {
+        let app = app_with_webhook(Some(vec![WebhookEvent::NeedsAction]));
+
+        assert_eq!(
+            app.dispatch_status_webhooks("thread-001", ThreadStatus::Done, None),
+            0
+        );
+        assert_eq!(
+            app.dispatch_status_webhooks("thread-001", ThreadStatus::Running, None),
+            0
+        );
+        assert_eq!(
+            app.dispatch_status_webhooks(
+                "thread-001",
+                ThreadStatus::Waiting,
+                Some(&WaitingFor::UserInput)
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn test_webhook_failure_is_surfaced() {
+        let mut app = App::default();
+
+        app.handle_message(AppMessage::WebhookFailed {
+            thread_id: "thread-001".to_string(),
+            host: "hooks.slack.com".to_string(),
+            error: "HTTP 500".to_string(),
+        });
+
+        assert_eq!(
+            app.stream_error.as_deref(),
+            Some("Webhook to hooks.slack.com failed: HTTP 500")
+        );
+    }
+}
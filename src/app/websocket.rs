@@ -4,21 +4,33 @@
 //! routing incoming messages to AppMessage, and managing connection state.
 
 use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::state::session::AskUserQuestionData;
 use crate::view_state::SystemStats;
-use crate::websocket::{WsClient, WsClientConfig, WsConnectionState, WsIncomingMessage};
+use crate::websocket::{
+    WsClient, WsClientConfig, WsConnectionState, WsControl, WsIncomingMessage, WsOutgoingMessage,
+};
 
+use super::event_log::ws_event_record;
 use super::AppMessage;
 
+/// Channels to a running WebSocket client
+#[derive(Debug, Clone)]
+pub struct WsHandle {
+    /// Messages to send to the server
+    pub sender: mpsc::Sender<WsOutgoingMessage>,
+    /// Requests for the connection loop, such as "reconnect now"
+    pub control: mpsc::Sender<WsControl>,
+}
+
 /// Start the WebSocket client and spawn a task to handle incoming messages.
 ///
-/// Returns Ok(sender) if connection succeeds, or Err(error_message) if it fails.
+/// Returns Ok(handle) if connection succeeds, or Err(error_message) if it fails.
 /// On failure, the app continues in SSE-only mode.
 pub async fn start_websocket(
     message_tx: mpsc::UnboundedSender<AppMessage>,
-) -> Result<tokio::sync::mpsc::Sender<crate::websocket::WsOutgoingMessage>, String> {
+) -> Result<WsHandle, String> {
     start_websocket_with_config(message_tx, WsClientConfig::default()).await
 }
 
@@ -28,7 +40,7 @@ pub async fn start_websocket(
 pub async fn start_websocket_with_config(
     message_tx: mpsc::UnboundedSender<AppMessage>,
     config: WsClientConfig,
-) -> Result<tokio::sync::mpsc::Sender<crate::websocket::WsOutgoingMessage>, String> {
+) -> Result<WsHandle, String> {
     let host = config.host.clone();
     info!("Attempting to connect WebSocket to {}", host);
 
@@ -38,8 +50,8 @@ pub async fn start_websocket_with_config(
 
             // Get the outgoing message sender before moving client into the task
             // We need to create a channel that bridges to the client's send method
-            let (outgoing_tx, mut outgoing_rx) =
-                mpsc::channel::<crate::websocket::WsOutgoingMessage>(100);
+            let (outgoing_tx, mut outgoing_rx) = mpsc::channel::<WsOutgoingMessage>(100);
+            let control = client.control_sender();
 
             // Get the state receiver for monitoring connection state
             let mut state_rx = client.state_receiver();
@@ -56,6 +68,10 @@ pub async fn start_websocket_with_config(
                         msg = client.recv() => {
                             match msg {
                                 Some(ws_msg) => {
+                                    if let Some((thread_id, record)) = ws_event_record(&ws_msg) {
+                                        let _ = message_tx_clone
+                                            .send(AppMessage::EventReceived { thread_id, record });
+                                    }
                                     if let Err(e) = route_ws_message(ws_msg, &message_tx_clone) {
                                         warn!("Failed to route WebSocket message: {}", e);
                                     }
@@ -104,7 +120,10 @@ pub async fn start_websocket_with_config(
                 }
             });
 
-            Ok(outgoing_tx)
+            Ok(WsHandle {
+                sender: outgoing_tx,
+                control,
+            })
         }
         Err(e) => {
             let error_msg = format!("Failed to connect to ws://{}/ws: {}", host, e);
@@ -166,6 +185,32 @@ fn route_ws_message(
                 })
                 .map_err(|e| format!("Failed to send PermissionRequested: {}", e))
         }
+        WsIncomingMessage::PermissionResolved(resolved) => {
+            info!(
+                "Received permission resolved: id={}, thread={:?}, allowed={:?}",
+                resolved.request_id, resolved.thread_id, resolved.allowed
+            );
+            message_tx
+                .send(AppMessage::PermissionResolved {
+                    permission_id: resolved.request_id,
+                    allowed: resolved.allowed,
+                })
+                .map_err(|e| format!("Failed to send PermissionResolved: {}", e))
+        }
+        WsIncomingMessage::PresenceUpdate(update) => {
+            debug!(
+                "Received presence update: thread={}, client={} ({}), state={:?}",
+                update.thread_id, update.client_id, update.client_kind, update.state
+            );
+            message_tx
+                .send(AppMessage::PresenceUpdated {
+                    thread_id: update.thread_id,
+                    client_id: update.client_id,
+                    client_kind: update.client_kind,
+                    state: update.state,
+                })
+                .map_err(|e| format!("Failed to send PresenceUpdated: {}", e))
+        }
         WsIncomingMessage::AgentStatus(status) => {
             // Agent status updates are routed to dashboard state
             info!(
@@ -293,6 +338,36 @@ fn route_ws_message(
                 })
                 .map_err(|e| format!("Failed to send ThreadMetadataUpdated: {}", e))
         }
+        WsIncomingMessage::ThreadDeleted(deleted) => {
+            // Thread deleted (possibly from another client)
+            info!("Received thread deleted: thread={}", deleted.thread_id);
+            message_tx
+                .send(AppMessage::ThreadDeleted {
+                    thread_id: deleted.thread_id,
+                })
+                .map_err(|e| format!("Failed to send ThreadDeleted: {}", e))
+        }
+        WsIncomingMessage::TaskUpdated(update) => {
+            debug!(
+                "Received task update: task={}, status={:?}",
+                update.task.id, update.task.status
+            );
+            message_tx
+                .send(AppMessage::TaskUpdated { task: update.task })
+                .map_err(|e| format!("Failed to send TaskUpdated: {}", e))
+        }
+        WsIncomingMessage::EditReverted(reverted) => {
+            info!(
+                "Received edit reverted: thread={}, count={}, paths={:?}",
+                reverted.thread_id, reverted.count, reverted.paths
+            );
+            message_tx
+                .send(AppMessage::EditsReverted {
+                    thread_id: reverted.thread_id,
+                    count: reverted.count,
+                })
+                .map_err(|e| format!("Failed to send EditsReverted: {}", e))
+        }
         WsIncomingMessage::SystemMetricsUpdate(metrics) => {
             // System metrics update - convert MB to GB for SystemStats
             let stats = SystemStats::new(
@@ -376,6 +451,28 @@ fn route_ws_message(
         WsIncomingMessage::ParseError { error, raw } => message_tx
             .send(AppMessage::WsParseError { error, raw })
             .map_err(|e| format!("Failed to send WsParseError: {}", e)),
+        // Acks are consumed by the connection loop
+        WsIncomingMessage::Ack(_) => Ok(()),
+        WsIncomingMessage::Disconnected { reason } => message_tx
+            .send(AppMessage::WsConnectionLost { reason })
+            .map_err(|e| format!("Failed to send WsConnectionLost: {}", e)),
+        WsIncomingMessage::PendingAcks(count) => message_tx
+            .send(AppMessage::WsPendingAcks(count))
+            .map_err(|e| format!("Failed to send WsPendingAcks: {}", e)),
+        WsIncomingMessage::Retransmitted {
+            message_id,
+            kind,
+            attempt,
+        } => message_tx
+            .send(AppMessage::WsRetransmitted {
+                message_id,
+                kind,
+                attempt,
+            })
+            .map_err(|e| format!("Failed to send WsRetransmitted: {}", e)),
+        WsIncomingMessage::Undelivered { message_id, kind } => message_tx
+            .send(AppMessage::WsUndelivered { message_id, kind })
+            .map_err(|e| format!("Failed to send WsUndelivered: {}", e)),
         WsIncomingMessage::SteeringQueued(queued) => {
             info!(
                 "Steering message queued for thread: {}",
@@ -694,6 +791,101 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_route_thread_deleted() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let ws_msg = WsIncomingMessage::ThreadDeleted(crate::websocket::messages::WsThreadDeleted {
+            thread_id: "thread-789".to_string(),
+            timestamp: 1705315800000,
+        });
+
+        let result = route_ws_message(ws_msg, &tx);
+        assert!(result.is_ok());
+
+        match rx.try_recv().unwrap() {
+            AppMessage::ThreadDeleted { thread_id } => assert_eq!(thread_id, "thread-789"),
+            other => panic!("Expected ThreadDeleted, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_route_edit_reverted() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let ws_msg = WsIncomingMessage::EditReverted(crate::websocket::messages::WsEditReverted {
+            thread_id: "thread-789".to_string(),
+            count: 2,
+            paths: vec!["src/main.rs".to_string()],
+            timestamp: 1705315800000,
+        });
+
+        assert!(route_ws_message(ws_msg, &tx).is_ok());
+        match rx.try_recv().unwrap() {
+            AppMessage::EditsReverted { thread_id, count } => {
+                assert_eq!(thread_id, "thread-789");
+                assert_eq!(count, 2);
+            }
+            other => panic!("Expected EditsReverted, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_route_permission_resolved() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let ws_msg = WsIncomingMessage::PermissionResolved(crate::websocket::WsPermissionResolved {
+            request_id: "perm-123".to_string(),
+            thread_id: Some("thread-456".to_string()),
+            allowed: Some(false),
+            timestamp: 1705315800000,
+        });
+
+        let result = route_ws_message(ws_msg, &tx);
+        assert!(result.is_ok());
+
+        match rx.try_recv().unwrap() {
+            AppMessage::PermissionResolved {
+                permission_id,
+                allowed,
+            } => {
+                assert_eq!(permission_id, "perm-123");
+                assert_eq!(allowed, Some(false));
+            }
+            other => panic!("Expected PermissionResolved, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_route_presence_update() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let ws_msg = WsIncomingMessage::PresenceUpdate(crate::websocket::WsPresenceUpdate {
+            thread_id: "thread-456".to_string(),
+            client_id: "web-1".to_string(),
+            client_kind: "web".to_string(),
+            state: crate::websocket::WsPresenceState::Typing,
+            timestamp: 1705315800000,
+        });
+
+        let result = route_ws_message(ws_msg, &tx);
+        assert!(result.is_ok());
+
+        match rx.try_recv().unwrap() {
+            AppMessage::PresenceUpdated {
+                thread_id,
+                client_kind,
+                state,
+                ..
+            } => {
+                assert_eq!(thread_id, "thread-456");
+                assert_eq!(client_kind, "web");
+                assert_eq!(state, crate::websocket::WsPresenceState::Typing);
+            }
+            other => panic!("Expected PresenceUpdated, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_start_websocket_connection_failure() {
         // Try to connect to a non-existent server
@@ -704,6 +896,8 @@ mod tests {
             max_backoff_secs: 1,
             auth_token: None,
             use_tls: false,
+            ack_timeout_secs: 5,
+            ack_max_attempts: 5,
         };
 
         let result = start_websocket_with_config(tx, config).await;
@@ -0,0 +1,116 @@
+//! "What's new" overlay for the App.
+//!
+//! The background update check stores the release notes of the update it
+//! downloads. On the first launch of that version the notes are shown once in
+//! an overlay; the shown version is recorded in `~/.spoq/update_state.json`
+//! before the overlay opens, so it never reappears, even after a crash.
+
+use std::time::Duration;
+
+use crate::cli::VERSION;
+use crate::update::UpdateStateManager;
+
+use super::{App, WhatsNewState};
+
+/// How long the "Couldn't open browser" error stays visible
+const BROWSER_ERROR_DURATION: Duration = Duration::from_secs(5);
+
+/// URL of the full release notes for `version` on GitHub
+pub fn release_notes_url(version: &str) -> String {
+    format!(
+        "{}/releases/tag/v{}",
+        env!("CARGO_PKG_REPOSITORY"),
+        version.trim_start_matches('v')
+    )
+}
+
+impl App {
+    /// Open "What's new" if this is the first launch after an update.
+    ///
+    /// Called once at startup.
+    pub fn load_whats_new(&mut self) {
+        let Some(manager) = UpdateStateManager::new() else {
+            return;
+        };
+        let mut state = manager.load();
+        let Some(notes) = state.unseen_release_notes(VERSION).map(str::to_string) else {
+            return;
+        };
+
+        state.mark_changelog_shown(VERSION);
+        if !manager.save(&state) {
+            tracing::warn!("Failed to record shown changelog for {}", VERSION);
+        }
+        self.show_whats_new(VERSION.to_string(), notes);
+    }
+
+    /// Show the "What's new" overlay for `version`
+    pub fn show_whats_new(&mut self, version: String, notes: String) {
+        self.whats_new = Some(WhatsNewState {
+            version,
+            notes,
+            scroll: 0,
+        });
+        self.mark_dirty();
+    }
+
+    /// Scroll the release notes by `delta` lines
+    pub fn scroll_whats_new(&mut self, delta: i32) {
+        if let Some(whats_new) = self.whats_new.as_mut() {
+            let max_scroll = whats_new.notes.lines().count().saturating_sub(1) as i32;
+            whats_new.scroll = (whats_new.scroll as i32 + delta).clamp(0, max_scroll) as u16;
+        }
+        self.mark_dirty();
+    }
+
+    /// Open the full release notes in the browser
+    pub fn open_release_notes(&mut self) {
+        let Some(whats_new) = self.whats_new.as_ref() else {
+            return;
+        };
+        let url = release_notes_url(&whats_new.version);
+        if let Err(e) = open::that(&url) {
+            self.set_timed_error(
+                format!("Couldn't open browser: {} ({})", e, url),
+                BROWSER_ERROR_DURATION,
+            );
+        }
+    }
+
+    /// Close the "What's new" overlay
+    pub fn close_whats_new(&mut self) {
+        self.whats_new = None;
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_notes_url() {
+        assert_eq!(
+            release_notes_url("0.4.0"),
+            "https://github.com/AWLSEN/spoq-tui/releases/tag/v0.4.0"
+        );
+        assert_eq!(
+            release_notes_url("v0.4.0"),
+            "https://github.com/AWLSEN/spoq-tui/releases/tag/v0.4.0"
+        );
+    }
+
+    #[test]
+    fn test_whats_new_scroll_is_clamped() {
+        let mut app = App::default();
+        app.show_whats_new("0.4.0".to_string(), "one\ntwo\nthree".to_string());
+
+        app.scroll_whats_new(-1);
+        assert_eq!(app.whats_new.as_ref().unwrap().scroll, 0);
+        app.scroll_whats_new(10);
+        assert_eq!(app.whats_new.as_ref().unwrap().scroll, 2);
+
+        app.close_whats_new();
+        assert!(app.whats_new.is_none());
+    }
+}
@@ -0,0 +1,292 @@
+//! Changing the active thread's working directory.
+//!
+//! `/cd path` changes it directly; `/cd` alone, or Ctrl+G (`ChangeDirectory`
+//! in the keymap), opens the unified picker scoped to folders and local
+//! repos. The change is saved on the backend first and only then applied to
+//! the cache, with a system notice in the conversation, since later turns run
+//! in whatever directory the backend has. Changing directory while a response
+//! streams is refused.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::models::MessageRole;
+
+use super::{App, AppMessage, Screen};
+
+/// How long the "Working directory changed" confirmation stays visible
+const CD_NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+/// How long a refused or failed change is shown
+const CD_ERROR_DURATION: Duration = Duration::from_secs(4);
+
+impl App {
+    /// `/cd [path]`: change directly, or open the folder picker
+    pub fn change_directory_command(&mut self, arg: &str) {
+        let arg = arg.trim();
+        if arg.is_empty() {
+            self.open_directory_picker();
+        } else {
+            self.change_working_directory(&expand_home(arg));
+        }
+    }
+
+    /// Open the unified picker to pick the active thread's working directory
+    pub fn open_directory_picker(&mut self) {
+        if self.working_directory_target().is_none() {
+            return;
+        }
+        self.open_unified_picker();
+        self.unified_picker.changing_directory = true;
+        self.unified_picker.apply_query();
+        self.mark_dirty();
+    }
+
+    /// Handle a key while the picker is choosing a working directory.
+    ///
+    /// Unlike the @ picker the query isn't typed into the input, so Esc and
+    /// Backspace leave the textarea alone.
+    pub fn handle_directory_picker_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.unified_picker.close(),
+            KeyCode::Enter => {
+                // Browsing directories: Enter opens a subdirectory
+                if self.unified_picker_descend() {
+                    return;
+                }
+                let Some(path) = self
+                    .unified_picker
+                    .selected_item()
+                    .and_then(|item| item.working_directory())
+                    .map(str::to_string)
+                else {
+                    self.unified_picker.validation_error = Some(
+                        "Only folders and cloned repos can be the working directory".to_string(),
+                    );
+                    self.mark_dirty();
+                    return;
+                };
+                self.unified_picker.close();
+                self.change_working_directory(&path);
+            }
+            KeyCode::Backspace => {
+                let emptied = !self.unified_picker_ascend() && self.unified_picker_backspace();
+                if emptied {
+                    self.unified_picker.close();
+                }
+            }
+            KeyCode::Up => self.unified_picker_move_up(),
+            KeyCode::Down => self.unified_picker_move_down(),
+            KeyCode::F(5) => self.refresh_picker_data(),
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.refresh_picker_data()
+            }
+            KeyCode::Char(c)
+                if !key.modifiers.intersects(
+                    KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER,
+                ) =>
+            {
+                self.unified_picker_type_char(c)
+            }
+            _ => {}
+        }
+        self.mark_dirty();
+    }
+
+    /// Save `path` as the active thread's working directory on the backend
+    pub fn change_working_directory(&mut self, path: &str) {
+        let Some(thread_id) = self.working_directory_target() else {
+            return;
+        };
+        let current = self
+            .cache
+            .get_thread(&thread_id)
+            .and_then(|t| t.working_directory.as_deref());
+        if current == Some(path) {
+            self.set_status_notice(format!("Already in {}", path), CD_NOTICE_DURATION);
+            return;
+        }
+
+        let tx = self.message_tx.clone();
+        let client = Arc::clone(&self.client);
+        let path = path.to_string();
+        tokio::spawn(async move {
+            let message = match client
+                .update_thread_working_directory(&thread_id, &path)
+                .await
+            {
+                Ok(()) => AppMessage::ThreadWorkingDirectoryChanged { thread_id, path },
+                Err(e) => AppMessage::ThreadWorkingDirectoryUpdateFailed {
+                    thread_id,
+                    path,
+                    error: e.to_string(),
+                },
+            };
+            let _ = tx.send(message);
+        });
+    }
+
+    /// Apply a working directory the backend accepted
+    pub(super) fn apply_working_directory_change(&mut self, thread_id: &str, path: &str) {
+        self.cache.set_thread_working_directory(thread_id, path);
+        self.cache.add_message_simple(
+            thread_id,
+            MessageRole::System,
+            format!("Working directory changed to {}", path),
+        );
+        if self.active_thread_id.as_deref() == Some(thread_id) {
+            self.set_status_notice(format!("Now in {}", path), CD_NOTICE_DURATION);
+        }
+        self.mark_dirty();
+    }
+
+    /// Report a working directory the backend refused
+    pub(super) fn fail_working_directory_change(&mut self, path: &str, error: &str) {
+        self.set_timed_error(
+            format!("Couldn't change directory to {}: {}", path, error),
+            CD_ERROR_DURATION,
+        );
+    }
+
+    /// The active thread, if its directory can be changed now; otherwise
+    /// shows why not
+    fn working_directory_target(&mut self) -> Option<String> {
+        let Some(thread_id) = self
+            .active_thread_id
+            .clone()
+            .filter(|_| self.screen == Screen::Conversation)
+        else {
+            self.set_timed_error(
+                "Open a thread to change its directory".to_string(),
+                CD_ERROR_DURATION,
+            );
+            return None;
+        };
+        if self.cache.is_thread_streaming(&thread_id) {
+            self.set_timed_error(
+                "Wait for the response to finish before changing directory".to_string(),
+                CD_ERROR_DURATION,
+            );
+            return None;
+        }
+        Some(thread_id)
+    }
+}
+
+/// Expand a leading `~` to the home directory
+fn expand_home(path: &str) -> String {
+    let rest = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => rest,
+        _ => return path.to_string(),
+    };
+    match dirs::home_dir() {
+        Some(home) => format!("{}{}", home.display(), rest),
+        None => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+
+    fn app_in_thread() -> App {
+        let mut app = App::default();
+        app.cache = ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app
+    }
+
+    #[test]
+    fn test_expand_home() {
+        let home = dirs::home_dir().unwrap().display().to_string();
+        assert_eq!(expand_home("~"), home);
+        assert_eq!(expand_home("~/code"), format!("{}/code", home));
+        assert_eq!(expand_home("~other/code"), "~other/code");
+        assert_eq!(expand_home("/srv/app"), "/srv/app");
+    }
+
+    #[test]
+    fn test_applied_change_updates_cache_and_adds_notice() {
+        let mut app = app_in_thread();
+        app.handle_message(AppMessage::ThreadWorkingDirectoryChanged {
+            thread_id: "thread-001".to_string(),
+            path: "/srv/app".to_string(),
+        });
+
+        let thread = app.cache.get_thread("thread-001").unwrap();
+        assert_eq!(thread.working_directory.as_deref(), Some("/srv/app"));
+        let last = app
+            .cache
+            .get_messages("thread-001")
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_eq!(last.role, MessageRole::System);
+        assert_eq!(last.content, "Working directory changed to /srv/app");
+    }
+
+    #[test]
+    fn test_failed_change_keeps_directory() {
+        let mut app = app_in_thread();
+        let before = app
+            .cache
+            .get_thread("thread-001")
+            .unwrap()
+            .working_directory
+            .clone();
+        app.handle_message(AppMessage::ThreadWorkingDirectoryUpdateFailed {
+            thread_id: "thread-001".to_string(),
+            path: "/nope".to_string(),
+            error: "No such directory".to_string(),
+        });
+
+        assert_eq!(
+            app.cache
+                .get_thread("thread-001")
+                .unwrap()
+                .working_directory,
+            before
+        );
+        assert!(app
+            .stream_error
+            .as_deref()
+            .is_some_and(|e| e.contains("/nope")));
+    }
+
+    #[test]
+    fn test_change_refused_while_streaming() {
+        let mut app = app_in_thread();
+        app.cache
+            .add_streaming_message("thread-001", "hi".to_string(), Vec::new());
+
+        app.open_directory_picker();
+        assert!(!app.unified_picker.visible);
+        assert!(app
+            .stream_error
+            .as_deref()
+            .is_some_and(|e| e.contains("Wait for the response")));
+    }
+
+    #[tokio::test]
+    async fn test_picker_leaves_input_alone() {
+        let mut app = app_in_thread();
+        app.textarea.set_content("draft");
+        app.open_directory_picker();
+        assert!(app.unified_picker.visible);
+        assert!(app.unified_picker.changing_directory);
+
+        for c in "src".chars() {
+            app.handle_directory_picker_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(app.unified_picker.query, "src");
+        app.handle_directory_picker_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        assert!(!app.unified_picker.visible);
+        assert!(!app.unified_picker.changing_directory);
+        assert_eq!(app.textarea.content(), "draft");
+    }
+}
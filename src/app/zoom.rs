@@ -0,0 +1,72 @@
+//! Conversation zoom levels for the App.
+//!
+//! `z` (rebindable as `CycleZoom`) cycles the active thread between showing
+//! everything, tool summaries and answers only. The level is remembered per
+//! thread; the message list is filtered by `ui::messages::zoom` before heights
+//! are calculated, so changing it drops the thread's cached layout.
+
+use std::time::Duration;
+
+use super::{App, ZoomLevel};
+
+/// How long the "Zoom: ..." notice stays visible
+const ZOOM_NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+impl App {
+    /// Zoom level of the active thread
+    pub fn zoom_level(&self) -> ZoomLevel {
+        self.active_thread_id
+            .as_ref()
+            .and_then(|thread_id| self.thread_zoom.get(thread_id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Cycle the active thread's zoom level
+    pub fn cycle_zoom(&mut self) {
+        let Some(thread_id) = self.active_thread_id.clone() else {
+            return;
+        };
+        let level = self.zoom_level().next();
+        if level == ZoomLevel::Full {
+            self.thread_zoom.remove(&thread_id);
+        } else {
+            self.thread_zoom.insert(thread_id.clone(), level);
+        }
+
+        // Cached lines and heights were laid out for the previous level
+        self.rendered_lines_cache.remove_thread(&thread_id);
+        self.height_cache = None;
+
+        self.set_status_notice(
+            format!("Zoom {}: {}", level.level(), level.label()),
+            ZOOM_NOTICE_DURATION,
+        );
+        self.mark_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_zoom_is_remembered_per_thread() {
+        let mut app = App::default();
+        app.active_thread_id = Some("thread-1".to_string());
+        assert_eq!(app.zoom_level(), ZoomLevel::Full);
+
+        app.cycle_zoom();
+        assert_eq!(app.zoom_level(), ZoomLevel::ToolSummaries);
+        app.cycle_zoom();
+        assert_eq!(app.zoom_level(), ZoomLevel::Answers);
+
+        app.active_thread_id = Some("thread-2".to_string());
+        assert_eq!(app.zoom_level(), ZoomLevel::Full);
+
+        app.active_thread_id = Some("thread-1".to_string());
+        app.cycle_zoom();
+        assert_eq!(app.zoom_level(), ZoomLevel::Full);
+        assert!(app.thread_zoom.is_empty());
+    }
+}
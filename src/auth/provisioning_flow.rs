@@ -1849,6 +1849,7 @@ fn run_local_conductor_flow(
     let config = crate::startup::config::SpoqConfig {
         conductor_mode: "local".to_string(),
         conductor_url: Some(format!("http://127.0.0.1:{}", port)),
+        ..crate::startup::config::SpoqConfig::load()
     };
     config.save().map_err(|e| CentralApiError::ServerError {
         status: 0,
@@ -1,9 +1,23 @@
 //! Error management methods for ThreadCache
 
+use std::time::{Duration, Instant};
+
 use crate::models::ErrorInfo;
 
 use super::ThreadCache;
 
+/// Dismissed errors kept per thread for undo
+pub const MAX_DISMISSED_ERRORS: usize = 10;
+
+/// An error removed with `d`, kept so it can be restored
+#[derive(Debug, Clone)]
+pub struct DismissedError {
+    pub error: ErrorInfo,
+    /// ID of the error that followed it, to put it back in the same place
+    pub next_id: Option<String>,
+    pub dismissed_at: Instant,
+}
+
 impl ThreadCache {
     /// Add an error to a thread's error list
     pub fn add_error(&mut self, thread_id: &str, error: ErrorInfo) {
@@ -58,17 +72,71 @@ impl ThreadCache {
         let resolved_id = self.resolve_thread_id(thread_id).to_string();
         if let Some(errors) = self.errors.get_mut(&resolved_id) {
             if self.focused_error_index < errors.len() {
-                errors.remove(self.focused_error_index);
+                let error = errors.remove(self.focused_error_index);
+                let next_id = errors.get(self.focused_error_index).map(|e| e.id.clone());
                 // Adjust focused index
                 if self.focused_error_index >= errors.len() && !errors.is_empty() {
                     self.focused_error_index = errors.len() - 1;
                 }
+
+                let dismissed = self.dismissed_errors.entry(resolved_id).or_default();
+                dismissed.push(DismissedError {
+                    error,
+                    next_id,
+                    dismissed_at: Instant::now(),
+                });
+                if dismissed.len() > MAX_DISMISSED_ERRORS {
+                    dismissed.remove(0);
+                }
                 return true;
             }
         }
         false
     }
 
+    /// Put the most recently dismissed error of a thread back where it was
+    /// and focus it.
+    ///
+    /// Errors dismissed longer than `ttl` ago are dropped instead. Returns
+    /// true if an error was restored.
+    pub fn restore_dismissed_error(&mut self, thread_id: &str, ttl: Duration) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        let Some(dismissed) = self.dismissed_errors.get_mut(&resolved_id) else {
+            return false;
+        };
+        dismissed.retain(|d| d.dismissed_at.elapsed() < ttl);
+        let Some(restored) = dismissed.pop() else {
+            self.dismissed_errors.remove(&resolved_id);
+            return false;
+        };
+
+        let errors = self.errors.entry(resolved_id).or_default();
+        // Before the error that followed it; if that one is gone too, by
+        // timestamp so the order stays the same as before the dismissal
+        let index = restored
+            .next_id
+            .as_ref()
+            .and_then(|next| errors.iter().position(|e| &e.id == next))
+            .unwrap_or_else(|| errors.partition_point(|e| e.timestamp <= restored.error.timestamp));
+        errors.insert(index, restored.error);
+        self.focused_error_index = index;
+        true
+    }
+
+    /// Number of dismissed errors a thread can still restore
+    pub fn dismissed_error_count(&self, thread_id: &str) -> usize {
+        let resolved_id = self.resolve_thread_id(thread_id);
+        self.dismissed_errors
+            .get(resolved_id)
+            .map_or(0, |d| d.len())
+    }
+
+    /// Drop a thread's dismissed errors (when the thread is closed)
+    pub fn forget_dismissed_errors(&mut self, thread_id: &str) {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        self.dismissed_errors.remove(&resolved_id);
+    }
+
     /// Clear all errors for a thread
     pub fn clear_errors(&mut self, thread_id: &str) {
         let resolved_id = self.resolve_thread_id(thread_id).to_string();
@@ -376,4 +444,98 @@ mod tests {
         let dismissed = cache.dismiss_focused_error(&thread_id);
         assert!(!dismissed);
     }
+
+    #[test]
+    fn test_restore_dismissed_error_puts_it_back_in_place() {
+        let mut cache = ThreadCache::new();
+        let thread_id = cache.create_streaming_thread("Hello".to_string());
+
+        cache.add_error_simple(&thread_id, "error1".to_string(), "First".to_string());
+        cache.add_error_simple(&thread_id, "error2".to_string(), "Second".to_string());
+        cache.add_error_simple(&thread_id, "error3".to_string(), "Third".to_string());
+
+        cache.set_focused_error_index(1);
+        assert!(cache.dismiss_focused_error(&thread_id));
+        assert_eq!(cache.dismissed_error_count(&thread_id), 1);
+
+        assert!(cache.restore_dismissed_error(&thread_id, Duration::from_secs(60)));
+        let codes: Vec<&str> = cache
+            .get_errors(&thread_id)
+            .unwrap()
+            .iter()
+            .map(|e| e.error_code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["error1", "error2", "error3"]);
+        assert_eq!(cache.focused_error_index(), 1);
+        assert_eq!(cache.dismissed_error_count(&thread_id), 0);
+    }
+
+    #[test]
+    fn test_restore_dismissed_errors_in_reverse_order() {
+        let mut cache = ThreadCache::new();
+        let thread_id = cache.create_streaming_thread("Hello".to_string());
+
+        cache.add_error_simple(&thread_id, "error1".to_string(), "First".to_string());
+        cache.add_error_simple(&thread_id, "error2".to_string(), "Second".to_string());
+        cache.add_error_simple(&thread_id, "error3".to_string(), "Third".to_string());
+
+        // Dismiss error1, then error2 (which moved into focus)
+        cache.dismiss_focused_error(&thread_id);
+        cache.dismiss_focused_error(&thread_id);
+
+        let ttl = Duration::from_secs(60);
+        assert!(cache.restore_dismissed_error(&thread_id, ttl));
+        assert!(cache.restore_dismissed_error(&thread_id, ttl));
+        assert!(!cache.restore_dismissed_error(&thread_id, ttl));
+
+        let codes: Vec<&str> = cache
+            .get_errors(&thread_id)
+            .unwrap()
+            .iter()
+            .map(|e| e.error_code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["error1", "error2", "error3"]);
+        assert_eq!(cache.focused_error_index(), 0);
+    }
+
+    #[test]
+    fn test_dismissed_errors_are_capped() {
+        let mut cache = ThreadCache::new();
+        let thread_id = cache.create_streaming_thread("Hello".to_string());
+
+        for i in 0..MAX_DISMISSED_ERRORS + 3 {
+            cache.add_error_simple(&thread_id, format!("error{}", i), "Error".to_string());
+            cache.dismiss_focused_error(&thread_id);
+        }
+
+        assert_eq!(
+            cache.dismissed_error_count(&thread_id),
+            MAX_DISMISSED_ERRORS
+        );
+    }
+
+    #[test]
+    fn test_expired_dismissed_errors_are_not_restored() {
+        let mut cache = ThreadCache::new();
+        let thread_id = cache.create_streaming_thread("Hello".to_string());
+
+        cache.add_error_simple(&thread_id, "error1".to_string(), "First".to_string());
+        cache.dismiss_focused_error(&thread_id);
+
+        assert!(!cache.restore_dismissed_error(&thread_id, Duration::ZERO));
+        assert_eq!(cache.error_count(&thread_id), 0);
+        assert_eq!(cache.dismissed_error_count(&thread_id), 0);
+    }
+
+    #[test]
+    fn test_forget_dismissed_errors() {
+        let mut cache = ThreadCache::new();
+        let thread_id = cache.create_streaming_thread("Hello".to_string());
+
+        cache.add_error_simple(&thread_id, "error1".to_string(), "First".to_string());
+        cache.dismiss_focused_error(&thread_id);
+        cache.forget_dismissed_errors(&thread_id);
+
+        assert!(!cache.restore_dismissed_error(&thread_id, Duration::from_secs(60)));
+    }
 }
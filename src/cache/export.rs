@@ -0,0 +1,403 @@
+//! Thread export
+//!
+//! Serializes a thread's metadata and messages — including tool events,
+//! subagent summaries and reasoning — to Markdown or pretty-printed JSON so
+//! conversations can be archived outside the app.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::models::{
+    Message, MessageRole, MessageSegment, SubagentEvent, SubagentEventStatus, Thread, ToolEvent,
+    ToolEventStatus,
+};
+
+use super::ThreadCache;
+
+/// Output format for thread exports
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Markdown with role headers and fenced code preserved
+    #[default]
+    Markdown,
+    /// Pretty-printed JSON of the thread and its messages
+    Json,
+}
+
+impl ExportFormat {
+    /// Parse a `--format` value ("md", "markdown" or "json")
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "md" | "markdown" => Some(Self::Markdown),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// File extension for this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// JSON export document
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    thread_id: &'a str,
+    exported_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thread: Option<&'a Thread>,
+    messages: &'a [Message],
+}
+
+impl ThreadCache {
+    /// Export a cached thread.
+    ///
+    /// Returns None if no messages are cached for the thread.
+    pub fn export_thread(&self, thread_id: &str, format: ExportFormat) -> Option<String> {
+        // A pending ID may have been reconciled since the export was requested
+        let thread_id = self.resolve_thread_id(thread_id);
+        let messages = self.messages.get(thread_id)?;
+        Some(render_export(
+            thread_id,
+            self.get_thread(thread_id),
+            messages,
+            format,
+        ))
+    }
+}
+
+/// Serialize a thread and its messages in the given format.
+pub fn render_export(
+    thread_id: &str,
+    thread: Option<&Thread>,
+    messages: &[Message],
+    format: ExportFormat,
+) -> String {
+    match format {
+        ExportFormat::Markdown => render_markdown(thread_id, thread, messages),
+        ExportFormat::Json => {
+            let doc = JsonExport {
+                thread_id,
+                exported_at: Utc::now().to_rfc3339(),
+                thread,
+                messages,
+            };
+            // Serializing plain data structures cannot fail
+            serde_json::to_string_pretty(&doc).unwrap_or_default()
+        }
+    }
+}
+
+/// Default file name for an export (`spoq-<thread id prefix>.<ext>`)
+pub fn default_export_file_name(thread_id: &str, format: ExportFormat) -> String {
+    let short_id: String = thread_id
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .take(12)
+        .collect();
+    format!("spoq-{}.{}", short_id, format.extension())
+}
+
+/// Directory the TUI writes exports to (`~/.spoq/exports`)
+pub fn default_export_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".spoq").join("exports"))
+}
+
+/// Write an export, creating parent directories as needed.
+pub fn write_export(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+fn render_markdown(thread_id: &str, thread: Option<&Thread>, messages: &[Message]) -> String {
+    let mut out = String::new();
+
+    let title = thread
+        .map(|t| t.title.as_str())
+        .filter(|t| !t.is_empty())
+        .unwrap_or("Untitled thread");
+    out.push_str(&format!("# {}\n\n", title));
+    out.push_str(&format!("- **Thread:** `{}`\n", thread_id));
+    if let Some(thread) = thread {
+        if let Some(description) = thread.description.as_deref().filter(|d| !d.is_empty()) {
+            out.push_str(&format!("- **Description:** {}\n", description));
+        }
+        out.push_str(&format!("- **Type:** {:?}\n", thread.thread_type));
+        if let Some(model) = &thread.model {
+            out.push_str(&format!("- **Model:** {}\n", model));
+        }
+        if let Some(dir) = &thread.working_directory {
+            out.push_str(&format!("- **Working directory:** `{}`\n", dir));
+        }
+        out.push_str(&format!(
+            "- **Created:** {}\n",
+            thread.created_at.format("%Y-%m-%d %H:%M UTC")
+        ));
+    }
+    out.push_str(&format!("- **Messages:** {}\n", messages.len()));
+    out.push_str(&format!(
+        "- **Exported:** {}\n",
+        Utc::now().format("%Y-%m-%d %H:%M UTC")
+    ));
+
+    for message in messages {
+        out.push_str("\n---\n\n");
+        out.push_str(&format!(
+            "## {} · {}\n\n",
+            role_header(message.role),
+            message.created_at.format("%Y-%m-%d %H:%M UTC")
+        ));
+
+        if !message.reasoning_content.trim().is_empty() {
+            out.push_str("> **Reasoning**\n>\n");
+            for line in message.reasoning_content.trim_end().lines() {
+                if line.is_empty() {
+                    out.push_str(">\n");
+                } else {
+                    out.push_str(&format!("> {}\n", line));
+                }
+            }
+            out.push('\n');
+        }
+
+        if !message.image_hashes.is_empty() {
+            out.push_str(&format!(
+                "*Attached images: {}*\n\n",
+                message.image_hashes.join(", ")
+            ));
+        }
+
+        if message.role == MessageRole::Assistant && !message.segments.is_empty() {
+            for segment in &message.segments {
+                match segment {
+                    MessageSegment::Text(text) => push_block(&mut out, text),
+                    MessageSegment::ToolEvent(event) => push_block(&mut out, &tool_markdown(event)),
+                    MessageSegment::SubagentEvent(event) => {
+                        push_block(&mut out, &subagent_markdown(event))
+                    }
+                }
+            }
+        } else {
+            let content = if message.is_streaming {
+                &message.partial_content
+            } else {
+                &message.content
+            };
+            push_block(&mut out, content);
+        }
+    }
+
+    out
+}
+
+fn role_header(role: MessageRole) -> &'static str {
+    match role {
+        MessageRole::User => "User",
+        MessageRole::Assistant => "Assistant",
+        MessageRole::System => "System",
+        MessageRole::Tool => "Tool",
+    }
+}
+
+/// Append a block of markdown followed by a blank line
+fn push_block(out: &mut String, text: &str) {
+    let text = text.trim_end();
+    if text.is_empty() {
+        return;
+    }
+    out.push_str(text);
+    out.push_str("\n\n");
+}
+
+fn tool_markdown(event: &ToolEvent) -> String {
+    let name = event.display_name.as_deref().unwrap_or(&event.function_name);
+    let status = match event.status {
+        ToolEventStatus::Running => "running",
+        ToolEventStatus::Complete => "done",
+        ToolEventStatus::Failed => "failed",
+    };
+    let mut md = format!("**Tool:** `{}` ({}", name, status);
+    if let Some(secs) = event.duration_secs {
+        md.push_str(&format!(", {:.1}s", secs));
+    }
+    md.push(')');
+    if let Some(args) = event.args_display.as_deref().filter(|a| !a.is_empty()) {
+        md.push_str(&format!(" — {}", args));
+    }
+    if let Some(result) = event.result_preview.as_deref().filter(|r| !r.is_empty()) {
+        md.push('\n');
+        md.push_str(&fenced(result));
+    }
+    md
+}
+
+fn subagent_markdown(event: &SubagentEvent) -> String {
+    let status = match event.status {
+        SubagentEventStatus::Running => "running",
+        SubagentEventStatus::Complete => "done",
+    };
+    let mut md = format!(
+        "**Subagent ({}):** {} ({}, {} tool calls)",
+        event.subagent_type, event.description, status, event.tool_call_count
+    );
+    if let Some(summary) = event.summary.as_deref().filter(|s| !s.is_empty()) {
+        md.push('\n');
+        for line in summary.trim_end().lines() {
+            md.push_str(&format!("\n> {}", line));
+        }
+    }
+    md
+}
+
+/// Wrap text in a code fence longer than any backtick run it contains
+fn fenced(text: &str) -> String {
+    let longest_run = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    format!("{}text\n{}\n{}", fence, text.trim_end(), fence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_with_rich_thread() -> ThreadCache {
+        let mut cache = ThreadCache::with_stub_data();
+        cache.add_message_simple(
+            "thread-001",
+            MessageRole::User,
+            "Show me the fix".to_string(),
+        );
+        cache.add_message_simple(
+            "thread-001",
+            MessageRole::Assistant,
+            "Here:\n```rust\nfn main() {}\n```".to_string(),
+        );
+        let message = cache
+            .get_messages_mut("thread-001")
+            .unwrap()
+            .last_mut()
+            .unwrap();
+        message.reasoning_content = "Check main first".to_string();
+        message.segments = vec![
+            MessageSegment::Text("Here:\n```rust\nfn main() {}\n```".to_string()),
+            MessageSegment::ToolEvent({
+                let mut event = ToolEvent::new("t1".to_string(), "Bash".to_string());
+                event.status = ToolEventStatus::Complete;
+                event.result_preview = Some("ok ```".to_string());
+                event
+            }),
+            MessageSegment::SubagentEvent({
+                let mut event = SubagentEvent::new(
+                    "task-1".to_string(),
+                    "Search callers".to_string(),
+                    "Explore".to_string(),
+                );
+                event.summary = Some("Found 2 callers".to_string());
+                event
+            }),
+        ];
+        cache
+    }
+
+    #[test]
+    fn test_export_format_parse() {
+        assert_eq!(ExportFormat::parse("md"), Some(ExportFormat::Markdown));
+        assert_eq!(ExportFormat::parse("JSON"), Some(ExportFormat::Json));
+        assert_eq!(ExportFormat::parse("pdf"), None);
+        assert_eq!(ExportFormat::Json.extension(), "json");
+    }
+
+    #[test]
+    fn test_export_markdown() {
+        let cache = cache_with_rich_thread();
+
+        let md = cache
+            .export_thread("thread-001", ExportFormat::Markdown)
+            .unwrap();
+
+        assert!(md.starts_with("# "));
+        assert!(md.contains("- **Thread:** `thread-001`"));
+        assert!(md.contains("## User · "));
+        assert!(md.contains("## Assistant · "));
+        assert!(md.contains("```rust\nfn main() {}\n```"));
+        assert!(md.contains("> **Reasoning**\n>\n> Check main first"));
+        assert!(md.contains("**Tool:** `Bash` (done)"));
+        // Result containing a fence gets a longer fence
+        assert!(md.contains("````text\nok ```\n````"));
+        assert!(md.contains("**Subagent (Explore):** Search callers"));
+        assert!(md.contains("> Found 2 callers"));
+    }
+
+    #[test]
+    fn test_export_json_round_trips_messages() {
+        let cache = cache_with_rich_thread();
+
+        let json = cache
+            .export_thread("thread-001", ExportFormat::Json)
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["thread_id"], "thread-001");
+        let messages: Vec<Message> =
+            serde_json::from_value(value["messages"].clone()).unwrap();
+        assert_eq!(
+            &messages,
+            cache.get_messages("thread-001").unwrap()
+        );
+        assert!(json.contains('\n'), "JSON should be pretty-printed");
+    }
+
+    #[test]
+    fn test_export_resolves_reconciled_pending_id() {
+        let mut cache = ThreadCache::new();
+        let pending_id = cache.create_streaming_thread("Hello".to_string());
+        cache.reconcile_thread_id(&pending_id, "real-backend-id", None);
+
+        let md = cache
+            .export_thread(&pending_id, ExportFormat::Markdown)
+            .unwrap();
+
+        assert!(md.contains("- **Thread:** `real-backend-id`"));
+        assert!(md.contains("Hello"));
+    }
+
+    #[test]
+    fn test_export_unknown_thread() {
+        let cache = ThreadCache::new();
+        assert!(cache
+            .export_thread("missing", ExportFormat::Markdown)
+            .is_none());
+    }
+
+    #[test]
+    fn test_default_export_file_name() {
+        assert_eq!(
+            default_export_file_name("0d4c/../9f2a-4b1e-8c3d", ExportFormat::Markdown),
+            "spoq-0d4c9f2a-4b1.md"
+        );
+    }
+
+    #[test]
+    fn test_write_export_creates_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("out.md");
+
+        write_export(&path, "# Thread").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "# Thread");
+    }
+}
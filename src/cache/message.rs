@@ -13,6 +13,10 @@ use super::ThreadCache;
 pub const MSG_ID_USER_CANCELLED: i64 = -1;
 /// Sentinel message ID: stream interrupted by steering.
 pub const MSG_ID_STEERING_INTERRUPTED: i64 = -2;
+/// Sentinel message ID: stream dropped and could not be resumed.
+pub const MSG_ID_STREAM_INTERRUPTED: i64 = -3;
+/// Sentinel message ID: stream ended by an error or a backend-side cancel.
+pub const MSG_ID_STREAM_ABORTED: i64 = -4;
 
 impl ThreadCache {
     /// Get messages for a thread
@@ -37,11 +41,8 @@ impl ThreadCache {
     pub fn add_message_simple(&mut self, thread_id: &str, role: MessageRole, content: String) {
         let now = Utc::now();
 
-        // Generate a simple message ID based on existing count
-        let existing_count = self.messages.get(thread_id).map(|m| m.len()).unwrap_or(0);
-
         let message = Message {
-            id: (existing_count + 1) as i64,
+            id: self.next_message_id(thread_id),
             thread_id: thread_id.to_string(),
             role,
             content,
@@ -53,6 +54,7 @@ impl ThreadCache {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         self.add_message(message);
@@ -98,6 +100,46 @@ impl ThreadCache {
         self.messages.insert(thread_id, messages);
     }
 
+    /// ID for the next message added to a thread: its position in the whole
+    /// thread, counting messages that haven't been loaded yet
+    pub fn next_message_id(&self, thread_id: &str) -> i64 {
+        let loaded = self.messages.get(thread_id).map(|m| m.len()).unwrap_or(0);
+        (self.older_message_count(thread_id) + loaded) as i64 + 1
+    }
+
+    /// Number of messages before the oldest cached one that haven't been
+    /// loaded yet
+    pub fn older_message_count(&self, thread_id: &str) -> usize {
+        self.older_messages.get(thread_id).copied().unwrap_or(0)
+    }
+
+    /// Record how many messages precede the oldest cached one
+    pub fn set_older_message_count(&mut self, thread_id: &str, count: usize) {
+        if count == 0 {
+            self.older_messages.remove(thread_id);
+        } else {
+            self.older_messages.insert(thread_id.to_string(), count);
+        }
+    }
+
+    /// Prepend a page of older messages (oldest first) to a thread.
+    ///
+    /// Messages already cached are skipped. Returns the number prepended.
+    pub fn prepend_messages(&mut self, thread_id: &str, page: Vec<Message>) -> usize {
+        let existing = self.messages.entry(thread_id.to_string()).or_default();
+        let oldest_id = existing.iter().map(|m| m.id).filter(|id| *id > 0).min();
+        let older: Vec<Message> = page
+            .into_iter()
+            .filter(|m| oldest_id.is_none_or(|oldest| m.id < oldest))
+            .collect();
+        let count = older.len();
+        existing.splice(0..0, older);
+
+        let remaining = self.older_message_count(thread_id).saturating_sub(count);
+        self.set_older_message_count(thread_id, remaining);
+        count
+    }
+
     /// Add a new message exchange to an existing thread.
     ///
     /// Creates a user message and a streaming assistant placeholder.
@@ -117,12 +159,7 @@ impl ThreadCache {
 
         let now = Utc::now();
 
-        // Get the next message ID based on existing messages
-        let next_id = self
-            .messages
-            .get(thread_id)
-            .map(|m| m.len() as i64 + 1)
-            .unwrap_or(1);
+        let next_id = self.next_message_id(thread_id);
 
         // Add user message
         let user_message = Message {
@@ -138,6 +175,7 @@ impl ThreadCache {
             segments: Vec::new(),
             render_version: 0,
             image_hashes,
+            reply_to: None,
         };
         self.add_message(user_message);
 
@@ -155,6 +193,7 @@ impl ThreadCache {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         self.add_message(assistant_message);
 
@@ -171,6 +210,40 @@ impl ThreadCache {
         true
     }
 
+    /// Prepare to re-send the last user message in a thread.
+    ///
+    /// Removes every message after the last user message (the failed or
+    /// partial response) and adds a fresh streaming assistant placeholder.
+    ///
+    /// # Returns
+    /// The user message content, or `None` if the thread has no user message.
+    pub fn begin_retry(&mut self, thread_id: &str) -> Option<String> {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        let messages = self.messages.get_mut(&resolved_id)?;
+        let user_index = messages.iter().rposition(|m| m.role == MessageRole::User)?;
+        messages.truncate(user_index + 1);
+        let content = messages[user_index].content.clone();
+
+        let assistant_message = Message {
+            id: 0, // Will be updated with real ID from backend
+            thread_id: resolved_id,
+            role: MessageRole::Assistant,
+            content: String::new(),
+            created_at: Utc::now(),
+            is_streaming: true,
+            partial_content: String::new(),
+            reasoning_content: String::new(),
+            reasoning_collapsed: false,
+            segments: Vec::new(),
+            render_version: 0,
+            image_hashes: Vec::new(),
+            reply_to: None,
+        };
+        messages.push(assistant_message);
+
+        Some(content)
+    }
+
     /// Append a token to the streaming message in a thread
     /// Finds the last message with is_streaming=true and appends the token
     pub fn append_to_message(&mut self, thread_id: &str, token: &str) {
@@ -224,8 +297,9 @@ impl ThreadCache {
         }
     }
 
-    /// Cancel a streaming message (user pressed Ctrl+C).
+    /// Cancel a streaming message (stream error or backend-side cancel).
     /// Marks the message as no longer streaming and appends a cancellation indicator.
+    /// Ctrl+C goes through `cancel_stream_by_user` instead.
     pub fn cancel_streaming_message(&mut self, thread_id: &str) {
         // Resolve the thread_id in case it's a pending ID that was reconciled
         let resolved_id = self.resolve_thread_id(thread_id).to_string();
@@ -235,7 +309,7 @@ impl ThreadCache {
                 streaming_msg.is_streaming = false;
                 // Use a temporary ID for cancelled messages (negative to distinguish from real IDs)
                 if streaming_msg.id == 0 {
-                    streaming_msg.id = MSG_ID_USER_CANCELLED;
+                    streaming_msg.id = MSG_ID_STREAM_ABORTED;
                 }
                 // Append cancellation indicator to content
                 if !streaming_msg.content.is_empty() {
@@ -249,6 +323,24 @@ impl ThreadCache {
         }
     }
 
+    /// Drop a turn that never reached the backend: the streaming placeholder
+    /// and the user message just before it.
+    /// Returns false if the thread has no streaming message.
+    pub fn discard_unsent_turn(&mut self, thread_id: &str) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        let Some(messages) = self.messages.get_mut(&resolved_id) else {
+            return false;
+        };
+        let Some(index) = messages.iter().rposition(|m| m.is_streaming) else {
+            return false;
+        };
+        messages.remove(index);
+        if index > 0 && messages[index - 1].role == MessageRole::User {
+            messages.remove(index - 1);
+        }
+        true
+    }
+
     /// Mark the current streaming message as interrupted by steering.
     /// Preserves accumulated content and clears the streaming flag so
     /// is_thread_streaming() won't permanently block new messages.
@@ -304,6 +396,64 @@ impl ThreadCache {
             None
         }
     }
+
+    /// Toggle the tool output blocks for a specific message
+    /// Returns true if the message had tool output to toggle
+    pub fn toggle_message_tool_output(&mut self, thread_id: &str, message_index: usize) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+
+        if let Some(message) = self
+            .messages
+            .get_mut(&resolved_id)
+            .and_then(|messages| messages.get_mut(message_index))
+        {
+            if message.has_tool_output() {
+                message.toggle_tool_output();
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Expand or collapse the subagent tree of a specific message
+    /// Returns true if the message had a subagent tree to toggle
+    pub fn toggle_message_subagents(&mut self, thread_id: &str, message_index: usize) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+
+        if let Some(message) = self
+            .messages
+            .get_mut(&resolved_id)
+            .and_then(|messages| messages.get_mut(message_index))
+        {
+            if message.has_subagent_tree() {
+                message.toggle_subagents();
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Find the index of the last finished assistant message with a subagent tree
+    pub fn find_last_subagent_tree_message_index(&self, thread_id: &str) -> Option<usize> {
+        let resolved_id = self.resolve_thread_id(thread_id);
+
+        self.messages.get(resolved_id).and_then(|messages| {
+            messages.iter().rposition(|m| {
+                m.role == MessageRole::Assistant && !m.is_streaming && m.has_subagent_tree()
+            })
+        })
+    }
+
+    /// Find the index of the last assistant message with tool output
+    pub fn find_last_tool_output_message_index(&self, thread_id: &str) -> Option<usize> {
+        let resolved_id = self.resolve_thread_id(thread_id);
+
+        self.messages.get(resolved_id).and_then(|messages| {
+            messages
+                .iter()
+                .rposition(|m| m.role == MessageRole::Assistant && m.has_tool_output())
+        })
+    }
 }
 
 #[cfg(test)]
@@ -323,6 +473,43 @@ mod tests {
         assert_eq!(messages[1].role, MessageRole::Assistant);
     }
 
+    #[test]
+    fn test_begin_retry_drops_failed_response() {
+        let mut cache = ThreadCache::with_stub_data();
+        cache.add_streaming_message("thread-001", "Try again".to_string(), Vec::new());
+        cache.append_to_message("thread-001", "partial");
+        cache.cancel_streaming_message("thread-001");
+
+        let content = cache.begin_retry("thread-001");
+
+        assert_eq!(content.as_deref(), Some("Try again"));
+        let messages = cache.get_messages("thread-001").unwrap();
+        assert_eq!(messages.len(), 4);
+        assert_eq!(messages[2].content, "Try again");
+        let last = messages.last().unwrap();
+        assert_eq!(last.role, MessageRole::Assistant);
+        assert!(last.is_streaming);
+        assert!(last.partial_content.is_empty());
+    }
+
+    #[test]
+    fn test_discard_unsent_turn() {
+        let mut cache = ThreadCache::with_stub_data();
+        cache.add_streaming_message("thread-001", "Never sent".to_string(), Vec::new());
+
+        assert!(cache.discard_unsent_turn("thread-001"));
+        let messages = cache.get_messages("thread-001").unwrap();
+        assert_eq!(messages.len(), 2);
+        assert!(!cache.is_thread_streaming("thread-001"));
+        assert!(!cache.discard_unsent_turn("thread-001"));
+    }
+
+    #[test]
+    fn test_begin_retry_without_user_message() {
+        let mut cache = ThreadCache::new();
+        assert!(cache.begin_retry("missing").is_none());
+    }
+
     #[test]
     fn test_add_message() {
         let mut cache = ThreadCache::new();
@@ -340,6 +527,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         cache.add_message(message);
@@ -349,6 +537,33 @@ mod tests {
         assert_eq!(messages.unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_prepend_messages_skips_cached_and_counts_down() {
+        let mut cache = ThreadCache::new();
+        cache.set_older_message_count("thread-x", 3);
+        cache.add_message_simple("thread-x", MessageRole::User, "fourth".to_string());
+        assert_eq!(cache.get_messages("thread-x").unwrap()[0].id, 4);
+
+        let mut page: Vec<Message> = Vec::new();
+        for (id, content) in [(2, "second"), (3, "third"), (4, "fourth")] {
+            let mut message = cache.get_messages("thread-x").unwrap()[0].clone();
+            message.id = id;
+            message.content = content.to_string();
+            page.push(message);
+        }
+
+        assert_eq!(cache.prepend_messages("thread-x", page), 2);
+        let ids: Vec<i64> = cache
+            .get_messages("thread-x")
+            .unwrap()
+            .iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, vec![2, 3, 4]);
+        assert_eq!(cache.older_message_count("thread-x"), 1);
+        assert_eq!(cache.next_message_id("thread-x"), 5);
+    }
+
     #[test]
     fn test_set_messages_replaces() {
         let mut cache = ThreadCache::with_stub_data();
@@ -366,6 +581,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         }];
 
         cache.set_messages("thread-001".to_string(), new_messages);
@@ -933,6 +1149,29 @@ mod tests {
         assert!(idx.is_none());
     }
 
+    #[test]
+    fn test_toggle_message_tool_output() {
+        let mut cache = ThreadCache::new();
+        let thread_id = cache.create_streaming_thread("Hello".to_string());
+        cache.start_tool_in_message(&thread_id, "tool-1".to_string(), "Bash".to_string());
+        cache.finalize_message(&thread_id, 100);
+
+        // No output yet: nothing to toggle
+        assert!(!cache.toggle_message_tool_output(&thread_id, 1));
+        assert_eq!(cache.find_last_tool_output_message_index(&thread_id), None);
+
+        cache.set_tool_result(&thread_id, "tool-1", "ls: cannot access 'x'", true);
+        assert_eq!(
+            cache.find_last_tool_output_message_index(&thread_id),
+            Some(1)
+        );
+        assert!(cache.toggle_message_tool_output(&thread_id, 1));
+
+        let messages = cache.get_messages(&thread_id).unwrap();
+        let event = messages[1].get_tool_event("tool-1").unwrap();
+        assert!(event.output_expanded);
+    }
+
     // ============= set_messages Merge Tests (Race Condition Fix) =============
 
     #[test]
@@ -961,6 +1200,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         cache.upsert_thread(thread);
 
@@ -979,6 +1223,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         let streaming_assistant_msg = Message {
             id: 0, // Placeholder ID
@@ -993,6 +1238,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         cache.set_messages(
             thread_id.clone(),
@@ -1013,6 +1259,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         let historical_msg2 = Message {
             id: 2,
@@ -1027,6 +1274,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         // This is the critical call that would previously REPLACE all messages
@@ -1075,6 +1323,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         cache.upsert_thread(thread);
 
@@ -1093,6 +1346,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         cache.set_messages(thread_id.clone(), vec![streaming_msg]);
 
@@ -1124,6 +1378,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         }];
 
         cache.set_messages("thread-001".to_string(), new_messages);
@@ -1156,6 +1411,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         cache.upsert_thread(thread);
 
@@ -1175,6 +1435,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         cache.set_messages(thread_id.clone(), vec![local_msg]);
 
@@ -1192,6 +1453,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         cache.set_messages(thread_id.clone(), vec![backend_msg]);
 
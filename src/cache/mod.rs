@@ -3,12 +3,28 @@
 //! Provides local caching for threads and messages with eviction and reconciliation support.
 
 mod error;
+mod export;
 mod message;
+mod provisional_title;
 mod reconciliation;
+mod search;
+mod stream_cancel;
+mod stream_resume;
 mod thread;
 mod tools;
-
-use std::collections::HashMap;
+mod usage;
+
+pub use error::{DismissedError, MAX_DISMISSED_ERRORS};
+pub use export::{
+    default_export_dir, default_export_file_name, render_export, write_export, ExportFormat,
+};
+pub use message::MSG_ID_USER_CANCELLED;
+pub use provisional_title::{provisional_title, MAX_PROVISIONAL_TITLE_CHARS};
+pub use search::MessageMatch;
+pub(crate) use search::find_matches;
+pub use usage::TokenUsage;
+
+use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 
 use crate::models::{ErrorInfo, Message, Thread};
@@ -33,12 +49,27 @@ pub struct ThreadCache {
     /// Pending title updates for threads that haven't been reconciled yet.
     /// Maps thread_id → (title, description). These are applied after reconciliation.
     pub(crate) pending_title_updates: HashMap<String, (String, Option<String>)>,
+    /// Threads the user renamed; metadata updates no longer change their titles
+    pub(crate) renamed_threads: HashSet<String>,
+    /// Threads still titled from their prompt, mapped to that prompt
+    pub(crate) provisional_titles: HashMap<String, String>,
     /// Inline errors per thread (displayed as banners)
     pub(crate) errors: HashMap<String, Vec<ErrorInfo>>,
     /// Index of currently focused error (for dismiss with 'd' key)
     pub(crate) focused_error_index: usize,
+    /// Recently dismissed errors per thread, oldest first (undo with 'u')
+    pub(crate) dismissed_errors: HashMap<String, Vec<DismissedError>>,
     /// Last accessed time for each thread (for LRU eviction)
     pub(crate) last_accessed: HashMap<String, Instant>,
+    /// Resume bookkeeping for in-flight streams, indexed by thread ID
+    pub(crate) stream_resume: HashMap<String, stream_resume::StreamResumeState>,
+    /// Stream generations and user cancellations, indexed by thread ID
+    pub(crate) stream_generations: HashMap<String, stream_cancel::StreamGeneration>,
+    /// Messages not loaded yet before the oldest cached one, per thread
+    /// (threads opened with only their latest page)
+    pub(crate) older_messages: HashMap<String, usize>,
+    /// Token usage per thread this session
+    pub(crate) usage: HashMap<String, TokenUsage>,
 }
 
 impl ThreadCache {
@@ -61,9 +92,16 @@ impl ThreadCache {
         self.messages.clear();
         self.thread_order.clear();
         self.pending_title_updates.clear();
+        self.renamed_threads.clear();
+        self.provisional_titles.clear();
         self.errors.clear();
+        self.dismissed_errors.clear();
         self.focused_error_index = 0;
         self.last_accessed.clear();
+        self.stream_resume.clear();
+        self.stream_generations.clear();
+        self.older_messages.clear();
+        self.usage.clear();
     }
 }
 
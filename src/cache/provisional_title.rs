@@ -0,0 +1,259 @@
+//! Provisional titles of new threads for ThreadCache
+//!
+//! A thread started from a prompt is titled locally with the gist of it (the
+//! first sentence, or its first few words) so thread cards and the switcher
+//! don't show a raw 300-character paste. The title stays provisional until
+//! the backend names the thread: a generated title replaces it, while the
+//! backend merely echoing the prompt back doesn't. A user's `/rename` ends it
+//! too, and is never overwritten.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::ThreadCache;
+
+/// Longest provisional title, ellipsis included
+pub const MAX_PROVISIONAL_TITLE_CHARS: usize = 48;
+
+/// Words kept when the first sentence is too long
+const MAX_PROVISIONAL_TITLE_WORDS: usize = 6;
+
+/// Title of a prompt without any text (e.g., only a code block)
+const FALLBACK_TITLE: &str = "New thread";
+
+/// Paste placeholders like `[Pasted #1 ~5 lines]`
+static PASTE_TOKEN_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\[Pasted #\d+ ~\d+ lines\]").expect("Invalid paste token regex"));
+
+/// Markdown links and images, keeping their text
+static LINK_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"!?\[([^\]]*)\]\([^)]*\)").expect("Invalid link regex"));
+
+/// Headings, quotes and list markers at the start of a line
+static LINE_PREFIX_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:(?:#{1,6}|>+|[-*+]|\d+[.)])\s+)+").expect("Invalid line prefix regex")
+});
+
+/// A prompt line without markdown or paste placeholders
+fn clean_line(line: &str) -> String {
+    let line = LINE_PREFIX_REGEX.replace(line.trim(), "");
+    let line = PASTE_TOKEN_REGEX.replace_all(&line, " ");
+    let line = LINK_REGEX.replace_all(&line, "$1");
+    line.replace("~~", "")
+        .chars()
+        .filter(|c| !matches!(c, '*' | '`'))
+        .collect()
+}
+
+/// Cut `title` to `MAX_PROVISIONAL_TITLE_CHARS` at a word boundary if it
+/// can, adding an ellipsis when anything was left out
+fn cap_title(title: &str, cut: bool) -> String {
+    let max = MAX_PROVISIONAL_TITLE_CHARS - 3;
+    let title = if title.chars().count() > MAX_PROVISIONAL_TITLE_CHARS {
+        let end = title
+            .char_indices()
+            .nth(max)
+            .map_or(title.len(), |(i, _)| i);
+        let head = &title[..end];
+        match head.rfind(' ') {
+            Some(space) if space > max / 2 => &title[..space],
+            _ => head,
+        }
+    } else if cut {
+        title
+    } else {
+        return title.to_string();
+    };
+    format!("{}...", title.trim_end_matches([',', ';', ':', ' ']))
+}
+
+/// Local title for a thread started with `prompt`: its first sentence if
+/// short enough, else its first few words, without markdown, code blocks or
+/// paste placeholders
+pub fn provisional_title(prompt: &str) -> String {
+    let mut in_fence = false;
+    let first_line = prompt
+        .lines()
+        .filter(|line| {
+            let fence =
+                line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~");
+            if fence {
+                in_fence = !in_fence;
+            }
+            !fence && !in_fence
+        })
+        .map(clean_line)
+        .find(|line| !line.trim().is_empty());
+    let Some(line) = first_line else {
+        return FALLBACK_TITLE.to_string();
+    };
+
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let sentence_len = words
+        .iter()
+        .position(|word| word.ends_with(['.', '?', '!']))
+        .map_or(words.len(), |i| i + 1);
+    let sentence = words[..sentence_len].join(" ");
+    if sentence.chars().count() <= MAX_PROVISIONAL_TITLE_CHARS {
+        return sentence.trim_end_matches('.').to_string();
+    }
+    let kept = sentence_len.min(MAX_PROVISIONAL_TITLE_WORDS);
+    cap_title(&words[..kept].join(" "), kept < sentence_len)
+}
+
+/// Whether a backend `title` is just the prompt echoed back (possibly
+/// truncated) rather than a name for the thread
+fn echoes_prompt(title: &str, prompt: &str) -> bool {
+    let title = title
+        .trim()
+        .trim_end_matches("...")
+        .trim_end_matches('\u{2026}')
+        .trim_end();
+    title.is_empty() || prompt.trim_start().starts_with(title)
+}
+
+impl ThreadCache {
+    /// Whether a thread still has its provisional title
+    pub fn is_title_provisional(&self, thread_id: &str) -> bool {
+        self.provisional_titles
+            .contains_key(self.resolve_thread_id(thread_id))
+    }
+
+    /// The provisional title to show instead of a backend `title` that only
+    /// echoes the thread's prompt; None when the backend title should win
+    pub fn provisional_title_over(&self, thread_id: &str, title: &str) -> Option<String> {
+        let resolved_id = self.resolve_thread_id(thread_id);
+        let prompt = self.provisional_titles.get(resolved_id)?;
+        let thread = self.threads.get(resolved_id)?;
+        (title == thread.title || echoes_prompt(title, prompt)).then(|| thread.title.clone())
+    }
+
+    /// Mark a new thread's title as derived from `prompt`
+    pub(super) fn mark_title_provisional(&mut self, thread_id: &str, prompt: &str) {
+        self.provisional_titles
+            .insert(thread_id.to_string(), prompt.to_string());
+    }
+
+    /// Mark a thread's title as final (named by the backend or the user)
+    pub(super) fn clear_provisional_title(&mut self, thread_id: &str) {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        self.provisional_titles.remove(&resolved_id);
+    }
+
+    /// Move the provisional flag of a pending ID to the thread's real ID
+    pub(super) fn reconcile_provisional_title(&mut self, pending_id: &str, real_id: &str) {
+        if let Some(prompt) = self.provisional_titles.remove(pending_id) {
+            self.provisional_titles.insert(real_id.to_string(), prompt);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_first_sentence_is_the_title() {
+        assert_eq!(
+            provisional_title("Fix the login bug. It fails when the password has a quote."),
+            "Fix the login bug"
+        );
+        assert_eq!(provisional_title("Why is CI red?"), "Why is CI red?");
+        assert_eq!(provisional_title("Hello"), "Hello");
+    }
+
+    #[test]
+    fn test_long_sentence_keeps_first_words() {
+        assert_eq!(
+            provisional_title(
+                "Can you explain how the reconciliation of pending thread IDs works in the cache"
+            ),
+            "Can you explain how the reconciliation..."
+        );
+        let title = provisional_title(&"a".repeat(300));
+        assert_eq!(title.chars().count(), MAX_PROVISIONAL_TITLE_CHARS);
+        assert!(title.ends_with("..."));
+    }
+
+    #[test]
+    fn test_markdown_code_and_pastes_are_stripped() {
+        assert_eq!(
+            provisional_title(
+                "```rust\nfn main() {}\n```\n## Refactor **this** [function](http://x)"
+            ),
+            "Refactor this function"
+        );
+        assert_eq!(
+            provisional_title("[Pasted #1 ~40 lines]\n\nWhat does this `panic` mean?"),
+            "What does this panic mean?"
+        );
+        assert_eq!(provisional_title("- 1. first item"), "first item");
+        assert_eq!(provisional_title("```\nonly code\n```"), FALLBACK_TITLE);
+    }
+
+    #[test]
+    fn test_backend_title_replaces_provisional_unless_it_echoes_the_prompt() {
+        let prompt = "Please look at this very long stack trace and tell me what broke";
+        let mut cache = ThreadCache::new();
+        let thread_id = cache.create_streaming_thread(prompt.to_string());
+        let title = cache.get_thread(&thread_id).unwrap().title.clone();
+        assert!(cache.is_title_provisional(&thread_id));
+
+        // Raw prompt (or its truncation) from the backend: keep the local title
+        assert_eq!(
+            cache.provisional_title_over(&thread_id, prompt),
+            Some(title.clone())
+        );
+        assert_eq!(
+            cache.provisional_title_over(&thread_id, "Please look at this very long..."),
+            Some(title)
+        );
+        assert_eq!(
+            cache.provisional_title_over(&thread_id, "Stack trace triage"),
+            None
+        );
+
+        cache.update_thread_metadata(&thread_id, Some("Stack trace triage".to_string()), None);
+        assert!(!cache.is_title_provisional(&thread_id));
+        assert_eq!(
+            cache.get_thread(&thread_id).unwrap().title,
+            "Stack trace triage"
+        );
+    }
+
+    #[test]
+    fn test_refetched_thread_keeps_provisional_title() {
+        let prompt = "Summarise the release notes. Then draft the announcement.";
+        let mut cache = ThreadCache::new();
+        let thread_id = cache.create_streaming_thread(prompt.to_string());
+
+        let mut fetched = cache.get_thread(&thread_id).unwrap().clone();
+        fetched.title = prompt.to_string();
+        cache.upsert_thread(fetched.clone());
+        assert_eq!(
+            cache.get_thread(&thread_id).unwrap().title,
+            "Summarise the release notes"
+        );
+
+        fetched.title = "Release announcement".to_string();
+        cache.upsert_thread(fetched);
+        assert_eq!(
+            cache.get_thread(&thread_id).unwrap().title,
+            "Release announcement"
+        );
+        assert!(!cache.is_title_provisional(&thread_id));
+    }
+
+    #[test]
+    fn test_rename_ends_provisional_title() {
+        let mut cache = ThreadCache::new();
+        let pending_id = cache.create_streaming_thread("Hello there".to_string());
+        cache.rename_thread(&pending_id, "Greetings".to_string());
+        assert!(!cache.is_title_provisional(&pending_id));
+
+        // The backend's title doesn't replace the user's
+        cache.reconcile_thread_id(&pending_id, "real-id", Some("Hello there".to_string()));
+        assert_eq!(cache.get_thread("real-id").unwrap().title, "Greetings");
+        assert!(cache.is_thread_renamed("real-id"));
+    }
+}
@@ -30,10 +30,11 @@ impl ThreadCache {
         // If pending_id equals real_id, nothing to do (this can happen in some flows)
         if pending_id == real_id {
             // Just update title if provided
-            if let Some(new_title) = title {
+            if let Some(new_title) = self.backend_title(pending_id, title) {
                 if let Some(thread) = self.threads.get_mut(pending_id) {
                     thread.title = new_title;
                 }
+                self.provisional_titles.remove(pending_id);
             }
             // Apply any pending title updates that arrived before reconciliation
             self.apply_pending_title_updates(pending_id);
@@ -41,14 +42,26 @@ impl ThreadCache {
         }
 
         // Remove the thread with pending_id and re-insert with real_id
+        let title = self.backend_title(pending_id, title);
         if let Some(mut thread) = self.threads.remove(pending_id) {
             thread.id = real_id.to_string();
-            if let Some(new_title) = title {
-                thread.title = new_title;
+            if let Some(ref new_title) = title {
+                thread.title = new_title.clone();
             }
             self.threads.insert(real_id.to_string(), thread);
         }
 
+        // A title from the user or the backend is final; otherwise the
+        // provisional one moves with the thread
+        if self.renamed_threads.remove(pending_id) {
+            self.renamed_threads.insert(real_id.to_string());
+        }
+        if title.is_some() {
+            self.provisional_titles.remove(pending_id);
+        } else {
+            self.reconcile_provisional_title(pending_id, real_id);
+        }
+
         // Update thread_order to replace pending_id with real_id
         if let Some(pos) = self.thread_order.iter().position(|id| id == pending_id) {
             self.thread_order[pos] = real_id.to_string();
@@ -67,6 +80,18 @@ impl ThreadCache {
         if let Some(errors) = self.errors.remove(pending_id) {
             self.errors.insert(real_id.to_string(), errors);
         }
+        if let Some(dismissed) = self.dismissed_errors.remove(pending_id) {
+            self.dismissed_errors.insert(real_id.to_string(), dismissed);
+        }
+
+        // Keep the stream generation so cancelled events stay dropped
+        if let Some(generation) = self.stream_generations.remove(pending_id) {
+            self.stream_generations
+                .insert(real_id.to_string(), generation);
+        }
+
+        // Token usage counted under the pending ID
+        self.reconcile_usage(pending_id, real_id);
 
         // Track the mapping so streaming tokens using the old pending ID
         // can be redirected to the correct thread
@@ -78,7 +103,10 @@ impl ThreadCache {
         // and real_id (in case the update used the real ID directly)
         if let Some((title, description)) = self.pending_title_updates.remove(pending_id) {
             if let Some(thread) = self.threads.get_mut(real_id) {
-                thread.title = title;
+                if !self.renamed_threads.contains(real_id) {
+                    thread.title = title;
+                    self.provisional_titles.remove(real_id);
+                }
                 if let Some(desc) = description {
                     thread.description = Some(desc);
                 }
@@ -87,6 +115,15 @@ impl ThreadCache {
         self.apply_pending_title_updates(real_id);
     }
 
+    /// The title reported when a thread is created, unless the user renamed
+    /// it or it only echoes the prompt of a provisionally titled thread
+    fn backend_title(&self, thread_id: &str, title: Option<String>) -> Option<String> {
+        title.filter(|title| {
+            !self.renamed_threads.contains(thread_id)
+                && self.provisional_title_over(thread_id, title).is_none()
+        })
+    }
+
     /// Sync a thread to the server (future implementation)
     ///
     /// TODO: Implement when backend PUT /threads/:id endpoint exists
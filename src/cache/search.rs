@@ -0,0 +1,187 @@
+//! Message search methods for ThreadCache
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use super::ThreadCache;
+
+/// A single search hit inside a thread's cached messages.
+///
+/// Offsets are byte offsets into the searched text (`content` for completed
+/// messages, `partial_content` for streaming ones).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageMatch {
+    /// Index of the message within the thread's message list
+    pub message_index: usize,
+    /// Byte offset where the match starts
+    pub start: usize,
+    /// Byte offset where the match ends (exclusive)
+    pub end: usize,
+}
+
+/// Find all non-overlapping, case-insensitive occurrences of `query` in `text`.
+///
+/// Returns `(start, end)` byte offsets into `text`. Lowercasing may change a
+/// character's length (e.g. `İ`), so offsets in the lowercased text are
+/// mapped back to the characters they came from.
+pub(crate) fn find_matches(text: &str, query: &str) -> Vec<(usize, usize)> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    // Byte range in `text` of the character each lowercased byte came from
+    let mut haystack = String::with_capacity(text.len());
+    let mut origin = Vec::with_capacity(text.len());
+    for (start, c) in text.char_indices() {
+        let end = start + c.len_utf8();
+        for lower in c.to_lowercase() {
+            haystack.push(lower);
+            origin.resize(haystack.len(), (start, end));
+        }
+    }
+    let needle = query.to_lowercase();
+
+    haystack
+        .match_indices(&needle)
+        .map(|(start, m)| (origin[start].0, origin[start + m.len() - 1].1))
+        .collect()
+}
+
+impl ThreadCache {
+    /// Search a thread's cached messages for `query`.
+    ///
+    /// Matching is case-insensitive and covers plain text as well as code
+    /// blocks, since both live in the raw message text. Matches are returned
+    /// in message order, then by position within the message.
+    pub fn search_messages(&self, thread_id: &str, query: &str) -> Vec<MessageMatch> {
+        let resolved_id = self.resolve_thread_id(thread_id);
+        let Some(messages) = self.messages.get(resolved_id) else {
+            return Vec::new();
+        };
+
+        messages
+            .iter()
+            .enumerate()
+            .flat_map(|(message_index, message)| {
+                let text = if message.is_streaming {
+                    &message.partial_content
+                } else {
+                    &message.content
+                };
+                find_matches(text, query)
+                    .into_iter()
+                    .map(move |(start, end)| MessageMatch {
+                        message_index,
+                        start,
+                        end,
+                    })
+            })
+            .collect()
+    }
+
+    /// A value that changes whenever a thread's cached message text does
+    /// (streamed tokens, loaded or edited messages), to re-run searches
+    pub fn content_signature(&self, thread_id: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        let resolved_id = self.resolve_thread_id(thread_id);
+        for message in self.messages.get(resolved_id).into_iter().flatten() {
+            message.id.hash(&mut hasher);
+            message.render_version.hash(&mut hasher);
+            message.content.len().hash(&mut hasher);
+            message.partial_content.len().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MessageRole;
+
+    #[test]
+    fn test_find_matches_case_insensitive() {
+        let matches = find_matches("Tokio and tokio and TOKIO", "tokio");
+        assert_eq!(matches, vec![(0, 5), (10, 15), (20, 25)]);
+    }
+
+    #[test]
+    fn test_find_matches_empty_query() {
+        assert!(find_matches("anything", "").is_empty());
+    }
+
+    #[test]
+    fn test_find_matches_preserves_byte_offsets_with_unicode() {
+        let text = "café — Rust";
+        let matches = find_matches(text, "rust");
+        assert_eq!(matches.len(), 1);
+        let (start, end) = matches[0];
+        assert_eq!(&text[start..end], "Rust");
+    }
+
+    #[test]
+    fn test_find_matches_folds_unicode_case() {
+        let text = "Ünïcode ÉTÉ and été";
+        let matches = find_matches(text, "été");
+        let found: Vec<&str> = matches.iter().map(|&(s, e)| &text[s..e]).collect();
+        assert_eq!(found, vec!["ÉTÉ", "été"]);
+
+        // Lowercasing İ grows it by a byte; later offsets still slice `text`
+        let text = "İstanbul Straße";
+        let matches = find_matches(text, "straße");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(&text[matches[0].0..matches[0].1], "Straße");
+    }
+
+    #[test]
+    fn test_content_signature_follows_streamed_text() {
+        let mut cache = ThreadCache::with_stub_data();
+        let before = cache.content_signature("thread-001");
+        assert_eq!(before, cache.content_signature("thread-001"));
+
+        cache.add_streaming_message("thread-001", "hello".to_string(), Vec::new());
+        let streaming = cache.content_signature("thread-001");
+        assert_ne!(before, streaming);
+
+        cache.append_to_message("thread-001", "more");
+        assert_ne!(streaming, cache.content_signature("thread-001"));
+    }
+
+    #[test]
+    fn test_search_messages_returns_message_index_and_offsets() {
+        let mut cache = ThreadCache::new();
+        cache.add_message_simple("t1", MessageRole::User, "How do I use tokio?".to_string());
+        cache.add_message_simple(
+            "t1",
+            MessageRole::Assistant,
+            "Add tokio:\n```toml\ntokio = \"1\"\n```".to_string(),
+        );
+
+        let matches = cache.search_messages("t1", "TOKIO");
+        assert_eq!(matches.len(), 3);
+        assert_eq!(matches[0], MessageMatch { message_index: 0, start: 13, end: 18 });
+        assert_eq!(matches[1].message_index, 1);
+        // Match inside the code block
+        assert_eq!(matches[2].message_index, 1);
+        let content = &cache.get_messages("t1").unwrap()[1].content;
+        assert_eq!(&content[matches[2].start..matches[2].end], "tokio");
+    }
+
+    #[test]
+    fn test_search_messages_unknown_thread() {
+        let cache = ThreadCache::new();
+        assert!(cache.search_messages("missing", "query").is_empty());
+    }
+
+    #[test]
+    fn test_search_messages_streaming_uses_partial_content() {
+        let mut cache = ThreadCache::with_stub_data();
+        let base = cache.get_messages("thread-001").unwrap().len();
+        cache.add_streaming_message("thread-001", "hello".to_string(), Vec::new());
+        cache.append_to_message("thread-001", "streamed needle");
+
+        let matches = cache.search_messages("thread-001", "needle");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].message_index, base + 1);
+    }
+}
@@ -0,0 +1,121 @@
+//! Stream cancellation tracking for ThreadCache
+//!
+//! Every stream request for a thread starts a new generation. Cancelling
+//! (Ctrl+C) finalizes the partial response right away and stamps the current
+//! generation with `cancelled_at`; events from that generation that still
+//! arrive afterwards (already queued, or sent before the backend stopped) are
+//! dropped instead of landing in the finalized message or in the next
+//! response. Starting the next stream clears the marker.
+
+use chrono::{DateTime, Utc};
+
+use super::message::MSG_ID_USER_CANCELLED;
+use super::ThreadCache;
+
+/// Per-thread stream generation
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct StreamGeneration {
+    /// Incremented for every new stream request
+    pub(crate) current: u64,
+    /// When the user cancelled the current generation
+    pub(crate) cancelled_at: Option<DateTime<Utc>>,
+}
+
+impl ThreadCache {
+    /// Start a new stream generation for a thread, returning its number
+    pub fn begin_stream_generation(&mut self, thread_id: &str) -> u64 {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        let generation = self.stream_generations.entry(resolved_id).or_default();
+        generation.current += 1;
+        generation.cancelled_at = None;
+        generation.current
+    }
+
+    /// Current stream generation of a thread (0 before the first stream)
+    pub fn stream_generation(&self, thread_id: &str) -> u64 {
+        let resolved_id = self.resolve_thread_id(thread_id);
+        self.stream_generations
+            .get(resolved_id)
+            .map_or(0, |g| g.current)
+    }
+
+    /// When the user cancelled the thread's current stream, if they did
+    pub fn stream_cancelled_at(&self, thread_id: &str) -> Option<DateTime<Utc>> {
+        let resolved_id = self.resolve_thread_id(thread_id);
+        self.stream_generations
+            .get(resolved_id)
+            .and_then(|g| g.cancelled_at)
+    }
+
+    /// Whether events for the thread's current stream should be dropped
+    pub fn is_stream_cancelled(&self, thread_id: &str) -> bool {
+        self.stream_cancelled_at(thread_id).is_some()
+    }
+
+    /// Cancel the streaming response of a thread on behalf of the user.
+    ///
+    /// The text received so far is kept as the message content and the
+    /// message gets the `MSG_ID_USER_CANCELLED` sentinel (rendered as
+    /// "Cancelled by user"). The current generation is marked cancelled.
+    ///
+    /// # Returns
+    /// `false` if the thread had no streaming message
+    pub fn cancel_stream_by_user(&mut self, thread_id: &str) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        let Some(msg) = self
+            .messages
+            .get_mut(&resolved_id)
+            .and_then(|messages| messages.iter_mut().rev().find(|m| m.is_streaming))
+        else {
+            return false;
+        };
+
+        if msg.id == 0 {
+            msg.id = MSG_ID_USER_CANCELLED;
+        }
+        msg.finalize();
+
+        self.stream_generations
+            .entry(resolved_id)
+            .or_default()
+            .cancelled_at = Some(Utc::now());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_keeps_partial_content_and_marks_generation() {
+        let mut cache = ThreadCache::with_stub_data();
+        cache.add_streaming_message("thread-001", "hello".to_string(), Vec::new());
+        assert_eq!(cache.begin_stream_generation("thread-001"), 1);
+        cache.append_to_message("thread-001", "partial answer");
+
+        assert!(cache.cancel_stream_by_user("thread-001"));
+        assert!(!cache.is_thread_streaming("thread-001"));
+        assert!(cache.is_stream_cancelled("thread-001"));
+
+        let last = cache.get_messages("thread-001").unwrap().last().unwrap();
+        assert_eq!(last.id, MSG_ID_USER_CANCELLED);
+        assert_eq!(last.content, "partial answer");
+
+        // Nothing left to cancel
+        assert!(!cache.cancel_stream_by_user("thread-001"));
+    }
+
+    #[test]
+    fn test_next_generation_clears_cancel_marker() {
+        let mut cache = ThreadCache::with_stub_data();
+        cache.add_streaming_message("thread-001", "hello".to_string(), Vec::new());
+        cache.begin_stream_generation("thread-001");
+        cache.cancel_stream_by_user("thread-001");
+        assert!(cache.stream_cancelled_at("thread-001").is_some());
+
+        assert_eq!(cache.begin_stream_generation("thread-001"), 2);
+        assert_eq!(cache.stream_generation("thread-001"), 2);
+        assert!(!cache.is_stream_cancelled("thread-001"));
+    }
+}
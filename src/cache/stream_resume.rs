@@ -0,0 +1,193 @@
+//! Stream resume tracking for ThreadCache
+//!
+//! When an SSE stream drops before a terminal event (laptop sleep, network
+//! change), the partial assistant message is kept streaming and the thread is
+//! marked interrupted. The last content sequence number seen is used as the
+//! resume offset so replayed tokens append to the same message.
+
+use super::message::MSG_ID_STREAM_INTERRUPTED;
+use super::ThreadCache;
+
+/// Per-thread resume bookkeeping for an in-flight stream
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct StreamResumeState {
+    /// Sequence number of the last content event received
+    pub(crate) last_seq: Option<u64>,
+    /// Whether the stream dropped and is waiting to be resumed
+    pub(crate) interrupted: bool,
+    /// Resume attempts since the last received event
+    pub(crate) attempts: u8,
+}
+
+impl ThreadCache {
+    /// Record the sequence number of a received content event.
+    ///
+    /// Receiving an event means the stream is making progress, so the resume
+    /// attempt counter is reset.
+    pub fn record_stream_seq(&mut self, thread_id: &str, seq: u64) {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        let state = self.stream_resume.entry(resolved_id).or_default();
+        state.last_seq = Some(state.last_seq.map_or(seq, |last| last.max(seq)));
+        state.attempts = 0;
+    }
+
+    /// Sequence number of the last content event received for a thread
+    pub fn last_stream_seq(&self, thread_id: &str) -> Option<u64> {
+        let resolved_id = self.resolve_thread_id(thread_id);
+        self.stream_resume.get(resolved_id).and_then(|s| s.last_seq)
+    }
+
+    /// Mark a thread's stream as interrupted.
+    ///
+    /// Returns false if the thread has no streaming message to resume.
+    pub fn mark_stream_interrupted(&mut self, thread_id: &str) -> bool {
+        if !self.is_thread_streaming(thread_id) {
+            return false;
+        }
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        self.stream_resume.entry(resolved_id).or_default().interrupted = true;
+        true
+    }
+
+    /// Check whether a thread's stream is waiting to be resumed
+    pub fn is_stream_interrupted(&self, thread_id: &str) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id);
+        self.stream_resume
+            .get(resolved_id)
+            .is_some_and(|s| s.interrupted)
+    }
+
+    /// IDs of all threads whose streams are waiting to be resumed, sorted
+    pub fn interrupted_streams(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .stream_resume
+            .iter()
+            .filter(|(_, s)| s.interrupted)
+            .map(|(id, _)| id.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Number of resume attempts made since the last received event
+    pub fn stream_resume_attempts(&self, thread_id: &str) -> u8 {
+        let resolved_id = self.resolve_thread_id(thread_id);
+        self.stream_resume.get(resolved_id).map_or(0, |s| s.attempts)
+    }
+
+    /// Start a resume attempt for an interrupted stream.
+    ///
+    /// Clears the interrupted flag so the same stream is not resumed twice
+    /// concurrently, and returns the offset to resume from.
+    pub fn begin_stream_resume(&mut self, thread_id: &str) -> Option<u64> {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        let state = self.stream_resume.entry(resolved_id).or_default();
+        state.interrupted = false;
+        state.attempts = state.attempts.saturating_add(1);
+        state.last_seq
+    }
+
+    /// Forget resume state for a thread (stream completed, failed or was cancelled)
+    pub fn clear_stream_resume(&mut self, thread_id: &str) {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        self.stream_resume.remove(&resolved_id);
+    }
+
+    /// Finalize a streaming message that cannot be resumed.
+    ///
+    /// Unlike cancellation, the partial content received so far is kept as the
+    /// message content. Resume state for the thread is cleared.
+    pub fn finalize_interrupted_message(&mut self, thread_id: &str) {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        if let Some(messages) = self.messages.get_mut(&resolved_id) {
+            if let Some(msg) = messages.iter_mut().rev().find(|m| m.is_streaming) {
+                if msg.id == 0 {
+                    msg.id = MSG_ID_STREAM_INTERRUPTED;
+                }
+                msg.finalize();
+            }
+        }
+        self.stream_resume.remove(&resolved_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_with_stream() -> ThreadCache {
+        let mut cache = ThreadCache::with_stub_data();
+        assert!(cache.add_streaming_message("thread-001", "hello".to_string(), Vec::new()));
+        cache.append_to_message("thread-001", "partial answer");
+        cache
+    }
+
+    #[test]
+    fn test_record_stream_seq_keeps_highest() {
+        let mut cache = ThreadCache::new();
+        assert_eq!(cache.last_stream_seq("thread-001"), None);
+
+        cache.record_stream_seq("thread-001", 5);
+        cache.record_stream_seq("thread-001", 3);
+        assert_eq!(cache.last_stream_seq("thread-001"), Some(5));
+
+        cache.record_stream_seq("thread-001", 7);
+        assert_eq!(cache.last_stream_seq("thread-001"), Some(7));
+    }
+
+    #[test]
+    fn test_mark_stream_interrupted_requires_streaming_message() {
+        let mut cache = ThreadCache::with_stub_data();
+        assert!(!cache.mark_stream_interrupted("thread-001"));
+        assert!(cache.interrupted_streams().is_empty());
+
+        let mut cache = cache_with_stream();
+        assert!(cache.mark_stream_interrupted("thread-001"));
+        assert!(cache.is_stream_interrupted("thread-001"));
+        assert_eq!(cache.interrupted_streams(), vec!["thread-001".to_string()]);
+    }
+
+    #[test]
+    fn test_begin_stream_resume_returns_offset_and_counts_attempts() {
+        let mut cache = cache_with_stream();
+        cache.record_stream_seq("thread-001", 12);
+        cache.mark_stream_interrupted("thread-001");
+
+        assert_eq!(cache.begin_stream_resume("thread-001"), Some(12));
+        assert!(!cache.is_stream_interrupted("thread-001"));
+        assert_eq!(cache.stream_resume_attempts("thread-001"), 1);
+
+        // Progress resets the attempt counter
+        cache.record_stream_seq("thread-001", 13);
+        assert_eq!(cache.stream_resume_attempts("thread-001"), 0);
+    }
+
+    #[test]
+    fn test_finalize_interrupted_message_keeps_partial_content() {
+        let mut cache = cache_with_stream();
+        cache.record_stream_seq("thread-001", 4);
+        cache.mark_stream_interrupted("thread-001");
+
+        cache.finalize_interrupted_message("thread-001");
+
+        let messages = cache.get_messages("thread-001").unwrap();
+        let last = messages.last().unwrap();
+        assert!(!last.is_streaming);
+        assert_eq!(last.content, "partial answer");
+        assert_eq!(last.id, MSG_ID_STREAM_INTERRUPTED);
+        assert!(!cache.is_thread_streaming("thread-001"));
+        assert!(!cache.is_stream_interrupted("thread-001"));
+        assert_eq!(cache.last_stream_seq("thread-001"), None);
+    }
+
+    #[test]
+    fn test_clear_stream_resume() {
+        let mut cache = cache_with_stream();
+        cache.record_stream_seq("thread-001", 1);
+        cache.mark_stream_interrupted("thread-001");
+
+        cache.clear_stream_resume("thread-001");
+        assert!(!cache.is_stream_interrupted("thread-001"));
+        assert_eq!(cache.last_stream_seq("thread-001"), None);
+    }
+}
@@ -4,15 +4,19 @@ use chrono::{Duration, Utc};
 use std::time::Instant;
 use uuid::Uuid;
 
-use crate::models::{Message, MessageRole, Thread, ThreadType};
+use crate::models::{Message, MessageRole, Thread, ThreadLink, ThreadLinkKind, ThreadType};
 
 use super::{ThreadCache, EVICTION_TIMEOUT_SECS};
 
 impl ThreadCache {
-    /// Get all threads in order (most recent first), excluding evicted threads
+    /// Get all threads in order, excluding evicted and archived threads.
+    ///
+    /// Pinned threads come first in their manual order, then the rest with
+    /// the most recent first.
     pub fn threads(&self) -> Vec<&Thread> {
         let now = Instant::now();
-        self.thread_order
+        let mut threads: Vec<&Thread> = self
+            .thread_order
             .iter()
             .filter_map(|id| {
                 // Check if thread is evicted (not accessed in EVICTION_TIMEOUT_SECS)
@@ -21,9 +25,12 @@ impl ThreadCache {
                         return None; // Evicted
                     }
                 }
-                self.threads.get(id)
+                self.threads.get(id).filter(|t| !t.archived)
             })
-            .collect()
+            .collect();
+        // Stable sort keeps MRU order among unpinned threads
+        threads.sort_by_key(|t| (!t.pinned, if t.pinned { t.pin_order } else { 0 }));
+        threads
     }
 
     /// Touch a thread to update its last_accessed time (prevents eviction)
@@ -41,7 +48,9 @@ impl ThreadCache {
     /// Remove a thread and all its associated data from the cache.
     ///
     /// Clears: threads, thread_order, messages, last_accessed, errors,
-    /// pending_title_updates, and pending_to_real mappings (both as key and value).
+    /// pending_title_updates, renamed_threads, provisional_titles, stream
+    /// resume state, and pending_to_real mappings
+    /// (both as key and value).
     ///
     /// Returns `true` if the thread existed and was removed, `false` otherwise.
     pub fn remove_thread(&mut self, thread_id: &str) -> bool {
@@ -52,7 +61,13 @@ impl ThreadCache {
             self.messages.remove(thread_id);
             self.last_accessed.remove(thread_id);
             self.errors.remove(thread_id);
+            self.dismissed_errors.remove(thread_id);
             self.pending_title_updates.remove(thread_id);
+            self.renamed_threads.remove(thread_id);
+            self.provisional_titles.remove(thread_id);
+            self.stream_resume.remove(thread_id);
+            self.stream_generations.remove(thread_id);
+            self.older_messages.remove(thread_id);
 
             // Remove from pending_to_real: both as key and as value
             self.pending_to_real.remove(thread_id);
@@ -65,6 +80,25 @@ impl ThreadCache {
         existed
     }
 
+    /// Put back a thread removed with `remove_thread`, at `position` in the
+    /// thread order
+    pub fn restore_thread(
+        &mut self,
+        thread: Thread,
+        messages: Option<Vec<Message>>,
+        position: usize,
+    ) {
+        let id = thread.id.clone();
+        self.thread_order.retain(|existing_id| existing_id != &id);
+        self.thread_order
+            .insert(position.min(self.thread_order.len()), id.clone());
+        self.last_accessed.insert(id.clone(), Instant::now());
+        if let Some(messages) = messages {
+            self.messages.insert(id.clone(), messages);
+        }
+        self.threads.insert(id, thread);
+    }
+
     /// Get a thread by ID
     pub fn get_thread(&self, id: &str) -> Option<&Thread> {
         self.threads.get(id)
@@ -77,9 +111,18 @@ impl ThreadCache {
     }
 
     /// Add or update a thread in the cache
-    pub fn upsert_thread(&mut self, thread: Thread) {
+    pub fn upsert_thread(&mut self, mut thread: Thread) {
         let id = thread.id.clone();
 
+        // Mutes are local: the backend's copy never has them
+        thread.muted |= self.threads.get(&id).is_some_and(|t| t.muted);
+
+        // A provisional title stays until the thread is really named
+        match self.provisional_title_over(&id, &thread.title) {
+            Some(title) => thread.title = title,
+            None => self.clear_provisional_title(&id),
+        }
+
         // Update thread order - move to front if exists, otherwise add to front
         self.thread_order.retain(|existing_id| existing_id != &id);
         self.thread_order.insert(0, id.clone());
@@ -123,6 +166,11 @@ impl ThreadCache {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         self.upsert_thread(thread);
@@ -142,16 +190,8 @@ impl ThreadCache {
         let thread_id = Uuid::new_v4().to_string();
         let now = Utc::now();
 
-        // Create title from first message (truncate if too long, respecting UTF-8 boundaries)
-        let title = if first_message.len() > 40 {
-            let mut end = 37;
-            while end > 0 && !first_message.is_char_boundary(end) {
-                end -= 1;
-            }
-            format!("{}...", &first_message[..end])
-        } else {
-            first_message.clone()
-        };
+        // Titled from the prompt until the backend names the thread
+        let title = super::provisional_title(&first_message);
 
         let thread = Thread {
             id: thread_id.clone(),
@@ -169,9 +209,15 @@ impl ThreadCache {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         self.upsert_thread(thread);
+        self.mark_title_provisional(&thread_id, &first_message);
 
         // Add the user message
         let user_message = Message {
@@ -187,6 +233,7 @@ impl ThreadCache {
             segments: Vec::new(),
             render_version: 0,
             image_hashes,
+            reply_to: None,
         };
         self.add_message(user_message);
 
@@ -204,6 +251,7 @@ impl ThreadCache {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         self.add_message(assistant_message);
 
@@ -226,7 +274,13 @@ impl ThreadCache {
         thread_type: ThreadType,
         working_directory: Option<String>,
     ) -> String {
-        self.create_pending_thread_with_images(first_message, thread_type, working_directory, Vec::new())
+        self.create_pending_thread_with_images(
+            first_message,
+            thread_type,
+            working_directory,
+            Vec::new(),
+            None,
+        )
     }
 
     /// Create a new pending thread with image hashes attached to the initial user message.
@@ -236,20 +290,13 @@ impl ThreadCache {
         thread_type: ThreadType,
         working_directory: Option<String>,
         image_hashes: Vec<String>,
+        reply_to: Option<i64>,
     ) -> String {
         let thread_id = Uuid::new_v4().to_string();
         let now = Utc::now();
 
-        // Create title from first message (truncate if too long, respecting UTF-8 boundaries)
-        let title = if first_message.len() > 40 {
-            let mut end = 37;
-            while end > 0 && !first_message.is_char_boundary(end) {
-                end -= 1;
-            }
-            format!("{}...", &first_message[..end])
-        } else {
-            first_message.clone()
-        };
+        // Titled from the prompt until the backend names the thread
+        let title = super::provisional_title(&first_message);
 
         let thread = Thread {
             id: thread_id.clone(),
@@ -267,9 +314,15 @@ impl ThreadCache {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         self.upsert_thread(thread);
+        self.mark_title_provisional(&thread_id, &first_message);
 
         // Add the user message
         let user_message = Message {
@@ -285,6 +338,7 @@ impl ThreadCache {
             segments: Vec::new(),
             render_version: 0,
             image_hashes,
+            reply_to,
         };
         self.add_message(user_message);
 
@@ -302,6 +356,7 @@ impl ThreadCache {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
         self.add_message(assistant_message);
 
@@ -339,6 +394,7 @@ impl ThreadCache {
             // Update title if provided
             if let Some(new_title) = title {
                 thread.title = new_title;
+                self.provisional_titles.remove(&resolved_id);
             }
 
             // Update description if provided
@@ -358,6 +414,161 @@ impl ThreadCache {
         }
     }
 
+    /// Replace a thread's preview text (e.g., with an outcome summary).
+    ///
+    /// Returns false if the thread is not cached.
+    pub fn set_thread_preview(&mut self, thread_id: &str, preview: String) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        match self.threads.get_mut(&resolved_id) {
+            Some(thread) => {
+                thread.preview = preview;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace a thread's links.
+    ///
+    /// Returns false if the thread is not cached.
+    pub fn set_thread_links(&mut self, thread_id: &str, links: Vec<ThreadLink>) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        match self.threads.get_mut(&resolved_id) {
+            Some(thread) => {
+                thread.links = links;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Pin or unpin a thread.
+    ///
+    /// Returns false if the thread is not cached.
+    pub fn set_thread_pin(&mut self, thread_id: &str, pinned: bool, pin_order: u32) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        match self.threads.get_mut(&resolved_id) {
+            Some(thread) => {
+                thread.pinned = pinned;
+                thread.pin_order = pin_order;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Mute or unmute a thread.
+    ///
+    /// Returns false if the thread is not cached.
+    pub fn set_thread_muted(&mut self, thread_id: &str, muted: bool) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        match self.threads.get_mut(&resolved_id) {
+            Some(thread) => {
+                thread.muted = muted;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Archive or unarchive a thread. Archived threads stay cached with
+    /// their messages but are left out of `threads()`.
+    ///
+    /// Returns false if the thread is not cached.
+    pub fn set_thread_archived(&mut self, thread_id: &str, archived: bool) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        match self.threads.get_mut(&resolved_id) {
+            Some(thread) => {
+                thread.archived = archived;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Rename a thread and mark its title as edited by the user.
+    ///
+    /// Returns the previous title, or None if the thread is not cached.
+    pub fn rename_thread(&mut self, thread_id: &str, title: String) -> Option<String> {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        let thread = self.threads.get_mut(&resolved_id)?;
+        let previous = std::mem::replace(&mut thread.title, title);
+        self.provisional_titles.remove(&resolved_id);
+        self.renamed_threads.insert(resolved_id);
+        Some(previous)
+    }
+
+    /// Undo a rename the backend rejected.
+    ///
+    /// Only restores `previous_title` if the thread still has the rejected
+    /// `title`, so a newer rename isn't lost. Returns true if it was restored.
+    pub fn revert_thread_rename(
+        &mut self,
+        thread_id: &str,
+        title: &str,
+        previous_title: String,
+    ) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        match self.threads.get_mut(&resolved_id) {
+            Some(thread) if thread.title == title => {
+                thread.title = previous_title;
+                self.renamed_threads.remove(&resolved_id);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the user renamed this thread
+    pub fn is_thread_renamed(&self, thread_id: &str) -> bool {
+        self.renamed_threads
+            .contains(self.resolve_thread_id(thread_id))
+    }
+
+    /// Set the model a thread runs with.
+    ///
+    /// Returns false if the thread is not cached.
+    pub fn set_thread_model(&mut self, thread_id: &str, model: Option<String>) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        match self.threads.get_mut(&resolved_id) {
+            Some(thread) => {
+                thread.model = model;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Set the working directory of a thread.
+    ///
+    /// Returns false if the thread is not cached.
+    pub fn set_thread_working_directory(&mut self, thread_id: &str, path: &str) -> bool {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        match self.threads.get_mut(&resolved_id) {
+            Some(thread) => {
+                thread.working_directory = Some(path.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Links from other cached threads to `thread_id`, as (linking thread ID, relation),
+    /// most recent linking thread first
+    pub fn inbound_thread_links(&self, thread_id: &str) -> Vec<(String, ThreadLinkKind)> {
+        self.thread_order
+            .iter()
+            .filter_map(|id| self.threads.get(id))
+            .flat_map(|thread| {
+                thread
+                    .links
+                    .iter()
+                    .filter(|link| link.thread_id == thread_id)
+                    .map(|link| (thread.id.clone(), link.kind))
+            })
+            .collect()
+    }
+
     /// Apply any pending title updates for a thread after reconciliation.
     ///
     /// This should be called after `reconcile_thread_id()` to flush any queued
@@ -369,7 +580,10 @@ impl ThreadCache {
         // Check if there are pending updates for this thread_id
         if let Some((title, description)) = self.pending_title_updates.remove(thread_id) {
             if let Some(thread) = self.threads.get_mut(thread_id) {
-                thread.title = title;
+                if !self.renamed_threads.contains(thread_id) {
+                    thread.title = title;
+                    self.provisional_titles.remove(thread_id);
+                }
                 if let Some(desc) = description {
                     thread.description = Some(desc);
                 }
@@ -398,6 +612,11 @@ impl ThreadCache {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         let messages1 = vec![
@@ -414,6 +633,7 @@ impl ThreadCache {
                 segments: Vec::new(),
                 render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
             },
             Message {
                 id: 2,
@@ -428,6 +648,7 @@ impl ThreadCache {
                 segments: Vec::new(),
                 render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
             },
         ];
 
@@ -448,6 +669,11 @@ impl ThreadCache {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         let messages2 = vec![
@@ -464,6 +690,7 @@ impl ThreadCache {
                 segments: Vec::new(),
                 render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
             },
             Message {
                 id: 4,
@@ -478,6 +705,7 @@ impl ThreadCache {
                 segments: Vec::new(),
                 render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
             },
         ];
 
@@ -498,6 +726,11 @@ impl ThreadCache {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         let messages3 = vec![
@@ -514,6 +747,7 @@ impl ThreadCache {
                 segments: Vec::new(),
                 render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
             },
             Message {
                 id: 6,
@@ -528,6 +762,7 @@ impl ThreadCache {
                 segments: Vec::new(),
                 render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
             },
         ];
 
@@ -560,6 +795,97 @@ mod tests {
         assert!(nonexistent.is_none());
     }
 
+    #[test]
+    fn test_threads_lists_pinned_first_in_pin_order() {
+        let mut cache = ThreadCache::with_stub_data();
+        let mru: Vec<String> = cache.threads().iter().map(|t| t.id.clone()).collect();
+        let last = mru.last().unwrap().clone();
+        let middle = mru[1].clone();
+
+        assert!(cache.set_thread_pin(&middle, true, 1));
+        assert!(cache.set_thread_pin(&last, true, 0));
+        assert!(!cache.set_thread_pin("missing", true, 0));
+
+        let ids: Vec<String> = cache.threads().iter().map(|t| t.id.clone()).collect();
+        assert_eq!(ids[0], last);
+        assert_eq!(ids[1], middle);
+        let unpinned: Vec<&String> = mru
+            .iter()
+            .filter(|id| **id != last && **id != middle)
+            .collect();
+        assert_eq!(ids[2..].iter().collect::<Vec<_>>(), unpinned);
+
+        cache.set_thread_pin(&last, false, 0);
+        assert_eq!(cache.threads()[0].id, middle);
+    }
+
+    #[test]
+    fn test_archived_threads_are_hidden_but_kept() {
+        let mut cache = ThreadCache::with_stub_data();
+        let count = cache.threads().len();
+
+        assert!(cache.set_thread_archived("thread-001", true));
+        assert!(!cache.set_thread_archived("missing", true));
+
+        assert_eq!(cache.threads().len(), count - 1);
+        assert!(cache.threads().iter().all(|t| t.id != "thread-001"));
+        assert!(cache.get_thread("thread-001").is_some_and(|t| t.archived));
+        assert!(cache.get_messages("thread-001").is_some());
+
+        cache.set_thread_archived("thread-001", false);
+        assert_eq!(cache.threads().len(), count);
+    }
+
+    #[test]
+    fn test_set_thread_links_and_inbound_links() {
+        let mut cache = ThreadCache::with_stub_data();
+        let link = ThreadLink::new(ThreadLinkKind::BlockedBy, "thread-001");
+
+        assert!(cache.set_thread_links("thread-002", vec![link.clone()]));
+        assert!(!cache.set_thread_links("missing", Vec::new()));
+
+        assert_eq!(cache.get_thread("thread-002").unwrap().links, vec![link]);
+        assert_eq!(
+            cache.inbound_thread_links("thread-001"),
+            vec![("thread-002".to_string(), ThreadLinkKind::BlockedBy)]
+        );
+        assert!(cache.inbound_thread_links("thread-002").is_empty());
+    }
+
+    #[test]
+    fn test_set_thread_model() {
+        let mut cache = ThreadCache::with_stub_data();
+
+        assert!(cache.set_thread_model("thread-001", Some("claude-opus-4-5".to_string())));
+        assert!(!cache.set_thread_model("missing", None));
+
+        assert_eq!(
+            cache.get_thread("thread-001").unwrap().model.as_deref(),
+            Some("claude-opus-4-5")
+        );
+    }
+
+    #[test]
+    fn test_rename_thread_and_revert() {
+        let mut cache = ThreadCache::with_stub_data();
+        let original = cache.get_thread("thread-001").unwrap().title.clone();
+
+        assert_eq!(
+            cache.rename_thread("thread-001", "Renamed".to_string()),
+            Some(original.clone())
+        );
+        assert!(cache.is_thread_renamed("thread-001"));
+        assert_eq!(cache.rename_thread("missing", "Renamed".to_string()), None);
+
+        // A rejected rename that was since superseded is left alone
+        assert!(!cache.revert_thread_rename("thread-001", "Older", original.clone()));
+        assert_eq!(cache.get_thread("thread-001").unwrap().title, "Renamed");
+
+        assert!(cache.revert_thread_rename("thread-001", "Renamed", original.clone()));
+        assert_eq!(cache.get_thread("thread-001").unwrap().title, original);
+        assert!(!cache.is_thread_renamed("thread-001"));
+    }
+
     #[test]
     fn test_upsert_thread_new() {
         let mut cache = ThreadCache::new();
@@ -580,6 +906,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         cache.upsert_thread(thread);
@@ -608,6 +939,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         cache.upsert_thread(updated_thread);
@@ -645,6 +981,11 @@ mod tests {
                 status: None,
                 verified: None,
                 verified_at: None,
+                links: Vec::new(),
+                pinned: false,
+                pin_order: 0,
+                archived: false,
+                muted: false,
             });
         }
 
@@ -668,6 +1009,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         });
 
         // Thread 1 should now be at front
@@ -843,9 +1189,9 @@ mod tests {
             cache.create_pending_thread(long_message.clone(), ThreadType::Conversation, None);
 
         let thread = cache.get_thread(&pending_id).unwrap();
-        // Title should be truncated to 37 chars + "..."
-        assert_eq!(thread.title.len(), 40);
-        assert!(thread.title.ends_with("..."));
+        // Provisional title: the first words of the prompt
+        assert_eq!(thread.title, "This is a very long message...");
+        assert!(cache.is_title_provisional(&pending_id));
         // Preview should be the full message
         assert_eq!(thread.preview, long_message);
     }
@@ -1117,6 +1463,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         cache.upsert_thread(thread);
 
@@ -1182,6 +1533,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         cache.upsert_thread(thread);
 
@@ -1388,6 +1744,15 @@ mod tests {
         assert!(cache.get_thread("thread-001").is_none());
     }
 
+    #[test]
+    fn test_set_thread_preview() {
+        let mut cache = ThreadCache::with_stub_data();
+
+        assert!(cache.set_thread_preview("thread-001", "✅ 14 tests fixed".to_string()));
+        assert_eq!(cache.get_thread("thread-001").unwrap().preview, "✅ 14 tests fixed");
+        assert!(!cache.set_thread_preview("missing", "x".to_string()));
+    }
+
     #[test]
     fn test_remove_thread_returns_false_if_not_existed() {
         let mut cache = ThreadCache::new();
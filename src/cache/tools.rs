@@ -72,13 +72,9 @@ impl ThreadCache {
         if let Some(messages) = self.messages.get_mut(&resolved_id) {
             // Search recent messages for the tool
             for msg in messages.iter_mut().rev().take(5) {
-                for segment in &mut msg.segments {
-                    if let crate::models::MessageSegment::ToolEvent(event) = segment {
-                        if event.tool_call_id == tool_call_id {
-                            event.set_result(content, is_error);
-                            return;
-                        }
-                    }
+                if msg.get_tool_event(tool_call_id).is_some() {
+                    msg.set_tool_result(tool_call_id, content, is_error);
+                    return;
                 }
             }
         }
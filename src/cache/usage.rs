@@ -0,0 +1,143 @@
+//! Token usage per thread for ThreadCache
+//!
+//! Counts are kept for the session in a map beside the threads, so refreshing
+//! a thread from the server (which doesn't send them) leaves them alone, and
+//! they move with the thread when a pending ID is reconciled.
+//!
+//! The backend's `usage` event follows each turn's `done`. Its reported
+//! prompt and completion tokens are added when present; otherwise the context
+//! window used stands in for the prompt, and the completion is estimated from
+//! the streamed text.
+
+use super::ThreadCache;
+
+/// Token counts of one thread this session
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    pub prompt_tokens: u64,
+    /// Completion tokens of finished turns
+    pub completion_tokens: u64,
+    /// Completion tokens estimated from the text streamed since the last
+    /// `usage` event, until it reports the real count
+    pub streamed_estimate: u64,
+    /// Turns a `usage` event was received for
+    pub turns: u32,
+}
+
+impl TokenUsage {
+    /// Completion tokens, counting the estimate of the turn in progress
+    pub fn completion(&self) -> u64 {
+        self.completion_tokens + self.streamed_estimate
+    }
+
+    /// Prompt and completion tokens together
+    pub fn total(&self) -> u64 {
+        self.prompt_tokens + self.completion()
+    }
+
+    /// Add `other`'s counts to these
+    fn merge(&mut self, other: TokenUsage) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.streamed_estimate += other.streamed_estimate;
+        self.turns += other.turns;
+    }
+}
+
+impl ThreadCache {
+    /// Usage of a thread, if any was recorded
+    pub fn thread_usage(&self, thread_id: &str) -> Option<TokenUsage> {
+        self.usage.get(self.resolve_thread_id(thread_id)).copied()
+    }
+
+    /// Usage of every thread that has any, by thread ID
+    pub fn usage_by_thread(&self) -> &std::collections::HashMap<String, TokenUsage> {
+        &self.usage
+    }
+
+    /// Count estimated completion tokens streamed to a thread
+    pub fn add_streamed_tokens(&mut self, thread_id: &str, tokens: u64) {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        self.usage.entry(resolved_id).or_default().streamed_estimate += tokens;
+    }
+
+    /// Record a turn's `usage` event.
+    ///
+    /// `context_used` counts as the prompt when `prompt_tokens` isn't
+    /// reported; a reported `completion_tokens` replaces the streamed estimate.
+    pub fn record_turn_usage(
+        &mut self,
+        thread_id: &str,
+        context_used: u32,
+        prompt_tokens: Option<u64>,
+        completion_tokens: Option<u64>,
+    ) {
+        let resolved_id = self.resolve_thread_id(thread_id).to_string();
+        let usage = self.usage.entry(resolved_id).or_default();
+        usage.prompt_tokens += prompt_tokens.unwrap_or(u64::from(context_used));
+        usage.completion_tokens += completion_tokens.unwrap_or(usage.streamed_estimate);
+        usage.streamed_estimate = 0;
+        usage.turns += 1;
+    }
+
+    /// Move usage recorded under a pending ID to the thread's real ID
+    pub(super) fn reconcile_usage(&mut self, pending_id: &str, real_id: &str) {
+        if let Some(usage) = self.usage.remove(pending_id) {
+            self.usage
+                .entry(real_id.to_string())
+                .or_default()
+                .merge(usage);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reported_completion_replaces_estimate() {
+        let mut cache = ThreadCache::new();
+        cache.add_streamed_tokens("t-1", 40);
+        assert_eq!(cache.thread_usage("t-1").unwrap().total(), 40);
+
+        cache.record_turn_usage("t-1", 10_000, Some(9_000), Some(55));
+        let usage = cache.thread_usage("t-1").unwrap();
+        assert_eq!(usage.prompt_tokens, 9_000);
+        assert_eq!(usage.completion(), 55);
+        assert_eq!(usage.turns, 1);
+
+        // Without reported counts: the context window and the estimate
+        cache.add_streamed_tokens("t-1", 20);
+        cache.record_turn_usage("t-1", 12_000, None, None);
+        let usage = cache.thread_usage("t-1").unwrap();
+        assert_eq!(usage.prompt_tokens, 21_000);
+        assert_eq!(usage.completion(), 75);
+        assert_eq!(usage.streamed_estimate, 0);
+    }
+
+    #[test]
+    fn test_usage_follows_reconciled_thread() {
+        let mut cache = ThreadCache::new();
+        let pending_id = cache.create_streaming_thread("Hello".to_string());
+        cache.add_streamed_tokens(&pending_id, 30);
+
+        cache.reconcile_thread_id(&pending_id, "real-id", None);
+        // Late events still using the pending ID land on the real thread
+        cache.record_turn_usage(&pending_id, 1_000, None, None);
+
+        let usage = cache.thread_usage("real-id").unwrap();
+        assert_eq!(usage.total(), 1_030);
+        assert!(!cache.usage_by_thread().contains_key(&pending_id));
+    }
+
+    #[test]
+    fn test_refetching_thread_keeps_usage() {
+        let mut cache = ThreadCache::with_stub_data();
+        let thread = cache.threads()[0].clone();
+        cache.record_turn_usage(&thread.id, 5_000, None, Some(100));
+
+        cache.upsert_thread(thread.clone());
+        assert_eq!(cache.thread_usage(&thread.id).unwrap().total(), 5_100);
+    }
+}
@@ -12,6 +12,61 @@ pub enum CliCommand {
     Update,
     /// Sync tokens to VPS
     Sync,
+    /// Run diagnostics (`doctor --theme`: theme contrast check)
+    Doctor,
+    /// Probe terminal capabilities and save them to config
+    Setup,
+    /// Print backend, auth and update diagnostics
+    Status,
+    /// Import conversation history from another tool
+    /// (`import --from <source> <path>`)
+    Import {
+        /// Transcript format (e.g. "claude-code", "json")
+        from: String,
+        /// File or directory of transcripts
+        path: Option<String>,
+    },
+    /// Export a thread (`export <thread-id> [--format md|json] [--out PATH]`)
+    Export {
+        /// Thread to export
+        thread_id: Option<String>,
+        /// Output format ("md" or "json")
+        format: String,
+        /// Output file (defaults to the current directory)
+        out: Option<String>,
+    },
+    /// Send a prompt and stream the answer to stdout
+    /// (`ask <prompt> [--thread ID] [--dir PATH] [--type conversation|programming]
+    /// [--json] [--permission-mode default|plan|bypass]`)
+    Ask {
+        /// Prompt to send
+        prompt: Option<String>,
+        /// Thread to continue (a new thread is created otherwise)
+        thread_id: Option<String>,
+        /// Working directory for the thread
+        dir: Option<String>,
+        /// Thread type ("conversation" or "programming")
+        thread_type: String,
+        /// Emit newline-delimited JSON events instead of plain text
+        json: bool,
+        /// Permission mode ("default", "plan" or "bypass")
+        permission_mode: String,
+    },
+    /// Write the settings profile to a file (`config export [--out PATH]`)
+    ConfigExport {
+        /// Output file (defaults to `spoq-settings.json`)
+        out: Option<String>,
+    },
+    /// Apply a settings profile
+    /// (`config import <path> [--strategy merge|replace|keep] [--dry-run]`)
+    ConfigImport {
+        /// Profile file to import
+        path: Option<String>,
+        /// How imported settings combine with existing ones
+        strategy: String,
+        /// Show the changes without writing anything
+        dry_run: bool,
+    },
     /// Run the TUI application (default)
     RunTui,
 }
@@ -38,18 +93,210 @@ pub fn parse_args<I>(args: I) -> CliCommand
 where
     I: Iterator<Item = String>,
 {
-    for arg in args.skip(1) {
-        // Skip the program name
+    let mut args = args.skip(1); // Skip the program name
+    while let Some(arg) = args.next() {
         match arg.as_str() {
             "--version" | "-V" => return CliCommand::Version,
             "--update" => return CliCommand::Update,
             "--sync" | "/sync" => return CliCommand::Sync,
+            "doctor" => return CliCommand::Doctor,
+            "setup" => return CliCommand::Setup,
+            "status" => return CliCommand::Status,
+            "import" => return parse_import_args(args),
+            "export" => return parse_export_args(args),
+            "ask" => return parse_ask_args(args),
+            "config" => return parse_config_args(args),
             _ => {}
         }
     }
     CliCommand::RunTui
 }
 
+/// Find `--inject-faults[=SPEC]` among the arguments.
+///
+/// Returns the fault spec ("" for the defaults) when the flag is present.
+/// The flag only changes how the TUI behaves, so [`parse_args`] ignores it.
+pub fn parse_inject_faults<I>(args: I) -> Option<String>
+where
+    I: Iterator<Item = String>,
+{
+    args.skip(1).find_map(|arg| {
+        if arg == "--inject-faults" {
+            Some(String::new())
+        } else {
+            arg.strip_prefix("--inject-faults=").map(str::to_string)
+        }
+    })
+}
+
+/// Whether `--safe-mode` is among the arguments.
+///
+/// Like `--inject-faults`, it only changes how the TUI starts, so
+/// [`parse_args`] ignores it.
+pub fn parse_safe_mode<I>(args: I) -> bool
+where
+    I: Iterator<Item = String>,
+{
+    args.skip(1).any(|arg| arg == "--safe-mode")
+}
+
+/// Whether `--fresh` is among the arguments: start on the CommandDeck
+/// instead of resuming the last session.
+///
+/// Like `--safe-mode`, [`parse_args`] ignores it.
+pub fn parse_fresh<I>(args: I) -> bool
+where
+    I: Iterator<Item = String>,
+{
+    args.skip(1).any(|arg| arg == "--fresh")
+}
+
+/// Parse the arguments following `import`.
+fn parse_import_args<I>(mut args: I) -> CliCommand
+where
+    I: Iterator<Item = String>,
+{
+    let mut from = "claude-code".to_string();
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        if arg == "--from" {
+            if let Some(value) = args.next() {
+                from = value;
+            }
+        } else if let Some(value) = arg.strip_prefix("--from=") {
+            from = value.to_string();
+        } else if path.is_none() {
+            path = Some(arg);
+        }
+    }
+    CliCommand::Import { from, path }
+}
+
+/// Parse the arguments following `export`.
+fn parse_export_args<I>(mut args: I) -> CliCommand
+where
+    I: Iterator<Item = String>,
+{
+    let mut thread_id = None;
+    let mut format = "md".to_string();
+    let mut out = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                if let Some(value) = args.next() {
+                    format = value;
+                }
+            }
+            "--out" | "-o" => out = args.next(),
+            _ => {
+                if let Some(value) = arg.strip_prefix("--format=") {
+                    format = value.to_string();
+                } else if let Some(value) = arg.strip_prefix("--out=") {
+                    out = Some(value.to_string());
+                } else if thread_id.is_none() {
+                    thread_id = Some(arg);
+                }
+            }
+        }
+    }
+    CliCommand::Export {
+        thread_id,
+        format,
+        out,
+    }
+}
+
+/// Parse the arguments following `ask`.
+fn parse_ask_args<I>(mut args: I) -> CliCommand
+where
+    I: Iterator<Item = String>,
+{
+    let mut prompt = None;
+    let mut thread_id = None;
+    let mut dir = None;
+    let mut thread_type = "conversation".to_string();
+    let mut json = false;
+    let mut permission_mode = "default".to_string();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--thread" | "-t" => thread_id = args.next(),
+            "--dir" | "-d" => dir = args.next(),
+            "--type" => {
+                if let Some(value) = args.next() {
+                    thread_type = value;
+                }
+            }
+            "--json" => json = true,
+            "--permission-mode" => {
+                if let Some(value) = args.next() {
+                    permission_mode = value;
+                }
+            }
+            _ => {
+                if let Some(value) = arg.strip_prefix("--thread=") {
+                    thread_id = Some(value.to_string());
+                } else if let Some(value) = arg.strip_prefix("--dir=") {
+                    dir = Some(value.to_string());
+                } else if let Some(value) = arg.strip_prefix("--type=") {
+                    thread_type = value.to_string();
+                } else if let Some(value) = arg.strip_prefix("--permission-mode=") {
+                    permission_mode = value.to_string();
+                } else if prompt.is_none() {
+                    prompt = Some(arg);
+                }
+            }
+        }
+    }
+    CliCommand::Ask {
+        prompt,
+        thread_id,
+        dir,
+        thread_type,
+        json,
+        permission_mode,
+    }
+}
+
+/// Parse the arguments following `config`.
+fn parse_config_args<I>(mut args: I) -> CliCommand
+where
+    I: Iterator<Item = String>,
+{
+    let action = args.next();
+    let mut path = None;
+    let mut strategy = "merge".to_string();
+    let mut dry_run = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" | "-o" => path = args.next(),
+            "--strategy" => {
+                if let Some(value) = args.next() {
+                    strategy = value;
+                }
+            }
+            "--dry-run" | "-n" => dry_run = true,
+            _ => {
+                if let Some(value) = arg.strip_prefix("--strategy=") {
+                    strategy = value.to_string();
+                } else if let Some(value) = arg.strip_prefix("--out=") {
+                    path = Some(value.to_string());
+                } else if path.is_none() {
+                    path = Some(arg);
+                }
+            }
+        }
+    }
+    match action.as_deref() {
+        Some("export") => CliCommand::ConfigExport { out: path },
+        Some("import") => CliCommand::ConfigImport {
+            path,
+            strategy,
+            dry_run,
+        },
+        _ => CliCommand::RunTui,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,6 +331,184 @@ mod tests {
         assert_eq!(parse_args(args.into_iter()), CliCommand::Sync);
     }
 
+    #[test]
+    fn test_parse_doctor_theme() {
+        let args = vec!["spoq".to_string(), "doctor".to_string(), "--theme".to_string()];
+        assert_eq!(parse_args(args.into_iter()), CliCommand::Doctor);
+    }
+
+    #[test]
+    fn test_parse_setup() {
+        let args = vec!["spoq".to_string(), "setup".to_string()];
+        assert_eq!(parse_args(args.into_iter()), CliCommand::Setup);
+    }
+
+    #[test]
+    fn test_parse_status() {
+        let args = vec!["spoq".to_string(), "status".to_string()];
+        assert_eq!(parse_args(args.into_iter()), CliCommand::Status);
+    }
+
+    #[test]
+    fn test_parse_inject_faults() {
+        let args = ["spoq", "--inject-faults"].map(String::from);
+        assert_eq!(parse_inject_faults(args.into_iter()), Some(String::new()));
+
+        let args = ["spoq", "--inject-faults=ws-drop=0.5"].map(String::from);
+        assert_eq!(
+            parse_inject_faults(args.clone().into_iter()),
+            Some("ws-drop=0.5".to_string())
+        );
+        assert_eq!(parse_args(args.into_iter()), CliCommand::RunTui);
+
+        let args = ["spoq"].map(String::from);
+        assert_eq!(parse_inject_faults(args.into_iter()), None);
+    }
+
+    #[test]
+    fn test_parse_safe_mode() {
+        let args = ["spoq", "--safe-mode"].map(String::from);
+        assert!(parse_safe_mode(args.clone().into_iter()));
+        assert_eq!(parse_args(args.into_iter()), CliCommand::RunTui);
+
+        let args = ["spoq"].map(String::from);
+        assert!(!parse_safe_mode(args.into_iter()));
+    }
+
+    #[test]
+    fn test_parse_fresh() {
+        let args = ["spoq", "--fresh"].map(String::from);
+        assert!(parse_fresh(args.clone().into_iter()));
+        assert_eq!(parse_args(args.into_iter()), CliCommand::RunTui);
+
+        let args = ["spoq", "--safe-mode"].map(String::from);
+        assert!(!parse_fresh(args.into_iter()));
+    }
+
+    #[test]
+    fn test_parse_import() {
+        let args = ["spoq", "import", "--from", "json", "threads.json"].map(String::from);
+        assert_eq!(
+            parse_args(args.into_iter()),
+            CliCommand::Import {
+                from: "json".to_string(),
+                path: Some("threads.json".to_string()),
+            }
+        );
+
+        let args = ["spoq", "import", "session.jsonl"].map(String::from);
+        assert_eq!(
+            parse_args(args.into_iter()),
+            CliCommand::Import {
+                from: "claude-code".to_string(),
+                path: Some("session.jsonl".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_export() {
+        let args = ["spoq", "export", "abc-123", "--format", "json", "--out", "t.json"]
+            .map(String::from);
+        assert_eq!(
+            parse_args(args.into_iter()),
+            CliCommand::Export {
+                thread_id: Some("abc-123".to_string()),
+                format: "json".to_string(),
+                out: Some("t.json".to_string()),
+            }
+        );
+
+        let args = ["spoq", "export", "abc-123"].map(String::from);
+        assert_eq!(
+            parse_args(args.into_iter()),
+            CliCommand::Export {
+                thread_id: Some("abc-123".to_string()),
+                format: "md".to_string(),
+                out: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_ask() {
+        let args = [
+            "spoq",
+            "ask",
+            "summarize this diff",
+            "--dir",
+            "/home/me/proj",
+            "--type=programming",
+            "--json",
+            "--permission-mode",
+            "bypass",
+        ]
+        .map(String::from);
+        assert_eq!(
+            parse_args(args.into_iter()),
+            CliCommand::Ask {
+                prompt: Some("summarize this diff".to_string()),
+                thread_id: None,
+                dir: Some("/home/me/proj".to_string()),
+                thread_type: "programming".to_string(),
+                json: true,
+                permission_mode: "bypass".to_string(),
+            }
+        );
+
+        let args = ["spoq", "ask", "--thread", "abc-123", "and now?"].map(String::from);
+        assert_eq!(
+            parse_args(args.into_iter()),
+            CliCommand::Ask {
+                prompt: Some("and now?".to_string()),
+                thread_id: Some("abc-123".to_string()),
+                dir: None,
+                thread_type: "conversation".to_string(),
+                json: false,
+                permission_mode: "default".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let args = ["spoq", "config", "export", "--out", "team.json"].map(String::from);
+        assert_eq!(
+            parse_args(args.into_iter()),
+            CliCommand::ConfigExport {
+                out: Some("team.json".to_string()),
+            }
+        );
+
+        let args = [
+            "spoq",
+            "config",
+            "import",
+            "team.json",
+            "--strategy=keep",
+            "--dry-run",
+        ]
+        .map(String::from);
+        assert_eq!(
+            parse_args(args.into_iter()),
+            CliCommand::ConfigImport {
+                path: Some("team.json".to_string()),
+                strategy: "keep".to_string(),
+                dry_run: true,
+            }
+        );
+
+        let args = ["spoq", "config", "import", "team.json"].map(String::from);
+        assert_eq!(
+            parse_args(args.into_iter()),
+            CliCommand::ConfigImport {
+                path: Some("team.json".to_string()),
+                strategy: "merge".to_string(),
+                dry_run: false,
+            }
+        );
+    }
+
     #[test]
     fn test_parse_no_args() {
         let args = vec!["spoq".to_string()];
@@ -0,0 +1,223 @@
+//! Ask command for Spoq CLI.
+//!
+//! `spoq ask <prompt>` sends a prompt without starting the TUI and streams
+//! the answer to stdout, for use from scripts. Nobody is around to answer
+//! permission prompts, so they're denied unless `--permission-mode bypass`
+//! is passed. Ctrl+C cancels the response on the backend before exiting.
+
+use color_eyre::{eyre::eyre, Result};
+use futures_util::StreamExt;
+
+use crate::auth::CredentialsManager;
+use crate::cli::backend::connect_conductor;
+use crate::cli_output::StreamPrinter;
+use crate::conductor::ConductorClient;
+use crate::models::{PermissionMode, StreamRequest, ThreadType};
+use crate::events::SseEvent;
+use crate::startup::auth::attempt_token_refresh;
+
+const USAGE: &str = "Usage: spoq ask <prompt> [--thread ID] [--dir PATH] \
+    [--type conversation|programming] [--json] [--permission-mode default|plan|bypass]";
+
+/// Parse a `--type` value
+fn parse_thread_type(name: &str) -> Option<ThreadType> {
+    match name.to_lowercase().as_str() {
+        "conversation" | "normal" => Some(ThreadType::Conversation),
+        "programming" | "code" => Some(ThreadType::Programming),
+        _ => None,
+    }
+}
+
+/// Parse a `--permission-mode` value
+fn parse_permission_mode(name: &str) -> Option<PermissionMode> {
+    match name.to_lowercase().as_str() {
+        "default" => Some(PermissionMode::Default),
+        "plan" => Some(PermissionMode::Plan),
+        "bypass" | "execution" => Some(PermissionMode::Execution),
+        _ => None,
+    }
+}
+
+/// Handle `spoq ask <prompt> [--thread ID] [--dir PATH] [--type TYPE] [--json]
+/// [--permission-mode MODE]`.
+///
+/// # Errors
+///
+/// Returns an error if the arguments are invalid, the user isn't signed in,
+/// the stream fails or reports an error, or the user interrupts it.
+pub fn handle_ask_command(
+    prompt: Option<&str>,
+    thread_id: Option<&str>,
+    dir: Option<&str>,
+    thread_type: &str,
+    json: bool,
+    permission_mode: &str,
+) -> Result<()> {
+    let prompt = prompt
+        .filter(|p| !p.trim().is_empty())
+        .ok_or_else(|| eyre!(USAGE))?;
+    let thread_type = parse_thread_type(thread_type).ok_or_else(|| {
+        eyre!(
+            "Unknown thread type '{}' (expected conversation or programming)",
+            thread_type
+        )
+    })?;
+    let mode = parse_permission_mode(permission_mode).ok_or_else(|| {
+        eyre!(
+            "Unknown permission mode '{}' (expected default, plan or bypass)",
+            permission_mode
+        )
+    })?;
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    refresh_expired_credentials(&runtime)?;
+    let conductor = connect_conductor(&runtime)?;
+
+    let request = match thread_id {
+        Some(id) => StreamRequest::with_thread(prompt.to_string(), id.to_string()),
+        None => StreamRequest::new(prompt.to_string()),
+    }
+    .with_type(thread_type)
+    .with_permission_mode(mode)
+    .with_working_directory(dir.map(str::to_string));
+
+    let mut printer = StreamPrinter::stdio(json);
+    runtime.block_on(stream_answer(
+        &conductor,
+        &request,
+        mode == PermissionMode::Execution,
+        &mut printer,
+    ))
+}
+
+/// Refresh an expired access token the way startup does, but without
+/// falling back to the interactive sign-in flow.
+fn refresh_expired_credentials(runtime: &tokio::runtime::Runtime) -> Result<()> {
+    let manager = CredentialsManager::new()
+        .ok_or_else(|| eyre!("Failed to initialize credentials manager"))?;
+    let credentials = manager.load();
+    if credentials.has_token() && credentials.is_expired() {
+        attempt_token_refresh(runtime, &credentials, &manager).map_err(|e| {
+            eyre!(
+                "Session expired and could not be refreshed ({}). Please run spoq to sign in again.",
+                e
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Stream the answer until it's done, fails, or Ctrl+C is pressed.
+async fn stream_answer<O: std::io::Write, E: std::io::Write>(
+    conductor: &ConductorClient,
+    request: &StreamRequest,
+    bypass: bool,
+    printer: &mut StreamPrinter<O, E>,
+) -> Result<()> {
+    let mut stream = conductor
+        .stream(request)
+        .await
+        .map_err(|e| eyre!("Failed to start stream: {}", e))?;
+    let mut thread_id = request.thread_id.clone();
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    loop {
+        let event = tokio::select! {
+            _ = &mut ctrl_c => {
+                printer.finish()?;
+                match thread_id {
+                    Some(ref id) => {
+                        if let Err(e) = conductor.cancel_stream(id).await {
+                            printer.note(&format!("failed to cancel the response: {}", e))?;
+                        }
+                    }
+                    None => printer.note("interrupted before the thread was created")?,
+                }
+                return Err(eyre!("Interrupted"));
+            }
+            event = stream.next() => event,
+        };
+
+        let event = match event {
+            Some(Ok(event)) => event,
+            Some(Err(e)) => {
+                printer.finish()?;
+                return Err(eyre!("Stream error: {}", e));
+            }
+            None => {
+                printer.finish()?;
+                return Err(eyre!("Connection closed before the response finished"));
+            }
+        };
+        printer.event(&event)?;
+
+        match event {
+            SseEvent::UserMessageSaved(saved) => thread_id = Some(saved.thread_id),
+            SseEvent::PermissionRequest(request) => {
+                if !bypass {
+                    printer.note(&format!(
+                        "denied {} (pass --permission-mode bypass to allow tools)",
+                        request.tool_name
+                    ))?;
+                }
+                if let Err(e) = conductor
+                    .respond_to_permission(&request.permission_id, bypass)
+                    .await
+                {
+                    printer.note(&format!("failed to answer permission request: {}", e))?;
+                }
+            }
+            SseEvent::Error(error) => {
+                printer.finish()?;
+                return Err(eyre!("{}", error.message));
+            }
+            SseEvent::Done(_) => {
+                printer.finish()?;
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_thread_type() {
+        assert_eq!(
+            parse_thread_type("conversation"),
+            Some(ThreadType::Conversation)
+        );
+        assert_eq!(
+            parse_thread_type("Programming"),
+            Some(ThreadType::Programming)
+        );
+        assert_eq!(parse_thread_type("chat"), None);
+    }
+
+    #[test]
+    fn test_parse_permission_mode() {
+        assert_eq!(
+            parse_permission_mode("default"),
+            Some(PermissionMode::Default)
+        );
+        assert_eq!(
+            parse_permission_mode("bypass"),
+            Some(PermissionMode::Execution)
+        );
+        assert_eq!(parse_permission_mode("yolo"), None);
+    }
+
+    #[test]
+    fn test_ask_requires_a_prompt() {
+        let err =
+            handle_ask_command(None, None, None, "conversation", false, "default").unwrap_err();
+        assert!(err.to_string().starts_with("Usage: spoq ask"));
+
+        let err = handle_ask_command(Some("hi"), None, None, "chat", false, "default").unwrap_err();
+        assert!(err.to_string().contains("Unknown thread type"));
+    }
+}
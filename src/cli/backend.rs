@@ -0,0 +1,56 @@
+//! Backend access for CLI commands.
+//!
+//! Resolves the conductor for the signed-in user the same way the TUI does:
+//! the local conductor when `~/.spoq/config.json` selects local mode,
+//! otherwise the user's VPS.
+
+use color_eyre::{eyre::eyre, Result};
+
+use crate::auth::central_api::CentralApiClient;
+use crate::auth::CredentialsManager;
+use crate::conductor::{local, ConductorClient};
+use crate::startup::SpoqConfig;
+
+/// Build an authenticated conductor client for CLI commands.
+///
+/// # Errors
+///
+/// Returns an error if the user is not signed in or has no VPS.
+pub fn connect_conductor(runtime: &tokio::runtime::Runtime) -> Result<ConductorClient> {
+    let manager = CredentialsManager::new()
+        .ok_or_else(|| eyre!("Failed to initialize credentials manager"))?;
+    let credentials = manager.load();
+    if !credentials.has_token() {
+        return Err(eyre!("Not authenticated. Please run spoq to authenticate first."));
+    }
+
+    let spoq_config = SpoqConfig::load();
+    let url = if spoq_config.is_local() {
+        spoq_config
+            .conductor_url
+            .unwrap_or_else(|| format!("http://127.0.0.1:{}", local::default_port()))
+    } else {
+        let mut client = CentralApiClient::new();
+        if let Some(ref token) = credentials.access_token {
+            client = client.with_auth(token);
+        }
+        let vps = runtime
+            .block_on(client.fetch_user_vps())
+            .map_err(|e| eyre!("Cannot verify VPS status: {}", e))?
+            .ok_or_else(|| eyre!("No VPS configured. Please run spoq to provision a VPS first."))?;
+        match (vps.url, vps.ip) {
+            (Some(url), _) => url,
+            (None, Some(ip)) => format!("http://{}:8000", ip),
+            (None, None) => return Err(eyre!("VPS has no URL or IP configured.")),
+        }
+    };
+
+    let mut conductor = ConductorClient::with_url(&url);
+    if let Some(ref token) = credentials.access_token {
+        conductor = conductor.with_auth(token);
+    }
+    if let Some(ref refresh) = credentials.refresh_token {
+        conductor = conductor.with_refresh_token(refresh);
+    }
+    Ok(conductor)
+}
@@ -0,0 +1,498 @@
+//! Settings profile export and import for Spoq CLI.
+//!
+//! Bundles the local settings files into one JSON profile that can be shared
+//! across a team:
+//!
+//! ```text
+//! spoq config export [--out spoq-settings.json]
+//! spoq config import spoq-settings.json [--strategy merge|replace|keep] [--dry-run]
+//! ```
+//!
+//! A profile holds the theme (`~/.spoq/theme.json`), the keymap
+//! (`~/.config/spoq/keymap.toml`) and the notification rules
+//! (`~/.spoq/webhooks.json`). Permission policies and prompt templates are
+//! kept by the conductor rather than in local files, so they aren't part of
+//! it; sections this version doesn't know are reported and skipped.
+//!
+//! Import strategies:
+//! - `merge` (default): imported values win, local values the profile
+//!   doesn't mention are kept
+//! - `replace`: imported files overwrite local ones
+//! - `keep`: only settings files that don't exist locally are written
+//!
+//! `--dry-run` prints a line diff of every file that would change.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use color_eyre::{eyre::eyre, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::startup::keymap::keymap_path;
+use crate::startup::CustomTheme;
+use crate::ui::messages::{diff_lines, DiffLine};
+use crate::webhooks::WebhookConfig;
+
+/// Profile format version written by this build
+pub const PROFILE_VERSION: u32 = 1;
+
+/// Default output file of `spoq config export`
+const DEFAULT_PROFILE_FILE: &str = "spoq-settings.json";
+
+/// A settings file that can be part of a profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsSection {
+    Theme,
+    Keymap,
+    Webhooks,
+}
+
+impl SettingsSection {
+    /// Every section, in profile order
+    pub const ALL: [SettingsSection; 3] = [
+        SettingsSection::Theme,
+        SettingsSection::Keymap,
+        SettingsSection::Webhooks,
+    ];
+
+    /// Key of the section in the profile
+    pub fn name(&self) -> &'static str {
+        match self {
+            SettingsSection::Theme => "theme",
+            SettingsSection::Keymap => "keymap",
+            SettingsSection::Webhooks => "webhooks",
+        }
+    }
+
+    /// Look up a section by its profile key
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|section| section.name() == name)
+    }
+
+    /// Local path of the settings file
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            SettingsSection::Theme => CustomTheme::path(),
+            SettingsSection::Keymap => keymap_path(),
+            SettingsSection::Webhooks => WebhookConfig::path(),
+        }
+    }
+
+    /// Whether the file is JSON (merged by key) rather than a keymap TOML
+    fn is_json(&self) -> bool {
+        !matches!(self, SettingsSection::Keymap)
+    }
+}
+
+/// How imported settings combine with the local ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    Merge,
+    Replace,
+    KeepExisting,
+}
+
+impl MergeStrategy {
+    /// Parse a `--strategy` value.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "merge" => Some(Self::Merge),
+            "replace" | "overwrite" => Some(Self::Replace),
+            "keep" | "keep-existing" => Some(Self::KeepExisting),
+            _ => None,
+        }
+    }
+}
+
+/// A shareable bundle of settings files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SettingsProfile {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    /// File contents by section name
+    #[serde(default)]
+    pub sections: BTreeMap<String, String>,
+}
+
+impl SettingsProfile {
+    /// Build a profile from the files `read` returns for each section
+    pub fn collect(read: impl Fn(SettingsSection) -> Option<String>) -> Self {
+        let sections = SettingsSection::ALL
+            .into_iter()
+            .filter_map(|section| read(section).map(|c| (section.name().to_string(), c)))
+            .collect();
+        Self {
+            version: PROFILE_VERSION,
+            exported_at: Utc::now(),
+            sections,
+        }
+    }
+}
+
+/// What importing a profile does to one settings file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionChange {
+    pub section: SettingsSection,
+    /// Current contents (None if the file doesn't exist)
+    pub before: Option<String>,
+    /// Contents after the import
+    pub after: String,
+}
+
+impl SectionChange {
+    /// Whether the import leaves the file as it is
+    pub fn is_unchanged(&self) -> bool {
+        self.before.as_deref() == Some(self.after.as_str())
+    }
+}
+
+/// Result of planning an import
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportPlan {
+    pub changes: Vec<SectionChange>,
+    /// Sections of the profile this version doesn't know
+    pub skipped: Vec<String>,
+}
+
+/// Work out the contents of every settings file after importing `profile`.
+///
+/// `read` returns the current contents of a section's file.
+///
+/// # Errors
+///
+/// Returns an error if the profile is from a newer format version or a
+/// section can't be merged (invalid JSON).
+pub fn plan_import(
+    profile: &SettingsProfile,
+    strategy: MergeStrategy,
+    read: impl Fn(SettingsSection) -> Option<String>,
+) -> Result<ImportPlan> {
+    if profile.version > PROFILE_VERSION {
+        return Err(eyre!(
+            "Profile version {} is newer than this spoq supports ({}); update spoq first",
+            profile.version,
+            PROFILE_VERSION
+        ));
+    }
+
+    let mut plan = ImportPlan::default();
+    for (name, incoming) in &profile.sections {
+        let Some(section) = SettingsSection::from_name(name) else {
+            plan.skipped.push(name.clone());
+            continue;
+        };
+        let before = read(section);
+        let after = match (before.as_deref(), strategy) {
+            (None, _) | (Some(_), MergeStrategy::Replace) => incoming.clone(),
+            (Some(existing), MergeStrategy::KeepExisting) => existing.to_string(),
+            (Some(existing), MergeStrategy::Merge) if section.is_json() => {
+                merge_json_text(existing, incoming)
+                    .map_err(|e| eyre!("Cannot merge {}: {}", name, e))?
+            }
+            (Some(existing), MergeStrategy::Merge) => merge_keymap(existing, incoming),
+        };
+        plan.changes.push(SectionChange {
+            section,
+            before,
+            after,
+        });
+    }
+    Ok(plan)
+}
+
+/// Merge two JSON documents, `incoming` winning on conflicts
+fn merge_json_text(existing: &str, incoming: &str) -> Result<String, serde_json::Error> {
+    let mut merged: Value = serde_json::from_str(existing)?;
+    merge_json(&mut merged, serde_json::from_str(incoming)?);
+    let mut text = serde_json::to_string_pretty(&merged)?;
+    text.push('\n');
+    Ok(text)
+}
+
+/// Objects are merged key by key, arrays gain the items they don't have yet,
+/// anything else is replaced
+fn merge_json(base: &mut Value, incoming: Value) {
+    match (base, incoming) {
+        (Value::Object(base), Value::Object(incoming)) => {
+            for (key, value) in incoming {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(incoming)) => {
+            for item in incoming {
+                if !base.contains(&item) {
+                    base.push(item);
+                }
+            }
+        }
+        (base, incoming) => *base = incoming,
+    }
+}
+
+/// Action name bound on a keymap line (`NewThread = "alt+n"`)
+fn keymap_line_key(line: &str) -> Option<&str> {
+    let line = line.trim();
+    if line.starts_with('#') {
+        return None;
+    }
+    line.split_once('=').map(|(key, _)| key.trim())
+}
+
+/// Merge two keymap files: bindings in `incoming` replace the local binding
+/// of the same action, new ones are appended
+fn merge_keymap(existing: &str, incoming: &str) -> String {
+    let incoming_lines: Vec<(&str, &str)> = incoming
+        .lines()
+        .filter_map(|line| keymap_line_key(line).map(|key| (key, line)))
+        .collect();
+    let find = |key: &str| incoming_lines.iter().find(|(k, _)| *k == key);
+
+    let mut merged: Vec<&str> = Vec::new();
+    let mut existing_keys = Vec::new();
+    for line in existing.lines() {
+        match keymap_line_key(line) {
+            Some(key) => {
+                existing_keys.push(key);
+                merged.push(find(key).map_or(line, |(_, l)| *l));
+            }
+            None => merged.push(line),
+        }
+    }
+    merged.extend(
+        incoming_lines
+            .iter()
+            .filter(|(key, _)| !existing_keys.contains(key))
+            .map(|(_, line)| *line),
+    );
+
+    let mut text = merged.join("\n");
+    text.push('\n');
+    text
+}
+
+/// Current contents of a section's file, None if it doesn't exist
+fn read_section(section: SettingsSection) -> Option<String> {
+    section
+        .path()
+        .and_then(|path| fs::read_to_string(path).ok())
+}
+
+/// Handle `spoq config export [--out PATH]`.
+///
+/// # Errors
+///
+/// Returns an error if the profile cannot be written.
+pub fn handle_config_export_command(out: Option<&str>) -> Result<()> {
+    let profile = SettingsProfile::collect(read_section);
+    let path = PathBuf::from(out.unwrap_or(DEFAULT_PROFILE_FILE));
+    let json = serde_json::to_string_pretty(&profile)?;
+    fs::write(&path, json).map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))?;
+
+    if profile.sections.is_empty() {
+        println!(
+            "No settings files found; wrote an empty profile to {}",
+            path.display()
+        );
+    } else {
+        let names: Vec<&str> = profile.sections.keys().map(String::as_str).collect();
+        println!("Exported {} to {}", names.join(", "), path.display());
+    }
+    Ok(())
+}
+
+/// Handle `spoq config import <path> [--strategy merge|replace|keep] [--dry-run]`.
+///
+/// # Errors
+///
+/// Returns an error if the arguments are invalid, the profile cannot be read
+/// or merged, or a settings file cannot be written.
+pub fn handle_config_import_command(
+    path: Option<&str>,
+    strategy: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let path = path.ok_or_else(|| {
+        eyre!("Usage: spoq config import <path> [--strategy merge|replace|keep] [--dry-run]")
+    })?;
+    let strategy = MergeStrategy::parse(strategy).ok_or_else(|| {
+        eyre!(
+            "Unknown strategy '{}' (expected merge, replace or keep)",
+            strategy
+        )
+    })?;
+    let contents = fs::read_to_string(path).map_err(|e| eyre!("Failed to read {}: {}", path, e))?;
+    let profile: SettingsProfile =
+        serde_json::from_str(&contents).map_err(|e| eyre!("Invalid profile {}: {}", path, e))?;
+
+    let plan = plan_import(&profile, strategy, read_section)?;
+    for name in &plan.skipped {
+        println!("Skipping unknown section '{}'", name);
+    }
+
+    for change in &plan.changes {
+        let target = change
+            .section
+            .path()
+            .ok_or_else(|| eyre!("Home directory not found"))?;
+        if change.is_unchanged() {
+            println!(
+                "{} ({}): unchanged",
+                change.section.name(),
+                target.display()
+            );
+            continue;
+        }
+        if dry_run {
+            print_change(change, &target);
+        } else {
+            write_settings_file(&target, &change.after)?;
+            println!("Updated {}", target.display());
+        }
+    }
+
+    if dry_run {
+        println!("Dry run: nothing was written");
+    }
+    Ok(())
+}
+
+/// Print the diff of one settings file
+fn print_change(change: &SectionChange, target: &Path) {
+    let verb = if change.before.is_some() {
+        "would update"
+    } else {
+        "would create"
+    };
+    println!("{} ({}): {}", change.section.name(), target.display(), verb);
+    for line in diff_lines(change.before.as_deref().unwrap_or(""), &change.after) {
+        match line {
+            DiffLine::Added(text) => println!("  + {}", text),
+            DiffLine::Removed(text) => println!("  - {}", text),
+            DiffLine::Context(_) | DiffLine::Break => {}
+        }
+    }
+}
+
+/// Write a settings file, creating its directory
+fn write_settings_file(path: &Path, contents: &str) -> Result<()> {
+    if crate::startup::integrity::is_read_only() {
+        return Err(eyre!(
+            "State files are read-only until corrupted files are regenerated"
+        ));
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents).map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(sections: &[(&str, &str)]) -> SettingsProfile {
+        SettingsProfile {
+            version: PROFILE_VERSION,
+            exported_at: Utc::now(),
+            sections: sections
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_collect_includes_existing_files_only() {
+        let profile = SettingsProfile::collect(|section| {
+            (section == SettingsSection::Keymap).then(|| "Quit = []\n".to_string())
+        });
+        assert_eq!(profile.sections.len(), 1);
+        assert_eq!(profile.sections["keymap"], "Quit = []\n");
+
+        let json = serde_json::to_string(&profile).unwrap();
+        let parsed: SettingsProfile = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, profile);
+    }
+
+    #[test]
+    fn test_merge_strategies() {
+        let local = |section: SettingsSection| -> Option<String> {
+            match section {
+                SettingsSection::Theme => Some(r##"{"dim": "#6c6c6c", "accent": "white"}"##.into()),
+                SettingsSection::Keymap => {
+                    Some("# mine\nNewThread = \"alt+n\"\nQuit = []\n".into())
+                }
+                SettingsSection::Webhooks => None,
+            }
+        };
+        let profile = profile(&[
+            ("theme", r#"{"accent": "cyan"}"#),
+            ("keymap", "Quit = \"ctrl+q\"\nToggleSidebar = \"alt+b\"\n"),
+            ("webhooks", r#"{"global": []}"#),
+            ("prompt_templates", "{}"),
+        ]);
+
+        let plan = plan_import(&profile, MergeStrategy::Merge, local).unwrap();
+        assert_eq!(plan.skipped, vec!["prompt_templates"]);
+        let after = |section| {
+            plan.changes
+                .iter()
+                .find(|c| c.section == section)
+                .map(|c| c.after.clone())
+                .unwrap()
+        };
+        let theme: Value = serde_json::from_str(&after(SettingsSection::Theme)).unwrap();
+        assert_eq!(theme["accent"], "cyan");
+        assert_eq!(theme["dim"], "#6c6c6c");
+        assert_eq!(
+            after(SettingsSection::Keymap),
+            "# mine\nNewThread = \"alt+n\"\nQuit = \"ctrl+q\"\nToggleSidebar = \"alt+b\"\n"
+        );
+        assert_eq!(after(SettingsSection::Webhooks), r#"{"global": []}"#);
+
+        let plan = plan_import(&profile, MergeStrategy::KeepExisting, local).unwrap();
+        assert!(plan
+            .changes
+            .iter()
+            .all(|c| c.is_unchanged() || c.section == SettingsSection::Webhooks));
+
+        let plan = plan_import(&profile, MergeStrategy::Replace, local).unwrap();
+        assert!(plan
+            .changes
+            .iter()
+            .all(|c| c.after == profile.sections[c.section.name()]));
+    }
+
+    #[test]
+    fn test_merge_json_unions_arrays() {
+        let merged = merge_json_text(
+            r#"{"global": [{"url": "a"}], "threads": {"t1": []}}"#,
+            r#"{"global": [{"url": "a"}, {"url": "b"}], "threads": {"t2": []}}"#,
+        )
+        .unwrap();
+        let merged: Value = serde_json::from_str(&merged).unwrap();
+        assert_eq!(merged["global"].as_array().unwrap().len(), 2);
+        assert!(merged["threads"]["t1"].is_array());
+        assert!(merged["threads"]["t2"].is_array());
+    }
+
+    #[test]
+    fn test_newer_profile_version_is_rejected() {
+        let mut newer = profile(&[]);
+        newer.version = PROFILE_VERSION + 1;
+        assert!(plan_import(&newer, MergeStrategy::Merge, |_| None).is_err());
+        assert_eq!(
+            MergeStrategy::parse("keep"),
+            Some(MergeStrategy::KeepExisting)
+        );
+        assert_eq!(MergeStrategy::parse("bogus"), None);
+    }
+}
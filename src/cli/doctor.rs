@@ -0,0 +1,108 @@
+//! Doctor command for Spoq CLI.
+//!
+//! `spoq doctor --theme` checks the active theme (`~/.spoq/theme.json`, or the
+//! built-in theme) for colors below the minimum contrast against the terminal
+//! background. The theme check is currently the only check, so plain
+//! `spoq doctor` runs it too.
+
+use color_eyre::{eyre::eyre, Result};
+
+use crate::startup::CustomTheme;
+use crate::ui::contrast::{adjust_for_contrast, theme_contrast_ratios, DEFAULT_MIN_CONTRAST};
+use crate::view_state::Theme;
+
+/// Handle the `doctor` command.
+///
+/// # Errors
+///
+/// Returns an error if the theme file is invalid, or if colors fail the
+/// contrast check and `auto_adjust_contrast` is off.
+pub fn handle_doctor_command() -> Result<()> {
+    let (custom, source) = match CustomTheme::load().map_err(|e| eyre!("{}", e))? {
+        Some(custom) => {
+            let source = CustomTheme::path()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| "theme.json".to_string());
+            (custom, source)
+        }
+        None => (
+            CustomTheme {
+                theme: Theme::default(),
+                background: ratatui::style::Color::Black,
+                min_contrast: DEFAULT_MIN_CONTRAST,
+                auto_adjust_contrast: false,
+            },
+            "built-in theme".to_string(),
+        ),
+    };
+
+    let (report, failures) = theme_report(&custom);
+    println!("Theme: {}", source);
+    for line in report {
+        println!("{}", line);
+    }
+
+    if failures == 0 {
+        println!("\nAll theme colors meet the minimum contrast.");
+        Ok(())
+    } else if custom.auto_adjust_contrast {
+        println!(
+            "\n{} color(s) below minimum contrast; adjusted automatically in the app.",
+            failures
+        );
+        Ok(())
+    } else {
+        Err(eyre!(
+            "{} theme color(s) below minimum contrast (set \"auto_adjust_contrast\": true to fix automatically)",
+            failures
+        ))
+    }
+}
+
+/// Contrast report lines for a theme, plus the number of failing colors
+pub fn theme_report(custom: &CustomTheme) -> (Vec<String>, usize) {
+    let mut lines = vec![format!(
+        "Minimum contrast: {:.1}:1 on {}",
+        custom.min_contrast, custom.background
+    )];
+    let mut failures = 0;
+
+    for (role, fg, ratio) in theme_contrast_ratios(&custom.theme, custom.background) {
+        if ratio >= custom.min_contrast {
+            lines.push(format!("  ✓ {:<8} {:>5.1}:1", role, ratio));
+        } else {
+            failures += 1;
+            let suggested = adjust_for_contrast(fg, custom.background, custom.min_contrast);
+            lines.push(format!(
+                "  ✗ {:<8} {:>5.1}:1  ({} fails; try {})",
+                role, ratio, fg, suggested
+            ));
+        }
+    }
+
+    (lines, failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_report_default_theme_passes() {
+        let custom = CustomTheme::parse("{}").unwrap();
+        let (lines, failures) = theme_report(&custom);
+
+        assert_eq!(failures, 0);
+        // Header plus one line per role
+        assert_eq!(lines.len(), 8);
+    }
+
+    #[test]
+    fn test_theme_report_flags_low_contrast() {
+        let custom = CustomTheme::parse(r##"{"waiting": "#101010"}"##).unwrap();
+        let (lines, failures) = theme_report(&custom);
+
+        assert_eq!(failures, 1);
+        assert!(lines.iter().any(|l| l.contains("✗ waiting")));
+    }
+}
@@ -0,0 +1,58 @@
+//! Export command for Spoq CLI.
+//!
+//! `spoq export <thread-id> [--format md|json] [--out PATH]` fetches a thread
+//! and its messages from the conductor and writes them to a file.
+
+use std::path::PathBuf;
+
+use color_eyre::{eyre::eyre, Result};
+
+use crate::cache::{default_export_file_name, render_export, write_export, ExportFormat};
+use crate::cli::backend::connect_conductor;
+use crate::models::Message;
+
+/// Handle `spoq export <thread-id> [--format md|json] [--out PATH]`.
+///
+/// # Errors
+///
+/// Returns an error if the arguments are invalid, the thread cannot be
+/// fetched, or the output file cannot be written.
+pub fn handle_export_command(
+    thread_id: Option<&str>,
+    format: &str,
+    out: Option<&str>,
+) -> Result<()> {
+    let thread_id = thread_id
+        .ok_or_else(|| eyre!("Usage: spoq export <thread-id> [--format md|json] [--out PATH]"))?;
+    let format = ExportFormat::parse(format)
+        .ok_or_else(|| eyre!("Unknown export format '{}' (expected md or json)", format))?;
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let conductor = connect_conductor(&runtime)?;
+
+    let detail = runtime
+        .block_on(conductor.fetch_thread_with_messages(thread_id))
+        .map_err(|e| eyre!("Failed to fetch thread {}: {}", thread_id, e))?;
+    let messages: Vec<Message> = detail
+        .messages
+        .into_iter()
+        .enumerate()
+        .map(|(i, m)| m.to_client_message(thread_id, i as i64 + 1))
+        .collect();
+
+    // Thread metadata comes from the thread list; export messages even if it is unavailable
+    let thread = runtime
+        .block_on(conductor.fetch_threads())
+        .ok()
+        .and_then(|threads| threads.into_iter().find(|t| t.id == thread_id));
+
+    let contents = render_export(thread_id, thread.as_ref(), &messages, format);
+    let path = out
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(default_export_file_name(thread_id, format)));
+    write_export(&path, &contents)
+        .map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("Exported {} messages to {}", messages.len(), path.display());
+    Ok(())
+}
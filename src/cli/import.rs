@@ -0,0 +1,352 @@
+//! Import command for Spoq CLI.
+//!
+//! Converts transcripts exported by other CLI agents into spoq threads:
+//!
+//! ```text
+//! spoq import --from claude-code ~/.claude/projects/my-project
+//! spoq import --from json threads.json
+//! ```
+//!
+//! Roles, timestamps and code blocks are preserved. Converted threads are
+//! sent to the conductor's batch import endpoint.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use color_eyre::{eyre::eyre, Result};
+use serde_json::Value;
+
+use crate::cli::backend::connect_conductor;
+use crate::models::{ImportedMessage, ImportedThread, MessageRole};
+
+/// Maximum length of a title derived from the first user message
+const DERIVED_TITLE_MAX_CHARS: usize = 60;
+
+/// Transcript formats that can be imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    /// Claude Code session logs (`~/.claude/projects/<project>/<session>.jsonl`)
+    ClaudeCode,
+    /// spoq's own import format: one thread object or an array of them
+    Json,
+}
+
+impl ImportSource {
+    /// Parse a `--from` value.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "claude-code" | "claude" => Some(Self::ClaudeCode),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Name recorded as the thread's source
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ClaudeCode => "claude-code",
+            Self::Json => "json",
+        }
+    }
+
+    /// File extension of transcripts when importing a directory
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::ClaudeCode => "jsonl",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// Handle `spoq import --from <source> <path>`.
+///
+/// # Errors
+///
+/// Returns an error if the source is unknown, the transcripts cannot be read,
+/// or the conductor rejects the import.
+pub fn handle_import_command(from: &str, path: Option<&str>) -> Result<()> {
+    let source = ImportSource::parse(from)
+        .ok_or_else(|| eyre!("Unknown import source '{}' (expected claude-code or json)", from))?;
+    let path = path.ok_or_else(|| eyre!("Usage: spoq import --from {} <path>", source.name()))?;
+
+    let threads = load_threads(source, Path::new(path))?;
+    if threads.is_empty() {
+        println!("No conversations found in {}", path);
+        return Ok(());
+    }
+
+    let message_count: usize = threads.iter().map(|t| t.messages.len()).sum();
+    println!(
+        "Importing {} conversation(s) ({} messages) from {}...",
+        threads.len(),
+        message_count,
+        source.name()
+    );
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let conductor = connect_conductor(&runtime)?;
+    let thread_ids = runtime
+        .block_on(conductor.import_threads(&threads))
+        .map_err(|e| eyre!("Import failed: {}", e))?;
+
+    for (thread, id) in threads.iter().zip(&thread_ids) {
+        println!("  ✓ {} ({})", thread.title, id);
+    }
+    println!("\nImported {} thread(s).", thread_ids.len());
+    Ok(())
+}
+
+/// Read and convert every transcript at `path` (a file or a directory).
+pub fn load_threads(source: ImportSource, path: &Path) -> Result<Vec<ImportedThread>> {
+    let files = transcript_files(source, path)?;
+    let mut threads = Vec::new();
+    for file in files {
+        let contents = fs::read_to_string(&file)
+            .map_err(|e| eyre!("Failed to read {}: {}", file.display(), e))?;
+        match source {
+            ImportSource::ClaudeCode => threads.extend(parse_claude_code(&contents)),
+            ImportSource::Json => threads.extend(
+                parse_json(&contents)
+                    .map_err(|e| eyre!("Failed to parse {}: {}", file.display(), e))?,
+            ),
+        }
+    }
+    Ok(threads)
+}
+
+/// List transcript files, sorted so imports are deterministic
+fn transcript_files(source: ImportSource, path: &Path) -> Result<Vec<PathBuf>> {
+    if path.is_file() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let entries =
+        fs::read_dir(path).map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?;
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some(source.extension()))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Parse spoq's import format: a single thread object or an array of them.
+pub fn parse_json(contents: &str) -> serde_json::Result<Vec<ImportedThread>> {
+    let value: Value = serde_json::from_str(contents)?;
+    let mut threads: Vec<ImportedThread> = if value.is_array() {
+        serde_json::from_value(value)?
+    } else {
+        vec![serde_json::from_value(value)?]
+    };
+    for thread in &mut threads {
+        if thread.source.is_empty() {
+            thread.source = ImportSource::Json.name().to_string();
+        }
+    }
+    Ok(threads)
+}
+
+/// Parse a Claude Code session log (one JSON event per line).
+///
+/// Text blocks are kept verbatim so fenced code survives; tool calls become a
+/// one-line summary; thinking blocks, tool results, sidechain (subagent) and
+/// meta lines are skipped. Returns `None` if the session has no messages.
+pub fn parse_claude_code(contents: &str) -> Option<ImportedThread> {
+    let mut summary: Option<String> = None;
+    let mut working_directory: Option<String> = None;
+    let mut messages: Vec<ImportedMessage> = Vec::new();
+
+    for line in contents.lines().filter(|l| !l.trim().is_empty()) {
+        let Ok(event) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        let kind = event.get("type").and_then(Value::as_str).unwrap_or_default();
+
+        if kind == "summary" {
+            if let Some(text) = event.get("summary").and_then(Value::as_str) {
+                summary = Some(text.to_string());
+            }
+            continue;
+        }
+
+        let role = match kind {
+            "user" => MessageRole::User,
+            "assistant" => MessageRole::Assistant,
+            _ => continue,
+        };
+        if is_flag_set(&event, "isSidechain") || is_flag_set(&event, "isMeta") {
+            continue;
+        }
+        if working_directory.is_none() {
+            working_directory = event.get("cwd").and_then(Value::as_str).map(String::from);
+        }
+
+        let Some(content) = event.pointer("/message/content").and_then(content_to_markdown)
+        else {
+            continue;
+        };
+        let created_at = event
+            .get("timestamp")
+            .and_then(Value::as_str)
+            .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            .map(|ts| ts.with_timezone(&Utc));
+
+        // Claude Code logs each assistant content block as its own line
+        match messages.last_mut() {
+            Some(last) if role == MessageRole::Assistant && last.role == MessageRole::Assistant => {
+                last.content.push_str("\n\n");
+                last.content.push_str(&content);
+            }
+            _ => messages.push(ImportedMessage {
+                role,
+                content,
+                created_at,
+            }),
+        }
+    }
+
+    if messages.is_empty() {
+        return None;
+    }
+
+    let title = summary.unwrap_or_else(|| derive_title(&messages));
+    Some(ImportedThread {
+        title,
+        working_directory,
+        source: ImportSource::ClaudeCode.name().to_string(),
+        messages,
+    })
+}
+
+fn is_flag_set(event: &Value, key: &str) -> bool {
+    event.get(key).and_then(Value::as_bool).unwrap_or(false)
+}
+
+/// Convert Claude message content (a string or a list of blocks) to markdown
+fn content_to_markdown(content: &Value) -> Option<String> {
+    if let Some(text) = content.as_str() {
+        return (!text.trim().is_empty()).then(|| text.to_string());
+    }
+
+    let parts: Vec<String> = content
+        .as_array()?
+        .iter()
+        .filter_map(|block| match block.get("type").and_then(Value::as_str)? {
+            "text" => block
+                .get("text")
+                .and_then(Value::as_str)
+                .filter(|t| !t.trim().is_empty())
+                .map(String::from),
+            "tool_use" => {
+                let name = block.get("name").and_then(Value::as_str).unwrap_or("tool");
+                Some(format!("*Used tool `{}`*", name))
+            }
+            _ => None,
+        })
+        .collect();
+
+    (!parts.is_empty()).then(|| parts.join("\n\n"))
+}
+
+/// Title from the first line of the first user message
+fn derive_title(messages: &[ImportedMessage]) -> String {
+    let first_line = messages
+        .iter()
+        .find(|m| m.role == MessageRole::User)
+        .and_then(|m| m.content.lines().find(|l| !l.trim().is_empty()))
+        .map(str::trim)
+        .unwrap_or("Imported conversation");
+
+    if first_line.chars().count() > DERIVED_TITLE_MAX_CHARS {
+        let truncated: String = first_line.chars().take(DERIVED_TITLE_MAX_CHARS - 1).collect();
+        format!("{}…", truncated.trim_end())
+    } else {
+        first_line.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SESSION: &str = r#"{"type":"summary","summary":"Fix flaky test","leafUuid":"abc"}
+{"type":"user","cwd":"/home/me/project","timestamp":"2025-01-02T10:00:00Z","message":{"role":"user","content":"Why does this test fail?"}}
+{"type":"assistant","timestamp":"2025-01-02T10:00:05Z","message":{"role":"assistant","content":[{"type":"thinking","thinking":"hmm"},{"type":"text","text":"It races:\n```rust\nassert!(done);\n```"}]}}
+{"type":"assistant","timestamp":"2025-01-02T10:00:06Z","message":{"role":"assistant","content":[{"type":"tool_use","id":"t1","name":"Read","input":{}}]}}
+{"type":"user","timestamp":"2025-01-02T10:00:07Z","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"t1","content":"file"}]}}
+{"type":"user","isSidechain":true,"message":{"role":"user","content":"subagent prompt"}}
+{"type":"user","isMeta":true,"message":{"role":"user","content":"<command-name>/clear</command-name>"}}
+not json
+"#;
+
+    #[test]
+    fn test_import_source_parse() {
+        assert_eq!(ImportSource::parse("claude-code"), Some(ImportSource::ClaudeCode));
+        assert_eq!(ImportSource::parse("json"), Some(ImportSource::Json));
+        assert_eq!(ImportSource::parse("cursor"), None);
+    }
+
+    #[test]
+    fn test_parse_claude_code_session() {
+        let thread = parse_claude_code(SESSION).unwrap();
+
+        assert_eq!(thread.title, "Fix flaky test");
+        assert_eq!(thread.source, "claude-code");
+        assert_eq!(thread.working_directory.as_deref(), Some("/home/me/project"));
+        assert_eq!(thread.messages.len(), 2);
+
+        let user = &thread.messages[0];
+        assert_eq!(user.role, MessageRole::User);
+        assert_eq!(user.content, "Why does this test fail?");
+        assert_eq!(
+            user.created_at.unwrap().to_rfc3339(),
+            "2025-01-02T10:00:00+00:00"
+        );
+
+        let assistant = &thread.messages[1];
+        assert_eq!(assistant.role, MessageRole::Assistant);
+        assert_eq!(
+            assistant.content,
+            "It races:\n```rust\nassert!(done);\n```\n\n*Used tool `Read`*"
+        );
+    }
+
+    #[test]
+    fn test_parse_claude_code_derives_title() {
+        let session = r#"{"type":"user","message":{"role":"user","content":"Refactor the websocket reconnect logic so that it backs off exponentially and gives up after ten tries"}}"#;
+        let thread = parse_claude_code(session).unwrap();
+
+        assert!(thread.title.ends_with('…'));
+        assert_eq!(thread.title.chars().count(), DERIVED_TITLE_MAX_CHARS);
+    }
+
+    #[test]
+    fn test_parse_claude_code_empty_session() {
+        assert!(parse_claude_code(r#"{"type":"summary","summary":"x"}"#).is_none());
+    }
+
+    #[test]
+    fn test_parse_json_single_and_array() {
+        let single = r#"{"title":"A","messages":[{"role":"user","content":"hi"}]}"#;
+        let threads = parse_json(single).unwrap();
+        assert_eq!(threads.len(), 1);
+        assert_eq!(threads[0].source, "json");
+
+        let array = r#"[{"title":"A","source":"aider","messages":[]},{"title":"B","messages":[]}]"#;
+        let threads = parse_json(array).unwrap();
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].source, "aider");
+    }
+
+    #[test]
+    fn test_load_threads_from_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.jsonl"), SESSION).unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let threads = load_threads(ImportSource::ClaudeCode, dir.path()).unwrap();
+
+        assert_eq!(threads.len(), 1);
+        assert_eq!(threads[0].title, "Fix flaky test");
+    }
+}
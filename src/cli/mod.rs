@@ -5,6 +5,13 @@
 //! - Version display
 //! - Update checking and installation
 //! - Token synchronization to VPS
+//! - Diagnostics (`spoq doctor --theme`)
+//! - Terminal capability probe (`spoq setup`)
+//! - Connection and auth diagnostics (`spoq status`)
+//! - Exporting threads (`spoq export <thread-id> [--format md|json] [--out PATH]`)
+//! - One-shot prompts streamed to stdout (`spoq ask <prompt> [--thread ID] [--json]`)
+//! - Importing history from other tools (`spoq import --from claude-code <path>`)
+//! - Sharing settings profiles (`spoq config export` / `spoq config import <path>`)
 //!
 //! # Usage
 //!
@@ -27,11 +34,26 @@
 //! ```
 
 pub mod args;
+pub mod ask;
+pub mod backend;
+pub mod config;
+pub mod doctor;
+pub mod export;
+pub mod import;
+pub mod setup;
+pub mod status;
 pub mod sync;
 pub mod update;
 pub mod version;
 
-pub use args::{parse_args, CliCommand};
+pub use args::{parse_args, parse_fresh, parse_inject_faults, parse_safe_mode, CliCommand};
+pub use ask::handle_ask_command;
+pub use config::{handle_config_export_command, handle_config_import_command};
+pub use doctor::handle_doctor_command;
+pub use export::handle_export_command;
+pub use import::handle_import_command;
+pub use setup::handle_setup_command;
+pub use status::handle_status_command;
 pub use sync::handle_sync_command;
 pub use update::handle_update_command;
 pub use version::{handle_version_command, VERSION};
@@ -61,6 +83,46 @@ pub fn run_cli_command(command: CliCommand) -> Option<Result<()>> {
         }
         CliCommand::Update => Some(handle_update_command()),
         CliCommand::Sync => Some(handle_sync_command()),
+        CliCommand::Doctor => Some(handle_doctor_command()),
+        CliCommand::Setup => Some(handle_setup_command()),
+        CliCommand::Status => Some(handle_status_command()),
+        CliCommand::Export {
+            thread_id,
+            format,
+            out,
+        } => Some(handle_export_command(
+            thread_id.as_deref(),
+            &format,
+            out.as_deref(),
+        )),
+        CliCommand::Ask {
+            prompt,
+            thread_id,
+            dir,
+            thread_type,
+            json,
+            permission_mode,
+        } => Some(handle_ask_command(
+            prompt.as_deref(),
+            thread_id.as_deref(),
+            dir.as_deref(),
+            &thread_type,
+            json,
+            &permission_mode,
+        )),
+        CliCommand::Import { from, path } => {
+            Some(handle_import_command(&from, path.as_deref()))
+        }
+        CliCommand::ConfigExport { out } => Some(handle_config_export_command(out.as_deref())),
+        CliCommand::ConfigImport {
+            path,
+            strategy,
+            dry_run,
+        } => Some(handle_config_import_command(
+            path.as_deref(),
+            &strategy,
+            dry_run,
+        )),
         CliCommand::RunTui => None,
     }
 }
@@ -0,0 +1,15 @@
+//! Setup command for Spoq CLI.
+//!
+//! `spoq setup` re-runs the terminal capability probe and saves the results,
+//! e.g. after switching terminal emulators.
+
+use color_eyre::Result;
+
+use crate::setup::run_capability_probe;
+
+/// Handle the `setup` command.
+pub fn handle_setup_command() -> Result<()> {
+    run_capability_probe();
+    println!("Run `spoq setup` again if you switch terminals.");
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! Status command for Spoq CLI.
+//!
+//! `spoq status` prints the same diagnostics as `/status` in the TUI:
+//! the Conductor URL with a health check, when the auth token expires, and
+//! the running version with any downloaded update. There is no live
+//! WebSocket outside the TUI, so that part is left out.
+
+use color_eyre::{eyre::eyre, Result};
+
+use crate::app::{load_update_state, StatusReport};
+use crate::auth::central_api::CentralApiClient;
+use crate::auth::credentials::Credentials;
+use crate::auth::CredentialsManager;
+use crate::conductor::{local, ConductorClient};
+use crate::startup::{SpoqConfig, StartupResult};
+
+use super::VERSION;
+
+/// Conductor URL the TUI would connect to: the local conductor in local
+/// mode, otherwise the user's VPS as reported by the central API
+fn resolve_conductor_url(
+    runtime: &tokio::runtime::Runtime,
+    credentials: &Credentials,
+) -> Result<String, String> {
+    let config = SpoqConfig::load();
+    if config.is_local() {
+        return Ok(config
+            .conductor_url
+            .unwrap_or_else(|| format!("http://127.0.0.1:{}", local::default_port())));
+    }
+
+    let Some(ref token) = credentials.access_token else {
+        return Err("not signed in".to_string());
+    };
+    let mut client = CentralApiClient::new().with_auth(token);
+    match runtime.block_on(client.fetch_user_vps()) {
+        Ok(Some(vps)) => StartupResult::build_vps_url(&vps)
+            .ok_or_else(|| "VPS has no URL or IP configured".to_string()),
+        Ok(None) => Err("no VPS provisioned".to_string()),
+        Err(e) => Err(format!("couldn't look up VPS: {}", e)),
+    }
+}
+
+/// Handle the `status` command.
+///
+/// # Errors
+///
+/// Returns an error if the Conductor can't be found or fails its health
+/// check, so scripts can test the exit code.
+pub fn handle_status_command() -> Result<()> {
+    let credentials = CredentialsManager::new()
+        .map(|manager| manager.load())
+        .unwrap_or_default();
+    let runtime = tokio::runtime::Runtime::new()?;
+
+    let url = resolve_conductor_url(&runtime, &credentials);
+    let (healthy, last_success) = match &url {
+        Ok(url) => {
+            let mut client = ConductorClient::with_url(url);
+            if let Some(ref token) = credentials.access_token {
+                client = client.with_auth(token);
+            }
+            let healthy = runtime.block_on(client.health_check()).unwrap_or(false);
+            (Some(healthy), client.last_success())
+        }
+        Err(_) => (None, None),
+    };
+
+    let report = StatusReport {
+        version: VERSION.to_string(),
+        conductor_url: url.as_ref().ok().cloned(),
+        healthy,
+        last_success,
+        websocket: None,
+        token_expires_at: credentials.expires_at,
+        has_token: credentials.has_token(),
+        pending_permissions: None,
+        update: load_update_state(),
+    };
+    print!("{}", report.to_text(chrono::Utc::now()));
+
+    match (url, healthy) {
+        (Err(reason), _) => Err(eyre!("Conductor unavailable: {}", reason)),
+        (Ok(_), Some(false)) => Err(eyre!("Conductor health check failed")),
+        _ => Ok(()),
+    }
+}
@@ -4,6 +4,7 @@
 
 use crate::update::{
     check_for_update, cleanup_backup, detect_platform, download_binary, install_update,
+    UpdateStateManager,
 };
 use color_eyre::Result;
 
@@ -87,6 +88,15 @@ pub fn handle_update_command() -> Result<()> {
             }
         };
 
+        // Remember the release notes so the next launch shows "What's new"
+        if let Some(state_manager) = UpdateStateManager::new() {
+            let mut state = state_manager.load();
+            state.pending_update_path = None;
+            state.available_version = Some(check_result.latest_version.clone());
+            state.release_notes = check_result.release_notes.clone();
+            let _ = state_manager.save(&state);
+        }
+
         println!(
             "Successfully updated to version {}!",
             check_result.latest_version
@@ -1,8 +1,11 @@
 //! CLI output utilities for clean ASCII-boxed output during setup flows.
 //!
 //! This module provides functions for rendering step-by-step progress
-//! with ASCII boxes, spinners, and status indicators.
+//! with ASCII boxes, spinners, and status indicators, and the streamed
+//! answers of `spoq ask`.
 
 mod boxes;
+mod stream;
 
 pub use boxes::*;
+pub use stream::StreamPrinter;
@@ -0,0 +1,127 @@
+//! Streamed answer output for `spoq ask`.
+//!
+//! Plain mode writes the answer text to stdout as it arrives and leaves
+//! everything else out, so the output can be piped. JSON mode writes every
+//! event as one line of JSON (newline-delimited JSON).
+
+use std::io::{self, Write};
+
+use crate::events::SseEvent;
+
+/// Writes streamed events to stdout, and notes about the stream to stderr.
+pub struct StreamPrinter<O: Write, E: Write> {
+    out: O,
+    err: E,
+    json: bool,
+    /// Whether the text written so far ends a line
+    at_line_start: bool,
+}
+
+impl StreamPrinter<io::Stdout, io::Stderr> {
+    /// Printer for the process's stdout and stderr
+    pub fn stdio(json: bool) -> Self {
+        Self::new(io::stdout(), io::stderr(), json)
+    }
+}
+
+impl<O: Write, E: Write> StreamPrinter<O, E> {
+    pub fn new(out: O, err: E, json: bool) -> Self {
+        Self {
+            out,
+            err,
+            json,
+            at_line_start: true,
+        }
+    }
+
+    /// Write an event as it arrives
+    pub fn event(&mut self, event: &SseEvent) -> io::Result<()> {
+        if self.json {
+            let line = serde_json::to_string(event).map_err(io::Error::other)?;
+            writeln!(self.out, "{}", line)?;
+            return self.out.flush();
+        }
+        if let SseEvent::Content(content) = event {
+            let text = &content.text;
+            if text.is_empty() {
+                return Ok(());
+            }
+            self.out.write_all(text.as_bytes())?;
+            self.at_line_start = text.ends_with('\n');
+            self.out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write a note about the stream (auto-denied permissions, cancellation)
+    pub fn note(&mut self, message: &str) -> io::Result<()> {
+        writeln!(self.err, "spoq: {}", message)?;
+        self.err.flush()
+    }
+
+    /// End the answer on a line of its own
+    pub fn finish(&mut self) -> io::Result<()> {
+        if !self.json && !self.at_line_start {
+            writeln!(self.out)?;
+            self.at_line_start = true;
+        }
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{ContentEvent, DoneEvent, EventMeta, UserMessageSavedEvent};
+
+    fn content(text: &str) -> SseEvent {
+        SseEvent::Content(ContentEvent {
+            text: text.to_string(),
+            meta: EventMeta::default(),
+        })
+    }
+
+    fn done() -> SseEvent {
+        SseEvent::Done(DoneEvent {
+            message_id: "7".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_plain_output_is_only_the_answer() {
+        let mut printer = StreamPrinter::new(Vec::new(), Vec::new(), false);
+        printer
+            .event(&SseEvent::UserMessageSaved(UserMessageSavedEvent {
+                message_id: "6".to_string(),
+                thread_id: "t-1".to_string(),
+            }))
+            .unwrap();
+        printer.event(&content("Hello")).unwrap();
+        printer.event(&content(", world")).unwrap();
+        printer.event(&done()).unwrap();
+        printer.note("denied Bash").unwrap();
+        printer.finish().unwrap();
+
+        assert_eq!(String::from_utf8(printer.out).unwrap(), "Hello, world\n");
+        assert_eq!(
+            String::from_utf8(printer.err).unwrap(),
+            "spoq: denied Bash\n"
+        );
+    }
+
+    #[test]
+    fn test_json_output_is_one_event_per_line() {
+        let mut printer = StreamPrinter::new(Vec::new(), Vec::new(), true);
+        printer.event(&content("Hi")).unwrap();
+        printer.event(&done()).unwrap();
+        printer.finish().unwrap();
+
+        let out = String::from_utf8(printer.out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["type"], "content");
+        assert_eq!(first["text"], "Hi");
+        assert_eq!(lines[1], r#"{"type":"done","message_id":"7"}"#);
+    }
+}
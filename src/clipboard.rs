@@ -1,10 +1,14 @@
-//! Clipboard image reading and file-based image ingestion.
+//! Clipboard access: image reading, file-based image ingestion, and text copy.
 //!
 //! Self-contained module for reading images from the system clipboard or from
-//! file paths. Handles PNG encoding, hashing, and base64 encoding.
+//! file paths, and for writing text to the system clipboard. Handles PNG
+//! encoding, hashing, and base64 encoding.
 //! No coupling to UI, networking, or application state.
 
+use std::sync::Mutex;
+
 use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use once_cell::sync::Lazy;
 use sha2::{Sha256, Digest};
 
 /// Maximum image size in bytes (4 MB).
@@ -18,6 +22,12 @@ const MAX_IMAGE_DIMENSION: u32 = 1568;
 /// Maximum number of pending images per submission.
 pub const MAX_PENDING_IMAGES: usize = 3;
 
+/// Preview width in terminal cells.
+const PREVIEW_COLUMNS: u32 = 16;
+
+/// Preview height in terminal cells (each cell shows two pixel rows).
+const PREVIEW_ROWS: u32 = 4;
+
 /// Image file extensions we recognize for drag-and-drop detection.
 const IMAGE_EXTENSIONS: &[&str] = &[".png", ".jpg", ".jpeg", ".gif", ".webp"];
 
@@ -30,6 +40,21 @@ pub struct ImageAttachment {
     pub base64_png: String,
     /// Original byte size of the PNG.
     pub byte_size: usize,
+    /// Pixel width of the PNG (0 if it couldn't be decoded).
+    pub width: u32,
+    /// Pixel height of the PNG (0 if it couldn't be decoded).
+    pub height: u32,
+    /// Thumbnail for the attachment tray.
+    pub preview: Option<ImagePreview>,
+}
+
+/// A downscaled copy of an image for drawing with half blocks.
+///
+/// Each cell is a (top, bottom) pair of RGB pixels, so one terminal row
+/// covers two pixel rows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImagePreview {
+    pub rows: Vec<Vec<([u8; 3], [u8; 3])>>,
 }
 
 /// Errors that can occur when reading an image.
@@ -47,6 +72,58 @@ pub enum ClipboardImageError {
     FileError(String),
 }
 
+/// Errors that can occur when writing text to the clipboard.
+#[derive(Debug)]
+pub enum ClipboardTextError {
+    /// No system clipboard is available (e.g., headless SSH session).
+    Unavailable(String),
+    /// The clipboard rejected the write.
+    WriteFailed(String),
+}
+
+impl std::fmt::Display for ClipboardTextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClipboardTextError::Unavailable(msg) => write!(f, "Clipboard unavailable: {}", msg),
+            ClipboardTextError::WriteFailed(msg) => write!(f, "Clipboard write failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardTextError {}
+
+/// Process-wide clipboard handle for text writes.
+///
+/// On X11/Wayland the copied text is served by the owning process, so the
+/// handle is kept alive instead of being dropped after each write.
+static TEXT_CLIPBOARD: Lazy<Mutex<Option<arboard::Clipboard>>> = Lazy::new(|| Mutex::new(None));
+
+/// Write text to the system clipboard.
+///
+/// Fails with `Unavailable` when there is no clipboard to talk to; callers
+/// can fall back to OSC 52 (see `terminal::write_osc52_clipboard`).
+pub fn try_write_clipboard_text(text: &str) -> Result<(), ClipboardTextError> {
+    let mut guard = TEXT_CLIPBOARD
+        .lock()
+        .map_err(|e| ClipboardTextError::Unavailable(e.to_string()))?;
+
+    if guard.is_none() {
+        let clipboard = arboard::Clipboard::new()
+            .map_err(|e| ClipboardTextError::Unavailable(e.to_string()))?;
+        *guard = Some(clipboard);
+    }
+
+    let result = guard
+        .as_mut()
+        .expect("clipboard initialized above")
+        .set_text(text.to_string());
+    result.map_err(|e| {
+        // Drop the handle so the next write reconnects
+        *guard = None;
+        ClipboardTextError::WriteFailed(e.to_string())
+    })
+}
+
 /// Try to read an image from the system clipboard.
 ///
 /// Uses `arboard` to access OS-level clipboard (NSPasteboard on macOS,
@@ -127,14 +204,49 @@ fn build_attachment(png_bytes: Vec<u8>) -> Result<ImageAttachment, ClipboardImag
     let hash = compute_short_hash(&png_bytes);
     let base64_png = BASE64.encode(&png_bytes);
     let byte_size = png_bytes.len();
+    let (width, height, preview) = match image::load_from_memory(&png_bytes) {
+        Ok(img) => (img.width(), img.height(), Some(build_preview(&img))),
+        Err(_) => (0, 0, None),
+    };
 
     Ok(ImageAttachment {
         hash,
         base64_png,
         byte_size,
+        width,
+        height,
+        preview,
     })
 }
 
+/// Shrink an image to fit `PREVIEW_COLUMNS` x `PREVIEW_ROWS` cells, keeping
+/// its aspect ratio.
+fn build_preview(img: &image::DynamicImage) -> ImagePreview {
+    let (w, h) = (img.width().max(1), img.height().max(1));
+    let max_h = PREVIEW_ROWS * 2;
+    let scale = (PREVIEW_COLUMNS as f64 / w as f64).min(max_h as f64 / h as f64);
+    let new_w = ((w as f64 * scale).round() as u32).clamp(1, PREVIEW_COLUMNS);
+    // Round up to whole cells
+    let new_h = ((h as f64 * scale).round() as u32)
+        .clamp(1, max_h)
+        .div_ceil(2)
+        * 2;
+
+    let small = img
+        .resize_exact(new_w, new_h, image::imageops::FilterType::Triangle)
+        .to_rgb8();
+    let rows = (0..new_h)
+        .step_by(2)
+        .map(|y| {
+            (0..new_w)
+                .map(|x| (small.get_pixel(x, y).0, small.get_pixel(x, y + 1).0))
+                .collect()
+        })
+        .collect();
+
+    ImagePreview { rows }
+}
+
 /// Downscale a PNG image if either dimension exceeds `MAX_IMAGE_DIMENSION`.
 ///
 /// Uses Lanczos3 filtering for high-quality downscaling. Returns the original
@@ -250,6 +362,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_attachment_records_dimensions_and_preview() {
+        use image::{ImageBuffer, RgbaImage};
+        let img: RgbaImage = ImageBuffer::from_pixel(320, 80, image::Rgba([0, 128, 255, 255]));
+        let mut buf = Vec::new();
+        img.write_to(&mut std::io::Cursor::new(&mut buf), image::ImageFormat::Png).unwrap();
+
+        let attachment = build_attachment(buf).unwrap();
+        assert_eq!((attachment.width, attachment.height), (320, 80));
+        let preview = attachment.preview.unwrap();
+        // 4:1 image -> 16 columns, 4 pixel rows -> 2 cell rows
+        assert_eq!(preview.rows.len(), 2);
+        assert_eq!(preview.rows[0].len(), 16);
+        assert_eq!(preview.rows[0][0], ([0, 128, 255], [0, 128, 255]));
+    }
+
     #[test]
     fn test_build_attachment_ok() {
         // Create a minimal valid PNG-like payload (just raw bytes for hashing test)
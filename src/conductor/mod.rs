@@ -4,20 +4,26 @@
 //! including streaming responses via Server-Sent Events (SSE).
 
 pub mod local;
+pub mod polling;
 
+use crate::adapters::fault_injection::{self, FaultInjectingHttpClient};
 use crate::adapters::ReqwestHttpClient;
 use crate::debug::{DebugEvent, DebugEventKind, DebugEventSender, RawSseEventData};
 use crate::events::SseEvent;
 use crate::models::{
-    CancelRequest, CancelResponse, Folder, GitHubRepo, Message, StreamRequest, Thread,
-    ThreadDetailResponse, ThreadListResponse,
+    CancelRequest, CancelResponse, CompactResponse, Folder, GitHubRepo, ImageAttachmentPayload,
+    ImportedThread, Message, MessagePageResponse, ModelInfo, ModelListResponse,
+    ResumeStreamRequest, ShareOptions, StreamRequest, Thread, ThreadDetailResponse, ThreadLink,
+    ThreadListResponse, ThreadShare,
 };
 use crate::models::picker::{
-    CloneResponse, SearchFoldersResponse, SearchReposResponse, SearchThreadsResponse,
+    CloneResponse, DirectoryListing, SearchFoldersResponse, SearchReposResponse,
+    SearchThreadsResponse,
 };
 use crate::sse::{SseParseError, SseParser};
 use crate::state::Task;
 use crate::traits::HttpClient;
+use chrono::{DateTime, Utc};
 use futures_util::stream::{self, Stream};
 use futures_util::StreamExt;
 use reqwest::Client;
@@ -25,7 +31,7 @@ use serde::Deserialize;
 use std::fs;
 use std::path::PathBuf;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 // macOS Keychain access for GitHub CLI OAuth tokens
 #[cfg(target_os = "macos")]
@@ -68,6 +74,23 @@ impl std::fmt::Display for ConductorError {
     }
 }
 
+impl ConductorError {
+    /// Whether the backend rejected a stream resume outright.
+    ///
+    /// These errors mean the stream is gone for good (unknown thread, expired
+    /// replay buffer, or no resume support), so retrying after another
+    /// reconnect will not help. Transport errors return false.
+    pub fn is_resume_unavailable(&self) -> bool {
+        match self {
+            ConductorError::ServerError { status, .. } => {
+                matches!(status, 400 | 404 | 405 | 409 | 410 | 501)
+            }
+            ConductorError::NotImplemented(_) => true,
+            _ => false,
+        }
+    }
+}
+
 impl std::error::Error for ConductorError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -146,6 +169,29 @@ pub struct SyncResponse {
     pub verification: Option<SyncVerification>,
 }
 
+/// Response from the attachment upload endpoint
+#[derive(Debug, Clone, Deserialize)]
+pub struct AttachmentUploadResponse {
+    /// ID to reference the attachment in a stream request
+    pub id: String,
+}
+
+/// A permission the backend is still waiting on
+#[derive(Debug, Clone, Deserialize)]
+pub struct PendingPermissionSummary {
+    #[serde(alias = "request_id")]
+    pub permission_id: String,
+    #[serde(default)]
+    pub thread_id: Option<String>,
+}
+
+/// Response from the pending permissions endpoint
+#[derive(Debug, Clone, Deserialize)]
+pub struct PendingPermissionsResponse {
+    #[serde(default)]
+    pub permissions: Vec<PendingPermissionSummary>,
+}
+
 /// Configuration for ConductorClient.
 #[derive(Debug, Clone)]
 pub struct ConductorConfig {
@@ -220,6 +266,8 @@ pub struct ConductorClient {
     refresh_token: Option<String>,
     /// Central API URL for token refresh
     central_api_url: String,
+    /// When a request last got a successful response
+    last_success: Mutex<Option<DateTime<Utc>>>,
 }
 
 /// Read GitHub CLI OAuth token using `gh auth token` command.
@@ -465,6 +513,7 @@ impl ConductorClient {
             auth_token: config.auth_token,
             refresh_token: config.refresh_token,
             central_api_url: config.central_api_url,
+            last_success: Mutex::new(None),
         }
     }
 
@@ -472,7 +521,8 @@ impl ConductorClient {
     ///
     /// This is a convenience constructor that uses the production HTTP implementation.
     pub fn with_default_http(config: ConductorConfig) -> Self {
-        Self::with_http(Arc::new(ReqwestHttpClient::new()), config)
+        let http = FaultInjectingHttpClient::wrap_if_enabled(Arc::new(ReqwestHttpClient::new()));
+        Self::with_http(http, config)
     }
 
     /// Create a new ConductorClient with a custom base URL.
@@ -584,6 +634,41 @@ impl ConductorClient {
         }
     }
 
+    /// Send a request with the auth header.
+    ///
+    /// With `--inject-faults`, the request may be delayed or fail with a 500
+    /// before it is sent.
+    async fn send_request(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, ConductorError> {
+        if let Some(faults) = fault_injection::active() {
+            if let Some(delay) = faults.delay() {
+                tracing::debug!("Injected fault: delaying request by {:?}", delay);
+                tokio::time::sleep(delay).await;
+            }
+            if faults.fail_request() {
+                tracing::debug!("Injected fault: failing request");
+                return Err(ConductorError::ServerError {
+                    status: fault_injection::INJECTED_STATUS,
+                    message: fault_injection::INJECTED_MESSAGE.to_string(),
+                });
+            }
+        }
+        let response = self.add_auth_header(builder).send().await?;
+        if response.status().is_success() {
+            if let Ok(mut last_success) = self.last_success.lock() {
+                *last_success = Some(Utc::now());
+            }
+        }
+        Ok(response)
+    }
+
+    /// When a request to the Conductor last got a successful response
+    pub fn last_success(&self) -> Option<DateTime<Utc>> {
+        self.last_success.lock().ok().and_then(|last| *last)
+    }
+
     /// Stream a conversation response from the Conductor API.
     ///
     /// Sends a POST request to `/v1/stream` and returns a stream of SSE events.
@@ -634,7 +719,7 @@ impl ConductorClient {
             .header("Content-Type", "application/json")
             .header("Accept", "text/event-stream")
             .json(request);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -645,118 +730,56 @@ impl ConductorClient {
             return Err(ConductorError::ServerError { status, message });
         }
 
-        // Get the byte stream from the response
-        let bytes_stream = response.bytes_stream();
-
-        // Create an SSE parser and process the byte stream
-        // Include debug_tx in the state tuple for emitting debug events
-        // Use Vec<u8> buffer to avoid data loss when UTF-8 chars are split across TCP chunks
-        let event_stream = stream::unfold(
-            (bytes_stream, SseParser::new(), Vec::<u8>::new(), debug_tx),
-            |(mut bytes_stream, mut parser, mut byte_buffer, debug_tx)| async move {
-                loop {
-                    // First, try to process any complete lines in the buffer
-                    // Look for newline in the byte buffer
-                    if let Some(newline_pos) = byte_buffer.iter().position(|&b| b == b'\n') {
-                        // Extract the line bytes (including newline)
-                        let line_bytes: Vec<u8> = byte_buffer.drain(..=newline_pos).collect();
-
-                        // Decode to string using lossy conversion to handle edge cases
-                        // where a multi-byte UTF-8 char might still be incomplete
-                        let line = String::from_utf8_lossy(&line_bytes[..line_bytes.len() - 1])
-                            .trim_end_matches('\r')
-                            .to_string();
-
-                        match parser.feed_line(&line) {
-                            Ok(Some(sse_event)) => {
-                                // Emit raw SSE debug event if debug channel is available
-                                if let Some(ref tx) = debug_tx {
-                                    let raw_data = RawSseEventData::new(
-                                        sse_event.event_type_name(),
-                                        format!("{:?}", sse_event),
-                                    );
-                                    let debug_event =
-                                        DebugEvent::new(DebugEventKind::RawSseEvent(raw_data));
-                                    let _ = tx.send(debug_event);
-                                }
+        Ok(sse_event_stream(response, debug_tx))
+    }
 
-                                // Convert the sse::SseEvent to events::SseEvent
-                                let event = convert_sse_event(sse_event);
-                                return Some((
-                                    Ok(event),
-                                    (bytes_stream, parser, byte_buffer, debug_tx),
-                                ));
-                            }
-                            Ok(None) => {
-                                // Continue processing buffer
-                                continue;
-                            }
-                            Err(e) => {
-                                return Some((
-                                    Err(ConductorError::SseParse(e)),
-                                    (bytes_stream, parser, byte_buffer, debug_tx),
-                                ));
-                            }
-                        }
-                    }
+    /// Resume an interrupted stream from the last event the client received.
+    ///
+    /// Replays events after `last_event_id` for the thread's in-flight
+    /// response, so remaining tokens can be appended to the partial message.
+    /// Returns a `ServerError` if the backend cannot resume (see
+    /// [`ConductorError::is_resume_unavailable`]).
+    ///
+    /// # Arguments
+    /// * `thread_id` - The thread whose stream was interrupted
+    /// * `last_event_id` - Sequence number of the last event received, if any
+    pub async fn resume_stream(
+        &self,
+        thread_id: &str,
+        last_event_id: Option<u64>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<SseEvent, ConductorError>> + Send>>, ConductorError>
+    {
+        let url = format!("{}/v1/stream/resume", self.base_url);
+        let request = ResumeStreamRequest::new(thread_id.to_string(), last_event_id);
 
-                    // Need more data from the stream
-                    match bytes_stream.next().await {
-                        Some(Ok(chunk)) => {
-                            // Append raw bytes to buffer - no UTF-8 conversion that could fail
-                            byte_buffer.extend_from_slice(&chunk);
-                            // Loop back to process the buffer
-                        }
-                        Some(Err(e)) => {
-                            return Some((
-                                Err(ConductorError::Http(e)),
-                                (bytes_stream, parser, byte_buffer, debug_tx),
-                            ));
-                        }
-                        None => {
-                            // Stream ended - process any remaining data in buffer
-                            if !byte_buffer.is_empty() {
-                                let line = String::from_utf8_lossy(&byte_buffer)
-                                    .trim_end_matches('\r')
-                                    .to_string();
-                                byte_buffer.clear();
-                                match parser.feed_line(&line) {
-                                    Ok(Some(sse_event)) => {
-                                        // Emit raw SSE debug event if debug channel is available
-                                        if let Some(ref tx) = debug_tx {
-                                            let raw_data = RawSseEventData::new(
-                                                sse_event.event_type_name(),
-                                                format!("{:?}", sse_event),
-                                            );
-                                            let debug_event = DebugEvent::new(
-                                                DebugEventKind::RawSseEvent(raw_data),
-                                            );
-                                            let _ = tx.send(debug_event);
-                                        }
-
-                                        let event = convert_sse_event(sse_event);
-                                        return Some((
-                                            Ok(event),
-                                            (bytes_stream, parser, byte_buffer, debug_tx),
-                                        ));
-                                    }
-                                    Ok(None) => {}
-                                    Err(e) => {
-                                        return Some((
-                                            Err(ConductorError::SseParse(e)),
-                                            (bytes_stream, parser, byte_buffer, debug_tx),
-                                        ));
-                                    }
-                                }
-                            }
-                            return None;
-                        }
-                    }
-                }
-            },
+        tracing::info!(
+            "STREAM_RESUME: url={}, thread_id={}, last_event_id={:?}",
+            url,
+            thread_id,
+            last_event_id
         );
 
-        Ok(Box::pin(event_stream))
+        let mut builder = self
+            .client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "text/event-stream")
+            .json(&request);
+        if let Some(id) = last_event_id {
+            builder = builder.header("Last-Event-ID", id.to_string());
+        }
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(sse_event_stream(response, None))
     }
 
     /// Check if the Conductor API is healthy and reachable.
@@ -767,7 +790,7 @@ impl ConductorClient {
         let url = format!("{}/v1/health", self.base_url);
 
         let builder = self.client.get(&url);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         Ok(response.status().is_success())
     }
@@ -784,7 +807,7 @@ impl ConductorClient {
         let url = format!("{}/v1/tokens/verify", self.base_url);
 
         let builder = self.client.get(&url);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         // Check for 401 and try to refresh
         if response.status().as_u16() == 401 && self.refresh_token.is_some() {
@@ -793,7 +816,7 @@ impl ConductorClient {
 
             // Retry the request with new token
             let builder = self.client.get(&url);
-            let response = self.add_auth_header(builder).send().await?;
+            let response = self.send_request(builder).await?;
 
             if response.status().is_success() {
                 return Ok(response.json::<TokensVerifyResponse>().await?);
@@ -844,7 +867,7 @@ impl ConductorClient {
         });
 
         let builder = self.client.post(&url).json(&body);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         // Check for 401 and try to refresh
         if response.status().as_u16() == 401 && self.refresh_token.is_some() {
@@ -853,7 +876,7 @@ impl ConductorClient {
 
             // Retry the request with new token
             let builder = self.client.post(&url).json(&body);
-            let response = self.add_auth_header(builder).send().await?;
+            let response = self.send_request(builder).await?;
 
             if response.status().is_success() {
                 return Ok(response.json::<SyncResponse>().await?);
@@ -897,7 +920,7 @@ impl ConductorClient {
         let request = CancelRequest::new(thread_id.to_string());
 
         let builder = self.client.post(&url).json(&request);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         // Both 200 (cancelled) and 404 (not_found) are valid responses
         // that return JSON CancelResponse
@@ -917,6 +940,107 @@ impl ConductorClient {
         }
     }
 
+    /// Compact a thread's context window.
+    ///
+    /// Calls `POST /v1/threads/{thread_id}/compact`. The backend summarizes
+    /// the older part of the conversation, as it does on its own when the
+    /// context fills up.
+    ///
+    /// # Returns
+    /// - `Ok(CompactResponse)` with the token counts before and after
+    /// - `Err(ConductorError::NotImplemented)` if the endpoint returns 404
+    /// - `Err(ConductorError::ServerError)` for other errors
+    pub async fn compact_context(&self, thread_id: &str) -> Result<CompactResponse, ConductorError> {
+        let url = format!("{}/v1/threads/{}/compact", self.base_url, thread_id);
+
+        let builder = self.client.post(&url);
+        let response = self.send_request(builder).await?;
+
+        let status = response.status();
+
+        if status.as_u16() == 404 {
+            return Err(ConductorError::NotImplemented(format!(
+                "/v1/threads/{}/compact",
+                thread_id
+            )));
+        }
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Share a thread, returning a link to a read-only copy.
+    ///
+    /// Calls `POST /v1/threads/{thread_id}/share`. Sharing an already shared
+    /// thread returns its existing link with the new options applied.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The ID of the thread to share
+    /// * `options` - Visibility and what the shared copy includes
+    ///
+    /// # Returns
+    /// - `Ok(ThreadShare)` with the shareable link
+    /// - `Err(ConductorError::ServerError)` if the server returns an error
+    pub async fn share_thread(
+        &self,
+        thread_id: &str,
+        options: &ShareOptions,
+    ) -> Result<ThreadShare, ConductorError> {
+        let url = format!("{}/v1/threads/{}/share", self.base_url, thread_id);
+
+        let builder = self.client.post(&url).json(options);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Revoke a thread's shareable link.
+    ///
+    /// Calls `DELETE /v1/threads/{thread_id}/share`.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the link was revoked, or the thread wasn't shared (404)
+    /// - `Err(ConductorError::ServerError)` for other errors
+    pub async fn unshare_thread(&self, thread_id: &str) -> Result<(), ConductorError> {
+        let url = format!("{}/v1/threads/{}/share", self.base_url, thread_id);
+
+        let builder = self.client.delete(&url);
+        let response = self.send_request(builder).await?;
+
+        let status = response.status();
+        if status.is_success() || status.as_u16() == 404 {
+            return Ok(());
+        }
+
+        let message = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        Err(ConductorError::ServerError {
+            status: status.as_u16(),
+            message,
+        })
+    }
+
     /// Fetch all threads from the backend.
     ///
     /// # Returns
@@ -925,7 +1049,7 @@ impl ConductorClient {
         let url = format!("{}/v1/threads", self.base_url);
 
         let builder = self.client.get(&url);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -947,7 +1071,7 @@ impl ConductorClient {
     pub async fn fetch_folders(&self) -> Result<Vec<Folder>, ConductorError> {
         let url = format!("{}/v1/folders", self.base_url);
         let builder = self.client.get(&url);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
         if !response.status().is_success() {
             let status = response.status().as_u16();
             let message = response
@@ -960,6 +1084,28 @@ impl ConductorClient {
         Ok(folders)
     }
 
+    /// Fetch the models threads can run with.
+    ///
+    /// Calls `GET /v1/models`.
+    ///
+    /// # Returns
+    /// A vector of models, or an error if the request fails
+    pub async fn list_models(&self) -> Result<Vec<ModelInfo>, ConductorError> {
+        let url = format!("{}/v1/models", self.base_url);
+        let builder = self.client.get(&url);
+        let response = self.send_request(builder).await?;
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+        let data: ModelListResponse = response.json().await?;
+        Ok(data.models)
+    }
+
     /// Fetch GitHub repositories from conductor.
     ///
     /// Returns top 10 most recent repos (personal + organization).
@@ -970,7 +1116,7 @@ impl ConductorClient {
         let url = format!("{}/v1/repos?limit=10", self.base_url);
 
         let builder = self.client.get(&url);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -1012,7 +1158,7 @@ impl ConductorClient {
         );
 
         let builder = self.client.get(&url);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         // Log response status
         let status = response.status();
@@ -1051,21 +1197,105 @@ impl ConductorClient {
         Ok(Vec::new())
     }
 
-    /// Fetch messages for a specific thread from the backend.
+    /// Start or resume a task.
+    ///
+    /// Calls `POST /v1/tasks/{task_id}/start`.
+    ///
+    /// # Returns
+    /// - `Ok(Task)` with the task's new state
+    /// - `Err(ConductorError::NotImplemented)` if the endpoint returns 404
+    /// - `Err(ConductorError::ServerError)` for other errors
+    pub async fn start_task(&self, task_id: &str) -> Result<Task, ConductorError> {
+        self.post_task_action(task_id, "start").await
+    }
+
+    /// Pause a running task.
+    ///
+    /// Calls `POST /v1/tasks/{task_id}/pause`.
+    ///
+    /// # Returns
+    /// - `Ok(Task)` with the task's new state
+    /// - `Err(ConductorError::NotImplemented)` if the endpoint returns 404
+    /// - `Err(ConductorError::ServerError)` for other errors
+    pub async fn pause_task(&self, task_id: &str) -> Result<Task, ConductorError> {
+        self.post_task_action(task_id, "pause").await
+    }
+
+    /// `POST /v1/tasks/{task_id}/{action}`, returning the updated task
+    async fn post_task_action(
+        &self,
+        task_id: &str,
+        action: &str,
+    ) -> Result<Task, ConductorError> {
+        let url = format!("{}/v1/tasks/{}/{}", self.base_url, task_id, action);
+
+        let builder = self.client.post(&url);
+        let response = self.send_request(builder).await?;
+
+        let status = response.status();
+
+        if status.as_u16() == 404 {
+            return Err(ConductorError::NotImplemented(format!(
+                "/v1/tasks/{}/{}",
+                task_id, action
+            )));
+        }
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Fetch one page of a thread's messages.
     ///
-    /// TODO: Expected endpoint: GET /v1/threads/{id}/messages
+    /// GET /v1/threads/{id}/messages?limit={limit}&before={before}
     ///
     /// # Arguments
     /// * `thread_id` - The ID of the thread to fetch messages for
+    /// * `limit` - Maximum number of messages in the page
+    /// * `before` - Position of the oldest message already loaded; None
+    ///   fetches the most recent page
     ///
     /// # Returns
-    /// A vector of messages for the specified thread, or an error if the request fails
-    pub async fn fetch_thread_messages(
+    /// The page (oldest first) with the number of messages before it, or an
+    /// error if the request fails
+    pub async fn fetch_messages(
         &self,
-        _thread_id: &str,
-    ) -> Result<Vec<Message>, ConductorError> {
-        // Stub: return empty vec for now
-        Ok(Vec::new())
+        thread_id: &str,
+        limit: usize,
+        before: Option<usize>,
+    ) -> Result<MessagePageResponse, ConductorError> {
+        let mut url = format!(
+            "{}/v1/threads/{}/messages?limit={}",
+            self.base_url, thread_id, limit
+        );
+        if let Some(before) = before {
+            url.push_str(&format!("&before={}", before));
+        }
+
+        let builder = self.client.get(&url);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        let data: MessagePageResponse = response.json().await?;
+        Ok(data)
     }
 
     /// Fetch a thread with its messages from the backend.
@@ -1081,7 +1311,7 @@ impl ConductorClient {
         );
 
         let builder = self.client.get(&url);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -1132,7 +1362,7 @@ impl ConductorClient {
         });
 
         let builder = self.client.post(&url).json(&body);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -1146,6 +1376,34 @@ impl ConductorClient {
         Ok(())
     }
 
+    /// Fetch the permissions the backend is still waiting on.
+    ///
+    /// Calls `GET /v1/permissions?status=pending`. Used to reconcile prompts
+    /// that may have been answered from another client.
+    ///
+    /// # Returns
+    /// The pending permissions across all threads, or an error if the request fails
+    pub async fn fetch_pending_permissions(
+        &self,
+    ) -> Result<Vec<PendingPermissionSummary>, ConductorError> {
+        let url = format!("{}/v1/permissions?status=pending", self.base_url);
+
+        let builder = self.client.get(&url);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        let data: PendingPermissionsResponse = response.json().await?;
+        Ok(data.permissions)
+    }
+
     /// Verify a thread via the REST endpoint.
     ///
     /// Calls `POST /v1/threads/{thread_id}/verify` to mark a thread as verified.
@@ -1160,7 +1418,7 @@ impl ConductorClient {
         let url = format!("{}/v1/threads/{}/verify", self.base_url, thread_id);
 
         let builder = self.client.post(&url);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         let status = response.status();
 
@@ -1192,22 +1450,121 @@ impl ConductorClient {
         Ok(verified)
     }
 
-    /// Delete a thread permanently.
-    ///
-    /// Calls `DELETE /v1/threads/{thread_id}` to delete the thread from the backend.
+    /// Request a one-line outcome summary of a thread's latest run.
     ///
-    /// # Arguments
-    /// * `thread_id` - The ID of the thread to delete
+    /// Calls `POST /v1/threads/{thread_id}/summary`. The backend generates a
+    /// short summary (e.g., "✅ 14 tests fixed, PR opened") from the thread's
+    /// most recent messages.
     ///
     /// # Returns
-    /// - `Ok(true)` if the thread was deleted successfully
-    /// - `Ok(false)` if the thread was not found (404)
-    /// - `Err(ConductorError)` for other errors
-    pub async fn delete_thread(&self, thread_id: &str) -> Result<bool, ConductorError> {
-        let url = format!("{}/v1/threads/{}", self.base_url, thread_id);
+    /// - `Ok(Some(summary))` if a summary was generated
+    /// - `Ok(None)` if the backend had nothing to summarize
+    /// - `Err(ConductorError::NotImplemented)` if the endpoint returns 404
+    /// - `Err(ConductorError::ServerError)` for other errors
+    pub async fn summarize_thread(&self, thread_id: &str) -> Result<Option<String>, ConductorError> {
+        let url = format!("{}/v1/threads/{}/summary", self.base_url, thread_id);
 
-        let builder = self.client.delete(&url);
-        let response = self.add_auth_header(builder).send().await?;
+        let builder = self.client.post(&url);
+        let response = self.send_request(builder).await?;
+
+        let status = response.status();
+
+        if status.as_u16() == 404 {
+            return Err(ConductorError::NotImplemented(format!(
+                "/v1/threads/{}/summary",
+                thread_id
+            )));
+        }
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        let body: serde_json::Value = response.json().await?;
+        let summary = body
+            .get("summary")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.lines().map(str::trim).find(|line| !line.is_empty()))
+            .map(|s| s.to_string());
+
+        Ok(summary)
+    }
+
+    /// Import threads converted from other tools' transcripts.
+    ///
+    /// POSTs to `/v1/threads/import` with `{"threads": [...]}`; the backend
+    /// creates one thread per entry and returns `{"thread_ids": [...]}`.
+    ///
+    /// # Returns
+    /// - `Ok(thread_ids)` with the created thread IDs, in request order
+    /// - `Err(ConductorError::NotImplemented)` if the endpoint returns 404
+    /// - `Err(ConductorError::ServerError)` for other errors
+    pub async fn import_threads(
+        &self,
+        threads: &[ImportedThread],
+    ) -> Result<Vec<String>, ConductorError> {
+        let url = format!("{}/v1/threads/import", self.base_url);
+
+        let builder = self
+            .client
+            .post(&url)
+            .json(&serde_json::json!({ "threads": threads }));
+        let response = self.send_request(builder).await?;
+
+        let status = response.status();
+
+        if status.as_u16() == 404 {
+            return Err(ConductorError::NotImplemented("/v1/threads/import".to_string()));
+        }
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        let body: serde_json::Value = response.json().await?;
+        let thread_ids = body
+            .get("thread_ids")
+            .and_then(|v| v.as_array())
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| id.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(thread_ids)
+    }
+
+    /// Delete a thread permanently.
+    ///
+    /// Calls `DELETE /v1/threads/{thread_id}` to delete the thread from the backend.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The ID of the thread to delete
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the thread was deleted successfully
+    /// - `Ok(false)` if the thread was not found (404)
+    /// - `Err(ConductorError)` for other errors
+    pub async fn delete_thread(&self, thread_id: &str) -> Result<bool, ConductorError> {
+        let url = format!("{}/v1/threads/{}", self.base_url, thread_id);
+
+        let builder = self.client.delete(&url);
+        let response = self.send_request(builder).await?;
 
         let status = response.status();
 
@@ -1253,7 +1610,7 @@ impl ConductorClient {
         });
 
         let builder = self.client.put(&url).json(&body);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -1290,7 +1647,227 @@ impl ConductorClient {
         });
 
         let builder = self.client.put(&url).json(&body);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(())
+    }
+
+    /// Update the model of a thread.
+    ///
+    /// Calls `PUT /v1/threads/{thread_id}/model` so the choice survives restarts.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The ID of the thread to update
+    /// * `model` - The model ID to use for the thread's next responses
+    ///
+    /// # Returns
+    /// - `Ok(())` on success
+    /// - `Err(ConductorError::ServerError)` if the server returns an error (404, 400, etc.)
+    pub async fn update_thread_model(
+        &self,
+        thread_id: &str,
+        model: &str,
+    ) -> Result<(), ConductorError> {
+        let url = format!("{}/v1/threads/{}/model", self.base_url, thread_id);
+
+        let body = serde_json::json!({
+            "model": model
+        });
+
+        let builder = self.client.put(&url).json(&body);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(())
+    }
+
+    /// Replace the links of a thread.
+    ///
+    /// Calls `PUT /v1/threads/{thread_id}/links` with the thread's full link list.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The ID of the thread to update
+    /// * `links` - Every link the thread should have after the update
+    ///
+    /// # Returns
+    /// - `Ok(())` on success
+    /// - `Err(ConductorError::ServerError)` if the server returns an error (404, 400, etc.)
+    pub async fn update_thread_links(
+        &self,
+        thread_id: &str,
+        links: &[ThreadLink],
+    ) -> Result<(), ConductorError> {
+        let url = format!("{}/v1/threads/{}/links", self.base_url, thread_id);
+
+        let body = serde_json::json!({
+            "links": links
+        });
+
+        let builder = self.client.put(&url).json(&body);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(())
+    }
+
+    /// Pin or unpin a thread.
+    ///
+    /// Calls `PUT /v1/threads/{thread_id}/pin`.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The ID of the thread to update
+    /// * `pinned` - Whether the thread is pinned
+    /// * `pin_order` - Position among pinned threads (lower sorts first)
+    ///
+    /// # Returns
+    /// - `Ok(())` on success
+    /// - `Err(ConductorError::ServerError)` if the server returns an error (404, 400, etc.)
+    pub async fn update_thread_pin(
+        &self,
+        thread_id: &str,
+        pinned: bool,
+        pin_order: u32,
+    ) -> Result<(), ConductorError> {
+        let url = format!("{}/v1/threads/{}/pin", self.base_url, thread_id);
+
+        let body = serde_json::json!({
+            "pinned": pinned,
+            "pin_order": pin_order
+        });
+
+        let builder = self.client.put(&url).json(&body);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(())
+    }
+
+    /// Archive or unarchive a thread.
+    ///
+    /// Calls `PUT /v1/threads/{thread_id}/archive`. Archived threads keep
+    /// their messages.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The ID of the thread to update
+    /// * `archived` - Whether the thread is archived
+    ///
+    /// # Returns
+    /// - `Ok(())` on success
+    /// - `Err(ConductorError::ServerError)` if the server returns an error (404, 400, etc.)
+    pub async fn archive_thread(
+        &self,
+        thread_id: &str,
+        archived: bool,
+    ) -> Result<(), ConductorError> {
+        let url = format!("{}/v1/threads/{}/archive", self.base_url, thread_id);
+
+        let body = serde_json::json!({ "archived": archived });
+
+        let builder = self.client.put(&url).json(&body);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(())
+    }
+
+    /// Rename a thread.
+    ///
+    /// Calls `PUT /v1/threads/{thread_id}/title`.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The ID of the thread to rename
+    /// * `title` - The new title
+    ///
+    /// # Returns
+    /// - `Ok(())` on success
+    /// - `Err(ConductorError::ServerError)` if the server returns an error (404, 400, etc.)
+    pub async fn rename_thread(&self, thread_id: &str, title: &str) -> Result<(), ConductorError> {
+        let url = format!("{}/v1/threads/{}/title", self.base_url, thread_id);
+
+        let body = serde_json::json!({ "title": title });
+
+        let builder = self.client.put(&url).json(&body);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(())
+    }
+
+    /// Change the working directory of a thread.
+    ///
+    /// Calls `PUT /v1/threads/{thread_id}/working-directory`. Later turns of
+    /// the thread run in the new directory.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The ID of the thread
+    /// * `path` - The new working directory (absolute path on the server)
+    ///
+    /// # Returns
+    /// - `Ok(())` on success
+    /// - `Err(ConductorError::ServerError)` if the server returns an error (404, 400, etc.)
+    pub async fn update_thread_working_directory(
+        &self,
+        thread_id: &str,
+        path: &str,
+    ) -> Result<(), ConductorError> {
+        let url = format!(
+            "{}/v1/threads/{}/working-directory",
+            self.base_url, thread_id
+        );
+
+        let body = serde_json::json!({ "working_directory": path });
+
+        let builder = self.client.put(&url).json(&body);
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -1327,7 +1904,7 @@ impl ConductorClient {
             .client
             .get(&url)
             .query(&[("q", query), ("limit", &limit.to_string())]);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -1343,6 +1920,37 @@ impl ConductorClient {
         Ok(SearchFoldersResponse { folders })
     }
 
+    /// List the entries of a directory on the backend host.
+    ///
+    /// GET /v1/fs/list?path={path}
+    ///
+    /// Used by the @ picker to browse for a working directory when there are
+    /// no indexed folders. `~` is expanded by the backend.
+    ///
+    /// # Arguments
+    /// * `path` - Directory to list
+    ///
+    /// # Returns
+    /// - `Ok(DirectoryListing)` with the resolved path and its entries
+    /// - `Err(ConductorError::ServerError)` if the path can't be read (403, 404, etc.)
+    pub async fn list_directory(&self, path: &str) -> Result<DirectoryListing, ConductorError> {
+        let url = format!("{}/v1/fs/list", self.base_url);
+
+        let builder = self.client.get(&url).query(&[("path", path)]);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(response.json().await?)
+    }
+
     /// Search threads by title.
     ///
     /// GET /v1/search/threads?q={query}&limit={limit}
@@ -1364,7 +1972,7 @@ impl ConductorClient {
             .client
             .get(&url)
             .query(&[("q", query), ("limit", &limit.to_string())]);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -1401,7 +2009,7 @@ impl ConductorClient {
             .client
             .get(&url)
             .query(&[("q", query), ("limit", &limit.to_string())]);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -1437,7 +2045,7 @@ impl ConductorClient {
         });
 
         let builder = self.client.post(&url).json(&body);
-        let response = self.add_auth_header(builder).send().await?;
+        let response = self.send_request(builder).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -1451,6 +2059,45 @@ impl ConductorClient {
         let data: CloneResponse = response.json().await?;
         Ok(data)
     }
+
+    // ==================== Attachments API ====================
+
+    /// Upload an image so a stream request can reference it.
+    ///
+    /// POST /v1/attachments
+    ///
+    /// # Arguments
+    /// * `image` - Base64-encoded PNG and its short hash
+    ///
+    /// # Returns
+    /// The attachment ID to pass in `StreamRequest::attachments`
+    pub async fn upload_attachment(
+        &self,
+        image: &ImageAttachmentPayload,
+    ) -> Result<String, ConductorError> {
+        let url = format!("{}/v1/attachments", self.base_url);
+
+        let body = serde_json::json!({
+            "hash": image.hash,
+            "media_type": "image/png",
+            "data": image.data,
+        });
+
+        let builder = self.client.post(&url).json(&body);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        let data: AttachmentUploadResponse = response.json().await?;
+        Ok(data.id)
+    }
 }
 
 impl Default for ConductorClient {
@@ -1612,9 +2259,13 @@ fn convert_sse_event(event: crate::sse::SseEvent) -> SseEvent {
         crate::sse::SseEvent::Usage {
             context_window_used,
             context_window_limit,
+            input_tokens,
+            output_tokens,
         } => SseEvent::Usage(crate::events::UsageEvent {
             context_window_used,
             context_window_limit,
+            input_tokens,
+            output_tokens,
         }),
         crate::sse::SseEvent::SystemInit {
             cli_session_id,
@@ -1644,6 +2295,136 @@ fn convert_sse_event(event: crate::sse::SseEvent) -> SseEvent {
     }
 }
 
+/// Turn a successful SSE HTTP response into a stream of parsed events.
+///
+/// Shared by [`ConductorClient::stream_with_debug`] and
+/// [`ConductorClient::resume_stream`].
+fn sse_event_stream(
+    response: reqwest::Response,
+    debug_tx: Option<DebugEventSender>,
+) -> Pin<Box<dyn Stream<Item = Result<SseEvent, ConductorError>> + Send>> {
+    // Get the byte stream from the response (with stalls and early
+    // disconnects when --inject-faults is on)
+    let bytes_stream: Pin<Box<dyn Stream<Item = reqwest::Result<bytes::Bytes>> + Send>> =
+        match fault_injection::active() {
+            Some(faults) => Box::pin(fault_injection::inject_stream_faults(
+                response.bytes_stream(),
+                faults.clone(),
+            )),
+            None => Box::pin(response.bytes_stream()),
+        };
+
+    // Create an SSE parser and process the byte stream
+    // Include debug_tx in the state tuple for emitting debug events
+    // Use Vec<u8> buffer to avoid data loss when UTF-8 chars are split across TCP chunks
+    let event_stream = stream::unfold(
+        (bytes_stream, SseParser::new(), Vec::<u8>::new(), debug_tx),
+        |(mut bytes_stream, mut parser, mut byte_buffer, debug_tx)| async move {
+            loop {
+                // First, try to process any complete lines in the buffer
+                // Look for newline in the byte buffer
+                if let Some(newline_pos) = byte_buffer.iter().position(|&b| b == b'\n') {
+                    // Extract the line bytes (including newline)
+                    let line_bytes: Vec<u8> = byte_buffer.drain(..=newline_pos).collect();
+
+                    // Decode to string using lossy conversion to handle edge cases
+                    // where a multi-byte UTF-8 char might still be incomplete
+                    let line = String::from_utf8_lossy(&line_bytes[..line_bytes.len() - 1])
+                        .trim_end_matches('\r')
+                        .to_string();
+
+                    match parser.feed_line(&line) {
+                        Ok(Some(sse_event)) => {
+                            // Emit raw SSE debug event if debug channel is available
+                            if let Some(ref tx) = debug_tx {
+                                let raw_data = RawSseEventData::new(
+                                    sse_event.event_type_name(),
+                                    format!("{:?}", sse_event),
+                                );
+                                let debug_event =
+                                    DebugEvent::new(DebugEventKind::RawSseEvent(raw_data));
+                                let _ = tx.send(debug_event);
+                            }
+
+                            // Convert the sse::SseEvent to events::SseEvent
+                            let event = convert_sse_event(sse_event);
+                            return Some((
+                                Ok(event),
+                                (bytes_stream, parser, byte_buffer, debug_tx),
+                            ));
+                        }
+                        Ok(None) => {
+                            // Continue processing buffer
+                            continue;
+                        }
+                        Err(e) => {
+                            return Some((
+                                Err(ConductorError::SseParse(e)),
+                                (bytes_stream, parser, byte_buffer, debug_tx),
+                            ));
+                        }
+                    }
+                }
+
+                // Need more data from the stream
+                match bytes_stream.next().await {
+                    Some(Ok(chunk)) => {
+                        // Append raw bytes to buffer - no UTF-8 conversion that could fail
+                        byte_buffer.extend_from_slice(&chunk);
+                        // Loop back to process the buffer
+                    }
+                    Some(Err(e)) => {
+                        return Some((
+                            Err(ConductorError::Http(e)),
+                            (bytes_stream, parser, byte_buffer, debug_tx),
+                        ));
+                    }
+                    None => {
+                        // Stream ended - process any remaining data in buffer
+                        if !byte_buffer.is_empty() {
+                            let line = String::from_utf8_lossy(&byte_buffer)
+                                .trim_end_matches('\r')
+                                .to_string();
+                            byte_buffer.clear();
+                            match parser.feed_line(&line) {
+                                Ok(Some(sse_event)) => {
+                                    // Emit raw SSE debug event if debug channel is available
+                                    if let Some(ref tx) = debug_tx {
+                                        let raw_data = RawSseEventData::new(
+                                            sse_event.event_type_name(),
+                                            format!("{:?}", sse_event),
+                                        );
+                                        let debug_event = DebugEvent::new(
+                                            DebugEventKind::RawSseEvent(raw_data),
+                                        );
+                                        let _ = tx.send(debug_event);
+                                    }
+
+                                    let event = convert_sse_event(sse_event);
+                                    return Some((
+                                        Ok(event),
+                                        (bytes_stream, parser, byte_buffer, debug_tx),
+                                    ));
+                                }
+                                Ok(None) => {}
+                                Err(e) => {
+                                    return Some((
+                                        Err(ConductorError::SseParse(e)),
+                                        (bytes_stream, parser, byte_buffer, debug_tx),
+                                    ));
+                                }
+                            }
+                        }
+                        return None;
+                    }
+                }
+            }
+        },
+    );
+
+    Box::pin(event_stream)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1699,6 +2480,25 @@ mod tests {
         assert!(display.contains("Internal Server Error"));
     }
 
+    #[test]
+    fn test_is_resume_unavailable() {
+        for status in [404, 409, 410, 501] {
+            let err = ConductorError::ServerError {
+                status,
+                message: String::new(),
+            };
+            assert!(err.is_resume_unavailable(), "status {}", status);
+        }
+        assert!(ConductorError::NotImplemented("/v1/stream/resume".to_string())
+            .is_resume_unavailable());
+
+        let transient = ConductorError::ServerError {
+            status: 503,
+            message: "Service Unavailable".to_string(),
+        };
+        assert!(!transient.is_resume_unavailable());
+    }
+
     #[test]
     fn test_conductor_error_from_sse_parse() {
         let sse_err = SseParseError::UnknownEventType("test".to_string());
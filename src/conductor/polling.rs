@@ -0,0 +1,267 @@
+//! Polling transport for stream responses.
+//!
+//! Last resort for networks where a proxy blocks or buffers streaming
+//! responses and the WebSocket can't connect either. The stream request is
+//! submitted with `POST /v1/stream/poll`, then new events are fetched with
+//! `GET /v1/stream/events?thread_id=..&after=..` until the backend reports the
+//! response done. Polled events carry the same event types and payloads as
+//! SSE, so they go through the same parser and come out as the same
+//! `SseEvent` stream `ConductorClient::stream` returns.
+//!
+//! The interval adapts: it drops back to `MIN_POLL_INTERVAL` whenever events
+//! arrive and doubles on every empty poll, up to `MAX_POLL_INTERVAL`.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::stream::{self, Stream};
+use serde::Deserialize;
+
+use super::{convert_sse_event, ConductorClient, ConductorError};
+use crate::events::SseEvent;
+use crate::models::StreamRequest;
+use crate::sse::parse_sse_event;
+
+/// Poll interval while events are arriving
+pub const MIN_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Poll interval after a long quiet period
+pub const MAX_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Adaptive delay between polls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollInterval {
+    current: Duration,
+}
+
+impl Default for PollInterval {
+    fn default() -> Self {
+        Self {
+            current: MIN_POLL_INTERVAL,
+        }
+    }
+}
+
+impl PollInterval {
+    /// Delay before the next poll
+    pub fn current(&self) -> Duration {
+        self.current
+    }
+
+    /// Adjust after a poll: back to the minimum if it returned events,
+    /// twice as long (capped) if it didn't
+    pub fn record(&mut self, got_events: bool) {
+        self.current = if got_events {
+            MIN_POLL_INTERVAL
+        } else {
+            (self.current * 2).min(MAX_POLL_INTERVAL)
+        };
+    }
+}
+
+/// One event from the poll endpoint
+#[derive(Debug, Clone, Deserialize)]
+pub struct PolledEvent {
+    /// SSE event type (`content`, `done`, ...)
+    pub event: String,
+    /// Event payload: a JSON string as in SSE `data:` lines, or a JSON object
+    #[serde(default)]
+    pub data: serde_json::Value,
+    /// Sequence number, used as the `after` cursor of the next poll
+    #[serde(default)]
+    pub seq: Option<u64>,
+}
+
+impl PolledEvent {
+    /// The payload as SSE `data:` text
+    fn data_text(&self) -> String {
+        match &self.data {
+            serde_json::Value::String(text) => text.clone(),
+            serde_json::Value::Null => String::new(),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// Response of `GET /v1/stream/events`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PollResponse {
+    #[serde(default)]
+    pub events: Vec<PolledEvent>,
+    /// No more events will follow for this response
+    #[serde(default)]
+    pub done: bool,
+}
+
+/// State carried between polls
+struct PollState {
+    client: Arc<ConductorClient>,
+    thread_id: String,
+    after: Option<u64>,
+    interval: PollInterval,
+    pending: VecDeque<Result<SseEvent, ConductorError>>,
+    first_poll: bool,
+    finished: bool,
+}
+
+impl ConductorClient {
+    /// Send a stream request over the polling transport.
+    ///
+    /// Returns the same event stream as [`ConductorClient::stream`]. The
+    /// request needs a thread ID, which the events are fetched by.
+    pub async fn stream_polling(
+        self: &Arc<Self>,
+        request: &StreamRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<SseEvent, ConductorError>> + Send>>, ConductorError>
+    {
+        let thread_id = request
+            .thread_id
+            .clone()
+            .ok_or_else(|| ConductorError::NotImplemented("polling without a thread_id".into()))?;
+        let url = format!("{}/v1/stream/poll", self.base_url);
+
+        tracing::info!("STREAM_POLL: url={}, thread_id={}", url, thread_id);
+
+        let builder = self
+            .client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .json(request);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(polling_event_stream(Arc::clone(self), thread_id))
+    }
+
+    /// Fetch the events of a thread's response after sequence number `after`.
+    pub async fn poll_stream_events(
+        &self,
+        thread_id: &str,
+        after: Option<u64>,
+    ) -> Result<PollResponse, ConductorError> {
+        let mut url = format!(
+            "{}/v1/stream/events?thread_id={}",
+            self.base_url,
+            urlencoding::encode(thread_id)
+        );
+        if let Some(seq) = after {
+            url.push_str(&format!("&after={}", seq));
+        }
+
+        let builder = self.client.get(&url);
+        let response = self.send_request(builder).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(ConductorError::ServerError { status, message });
+        }
+
+        Ok(response.json().await?)
+    }
+}
+
+/// Poll until the response is done, yielding the events in order
+fn polling_event_stream(
+    client: Arc<ConductorClient>,
+    thread_id: String,
+) -> Pin<Box<dyn Stream<Item = Result<SseEvent, ConductorError>> + Send>> {
+    let state = PollState {
+        client,
+        thread_id,
+        after: None,
+        interval: PollInterval::default(),
+        pending: VecDeque::new(),
+        first_poll: true,
+        finished: false,
+    };
+
+    Box::pin(stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.pending.pop_front() {
+                return Some((item, state));
+            }
+            if state.finished {
+                return None;
+            }
+
+            if !state.first_poll {
+                tokio::time::sleep(state.interval.current()).await;
+            }
+            state.first_poll = false;
+
+            match state
+                .client
+                .poll_stream_events(&state.thread_id, state.after)
+                .await
+            {
+                Ok(poll) => {
+                    state.interval.record(!poll.events.is_empty());
+                    state.finished = poll.done;
+                    for event in poll.events {
+                        if let Some(seq) = event.seq {
+                            state.after = Some(state.after.map_or(seq, |a| a.max(seq)));
+                        }
+                        let parsed = parse_sse_event(&event.event, &event.data_text())
+                            .map(convert_sse_event)
+                            .map_err(ConductorError::SseParse);
+                        state.pending.push_back(parsed);
+                    }
+                }
+                Err(e) => {
+                    state.finished = true;
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_interval_backs_off_and_resets() {
+        let mut interval = PollInterval::default();
+        assert_eq!(interval.current(), MIN_POLL_INTERVAL);
+
+        interval.record(false);
+        assert_eq!(interval.current(), Duration::from_secs(1));
+        for _ in 0..10 {
+            interval.record(false);
+        }
+        assert_eq!(interval.current(), MAX_POLL_INTERVAL);
+
+        interval.record(true);
+        assert_eq!(interval.current(), MIN_POLL_INTERVAL);
+    }
+
+    #[test]
+    fn test_polled_event_data_text() {
+        let response: PollResponse = serde_json::from_str(
+            r#"{"events": [
+                {"event": "content", "data": "{\"text\": \"Hi\"}", "seq": 1},
+                {"event": "done", "data": {"message_id": "42"}, "seq": 2}
+            ], "done": true}"#,
+        )
+        .unwrap();
+
+        assert!(response.done);
+        assert_eq!(response.events[0].data_text(), r#"{"text": "Hi"}"#);
+        assert_eq!(response.events[1].data_text(), r#"{"message_id":"42"}"#);
+    }
+}
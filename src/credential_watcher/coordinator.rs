@@ -54,7 +54,8 @@ pub fn handle_credential_change(
         return;
     }
 
-    // Debounce the change
+    // Debounce the change, remembering where it came from for the sync report
+    state.record_change(source);
     if debouncer.on_change() {
         tracing::debug!("Starting debounce timer ({}ms)", DEBOUNCE_MS);
         spawn_debounce_timer(message_tx.clone());
@@ -77,6 +78,7 @@ pub fn handle_debounce_expired(
     if !state.should_sync() {
         tracing::debug!("Debounce expired but sync not allowed");
         debouncer.reset();
+        state.changed_files.clear();
         return;
     }
 
@@ -85,7 +87,34 @@ pub fn handle_debounce_expired(
     debouncer.reset();
 
     // Trigger sync using existing infrastructure
-    let _ = message_tx.send(AppMessage::TriggerSync);
+    let _ = message_tx.send(AppMessage::TriggerSync {
+        changed_files: state.take_changed_files(),
+    });
+}
+
+/// Handles a manual sync request (the /sync command).
+///
+/// Goes through the same path as auto-sync so a file change can't start a
+/// second, overlapping sync. Manual syncs ignore backoff since the user asked
+/// for one explicitly. Returns false if a sync is already running.
+pub fn handle_manual_sync(
+    state: &mut CredentialWatchState,
+    debouncer: &mut Debouncer,
+    message_tx: &mpsc::UnboundedSender<AppMessage>,
+) -> bool {
+    if state.sync_pending {
+        tracing::debug!("Manual sync requested while a sync is pending");
+        return false;
+    }
+
+    tracing::info!("Manual sync requested");
+    state.sync_started();
+    debouncer.reset();
+
+    let _ = message_tx.send(AppMessage::TriggerSync {
+        changed_files: state.take_changed_files(),
+    });
+    true
 }
 
 /// Handles sync completion (success).
@@ -159,12 +188,78 @@ mod tests {
 
         // Should have sent TriggerSync
         let msg = rx.recv().await;
-        assert!(matches!(msg, Some(AppMessage::TriggerSync)));
+        assert!(matches!(msg, Some(AppMessage::TriggerSync { .. })));
 
         // State should indicate sync started
         assert!(state.sync_pending);
     }
 
+    #[tokio::test]
+    async fn test_changes_in_window_coalesce_into_one_sync() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut state = CredentialWatchState::new();
+        let mut debouncer = Debouncer::new();
+
+        handle_credential_change(&mut state, &mut debouncer, &tx, "file: hosts.yml");
+        handle_credential_change(&mut state, &mut debouncer, &tx, "file: hosts.yml");
+        handle_credential_change(&mut state, &mut debouncer, &tx, "file: config.yml");
+        handle_debounce_expired(&mut state, &mut debouncer, &tx);
+
+        match rx.try_recv() {
+            Ok(AppMessage::TriggerSync { changed_files }) => {
+                assert_eq!(changed_files, vec!["file: hosts.yml", "file: config.yml"]);
+            }
+            other => panic!("expected TriggerSync, got {:?}", other),
+        }
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_handle_manual_sync_marks_pending() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut state = CredentialWatchState::new();
+        let mut debouncer = Debouncer::new();
+
+        assert!(handle_manual_sync(&mut state, &mut debouncer, &tx));
+        assert!(state.sync_pending);
+        assert!(matches!(rx.try_recv(), Ok(AppMessage::TriggerSync { .. })));
+
+        // A second request while the first is running is refused
+        assert!(!handle_manual_sync(&mut state, &mut debouncer, &tx));
+        assert!(rx.try_recv().is_err());
+
+        // And file changes don't start an overlapping sync
+        handle_credential_change(&mut state, &mut debouncer, &tx, "file: hosts.yml");
+        assert!(!debouncer.has_pending());
+    }
+
+    #[test]
+    fn test_handle_manual_sync_ignores_backoff() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = CredentialWatchState::new();
+        let mut debouncer = Debouncer::new();
+
+        state.sync_started();
+        state.sync_failed();
+        assert!(state.backoff.is_in_backoff());
+
+        assert!(handle_manual_sync(&mut state, &mut debouncer, &tx));
+    }
+
+    #[test]
+    fn test_handle_credential_change_in_backoff() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut state = CredentialWatchState::new();
+        let mut debouncer = Debouncer::new();
+
+        state.sync_started();
+        state.sync_failed();
+        handle_credential_change(&mut state, &mut debouncer, &tx, "file: hosts.yml");
+
+        assert!(!debouncer.has_pending());
+        assert!(state.changed_files.is_empty());
+    }
+
     #[tokio::test]
     async fn test_handle_debounce_expired_no_pending() {
         let (tx, mut rx) = mpsc::unbounded_channel();
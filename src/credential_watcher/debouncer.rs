@@ -6,7 +6,7 @@
 //!
 //! # How it works
 //!
-//! 1. First change event starts a 500ms timer
+//! 1. First change event starts a 2s timer
 //! 2. Subsequent changes within the window are ignored (timer already running)
 //! 3. When timer fires, trigger sync if changes are still pending
 //! 4. Reset state after sync starts
@@ -14,7 +14,7 @@
 use std::time::{Duration, Instant};
 
 /// Debounce window - changes within this window are coalesced
-pub const DEBOUNCE_MS: u64 = 500;
+pub const DEBOUNCE_MS: u64 = 2000;
 
 /// Manages debouncing of credential change events.
 ///
@@ -197,6 +197,6 @@ mod tests {
 
     #[test]
     fn test_debounce_constant() {
-        assert_eq!(DEBOUNCE_MS, 500);
+        assert_eq!(DEBOUNCE_MS, 2000);
     }
 }
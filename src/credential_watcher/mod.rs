@@ -14,7 +14,7 @@
 //!          ▼
 //!  ┌───────────────┐
 //!  │   Debouncer   │
-//!  │   (500ms)     │
+//!  │   (2s)        │
 //!  └───────┬───────┘
 //!          ▼
 //!  ┌───────────────┐
@@ -46,9 +46,10 @@ mod types;
 
 // Public exports
 pub use coordinator::{
-    handle_credential_change, handle_debounce_expired, handle_sync_complete, handle_sync_failed,
+    handle_credential_change, handle_debounce_expired, handle_manual_sync, handle_sync_complete,
+    handle_sync_failed,
 };
 pub use debouncer::Debouncer;
 pub use file_watcher::spawn_file_watcher;
 pub use state::{CredentialWatchState, ExponentialBackoff};
-pub use types::{CredentialChangeEvent, CredentialSource};
+pub use types::{CredentialChangeEvent, CredentialSource, CredentialSyncResult, SyncReport};
@@ -137,6 +137,9 @@ pub struct CredentialWatchState {
     /// Timestamp of pending change (for debouncing)
     pub pending_change: Option<Instant>,
 
+    /// Sources that changed since the last sync was triggered
+    pub changed_files: Vec<String>,
+
     /// Whether the watcher system is enabled
     pub enabled: bool,
 }
@@ -150,6 +153,7 @@ impl CredentialWatchState {
             backoff: ExponentialBackoff::new(),
             sync_pending: false,
             pending_change: None,
+            changed_files: Vec::new(),
             enabled: true,
         }
     }
@@ -189,6 +193,20 @@ impl CredentialWatchState {
         self.backoff.record_failure();
     }
 
+    /// Remember a changed source so the next sync can report it.
+    ///
+    /// Duplicate sources within one debounce window are recorded once.
+    pub fn record_change(&mut self, source: &str) {
+        if !self.changed_files.iter().any(|f| f == source) {
+            self.changed_files.push(source.to_string());
+        }
+    }
+
+    /// Take the sources collected since the last sync was triggered.
+    pub fn take_changed_files(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.changed_files)
+    }
+
     /// Disable the watcher system.
     pub fn disable(&mut self) {
         self.enabled = false;
@@ -319,4 +337,19 @@ mod tests {
         assert!(!state.sync_pending);
         assert_eq!(state.backoff.failure_count(), 1);
     }
+
+    #[test]
+    fn test_credential_watch_state_record_change_dedups() {
+        let mut state = CredentialWatchState::new();
+
+        state.record_change("file: hosts.yml");
+        state.record_change("file: hosts.yml");
+        state.record_change("file: auth.json");
+
+        assert_eq!(
+            state.take_changed_files(),
+            vec!["file: hosts.yml".to_string(), "file: auth.json".to_string()]
+        );
+        assert!(state.changed_files.is_empty());
+    }
 }
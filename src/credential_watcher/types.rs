@@ -2,6 +2,8 @@
 
 use std::path::PathBuf;
 
+use crate::conductor::SyncResponse;
+
 /// Source of a credential change event
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CredentialSource {
@@ -58,6 +60,70 @@ impl CredentialChangeEvent {
     }
 }
 
+/// Credentials pushed by a full sync, as (sync key, display name)
+const SYNCED_CREDENTIALS: &[(&str, &str)] = &[("github_cli", "GitHub CLI"), ("codex", "Codex")];
+
+/// Outcome of a sync for a single credential
+#[derive(Debug, Clone, PartialEq)]
+pub struct CredentialSyncResult {
+    /// Display name (e.g. "GitHub CLI")
+    pub name: String,
+    /// Whether the server accepted this credential
+    pub pushed: bool,
+    /// Whether the VPS verified it works (None if not checked)
+    pub verified: Option<bool>,
+}
+
+/// Per-credential breakdown of a sync, shown in the sync dialog
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncReport {
+    /// Sources whose changes triggered the sync (empty for manual syncs)
+    pub changed_files: Vec<String>,
+    /// What happened to each credential
+    pub credentials: Vec<CredentialSyncResult>,
+    /// Message returned by the server
+    pub server_message: String,
+}
+
+impl SyncReport {
+    /// Build a report from the server's sync response.
+    ///
+    /// Older servers don't return the `synced` list; in that case every
+    /// credential counts as pushed if the sync as a whole succeeded.
+    pub fn from_response(
+        changed_files: Vec<String>,
+        response: &SyncResponse,
+        github_cli_verified: bool,
+    ) -> Self {
+        let credentials = SYNCED_CREDENTIALS
+            .iter()
+            .map(|(key, name)| CredentialSyncResult {
+                name: name.to_string(),
+                pushed: match &response.synced {
+                    Some(synced) => synced.iter().any(|s| s == key),
+                    None => response.success,
+                },
+                verified: (*key == "github_cli").then_some(github_cli_verified),
+            })
+            .collect();
+
+        Self {
+            changed_files,
+            credentials,
+            server_message: response.message.clone(),
+        }
+    }
+
+    /// Build a report for a sync that never got a response.
+    pub fn failed(changed_files: Vec<String>, error: &str) -> Self {
+        Self {
+            changed_files,
+            credentials: Vec::new(),
+            server_message: error.to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,4 +156,40 @@ mod tests {
         let keychain_event = CredentialChangeEvent::keychain();
         assert!(keychain_event.source.is_keychain());
     }
+
+    fn response(synced: Option<Vec<&str>>) -> SyncResponse {
+        SyncResponse {
+            success: true,
+            message: "Tokens synced".to_string(),
+            synced: synced.map(|s| s.into_iter().map(String::from).collect()),
+            verification: None,
+        }
+    }
+
+    #[test]
+    fn test_sync_report_from_response_uses_synced_list() {
+        let report = SyncReport::from_response(
+            vec!["file: hosts.yml".to_string()],
+            &response(Some(vec!["github_cli"])),
+            true,
+        );
+
+        assert_eq!(report.changed_files, vec!["file: hosts.yml"]);
+        assert_eq!(report.server_message, "Tokens synced");
+        assert_eq!(report.credentials.len(), 2);
+        assert_eq!(report.credentials[0].name, "GitHub CLI");
+        assert!(report.credentials[0].pushed);
+        assert_eq!(report.credentials[0].verified, Some(true));
+        assert_eq!(report.credentials[1].name, "Codex");
+        assert!(!report.credentials[1].pushed);
+        assert_eq!(report.credentials[1].verified, None);
+    }
+
+    #[test]
+    fn test_sync_report_without_synced_list_falls_back_to_success() {
+        let report = SyncReport::from_response(Vec::new(), &response(None), false);
+
+        assert!(report.credentials.iter().all(|c| c.pushed));
+        assert_eq!(report.credentials[0].verified, Some(false));
+    }
 }
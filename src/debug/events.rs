@@ -267,6 +267,9 @@ pub struct StateSnapshot {
     pub session_id: Option<String>,
     /// Current thread ID if any
     pub thread_id: Option<String>,
+    /// Full app state dump (see `App::debug_snapshot`), refreshed about once a second
+    #[serde(default)]
+    pub app: Option<serde_json::Value>,
 }
 
 /// Information about an active tool.
@@ -341,6 +341,7 @@ mod tests {
             active_subagents: 3,
             session_id: Some("session-123".to_string()),
             thread_id: Some("thread-456".to_string()),
+            app: None,
         };
 
         assert_eq!(snapshot.active_subagents, 3);
@@ -359,6 +360,7 @@ mod tests {
             active_subagents: 2,
             session_id: Some("test-session".to_string()),
             thread_id: None,
+            app: None,
         };
 
         let json = serde_json::to_string(&snapshot).expect("Failed to serialize");
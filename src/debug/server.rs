@@ -96,6 +96,7 @@ pub async fn start_debug_server_on(
         .route("/", get(dashboard_handler))
         .route("/ws", get(websocket_handler))
         .route("/state", get(state_handler))
+        .route("/snapshot", get(snapshot_handler))
         .layer(cors)
         .with_state(server_state);
 
@@ -185,3 +186,11 @@ async fn state_handler(State(state): State<DebugServerState>) -> impl IntoRespon
     let snapshot = state.state_snapshot.read().await;
     Json(snapshot.clone())
 }
+
+/// Handler for the app snapshot endpoint.
+///
+/// Returns `null` until the app has published its first snapshot.
+async fn snapshot_handler(State(state): State<DebugServerState>) -> impl IntoResponse {
+    let snapshot = state.state_snapshot.read().await;
+    Json(snapshot.app.clone().unwrap_or(serde_json::Value::Null))
+}
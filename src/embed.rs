@@ -0,0 +1,332 @@
+//! Stable embedding facade.
+//!
+//! This module is the supported way to embed the spoq TUI in another
+//! application. It wraps [`App`] behind a small surface that is covered by
+//! semver: items here only change in breaking ways on a major version bump,
+//! and [`EMBED_API_VERSION`] is incremented whenever that happens.
+//!
+//! Internals such as `conductor`, `websocket` and `events` are free to change
+//! between releases; embedders should not reach into them directly.
+//!
+//! To show a conversation without the rest of the app, render a
+//! [`ConversationView`](crate::widgets::ConversationView) instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use ratatui::{backend::TestBackend, Terminal};
+//! use spoq::adapters::MockHttpClient;
+//! use spoq::embed::{EmbedEvent, EmbeddedApp};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut embedded = EmbeddedApp::builder()
+//!     .base_url("http://localhost:8000")
+//!     .http_client(Arc::new(MockHttpClient::new()))
+//!     .viewport(100, 30)
+//!     .build()?;
+//! let mut terminal = Terminal::new(TestBackend::new(100, 30))?;
+//!
+//! embedded.handle_event(EmbedEvent::Tick);
+//! if embedded.needs_redraw() {
+//!     terminal.draw(|frame| embedded.render(frame))?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use ratatui::Frame;
+use tokio::sync::mpsc;
+
+use crate::app::{App, AppMessage, Screen};
+use crate::conductor::{ConductorClient, ConductorConfig};
+use crate::models::{Message, Thread};
+use crate::traits::HttpClient;
+
+pub use crate::app::observe::{AppEvent, ObserverId};
+
+/// Version of the embedding API.
+///
+/// Bumped on every breaking change to this module.
+pub const EMBED_API_VERSION: u32 = 1;
+
+/// Errors returned by the embedding facade.
+#[derive(Debug)]
+pub enum EmbedError {
+    /// The underlying application state could not be created
+    InitFailed(String),
+}
+
+impl std::fmt::Display for EmbedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmbedError::InitFailed(msg) => write!(f, "Failed to initialize app: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for EmbedError {}
+
+/// Events an embedder feeds into the app.
+///
+/// Covered by semver like the rest of this module; new variants may be added
+/// in minor releases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EmbedEvent {
+    /// A thread's title and/or description changed
+    ThreadUpdated {
+        thread_id: String,
+        title: Option<String>,
+        description: Option<String>,
+    },
+    /// A token of the response streaming on a thread
+    StreamToken { thread_id: String, token: String },
+    /// The response on a thread finished streaming
+    StreamComplete { thread_id: String, message_id: i64 },
+    /// The response on a thread failed
+    StreamError { thread_id: String, error: String },
+    /// The backend connection went up (true) or down (false)
+    ConnectionChanged(bool),
+    /// The host viewport changed size
+    Resize { width: u16, height: u16 },
+    /// Host focus changed (gates notifications)
+    FocusChanged(bool),
+    /// Animation tick (spinners, cursor blink, smooth scroll)
+    Tick,
+}
+
+/// Builder for [`EmbeddedApp`].
+#[derive(Default)]
+pub struct EmbeddedAppBuilder {
+    base_url: Option<String>,
+    auth_token: Option<String>,
+    http: Option<Arc<dyn HttpClient>>,
+    viewport: Option<(u16, u16)>,
+}
+
+impl EmbeddedAppBuilder {
+    /// Set the backend base URL
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Set the Bearer token used for backend requests
+    pub fn auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+
+    /// Inject the HTTP client used for backend requests
+    pub fn http_client(mut self, http: Arc<dyn HttpClient>) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Set the initial viewport size in columns and rows
+    pub fn viewport(mut self, width: u16, height: u16) -> Self {
+        self.viewport = Some((width, height));
+        self
+    }
+
+    /// Build the embedded app
+    pub fn build(self) -> Result<EmbeddedApp, EmbedError> {
+        let mut config = match self.base_url {
+            Some(url) => ConductorConfig::with_base_url(url),
+            None => ConductorConfig::default(),
+        };
+        if let Some(token) = self.auth_token.as_deref() {
+            config = config.with_auth(token);
+        }
+
+        let client = match self.http {
+            Some(http) => ConductorClient::with_http(http, config),
+            None => ConductorClient::with_default_http(config),
+        };
+
+        let mut app = App::with_client(Arc::new(client))
+            .map_err(|e| EmbedError::InitFailed(e.to_string()))?;
+        if let Some((width, height)) = self.viewport {
+            app.update_terminal_dimensions(width, height);
+        }
+
+        let message_rx = app
+            .message_rx
+            .take()
+            .ok_or_else(|| EmbedError::InitFailed("message channel unavailable".to_string()))?;
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        Ok(EmbeddedApp {
+            app,
+            message_rx,
+            event_tx,
+            event_rx,
+        })
+    }
+}
+
+/// An embeddable spoq application.
+///
+/// Owns the application state and its internal message channel. The host is
+/// responsible for the event loop: feed events with [`handle_event`], drain
+/// async results with [`process_pending`], and call [`render`] when
+/// [`needs_redraw`] is true.
+///
+/// [`handle_event`]: EmbeddedApp::handle_event
+/// [`process_pending`]: EmbeddedApp::process_pending
+/// [`render`]: EmbeddedApp::render
+/// [`needs_redraw`]: EmbeddedApp::needs_redraw
+pub struct EmbeddedApp {
+    app: App,
+    message_rx: mpsc::UnboundedReceiver<AppMessage>,
+    event_tx: mpsc::UnboundedSender<EmbedEvent>,
+    event_rx: mpsc::UnboundedReceiver<EmbedEvent>,
+}
+
+impl EmbeddedApp {
+    /// Start building an embedded app
+    pub fn builder() -> EmbeddedAppBuilder {
+        EmbeddedAppBuilder::default()
+    }
+
+    /// Feed a single event into the app
+    pub fn handle_event(&mut self, event: EmbedEvent) {
+        match event {
+            EmbedEvent::ThreadUpdated {
+                thread_id,
+                title,
+                description,
+            } => self.app.handle_message(AppMessage::ThreadMetadataUpdated {
+                thread_id,
+                title,
+                description,
+            }),
+            EmbedEvent::StreamToken { thread_id, token } => self
+                .app
+                .handle_message(AppMessage::StreamToken { thread_id, token }),
+            EmbedEvent::StreamComplete {
+                thread_id,
+                message_id,
+            } => self.app.handle_message(AppMessage::StreamComplete {
+                thread_id,
+                message_id,
+            }),
+            EmbedEvent::StreamError { thread_id, error } => {
+                self.app.handle_message(AppMessage::StreamError {
+                    thread_id,
+                    error,
+                    error_code: None,
+                })
+            }
+            EmbedEvent::ConnectionChanged(connected) => self
+                .app
+                .handle_message(AppMessage::ConnectionStatus(connected)),
+            EmbedEvent::Resize { width, height } => {
+                self.app.update_terminal_dimensions(width, height);
+            }
+            EmbedEvent::FocusChanged(focused) => self.app.set_terminal_focus(focused),
+            EmbedEvent::Tick => self.app.tick(),
+        }
+    }
+
+    /// Apply all messages produced by the app's own async tasks, and the
+    /// events sent through [`sender`](EmbeddedApp::sender).
+    ///
+    /// Returns the number processed.
+    pub fn process_pending(&mut self) -> usize {
+        let mut processed = 0;
+        while let Ok(msg) = self.message_rx.try_recv() {
+            self.app.handle_message(msg);
+            processed += 1;
+        }
+        while let Ok(event) = self.event_rx.try_recv() {
+            self.handle_event(event);
+            processed += 1;
+        }
+        processed
+    }
+
+    /// Get a sender for feeding events from host-side async tasks
+    pub fn sender(&self) -> mpsc::UnboundedSender<EmbedEvent> {
+        self.event_tx.clone()
+    }
+
+    /// Call `callback` for every high-level [`AppEvent`]
+    pub fn subscribe(&mut self, callback: impl FnMut(&AppEvent) + Send + 'static) -> ObserverId {
+        self.app.observers.subscribe(callback)
+    }
+
+    /// Receive every [`AppEvent`] on a channel; dropping the receiver unsubscribes
+    pub fn subscribe_channel(&mut self) -> mpsc::UnboundedReceiver<AppEvent> {
+        self.app.observers.subscribe_channel()
+    }
+
+    /// Remove a callback. Returns false if it wasn't registered.
+    pub fn unsubscribe(&mut self, id: ObserverId) -> bool {
+        self.app.observers.unsubscribe(id)
+    }
+
+    /// Add or update a thread in the local cache
+    pub fn upsert_thread(&mut self, thread: Thread) {
+        self.app.cache.upsert_thread(thread);
+        self.app.mark_dirty();
+    }
+
+    /// Replace the cached messages for a thread
+    pub fn set_messages(&mut self, thread_id: impl Into<String>, messages: Vec<Message>) {
+        self.app.cache.set_messages(thread_id.into(), messages);
+        self.app.mark_dirty();
+    }
+
+    /// Show a thread in the conversation view.
+    ///
+    /// If the thread's messages are not cached, they are fetched in the
+    /// background; this requires a running Tokio runtime.
+    pub fn open_thread(&mut self, thread_id: impl Into<String>) {
+        self.app.open_thread(thread_id.into());
+    }
+
+    /// Return to the thread overview
+    pub fn show_overview(&mut self) {
+        self.app.navigate_to_command_deck();
+    }
+
+    /// ID of the thread currently shown, if any
+    pub fn active_thread_id(&self) -> Option<&str> {
+        if self.app.screen == Screen::Conversation {
+            self.app.active_thread_id.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Cached threads, most recent first
+    pub fn threads(&self) -> Vec<&Thread> {
+        self.app.cache.threads()
+    }
+
+    /// Cached messages for a thread
+    pub fn messages(&self, thread_id: &str) -> &[Message] {
+        self.app
+            .cache
+            .get_messages(thread_id)
+            .map(|m| m.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Whether any state changed since the last [`render`](EmbeddedApp::render)
+    pub fn needs_redraw(&self) -> bool {
+        self.app.needs_redraw || self.app.is_streaming()
+    }
+
+    /// Render the app into a frame and clear the redraw flag
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        self.app.update_terminal_dimensions(area.width, area.height);
+        crate::ui::render(frame, &mut self.app);
+        self.app.needs_redraw = false;
+    }
+}
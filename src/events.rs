@@ -3,33 +3,33 @@
 //! This module defines all the event types that can be received from the Conductor
 //! backend via Server-Sent Events (SSE) during streaming conversations.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Metadata included with every SSE event.
 ///
 /// Contains sequencing and identification information for event ordering
 /// and session/thread association. Backend sends these fields flattened
 /// at root level of each event JSON.
-#[derive(Debug, Clone, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct EventMeta {
     /// Sequence number for ordering events within a stream
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub seq: Option<u64>,
     /// Session ID for the current streaming session
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
     /// Thread ID this event belongs to
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thread_id: Option<String>,
     /// Unix timestamp in milliseconds of when the event was generated
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<u64>,
 }
 
 /// Content streaming event containing assistant text output.
 ///
 /// Received incrementally as the assistant generates response text.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ContentEvent {
     /// A chunk of text content from the assistant's response
     pub text: String,
@@ -41,7 +41,7 @@ pub struct ContentEvent {
 /// Reasoning/thinking event containing assistant's internal reasoning.
 ///
 /// Shows the assistant's chain-of-thought process when enabled.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ReasoningEvent {
     /// A chunk of reasoning text from the assistant
     pub text: String,
@@ -50,7 +50,7 @@ pub struct ReasoningEvent {
 /// Event indicating a tool call has started.
 ///
 /// Sent when the assistant begins invoking a tool.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ToolCallStartEvent {
     /// Name of the tool being called
     pub tool_name: String,
@@ -61,7 +61,7 @@ pub struct ToolCallStartEvent {
 /// Event containing incremental tool call arguments.
 ///
 /// Sent as the tool's input arguments are being streamed.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ToolCallArgumentEvent {
     /// The tool call this argument chunk belongs to
     pub tool_call_id: String,
@@ -73,7 +73,7 @@ pub struct ToolCallArgumentEvent {
 /// Event indicating tool execution has begun.
 ///
 /// Sent after arguments are complete and the tool is being executed.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ToolExecutingEvent {
     /// The tool call that is now executing
     pub tool_call_id: String,
@@ -88,7 +88,7 @@ pub struct ToolExecutingEvent {
 /// Event containing the result of a tool execution.
 ///
 /// Sent after a tool has completed execution.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ToolResultEvent {
     /// The tool call this result belongs to
     pub tool_call_id: String,
@@ -99,7 +99,7 @@ pub struct ToolResultEvent {
 /// Event containing the result of a server-side tool execution (web search, code execution).
 ///
 /// These tools are executed server-side by the LLM provider, not by Conductor.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ServerToolResultEvent {
     /// The tool call this result belongs to
     pub tool_call_id: String,
@@ -112,7 +112,7 @@ pub struct ServerToolResultEvent {
 /// Event indicating the streaming response is complete.
 ///
 /// Sent when the assistant has finished generating the response.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DoneEvent {
     /// The ID of the completed message
     pub message_id: String,
@@ -121,7 +121,7 @@ pub struct DoneEvent {
 /// Event indicating an error occurred during streaming.
 ///
 /// May be sent at any point if an error occurs.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ErrorEvent {
     /// Human-readable error message
     pub message: String,
@@ -132,7 +132,7 @@ pub struct ErrorEvent {
 /// Event confirming a user message has been saved.
 ///
 /// Sent after the user's input message has been persisted.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UserMessageSavedEvent {
     /// The ID assigned to the saved user message
     pub message_id: String,
@@ -141,7 +141,7 @@ pub struct UserMessageSavedEvent {
 }
 
 /// A single todo item in the assistant's task list.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TodoItem {
     /// The todo item content/description
     pub content: String,
@@ -155,7 +155,7 @@ pub struct TodoItem {
 /// Event indicating the todo list has been updated.
 ///
 /// Sent when the assistant modifies its internal task tracking.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TodosUpdatedEvent {
     /// The current list of todos
     pub todos: Vec<TodoItem>,
@@ -164,7 +164,7 @@ pub struct TodosUpdatedEvent {
 /// Event indicating a subagent has been started.
 ///
 /// Sent when the assistant spawns a new subagent to handle a task.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SubagentStartedEvent {
     /// Unique identifier for this subagent task
     pub task_id: String,
@@ -177,7 +177,7 @@ pub struct SubagentStartedEvent {
 /// Event containing progress updates from a subagent.
 ///
 /// Sent when a running subagent reports progress on its task.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SubagentProgressEvent {
     /// The task ID this progress update belongs to
     pub task_id: String,
@@ -188,7 +188,7 @@ pub struct SubagentProgressEvent {
 /// Event indicating a subagent has completed its task.
 ///
 /// Sent when a subagent finishes execution and returns results.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SubagentCompletedEvent {
     /// The task ID that has completed
     pub task_id: String,
@@ -202,7 +202,7 @@ pub struct SubagentCompletedEvent {
 /// Event requesting user permission for an action.
 ///
 /// Sent when the assistant needs explicit user approval to proceed.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PermissionRequestEvent {
     /// Unique identifier for this permission request
     pub permission_id: String,
@@ -222,7 +222,7 @@ pub struct PermissionRequestEvent {
 /// Event indicating context has been compacted.
 ///
 /// Sent when the conversation context is compacted to free up space.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ContextCompactedEvent {
     /// Number of messages removed during compaction
     pub messages_removed: u32,
@@ -237,14 +237,14 @@ pub struct ContextCompactedEvent {
 }
 
 /// Skills injected event - sent when skills are loaded in the session
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SkillsInjectedEvent {
     /// List of skill names that were injected
     pub skills: Vec<String>,
 }
 
 /// OAuth consent required event
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct OAuthConsentRequiredEvent {
     /// OAuth provider name
     pub provider: String,
@@ -257,7 +257,7 @@ pub struct OAuthConsentRequiredEvent {
 }
 
 /// Thread updated event - sent when thread metadata is changed
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ThreadUpdatedEvent {
     /// The ID of the thread that was updated
     pub thread_id: String,
@@ -270,16 +270,22 @@ pub struct ThreadUpdatedEvent {
 }
 
 /// Usage event - sent after done to provide context window usage info
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UsageEvent {
     /// Current context window tokens used
     pub context_window_used: u32,
     /// Maximum context window limit
     pub context_window_limit: u32,
+    /// Prompt tokens of the turn, when the backend reports them
+    #[serde(default)]
+    pub input_tokens: Option<u64>,
+    /// Completion tokens of the turn, when the backend reports them
+    #[serde(default)]
+    pub output_tokens: Option<u64>,
 }
 
 /// SystemInit event - sent when Claude CLI starts with session info
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SystemInitEvent {
     /// Claude CLI session ID (distinct from EventMeta's session_id)
     pub cli_session_id: String,
@@ -292,7 +298,7 @@ pub struct SystemInitEvent {
 }
 
 /// Cancelled event - sent when a stream is cancelled by user request
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CancelledEvent {
     /// Reason for cancellation (e.g., "user_requested")
     pub reason: String,
@@ -301,7 +307,7 @@ pub struct CancelledEvent {
 /// Rate limit hit event.
 ///
 /// Sent when the current account hits rate limit and next account is available.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RateLimitedEvent {
     /// Error message from the rate limit
     pub message: String,
@@ -334,7 +340,7 @@ pub struct RateLimitedEvent {
 ///     _ => {}
 /// }
 /// ```
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SseEvent {
     /// Assistant text content chunk
@@ -385,11 +391,42 @@ pub enum SseEvent {
     RateLimited(RateLimitedEvent),
 }
 
+impl SseEvent {
+    /// Returns the event type name as sent on the wire (`content`, `tool_result`, ...).
+    pub fn event_type_name(&self) -> &'static str {
+        match self {
+            SseEvent::Content(_) => "content",
+            SseEvent::Reasoning(_) => "reasoning",
+            SseEvent::ToolCallStart(_) => "tool_call_start",
+            SseEvent::ToolCallArgument(_) => "tool_call_argument",
+            SseEvent::ToolExecuting(_) => "tool_executing",
+            SseEvent::ToolResult(_) => "tool_result",
+            SseEvent::ServerToolResult(_) => "server_tool_result",
+            SseEvent::Done(_) => "done",
+            SseEvent::Error(_) => "error",
+            SseEvent::UserMessageSaved(_) => "user_message_saved",
+            SseEvent::TodosUpdated(_) => "todos_updated",
+            SseEvent::SubagentStarted(_) => "subagent_started",
+            SseEvent::SubagentProgress(_) => "subagent_progress",
+            SseEvent::SubagentCompleted(_) => "subagent_completed",
+            SseEvent::PermissionRequest(_) => "permission_request",
+            SseEvent::ContextCompacted(_) => "context_compacted",
+            SseEvent::SkillsInjected(_) => "skills_injected",
+            SseEvent::OAuthConsentRequired(_) => "oauth_consent_required",
+            SseEvent::ThreadUpdated(_) => "thread_updated",
+            SseEvent::Usage(_) => "usage",
+            SseEvent::SystemInit(_) => "system_init",
+            SseEvent::Cancelled(_) => "cancelled",
+            SseEvent::RateLimited(_) => "rate_limited",
+        }
+    }
+}
+
 /// Wraps an SSE event with its metadata.
 ///
 /// This is the top-level structure received from the SSE stream,
 /// containing both the event-specific data and common metadata.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SseEventWithMeta {
     /// The event payload
     #[serde(flatten)]
@@ -73,6 +73,10 @@ pub enum Command {
     DeleteWordBackward,
     /// Delete to line start (Cmd+Backspace, Ctrl+U)
     DeleteToLineStart,
+    /// Undo the last edit (Ctrl+Z)
+    Undo,
+    /// Redo the last undone edit (Ctrl+Y, Ctrl+Shift+Z)
+    Redo,
     /// Move cursor left
     MoveCursorLeft,
     /// Move cursor right
@@ -169,6 +173,10 @@ pub enum Command {
     CloseSwitcher,
     /// Confirm thread switcher selection (Enter or any other key)
     ConfirmSwitcherSelection,
+    /// Type a character into the thread switcher filter
+    SwitcherTypeChar(char),
+    /// Delete the last character of the thread switcher filter
+    SwitcherBackspace,
 
     // =========================================================================
     // Permission Commands
@@ -323,6 +331,8 @@ pub enum Command {
     // =========================================================================
     /// Dismiss focused error (d key)
     DismissError,
+    /// Restore the last dismissed error (u key)
+    UndoDismissError,
     /// Toggle reasoning/thinking block (t key)
     ToggleReasoning,
     /// Open OAuth URL in browser (o key)
@@ -374,6 +384,8 @@ impl Command {
             Command::DeleteChar => "Delete character",
             Command::DeleteWordBackward => "Delete word backward",
             Command::DeleteToLineStart => "Delete to line start",
+            Command::Undo => "Undo",
+            Command::Redo => "Redo",
             Command::MoveCursorLeft => "Move cursor left",
             Command::MoveCursorRight => "Move cursor right",
             Command::MoveCursorUp => "Move cursor up",
@@ -414,6 +426,8 @@ impl Command {
             Command::CycleSwitcherBackward => "Previous thread in switcher",
             Command::CloseSwitcher => "Close thread switcher",
             Command::ConfirmSwitcherSelection => "Confirm thread selection",
+            Command::SwitcherTypeChar(_) => "Filter threads in switcher",
+            Command::SwitcherBackspace => "Delete filter character in switcher",
             Command::ApprovePermission => "Approve permission",
             Command::DenyPermission => "Deny permission",
             Command::AlwaysAllowPermission => "Always allow permission",
@@ -473,6 +487,7 @@ impl Command {
             Command::ClaudeAccountsPasteSubmit => "Submit pasted token",
             Command::ClaudeAccountsPasteCancel => "Cancel paste token mode",
             Command::DismissError => "Dismiss error",
+            Command::UndoDismissError => "Undo dismiss error",
             Command::ToggleReasoning => "Toggle reasoning view",
             Command::OpenOAuthUrl => "Open OAuth URL",
             Command::Resize { .. } => "Terminal resize",
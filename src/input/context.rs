@@ -63,9 +63,9 @@ pub struct InputContext {
     pub modal: ModalType,
     /// Whether input is empty (affects Escape behavior)
     pub input_is_empty: bool,
-    /// Whether cursor is on first line (affects Up arrow behavior)
+    /// Whether cursor is on the first (soft-wrapped) row (affects Up arrow behavior)
     pub cursor_on_first_line: bool,
-    /// Whether cursor is on last line (affects Down arrow behavior)
+    /// Whether cursor is on the last (soft-wrapped) row (affects Down arrow behavior)
     pub cursor_on_last_line: bool,
     /// Whether history navigation is active
     pub is_navigating_history: bool,
@@ -78,9 +78,9 @@ pub fn handle_editing_command(app: &mut App, cmd: &Command) -> bool {
 
         Command::Backspace => {
             app.reset_cursor_blink();
-            // Check if we should clear the folder chip instead of backspace
-            if app.should_clear_folder_on_backspace() {
-                app.clear_folder();
+            // Check if we should remove a folder chip instead of backspace
+            if let Some(index) = app.folder_chip_at_cursor() {
+                app.remove_folder(index);
             } else {
                 app.textarea.backspace();
             }
@@ -105,6 +105,18 @@ pub fn handle_editing_command(app: &mut App, cmd: &Command) -> bool {
             true
         }
 
+        Command::Undo => {
+            app.reset_cursor_blink();
+            app.textarea.undo();
+            true
+        }
+
+        Command::Redo => {
+            app.reset_cursor_blink();
+            app.textarea.redo();
+            true
+        }
+
         Command::MoveCursorLeft => {
             app.reset_cursor_blink();
             app.textarea.move_cursor_left();
@@ -185,6 +197,39 @@ pub fn handle_editing_command(app: &mut App, cmd: &Command) -> bool {
             // Check if input is a slash command
             if trimmed.starts_with('/') {
                 tracing::info!("Detected slash prefix, parsing: '{}'", trimmed);
+                match SlashCommand::parse_with_argument(trimmed) {
+                    Some((SlashCommand::Rename, title)) => {
+                        app.rename_active_thread(title);
+                        app.textarea.clear();
+                        return true;
+                    }
+                    Some((SlashCommand::Editor, mode)) => {
+                        app.set_editor_handoff_command(mode);
+                        app.textarea.clear();
+                        return true;
+                    }
+                    Some((SlashCommand::Notify, setting)) => {
+                        app.notify_command(setting);
+                        app.textarea.clear();
+                        return true;
+                    }
+                    Some((SlashCommand::Log, level)) => {
+                        app.log_level_command(level);
+                        app.textarea.clear();
+                        return true;
+                    }
+                    Some((SlashCommand::Debug, option)) => {
+                        app.debug_command(option);
+                        app.textarea.clear();
+                        return true;
+                    }
+                    Some((SlashCommand::Cd, path)) => {
+                        app.change_directory_command(path);
+                        app.textarea.clear();
+                        return true;
+                    }
+                    _ => {}
+                }
                 if let Some(slash_cmd) = SlashCommand::parse(trimmed) {
                     tracing::info!("Parsed slash command: {:?}", slash_cmd);
                     // Execute the slash command instead of submitting as message
@@ -221,9 +266,7 @@ pub fn handle_editing_command(app: &mut App, cmd: &Command) -> bool {
             if app.should_summarize_paste(text) {
                 app.textarea.insert_paste_token(text.clone());
             } else {
-                for ch in text.chars() {
-                    app.textarea.insert_char(ch);
-                }
+                app.textarea.insert_text(text);
             }
             app.mark_dirty();
             true
@@ -297,6 +340,20 @@ mod tests {
         assert!(!app.textarea.content().contains("world"));
     }
 
+    #[test]
+    fn test_handle_undo_redo() {
+        let mut app = create_test_app();
+        app.focus = Focus::Input;
+        for c in "hello".chars() {
+            handle_editing_command(&mut app, &Command::InsertChar(c));
+        }
+
+        assert!(handle_editing_command(&mut app, &Command::Undo));
+        assert!(app.textarea.is_empty());
+        assert!(handle_editing_command(&mut app, &Command::Redo));
+        assert_eq!(app.textarea.content(), "hello");
+    }
+
     #[test]
     fn test_handle_move_cursor() {
         let mut app = create_test_app();
@@ -345,6 +402,25 @@ mod tests {
     // Note: Cursor is now always visible (solid caret mode).
     // These tests verify cursor stays visible through various editing operations.
 
+    #[tokio::test]
+    async fn test_submit_rename_command_renames_active_thread() {
+        let mut app = create_test_app();
+        app.cache = crate::cache::ThreadCache::with_stub_data();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some("thread-001".to_string());
+        app.textarea.set_content("/rename Fix the login flow");
+
+        let submit = Command::SubmitInput(ThreadType::Conversation);
+        let handled = handle_editing_command(&mut app, &submit);
+
+        assert!(handled);
+        assert!(app.textarea.is_empty());
+        assert_eq!(
+            app.cache.get_thread("thread-001").unwrap().title,
+            "Fix the login flow"
+        );
+    }
+
     #[test]
     fn test_insert_char_keeps_cursor_visible() {
         let mut app = create_test_app();
@@ -494,6 +494,16 @@ pub fn handle_thread_switcher_command(app: &mut App, cmd: &Command) -> bool {
             true
         }
 
+        Command::SwitcherTypeChar(c) => {
+            app.switcher_type_char(*c);
+            true
+        }
+
+        Command::SwitcherBackspace => {
+            app.switcher_backspace();
+            true
+        }
+
         _ => false,
     }
 }
@@ -512,6 +522,8 @@ pub fn handle_misc_command(app: &mut App, cmd: &Command) -> bool {
             }
         }
 
+        Command::UndoDismissError => app.undo_dismiss_error(),
+
         Command::ToggleReasoning => {
             app.toggle_reasoning();
             true
@@ -531,7 +543,12 @@ pub fn handle_misc_command(app: &mut App, cmd: &Command) -> bool {
             true
         }
 
-        Command::Quit | Command::ForceQuit => {
+        Command::Quit => {
+            app.request_quit();
+            true
+        }
+
+        Command::ForceQuit => {
             app.quit();
             true
         }
@@ -1264,6 +1281,23 @@ mod tests {
         assert!(handled);
     }
 
+    #[test]
+    fn test_handle_undo_dismiss_error_restores_error() {
+        let mut app = create_test_app();
+        app.active_thread_id = Some("test-thread".to_string());
+        app.cache.add_error_simple(
+            "test-thread",
+            "test_error".to_string(),
+            "Test message".to_string(),
+        );
+
+        assert!(handle_misc_command(&mut app, &Command::DismissError));
+        assert!(!app.has_errors());
+        assert!(handle_misc_command(&mut app, &Command::UndoDismissError));
+        assert!(app.has_errors());
+        assert!(!handle_misc_command(&mut app, &Command::UndoDismissError));
+    }
+
     #[test]
     fn test_handle_dismiss_error_without_errors() {
         let mut app = create_test_app();
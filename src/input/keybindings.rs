@@ -315,6 +315,10 @@ impl KeybindingConfig {
         );
         threads.insert(KeyCombo::plain(KeyCode::Char('q')), Command::Quit);
         threads.insert(KeyCombo::plain(KeyCode::Char('d')), Command::DismissError);
+        threads.insert(
+            KeyCombo::plain(KeyCode::Char('u')),
+            Command::UndoDismissError,
+        );
         threads.insert(
             KeyCombo::plain(KeyCode::Char('t')),
             Command::ToggleReasoning,
@@ -373,6 +377,19 @@ impl KeybindingConfig {
             Command::DeleteToLineStart,
         );
 
+        // Undo/redo
+        self.input_editing
+            .insert(KeyCombo::ctrl(KeyCode::Char('z')), Command::Undo);
+        self.input_editing
+            .insert(KeyCombo::ctrl(KeyCode::Char('y')), Command::Redo);
+        self.input_editing.insert(
+            KeyCombo::new(
+                KeyCode::Char('Z'),
+                KeyModifiers::CONTROL.union(KeyModifiers::SHIFT),
+            ),
+            Command::Redo,
+        );
+
         // Newline insertion
         self.input_editing
             .insert(KeyCombo::shift(KeyCode::Enter), Command::InsertNewline);
@@ -0,0 +1,552 @@
+//! User-configurable key chords for the main event loop.
+//!
+//! Maps logical [`Action`]s (new thread, back to the command deck, ...) to
+//! key chords. [`Keymap::default`] holds the built-in bindings; a keymap file
+//! (see `startup::keymap`) replaces the chords of any action it lists:
+//!
+//! ```toml
+//! # ~/.config/spoq/keymap.toml
+//! BackToCommandDeck = ["shift+esc", "ctrl+b"]
+//! NewThread = "alt+n"
+//! Quit = []            # unbind
+//! ```
+//!
+//! Chords are `+`-separated modifiers (`ctrl`, `alt`, `shift`, `super`)
+//! followed by a key: a single character or one of `esc`, `enter`, `tab`,
+//! `backspace`, `delete`, `up`, `down`, `left`, `right`, `home`, `end`,
+//! `pageup`, `pagedown`, `space`, `f1`-`f12`.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::keybindings::KeyCombo;
+
+/// A rebindable action handled by the main event loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Cancel the stream, clear the input, or (pressed twice) exit
+    Interrupt,
+    /// Leave the conversation for the command deck
+    BackToCommandDeck,
+    /// Start a new thread
+    NewThread,
+    /// Submit the input as a programming thread (command deck)
+    SubmitAsProgramming,
+    /// Cycle the permission mode
+    CyclePermissionMode,
+    /// Show or hide the reasoning block (conversation)
+    ToggleReasoning,
+    /// Show or hide the output of the tool events (conversation)
+    ToggleToolOutput,
+    /// Expand or collapse the finished subagent tree (conversation)
+    ToggleSubagents,
+    /// Copy the full output of the tool events (conversation)
+    CopyToolOutput,
+    /// Expand or collapse the turn's TODO/FIXME follow-ups (conversation)
+    ToggleFollowUps,
+    /// Show or hide message timestamps (conversation)
+    ToggleTimestamps,
+    /// Cycle how much of the conversation is shown (conversation)
+    CycleZoom,
+    /// Dismiss the focused error (conversation)
+    DismissError,
+    /// Restore the most recently dismissed error (conversation)
+    UndoDismissError,
+    /// Open the first pending question (command deck)
+    OpenQuestion,
+    /// Show or hide the needs-action sidebar (conversation)
+    ToggleSidebar,
+    /// Number the links in view to open one by typing it (conversation)
+    LinkHints,
+    /// Pick a new working directory for the thread (conversation)
+    ChangeDirectory,
+    /// Discard the newest attached image
+    DiscardImage,
+    /// Search the input history (input focused)
+    SearchHistory,
+    /// Queue the input behind the streaming response instead of steering it
+    QueuePrompt,
+    /// Reconnect right away while the connection is down
+    ReconnectNow,
+    /// Exit immediately
+    Quit,
+}
+
+impl Action {
+    /// Every action, in the order `/keys` lists them
+    pub const ALL: [Action; 23] = [
+        Action::Interrupt,
+        Action::BackToCommandDeck,
+        Action::NewThread,
+        Action::SubmitAsProgramming,
+        Action::CyclePermissionMode,
+        Action::ToggleReasoning,
+        Action::ToggleToolOutput,
+        Action::ToggleSubagents,
+        Action::CopyToolOutput,
+        Action::ToggleFollowUps,
+        Action::ToggleTimestamps,
+        Action::CycleZoom,
+        Action::DismissError,
+        Action::UndoDismissError,
+        Action::OpenQuestion,
+        Action::ToggleSidebar,
+        Action::LinkHints,
+        Action::ChangeDirectory,
+        Action::DiscardImage,
+        Action::SearchHistory,
+        Action::QueuePrompt,
+        Action::ReconnectNow,
+        Action::Quit,
+    ];
+
+    /// Name used in the keymap file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Interrupt => "Interrupt",
+            Action::BackToCommandDeck => "BackToCommandDeck",
+            Action::NewThread => "NewThread",
+            Action::SubmitAsProgramming => "SubmitAsProgramming",
+            Action::CyclePermissionMode => "CyclePermissionMode",
+            Action::ToggleReasoning => "ToggleReasoning",
+            Action::ToggleToolOutput => "ToggleToolOutput",
+            Action::ToggleSubagents => "ToggleSubagents",
+            Action::CopyToolOutput => "CopyToolOutput",
+            Action::ToggleFollowUps => "ToggleFollowUps",
+            Action::ToggleTimestamps => "ToggleTimestamps",
+            Action::CycleZoom => "CycleZoom",
+            Action::DismissError => "DismissError",
+            Action::UndoDismissError => "UndoDismissError",
+            Action::OpenQuestion => "OpenQuestion",
+            Action::ToggleSidebar => "ToggleSidebar",
+            Action::LinkHints => "LinkHints",
+            Action::ChangeDirectory => "ChangeDirectory",
+            Action::DiscardImage => "DiscardImage",
+            Action::SearchHistory => "SearchHistory",
+            Action::QueuePrompt => "QueuePrompt",
+            Action::ReconnectNow => "ReconnectNow",
+            Action::Quit => "Quit",
+        }
+    }
+
+    /// Look up an action by name (case, `_` and `-` are ignored)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized: String = name
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .collect::<String>()
+            .to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|action| action.name().to_lowercase() == normalized)
+    }
+
+    /// Built-in chords
+    fn default_chords(&self) -> Vec<KeyCombo> {
+        match self {
+            Action::Interrupt => vec![KeyCombo::ctrl(KeyCode::Char('c'))],
+            Action::BackToCommandDeck => vec![
+                KeyCombo::shift(KeyCode::Esc),
+                KeyCombo::ctrl(KeyCode::Char('w')),
+            ],
+            Action::NewThread => vec![
+                KeyCombo::shift(KeyCode::Char('N')),
+                KeyCombo::ctrl(KeyCode::Char('n')),
+            ],
+            Action::SubmitAsProgramming => vec![KeyCombo::alt(KeyCode::Char('p'))],
+            Action::CyclePermissionMode => vec![KeyCombo::plain(KeyCode::BackTab)],
+            Action::ToggleReasoning => vec![KeyCombo::plain(KeyCode::Char('t'))],
+            Action::ToggleToolOutput => vec![KeyCombo::plain(KeyCode::Char('o'))],
+            Action::ToggleSubagents => vec![KeyCombo::plain(KeyCode::Char('s'))],
+            Action::CopyToolOutput => vec![KeyCombo::shift(KeyCode::Char('O'))],
+            Action::ToggleFollowUps => vec![KeyCombo::plain(KeyCode::Char('f'))],
+            Action::ToggleTimestamps => vec![KeyCombo::shift(KeyCode::Char('T'))],
+            Action::CycleZoom => vec![KeyCombo::plain(KeyCode::Char('z'))],
+            Action::DismissError => vec![KeyCombo::plain(KeyCode::Char('d'))],
+            Action::UndoDismissError => vec![KeyCombo::plain(KeyCode::Char('u'))],
+            Action::OpenQuestion => vec![
+                KeyCombo::plain(KeyCode::Char('a')),
+                KeyCombo::shift(KeyCode::Char('A')),
+            ],
+            Action::ToggleSidebar => vec![KeyCombo::ctrl(KeyCode::Char('b'))],
+            Action::LinkHints => vec![KeyCombo::ctrl(KeyCode::Char('o'))],
+            Action::ChangeDirectory => vec![KeyCombo::ctrl(KeyCode::Char('g'))],
+            Action::DiscardImage => vec![KeyCombo::ctrl(KeyCode::Char('x'))],
+            Action::SearchHistory => vec![KeyCombo::ctrl(KeyCode::Char('r'))],
+            Action::QueuePrompt => vec![KeyCombo::alt(KeyCode::Char('q'))],
+            Action::ReconnectNow => vec![KeyCombo::shift(KeyCode::Char('R'))],
+            Action::Quit => vec![KeyCombo::plain(KeyCode::Char('q'))],
+        }
+    }
+}
+
+/// A problem in the keymap file, reported at startup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapWarning {
+    /// 1-based line number (0 when the whole file is affected)
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for KeymapWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}", self.message);
+        }
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Effective action bindings
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyCombo, Action>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = Action::ALL
+            .into_iter()
+            .flat_map(|action| {
+                action
+                    .default_chords()
+                    .into_iter()
+                    .map(move |chord| (chord, action))
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    /// Build a keymap from keymap file contents.
+    ///
+    /// Lines that can't be used are skipped and reported; the rest apply on
+    /// top of the defaults.
+    pub fn parse(contents: &str) -> (Self, Vec<KeymapWarning>) {
+        let mut keymap = Self::default();
+        let mut warnings = Vec::new();
+        // chord -> (action, line) for chords bound by the file
+        let mut bound_by_file: HashMap<KeyCombo, (Action, usize)> = HashMap::new();
+
+        for (index, raw_line) in contents.lines().enumerate() {
+            let line = index + 1;
+            let text = strip_comment(raw_line).trim();
+            if text.is_empty() {
+                continue;
+            }
+            let mut warn = |message: String| warnings.push(KeymapWarning { line, message });
+
+            let Some((name, value)) = text.split_once('=') else {
+                warn(format!("expected `Action = \"chord\"`, got `{}`", text));
+                continue;
+            };
+            let name = name.trim().trim_matches('"');
+            let Some(action) = Action::from_name(name) else {
+                warn(format!("unknown action `{}`", name));
+                continue;
+            };
+            let chords = match parse_chord_list(value.trim()) {
+                Ok(chords) => chords,
+                Err(message) => {
+                    warn(message);
+                    continue;
+                }
+            };
+
+            // The file replaces the action's defaults (or an earlier line)
+            keymap.bindings.retain(|_, bound| *bound != action);
+            bound_by_file.retain(|_, (bound, _)| *bound != action);
+            for chord in chords {
+                if let Some((other, other_line)) = bound_by_file.get(&chord) {
+                    if *other != action {
+                        warn(format!(
+                            "{} is already bound to {} on line {}",
+                            format_chord(&chord),
+                            other.name(),
+                            other_line
+                        ));
+                    }
+                    continue;
+                }
+                bound_by_file.insert(chord, (action, line));
+                keymap.bindings.insert(chord, action);
+            }
+        }
+
+        (keymap, warnings)
+    }
+
+    /// The action bound to a key event, if any
+    pub fn action_for(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .get(&normalize(KeyCombo::new(key.code, key.modifiers)))
+            .copied()
+    }
+
+    /// Chords bound to an action, formatted and sorted
+    pub fn chords_for(&self, action: Action) -> Vec<String> {
+        let mut chords: Vec<String> = self
+            .bindings
+            .iter()
+            .filter(|(_, bound)| **bound == action)
+            .map(|(chord, _)| format_chord(chord))
+            .collect();
+        chords.sort();
+        chords
+    }
+}
+
+/// Drop a trailing `#` comment (outside quotes)
+fn strip_comment(line: &str) -> &str {
+    let mut in_quotes = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '#' if !in_quotes => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parse `"chord"` or `["chord", ...]`
+fn parse_chord_list(value: &str) -> Result<Vec<KeyCombo>, String> {
+    let items: Vec<&str> = match value.strip_prefix('[') {
+        Some(rest) => {
+            let inner = rest
+                .strip_suffix(']')
+                .ok_or_else(|| format!("unterminated list `{}`", value))?;
+            inner
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .collect()
+        }
+        None => vec![value],
+    };
+
+    items
+        .into_iter()
+        .map(|item| {
+            let chord = item
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .ok_or_else(|| format!("chord must be a quoted string, got `{}`", item))?;
+            parse_chord(chord).ok_or_else(|| format!("invalid key chord `{}`", chord))
+        })
+        .collect()
+}
+
+/// Parse a chord like `ctrl+shift+n`
+pub fn parse_chord(chord: &str) -> Option<KeyCombo> {
+    let parts: Vec<&str> = chord.split('+').map(str::trim).collect();
+    // "+" and "ctrl++" bind the plus key
+    let (key, modifier_parts) = match parts.as_slice() {
+        [rest @ .., "", ""] => ("+", rest),
+        [rest @ .., key] => (*key, rest),
+        [] => return None,
+    };
+
+    let mut modifiers = KeyModifiers::NONE;
+    for part in modifier_parts {
+        modifiers |= match part.to_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" | "option" | "meta" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            "super" | "cmd" => KeyModifiers::SUPER,
+            _ => return None,
+        };
+    }
+
+    let lower = key.to_lowercase();
+    let code = match lower.as_str() {
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" | "return" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "space" => KeyCode::Char(' '),
+        _ => {
+            if let Some(n) = lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                if (1..=12).contains(&n) {
+                    KeyCode::F(n)
+                } else {
+                    return None;
+                }
+            } else {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return None,
+                }
+            }
+        }
+    };
+
+    Some(normalize(KeyCombo::new(code, modifiers)))
+}
+
+/// Canonical form so chords from the file and from crossterm compare equal.
+///
+/// Terminals report Shift+Tab as `BackTab` and Shift+letter as an uppercase
+/// char, with or without the SHIFT flag.
+fn normalize(combo: KeyCombo) -> KeyCombo {
+    let mut modifiers = combo.modifiers
+        & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT | KeyModifiers::SUPER);
+    let code = match combo.code {
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) && c.is_ascii_lowercase() => {
+            KeyCode::Char(c.to_ascii_uppercase())
+        }
+        code => code,
+    };
+    match code {
+        KeyCode::BackTab => modifiers.remove(KeyModifiers::SHIFT),
+        KeyCode::Char(c) if c.is_ascii_uppercase() => modifiers.insert(KeyModifiers::SHIFT),
+        // Shifted symbols (`?`, `!`) arrive with or without SHIFT
+        KeyCode::Char(c) if !c.is_ascii_alphanumeric() => modifiers.remove(KeyModifiers::SHIFT),
+        _ => {}
+    }
+    KeyCombo::new(code, modifiers)
+}
+
+/// Display a chord the way the keymap file spells it (`ctrl+n`, `shift+tab`)
+pub fn format_chord(combo: &KeyCombo) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    if combo.modifiers.contains(KeyModifiers::CONTROL) {
+        parts.push("ctrl".to_string());
+    }
+    if combo.modifiers.contains(KeyModifiers::ALT) {
+        parts.push("alt".to_string());
+    }
+    if combo.modifiers.contains(KeyModifiers::SUPER) {
+        parts.push("super".to_string());
+    }
+    let shifted_letter = matches!(combo.code, KeyCode::Char(c) if c.is_ascii_uppercase());
+    if combo.modifiers.contains(KeyModifiers::SHIFT) || combo.code == KeyCode::BackTab {
+        parts.push("shift".to_string());
+    }
+    let key = match combo.code {
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(c) if shifted_letter => c.to_ascii_lowercase().to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Esc => "esc".to_string(),
+        KeyCode::Enter => "enter".to_string(),
+        KeyCode::Tab | KeyCode::BackTab => "tab".to_string(),
+        KeyCode::Backspace => "backspace".to_string(),
+        KeyCode::Delete => "delete".to_string(),
+        KeyCode::Up => "up".to_string(),
+        KeyCode::Down => "down".to_string(),
+        KeyCode::Left => "left".to_string(),
+        KeyCode::Right => "right".to_string(),
+        KeyCode::Home => "home".to_string(),
+        KeyCode::End => "end".to_string(),
+        KeyCode::PageUp => "pageup".to_string(),
+        KeyCode::PageDown => "pagedown".to_string(),
+        KeyCode::F(n) => format!("f{}", n),
+        other => format!("{:?}", other).to_lowercase(),
+    };
+    parts.push(key);
+    parts.join("+")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_defaults_match_builtin_keys() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.action_for(&key(KeyCode::Char('w'), KeyModifiers::CONTROL)),
+            Some(Action::BackToCommandDeck)
+        );
+        assert_eq!(
+            keymap.action_for(&key(KeyCode::Char('N'), KeyModifiers::SHIFT)),
+            Some(Action::NewThread)
+        );
+        assert_eq!(
+            keymap.action_for(&key(KeyCode::BackTab, KeyModifiers::SHIFT)),
+            Some(Action::CyclePermissionMode)
+        );
+        assert_eq!(
+            keymap.action_for(&key(KeyCode::Char('O'), KeyModifiers::SHIFT)),
+            Some(Action::CopyToolOutput)
+        );
+        assert_eq!(
+            keymap.action_for(&key(KeyCode::Char('x'), KeyModifiers::NONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_file_replaces_action_defaults() {
+        let (keymap, warnings) = Keymap::parse(
+            "# avoid tmux clashes\nBackToCommandDeck = [\"shift+esc\", \"ctrl+b\"]\nnew_thread = \"alt+n\" # was ctrl+n\n",
+        );
+        assert!(warnings.is_empty());
+        assert_eq!(
+            keymap.action_for(&key(KeyCode::Char('w'), KeyModifiers::CONTROL)),
+            None
+        );
+        assert_eq!(
+            keymap.action_for(&key(KeyCode::Char('b'), KeyModifiers::CONTROL)),
+            Some(Action::BackToCommandDeck)
+        );
+        assert_eq!(keymap.chords_for(Action::NewThread), vec!["alt+n"]);
+    }
+
+    #[test]
+    fn test_unknown_actions_and_conflicts_are_reported() {
+        let (keymap, warnings) = Keymap::parse(
+            "Quit = \"ctrl+q\"\nFrobnicate = \"ctrl+f\"\nToggleReasoning = \"ctrl+q\"\nNewThread = \"ctrl+\"\n",
+        );
+        let lines: Vec<usize> = warnings.iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![2, 3, 4]);
+        assert!(warnings[0].message.contains("unknown action `Frobnicate`"));
+        assert_eq!(
+            warnings[1].to_string(),
+            "line 3: ctrl+q is already bound to Quit on line 1"
+        );
+        assert_eq!(
+            keymap.action_for(&key(KeyCode::Char('q'), KeyModifiers::CONTROL)),
+            Some(Action::Quit)
+        );
+        // The invalid line leaves NewThread's defaults alone
+        assert_eq!(keymap.chords_for(Action::NewThread).len(), 2);
+    }
+
+    #[test]
+    fn test_parse_and_format_chords_round_trip() {
+        for chord in [
+            "ctrl+n",
+            "shift+tab",
+            "alt+shift+p",
+            "f5",
+            "ctrl++",
+            "+",
+            "q",
+        ] {
+            let combo = parse_chord(chord).unwrap();
+            assert_eq!(format_chord(&combo), chord);
+        }
+        assert_eq!(parse_chord("shift+n"), parse_chord("N"));
+        assert!(parse_chord("hyper+n").is_none());
+    }
+}
@@ -31,18 +31,21 @@
 //! - [`context`] - [`InputContext`] for tracking current UI state
 //! - [`registry`] - [`CommandRegistry`] for mapping keys to commands
 //! - [`keybindings`] - Default key binding configuration
+//! - [`keymap`] - User-configurable chords for the main event loop
 //! - [`handlers`] - Command execution handlers
 
 pub mod command;
 pub mod context;
 pub mod handlers;
 pub mod keybindings;
+pub mod keymap;
 pub mod registry;
 pub mod slash_command;
 
 pub use command::Command;
 pub use context::{InputContext, ModalType};
 pub use keybindings::{KeyCombo, KeybindingConfig};
+pub use keymap::{Action, Keymap, KeymapWarning};
 pub use registry::CommandRegistry;
 pub use slash_command::SlashCommand;
 
@@ -221,8 +224,8 @@ impl App {
             focus: self.focus,
             modal,
             input_is_empty: self.textarea.is_empty(),
-            cursor_on_first_line: self.textarea.is_cursor_on_first_line(),
-            cursor_on_last_line: self.textarea.is_cursor_on_last_line(),
+            cursor_on_first_line: self.textarea.is_cursor_on_first_visual_row(),
+            cursor_on_last_line: self.textarea.is_cursor_on_last_visual_row(),
             is_navigating_history: self.input_history.is_navigating(),
             has_oauth_url: self.session_state.oauth_url.is_some(),
             has_errors: self.has_errors(),
@@ -157,6 +157,19 @@ impl CommandRegistry {
                     return Some(cmd.clone());
                 }
 
+                // Typing filters the list
+                match key.code {
+                    KeyCode::Backspace => return Some(Command::SwitcherBackspace),
+                    KeyCode::Char(c)
+                        if !key.modifiers.intersects(
+                            KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER,
+                        ) =>
+                    {
+                        return Some(Command::SwitcherTypeChar(c));
+                    }
+                    _ => {}
+                }
+
                 // Any other key confirms selection
                 Some(Command::ConfirmSwitcherSelection)
             }
@@ -476,6 +489,13 @@ impl CommandRegistry {
                     Command::Noop
                 }
             }
+            Command::UndoDismissError => {
+                if context.is_conversation_screen() {
+                    Command::UndoDismissError
+                } else {
+                    Command::Noop
+                }
+            }
             Command::ToggleReasoning => {
                 if context.is_conversation_screen() {
                     Command::ToggleReasoning
@@ -592,12 +612,26 @@ mod tests {
         let registry = CommandRegistry::new();
         let context = InputContext::new().with_modal(ModalType::ThreadSwitcher);
 
-        let key = make_key_event(KeyCode::Char('x'), KeyModifiers::NONE);
+        let key = make_key_event(KeyCode::PageDown, KeyModifiers::NONE);
         let cmd = registry.dispatch(key, &context);
 
         assert!(matches!(cmd, Some(Command::ConfirmSwitcherSelection)));
     }
 
+    #[test]
+    fn test_dispatch_thread_switcher_typing_filters() {
+        let registry = CommandRegistry::new();
+        let context = InputContext::new().with_modal(ModalType::ThreadSwitcher);
+
+        let key = make_key_event(KeyCode::Char('x'), KeyModifiers::NONE);
+        let cmd = registry.dispatch(key, &context);
+        assert!(matches!(cmd, Some(Command::SwitcherTypeChar('x'))));
+
+        let key = make_key_event(KeyCode::Backspace, KeyModifiers::NONE);
+        let cmd = registry.dispatch(key, &context);
+        assert!(matches!(cmd, Some(Command::SwitcherBackspace)));
+    }
+
     #[test]
     fn test_dispatch_permission_handles_char() {
         let registry = CommandRegistry::new();
@@ -55,6 +55,105 @@ pub enum SlashCommand {
     /// Primary: /discard
     /// Aliases: /delete
     Discard,
+
+    /// Toggle simulated typing (paced reveal of streamed responses)
+    /// Primary: /typing
+    Typing,
+
+    /// Export the current thread to Markdown
+    /// Primary: /export
+    Export,
+
+    /// Link the current thread to another thread ("follows", "blocked by")
+    /// Primary: /link
+    Link,
+
+    /// Back up corrupted state files and regenerate defaults
+    /// Primary: /regenerate
+    Regenerate,
+
+    /// Show the effective key bindings
+    /// Primary: /keys
+    /// Aliases: /keymap
+    Keys,
+
+    /// Open the notifications panel
+    /// Primary: /notifications
+    /// Aliases: /inbox
+    Notifications,
+
+    /// Pick the model for the active thread (or the next new thread)
+    /// Primary: /model
+    /// Aliases: /models
+    Model,
+
+    /// Rename the current thread (`/rename New title`, or an editor without one)
+    /// Primary: /rename
+    Rename,
+
+    /// Show context window usage (used / limit / last compaction)
+    /// Primary: /context
+    Context,
+
+    /// Show the raw SSE/WebSocket events received for the current thread
+    /// Primary: /events
+    Events,
+
+    /// Open finished answers (or their last code block) in $EDITOR for the
+    /// current thread (`/editor answer|code|off`)
+    /// Primary: /editor
+    Editor,
+
+    /// Toggle desktop notifications and their categories
+    /// (`/notify on|off|<category>`)
+    /// Primary: /notify
+    Notify,
+
+    /// Show backend, WebSocket and auth diagnostics
+    /// Primary: /status
+    Status,
+
+    /// Show or hide message timestamps and day headers
+    /// Primary: /timestamps
+    Timestamps,
+
+    /// Show the active thread's outcome metrics
+    /// Primary: /metrics
+    Metrics,
+
+    /// Compact the active thread's context window now
+    /// Primary: /compact
+    Compact,
+
+    /// Tail the log file, or set the log level (`/log debug`)
+    /// Primary: /log
+    Log,
+
+    /// Show token usage per thread and for the session
+    /// Primary: /usage
+    Usage,
+
+    /// Archive the current thread
+    /// Primary: /archive
+    Archive,
+
+    /// Write a JSON snapshot of the app state for bug reports
+    /// (`/debug snapshot`)
+    /// Primary: /debug
+    Debug,
+
+    /// Change the current thread's working directory (`/cd path`, or a
+    /// folder picker without one)
+    /// Primary: /cd
+    Cd,
+
+    /// Share this thread as a link, or show and revoke its link
+    /// Primary: /share
+    Share,
+
+    /// Mute this thread's notifications and needs-action routing, or unmute it
+    /// Primary: /mute
+    Mute,
 }
 
 impl SlashCommand {
@@ -71,6 +170,29 @@ impl SlashCommand {
             SlashCommand::Threads,
             SlashCommand::Claude,
             SlashCommand::Discard,
+            SlashCommand::Typing,
+            SlashCommand::Export,
+            SlashCommand::Link,
+            SlashCommand::Regenerate,
+            SlashCommand::Keys,
+            SlashCommand::Notifications,
+            SlashCommand::Model,
+            SlashCommand::Rename,
+            SlashCommand::Context,
+            SlashCommand::Events,
+            SlashCommand::Editor,
+            SlashCommand::Notify,
+            SlashCommand::Status,
+            SlashCommand::Timestamps,
+            SlashCommand::Metrics,
+            SlashCommand::Compact,
+            SlashCommand::Log,
+            SlashCommand::Usage,
+            SlashCommand::Archive,
+            SlashCommand::Debug,
+            SlashCommand::Cd,
+            SlashCommand::Share,
+            SlashCommand::Mute,
         ]
     }
 
@@ -103,6 +225,29 @@ impl SlashCommand {
             "threads" | "sessions" | "resume" => Some(SlashCommand::Threads),
             "claude" | "accounts" => Some(SlashCommand::Claude),
             "discard" | "delete" => Some(SlashCommand::Discard),
+            "typing" => Some(SlashCommand::Typing),
+            "export" => Some(SlashCommand::Export),
+            "link" => Some(SlashCommand::Link),
+            "regenerate" => Some(SlashCommand::Regenerate),
+            "keys" | "keymap" => Some(SlashCommand::Keys),
+            "notifications" | "inbox" => Some(SlashCommand::Notifications),
+            "model" | "models" => Some(SlashCommand::Model),
+            "rename" => Some(SlashCommand::Rename),
+            "context" => Some(SlashCommand::Context),
+            "events" => Some(SlashCommand::Events),
+            "editor" => Some(SlashCommand::Editor),
+            "notify" => Some(SlashCommand::Notify),
+            "status" | "health" => Some(SlashCommand::Status),
+            "timestamps" => Some(SlashCommand::Timestamps),
+            "metrics" => Some(SlashCommand::Metrics),
+            "compact" => Some(SlashCommand::Compact),
+            "log" | "logs" => Some(SlashCommand::Log),
+            "usage" => Some(SlashCommand::Usage),
+            "archive" => Some(SlashCommand::Archive),
+            "debug" => Some(SlashCommand::Debug),
+            "cd" => Some(SlashCommand::Cd),
+            "share" => Some(SlashCommand::Share),
+            "mute" => Some(SlashCommand::Mute),
             _ => None,
         }
     }
@@ -122,6 +267,29 @@ impl SlashCommand {
             SlashCommand::Threads => "/threads",
             SlashCommand::Claude => "/claude",
             SlashCommand::Discard => "/discard",
+            SlashCommand::Typing => "/typing",
+            SlashCommand::Export => "/export",
+            SlashCommand::Link => "/link",
+            SlashCommand::Regenerate => "/regenerate",
+            SlashCommand::Keys => "/keys",
+            SlashCommand::Notifications => "/notifications",
+            SlashCommand::Model => "/model",
+            SlashCommand::Rename => "/rename",
+            SlashCommand::Context => "/context",
+            SlashCommand::Events => "/events",
+            SlashCommand::Editor => "/editor",
+            SlashCommand::Notify => "/notify",
+            SlashCommand::Status => "/status",
+            SlashCommand::Timestamps => "/timestamps",
+            SlashCommand::Metrics => "/metrics",
+            SlashCommand::Compact => "/compact",
+            SlashCommand::Log => "/log",
+            SlashCommand::Usage => "/usage",
+            SlashCommand::Archive => "/archive",
+            SlashCommand::Debug => "/debug",
+            SlashCommand::Cd => "/cd",
+            SlashCommand::Share => "/share",
+            SlashCommand::Mute => "/mute",
         }
     }
 
@@ -141,6 +309,29 @@ impl SlashCommand {
             SlashCommand::Threads => vec!["/threads", "/sessions", "/resume"],
             SlashCommand::Claude => vec!["/claude", "/accounts"],
             SlashCommand::Discard => vec!["/discard", "/delete"],
+            SlashCommand::Typing => vec!["/typing"],
+            SlashCommand::Export => vec!["/export"],
+            SlashCommand::Link => vec!["/link"],
+            SlashCommand::Regenerate => vec!["/regenerate"],
+            SlashCommand::Keys => vec!["/keys", "/keymap"],
+            SlashCommand::Notifications => vec!["/notifications", "/inbox"],
+            SlashCommand::Model => vec!["/model", "/models"],
+            SlashCommand::Rename => vec!["/rename"],
+            SlashCommand::Context => vec!["/context"],
+            SlashCommand::Events => vec!["/events"],
+            SlashCommand::Editor => vec!["/editor"],
+            SlashCommand::Notify => vec!["/notify"],
+            SlashCommand::Status => vec!["/status", "/health"],
+            SlashCommand::Timestamps => vec!["/timestamps"],
+            SlashCommand::Metrics => vec!["/metrics"],
+            SlashCommand::Compact => vec!["/compact"],
+            SlashCommand::Log => vec!["/log", "/logs"],
+            SlashCommand::Usage => vec!["/usage"],
+            SlashCommand::Archive => vec!["/archive"],
+            SlashCommand::Debug => vec!["/debug"],
+            SlashCommand::Cd => vec!["/cd"],
+            SlashCommand::Share => vec!["/share"],
+            SlashCommand::Mute => vec!["/mute"],
         }
     }
 
@@ -157,9 +348,68 @@ impl SlashCommand {
             SlashCommand::Threads => "View and manage threads",
             SlashCommand::Claude => "Manage Claude Code accounts",
             SlashCommand::Discard => "Delete the current thread",
+            SlashCommand::Typing => "Toggle simulated typing for responses",
+            SlashCommand::Export => "Export the current thread to Markdown",
+            SlashCommand::Link => "Link this thread to another thread",
+            SlashCommand::Regenerate => "Back up corrupted state files and reset to defaults",
+            SlashCommand::Keys => "Show the active key bindings",
+            SlashCommand::Notifications => "Open, dismiss or clear notifications",
+            SlashCommand::Model => "Choose the model for this thread",
+            SlashCommand::Rename => "Rename this thread",
+            SlashCommand::Context => "Show context window usage",
+            SlashCommand::Events => "Show the raw event log of this thread",
+            SlashCommand::Editor => "Open finished answers in $EDITOR",
+            SlashCommand::Notify => "Configure desktop notifications",
+            SlashCommand::Status => "Show connection and auth diagnostics",
+            SlashCommand::Timestamps => "Show or hide message times",
+            SlashCommand::Metrics => "Show turns, denials, errors and reverts for this thread",
+            SlashCommand::Compact => "Compact this thread's context to free up tokens",
+            SlashCommand::Log => "Show the log, or set its level (/log debug)",
+            SlashCommand::Usage => "Show token usage per thread and for the session",
+            SlashCommand::Archive => "Archive this thread (hide it from the CommandDeck)",
+            SlashCommand::Debug => "Write a state snapshot for bug reports (/debug snapshot)",
+            SlashCommand::Cd => "Change this thread's working directory (/cd ~/code/app)",
+            SlashCommand::Share => "Share this thread as a link (or revoke its link)",
+            SlashCommand::Mute => "Mute this thread's notifications (or unmute it)",
         }
     }
 
+    /// Whether the command takes an argument after its name (`/rename New title`)
+    pub fn takes_argument(&self) -> bool {
+        matches!(
+            self,
+            SlashCommand::Rename
+                | SlashCommand::Editor
+                | SlashCommand::Notify
+                | SlashCommand::Log
+                | SlashCommand::Debug
+                | SlashCommand::Cd
+        )
+    }
+
+    /// Parse a slash command followed by an argument.
+    ///
+    /// Returns the command and its trimmed argument for commands that take
+    /// one; None for anything else, including commands without an argument.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spoq::input::slash_command::SlashCommand;
+    ///
+    /// assert_eq!(
+    ///     SlashCommand::parse_with_argument("/rename Fix the login flow"),
+    ///     Some((SlashCommand::Rename, "Fix the login flow"))
+    /// );
+    /// assert_eq!(SlashCommand::parse_with_argument("/rename"), None);
+    /// assert_eq!(SlashCommand::parse_with_argument("/sync now"), None);
+    /// ```
+    pub fn parse_with_argument(input: &str) -> Option<(Self, &str)> {
+        let (name, argument) = input.trim().split_once(char::is_whitespace)?;
+        let command = Self::parse(name).filter(Self::takes_argument)?;
+        Some((command, argument.trim()))
+    }
+
     /// Filter commands by a search query.
     ///
     /// Returns commands whose primary name or aliases match the query.
@@ -171,7 +421,7 @@ impl SlashCommand {
     /// use spoq::input::slash_command::SlashCommand;
     ///
     /// let results = SlashCommand::filter("");
-    /// assert_eq!(results.len(), 7); // All commands
+    /// assert_eq!(results.len(), SlashCommand::all().len()); // All commands
     ///
     /// let results = SlashCommand::filter("/sy");
     /// assert_eq!(results, vec![SlashCommand::Sync]);
@@ -339,9 +589,197 @@ mod tests {
         assert_eq!(results, vec![SlashCommand::Discard]);
     }
 
+    #[test]
+    fn test_parse_typing() {
+        assert_eq!(SlashCommand::parse("/typing"), Some(SlashCommand::Typing));
+        assert_eq!(SlashCommand::Typing.name(), "/typing");
+        assert_eq!(SlashCommand::filter("/ty"), vec![SlashCommand::Typing]);
+    }
+
+    #[test]
+    fn test_parse_export() {
+        assert_eq!(SlashCommand::parse("/export"), Some(SlashCommand::Export));
+        assert_eq!(SlashCommand::Export.name(), "/export");
+        assert_eq!(SlashCommand::filter("/ex"), vec![SlashCommand::Export]);
+    }
+
+    #[test]
+    fn test_parse_link() {
+        assert_eq!(SlashCommand::parse("/link"), Some(SlashCommand::Link));
+        assert_eq!(SlashCommand::Link.name(), "/link");
+        assert_eq!(SlashCommand::filter("/li"), vec![SlashCommand::Link]);
+    }
+
+    #[test]
+    fn test_parse_regenerate() {
+        assert_eq!(
+            SlashCommand::parse("/regenerate"),
+            Some(SlashCommand::Regenerate)
+        );
+        assert_eq!(SlashCommand::Regenerate.name(), "/regenerate");
+        assert_eq!(SlashCommand::filter("/reg"), vec![SlashCommand::Regenerate]);
+    }
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(SlashCommand::parse("/keys"), Some(SlashCommand::Keys));
+        assert_eq!(SlashCommand::parse("/keymap"), Some(SlashCommand::Keys));
+        assert_eq!(SlashCommand::Keys.name(), "/keys");
+    }
+
+    #[test]
+    fn test_parse_notifications() {
+        assert_eq!(
+            SlashCommand::parse("/notifications"),
+            Some(SlashCommand::Notifications)
+        );
+        assert_eq!(SlashCommand::parse("/inbox"), Some(SlashCommand::Notifications));
+        assert_eq!(SlashCommand::Notifications.name(), "/notifications");
+    }
+
+    #[test]
+    fn test_parse_model() {
+        assert_eq!(SlashCommand::parse("/model"), Some(SlashCommand::Model));
+        assert_eq!(SlashCommand::parse("/models"), Some(SlashCommand::Model));
+        assert_eq!(SlashCommand::Model.name(), "/model");
+        assert_eq!(SlashCommand::filter("/mo"), vec![SlashCommand::Model]);
+    }
+
     #[test]
     fn test_all_includes_discard() {
         let all = SlashCommand::all();
         assert!(all.contains(&SlashCommand::Discard));
     }
+
+    #[test]
+    fn test_parse_rename() {
+        assert_eq!(SlashCommand::parse("/rename"), Some(SlashCommand::Rename));
+        assert_eq!(SlashCommand::parse("/rename New title"), None);
+        assert_eq!(
+            SlashCommand::parse_with_argument("/RENAME   New  title "),
+            Some((SlashCommand::Rename, "New  title"))
+        );
+        assert_eq!(SlashCommand::parse_with_argument("/model opus"), None);
+        assert_eq!(SlashCommand::parse_with_argument("/nope title"), None);
+    }
+
+    #[test]
+    fn test_parse_context() {
+        assert_eq!(SlashCommand::parse("/context"), Some(SlashCommand::Context));
+        assert_eq!(SlashCommand::filter("/cont"), vec![SlashCommand::Context]);
+    }
+
+    #[test]
+    fn test_parse_events() {
+        assert_eq!(SlashCommand::parse("/events"), Some(SlashCommand::Events));
+        assert_eq!(SlashCommand::filter("/ev"), vec![SlashCommand::Events]);
+    }
+
+    #[test]
+    fn test_parse_editor() {
+        assert_eq!(SlashCommand::parse("/editor"), Some(SlashCommand::Editor));
+        assert_eq!(
+            SlashCommand::parse_with_argument("/editor code"),
+            Some((SlashCommand::Editor, "code"))
+        );
+        assert_eq!(SlashCommand::filter("/ed"), vec![SlashCommand::Editor]);
+    }
+
+    #[test]
+    fn test_parse_notify() {
+        assert_eq!(SlashCommand::parse("/notify"), Some(SlashCommand::Notify));
+        assert_eq!(
+            SlashCommand::parse_with_argument("/notify failure"),
+            Some((SlashCommand::Notify, "failure"))
+        );
+        assert_eq!(SlashCommand::filter("/notify"), vec![SlashCommand::Notify]);
+    }
+
+    #[test]
+    fn test_parse_log() {
+        assert_eq!(SlashCommand::parse("/log"), Some(SlashCommand::Log));
+        assert_eq!(SlashCommand::parse("/logs"), Some(SlashCommand::Log));
+        assert_eq!(
+            SlashCommand::parse_with_argument("/log debug"),
+            Some((SlashCommand::Log, "debug"))
+        );
+        assert_eq!(SlashCommand::filter("/lo"), vec![SlashCommand::Log]);
+    }
+
+    #[test]
+    fn test_parse_usage() {
+        assert_eq!(SlashCommand::parse("/usage"), Some(SlashCommand::Usage));
+        assert_eq!(SlashCommand::filter("/us"), vec![SlashCommand::Usage]);
+    }
+
+    #[test]
+    fn test_parse_archive() {
+        assert_eq!(SlashCommand::parse("/archive"), Some(SlashCommand::Archive));
+        assert_eq!(SlashCommand::filter("/arc"), vec![SlashCommand::Archive]);
+    }
+
+    #[test]
+    fn test_parse_debug_with_argument() {
+        assert_eq!(SlashCommand::parse("/debug"), Some(SlashCommand::Debug));
+        assert_eq!(
+            SlashCommand::parse_with_argument("/debug snapshot"),
+            Some((SlashCommand::Debug, "snapshot"))
+        );
+        assert_eq!(SlashCommand::filter("/deb"), vec![SlashCommand::Debug]);
+    }
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(SlashCommand::parse("/status"), Some(SlashCommand::Status));
+        assert_eq!(SlashCommand::parse("/health"), Some(SlashCommand::Status));
+        assert_eq!(SlashCommand::filter("/sta"), vec![SlashCommand::Status]);
+    }
+
+    #[test]
+    fn test_parse_timestamps() {
+        assert_eq!(
+            SlashCommand::parse("/timestamps"),
+            Some(SlashCommand::Timestamps)
+        );
+        assert_eq!(
+            SlashCommand::filter("/times"),
+            vec![SlashCommand::Timestamps]
+        );
+    }
+
+    #[test]
+    fn test_parse_metrics() {
+        assert_eq!(SlashCommand::parse("/metrics"), Some(SlashCommand::Metrics));
+        assert_eq!(SlashCommand::filter("/me"), vec![SlashCommand::Metrics]);
+    }
+
+    #[test]
+    fn test_parse_compact() {
+        assert_eq!(SlashCommand::parse("/compact"), Some(SlashCommand::Compact));
+        assert_eq!(SlashCommand::filter("/com"), vec![SlashCommand::Compact]);
+    }
+
+    #[test]
+    fn test_parse_cd_with_argument() {
+        assert_eq!(SlashCommand::parse("/cd"), Some(SlashCommand::Cd));
+        assert_eq!(
+            SlashCommand::parse_with_argument("/cd ~/code/app"),
+            Some((SlashCommand::Cd, "~/code/app"))
+        );
+        assert_eq!(SlashCommand::filter("/cd"), vec![SlashCommand::Cd]);
+    }
+
+    #[test]
+    fn test_parse_share() {
+        assert_eq!(SlashCommand::parse("/share"), Some(SlashCommand::Share));
+        assert_eq!(SlashCommand::Share.name(), "/share");
+        assert_eq!(SlashCommand::filter("/sh"), vec![SlashCommand::Share]);
+    }
+
+    #[test]
+    fn test_parse_mute() {
+        assert_eq!(SlashCommand::parse("/mute"), Some(SlashCommand::Mute));
+        assert_eq!(SlashCommand::Mute.name(), "/mute");
+        assert_eq!(SlashCommand::filter("/mu"), vec![SlashCommand::Mute]);
+    }
 }
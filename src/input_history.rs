@@ -68,21 +68,25 @@ impl InputHistory {
         };
 
         let reader = BufReader::new(file);
-        let entries: Vec<String> = reader
+        let lines: Vec<String> = reader
             .lines()
             .map_while(Result::ok)
             .filter(|line| !line.is_empty())
+            .map(|line| line.replace("\\n", "\n"))
             .collect();
 
         Self {
-            entries,
+            entries: compact_entries(&lines),
             index: None,
             current_input: String::new(),
         }
     }
 
-    /// Persist history to file.
+    /// Persist history to file (skipped while state files are read-only).
     pub fn save(&self) {
+        if crate::startup::integrity::is_read_only() {
+            return;
+        }
         let Some(path) = Self::history_path() else {
             return;
         };
@@ -98,7 +102,7 @@ impl InputHistory {
         };
 
         let mut writer = std::io::BufWriter::new(file);
-        for entry in &self.entries {
+        for entry in &compact_entries(&self.entries) {
             // Replace newlines with a special marker for multi-line entries
             let escaped = entry.replace('\n', "\\n");
             let _ = writeln!(writer, "{}", escaped);
@@ -189,6 +193,36 @@ impl InputHistory {
         }
     }
 
+    /// Fuzzy search the history for `query`, best matches first.
+    ///
+    /// An entry matches when the query's characters appear in it in order
+    /// (case-insensitive). Entries starting with the query rank first, then
+    /// entries containing it, then scattered matches; within a rank the most recent entry comes
+    /// first. Identical entries are returned once. An empty query matches
+    /// every entry, most recent first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&str> {
+        let query = query.to_lowercase();
+        let mut seen = std::collections::HashSet::new();
+        let mut matches: Vec<(u8, &str)> = Vec::new();
+
+        for entry in self.entries.iter().rev() {
+            if !seen.insert(entry.as_str()) {
+                continue;
+            }
+            if let Some(rank) = match_rank(&entry.to_lowercase(), &query) {
+                matches.push((rank, entry.as_str()));
+            }
+        }
+
+        // Stable sort keeps recency order within a rank
+        matches.sort_by_key(|(rank, _)| *rank);
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, entry)| entry)
+            .collect()
+    }
+
     /// Reset navigation index to None (called after submit).
     pub fn reset_navigation(&mut self) {
         self.index = None;
@@ -224,6 +258,34 @@ impl InputHistory {
     }
 }
 
+/// Rank of a fuzzy match (lower is better), or `None` if `entry` doesn't match.
+///
+/// Both strings are expected lowercased.
+pub(crate) fn match_rank(entry: &str, query: &str) -> Option<u8> {
+    if entry.starts_with(query) {
+        return Some(0);
+    }
+    if entry.contains(query) {
+        return Some(1);
+    }
+    let mut chars = entry.chars();
+    query.chars().all(|q| chars.any(|c| c == q)).then_some(2)
+}
+
+/// Entries as written to the history file: consecutive duplicates collapsed
+/// and only the newest `MAX_HISTORY_SIZE` kept.
+fn compact_entries(entries: &[String]) -> Vec<String> {
+    let mut compacted: Vec<String> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        if compacted.last() != Some(entry) {
+            compacted.push(entry.clone());
+        }
+    }
+    let excess = compacted.len().saturating_sub(MAX_HISTORY_SIZE);
+    compacted.drain(..excess);
+    compacted
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -396,4 +458,55 @@ mod tests {
         let history: InputHistory = Default::default();
         assert!(history.is_empty());
     }
+
+    #[test]
+    fn test_search_ranks_substring_then_fuzzy_by_recency() {
+        let mut history = InputHistory::new();
+        history.add("fix the login bug".to_string());
+        history.add("run cargo test".to_string());
+        history.add("fix tests".to_string());
+        history.add("format the code".to_string());
+
+        // "fix" prefixes win, newest first; "f..i..x" nowhere else
+        assert_eq!(
+            history.search("fix", 10),
+            vec!["fix tests", "fix the login bug"]
+        );
+
+        // Substring matches beat scattered ones, newest first within each
+        assert_eq!(
+            history.search("te", 10),
+            vec![
+                "fix tests",
+                "run cargo test",
+                "format the code",
+                "fix the login bug"
+            ]
+        );
+        assert_eq!(history.search("ftc", 10), vec!["format the code"]);
+        assert_eq!(history.search("RUN", 10), vec!["run cargo test"]);
+        assert!(history.search("zzz", 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_empty_query_dedups_newest_first() {
+        let mut history = InputHistory::new();
+        history.add("one".to_string());
+        history.add("two".to_string());
+        history.add("one".to_string());
+
+        assert_eq!(history.search("", 10), vec!["one", "two"]);
+        assert_eq!(history.search("", 1), vec!["one"]);
+    }
+
+    #[test]
+    fn test_compact_entries_dedups_and_caps() {
+        let entries: Vec<String> = ["a", "a", "b", "a"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(compact_entries(&entries), vec!["a", "b", "a"]);
+
+        let many: Vec<String> = (0..MAX_HISTORY_SIZE + 5).map(|i| i.to_string()).collect();
+        let compacted = compact_entries(&many);
+        assert_eq!(compacted.len(), MAX_HISTORY_SIZE);
+        assert_eq!(compacted[0], "5");
+    }
 }
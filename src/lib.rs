@@ -17,6 +17,8 @@
 //! - [`sse`] - Server-sent events parsing
 //! - [`widgets`] - Reusable UI widgets
 //! - [`prelude`] - Convenient re-exports
+//! - [`embed`] - Stable facade for embedding the app (semver-covered)
+//! - [`testing`] - Fake backend and scripted events for integration tests
 //!
 //! # Internal Modules
 //!
@@ -53,12 +55,18 @@ pub mod adapters;
 /// Server-sent events parsing
 pub mod sse;
 
-/// Reusable UI widgets (TextAreaInput)
+/// Reusable UI widgets (TextAreaInput, ConversationView)
 pub mod widgets;
 
 /// Convenient re-exports of commonly used types
 pub mod prelude;
 
+/// Stable facade for embedding the app in other programs
+pub mod embed;
+
+/// Fake Conductor and scripted event feed for integration tests
+pub mod testing;
+
 // ============================================================================
 // Internal modules - Required by main.rs but not part of stable public API
 // ============================================================================
@@ -157,6 +165,14 @@ pub mod websocket;
 #[doc(hidden)]
 pub mod credential_watcher;
 
-/// Native OS notifications for task completion
+/// Native OS notifications and their per-event settings
 #[doc(hidden)]
 pub mod notifications;
+
+/// Thread event delivery to external webhooks
+#[doc(hidden)]
+pub mod webhooks;
+
+/// Log file setup, rotation and runtime level switching
+#[doc(hidden)]
+pub mod logging;
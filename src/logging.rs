@@ -0,0 +1,289 @@
+//! Log file setup, rotation and runtime level switching.
+//!
+//! Logs are written to `SPOQ_LOG_FILE`, else `log_file` in
+//! ~/.spoq/config.json, else /tmp/spoq_debug.log. Once the file passes
+//! `MAX_LOG_BYTES` it is moved to `<path>.1` (replacing the previous one) and
+//! a new file is started. The level comes from `RUST_LOG` (default `info`)
+//! and can be changed while running with `/log <level>`; `/log` alone opens a
+//! viewer tailing the file.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use tracing_subscriber::{fmt, prelude::*, reload, EnvFilter, Registry};
+
+/// Log file used when none is configured
+pub const DEFAULT_LOG_PATH: &str = "/tmp/spoq_debug.log";
+
+/// Environment variable overriding the log file path
+pub const LOG_FILE_ENV_VAR: &str = "SPOQ_LOG_FILE";
+
+/// Size past which the log file is rotated
+pub const MAX_LOG_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Bytes read from the end of the file when tailing it
+const TAIL_BYTES: u64 = 256 * 1024;
+
+/// Handle for swapping the level filter at runtime
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// Path logs are written to, once `init` has run
+static LOG_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// Log levels, least severe first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Name as used in filters (`info`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "trace",
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        }
+    }
+
+    /// Parse a level name, case-insensitively
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "trace" => Some(LogLevel::Trace),
+            "debug" => Some(LogLevel::Debug),
+            "info" => Some(LogLevel::Info),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "error" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+
+    /// Level of a line written by the fmt layer (`<timestamp>  WARN message`)
+    pub fn of_line(line: &str) -> Option<Self> {
+        line.split_whitespace().take(2).find_map(|word| match word {
+            "TRACE" => Some(LogLevel::Trace),
+            "DEBUG" => Some(LogLevel::Debug),
+            "INFO" => Some(LogLevel::Info),
+            "WARN" => Some(LogLevel::Warn),
+            "ERROR" => Some(LogLevel::Error),
+            _ => None,
+        })
+    }
+}
+
+/// Where logs go: `SPOQ_LOG_FILE`, else the configured path, else the default
+pub fn resolve_log_path(configured: Option<&str>) -> PathBuf {
+    std::env::var(LOG_FILE_ENV_VAR)
+        .ok()
+        .filter(|path| !path.is_empty())
+        .or_else(|| configured.map(str::to_string))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_LOG_PATH))
+}
+
+/// Path logs are written to
+pub fn log_path() -> PathBuf {
+    LOG_PATH
+        .get()
+        .cloned()
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_LOG_PATH))
+}
+
+/// Path the log file is moved to when rotated (`<path>.1`)
+pub fn rotated_path(path: &Path) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(".1");
+    PathBuf::from(rotated)
+}
+
+/// Start logging to `path`. Call once, before anything is logged.
+pub fn init(path: PathBuf) -> io::Result<()> {
+    let file = RotatingFile::open(&path, MAX_LOG_BYTES)?;
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let (filter, handle) = reload::Layer::new(filter);
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(
+            fmt::layer()
+                .with_writer(Mutex::new(file))
+                .with_ansi(false)
+                .with_target(false),
+        )
+        .init();
+
+    let _ = FILTER_HANDLE.set(handle);
+    let _ = LOG_PATH.set(path);
+    Ok(())
+}
+
+/// Change the log level while running
+pub fn set_level(level: LogLevel) -> Result<(), String> {
+    let handle = FILTER_HANDLE
+        .get()
+        .ok_or_else(|| "logging is not initialized".to_string())?;
+    handle
+        .reload(EnvFilter::new(level.as_str()))
+        .map_err(|e| e.to_string())
+}
+
+/// Log file that moves itself to `<path>.1` once it grows past a limit.
+///
+/// The check happens before each write, so a record is never split across
+/// the two files.
+pub struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+}
+
+impl RotatingFile {
+    /// Open `path` for appending
+    pub fn open(path: &Path, max_bytes: u64) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            size,
+            max_bytes,
+        })
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        std::fs::rename(&self.path, rotated_path(&self.path))?;
+        self.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size >= self.max_bytes {
+            // Keep logging to the full file rather than losing records
+            let _ = self.rotate();
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// The last `max_lines` lines of the file at `path`.
+///
+/// Only the last `TAIL_BYTES` of the file are read, however large it is.
+pub fn read_tail(path: &Path, max_lines: usize) -> io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let start = file.metadata()?.len().saturating_sub(TAIL_BYTES);
+    file.seek(SeekFrom::Start(start))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+
+    let text = String::from_utf8_lossy(&bytes);
+    let mut lines = text.lines();
+    if start > 0 {
+        // The read most likely began mid-line
+        lines.next();
+    }
+    let lines: Vec<&str> = lines.collect();
+    let skip = lines.len().saturating_sub(max_lines);
+    Ok(lines[skip..].iter().map(|line| line.to_string()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_level_parse_and_order() {
+        assert_eq!(LogLevel::parse("DEBUG"), Some(LogLevel::Debug));
+        assert_eq!(LogLevel::parse("warning"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::parse("loud"), None);
+        assert!(LogLevel::Error > LogLevel::Warn);
+        assert!(LogLevel::Debug < LogLevel::Info);
+    }
+
+    #[test]
+    fn test_level_of_line() {
+        assert_eq!(
+            LogLevel::of_line("2026-10-15T09:12:01.123456Z  WARN Reconnecting"),
+            Some(LogLevel::Warn)
+        );
+        assert_eq!(
+            LogLevel::of_line("2026-10-15T09:12:01.123456Z ERROR boom: INFO"),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(LogLevel::of_line("    at src/main.rs:12"), None);
+    }
+
+    #[test]
+    fn test_resolve_log_path_prefers_config_over_default() {
+        if std::env::var(LOG_FILE_ENV_VAR).is_ok() {
+            return;
+        }
+        assert_eq!(
+            resolve_log_path(Some("/var/log/spoq.log")),
+            PathBuf::from("/var/log/spoq.log")
+        );
+        assert_eq!(resolve_log_path(None), PathBuf::from(DEFAULT_LOG_PATH));
+    }
+
+    #[test]
+    fn test_rotating_file_rotates_past_limit() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("spoq.log");
+        let mut file = RotatingFile::open(&path, 16).unwrap();
+
+        file.write_all(b"first line 12345\n").unwrap();
+        file.write_all(b"second\n").unwrap();
+        file.flush().unwrap();
+
+        let rotated = std::fs::read_to_string(rotated_path(&path)).unwrap();
+        assert_eq!(rotated, "first line 12345\n");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second\n");
+    }
+
+    #[test]
+    fn test_read_tail_returns_last_lines() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("spoq.log");
+        let text: String = (0..10).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(&path, text).unwrap();
+
+        assert_eq!(
+            read_tail(&path, 3).unwrap(),
+            vec!["line 7", "line 8", "line 9"]
+        );
+        assert_eq!(read_tail(&path, 100).unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_read_tail_drops_partial_first_line() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("spoq.log");
+        let mut text = "x".repeat(TAIL_BYTES as usize);
+        text.push_str("\nlast\n");
+        std::fs::write(&path, text).unwrap();
+
+        assert_eq!(read_tail(&path, 10).unwrap(), vec!["last"]);
+    }
+}
@@ -1,17 +1,15 @@
-use spoq::app::{start_websocket_with_config, App, AppMessage, BrowseListSelectAction, Focus, Screen, ScrollBoundary, UnifiedPickerAction};
-use spoq::cli::{parse_args, run_cli_command};
+use spoq::app::{App, AppMessage, BrowseListSelectAction, Focus, Screen, ScrollBoundary, UnifiedPickerAction};
+use spoq::cli::{parse_args, parse_fresh, parse_inject_faults, parse_safe_mode, run_cli_command};
 use spoq::credential_watcher::spawn_file_watcher;
-use spoq::debug::{DebugEvent, DebugEventKind, StateChangeData, StateType};
-use spoq::input::translate_shifted_char;
+use spoq::input::{translate_shifted_char, Action, SlashCommand};
 use spoq::models;
 use spoq::models::dashboard::WaitingFor;
 use spoq::startup::{run_preflight_checks, StartupConfig};
-use spoq::terminal::{setup_panic_hook, TerminalManager};
+use spoq::terminal::{run_editor, setup_panic_hook, write_attention, write_osc52_clipboard, TerminalManager};
 use spoq::ui;
-use spoq::websocket::WsClientConfig;
 
 use color_eyre::Result;
-use crossterm::event::{Event, EventStream, KeyCode, KeyEventKind, KeyModifiers, MouseEventKind};
+use crossterm::event::{Event, EventStream, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind};
 use crossterm::terminal::{BeginSynchronizedUpdate, EndSynchronizedUpdate};
 use crossterm::execute;
 use futures::StreamExt;
@@ -28,15 +26,16 @@ use tokio::sync::mpsc;
 /// 3. Download the update if available
 /// 4. Store the pending update path in state for next launch
 ///
-/// Errors are silently ignored to avoid disrupting the user experience.
-async fn check_and_download_update() {
+/// Returns the downloaded version, if any. Errors are silently ignored to
+/// avoid disrupting the user experience.
+async fn check_and_download_update() -> Option<String> {
     use spoq::update::{check_for_update, detect_platform, download_binary, UpdateStateManager};
     use std::time::{SystemTime, UNIX_EPOCH};
 
     // Load update state to check when we last checked
     let state_manager = match UpdateStateManager::new() {
         Some(mgr) => mgr,
-        None => return, // Can't determine home dir - skip update check
+        None => return None, // Can't determine home dir - skip update check
     };
 
     let mut state = state_manager.load();
@@ -51,7 +50,7 @@ async fn check_and_download_update() {
     if let Some(last_check) = state.last_check {
         if now - last_check < CHECK_INTERVAL_SECONDS {
             // Too soon since last check - skip
-            return;
+            return None;
         }
     }
 
@@ -62,33 +61,36 @@ async fn check_and_download_update() {
     // Step 1: Check for updates
     let check_result = match check_for_update().await {
         Ok(result) => result,
-        Err(_) => return, // Network error or API down - silently skip
+        Err(_) => return None, // Network error or API down - silently skip
     };
 
     if !check_result.update_available {
         // Already on latest version
-        return;
+        return None;
     }
 
     // Step 2: Download the update
     let platform = match detect_platform() {
         Ok(p) => p,
-        Err(_) => return, // Unsupported platform - skip
+        Err(_) => return None, // Unsupported platform - skip
     };
 
     let download_result = match download_binary(platform, Some(&check_result.latest_version)).await
     {
         Ok(result) => result,
-        Err(_) => return, // Download failed - silently skip
+        Err(_) => return None, // Download failed - silently skip
     };
 
     // Step 3: Store the pending update path in state
     state.pending_update_path = Some(download_result.file_path.to_string_lossy().to_string());
-    state.available_version = Some(check_result.latest_version);
+    state.available_version = Some(check_result.latest_version.clone());
+    // Shown once as "What's new" on the first launch of the new version
+    state.release_notes = check_result.release_notes;
     let _ = state_manager.save(&state);
 
     // Update is now ready for installation on next launch
     // User will see notification in TUI or can run `spoq --update` manually
+    Some(check_result.latest_version)
 }
 
 fn main() -> Result<()> {
@@ -98,28 +100,30 @@ fn main() -> Result<()> {
         return result;
     }
 
-    // Initialize tracing to write to /tmp/spoq_debug.log
+    // Initialize tracing to the log file (rotated past 10MB, level switchable with /log)
+    {
+        let configured = spoq::startup::SpoqConfig::load().log_file;
+        let path = spoq::logging::resolve_log_path(configured.as_deref());
+        if let Err(e) = spoq::logging::init(path.clone()) {
+            panic!("Failed to open {}: {}", path.display(), e);
+        }
+    }
+
+    // Developer failure injection: --inject-faults[=SPEC] or SPOQ_INJECT_FAULTS
     {
-        use std::fs::OpenOptions;
-        use tracing_subscriber::{fmt, prelude::*, EnvFilter};
-
-        let log_file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open("/tmp/spoq_debug.log")
-            .expect("Failed to open /tmp/spoq_debug.log");
-
-        let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-
-        tracing_subscriber::registry()
-            .with(
-                fmt::layer()
-                    .with_writer(std::sync::Mutex::new(log_file))
-                    .with_ansi(false)
-                    .with_target(false),
-            )
-            .with(filter)
-            .init();
+        use spoq::adapters::fault_injection::{self, FaultConfig, FAULTS_ENV_VAR};
+
+        let spec = parse_inject_faults(std::env::args())
+            .or_else(|| std::env::var(FAULTS_ENV_VAR).ok());
+        if let Some(spec) = spec {
+            match FaultConfig::parse(&spec) {
+                Ok(config) => fault_injection::enable(config),
+                Err(e) => {
+                    eprintln!("Invalid --inject-faults spec: {}", e);
+                    std::process::exit(2);
+                }
+            }
+        }
     }
 
     color_eyre::install()?;
@@ -134,6 +138,11 @@ fn main() -> Result<()> {
     // This runtime will be used for auth flows and then for TUI async operations
     let runtime = tokio::runtime::Runtime::new()?;
 
+    // Corrupted state files put the app in read-only mode before anything
+    // (preflight, the capability probe) can overwrite them with defaults
+    let corrupted_files = spoq::startup::check_state_files();
+    spoq::startup::integrity::set_read_only(!corrupted_files.is_empty());
+
     // =========================================================
     // Pre-flight checks - auth, VPS, health (via startup module)
     // Set SPOQ_DEV=1 to skip auth and use localhost:8000
@@ -152,13 +161,9 @@ fn main() -> Result<()> {
     let vps_url = startup_result.vps_url;
     let debug_tx = startup_result.debug_tx;
     let debug_server_handle = startup_result.debug_server_handle;
-
-    // =========================================================
-    // Update check - run in background, non-blocking
-    // =========================================================
-    runtime.spawn(async {
-        check_and_download_update().await;
-    });
+    let debug_state_snapshot = startup_result.debug_state_snapshot;
+    let crash_snapshot = startup_result.crash_snapshot;
+    let needs_setup = startup_result.needs_setup;
 
     // =========================================================
     // TUI initialization - user is now authenticated
@@ -168,139 +173,135 @@ fn main() -> Result<()> {
     // Small delay to let PTY fully initialize (needed for some terminal emulators)
     thread::sleep(Duration::from_millis(100));
 
+    // Terminal capabilities from `spoq setup` (probed now on first launch);
+    // unsupported features are never enabled
+    let capabilities = spoq::setup::load_or_probe_capabilities();
+
     // Create terminal manager - handles all setup and cleanup via RAII
-    let mut term_manager = TerminalManager::new()?;
+    let mut term_manager = TerminalManager::with_capabilities(&capabilities)?;
 
     // Initialize application state with debug sender, VPS URL, and credentials
     let mut app = App::with_credentials(debug_tx, vps_url, credentials)?;
+    app.debug_state_snapshot = debug_state_snapshot;
 
     // Log initial auth state for debugging
     app.log_initial_auth_state();
 
-    // Capture initial terminal dimensions
-    let size = term_manager.size()?;
-    app.update_terminal_dimensions(size.width, size.height);
-
-    // Initialize server connection - user is already authenticated with ready VPS
-    // Login and Provisioning screens are handled by pre-flight checks above
-    runtime.block_on(async {
-        // Load threads from backend (async initialization)
-        app.initialize().await;
-
-        // Load folders for the folder picker (async, non-blocking)
-        app.load_folders();
-
-        // Load GitHub repos for empty state (async, non-blocking)
-        app.load_repos();
-
-        // Preload unified picker data in background (repos, threads, folders)
-        // This enables instant @ picker opening
-        app.preload_picker_data();
-
-        // Connect WebSocket for real-time communication
-        // Build config with token from credentials and VPS URL
-        let mut ws_config = WsClientConfig::default();
-
-        // Use VPS URL for WebSocket host (strip protocol prefix)
-        // Use wss:// for HTTPS URLs, ws:// for HTTP/plain URLs
-        // Default to wss:// for domains (likely behind Cloudflare Tunnel)
-        if let Some(ref url) = app.vps_url {
-            let (host, use_tls) = if url.starts_with("https://") {
-                (url.strip_prefix("https://").unwrap(), true)
-            } else if url.starts_with("http://") {
-                (url.strip_prefix("http://").unwrap(), false)
-            } else {
-                // No protocol prefix - default to TLS for domains, non-TLS for IPs
-                let is_ip = url.split(':').next().map_or(false, |h| {
-                    h.parse::<std::net::Ipv4Addr>().is_ok()
-                        || h.parse::<std::net::Ipv6Addr>().is_ok()
-                });
-                (url.as_str(), !is_ip) // Use TLS for domains
-            };
-            ws_config = ws_config.with_host(host).with_tls(use_tls);
+    // =========================================================
+    // Update check - run in background, non-blocking
+    // =========================================================
+    let update_tx = app.message_tx.clone();
+    runtime.spawn(async move {
+        if let Some(version) = check_and_download_update().await {
+            let _ = update_tx.send(AppMessage::UpdateReady { version });
         }
+    });
 
-        // Add auth token if available
-        if let Some(ref token) = app.credentials.access_token {
-            ws_config = ws_config.with_auth(token);
-        }
+    // Downgrade hyperlinks, OSC 52 and colors the terminal can't handle
+    app.apply_terminal_capabilities(capabilities);
 
-        // Emit debug event showing connection attempt
-        if let Some(ref tx) = app.debug_tx {
-            let _ = tx.send(DebugEvent::new(DebugEventKind::StateChange(
-                StateChangeData::new(
-                    StateType::WebSocket,
-                    "WS_CONNECTING",
-                    format!(
-                        "Connecting to {} (use_tls={}, has_token={})",
-                        ws_config.host,
-                        ws_config.use_tls,
-                        ws_config.auth_token.is_some()
-                    ),
-                ),
-            )));
-        }
+    // --safe-mode: built-in theme and keymap, no webhooks, preloads or watchers
+    let safe_mode = parse_safe_mode(std::env::args());
+    if safe_mode {
+        app.enter_safe_mode();
+    } else {
+        // Apply ~/.spoq/theme.json, warning about low-contrast colors
+        app.load_custom_theme();
 
-        // If connection fails, app continues in SSE-only mode
-        match start_websocket_with_config(app.message_tx.clone(), ws_config).await {
-            Ok(sender) => {
-                if let Some(ref tx) = app.debug_tx {
-                    let _ = tx.send(DebugEvent::new(DebugEventKind::StateChange(
-                        StateChangeData::new(
-                            StateType::WebSocket,
-                            "WS_INIT",
-                            "WebSocket connected successfully",
-                        ),
-                    )));
-                }
-                app.ws_sender = Some(sender.clone());
-
-                // Request accounts list on connect to populate header badge
-                let msg = spoq::websocket::WsOutgoingMessage::ClaudeAccountsListRequest(
-                    spoq::websocket::messages::WsClaudeAccountsListRequest::new(
-                        uuid::Uuid::new_v4().to_string(),
-                    ),
-                );
-                let _ = sender.try_send(msg);
-            }
-            Err(e) => {
-                if let Some(ref tx) = app.debug_tx {
-                    let _ = tx.send(DebugEvent::new(DebugEventKind::StateChange(
-                        StateChangeData::new(
-                            StateType::WebSocket,
-                            "WS_INIT_FAILED",
-                            format!("WebSocket connection failed: {}", e),
-                        ),
-                    )));
-                }
-                app.ws_sender = None;
-            }
+        // Apply ~/.config/spoq/keymap.toml, warning about skipped lines
+        app.load_keymap();
+    }
+
+    // Release notes of the update that just installed, shown once
+    app.load_whats_new();
+
+    if spoq::adapters::fault_injection::is_enabled() {
+        app.set_status_notice(
+            "Failure injection is on: expect dropped frames, 500s and slow responses".to_string(),
+            Duration::from_secs(8),
+        );
+    }
+
+    // Route thread events to webhooks configured in ~/.spoq/webhooks.json
+    if !safe_mode {
+        app.webhooks = spoq::webhooks::WebhookConfig::load();
+    }
+
+    // Show the read-only banner for corrupted state files
+    app.enter_read_only_mode(corrupted_files);
+
+    // Settings changed in the app are written back to ~/.spoq/config.json
+    app.config_path = spoq::startup::SpoqConfig::config_path().ok();
+
+    // Local pins and mutes, merged into the threads as they load
+    app.thread_state_path = spoq::app::ThreadState::path();
+    if let Some(path) = app.thread_state_path.as_deref() {
+        app.thread_state = spoq::app::ThreadState::load(path);
+    }
+
+    // Last viewed thread, reopened after initialize() unless disabled
+    app.session_path = spoq::app::SavedSession::path();
+    if !safe_mode {
+        app.render_snapshot_dir = spoq::app::RenderSnapshot::dir();
+        app.thread_metrics_path = spoq::app::ThreadMetrics::path();
+        if let Some(path) = app.thread_metrics_path.as_deref() {
+            app.thread_metrics = spoq::app::ThreadMetrics::load_all(path);
         }
+    }
+    let resume = !safe_mode
+        && !parse_fresh(std::env::args())
+        && spoq::startup::SpoqConfig::load().resume_session;
+    let saved_session = if resume { app.load_session() } else { None };
+
+    // Capture initial terminal dimensions
+    let size = term_manager.size()?;
+    app.update_terminal_dimensions(size.width, size.height);
 
+    // Initialize server connection - user is already authenticated with ready VPS
+    // unless pre-flight checks asked for the setup wizard
+    runtime.block_on(async {
         // =========================================================================
         // Initialize Credential Auto-Sync
         // =========================================================================
         tracing::info!("Starting credential change detection...");
 
         // Start file watcher for ~/.claude.json and ~/.config/gh/hosts.yml
-        match spawn_file_watcher(app.message_tx.clone()) {
-            Ok(watcher) => {
-                app.set_credential_file_watcher(watcher);
-                tracing::info!("Credential file watcher started");
-            }
-            Err(e) => {
-                // Non-fatal: Keychain polling still works
-                tracing::warn!("Failed to start file watcher: {}", e);
+        // (not in safe mode)
+        if !safe_mode {
+            match spawn_file_watcher(app.message_tx.clone()) {
+                Ok(watcher) => {
+                    app.set_credential_file_watcher(watcher);
+                    tracing::info!("Credential file watcher started");
+                }
+                Err(e) => {
+                    // Non-fatal: Keychain polling still works
+                    tracing::warn!("Failed to start file watcher: {}", e);
+                }
             }
         }
 
+        // First run: the wizard signs in and finds a VPS, then connects
+        if needs_setup {
+            app.start_setup_wizard();
+            return;
+        }
+
+        // Session and crash snapshot are applied once threads arrive
+        app.pending_session = saved_session;
+        app.pending_crash_snapshot = crash_snapshot;
+
+        // Threads, folders, repos, the @ picker preload (skipped in safe
+        // mode) and the WebSocket load in the background; the CommandDeck
+        // renders right away and fills in as each one reports back
+        app.start_startup_loads(!safe_mode);
     });
 
     // Main event loop
     let result = runtime.block_on(run_app(term_manager.terminal(), &mut app));
 
-    // Before exiting, save input history
+    // Before exiting, save input history and the session to resume
     app.input_history.save();
+    app.save_session();
 
     // Restore terminal explicitly (also happens via Drop, but this shows intent)
     term_manager.restore()?;
@@ -313,7 +314,7 @@ fn main() -> Result<()> {
     result
 }
 
-async fn run_app<B: ratatui::backend::Backend>(
+async fn run_app<B: ratatui::backend::Backend + std::io::Write>(
     terminal: &mut Terminal<B>,
     app: &mut App,
 ) -> Result<()>
@@ -348,17 +349,54 @@ where
             }
         }
 
-        // Draw the UI only when needed (dirty flag or streaming)
-        if app.needs_redraw || app.is_streaming() {
+        // Draw the UI only when needed, and not while it is still being
+        // resized. Streamed tokens mark the app dirty once per frame (they are
+        // coalesced), and ticks keep the streaming spinner moving.
+        if app.needs_redraw && !app.resize_pending() {
+            // Show any tokens still coalescing in this frame
+            app.flush_stream_tokens();
             // Synchronized output (DEC mode 2026) - batch all updates atomically
             // This prevents flickering/tearing during render
+            // (skipped on terminals that don't support it)
+            let synchronized = app.terminal_capabilities.synchronized_output;
             let mut stdout = std::io::stdout();
-            let _ = execute!(stdout, BeginSynchronizedUpdate);
+            if synchronized {
+                let _ = execute!(stdout, BeginSynchronizedUpdate);
+            }
             terminal.draw(|f| {
                 ui::render(f, &mut *app);
             })?;
-            let _ = execute!(stdout, EndSynchronizedUpdate);
+            if synchronized {
+                let _ = execute!(stdout, EndSynchronizedUpdate);
+            }
             app.needs_redraw = false;
+
+            // What the panic hook saves if the app crashes before the next frame
+            app.record_crash_state();
+        }
+
+        // Clipboard copies that fell back to OSC 52 are written after the
+        // frame, through the terminal's own writer so they stay in order with it
+        if let Some(text) = app.pending_osc52_copy.take() {
+            let _ = write_osc52_clipboard(terminal.backend_mut(), &text);
+        }
+
+        // Bell + OSC 777 when something needs the user while unfocused
+        if let Some(body) = app.pending_attention.take() {
+            let _ = write_attention(&mut std::io::stdout(), "spoq", &body);
+        }
+
+        // Hand the terminal to $EDITOR for a finished answer (`/editor`).
+        // The event stream is dropped first so it doesn't read the editor's input.
+        if let Some(path) = app.pending_editor_open.take() {
+            drop(event_stream);
+            if let Err(e) = run_editor(&mut std::io::stdout(), &path, &app.terminal_capabilities) {
+                app.set_timed_error(format!("Couldn't open editor: {}", e), Duration::from_secs(4));
+            }
+            terminal.clear()?;
+            event_stream = EventStream::new();
+            app.mark_dirty();
+            continue;
         }
 
         // Poll both keyboard events and message channel using tokio::select!
@@ -371,9 +409,27 @@ where
                 // Increment tick counter for animations (spinner, cursor blink)
                 app.tick();
 
+                // Apply the terminal size once a burst of resizes has settled
+                app.apply_settled_resize();
+
+                // Insert keys held back as a possible paste once they stop coming
+                app.flush_idle_paste_burst();
+
                 // Check for thread switcher auto-confirm (Tab release simulation)
                 app.check_switcher_timeout();
 
+                // Drop permission prompts that were answered elsewhere or timed out
+                app.check_stale_permissions();
+
+                // Clear typing indicators from other clients that went quiet
+                app.check_presence_expiry();
+
+                // Refresh picker data past its TTL in the background
+                app.refresh_stale_picker_data();
+
+                // Fetch the next older page when scrolled near the top of a long thread
+                app.load_older_messages_if_needed();
+
                 // Unified picker uses local filtering now - no debounced API calls needed
             }
 
@@ -382,15 +438,17 @@ where
                 if let Some(Ok(event)) = event_result {
                     match event {
                         Event::Resize(width, height) => {
-                            // Update app state with new terminal dimensions
-                            app.update_terminal_dimensions(width, height);
-                            // Redraw will happen on next loop iteration
+                            // Applied on a tick once the size stops changing
+                            app.queue_resize(width, height);
                             continue;
                         }
                         Event::Key(key) if key.kind == KeyEventKind::Press => {
                             // Any key press likely changes state (input, navigation, etc.)
                             app.mark_dirty();
 
+                            // Any key press reveals paced stream text instantly
+                            app.reveal_all_pending();
+
                             // DEBUG: Log ALL key events
                             app.emit_debug_state_change(
                                 "KeyEvent",
@@ -401,21 +459,88 @@ where
                                 "",
                             );
 
-                            // Global keybinds (always active)
-                            match key.code {
-                                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            // Without bracketed paste, a paste arrives as a flood of
+                            // keys: hold them back and insert them as one paste
+                            if app.paste_burst_key(&key) {
+                                continue;
+                            }
+
+                            // Input history search (Ctrl+R): capture all keys while open
+                            // =========================================================
+                            if app.history_search.active {
+                                match key.code {
+                                    _ if app.keymap.action_for(&key) == Some(Action::SearchHistory) => {
+                                        app.history_search_next();
+                                    }
+                                    KeyCode::Esc => app.close_history_search(),
+                                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                        app.close_history_search();
+                                    }
+                                    KeyCode::Enter => app.accept_history_search(),
+                                    KeyCode::Up => app.history_search_prev(),
+                                    KeyCode::Down => app.history_search_next(),
+                                    KeyCode::Backspace => app.history_search_backspace(),
+                                    KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                        app.history_search_type_char(c);
+                                    }
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Link hints (Ctrl+O): digits pick a link, any other key closes them
+                            // =========================================================
+                            if app.link_hints.is_some() {
+                                app.handle_link_hint_key(key);
+                                continue;
+                            }
+
+                            // =========================================================
+                            // Setup wizard: captures all keys except Ctrl+C, unless
+                            // its VPS config dialog is open (handled below)
+                            // =========================================================
+                            if app.screen == Screen::Setup
+                                && !matches!(app.dashboard.overlay(), Some(spoq::view_state::OverlayState::VpsConfig { .. }))
+                            {
+                                match key.code {
+                                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                        app.quit();
+                                        return Ok(());
+                                    }
+                                    KeyCode::Enter => app.setup_wizard_enter(),
+                                    KeyCode::Esc => app.setup_wizard_back(),
+                                    KeyCode::Char('o') => app.open_setup_auth_url(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Global keybinds (always active), rebindable via keymap.toml
+                            let bound_action = app.keymap.action_for(&key);
+                            match bound_action {
+                                Some(Action::Interrupt) => {
+                                    // Priority 0: Ctrl+C in the quit confirmation quits anyway
+                                    if app.quit_confirm.is_some() {
+                                        app.quit();
+                                        return Ok(());
+                                    }
+
                                     // Priority 1: In Conversation view + streaming: Cancel the stream
                                     if app.screen == Screen::Conversation && app.is_streaming() {
-                                        app.cancel_active_stream();
+                                        app.request_stream_cancel();
                                         app.last_ctrl_c_time = None; // Reset exit timer
                                         app.mark_dirty();
                                         continue;
                                     }
 
-                                    // Priority 2: If textarea has text or images, clear them
-                                    if !app.textarea.is_empty() || !app.pending_images.is_empty() {
+                                    // Priority 2: If textarea has text, images or a reply reference, clear them
+                                    if !app.textarea.is_empty()
+                                        || !app.pending_images.is_empty()
+                                        || app.active_pending_reply().is_some()
+                                    {
                                         app.textarea.clear();
                                         app.pending_images.clear();
+                                        app.cancel_reply();
                                         app.last_ctrl_c_time = None; // Reset exit timer
                                         app.mark_dirty();
                                         continue;
@@ -425,9 +550,14 @@ where
                                     let now = std::time::Instant::now();
                                     if let Some(last_time) = app.last_ctrl_c_time {
                                         if now.duration_since(last_time).as_secs() < 2 {
-                                            // Second Ctrl+C within 2 seconds - exit
-                                            app.quit();
-                                            return Ok(());
+                                            // Second Ctrl+C within 2 seconds - exit, or
+                                            // confirm first if work is still in flight
+                                            app.last_ctrl_c_time = None;
+                                            app.request_quit();
+                                            if app.should_quit {
+                                                return Ok(());
+                                            }
+                                            continue;
                                         }
                                     }
                                     // First Ctrl+C or timeout expired - set timestamp
@@ -435,41 +565,71 @@ where
                                     app.mark_dirty(); // Force redraw to show warning message
                                     continue;
                                 }
-                                // Shift+Escape to return to CommandDeck from Conversation
-                                // (kept for terminals that support it)
-                                KeyCode::Esc if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                                // Return to CommandDeck from Conversation
+                                // (Shift+Escape, Ctrl+W by default)
+                                Some(Action::BackToCommandDeck) => {
                                     if app.screen == Screen::Conversation {
                                         app.navigate_to_command_deck();
                                     }
                                     continue;
                                 }
-                                // Ctrl+W to return to CommandDeck (explicit close/back binding)
-                                KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                // Create new thread (Shift+N, Ctrl+N by default)
+                                Some(Action::NewThread) => {
+                                    app.create_new_thread();
+                                    continue;
+                                }
+                                // Submit as Programming thread (from CommandDeck)
+                                Some(Action::SubmitAsProgramming) => {
+                                    if app.screen == Screen::CommandDeck && !app.textarea.is_empty() {
+                                        app.submit_input(models::ThreadType::Programming);
+                                    }
+                                    continue;
+                                }
+                                // Needs-action sidebar (Ctrl+B by default)
+                                Some(Action::ToggleSidebar) => {
                                     if app.screen == Screen::Conversation {
-                                        app.navigate_to_command_deck();
+                                        app.toggle_sidebar();
                                     }
                                     continue;
                                 }
-                                // Shift+N to create new thread
-                                KeyCode::Char('N') if key.modifiers.contains(KeyModifiers::SHIFT) => {
-                                    app.create_new_thread();
+                                // Number the links in view to open one (Ctrl+O by default)
+                                Some(Action::LinkHints) if app.screen == Screen::Conversation => {
+                                    app.start_link_hints();
                                     continue;
                                 }
-                                // CapsLock is tricky - use Ctrl+N as alternative
-                                KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                                    app.create_new_thread();
+                                // Pick the thread's working directory (Ctrl+G by default)
+                                Some(Action::ChangeDirectory) if app.screen == Screen::Conversation => {
+                                    app.open_directory_picker();
                                     continue;
                                 }
-                                // Alt+P to submit as Programming thread (from CommandDeck)
-                                KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::ALT) => {
-                                    if app.screen == Screen::CommandDeck && !app.textarea.is_empty() {
-                                        app.submit_input(models::ThreadType::Programming);
-                                    }
+                                // Drop the newest attached image (Ctrl+X by default)
+                                Some(Action::DiscardImage) if !app.pending_images.is_empty() => {
+                                    app.remove_last_image();
+                                    continue;
+                                }
+                                // Search the input history (Ctrl+R by default)
+                                Some(Action::SearchHistory) if app.open_history_search() => {
+                                    continue;
+                                }
+                                // Queue the input behind the streaming response (Alt+Q by default)
+                                Some(Action::QueuePrompt) if app.queue_input() => {
                                     continue;
                                 }
                                 _ => {}
                             }
 
+                            // Alt+1..9 opens the matching sidebar entry
+                            if app.screen == Screen::Conversation
+                                && key.modifiers.contains(KeyModifiers::ALT)
+                            {
+                                if let KeyCode::Char(c @ '1'..='9') = key.code {
+                                    let number = c.to_digit(10).unwrap_or(0) as usize;
+                                    if app.open_sidebar_entry(number) {
+                                        continue;
+                                    }
+                                }
+                            }
+
                             // =========================================================
                             // Sync Dialog Dismissal
                             // Any key press dismisses the sync dialog when complete/failed
@@ -496,6 +656,11 @@ where
                                 app.mark_dirty();
                                 continue;
                             }
+                            if app.keys_dialog_visible {
+                                app.keys_dialog_visible = false;
+                                app.mark_dirty();
+                                continue;
+                            }
 
                             // =========================================================
                             // Dashboard Question Overlay Key Handling (CommandDeck)
@@ -559,6 +724,13 @@ where
                                             app.mark_dirty();
                                             continue;
                                         }
+                                        KeyCode::Char(c) if c.is_ascii_digit() => {
+                                            // Digits pick the numbered option
+                                            let number = c.to_digit(10).unwrap_or(0) as usize;
+                                            app.dashboard.question_select_number(number);
+                                            app.mark_dirty();
+                                            continue;
+                                        }
                                         KeyCode::Enter => {
                                             if let Some((thread_id, request_id, answers)) = app.dashboard.question_confirm() {
                                                 app.submit_dashboard_question(&thread_id, &request_id, answers);
@@ -1021,9 +1193,61 @@ where
                                 }
                             }
 
-                            // Handle input routing based on the top needs-action thread type
+                            // Full tool input of a permission (modal, opened by `e` on the prompt)
+                            // =========================================================
+                            if app.permission_detail.is_some() {
+                                if app.permission_detail_request().is_none() {
+                                    // Answered elsewhere or timed out
+                                    app.close_permission_detail();
+                                } else {
+                                    match key.code {
+                                        KeyCode::Up | KeyCode::Char('k') => app.scroll_permission_detail(-1),
+                                        KeyCode::Down | KeyCode::Char('j') => app.scroll_permission_detail(1),
+                                        KeyCode::PageUp => app.scroll_permission_detail(-10),
+                                        KeyCode::PageDown => app.scroll_permission_detail(10),
+                                        KeyCode::Char(c @ ('y' | 'Y' | 'a' | 'A' | 'n' | 'N')) => {
+                                            app.answer_permission_detail(c)
+                                        }
+                                        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('e') => {
+                                            app.close_permission_detail()
+                                        }
+                                        _ => {}
+                                    }
+                                    continue;
+                                }
+                            }
+
+                            // Needs-action selection (CommandDeck, several threads waiting)
+                            // Up/Down pick the thread y/a/n act on, Enter opens it
+                            // =========================================================
+                            if app.needs_action_selectable()
+                                && app.focus == Focus::Input
+                                && app.textarea.is_empty()
+                                && app.dashboard.overlay().is_none()
+                                && key.modifiers.is_empty()
+                            {
+                                match key.code {
+                                    KeyCode::Up => {
+                                        app.move_needs_action_selection(-1);
+                                        continue;
+                                    }
+                                    KeyCode::Down => {
+                                        app.move_needs_action_selection(1);
+                                        continue;
+                                    }
+                                    KeyCode::Enter => {
+                                        if app.open_selected_needs_action_thread() {
+                                            continue;
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+
+                            // Handle input routing based on the targeted needs-action thread type
+                            // (selected on the CommandDeck, else the top one)
                             // This takes priority over all other key handling
-                            if let Some((thread_id, waiting_for)) = app.dashboard.get_top_needs_action_thread() {
+                            if let Some((thread_id, waiting_for)) = app.needs_action_target() {
                                 match waiting_for {
                                     WaitingFor::Permission { ref request_id, ref tool_name } => {
                                         // Check if this is an AskUserQuestion prompt
@@ -1075,13 +1299,24 @@ where
                                                     app.question_confirm();
                                                     continue;
                                                 }
+                                                KeyCode::Char(c) if c.is_ascii_digit() => {
+                                                    // Digits pick the numbered option
+                                                    // CommandDeck: only capture when textarea is empty
+                                                    if app.screen == Screen::Conversation || app.textarea.is_empty() {
+                                                        let number = c.to_digit(10).unwrap_or(0) as usize;
+                                                        if app.question_select_number(number) {
+                                                            continue;
+                                                        }
+                                                    }
+                                                    // Fall through to type the digit in textarea
+                                                }
                                                 KeyCode::Char('n') | KeyCode::Char('N') => {
                                                     // Conversation: textarea is hidden, so always capture
                                                     // CommandDeck: only capture when textarea is empty
                                                     if app.screen == Screen::Conversation || app.textarea.is_empty() {
-                                                        let permission_id = app.dashboard.pending_permissions_iter()
-                                                            .find(|(_, p)| p.tool_name == "AskUserQuestion")
-                                                            .map(|(_, p)| p.permission_id.clone());
+                                                        let permission_id = app.dashboard.get_pending_permission(&thread_id)
+                                                            .filter(|p| p.tool_name == "AskUserQuestion")
+                                                            .map(|p| p.permission_id.clone());
                                                         if let Some(pid) = permission_id {
                                                             app.deny_permission(&pid);
                                                             continue;
@@ -1089,10 +1324,13 @@ where
                                                     }
                                                     // Fall through to type 'n' in textarea
                                                 }
-                                                KeyCode::Char('a') | KeyCode::Char('A') => {
+                                                KeyCode::Char(c @ ('a' | 'A')) => {
                                                     // Conversation: textarea is hidden, so always capture
                                                     // CommandDeck: only capture when textarea is empty
-                                                    if app.screen == Screen::Conversation || app.textarea.is_empty() {
+                                                    // ('a' on the Threads panel archives instead)
+                                                    if (app.screen == Screen::Conversation || app.textarea.is_empty())
+                                                        && !app.is_threads_panel_archive_key(c)
+                                                    {
                                                         if app.open_ask_user_question_dialog() {
                                                             tracing::debug!("Opened AskUserQuestion dialog via 'A' key");
                                                             continue;
@@ -1108,8 +1346,11 @@ where
                                             // Standard permission prompt (y/a/n)
                                             // Conversation: textarea is hidden, so always capture
                                             // CommandDeck: only capture when textarea is empty
+                                            // ('a' on the Threads panel archives instead)
                                             if let KeyCode::Char(c) = key.code {
-                                                if app.screen == Screen::Conversation || app.textarea.is_empty() {
+                                                if (app.screen == Screen::Conversation || app.textarea.is_empty())
+                                                    && !app.is_threads_panel_archive_key(c)
+                                                {
                                                     // Debug: emit key press to debug system
                                                     app.emit_debug_state_change(
                                                         "permission_key",
@@ -1155,10 +1396,23 @@ where
                                                 app.question_confirm();
                                                 continue;
                                             }
-                                            KeyCode::Char('a') | KeyCode::Char('A') => {
+                                            KeyCode::Char(c) if c.is_ascii_digit() && !app.question_state.other_active => {
+                                                // Digits pick the numbered option ("Other" text keeps them)
+                                                if app.screen == Screen::Conversation || app.textarea.is_empty() {
+                                                    let number = c.to_digit(10).unwrap_or(0) as usize;
+                                                    if app.question_select_number(number) {
+                                                        continue;
+                                                    }
+                                                }
+                                                // Fall through to type the digit in textarea
+                                            }
+                                            KeyCode::Char(c @ ('a' | 'A')) => {
                                                 // [a] Answer: open question dialog when textarea is empty
                                                 // Same pattern as other permission events in the command deck
-                                                if app.screen == Screen::Conversation || app.textarea.is_empty() {
+                                                // ('a' on the Threads panel archives instead)
+                                                if (app.screen == Screen::Conversation || app.textarea.is_empty())
+                                                    && !app.is_threads_panel_archive_key(c)
+                                                {
                                                     if app.open_ask_user_question_dialog() {
                                                         continue;
                                                     }
@@ -1321,6 +1575,19 @@ where
                                         }
                                         continue;
                                     }
+                                    KeyCode::Char(' ')
+                                        if SlashCommand::parse(&app.slash_autocomplete_query)
+                                            .is_some_and(|cmd| cmd.takes_argument()) =>
+                                    {
+                                        // Command takes an argument (`/rename New title`):
+                                        // close the dropdown and keep typing into the input
+                                        app.textarea.insert_char(' ');
+                                        app.slash_autocomplete_visible = false;
+                                        app.slash_autocomplete_query.clear();
+                                        app.slash_autocomplete_cursor = 0;
+                                        app.mark_dirty();
+                                        continue;
+                                    }
                                     KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER) => {
                                         // Append character to query
                                         app.slash_autocomplete_query.push(c);
@@ -1348,6 +1615,10 @@ where
 
                             // Unified @ Picker Key Handling (when visible)
                             // =========================================================
+                            if app.unified_picker.visible && app.unified_picker.changing_directory {
+                                app.handle_directory_picker_key(key);
+                                continue;
+                            }
                             if app.unified_picker.visible {
                                 match key.code {
                                     KeyCode::Esc => {
@@ -1359,7 +1630,11 @@ where
                                     }
                                     KeyCode::Char(' ') => {
                                         // Space = "I want to type a message first"
-                                        // Show selection in input and save for later submission
+                                        // Folders become chips (more can be mentioned)
+                                        if app.unified_picker_attach_selected() {
+                                            continue;
+                                        }
+                                        // Show other selections in input and save for later submission
                                         if let Some(item) = app.unified_picker.selected_item().cloned() {
                                             let display_name = item.display_name();
 
@@ -1378,6 +1653,10 @@ where
                                         continue;
                                     }
                                     KeyCode::Enter => {
+                                        // Browsing directories: Enter opens a subdirectory
+                                        if app.unified_picker_descend() {
+                                            continue;
+                                        }
                                         let action = app.unified_picker_submit();
                                         app.mark_dirty();
 
@@ -1386,7 +1665,11 @@ where
                                                 // Nothing selected - do nothing
                                             }
                                             UnifiedPickerAction::MessageRequired => {
-                                                // No message - save pending selection, show selection in input, add space
+                                                // No message - attach folders as chips, so more can be mentioned
+                                                if app.unified_picker_attach_selected() {
+                                                    continue;
+                                                }
+                                                // Otherwise save pending selection, show selection in input, add space
                                                 if let Some(item) = app.unified_picker.selected_item().cloned() {
                                                     // Get the display name for the selected item
                                                     let display_name = item.display_name();
@@ -1408,9 +1691,9 @@ where
                                                 // Clear pending selection on success
                                                 app.unified_picker.clear_pending_selection();
 
-                                                // Set working directory
+                                                // Attach working directory (next to any already attached)
                                                 let folder = models::Folder { name, path };
-                                                app.selected_folder = Some(folder);
+                                                app.attach_folder(folder);
 
                                                 // Clear textarea and set the message
                                                 app.textarea.clear();
@@ -1464,7 +1747,10 @@ where
                                         continue;
                                     }
                                     KeyCode::Backspace => {
-                                        if app.unified_picker.query.is_empty() {
+                                        if app.unified_picker_ascend() {
+                                            // Browsing directories: go up instead of closing
+                                            app.mark_dirty();
+                                        } else if app.unified_picker.query.is_empty() {
                                             // Query is empty, close picker and remove @
                                             app.textarea.backspace(); // Remove the @
                                             app.close_unified_picker();
@@ -1487,6 +1773,14 @@ where
                                         app.mark_dirty();
                                         continue;
                                     }
+                                    KeyCode::F(5) => {
+                                        app.refresh_picker_data();
+                                        continue;
+                                    }
+                                    KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                        app.refresh_picker_data();
+                                        continue;
+                                    }
                                     KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER) => {
                                         // Type char - filters locally (instant)
                                         app.unified_picker_type_char(c);
@@ -1500,6 +1794,238 @@ where
                                 }
                             }
 
+                            // Thread deletion confirmation (modal, any screen)
+                            // =========================================================
+                            if app.thread_delete_confirm.is_some() {
+                                match key.code {
+                                    KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                                        app.confirm_thread_delete();
+                                    }
+                                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                                        app.cancel_thread_delete();
+                                    }
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Thread archive confirmation (modal, `a` on the Threads panel)
+                            // =========================================================
+                            if app.thread_archive_confirm.is_some() {
+                                match key.code {
+                                    KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                                        app.confirm_thread_archive();
+                                    }
+                                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                                        app.cancel_thread_archive();
+                                    }
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Cancel-with-queued-prompts confirmation (modal, opened by Ctrl+C)
+                            // =========================================================
+                            if app.queue_cancel_confirm.is_some() {
+                                match key.code {
+                                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                                        app.confirm_queue_cancel(true);
+                                    }
+                                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Enter => {
+                                        app.confirm_queue_cancel(false);
+                                    }
+                                    KeyCode::Esc => app.dismiss_queue_cancel(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Thread link editor (modal, opened by /link)
+                            // =========================================================
+                            if app.link_editor.is_some() {
+                                match key.code {
+                                    KeyCode::Up | KeyCode::Char('k') => app.link_editor_move(-1),
+                                    KeyCode::Down | KeyCode::Char('j') => app.link_editor_move(1),
+                                    KeyCode::Tab => app.link_editor_cycle_kind(),
+                                    KeyCode::Enter | KeyCode::Char(' ') => app.toggle_selected_link(),
+                                    KeyCode::Esc => app.close_link_editor(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Thread share dialog (modal, opened by /share or `s`)
+                            // =========================================================
+                            if app.share_dialog.is_some() {
+                                app.handle_share_dialog_key(key);
+                                continue;
+                            }
+
+                            // Inline thread title editor (modal, opened by /rename or `r`)
+                            // =========================================================
+                            if app.thread_rename.is_some() {
+                                match key.code {
+                                    KeyCode::Enter => app.confirm_thread_rename(),
+                                    KeyCode::Esc => app.close_thread_rename(),
+                                    KeyCode::Backspace => app.thread_rename_backspace(),
+                                    KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER) => {
+                                        app.thread_rename_type_char(c)
+                                    }
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Notifications panel (modal, opened by /notifications)
+                            // =========================================================
+                            if app.notifications_panel.is_some() {
+                                match key.code {
+                                    KeyCode::Up | KeyCode::Char('k') => app.notifications_panel_move(-1),
+                                    KeyCode::Down | KeyCode::Char('j') => app.notifications_panel_move(1),
+                                    KeyCode::Enter => app.open_selected_notification(),
+                                    KeyCode::Char('d') => app.dismiss_selected_notification(),
+                                    KeyCode::Char('D') => app.clear_all_notifications(),
+                                    KeyCode::Esc => app.close_notifications_panel(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Raw event log (modal, opened by /events)
+                            // =========================================================
+                            if app.event_log_view.is_some() {
+                                match key.code {
+                                    KeyCode::Up | KeyCode::Char('k') => app.scroll_event_log(-1),
+                                    KeyCode::Down | KeyCode::Char('j') => app.scroll_event_log(1),
+                                    KeyCode::PageUp => app.scroll_event_log(-10),
+                                    KeyCode::PageDown => app.scroll_event_log(10),
+                                    KeyCode::Esc | KeyCode::Char('q') => app.close_event_log(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Log viewer (modal, opened by /log)
+                            // =========================================================
+                            if app.log_view.is_some() {
+                                use spoq::logging::LogLevel;
+                                match key.code {
+                                    KeyCode::Up | KeyCode::Char('k') => app.scroll_log_view(-1),
+                                    KeyCode::Down | KeyCode::Char('j') => app.scroll_log_view(1),
+                                    KeyCode::PageUp => app.scroll_log_view(-10),
+                                    KeyCode::PageDown => app.scroll_log_view(10),
+                                    KeyCode::Char('e') => app.set_log_view_level(LogLevel::Error),
+                                    KeyCode::Char('w') => app.set_log_view_level(LogLevel::Warn),
+                                    KeyCode::Char('i') => app.set_log_view_level(LogLevel::Info),
+                                    KeyCode::Char('d') => app.set_log_view_level(LogLevel::Debug),
+                                    KeyCode::Char('f') => app.toggle_log_follow(),
+                                    KeyCode::Esc | KeyCode::Char('q') => app.close_log_view(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Token usage (modal, opened by /usage)
+                            // =========================================================
+                            if app.usage_view.is_some() {
+                                match key.code {
+                                    KeyCode::Up | KeyCode::Char('k') => app.scroll_usage_view(-1),
+                                    KeyCode::Down | KeyCode::Char('j') => app.scroll_usage_view(1),
+                                    KeyCode::PageUp => app.scroll_usage_view(-10),
+                                    KeyCode::PageDown => app.scroll_usage_view(10),
+                                    KeyCode::Esc | KeyCode::Char('q') => app.close_usage_view(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Connection diagnostics (modal, opened by /status)
+                            // =========================================================
+                            if app.status_view.is_some() {
+                                match key.code {
+                                    KeyCode::Char('c') => app.copy_status_report(),
+                                    KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                                        app.close_status_view()
+                                    }
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Execute mode confirmation (modal, Shift+Tab into bypass)
+                            // =========================================================
+                            if app.bypass_confirm.is_some() {
+                                match key.code {
+                                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                                        app.confirm_bypass()
+                                    }
+                                    _ => app.skip_bypass(),
+                                }
+                                continue;
+                            }
+
+                            // Quit confirmation (modal, work still in flight)
+                            // =========================================================
+                            if app.quit_confirm.is_some() {
+                                match key.code {
+                                    KeyCode::Char('q') | KeyCode::Char('y') => {
+                                        app.quit();
+                                        return Ok(());
+                                    }
+                                    KeyCode::Char('w') => {
+                                        app.quit_when_streams_finish();
+                                        if app.should_quit {
+                                            return Ok(());
+                                        }
+                                    }
+                                    KeyCode::Char('j') | KeyCode::Enter => app.jump_to_busy_thread(),
+                                    KeyCode::Esc | KeyCode::Char('n') => app.cancel_quit(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Thread metrics pane (modal, opened by /metrics)
+                            // =========================================================
+                            if app.thread_metrics_view.is_some() {
+                                if matches!(
+                                    key.code,
+                                    KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')
+                                ) {
+                                    app.close_thread_metrics();
+                                }
+                                continue;
+                            }
+
+                            // "What's new" overlay (modal, shown once after an update)
+                            // =========================================================
+                            if app.whats_new.is_some() {
+                                match key.code {
+                                    KeyCode::Up | KeyCode::Char('k') => app.scroll_whats_new(-1),
+                                    KeyCode::Down | KeyCode::Char('j') => app.scroll_whats_new(1),
+                                    KeyCode::PageUp => app.scroll_whats_new(-10),
+                                    KeyCode::PageDown => app.scroll_whats_new(10),
+                                    KeyCode::Char('o') => app.open_release_notes(),
+                                    KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                                        app.close_whats_new()
+                                    }
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
+                            // Model picker handling (modal)
+                            if app.model_picker.is_some() {
+                                match key.code {
+                                    KeyCode::Up | KeyCode::Char('k') => app.model_picker_move(-1),
+                                    KeyCode::Down | KeyCode::Char('j') => app.model_picker_move(1),
+                                    KeyCode::Enter => app.confirm_model_selection(),
+                                    KeyCode::Esc => app.close_model_picker(),
+                                    _ => {}
+                                }
+                                continue;
+                            }
+
                             // Thread switcher handling (takes priority when visible)
                             if app.thread_switcher.visible {
                                 match key.code {
@@ -1519,6 +2045,20 @@ where
                                         app.confirm_switcher_selection();
                                         continue;
                                     }
+                                    KeyCode::Backspace => {
+                                        app.switcher_backspace();
+                                        continue;
+                                    }
+                                    // Typing filters the list (and stops auto-confirm)
+                                    KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER) => {
+                                        let c = if key.modifiers.contains(KeyModifiers::SHIFT) && !c.is_uppercase() {
+                                            translate_shifted_char(c)
+                                        } else {
+                                            c
+                                        };
+                                        app.switcher_type_char(c);
+                                        continue;
+                                    }
                                     _ => {
                                         // Any other key closes and confirms
                                         app.confirm_switcher_selection();
@@ -1559,6 +2099,15 @@ where
                                         app.browse_list_move_down();
                                         continue;
                                     }
+                                    KeyCode::Char('d') | KeyCode::Char('x')
+                                        if key.modifiers.is_empty()
+                                            && app.browse_list.search_query.is_empty()
+                                            && app.browse_list.mode == spoq::app::BrowseListMode::Threads =>
+                                    {
+                                        // Delete selected thread (only when not searching)
+                                        app.request_browse_list_thread_delete();
+                                        continue;
+                                    }
                                     KeyCode::Enter => {
                                         // Select the current item
                                         let action = app.browse_list_select();
@@ -1569,9 +2118,9 @@ where
                                                 app.open_thread(id); // This handles all the navigation and message loading
                                             }
                                             BrowseListSelectAction::SetWorkingDirectory { path, name } => {
-                                                // Set working directory and go to CommandDeck
+                                                // Attach working directory and go to CommandDeck
                                                 app.close_browse_list();
-                                                app.selected_folder = Some(models::Folder {
+                                                app.attach_folder(models::Folder {
                                                     name,
                                                     path,
                                                 });
@@ -1606,50 +2155,55 @@ where
                                         }
                                         continue;
                                     }
-                                    KeyCode::Backspace => {
-                                        // Remove last character from search
-                                        match app.browse_list.mode {
-                                            spoq::app::BrowseListMode::Threads => {
-                                                // Threads: debounced API search
-                                                if let Some(query) = app.browse_list_backspace() {
-                                                    let message_tx = app.message_tx.clone();
-                                                    tokio::spawn(async move {
-                                                        tokio::time::sleep(std::time::Duration::from_millis(
-                                                            spoq::ui::SEARCH_DEBOUNCE_MS,
-                                                        ))
-                                                        .await;
-                                                        let _ = message_tx
-                                                            .send(AppMessage::BrowseListSearchDebounced { query });
-                                                    });
-                                                }
-                                            }
-                                            spoq::app::BrowseListMode::Repos => {
-                                                // Repos: instant local filter
-                                                app.browse_list_repos_backspace();
-                                            }
-                                        }
+                                    KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                        // Cycle threads -> repos -> both
+                                        app.browse_list_cycle_mode();
                                         continue;
                                     }
-                                    KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER) => {
-                                        // Auto-search: any printable character adds to search
-                                        match app.browse_list.mode {
-                                            spoq::app::BrowseListMode::Threads => {
-                                                // Threads: debounced API search
-                                                let query = app.browse_list_type_char(c);
-                                                let message_tx = app.message_tx.clone();
-                                                tokio::spawn(async move {
-                                                    tokio::time::sleep(std::time::Duration::from_millis(
-                                                        spoq::ui::SEARCH_DEBOUNCE_MS,
-                                                    ))
-                                                    .await;
-                                                    let _ = message_tx
-                                                        .send(AppMessage::BrowseListSearchDebounced { query });
-                                                });
+                                    KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                        // Cycle all -> active -> archived threads
+                                        app.cycle_browse_list_archive_filter();
+                                        continue;
+                                    }
+                                    KeyCode::Backspace | KeyCode::Char(_) => {
+                                        let query = match key.code {
+                                            KeyCode::Backspace => {
+                                                // Remove last character from search
+                                                match app.browse_list.mode {
+                                                    // Threads: debounced API search
+                                                    spoq::app::BrowseListMode::Threads => app.browse_list_backspace(),
+                                                    spoq::app::BrowseListMode::Repos => {
+                                                        // Repos: instant local filter
+                                                        app.browse_list_repos_backspace();
+                                                        None
+                                                    }
+                                                    // Both: local repo filter now, thread search debounced
+                                                    spoq::app::BrowseListMode::All => app.browse_list_combined_backspace(),
+                                                }
                                             }
-                                            spoq::app::BrowseListMode::Repos => {
-                                                // Repos: instant local filter
-                                                app.browse_list_repos_type_char(c);
+                                            KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER) => {
+                                                // Auto-search: any printable character adds to search
+                                                match app.browse_list.mode {
+                                                    spoq::app::BrowseListMode::Threads => Some(app.browse_list_type_char(c)),
+                                                    spoq::app::BrowseListMode::Repos => {
+                                                        app.browse_list_repos_type_char(c);
+                                                        None
+                                                    }
+                                                    spoq::app::BrowseListMode::All => Some(app.browse_list_combined_type_char(c)),
+                                                }
                                             }
+                                            _ => None,
+                                        };
+                                        if let Some(query) = query {
+                                            let message_tx = app.message_tx.clone();
+                                            tokio::spawn(async move {
+                                                tokio::time::sleep(std::time::Duration::from_millis(
+                                                    spoq::ui::SEARCH_DEBOUNCE_MS,
+                                                ))
+                                                .await;
+                                                let _ = message_tx
+                                                    .send(AppMessage::BrowseListSearchDebounced { query });
+                                            });
                                         }
                                         continue;
                                     }
@@ -1696,6 +2250,188 @@ where
                                 }
                             }
 
+                            // Message Search Key Handling (Conversation screen)
+                            // =========================================================
+                            if app.screen == Screen::Conversation {
+                                if app.message_search.editing {
+                                    // Typing the query: capture all keys
+                                    match key.code {
+                                        KeyCode::Esc => app.close_message_search(),
+                                        KeyCode::Enter => app.confirm_message_search(),
+                                        KeyCode::Backspace => app.message_search_backspace(),
+                                        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                            app.message_search_type_char(c);
+                                        }
+                                        _ => {}
+                                    }
+                                    continue;
+                                }
+
+                                if app.message_search.active {
+                                    // Query confirmed: n/N navigate, other keys fall through (scrolling etc.)
+                                    match key.code {
+                                        KeyCode::Esc => {
+                                            app.close_message_search();
+                                            continue;
+                                        }
+                                        KeyCode::Char('n') => {
+                                            app.message_search_next();
+                                            continue;
+                                        }
+                                        KeyCode::Char('N') => {
+                                            app.message_search_prev();
+                                            continue;
+                                        }
+                                        KeyCode::Char('/') => {
+                                            app.open_message_search();
+                                            continue;
+                                        }
+                                        _ => {}
+                                    }
+                                } else if key.code == KeyCode::Char('/')
+                                    && app.focus != Focus::Input
+                                    && !key.modifiers.contains(KeyModifiers::CONTROL)
+                                    && app.open_message_search()
+                                {
+                                    continue;
+                                }
+                            }
+
+                            // Unarchive the open thread (U in an empty input, as its banner says)
+                            // =========================================================
+                            if app.screen == Screen::Conversation
+                                && app.textarea.is_empty()
+                                && key.code == KeyCode::Char('U')
+                                && !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                                && app.unarchive_active_thread()
+                            {
+                                continue;
+                            }
+
+                            // Vim-style navigate mode (vim_navigation, Esc from an empty input)
+                            // j/k, Ctrl+d/Ctrl+u, gg/G and {/} scroll; i or typing returns to input
+                            // =========================================================
+                            if app.is_navigating() && app.focus != Focus::Input && app.handle_navigate_key(key) {
+                                continue;
+                            }
+
+                            // Expanded follow-up list (input not focused), before
+                            // auto-focus below would turn its keys into typing
+                            // =========================================================
+                            if app.handle_followup_key(key.code) {
+                                continue;
+                            }
+
+                            // Rebindable conversation actions (input not focused), before
+                            // auto-focus below would turn their keys into typing
+                            // =========================================================
+                            if let Some(action) = bound_action {
+                                if app.handle_panel_action(action) {
+                                    continue;
+                                }
+                            }
+
+                            // Conversation actions (input not focused)
+                            // y = copy last/focused assistant message, Y = its code blocks,
+                            // r = reply to the focused search match, or retry the last
+                            //     message after a stream error,
+                            // g/Home = jump to top, G/End = jump to bottom,
+                            // 1-9 = open the thread behind a header link chip
+                            // =========================================================
+                            if app.screen == Screen::Conversation
+                                && app.focus != Focus::Input
+                                && !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                            {
+                                match key.code {
+                                    KeyCode::Char('y') => {
+                                        app.copy_assistant_message();
+                                        continue;
+                                    }
+                                    KeyCode::Char('Y') => {
+                                        app.copy_assistant_code_blocks();
+                                        continue;
+                                    }
+                                    KeyCode::Char('r') => {
+                                        if !app.reply_to_focused_message() {
+                                            app.retry_last_message();
+                                        }
+                                        continue;
+                                    }
+                                    KeyCode::Char('g') | KeyCode::Home => {
+                                        app.scroll_to_top();
+                                        continue;
+                                    }
+                                    KeyCode::Char('G') | KeyCode::End => {
+                                        app.scroll_to_bottom();
+                                        continue;
+                                    }
+                                    KeyCode::Char(c @ '1'..='9') => {
+                                        if app.follow_thread_link(c as usize - '1' as usize) {
+                                            continue;
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+
+                            // Thread deletion from the Threads panel (CommandDeck)
+                            // =========================================================
+                            if app.screen == Screen::CommandDeck
+                                && app.focus == Focus::Threads
+                                && app.textarea.is_empty()
+                                && key.modifiers.is_empty()
+                                && matches!(key.code, KeyCode::Char('d') | KeyCode::Char('x'))
+                                && app.request_selected_thread_delete()
+                            {
+                                continue;
+                            }
+
+                            // Thread archiving from the Threads panel (CommandDeck)
+                            // =========================================================
+                            // (the needs-action prompt routing above leaves this `a` to it)
+                            if key.modifiers.is_empty()
+                                && key.code == KeyCode::Char('a')
+                                && app.is_threads_panel_archive_key('a')
+                                && app.request_selected_thread_archive()
+                            {
+                                continue;
+                            }
+
+                            // Thread pinning, renaming, sharing and muting from the Threads panel (CommandDeck)
+                            // =========================================================
+                            if app.screen == Screen::CommandDeck
+                                && app.focus == Focus::Threads
+                                && app.textarea.is_empty()
+                            {
+                                match key.code {
+                                    KeyCode::Char('p') if key.modifiers.is_empty() => {
+                                        app.toggle_selected_thread_pin();
+                                        continue;
+                                    }
+                                    KeyCode::Char('r') if key.modifiers.is_empty() => {
+                                        app.open_selected_thread_rename();
+                                        continue;
+                                    }
+                                    KeyCode::Char('s') if key.modifiers.is_empty() => {
+                                        app.open_selected_thread_share();
+                                        continue;
+                                    }
+                                    KeyCode::Char('m') if key.modifiers.is_empty() => {
+                                        app.toggle_selected_thread_mute();
+                                        continue;
+                                    }
+                                    KeyCode::Up if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                                        app.move_selected_pinned_thread(-1);
+                                        continue;
+                                    }
+                                    KeyCode::Down if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                                        app.move_selected_pinned_thread(1);
+                                        continue;
+                                    }
+                                    _ => {}
+                                }
+                            }
+
                             // Auto-focus to Input when user starts typing
                             // (printable characters only, not Ctrl combinations)
                             if let KeyCode::Char(_) = key.code {
@@ -1707,17 +2443,8 @@ where
 
                             // Handle input-specific keys when Input is focused
                             if app.focus == Focus::Input {
-                                // Check for Shift+Escape FIRST (before plain Escape)
-                                // This ensures Shift+Escape goes back to CommandDeck even when typing
-                                if key.code == KeyCode::Esc && key.modifiers.contains(KeyModifiers::SHIFT) {
-                                    if app.screen == Screen::Conversation {
-                                        app.navigate_to_command_deck();
-                                    }
-                                    continue;
-                                }
-
-                                // Shift+Tab cycles permission mode (works while typing, all threads)
-                                if key.code == KeyCode::BackTab {
+                                // Cycle permission mode (Shift+Tab by default; works while typing, all threads)
+                                if bound_action == Some(Action::CyclePermissionMode) {
                                     if app.screen == Screen::Conversation || app.screen == Screen::CommandDeck {
                                         app.cycle_permission_mode();
                                     }
@@ -1776,6 +2503,22 @@ where
                                         app.reset_cursor_blink();
                                         continue;
                                     }
+                                    // Ctrl+Z = undo, Ctrl+Y / Ctrl+Shift+Z = redo
+                                    // (raw mode, so Ctrl+Z doesn't suspend)
+                                    KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                        app.textarea.redo();
+                                        app.reset_cursor_blink();
+                                        continue;
+                                    }
+                                    KeyCode::Char('z') | KeyCode::Char('Z') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                        if key.modifiers.contains(KeyModifiers::SHIFT) || key.code == KeyCode::Char('Z') {
+                                            app.textarea.redo();
+                                        } else {
+                                            app.textarea.undo();
+                                        }
+                                        app.reset_cursor_blink();
+                                        continue;
+                                    }
                                     // Ctrl+J = ASCII LF (newline) - works in ALL terminals
                                     // MUST come before plain Char(c) handler
                                     KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -1815,9 +2558,7 @@ where
                                                         if app.should_summarize_paste(&text) {
                                                             app.textarea.insert_paste_token(text);
                                                         } else {
-                                                            for ch in text.chars() {
-                                                                app.textarea.insert_char(ch);
-                                                            }
+                                                            app.textarea.insert_text(&text);
                                                         }
                                                         app.reset_cursor_blink();
                                                     }
@@ -1885,12 +2626,15 @@ where
                                         // Normal character insertion
                                         app.textarea.insert_char(char_to_insert);
                                         app.reset_cursor_blink();
+                                        app.note_local_typing();
                                         continue;
                                     }
                                     KeyCode::Backspace => {
-                                        // Check if we should clear the folder chip instead of backspace
-                                        if app.should_clear_folder_on_backspace() {
-                                            app.clear_folder();
+                                        // Check if we should remove a folder chip instead of backspace
+                                        if let Some(index) = app.folder_chip_at_cursor() {
+                                            app.remove_folder(index);
+                                        } else if app.should_remove_image_on_backspace() {
+                                            app.remove_last_image();
                                         } else {
                                             app.textarea.backspace();
                                             app.reset_cursor_blink();
@@ -1913,8 +2657,8 @@ where
                                         continue;
                                     }
                                     KeyCode::Up => {
-                                        // If cursor is on first line, try to navigate history up
-                                        if app.textarea.is_cursor_on_first_line() {
+                                        // If cursor is on the top row, try to navigate history up
+                                        if app.textarea.is_cursor_on_first_visual_row() {
                                             let current_content = app.textarea.content();
                                             if let Some(history_entry) = app.input_history.navigate_up(&current_content) {
                                                 let entry = history_entry.to_string();
@@ -1929,8 +2673,8 @@ where
                                         continue;
                                     }
                                     KeyCode::Down => {
-                                        // If cursor is on last line and navigating history, go forward
-                                        if app.textarea.is_cursor_on_last_line() {
+                                        // If cursor is on the bottom row and navigating history, go forward
+                                        if app.textarea.is_cursor_on_last_visual_row() {
                                             // Only handle history navigation if we're currently navigating
                                             if app.input_history.is_navigating() {
                                                 if let Some(history_entry) = app.input_history.navigate_down() {
@@ -2004,9 +2748,9 @@ where
 
                                                 match pending {
                                                     PickerItem::Folder { path, name } | PickerItem::Repo { local_path: Some(path), name, .. } => {
-                                                        // Set working directory and submit
+                                                        // Attach working directory and submit
                                                         let folder = models::Folder { name, path };
-                                                        app.selected_folder = Some(folder);
+                                                        app.attach_folder(folder);
                                                         app.textarea.clear();
                                                         app.textarea.set_content(&message);
                                                         app.submit_input(models::ThreadType::Programming);
@@ -2051,16 +2795,25 @@ where
                                             }
                                         }
 
-                                        // No pending selection - plain Enter = Conversation thread
-                                        app.submit_input(models::ThreadType::Conversation);
+                                        // No pending selection - plain Enter = Conversation thread,
+                                        // unless folders are attached
+                                        let thread_type = if app.selected_folders.is_empty() {
+                                            models::ThreadType::Conversation
+                                        } else {
+                                            models::ThreadType::Programming
+                                        };
+                                        app.submit_input(thread_type);
                                         continue;
                                     }
                                     KeyCode::Esc => {
                                         // Plain Escape (no Shift) - depends on input state and screen
                                         if app.screen == Screen::Conversation {
                                             if app.textarea.is_empty() {
-                                                // Empty input: go back to CommandDeck
-                                                app.navigate_to_command_deck();
+                                                // Empty input: navigate mode when vim navigation
+                                                // is on, else go back to CommandDeck
+                                                if !app.enter_navigate_mode() {
+                                                    app.navigate_to_command_deck();
+                                                }
                                             } else {
                                                 // Has content: just unfocus to allow navigation
                                                 app.focus = Focus::Threads;
@@ -2075,18 +2828,48 @@ where
                                 }
                             }
 
+                            // Rebindable panel actions (when not typing in input)
+                            match bound_action {
+                                Some(Action::CyclePermissionMode) => {
+                                    // Conversation/CommandDeck screens: cycle permission mode (all threads)
+                                    if app.screen == Screen::Conversation || app.screen == Screen::CommandDeck {
+                                        app.cycle_permission_mode();
+                                    }
+                                    continue;
+                                }
+                                Some(Action::Quit) if app.focus != Focus::Input => {
+                                    app.request_quit();
+                                    if app.should_quit {
+                                        return Ok(());
+                                    }
+                                    continue;
+                                }
+                                // Dismiss focused error in Conversation screen
+                                Some(Action::DismissError) if app.focus != Focus::Input && app.screen == Screen::Conversation => {
+                                    if app.has_errors() {
+                                        app.dismiss_focused_error();
+                                    }
+                                    continue;
+                                }
+                                // Toggle thinking/reasoning block in Conversation screen
+                                Some(Action::ToggleReasoning) if app.focus != Focus::Input && app.screen == Screen::Conversation => {
+                                    app.toggle_reasoning();
+                                    continue;
+                                }
+                                // Open first user input question dialog in dashboard view
+                                Some(Action::OpenQuestion) if app.focus != Focus::Input && app.screen == Screen::CommandDeck => {
+                                    app.open_ask_user_question_dialog();
+                                    continue;
+                                }
+                                _ => {}
+                            }
+
                             // Panel navigation (when not typing in input)
                             match key.code {
                                 KeyCode::Tab => {
                                     // Double-tap Tab opens thread switcher
                                     app.handle_tab_press();
                                 }
-                                KeyCode::BackTab => {
-                                    // Shift+Tab in Conversation/CommandDeck screens: cycle permission mode (all threads)
-                                    if app.screen == Screen::Conversation || app.screen == Screen::CommandDeck {
-                                        app.cycle_permission_mode();
-                                    }
-                                }
                                 KeyCode::Esc if app.focus != Focus::Input => {
                                     // Escape when not in input: go back to CommandDeck
                                     if app.screen == Screen::Conversation {
@@ -2145,30 +2928,12 @@ where
                                     let max_threads = app.cache.threads().len();
                                     app.move_down(max_threads);
                                 }
-                                KeyCode::Char('q') if app.focus != Focus::Input => {
-                                    app.quit();
-                                    return Ok(());
-                                }
-                                // 'd' to dismiss focused error in Conversation screen
-                                KeyCode::Char('d') if app.focus != Focus::Input && app.screen == Screen::Conversation => {
-                                    if app.has_errors() {
-                                        app.dismiss_focused_error();
-                                    }
-                                }
-                                // 't' to toggle thinking/reasoning block in Conversation screen
-                                KeyCode::Char('t') if app.focus != Focus::Input && app.screen == Screen::Conversation => {
-                                    app.toggle_reasoning();
-                                }
-                                // 'A' to open first user input question dialog in dashboard view
-                                KeyCode::Char('a') | KeyCode::Char('A') if app.focus != Focus::Input && app.screen == Screen::CommandDeck => {
-                                    app.open_ask_user_question_dialog();
-                                }
                                 // Note: Custom mouse selection removed - native terminal selection now handles copy
                                 _ => {}
                             }
                         }
                         Event::Mouse(mouse_event) => {
-                            // Handle mouse events for scroll only (click/hover system removed)
+                            // Handle mouse events for scroll (and sidebar clicks)
                             match mouse_event.kind {
                                 // Momentum-based scrolling for smooth feel
                                 // Each scroll event adds velocity, momentum system handles animation
@@ -2179,6 +2944,9 @@ where
                                         }
                                         app.user_has_scrolled = app.unified_scroll > 0;
                                         app.scroll_changed = true;
+                                    } else if app.screen == Screen::CommandDeck {
+                                        // Scroll whichever thread list section is under the cursor
+                                        app.scroll_thread_list(mouse_event.column, mouse_event.row, 1);
                                     }
                                 }
                                 MouseEventKind::ScrollUp => {
@@ -2186,6 +2954,14 @@ where
                                         app.unified_scroll = (app.unified_scroll + 1).min(app.max_scroll);
                                         app.user_has_scrolled = true;
                                         app.scroll_changed = true;
+                                    } else if app.screen == Screen::CommandDeck {
+                                        app.scroll_thread_list(mouse_event.column, mouse_event.row, -1);
+                                    }
+                                }
+                                // Clicking a needs-action sidebar entry opens that thread
+                                MouseEventKind::Down(MouseButton::Left) => {
+                                    if app.screen == Screen::Conversation {
+                                        app.click_sidebar(mouse_event.column, mouse_event.row);
                                     }
                                 }
                                 // Ignore other mouse events (right click, drag, etc.)
@@ -2238,30 +3014,14 @@ where
 
                             // Process text content (existing logic)
                             if !text.is_empty() {
-                                if app.should_summarize_paste(&text) {
-                                    // Insert as atomic token
-                                    app.textarea.insert_paste_token(text);
-                                    app.reset_cursor_blink();
-                                } else {
-                                    // Insert normally character by character
-                                    for ch in text.chars() {
-                                        app.textarea.insert_char(ch);
-                                    }
-                                    app.reset_cursor_blink();
-                                }
+                                app.insert_pasted_text(text);
                             }
 
                             app.mark_dirty();
                             continue;
                         }
-                        Event::FocusGained => {
-                            app.focus_supported = true;
-                            app.is_focused = true;
-                        }
-                        Event::FocusLost => {
-                            app.focus_supported = true;
-                            app.is_focused = false;
-                        }
+                        Event::FocusGained => app.set_terminal_focus(true),
+                        Event::FocusLost => app.set_terminal_focus(false),
                         _ => {}
                     }
                 }
@@ -2,13 +2,22 @@
 //!
 //! Caches parsed output keyed by a hash of the input content.
 //! When the same content is requested, returns cached lines instead of re-parsing.
+//! Streaming messages are rendered incrementally instead, keyed by message.
+//! The links of a message (for link hints) are cached by message as well.
 
 use ratatui::text::Line;
 use std::collections::HashMap;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
 
-use crate::markdown::{render_markdown, MARKDOWN_CACHE_MAX_ENTRIES};
+use super::incremental::StreamingRender;
+use crate::markdown::{
+    extract_links, render_markdown, render_markdown_width, LinkInfo, MARKDOWN_CACHE_MAX_ENTRIES,
+};
+
+/// Streaming texts rendered incrementally at once before the least recently
+/// used is dropped
+const MAX_STREAMING_ENTRIES: usize = 32;
 
 /// Cached result from markdown rendering
 #[derive(Clone)]
@@ -36,6 +45,16 @@ pub struct MarkdownCache {
     hits: u64,
     /// Statistics: cache misses
     misses: u64,
+    /// Incremental renders of streaming texts, keyed by message ID and
+    /// segment index
+    streaming: HashMap<(i64, usize), StreamingRender>,
+    /// Uses of `render_streaming`, to find the least recently used entry
+    streaming_ticks: u64,
+    /// Statistics: bytes handed to the markdown parser
+    parsed_bytes: u64,
+    /// Links of messages by message ID, with the hash of the content (and
+    /// code block setting) they were extracted from
+    links: HashMap<i64, (u64, Arc<Vec<LinkInfo>>)>,
 }
 
 impl Default for MarkdownCache {
@@ -52,6 +71,10 @@ impl MarkdownCache {
             insertion_order: Vec::new(),
             hits: 0,
             misses: 0,
+            streaming: HashMap::new(),
+            streaming_ticks: 0,
+            parsed_bytes: 0,
+            links: HashMap::new(),
         }
     }
 
@@ -72,7 +95,98 @@ impl MarkdownCache {
     /// when they need to modify the lines.
     pub fn render(&mut self, content: &str) -> Arc<Vec<Line<'static>>> {
         let hash = Self::hash_content(content);
+        self.get_or_render(hash, content.len(), || render_markdown(content))
+    }
 
+    /// Render markdown for a content area `width` columns wide, with caching.
+    ///
+    /// Like `render`, but tables are fitted to the width, so entries are
+    /// keyed by content and width.
+    pub fn render_width(&mut self, content: &str, width: usize) -> Arc<Vec<Line<'static>>> {
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        width.hash(&mut hasher);
+        let hash = hasher.finish();
+        self.get_or_render(hash, content.len(), || {
+            render_markdown_width(content, width)
+        })
+    }
+
+    /// Render the growing text of a streaming message (segment `segment` of
+    /// message `message_id`) for a content area `width` columns wide.
+    ///
+    /// Only the part after the last completed block is re-parsed when
+    /// `content` has grown since the last call (see `incremental`); content
+    /// that changed other than by appending is rendered from scratch.
+    pub fn render_streaming(
+        &mut self,
+        message_id: i64,
+        segment: usize,
+        content: &str,
+        width: usize,
+    ) -> Arc<Vec<Line<'static>>> {
+        let key = (message_id, segment);
+        if !self.streaming.contains_key(&key) && self.streaming.len() >= MAX_STREAMING_ENTRIES {
+            let oldest = self
+                .streaming
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| *key);
+            if let Some(oldest) = oldest {
+                self.streaming.remove(&oldest);
+            }
+        }
+
+        self.streaming_ticks += 1;
+        let entry = self.streaming.entry(key).or_default();
+        entry.last_used = self.streaming_ticks;
+        let (lines, unchanged) = entry.render(content, width, &mut self.parsed_bytes);
+        if unchanged {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        lines
+    }
+
+    /// Links of message `message_id`, whose text is `content`, each URL once
+    /// (see `extract_links`).
+    ///
+    /// Extracted again only when the content changed; links in code are
+    /// included with `include_code`.
+    pub fn links(
+        &mut self,
+        message_id: i64,
+        content: &str,
+        include_code: bool,
+    ) -> Arc<Vec<LinkInfo>> {
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        include_code.hash(&mut hasher);
+        let hash = hasher.finish();
+        if let Some((cached_hash, links)) = self.links.get(&message_id) {
+            if *cached_hash == hash {
+                return Arc::clone(links);
+            }
+        }
+
+        // Link lists are small; start over rather than track their use
+        if self.links.len() >= MARKDOWN_CACHE_MAX_ENTRIES && !self.links.contains_key(&message_id) {
+            self.links.clear();
+        }
+        let links = Arc::new(extract_links(content, include_code));
+        self.links.insert(message_id, (hash, Arc::clone(&links)));
+        links
+    }
+
+    /// Cached lines for `hash` (of `len` bytes of content), rendering and
+    /// storing them on a miss
+    fn get_or_render(
+        &mut self,
+        hash: u64,
+        len: usize,
+        render: impl FnOnce() -> Vec<Line<'static>>,
+    ) -> Arc<Vec<Line<'static>>> {
         // Check cache - return Arc clone for zero-copy access
         if let Some(cached) = self.entries.get(&hash) {
             self.hits += 1;
@@ -81,7 +195,8 @@ impl MarkdownCache {
 
         // Cache miss - render and store
         self.misses += 1;
-        let lines = Arc::new(render_markdown(content));
+        self.parsed_bytes += len as u64;
+        let lines = Arc::new(render());
 
         // Evict oldest entries if at capacity
         while self.entries.len() >= MARKDOWN_CACHE_MAX_ENTRIES && !self.insertion_order.is_empty() {
@@ -106,6 +221,11 @@ impl MarkdownCache {
         (self.hits, self.misses)
     }
 
+    /// Total bytes of markdown parsed (cache misses and streaming tails)
+    pub fn parsed_bytes(&self) -> u64 {
+        self.parsed_bytes
+    }
+
     /// Get the number of entries currently in the cache
     pub fn len(&self) -> usize {
         self.entries.len()
@@ -120,6 +240,8 @@ impl MarkdownCache {
     pub fn clear(&mut self) {
         self.entries.clear();
         self.insertion_order.clear();
+        self.streaming.clear();
+        self.links.clear();
         // Don't reset stats - they're useful for debugging
     }
 
@@ -334,4 +456,132 @@ fn main() {
 
         assert_eq!(cache.len(), 4);
     }
+
+    /// Markdown of `sections` repeated sections mixing every block kind
+    fn long_document(sections: usize) -> String {
+        (0..sections)
+            .map(|i| {
+                format!(
+                    "## Section {i}\n\n\
+                     Paragraph {i} with **bold**, *italic* and `code`, wrapping over\n\
+                     a soft break and a link to https://example.com/{i}.\n\n\
+                     - item one of {i}\n\
+                     - [x] done item {i}\n\n\
+                     ```rust\nfn section_{i}() {{\n\n    let x = {i};\n}}\n```\n\n\
+                     | Name | Value |\n|------|-------|\n| n{i} | {i} |\n\n"
+                )
+            })
+            .collect()
+    }
+
+    /// Stream `doc` in 7-byte tokens; the final lines and the bytes parsed
+    fn stream(doc: &str) -> (Arc<Vec<Line<'static>>>, u64) {
+        let mut cache = MarkdownCache::new();
+        let mut lines = Arc::new(Vec::new());
+        let mut end = 0;
+        while end < doc.len() {
+            end = (end + 7).min(doc.len());
+            lines = cache.render_streaming(1, 0, &doc[..end], 80);
+        }
+        (lines, cache.parsed_bytes())
+    }
+
+    #[test]
+    fn test_streaming_parse_work_is_linear() {
+        let half = long_document(100);
+        let full = long_document(200);
+        assert!(full.len() >= 50_000);
+
+        let (_, half_parsed) = stream(&half);
+        let (_, full_parsed) = stream(&full);
+
+        // Re-parsing everything on each token would be ~3800x the length
+        assert!(
+            full_parsed < 100 * full.len() as u64,
+            "parsed {} bytes for {}",
+            full_parsed,
+            full.len()
+        );
+        // Twice the text, about twice the work (quadratic would be 4x)
+        assert!(
+            full_parsed < 3 * half_parsed,
+            "{} vs {}",
+            full_parsed,
+            half_parsed
+        );
+    }
+
+    #[test]
+    fn test_streamed_render_equals_one_shot() {
+        let doc = long_document(150);
+        let (streamed, _) = stream(&doc);
+        assert_eq!(*streamed, render_markdown_width(&doc, 80));
+
+        // Also at every block boundary along the way
+        let mut cache = MarkdownCache::new();
+        for (end, _) in doc.match_indices("\n\n").take(40) {
+            let partial = &doc[..end + 1];
+            assert_eq!(
+                *cache.render_streaming(2, 0, partial, 80),
+                render_markdown_width(partial, 80)
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaming_open_fence_renders_as_code() {
+        let mut cache = MarkdownCache::new();
+        let lines = cache.render_streaming(1, 0, "Intro\n\n```rust\nfn main() {\n\n    let", 80);
+        let last = lines.last().unwrap();
+        assert_eq!(last.spans[0].content, "    let");
+        assert_eq!(
+            last.spans[0].style.fg,
+            Some(ratatui::style::Color::DarkGray)
+        );
+    }
+
+    #[test]
+    fn test_streaming_restarts_when_content_is_replaced() {
+        let mut cache = MarkdownCache::new();
+        cache.render_streaming(1, 0, "First paragraph.\n\nSecond", 80);
+        let lines = cache.render_streaming(1, 0, "Other text", 80);
+        assert_eq!(*lines, render_markdown_width("Other text", 80));
+
+        // Unchanged content is a hit
+        let (hits, _) = cache.stats();
+        cache.render_streaming(1, 0, "Other text", 80);
+        assert_eq!(cache.stats().0, hits + 1);
+    }
+
+    #[test]
+    fn test_links_cached_per_message_until_content_changes() {
+        let mut cache = MarkdownCache::new();
+        let first = cache.links(7, "See https://one.test", false);
+        assert_eq!(first.len(), 1);
+        assert!(Arc::ptr_eq(
+            &first,
+            &cache.links(7, "See https://one.test", false)
+        ));
+
+        let grown = cache.links(7, "See https://one.test and https://two.test", false);
+        assert_eq!(grown.len(), 2);
+
+        let code = "```\nhttps://code.test\n```";
+        assert!(cache.links(8, code, false).is_empty());
+        assert_eq!(cache.links(8, code, true).len(), 1);
+    }
+
+    #[test]
+    fn test_render_width_is_cached_per_width() {
+        let mut cache = MarkdownCache::new();
+        let content = "| A | B |\n|---|---|\n| 1 | 2 |";
+
+        cache.render_width(content, 80);
+        cache.render_width(content, 80);
+        assert_eq!(cache.stats(), (1, 1));
+
+        cache.render_width(content, 40);
+        assert_eq!(cache.stats(), (1, 2));
+        assert_eq!(cache.len(), 2);
+    }
 }
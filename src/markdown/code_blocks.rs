@@ -0,0 +1,93 @@
+//! Code block extraction from markdown
+//!
+//! Uses the same pulldown_cmark parser configuration as rendering, so what is
+//! extracted matches the code blocks shown in the conversation view.
+
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
+
+use super::parser_options;
+
+/// A fenced or indented code block found in markdown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// Language from the info string (e.g., "rust"), if any
+    pub language: Option<String>,
+    /// Raw code content, without the fences
+    pub code: String,
+}
+
+/// Extract all code blocks from markdown text, in document order.
+///
+/// An unterminated fence (e.g., while streaming) yields the content received
+/// so far.
+pub fn extract_code_blocks(text: &str) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<CodeBlock> = None;
+
+    for event in Parser::new_ext(text, parser_options()) {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .map(|lang| lang.to_string()),
+                    CodeBlockKind::Indented => None,
+                };
+                current = Some(CodeBlock {
+                    language,
+                    code: String::new(),
+                });
+            }
+            Event::Text(text) => {
+                if let Some(block) = current.as_mut() {
+                    block.code.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                blocks.extend(current.take());
+            }
+            _ => {}
+        }
+    }
+
+    blocks.extend(current);
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_fenced_blocks_in_order() {
+        let md = "Intro\n\n```rust\nfn main() {}\n```\n\nThen:\n\n```\nls -la\n```\n";
+        let blocks = extract_code_blocks(md);
+
+        assert_eq!(
+            blocks,
+            vec![
+                CodeBlock {
+                    language: Some("rust".to_string()),
+                    code: "fn main() {}\n".to_string(),
+                },
+                CodeBlock {
+                    language: None,
+                    code: "ls -la\n".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_ignores_inline_code() {
+        assert!(extract_code_blocks("Use `cargo test` here").is_empty());
+    }
+
+    #[test]
+    fn test_extract_unterminated_block() {
+        let blocks = extract_code_blocks("```python\nprint('hi')\n");
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].code, "print('hi')\n");
+    }
+}
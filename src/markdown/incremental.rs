@@ -0,0 +1,201 @@
+//! Incremental markdown rendering for streaming messages
+//!
+//! A streaming message only grows at the end, so everything before the last
+//! completed top-level block is rendered once and kept; each frame re-parses
+//! only the tail after it. Blocks are cut where a blank line outside a code
+//! fence is followed by an unindented line that doesn't start a list item:
+//! there, rendering the two halves separately gives the same lines as
+//! rendering the whole. A code fence that hasn't closed yet stays in the tail
+//! and is rendered as code until its closing fence arrives.
+//!
+//! Reference-style link definitions arriving after their use are the one
+//! thing this misses; the finished message is rendered in one pass anyway.
+
+use std::sync::Arc;
+
+use ratatui::text::Line;
+
+use super::render_markdown_blocks;
+
+/// Tracks line starts of a growing text to find block boundaries
+#[derive(Debug, Default)]
+struct BlockScanner {
+    /// Byte offset of the first line not scanned yet
+    pos: usize,
+    /// Open code fence: its character and length
+    fence: Option<(u8, usize)>,
+    /// The last scanned line was blank (outside a fence)
+    after_blank: bool,
+}
+
+/// Length of the code fence opening or closing `line` (` ``` ` or `~~~`,
+/// indented at most three spaces), with its character
+fn fence_marker(line: &str) -> Option<(u8, usize)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let ch = *trimmed.as_bytes().first()?;
+    if ch != b'`' && ch != b'~' {
+        return None;
+    }
+    let len = trimmed.bytes().take_while(|b| *b == ch).count();
+    (len >= 3).then_some((ch, len))
+}
+
+/// Whether a line following a blank line starts a new top-level block that
+/// can be rendered on its own: not indented (list item or indented code
+/// continuation) and not a list item itself
+fn starts_independent_block(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    let Some(&first) = bytes.first() else {
+        return false;
+    };
+    if first == b' ' || first == b'\t' {
+        return false;
+    }
+    let marker_end = if matches!(first, b'-' | b'*' | b'+') {
+        1
+    } else {
+        let digits = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 || !matches!(bytes.get(digits), Some(b'.' | b')')) {
+            return true;
+        }
+        digits + 1
+    };
+    !matches!(bytes.get(marker_end), None | Some(b' ' | b'\t'))
+}
+
+impl BlockScanner {
+    /// Scan the complete lines of `content` added since the last call.
+    ///
+    /// Returns the start of the last line that begins an independent block.
+    fn advance(&mut self, content: &str) -> Option<usize> {
+        let mut boundary = None;
+        while let Some(newline) = content[self.pos..].find('\n') {
+            let start = self.pos;
+            let line = content[start..start + newline].trim_end_matches('\r');
+            self.pos = start + newline + 1;
+
+            if let Some((ch, len)) = self.fence {
+                let closes = fence_marker(line).is_some_and(|(c, l)| {
+                    c == ch && l >= len && line.trim().bytes().all(|b| b == ch)
+                });
+                if closes {
+                    self.fence = None;
+                }
+                self.after_blank = false;
+                continue;
+            }
+            if line.trim().is_empty() {
+                self.after_blank = true;
+                continue;
+            }
+            if self.after_blank && starts_independent_block(line) {
+                boundary = Some(start);
+            }
+            self.fence = fence_marker(line);
+            self.after_blank = false;
+        }
+        boundary
+    }
+}
+
+/// Incremental render state of one streaming text
+#[derive(Debug, Default)]
+pub(super) struct StreamingRender {
+    /// Content width the lines were rendered for
+    width: usize,
+    /// The text scanned so far, to detect anything but appends
+    seen: String,
+    scanner: BlockScanner,
+    /// Byte length of the rendered stable prefix
+    stable_len: usize,
+    /// Lines of the stable prefix
+    stable_lines: Vec<Line<'static>>,
+    /// Last result and the content length it was rendered for
+    last: Option<(usize, Arc<Vec<Line<'static>>>)>,
+    /// Tick of the last use, for eviction
+    pub(super) last_used: u64,
+}
+
+impl StreamingRender {
+    /// Render `content` for a content area `width` columns wide, parsing only
+    /// what's new since the last call. `parsed_bytes` counts the bytes handed
+    /// to the parser.
+    ///
+    /// Returns the lines and whether they're the previous result, unchanged.
+    pub(super) fn render(
+        &mut self,
+        content: &str,
+        width: usize,
+        parsed_bytes: &mut u64,
+    ) -> (Arc<Vec<Line<'static>>>, bool) {
+        if self.width != width || !content.starts_with(self.seen.as_str()) {
+            *self = StreamingRender {
+                width,
+                last_used: self.last_used,
+                ..Default::default()
+            };
+        }
+        if let Some((len, lines)) = &self.last {
+            if *len == content.len() {
+                return (Arc::clone(lines), true);
+            }
+        }
+
+        let scanned = self.scanner.pos;
+        let boundary = self.scanner.advance(content);
+        self.seen.push_str(&content[scanned..self.scanner.pos]);
+        if let Some(boundary) = boundary.filter(|b| *b > self.stable_len) {
+            let block = &content[self.stable_len..boundary];
+            *parsed_bytes += block.len() as u64;
+            self.stable_lines
+                .extend(render_markdown_blocks(block, Some(width)).lines);
+            self.stable_len = boundary;
+        }
+
+        let tail = &content[self.stable_len..];
+        *parsed_bytes += tail.len() as u64;
+        let mut lines = self.stable_lines.clone();
+        lines.extend(render_markdown_blocks(tail, Some(width)).lines);
+        if lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+
+        let lines = Arc::new(lines);
+        self.last = Some((content.len(), Arc::clone(&lines)));
+        (lines, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boundary_skips_fences_lists_and_indents() {
+        let mut scanner = BlockScanner::default();
+        let text = "Intro\n\n```\ncode\n\nmore\n```\n\n- item\n\n  continued\n\nAfter\n";
+        let after = text.find("After").unwrap();
+        assert_eq!(scanner.advance(text), Some(after));
+
+        // Nothing new past the last boundary
+        let mut scanner = BlockScanner::default();
+        assert_eq!(scanner.advance("```\nopen fence\n\nstill code\n"), None);
+    }
+
+    #[test]
+    fn test_list_markers_and_fences() {
+        assert!(!starts_independent_block("- item"));
+        assert!(!starts_independent_block("12. item"));
+        assert!(!starts_independent_block("    code"));
+        assert!(starts_independent_block("-dash"));
+        assert!(starts_independent_block("2024 was a year"));
+        assert!(starts_independent_block("# Heading"));
+
+        assert_eq!(fence_marker("````rust"), Some((b'`', 4)));
+        assert_eq!(fence_marker("    ```"), None);
+        assert_eq!(fence_marker("``"), None);
+    }
+}
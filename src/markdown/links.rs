@@ -5,9 +5,11 @@
 //! - Returns `LinkInfo` metadata for rendering OSC 8 hyperlinks
 
 use once_cell::sync::Lazy;
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
 use ratatui::{style::Style, text::Span};
 use regex::Regex;
 
+use crate::markdown::parser_options;
 use crate::markdown::styles::{wrap_osc8_hyperlink, STYLE_LINK};
 
 /// Regex pattern for detecting plain text URLs (http:// or https://)
@@ -66,6 +68,64 @@ pub fn detect_plain_urls(text: &str) -> Vec<LinkInfo> {
         .collect()
 }
 
+/// Extract the links of a markdown text, each URL once, in reading order.
+///
+/// Finds markdown links (including `<autolinks>`) and plain URLs in text,
+/// with byte positions in `text`. Plain URLs in code blocks and inline code
+/// are only included with `include_code`, as rendering doesn't link them.
+pub fn extract_links(text: &str, include_code: bool) -> Vec<LinkInfo> {
+    let mut links: Vec<LinkInfo> = Vec::new();
+    let mut in_code_block = false;
+    // URL, display text and start of the markdown link being read
+    let mut current_link: Option<(String, String, usize)> = None;
+
+    for (event, range) in Parser::new_ext(text, parser_options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                current_link = Some((dest_url.to_string(), String::new(), range.start));
+            }
+            Event::End(TagEnd::Link) => {
+                if let Some((url, link_text, start)) = current_link.take() {
+                    links.push(LinkInfo::new(url, link_text, start, range.end));
+                }
+            }
+            Event::Text(content) => {
+                if let Some((_, link_text, _)) = current_link.as_mut() {
+                    link_text.push_str(&content);
+                } else if !in_code_block || include_code {
+                    links.extend(plain_urls_at(&content, range.start));
+                }
+            }
+            Event::Code(code) => {
+                if let Some((_, link_text, _)) = current_link.as_mut() {
+                    link_text.push_str(&code);
+                } else if include_code {
+                    // Skip the opening backticks
+                    let offset = text[range.clone()].find(code.as_ref()).unwrap_or(0);
+                    links.extend(plain_urls_at(&code, range.start + offset));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    links.retain(|link| seen.insert(link.url.clone()));
+    links
+}
+
+/// Plain URLs in `text`, which starts at byte `base_position`
+fn plain_urls_at(text: &str, base_position: usize) -> Vec<LinkInfo> {
+    let mut urls = detect_plain_urls(text);
+    for url in &mut urls {
+        url.start += base_position;
+        url.end += base_position;
+    }
+    urls
+}
+
 /// Render text with plain URL detection
 /// Splits text into spans where URLs are styled differently and tracked in links vec
 pub(crate) fn render_text_with_urls(
@@ -186,6 +246,59 @@ mod tests {
         assert!(urls.is_empty());
     }
 
+    #[test]
+    fn test_extract_links_in_reading_order() {
+        let md = "See [the docs](https://docs.rs) and https://crates.io or <https://example.com>.";
+        let links = extract_links(md, false);
+        let urls: Vec<&str> = links.iter().map(|l| l.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://docs.rs",
+                "https://crates.io",
+                "https://example.com"
+            ]
+        );
+        assert_eq!(links[0].text, "the docs");
+        assert_eq!(
+            &md[links[0].start..links[0].end],
+            "[the docs](https://docs.rs)"
+        );
+        assert_eq!(&md[links[1].start..links[1].end], "https://crates.io");
+    }
+
+    #[test]
+    fn test_extract_links_dedups_urls() {
+        let md = "https://one.com then [one](https://one.com) and https://two.com";
+        let urls: Vec<String> = extract_links(md, false)
+            .into_iter()
+            .map(|l| l.url)
+            .collect();
+        assert_eq!(urls, vec!["https://one.com", "https://two.com"]);
+    }
+
+    #[test]
+    fn test_extract_links_skips_code_unless_asked() {
+        let md = "Run `curl https://api.test/a`:\n\n```sh\ncurl https://api.test/b\n```\n\nhttps://docs.test";
+        let urls: Vec<String> = extract_links(md, false)
+            .into_iter()
+            .map(|l| l.url)
+            .collect();
+        assert_eq!(urls, vec!["https://docs.test"]);
+
+        let links = extract_links(md, true);
+        let urls: Vec<&str> = links.iter().map(|l| l.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://api.test/a",
+                "https://api.test/b",
+                "https://docs.test"
+            ]
+        );
+        assert!(links.iter().all(|l| md[l.start..l.end] == l.url));
+    }
+
     #[test]
     fn test_markdown_link_detection() {
         let parsed = render_markdown_with_links("Click [here](https://example.com) for info");
@@ -1,26 +1,36 @@
 //! Markdown parser for terminal rendering
 //!
 //! Converts markdown text to styled ratatui Lines for display in the TUI.
-//! Handles code blocks, inline code, bold, italic, headings, and hyperlinks.
+//! Handles code blocks, inline code, bold, italic, headings, hyperlinks,
+//! tables and task lists.
 //!
 //! Includes a memoization layer (`MarkdownCache`) that caches parsed output
-//! keyed by content hash to avoid re-parsing unchanged content.
+//! keyed by content hash to avoid re-parsing unchanged content, and renders
+//! streaming messages incrementally (see `incremental`).
 //!
 //! URL Detection:
 //! - Detects markdown links `[text](url)` via pulldown_cmark events
 //! - Detects plain text URLs using regex pattern `https?://[^\s<>\[\]]+`
 //! - Returns `LinkInfo` metadata for rendering OSC 8 hyperlinks
+//! - `extract_links` lists a message's links for the link hint mode
 
 mod cache;
+mod code_blocks;
+mod incremental;
 mod links;
+mod preview;
 mod styles;
+mod tables;
 
 pub use cache::MarkdownCache;
-pub use links::{detect_plain_urls, LinkInfo, ParsedMarkdown};
+pub use code_blocks::{extract_code_blocks, CodeBlock};
+pub use links::{detect_plain_urls, extract_links, LinkInfo, ParsedMarkdown};
+pub use preview::{preview_text, truncate_at_boundary, PreviewCache};
 pub use styles::{
-    contains_osc8_sequence, display_width_ignoring_escapes, strip_osc8_sequences,
-    wrap_osc8_hyperlink,
+    contains_osc8_sequence, display_width_ignoring_escapes, osc8_url, set_hyperlinks_enabled,
+    strip_osc8_sequences, wrap_osc8_hyperlink,
 };
+pub use tables::{table_height, STACKED_TABLE_WIDTH};
 
 use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
 use ratatui::{
@@ -30,10 +40,23 @@ use ratatui::{
 
 use links::render_text_with_urls;
 use styles::{STYLE_CODE_BLOCK, STYLE_HEADING, STYLE_INLINE_CODE};
+use tables::render_table;
 
 /// Maximum number of entries in the markdown cache before eviction
 pub const MARKDOWN_CACHE_MAX_ENTRIES: usize = 500;
 
+/// Parser options shared by rendering and extraction.
+///
+/// Enables tables, task lists and strikethrough in addition to the
+/// CommonMark defaults.
+pub(crate) fn parser_options() -> Options {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options
+}
+
 /// Render markdown text to a vector of styled Lines.
 ///
 /// Each newline in the input becomes a separate Line object, which is critical
@@ -52,6 +75,14 @@ pub fn render_markdown(text: &str) -> Vec<Line<'static>> {
     render_markdown_with_links(text).lines
 }
 
+/// Render markdown text for a content area `width` columns wide.
+///
+/// Same as `render_markdown`, except that tables are fitted to the width
+/// (see `table_height`) instead of being laid out at their natural size.
+pub fn render_markdown_width(text: &str, width: usize) -> Vec<Line<'static>> {
+    render_markdown_lines(text, Some(width)).lines
+}
+
 /// Render markdown text to styled Lines with link detection.
 ///
 /// Returns a `ParsedMarkdown` struct containing:
@@ -69,16 +100,33 @@ pub fn render_markdown(text: &str) -> Vec<Line<'static>> {
 /// - Headings (# Heading) - cyan and bold
 /// - Markdown links [text](url) - blue and underlined
 /// - Plain text URLs (http:// and https://) - detected via regex
+/// - Tables - box-drawing borders
+/// - Task list items (- [ ] / - [x]) - ☐ / ☑ in place of the bullet
 ///
 /// Gracefully handles incomplete markdown during streaming by rendering
 /// partial content without crashing.
 pub fn render_markdown_with_links(text: &str) -> ParsedMarkdown {
-    // Enable table parsing in addition to default options
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_TABLES);
-    options.insert(Options::ENABLE_STRIKETHROUGH);
+    render_markdown_lines(text, None)
+}
+
+/// Render markdown with tables fitted to `width` columns (None: unbounded)
+fn render_markdown_lines(text: &str, width: Option<usize>) -> ParsedMarkdown {
+    let mut parsed = render_markdown_blocks(text, width);
+
+    // Ensure we return at least one empty line for empty input
+    if parsed.lines.is_empty() {
+        parsed.lines.push(Line::from(""));
+    }
+
+    parsed
+}
 
-    let parser = Parser::new_ext(text, options);
+/// Render the blocks of `text`, without the empty line of empty input.
+///
+/// Text split between top-level blocks renders to the concatenation of its
+/// parts' lines, which the incremental streaming render relies on.
+fn render_markdown_blocks(text: &str, width: Option<usize>) -> ParsedMarkdown {
+    let parser = Parser::new_ext(text, parser_options());
     let mut lines: Vec<Line<'static>> = Vec::new();
     let mut current_spans: Vec<Span<'static>> = Vec::new();
     let mut links: Vec<LinkInfo> = Vec::new();
@@ -223,7 +271,7 @@ pub fn render_markdown_with_links(text: &str) -> ParsedMarkdown {
                     }
                     TagEnd::Table => {
                         // End of table - render the collected table data
-                        render_table_to_lines(&table_rows, &mut lines);
+                        lines.extend(render_table(&table_rows, width));
                         in_table = false;
                         table_rows.clear();
                     }
@@ -312,6 +360,18 @@ pub fn render_markdown_with_links(text: &str) -> ParsedMarkdown {
                     lines.push(Line::from("")); // Add blank line for visual separation
                 }
             }
+            Event::TaskListMarker(checked) => {
+                // Replace the item's bullet with a checkbox
+                if current_spans.last().is_some_and(|s| s.content == "• ") {
+                    current_spans.pop();
+                }
+                let (glyph, style) = if checked {
+                    ("\u{2611} ", Style::default().fg(Color::Green))
+                } else {
+                    ("\u{2610} ", *style_stack.last().unwrap_or(&Style::default()))
+                };
+                current_spans.push(Span::styled(glyph.to_string(), style));
+            }
             Event::HardBreak => {
                 if in_table {
                     // Inside a table cell - convert to space
@@ -330,125 +390,9 @@ pub fn render_markdown_with_links(text: &str) -> ParsedMarkdown {
         lines.push(Line::from(current_spans));
     }
 
-    // Ensure we return at least one empty line for empty input
-    if lines.is_empty() {
-        lines.push(Line::from(""));
-    }
-
     ParsedMarkdown { lines, links }
 }
 
-/// Render a table to styled Lines.
-///
-/// Takes the collected table rows (each row is a Vec of cell strings) and
-/// renders them as formatted lines with proper column alignment and borders.
-fn render_table_to_lines(table_rows: &[Vec<String>], lines: &mut Vec<Line<'static>>) {
-    if table_rows.is_empty() {
-        return;
-    }
-
-    // Calculate the maximum width for each column
-    let num_cols = table_rows.iter().map(|r| r.len()).max().unwrap_or(0);
-    if num_cols == 0 {
-        return;
-    }
-
-    let mut col_widths: Vec<usize> = vec![0; num_cols];
-    for row in table_rows {
-        for (i, cell) in row.iter().enumerate() {
-            if i < num_cols {
-                col_widths[i] = col_widths[i].max(cell.trim().len());
-            }
-        }
-    }
-
-    // Ensure minimum column width of 3 for readability
-    for width in &mut col_widths {
-        *width = (*width).max(3);
-    }
-
-    // Style for table borders
-    let border_style = Style::default().fg(Color::DarkGray);
-    let header_style = Style::default()
-        .fg(Color::Cyan)
-        .add_modifier(Modifier::BOLD);
-    let cell_style = Style::default();
-
-    // Build the top border
-    let top_border = build_table_border(&col_widths, '┌', '┬', '┐');
-    lines.push(Line::from(Span::styled(top_border, border_style)));
-
-    // Render each row
-    for (row_idx, row) in table_rows.iter().enumerate() {
-        let mut spans: Vec<Span<'static>> = Vec::new();
-        spans.push(Span::styled("│".to_string(), border_style));
-
-        for (col_idx, cell) in row.iter().enumerate() {
-            if col_idx < num_cols {
-                let width = col_widths[col_idx];
-                let content = cell.trim();
-                let padded = format!(" {:<width$} ", content, width = width);
-
-                // Use header style for first row, regular style for others
-                let style = if row_idx == 0 {
-                    header_style
-                } else {
-                    cell_style
-                };
-                spans.push(Span::styled(padded, style));
-                spans.push(Span::styled("│".to_string(), border_style));
-            }
-        }
-
-        // Pad missing columns
-        for col_idx in row.len()..num_cols {
-            let width = col_widths[col_idx];
-            let padded = format!(" {:<width$} ", "", width = width);
-            let style = if row_idx == 0 {
-                header_style
-            } else {
-                cell_style
-            };
-            spans.push(Span::styled(padded, style));
-            spans.push(Span::styled("│".to_string(), border_style));
-        }
-
-        lines.push(Line::from(spans));
-
-        // Add separator after header row
-        if row_idx == 0 && table_rows.len() > 1 {
-            let separator = build_table_border(&col_widths, '├', '┼', '┤');
-            lines.push(Line::from(Span::styled(separator, border_style)));
-        }
-    }
-
-    // Build the bottom border
-    let bottom_border = build_table_border(&col_widths, '└', '┴', '┘');
-    lines.push(Line::from(Span::styled(bottom_border, border_style)));
-
-    // Add blank line after table for visual separation
-    lines.push(Line::from(""));
-}
-
-/// Build a table border line with the given corner and junction characters.
-fn build_table_border(col_widths: &[usize], left: char, middle: char, right: char) -> String {
-    let mut border = String::new();
-    border.push(left);
-
-    for (i, &width) in col_widths.iter().enumerate() {
-        // +2 for padding on each side of cell content
-        for _ in 0..(width + 2) {
-            border.push('─');
-        }
-        if i < col_widths.len() - 1 {
-            border.push(middle);
-        }
-    }
-
-    border.push(right);
-    border
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -891,4 +835,35 @@ mod tests {
         // Should not panic on empty cells
         assert!(lines.len() >= 4, "Should render table structure even with empty cells");
     }
+
+    #[test]
+    fn test_task_list_items() {
+        let lines = render_markdown("- [ ] write tests\n- [x] fix the bug\n- plain item");
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(
+            rendered,
+            vec!["\u{2610} write tests", "\u{2611} fix the bug", "• plain item"]
+        );
+    }
+
+    #[test]
+    fn test_table_fits_content_width() {
+        let md = format!(
+            "| Name | Description |\n|---|---|\n| cache | {} |",
+            "keeps rendered markdown around ".repeat(4)
+        );
+        let unbounded = render_markdown(&md);
+        assert!(unbounded[3].width() > 80);
+
+        let fitted = render_markdown_width(&md, 80);
+        assert!(fitted.iter().all(|line| line.width() <= 80));
+        let rows = vec![
+            vec!["Name".to_string(), "Description".to_string()],
+            vec!["cache".to_string(), "keeps rendered markdown around ".repeat(4)],
+        ];
+        assert_eq!(fitted.len(), table_height(&rows, Some(80)));
+    }
 }
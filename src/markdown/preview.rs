@@ -0,0 +1,285 @@
+//! One-line previews of message text
+//!
+//! Thread previews (CommandDeck rows, the thread switcher, BrowseList) show a
+//! message as a single line of plain text: markdown syntax is stripped, code
+//! blocks are replaced by a label such as `[rust code]`, and long text is cut
+//! at a sentence or word boundary instead of mid-word.
+//!
+//! Stripping parses the markdown, so results are cached per thread and keyed
+//! by the id of the message they were built from (`PreviewCache`).
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::parser_options;
+
+/// Appended to previews that were cut short
+const ELLIPSIS: &str = "…";
+
+/// Convert markdown to a single line of plain text for previews.
+///
+/// Code blocks (including unclosed fences of streaming text) are replaced by
+/// a label naming their language; inline code keeps its text.
+pub fn preview_text(markdown: &str) -> String {
+    let mut text = String::new();
+    let mut in_code_block = false;
+
+    for event in Parser::new_ext(markdown, parser_options()) {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                in_code_block = true;
+                text.push(' ');
+                text.push_str(&code_block_label(&kind));
+                text.push(' ');
+            }
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Text(content) | Event::Code(content) if !in_code_block => {
+                text.push_str(&content);
+            }
+            // Inline markup ends mid-sentence; blocks end with a break
+            Event::End(
+                TagEnd::Emphasis
+                | TagEnd::Strong
+                | TagEnd::Strikethrough
+                | TagEnd::Link
+                | TagEnd::Image,
+            ) => {}
+            Event::SoftBreak | Event::HardBreak | Event::Rule | Event::End(_) => text.push(' '),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Label standing in for a code block, e.g. `[rust code]`
+fn code_block_label(kind: &CodeBlockKind) -> String {
+    let language = match kind {
+        CodeBlockKind::Fenced(info) => info
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .next()
+            .unwrap_or(""),
+        CodeBlockKind::Indented => "",
+    };
+    if language.is_empty() {
+        "[code]".to_string()
+    } else {
+        format!("[{} code]", language)
+    }
+}
+
+/// Cut plain text to `max_width` columns at a sentence or word boundary.
+///
+/// Text that fits is returned unchanged. Otherwise the cut prefers the end of
+/// the last sentence, then the last word, as long as that keeps at least half
+/// of the room; only a single giant word is cut mid-word. Cut text ends with `…`.
+pub fn truncate_at_boundary(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    // Longest prefix that leaves room for the ellipsis
+    let budget = max_width - ELLIPSIS.width();
+    let mut end = 0;
+    let mut width = 0;
+    for (index, c) in text.char_indices() {
+        let char_width = c.width().unwrap_or(0);
+        if width + char_width > budget {
+            break;
+        }
+        width += char_width;
+        end = index + c.len_utf8();
+    }
+    let head = &text[..end];
+    let min_len = head.len() / 2;
+
+    // End of the last complete sentence (punctuation followed by a space),
+    // leaving a column for the space before the ellipsis
+    let sentence_end = head
+        .char_indices()
+        .zip(head.chars().skip(1))
+        .filter(|((_, c), next)| matches!(c, '.' | '!' | '?') && next.is_whitespace())
+        .map(|((index, _), _)| index + 1)
+        .last();
+    if let Some(cut) = sentence_end.filter(|cut| *cut >= min_len) {
+        return format!("{} {}", &head[..cut], ELLIPSIS);
+    }
+
+    // End of the last complete word
+    let word_end = if text[end..].starts_with(char::is_whitespace) {
+        Some(end)
+    } else {
+        head.rfind(char::is_whitespace)
+    };
+    let cut = word_end.filter(|cut| *cut >= min_len).unwrap_or(end);
+    format!("{}{}", head[..cut].trim_end(), ELLIPSIS)
+}
+
+/// Preview of one thread and the message it was built from
+#[derive(Debug, Clone)]
+struct CachedPreview {
+    message_id: i64,
+    source_hash: u64,
+    text: String,
+}
+
+/// Plain-text thread previews, shared by every view that lists threads.
+///
+/// Each thread keeps the preview of its latest message, keyed by the
+/// message's id. Id 0 stands for text without a message id (a message that
+/// is still streaming, or the preview text of a thread whose messages aren't
+/// loaded); those previews are rebuilt whenever the text changes.
+#[derive(Debug, Clone, Default)]
+pub struct PreviewCache {
+    entries: HashMap<String, CachedPreview>,
+}
+
+impl PreviewCache {
+    /// Create an empty preview cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make sure a thread's preview is built from `source` (message `message_id`)
+    pub fn update(&mut self, thread_id: &str, message_id: i64, source: &str) {
+        if let Some(entry) = self.entries.get(thread_id) {
+            if entry.message_id == message_id && message_id != 0 {
+                return;
+            }
+        }
+
+        let source_hash = hash_source(source);
+        if let Some(entry) = self.entries.get(thread_id) {
+            if entry.message_id == message_id && entry.source_hash == source_hash {
+                return;
+            }
+        }
+        self.entries.insert(
+            thread_id.to_string(),
+            CachedPreview {
+                message_id,
+                source_hash,
+                text: preview_text(source),
+            },
+        );
+    }
+
+    /// Plain-text preview of a thread (untruncated), if one has been built
+    pub fn get(&self, thread_id: &str) -> Option<&str> {
+        self.entries
+            .get(thread_id)
+            .map(|entry| entry.text.as_str())
+            .filter(|text| !text.is_empty())
+    }
+
+    /// Preview of a thread cut to `max_width` columns
+    pub fn truncated(&self, thread_id: &str, max_width: usize) -> Option<String> {
+        self.get(thread_id)
+            .map(|text| truncate_at_boundary(text, max_width))
+    }
+
+    /// Drop previews of threads that are no longer listed
+    pub fn retain_threads(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.entries.retain(|thread_id, _| keep(thread_id));
+    }
+
+    /// Number of cached previews
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no previews are cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Hash of preview source text, to notice edits of id-less text
+fn hash_source(source: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_text_strips_markdown() {
+        assert_eq!(
+            preview_text(
+                "# Plan\n\n**Fix** the `login` flow, see [docs](https://x.dev).\n\n- one\n- two"
+            ),
+            "Plan Fix the login flow, see docs. one two"
+        );
+    }
+
+    #[test]
+    fn test_preview_text_replaces_code_blocks() {
+        assert_eq!(
+            preview_text("Try this:\n\n```rust,ignore\nfn main() {}\n```\n\nThen rebuild."),
+            "Try this: [rust code] Then rebuild."
+        );
+        // Unclosed fences (streaming) never leak code into the preview
+        assert_eq!(
+            preview_text("Here:\n```python\nimport os\nos.remove("),
+            "Here: [python code]"
+        );
+        assert_eq!(preview_text("```\nplain\n```"), "[code]");
+    }
+
+    #[test]
+    fn test_truncate_at_boundary_prefers_sentences() {
+        let text = "Tests pass now. The flaky one was a race in the watcher setup.";
+        assert_eq!(truncate_at_boundary(text, 80), text);
+        assert_eq!(truncate_at_boundary(text, 30), "Tests pass now. …");
+    }
+
+    #[test]
+    fn test_truncate_at_boundary_cuts_at_words() {
+        assert_eq!(
+            truncate_at_boundary("Refactor the websocket reconnect logic", 20),
+            "Refactor the…"
+        );
+        // A single giant word is cut mid-word
+        assert_eq!(truncate_at_boundary("abcdefghijklmnop", 6), "abcde…");
+        assert_eq!(truncate_at_boundary("anything", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_at_boundary_respects_wide_chars() {
+        let truncated = truncate_at_boundary("日本語のテキストです", 7);
+        assert_eq!(truncated, "日本語…");
+        assert!(truncated.width() <= 7);
+    }
+
+    #[test]
+    fn test_preview_cache_rebuilds_on_new_message() {
+        let mut cache = PreviewCache::new();
+        cache.update("t1", 4, "**first**");
+        assert_eq!(cache.get("t1"), Some("first"));
+
+        // Same message id: the source is not re-read
+        cache.update("t1", 4, "ignored");
+        assert_eq!(cache.get("t1"), Some("first"));
+
+        cache.update("t1", 6, "`second`");
+        assert_eq!(cache.get("t1"), Some("second"));
+
+        // Id-less text is rebuilt when it changes
+        cache.update("t2", 0, "draft");
+        cache.update("t2", 0, "draft two");
+        assert_eq!(cache.truncated("t2", 7), Some("draft…".to_string()));
+
+        cache.retain_threads(|id| id == "t2");
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get("t1").is_none());
+    }
+}
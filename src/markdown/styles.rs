@@ -3,8 +3,20 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 use ratatui::style::{Color, Modifier, Style};
+use std::sync::atomic::{AtomicBool, Ordering};
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+/// Whether links are wrapped in OSC 8 sequences (off for terminals without hyperlink support)
+static HYPERLINKS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable OSC 8 hyperlinks in rendered markdown.
+///
+/// When disabled, `wrap_osc8_hyperlink` returns the plain link text.
+pub fn set_hyperlinks_enabled(enabled: bool) {
+    HYPERLINKS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
 /// Style for code blocks - gray/dim color
 pub const STYLE_CODE_BLOCK: Style = Style::new().fg(Color::DarkGray);
 
@@ -29,8 +41,12 @@ pub const STYLE_LINK: Style = Style::new()
 /// * `text` - The display text for the link
 ///
 /// # Returns
-/// A string with the text wrapped in OSC 8 escape sequences
+/// A string with the text wrapped in OSC 8 escape sequences, or the plain
+/// text if hyperlinks are disabled
 pub fn wrap_osc8_hyperlink(url: &str, text: &str) -> String {
+    if !HYPERLINKS_ENABLED.load(Ordering::Relaxed) {
+        return text.to_string();
+    }
     // OSC 8 format: ESC ] 8 ; ; url BEL text ESC ] 8 ; ; BEL
     // ESC = \x1B, BEL = \x07
     format!("\x1b]8;;{}\x07{}\x1b]8;;\x07", url, text)
@@ -59,7 +75,8 @@ pub fn strip_osc8_sequences(s: &str) -> String {
 ///
 /// Escape sequences have zero display width in the terminal but contain characters
 /// that would otherwise be counted. This function strips OSC 8 sequences before
-/// calculating width using Unicode width rules.
+/// calculating width using Unicode width rules, per grapheme cluster as ratatui
+/// lays out the buffer (so emoji with modifiers and combining marks count once).
 ///
 /// # Arguments
 /// * `s` - The string to measure
@@ -68,7 +85,7 @@ pub fn strip_osc8_sequences(s: &str) -> String {
 /// The display width in terminal columns
 pub fn display_width_ignoring_escapes(s: &str) -> usize {
     let stripped = strip_osc8_sequences(s);
-    stripped.width()
+    stripped.graphemes(true).map(UnicodeWidthStr::width).sum()
 }
 
 /// Check if a string contains OSC 8 escape sequences
@@ -82,6 +99,20 @@ pub fn contains_osc8_sequence(s: &str) -> bool {
     s.contains("\x1b]8;;")
 }
 
+/// URL of the first OSC 8 hyperlink opened in a string
+///
+/// # Arguments
+/// * `s` - The string to search
+///
+/// # Returns
+/// The URL of the first non-empty `\x1b]8;;{url}\x07` sequence, if any
+pub fn osc8_url(s: &str) -> Option<&str> {
+    s.split("\x1b]8;;")
+        .skip(1)
+        .filter_map(|rest| rest.split_once('\x07').map(|(url, _)| url))
+        .find(|url| !url.is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,6 +127,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_osc8_url() {
+        let linked = "see \x1b]8;;https://example.com\x07docs\x1b]8;;\x07 now";
+        assert_eq!(osc8_url(linked), Some("https://example.com"));
+        assert_eq!(osc8_url("plain text"), None);
+        assert_eq!(osc8_url("\x1b]8;;\x07"), None);
+    }
+
     #[test]
     fn test_wrap_osc8_hyperlink_empty_text() {
         let result = wrap_osc8_hyperlink("https://example.com", "");
@@ -0,0 +1,276 @@
+//! GFM table rendering
+//!
+//! Tables are drawn with box-drawing borders. Given the available width,
+//! columns are narrowed (cells cut with `…`) until the table fits; below
+//! `STACKED_TABLE_WIDTH` columns each row is shown as `Header: value` lines
+//! instead. Every table line fits the width, so the table's height is known
+//! up front (`table_height`).
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Narrowest content width that still gets a bordered table
+pub const STACKED_TABLE_WIDTH: usize = 60;
+
+/// Narrowest column, in display columns
+const MIN_COLUMN_WIDTH: usize = 3;
+
+/// Layout of a table at some width
+enum TableLayout {
+    /// Bordered grid with these column widths
+    Grid(Vec<usize>),
+    /// `Header: value` lines per row
+    Stacked,
+}
+
+/// Rendered height of a table (first row is the header) at `width`
+/// columns (None: unbounded)
+pub fn table_height(rows: &[Vec<String>], width: Option<usize>) -> usize {
+    let natural = natural_widths(rows);
+    if natural.is_empty() {
+        return 0;
+    }
+    match layout(&natural, width) {
+        // Borders, the header separator and a blank line after
+        TableLayout::Grid(_) => rows.len() + 2 + usize::from(rows.len() > 1) + 1,
+        TableLayout::Stacked => stacked_height(rows.len(), natural.len()),
+    }
+}
+
+/// Widest cell of each column
+fn natural_widths(rows: &[Vec<String>]) -> Vec<usize> {
+    let columns = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let mut natural = vec![MIN_COLUMN_WIDTH; columns];
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            natural[i] = natural[i].max(cell.trim().width());
+        }
+    }
+    natural
+}
+
+/// Height of the stacked layout: a line per cell, a rule between rows and a
+/// blank line after
+fn stacked_height(rows: usize, columns: usize) -> usize {
+    match rows {
+        // Header only: the column names on one line
+        1 => 2,
+        _ => (rows - 1) * columns + (rows - 2) + 1,
+    }
+}
+
+/// Column widths that fit `width`, or the stacked layout when they can't
+fn layout(natural: &[usize], width: Option<usize>) -> TableLayout {
+    let Some(width) = width else {
+        return TableLayout::Grid(natural.to_vec());
+    };
+    // Two padding columns per cell and the borders between them
+    let chrome = natural.len() * 3 + 1;
+    let total: usize = natural.iter().sum::<usize>() + chrome;
+    if total <= width {
+        return TableLayout::Grid(natural.to_vec());
+    }
+    if width < STACKED_TABLE_WIDTH || width < chrome + natural.len() * MIN_COLUMN_WIDTH {
+        return TableLayout::Stacked;
+    }
+
+    // Share the room out evenly; columns narrower than their share keep
+    // their width and leave the rest to the wider ones
+    let mut widths = natural.to_vec();
+    let mut remaining = width - chrome;
+    let mut order: Vec<usize> = (0..natural.len()).collect();
+    order.sort_by_key(|&i| natural[i]);
+    for (placed, &column) in order.iter().enumerate() {
+        let share = remaining / (natural.len() - placed);
+        widths[column] = natural[column].min(share).max(MIN_COLUMN_WIDTH);
+        remaining = remaining.saturating_sub(widths[column]);
+    }
+    TableLayout::Grid(widths)
+}
+
+/// Cut a cell to `width` columns, ending with `…` when cut
+fn fit_cell(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    let mut fitted = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width + 1 > width {
+            break;
+        }
+        used += char_width;
+        fitted.push(c);
+    }
+    fitted.push('\u{2026}');
+    fitted
+}
+
+/// Render a table (first row is the header) at `width` columns (None:
+/// unbounded).
+pub fn render_table(rows: &[Vec<String>], width: Option<usize>) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let natural = natural_widths(rows);
+    let columns = natural.len();
+    if columns == 0 {
+        return lines;
+    }
+
+    let border_style = Style::default().fg(Color::DarkGray);
+    let header_style = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let cell = |row: &Vec<String>, i: usize| row.get(i).map(|c| c.trim()).unwrap_or("").to_string();
+
+    let widths = match layout(&natural, width) {
+        TableLayout::Grid(widths) => widths,
+        TableLayout::Stacked => {
+            let header = &rows[0];
+            if rows.len() == 1 {
+                let names: Vec<String> = (0..columns).map(|i| cell(header, i)).collect();
+                lines.push(Line::from(Span::styled(
+                    names.join(" \u{00B7} "),
+                    header_style,
+                )));
+            }
+            for (row_idx, row) in rows.iter().enumerate().skip(1) {
+                if row_idx > 1 {
+                    let rule_width = width.unwrap_or(0).min(20);
+                    lines.push(Line::from(Span::styled(
+                        "\u{2500}".repeat(rule_width),
+                        border_style,
+                    )));
+                }
+                for i in 0..columns {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{}: ", cell(header, i)), header_style),
+                        Span::raw(cell(row, i)),
+                    ]));
+                }
+            }
+            lines.push(Line::from(""));
+            return lines;
+        }
+    };
+
+    lines.push(Line::from(Span::styled(
+        build_table_border(&widths, '┌', '┬', '┐'),
+        border_style,
+    )));
+    for (row_idx, row) in rows.iter().enumerate() {
+        let style = if row_idx == 0 {
+            header_style
+        } else {
+            Style::default()
+        };
+        let mut spans = vec![Span::styled("│".to_string(), border_style)];
+        for (i, &column_width) in widths.iter().enumerate() {
+            let content = fit_cell(&cell(row, i), column_width);
+            let padding = column_width.saturating_sub(content.width());
+            spans.push(Span::styled(
+                format!(" {}{} ", content, " ".repeat(padding)),
+                style,
+            ));
+            spans.push(Span::styled("│".to_string(), border_style));
+        }
+        lines.push(Line::from(spans));
+
+        // Separator after the header row
+        if row_idx == 0 && rows.len() > 1 {
+            lines.push(Line::from(Span::styled(
+                build_table_border(&widths, '├', '┼', '┤'),
+                border_style,
+            )));
+        }
+    }
+    lines.push(Line::from(Span::styled(
+        build_table_border(&widths, '└', '┴', '┘'),
+        border_style,
+    )));
+
+    // Blank line after the table for visual separation
+    lines.push(Line::from(""));
+    lines
+}
+
+/// Build a table border line with the given corner and junction characters.
+fn build_table_border(col_widths: &[usize], left: char, middle: char, right: char) -> String {
+    let mut border = String::new();
+    border.push(left);
+
+    for (i, &width) in col_widths.iter().enumerate() {
+        // +2 for padding on each side of cell content
+        for _ in 0..(width + 2) {
+            border.push('─');
+        }
+        if i < col_widths.len() - 1 {
+            border.push(middle);
+        }
+    }
+
+    border.push(right);
+    border
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(table: &[&[&str]]) -> Vec<Vec<String>> {
+        table
+            .iter()
+            .map(|row| row.iter().map(|c| c.to_string()).collect())
+            .collect()
+    }
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_wide_table_shrinks_to_width_with_ellipsis() {
+        let table = rows(&[
+            &["Name", "Description"],
+            &["parser", &"a very long description ".repeat(6)],
+        ]);
+        let lines = render_table(&table, Some(70));
+        assert!(lines.iter().all(|l| text(l).width() <= 70));
+        assert!(text(&lines[3]).contains('\u{2026}'));
+        assert!(text(&lines[3]).contains("parser"));
+        assert_eq!(lines.len(), table_height(&table, Some(70)));
+    }
+
+    #[test]
+    fn test_narrow_width_stacks_rows() {
+        let table = rows(&[
+            &["Name", "Notes"],
+            &["Alice", "Maintains the parser and the markdown cache"],
+            &["Bob", "Reviews"],
+        ]);
+        let lines = render_table(&table, Some(40));
+        let rendered: Vec<String> = lines.iter().map(text).collect();
+        assert_eq!(rendered[0], "Name: Alice");
+        assert_eq!(
+            rendered[1],
+            "Notes: Maintains the parser and the markdown cache"
+        );
+        assert!(rendered[2].starts_with('\u{2500}'));
+        assert_eq!(rendered[3], "Name: Bob");
+        assert_eq!(lines.len(), table_height(&table, Some(40)));
+
+        // A small table still fits as a grid
+        let small = rows(&[&["A", "B"], &["1", "2"]]);
+        assert!(text(&render_table(&small, Some(40))[0]).starts_with('┌'));
+    }
+
+    #[test]
+    fn test_table_that_fits_keeps_natural_widths() {
+        let table = rows(&[&["A", "B"], &["1", "2"]]);
+        assert_eq!(render_table(&table, Some(80)), render_table(&table, None));
+        assert_eq!(table_height(&table, None), 6);
+    }
+}
@@ -0,0 +1,33 @@
+//! Conversation history imported from other tools.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::MessageRole;
+
+/// A message from an imported transcript
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImportedMessage {
+    /// Author role
+    pub role: MessageRole,
+    /// Message text (markdown, code blocks preserved)
+    pub content: String,
+    /// Original timestamp, if the transcript recorded one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// A conversation converted from another tool's transcript
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImportedThread {
+    /// Thread title
+    pub title: String,
+    /// Working directory the conversation ran in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_directory: Option<String>,
+    /// Tool the transcript came from (e.g. "claude-code")
+    #[serde(default)]
+    pub source: String,
+    /// Messages in chronological order
+    pub messages: Vec<ImportedMessage>,
+}
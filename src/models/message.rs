@@ -71,6 +71,9 @@ pub struct ServerMessage {
     /// Image hashes for attached images (from backend persistence)
     #[serde(default)]
     pub image_hashes: Option<Vec<String>>,
+    /// ID of the earlier message this one replies to
+    #[serde(default)]
+    pub reply_to: Option<i64>,
 }
 
 impl ServerMessage {
@@ -157,6 +160,7 @@ impl ServerMessage {
             segments,
             render_version: 0,
             image_hashes: self.image_hashes.unwrap_or_default(),
+            reply_to: self.reply_to,
         }
     }
 }
@@ -195,6 +199,9 @@ pub struct Message {
     /// Image hashes for attached images (user messages only)
     #[serde(default)]
     pub image_hashes: Vec<String>,
+    /// ID of the earlier message this one replies to (user messages only)
+    #[serde(default)]
+    pub reply_to: Option<i64>,
 }
 
 impl Message {
@@ -228,6 +235,25 @@ impl Message {
         }
     }
 
+    /// First non-empty line of the content, cut to `max_chars` with "…"
+    pub fn excerpt(&self, max_chars: usize) -> String {
+        let content = if self.is_streaming {
+            &self.partial_content
+        } else {
+            &self.content
+        };
+        let line = content
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or_default();
+        if line.chars().count() <= max_chars {
+            return line.to_string();
+        }
+        let cut: String = line.chars().take(max_chars.saturating_sub(1)).collect();
+        format!("{}\u{2026}", cut.trim_end())
+    }
+
     /// Toggle the reasoning collapsed state
     pub fn toggle_reasoning_collapsed(&mut self) {
         self.reasoning_collapsed = !self.reasoning_collapsed;
@@ -315,6 +341,45 @@ impl Message {
         }
     }
 
+    /// Set the result of a tool event by its tool_call_id
+    pub fn set_tool_result(&mut self, tool_call_id: &str, content: &str, is_error: bool) {
+        for segment in &mut self.segments {
+            if let MessageSegment::ToolEvent(event) = segment {
+                if event.tool_call_id == tool_call_id {
+                    event.set_result(content, is_error);
+                    self.invalidate_render_cache();
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Check if any tool event has output to show
+    pub fn has_tool_output(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|s| matches!(s, MessageSegment::ToolEvent(e) if e.has_output()))
+    }
+
+    /// Toggle the output blocks of the tool events.
+    ///
+    /// Expands every block if any is collapsed, otherwise collapses them all.
+    /// Returns whether the blocks are now expanded.
+    pub fn toggle_tool_output(&mut self) -> bool {
+        let expand = self.segments.iter().any(
+            |s| matches!(s, MessageSegment::ToolEvent(e) if e.has_output() && !e.output_expanded),
+        );
+        for segment in &mut self.segments {
+            if let MessageSegment::ToolEvent(event) = segment {
+                if event.has_output() {
+                    event.output_expanded = expand;
+                }
+            }
+        }
+        self.invalidate_render_cache();
+        expand
+    }
+
     /// Append a chunk of JSON arguments to a tool event by its tool_call_id
     pub fn append_tool_arg_chunk(&mut self, tool_call_id: &str, chunk: &str) {
         for segment in &mut self.segments {
@@ -390,6 +455,33 @@ impl Message {
             matches!(s, MessageSegment::SubagentEvent(e) if e.status == SubagentEventStatus::Running)
         })
     }
+
+    /// Check if there are several subagents, whose tree collapses to a
+    /// summary line once the response is done
+    pub fn has_subagent_tree(&self) -> bool {
+        self.segments
+            .iter()
+            .filter(|s| matches!(s, MessageSegment::SubagentEvent(_)))
+            .count()
+            > 1
+    }
+
+    /// Expand or collapse the subagent tree.
+    ///
+    /// Returns whether the tree is now expanded.
+    pub fn toggle_subagents(&mut self) -> bool {
+        let expand = !self
+            .segments
+            .iter()
+            .any(|s| matches!(s, MessageSegment::SubagentEvent(e) if e.expanded));
+        for segment in &mut self.segments {
+            if let MessageSegment::SubagentEvent(event) = segment {
+                event.expanded = expand;
+            }
+        }
+        self.invalidate_render_cache();
+        expand
+    }
 }
 
 #[cfg(test)]
@@ -411,6 +503,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         }
     }
 
@@ -462,6 +555,37 @@ mod tests {
         assert_eq!(message.render_version, 2);
     }
 
+    #[test]
+    fn test_excerpt_uses_first_line() {
+        let mut message = create_test_message();
+        message.content = "\n  Use the retry queue here.\nSecond line".to_string();
+        assert_eq!(message.excerpt(40), "Use the retry queue here.");
+        assert_eq!(message.excerpt(10), "Use the r\u{2026}");
+    }
+
+    #[test]
+    fn test_toggle_tool_output_expands_then_collapses() {
+        let mut message = create_test_message();
+        message.start_tool_event("tool-1".to_string(), "Bash".to_string());
+        message.start_tool_event("tool-2".to_string(), "Read".to_string());
+        assert!(!message.has_tool_output());
+
+        let version = message.render_version;
+        message.set_tool_result("tool-1", "permission denied", true);
+        assert!(message.render_version > version);
+        assert!(message.has_tool_output());
+
+        let version = message.render_version;
+        assert!(message.toggle_tool_output());
+        assert!(message.render_version > version);
+        assert!(message.get_tool_event("tool-1").unwrap().output_expanded);
+        // Tools without output stay collapsed
+        assert!(!message.get_tool_event("tool-2").unwrap().output_expanded);
+
+        assert!(!message.toggle_tool_output());
+        assert!(!message.get_tool_event("tool-1").unwrap().output_expanded);
+    }
+
     #[test]
     fn test_render_version_increments_on_subagent_events() {
         let mut message = create_test_message();
@@ -478,6 +602,26 @@ mod tests {
         assert_eq!(message.render_version, 3);
     }
 
+    #[test]
+    fn test_toggle_subagents() {
+        let mut message = create_test_message();
+        message.start_subagent_event(
+            "task-1".to_string(),
+            "One".to_string(),
+            "Explore".to_string(),
+        );
+        assert!(!message.has_subagent_tree());
+        message.start_subagent_event("task-2".to_string(), "Two".to_string(), "Plan".to_string());
+        assert!(message.has_subagent_tree());
+
+        let version = message.render_version;
+        assert!(message.toggle_subagents());
+        assert!(message.get_subagent_event("task-2").unwrap().expanded);
+        assert!(message.render_version > version);
+        assert!(!message.toggle_subagents());
+        assert!(!message.get_subagent_event("task-1").unwrap().expanded);
+    }
+
     #[test]
     fn test_start_subagent_event() {
         let mut message = create_test_message();
@@ -869,6 +1013,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
 
         let msg = server_msg.to_client_message("thread-1", 42);
@@ -923,6 +1068,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
 
         let msg = server_msg.to_client_message("thread-1", 1);
@@ -948,6 +1094,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
 
         let msg = server_msg.to_client_message("thread-2", 99);
@@ -988,6 +1135,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
 
         let msg = server_msg.to_client_message("thread-3", 5);
@@ -1030,6 +1178,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
 
         let msg = server_msg.to_client_message("thread-4", 0);
@@ -1057,6 +1206,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
 
         let msg = server_msg.to_client_message("thread-5", 10);
@@ -1087,6 +1237,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
 
         let msg = server_msg.to_client_message("thread-6", 20);
@@ -1118,6 +1269,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
         let client_msg = server_msg.to_client_message("thread-1", 1);
         assert_eq!(client_msg.content, "User's actual message");
@@ -1140,6 +1292,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
         let client_msg = server_msg.to_client_message("thread-1", 1);
         // Assistant messages should NOT have prefix stripped
@@ -1157,6 +1310,7 @@ mod tests {
             tool_call_id: None,
             name: None,
             image_hashes: None,
+            reply_to: None,
         };
         let client_msg = server_msg.to_client_message("thread-1", 1);
         assert_eq!(client_msg.content, "Block content");
@@ -1,6 +1,8 @@
 pub mod dashboard;
 pub mod file;
 mod folder;
+mod history_import;
+mod model_info;
 mod repository;
 mod message;
 pub mod picker;
@@ -16,11 +18,16 @@ pub use dashboard::{
 };
 pub use file::FileEntry;
 pub use folder::Folder;
+pub use history_import::{ImportedMessage, ImportedThread};
+pub use model_info::{ModelInfo, ModelListResponse};
 pub use repository::{GitHubRepo, PrimaryLanguage};
 pub use message::*;
 pub use picker::*;
 pub use request::PermissionMode;
-pub use request::{CancelRequest, CancelResponse, ImageAttachmentPayload, StreamRequest};
+pub use request::{
+    CancelRequest, CancelResponse, CompactResponse, ImageAttachmentPayload, ResumeStreamRequest,
+    ShareOptions, ShareVisibility, StreamRequest, ThreadShare,
+};
 pub use steering::{QueuedSteeringMessage, SteeringMessageState};
 pub use text_utils::strip_thread_prefix;
 pub use thread::*;
@@ -150,6 +157,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         assert_eq!(thread.id, "thread-123");
@@ -177,6 +189,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         assert_eq!(thread.id, "thread-456");
@@ -202,6 +219,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         let json = serde_json::to_string(&thread).expect("Failed to serialize");
@@ -228,6 +250,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         let json = serde_json::to_string(&thread).expect("Failed to serialize");
@@ -385,6 +412,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         assert_eq!(message.id, 1);
@@ -419,6 +447,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         let json = serde_json::to_string(&message).expect("Failed to serialize");
@@ -442,6 +471,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.append_token("Hello");
@@ -469,6 +499,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         // append_token should add to both partial_content AND segments
@@ -507,6 +538,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         assert!(message.is_streaming);
@@ -535,6 +567,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.finalize();
@@ -560,6 +593,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         // Simulate streaming tokens
@@ -595,6 +629,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         let json = serde_json::to_string(&message).expect("Failed to serialize");
@@ -655,6 +690,18 @@ mod tests {
         assert_eq!(request.reply_to, Some(42));
     }
 
+    #[test]
+    fn test_stream_request_with_reply_to_builder() {
+        let request = StreamRequest::with_thread("Why?".to_string(), "thread-123".to_string())
+            .with_reply_to(Some(7));
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["reply_to"], 7);
+
+        let request = request.with_reply_to(None);
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("reply_to").is_none());
+    }
+
     #[test]
     fn test_stream_request_serialization() {
         let request = StreamRequest {
@@ -665,8 +712,11 @@ mod tests {
             thread_type: Some(ThreadType::Programming),
             permission_mode: Some(PermissionMode::Plan),
             working_directory: None,
+            additional_directories: Vec::new(),
             plan_mode: false,
             images: Vec::new(),
+            attachments: Vec::new(),
+            model: Some("claude-opus-4-5".to_string()),
             use_next_account: None,
             current_account_id: None,
         };
@@ -762,6 +812,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.append_reasoning_token("Let me think...");
@@ -786,6 +837,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         // "Let me analyze this step by step" = 7 words
@@ -810,6 +862,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         assert!(!message.reasoning_collapsed);
@@ -834,6 +887,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         // Reasoning should not be collapsed while streaming
@@ -862,6 +916,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.finalize();
@@ -886,6 +941,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         let json = serde_json::to_string(&message).expect("Failed to serialize");
@@ -1166,6 +1222,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.start_tool_event("tool-333".to_string(), "Read".to_string());
@@ -1195,6 +1252,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.start_tool_event("tool-444".to_string(), "Bash".to_string());
@@ -1224,6 +1282,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.start_tool_event("tool-555".to_string(), "Write".to_string());
@@ -1252,6 +1311,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.start_tool_event("tool-666".to_string(), "Grep".to_string());
@@ -1279,6 +1339,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         assert!(!message.has_running_tools());
@@ -1305,6 +1366,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.start_tool_event("tool-1".to_string(), "Read".to_string());
@@ -1337,6 +1399,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.add_text_segment("Hello".to_string());
@@ -1365,6 +1428,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.add_text_segment("Let me check that file...".to_string());
@@ -1394,6 +1458,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         // Start a tool event
@@ -1432,6 +1497,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         // Start multiple tool events
@@ -1472,6 +1538,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         message.start_tool_event("tool-123".to_string(), "Read".to_string());
@@ -1501,6 +1568,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         // Simulate a realistic interleaved streaming scenario
@@ -1559,6 +1627,7 @@ mod tests {
             segments: Vec::new(),
             render_version: 0,
             image_hashes: Vec::new(),
+            reply_to: None,
         };
 
         // Build up content with interleaved text and tools
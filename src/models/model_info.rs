@@ -0,0 +1,48 @@
+//! Model catalog from the conductor API.
+
+use serde::{Deserialize, Serialize};
+
+/// A model threads can run with
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ModelInfo {
+    /// Model ID sent with stream requests (e.g., "claude-sonnet-4-5")
+    pub id: String,
+
+    /// Human-readable name (e.g., "Claude Sonnet 4.5")
+    #[serde(default, alias = "name")]
+    pub display_name: Option<String>,
+}
+
+impl ModelInfo {
+    /// Name to show in the picker (falls back to the ID)
+    pub fn label(&self) -> &str {
+        self.display_name.as_deref().unwrap_or(&self.id)
+    }
+}
+
+/// Response from `GET /v1/models`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ModelListResponse {
+    pub models: Vec<ModelInfo>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_list_deserialization() {
+        let json = r#"{
+            "models": [
+                {"id": "claude-opus-4-5", "name": "Claude Opus 4.5"},
+                {"id": "claude-haiku-4-5"}
+            ]
+        }"#;
+
+        let response: ModelListResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.models.len(), 2);
+        assert_eq!(response.models[0].label(), "Claude Opus 4.5");
+        assert_eq!(response.models[1].label(), "claude-haiku-4-5");
+    }
+}
@@ -41,6 +41,17 @@ pub struct FolderEntry {
     pub is_dir: bool,
 }
 
+/// Response from /v1/fs/list
+#[derive(Debug, Clone, Deserialize)]
+pub struct DirectoryListing {
+    /// Absolute path of the listed directory (`~` expanded)
+    pub path: String,
+    /// Parent directory, None at the filesystem root
+    #[serde(default)]
+    pub parent: Option<String>,
+    pub entries: Vec<FolderEntry>,
+}
+
 /// Response from /v1/search/repos
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -91,6 +102,8 @@ pub struct ThreadEntry {
     pub last_activity: Option<String>,
     #[serde(default)]
     pub message_count: Option<i64>,
+    #[serde(default)]
+    pub archived: bool,
 }
 
 /// Response from /v1/clone
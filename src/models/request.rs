@@ -38,7 +38,7 @@ pub struct StreamRequest {
     /// Thread ID - None means create a new thread
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_id: Option<String>,
-    /// Message ID to reply to - for future stitching support
+    /// ID of the earlier message this prompt replies to
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<i64>,
     /// Type of thread to create (normal or programming)
@@ -50,12 +50,21 @@ pub struct StreamRequest {
     /// Working directory for the request
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_directory: Option<String>,
+    /// More directories the agent may work in, besides the working directory
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_directories: Vec<String>,
     /// Plan mode flag - when true, conductor uses read-only tool registry
     #[serde(default)]
     pub plan_mode: bool,
     /// Image attachments as base64-encoded PNGs
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub images: Vec<ImageAttachmentPayload>,
+    /// IDs of attachments uploaded beforehand via `POST /v1/attachments`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
+    /// Model to run the thread with (None = the thread's or backend's default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
     /// Use next account after rate limit (waterfall failover)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_next_account: Option<bool>,
@@ -75,8 +84,11 @@ impl StreamRequest {
             thread_type: None,
             permission_mode: None,
             working_directory: None,
+            additional_directories: Vec::new(),
             plan_mode: false,
             images: Vec::new(),
+            attachments: Vec::new(),
+            model: None,
             use_next_account: None,
             current_account_id: None,
         }
@@ -92,8 +104,11 @@ impl StreamRequest {
             thread_type: None,
             permission_mode: None,
             working_directory: None,
+            additional_directories: Vec::new(),
             plan_mode: false,
             images: Vec::new(),
+            attachments: Vec::new(),
+            model: None,
             use_next_account: None,
             current_account_id: None,
         }
@@ -110,8 +125,11 @@ impl StreamRequest {
             thread_type: None,
             permission_mode: None,
             working_directory: None,
+            additional_directories: Vec::new(),
             plan_mode: false,
             images: Vec::new(),
+            attachments: Vec::new(),
+            model: None,
             use_next_account: None,
             current_account_id: None,
         }
@@ -135,6 +153,12 @@ impl StreamRequest {
         self
     }
 
+    /// Set additional directories for this request (builder pattern)
+    pub fn with_additional_directories(mut self, paths: Vec<String>) -> Self {
+        self.additional_directories = paths;
+        self
+    }
+
     /// Set plan mode for this request (builder pattern)
     pub fn with_plan_mode(mut self, plan_mode: bool) -> Self {
         self.plan_mode = plan_mode;
@@ -147,6 +171,24 @@ impl StreamRequest {
         self
     }
 
+    /// Reference uploaded attachments by ID (builder pattern)
+    pub fn with_attachments(mut self, attachment_ids: Vec<String>) -> Self {
+        self.attachments = attachment_ids;
+        self
+    }
+
+    /// Set the model for this request (builder pattern)
+    pub fn with_model(mut self, model: Option<String>) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Reply to a specific earlier message (builder pattern)
+    pub fn with_reply_to(mut self, reply_to: Option<i64>) -> Self {
+        self.reply_to = reply_to;
+        self
+    }
+
     /// Set use_next_account flag for rate limit failover (builder pattern)
     pub fn with_use_next_account(mut self, use_next: bool, current_id: String) -> Self {
         self.use_next_account = Some(use_next);
@@ -169,6 +211,26 @@ impl CancelRequest {
     }
 }
 
+/// Request structure for resuming an interrupted stream
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResumeStreamRequest {
+    /// The thread ID whose stream should be resumed
+    pub thread_id: String,
+    /// Sequence number of the last event received (None = replay from start)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_event_id: Option<u64>,
+}
+
+impl ResumeStreamRequest {
+    /// Create a new ResumeStreamRequest for the given thread
+    pub fn new(thread_id: String, last_event_id: Option<u64>) -> Self {
+        Self {
+            thread_id,
+            last_event_id,
+        }
+    }
+}
+
 /// Response structure from the cancel endpoint
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CancelResponse {
@@ -187,6 +249,76 @@ impl CancelResponse {
     }
 }
 
+/// Response structure from the compact endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompactResponse {
+    /// Context tokens before compacting
+    pub tokens_before: u32,
+    /// Context tokens after compacting
+    pub tokens_after: u32,
+    /// Context window size, if the backend knows it
+    #[serde(default)]
+    pub token_limit: Option<u32>,
+}
+
+/// Who can open a shared thread's link
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ShareVisibility {
+    /// Anyone with the link
+    #[default]
+    LinkOnly,
+    /// Signed-in members of the owner's organization
+    Org,
+}
+
+impl ShareVisibility {
+    /// Label shown in the share dialog
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShareVisibility::LinkOnly => "Anyone with the link",
+            ShareVisibility::Org => "My organization",
+        }
+    }
+
+    /// The other visibility
+    pub fn toggled(&self) -> Self {
+        match self {
+            ShareVisibility::LinkOnly => ShareVisibility::Org,
+            ShareVisibility::Org => ShareVisibility::LinkOnly,
+        }
+    }
+}
+
+/// Request body of the share endpoint: what the shared copy contains
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ShareOptions {
+    pub visibility: ShareVisibility,
+    /// Include the output of tool calls
+    pub include_tool_outputs: bool,
+    /// Include reasoning blocks
+    pub include_reasoning: bool,
+}
+
+impl Default for ShareOptions {
+    fn default() -> Self {
+        Self {
+            visibility: ShareVisibility::LinkOnly,
+            include_tool_outputs: true,
+            include_reasoning: false,
+        }
+    }
+}
+
+/// Response structure from the share endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ThreadShare {
+    /// Shareable link to the thread
+    pub url: String,
+    #[serde(default)]
+    pub visibility: ShareVisibility,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -373,6 +505,21 @@ mod tests {
         assert!(!json.contains("working_directory"));
     }
 
+    #[test]
+    fn test_stream_request_with_additional_directories_serialization() {
+        let request = StreamRequest::new("Test".to_string())
+            .with_working_directory(Some("/home/user/frontend".to_string()))
+            .with_additional_directories(vec!["/home/user/backend".to_string()]);
+
+        let json = serde_json::to_string(&request).expect("Failed to serialize");
+        assert!(json.contains(r#""additional_directories":["/home/user/backend"]"#));
+
+        // Omitted when there are none
+        let json = serde_json::to_string(&StreamRequest::new("Test".to_string()))
+            .expect("Failed to serialize");
+        assert!(!json.contains("additional_directories"));
+    }
+
     #[test]
     fn test_stream_request_full_builder_chain() {
         let request = StreamRequest::new("Code task".to_string())
@@ -389,6 +536,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stream_request_with_model_serialization() {
+        let request = StreamRequest::new("Test".to_string());
+        let json = serde_json::to_string(&request).expect("Failed to serialize");
+        assert!(!json.contains("\"model\""));
+
+        let request = request.with_model(Some("claude-opus-4-5".to_string()));
+        let json = serde_json::to_string(&request).expect("Failed to serialize");
+        assert!(json.contains(r#""model":"claude-opus-4-5""#));
+    }
+
     // ============= CancelRequest Tests =============
 
     #[test]
@@ -408,6 +566,22 @@ mod tests {
         assert_eq!(request, deserialized);
     }
 
+    // ============= ResumeStreamRequest Tests =============
+
+    #[test]
+    fn test_resume_stream_request_serialization() {
+        let request = ResumeStreamRequest::new("thread-abc".to_string(), Some(42));
+        let json = serde_json::to_string(&request).expect("Failed to serialize");
+        assert_eq!(json, r#"{"thread_id":"thread-abc","last_event_id":42}"#);
+    }
+
+    #[test]
+    fn test_resume_stream_request_without_last_event_id() {
+        let request = ResumeStreamRequest::new("thread-abc".to_string(), None);
+        let json = serde_json::to_string(&request).expect("Failed to serialize");
+        assert_eq!(json, r#"{"thread_id":"thread-abc"}"#);
+    }
+
     // ============= CancelResponse Tests =============
 
     #[test]
@@ -440,4 +614,31 @@ mod tests {
         assert_eq!(response.message, "OK");
         assert!(response.is_cancelled());
     }
+
+    // ============= Share Tests =============
+
+    #[test]
+    fn test_share_options_serialization() {
+        let options = ShareOptions {
+            visibility: ShareVisibility::Org,
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "visibility": "org",
+                "include_tool_outputs": true,
+                "include_reasoning": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_thread_share_defaults_to_link_only() {
+        let share: ThreadShare =
+            serde_json::from_str(r#"{"url":"https://spoq.dev/s/abc"}"#).unwrap();
+        assert_eq!(share.url, "https://spoq.dev/s/abc");
+        assert_eq!(share.visibility, ShareVisibility::LinkOnly);
+    }
 }
@@ -6,7 +6,9 @@ use uuid::Uuid;
 use super::dashboard::{
     compute_duration, derive_repository, infer_status_from_agent_state, ThreadStatus,
 };
-use super::{deserialize_id, deserialize_nullable_string, deserialize_thread_type, ServerMessage};
+use super::{
+    deserialize_id, deserialize_nullable_string, deserialize_thread_type, Message, ServerMessage,
+};
 
 /// Type of thread - determines UI behavior and available features
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -45,6 +47,73 @@ impl ThreadMode {
     }
 }
 
+/// Typed relation from one thread to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreadLinkKind {
+    /// This thread is a follow-up of the target thread
+    FollowUpOf,
+    /// This thread can't proceed until the target thread is done
+    BlockedBy,
+}
+
+impl ThreadLinkKind {
+    /// All relation kinds, in the order the link editor cycles through them
+    pub const ALL: [ThreadLinkKind; 2] = [ThreadLinkKind::FollowUpOf, ThreadLinkKind::BlockedBy];
+
+    /// Label for the relation as seen from the linking thread
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThreadLinkKind::FollowUpOf => "follows",
+            ThreadLinkKind::BlockedBy => "blocked by",
+        }
+    }
+
+    /// Label for the relation as seen from the target thread
+    pub fn inverse_label(&self) -> &'static str {
+        match self {
+            ThreadLinkKind::FollowUpOf => "followed by",
+            ThreadLinkKind::BlockedBy => "blocks",
+        }
+    }
+
+    /// Icon shown in chips and hints
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ThreadLinkKind::FollowUpOf => "\u{21b3}",
+            ThreadLinkKind::BlockedBy => "\u{2298}",
+        }
+    }
+
+    /// The next relation kind (wraps around)
+    pub fn next(&self) -> Self {
+        match self {
+            ThreadLinkKind::FollowUpOf => ThreadLinkKind::BlockedBy,
+            ThreadLinkKind::BlockedBy => ThreadLinkKind::FollowUpOf,
+        }
+    }
+}
+
+/// A link from a thread to another thread
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ThreadLink {
+    /// Relation to the target thread
+    pub kind: ThreadLinkKind,
+    /// ID of the target thread
+    #[serde(deserialize_with = "deserialize_id")]
+    pub thread_id: String,
+}
+
+impl ThreadLink {
+    /// Create a link to `thread_id`
+    pub fn new(kind: ThreadLinkKind, thread_id: impl Into<String>) -> Self {
+        Self {
+            kind,
+            thread_id: thread_id.into(),
+        }
+    }
+}
+
 /// Represents an inline error to be displayed in a thread
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ErrorInfo {
@@ -128,6 +197,28 @@ pub struct Thread {
     /// When the verification occurred
     #[serde(default)]
     pub verified_at: Option<DateTime<Utc>>,
+
+    /// Links to related threads ("follows", "blocked by"), edited with `/link`
+    #[serde(default)]
+    pub links: Vec<ThreadLink>,
+
+    /// Whether the thread is pinned to the top of the thread lists
+    #[serde(default)]
+    pub pinned: bool,
+
+    /// Position among pinned threads (lower sorts first), reordered manually
+    #[serde(default)]
+    pub pin_order: u32,
+
+    /// Whether the thread is archived: hidden from the CommandDeck and the
+    /// thread switcher, but kept with its messages
+    #[serde(default)]
+    pub archived: bool,
+
+    /// Whether the thread is muted (`m` or `/mute`): no desktop notifications
+    /// and never the needs-action target. Local only, kept in `ThreadState`
+    #[serde(default)]
+    pub muted: bool,
 }
 
 impl Thread {
@@ -227,3 +318,27 @@ pub struct ThreadDetailResponse {
     #[serde(default)]
     pub messages: Vec<ServerMessage>,
 }
+
+/// One page of a thread's messages, from the paginated messages endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MessagePageResponse {
+    /// Messages in this page, oldest first
+    #[serde(default)]
+    pub messages: Vec<ServerMessage>,
+    /// Number of messages in the thread before this page
+    #[serde(default)]
+    pub offset: usize,
+}
+
+impl MessagePageResponse {
+    /// Convert to client messages, numbered by their position in the thread
+    /// so later pages line up with the ones already loaded
+    pub fn into_client_messages(self, thread_id: &str) -> Vec<Message> {
+        let offset = self.offset as i64;
+        self.messages
+            .into_iter()
+            .enumerate()
+            .map(|(i, m)| m.to_client_message(thread_id, offset + i as i64 + 1))
+            .collect()
+    }
+}
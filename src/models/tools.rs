@@ -41,6 +41,12 @@ pub struct ToolEvent {
     /// Whether the result was an error
     #[serde(default)]
     pub result_is_error: bool,
+    /// Full result content, shown in the expandable output block
+    #[serde(default)]
+    pub output: Option<String>,
+    /// Whether the output block is expanded (collapsed by default)
+    #[serde(default)]
+    pub output_expanded: bool,
 }
 
 impl ToolEvent {
@@ -58,6 +64,8 @@ impl ToolEvent {
             args_display: None,
             result_preview: None,
             result_is_error: false,
+            output: None,
+            output_expanded: false,
         }
     }
 
@@ -82,7 +90,7 @@ impl ToolEvent {
         self.args_json.push_str(chunk);
     }
 
-    /// Set the result preview, truncating if necessary
+    /// Set the result preview, truncating if necessary, and keep the full output
     ///
     /// # Arguments
     /// * `content` - The full result content
@@ -91,6 +99,7 @@ impl ToolEvent {
         const MAX_PREVIEW_LEN: usize = 500;
 
         self.result_is_error = is_error;
+        self.output = Some(content.to_string());
 
         if content.len() <= MAX_PREVIEW_LEN {
             self.result_preview = Some(content.to_string());
@@ -116,6 +125,11 @@ impl ToolEvent {
             self.result_preview = Some(format!("{}...", preview));
         }
     }
+
+    /// Whether there is non-empty output to show
+    pub fn has_output(&self) -> bool {
+        self.output.as_deref().is_some_and(|o| !o.trim().is_empty())
+    }
 }
 
 /// Function details within a tool call
@@ -175,6 +189,10 @@ pub struct SubagentEvent {
     /// Number of tool calls made by the subagent
     #[serde(default)]
     pub tool_call_count: usize,
+    /// Whether the subagent tree stays expanded once the response is done
+    /// (collapsed to a summary line by default)
+    #[serde(default)]
+    pub expanded: bool,
 }
 
 impl SubagentEvent {
@@ -191,9 +209,16 @@ impl SubagentEvent {
             progress_message: None,
             summary: None,
             tool_call_count: 0,
+            expanded: false,
         }
     }
 
+    /// Whole seconds the subagent has been running, or ran for once complete
+    pub fn elapsed_secs(&self) -> i64 {
+        let end = self.completed_at.unwrap_or_else(Utc::now);
+        (end - self.started_at).num_seconds().max(0)
+    }
+
     /// Update the progress message and optionally increment tool call count
     pub fn update_progress(&mut self, message: Option<String>, increment_tool_calls: bool) {
         self.progress_message = message;
@@ -216,6 +241,21 @@ impl SubagentEvent {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tool_event_set_result_keeps_full_output() {
+        let mut event = ToolEvent::new("call-1".to_string(), "Bash".to_string());
+        assert!(!event.has_output());
+
+        let stderr = "error: ".repeat(200);
+        event.set_result(&stderr, true);
+
+        assert!(event.result_is_error);
+        assert!(event.result_preview.as_ref().unwrap().len() < stderr.len());
+        assert_eq!(event.output.as_deref(), Some(stderr.as_str()));
+        assert!(event.has_output());
+        assert!(!event.output_expanded);
+    }
+
     #[test]
     fn test_subagent_event_new() {
         let event = SubagentEvent::new(
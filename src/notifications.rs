@@ -1,26 +1,177 @@
-//! Native OS notification support for task completion events.
+//! Native OS notification support.
 //!
-//! Sends macOS Notification Center banners when the TUI is not focused
-//! and a task (stream) completes. Uses `osascript` on macOS for reliable
-//! delivery from terminal apps (no bundle identifier or permissions needed).
+//! Sends macOS Notification Center banners when the TUI is not focused and
+//! something happens: a response finishes, a permission or question needs an
+//! answer, a task fails or an update is ready. Uses `osascript` on macOS for
+//! reliable delivery from terminal apps (no bundle identifier or permissions
+//! needed).
+//!
+//! Which events notify is set in `notifications` in `~/.spoq/config.json`
+//! (or with `/notify`); quiet hours suppress everything but failures.
+
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+
+/// Kind of event a notification is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    /// A response finished streaming
+    StreamComplete,
+    /// A tool call waits for permission
+    PermissionRequested,
+    /// The agent asked a question (AskUserQuestion)
+    QuestionAsked,
+    /// A response failed
+    TaskFailed,
+    /// A new version was downloaded
+    UpdateAvailable,
+}
+
+impl NotificationKind {
+    /// All kinds, in settings order
+    pub const ALL: [NotificationKind; 5] = [
+        NotificationKind::StreamComplete,
+        NotificationKind::PermissionRequested,
+        NotificationKind::QuestionAsked,
+        NotificationKind::TaskFailed,
+        NotificationKind::UpdateAvailable,
+    ];
+
+    /// Name used by `/notify <name>`
+    pub fn name(self) -> &'static str {
+        match self {
+            NotificationKind::StreamComplete => "complete",
+            NotificationKind::PermissionRequested => "permission",
+            NotificationKind::QuestionAsked => "question",
+            NotificationKind::TaskFailed => "failure",
+            NotificationKind::UpdateAvailable => "update",
+        }
+    }
+
+    /// Look a kind up by its `/notify` name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Notification subtitle
+    pub fn subtitle(self) -> &'static str {
+        match self {
+            NotificationKind::StreamComplete => "Task Complete",
+            NotificationKind::PermissionRequested => "Permission Needed",
+            NotificationKind::QuestionAsked => "Question",
+            NotificationKind::TaskFailed => "Task Failed",
+            NotificationKind::UpdateAvailable => "Update Available",
+        }
+    }
+}
 
-/// Send a native OS notification for task completion.
+/// Daily window in which only failures notify, e.g. 22:00–08:00.
 ///
-/// Spawns a background task so the notification dispatch never blocks
-/// the event loop. Errors are logged and silently discarded.
-pub fn notify_task_complete(thread_title: Option<&str>) {
-    let body = match thread_title {
-        Some(title) if !title.is_empty() => title.to_string(),
-        _ => "Agent response finished".to_string(),
-    };
+/// A window whose end is before its start wraps past midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    /// Local time the window starts ("22:00")
+    pub start: NaiveTime,
+    /// Local time the window ends ("08:00")
+    pub end: NaiveTime,
+}
 
-    tracing::debug!("Sending OS notification: {}", body);
+impl QuietHours {
+    /// Whether `time` falls inside the window
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
 
-    tokio::spawn(async move {
-        let _ = tokio::task::spawn_blocking(move || {
-            send_notification("spoq", "Task Complete", &body);
-        })
-        .await;
+/// Desktop notification preferences (`notifications` in the config file)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSettings {
+    /// Master switch
+    pub enabled: bool,
+    /// Notify when a response finishes
+    pub stream_complete: bool,
+    /// Notify when a tool call needs permission
+    pub permission_requested: bool,
+    /// Notify when the agent asks a question
+    pub question_asked: bool,
+    /// Notify when a response fails
+    pub task_failed: bool,
+    /// Notify when an update is ready to install
+    pub update_available: bool,
+    /// Window in which only failures notify
+    pub quiet_hours: Option<QuietHours>,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            stream_complete: true,
+            permission_requested: true,
+            question_asked: true,
+            task_failed: true,
+            update_available: true,
+            quiet_hours: None,
+        }
+    }
+}
+
+impl NotificationSettings {
+    /// Whether a kind is switched on (ignoring the master switch)
+    pub fn category(&self, kind: NotificationKind) -> bool {
+        match kind {
+            NotificationKind::StreamComplete => self.stream_complete,
+            NotificationKind::PermissionRequested => self.permission_requested,
+            NotificationKind::QuestionAsked => self.question_asked,
+            NotificationKind::TaskFailed => self.task_failed,
+            NotificationKind::UpdateAvailable => self.update_available,
+        }
+    }
+
+    /// Switch a kind on or off
+    pub fn set_category(&mut self, kind: NotificationKind, on: bool) {
+        let flag = match kind {
+            NotificationKind::StreamComplete => &mut self.stream_complete,
+            NotificationKind::PermissionRequested => &mut self.permission_requested,
+            NotificationKind::QuestionAsked => &mut self.question_asked,
+            NotificationKind::TaskFailed => &mut self.task_failed,
+            NotificationKind::UpdateAvailable => &mut self.update_available,
+        };
+        *flag = on;
+    }
+
+    /// Whether a notification of `kind` may fire at local time `now`.
+    ///
+    /// Quiet hours let failures through.
+    pub fn allows(&self, kind: NotificationKind, now: NaiveTime) -> bool {
+        if !self.enabled || !self.category(kind) {
+            return false;
+        }
+        let quiet = self.quiet_hours.is_some_and(|q| q.contains(now));
+        !quiet || kind == NotificationKind::TaskFailed
+    }
+}
+
+/// Send a native OS notification.
+///
+/// Dispatches on a blocking task so the notification never blocks the event
+/// loop; outside a Tokio runtime (e.g. in tests) nothing is sent. Errors are
+/// logged and silently discarded.
+pub fn notify(kind: NotificationKind, body: String) {
+    let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+        return;
+    };
+    tracing::debug!("Sending OS notification ({:?}): {}", kind, body);
+
+    runtime.spawn_blocking(move || {
+        send_notification("spoq", kind.subtitle(), &body);
     });
 }
 
@@ -56,3 +207,49 @@ fn send_notification(title: &str, subtitle: &str, body: &str) {
 fn send_notification(_title: &str, _subtitle: &str, _body: &str) {
     // No-op on non-macOS platforms for now
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_quiet_hours_wrap_past_midnight() {
+        let quiet = QuietHours {
+            start: at(22, 0),
+            end: at(8, 0),
+        };
+        assert!(quiet.contains(at(23, 30)));
+        assert!(quiet.contains(at(3, 0)));
+        assert!(!quiet.contains(at(8, 0)));
+        assert!(!quiet.contains(at(12, 0)));
+    }
+
+    #[test]
+    fn test_settings_allow_only_failures_in_quiet_hours() {
+        let mut settings: NotificationSettings =
+            serde_json::from_str(r#"{"quiet_hours": {"start": "22:00", "end": "08:00"}}"#).unwrap();
+        assert!(settings.stream_complete);
+
+        assert!(settings.allows(NotificationKind::StreamComplete, at(12, 0)));
+        assert!(!settings.allows(NotificationKind::StreamComplete, at(23, 0)));
+        assert!(settings.allows(NotificationKind::TaskFailed, at(23, 0)));
+
+        settings.set_category(NotificationKind::TaskFailed, false);
+        assert!(!settings.allows(NotificationKind::TaskFailed, at(23, 0)));
+
+        settings.enabled = false;
+        assert!(!settings.allows(NotificationKind::PermissionRequested, at(12, 0)));
+    }
+
+    #[test]
+    fn test_kind_names_round_trip() {
+        for kind in NotificationKind::ALL {
+            assert_eq!(NotificationKind::from_name(kind.name()), Some(kind));
+        }
+        assert_eq!(NotificationKind::from_name("nope"), None);
+    }
+}
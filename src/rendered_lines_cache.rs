@@ -4,6 +4,12 @@ use std::collections::HashMap;
 /// Maximum number of cached message renders to keep in memory
 const MAX_RENDER_CACHE_SIZE: usize = 500;
 
+/// Maximum number of rendered lines across all cached messages
+const MAX_RENDER_CACHE_LINES: usize = 20_000;
+
+/// Maximum number of cached tool diffs (see `ui::messages::tool_diff`)
+const MAX_TOOL_DIFF_CACHE_SIZE: usize = 200;
+
 /// Key for the rendered lines cache: (thread_id, message_id, render_version)
 pub type RenderCacheKey = (String, i64, u64);
 
@@ -12,12 +18,20 @@ pub type RenderCacheKey = (String, i64, u64);
 ///
 /// The cache tracks viewport width and automatically invalidates
 /// when the terminal is resized, ensuring wrapped lines are correct.
+///
+/// Entries are evicted least-recently-used first once either the entry
+/// count or the total line count exceeds its bound, so scrolling through a
+/// very long thread does not grow memory without limit.
 #[derive(Debug, Default)]
 pub struct RenderedLinesCache {
     cache: HashMap<RenderCacheKey, Vec<Line<'static>>>,
     access_order: Vec<RenderCacheKey>,
+    /// Total lines across all cached entries
+    total_lines: usize,
     /// Last viewport width used for rendering. Cache is cleared on width change.
     last_viewport_width: Option<u16>,
+    /// Diff lines of file edit tool events by (tool_call_id, expanded)
+    tool_diffs: HashMap<(String, bool), Vec<Line<'static>>>,
 }
 
 impl RenderedLinesCache {
@@ -38,8 +52,7 @@ impl RenderedLinesCache {
             _ => {
                 // Width changed or first render - clear cache
                 if !self.cache.is_empty() {
-                    self.cache.clear();
-                    self.access_order.clear();
+                    self.clear();
                 }
                 self.last_viewport_width = Some(viewport_width);
                 true
@@ -55,8 +68,10 @@ impl RenderedLinesCache {
     ) -> Option<&Vec<Line<'static>>> {
         let key = (thread_id.to_string(), message_id, render_version);
         if self.cache.contains_key(&key) {
-            self.access_order.retain(|k| *k != key);
-            self.access_order.push(key.clone());
+            if self.access_order.last() != Some(&key) {
+                self.access_order.retain(|k| *k != key);
+                self.access_order.push(key.clone());
+            }
             self.cache.get(&key)
         } else {
             None
@@ -71,23 +86,53 @@ impl RenderedLinesCache {
         lines: Vec<Line<'static>>,
     ) {
         let key = (thread_id.to_string(), message_id, render_version);
-        while self.cache.len() >= MAX_RENDER_CACHE_SIZE {
-            if let Some(oldest_key) = self.access_order.first().cloned() {
-                self.cache.remove(&oldest_key);
-                self.access_order.remove(0);
-            } else {
+        // Remove old versions (or a previous copy) of the same message in the same thread
+        self.remove_where(|k| k.0 == thread_id && k.1 == message_id);
+
+        while self.cache.len() >= MAX_RENDER_CACHE_SIZE
+            || (self.total_lines + lines.len() > MAX_RENDER_CACHE_LINES && !self.cache.is_empty())
+        {
+            if self.access_order.is_empty() {
                 break;
             }
+            let oldest_key = self.access_order.remove(0);
+            if let Some(evicted) = self.cache.remove(&oldest_key) {
+                self.total_lines -= evicted.len();
+            }
         }
-        // Remove old versions of the same message in the same thread
-        self.cache
-            .retain(|k, _| k.0 != thread_id || k.1 != message_id || k.2 == render_version);
-        self.access_order
-            .retain(|k| k.0 != thread_id || k.1 != message_id || k.2 == render_version);
+
+        self.total_lines += lines.len();
         self.cache.insert(key.clone(), lines);
         self.access_order.push(key);
     }
 
+    /// Remove every entry whose key matches `predicate`
+    fn remove_where(&mut self, predicate: impl Fn(&RenderCacheKey) -> bool) {
+        let total_lines = &mut self.total_lines;
+        self.cache.retain(|k, lines| {
+            let remove = predicate(k);
+            if remove {
+                *total_lines -= lines.len();
+            }
+            !remove
+        });
+        self.access_order.retain(|k| !predicate(k));
+    }
+
+    /// Viewport width the cached renders were laid out for
+    pub fn viewport_width(&self) -> Option<u16> {
+        self.last_viewport_width
+    }
+
+    /// Cached renders of a thread as (message_id, render_version, lines)
+    pub fn thread_entries(&self, thread_id: &str) -> Vec<(i64, u64, &Vec<Line<'static>>)> {
+        self.cache
+            .iter()
+            .filter(|(key, _)| key.0 == thread_id)
+            .map(|(key, lines)| (key.1, key.2, lines))
+            .collect()
+    }
+
     pub fn contains(&self, thread_id: &str, message_id: i64, render_version: u64) -> bool {
         self.cache
             .contains_key(&(thread_id.to_string(), message_id, render_version))
@@ -96,12 +141,44 @@ impl RenderedLinesCache {
     pub fn clear(&mut self) {
         self.cache.clear();
         self.access_order.clear();
+        self.total_lines = 0;
+        self.tool_diffs.clear();
+    }
+
+    /// Cached diff lines of a file edit tool call
+    pub fn get_tool_diff(&self, tool_call_id: &str, expanded: bool) -> Option<&Vec<Line<'static>>> {
+        self.tool_diffs.get(&(tool_call_id.to_string(), expanded))
+    }
+
+    /// Cache the diff lines of a file edit tool call
+    pub fn insert_tool_diff(
+        &mut self,
+        tool_call_id: &str,
+        expanded: bool,
+        lines: Vec<Line<'static>>,
+    ) {
+        if self.tool_diffs.len() >= MAX_TOOL_DIFF_CACHE_SIZE {
+            // Diffs are cheap to rebuild; start over rather than track recency
+            self.tool_diffs.clear();
+        }
+        self.tool_diffs
+            .insert((tool_call_id.to_string(), expanded), lines);
+    }
+
+    /// Drop all cached renders for a thread (e.g. after it is deleted)
+    pub fn remove_thread(&mut self, thread_id: &str) {
+        self.remove_where(|k| k.0 == thread_id);
     }
 
     pub fn len(&self) -> usize {
         self.cache.len()
     }
 
+    /// Total rendered lines held across all entries
+    pub fn total_lines(&self) -> usize {
+        self.total_lines
+    }
+
     pub fn is_empty(&self) -> bool {
         self.cache.is_empty()
     }
@@ -148,6 +225,33 @@ mod tests {
         assert!(cache.contains("thread1", MAX_RENDER_CACHE_SIZE as i64, 0));
     }
 
+    #[test]
+    fn test_cache_line_budget_eviction() {
+        let mut cache = RenderedLinesCache::new();
+        let big = vec![Line::from(""); MAX_RENDER_CACHE_LINES / 2];
+        cache.insert("thread1", 1, 0, big.clone());
+        cache.insert("thread1", 2, 0, big);
+        // Touch message 1 so message 2 is least recently used
+        cache.get("thread1", 1, 0);
+
+        cache.insert("thread1", 3, 0, vec![Line::from("small")]);
+
+        assert!(cache.contains("thread1", 1, 0));
+        assert!(!cache.contains("thread1", 2, 0));
+        assert!(cache.contains("thread1", 3, 0));
+        assert_eq!(cache.total_lines(), MAX_RENDER_CACHE_LINES / 2 + 1);
+    }
+
+    #[test]
+    fn test_cache_reinsert_same_version_keeps_line_count() {
+        let mut cache = RenderedLinesCache::new();
+        cache.insert("thread1", 1, 0, vec![Line::from("a"), Line::from("b")]);
+        cache.insert("thread1", 1, 0, vec![Line::from("a"), Line::from("b")]);
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.total_lines(), 2);
+    }
+
     #[test]
     fn test_cache_different_threads_same_message_id() {
         let mut cache = RenderedLinesCache::new();
@@ -185,6 +289,21 @@ mod tests {
         assert!(cache.contains("thread2", 1, 0));
     }
 
+    #[test]
+    fn test_cache_remove_thread() {
+        let mut cache = RenderedLinesCache::new();
+        cache.insert("thread1", 1, 0, vec![Line::from("a")]);
+        cache.insert("thread1", 2, 0, vec![Line::from("b")]);
+        cache.insert("thread2", 1, 0, vec![Line::from("c")]);
+
+        cache.remove_thread("thread1");
+
+        assert!(!cache.contains("thread1", 1, 0));
+        assert!(!cache.contains("thread1", 2, 0));
+        assert!(cache.contains("thread2", 1, 0));
+        assert_eq!(cache.len(), 1);
+    }
+
     #[test]
     fn test_cache_width_invalidation_same_width() {
         let mut cache = RenderedLinesCache::new();
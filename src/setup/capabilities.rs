@@ -0,0 +1,48 @@
+//! Terminal capability probe for the setup flow.
+//!
+//! Probes the user's terminal, prints a results screen, and saves the results
+//! to `~/.spoq/config.json` so later launches downgrade unsupported features
+//! without probing again.
+
+use crate::cli_output::{self, icons};
+use crate::startup::config::SpoqConfig;
+use crate::terminal::TerminalCapabilities;
+
+/// Probe the terminal, print the results screen, and save them to config.
+///
+/// Saving is best-effort: a failed write only means the probe runs again on
+/// the next launch.
+pub fn run_capability_probe() -> TerminalCapabilities {
+    cli_output::print_header("SPOQ TERMINAL SETUP");
+    cli_output::print_step_start(1, "TERMINAL CAPABILITIES");
+
+    let capabilities = TerminalCapabilities::probe();
+    for (feature, supported) in capabilities.report() {
+        if supported {
+            cli_output::print_step_line(icons::SUCCESS, feature);
+        } else {
+            cli_output::print_step_line(icons::WARNING, &format!("{} (disabled)", feature));
+        }
+    }
+
+    let mut config = SpoqConfig::load();
+    config.terminal_capabilities = Some(capabilities);
+    match config.save() {
+        Ok(()) => cli_output::print_step_line(icons::SUCCESS, "Saved to ~/.spoq/config.json"),
+        Err(e) => {
+            cli_output::print_step_line(icons::FAILURE, &format!("Could not save results: {}", e))
+        }
+    }
+    cli_output::print_step_end();
+
+    capabilities
+}
+
+/// Saved capabilities, probing (and saving) them first if this terminal has
+/// never been probed, with environment overrides (`SPOQ_NO_SYNC`) applied.
+pub fn load_or_probe_capabilities() -> TerminalCapabilities {
+    SpoqConfig::load()
+        .terminal_capabilities
+        .unwrap_or_else(run_capability_probe)
+        .with_env_overrides(|name| std::env::var(name).ok())
+}
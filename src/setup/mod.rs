@@ -1,9 +1,12 @@
 //! Setup module for Spoq TUI.
 //!
-//! This module provides GitHub CLI and Claude CLI authentication automation for the setup flow.
+//! This module provides GitHub CLI and Claude CLI authentication automation for the setup flow,
+//! plus the terminal capability probe.
 
+pub mod capabilities;
 pub mod claude_auth;
 pub mod gh_auth;
 
+pub use capabilities::{load_or_probe_capabilities, run_capability_probe};
 pub use claude_auth::{run_claude_setup_token, run_claude_setup_token_with_events, run_claude_setup_token_async, ClaudeAuthError, ClaudeAuthResult, ClaudeSetupEvent};
 pub use gh_auth::{ensure_gh_authenticated, is_gh_authenticated, is_gh_installed, GhAuthError};
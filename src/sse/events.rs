@@ -118,6 +118,12 @@ pub enum SseEvent {
     Usage {
         context_window_used: u32,
         context_window_limit: u32,
+        /// Prompt tokens of the turn, when reported
+        #[serde(default)]
+        input_tokens: Option<u64>,
+        /// Completion tokens of the turn, when reported
+        #[serde(default)]
+        output_tokens: Option<u64>,
     },
     /// System initialization - sent when Claude CLI starts
     SystemInit {
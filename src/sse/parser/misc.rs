@@ -98,6 +98,8 @@ pub(super) fn parse_usage_event(event_type: &str, data: &str) -> Result<SseEvent
     Ok(SseEvent::Usage {
         context_window_used: payload.context_window_used,
         context_window_limit: payload.context_window_limit,
+        input_tokens: payload.input_tokens,
+        output_tokens: payload.output_tokens,
     })
 }
 
@@ -303,6 +305,25 @@ mod tests {
             SseEvent::Usage {
                 context_window_used: 100000,
                 context_window_limit: 200000,
+                input_tokens: None,
+                output_tokens: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_usage_with_turn_tokens() {
+        let result = parse_sse_event(
+            "usage",
+            r#"{"context_window_used": 100000, "context_window_limit": 200000, "input_tokens": 98000, "output_tokens": 1200}"#,
+        );
+        assert_eq!(
+            result.unwrap(),
+            SseEvent::Usage {
+                context_window_used: 100000,
+                context_window_limit: 200000,
+                input_tokens: Some(98000),
+                output_tokens: Some(1200),
             }
         );
     }
@@ -119,6 +119,10 @@ pub(crate) struct ThreadUpdatedPayload {
 pub(crate) struct UsagePayload {
     pub context_window_used: u32,
     pub context_window_limit: u32,
+    #[serde(default)]
+    pub input_tokens: Option<u64>,
+    #[serde(default)]
+    pub output_tokens: Option<u64>,
 }
 
 /// SystemInit payload
@@ -135,6 +135,10 @@ pub struct StartupResult {
     pub debug_server_handle: Option<JoinHandle<()>>,
     /// State snapshot for debug server
     pub debug_state_snapshot: Option<Arc<RwLock<crate::debug::StateSnapshot>>>,
+    /// Crash snapshot the user chose to restore
+    pub crash_snapshot: Option<crate::terminal::CrashSnapshot>,
+    /// No login or VPS yet: open the setup wizard instead of the CommandDeck
+    pub needs_setup: bool,
 }
 
 impl StartupResult {
@@ -147,6 +151,8 @@ impl StartupResult {
             debug_tx: None,
             debug_server_handle: None,
             debug_state_snapshot: None,
+            crash_snapshot: None,
+            needs_setup: false,
         }
     }
 
@@ -175,6 +181,18 @@ impl StartupResult {
         self
     }
 
+    /// Set the crash snapshot to restore.
+    pub fn with_crash_snapshot(mut self, snapshot: Option<crate::terminal::CrashSnapshot>) -> Self {
+        self.crash_snapshot = snapshot;
+        self
+    }
+
+    /// Open the setup wizard to finish signing in or choosing a VPS.
+    pub fn with_needs_setup(mut self, needs_setup: bool) -> Self {
+        self.needs_setup = needs_setup;
+        self
+    }
+
     /// Build VPS URL from VpsStatusResponse.
     pub fn build_vps_url(vps: &VpsStatusResponse) -> Option<String> {
         vps.hostname
@@ -195,17 +213,85 @@ pub struct SpoqConfig {
     /// Conductor URL override (used for local mode)
     #[serde(default)]
     pub conductor_url: Option<String>,
+    /// Terminal features detected by `spoq setup` (None until probed)
+    #[serde(default)]
+    pub terminal_capabilities: Option<crate::terminal::TerminalCapabilities>,
+    /// Reopen the last viewed thread on startup (`--fresh` skips it once)
+    #[serde(default = "default_resume_session")]
+    pub resume_session: bool,
+    /// Per-thread `/editor` setting: what opens in $EDITOR when a response finishes
+    #[serde(default)]
+    pub editor_handoff: std::collections::HashMap<String, crate::app::EditorHandoff>,
+    /// Desktop notification categories and quiet hours (`/notify`)
+    #[serde(default)]
+    pub notifications: crate::notifications::NotificationSettings,
+    /// Rows of text the input grows to before it scrolls
+    #[serde(default = "default_input_max_rows")]
+    pub input_max_rows: u16,
+    /// Log file path (`SPOQ_LOG_FILE` overrides it; default /tmp/spoq_debug.log)
+    #[serde(default)]
+    pub log_file: Option<String>,
+    /// Dollars per million prompt/completion tokens by model name, for the
+    /// `/usage` cost estimate (`default` applies to other models)
+    #[serde(default)]
+    pub token_prices: std::collections::HashMap<String, crate::app::TokenPrice>,
+    /// Esc in an empty input enters a vim-style navigate mode for the
+    /// conversation (`j`/`k`, `gg`/`G`, `{`/`}`...) instead of leaving it
+    #[serde(default)]
+    pub vim_navigation: bool,
+    /// Seconds a dismissed inline error can still be restored with `u`
+    #[serde(default = "default_dismissed_error_ttl_secs")]
+    pub dismissed_error_ttl_secs: u64,
+    /// Ctrl+O link hints also label links in code blocks and inline code
+    #[serde(default)]
+    pub link_hints_include_code: bool,
+    /// Blink the input cursor (it stops blinking once idle); solid by default
+    #[serde(default)]
+    pub cursor_blink: bool,
+    /// Working directories where Shift+Tab may switch to Execute (bypass
+    /// permissions) mode, subdirectories included; empty allows any
+    #[serde(default)]
+    pub bypass_allowed_dirs: Vec<String>,
+    /// Per-thread `bypass_allowed_dirs`: a thread listed here may use Execute
+    /// mode only in its own directories, instead of the global list
+    #[serde(default)]
+    pub bypass_thread_allowed_dirs: std::collections::HashMap<String, Vec<String>>,
 }
 
 fn default_conductor_mode() -> String {
     "remote".to_string()
 }
 
+fn default_resume_session() -> bool {
+    true
+}
+
+fn default_input_max_rows() -> u16 {
+    crate::ui::input::MAX_INPUT_LINES
+}
+
+fn default_dismissed_error_ttl_secs() -> u64 {
+    300
+}
+
 impl Default for SpoqConfig {
     fn default() -> Self {
         Self {
             conductor_mode: default_conductor_mode(),
             conductor_url: None,
+            terminal_capabilities: None,
+            resume_session: default_resume_session(),
+            editor_handoff: Default::default(),
+            notifications: Default::default(),
+            input_max_rows: default_input_max_rows(),
+            log_file: None,
+            token_prices: Default::default(),
+            vim_navigation: false,
+            dismissed_error_ttl_secs: default_dismissed_error_ttl_secs(),
+            link_hints_include_code: false,
+            cursor_blink: false,
+            bypass_allowed_dirs: Vec::new(),
+            bypass_thread_allowed_dirs: Default::default(),
         }
     }
 }
@@ -220,14 +306,30 @@ impl SpoqConfig {
 
     pub fn load() -> Self {
         Self::config_path()
+            .map(|p| Self::load_from(&p))
+            .unwrap_or_default()
+    }
+
+    /// Load the config at `path`; a missing or invalid file yields the default
+    pub fn load_from(path: &std::path::Path) -> Self {
+        std::fs::read_to_string(path)
             .ok()
-            .and_then(|p| std::fs::read_to_string(p).ok())
             .and_then(|s| serde_json::from_str(&s).ok())
             .unwrap_or_default()
     }
 
     pub fn save(&self) -> Result<(), std::io::Error> {
-        let path = Self::config_path()?;
+        self.save_to(&Self::config_path()?)
+    }
+
+    /// Write the config to `path`
+    pub fn save_to(&self, path: &std::path::Path) -> Result<(), std::io::Error> {
+        if crate::startup::integrity::is_read_only() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                "state files are read-only until corrupted files are regenerated",
+            ));
+        }
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
@@ -306,6 +408,7 @@ mod tests {
         assert!(result.vps_state.is_none());
         assert!(result.vps_url.is_none());
         assert!(result.debug_tx.is_none());
+        assert!(!result.needs_setup);
     }
 
     #[test]
@@ -420,4 +523,12 @@ mod tests {
 
         assert_eq!(StartupResult::build_vps_url(&vps), None);
     }
+
+    #[test]
+    fn test_spoq_config_without_capabilities_loads() {
+        let config: SpoqConfig =
+            serde_json::from_str(r#"{"conductor_mode": "local"}"#).unwrap();
+        assert!(config.is_local());
+        assert!(config.terminal_capabilities.is_none());
+    }
 }
@@ -0,0 +1,117 @@
+//! Crash snapshot detection at startup.
+//!
+//! A panic leaves a snapshot of the draft and the in-flight stream in
+//! `~/.local/share/spoq/crash` (see `terminal::crash`). Before the TUI starts
+//! the newest one is described and the user is asked whether to restore it;
+//! either way the snapshots are then removed so they're only offered once.
+
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use crate::terminal::CrashSnapshot;
+
+/// Snapshot files in `dir`, newest first
+pub fn find_crash_snapshots(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    // Named after the crash time, so the names sort chronologically
+    paths.sort();
+    paths.reverse();
+    paths
+}
+
+/// One-line description of what a snapshot would restore
+pub fn describe_crash_snapshot(snapshot: &CrashSnapshot) -> String {
+    let mut parts = Vec::new();
+    if let Some(thread_id) = &snapshot.thread_id {
+        parts.push(format!("thread {}", thread_id));
+    }
+    let draft_chars = snapshot.draft.trim().chars().count();
+    if draft_chars > 0 {
+        parts.push(format!("a {}-character draft", draft_chars));
+    }
+    if snapshot.partial_content.is_some() {
+        parts.push("a partial response".to_string());
+    }
+    if let Some(perm) = &snapshot.pending_permission {
+        parts.push(format!("a pending {} request", perm.tool_name));
+    }
+    parts.join(", ")
+}
+
+/// Offer to restore the newest crash snapshot.
+///
+/// Returns the snapshot if the user accepted. Without an interactive stdin
+/// nothing is asked and the snapshots are kept for a later start.
+pub fn offer_crash_restore() -> Option<CrashSnapshot> {
+    let dir = CrashSnapshot::dir()?;
+    let paths = find_crash_snapshots(&dir);
+    let snapshot = paths
+        .iter()
+        .find_map(|path| CrashSnapshot::load_from(path))?;
+    if !std::io::stdin().is_terminal() {
+        return None;
+    }
+
+    println!(
+        "SPOQ crashed at {} with {}.",
+        snapshot
+            .crashed_at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M"),
+        describe_crash_snapshot(&snapshot)
+    );
+    print!("Restore the draft and reopen the thread? [Y/n] ");
+    std::io::stdout().flush().ok();
+
+    let mut line = String::new();
+    let accepted = match std::io::stdin().lock().read_line(&mut line) {
+        Ok(_) => !matches!(line.trim().to_lowercase().as_str(), "n" | "no"),
+        Err(_) => false,
+    };
+
+    for path in &paths {
+        if let Err(e) = std::fs::remove_file(path) {
+            tracing::warn!("Failed to remove crash snapshot {}: {}", path.display(), e);
+        }
+    }
+    accepted.then_some(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newest_snapshot_comes_first() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["20260101T090000.000Z.json", "20260102T090000.000Z.json"] {
+            std::fs::write(dir.path().join(name), "{}").unwrap();
+        }
+        std::fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+        let paths = find_crash_snapshots(dir.path());
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with("20260102T090000.000Z.json"));
+        assert!(find_crash_snapshots(&dir.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_describe_lists_what_would_be_restored() {
+        let snapshot = CrashSnapshot {
+            thread_id: Some("t-1".to_string()),
+            draft: "hello".to_string(),
+            partial_content: Some("Hi".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            describe_crash_snapshot(&snapshot),
+            "thread t-1, a 5-character draft, a partial response"
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Startup integrity check for local state files.
+//!
+//! A corrupted `~/.spoq/config.json`, `~/.spoq/update_state.json` or
+//! `~/.spoq_history` used to be silently replaced with defaults the next time
+//! it was saved. Instead, startup checks each file up front; if any fails to
+//! parse, the app runs in read-only mode (no state file is written) and shows
+//! a banner until the user backs the file up and regenerates defaults.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::startup::config::SpoqConfig;
+use crate::update::UpdateState;
+
+/// Whether state files must not be written (set when a corrupted file was found)
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable read-only mode for state files
+pub fn set_read_only(read_only: bool) {
+    READ_ONLY.store(read_only, Ordering::Relaxed);
+}
+
+/// Whether state files are currently read-only
+pub fn is_read_only() -> bool {
+    READ_ONLY.load(Ordering::Relaxed)
+}
+
+/// A local state file checked at startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateFileKind {
+    /// `~/.spoq/config.json`
+    Config,
+    /// `~/.spoq_history`
+    InputHistory,
+    /// `~/.spoq/update_state.json`
+    UpdateState,
+}
+
+impl StateFileKind {
+    /// Every checked state file
+    pub const ALL: [StateFileKind; 3] = [
+        StateFileKind::Config,
+        StateFileKind::InputHistory,
+        StateFileKind::UpdateState,
+    ];
+
+    /// Human-readable name
+    pub fn label(&self) -> &'static str {
+        match self {
+            StateFileKind::Config => "config",
+            StateFileKind::InputHistory => "input history",
+            StateFileKind::UpdateState => "update state",
+        }
+    }
+
+    /// Location of the file relative to the home directory
+    pub fn path_in(&self, home: &Path) -> PathBuf {
+        match self {
+            StateFileKind::Config => home.join(".spoq").join("config.json"),
+            StateFileKind::InputHistory => home.join(".spoq_history"),
+            StateFileKind::UpdateState => home.join(".spoq").join("update_state.json"),
+        }
+    }
+
+    /// Check file contents, returning a description of the problem
+    fn validate(&self, bytes: &[u8]) -> Result<(), String> {
+        match self {
+            StateFileKind::Config => serde_json::from_slice::<SpoqConfig>(bytes)
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+            StateFileKind::UpdateState => serde_json::from_slice::<UpdateState>(bytes)
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+            StateFileKind::InputHistory => std::str::from_utf8(bytes)
+                .map(|_| ())
+                .map_err(|e| format!("invalid UTF-8 at byte {}", e.valid_up_to())),
+        }
+    }
+
+    /// Contents written when the file is regenerated
+    fn default_contents(&self) -> String {
+        match self {
+            StateFileKind::Config => {
+                serde_json::to_string_pretty(&SpoqConfig::default()).unwrap_or_default()
+            }
+            StateFileKind::UpdateState => {
+                serde_json::to_string_pretty(&UpdateState::default()).unwrap_or_default()
+            }
+            StateFileKind::InputHistory => String::new(),
+        }
+    }
+}
+
+/// A state file that failed to parse
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorruptedFile {
+    /// Which state file
+    pub kind: StateFileKind,
+    /// Where it lives
+    pub path: PathBuf,
+    /// Parse error
+    pub error: String,
+}
+
+impl fmt::Display for CorruptedFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) is corrupted: {}",
+            self.kind.label(),
+            self.path.display(),
+            self.error
+        )
+    }
+}
+
+/// Check every state file in the user's home directory.
+///
+/// Missing or unreadable files are not reported; they fall back to defaults
+/// as before.
+pub fn check_state_files() -> Vec<CorruptedFile> {
+    dirs::home_dir()
+        .map(|home| check_state_files_in(&home))
+        .unwrap_or_default()
+}
+
+/// Check every state file under `home`
+pub fn check_state_files_in(home: &Path) -> Vec<CorruptedFile> {
+    StateFileKind::ALL
+        .iter()
+        .filter_map(|kind| {
+            let path = kind.path_in(home);
+            let bytes = std::fs::read(&path).ok()?;
+            let error = kind.validate(&bytes).err()?;
+            Some(CorruptedFile {
+                kind: *kind,
+                path,
+                error,
+            })
+        })
+        .collect()
+}
+
+/// Move a corrupted file aside and write defaults in its place.
+///
+/// The original is kept next to it as `<name>.corrupt-<timestamp>`; returns
+/// the backup path.
+pub fn backup_and_regenerate(file: &CorruptedFile) -> io::Result<PathBuf> {
+    let file_name = file
+        .path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let backup = file.path.with_file_name(format!(
+        "{}.corrupt-{}",
+        file_name,
+        chrono::Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    std::fs::rename(&file.path, &backup)?;
+    std::fs::write(&file.path, file.kind.default_contents())?;
+    Ok(backup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(home: &Path, kind: StateFileKind, contents: &[u8]) {
+        let path = kind.path_in(home);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn test_valid_and_missing_files_pass() {
+        let home = TempDir::new().unwrap();
+        assert!(check_state_files_in(home.path()).is_empty());
+
+        write(
+            home.path(),
+            StateFileKind::Config,
+            br#"{"conductor_mode":"local"}"#,
+        );
+        write(home.path(), StateFileKind::UpdateState, b"{}");
+        write(home.path(), StateFileKind::InputHistory, b"hello\nworld\n");
+        assert!(check_state_files_in(home.path()).is_empty());
+    }
+
+    #[test]
+    fn test_detects_corrupted_files() {
+        let home = TempDir::new().unwrap();
+        write(home.path(), StateFileKind::Config, b"{\"conductor_mode\": ");
+        write(home.path(), StateFileKind::InputHistory, b"ok\n\xff\xfe\n");
+
+        let corrupted = check_state_files_in(home.path());
+        let kinds: Vec<_> = corrupted.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![StateFileKind::Config, StateFileKind::InputHistory]
+        );
+        assert_eq!(corrupted[1].error, "invalid UTF-8 at byte 3");
+        assert!(corrupted[0].to_string().starts_with("config ("));
+    }
+
+    #[test]
+    fn test_backup_and_regenerate() {
+        let home = TempDir::new().unwrap();
+        write(home.path(), StateFileKind::UpdateState, b"not json");
+        let corrupted = check_state_files_in(home.path()).remove(0);
+
+        let backup = backup_and_regenerate(&corrupted).unwrap();
+
+        assert_eq!(std::fs::read(&backup).unwrap(), b"not json");
+        assert!(backup
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("update_state.json.corrupt-"));
+        assert!(check_state_files_in(home.path()).is_empty());
+    }
+}
@@ -0,0 +1,65 @@
+//! Keymap file loading.
+//!
+//! Custom key chords are read from `~/.config/spoq/keymap.toml` (format in
+//! `input::keymap`). A missing file means the built-in bindings; problems in
+//! the file are returned as warnings so startup can show them.
+
+use std::path::{Path, PathBuf};
+
+use crate::input::keymap::{Keymap, KeymapWarning};
+
+/// Path of the keymap file (`~/.config/spoq/keymap.toml`)
+pub fn keymap_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".config").join("spoq").join("keymap.toml"))
+}
+
+/// Load the keymap, falling back to the defaults when there is no file
+pub fn load_keymap() -> (Keymap, Vec<KeymapWarning>) {
+    match keymap_path() {
+        Some(path) => load_keymap_from(&path),
+        None => (Keymap::default(), Vec::new()),
+    }
+}
+
+/// Load a keymap from a specific path
+pub fn load_keymap_from(path: &Path) -> (Keymap, Vec<KeymapWarning>) {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Keymap::parse(&contents),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => (Keymap::default(), Vec::new()),
+        Err(e) => (
+            Keymap::default(),
+            vec![KeymapWarning {
+                line: 0,
+                message: format!("failed to read {}: {}", path.display(), e),
+            }],
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::keymap::Action;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_missing_file_uses_defaults() {
+        let dir = TempDir::new().unwrap();
+        let (keymap, warnings) = load_keymap_from(&dir.path().join("keymap.toml"));
+        assert!(warnings.is_empty());
+        assert_eq!(keymap.chords_for(Action::Interrupt), vec!["ctrl+c"]);
+    }
+
+    #[test]
+    fn test_loads_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("keymap.toml");
+        std::fs::write(&path, "BackToCommandDeck = \"ctrl+b\"\nBogus = \"x\"\n").unwrap();
+
+        let (keymap, warnings) = load_keymap_from(&path);
+
+        assert_eq!(keymap.chords_for(Action::BackToCommandDeck), vec!["ctrl+b"]);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 2);
+    }
+}
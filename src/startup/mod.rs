@@ -10,7 +10,11 @@
 //! - [`auth`] - Authentication and credential validation
 //! - [`vps`] - VPS verification and management
 //! - [`health`] - Health check loop
+//! - [`integrity`] - Corrupted state file detection and read-only mode
+//! - [`crash`] - Crash snapshot restore offer
 //! - [`debug`] - Debug system initialization
+//! - [`theme`] - Custom theme loading
+//! - [`keymap`] - Custom key chord loading
 //!
 //! # Usage
 //!
@@ -24,13 +28,20 @@
 
 pub mod auth;
 pub mod config;
+pub mod crash;
 pub mod debug;
 pub mod health;
+pub mod integrity;
+pub mod keymap;
 pub mod preflight;
+pub mod theme;
 pub mod vps;
 
 pub use config::{SpoqConfig, StartupConfig, StartupResult};
+pub use integrity::{check_state_files, CorruptedFile, StateFileKind};
+pub use keymap::load_keymap;
 pub use preflight::run_preflight_checks;
+pub use theme::{CustomTheme, ThemeLoadError};
 
 #[cfg(test)]
 mod tests {
@@ -5,6 +5,7 @@
 
 use super::auth::validate_credentials;
 use super::config::{StartupConfig, StartupResult};
+use super::crash::offer_crash_restore;
 use super::debug::start_debug_system;
 use super::health::run_health_check_loop;
 use super::vps::{build_vps_url, verify_vps, VpsError};
@@ -62,11 +63,15 @@ impl From<VpsError> for PreflightError {
 /// Run all preflight checks before starting the TUI.
 ///
 /// This function orchestrates:
-/// 1. Credential validation (SPOQ auth flow if needed)
-/// 2. VPS verification (provisioning + GH auto-login if needed)
+/// 0. Crash snapshot restore offer (after a panic in the last run)
+/// 1. Credential validation (token refresh if needed)
+/// 2. VPS verification (auto-start if stopped)
 /// 3. Health check loop (with credential sync and GH auto-login retry)
 /// 4. Debug system startup
 ///
+/// Without a login or a VPS, the checks stop early and the result asks for
+/// the TUI's setup wizard (`needs_setup`) instead.
+///
 /// # Arguments
 /// * `runtime` - Tokio runtime for async operations
 /// * `config` - Startup configuration
@@ -78,6 +83,9 @@ pub fn run_preflight_checks(
     runtime: &tokio::runtime::Runtime,
     config: StartupConfig,
 ) -> Result<StartupResult, PreflightError> {
+    // Step 0: Offer to restore what the last run was doing when it crashed
+    let crash_snapshot = offer_crash_restore();
+
     // Dev mode: skip auth and use localhost conductor
     if config.dev_mode {
         println!("🔧 Dev mode enabled - skipping authentication");
@@ -109,7 +117,8 @@ pub fn run_preflight_checks(
 
         let result = StartupResult::new(credentials)
             .with_vps_url(Some(dev_url))
-            .with_debug(debug_tx, debug_handle, debug_snapshot);
+            .with_debug(debug_tx, debug_handle, debug_snapshot)
+            .with_crash_snapshot(crash_snapshot);
 
         println!("Starting SPOQ (dev mode)...\n");
         return Ok(result);
@@ -120,6 +129,13 @@ pub fn run_preflight_checks(
         PreflightError::CredentialsManager("Failed to initialize credentials manager".to_string())
     })?;
 
+    // No login yet: the setup wizard signs in inside the TUI
+    let stored = manager.load();
+    if stored.access_token.is_none() {
+        println!("No credentials found. Starting setup...");
+        return Ok(setup_result(runtime, &config, stored, crash_snapshot));
+    }
+
     // Step 1: Validate credentials (SPOQ auth flow if needed)
     println!("Checking authentication...");
     let mut credentials = validate_credentials(runtime, &manager)?;
@@ -179,7 +195,8 @@ pub fn run_preflight_checks(
 
         let result = StartupResult::new(credentials)
             .with_vps_url(Some(conductor_url))
-            .with_debug(debug_tx, debug_handle, debug_snapshot);
+            .with_debug(debug_tx, debug_handle, debug_snapshot)
+            .with_crash_snapshot(crash_snapshot);
 
         println!("Starting SPOQ...\n");
         return Ok(result);
@@ -189,7 +206,11 @@ pub fn run_preflight_checks(
     let (vps_state, vps_url) = if config.skip_vps_check {
         (None, None)
     } else {
-        let vps = verify_vps(runtime, &mut credentials, &manager)?;
+        let Some(vps) = verify_vps(runtime, &credentials)? else {
+            // No VPS yet: the setup wizard finds or sets one up
+            println!("No VPS found. Starting setup...");
+            return Ok(setup_result(runtime, &config, credentials, crash_snapshot));
+        };
         let url = build_vps_url(&vps).ok_or_else(|| {
             PreflightError::Vps(VpsError::StatusCheckFailed(
                 "VPS has no hostname, url, or IP address".to_string(),
@@ -229,13 +250,38 @@ pub fn run_preflight_checks(
     let result = StartupResult::new(credentials)
         .with_vps_state(vps_state)
         .with_vps_url(vps_url)
-        .with_debug(debug_tx, debug_handle, debug_snapshot);
+        .with_debug(debug_tx, debug_handle, debug_snapshot)
+        .with_crash_snapshot(crash_snapshot);
 
     println!("Starting SPOQ...\n");
 
     Ok(result)
 }
 
+/// Startup result that opens the setup wizard
+fn setup_result(
+    runtime: &tokio::runtime::Runtime,
+    config: &StartupConfig,
+    credentials: crate::auth::credentials::Credentials,
+    crash_snapshot: Option<crate::terminal::CrashSnapshot>,
+) -> StartupResult {
+    let (debug_tx, debug_handle, debug_snapshot) = if config.enable_debug {
+        let debug_result = runtime.block_on(start_debug_system(config.debug_port));
+        (
+            debug_result.tx,
+            debug_result.server_handle,
+            debug_result.state_snapshot,
+        )
+    } else {
+        (None, None, None)
+    };
+
+    StartupResult::new(credentials)
+        .with_debug(debug_tx, debug_handle, debug_snapshot)
+        .with_crash_snapshot(crash_snapshot)
+        .with_needs_setup(true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
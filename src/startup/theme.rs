@@ -0,0 +1,177 @@
+//! Custom theme loading.
+//!
+//! A custom theme is read from `~/.spoq/theme.json`. Every field is optional;
+//! omitted colors keep their defaults. Colors accept names (`"light-green"`),
+//! hex (`"#04b575"`) or palette indices (`"244"`).
+//!
+//! ```json
+//! {
+//!   "dim": "#6c6c6c",
+//!   "accent": "white",
+//!   "background": "black",
+//!   "min_contrast": 4.5,
+//!   "auto_adjust_contrast": true
+//! }
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use ratatui::style::Color;
+use serde::Deserialize;
+
+use crate::ui::contrast::DEFAULT_MIN_CONTRAST;
+use crate::view_state::Theme;
+
+/// File name of the custom theme inside `~/.spoq`
+const THEME_FILE: &str = "theme.json";
+
+/// Error loading the custom theme file
+#[derive(Debug)]
+pub enum ThemeLoadError {
+    /// The file exists but could not be read
+    Read(std::io::Error),
+    /// The file is not valid theme JSON
+    Parse(String),
+    /// A color value could not be parsed
+    InvalidColor { role: &'static str, value: String },
+}
+
+impl std::fmt::Display for ThemeLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeLoadError::Read(e) => write!(f, "Failed to read theme file: {}", e),
+            ThemeLoadError::Parse(e) => write!(f, "Invalid theme file: {}", e),
+            ThemeLoadError::InvalidColor { role, value } => {
+                write!(f, "Invalid color for '{}': {}", role, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThemeLoadError {}
+
+/// Raw contents of the theme file
+#[derive(Debug, Default, Deserialize)]
+struct ThemeFile {
+    active: Option<String>,
+    success: Option<String>,
+    error: Option<String>,
+    waiting: Option<String>,
+    dim: Option<String>,
+    border: Option<String>,
+    accent: Option<String>,
+    background: Option<String>,
+    min_contrast: Option<f64>,
+    #[serde(default)]
+    auto_adjust_contrast: bool,
+}
+
+/// A custom theme plus its contrast settings
+#[derive(Debug, Clone)]
+pub struct CustomTheme {
+    /// Theme colors, with defaults for omitted roles
+    pub theme: Theme,
+    /// Terminal background the colors are drawn on (default: black)
+    pub background: Color,
+    /// Minimum contrast ratio every color must reach
+    pub min_contrast: f64,
+    /// Adjust failing colors instead of only warning
+    pub auto_adjust_contrast: bool,
+}
+
+impl CustomTheme {
+    /// Path of the custom theme file (`~/.spoq/theme.json`)
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".spoq").join(THEME_FILE))
+    }
+
+    /// Load the custom theme, if one exists.
+    ///
+    /// Returns `Ok(None)` when there is no theme file.
+    pub fn load() -> Result<Option<Self>, ThemeLoadError> {
+        match Self::path() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(None),
+        }
+    }
+
+    /// Load a custom theme from a specific path
+    pub fn load_from(path: &Path) -> Result<Option<Self>, ThemeLoadError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(ThemeLoadError::Read(e)),
+        };
+        Self::parse(&contents).map(Some)
+    }
+
+    /// Parse theme JSON
+    pub fn parse(json: &str) -> Result<Self, ThemeLoadError> {
+        let file: ThemeFile =
+            serde_json::from_str(json).map_err(|e| ThemeLoadError::Parse(e.to_string()))?;
+
+        let mut theme = Theme::default();
+        let roles: [(&'static str, &Option<String>, &mut Color); 7] = [
+            ("active", &file.active, &mut theme.active),
+            ("success", &file.success, &mut theme.success),
+            ("error", &file.error, &mut theme.error),
+            ("waiting", &file.waiting, &mut theme.waiting),
+            ("dim", &file.dim, &mut theme.dim),
+            ("border", &file.border, &mut theme.border),
+            ("accent", &file.accent, &mut theme.accent),
+        ];
+        for (role, value, slot) in roles {
+            if let Some(value) = value {
+                *slot = parse_color(role, value)?;
+            }
+        }
+
+        let background = match &file.background {
+            Some(value) => parse_color("background", value)?,
+            None => Color::Black,
+        };
+
+        Ok(Self {
+            theme,
+            background,
+            min_contrast: file.min_contrast.unwrap_or(DEFAULT_MIN_CONTRAST),
+            auto_adjust_contrast: file.auto_adjust_contrast,
+        })
+    }
+}
+
+fn parse_color(role: &'static str, value: &str) -> Result<Color, ThemeLoadError> {
+    value.parse().map_err(|_| ThemeLoadError::InvalidColor {
+        role,
+        value: value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_partial_theme_keeps_defaults() {
+        let custom = CustomTheme::parse(r##"{"dim": "#6c6c6c", "min_contrast": 4.5}"##).unwrap();
+
+        assert_eq!(custom.theme.dim, Color::Rgb(0x6c, 0x6c, 0x6c));
+        assert_eq!(custom.theme.accent, Theme::default().accent);
+        assert_eq!(custom.background, Color::Black);
+        assert_eq!(custom.min_contrast, 4.5);
+        assert!(!custom.auto_adjust_contrast);
+    }
+
+    #[test]
+    fn test_parse_invalid_color() {
+        let err = CustomTheme::parse(r#"{"accent": "not-a-color"}"#).unwrap_err();
+        assert!(matches!(err, ThemeLoadError::InvalidColor { role: "accent", .. }));
+    }
+
+    #[test]
+    fn test_load_missing_file_is_none() {
+        let dir = tempfile::tempdir().unwrap();
+        let result = CustomTheme::load_from(&dir.path().join(THEME_FILE)).unwrap();
+        assert!(result.is_none());
+    }
+}
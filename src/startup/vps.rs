@@ -4,8 +4,8 @@
 //! It uses dependency injection via the HttpClient trait for testability.
 
 use crate::auth::central_api::{CentralApiClient, VpsStatusResponse};
-use crate::auth::credentials::{Credentials, CredentialsManager};
-use crate::auth::{run_provisioning_flow, start_stopped_vps};
+use crate::auth::credentials::Credentials;
+use crate::auth::start_stopped_vps;
 
 /// Error type for VPS operations during startup.
 #[derive(Debug)]
@@ -71,81 +71,40 @@ pub fn fetch_vps_status(
 /// - provisioning/pending/creating: Continue to health check
 /// - stopped: Auto-start
 /// - failed/terminated: Error
-/// - No VPS: Run provisioning flow
+/// - No VPS: `Ok(None)`, the TUI's setup wizard sets one up
 ///
 /// # Arguments
 /// * `runtime` - Tokio runtime for async operations
-/// * `credentials` - User credentials (may be updated during provisioning)
-/// * `manager` - Credentials manager for saving updated credentials
+/// * `credentials` - User credentials
 ///
 /// # Returns
-/// * `Ok(VpsStatusResponse)` - VPS is ready or starting
+/// * `Ok(Some(VpsStatusResponse))` - VPS is ready or starting
+/// * `Ok(None)` - No VPS configured
 /// * `Err(VpsError)` - VPS cannot be used
 pub fn verify_vps(
     runtime: &tokio::runtime::Runtime,
-    credentials: &mut Credentials,
-    manager: &CredentialsManager,
-) -> Result<VpsStatusResponse, VpsError> {
+    credentials: &Credentials,
+) -> Result<Option<VpsStatusResponse>, VpsError> {
     // Fetch VPS state from API
-    let mut vps_state = fetch_vps_status(runtime, credentials)?;
-
-    // If no VPS exists, run provisioning flow
-    if vps_state.is_none() {
-        println!("No VPS found. Starting provisioning...");
-        run_provisioning_flow(runtime, credentials)
-            .map_err(|e| VpsError::ProvisioningFailed(e.to_string()))?;
-
-        // Save credentials after provisioning (tokens may have been refreshed)
-        if !manager.save(credentials) {
-            eprintln!("Warning: Failed to save credentials after provisioning");
-        }
-
-        // If user chose Local mode, there's no VPS record in the backend.
-        // Return a synthetic response with the local conductor URL.
-        let spoq_config = crate::startup::config::SpoqConfig::load();
-        if spoq_config.is_local() {
-            let local_url = spoq_config
-                .conductor_url
-                .unwrap_or_else(|| format!("http://127.0.0.1:{}", crate::conductor::local::default_port()));
-            return Ok(VpsStatusResponse {
-                vps_id: "local".to_string(),
-                status: "ready".to_string(),
-                hostname: None,
-                ip: None,
-                url: Some(local_url),
-                ssh_username: None,
-                provider: Some("local".to_string()),
-                plan_id: None,
-                data_center_id: None,
-                created_at: None,
-                ready_at: None,
-            });
-        }
-
-        // Fetch VPS state again after provisioning
-        vps_state = fetch_vps_status(runtime, credentials)?;
-    }
-
-    // At this point, VPS should exist
-    let vps = vps_state.ok_or_else(|| {
-        VpsError::ProvisioningFailed("VPS still not found after provisioning".to_string())
-    })?;
+    let Some(vps) = fetch_vps_status(runtime, credentials)? else {
+        return Ok(None);
+    };
 
     // Handle VPS state
     match vps.status.as_str() {
         "ready" | "running" | "active" => {
             println!("  VPS is ready (status: {})", vps.status);
-            Ok(vps)
+            Ok(Some(vps))
         }
         "provisioning" | "pending" | "creating" => {
             println!("  VPS is still provisioning, checking health...");
-            Ok(vps)
+            Ok(Some(vps))
         }
         "stopped" => {
             println!("  VPS is stopped, starting...");
             let started_vps = start_stopped_vps(runtime, credentials)
                 .map_err(|e| VpsError::StartFailed(e.to_string()))?;
-            Ok(started_vps)
+            Ok(Some(started_vps))
         }
         "failed" | "terminated" => Err(VpsError::UnrecoverableState(format!(
             "VPS is in {} state. Please contact support@spoq.dev for assistance.",
@@ -1,17 +1,58 @@
 //! App-level caching for unified picker data.
 //!
-//! Implements a tiered caching strategy:
-//! - Repos: Cached for entire session (rarely change)
-//! - Threads: Cached with 5-minute TTL (change more often)
-//! - Folders: Cached for entire session (local, rarely change)
+//! Implements a tiered caching strategy, each kind refreshed in the
+//! background once older than its TTL:
+//! - Repos: 30-minute TTL (rarely change)
+//! - Threads: 5-minute TTL (change more often)
+//! - Folders: 10-minute TTL (local, rarely change)
+//!
+//! Stale data stays usable while a refresh is in flight, so opening a picker
+//! never waits on the network once something is cached.
 
+use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 
 use crate::models::picker::PickerItem;
 
+/// Time-to-live for repo cache (30 minutes)
+const REPOS_TTL: Duration = Duration::from_secs(30 * 60);
+
 /// Time-to-live for thread cache (5 minutes)
 const THREADS_TTL: Duration = Duration::from_secs(5 * 60);
 
+/// Time-to-live for folder cache (10 minutes)
+const FOLDERS_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Minimum time between background refresh attempts of one kind, so a
+/// failing endpoint isn't retried on every tick
+const REFRESH_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Kind of cached picker data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PickerDataKind {
+    Repos,
+    Threads,
+    Folders,
+}
+
+impl PickerDataKind {
+    /// All kinds, in picker section order
+    pub const ALL: [PickerDataKind; 3] = [
+        PickerDataKind::Repos,
+        PickerDataKind::Threads,
+        PickerDataKind::Folders,
+    ];
+
+    /// How long cached data of this kind counts as fresh
+    pub fn ttl(self) -> Duration {
+        match self {
+            PickerDataKind::Repos => REPOS_TTL,
+            PickerDataKind::Threads => THREADS_TTL,
+            PickerDataKind::Folders => FOLDERS_TTL,
+        }
+    }
+}
+
 /// Cached data with timestamp
 #[derive(Debug, Clone)]
 pub struct CachedData {
@@ -44,14 +85,18 @@ impl CachedData {
 /// App-level cache for picker data
 #[derive(Debug, Clone, Default)]
 pub struct AppCache {
-    /// Cached repos (session-level, rarely change)
+    /// Cached repos (30-minute TTL)
     pub repos: Option<CachedData>,
     /// Cached threads (5-minute TTL)
     pub threads: Option<CachedData>,
-    /// Cached folders (session-level, local)
+    /// Cached folders (10-minute TTL)
     pub folders: Option<CachedData>,
     /// Whether initial preload has been triggered
     pub preload_started: bool,
+    /// Kinds with a request in flight
+    refreshing: HashSet<PickerDataKind>,
+    /// When each kind was last requested
+    requested_at: HashMap<PickerDataKind, Instant>,
 }
 
 impl AppCache {
@@ -99,16 +144,67 @@ impl AppCache {
     /// Cache repos
     pub fn set_repos(&mut self, items: Vec<PickerItem>) {
         self.repos = Some(CachedData::new(items));
+        self.finish_refresh(PickerDataKind::Repos);
     }
 
     /// Cache threads
     pub fn set_threads(&mut self, items: Vec<PickerItem>) {
         self.threads = Some(CachedData::new(items));
+        self.finish_refresh(PickerDataKind::Threads);
     }
 
     /// Cache folders
     pub fn set_folders(&mut self, items: Vec<PickerItem>) {
         self.folders = Some(CachedData::new(items));
+        self.finish_refresh(PickerDataKind::Folders);
+    }
+
+    /// Cached data of a kind, fresh or not
+    pub fn entry(&self, kind: PickerDataKind) -> Option<&CachedData> {
+        match kind {
+            PickerDataKind::Repos => self.repos.as_ref(),
+            PickerDataKind::Threads => self.threads.as_ref(),
+            PickerDataKind::Folders => self.folders.as_ref(),
+        }
+    }
+
+    /// Whether a kind is missing or older than its TTL
+    pub fn is_stale(&self, kind: PickerDataKind) -> bool {
+        self.entry(kind)
+            .map(|c| c.is_older_than(kind.ttl()))
+            .unwrap_or(true)
+    }
+
+    /// Whether a request for a kind is in flight
+    pub fn is_refreshing(&self, kind: PickerDataKind) -> bool {
+        self.refreshing.contains(&kind)
+    }
+
+    /// Whether a background refresh of a kind should start now: stale, not
+    /// already in flight, and not attempted within the retry interval
+    pub fn refresh_due(&self, kind: PickerDataKind) -> bool {
+        self.is_stale(kind)
+            && !self.is_refreshing(kind)
+            && self
+                .requested_at
+                .get(&kind)
+                .is_none_or(|at| at.elapsed() >= REFRESH_RETRY_INTERVAL)
+    }
+
+    /// Record that a request for a kind was sent.
+    ///
+    /// Returns false (nothing to send) if one is already in flight.
+    pub fn start_refresh(&mut self, kind: PickerDataKind) -> bool {
+        if !self.refreshing.insert(kind) {
+            return false;
+        }
+        self.requested_at.insert(kind, Instant::now());
+        true
+    }
+
+    /// Record that the request for a kind completed (or failed)
+    pub fn finish_refresh(&mut self, kind: PickerDataKind) {
+        self.refreshing.remove(&kind);
     }
 
     /// Mark that preload has been started
@@ -165,4 +261,39 @@ mod tests {
         assert!(cache.has_fresh_threads());
         assert!(cache.get_fresh_threads().is_some());
     }
+
+    #[test]
+    fn test_app_cache_refresh_tracking() {
+        let mut cache = AppCache::new();
+        assert!(cache.is_stale(PickerDataKind::Repos));
+        assert!(cache.refresh_due(PickerDataKind::Repos));
+
+        assert!(cache.start_refresh(PickerDataKind::Repos));
+        assert!(!cache.start_refresh(PickerDataKind::Repos));
+        assert!(cache.is_refreshing(PickerDataKind::Repos));
+        assert!(!cache.refresh_due(PickerDataKind::Repos));
+
+        // A failed request isn't retried right away
+        cache.finish_refresh(PickerDataKind::Repos);
+        assert!(!cache.refresh_due(PickerDataKind::Repos));
+
+        cache.set_repos(vec![]);
+        assert!(!cache.is_stale(PickerDataKind::Repos));
+        assert!(!cache.is_refreshing(PickerDataKind::Repos));
+
+        // Data older than its TTL is stale but still served (skipped if the
+        // monotonic clock started too recently to go back that far)
+        if let Some(cached_at) = Instant::now().checked_sub(FOLDERS_TTL + Duration::from_secs(1)) {
+            cache.folders = Some(CachedData {
+                items: vec![PickerItem::Folder {
+                    name: "app".to_string(),
+                    path: "/srv/app".to_string(),
+                }],
+                cached_at,
+            });
+            assert!(cache.is_stale(PickerDataKind::Folders));
+            assert!(cache.refresh_due(PickerDataKind::Folders));
+            assert!(cache.get_folders().is_some());
+        }
+    }
 }
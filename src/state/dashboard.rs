@@ -5,7 +5,8 @@
 
 use crate::models::dashboard::{Aggregate, PlanRequest, ThreadStatus, WaitingFor};
 use crate::models::{Thread, ThreadMode};
-use crate::state::session::{AskUserQuestionData, PermissionRequest};
+use crate::state::session::{AskUserQuestionData, PermissionRequest, QuestionConstraints};
+use crate::state::Notification;
 use crate::view_state::{
     OverlayState, Progress, RenderContext, SystemStats, Theme, ThreadView,
 };
@@ -14,6 +15,9 @@ use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 use tracing::info;
 
+/// Maximum number of notifications kept in the panel
+pub const MAX_NOTIFICATIONS: usize = 50;
+
 // ============================================================================
 // PlanApprovalState
 // ============================================================================
@@ -97,20 +101,31 @@ pub struct DashboardQuestionState {
     pub other_active: bool,
     /// Tracks which questions have been answered (for multi-question flow)
     pub answered: Vec<bool>,
+    /// Answer limits per question
+    pub constraints: Vec<QuestionConstraints>,
+    /// Why the last confirm was refused, shown until the answer changes
+    pub validation_error: Option<String>,
+    /// Default option per question, from the question's `default` label
+    pub defaults: Vec<Option<usize>>,
+    /// Whether any answer has been changed since the overlay opened
+    pub touched: bool,
 }
 
 impl DashboardQuestionState {
     /// Create a new DashboardQuestionState for the given question data
     ///
     /// Initializes navigation state based on the number of questions and options.
+    /// Questions with a `default` start on that option.
     pub fn from_question_data(data: &AskUserQuestionData) -> Self {
         let num_questions = data.questions.len();
         let options_per_question: Vec<usize> =
             data.questions.iter().map(|q| q.options.len()).collect();
+        let defaults: Vec<Option<usize>> =
+            data.questions.iter().map(|q| q.default_index()).collect();
 
         Self {
             tab_index: 0,
-            selections: vec![Some(0); num_questions],
+            selections: defaults.iter().map(|d| d.or(Some(0))).collect(),
             multi_selections: options_per_question
                 .iter()
                 .map(|&count| vec![false; count])
@@ -118,6 +133,10 @@ impl DashboardQuestionState {
             other_texts: vec![String::new(); num_questions],
             other_active: false,
             answered: vec![false; num_questions],
+            constraints: data.questions.iter().map(|q| q.constraints).collect(),
+            validation_error: None,
+            defaults,
+            touched: false,
         }
     }
 
@@ -129,6 +148,10 @@ impl DashboardQuestionState {
         self.other_texts.clear();
         self.other_active = false;
         self.answered.clear();
+        self.constraints.clear();
+        self.validation_error = None;
+        self.defaults.clear();
+        self.touched = false;
     }
 
     /// Get the currently selected option index for the current tab
@@ -142,19 +165,25 @@ impl DashboardQuestionState {
     pub fn set_current_selection(&mut self, selection: Option<usize>) {
         if self.tab_index < self.selections.len() {
             self.selections[self.tab_index] = selection;
+            self.validation_error = None;
+            self.touched = true;
         }
     }
 
     /// Move to the previous option in the current question
     ///
     /// Wraps from first option to "Other" and from "Other" to last option.
+    /// "Other" is skipped when the question doesn't allow it.
     pub fn prev_option(&mut self, option_count: usize) {
+        let allow_other = self.current_constraints().allow_other;
         if let Some(current) = self.current_selection() {
             if current > 0 {
                 self.set_current_selection(Some(current - 1));
-            } else {
+            } else if allow_other {
                 // Wrap to "Other" (None)
                 self.set_current_selection(None);
+            } else if option_count > 0 {
+                self.set_current_selection(Some(option_count - 1));
             }
         } else {
             // Currently on "Other", move to last option
@@ -167,13 +196,17 @@ impl DashboardQuestionState {
     /// Move to the next option in the current question
     ///
     /// Wraps from last option to "Other" and from "Other" to first option.
+    /// "Other" is skipped when the question doesn't allow it.
     pub fn next_option(&mut self, option_count: usize) {
+        let allow_other = self.current_constraints().allow_other;
         if let Some(current) = self.current_selection() {
             if current < option_count.saturating_sub(1) {
                 self.set_current_selection(Some(current + 1));
-            } else {
+            } else if allow_other {
                 // Wrap to "Other" (None)
                 self.set_current_selection(None);
+            } else {
+                self.set_current_selection(Some(0));
             }
         } else {
             // Currently on "Other", wrap to first option
@@ -185,6 +218,7 @@ impl DashboardQuestionState {
     pub fn next_tab(&mut self, num_questions: usize) {
         if num_questions > 1 {
             self.tab_index = (self.tab_index + 1) % num_questions;
+            self.validation_error = None;
         }
     }
 
@@ -193,10 +227,38 @@ impl DashboardQuestionState {
         if let Some(options) = self.multi_selections.get_mut(self.tab_index) {
             if option_index < options.len() {
                 options[option_index] = !options[option_index];
+                self.validation_error = None;
+                self.touched = true;
             }
         }
     }
 
+    /// Pick option `number` (1-based, as numbered on screen) on the current tab
+    ///
+    /// Moves the cursor there and, for multi-select questions, toggles it.
+    /// Returns false if the question has no such option among the first nine.
+    pub fn select_number(
+        &mut self,
+        number: usize,
+        option_count: usize,
+        multi_select: bool,
+    ) -> bool {
+        if number == 0 || number > option_count.min(9) {
+            return false;
+        }
+        self.set_current_selection(Some(number - 1));
+        if multi_select {
+            self.toggle_multi_selection(number - 1);
+        }
+        true
+    }
+
+    /// Whether Enter should submit every question as it stands: nothing has
+    /// been changed yet and the prompt came with defaults
+    pub fn submits_defaults(&self) -> bool {
+        !self.touched && self.defaults.iter().any(Option::is_some)
+    }
+
     /// Check if a multi-select option is selected for the current tab
     pub fn is_multi_selected(&self, option_index: usize) -> bool {
         self.multi_selections
@@ -215,9 +277,16 @@ impl DashboardQuestionState {
     }
 
     /// Append a character to the current tab's "Other" text
+    ///
+    /// Ignored once the text has reached the question's `other_max_length`.
     pub fn push_other_char(&mut self, c: char) {
+        let constraints = self.current_constraints();
         if let Some(text) = self.other_texts.get_mut(self.tab_index) {
-            text.push(c);
+            if constraints.other_text_has_room(text) {
+                text.push(c);
+                self.validation_error = None;
+                self.touched = true;
+            }
         }
     }
 
@@ -225,6 +294,40 @@ impl DashboardQuestionState {
     pub fn pop_other_char(&mut self) {
         if let Some(text) = self.other_texts.get_mut(self.tab_index) {
             text.pop();
+            self.validation_error = None;
+            self.touched = true;
+        }
+    }
+
+    /// Answer limits of the current question
+    pub fn current_constraints(&self) -> QuestionConstraints {
+        self.constraints
+            .get(self.tab_index)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Why question `index`'s answer can't be submitted, None if it can
+    ///
+    /// For single-select questions the "Other" text only counts when
+    /// "Other" is the selection.
+    pub fn answer_error(&self, index: usize, multi_select: bool) -> Option<String> {
+        let constraints = self.constraints.get(index).copied().unwrap_or_default();
+        let other_text = self
+            .other_texts
+            .get(index)
+            .map(String::as_str)
+            .unwrap_or("");
+        if multi_select {
+            let selected = self
+                .multi_selections
+                .get(index)
+                .map_or(0, |options| options.iter().filter(|&&on| on).count());
+            constraints.answer_error(selected, other_text)
+        } else if self.selections.get(index).copied().flatten().is_some() {
+            constraints.answer_error(1, "")
+        } else {
+            constraints.answer_error(0, other_text)
         }
     }
 
@@ -343,6 +446,12 @@ pub struct DashboardState {
     /// Set when ThreadModeUpdate { mode: Plan } received, cleared on exit
     planning_threads: HashSet<String>,
 
+    /// When each thread's current run started (set on entering Running)
+    run_started_at: HashMap<String, Instant>,
+    /// Outcome summaries of each thread's last long run, shown on its card
+    /// Cleared when the thread starts running again
+    run_summaries: HashMap<String, String>,
+
     /// Current overlay state (if an overlay is open)
     overlay: Option<OverlayState>,
     /// Navigation state for question overlay (when Question overlay is open)
@@ -353,10 +462,18 @@ pub struct DashboardState {
     /// Last-submitted VPS credentials (for retry after re-auth)
     vps_pending_credentials: Option<(String, String, String)>,
 
+    /// Notifications panel entries, newest first
+    notifications: Vec<Notification>,
+
     /// Cached computed thread views (sorted: needs_action first, then by updated_at)
     thread_views: Vec<ThreadView>,
     /// True when threads/waiting_for changed and views need recomputation
     thread_views_dirty: bool,
+
+    /// Entry of `needs_action_threads()` that CommandDeck keys act on
+    needs_action_selected: usize,
+    /// Thread of that entry, to keep it selected when the list reorders
+    needs_action_selected_id: Option<String>,
 }
 
 impl Default for DashboardState {
@@ -379,12 +496,17 @@ impl DashboardState {
             pending_questions: HashMap::new(),
             pending_permissions: HashMap::new(),
             planning_threads: HashSet::new(),
+            run_started_at: HashMap::new(),
+            run_summaries: HashMap::new(),
             vps_pending_credentials: None,
             overlay: None,
             question_state: None,
             aggregate: Aggregate::new(),
+            notifications: Vec::new(),
             thread_views: Vec::new(),
             thread_views_dirty: true,
+            needs_action_selected: 0,
+            needs_action_selected_id: None,
         }
     }
 
@@ -399,7 +521,11 @@ impl DashboardState {
     /// will be populated by subsequent WebSocket updates.
     pub fn set_threads(&mut self, threads: Vec<Thread>, agent_states: &HashMap<String, String>) {
         self.threads.clear();
-        for thread in threads {
+        for mut thread in threads {
+            // Keep run summaries over the stored first-message preview
+            if let Some(summary) = self.run_summaries.get(&thread.id) {
+                thread.preview = summary.clone();
+            }
             self.threads.insert(thread.id.clone(), thread);
         }
         // Convert HashMap<String, String> to HashMap<String, (String, Option<String>)>
@@ -415,12 +541,41 @@ impl DashboardState {
     /// Add a single thread (from WebSocket thread_created event)
     ///
     /// If the thread already exists, it will be replaced.
-    pub fn add_thread(&mut self, thread: Thread) {
+    pub fn add_thread(&mut self, mut thread: Thread) {
+        // Mutes are local: the backend's copy never has them
+        thread.muted |= self.threads.get(&thread.id).is_some_and(|t| t.muted);
         self.threads.insert(thread.id.clone(), thread);
         self.recompute_aggregate();
         self.thread_views_dirty = true;
     }
 
+    /// Remove a thread and all per-thread dashboard state
+    ///
+    /// Called when a thread is deleted locally or by another client.
+    pub fn remove_thread(&mut self, thread_id: &str) {
+        self.threads.remove(thread_id);
+        self.agent_states.remove(thread_id);
+        self.waiting_for.remove(thread_id);
+        self.plan_requests.remove(thread_id);
+        self.plan_approval_states.remove(thread_id);
+        self.locally_verified.remove(thread_id);
+        self.phase_progress.remove(thread_id);
+        self.pending_questions.remove(thread_id);
+        self.pending_permissions.remove(thread_id);
+        self.planning_threads.remove(thread_id);
+        self.run_started_at.remove(thread_id);
+        self.run_summaries.remove(thread_id);
+        if self
+            .overlay
+            .as_ref()
+            .is_some_and(|o| o.thread_id() == thread_id)
+        {
+            self.collapse_overlay();
+        }
+        self.recompute_aggregate();
+        self.thread_views_dirty = true;
+    }
+
     /// Update a single thread's status
     ///
     /// This method also handles cleanup of pending questions and permissions when:
@@ -438,6 +593,20 @@ impl DashboardState {
             tracing::warn!("Received status update for unknown thread: {}", thread_id);
         }
 
+        // Track run timing; a new run makes the previous summary stale
+        match status {
+            ThreadStatus::Running => {
+                self.run_started_at
+                    .entry(thread_id.to_string())
+                    .or_insert_with(Instant::now);
+                self.run_summaries.remove(thread_id);
+            }
+            ThreadStatus::Done | ThreadStatus::Error => {
+                self.run_started_at.remove(thread_id);
+            }
+            ThreadStatus::Waiting => {}
+        }
+
         // Check if we should clear pending question data:
         // 1. Thread completed (Done, Error) - question is no longer relevant
         // 2. Thread no longer waiting for UserInput - question was answered or cancelled
@@ -661,6 +830,36 @@ impl DashboardState {
         }
     }
 
+    /// Last known status of a thread
+    pub fn thread_status(&self, thread_id: &str) -> Option<ThreadStatus> {
+        self.threads.get(thread_id).and_then(|t| t.status)
+    }
+
+    /// How long a thread's current run has been going, if it is running
+    ///
+    /// Runs are timed from the first Running status update until Done/Error;
+    /// time spent waiting for the user counts toward the run.
+    pub fn run_elapsed(&self, thread_id: &str) -> Option<std::time::Duration> {
+        self.run_started_at.get(thread_id).map(|start| start.elapsed())
+    }
+
+    /// Set the outcome summary of a thread's last run
+    ///
+    /// Replaces the thread's preview so the card shows the outcome instead of
+    /// the first message.
+    pub fn set_run_summary(&mut self, thread_id: &str, summary: String) {
+        if let Some(thread) = self.threads.get_mut(thread_id) {
+            thread.preview = summary.clone();
+        }
+        self.run_summaries.insert(thread_id.to_string(), summary);
+        self.thread_views_dirty = true;
+    }
+
+    /// Get the outcome summary of a thread's last run
+    pub fn get_run_summary(&self, thread_id: &str) -> Option<&str> {
+        self.run_summaries.get(thread_id).map(|s| s.as_str())
+    }
+
     /// Update a thread's mode (normal, plan, exec)
     ///
     /// Called when receiving thread mode updates from WebSocket.
@@ -671,6 +870,39 @@ impl DashboardState {
         self.thread_views_dirty = true;
     }
 
+    /// Replace a thread's links (follow-up of, blocked by)
+    pub fn update_thread_links(&mut self, thread_id: &str, links: Vec<crate::models::ThreadLink>) {
+        if let Some(thread) = self.threads.get_mut(thread_id) {
+            thread.links = links;
+        }
+        self.thread_views_dirty = true;
+    }
+
+    /// Pin or unpin a thread
+    pub fn update_thread_pin(&mut self, thread_id: &str, pinned: bool, pin_order: u32) {
+        if let Some(thread) = self.threads.get_mut(thread_id) {
+            thread.pinned = pinned;
+            thread.pin_order = pin_order;
+        }
+        self.thread_views_dirty = true;
+    }
+
+    /// Mute or unmute a thread
+    pub fn update_thread_muted(&mut self, thread_id: &str, muted: bool) {
+        if let Some(thread) = self.threads.get_mut(thread_id) {
+            thread.muted = muted;
+        }
+        self.thread_views_dirty = true;
+    }
+
+    /// Archive or unarchive a thread; archived threads get no view
+    pub fn update_thread_archived(&mut self, thread_id: &str, archived: bool) {
+        if let Some(thread) = self.threads.get_mut(thread_id) {
+            thread.archived = archived;
+        }
+        self.thread_views_dirty = true;
+    }
+
     /// Update a thread's verification status
     ///
     /// Called when receiving thread verified events from WebSocket.
@@ -759,6 +991,11 @@ impl DashboardState {
         self.thread_views_dirty = true;
     }
 
+    /// Number of threads waiting on a question answer
+    pub fn pending_question_count(&self) -> usize {
+        self.pending_questions.len()
+    }
+
     /// Store a pending permission request for a thread
     ///
     /// Called when receiving a permission request from WebSocket.
@@ -833,6 +1070,66 @@ impl DashboardState {
         !self.pending_permissions.is_empty()
     }
 
+    // ========================================================================
+    // Notifications
+    // ========================================================================
+
+    /// Add a notification to the top of the panel
+    ///
+    /// Only the newest `MAX_NOTIFICATIONS` are kept.
+    pub fn push_notification(&mut self, notification: Notification) {
+        self.notifications.insert(0, notification);
+        self.notifications.truncate(MAX_NOTIFICATIONS);
+    }
+
+    /// Notifications, newest first
+    pub fn notifications(&self) -> &[Notification] {
+        &self.notifications
+    }
+
+    /// Number of notifications the user hasn't seen
+    pub fn unread_notification_count(&self) -> usize {
+        self.notifications.iter().filter(|n| !n.read).count()
+    }
+
+    /// Mark one notification as read
+    ///
+    /// Returns true if it was unread.
+    pub fn mark_notification_read(&mut self, id: &str) -> bool {
+        match self.notifications.iter_mut().find(|n| n.id == id) {
+            Some(notification) if !notification.read => {
+                notification.read = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Mark all notifications for a thread as read (e.g. when it is opened)
+    pub fn mark_thread_notifications_read(&mut self, thread_id: &str) {
+        for notification in &mut self.notifications {
+            if notification.thread_id == thread_id {
+                notification.read = true;
+            }
+        }
+    }
+
+    /// Remove a notification
+    ///
+    /// Only the notification goes: a permission prompt or question the
+    /// thread is still waiting on stays pending.
+    pub fn dismiss_notification(&mut self, id: &str) -> Option<Notification> {
+        let index = self.notifications.iter().position(|n| n.id == id)?;
+        Some(self.notifications.remove(index))
+    }
+
+    /// Remove all notifications, returning their IDs
+    ///
+    /// Like `dismiss_notification`, pending prompts are left alone.
+    pub fn clear_notifications(&mut self) -> Vec<String> {
+        self.notifications.drain(..).map(|n| n.id).collect()
+    }
+
     // ========================================================================
     // UI State (from click handlers)
     // ========================================================================
@@ -1378,6 +1675,24 @@ impl DashboardState {
         }
     }
 
+    /// Pick option `number` (1-based) in the question overlay by digit key
+    ///
+    /// Selects it, toggling it on multi-select questions, and scrolls it
+    /// into view. Returns false if there's no such option.
+    pub fn question_select_number(&mut self, number: usize) -> bool {
+        let option_count = self.get_current_option_count();
+        let multi_select = self.is_current_question_multi_select();
+        let selected = self
+            .question_state
+            .as_mut()
+            .is_some_and(|state| state.select_number(number, option_count, multi_select));
+        if selected {
+            let visible = self.estimate_visible_option_count();
+            self.ensure_option_visible(visible);
+        }
+        selected
+    }
+
     /// Check if "Other" text input is active in question overlay
     pub fn is_question_other_active(&self) -> bool {
         self.question_state
@@ -1434,18 +1749,40 @@ impl DashboardState {
     ///
     /// For single questions: returns Some((thread_id, request_id, answers)) to submit
     /// For multiple questions: marks current as answered and advances to next
-    ///                         Returns Some only when all questions are answered
+    ///                         Returns Some only when all questions are answered,
+    ///                         or at once while the defaults are untouched
     ///
-    /// Returns None if not ready to submit.
+    /// Returns None if not ready to submit. Answers that don't meet the
+    /// question's constraints set `validation_error` instead.
     pub fn question_confirm(
         &mut self,
     ) -> Option<(String, String, std::collections::HashMap<String, String>)> {
         let num_questions = self.get_question_count();
 
+        // Enter on an untouched prompt with defaults submits them as they stand
+        let submits_defaults = self
+            .question_state
+            .as_ref()
+            .is_some_and(|s| s.submits_defaults());
+        if num_questions > 1 && submits_defaults {
+            if let Some((index, error)) = self.first_invalid_question_answer() {
+                if let Some(state) = &mut self.question_state {
+                    state.tab_index = index;
+                    state.validation_error = Some(error);
+                }
+                return None;
+            }
+            return self.build_question_answers();
+        }
+
         // Check if "Other" is selected and not in text input mode
         let should_activate_other = self.question_state
             .as_ref()
-            .map(|s| s.current_selection().is_none() && !s.other_active)
+            .map(|s| {
+                s.current_selection().is_none()
+                    && !s.other_active
+                    && s.current_constraints().allow_other
+            })
             .unwrap_or(false);
 
         if should_activate_other {
@@ -1463,6 +1800,14 @@ impl DashboardState {
             }
         }
 
+        let multi_select = self.is_current_question_multi_select();
+        if let Some(state) = &mut self.question_state {
+            if let Some(error) = state.answer_error(state.tab_index, multi_select) {
+                state.validation_error = Some(error);
+                return None;
+            }
+        }
+
         // Deactivate "Other" mode as we're confirming
         self.question_deactivate_other();
 
@@ -1472,11 +1817,18 @@ impl DashboardState {
         }
 
         // Multiple questions: mark current as answered and advance
+        let invalid = self.first_invalid_question_answer();
         if let Some(state) = &mut self.question_state {
             state.mark_current_answered();
 
             // Check if all questions are now answered
             if state.all_answered() {
+                // Answers confirmed earlier may have changed since
+                if let Some((index, error)) = invalid {
+                    state.tab_index = index;
+                    state.validation_error = Some(error);
+                    return None;
+                }
                 return self.build_question_answers();
             }
 
@@ -1487,6 +1839,24 @@ impl DashboardState {
         None
     }
 
+    /// First question (index and reason) whose answer doesn't meet its
+    /// constraints
+    fn first_invalid_question_answer(&self) -> Option<(usize, String)> {
+        let Some(OverlayState::Question {
+            question_data: Some(data),
+            ..
+        }) = &self.overlay
+        else {
+            return None;
+        };
+        let state = self.question_state.as_ref()?;
+        data.questions.iter().enumerate().find_map(|(i, question)| {
+            state
+                .answer_error(i, question.multi_select)
+                .map(|error| (i, error))
+        })
+    }
+
     /// Build the answers map from current question state
     ///
     /// Returns (thread_id, request_id, answers) tuple for WebSocket response.
@@ -1588,10 +1958,18 @@ impl DashboardState {
             }
         });
 
+        // Only worth highlighting when there's a choice
+        let selected_needs_action = self
+            .needs_action_selected_id
+            .as_deref()
+            .filter(|_| self.needs_action_threads().len() > 1);
+
         RenderContext::new(&self.thread_views, &self.aggregate, system_stats, theme, repos)
             .with_overlay(self.overlay.as_ref())
             .with_question_state(self.question_state.as_ref())
             .with_question_timer(question_timer)
+            .with_unread_notifications(self.unread_notification_count())
+            .with_selected_needs_action(selected_needs_action)
     }
 
     /// Compute and cache thread views if dirty
@@ -1602,10 +1980,50 @@ impl DashboardState {
         if self.thread_views_dirty {
             self.thread_views = self.build_thread_views();
             self.thread_views_dirty = false;
+            self.reanchor_needs_action_selection();
         }
         &self.thread_views
     }
 
+    /// Grouping hint for a thread's row, e.g. "⛔ blocked by Fix auth +1"
+    ///
+    /// Shows the thread's first link, or else the first thread linking to it.
+    fn link_hint(&self, thread: &Thread) -> Option<String> {
+        let title = |id: &str| {
+            self.threads
+                .get(id)
+                .map(|t| t.title.clone())
+                .unwrap_or_else(|| id.chars().take(8).collect())
+        };
+
+        let mut inbound: Vec<&Thread> = self
+            .threads
+            .values()
+            .filter(|t| t.links.iter().any(|l| l.thread_id == thread.id))
+            .collect();
+        inbound.sort_by_key(|t| std::cmp::Reverse(t.updated_at));
+
+        let mut hints = thread
+            .links
+            .iter()
+            .map(|link| (link.kind.icon(), link.kind.label(), title(&link.thread_id)))
+            .chain(inbound.iter().flat_map(|other| {
+                other
+                    .links
+                    .iter()
+                    .filter(move |l| l.thread_id == thread.id)
+                    .map(move |l| (l.kind.icon(), l.kind.inverse_label(), other.title.clone()))
+            }));
+
+        let (icon, label, target) = hints.next()?;
+        let more = hints.count();
+        Some(if more > 0 {
+            format!("{} {} {} +{}", icon, label, target, more)
+        } else {
+            format!("{} {} {}", icon, label, target)
+        })
+    }
+
     /// Get what a thread is waiting for
     pub fn get_waiting_for(&self, thread_id: &str) -> Option<&WaitingFor> {
         self.waiting_for.get(thread_id)
@@ -1692,10 +2110,78 @@ impl DashboardState {
         None
     }
 
+    /// All threads that need action with their waiting type, in display order
+    pub fn needs_action_threads(&self) -> Vec<(String, WaitingFor)> {
+        self.thread_views
+            .iter()
+            .filter(|view| view.needs_action)
+            .filter_map(|view| {
+                self.waiting_for
+                    .get(&view.id)
+                    .map(|wf| (view.id.clone(), wf.clone()))
+            })
+            .collect()
+    }
+
+    /// Index of the selected needs-action entry in `needs_action_threads()`
+    pub fn selected_needs_action_index(&self) -> usize {
+        self.needs_action_selected
+            .min(self.needs_action_threads().len().saturating_sub(1))
+    }
+
+    /// Get the selected needs-action thread and its waiting type
+    ///
+    /// This is the top one until the selection is moved.
+    pub fn get_selected_needs_action_thread(&self) -> Option<(String, WaitingFor)> {
+        let mut threads = self.needs_action_threads();
+        let last = threads.len().checked_sub(1)?;
+        let index = self.needs_action_selected.min(last);
+        Some(threads.swap_remove(index))
+    }
+
+    /// Get the pending permission for the selected needs-action thread only
+    pub fn get_selected_pending_permission(&self) -> Option<&PermissionRequest> {
+        let (thread_id, _) = self.get_selected_needs_action_thread()?;
+        self.get_pending_permission(&thread_id)
+    }
+
+    /// Move the needs-action selection by `delta` entries, clamped to the list
+    ///
+    /// Returns true if the selection changed.
+    pub fn move_needs_action_selection(&mut self, delta: isize) -> bool {
+        let threads = self.needs_action_threads();
+        let Some(last) = threads.len().checked_sub(1) else {
+            return false;
+        };
+        let current = self.needs_action_selected.min(last);
+        let target = current.saturating_add_signed(delta).min(last);
+        self.needs_action_selected = target;
+        self.needs_action_selected_id = Some(threads[target].0.clone());
+        target != current
+    }
+
     // ========================================================================
     // Private Helpers
     // ========================================================================
 
+    /// Keep the needs-action selection on the same thread after the views are
+    /// rebuilt; once that thread is dealt with, the entry taking its place
+    /// is selected. Until the selection is first moved it stays on the top one.
+    fn reanchor_needs_action_selection(&mut self) {
+        let Some(selected_id) = self.needs_action_selected_id.as_ref() else {
+            return;
+        };
+        let threads = self.needs_action_threads();
+        let kept = threads.iter().position(|(t, _)| t == selected_id);
+        self.needs_action_selected = kept.unwrap_or(
+            self.needs_action_selected
+                .min(threads.len().saturating_sub(1)),
+        );
+        self.needs_action_selected_id = threads
+            .get(self.needs_action_selected)
+            .map(|(id, _)| id.clone());
+    }
+
     /// Recompute aggregate statistics from current thread data
     fn recompute_aggregate(&mut self) {
         let mut aggregate = Aggregate::new();
@@ -1715,6 +2201,7 @@ impl DashboardState {
         let mut views: Vec<ThreadView> =
             self.threads
                 .values()
+                .filter(|thread| !thread.archived)
                 .map(|thread| {
                     let status = thread.effective_status(&self.agent_states);
                     let waiting_for = self.waiting_for.get(&thread.id).cloned();
@@ -1775,19 +2262,37 @@ impl DashboardState {
                     .with_progress(progress)
                     .with_duration(thread.display_duration())
                     .with_current_operation(current_operation)
-                    .with_activity_text(activity_text);
+                    .with_activity_text(activity_text)
+                    .with_summary(self.run_summaries.get(&thread.id).cloned())
+                    .with_link_hint(self.link_hint(thread))
+                    .with_pinned(thread.pinned)
+                    .with_muted(thread.muted);
 
                     // If thread has a pending permission, mark as needing action
                     if has_pending_permission {
                         view.needs_action = true;
                     }
 
+                    // Muted threads still wait, but are listed with the rest
+                    // and never become the needs-action target
+                    if thread.muted {
+                        view.needs_action = false;
+                    }
+
                     view
                 })
                 .collect();
 
-        // Sort: needs_action first, then by updated_at (most recent first)
+        // Sort: pinned first (in pin order), then needs_action, then by
+        // updated_at (most recent first)
         views.sort_by(|a, b| {
+            let pin_order = |id: &str| self.threads.get(id).map_or(0, |t| t.pin_order);
+            match (a.pinned, b.pinned) {
+                (true, false) => return std::cmp::Ordering::Less,
+                (false, true) => return std::cmp::Ordering::Greater,
+                (true, true) => return pin_order(&a.id).cmp(&pin_order(&b.id)),
+                (false, false) => {}
+            }
             match (a.needs_action, b.needs_action) {
                 (true, false) => std::cmp::Ordering::Less,
                 (false, true) => std::cmp::Ordering::Greater,
@@ -1835,9 +2340,82 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         }
     }
 
+    #[test]
+    fn test_remove_thread_clears_thread_state_and_overlay() {
+        let mut state = DashboardState::new();
+        state.add_thread(make_thread("t1", "Doomed"));
+        state.add_thread(make_thread("t2", "Kept"));
+        state.set_thread_planning("t1", true);
+        state.expand_thread("t1", 0);
+        assert!(state.overlay().is_some());
+
+        state.remove_thread("t1");
+
+        assert!(state.get_thread("t1").is_none());
+        assert!(state.get_thread("t2").is_some());
+        assert!(!state.is_thread_planning("t1"));
+        assert!(state.overlay().is_none());
+        assert_eq!(state.compute_thread_views().len(), 1);
+    }
+
+    #[test]
+    fn test_run_timing_and_summary() {
+        let mut state = DashboardState::new();
+        state.add_thread(make_thread("t1", "Long run"));
+        assert!(state.run_elapsed("t1").is_none());
+
+        state.update_thread_status("t1", ThreadStatus::Running, None);
+        assert!(state.run_elapsed("t1").is_some());
+
+        state.update_thread_status("t1", ThreadStatus::Done, None);
+        assert!(state.run_elapsed("t1").is_none());
+
+        state.set_run_summary("t1", "✅ 14 tests fixed".to_string());
+        assert_eq!(state.get_thread("t1").unwrap().preview, "✅ 14 tests fixed");
+        let views = state.compute_thread_views();
+        assert_eq!(views[0].summary.as_deref(), Some("✅ 14 tests fixed"));
+
+        // Full refresh keeps the summary over the stored preview
+        state.set_threads(vec![make_thread("t1", "Long run")], &HashMap::new());
+        assert_eq!(state.get_thread("t1").unwrap().preview, "✅ 14 tests fixed");
+
+        // A new run makes the summary stale
+        state.update_thread_status("t1", ThreadStatus::Running, None);
+        assert!(state.get_run_summary("t1").is_none());
+        assert!(state.compute_thread_views()[0].summary.is_none());
+    }
+
+    #[test]
+    fn test_thread_views_link_hints() {
+        use crate::models::{ThreadLink, ThreadLinkKind};
+
+        let mut state = DashboardState::new();
+        state.add_thread(make_thread("t1", "Design API"));
+        state.add_thread(make_thread("t2", "Ship API"));
+        state.add_thread(make_thread("t3", "Unrelated"));
+
+        state.update_thread_links("t2", vec![ThreadLink::new(ThreadLinkKind::BlockedBy, "t1")]);
+
+        let views = state.compute_thread_views();
+        let hint = |id: &str| {
+            views
+                .iter()
+                .find(|v| v.id == id)
+                .and_then(|v| v.link_hint.clone())
+        };
+        assert_eq!(hint("t2").as_deref(), Some("\u{2298} blocked by Design API"));
+        assert_eq!(hint("t1").as_deref(), Some("\u{2298} blocks Ship API"));
+        assert_eq!(hint("t3"), None);
+    }
+
     // -------------------- Overlay Tests --------------------
 
     #[test]
@@ -2006,6 +2584,8 @@ mod tests {
                 header: String::new(),
                 options,
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -2054,6 +2634,8 @@ mod tests {
                 header: String::new(),
                 options,
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -2108,6 +2690,8 @@ mod tests {
                 header: String::new(),
                 options,
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -2287,6 +2871,52 @@ mod tests {
         assert_eq!(views[1].id, "t1");
     }
 
+    #[test]
+    fn test_compute_thread_views_sorts_pinned_first_in_pin_order() {
+        let mut state = DashboardState::new();
+
+        let mut waiting = make_thread("waiting", "Waiting");
+        waiting.status = Some(ThreadStatus::Waiting);
+        state.threads.insert("waiting".to_string(), waiting);
+        state
+            .threads
+            .insert("second".to_string(), make_thread("second", "Second"));
+        state
+            .threads
+            .insert("first".to_string(), make_thread("first", "First"));
+        state.update_thread_pin("second", true, 1);
+        state.update_thread_pin("first", true, 0);
+
+        let views = state.compute_thread_views();
+
+        let ids: Vec<&str> = views.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, vec!["first", "second", "waiting"]);
+        assert!(views[0].pinned);
+        assert!(!views[2].pinned);
+    }
+
+    #[test]
+    fn test_compute_thread_views_skips_archived() {
+        let mut state = DashboardState::new();
+        state
+            .threads
+            .insert("kept".to_string(), make_thread("kept", "Kept"));
+        state
+            .threads
+            .insert("done".to_string(), make_thread("done", "Done"));
+
+        state.update_thread_archived("done", true);
+        let ids: Vec<String> = state
+            .compute_thread_views()
+            .iter()
+            .map(|v| v.id.clone())
+            .collect();
+        assert_eq!(ids, vec!["kept".to_string()]);
+
+        state.update_thread_archived("done", false);
+        assert_eq!(state.compute_thread_views().len(), 2);
+    }
+
     #[test]
     fn test_compute_thread_views_cached() {
         let mut state = DashboardState::new();
@@ -3151,6 +3781,8 @@ mod tests {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3184,6 +3816,8 @@ mod tests {
                 header: "Test".to_string(),
                 options: vec![],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3210,6 +3844,8 @@ mod tests {
                 header: "Test".to_string(),
                 options: vec![],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3233,6 +3869,8 @@ mod tests {
                 header: "Test".to_string(),
                 options: vec![],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3259,6 +3897,8 @@ mod tests {
                     description: "Option A".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3278,6 +3918,8 @@ mod tests {
                     },
                 ],
                 multi_select: true,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3306,6 +3948,8 @@ mod tests {
                 header: "First".to_string(),
                 options: vec![],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3316,6 +3960,8 @@ mod tests {
                 header: "Second".to_string(),
                 options: vec![],
                 multi_select: true,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3367,6 +4013,8 @@ mod tests {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3432,6 +4080,8 @@ mod tests {
                     description: "Enable feature A".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3475,6 +4125,8 @@ mod tests {
                     },
                 ],
                 multi_select: true,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3526,6 +4178,8 @@ mod tests {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3564,6 +4218,8 @@ mod tests {
                     description: "First option".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3614,6 +4270,8 @@ mod tests {
                         },
                     ],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Q2?".to_string(),
@@ -3633,6 +4291,8 @@ mod tests {
                         },
                     ],
                     multi_select: true,
+                    constraints: Default::default(),
+                    default: None,
                 },
             ],
             answers: std::collections::HashMap::new(),
@@ -3675,6 +4335,8 @@ mod tests {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3716,6 +4378,8 @@ mod tests {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3749,18 +4413,24 @@ mod tests {
                     header: "Q1".to_string(),
                     options: vec![],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Q2?".to_string(),
                     header: "Q2".to_string(),
                     options: vec![],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Q3?".to_string(),
                     header: "Q3".to_string(),
                     options: vec![],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
             ],
             answers: std::collections::HashMap::new(),
@@ -3800,6 +4470,8 @@ mod tests {
                     },
                 ],
                 multi_select: true,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3839,6 +4511,8 @@ mod tests {
                     description: "".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -3875,12 +4549,16 @@ mod tests {
                     header: "Q1".to_string(),
                     options: vec![],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Q2?".to_string(),
                     header: "Q2".to_string(),
                     options: vec![],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
             ],
             answers: std::collections::HashMap::new(),
@@ -3916,18 +4594,24 @@ mod tests {
                     header: "Q1".to_string(),
                     options: vec![],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Q2?".to_string(),
                     header: "Q2".to_string(),
                     options: vec![],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Q3?".to_string(),
                     header: "Q3".to_string(),
                     options: vec![],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
             ],
             answers: std::collections::HashMap::new(),
@@ -3983,6 +4667,8 @@ mod tests {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -4023,6 +4709,8 @@ mod tests {
                         description: "".to_string(),
                     }],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Q2?".to_string(),
@@ -4032,6 +4720,8 @@ mod tests {
                         description: "".to_string(),
                     }],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
             ],
             answers: std::collections::HashMap::new(),
@@ -4070,6 +4760,8 @@ mod tests {
                     description: "".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -4105,6 +4797,8 @@ mod tests {
                     description: "".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -4135,6 +4829,124 @@ mod tests {
         assert_eq!(answers.get("Q?"), Some(&"Hi".to_string()));
     }
 
+    #[test]
+    fn test_question_confirm_enforces_constraints() {
+        use crate::state::session::{
+            AskUserQuestionData, Question, QuestionConstraints, QuestionOption,
+        };
+
+        let mut state = DashboardState::new();
+        let thread = make_thread("t1", "Test Thread");
+        state.threads.insert("t1".to_string(), thread);
+
+        let question_data = AskUserQuestionData {
+            questions: vec![Question {
+                question: "Q?".to_string(),
+                header: "Q".to_string(),
+                options: vec![
+                    QuestionOption {
+                        label: "A".to_string(),
+                        description: "".to_string(),
+                    },
+                    QuestionOption {
+                        label: "B".to_string(),
+                        description: "".to_string(),
+                    },
+                ],
+                multi_select: true,
+                constraints: QuestionConstraints {
+                    max_selections: Some(1),
+                    other_max_length: Some(2),
+                    ..Default::default()
+                },
+                default: None,
+            }],
+            answers: std::collections::HashMap::new(),
+        };
+
+        state.set_pending_question("t1", "req-limits".to_string(), question_data);
+        state.expand_thread("t1", 10);
+
+        // Two options toggled: over the limit
+        state.question_toggle_option();
+        state.question_next_option();
+        state.question_toggle_option();
+        assert!(state.question_confirm().is_none());
+        assert_eq!(
+            state.question_state.as_ref().unwrap().validation_error.as_deref(),
+            Some("Select at most 1 option")
+        );
+
+        // "Other" text stops at its max length
+        state.question_toggle_option();
+        state.question_next_option();
+        state.question_activate_other();
+        for c in "xyz".chars() {
+            state.question_type_char(c);
+        }
+        assert_eq!(
+            state.question_state.as_ref().unwrap().current_other_text(),
+            "xy"
+        );
+
+        // Option A plus "Other" text is still two answers
+        assert!(state.question_confirm().is_none());
+        state.question_cancel_other();
+        state.question_next_option();
+        let (_, _, answers) = state.question_confirm().unwrap();
+        assert_eq!(answers.get("Q?"), Some(&"A".to_string()));
+    }
+
+    #[test]
+    fn test_question_defaults_and_number_keys() {
+        use crate::state::session::{AskUserQuestionData, Question, QuestionOption};
+
+        let option = |label: &str| QuestionOption {
+            label: label.to_string(),
+            description: "".to_string(),
+        };
+        let question = |text: &str, multi_select: bool, default: Option<&str>| Question {
+            question: text.to_string(),
+            header: text.to_string(),
+            options: vec![option("A"), option("B"), option("C")],
+            multi_select,
+            constraints: Default::default(),
+            default: default.map(str::to_string),
+        };
+
+        let mut state = DashboardState::new();
+        state
+            .threads
+            .insert("t1".to_string(), make_thread("t1", "Test Thread"));
+        let question_data = AskUserQuestionData {
+            questions: vec![
+                question("Q1?", false, Some("B")),
+                question("Q2?", true, None),
+            ],
+            answers: std::collections::HashMap::new(),
+        };
+        state.set_pending_question("t1", "req-defaults".to_string(), question_data.clone());
+        state.expand_thread("t1", 10);
+
+        // The cursor starts on the default, and Enter submits it right away
+        assert_eq!(state.question_state().unwrap().current_selection(), Some(1));
+        let (_, _, answers) = state.question_confirm().unwrap();
+        assert_eq!(answers.get("Q1?"), Some(&"B".to_string()));
+
+        // Digits select, and toggle on multi-select questions
+        state.set_pending_question("t1", "req-numbers".to_string(), question_data);
+        state.expand_thread("t1", 10);
+        assert!(state.question_select_number(3));
+        assert!(!state.question_select_number(4));
+        assert_eq!(state.question_state().unwrap().current_selection(), Some(2));
+        assert!(state.question_confirm().is_none());
+        assert!(state.question_select_number(1));
+        assert!(state.question_select_number(2));
+        let (_, _, answers) = state.question_confirm().unwrap();
+        assert_eq!(answers.get("Q1?"), Some(&"C".to_string()));
+        assert_eq!(answers.get("Q2?"), Some(&"A, B".to_string()));
+    }
+
     #[test]
     fn test_question_state_reset_on_collapse() {
         use crate::state::session::{AskUserQuestionData, Question};
@@ -4149,6 +4961,8 @@ mod tests {
                 header: "Q".to_string(),
                 options: vec![],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -4309,6 +5123,71 @@ mod tests {
         assert!(matches!(waiting_for, WaitingFor::UserInput));
     }
 
+    /// Three threads waiting on permissions, newest first: t3, t2, t1
+    fn state_with_three_permissions() -> DashboardState {
+        let mut state = DashboardState::new();
+        for (i, id) in ["t1", "t2", "t3"].iter().enumerate() {
+            let mut thread = make_thread(id, id);
+            thread.status = Some(ThreadStatus::Waiting);
+            thread.updated_at = Utc::now() + chrono::Duration::seconds(i as i64);
+            state.threads.insert(id.to_string(), thread);
+            state.waiting_for.insert(
+                id.to_string(),
+                WaitingFor::Permission {
+                    request_id: format!("req-{}", id),
+                    tool_name: "Bash".to_string(),
+                },
+            );
+        }
+        state.thread_views_dirty = true;
+        let _ = state.compute_thread_views();
+        state
+    }
+
+    #[test]
+    fn test_needs_action_selection_moves_and_clamps() {
+        let mut state = state_with_three_permissions();
+        assert_eq!(state.needs_action_threads().len(), 3);
+        assert_eq!(state.get_selected_needs_action_thread().unwrap().0, "t3");
+
+        assert!(state.move_needs_action_selection(1));
+        assert!(state.move_needs_action_selection(1));
+        assert!(!state.move_needs_action_selection(1));
+        assert_eq!(state.selected_needs_action_index(), 2);
+        assert_eq!(state.get_selected_needs_action_thread().unwrap().0, "t1");
+        // The top entry is unaffected
+        assert_eq!(state.get_top_needs_action_thread().unwrap().0, "t3");
+
+        assert!(state.move_needs_action_selection(-5));
+        assert_eq!(state.selected_needs_action_index(), 0);
+        assert!(!state.move_needs_action_selection(-1));
+    }
+
+    #[test]
+    fn test_needs_action_selection_follows_its_thread() {
+        let mut state = state_with_three_permissions();
+        state.move_needs_action_selection(1);
+        assert_eq!(state.get_selected_needs_action_thread().unwrap().0, "t2");
+
+        // A newer thread starts waiting above: t2 stays selected
+        let mut t4 = make_thread("t4", "t4");
+        t4.status = Some(ThreadStatus::Waiting);
+        t4.updated_at = Utc::now() + chrono::Duration::seconds(10);
+        state.threads.insert("t4".to_string(), t4);
+        state
+            .waiting_for
+            .insert("t4".to_string(), WaitingFor::UserInput);
+        state.thread_views_dirty = true;
+        let _ = state.compute_thread_views();
+        assert_eq!(state.selected_needs_action_index(), 2);
+        assert_eq!(state.get_selected_needs_action_thread().unwrap().0, "t2");
+
+        // Once t2 is answered, the entry below takes its place
+        state.remove_thread("t2");
+        let _ = state.compute_thread_views();
+        assert_eq!(state.get_selected_needs_action_thread().unwrap().0, "t1");
+    }
+
     // -------------------- build_question_answers Tests --------------------
     // These tests verify the answers HashMap is built correctly for WebSocket response
 
@@ -4339,6 +5218,8 @@ mod tests {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -4391,6 +5272,8 @@ mod tests {
                     },
                 ],
                 multi_select: true,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -4439,6 +5322,8 @@ mod tests {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -4491,6 +5376,8 @@ mod tests {
                     },
                 ],
                 multi_select: true,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -4542,6 +5429,8 @@ mod tests {
                         },
                     ],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Which framework?".to_string(),
@@ -4557,6 +5446,8 @@ mod tests {
                         },
                     ],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
             ],
             answers: std::collections::HashMap::new(),
@@ -4601,6 +5492,8 @@ mod tests {
                     description: "Industry standard".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -4639,6 +5532,8 @@ mod tests {
                     description: "JavaScript linter with extensive rule set".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         };
@@ -5647,4 +6542,70 @@ mod tests {
         // Top thread is UserInput, no permissions set
         assert!(state.get_top_pending_permission().is_none());
     }
+
+    // -------------------- Notifications Tests --------------------
+
+    fn make_notification(id: &str, thread_id: &str) -> Notification {
+        Notification {
+            id: id.to_string(),
+            thread_id: thread_id.to_string(),
+            message_id: None,
+            timestamp: Utc::now(),
+            message: "Needs permission: Bash".to_string(),
+            read: false,
+        }
+    }
+
+    #[test]
+    fn test_notifications_newest_first_and_unread_count() {
+        let mut state = DashboardState::new();
+        state.push_notification(make_notification("n1", "t1"));
+        state.push_notification(make_notification("n2", "t2"));
+
+        assert_eq!(state.notifications()[0].id, "n2");
+        assert_eq!(state.unread_notification_count(), 2);
+
+        assert!(state.mark_notification_read("n1"));
+        assert!(!state.mark_notification_read("n1"));
+        assert_eq!(state.unread_notification_count(), 1);
+
+        state.mark_thread_notifications_read("t2");
+        assert_eq!(state.unread_notification_count(), 0);
+    }
+
+    #[test]
+    fn test_dismiss_notification_keeps_pending_permission() {
+        let mut state = DashboardState::new();
+        state.set_pending_permission(
+            "t1",
+            PermissionRequest {
+                permission_id: "perm-1".to_string(),
+                thread_id: Some("t1".to_string()),
+                tool_name: "Bash".to_string(),
+                description: "Run a command".to_string(),
+                context: None,
+                tool_input: None,
+                received_at: Instant::now(),
+            },
+        );
+        state.push_notification(make_notification("n1", "t1"));
+        state.push_notification(make_notification("n2", "t1"));
+
+        assert_eq!(state.dismiss_notification("n1").map(|n| n.id), Some("n1".to_string()));
+        assert!(state.dismiss_notification("n1").is_none());
+        assert_eq!(state.clear_notifications(), vec!["n2".to_string()]);
+
+        assert!(state.notifications().is_empty());
+        assert!(state.get_pending_permission("t1").is_some());
+    }
+
+    #[test]
+    fn test_notifications_are_capped() {
+        let mut state = DashboardState::new();
+        for i in 0..MAX_NOTIFICATIONS + 5 {
+            state.push_notification(make_notification(&format!("n{}", i), "t1"));
+        }
+        assert_eq!(state.notifications().len(), MAX_NOTIFICATIONS);
+        assert_eq!(state.notifications()[0].id, format!("n{}", MAX_NOTIFICATIONS + 4));
+    }
 }
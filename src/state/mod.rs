@@ -18,11 +18,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 // Re-export new state types at module level
-pub use cache::AppCache;
+pub use cache::{AppCache, PickerDataKind};
 pub use dashboard::DashboardState;
 pub use file_picker::{FilePickerState, MAX_VISIBLE_ROWS as FILE_PICKER_MAX_VISIBLE_ROWS};
 pub use picker::{
-    SectionState, UnifiedPickerState, DEFAULT_SEARCH_LIMIT, SEARCH_DEBOUNCE_MS,
+    is_path_query, DirectoryBrowse, SectionState, UnifiedPickerState, DEFAULT_SEARCH_LIMIT,
+    SEARCH_DEBOUNCE_MS,
 };
 pub use session::{
     AskUserQuestionData, AskUserQuestionState, PermissionRequest, Question, QuestionOption,
@@ -47,6 +48,7 @@ pub struct Thread {
 pub enum TaskStatus {
     Pending,
     InProgress,
+    Paused,
     Completed,
 }
 
@@ -59,13 +61,19 @@ pub struct Task {
     pub progress: f32, // 0.0 to 1.0
 }
 
-/// Represents a system notification
-/// Note: Planned for Phase 3+ (UI notifications)
-#[allow(dead_code)]
+/// An entry in the CommandDeck notifications panel
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Notification {
+    /// Notification ID (shared with the backend for dismissal sync)
+    pub id: String,
+    /// Thread that produced the notification
+    pub thread_id: String,
+    /// Message the notification refers to, if any (opening scrolls to it)
+    pub message_id: Option<i64>,
     pub timestamp: DateTime<Utc>,
     pub message: String,
+    /// Whether the user has seen it (unread ones count in the header)
+    pub read: bool,
 }
 
 /// Todo item status enum
@@ -9,10 +9,12 @@
 //! - Server-side search with debounce (150ms)
 //! - Independent loading states per section
 //! - Keyboard navigation across sections
+//! - Directory browsing when there are no indexed folders or the query is a path
 
+use std::path::Path;
 use std::time::Instant;
 
-use crate::models::picker::{PickerItem, PickerSection};
+use crate::models::picker::{DirectoryListing, PickerItem, PickerSection};
 
 /// Debounce delay for search queries (milliseconds)
 pub const SEARCH_DEBOUNCE_MS: u64 = 150;
@@ -34,6 +36,12 @@ pub struct SectionState {
     pub loading: bool,
     /// Error message if the search failed
     pub error: Option<String>,
+    /// When the shown items were fetched (None until cached)
+    pub updated_at: Option<Instant>,
+    /// Whether the shown items are older than their cache TTL
+    pub stale: bool,
+    /// Whether a background refresh is in flight
+    pub refreshing: bool,
 }
 
 impl SectionState {
@@ -63,6 +71,9 @@ impl SectionState {
         self.items.clear();
         self.loading = false;
         self.error = None;
+        self.updated_at = None;
+        self.stale = false;
+        self.refreshing = false;
     }
 
     /// Set loading state
@@ -103,6 +114,51 @@ impl SectionState {
     }
 }
 
+/// Whether a picker query names a path (`/srv/app`, `~/src`)
+pub fn is_path_query(query: &str) -> bool {
+    query.starts_with('/') || query.starts_with('~')
+}
+
+/// Split a path query into the directory to list and the name filter:
+/// `/srv/ap` -> (`/srv/`, `ap`), `~` -> (`~`, ``)
+pub fn split_path_query(query: &str) -> (&str, &str) {
+    match query.rfind('/') {
+        Some(i) => (&query[..=i], &query[i + 1..]),
+        None => ("~", query.trim_start_matches('~')),
+    }
+}
+
+/// Last component of a path, for display (`/srv/app/` -> `app`)
+fn dir_name(path: &str) -> String {
+    let trimmed = path.trim_end_matches('/');
+    match trimmed.rsplit('/').next() {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => path.to_string(),
+    }
+}
+
+/// Backend directory listing shown in the folders section
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryBrowse {
+    /// Directory as requested (`~`, `/srv/`, ...)
+    pub requested: String,
+    /// Whether the listing request is in flight
+    pub loading: bool,
+    /// Error from the backend (e.g. permission denied), shown inline
+    pub error: Option<String>,
+    /// Listing of `requested`, once it has arrived
+    pub listing: Option<DirectoryListing>,
+}
+
+impl DirectoryBrowse {
+    /// Path shown in the section header
+    pub fn display_path(&self) -> &str {
+        self.listing
+            .as_ref()
+            .map_or(self.requested.as_str(), |l| l.path.as_str())
+    }
+}
+
 /// Unified picker state
 #[derive(Debug, Clone)]
 pub struct UnifiedPickerState {
@@ -131,6 +187,14 @@ pub struct UnifiedPickerState {
     /// Pending selection - stored when picker closes without message
     /// Used when user types message and presses Enter
     pub pending_selection: Option<PickerItem>,
+    /// Paths of the folders already attached to the message (marked in the list)
+    pub attached_paths: Vec<String>,
+    /// Directory listing replacing the folder results, if browsing
+    pub browse: Option<DirectoryBrowse>,
+    /// Picking the active thread's new working directory (`/cd`) rather
+    /// than mentioning something in the input: threads are left out and the
+    /// query isn't mirrored in the textarea
+    pub changing_directory: bool,
 }
 
 impl Default for UnifiedPickerState {
@@ -156,6 +220,9 @@ impl UnifiedPickerState {
             clone_message: None,
             validation_error: None,
             pending_selection: None,
+            attached_paths: Vec::new(),
+            browse: None,
+            changing_directory: false,
         }
     }
 
@@ -190,6 +257,8 @@ impl UnifiedPickerState {
         self.cloning = false;
         self.clone_message = None;
         self.validation_error = None;
+        self.browse = None;
+        self.changing_directory = false;
         // Don't clear pending_selection - it's used after close
     }
 
@@ -213,19 +282,202 @@ impl UnifiedPickerState {
         self.pending_selection.is_some()
     }
 
+    /// Whether the item is a folder already attached to the message
+    pub fn is_attached(&self, item: &PickerItem) -> bool {
+        if matches!(item, PickerItem::Thread { .. }) {
+            return false;
+        }
+        item.working_directory()
+            .is_some_and(|path| self.attached_paths.iter().any(|p| p == path))
+    }
+
     /// Update the search query and filter items locally (instant)
     pub fn set_query(&mut self, query: String) {
-        self.query = query.clone();
+        self.query = query;
         // Clear validation error when user modifies query
         self.validation_error = None;
+        self.apply_query();
+    }
+
+    /// Filter all sections by the current query, e.g. after a refresh
+    /// replaced their items
+    pub fn apply_query(&mut self) {
         // Filter all sections locally - no API call needed
-        self.repos.filter_by_query(&query);
-        self.threads.filter_by_query(&query);
-        self.folders.filter_by_query(&query);
+        self.repos.filter_by_query(&self.query);
+        self.threads.filter_by_query(&self.query);
+        self.folders.filter_by_query(&self.query);
+        if is_path_query(&self.query) {
+            // A path only makes sense for the directory listing
+            self.repos.items.clear();
+            self.threads.items.clear();
+        }
+        if self.changing_directory {
+            self.threads.items.clear();
+        }
+        self.apply_browse_filter();
         // Reset selection if current item is no longer visible
         self.validate_selection();
     }
 
+    // ========================================================================
+    // Directory browsing
+    // ========================================================================
+
+    /// Whether the folders section is showing a directory listing
+    pub fn is_browsing(&self) -> bool {
+        self.browse.is_some()
+    }
+
+    /// Directory the folders section should list, or None for the normal
+    /// folder results.
+    ///
+    /// A path query lists its own directory. Otherwise browsing is the
+    /// fallback once the folder search has come back empty: it stays in the
+    /// current directory, starting from home.
+    pub fn wanted_browse_dir(&self) -> Option<String> {
+        if is_path_query(&self.query) {
+            return Some(split_path_query(&self.query).0.to_string());
+        }
+        if self.folders.loading || !self.folders.all_items.is_empty() {
+            return None;
+        }
+        Some(
+            self.browse
+                .as_ref()
+                .map_or_else(|| "~".to_string(), |b| b.requested.clone()),
+        )
+    }
+
+    /// Start listing `dir`; the folders section shows it as loading
+    pub fn start_browse(&mut self, dir: String) {
+        self.browse = Some(DirectoryBrowse {
+            requested: dir,
+            loading: true,
+            error: None,
+            listing: None,
+        });
+        if self.selected_section == PickerSection::Folders {
+            self.selected_index = 0;
+        }
+        self.apply_browse_filter();
+        self.validate_selection();
+    }
+
+    /// Stop browsing and show the folder results again
+    pub fn stop_browse(&mut self) {
+        if self.browse.take().is_some() {
+            let query = self.query.clone();
+            self.folders.filter_by_query(&query);
+            self.validate_selection();
+        }
+    }
+
+    /// Store a listing; ignored if the picker has moved to another directory
+    pub fn set_directory_listing(&mut self, requested: &str, listing: DirectoryListing) {
+        match self.browse.as_mut() {
+            Some(browse) if browse.requested == requested => {
+                browse.loading = false;
+                browse.error = None;
+                browse.listing = Some(listing);
+            }
+            _ => return,
+        }
+        self.apply_browse_filter();
+        self.validate_selection();
+    }
+
+    /// Show a listing error inline; the picker stays open
+    pub fn set_directory_error(&mut self, requested: &str, error: String) {
+        if let Some(browse) = self.browse.as_mut() {
+            if browse.requested == requested {
+                browse.loading = false;
+                browse.error = Some(error);
+                browse.listing = None;
+            }
+        }
+        self.apply_browse_filter();
+        self.validate_selection();
+    }
+
+    /// The selected subdirectory, if Enter should open it rather than pick it
+    pub fn descend_target(&self) -> Option<String> {
+        let current = self.browse.as_ref()?.listing.as_ref()?.path.as_str();
+        if self.selected_section != PickerSection::Folders {
+            return None;
+        }
+        match self.selected_item() {
+            Some(PickerItem::Folder { path, .. }) if path != current => Some(path.clone()),
+            _ => None,
+        }
+    }
+
+    /// Parent of the browsed directory, None at the root or when unknown
+    pub fn browse_parent(&self) -> Option<String> {
+        let browse = self.browse.as_ref()?;
+        match &browse.listing {
+            Some(listing) => listing.parent.clone(),
+            // Failed listing: fall back to the requested path itself
+            None if browse.requested.starts_with('/') => Path::new(&browse.requested)
+                .parent()
+                .map(|p| p.to_string_lossy().into_owned()),
+            None => None,
+        }
+    }
+
+    /// Name filter applied to the listing: the last path segment of a path
+    /// query, or the whole query when browsing as a fallback
+    fn browse_filter(&self) -> &str {
+        if is_path_query(&self.query) {
+            split_path_query(&self.query).1
+        } else {
+            &self.query
+        }
+    }
+
+    /// Rebuild the folders section from the listing: the listed directory
+    /// itself first (picking it selects it), then matching subdirectories.
+    /// Hidden directories only show when the filter starts with a dot.
+    fn apply_browse_filter(&mut self) {
+        let Some(browse) = &self.browse else {
+            return;
+        };
+        let filter = self.browse_filter().to_lowercase();
+        let show_hidden = filter.starts_with('.');
+
+        let mut items = Vec::new();
+        if let Some(listing) = &browse.listing {
+            items.push(PickerItem::Folder {
+                name: dir_name(&listing.path),
+                path: listing.path.clone(),
+            });
+            items.extend(
+                listing
+                    .entries
+                    .iter()
+                    .filter(|e| e.is_dir)
+                    .filter(|e| show_hidden || !e.name.starts_with('.'))
+                    .filter(|e| e.name.to_lowercase().contains(&filter))
+                    .map(|e| PickerItem::Folder {
+                        name: format!("{}/", e.name),
+                        path: e.path.clone(),
+                    }),
+            );
+        }
+        self.folders.items = items;
+    }
+
+    /// Whether a section takes up lines in the picker (header + items)
+    pub fn shows_section(&self, section: PickerSection) -> bool {
+        if section == PickerSection::Folders && self.is_browsing() {
+            return true;
+        }
+        if section == PickerSection::Threads && self.changing_directory {
+            return false;
+        }
+        let state = self.section_state(section);
+        !state.items.is_empty() || state.loading
+    }
+
     /// Check if debounce period has elapsed since last query change
     /// NOTE: With local filtering, this is no longer needed for search
     /// but kept for potential future use (e.g., async refresh)
@@ -423,7 +675,7 @@ impl UnifiedPickerState {
 
         for (section, section_state) in sections {
             // Skip empty sections (they don't render headers)
-            if section_state.items.is_empty() && !section_state.loading {
+            if !self.shows_section(section) {
                 continue;
             }
 
@@ -445,13 +697,17 @@ impl UnifiedPickerState {
 
     /// Calculate total number of lines (headers + items)
     pub fn total_lines(&self) -> usize {
-        let sections = [&self.repos, &self.threads, &self.folders];
+        let sections = [
+            PickerSection::Repos,
+            PickerSection::Threads,
+            PickerSection::Folders,
+        ];
         let mut total = 0;
 
         for section in sections {
-            if !section.items.is_empty() || section.loading {
+            if self.shows_section(section) {
                 total += 1; // header
-                total += section.items.len();
+                total += self.section_state(section).items.len();
             }
         }
 
@@ -627,6 +883,35 @@ mod tests {
         assert!(state.folders.loading);
     }
 
+    #[test]
+    fn test_is_attached() {
+        let mut state = UnifiedPickerState::new();
+        state.attached_paths = vec!["/home/user/web".to_string()];
+
+        let folder = PickerItem::Folder {
+            name: "web".to_string(),
+            path: "/home/user/web".to_string(),
+        };
+        let repo = PickerItem::Repo {
+            name: "web".to_string(),
+            local_path: Some("/home/user/web".to_string()),
+            url: "https://github.com/user/web".to_string(),
+        };
+        let thread = PickerItem::Thread {
+            id: "t1".to_string(),
+            title: "Fix web".to_string(),
+            working_directory: Some("/home/user/web".to_string()),
+        };
+        let other = PickerItem::Folder {
+            name: "api".to_string(),
+            path: "/home/user/api".to_string(),
+        };
+        assert!(state.is_attached(&folder));
+        assert!(state.is_attached(&repo));
+        assert!(!state.is_attached(&thread));
+        assert!(!state.is_attached(&other));
+    }
+
     #[test]
     fn test_picker_state_close() {
         let mut state = UnifiedPickerState::new();
@@ -1144,4 +1429,127 @@ mod tests {
         });
         assert_eq!(state.total_lines(), 4); // 2 + 1 header + 1 item
     }
+
+    // ========================================================================
+    // Directory Browsing Tests
+    // ========================================================================
+
+    fn listing(path: &str, parent: Option<&str>, names: &[&str]) -> DirectoryListing {
+        use crate::models::picker::FolderEntry;
+        DirectoryListing {
+            path: path.to_string(),
+            parent: parent.map(str::to_string),
+            entries: names
+                .iter()
+                .map(|name| FolderEntry {
+                    name: name.to_string(),
+                    path: format!("{}/{}", path, name),
+                    is_dir: true,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_split_path_query() {
+        assert_eq!(split_path_query("/srv/ap"), ("/srv/", "ap"));
+        assert_eq!(split_path_query("/"), ("/", ""));
+        assert_eq!(split_path_query("~/src"), ("~/", "src"));
+        assert_eq!(split_path_query("~"), ("~", ""));
+        assert!(is_path_query("~/src"));
+        assert!(!is_path_query("src"));
+    }
+
+    #[test]
+    fn test_wanted_browse_dir() {
+        let mut state = UnifiedPickerState::new();
+        state.open();
+        // Folder search still loading: no fallback yet
+        assert_eq!(state.wanted_browse_dir(), None);
+
+        state.folders.set_items(vec![]);
+        assert_eq!(state.wanted_browse_dir(), Some("~".to_string()));
+
+        state.set_query("/srv/ap".to_string());
+        assert_eq!(state.wanted_browse_dir(), Some("/srv/".to_string()));
+
+        state.folders.set_items(vec![PickerItem::Folder {
+            name: "app".to_string(),
+            path: "/srv/app".to_string(),
+        }]);
+        state.set_query("app".to_string());
+        assert_eq!(state.wanted_browse_dir(), None);
+    }
+
+    #[test]
+    fn test_directory_listing_filters_hidden_dirs() {
+        let mut state = UnifiedPickerState::new();
+        state.open();
+        state.set_query("/home/me/".to_string());
+        state.start_browse("/home/me/".to_string());
+        state.set_directory_listing(
+            "/home/me/",
+            listing("/home/me", Some("/home"), &["src", ".config", "Scratch"]),
+        );
+
+        let names: Vec<_> = state
+            .folders
+            .items
+            .iter()
+            .map(|i| i.display_name())
+            .collect();
+        assert_eq!(names, vec!["me", "src/", "Scratch/"]);
+        assert_eq!(state.selected_section, PickerSection::Folders);
+
+        state.set_query("/home/me/.c".to_string());
+        let names: Vec<_> = state
+            .folders
+            .items
+            .iter()
+            .map(|i| i.display_name())
+            .collect();
+        assert_eq!(names, vec!["me", ".config/"]);
+    }
+
+    #[test]
+    fn test_directory_listing_ignores_stale_responses() {
+        let mut state = UnifiedPickerState::new();
+        state.start_browse("/b/".to_string());
+        state.set_directory_listing("/a/", listing("/a", Some("/"), &["x"]));
+        assert!(state.browse.as_ref().unwrap().loading);
+        assert!(state.folders.items.is_empty());
+    }
+
+    #[test]
+    fn test_descend_target_and_parent() {
+        let mut state = UnifiedPickerState::new();
+        state.start_browse("~".to_string());
+        state.set_directory_listing("~", listing("/home/me", Some("/home"), &["src"]));
+        state.selected_section = PickerSection::Folders;
+
+        // The listed directory itself is picked, not opened
+        state.selected_index = 0;
+        assert_eq!(state.descend_target(), None);
+        state.selected_index = 1;
+        assert_eq!(state.descend_target(), Some("/home/me/src".to_string()));
+        assert_eq!(state.browse_parent(), Some("/home".to_string()));
+    }
+
+    #[test]
+    fn test_directory_error_keeps_picker_open() {
+        let mut state = UnifiedPickerState::new();
+        state.open();
+        state.start_browse("/root/secret".to_string());
+        state.set_directory_error("/root/secret", "Permission denied".to_string());
+
+        assert!(state.visible);
+        let browse = state.browse.as_ref().unwrap();
+        assert_eq!(browse.error.as_deref(), Some("Permission denied"));
+        assert!(state.shows_section(PickerSection::Folders));
+        // Going up still works without a listing
+        assert_eq!(state.browse_parent(), Some("/root".to_string()));
+
+        state.stop_browse();
+        assert!(!state.is_browsing());
+    }
 }
@@ -10,6 +10,6 @@ mod question_state;
 mod session_state;
 
 // Re-export all public types at module level
-pub use question_data::{AskUserQuestionData, Question, QuestionOption};
+pub use question_data::{AskUserQuestionData, Question, QuestionConstraints, QuestionOption};
 pub use question_state::AskUserQuestionState;
 pub use session_state::{PermissionRequest, SessionState};
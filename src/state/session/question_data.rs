@@ -28,6 +28,108 @@ pub struct Question {
     /// Whether multiple options can be selected
     #[serde(rename = "multiSelect", default)]
     pub multi_select: bool,
+    /// Limits on the answer (selection count, "Other" text)
+    #[serde(flatten)]
+    pub constraints: QuestionConstraints,
+    /// Label of the option the prompt starts on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+impl Question {
+    /// Index of the `default` option, None if unset or not one of the options
+    pub fn default_index(&self) -> Option<usize> {
+        let default = self.default.as_deref()?;
+        self.options.iter().position(|o| o.label == default)
+    }
+}
+
+/// Answer limits of a question, sent alongside it in the tool input.
+///
+/// All fields are optional in the payload; a question without them accepts
+/// any answer, as before.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct QuestionConstraints {
+    /// Fewest answers (selected options, plus "Other" text) to submit
+    #[serde(
+        rename = "minSelections",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min_selections: Option<usize>,
+    /// Most answers (selected options, plus "Other" text) to submit
+    #[serde(
+        rename = "maxSelections",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_selections: Option<usize>,
+    /// Whether a free-text "Other" answer is offered
+    #[serde(rename = "allowOther", default = "default_allow_other")]
+    pub allow_other: bool,
+    /// Maximum length of the "Other" text, in characters
+    #[serde(
+        rename = "otherMaxLength",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub other_max_length: Option<usize>,
+}
+
+fn default_allow_other() -> bool {
+    true
+}
+
+impl Default for QuestionConstraints {
+    fn default() -> Self {
+        Self {
+            min_selections: None,
+            max_selections: None,
+            allow_other: default_allow_other(),
+            other_max_length: None,
+        }
+    }
+}
+
+impl QuestionConstraints {
+    /// Why an answer can't be submitted, None if it meets the limits.
+    ///
+    /// `selected` is the number of options chosen; non-empty `other_text`
+    /// counts as one more answer.
+    pub fn answer_error(&self, selected: usize, other_text: &str) -> Option<String> {
+        if let Some(max) = self.other_max_length {
+            if other_text.chars().count() > max {
+                return Some(format!("Other answer is limited to {} characters", max));
+            }
+        }
+        let count = selected + usize::from(!other_text.is_empty());
+        if let Some(min) = self.min_selections {
+            if count < min {
+                return Some(format!("Select at least {}", plural_options(min)));
+            }
+        }
+        if let Some(max) = self.max_selections {
+            if count > max {
+                return Some(format!("Select at most {}", plural_options(max)));
+            }
+        }
+        None
+    }
+
+    /// Whether one more character fits in `other_text`
+    pub fn other_text_has_room(&self, other_text: &str) -> bool {
+        self.other_max_length
+            .is_none_or(|max| other_text.chars().count() < max)
+    }
+}
+
+/// "1 option", "3 options"
+fn plural_options(count: usize) -> String {
+    if count == 1 {
+        "1 option".to_string()
+    } else {
+        format!("{} options", count)
+    }
 }
 
 /// Data structure for the AskUserQuestion tool
@@ -70,6 +172,8 @@ mod tests {
                 },
             ],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         };
         assert_eq!(question.question, "Which library should we use?");
         assert_eq!(question.header, "Auth method");
@@ -88,6 +192,8 @@ mod tests {
                     description: "Desc A".to_string(),
                 }],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: HashMap::new(),
         };
@@ -128,6 +234,8 @@ mod tests {
             header: "Test".to_string(),
             options: vec![],
             multi_select: true,
+            constraints: Default::default(),
+            default: None,
         };
 
         let json = serde_json::to_string(&question).expect("Failed to serialize");
@@ -248,9 +356,64 @@ mod tests {
                 description: "B".to_string(),
             }],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         };
         let q2 = q1.clone();
 
         assert_eq!(q1, q2);
     }
+
+    #[test]
+    fn test_question_constraints_deserialization() {
+        let json = r#"{
+            "question": "Which files?",
+            "header": "Files",
+            "options": [],
+            "multiSelect": true,
+            "minSelections": 1,
+            "maxSelections": 2,
+            "allowOther": false,
+            "otherMaxLength": 40
+        }"#;
+
+        let q: Question = serde_json::from_str(json).expect("Failed to deserialize");
+        assert_eq!(q.constraints.min_selections, Some(1));
+        assert_eq!(q.constraints.max_selections, Some(2));
+        assert!(!q.constraints.allow_other);
+        assert_eq!(q.constraints.other_max_length, Some(40));
+
+        // Questions without constraints accept anything and offer Other
+        let q: Question =
+            serde_json::from_str(r#"{"question": "Q", "header": "H", "options": []}"#)
+                .expect("Failed to deserialize");
+        assert_eq!(q.constraints, QuestionConstraints::default());
+        assert!(q.constraints.allow_other);
+    }
+
+    #[test]
+    fn test_question_constraints_answer_error() {
+        let constraints = QuestionConstraints {
+            min_selections: Some(1),
+            max_selections: Some(2),
+            other_max_length: Some(5),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            constraints.answer_error(0, ""),
+            Some("Select at least 1 option".to_string())
+        );
+        assert_eq!(constraints.answer_error(1, ""), None);
+        assert_eq!(constraints.answer_error(0, "other"), None);
+        assert_eq!(
+            constraints.answer_error(2, "x"),
+            Some("Select at most 2 options".to_string())
+        );
+        assert!(constraints.answer_error(0, "too long").is_some());
+
+        assert!(constraints.other_text_has_room("four"));
+        assert!(!constraints.other_text_has_room("fives"));
+        assert_eq!(QuestionConstraints::default().answer_error(0, ""), None);
+    }
 }
@@ -2,9 +2,9 @@
 //!
 //! Contains the state machine for the AskUserQuestion prompt UI.
 //! This tracks current tab, selections, "Other" text input, and
-//! multi-select toggle states.
+//! multi-select toggle states, along with each question's answer limits.
 
-use super::AskUserQuestionData;
+use super::{AskUserQuestionData, QuestionConstraints};
 
 /// State for the AskUserQuestion prompt UI
 ///
@@ -27,6 +27,14 @@ pub struct AskUserQuestionState {
     /// When a user confirms an answer on a question, that index is marked true.
     /// All must be true before final submission on multi-question prompts.
     pub answered: Vec<bool>,
+    /// Answer limits per question (defaults when created with `new`)
+    pub constraints: Vec<QuestionConstraints>,
+    /// Why the last confirm was refused, shown until the answer changes
+    pub validation_error: Option<String>,
+    /// Default option per question, from the question's `default` label
+    pub defaults: Vec<Option<usize>>,
+    /// Whether any answer has been changed since the prompt opened
+    pub touched: bool,
 }
 
 impl AskUserQuestionState {
@@ -54,18 +62,30 @@ impl AskUserQuestionState {
                 .map(|&count| vec![false; count])
                 .collect(),
             answered: vec![false; num_questions],
+            constraints: vec![QuestionConstraints::default(); num_questions],
+            validation_error: None,
+            defaults: vec![None; num_questions],
+            touched: false,
         }
     }
 
     /// Create state from AskUserQuestionData
     ///
     /// Convenience constructor that extracts the number of questions and options
-    /// from the data structure.
+    /// from the data structure. Questions with a `default` start on that option.
     pub fn from_data(data: &AskUserQuestionData) -> Self {
         let num_questions = data.questions.len();
         let options_per_question: Vec<usize> =
             data.questions.iter().map(|q| q.options.len()).collect();
-        Self::new(num_questions, &options_per_question)
+        let mut state = Self::new(num_questions, &options_per_question);
+        state.constraints = data.questions.iter().map(|q| q.constraints).collect();
+        state.defaults = data.questions.iter().map(|q| q.default_index()).collect();
+        for (selection, default) in state.selections.iter_mut().zip(&state.defaults) {
+            if default.is_some() {
+                *selection = *default;
+            }
+        }
+        state
     }
 
     /// Reset all state to defaults
@@ -79,6 +99,10 @@ impl AskUserQuestionState {
         self.other_active = false;
         self.multi_selections.clear();
         self.answered.clear();
+        self.constraints.clear();
+        self.validation_error = None;
+        self.defaults.clear();
+        self.touched = false;
     }
 
     /// Get the currently selected option index for the current tab
@@ -105,6 +129,8 @@ impl AskUserQuestionState {
     pub fn set_current_selection(&mut self, selection: Option<usize>) {
         if self.tab_index < self.selections.len() {
             self.selections[self.tab_index] = selection;
+            self.validation_error = None;
+            self.touched = true;
         }
     }
 
@@ -116,10 +142,48 @@ impl AskUserQuestionState {
         if let Some(options) = self.multi_selections.get_mut(self.tab_index) {
             if option_index < options.len() {
                 options[option_index] = !options[option_index];
+                self.validation_error = None;
+                self.touched = true;
             }
         }
     }
 
+    /// Pick option `number` (1-based, as numbered on screen) on the current tab
+    ///
+    /// Moves the cursor there and, for multi-select questions, toggles it.
+    /// Returns false if the question has no such option among the first nine.
+    pub fn select_number(
+        &mut self,
+        number: usize,
+        option_count: usize,
+        multi_select: bool,
+    ) -> bool {
+        if number == 0 || number > option_count.min(9) {
+            return false;
+        }
+        self.set_current_selection(Some(number - 1));
+        if multi_select {
+            self.toggle_multi_selection(number - 1);
+        }
+        true
+    }
+
+    /// Number of answers picked on the current tab: toggled options, plus
+    /// the "Other" text when it isn't empty
+    pub fn selected_count(&self) -> usize {
+        let options = self
+            .multi_selections
+            .get(self.tab_index)
+            .map_or(0, |options| options.iter().filter(|&&on| on).count());
+        options + usize::from(!self.current_other_text().is_empty())
+    }
+
+    /// Whether Enter should submit every question as it stands: nothing has
+    /// been changed yet and the prompt came with defaults
+    pub fn submits_defaults(&self) -> bool {
+        !self.touched && self.defaults.iter().any(Option::is_some)
+    }
+
     /// Check if a multi-select option is selected for the current tab
     ///
     /// # Arguments
@@ -135,9 +199,16 @@ impl AskUserQuestionState {
     }
 
     /// Append a character to the current tab's "Other" text
+    ///
+    /// Ignored once the text has reached the question's `other_max_length`.
     pub fn push_other_char(&mut self, c: char) {
+        let constraints = self.current_constraints();
         if let Some(text) = self.other_texts.get_mut(self.tab_index) {
-            text.push(c);
+            if constraints.other_text_has_room(text) {
+                text.push(c);
+                self.validation_error = None;
+                self.touched = true;
+            }
         }
     }
 
@@ -145,6 +216,40 @@ impl AskUserQuestionState {
     pub fn pop_other_char(&mut self) {
         if let Some(text) = self.other_texts.get_mut(self.tab_index) {
             text.pop();
+            self.validation_error = None;
+            self.touched = true;
+        }
+    }
+
+    /// Answer limits of the current question
+    pub fn current_constraints(&self) -> QuestionConstraints {
+        self.constraints
+            .get(self.tab_index)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Why question `index`'s answer can't be submitted, None if it can
+    ///
+    /// For single-select questions the "Other" text only counts when
+    /// "Other" is the selection.
+    pub fn answer_error(&self, index: usize, multi_select: bool) -> Option<String> {
+        let constraints = self.constraints.get(index).copied().unwrap_or_default();
+        let other_text = self
+            .other_texts
+            .get(index)
+            .map(String::as_str)
+            .unwrap_or("");
+        if multi_select {
+            let selected = self
+                .multi_selections
+                .get(index)
+                .map_or(0, |options| options.iter().filter(|&&on| on).count());
+            constraints.answer_error(selected, other_text)
+        } else if self.selections.get(index).copied().flatten().is_some() {
+            constraints.answer_error(1, "")
+        } else {
+            constraints.answer_error(0, other_text)
         }
     }
 
@@ -155,6 +260,7 @@ impl AskUserQuestionState {
     pub fn next_tab(&mut self, num_questions: usize) {
         if num_questions > 0 {
             self.tab_index = (self.tab_index + 1) % num_questions;
+            self.validation_error = None;
         }
     }
 
@@ -169,6 +275,7 @@ impl AskUserQuestionState {
             } else {
                 self.tab_index - 1
             };
+            self.validation_error = None;
         }
     }
 
@@ -266,6 +373,8 @@ mod tests {
                         },
                     ],
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Q2?".to_string(),
@@ -275,6 +384,8 @@ mod tests {
                         description: "".to_string(),
                     }],
                     multi_select: true,
+                    constraints: Default::default(),
+                    default: None,
                 },
             ],
             answers: HashMap::new(),
@@ -608,4 +719,113 @@ mod tests {
         assert!(cloned.answered[0]);
         assert!(!cloned.answered[1]);
     }
+
+    #[test]
+    fn test_ask_user_question_state_other_max_length() {
+        let mut state = AskUserQuestionState::new(1, &[2]);
+        state.constraints[0].other_max_length = Some(3);
+        state.set_current_selection(None);
+        for c in "abcdef".chars() {
+            state.push_other_char(c);
+        }
+        assert_eq!(state.current_other_text(), "abc");
+
+        state.pop_other_char();
+        state.push_other_char('z');
+        assert_eq!(state.current_other_text(), "abz");
+    }
+
+    #[test]
+    fn test_ask_user_question_state_answer_error() {
+        let mut state = AskUserQuestionState::new(2, &[3, 3]);
+        state.constraints[1] = QuestionConstraints {
+            min_selections: Some(1),
+            max_selections: Some(2),
+            ..Default::default()
+        };
+
+        // Unconstrained single-select question
+        assert_eq!(state.answer_error(0, false), None);
+
+        // Multi-select question with nothing toggled
+        state.tab_index = 1;
+        assert!(state.answer_error(1, true).is_some());
+        state.toggle_multi_selection(0);
+        assert_eq!(state.answer_error(1, true), None);
+        state.toggle_multi_selection(1);
+        state.set_current_selection(None);
+        state.push_other_char('x');
+        assert_eq!(
+            state.answer_error(1, true),
+            Some("Select at most 2 options".to_string())
+        );
+
+        // Changing the answer clears a shown error
+        state.validation_error = state.answer_error(1, true);
+        state.toggle_multi_selection(1);
+        assert!(state.validation_error.is_none());
+    }
+
+    #[test]
+    fn test_ask_user_question_state_from_data_starts_on_default() {
+        let option = |label: &str| QuestionOption {
+            label: label.to_string(),
+            description: "".to_string(),
+        };
+        let data = AskUserQuestionData {
+            questions: vec![
+                Question {
+                    question: "Q1?".to_string(),
+                    header: "Q1".to_string(),
+                    options: vec![option("A"), option("B"), option("C")],
+                    multi_select: false,
+                    constraints: Default::default(),
+                    default: Some("C".to_string()),
+                },
+                Question {
+                    question: "Q2?".to_string(),
+                    header: "Q2".to_string(),
+                    options: vec![option("D"), option("E")],
+                    multi_select: false,
+                    constraints: Default::default(),
+                    default: Some("missing".to_string()),
+                },
+            ],
+            answers: HashMap::new(),
+        };
+
+        let mut state = AskUserQuestionState::from_data(&data);
+
+        assert_eq!(state.selections, vec![Some(2), Some(0)]);
+        assert_eq!(state.defaults, vec![Some(2), None]);
+        assert!(state.submits_defaults());
+
+        // Looking at another tab isn't an answer change
+        state.next_tab(2);
+        assert!(state.submits_defaults());
+        state.set_current_selection(Some(1));
+        assert!(!state.submits_defaults());
+    }
+
+    #[test]
+    fn test_ask_user_question_state_select_number() {
+        let mut state = AskUserQuestionState::new(2, &[3, 3]);
+
+        assert!(state.select_number(3, 3, false));
+        assert_eq!(state.current_selection(), Some(2));
+        assert!(state.touched);
+        assert!(!state.select_number(4, 3, false));
+        assert!(!state.select_number(0, 3, false));
+        assert_eq!(state.current_selection(), Some(2));
+
+        // Multi-select digits toggle the option
+        state.tab_index = 1;
+        assert!(state.select_number(1, 3, true));
+        assert!(state.select_number(3, 3, true));
+        assert_eq!(state.current_selection(), Some(2));
+        assert_eq!(state.selected_count(), 2);
+        assert!(state.select_number(1, 3, true));
+        assert!(!state.is_multi_selected(0));
+        assert_eq!(state.selected_count(), 1);
+    }
 }
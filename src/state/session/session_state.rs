@@ -56,6 +56,14 @@ pub struct SessionState {
     /// Context token limit (max capacity)
     pub context_token_limit: Option<u32>,
 
+    /// When the backend last compacted the context
+    #[serde(default)]
+    pub last_compaction_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Whether the "context almost full" hint was already shown
+    #[serde(default)]
+    pub context_hint_shown: bool,
+
     /// OAuth requirement: (provider, skill_name)
     /// Set when a skill requires OAuth authentication
     pub oauth_required: Option<(String, String)>,
@@ -107,6 +115,21 @@ impl SessionState {
         self.context_token_limit = Some(limit);
     }
 
+    /// Record a context compaction
+    pub fn record_compaction(&mut self, at: chrono::DateTime<chrono::Utc>) {
+        self.last_compaction_at = Some(at);
+    }
+
+    /// Percentage of the context window in use, if both numbers are known
+    pub fn context_usage_percent(&self) -> Option<u32> {
+        match (self.context_tokens_used, self.context_token_limit) {
+            (Some(used), Some(limit)) if limit > 0 => {
+                Some((used as f64 / limit as f64 * 100.0).round() as u32)
+            }
+            _ => None,
+        }
+    }
+
     /// Set OAuth consent URL
     pub fn set_oauth_url(&mut self, url: String) {
         self.oauth_url = Some(url);
@@ -157,6 +180,8 @@ impl SessionState {
         self.skills.clear();
         self.context_tokens_used = None;
         self.context_token_limit = None;
+        self.last_compaction_at = None;
+        self.context_hint_shown = false;
         self.oauth_required = None;
         self.oauth_url = None;
         self.allowed_tools.clear();
@@ -185,6 +210,18 @@ mod tests {
         assert!(state.allowed_tools.is_empty());
     }
 
+    #[test]
+    fn test_context_usage_percent_needs_both_values() {
+        let mut state = SessionState::new();
+        assert_eq!(state.context_usage_percent(), None);
+        state.set_context_tokens(45_000);
+        assert_eq!(state.context_usage_percent(), None);
+        state.set_context_token_limit(0);
+        assert_eq!(state.context_usage_percent(), None);
+        state.set_context_token_limit(200_000);
+        assert_eq!(state.context_usage_percent(), Some(23));
+    }
+
     #[test]
     fn test_add_skill() {
         let mut state = SessionState::new();
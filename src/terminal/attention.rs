@@ -0,0 +1,74 @@
+//! Terminal attention requests.
+//!
+//! A bell (BEL) makes most terminals raise the window's urgency hint when it
+//! is in the background (taskbar flash, dock bounce, tmux window flag).
+//! Terminals that understand OSC 777 also show a desktop notification; the
+//! rest ignore the sequence.
+
+use std::io::{self, Write};
+
+/// Build the OSC 777 notification followed by a bell.
+///
+/// Inside tmux the OSC is wrapped in a DCS passthrough so it reaches the outer
+/// terminal; the bell is left bare because tmux forwards bells itself.
+pub fn attention_sequence(title: &str, body: &str, in_tmux: bool) -> String {
+    let notify = format!(
+        "\x1b]777;notify;{};{}\x07",
+        sanitize_field(title),
+        sanitize_field(body)
+    );
+    if in_tmux {
+        format!("\x1bPtmux;\x1b{}\x1b\\\x07", notify)
+    } else {
+        format!("{}\x07", notify)
+    }
+}
+
+/// Ask the terminal for the user's attention.
+///
+/// # Arguments
+///
+/// * `writer` - The output writer (typically stdout)
+/// * `title` - Notification title (OSC 777 terminals)
+/// * `body` - Notification body (OSC 777 terminals)
+pub fn write_attention<W: Write>(writer: &mut W, title: &str, body: &str) -> io::Result<()> {
+    let in_tmux = std::env::var_os("TMUX").is_some();
+    writer.write_all(attention_sequence(title, body, in_tmux).as_bytes())?;
+    writer.flush()
+}
+
+/// Drop characters that would end the field or the escape sequence early
+fn sanitize_field(text: &str) -> String {
+    text.chars()
+        .filter(|c| *c != ';' && !c.is_control())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attention_sequence() {
+        assert_eq!(
+            attention_sequence("spoq", "Response finished", false),
+            "\x1b]777;notify;spoq;Response finished\x07\x07"
+        );
+    }
+
+    #[test]
+    fn test_attention_sequence_tmux_passthrough() {
+        assert_eq!(
+            attention_sequence("spoq", "done", true),
+            "\x1bPtmux;\x1b\x1b]777;notify;spoq;done\x07\x1b\\\x07"
+        );
+    }
+
+    #[test]
+    fn test_attention_sequence_strips_separators_and_controls() {
+        assert_eq!(
+            attention_sequence("spoq", "a;b\x07c\x1b]d", false),
+            "\x1b]777;notify;spoq;abc]d\x07\x07"
+        );
+    }
+}
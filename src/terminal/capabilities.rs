@@ -0,0 +1,338 @@
+//! Terminal capability detection.
+//!
+//! Detects which optional terminal features are safe to use so the app can
+//! downgrade up front instead of emitting sequences the terminal ignores or
+//! mis-renders. Detection is mostly environment-based (`TERM`, `TERM_PROGRAM`,
+//! `COLORTERM`); kitty keyboard support and synchronized output (DECRQM for
+//! mode 2026) are confirmed with live queries.
+//!
+//! Results are saved to `~/.spoq/config.json` by `spoq setup`, so the probe
+//! only runs once per machine. `SPOQ_NO_SYNC=1` turns synchronized output off
+//! on every launch, whatever the probe found.
+
+use std::io::{Read, Write};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, is_raw_mode_enabled};
+use serde::{Deserialize, Serialize};
+
+/// Environment variable that turns synchronized output off (`SPOQ_NO_SYNC=1`)
+pub const NO_SYNC_ENV_VAR: &str = "SPOQ_NO_SYNC";
+
+/// DECRQM for mode 2026, then a primary device attributes request. Every
+/// terminal answers the latter, so its reply marks the end of the answers.
+const SYNC_OUTPUT_QUERY: &[u8] = b"\x1b[?2026$p\x1b[c";
+
+/// How long to wait for the terminal to answer the synchronized output query
+const QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Optional terminal features the app can use.
+///
+/// Every field defaults to `true` so configs written before the probe existed
+/// (and unprobed terminals) keep the previous behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TerminalCapabilities {
+    /// 24-bit RGB colors (otherwise colors are mapped to the 256-color palette)
+    pub truecolor: bool,
+    /// Kitty keyboard protocol (Shift+Enter, Ctrl+Enter, ...)
+    pub kitty_keyboard: bool,
+    /// Synchronized output (DEC mode 2026)
+    pub synchronized_output: bool,
+    /// Clipboard writes via OSC 52
+    pub osc52: bool,
+    /// Clickable OSC 8 hyperlinks
+    pub hyperlinks: bool,
+    /// Mouse reporting (scroll wheel, clicks)
+    pub mouse: bool,
+}
+
+impl Default for TerminalCapabilities {
+    fn default() -> Self {
+        Self {
+            truecolor: true,
+            kitty_keyboard: true,
+            synchronized_output: true,
+            osc52: true,
+            hyperlinks: true,
+            mouse: true,
+        }
+    }
+}
+
+/// Terminals (by `TERM_PROGRAM`) known to support synchronized output
+const SYNC_OUTPUT_PROGRAMS: &[&str] = &[
+    "iterm.app",
+    "wezterm",
+    "ghostty",
+    "vscode",
+    "contour",
+    "tmux",
+];
+
+/// `TERM` fragments for terminals known to support synchronized output
+const SYNC_OUTPUT_TERMS: &[&str] = &[
+    "kitty",
+    "ghostty",
+    "foot",
+    "alacritty",
+    "wezterm",
+    "contour",
+];
+
+/// Terminals (by `TERM_PROGRAM`) known to render 24-bit color
+const TRUECOLOR_PROGRAMS: &[&str] = &["iterm.app", "wezterm", "ghostty", "vscode", "hyper"];
+
+impl TerminalCapabilities {
+    /// Probe the current terminal.
+    ///
+    /// Uses environment heuristics, then asks the terminal directly whether
+    /// it supports the kitty keyboard protocol. The query briefly enables raw
+    /// mode, so call this before the TUI starts.
+    pub fn probe() -> Self {
+        let mut capabilities = Self::detect_from_env(|name| std::env::var(name).ok());
+        if let Ok(supported) = crossterm::terminal::supports_keyboard_enhancement() {
+            capabilities.kitty_keyboard = supported;
+        }
+        if let Some(supported) = query_synchronized_output() {
+            capabilities.synchronized_output = supported;
+        }
+        capabilities
+    }
+
+    /// Apply environment overrides to probed or saved capabilities.
+    ///
+    /// `SPOQ_NO_SYNC` (any value but `0`) turns synchronized output off, for
+    /// terminals that print its escapes literally.
+    pub fn with_env_overrides(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        if var(NO_SYNC_ENV_VAR).is_some_and(|v| !v.is_empty() && v != "0") {
+            self.synchronized_output = false;
+        }
+        self
+    }
+
+    /// Detect capabilities from environment variables.
+    ///
+    /// `var` looks up an environment variable; it is injected for testing.
+    pub fn detect_from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        let term = var("TERM").unwrap_or_default().to_lowercase();
+        let program = var("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+        let colorterm = var("COLORTERM").unwrap_or_default().to_lowercase();
+
+        // Dumb terminals and the Linux console support none of the extras
+        if term.is_empty() || term == "dumb" || term == "linux" {
+            return Self {
+                truecolor: false,
+                kitty_keyboard: false,
+                synchronized_output: false,
+                osc52: false,
+                hyperlinks: false,
+                mouse: term == "linux",
+            };
+        }
+
+        let apple_terminal = program == "apple_terminal";
+        let term_matches = |list: &[&str]| list.iter().any(|t| term.contains(t));
+        let program_matches = |list: &[&str]| list.iter().any(|p| program == *p);
+
+        let truecolor = !apple_terminal
+            && (colorterm == "truecolor"
+                || colorterm == "24bit"
+                || term.contains("truecolor")
+                || term.contains("direct")
+                || term.contains("kitty")
+                || program_matches(TRUECOLOR_PROGRAMS));
+
+        let kitty_keyboard = term.contains("kitty")
+            || term.contains("ghostty")
+            || term.contains("foot")
+            || program_matches(&["wezterm", "ghostty"]);
+
+        let synchronized_output =
+            program_matches(SYNC_OUTPUT_PROGRAMS) || term_matches(SYNC_OUTPUT_TERMS);
+
+        Self {
+            truecolor,
+            kitty_keyboard,
+            synchronized_output,
+            // Terminal.app ignores OSC 52 and prints OSC 8 targets literally on old versions
+            osc52: !apple_terminal,
+            hyperlinks: !apple_terminal && !term.starts_with("screen"),
+            mouse: true,
+        }
+    }
+
+    /// Human-readable probe results, one `(feature, supported)` pair per capability
+    pub fn report(&self) -> [(&'static str, bool); 6] {
+        [
+            ("Truecolor (24-bit)", self.truecolor),
+            ("Kitty keyboard protocol", self.kitty_keyboard),
+            ("Synchronized output", self.synchronized_output),
+            ("OSC 52 clipboard", self.osc52),
+            ("OSC 8 hyperlinks", self.hyperlinks),
+            ("Mouse support", self.mouse),
+        ]
+    }
+}
+
+/// Ask the terminal whether it supports synchronized output (DECRQM 2026).
+///
+/// Returns None when the terminal can't be reached or doesn't answer in time.
+/// Briefly enables raw mode unless it is already on.
+fn query_synchronized_output() -> Option<bool> {
+    let was_raw = is_raw_mode_enabled().unwrap_or(false);
+    if !was_raw {
+        enable_raw_mode().ok()?;
+    }
+
+    let reply = read_sync_output_reply();
+
+    if !was_raw {
+        let _ = disable_raw_mode();
+    }
+    reply.map(|reply| parse_sync_output_reply(&reply))
+}
+
+/// Send the synchronized output query to the terminal and read its answers
+fn read_sync_output_reply() -> Option<Vec<u8>> {
+    let mut tty = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    tty.write_all(SYNC_OUTPUT_QUERY).ok()?;
+    tty.flush().ok()?;
+
+    // Read on a thread so a silent terminal can't block startup
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut reply = Vec::new();
+        let mut byte = [0u8; 1];
+        while !ends_with_device_attributes(&reply) {
+            match tty.read(&mut byte) {
+                Ok(1) => reply.push(byte[0]),
+                _ => break,
+            }
+        }
+        let _ = tx.send(reply);
+    });
+    rx.recv_timeout(QUERY_TIMEOUT).ok()
+}
+
+/// Whether `reply` ends with a primary device attributes reply (`ESC [ ? ... c`)
+fn ends_with_device_attributes(reply: &[u8]) -> bool {
+    reply.last() == Some(&b'c')
+        && reply
+            .iter()
+            .rposition(|b| *b == 0x1b)
+            .is_some_and(|esc| reply[esc..].starts_with(b"\x1b[?"))
+}
+
+/// Whether the terminal's answers report mode 2026 as supported.
+///
+/// The DECRPM reply is `ESC [ ? 2026 ; Ps $ y`, where Ps is 1 (set), 2
+/// (reset) or 3 (permanently set) for a supported mode. No reply, 0 (not
+/// recognized) or 4 (permanently reset) mean it can't be used.
+fn parse_sync_output_reply(reply: &[u8]) -> bool {
+    const PREFIX: &[u8] = b"\x1b[?2026;";
+    reply
+        .windows(PREFIX.len() + 3)
+        .find(|w| w.starts_with(PREFIX))
+        .is_some_and(|w| matches!(w[PREFIX.len()], b'1'..=b'3') && w.ends_with(b"$y"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn detect(vars: &[(&str, &str)]) -> TerminalCapabilities {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        TerminalCapabilities::detect_from_env(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_detect_kitty() {
+        let caps = detect(&[("TERM", "xterm-kitty")]);
+        assert!(caps.truecolor);
+        assert!(caps.kitty_keyboard);
+        assert!(caps.synchronized_output);
+        assert!(caps.osc52);
+        assert!(caps.hyperlinks);
+        assert!(caps.mouse);
+    }
+
+    #[test]
+    fn test_detect_apple_terminal_downgrades() {
+        let caps = detect(&[
+            ("TERM", "xterm-256color"),
+            ("TERM_PROGRAM", "Apple_Terminal"),
+        ]);
+        assert!(!caps.truecolor);
+        assert!(!caps.kitty_keyboard);
+        assert!(!caps.synchronized_output);
+        assert!(!caps.osc52);
+        assert!(!caps.hyperlinks);
+        assert!(caps.mouse);
+    }
+
+    #[test]
+    fn test_detect_colorterm_enables_truecolor() {
+        let caps = detect(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]);
+        assert!(caps.truecolor);
+        assert!(!caps.synchronized_output);
+    }
+
+    #[test]
+    fn test_detect_dumb_terminal() {
+        let caps = detect(&[("TERM", "dumb")]);
+        assert_eq!(
+            caps.report()
+                .iter()
+                .filter(|(_, supported)| *supported)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_parse_sync_output_reply() {
+        assert!(parse_sync_output_reply(b"\x1b[?2026;2$y\x1b[?62;22c"));
+        assert!(parse_sync_output_reply(b"\x1b[?2026;1$y\x1b[?1;2c"));
+        // Not recognized, permanently reset, or no DECRPM reply at all
+        assert!(!parse_sync_output_reply(b"\x1b[?2026;0$y\x1b[?62c"));
+        assert!(!parse_sync_output_reply(b"\x1b[?2026;4$y\x1b[?62c"));
+        assert!(!parse_sync_output_reply(b"\x1b[?62;22c"));
+    }
+
+    #[test]
+    fn test_device_attributes_end_the_reply() {
+        assert!(!ends_with_device_attributes(b"\x1b[?2026;2$y"));
+        assert!(ends_with_device_attributes(b"\x1b[?2026;2$y\x1b[?62;22c"));
+        assert!(!ends_with_device_attributes(b"abc"));
+    }
+
+    #[test]
+    fn test_no_sync_env_override() {
+        let caps = detect(&[("TERM", "xterm-kitty")]);
+        let override_with = |value: &str| {
+            let value = value.to_string();
+            caps.with_env_overrides(|name| (name == NO_SYNC_ENV_VAR).then(|| value.clone()))
+        };
+        assert!(!override_with("1").synchronized_output);
+        assert!(override_with("0").synchronized_output);
+        assert!(caps.with_env_overrides(|_| None).synchronized_output);
+    }
+
+    #[test]
+    fn test_missing_fields_default_to_supported() {
+        let caps: TerminalCapabilities = serde_json::from_str(r#"{"mouse": false}"#).unwrap();
+        assert!(!caps.mouse);
+        assert!(caps.truecolor);
+        assert!(caps.hyperlinks);
+    }
+}
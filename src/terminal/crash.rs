@@ -0,0 +1,168 @@
+//! Crash snapshots written by the panic hook.
+//!
+//! The main loop records what a panic would lose after each frame: the active
+//! thread, the draft in the input, the partial response streaming in and any
+//! pending permission or question. The panic hook writes the last record to
+//! `~/.local/share/spoq/crash/<timestamp>.json` and runs the recorded
+//! disconnect (cancelling the stream on the backend) before the process goes
+//! down. On the next start the preflight offers to restore the draft and
+//! reopen the thread (see `startup::crash`).
+//!
+//! The hook only `try_lock`s the record, so a panic while the main loop holds
+//! the lock skips the snapshot instead of deadlocking, and a panic inside the
+//! hook itself doesn't run it again.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, TryLockError};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::state::PermissionRequest;
+
+/// What a panic would lose, restored on the next start
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CrashSnapshot {
+    /// Thread open in the Conversation screen
+    #[serde(default)]
+    pub thread_id: Option<String>,
+    /// Text in the input
+    #[serde(default)]
+    pub draft: String,
+    /// Response of the active thread received so far, if it was streaming
+    #[serde(default)]
+    pub partial_content: Option<String>,
+    /// Permission (or question) the active thread was waiting on
+    #[serde(default)]
+    pub pending_permission: Option<PermissionRequest>,
+    /// Text typed into the "Other" answers of a pending question
+    #[serde(default)]
+    pub question_other_texts: Vec<String>,
+    #[serde(default = "Utc::now")]
+    pub crashed_at: DateTime<Utc>,
+}
+
+impl CrashSnapshot {
+    /// Whether there's anything worth writing
+    pub fn is_empty(&self) -> bool {
+        self.draft.trim().is_empty()
+            && self.partial_content.is_none()
+            && self.pending_permission.is_none()
+    }
+
+    /// Directory of the snapshots (`~/.local/share/spoq/crash`)
+    pub fn dir() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".local").join("share").join("spoq").join("crash"))
+    }
+
+    /// Load a snapshot; invalid files yield None
+    pub fn load_from(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Write the snapshot into `dir`, named after the time of the crash
+    pub fn save_in(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!(
+            "{}.json",
+            self.crashed_at.format("%Y%m%dT%H%M%S%.3fZ")
+        ));
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(&path, json)?;
+        Ok(path)
+    }
+}
+
+/// Best-effort disconnect run by the panic hook (e.g., cancel the stream)
+pub type CrashDisconnect = Box<dyn Fn(&CrashSnapshot) + Send>;
+
+/// Last recorded state, read by the panic hook
+struct CrashRecord {
+    snapshot: Option<CrashSnapshot>,
+    disconnect: Option<CrashDisconnect>,
+}
+
+static CRASH_RECORD: Mutex<CrashRecord> = Mutex::new(CrashRecord {
+    snapshot: None,
+    disconnect: None,
+});
+
+/// Set once the panic hook has handled a crash
+static CRASH_HANDLED: AtomicBool = AtomicBool::new(false);
+
+/// Record what the panic hook should save and how to disconnect.
+///
+/// Empty snapshots are dropped, so nothing is written for a crash with nothing
+/// in flight.
+pub fn record_crash_state(snapshot: CrashSnapshot, disconnect: Option<CrashDisconnect>) {
+    let snapshot = (!snapshot.is_empty()).then_some(snapshot);
+    let mut record = match CRASH_RECORD.lock() {
+        Ok(record) => record,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    record.snapshot = snapshot;
+    record.disconnect = disconnect;
+}
+
+/// Save the recorded snapshot and run the recorded disconnect. Called from
+/// the panic hook.
+///
+/// Returns the path of the snapshot written, if any.
+pub(super) fn write_crash_snapshot() -> Option<PathBuf> {
+    if CRASH_HANDLED.swap(true, Ordering::SeqCst) {
+        return None;
+    }
+    let record = match CRASH_RECORD.try_lock() {
+        Ok(record) => record,
+        Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+        // Held by the panicking thread: skip rather than deadlock
+        Err(TryLockError::WouldBlock) => return None,
+    };
+    let mut snapshot = record.snapshot.clone()?;
+    snapshot.crashed_at = Utc::now();
+
+    let path = CrashSnapshot::dir().and_then(|dir| snapshot.save_in(&dir).ok());
+    if let Some(disconnect) = &record.disconnect {
+        disconnect(&snapshot);
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> CrashSnapshot {
+        CrashSnapshot {
+            thread_id: Some("thread-1".to_string()),
+            draft: "half-typed follow-up".to_string(),
+            partial_content: Some("The answer so".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_snapshot_round_trips_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = snapshot().save_in(dir.path()).unwrap();
+
+        assert!(path.extension().is_some_and(|ext| ext == "json"));
+        let loaded = CrashSnapshot::load_from(&path).unwrap();
+        assert_eq!(loaded.thread_id.as_deref(), Some("thread-1"));
+        assert_eq!(loaded.draft, "half-typed follow-up");
+        assert_eq!(loaded.partial_content.as_deref(), Some("The answer so"));
+    }
+
+    #[test]
+    fn test_empty_snapshot_has_nothing_to_restore() {
+        let empty = CrashSnapshot {
+            thread_id: Some("thread-1".to_string()),
+            draft: "  ".to_string(),
+            ..Default::default()
+        };
+        assert!(empty.is_empty());
+        assert!(!snapshot().is_empty());
+    }
+}
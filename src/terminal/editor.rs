@@ -0,0 +1,100 @@
+//! Handing the terminal to an external editor.
+//!
+//! The TUI is suspended (raw mode off, main screen restored) while the editor
+//! runs and set up again afterwards, the same way `TerminalManager` sets it up
+//! at startup.
+
+use crossterm::terminal::enable_raw_mode;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, ExitStatus};
+
+use super::capabilities::TerminalCapabilities;
+use super::enhancements::{pop_keyboard_enhancements, push_keyboard_enhancements};
+use super::setup::{enter_tui_mode_with, leave_tui_mode};
+
+/// Editor used when neither `$VISUAL` nor `$EDITOR` is set
+const FALLBACK_EDITOR: &str = "vi";
+
+/// The editor command line, split into program and arguments.
+///
+/// Uses `$VISUAL`, then `$EDITOR`, then `vi`. Values like `code --wait` are
+/// split on whitespace. `var` looks up an environment variable; it is
+/// injected for testing.
+pub fn editor_command(var: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|name| var(name))
+        .map(|value| {
+            value
+                .split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .find(|parts| !parts.is_empty())
+        .unwrap_or_else(|| vec![FALLBACK_EDITOR.to_string()])
+}
+
+/// Suspend the TUI, open `path` in the user's editor and wait for it to exit.
+///
+/// The TUI is restored whether or not the editor could be started; the
+/// caller should clear the terminal so the next frame is drawn in full.
+///
+/// # Errors
+///
+/// Returns an error if the editor could not be started or the terminal
+/// could not be set up again.
+pub fn run_editor<W: Write>(
+    writer: &mut W,
+    path: &Path,
+    capabilities: &TerminalCapabilities,
+) -> io::Result<ExitStatus> {
+    if capabilities.kitty_keyboard {
+        pop_keyboard_enhancements(writer);
+    }
+    leave_tui_mode(writer);
+
+    let command = editor_command(|name| std::env::var(name).ok());
+    let status = Command::new(&command[0])
+        .args(&command[1..])
+        .arg(path)
+        .status();
+
+    enable_raw_mode()?;
+    enter_tui_mode_with(writer, capabilities)?;
+    if capabilities.kitty_keyboard {
+        push_keyboard_enhancements(writer);
+    }
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[test]
+    fn test_editor_command_prefers_visual() {
+        assert_eq!(
+            editor_command(env(&[("VISUAL", "code --wait"), ("EDITOR", "nano")])),
+            vec!["code", "--wait"]
+        );
+        assert_eq!(editor_command(env(&[("EDITOR", "nano")])), vec!["nano"]);
+    }
+
+    #[test]
+    fn test_editor_command_falls_back_to_vi() {
+        assert_eq!(editor_command(env(&[])), vec!["vi"]);
+        assert_eq!(
+            editor_command(env(&[("VISUAL", "  "), ("EDITOR", "hx")])),
+            vec!["hx"]
+        );
+    }
+}
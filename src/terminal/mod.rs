@@ -23,13 +23,23 @@
 //! }
 //! ```
 
+mod attention;
+mod capabilities;
+mod crash;
+mod editor;
 mod enhancements;
+mod osc52;
 mod panic;
 mod setup;
 
+pub use attention::{attention_sequence, write_attention};
+pub use capabilities::TerminalCapabilities;
+pub use crash::{record_crash_state, CrashDisconnect, CrashSnapshot};
+pub use editor::{editor_command, run_editor};
 pub use enhancements::{enable_keyboard_enhancements, push_keyboard_enhancements};
+pub use osc52::{osc52_sequence, write_osc52_clipboard};
 pub use panic::setup_panic_hook;
-pub use setup::{enter_tui_mode, leave_tui_mode};
+pub use setup::{enter_tui_mode, enter_tui_mode_with, leave_tui_mode};
 
 use color_eyre::Result;
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
@@ -114,17 +124,29 @@ impl TerminalManager {
     ///
     /// Returns an error if terminal setup fails.
     pub fn new() -> Result<Self> {
+        Self::with_capabilities(&TerminalCapabilities::default())
+    }
+
+    /// Create a terminal manager that only enables features the terminal
+    /// supports (mouse capture, keyboard enhancements).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if terminal setup fails.
+    pub fn with_capabilities(capabilities: &TerminalCapabilities) -> Result<Self> {
         // Enable raw mode first
         enable_raw_mode()?;
 
         let mut stdout = io::stdout();
 
         // Enter TUI mode (alternate screen, bracketed paste, mouse capture)
-        enter_tui_mode(&mut stdout)?;
+        enter_tui_mode_with(&mut stdout, capabilities)?;
 
         // Enable keyboard enhancements (Kitty protocol)
         // Silently fails on unsupported terminals
-        push_keyboard_enhancements(&mut stdout);
+        if capabilities.kitty_keyboard {
+            push_keyboard_enhancements(&mut stdout);
+        }
 
         // Create the terminal
         let backend = CrosstermBackend::new(stdout);
@@ -152,6 +174,14 @@ impl TerminalManager {
         Ok(self.terminal.size()?.into())
     }
 
+    /// Copy text to the clipboard via OSC 52.
+    ///
+    /// Used as a fallback when the system clipboard is unavailable.
+    pub fn copy_to_clipboard_osc52(&mut self, text: &str) -> Result<()> {
+        write_osc52_clipboard(self.terminal.backend_mut(), text)?;
+        Ok(())
+    }
+
     /// Manually restore the terminal.
     ///
     /// This is called automatically on drop, but can be called manually
@@ -0,0 +1,59 @@
+//! OSC 52 clipboard escape sequences.
+//!
+//! OSC 52 asks the terminal emulator itself to set the clipboard, which works
+//! over SSH and in headless sessions where no system clipboard is reachable.
+//! Support varies by terminal; unsupported terminals ignore the sequence.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use std::io::{self, Write};
+
+/// Build the OSC 52 sequence that sets the clipboard to `text`.
+///
+/// Inside tmux the sequence is wrapped in a DCS passthrough so it reaches the
+/// outer terminal.
+pub fn osc52_sequence(text: &str, in_tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", BASE64.encode(text));
+    if in_tmux {
+        format!("\x1bPtmux;\x1b{}\x1b\\", sequence)
+    } else {
+        sequence
+    }
+}
+
+/// Write text to the clipboard via OSC 52.
+///
+/// # Arguments
+///
+/// * `writer` - The output writer (typically stdout)
+/// * `text` - The text to copy
+pub fn write_osc52_clipboard<W: Write>(writer: &mut W, text: &str) -> io::Result<()> {
+    let in_tmux = std::env::var_os("TMUX").is_some();
+    writer.write_all(osc52_sequence(text, in_tmux).as_bytes())?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(osc52_sequence("hi", false), "\x1b]52;c;aGk=\x07");
+    }
+
+    #[test]
+    fn test_osc52_sequence_tmux_passthrough() {
+        assert_eq!(
+            osc52_sequence("hi", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_write_osc52_clipboard() {
+        let mut out = Vec::new();
+        write_osc52_clipboard(&mut out, "hi").unwrap();
+        let written = String::from_utf8(out).unwrap();
+        assert!(written.contains("]52;c;aGk="));
+    }
+}
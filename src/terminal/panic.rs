@@ -2,8 +2,10 @@
 //!
 //! This module provides a panic hook that restores the terminal to a usable
 //! state when the application panics. This ensures the user's terminal is
-//! not left in an unusable state.
+//! not left in an unusable state. It also saves a crash snapshot of the
+//! in-flight stream (see `crash`).
 
+use super::crash::write_crash_snapshot;
 use super::setup::emergency_restore;
 use std::panic;
 
@@ -12,7 +14,8 @@ use std::panic;
 /// This should be called early in main(), before creating the `TerminalManager`.
 /// The panic hook will:
 /// 1. Restore the terminal to a usable state
-/// 2. Call the original panic hook (to print the panic message)
+/// 2. Write the crash snapshot and cancel the stream on the backend
+/// 3. Call the original panic hook (to print the panic message)
 ///
 /// # Example
 ///
@@ -31,8 +34,17 @@ pub fn setup_panic_hook() {
         // Restore terminal state first
         emergency_restore();
 
+        let snapshot_path = write_crash_snapshot();
+
         // Then call the original panic hook to display the panic message
         original_hook(panic_info);
+
+        if let Some(path) = snapshot_path {
+            eprintln!(
+                "Saved the unsent draft and partial response to {}",
+                path.display()
+            );
+        }
     }));
 }
 
@@ -14,6 +14,8 @@ use crossterm::{
 };
 use std::io::{self, Write};
 
+use super::capabilities::TerminalCapabilities;
+
 /// Enter TUI mode.
 ///
 /// This sets up the terminal for TUI operation:
@@ -29,13 +31,23 @@ use std::io::{self, Write};
 ///
 /// Returns an error if any terminal commands fail.
 pub fn enter_tui_mode<W: Write>(writer: &mut W) -> io::Result<()> {
-    execute!(
-        writer,
-        EnterAlternateScreen,
-        EnableBracketedPaste,
-        EnableMouseCapture,
-        EnableFocusChange
-    )
+    enter_tui_mode_with(writer, &TerminalCapabilities::default())
+}
+
+/// Enter TUI mode, skipping mouse capture if the terminal lacks mouse support.
+///
+/// # Errors
+///
+/// Returns an error if any terminal commands fail.
+pub fn enter_tui_mode_with<W: Write>(
+    writer: &mut W,
+    capabilities: &TerminalCapabilities,
+) -> io::Result<()> {
+    execute!(writer, EnterAlternateScreen, EnableBracketedPaste)?;
+    if capabilities.mouse {
+        execute!(writer, EnableMouseCapture)?;
+    }
+    execute!(writer, EnableFocusChange)
 }
 
 /// Leave TUI mode and restore terminal to normal state.
@@ -106,6 +118,22 @@ mod tests {
         // We don't verify the exact content since it depends on terminal state
     }
 
+    #[test]
+    fn test_enter_tui_mode_without_mouse_skips_capture() {
+        let mut with_mouse = Vec::new();
+        enter_tui_mode(&mut with_mouse).unwrap();
+        let mut without_mouse = Vec::new();
+        let capabilities = TerminalCapabilities {
+            mouse: false,
+            ..Default::default()
+        };
+        enter_tui_mode_with(&mut without_mouse, &capabilities).unwrap();
+
+        // Mouse capture enables SGR mouse reporting (mode 1006)
+        assert!(String::from_utf8_lossy(&with_mouse).contains("?1006h"));
+        assert!(!String::from_utf8_lossy(&without_mouse).contains("?1006h"));
+    }
+
     #[test]
     fn test_emergency_restore_does_not_panic() {
         // This test verifies that emergency_restore doesn't panic
@@ -0,0 +1,165 @@
+//! Scripted delivery of app messages.
+//!
+//! [`ScriptedEventFeed`] is a timeline of [`AppMessage`]s, the events the
+//! SSE stream and WebSocket tasks would otherwise produce, each sent after an
+//! optional delay on the app's own message channel. [`pump_until`] applies
+//! received messages to the app the way the main loop does.
+
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::app::{App, AppMessage};
+
+/// A timeline of messages to send to an app
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedEventFeed {
+    /// Messages with the delay before each
+    steps: Vec<(Duration, AppMessage)>,
+}
+
+impl ScriptedEventFeed {
+    /// Create an empty feed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send a message right after the previous one
+    pub fn then(self, message: AppMessage) -> Self {
+        self.after(Duration::ZERO, message)
+    }
+
+    /// Send a message `delay` after the previous one
+    pub fn after(mut self, delay: Duration, message: AppMessage) -> Self {
+        self.steps.push((delay, message));
+        self
+    }
+
+    /// A streamed token for a thread
+    pub fn stream_token(self, thread_id: &str, token: &str) -> Self {
+        self.then(AppMessage::StreamToken {
+            thread_id: thread_id.to_string(),
+            token: token.to_string(),
+        })
+    }
+
+    /// A permission prompt for a thread's tool call
+    pub fn permission_requested(
+        self,
+        permission_id: &str,
+        thread_id: &str,
+        tool_name: &str,
+        description: &str,
+    ) -> Self {
+        self.then(AppMessage::PermissionRequested {
+            permission_id: permission_id.to_string(),
+            thread_id: Some(thread_id.to_string()),
+            tool_name: tool_name.to_string(),
+            description: description.to_string(),
+            tool_input: None,
+        })
+    }
+
+    /// A subagent starting work
+    pub fn subagent_started(self, task_id: &str, description: &str, subagent_type: &str) -> Self {
+        self.then(AppMessage::SubagentStarted {
+            task_id: task_id.to_string(),
+            description: description.to_string(),
+            subagent_type: subagent_type.to_string(),
+        })
+    }
+
+    /// The end of a thread's response, saved as `message_id`
+    pub fn stream_complete(self, thread_id: &str, message_id: i64) -> Self {
+        self.then(AppMessage::StreamComplete {
+            thread_id: thread_id.to_string(),
+            message_id,
+        })
+    }
+
+    /// Number of messages in the feed
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether the feed has no messages
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Send the timeline on `tx` from a background task.
+    ///
+    /// Use [`App::message_sender`] for the normal message channel. The task
+    /// stops early if the channel closes.
+    pub fn play(self, tx: mpsc::UnboundedSender<AppMessage>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            for (delay, message) in self.steps {
+                if !delay.is_zero() {
+                    tokio::time::sleep(delay).await;
+                }
+                if tx.send(message).is_err() {
+                    return;
+                }
+            }
+        })
+    }
+}
+
+/// Apply messages from `rx` to the app until `done` holds or `timeout` passes.
+///
+/// Streamed tokens are appended as each message is applied, rather than on
+/// the next frame. Returns whether `done` held.
+pub async fn pump_until(
+    app: &mut App,
+    rx: &mut mpsc::UnboundedReceiver<AppMessage>,
+    timeout: Duration,
+    mut done: impl FnMut(&App) -> bool,
+) -> bool {
+    let deadline = tokio::time::Instant::now() + timeout;
+    while !done(app) {
+        match tokio::time::timeout_at(deadline, rx.recv()).await {
+            Ok(Some(message)) => {
+                app.handle_message(message);
+                app.flush_stream_tokens();
+            }
+            _ => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_feed_plays_in_order_after_delays() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let feed = ScriptedEventFeed::new()
+            .stream_token("t1", "Hello")
+            .after(
+                Duration::from_millis(20),
+                AppMessage::StreamToken {
+                    thread_id: "t1".to_string(),
+                    token: " world".to_string(),
+                },
+            )
+            .stream_complete("t1", 9);
+        assert_eq!(feed.len(), 3);
+
+        let started = tokio::time::Instant::now();
+        feed.play(tx).await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(20));
+
+        let mut tokens = String::new();
+        while let Ok(message) = rx.try_recv() {
+            match message {
+                AppMessage::StreamToken { token, .. } => tokens.push_str(&token),
+                AppMessage::StreamComplete { message_id, .. } => assert_eq!(message_id, 9),
+                other => panic!("unexpected message: {:?}", other),
+            }
+        }
+        assert_eq!(tokens, "Hello world");
+    }
+}
@@ -0,0 +1,506 @@
+//! In-memory Conductor backend for integration tests.
+//!
+//! [`FakeConductor`] answers the Conductor REST and streaming endpoints from
+//! fixtures held in memory. It implements [`HttpClient`], and also serves the
+//! same fixtures on a loopback port, because most [`ConductorClient`] calls
+//! still go through reqwest rather than the injected client.
+//!
+//! Each `POST /v1/stream` replies with the next script queued by
+//! [`FakeConductor::script_stream`]: a list of [`StreamStep`]s sent as SSE
+//! frames. [`StreamStep::AwaitPermission`] holds the stream until the app
+//! answers that permission over HTTP, so a script can pause on a prompt the
+//! way the real backend does.
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header, Method, StatusCode, Uri},
+    response::IntoResponse,
+    Json, Router,
+};
+use bytes::Bytes;
+use futures::{stream, Stream, StreamExt};
+use serde_json::{json, Value};
+use tokio::sync::Notify;
+
+use crate::conductor::{ConductorClient, ConductorConfig};
+use crate::models::{ServerMessage, Thread};
+use crate::traits::{Headers, HttpClient, HttpError, Response};
+
+/// One step of a scripted `/v1/stream` reply
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamStep {
+    /// Send an SSE event (`event: <name>` with JSON `data`)
+    Event { name: String, data: Value },
+    /// Wait before the next step
+    Delay(Duration),
+    /// Hold the stream until the app answers this permission
+    AwaitPermission(String),
+}
+
+impl StreamStep {
+    /// An SSE event with a JSON payload
+    pub fn event(name: impl Into<String>, data: Value) -> Self {
+        StreamStep::Event {
+            name: name.into(),
+            data,
+        }
+    }
+
+    /// A chunk of assistant text
+    pub fn content(text: impl Into<String>) -> Self {
+        Self::event("content", json!({ "text": text.into() }))
+    }
+
+    /// A permission prompt for a tool call
+    pub fn permission_request(
+        permission_id: impl Into<String>,
+        tool_name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self::event(
+            "permission_request",
+            json!({
+                "permission_id": permission_id.into(),
+                "tool_name": tool_name.into(),
+                "description": description.into(),
+            }),
+        )
+    }
+
+    /// A subagent starting work
+    pub fn subagent_started(
+        task_id: impl Into<String>,
+        description: impl Into<String>,
+        subagent_type: impl Into<String>,
+    ) -> Self {
+        Self::event(
+            "subagent_started",
+            json!({
+                "task_id": task_id.into(),
+                "description": description.into(),
+                "subagent_type": subagent_type.into(),
+            }),
+        )
+    }
+
+    /// The end of the response, saved as `message_id`
+    pub fn done(message_id: i64) -> Self {
+        Self::event("done", json!({ "message_id": message_id.to_string() }))
+    }
+
+    /// The step as SSE wire text (empty for steps that send nothing)
+    fn frame(&self) -> Option<String> {
+        match self {
+            StreamStep::Event { name, data } => {
+                Some(format!("event: {}\ndata: {}\n\n", name, data))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A request the fake received, for assertions
+#[derive(Debug, Clone, PartialEq)]
+pub struct FakeRequest {
+    /// HTTP method (GET, POST, PUT, ...)
+    pub method: String,
+    /// Path and query, without the base URL
+    pub path: String,
+    /// Request body (empty when there was none)
+    pub body: String,
+}
+
+/// How the fake answers a request
+enum FakeReply {
+    Json(u16, Value),
+    Stream(Vec<StreamStep>),
+}
+
+/// Fixtures and recorded traffic
+#[derive(Default)]
+struct FakeState {
+    threads: Vec<Thread>,
+    messages: HashMap<String, Vec<ServerMessage>>,
+    /// Canned replies by (method, path), checked before the built-in routes
+    routes: HashMap<(String, String), (u16, Value)>,
+    /// How long to hold replies, by (method, path)
+    delays: HashMap<(String, String), Duration>,
+    /// Replies for the next `/v1/stream` requests, in order
+    stream_scripts: VecDeque<Vec<StreamStep>>,
+    requests: Vec<FakeRequest>,
+    /// Permission answers received, by permission ID
+    permission_answers: HashMap<String, bool>,
+}
+
+struct Shared {
+    state: Mutex<FakeState>,
+    /// Woken whenever a permission is answered
+    answered: Notify,
+}
+
+/// Scriptable in-memory Conductor.
+///
+/// # Example
+///
+/// ```ignore
+/// use spoq::app::App;
+/// use spoq::testing::{FakeConductor, StreamStep};
+///
+/// let fake = FakeConductor::start().await?;
+/// fake.script_stream(vec![StreamStep::content("Hello"), StreamStep::done(1)]);
+/// let app = App::with_client(fake.client())?;
+/// ```
+#[derive(Clone)]
+pub struct FakeConductor {
+    base_url: String,
+    shared: Arc<Shared>,
+}
+
+impl FakeConductor {
+    /// Start serving on a free loopback port.
+    ///
+    /// The server runs until the Tokio runtime shuts down.
+    pub async fn start() -> std::io::Result<Self> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let fake = Self {
+            base_url: format!("http://{}", listener.local_addr()?),
+            shared: Arc::new(Shared {
+                state: Mutex::new(FakeState::default()),
+                answered: Notify::new(),
+            }),
+        };
+
+        let router = Router::new()
+            .fallback(serve_request)
+            .with_state(fake.clone());
+        tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, router).await {
+                tracing::error!("Fake conductor error: {}", e);
+            }
+        });
+
+        Ok(fake)
+    }
+
+    /// Base URL the fake serves on
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// A Conductor client pointed at the fake, for `App::with_client`
+    pub fn client(&self) -> Arc<ConductorClient> {
+        let config = ConductorConfig::with_base_url(self.base_url.clone());
+        Arc::new(ConductorClient::with_http(Arc::new(self.clone()), config))
+    }
+
+    /// Add a thread to the thread list
+    pub fn add_thread(&self, thread: Thread) {
+        self.state().threads.push(thread);
+    }
+
+    /// Set the messages returned with a thread
+    pub fn set_messages(&self, thread_id: &str, messages: Vec<ServerMessage>) {
+        self.state()
+            .messages
+            .insert(thread_id.to_string(), messages);
+    }
+
+    /// Answer `method path` with a JSON body, ahead of the built-in routes
+    pub fn respond(&self, method: &str, path: &str, status: u16, body: Value) {
+        self.state()
+            .routes
+            .insert((method.to_uppercase(), path.to_string()), (status, body));
+    }
+
+    /// Hold replies to `method path` for `delay`, to stand in for a slow
+    /// endpoint
+    pub fn delay(&self, method: &str, path: &str, delay: Duration) {
+        self.state()
+            .delays
+            .insert((method.to_uppercase(), path.to_string()), delay);
+    }
+
+    /// Queue the reply for the next `/v1/stream` request.
+    ///
+    /// Streams without a script just finish.
+    pub fn script_stream(&self, steps: Vec<StreamStep>) {
+        self.state().stream_scripts.push_back(steps);
+    }
+
+    /// Requests received so far, oldest first
+    pub fn requests(&self) -> Vec<FakeRequest> {
+        self.state().requests.clone()
+    }
+
+    /// How a permission was answered, if it was
+    pub fn permission_answer(&self, permission_id: &str) -> Option<bool> {
+        self.state().permission_answers.get(permission_id).copied()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, FakeState> {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Record a request and pick its reply
+    fn reply(&self, method: &str, path: &str, body: &str) -> FakeReply {
+        let mut state = self.state();
+        state.requests.push(FakeRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        });
+
+        let route = path.split('?').next().unwrap_or(path);
+        if let Some((status, body)) = state.routes.get(&(method.to_string(), route.to_string())) {
+            return FakeReply::Json(*status, body.clone());
+        }
+
+        let segments: Vec<&str> = route.trim_matches('/').split('/').collect();
+        match (method, segments.as_slice()) {
+            ("GET", ["v1", "threads"]) => FakeReply::Json(
+                200,
+                json!({ "threads": state.threads, "total": state.threads.len() }),
+            ),
+            ("GET", ["v1", "threads", id]) => match state.threads.iter().find(|t| t.id == *id) {
+                Some(thread) => FakeReply::Json(
+                    200,
+                    json!({
+                        "id": thread.id,
+                        "type": thread.thread_type,
+                        "name": thread.title,
+                        "project_path": thread.working_directory,
+                        "messages": state.messages.get(*id).cloned().unwrap_or_default(),
+                    }),
+                ),
+                None => not_found(route),
+            },
+            ("POST", ["v1", "stream"]) => {
+                FakeReply::Stream(state.stream_scripts.pop_front().unwrap_or_default())
+            }
+            ("POST", ["v1", "permissions", id]) => {
+                let approved = serde_json::from_str::<Value>(body)
+                    .ok()
+                    .and_then(|v| v.get("approved").and_then(Value::as_bool))
+                    .unwrap_or(false);
+                state.permission_answers.insert(id.to_string(), approved);
+                self.shared.answered.notify_waiters();
+                FakeReply::Json(200, json!({}))
+            }
+            _ => not_found(route),
+        }
+    }
+
+    /// Wait out the delay set for `method path`, if any
+    async fn hold(&self, method: &str, path: &str) {
+        let route = path.split('?').next().unwrap_or(path);
+        let delay = self
+            .state()
+            .delays
+            .get(&(method.to_string(), route.to_string()))
+            .copied();
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Play a stream script as SSE frames
+    fn stream_frames(&self, steps: Vec<StreamStep>) -> impl Stream<Item = Bytes> + Send {
+        stream::unfold(
+            (VecDeque::from(steps), self.clone()),
+            |(mut steps, fake)| async move {
+                while let Some(step) = steps.pop_front() {
+                    match &step {
+                        StreamStep::Delay(delay) => tokio::time::sleep(*delay).await,
+                        StreamStep::AwaitPermission(id) => fake.wait_for_answer(id).await,
+                        StreamStep::Event { .. } => {}
+                    }
+                    if let Some(frame) = step.frame() {
+                        return Some((Bytes::from(frame), (steps, fake)));
+                    }
+                }
+                None
+            },
+        )
+    }
+
+    async fn wait_for_answer(&self, permission_id: &str) {
+        loop {
+            // Registered before the check, so an answer in between still wakes it
+            let answered = self.shared.answered.notified();
+            if self.permission_answer(permission_id).is_some() {
+                return;
+            }
+            answered.await;
+        }
+    }
+
+    /// Strip the base URL from a full request URL
+    fn path_of<'a>(&self, url: &'a str) -> &'a str {
+        url.strip_prefix(self.base_url.as_str()).unwrap_or(url)
+    }
+}
+
+fn not_found(path: &str) -> FakeReply {
+    FakeReply::Json(404, json!({ "error": format!("no fixture for {}", path) }))
+}
+
+/// Axum handler answering every request from the fixtures
+async fn serve_request(
+    State(fake): State<FakeConductor>,
+    method: Method,
+    uri: Uri,
+    body: String,
+) -> axum::response::Response {
+    let path = uri
+        .path_and_query()
+        .map(|p| p.as_str())
+        .unwrap_or(uri.path());
+    let reply = fake.reply(method.as_str(), path, &body);
+    fake.hold(method.as_str(), path).await;
+    match reply {
+        FakeReply::Json(status, body) => {
+            let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            (status, Json(body)).into_response()
+        }
+        FakeReply::Stream(steps) => {
+            let frames = fake.stream_frames(steps).map(Ok::<_, Infallible>);
+            (
+                [(header::CONTENT_TYPE, "text/event-stream")],
+                Body::from_stream(frames),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[async_trait]
+impl HttpClient for FakeConductor {
+    async fn get(&self, url: &str, _headers: &Headers) -> Result<Response, HttpError> {
+        let reply = self.reply("GET", self.path_of(url), "");
+        self.hold("GET", self.path_of(url)).await;
+        match reply {
+            FakeReply::Json(status, body) => {
+                Ok(Response::new(status, Bytes::from(body.to_string())))
+            }
+            FakeReply::Stream(_) => Err(HttpError::Other(
+                "Stream response on non-stream request".to_string(),
+            )),
+        }
+    }
+
+    async fn post(&self, url: &str, body: &str, _headers: &Headers) -> Result<Response, HttpError> {
+        let reply = self.reply("POST", self.path_of(url), body);
+        self.hold("POST", self.path_of(url)).await;
+        match reply {
+            FakeReply::Json(status, body) => {
+                Ok(Response::new(status, Bytes::from(body.to_string())))
+            }
+            FakeReply::Stream(_) => Err(HttpError::Other(
+                "Stream response on non-stream request".to_string(),
+            )),
+        }
+    }
+
+    async fn post_stream(
+        &self,
+        url: &str,
+        body: &str,
+        _headers: &Headers,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, HttpError>> + Send>>, HttpError> {
+        match self.reply("POST", self.path_of(url), body) {
+            FakeReply::Stream(steps) => Ok(Box::pin(self.stream_frames(steps).map(Ok))),
+            FakeReply::Json(status, body) => Err(HttpError::ServerError {
+                status,
+                message: body.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_step_frames() {
+        assert_eq!(
+            StreamStep::content("Hi").frame().as_deref(),
+            Some("event: content\ndata: {\"text\":\"Hi\"}\n\n")
+        );
+        assert_eq!(
+            StreamStep::done(7).frame().as_deref(),
+            Some("event: done\ndata: {\"message_id\":\"7\"}\n\n")
+        );
+        assert_eq!(StreamStep::Delay(Duration::from_millis(5)).frame(), None);
+    }
+
+    #[tokio::test]
+    async fn test_stream_waits_for_permission_answer() {
+        let fake = FakeConductor::start().await.unwrap();
+        fake.script_stream(vec![
+            StreamStep::permission_request("perm-1", "Bash", "Run ls"),
+            StreamStep::AwaitPermission("perm-1".to_string()),
+            StreamStep::done(3),
+        ]);
+
+        let mut frames = fake
+            .post_stream(
+                &format!("{}/v1/stream", fake.base_url()),
+                "{}",
+                &Headers::new(),
+            )
+            .await
+            .unwrap();
+        let first = frames.next().await.unwrap().unwrap();
+        assert!(String::from_utf8_lossy(&first).starts_with("event: permission_request"));
+
+        let pending = tokio::time::timeout(Duration::from_millis(50), frames.next()).await;
+        assert!(pending.is_err(), "stream should wait for the answer");
+
+        fake.post(
+            &format!("{}/v1/permissions/perm-1", fake.base_url()),
+            r#"{"approved":true}"#,
+            &Headers::new(),
+        )
+        .await
+        .unwrap();
+        let last = frames.next().await.unwrap().unwrap();
+        assert!(String::from_utf8_lossy(&last).starts_with("event: done"));
+        assert_eq!(fake.permission_answer("perm-1"), Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_serves_thread_fixtures_over_http() {
+        let fake = FakeConductor::start().await.unwrap();
+        let client = fake.client();
+        assert!(client.fetch_threads().await.unwrap().is_empty());
+
+        fake.respond("GET", "/v1/threads", 500, json!({ "error": "down" }));
+        assert!(client.fetch_threads().await.is_err());
+        assert_eq!(fake.requests().len(), 2);
+        assert_eq!(fake.requests()[0].path, "/v1/threads");
+    }
+
+    #[tokio::test]
+    async fn test_delayed_route_is_recorded_before_it_replies() {
+        let fake = FakeConductor::start().await.unwrap();
+        fake.delay("GET", "/v1/threads", Duration::from_millis(200));
+        let client = fake.client();
+
+        let slow = tokio::time::timeout(Duration::from_millis(50), client.fetch_threads()).await;
+        assert!(slow.is_err(), "reply should be held");
+        assert_eq!(fake.requests().len(), 1);
+        assert!(client.fetch_threads().await.unwrap().is_empty());
+    }
+}
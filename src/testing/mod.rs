@@ -0,0 +1,35 @@
+//! Integration-test harness.
+//!
+//! Drives a real [`App`](crate::app::App) without a backend:
+//!
+//! - [`FakeConductor`] - In-memory Conductor with fixtures and scripted
+//!   streams, passed to the app as an ordinary client
+//! - [`ScriptedEventFeed`] - Timeline of app messages (stream tokens,
+//!   permission prompts, subagent events) with optional delays
+//! - [`pump_until`] - Apply received messages until a condition holds
+//!
+//! # Example
+//!
+//! ```ignore
+//! use std::time::Duration;
+//! use spoq::app::App;
+//! use spoq::models::ThreadType;
+//! use spoq::testing::{pump_until, FakeConductor, StreamStep};
+//!
+//! let fake = FakeConductor::start().await?;
+//! fake.script_stream(vec![StreamStep::content("Hi"), StreamStep::done(1)]);
+//!
+//! let mut app = App::with_client(fake.client())?;
+//! let mut rx = app.message_rx.take().unwrap();
+//! for c in "hello".chars() {
+//!     app.textarea.insert_char(c);
+//! }
+//! app.submit_input(ThreadType::Conversation);
+//! pump_until(&mut app, &mut rx, Duration::from_secs(5), |app| !app.is_streaming()).await;
+//! ```
+
+pub mod event_feed;
+pub mod fake_conductor;
+
+pub use event_feed::{pump_until, ScriptedEventFeed};
+pub use fake_conductor::{FakeConductor, FakeRequest, StreamStep};
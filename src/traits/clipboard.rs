@@ -0,0 +1,15 @@
+//! Clipboard trait abstraction.
+//!
+//! Provides a trait-based abstraction for writing text to the system
+//! clipboard, so copy commands can be tested without a display server.
+
+use crate::clipboard::ClipboardTextError;
+
+/// Trait for writing text to the system clipboard.
+///
+/// When the write fails the App falls back to OSC 52 through the terminal,
+/// if the terminal supports it.
+pub trait ClipboardProvider: Send {
+    /// Write text to the clipboard
+    fn write_text(&mut self, text: &str) -> Result<(), ClipboardTextError>;
+}
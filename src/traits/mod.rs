@@ -5,18 +5,21 @@
 //!
 //! # Traits
 //!
+//! - [`ClipboardProvider`] - System clipboard writes
 //! - [`HttpClient`] - HTTP client operations (GET, POST, streaming)
 //! - [`WebSocketConnection`] - WebSocket connection management
 //! - [`CredentialsProvider`] - Credentials storage and retrieval
 //! - [`SseParser`] - Server-Sent Events parsing
 //! - [`TerminalBackend`] - Terminal rendering backend
 
+pub mod clipboard;
 pub mod credentials;
 pub mod http;
 pub mod sse;
 pub mod terminal;
 pub mod websocket;
 
+pub use clipboard::ClipboardProvider;
 pub use credentials::{CredentialsError, CredentialsProvider};
 pub use http::{Headers, HttpClient, HttpError, Response};
 pub use sse::{SseParseError as TraitSseParseError, SseParserTrait};
@@ -1,7 +1,9 @@
 //! Full-screen Browse List rendering
 //!
 //! Implements the full-screen list view for browsing threads and repos.
-//! Accessible via /threads and /repos slash commands.
+//! Accessible via /threads and /repos slash commands; Ctrl+T cycles through
+//! threads, repos and both together, and Ctrl+A shows all, active or
+//! archived threads.
 //!
 //! Follows the same minimal aesthetic as the dashboard thread list.
 
@@ -12,9 +14,12 @@ use ratatui::{
     widgets::Paragraph,
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
-use crate::app::{App, BrowseListMode};
+use crate::app::{App, BrowseListItem, BrowseListMode, ThreadArchiveFilter};
+use crate::markdown::PreviewCache;
 
+use super::helpers::format_compact_age;
 use super::layout::LayoutContext;
 use super::theme::{COLOR_ACCENT, COLOR_DIM, COLOR_HEADER};
 
@@ -27,6 +32,9 @@ pub const MAX_ITEMS: usize = 50;
 /// Lines per item (name + path + blank line spacing)
 const LINES_PER_ITEM: usize = 3;
 
+/// Narrowest room worth showing a message preview in
+const MIN_PREVIEW_WIDTH: usize = 12;
+
 /// Debounce delay for search in milliseconds
 pub const SEARCH_DEBOUNCE_MS: u64 = 300;
 
@@ -40,20 +48,7 @@ fn format_relative_time(timestamp: &Option<String>) -> String {
         return String::new();
     };
 
-    let now = chrono::Utc::now();
-    let duration = now.signed_duration_since(dt);
-
-    if duration.num_seconds() < 60 {
-        "now".to_string()
-    } else if duration.num_minutes() < 60 {
-        format!("{}m", duration.num_minutes())
-    } else if duration.num_hours() < 24 {
-        format!("{}h", duration.num_hours())
-    } else if duration.num_days() < 30 {
-        format!("{}d", duration.num_days())
-    } else {
-        format!("{}mo", duration.num_days() / 30)
-    }
+    format_compact_age(chrono::Utc::now().signed_duration_since(dt))
 }
 
 /// Truncate string to fit width, adding "..." if needed
@@ -95,18 +90,19 @@ pub fn render_browse_list(frame: &mut Frame, app: &mut App) {
 fn render_header(frame: &mut Frame, area: Rect, app: &App) {
     let centered_area = calculate_centered_area(area);
 
-    // Title in center
+    // Title in center, naming the archive filter unless it shows all threads
     let title = match app.browse_list.mode {
-        BrowseListMode::Threads => "threads",
+        BrowseListMode::Threads => match app.browse_list.archive_filter {
+            ThreadArchiveFilter::All => "threads",
+            ThreadArchiveFilter::Active => "active threads",
+            ThreadArchiveFilter::Archived => "archived threads",
+        },
         BrowseListMode::Repos => "repos",
+        BrowseListMode::All => "threads & repos",
     };
 
     // Count on right
-    let count = match app.browse_list.mode {
-        BrowseListMode::Threads => app.browse_list.threads.len(),
-        BrowseListMode::Repos => app.browse_list.repos.len(),
-    };
-    let count_text = count.to_string();
+    let count_text = app.browse_list.item_count().to_string();
 
     // Calculate positions
     let esc_text = "esc";
@@ -162,16 +158,31 @@ fn render_search_input(frame: &mut Frame, area: Rect, app: &App) {
     let search_line = Line::from(spans);
     frame.render_widget(Paragraph::new(search_line), Rect::new(centered_area.x, centered_area.y, centered_area.width, 1));
 
-    // Show "searching..." on the right if searching
-    if app.browse_list.searching {
-        let indicator = "searching...";
-        let indicator_width = indicator.len() as u16;
-        let indicator_span = Span::styled(indicator, Style::default().fg(COLOR_DIM));
-        frame.render_widget(
-            Paragraph::new(Line::from(vec![indicator_span])),
-            Rect::new(centered_area.x + centered_area.width - indicator_width, centered_area.y, indicator_width, 1),
-        );
-    }
+    // Show "searching..." on the right if searching, otherwise what Ctrl+T
+    // (and in Threads mode Ctrl+A) switches to
+    let indicator = if app.browse_list.searching {
+        "searching...".to_string()
+    } else {
+        let next = match app.browse_list.mode {
+            BrowseListMode::Threads => "repos",
+            BrowseListMode::Repos => "all",
+            BrowseListMode::All => "threads",
+        };
+        match app.browse_list.mode {
+            BrowseListMode::Threads => format!(
+                "^a {}  ^t {}",
+                app.browse_list.archive_filter.next().label().to_lowercase(),
+                next
+            ),
+            _ => format!("^t {}", next),
+        }
+    };
+    let indicator_width = (indicator.len() as u16).min(centered_area.width);
+    let indicator_span = Span::styled(indicator, Style::default().fg(COLOR_DIM));
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![indicator_span])),
+        Rect::new(centered_area.x + centered_area.width - indicator_width, centered_area.y, indicator_width, 1),
+    );
 }
 
 /// Render the main list content
@@ -189,8 +200,14 @@ fn render_list_content(frame: &mut Frame, area: Rect, app: &App) {
         }
     }
 
-    // Handle loading state (initial load, not search)
-    if app.browse_list.loading && !app.browse_list.searching {
+    let items_count = app.browse_list.item_count();
+
+    // Handle loading state (initial load, not search); the combined list shows
+    // its repos while the threads load
+    if app.browse_list.loading
+        && !app.browse_list.searching
+        && (app.browse_list.mode != BrowseListMode::All || items_count == 0)
+    {
         let loading_line = Line::from(vec![
             Span::styled("loading...", Style::default().fg(COLOR_DIM)),
         ]);
@@ -199,11 +216,6 @@ fn render_list_content(frame: &mut Frame, area: Rect, app: &App) {
     }
 
     // Handle empty state
-    let items_count = match app.browse_list.mode {
-        BrowseListMode::Threads => app.browse_list.threads.len(),
-        BrowseListMode::Repos => app.browse_list.repos.len(),
-    };
-
     if items_count == 0 {
         let empty_msg = if !app.browse_list.search_query.is_empty() {
             "no results"
@@ -211,6 +223,7 @@ fn render_list_content(frame: &mut Frame, area: Rect, app: &App) {
             match app.browse_list.mode {
                 BrowseListMode::Threads => "no threads",
                 BrowseListMode::Repos => "no repos",
+                BrowseListMode::All => "no threads or repos",
             }
         };
         let empty_line = Line::from(vec![
@@ -231,7 +244,7 @@ fn render_list_content(frame: &mut Frame, area: Rect, app: &App) {
                 let is_selected = i == selected_index;
                 let row_y = centered_area.y + (display_idx * LINES_PER_ITEM) as u16;
 
-                render_thread_item(frame, centered_area.x, row_y, centered_area.width, thread, is_selected);
+                render_thread_item(frame, centered_area.x, row_y, centered_area.width, thread, &app.thread_previews, is_selected);
             }
         }
         BrowseListMode::Repos => {
@@ -242,13 +255,59 @@ fn render_list_content(frame: &mut Frame, area: Rect, app: &App) {
                 render_repo_item(frame, centered_area.x, row_y, centered_area.width, repo, is_selected);
             }
         }
+        BrowseListMode::All => render_combined_items(frame, centered_area, app),
+    }
+}
+
+/// Render the combined list: a header above each section (and above the
+/// first visible item, so the section on screen is always named)
+fn render_combined_items(frame: &mut Frame, area: Rect, app: &App) {
+    let items = app.browse_list.combined_items();
+    let bottom = area.y + area.height;
+    let mut y = area.y;
+    let mut section = None;
+
+    for (i, item) in items.iter().enumerate().skip(app.browse_list.scroll_offset) {
+        if section != Some(item.section()) {
+            section = Some(item.section());
+            if y >= bottom {
+                break;
+            }
+            let count = items.iter().filter(|other| other.section() == item.section()).count();
+            let header = Line::from(vec![
+                Span::styled(
+                    item.section().label(),
+                    Style::default().fg(COLOR_DIM).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!(" {}", count), Style::default().fg(COLOR_DIM)),
+            ]);
+            frame.render_widget(Paragraph::new(header), Rect::new(area.x, y, area.width, 1));
+            y += 1;
+        }
+        // Each item needs its two text rows
+        if y + 2 > bottom {
+            break;
+        }
+
+        let is_selected = i == app.browse_list.selected_index;
+        match *item {
+            BrowseListItem::Thread(index) => {
+                let thread = &app.browse_list.threads[index];
+                render_thread_item(frame, area.x, y, area.width, thread, &app.thread_previews, is_selected);
+            }
+            BrowseListItem::Repo(index) => {
+                let repo = &app.browse_list.repos[index];
+                render_repo_item(frame, area.x, y, area.width, repo, is_selected);
+            }
+        }
+        y += LINES_PER_ITEM as u16;
     }
 }
 
 /// Render a single thread item (2 lines + spacing)
 /// Line 1: > Title                                    2h
-/// Line 2:   ~/path/to/directory
-fn render_thread_item(frame: &mut Frame, x: u16, y: u16, width: u16, thread: &crate::models::picker::ThreadEntry, is_selected: bool) {
+/// Line 2:   ~/path/to/directory  latest message preview
+fn render_thread_item(frame: &mut Frame, x: u16, y: u16, width: u16, thread: &crate::models::picker::ThreadEntry, previews: &PreviewCache, is_selected: bool) {
     let content_width = (width as usize).saturating_sub(2); // Account for "> " prefix
 
     // Title
@@ -260,8 +319,13 @@ fn render_thread_item(frame: &mut Frame, x: u16, y: u16, width: u16, thread: &cr
         }
     });
 
-    // Time
+    // Time, after an archived marker
     let time_text = format_relative_time(&thread.last_activity);
+    let time_text = match (thread.archived, time_text.is_empty()) {
+        (true, true) => "archived".to_string(),
+        (true, false) => format!("archived {}", time_text),
+        (false, _) => time_text,
+    };
     let time_width = time_text.len();
 
     // Truncate title to fit with time
@@ -305,11 +369,21 @@ fn render_thread_item(frame: &mut Frame, x: u16, y: u16, width: u16, thread: &cr
     let dir_text = truncate(&dir_display, content_width);
     let dir_style = Style::default().fg(COLOR_DIM);
 
-    let dir_line = Line::from(vec![
+    // Latest message preview in the room left after the directory
+    let preview_width = content_width.saturating_sub(dir_text.width() + 2);
+    let preview = (preview_width >= MIN_PREVIEW_WIDTH)
+        .then(|| previews.truncated(&thread.id, preview_width))
+        .flatten();
+
+    let mut dir_spans = vec![
         Span::raw("  "), // Same indent as prefix
         Span::styled(dir_text, dir_style),
-    ]);
-    frame.render_widget(Paragraph::new(dir_line), Rect::new(x, y + 1, width, 1));
+    ];
+    if let Some(preview) = preview {
+        dir_spans.push(Span::raw("  "));
+        dir_spans.push(Span::styled(preview, dir_style.add_modifier(Modifier::ITALIC)));
+    }
+    frame.render_widget(Paragraph::new(Line::from(dir_spans)), Rect::new(x, y + 1, width, 1));
 
     // Line 3 is blank (spacing) - no need to render
 }
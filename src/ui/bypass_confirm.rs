@@ -0,0 +1,105 @@
+//! Execute mode confirmation rendering
+//!
+//! Shown when Shift+Tab would switch to Execute (bypass permissions) mode:
+//! what the mode means and where it applies, or why it isn't allowed there.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::helpers::shorten_path;
+use super::theme::{COLOR_ACCENT, COLOR_BYPASS_BG, COLOR_DIM, COLOR_HEADER};
+
+/// Longest working directory shown
+const DIRECTORY_WIDTH: usize = 40;
+
+/// Render the Execute mode confirmation as a centered dialog
+pub fn render_bypass_confirm(frame: &mut Frame, app: &App) {
+    let Some(ref confirm) = app.bypass_confirm else {
+        return;
+    };
+    let home = dirs::home_dir();
+    let directory = confirm
+        .working_directory
+        .as_deref()
+        .map(|wd| shorten_path(wd, home.as_ref().and_then(|h| h.to_str()), DIRECTORY_WIDTH))
+        .unwrap_or_else(|| "no working directory".to_string());
+    let mut lines = vec![
+        Line::from(Span::raw(
+            "Every tool call runs without a prompt: commands, edits, deletes.",
+        )),
+        Line::from(vec![
+            Span::styled("Applies to ", Style::default().fg(COLOR_DIM)),
+            Span::raw(confirm.target.clone()),
+            Span::styled(format!(" ({})", directory), Style::default().fg(COLOR_DIM)),
+        ]),
+    ];
+    if let Some(ref refusal) = confirm.refusal {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("Not allowed: {}", refusal),
+            Style::default()
+                .fg(COLOR_BYPASS_BG)
+                .add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    let area = frame.area();
+    let dialog_width = 72u16.min(area.width.saturating_sub(4));
+    let dialog_height = (lines.len() as u16 + 5).min(area.height.saturating_sub(2));
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            " Switch to Execute mode? ",
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BYPASS_BG));
+    frame.render_widget(block, dialog_area);
+
+    let rows_area = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(3),
+    };
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), rows_area);
+
+    let hint_area = Rect {
+        x: rows_area.x,
+        y: dialog_area.y + dialog_area.height.saturating_sub(2),
+        width: rows_area.width,
+        height: 1,
+    };
+    let hint = if confirm.refusal.is_some() {
+        vec![
+            Span::styled("[any key] ", Style::default().fg(COLOR_ACCENT)),
+            Span::styled("skip to Default", Style::default().fg(COLOR_DIM)),
+        ]
+    } else {
+        vec![
+            Span::styled("[Y] ", Style::default().fg(COLOR_ACCENT)),
+            Span::styled("switch  ", Style::default().fg(COLOR_DIM)),
+            Span::styled("[any other key] ", Style::default().fg(COLOR_ACCENT)),
+            Span::styled("skip to Default", Style::default().fg(COLOR_DIM)),
+        ]
+    };
+    frame.render_widget(Paragraph::new(Line::from(hint)), hint_area);
+}
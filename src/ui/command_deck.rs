@@ -11,10 +11,11 @@ use ratatui::{
 };
 
 use crate::app::App;
-use crate::ui::dashboard::{render_dashboard, Theme};
+use crate::ui::dashboard::render_dashboard;
 
 use super::conversation::{create_mode_indicator_line, render_mode_indicator};
-use super::input::{calculate_input_area_height_with_images, render_input_area};
+use super::history_search::render_history_search;
+use super::input::{input_area_height, render_input_area};
 use super::layout::LayoutContext;
 use super::slash_autocomplete::render_slash_autocomplete;
 use super::unified_picker::render_unified_picker;
@@ -48,10 +49,8 @@ pub fn render_command_deck(frame: &mut Frame, app: &mut App) {
     // Create layout context from terminal dimensions stored in app state
     let ctx = LayoutContext::new(app.terminal_width, app.terminal_height);
 
-    // Input height is dynamic based on line count (hard wrap inserts actual newlines)
-    let line_count = app.textarea.line_count();
-    let has_images = !app.pending_images.is_empty();
-    let input_height = calculate_input_area_height_with_images(line_count, has_images);
+    // Input height grows with the soft-wrapped rows, up to the configured max
+    let input_height = input_area_height(app, size.width);
 
     // Check if we need to show mode indicator or Ctrl+C warning
     let mode_indicator_line = create_mode_indicator_line(app.permission_mode);
@@ -80,6 +79,9 @@ pub fn render_command_deck(frame: &mut Frame, app: &mut App) {
             render_slash_autocomplete(frame, app, main_chunks[2]);
         }
 
+        // Render input history search overlay (if open)
+        render_history_search(frame, app, main_chunks[2]);
+
         // Render unified @ picker overlay (if visible)
         if app.unified_picker.visible {
             render_unified_picker(frame, &app.unified_picker, main_chunks[2]);
@@ -102,6 +104,9 @@ pub fn render_command_deck(frame: &mut Frame, app: &mut App) {
             render_slash_autocomplete(frame, app, main_chunks[1]);
         }
 
+        // Render input history search overlay (if open)
+        render_history_search(frame, app, main_chunks[1]);
+
         // Render unified @ picker overlay (if visible)
         if app.unified_picker.visible {
             render_unified_picker(frame, &app.unified_picker, main_chunks[1]);
@@ -125,14 +130,20 @@ pub fn render_main_content(frame: &mut Frame, area: Rect, app: &mut App, _ctx: &
 /// This builds a RenderContext from App state and calls render_dashboard
 /// to display the multi-thread dashboard view.
 fn render_dashboard_content(frame: &mut Frame, area: Rect, app: &mut App) {
+    let loading = app.section_loading();
     // Build the render context from app state
-    let theme = Theme::default();
     let render_ctx = app
         .dashboard
-        .build_render_context(&app.system_stats, &theme, &app.repos);
-
-    // Interaction system removed - no longer need hit registry
-    render_dashboard(frame, area, &render_ctx);
+        .build_render_context(&app.system_stats, &app.theme, &app.repos)
+        .with_scroll(app.thread_list_scroll)
+        .with_previews(&app.thread_previews)
+        .with_unread(&app.unread_counts)
+        .with_token_usage(app.cache.usage_by_thread())
+        .with_loading(loading);
+
+    // Keep the rendered section rects for mouse wheel hit-testing
+    let layout = render_dashboard(frame, area, &render_ctx);
+    app.thread_list_layout = layout;
 }
 
 // ============================================================================
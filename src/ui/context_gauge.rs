@@ -0,0 +1,124 @@
+//! Context usage gauge for the conversation header
+//!
+//! Shows how full the context window is, colored by how close it is to the
+//! limit. When only one of the two numbers is known the gauge shows just that
+//! number; with neither it is hidden.
+
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
+
+use super::layout::LayoutContext;
+use super::theme::COLOR_DIM;
+
+/// Usage (percent) from which the gauge turns yellow
+const WARN_PERCENT: u32 = 60;
+
+/// Usage (percent) from which the gauge turns red
+const CRITICAL_PERCENT: u32 = 85;
+
+/// Format a token count compactly: `950`, `45.2k`, `200k`, `1.5M`
+pub fn format_token_count(tokens: u64) -> String {
+    let trim = |s: String| s.trim_end_matches(".0").to_string();
+    if tokens >= 1_000_000 {
+        format!("{}M", trim(format!("{:.1}", tokens as f64 / 1_000_000.0)))
+    } else if tokens >= 1_000 {
+        format!("{}k", trim(format!("{:.1}", tokens as f64 / 1_000.0)))
+    } else {
+        tokens.to_string()
+    }
+}
+
+/// Gauge color for a usage percentage
+fn gauge_color(percent: u32) -> Color {
+    if percent < WARN_PERCENT {
+        Color::Green
+    } else if percent < CRITICAL_PERCENT {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
+
+/// Build the context gauge badge, or None when nothing is known.
+pub fn context_gauge(
+    used: Option<u32>,
+    limit: Option<u32>,
+    ctx: &LayoutContext,
+) -> Option<Span<'static>> {
+    let is_narrow = ctx.is_narrow();
+    let is_extra_small = ctx.is_extra_small();
+
+    match (used, limit.filter(|l| *l > 0)) {
+        (Some(used), Some(limit)) => {
+            let percent = (used as f64 / limit as f64 * 100.0).round() as u32;
+            let text = if is_extra_small {
+                format!("{}% ", percent)
+            } else {
+                let blocks: u32 = if is_narrow { 5 } else { 10 };
+                let filled = (percent * blocks / 100).min(blocks) as usize;
+                format!(
+                    "[{}{}] {}% ",
+                    "\u{2588}".repeat(filled),
+                    "\u{2591}".repeat(blocks as usize - filled),
+                    percent
+                )
+            };
+            Some(Span::styled(
+                text,
+                Style::default().fg(gauge_color(percent)),
+            ))
+        }
+        (Some(used), None) => Some(Span::styled(
+            format!("{} tok ", format_token_count(used.into())),
+            Style::default().fg(COLOR_DIM),
+        )),
+        (None, Some(limit)) if !is_extra_small => Some(Span::styled(
+            format!("--/{} ", format_token_count(limit.into())),
+            Style::default().fg(COLOR_DIM),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_token_count() {
+        assert_eq!(format_token_count(950), "950");
+        assert_eq!(format_token_count(45_230), "45.2k");
+        assert_eq!(format_token_count(200_000), "200k");
+        assert_eq!(format_token_count(1_500_000), "1.5M");
+    }
+
+    #[test]
+    fn test_context_gauge_colors_by_threshold() {
+        let ctx = LayoutContext::new(120, 40);
+
+        let gauge = context_gauge(Some(50_000), Some(100_000), &ctx).unwrap();
+        assert_eq!(gauge.content, "[█████░░░░░] 50% ");
+        assert_eq!(gauge.style.fg, Some(Color::Green));
+
+        let gauge = context_gauge(Some(70_000), Some(100_000), &ctx).unwrap();
+        assert_eq!(gauge.style.fg, Some(Color::Yellow));
+
+        let gauge = context_gauge(Some(92_000), Some(100_000), &ctx).unwrap();
+        assert_eq!(gauge.style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_context_gauge_degrades_with_partial_data() {
+        let ctx = LayoutContext::new(120, 40);
+
+        let used_only = context_gauge(Some(45_230), None, &ctx).unwrap();
+        assert_eq!(used_only.content, "45.2k tok ");
+
+        let limit_only = context_gauge(None, Some(200_000), &ctx).unwrap();
+        assert_eq!(limit_only.content, "--/200k ");
+
+        assert!(context_gauge(None, None, &ctx).is_none());
+    }
+}
@@ -0,0 +1,254 @@
+//! Contrast checking for theme colors.
+//!
+//! Computes WCAG-style contrast ratios between theme foreground colors and the
+//! terminal background, reports the pairs that fall below a minimum, and can
+//! nudge failing colors toward white/black until they pass.
+//!
+//! Named and indexed colors are resolved with xterm's default palette; the
+//! real terminal palette may differ, so results are approximate for them.
+
+use ratatui::style::Color;
+
+use crate::view_state::Theme;
+
+/// Default minimum contrast ratio (WCAG AA for large text and UI components)
+pub const DEFAULT_MIN_CONTRAST: f64 = 3.0;
+
+/// A theme color that fails the minimum contrast against the background
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastIssue {
+    /// Theme role of the failing color (e.g., "dim")
+    pub role: &'static str,
+    /// The failing foreground color
+    pub fg: Color,
+    /// Background it was checked against
+    pub bg: Color,
+    /// Measured contrast ratio
+    pub ratio: f64,
+    /// Required minimum ratio
+    pub required: f64,
+}
+
+/// Resolve a color to RGB using xterm's default palette.
+///
+/// Returns None for `Color::Reset`, whose value depends on the terminal.
+pub fn color_to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let rgb = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i) => return Some(indexed_to_rgb(i)),
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 0, 0),
+        Color::Green => (0, 205, 0),
+        Color::Yellow => (205, 205, 0),
+        Color::Blue => (0, 0, 238),
+        Color::Magenta => (205, 0, 205),
+        Color::Cyan => (0, 205, 205),
+        Color::Gray => (229, 229, 229),
+        Color::DarkGray => (127, 127, 127),
+        Color::LightRed => (255, 0, 0),
+        Color::LightGreen => (0, 255, 0),
+        Color::LightYellow => (255, 255, 0),
+        Color::LightBlue => (92, 92, 255),
+        Color::LightMagenta => (255, 0, 255),
+        Color::LightCyan => (0, 255, 255),
+        Color::White => (255, 255, 255),
+    };
+    Some(rgb)
+}
+
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    const ANSI: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+    const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match index {
+        0..=15 => color_to_rgb(ANSI[index as usize]).unwrap_or((0, 0, 0)),
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE[(i / 36) as usize],
+                CUBE[((i / 6) % 6) as usize],
+                CUBE[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// Relative luminance of an sRGB color (0.0 = black, 1.0 = white)
+fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    fn channel(c: u8) -> f64 {
+        let c = c as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+}
+
+/// Contrast ratio between two colors, from 1.0 (none) to 21.0 (black/white).
+///
+/// Returns None if either color cannot be resolved.
+pub fn contrast_ratio(fg: Color, bg: Color) -> Option<f64> {
+    let l1 = relative_luminance(color_to_rgb(fg)?);
+    let l2 = relative_luminance(color_to_rgb(bg)?);
+    let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+    Some((lighter + 0.05) / (darker + 0.05))
+}
+
+/// Blend `fg` toward white (dark background) or black (light background)
+/// until it reaches `min` contrast against `bg`.
+///
+/// Returns `fg` unchanged if it already passes or cannot be resolved.
+pub fn adjust_for_contrast(fg: Color, bg: Color, min: f64) -> Color {
+    let (Some(fg_rgb), Some(bg_rgb)) = (color_to_rgb(fg), color_to_rgb(bg)) else {
+        return fg;
+    };
+    if contrast_ratio(fg, bg).is_some_and(|ratio| ratio >= min) {
+        return fg;
+    }
+
+    let target = if relative_luminance(bg_rgb) < 0.5 { 255.0 } else { 0.0 };
+    let mut adjusted = fg;
+    for step in 1..=20 {
+        let amount = step as f64 / 20.0;
+        let blend = |c: u8| (c as f64 + (target - c as f64) * amount).round() as u8;
+        adjusted = Color::Rgb(blend(fg_rgb.0), blend(fg_rgb.1), blend(fg_rgb.2));
+        if contrast_ratio(adjusted, bg).is_some_and(|ratio| ratio >= min) {
+            break;
+        }
+    }
+    adjusted
+}
+
+/// Theme colors by role, in display order
+fn theme_roles(theme: &Theme) -> [(&'static str, Color); 7] {
+    [
+        ("active", theme.active),
+        ("success", theme.success),
+        ("error", theme.error),
+        ("waiting", theme.waiting),
+        ("dim", theme.dim),
+        ("border", theme.border),
+        ("accent", theme.accent),
+    ]
+}
+
+/// Contrast ratio of every theme role against `bg`.
+///
+/// Roles whose color cannot be resolved are omitted.
+pub fn theme_contrast_ratios(theme: &Theme, bg: Color) -> Vec<(&'static str, Color, f64)> {
+    theme_roles(theme)
+        .into_iter()
+        .filter_map(|(role, fg)| contrast_ratio(fg, bg).map(|ratio| (role, fg, ratio)))
+        .collect()
+}
+
+/// Theme colors that fall below `min` contrast against `bg`
+pub fn check_theme_contrast(theme: &Theme, bg: Color, min: f64) -> Vec<ContrastIssue> {
+    theme_contrast_ratios(theme, bg)
+        .into_iter()
+        .filter(|(_, _, ratio)| *ratio < min)
+        .map(|(role, fg, ratio)| ContrastIssue {
+            role,
+            fg,
+            bg,
+            ratio,
+            required: min,
+        })
+        .collect()
+}
+
+/// Adjust every theme color below `min` contrast against `bg`.
+///
+/// Returns the issues that were fixed.
+pub fn enforce_theme_contrast(theme: &mut Theme, bg: Color, min: f64) -> Vec<ContrastIssue> {
+    let issues = check_theme_contrast(theme, bg, min);
+    for issue in &issues {
+        let adjusted = adjust_for_contrast(issue.fg, bg, min);
+        match issue.role {
+            "active" => theme.active = adjusted,
+            "success" => theme.success = adjusted,
+            "error" => theme.error = adjusted,
+            "waiting" => theme.waiting = adjusted,
+            "dim" => theme.dim = adjusted,
+            "border" => theme.border = adjusted,
+            "accent" => theme.accent = adjusted,
+            _ => {}
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contrast_ratio_extremes() {
+        let ratio = contrast_ratio(Color::White, Color::Black).unwrap();
+        assert!((ratio - 21.0).abs() < 0.01);
+        let same = contrast_ratio(Color::Red, Color::Red).unwrap();
+        assert!((same - 1.0).abs() < f64::EPSILON);
+        assert!(contrast_ratio(Color::Reset, Color::Black).is_none());
+    }
+
+    #[test]
+    fn test_indexed_colors_resolve() {
+        assert_eq!(color_to_rgb(Color::Indexed(1)), Some((205, 0, 0)));
+        assert_eq!(color_to_rgb(Color::Indexed(16)), Some((0, 0, 0)));
+        assert_eq!(color_to_rgb(Color::Indexed(231)), Some((255, 255, 255)));
+        assert_eq!(color_to_rgb(Color::Indexed(232)), Some((8, 8, 8)));
+    }
+
+    #[test]
+    fn test_default_theme_passes_on_dark_background() {
+        let issues = check_theme_contrast(&Theme::default(), Color::Black, DEFAULT_MIN_CONTRAST);
+        assert!(issues.is_empty(), "{:?}", issues);
+    }
+
+    #[test]
+    fn test_low_contrast_color_is_reported_and_adjusted() {
+        let mut theme = Theme {
+            dim: Color::Rgb(30, 30, 40),
+            ..Theme::default()
+        };
+
+        let issues = check_theme_contrast(&theme, Color::Black, DEFAULT_MIN_CONTRAST);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].role, "dim");
+
+        let fixed = enforce_theme_contrast(&mut theme, Color::Black, DEFAULT_MIN_CONTRAST);
+        assert_eq!(fixed.len(), 1);
+        assert!(contrast_ratio(theme.dim, Color::Black).unwrap() >= DEFAULT_MIN_CONTRAST);
+        assert!(check_theme_contrast(&theme, Color::Black, DEFAULT_MIN_CONTRAST).is_empty());
+    }
+
+    #[test]
+    fn test_adjust_darkens_on_light_background() {
+        let adjusted = adjust_for_contrast(Color::Rgb(230, 230, 230), Color::White, 4.5);
+        assert!(contrast_ratio(adjusted, Color::White).unwrap() >= 4.5);
+    }
+}
@@ -14,12 +14,16 @@ use ratatui::{
 use crate::app::App;
 use crate::models::{MessageSegment, PermissionMode, ToolEventStatus};
 
+use super::context_gauge::{context_gauge, format_token_count};
 use super::file_picker::render_file_picker;
-use super::helpers::{inner_rect, truncate_string, SPINNER_FRAMES};
+use super::helpers::{inner_rect, shorten_path, truncate_string, SPINNER_FRAMES};
+use super::history_search::render_history_search;
 use super::layout::LayoutContext;
 use super::messages::render_messages_area;
+use super::sidebar::{render_needs_action_sidebar, SIDEBAR_WIDTH};
 use super::slash_autocomplete::{render_slash_autocomplete_anchored, AnchorMode};
-use super::theme::{COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+use super::theme::{COLOR_BORDER, COLOR_BYPASS_BG, COLOR_DIM, COLOR_HEADER};
+use super::unified_picker::render_unified_picker;
 
 // ============================================================================
 // Mode Indicator
@@ -34,12 +38,16 @@ pub fn create_mode_indicator_line(mode: PermissionMode) -> Option<Line<'static>>
                 .fg(Color::Magenta)
                 .add_modifier(Modifier::BOLD),
         )])),
-        PermissionMode::Execution => Some(Line::from(vec![Span::styled(
-            " [EXECUTE]",
-            Style::default()
-                .fg(Color::Rgb(255, 140, 0))
-                .add_modifier(Modifier::BOLD),
-        )])),
+        PermissionMode::Execution => Some(Line::from(vec![
+            Span::raw(" "),
+            Span::styled(
+                " [EXECUTE] no tool prompts ",
+                Style::default()
+                    .fg(Color::White)
+                    .bg(COLOR_BYPASS_BG)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])),
     }
 }
 
@@ -58,13 +66,28 @@ pub fn render_conversation_screen(frame: &mut Frame, app: &mut App) {
     let size = frame.area();
 
     // Create layout context for responsive calculations
-    let ctx = LayoutContext::new(app.terminal_width, app.terminal_height);
+    let full_ctx = LayoutContext::new(app.terminal_width, app.terminal_height);
 
     // Determine if we should show the streaming indicator
-    let show_streaming_indicator = app.is_streaming();
+    // (the same bottom row hosts the message search bar while searching)
+    let show_streaming_indicator =
+        app.is_streaming() || app.message_search.active || compacting_active_thread(app);
 
     // Create main layout sections
-    let inner = inner_rect(size, 0);
+    let full_inner = inner_rect(size, 0);
+
+    // Needs-action sidebar on the right; the conversation keeps the rest
+    let (inner, ctx) = if app.sidebar_visible() {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(SIDEBAR_WIDTH)])
+            .split(full_inner);
+        render_needs_action_sidebar(frame, columns[1], app);
+        (columns[0], full_ctx.main_column(SIDEBAR_WIDTH))
+    } else {
+        app.sidebar_layout = Default::default();
+        (full_inner, full_ctx)
+    };
 
     // Calculate responsive layout heights
     // Input is now part of unified scroll in render_messages_area
@@ -84,10 +107,14 @@ pub fn render_conversation_screen(frame: &mut Frame, app: &mut App) {
 
         render_conversation_header(frame, main_chunks[0], app, &ctx);
         render_messages_area(frame, main_chunks[1], app, &ctx);
-        render_streaming_indicator(frame, main_chunks[2], app, &ctx);
+        if app.message_search.active {
+            render_message_search_bar(frame, main_chunks[2], app);
+        } else {
+            render_streaming_indicator(frame, main_chunks[2], app, &ctx);
+        }
 
-        // Render slash autocomplete overlay (if visible) - must be last for proper layering
-        if app.slash_autocomplete_visible {
+        // Render slash autocomplete / history search overlay (if visible) - must be last for proper layering
+        if app.slash_autocomplete_visible || app.history_search.active {
             // Calculate where input section starts in the viewport
             // With unified scroll anchored to bottom, input is near the bottom
             let viewport_height = main_chunks[1].height as usize;
@@ -102,6 +129,21 @@ pub fn render_conversation_screen(frame: &mut Frame, app: &mut App) {
                 height: 1,
             };
             render_slash_autocomplete_anchored(frame, app, input_anchor_area, AnchorMode::Above);
+            render_history_search(frame, app, input_anchor_area);
+        }
+
+        // Render the /cd folder picker above the input (if open)
+        if app.unified_picker.visible && app.unified_picker.changing_directory {
+            let viewport_height = main_chunks[1].height as usize;
+            let content_top = app.total_content_lines.saturating_sub(viewport_height + app.unified_scroll as usize);
+            let input_y_in_viewport = app.input_section_start.saturating_sub(content_top);
+            let input_anchor_area = Rect {
+                x: main_chunks[1].x + 2,
+                y: main_chunks[1].y + input_y_in_viewport as u16,
+                width: main_chunks[1].width.saturating_sub(4),
+                height: 1,
+            };
+            render_unified_picker(frame, &app.unified_picker, input_anchor_area);
         }
 
         // Render file picker overlay (if visible)
@@ -138,8 +180,8 @@ pub fn render_conversation_screen(frame: &mut Frame, app: &mut App) {
         render_conversation_header(frame, main_chunks[0], app, &ctx);
         render_messages_area(frame, main_chunks[1], app, &ctx);
 
-        // Render slash autocomplete overlay (if visible) - must be last for proper layering
-        if app.slash_autocomplete_visible {
+        // Render slash autocomplete / history search overlay (if visible) - must be last for proper layering
+        if app.slash_autocomplete_visible || app.history_search.active {
             // Calculate where input section starts in the viewport
             // With unified scroll anchored to bottom, input is near the bottom
             let viewport_height = main_chunks[1].height as usize;
@@ -154,6 +196,21 @@ pub fn render_conversation_screen(frame: &mut Frame, app: &mut App) {
                 height: 1,
             };
             render_slash_autocomplete_anchored(frame, app, input_anchor_area, AnchorMode::Above);
+            render_history_search(frame, app, input_anchor_area);
+        }
+
+        // Render the /cd folder picker above the input (if open)
+        if app.unified_picker.visible && app.unified_picker.changing_directory {
+            let viewport_height = main_chunks[1].height as usize;
+            let content_top = app.total_content_lines.saturating_sub(viewport_height + app.unified_scroll as usize);
+            let input_y_in_viewport = app.input_section_start.saturating_sub(content_top);
+            let input_anchor_area = Rect {
+                x: main_chunks[1].x + 2,
+                y: main_chunks[1].y + input_y_in_viewport as u16,
+                width: main_chunks[1].width.saturating_sub(4),
+                height: 1,
+            };
+            render_unified_picker(frame, &app.unified_picker, input_anchor_area);
         }
 
         // Render file picker overlay (if visible)
@@ -288,6 +345,13 @@ pub fn render_mode_indicator(frame: &mut Frame, area: Rect, mode_line: Line<'sta
     frame.render_widget(indicator, area);
 }
 
+/// Whether `/compact` is running on the open thread
+fn compacting_active_thread(app: &App) -> bool {
+    app.active_thread_id
+        .as_deref()
+        .is_some_and(|thread_id| app.is_compacting(thread_id))
+}
+
 /// Render the streaming indicator bar
 ///
 /// Adapts to terminal width using `LayoutContext`:
@@ -305,6 +369,16 @@ pub fn render_streaming_indicator(frame: &mut Frame, area: Rect, app: &App, ctx:
         .as_ref()
         .and_then(|msgs| msgs.iter().find(|m| m.is_streaming));
 
+    if streaming_message.is_none() && compacting_active_thread(app) {
+        let spinner = SPINNER_FRAMES[(app.tick_count % 10) as usize];
+        let indicator_line = Line::from(vec![Span::styled(
+            format!("  {} Compacting context...", spinner),
+            Style::default().fg(Color::DarkGray),
+        )]);
+        frame.render_widget(Paragraph::new(indicator_line), area);
+        return;
+    }
+
     if let Some(streaming_msg) = streaming_message {
         // Check if cancel is in progress
         if app.cancel_in_progress {
@@ -370,6 +444,41 @@ pub fn render_streaming_indicator(frame: &mut Frame, area: Rect, app: &App, ctx:
     }
 }
 
+/// Render the message search bar (query, match position, key hints)
+pub fn render_message_search_bar(frame: &mut Frame, area: Rect, app: &App) {
+    let search = &app.message_search;
+
+    let status = if search.query.is_empty() {
+        String::new()
+    } else if search.matches.is_empty() {
+        "  no matches".to_string()
+    } else {
+        format!("  {}/{}", search.current + 1, search.matches.len())
+    };
+
+    let status_color = if !search.query.is_empty() && search.matches.is_empty() {
+        Color::Red
+    } else {
+        COLOR_DIM
+    };
+
+    let search_line = Line::from(vec![
+        Span::styled("  / ", Style::default().fg(Color::Yellow)),
+        Span::styled(search.query.clone(), Style::default().fg(COLOR_HEADER)),
+        Span::styled(status, Style::default().fg(status_color)),
+        Span::styled(
+            if search.editing {
+                "  [Enter: confirm  Esc: close]"
+            } else {
+                "  [n: next  N: prev  /: new search  Esc: close]"
+            },
+            Style::default().fg(COLOR_DIM),
+        ),
+    ]);
+
+    frame.render_widget(Paragraph::new(search_line), area);
+}
+
 /// Render the thread title header with connection status and badges
 ///
 /// Adapts to terminal dimensions using `LayoutContext`:
@@ -415,63 +524,25 @@ pub fn render_conversation_header(frame: &mut Frame, area: Rect, app: &App, ctx:
         badges.push(Span::styled(skills_badge, Style::default().fg(Color::Cyan)));
     }
 
-    // Context progress bar - abbreviated on narrow terminals
-    let ctx_badge = match (
+    // Context usage gauge - hidden until usage or the limit is known
+    if let Some(gauge) = context_gauge(
         app.session_state.context_tokens_used,
         app.session_state.context_token_limit,
+        ctx,
     ) {
-        (Some(used), Some(limit)) if limit > 0 => {
-            let percentage = (used as f64 / limit as f64 * 100.0).round() as u32;
-            if is_extra_small {
-                // Minimal: just percentage
-                format!("{}% ", percentage)
-            } else if is_narrow {
-                // Short bar with 5 blocks
-                let filled_blocks = (percentage / 20).min(5) as usize;
-                let empty_blocks = 5 - filled_blocks;
-                format!(
-                    "[{}{}] {}% ",
-                    "\u{2588}".repeat(filled_blocks),
-                    "\u{2591}".repeat(empty_blocks),
-                    percentage
-                )
-            } else {
-                // Full bar with 10 blocks
-                let filled_blocks = (percentage / 10).min(10) as usize;
-                let empty_blocks = 10 - filled_blocks;
-                format!(
-                    "[{}{}] {}% ",
-                    "\u{2588}".repeat(filled_blocks),
-                    "\u{2591}".repeat(empty_blocks),
-                    percentage
-                )
-            }
-        }
-        _ => {
-            if is_extra_small {
-                "-- ".to_string()
-            } else if is_narrow {
-                "[\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}] -- ".to_string()
-            } else {
-                "[\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}] -- ".to_string()
-            }
-        }
-    };
-    badges.push(Span::styled(ctx_badge, Style::default().fg(COLOR_DIM)));
+        badges.push(gauge);
+    }
 
-    // Model badge [sonnet] if available - abbreviated on narrow terminals
-    if let Some(model) = model_name {
+    // Session token total of the thread - hidden on extra small
+    if let Some(total) = app
+        .active_thread_id
+        .as_deref()
+        .and_then(|id| app.thread_token_total(id))
+    {
         if !is_extra_small {
-            let model_badge = if is_narrow {
-                // Truncate model name on narrow terminals
-                let short_model = truncate_string(&model, 8);
-                format!("[{}] ", short_model)
-            } else {
-                format!("[{}] ", model)
-            };
             badges.push(Span::styled(
-                model_badge,
-                Style::default().fg(Color::Magenta),
+                format!("{} tokens ", format_token_count(total)),
+                Style::default().fg(COLOR_DIM),
             ));
         }
     }
@@ -493,6 +564,16 @@ pub fn render_conversation_header(frame: &mut Frame, area: Rect, app: &App, ctx:
         }
     }
 
+    // Polling transport badge (streaming blocked)
+    if app.polling_mode {
+        let label = if is_extra_small {
+            "poll "
+        } else {
+            "polling mode "
+        };
+        badges.push(Span::styled(label, Style::default().fg(Color::Yellow)));
+    }
+
     // Connection status badge (always shown)
     let (status_icon, status_color) = if app.connection_status {
         ("\u{25CF}", Color::LightGreen)
@@ -518,7 +599,7 @@ pub fn render_conversation_header(frame: &mut Frame, area: Rect, app: &App, ctx:
     let display_title = truncate_string(thread_title, max_title_len);
 
     // Thread title and description (left side)
-    let mut title_lines = vec![Line::from(vec![
+    let mut title_spans = vec![
         Span::styled("  Thread: ", Style::default().fg(COLOR_DIM)),
         Span::styled(
             display_title,
@@ -526,7 +607,91 @@ pub fn render_conversation_header(frame: &mut Frame, area: Rect, app: &App, ctx:
                 .fg(COLOR_HEADER)
                 .add_modifier(Modifier::BOLD),
         ),
-    ])];
+    ];
+
+    // Model chip [sonnet] next to the title - abbreviated on narrow terminals
+    if let Some(model) = model_name {
+        if !is_extra_small {
+            let model_chip = if is_narrow {
+                // Truncate model name on narrow terminals
+                truncate_string(&model, 8)
+            } else {
+                model
+            };
+            title_spans.push(Span::styled(
+                format!("  [{}]", model_chip),
+                Style::default().fg(Color::Magenta),
+            ));
+        }
+    }
+
+    // Zoom level chip when some content is hidden
+    let zoom = app.zoom_level();
+    if zoom != crate::app::ZoomLevel::Full && !is_extra_small {
+        title_spans.push(Span::styled(
+            format!("  [zoom {}: {}]", zoom.level(), zoom.label()),
+            Style::default().fg(COLOR_DIM),
+        ));
+    }
+
+    // Other clients on this thread ("also open on web", "web is typing…")
+    if !is_extra_small {
+        if let Some(thread_id) = app.active_thread_id.as_deref() {
+            let typing = app.typing_client_kinds(thread_id);
+            if !typing.is_empty() {
+                title_spans.push(Span::styled(
+                    format!("  {} is typing…", typing.join(", ")),
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::ITALIC),
+                ));
+            } else {
+                let present = app.present_client_kinds(thread_id);
+                if !present.is_empty() {
+                    title_spans.push(Span::styled(
+                        format!("  also open on {}", present.join(", ")),
+                        Style::default().fg(COLOR_DIM),
+                    ));
+                }
+            }
+        }
+    }
+
+    // Link chips, numbered for 1-9 navigation - hidden on extra small
+    if !is_extra_small {
+        if let Some(thread_id) = app.active_thread_id.as_deref() {
+            let max_chip_title = if is_narrow { 12 } else { 24 };
+            for (index, chip) in app.thread_link_chips(thread_id).iter().take(9).enumerate() {
+                title_spans.push(Span::raw("  "));
+                title_spans.push(Span::styled(
+                    format!(
+                        "[{} {} {} {}]",
+                        index + 1,
+                        chip.icon,
+                        chip.label,
+                        truncate_string(&chip.title, max_chip_title)
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+        }
+    }
+
+    let mut title_lines = vec![Line::from(title_spans)];
+
+    // Working directory on the second line (before the description, which
+    // is what gets clipped when the header has no room for both)
+    if let Some(wd) = thread_info.and_then(|t| t.working_directory.as_deref()) {
+        let home = dirs::home_dir();
+        let max_width = (header_chunks[0].width as usize).saturating_sub(5);
+        title_lines.push(Line::from(Span::styled(
+            format!(
+                "  📁 {}",
+                shorten_path(wd, home.as_ref().and_then(|h| h.to_str()), max_width)
+            ),
+            Style::default().fg(COLOR_DIM),
+        )));
+    }
 
     // Add description line if present, not empty, and we have space (not compact)
     if !is_compact {
@@ -543,16 +708,6 @@ pub fn render_conversation_header(frame: &mut Frame, area: Rect, app: &App, ctx:
         }
     }
 
-    // Add working directory line if present
-    if let Some(thread) = thread_info {
-        if let Some(wd) = thread.working_directory.as_ref() {
-            title_lines.push(Line::from(Span::styled(
-                format!("  📁 {}", wd),
-                Style::default().fg(COLOR_DIM),
-            )));
-        }
-    }
-
     let title_widget = Paragraph::new(title_lines).block(header_block);
     frame.render_widget(title_widget, header_chunks[0]);
 
@@ -8,6 +8,7 @@ use crate::ui::dashboard::RenderContext;
 ///
 /// Returns appropriate hint text depending on whether:
 /// - An overlay is open (show "esc close")
+/// - Several threads need action (show how to pick one)
 /// - Default state (no hint needed)
 ///
 /// # Arguments
@@ -18,11 +19,30 @@ use crate::ui::dashboard::RenderContext;
 pub fn get_footer_hint(ctx: &RenderContext) -> &'static str {
     if ctx.overlay.is_some() {
         "esc close"
+    } else if ctx.selected_needs_action.is_some() {
+        "\u{2191}\u{2193} select waiting thread  enter open"
     } else {
         "" // No filter functionality, so no hint needed
     }
 }
 
+/// Sections still loading at startup, e.g. "loading folders, repos..."
+///
+/// Shown at the right of the footer; None once everything has arrived.
+pub fn get_loading_hint(ctx: &RenderContext) -> Option<String> {
+    let loading = ctx.loading;
+    let sections: Vec<&str> = [
+        (loading.threads, "threads"),
+        (loading.folders, "folders"),
+        (loading.repos, "repos"),
+        (loading.picker, "picker"),
+    ]
+    .into_iter()
+    .filter_map(|(on, name)| on.then_some(name))
+    .collect();
+    (!sections.is_empty()).then(|| format!("loading {}...", sections.join(", ")))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -31,7 +51,7 @@ pub fn get_footer_hint(ctx: &RenderContext) -> &'static str {
 mod tests {
     use super::*;
     use crate::models::dashboard::Aggregate;
-    use crate::ui::dashboard::{OverlayState, SystemStats, Theme};
+    use crate::ui::dashboard::{OverlayState, SectionLoading, SystemStats, Theme};
 
     #[test]
     fn test_footer_hint_default_state() {
@@ -45,6 +65,18 @@ mod tests {
         assert_eq!(get_footer_hint(&ctx), "");
     }
 
+    #[test]
+    fn test_footer_hint_with_needs_action_selection() {
+        let threads = vec![];
+        let aggregate = Aggregate::new();
+        let stats = SystemStats::default();
+        let theme = Theme::default();
+        let ctx = RenderContext::new(&threads, &aggregate, &stats, &theme, &[])
+            .with_selected_needs_action(Some("t-2"));
+
+        assert!(get_footer_hint(&ctx).contains("select waiting thread"));
+    }
+
     #[test]
     fn test_footer_hint_with_overlay_question() {
         let threads = vec![];
@@ -123,4 +155,25 @@ mod tests {
 
         assert_eq!(get_footer_hint(&ctx), "esc close");
     }
+
+    #[test]
+    fn test_loading_hint_lists_pending_sections() {
+        let threads = vec![];
+        let aggregate = Aggregate::new();
+        let stats = SystemStats::default();
+        let theme = Theme::default();
+
+        let ctx = RenderContext::new(&threads, &aggregate, &stats, &theme, &[]);
+        assert_eq!(get_loading_hint(&ctx), None);
+
+        let ctx = ctx.with_loading(SectionLoading {
+            folders: true,
+            picker: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            get_loading_hint(&ctx),
+            Some("loading folders, picker...".to_string())
+        );
+    }
 }
@@ -30,7 +30,7 @@ const LOGO_WIDTH: u16 = 15;
 /// [left_stats] [spacer] [logo]
 /// ```
 ///
-/// - Left section (x=2): Connection status, CPU bar, RAM usage
+/// - Left section (x=2): Connection status, CPU bar, RAM usage, unread notifications
 /// - Right: SPOQ logo (2 rows, 15 chars wide)
 ///
 /// # Arguments
@@ -55,7 +55,7 @@ pub fn render(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
 // Section Renderers
 // ============================================================================
 
-/// Render the left section with CPU bar and RAM usage
+/// Render the left section with CPU bar, RAM usage and unread count
 fn render_left_section(buf: &mut Buffer, area: Rect, ctx: &RenderContext) {
     let mut x = area.x + 2;
     // Vertically center the single-line text within the header area
@@ -107,6 +107,20 @@ fn render_left_section(buf: &mut Buffer, area: Rect, ctx: &RenderContext) {
         }
         offset += 1;
     }
+
+    // Unread notifications: "  3 unread"
+    if ctx.unread_notifications > 0 {
+        let unread_text = format!("  {} unread", ctx.unread_notifications);
+        for ch in unread_text.chars() {
+            let pos_x = x + offset;
+            if pos_x < area.x + area.width {
+                buf[(pos_x, y)]
+                    .set_char(ch)
+                    .set_style(Style::default().fg(ctx.theme.accent));
+            }
+            offset += 1;
+        }
+    }
 }
 
 /// Render the SPOQ logo (top-right aligned)
@@ -268,4 +282,32 @@ mod tests {
             );
         }
     }
+
+    // -------------------- unread notifications Tests --------------------
+
+    #[test]
+    fn test_header_shows_unread_notifications() {
+        use crate::models::dashboard::Aggregate;
+        use crate::view_state::{SystemStats, Theme};
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let aggregate = Aggregate::default();
+        let system_stats = SystemStats::default();
+        let theme = Theme::default();
+        let render_header = |unread: usize| {
+            let ctx = RenderContext::new(&[], &aggregate, &system_stats, &theme, &[])
+                .with_unread_notifications(unread);
+            let mut terminal = Terminal::new(TestBackend::new(80, 3)).unwrap();
+            terminal
+                .draw(|frame| render(frame, frame.area(), &ctx))
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, 1)].symbol().to_string())
+                .collect::<String>()
+        };
+
+        assert!(render_header(3).contains("3 unread"));
+        assert!(!render_header(0).contains("unread"));
+    }
 }
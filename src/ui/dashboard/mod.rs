@@ -20,11 +20,12 @@ pub mod vps_config_card;
 // This allows existing code using `crate::ui::dashboard::*` to keep working
 pub use crate::models::ThreadMode;
 pub use crate::view_state::{
-    ClaudeLoginState, OverlayState, Progress, RenderContext, SystemStats, Theme, ThreadView,
+    ClaudeLoginState, OverlayState, Progress, RenderContext, SectionLoading, SystemStats, Theme,
+    ThreadListLayout, ThreadListPanel, ThreadListScroll, ThreadView,
 };
 
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     text::Line,
     Frame,
@@ -61,15 +62,18 @@ use ratatui::{
 /// * `frame` - The ratatui frame to render into
 /// * `area` - The rectangle area allocated for the dashboard
 /// * `ctx` - The render context containing all data for rendering
+///
+/// # Returns
+/// The rendered thread list sections, for mouse hit-testing
 pub fn render_dashboard(
     frame: &mut Frame,
     area: Rect,
     ctx: &RenderContext,
-) {
+) -> ThreadListLayout {
     // Minimum dimensions check
     if area.width < 40 || area.height < 10 {
         states::render_heavy_load(frame, area);
-        return;
+        return ThreadListLayout::default();
     }
 
     // Layout: header (3 rows) + margin + thread list (remaining) + footer (1 row)
@@ -92,19 +96,29 @@ pub fn render_dashboard(
     // chunks[1] is the margin - intentionally left empty
 
     // Render thread list (shows all threads)
-    thread_list::render(frame, chunks[2], ctx);
+    let layout = thread_list::render(frame, chunks[2], ctx);
 
     // Render footer hint
     let hint = footer::get_footer_hint(ctx);
     let hint_line = Line::styled(hint, Style::default().fg(Color::DarkGray));
     frame.render_widget(hint_line, chunks[3]);
 
+    // Sections still loading at startup, at the right of the footer
+    if let Some(loading) = footer::get_loading_hint(ctx) {
+        frame.render_widget(
+            Line::styled(loading, Style::default().fg(Color::DarkGray)).alignment(Alignment::Right),
+            chunks[3],
+        );
+    }
+
     // Render overlay if present
     if let Some(overlay_state) = ctx.overlay {
         // Calculate overlay position based on anchor_y or center
         let overlay_area = calculate_overlay_area(area, overlay_state);
         overlay::render(frame, overlay_area, overlay_state, ctx);
     }
+
+    layout
 }
 
 /// Calculate the overlay area based on the overlay state's anchor position
@@ -192,6 +206,8 @@ mod tests {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         });
@@ -259,6 +275,8 @@ mod tests {
                 header: "Input".to_string(),
                 options: vec![],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: std::collections::HashMap::new(),
         });
@@ -365,6 +365,11 @@ fn render_question_content(
         .map(|s| s.answered.clone())
         .unwrap_or_default();
 
+    let constraints = question_data
+        .and_then(|qd| qd.questions.get(tab_index))
+        .map(|q| q.constraints)
+        .unwrap_or_default();
+
     // Determine if scroll is needed by comparing content height to card height
     let inner_width = area.width as usize;
     let options_pairs: Vec<(String, String)> = option_labels
@@ -393,6 +398,9 @@ fn render_question_content(
         tabs_answered: &tabs_answered,
         scroll_offset,
         needs_scroll,
+        allow_other: constraints.allow_other,
+        other_max_length: constraints.other_max_length,
+        validation_error: question_state.and_then(|s| s.validation_error.as_deref()),
     };
 
     question_card::render_question(frame, area, thread_id, title, repo, &config);
@@ -561,6 +569,8 @@ mod tests {
                 header: "Choice".to_string(),
                 options,
                 multi_select,
+                constraints: Default::default(),
+                default: None,
             }],
             answers: HashMap::new(),
         }
@@ -711,12 +721,16 @@ mod tests {
                     header: "Q1".to_string(),
                     options: options.clone(),
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
                 Question {
                     question: "Second question?".to_string(),
                     header: "Q2".to_string(),
                     options,
                     multi_select: false,
+                    constraints: Default::default(),
+                    default: None,
                 },
             ],
             answers: HashMap::new(),
@@ -895,6 +909,13 @@ mod tests {
             question_state: None,
             question_timer_secs: None,
             repos: &repos,
+            scroll: Default::default(),
+            unread_notifications: 0,
+            previews: None,
+            unread: None,
+            token_usage: None,
+            selected_needs_action: None,
+            loading: Default::default(),
         };
 
         terminal
@@ -936,6 +957,13 @@ mod tests {
             question_state: None,
             question_timer_secs: None,
             repos: &repos,
+            scroll: Default::default(),
+            unread_notifications: 0,
+            previews: None,
+            unread: None,
+            token_usage: None,
+            selected_needs_action: None,
+            loading: Default::default(),
         };
 
         terminal
@@ -977,6 +1005,13 @@ mod tests {
             question_state: None,
             question_timer_secs: None,
             repos: &repos,
+            scroll: Default::default(),
+            unread_notifications: 0,
+            previews: None,
+            unread: None,
+            token_usage: None,
+            selected_needs_action: None,
+            loading: Default::default(),
         };
 
         terminal
@@ -1019,6 +1054,13 @@ mod tests {
             question_state: None,
             question_timer_secs: None,
             repos: &repos,
+            scroll: Default::default(),
+            unread_notifications: 0,
+            previews: None,
+            unread: None,
+            token_usage: None,
+            selected_needs_action: None,
+            loading: Default::default(),
         };
 
         terminal
@@ -12,22 +12,24 @@
 //! │                                                            │
 //! │  Which authentication method should I use?                 │
 //! │                                                            │
-//! │    > [●] JWT tokens                                        │
-//! │      [ ] Session cookies                                   │
-//! │      [ ] OAuth 2.0 only                                    │
+//! │    > [●] 1. JWT tokens                                     │
+//! │      [ ] 2. Session cookies                                │
+//! │      [ ] 3. OAuth 2.0 only                                 │
 //! │      [ ] Other: _______________________________            │
 //! │                                                            │
-//! │  ↑↓ navigate   enter select   esc cancel          (4:32)  │
+//! │  ↑↓ navigate   1-9 pick   enter select   esc cancel (4:32) │
 //! ╰────────────────────────────────────────────────────────────╯
 //! ```
 //!
 //! ## Multi-Select Mode (checkboxes)
 //!
 //! ```text
-//! │    > [x] Linting                                           │
-//! │      [x] Unit tests                                        │
-//! │      [ ] E2E tests                                         │
+//! │    > [x] 1. Linting                                        │
+//! │      [x] 2. Unit tests                                     │
+//! │      [ ] 3. E2E tests                                      │
 //! │      [ ] Other: _______________________________            │
+//! │                                                            │
+//! │  ↑↓ navigate   1-9 pick   enter select   esc   2 selected  │
 //! ```
 
 use ratatui::{
@@ -84,6 +86,12 @@ pub struct QuestionRenderConfig<'a> {
     pub scroll_offset: usize,
     /// Whether the card content exceeds available height (enables scroll indicators)
     pub needs_scroll: bool,
+    /// Whether the "Other" row is shown
+    pub allow_other: bool,
+    /// Maximum "Other" length, shown as a counter while "Other" is selected
+    pub other_max_length: Option<usize>,
+    /// Why the last confirm was refused (red hint above the help line)
+    pub validation_error: Option<&'a str>,
 }
 
 impl<'a> Default for QuestionRenderConfig<'a> {
@@ -103,6 +111,9 @@ impl<'a> Default for QuestionRenderConfig<'a> {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         }
     }
 }
@@ -213,7 +224,7 @@ pub fn render_question(
             .iter()
             .enumerate()
             .map(|(i, opt)| {
-                let label_text = format!("  [ ] {}", opt);
+                let label_text = format!("  [ ] {}{}", number_prefix(i), opt);
                 let label_lines =
                     wrap_text(&label_text, available_width, MEASURE_MAX_OPTION_LINES).len();
                 let desc_lines = config
@@ -282,7 +293,7 @@ pub fn render_question(
             "  "
         };
 
-        let option_text = format!("{}{} {}", cursor_char, marker, opt);
+        let option_text = format!("{}{} {}{}", cursor_char, marker, number_prefix(i), opt);
         let opt_available_width = (area.width - option_indent) as usize;
 
         // Wrap option text with generous limit
@@ -347,7 +358,7 @@ pub fn render_question(
     }
 
     // Render "Other" option
-    if y < area.y + area.height.saturating_sub(2) {
+    if config.allow_other && y < area.y + area.height.saturating_sub(2) {
         let is_other_cursor = config.other_selected
             || (config.selected_index.is_none()
                 || config.selected_index == Some(config.options.len()));
@@ -367,8 +378,18 @@ pub fn render_question(
 
         // Build Other line with input field
         let other_prefix = format!("{}{} Other: ", cursor_char, marker);
+        // Length counter ("12/40"), red once the limit is reached
+        let counter = config
+            .other_max_length
+            .filter(|_| config.other_selected)
+            .map(|max| {
+                let len = config.other_input.chars().count();
+                let color = if len >= max { Color::Red } else { Color::DarkGray };
+                (format!(" {}/{}", len, max), color)
+            });
+        let counter_width = counter.as_ref().map_or(0, |(text, _)| text.len());
         let input_width = (area.width - option_indent)
-            .saturating_sub(other_prefix.len() as u16) as usize;
+            .saturating_sub((other_prefix.len() + counter_width) as u16) as usize;
 
         // Display input or underscores placeholder
         let input_display = if config.other_input.is_empty() {
@@ -387,7 +408,7 @@ pub fn render_question(
         let other_area = Rect::new(area.x + option_indent, y, area.width - option_indent, 1);
 
         // Build spans for proper styling
-        let spans = vec![
+        let mut spans = vec![
             Span::styled(&other_prefix, style),
             Span::styled(
                 input_display,
@@ -398,6 +419,9 @@ pub fn render_question(
                 },
             ),
         ];
+        if let Some((text, color)) = counter {
+            spans.push(Span::styled(text, Style::default().fg(color)));
+        }
         frame.render_widget(Line::from(spans), other_area);
 
         y += 1;
@@ -405,10 +429,38 @@ pub fn render_question(
 
     // Skip to help text row (last row)
     let help_row_y = area.y + area.height.saturating_sub(1);
+    let mut show_help = help_row_y > y;
+
+    // Why the last confirm was refused: above the help line, or in its
+    // place when the card has no spare row
+    if let Some(error) = config.validation_error {
+        let error_y = if help_row_y > y + 1 { help_row_y - 1 } else { help_row_y };
+        if error_y >= y {
+            frame.render_widget(
+                Line::styled(
+                    truncate_with_ellipsis(error, area.width as usize),
+                    Style::default().fg(Color::Red),
+                ),
+                Rect::new(area.x, error_y, area.width, 1),
+            );
+            if error_y == help_row_y {
+                show_help = false;
+            }
+        }
+    }
 
     // Render help text with timer
-    if help_row_y > y {
+    if show_help {
         let has_multiple_tabs = config.tab_headers.len() > 1;
+        // Running count of checked options (and a filled-in "Other")
+        let selected_count = config.multi_select.then(|| {
+            config
+                .multi_selections
+                .iter()
+                .filter(|checked| **checked)
+                .count()
+                + usize::from(!config.other_input.is_empty())
+        });
         render_help_line(
             frame,
             area.x,
@@ -416,6 +468,7 @@ pub fn render_question(
             area.width,
             config.timer_seconds,
             has_multiple_tabs,
+            selected_count,
         );
     }
 }
@@ -463,6 +516,9 @@ pub fn render(
                 tabs_answered: &[],
                 scroll_offset: 0,
                 needs_scroll: false,
+                allow_other: true,
+                other_max_length: None,
+                validation_error: None,
             };
             render_question(frame, area, thread_id, title, repo, &config);
         }
@@ -484,21 +540,26 @@ fn render_help_line(
     width: u16,
     timer_seconds: Option<u32>,
     has_multiple_tabs: bool,
+    selected_count: Option<usize>,
 ) {
     let help_text = if has_multiple_tabs {
-        "\u{2191}\u{2193} navigate   tab next   enter select   esc cancel"
+        "\u{2191}\u{2193} navigate   1-9 pick   tab next   enter select   esc cancel"
     } else {
-        "\u{2191}\u{2193} navigate   enter select   esc cancel"
+        "\u{2191}\u{2193} navigate   1-9 pick   enter select   esc cancel"
     };
 
-    // Format timer
-    let timer_text = timer_seconds
-        .map(|secs| {
-            let mins = secs / 60;
-            let secs = secs % 60;
-            format!("({:}:{:02})", mins, secs)
-        })
-        .unwrap_or_default();
+    // Format timer, after the selected count for multi-select questions
+    let timer = timer_seconds.map(|secs| {
+        let mins = secs / 60;
+        let secs = secs % 60;
+        format!("({:}:{:02})", mins, secs)
+    });
+    let timer_text = selected_count
+        .map(|count| format!("{} selected", count))
+        .into_iter()
+        .chain(timer)
+        .collect::<Vec<_>>()
+        .join("  ");
 
     // Timer style: red if < 10 seconds
     let timer_style = if timer_seconds.map(|s| s < 10).unwrap_or(false) {
@@ -875,6 +936,16 @@ struct ContentMeasurement {
     total_rows: u16,
 }
 
+/// `"N. "` shortcut prefix for the first nine options, matching the digit
+/// key that picks them
+pub fn number_prefix(index: usize) -> String {
+    if index < 9 {
+        format!("{}. ", index + 1)
+    } else {
+        String::new()
+    }
+}
+
 /// Measure exact content height for a question card.
 ///
 /// Wraps all text sections with generous line limits to get true dimensions.
@@ -906,8 +977,8 @@ fn measure_content(
     let desc_width = inner_width.saturating_sub(DESCRIPTION_INDENT as usize);
 
     let mut option_rows_vec = Vec::with_capacity(options.len());
-    for (label, desc) in options {
-        let prefix = format!("  [ ] {}", label);
+    for (i, (label, desc)) in options.iter().enumerate() {
+        let prefix = format!("  [ ] {}{}", number_prefix(i), label);
         let label_lines = if label_width > 0 {
             wrap_text(&prefix, label_width, MEASURE_MAX_OPTION_LINES).len() as u16
         } else {
@@ -1187,6 +1258,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1234,6 +1308,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1276,6 +1353,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1317,6 +1397,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1358,6 +1441,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1388,7 +1474,7 @@ mod tests {
 
         terminal
             .draw(|frame| {
-                render_help_line(frame, 0, 0, 60, Some(272), false);
+                render_help_line(frame, 0, 0, 60, Some(272), false, None);
             })
             .unwrap();
 
@@ -1402,7 +1488,7 @@ mod tests {
 
         terminal
             .draw(|frame| {
-                render_help_line(frame, 0, 0, 60, Some(5), false);
+                render_help_line(frame, 0, 0, 60, Some(5), false, None);
             })
             .unwrap();
 
@@ -1416,7 +1502,7 @@ mod tests {
 
         terminal
             .draw(|frame| {
-                render_help_line(frame, 0, 0, 60, None, false);
+                render_help_line(frame, 0, 0, 60, None, false, None);
             })
             .unwrap();
 
@@ -1593,6 +1679,9 @@ mod tests {
             tabs_answered: &tabs_answered,
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1637,6 +1726,9 @@ mod tests {
             tabs_answered: &tabs_answered,
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1664,13 +1756,39 @@ mod tests {
 
         terminal
             .draw(|frame| {
-                render_help_line(frame, 0, 0, 60, None, true);
+                render_help_line(frame, 0, 0, 60, None, true, None);
             })
             .unwrap();
 
         // Should render with tab hint
     }
 
+    #[test]
+    fn test_help_line_shows_selected_count() {
+        let backend = TestBackend::new(80, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|frame| {
+                render_help_line(frame, 0, 0, 80, Some(65), false, Some(2));
+            })
+            .unwrap();
+
+        let buf = terminal.backend().buffer();
+        let row: String = (0..80)
+            .map(|x| buf.cell((x, 0)).unwrap().symbol())
+            .collect();
+        assert!(row.contains("1-9 pick"));
+        assert!(row.contains("2 selected  (1:05)"));
+    }
+
+    #[test]
+    fn test_number_prefix_covers_first_nine_options() {
+        assert_eq!(number_prefix(0), "1. ");
+        assert_eq!(number_prefix(8), "9. ");
+        assert_eq!(number_prefix(9), "");
+    }
+
     // -------------------- Option Description Tests --------------------
 
     #[test]
@@ -1702,6 +1820,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
 
@@ -1756,6 +1877,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1811,6 +1935,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
 
@@ -1859,6 +1986,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1907,6 +2037,9 @@ mod tests {
             tabs_answered: &[],
             scroll_offset: 0,
             needs_scroll: false,
+            allow_other: true,
+            other_max_length: None,
+            validation_error: None,
         };
 
         
@@ -1929,6 +2062,54 @@ mod tests {
 
     // -------------------- Content Measurement Tests --------------------
 
+    #[test]
+    fn test_render_question_constraints() {
+        let backend = TestBackend::new(60, 15);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let options = vec!["First".to_string(), "Second".to_string()];
+        let config = QuestionRenderConfig {
+            question: "Select:",
+            options: &options,
+            selected_index: None,
+            other_input: "abc",
+            other_selected: true,
+            other_max_length: Some(3),
+            validation_error: Some("Select at least 2 options"),
+            ..Default::default()
+        };
+
+        terminal
+            .draw(|frame| {
+                render_question(frame, Rect::new(0, 0, 56, 14), "t1", "Title", "repo", &config);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..14u16)
+            .map(|y| (0..56u16).map(|x| buffer[(x, y)].symbol().to_string()).collect())
+            .collect();
+        assert!(rows.iter().any(|row| row.contains("Other: abc") && row.contains("3/3")));
+        assert!(rows[12].contains("Select at least 2 options"));
+
+        // Without "Other" the row is left out
+        let config = QuestionRenderConfig {
+            allow_other: false,
+            ..config
+        };
+        terminal
+            .draw(|frame| {
+                render_question(frame, Rect::new(0, 0, 56, 14), "t1", "Title", "repo", &config);
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..14u16)
+            .flat_map(|y| (0..56u16).map(move |x| (x, y)))
+            .map(|pos| buffer[pos].symbol().to_string())
+            .collect();
+        assert!(!text.contains("Other:"));
+    }
+
     #[test]
     fn test_calculate_height_simple() {
         // header(1) + blanks(3) + question(2 min) + options(2*1) + other(1) + help(1) = 10
@@ -12,7 +12,12 @@ use ratatui::{
 
 /// Renders GitHub repos list when no threads exist
 /// Displays top 10 recent repos + quick tip about @ tagging
-pub fn render_all_clear(frame: &mut Frame, area: Rect, repos: &[crate::models::GitHubRepo]) {
+pub fn render_all_clear(
+    frame: &mut Frame,
+    area: Rect,
+    repos: &[crate::models::GitHubRepo],
+    repos_loading: bool,
+) {
     use ratatui::text::Span;
 
     let mut lines = vec![];
@@ -29,7 +34,11 @@ pub fn render_all_clear(frame: &mut Frame, area: Rect, repos: &[crate::models::G
     // Repos list (top 10)
     if repos.is_empty() {
         lines.push(Line::styled(
-            "Loading repositories...",
+            if repos_loading {
+                "Loading repositories..."
+            } else {
+                "No repositories found"
+            },
             Style::default().fg(Color::Gray),
         ));
     } else {
@@ -102,6 +111,18 @@ pub fn render_all_clear(frame: &mut Frame, area: Rect, repos: &[crate::models::G
     frame.render_widget(paragraph, centered_area);
 }
 
+/// Renders a placeholder while the thread list is still being fetched
+pub fn render_threads_loading(frame: &mut Frame, area: Rect) {
+    let line = Line::styled("Loading threads...", Style::default().fg(Color::DarkGray));
+    let y_offset = area.height / 2;
+    if y_offset < area.height {
+        frame.render_widget(
+            Paragraph::new(line).alignment(Alignment::Center),
+            Rect::new(area.x, area.y + y_offset, area.width, 1),
+        );
+    }
+}
+
 /// Renders "heavy load" warning when system is under heavy load
 /// Displayed at top of status bar area
 pub fn render_heavy_load(frame: &mut Frame, area: Rect) {
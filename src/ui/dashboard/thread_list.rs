@@ -1,12 +1,13 @@
 //! Thread list component for the dashboard
 //!
-//! Renders the list of threads with a separator between need-action and autonomous threads.
+//! Renders the list of threads: pinned threads first, then need-action and
+//! autonomous threads, with a separator between the sections.
 
 use ratatui::{layout::Rect, style::Style, text::Span, Frame};
 
 use super::states;
 use super::thread_row;
-use super::{RenderContext, ThreadView};
+use super::{RenderContext, ThreadListLayout, ThreadListPanel, ThreadView};
 
 // ============================================================================
 // Constants
@@ -18,6 +19,9 @@ const MIN_HEIGHT: u16 = 5;
 /// Maximum number of need-action threads to display before showing "+ N more"
 const MAX_NEED_ACTION_DISPLAY: usize = 5;
 
+/// Maximum number of pinned threads to display before showing "+ N more"
+const MAX_PINNED_DISPLAY: usize = 5;
+
 /// Height of each thread row (title + directory + spacing)
 const ROW_HEIGHT: u16 = 3;
 
@@ -37,6 +41,9 @@ const THREAD_LIST_WIDTH_PERCENT: f32 = 0.915;
 ///
 /// Split view showing all threads:
 /// ```text
+/// [pinned threads - max 5, in manual order]
+/// [+ N more if needed]
+/// [separator line --------]
 /// [need_action threads - max 5]
 /// [+ N more if needed]
 /// [separator line --------]
@@ -44,21 +51,27 @@ const THREAD_LIST_WIDTH_PERCENT: f32 = 0.915;
 /// [+ N more if needed]
 /// ```
 ///
+/// Each section starts at its offset in `ctx.scroll` (clamped to the
+/// section's last page).
+///
 /// # Arguments
 /// * `frame` - The ratatui frame to render into
 /// * `area` - The rectangle area allocated for the thread list
 /// * `ctx` - The render context containing thread views
-pub fn render(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
+///
+/// # Returns
+/// The rendered sections, for mouse hit-testing
+pub fn render(frame: &mut Frame, area: Rect, ctx: &RenderContext) -> ThreadListLayout {
     // Check minimum height requirement
     if area.height < MIN_HEIGHT {
-        return;
+        return ThreadListLayout::default();
     }
 
     // Calculate centered area with 84% width
     let centered_area = calculate_centered_area(area);
 
     // Always use split view - no filter modes
-    render_split_view(frame, centered_area, ctx);
+    render_split_view(frame, centered_area, ctx)
 }
 
 /// Calculate a horizontally centered area with 84% width
@@ -76,38 +89,100 @@ fn calculate_centered_area(area: Rect) -> Rect {
 // Split View
 // ============================================================================
 
-/// Render split view with need-action and autonomous sections
+/// Render the pinned section at the top of the list
+///
+/// Pinned threads keep their manual order and are not scrolled. Returns the
+/// area left below the section (all of `area` when nothing is pinned).
+fn render_pinned_section(
+    frame: &mut Frame,
+    area: Rect,
+    pinned: &[&ThreadView],
+    ctx: &RenderContext,
+) -> Rect {
+    if pinned.is_empty() {
+        return area;
+    }
+
+    let mut y = area.y;
+    for thread in pinned.iter().take(MAX_PINNED_DISPLAY) {
+        if y + ROW_HEIGHT > area.bottom() {
+            break;
+        }
+        let row_rect = Rect::new(area.x, y, area.width, ROW_HEIGHT);
+        thread_row::render(frame, row_rect, thread, ctx);
+        y += ROW_HEIGHT;
+    }
+
+    if pinned.len() > MAX_PINNED_DISPLAY && y < area.bottom() {
+        frame.render_widget(
+            Span::styled(
+                format!("+ {} more pinned", pinned.len() - MAX_PINNED_DISPLAY),
+                Style::default(),
+            ),
+            Rect::new(area.x + 2, y, area.width.saturating_sub(2), 1),
+        );
+        y += 1;
+    }
+
+    if y < area.bottom() {
+        let sep_width = ((area.width as f32) * SEPARATOR_WIDTH_PERCENT).max(4.0) as u16;
+        frame.render_widget(
+            Span::raw("\u{2500}".repeat(sep_width as usize)),
+            Rect::new(area.x + 2, y, sep_width, 1),
+        );
+        y += 1;
+    }
+
+    let y = y.min(area.bottom());
+    Rect::new(area.x, y, area.width, area.bottom() - y)
+}
+
+/// Render split view with pinned, need-action and autonomous sections
 fn render_split_view(
     frame: &mut Frame,
     area: Rect,
     ctx: &RenderContext,
-) {
-    // Partition threads into need_action and autonomous
+) -> ThreadListLayout {
+    // Pinned threads get their own section at the top
+    let (pinned, unpinned): (Vec<&ThreadView>, Vec<&ThreadView>) =
+        ctx.threads.iter().partition(|t| t.pinned);
+    let area = render_pinned_section(frame, area, &pinned, ctx);
+
+    // Partition the rest into need_action and autonomous
     let (need_action, autonomous): (Vec<&ThreadView>, Vec<&ThreadView>) =
-        ctx.threads.iter().partition(|t| t.needs_action);
+        unpinned.into_iter().partition(|t| t.needs_action);
 
     // Calculate layout heights (multiply by ROW_HEIGHT for multi-line rows)
-    let need_action_height = (need_action.len().min(MAX_NEED_ACTION_DISPLAY) as u16) * ROW_HEIGHT;
-    let separator_height: u16 = 1;
-    // Note: autonomous_height is computed dynamically based on actual separator position
-    let _autonomous_height = area
-        .height
-        .saturating_sub(need_action_height + separator_height);
+    let need_action_rows = need_action.len().min(MAX_NEED_ACTION_DISPLAY);
+    let need_action_height = (need_action_rows as u16) * ROW_HEIGHT;
+    let need_action_offset = ctx
+        .scroll
+        .need_action
+        .min(need_action.len().saturating_sub(need_action_rows));
 
     // Render need_action threads (each thread takes ROW_HEIGHT lines)
-    for (i, thread) in need_action.iter().take(MAX_NEED_ACTION_DISPLAY).enumerate() {
+    for (i, thread) in need_action
+        .iter()
+        .skip(need_action_offset)
+        .take(MAX_NEED_ACTION_DISPLAY)
+        .enumerate()
+    {
         let y_offset = (i as u16) * ROW_HEIGHT;
+        // Pinned threads above may leave less room than MAX_NEED_ACTION_DISPLAY rows
+        if y_offset + ROW_HEIGHT > area.height {
+            break;
+        }
         let row_rect = Rect::new(area.x, area.y + y_offset, area.width, ROW_HEIGHT);
         thread_row::render(frame, row_rect, thread, ctx);
     }
 
-    // Show "+ N more" if there are more need_action threads than displayed
+    // Show "+ N more" if there are more need_action threads below the displayed ones
     let need_action_more_y = if need_action.len() > MAX_NEED_ACTION_DISPLAY {
-        let more_text = format!("+ {} more", need_action.len() - MAX_NEED_ACTION_DISPLAY);
+        let below = need_action.len() - need_action_offset - need_action_rows;
         let row_y = area.y + (MAX_NEED_ACTION_DISPLAY as u16) * ROW_HEIGHT;
-        if row_y < area.bottom() {
+        if row_y < area.bottom() && below > 0 {
             frame.render_widget(
-                Span::styled(more_text, Style::default()),
+                Span::styled(format!("+ {} more", below), Style::default()),
                 Rect::new(area.x + 2, row_y, area.width.saturating_sub(2), 1),
             );
         }
@@ -116,6 +191,17 @@ fn render_split_view(
         area.y + need_action_height
     };
 
+    let need_action_panel = (!need_action.is_empty()).then(|| ThreadListPanel {
+        area: Rect::new(
+            area.x,
+            area.y,
+            area.width,
+            need_action_more_y.min(area.bottom()).saturating_sub(area.y),
+        ),
+        total: need_action.len(),
+        visible: need_action_rows,
+    });
+
     // Render separator line "────────" only if there are need_action threads
     let sep_y = need_action_more_y;
     let autonomous_start_y = if !need_action.is_empty() {
@@ -133,44 +219,71 @@ fn render_split_view(
         // No divider when no need_action threads - start autonomous from top
         area.y
     };
-    let available_autonomous_rows = area.bottom().saturating_sub(autonomous_start_y) / ROW_HEIGHT;
-
-    for (i, thread) in autonomous
+    let available_autonomous_rows =
+        (area.bottom().saturating_sub(autonomous_start_y) / ROW_HEIGHT) as usize;
+    let autonomous_offset = ctx
+        .scroll
+        .threads
+        .min(autonomous.len().saturating_sub(available_autonomous_rows));
+
+    let shown = autonomous
         .iter()
-        .take(available_autonomous_rows as usize)
-        .enumerate()
-    {
+        .skip(autonomous_offset)
+        .take(available_autonomous_rows);
+    for (i, thread) in shown.enumerate() {
         let y_offset = (i as u16) * ROW_HEIGHT;
         let row_rect = Rect::new(area.x, autonomous_start_y + y_offset, area.width, ROW_HEIGHT);
         thread_row::render(frame, row_rect, thread, ctx);
     }
 
-    // Show "+ N more" if there are more autonomous threads than displayed
-    if autonomous.len() > available_autonomous_rows as usize {
-        let more_text = format!(
-            "+ {} more",
-            autonomous.len() - available_autonomous_rows as usize
-        );
+    // Show "+ N more" if there are more autonomous threads below the displayed ones
+    let below = autonomous
+        .len()
+        .saturating_sub(autonomous_offset + available_autonomous_rows);
+    if below > 0 {
         let row_y = area.bottom().saturating_sub(1);
         if row_y >= autonomous_start_y {
             frame.render_widget(
-                Span::styled(more_text, Style::default()),
+                Span::styled(format!("+ {} more", below), Style::default()),
                 Rect::new(area.x + 2, row_y, area.width.saturating_sub(2), 1),
             );
         }
     }
 
-    // Special state: "all clear" when no need_action threads AND no autonomous threads
-    if need_action.is_empty() && autonomous.is_empty() {
-        // Calculate the area available for the "all clear" message
-        // (from top of area to separator line)
-        let need_action_area = Rect::new(
-            area.x,
-            area.y,
-            area.width,
-            need_action_more_y.saturating_sub(area.y),
-        );
-        states::render_all_clear(frame, need_action_area, ctx.repos);
+    let threads_panel = (!autonomous.is_empty() && autonomous_start_y < area.bottom()).then(|| {
+        ThreadListPanel {
+            area: Rect::new(
+                area.x,
+                autonomous_start_y,
+                area.width,
+                area.bottom() - autonomous_start_y,
+            ),
+            total: autonomous.len(),
+            visible: available_autonomous_rows,
+        }
+    });
+
+    // Special state: "all clear" when there are no threads in any section
+    if pinned.is_empty() && need_action.is_empty() && autonomous.is_empty() {
+        if ctx.loading.threads {
+            // Nothing else is drawn yet, so center it in the whole list
+            states::render_threads_loading(frame, area);
+        } else {
+            // Calculate the area available for the "all clear" message
+            // (from top of area to separator line)
+            let need_action_area = Rect::new(
+                area.x,
+                area.y,
+                area.width,
+                need_action_more_y.saturating_sub(area.y),
+            );
+            states::render_all_clear(frame, need_action_area, ctx.repos, ctx.loading.repos);
+        }
+    }
+
+    ThreadListLayout {
+        need_action: need_action_panel,
+        threads: threads_panel,
     }
 }
 
@@ -488,4 +601,130 @@ mod tests {
             "Expected all clear to NOT show when both lists have threads"
         );
     }
+
+    // -------------------- Scrolling Tests --------------------
+
+    #[test]
+    fn test_render_applies_scroll_offset_and_reports_panels() {
+        use crate::ui::dashboard::{RenderContext, ThreadListScroll};
+        use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+
+        let threads: Vec<ThreadView> = (0..10)
+            .map(|i| {
+                make_thread(
+                    &i.to_string(),
+                    &format!("Thread {}", i),
+                    false,
+                    ThreadStatus::Done,
+                )
+            })
+            .collect();
+        let aggregate = crate::models::dashboard::Aggregate::default();
+        let system_stats = crate::view_state::SystemStats::default();
+        let theme = crate::view_state::Theme::default();
+        let ctx = RenderContext::new(&threads, &aggregate, &system_stats, &theme, &[])
+            .with_scroll(ThreadListScroll {
+                need_action: 0,
+                threads: 2,
+            });
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+        let mut layout = Default::default();
+        terminal
+            .draw(|frame| layout = super::render(frame, Rect::new(0, 0, 80, 12), &ctx))
+            .unwrap();
+
+        assert!(layout.need_action.is_none());
+        let panel = layout.threads.unwrap();
+        assert_eq!(panel.total, 10);
+        assert_eq!(panel.visible, 4);
+        assert_eq!(panel.max_offset(), 6);
+
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Thread 2"));
+        assert!(!screen.contains("Thread 0"));
+        assert!(!screen.contains("Thread 1"));
+        assert!(screen.contains("+ 4 more"));
+    }
+
+    #[test]
+    fn test_render_lists_pinned_section_above_need_action() {
+        use crate::ui::dashboard::RenderContext;
+        use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+
+        let threads = vec![
+            make_thread("1", "Waiting Thread", true, ThreadStatus::Waiting),
+            make_thread("2", "Pinned Thread", false, ThreadStatus::Done).with_pinned(true),
+            make_thread("3", "Idle Thread", false, ThreadStatus::Done),
+        ];
+        let aggregate = crate::models::dashboard::Aggregate::default();
+        let system_stats = crate::view_state::SystemStats::default();
+        let theme = crate::view_state::Theme::default();
+        let ctx = RenderContext::new(&threads, &aggregate, &system_stats, &theme, &[]);
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 16)).unwrap();
+        let mut layout = Default::default();
+        terminal
+            .draw(|frame| layout = super::render(frame, Rect::new(0, 0, 80, 16), &ctx))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row_of = |text: &str| {
+            (0..buffer.area.height).find(|&y| {
+                let row: String = (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect();
+                row.contains(text)
+            })
+        };
+        assert_eq!(row_of("Pinned Thread"), Some(0));
+        assert!(row_of("Waiting Thread").unwrap() > 3);
+        assert!(row_of("Idle Thread").unwrap() > row_of("Waiting Thread").unwrap());
+
+        // Pinned threads sit above the scrollable sections
+        assert_eq!(layout.need_action.unwrap().area.y, 4);
+        assert_eq!(layout.threads.unwrap().total, 1);
+    }
+
+    #[test]
+    fn test_render_marks_selected_need_action_thread() {
+        use crate::ui::dashboard::RenderContext;
+        use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+
+        let threads = vec![
+            make_thread("1", "First Waiting", true, ThreadStatus::Waiting),
+            make_thread("2", "Second Waiting", true, ThreadStatus::Waiting),
+        ];
+        let aggregate = crate::models::dashboard::Aggregate::default();
+        let system_stats = crate::view_state::SystemStats::default();
+        let theme = crate::view_state::Theme::default();
+        let ctx = RenderContext::new(&threads, &aggregate, &system_stats, &theme, &[])
+            .with_selected_needs_action(Some("2"));
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+        terminal
+            .draw(|frame| {
+                super::render(frame, Rect::new(0, 0, 80, 12), &ctx);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        };
+        // The card is centered, so rows start after its left padding
+        assert!(row(0).trim_start().starts_with("First Waiting"));
+        let selected = row(3);
+        assert!(selected.trim_start().starts_with("\u{25b6} Second Waiting"));
+        let marker_x = selected.chars().take_while(|c| *c == ' ').count() as u16;
+        assert_eq!(buffer[(marker_x, 3)].fg, theme.accent);
+    }
 }
@@ -24,9 +24,11 @@ use ratatui::{
     style::{Modifier, Style},
     Frame,
 };
+use unicode_width::UnicodeWidthChar;
 
 use crate::models::dashboard::{ThreadStatus, WaitingFor};
 use crate::ui::dashboard::{RenderContext, ThreadMode, ThreadView};
+use crate::ui::format_token_count;
 
 // ============================================================================
 // Public API
@@ -60,6 +62,32 @@ pub fn render(
     }
 }
 
+/// Glyph shown before the title of pinned threads
+const PIN_GLYPH: &str = "📌";
+
+/// Marker before the title of the needs-action thread the keys act on
+const SELECTED_GLYPH: &str = "\u{25b6}";
+
+/// Mute glyph shown before muted thread titles
+const MUTE_GLYPH: &str = "🔕";
+
+/// Title column text, prefixed with the pin and mute glyphs that apply
+fn title_text(thread: &ThreadView, title_width: u16) -> String {
+    let mut max_len = title_width.saturating_sub(1) as usize;
+    let mut text = String::new();
+    for glyph in [(thread.pinned, PIN_GLYPH), (thread.muted, MUTE_GLYPH)]
+        .into_iter()
+        .filter_map(|(shown, glyph)| shown.then_some(glyph))
+    {
+        // Each glyph takes two columns plus a space
+        text.push_str(glyph);
+        text.push(' ');
+        max_len = max_len.saturating_sub(3);
+    }
+    text.push_str(&truncate(&thread.title, max_len));
+    text
+}
+
 /// Render an action thread row (needs user input)
 ///
 /// Layout (line 1): Title (42%) | Mode (8%) | Status (25%) | Actions (25%)
@@ -81,9 +109,26 @@ fn render_action_thread(
     let mut x = area.x;
     let y = area.y;
 
-    // Title column (bold)
-    let title_text = truncate(&thread.title, title_width.saturating_sub(1) as usize);
-    let title_style = Style::default().add_modifier(Modifier::BOLD);
+    // Title column (bold); the selected needs-action thread is marked and
+    // accented so it's clear which prompt y/a/n answer
+    let selected = ctx.selected_needs_action == Some(thread.id.as_str());
+    let (title_text, title_style) = if selected {
+        (
+            format!(
+                "{} {}",
+                SELECTED_GLYPH,
+                title_text(thread, title_width.saturating_sub(2))
+            ),
+            Style::default()
+                .fg(ctx.theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        (
+            title_text(thread, title_width),
+            Style::default().add_modifier(Modifier::BOLD),
+        )
+    };
     render_text(buf, x, y, &title_text, title_style, area);
     x += title_width;
 
@@ -109,6 +154,12 @@ fn render_action_thread(
         let dir_text = truncate(&thread.repository, area.width.saturating_sub(4) as usize);
         let dir_style = Style::default().fg(ctx.theme.dim);
         render_text(buf, area.x + 2, area.y + 1, &dir_text, dir_style, area);
+
+        let unread_x = area.x + 2 + dir_text.chars().count() as u16 + 2;
+        let tokens_x = render_unread(buf, unread_x, area, thread, ctx);
+        let hint_x = render_tokens(buf, tokens_x, area, thread, ctx);
+        let preview_x = render_link_hint(buf, hint_x, area, thread, ctx);
+        render_preview(buf, preview_x, area, thread, ctx);
     }
 
     // Action buttons (right-aligned) - must be last as it takes frame
@@ -137,8 +188,12 @@ fn render_autonomous_thread(
     let y = area.y;
 
     // Title column (bold)
-    let title_text = truncate(&thread.title, title_width.saturating_sub(1) as usize);
-    let title_style = Style::default().add_modifier(Modifier::BOLD);
+    let title_text = title_text(thread, title_width);
+    let title_style = if thread.muted {
+        Style::default().add_modifier(Modifier::DIM)
+    } else {
+        Style::default().add_modifier(Modifier::BOLD)
+    };
     render_text(buf, x, y, &title_text, title_style, area);
     x += title_width;
 
@@ -167,11 +222,147 @@ fn render_autonomous_thread(
     let time_style = Style::default().fg(ctx.theme.dim);
     render_text(buf, x, y, &time_text, time_style, area);
 
-    // Render directory on second line, indented 2 spaces
+    // Render directory on second line, indented 2 spaces, followed by the
+    // outcome summary of the last long run (if any)
     if area.height >= 2 {
         let dir_text = truncate(&thread.repository, area.width.saturating_sub(4) as usize);
         let dir_style = Style::default().fg(ctx.theme.dim);
         render_text(buf, area.x + 2, area.y + 1, &dir_text, dir_style, area);
+
+        let unread_x = area.x + 2 + dir_text.chars().count() as u16 + 2;
+        let tokens_x = render_unread(buf, unread_x, area, thread, ctx);
+        let mut hint_x = render_tokens(buf, tokens_x, area, thread, ctx);
+        if let Some(ref summary) = thread.summary {
+            let summary_x = hint_x;
+            let available = (area.x + area.width).saturating_sub(summary_x + 1);
+            if available > 3 {
+                let summary_text = truncate(summary, available as usize);
+                let summary_style = Style::default().fg(summary_color(thread, ctx));
+                render_text(buf, summary_x, area.y + 1, &summary_text, summary_style, area);
+                hint_x = summary_x + summary_text.chars().count() as u16 + 2;
+            }
+        }
+        let preview_x = render_link_hint(buf, hint_x, area, thread, ctx);
+        render_preview(buf, preview_x, area, thread, ctx);
+    }
+}
+
+/// Marker for messages that arrived while the thread wasn't visible
+fn unread_text(count: usize) -> Option<String> {
+    (count > 0).then(|| format!("\u{25CF} {} new", count))
+}
+
+/// Render the thread's unread marker (e.g., "● 12 new") on the directory
+/// line, starting at `x`, if there is room
+///
+/// Returns where the next item on the line starts.
+fn render_unread(
+    buf: &mut ratatui::buffer::Buffer,
+    x: u16,
+    area: Rect,
+    thread: &ThreadView,
+    ctx: &RenderContext,
+) -> u16 {
+    let count = ctx
+        .unread
+        .and_then(|unread| unread.get(&thread.id).copied())
+        .unwrap_or(0);
+    let Some(text) = unread_text(count) else {
+        return x;
+    };
+    let available = (area.x + area.width).saturating_sub(x + 1);
+    let width = text.chars().count() as u16;
+    if available >= width {
+        let unread_style = Style::default()
+            .fg(ctx.theme.accent)
+            .add_modifier(Modifier::BOLD);
+        render_text(buf, x, area.y + 1, &text, unread_style, area);
+        return x + width + 2;
+    }
+    x
+}
+
+/// Tokens the thread used this session
+fn tokens_text(total: u64) -> Option<String> {
+    (total > 0).then(|| format!("{} tokens", format_token_count(total)))
+}
+
+/// Render the thread's session token count (e.g., "12.5k tokens") on the
+/// directory line, starting at `x`, if there is room
+///
+/// Returns where the next item on the line starts.
+fn render_tokens(
+    buf: &mut ratatui::buffer::Buffer,
+    x: u16,
+    area: Rect,
+    thread: &ThreadView,
+    ctx: &RenderContext,
+) -> u16 {
+    let total = ctx
+        .token_usage
+        .and_then(|usage| usage.get(&thread.id))
+        .map_or(0, |usage| usage.total());
+    let Some(text) = tokens_text(total) else {
+        return x;
+    };
+    let available = (area.x + area.width).saturating_sub(x + 1);
+    let width = text.chars().count() as u16;
+    if available >= width {
+        let tokens_style = Style::default().fg(ctx.theme.dim);
+        render_text(buf, x, area.y + 1, &text, tokens_style, area);
+        return x + width + 2;
+    }
+    x
+}
+
+/// Render the thread's link grouping hint (e.g., "⛔ blocked by Fix auth") on
+/// the directory line, starting at `x`, if there is room
+///
+/// Returns where the next item on the line starts.
+fn render_link_hint(
+    buf: &mut ratatui::buffer::Buffer,
+    x: u16,
+    area: Rect,
+    thread: &ThreadView,
+    ctx: &RenderContext,
+) -> u16 {
+    let Some(ref hint) = thread.link_hint else {
+        return x;
+    };
+    let available = (area.x + area.width).saturating_sub(x + 1);
+    if available > 3 {
+        let hint_text = truncate(hint, available as usize);
+        let hint_style = Style::default().fg(ctx.theme.waiting);
+        render_text(buf, x, area.y + 1, &hint_text, hint_style, area);
+        return x + hint_text.chars().count() as u16 + 2;
+    }
+    x
+}
+
+/// Narrowest room worth showing a message preview in
+const MIN_PREVIEW_WIDTH: u16 = 12;
+
+/// Render a preview of the thread's latest message at the end of the
+/// directory line, starting at `x`, if there is room
+fn render_preview(
+    buf: &mut ratatui::buffer::Buffer,
+    x: u16,
+    area: Rect,
+    thread: &ThreadView,
+    ctx: &RenderContext,
+) {
+    let Some(previews) = ctx.previews else {
+        return;
+    };
+    let available = (area.x + area.width).saturating_sub(x + 1);
+    if available < MIN_PREVIEW_WIDTH {
+        return;
+    }
+    if let Some(preview) = previews.truncated(&thread.id, available as usize) {
+        let preview_style = Style::default()
+            .fg(ctx.theme.dim)
+            .add_modifier(Modifier::ITALIC);
+        render_text(buf, x, area.y + 1, &preview, preview_style, area);
     }
 }
 
@@ -206,11 +397,21 @@ fn activity_color(thread: &ThreadView, ctx: &RenderContext) -> ratatui::style::C
     }
 }
 
+/// Get the color for the run summary based on thread status
+fn summary_color(thread: &ThreadView, ctx: &RenderContext) -> ratatui::style::Color {
+    match thread.status {
+        ThreadStatus::Error => ctx.theme.error,
+        _ => ctx.theme.success,
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
 /// Render text at a specific position
+///
+/// Wide characters (like the pin glyph) advance two columns.
 fn render_text(
     buf: &mut ratatui::buffer::Buffer,
     x: u16,
@@ -219,11 +420,13 @@ fn render_text(
     style: Style,
     area: Rect,
 ) {
-    for (offset, ch) in text.chars().enumerate() {
-        let pos_x = x + offset as u16;
-        if pos_x < area.x + area.width {
-            buf[(pos_x, y)].set_char(ch).set_style(style);
+    let mut pos_x = x;
+    for ch in text.chars() {
+        if pos_x >= area.x + area.width {
+            break;
         }
+        buf[(pos_x, y)].set_char(ch).set_style(style);
+        pos_x += ch.width().unwrap_or(1).max(1) as u16;
     }
 }
 
@@ -551,6 +754,48 @@ mod tests {
         assert_eq!(result, "日本...");
     }
 
+    #[test]
+    fn test_title_text_prefixes_pin_glyph() {
+        let thread = ThreadView::new(
+            "t".to_string(),
+            "Release checklist".to_string(),
+            "~/repo".to_string(),
+        );
+        assert_eq!(title_text(&thread, 40), "Release checklist");
+
+        let pinned = thread.with_pinned(true);
+        assert_eq!(title_text(&pinned, 40), "📌 Release checklist");
+        // Glyph (2 columns) + space + title fit in the column minus padding
+        assert_eq!(title_text(&pinned, 12), "📌 Relea...");
+    }
+
+    #[test]
+    fn test_title_text_prefixes_mute_glyph() {
+        let thread = ThreadView::new(
+            "t".to_string(),
+            "Release checklist".to_string(),
+            "~/repo".to_string(),
+        );
+        let muted = thread.with_muted(true);
+        assert_eq!(title_text(&muted, 40), "🔕 Release checklist");
+
+        let both = muted.with_pinned(true);
+        assert_eq!(title_text(&both, 40), "📌 🔕 Release checklist");
+        assert_eq!(title_text(&both, 15), "📌 🔕 Relea...");
+    }
+
+    #[test]
+    fn test_unread_text() {
+        assert_eq!(unread_text(0), None);
+        assert_eq!(unread_text(12).as_deref(), Some("\u{25CF} 12 new"));
+    }
+
+    #[test]
+    fn test_tokens_text() {
+        assert_eq!(tokens_text(0), None);
+        assert_eq!(tokens_text(12_500).as_deref(), Some("12.5k tokens"));
+    }
+
     // -------------------- Button Generation Tests --------------------
 
     #[test]
@@ -573,6 +818,10 @@ mod tests {
             needs_action: true,
             current_operation: None,
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         // Verify the match would produce 3 buttons for permission
@@ -615,6 +864,10 @@ mod tests {
             needs_action: true,
             current_operation: None,
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         // Verify the match would produce 2 buttons for plan approval
@@ -652,6 +905,10 @@ mod tests {
             needs_action: true,
             current_operation: None,
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         // Verify the match would produce 1 button for user input
@@ -684,6 +941,10 @@ mod tests {
             needs_action: false,
             current_operation: None,
             activity_text: Some("done".to_string()),
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         // Verify the match would produce 1 button for done status
@@ -719,6 +980,10 @@ mod tests {
             needs_action: false,
             current_operation: Some("Running tests".to_string()),
             activity_text: Some("Running tests".to_string()),
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         // Verify running status produces no buttons
@@ -767,6 +1032,10 @@ mod tests {
             needs_action: false,
             current_operation: Some("Edit: handlers.rs".to_string()),
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         assert_eq!(compute_activity_text(&thread), "Edit: handlers.rs");
@@ -789,6 +1058,10 @@ mod tests {
             needs_action: false,
             current_operation: None,
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         assert_eq!(compute_activity_text(&thread), "Thinking...");
@@ -811,6 +1084,10 @@ mod tests {
             needs_action: false,
             current_operation: None,
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         assert_eq!(compute_activity_text(&thread), "ready");
@@ -833,6 +1110,10 @@ mod tests {
             needs_action: false,
             current_operation: None,
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         assert_eq!(compute_activity_text(&thread), "ready");
@@ -855,6 +1136,10 @@ mod tests {
             needs_action: false,
             current_operation: None,
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         assert_eq!(compute_activity_text(&thread), "error");
@@ -877,6 +1162,10 @@ mod tests {
             needs_action: false,
             current_operation: None,
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         assert_eq!(compute_activity_text(&thread), "waiting");
@@ -902,6 +1191,10 @@ mod tests {
             needs_action: false,
             current_operation: Some("Edit: main.rs".to_string()),
             activity_text: Some("Edit: main.rs".to_string()),
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         // Running threads use accent color
@@ -926,6 +1219,10 @@ mod tests {
             needs_action: false,
             current_operation: None,
             activity_text: Some("done".to_string()),
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         // Done threads use dim color
@@ -950,6 +1247,10 @@ mod tests {
             needs_action: false,
             current_operation: None,
             activity_text: Some("error".to_string()),
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         // Error threads use error color
@@ -974,6 +1275,10 @@ mod tests {
             needs_action: false,
             current_operation: None,
             activity_text: Some("idle".to_string()),
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         };
 
         // Idle threads use dim color
@@ -1011,6 +1316,13 @@ mod tests {
             question_state: None,
             question_timer_secs: None,
             repos: REPOS,
+            scroll: Default::default(),
+            unread_notifications: 0,
+            previews: None,
+            unread: None,
+            token_usage: None,
+            selected_needs_action: None,
+            loading: Default::default(),
         }
     }
 }
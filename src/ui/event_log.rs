@@ -0,0 +1,161 @@
+//! Raw event log rendering
+//!
+//! Shown by `/events`: the SSE and WebSocket events received for the active
+//! thread, newest first, one per line with arrival time, transport, type,
+//! payload size and the start of the payload.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, EventRecord, EventSource};
+
+use super::helpers::truncate_string;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Width of the event type column
+const EVENT_TYPE_WIDTH: usize = 22;
+
+/// Compact payload size (`512 B`, `3.4 KB`)
+fn format_payload_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
+
+/// One log line: time, transport, type, size and payload excerpt
+fn event_line(record: &EventRecord, width: usize) -> Line<'static> {
+    let time = record
+        .received_at
+        .with_timezone(&chrono::Local)
+        .format("%H:%M:%S%.3f");
+    let source_style = match record.source {
+        EventSource::Sse => Style::default().fg(COLOR_ACCENT),
+        EventSource::Ws => Style::default().fg(COLOR_HEADER),
+    };
+    // Time (13) + source (4) + type + size (10)
+    let payload_width = width.saturating_sub(27 + EVENT_TYPE_WIDTH);
+    let payload = record.payload.replace(['\n', '\r'], " ");
+
+    Line::from(vec![
+        Span::styled(format!("{} ", time), Style::default().fg(COLOR_DIM)),
+        Span::styled(format!("{:<3} ", record.source.label()), source_style),
+        Span::raw(format!(
+            "{:<width$}",
+            truncate_string(&record.event_type, EVENT_TYPE_WIDTH - 1),
+            width = EVENT_TYPE_WIDTH
+        )),
+        Span::styled(
+            format!("{:>9} ", format_payload_size(record.payload_size)),
+            Style::default().fg(COLOR_DIM),
+        ),
+        Span::styled(
+            truncate_string(&payload, payload_width),
+            Style::default().fg(COLOR_DIM),
+        ),
+    ])
+}
+
+/// Render the event log as a centered overlay
+pub fn render_event_log(frame: &mut Frame, app: &App) {
+    let Some(ref view) = app.event_log_view else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = 120u16.min(area.width.saturating_sub(4));
+    let dialog_height = area.height.saturating_sub(4).max(6);
+
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let count = app.event_log.len(&view.thread_id);
+    let thread_title = app
+        .cache
+        .get_thread(&view.thread_id)
+        .map(|t| t.title.as_str())
+        .unwrap_or(view.thread_id.as_str());
+    let block = Block::default()
+        .title(Span::styled(
+            format!(
+                " Events: {} ({}) ",
+                truncate_string(thread_title, 40),
+                count
+            ),
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(2),
+    };
+    // Leave room for the blank line and the hint line
+    let visible_rows = (inner.height as usize).saturating_sub(2);
+
+    let mut lines: Vec<Line> = if count == 0 {
+        vec![Line::from(Span::styled(
+            "  No events received for this thread yet",
+            Style::default().fg(COLOR_DIM),
+        ))]
+    } else {
+        app.event_log
+            .events(&view.thread_id)
+            .rev()
+            .skip(view.scroll)
+            .take(visible_rows)
+            .map(|record| event_line(record, inner.width as usize))
+            .collect()
+    };
+    lines.resize(visible_rows.max(lines.len()), Line::from(""));
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("[↑↓] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("scroll  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[PgUp/PgDn] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("page  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("close", Style::default().fg(COLOR_DIM)),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_line_columns() {
+        let record = EventRecord::new(EventSource::Ws, "thread_updated", "{\"a\":\n1}");
+        let text: String = event_line(&record, 100)
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert!(text.contains("ws  thread_updated"));
+        assert!(text.contains("8 B {\"a\": 1}"));
+        assert_eq!(format_payload_size(2048), "2.0 KB");
+    }
+}
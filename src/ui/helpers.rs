@@ -7,6 +7,8 @@
 
 use ratatui::layout::Rect;
 use serde_json::Value;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Spinner frames for tool status animation
 pub const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
@@ -55,28 +57,106 @@ pub fn extract_short_model_name(full_name: &str) -> &str {
     }
 }
 
-/// Truncate a string to approximately max_len bytes, adding "..." if truncated.
-/// Safely handles UTF-8 by finding the nearest char boundary.
+/// Truncate a string to max_len display columns, adding "..." if truncated.
+///
+/// Cuts between grapheme clusters, so emoji sequences, CJK characters and
+/// combining marks are never split.
 pub fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
+    if display_width(s) <= max_len {
         s.to_string()
     } else {
-        let target = max_len.saturating_sub(3);
-        let end = find_char_boundary(s, target);
-        format!("{}...", &s[..end])
+        format!("{}...", prefix_within_width(s, max_len.saturating_sub(3)))
     }
 }
 
-/// Find the nearest valid UTF-8 char boundary at or before the given byte index.
-pub fn find_char_boundary(s: &str, index: usize) -> usize {
-    if index >= s.len() {
-        return s.len();
+/// Compact age for list rows: "now", "5m", "3h", "2d", "4mo"
+pub fn format_compact_age(age: chrono::Duration) -> String {
+    if age.num_seconds() < 60 {
+        "now".to_string()
+    } else if age.num_minutes() < 60 {
+        format!("{}m", age.num_minutes())
+    } else if age.num_hours() < 24 {
+        format!("{}h", age.num_hours())
+    } else if age.num_days() < 30 {
+        format!("{}d", age.num_days())
+    } else {
+        format!("{}mo", age.num_days() / 30)
     }
-    let mut end = index;
-    while end > 0 && !s.is_char_boundary(end) {
-        end -= 1;
+}
+
+/// Display width of a string in terminal columns.
+///
+/// Measured per grapheme cluster, the way ratatui fills the buffer, so wide
+/// (CJK, emoji) and zero-width (combining, modifier) characters count as they
+/// render.
+pub fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+/// Longest prefix of `s` that fits in `max_width` columns, ending on a
+/// grapheme cluster boundary.
+pub fn prefix_within_width(s: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (index, grapheme) in s.grapheme_indices(true) {
+        width += grapheme.width();
+        if width > max_width {
+            return &s[..index];
+        }
+    }
+    s
+}
+
+/// Shorten a path to fit in `max_width` columns.
+///
+/// The home directory becomes `~`; if the path is still too wide, the middle
+/// is replaced with "…", keeping the first component and as many trailing
+/// components as fit. Falls back to "…" and the tail of the last component.
+pub fn shorten_path(path: &str, home: Option<&str>, max_width: usize) -> String {
+    let path = match home.filter(|home| !home.is_empty() && *home != "/") {
+        Some(home) if path == home => "~".to_string(),
+        Some(home) if path.starts_with(home) && path[home.len()..].starts_with('/') => {
+            format!("~{}", &path[home.len()..])
+        }
+        _ => path.to_string(),
+    };
+    if display_width(&path) <= max_width {
+        return path;
+    }
+
+    // Head: "~" or the first component, with its slash
+    let head_end = path
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == '/')
+        .map(|(i, _)| i + 1)
+        .unwrap_or(0);
+    let head = &path[..head_end];
+    let budget = max_width.saturating_sub(display_width(head) + 1);
+    // Longest tail starting at a component boundary that fits
+    let tail = path[head_end..]
+        .match_indices('/')
+        .map(|(i, _)| &path[head_end + i + 1..])
+        .find(|tail| !tail.is_empty() && display_width(tail) <= budget);
+    if let (Some(tail), false) = (tail, head.is_empty()) {
+        return format!("{}…{}", head, tail);
+    }
+
+    let graphemes: Vec<&str> = path.graphemes(true).collect();
+    let mut width = 0;
+    let mut start = graphemes.len();
+    while start > 0 {
+        let next = width + graphemes[start - 1].width();
+        if next + 1 > max_width {
+            break;
+        }
+        width = next;
+        start -= 1;
+    }
+    if max_width == 0 {
+        String::new()
+    } else {
+        format!("…{}", graphemes[start..].concat())
     }
-    end
 }
 
 /// Format tool arguments into a human-readable display string
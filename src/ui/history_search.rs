@@ -0,0 +1,111 @@
+//! Input history search overlay rendering
+//!
+//! Shows the Ctrl+R query and the best matching history entries in a
+//! dropdown anchored above the input, most relevant first.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIALOG_BG, COLOR_DIM, COLOR_HEADER};
+
+/// Marker appended to entries that span several lines
+const MULTILINE_MARKER: &str = " ⏎";
+
+/// One-line label for a history entry: its first line, marked if more follow
+pub fn history_entry_label(entry: &str) -> String {
+    let mut lines = entry.lines();
+    let first = lines.next().unwrap_or_default();
+    if lines.next().is_some() {
+        format!("{}{}", first, MULTILINE_MARKER)
+    } else {
+        first.to_string()
+    }
+}
+
+/// Render the history search dropdown above `input_area`
+pub fn render_history_search(frame: &mut Frame, app: &App, input_area: Rect) {
+    let search = &app.history_search;
+    if !search.active {
+        return;
+    }
+
+    let area = frame.area();
+    let rows = search.matches.len().max(1) as u16;
+    let dialog_width = 60.min(input_area.width);
+    let dialog_height = (rows + 2).min(area.height.saturating_sub(6));
+
+    let dialog_area = Rect {
+        x: input_area.x,
+        y: input_area.y.saturating_sub(dialog_height),
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            format!(" history: {}_ ", search.query),
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER))
+        .style(Style::default().bg(COLOR_DIALOG_BG));
+
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(2),
+    };
+
+    let lines: Vec<Line> = if search.matches.is_empty() {
+        vec![Line::from(Span::styled(
+            "  No matching history",
+            Style::default().fg(COLOR_DIM),
+        ))]
+    } else {
+        search
+            .matches
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| {
+                let label = history_entry_label(entry);
+                if idx == search.selected {
+                    let style = Style::default()
+                        .fg(COLOR_ACCENT)
+                        .add_modifier(Modifier::BOLD);
+                    Line::from(vec![Span::styled("▸ ", style), Span::styled(label, style)])
+                } else {
+                    Line::from(vec![Span::raw("  "), Span::raw(label)])
+                }
+            })
+            .collect()
+    };
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_entry_label() {
+        assert_eq!(history_entry_label("git status"), "git status");
+        assert_eq!(history_entry_label("first\nsecond"), "first ⏎");
+        assert_eq!(history_entry_label(""), "");
+    }
+}
@@ -1,6 +1,6 @@
 //! Folder chip rendering for input area.
 //!
-//! Provides the visual "chip" that displays the selected folder name.
+//! Provides the visual "chips" that display the attached folder names.
 
 use ratatui::{
     buffer::Buffer,
@@ -45,6 +45,15 @@ pub fn calculate_chip_width(folder_name: &str) -> u16 {
     (3 + display_name.len() + 1) as u16
 }
 
+/// Calculate the width of a row of folder chips in columns, including the
+/// space after each chip.
+pub fn calculate_chips_width(folder_names: &[&str]) -> u16 {
+    folder_names
+        .iter()
+        .map(|name| calculate_chip_width(name) + 1)
+        .sum()
+}
+
 /// Render the folder chip directly to the buffer.
 ///
 /// The chip is rendered at the specified position with the format: `[folder-name]`
@@ -61,6 +70,13 @@ pub fn render_folder_chip(buf: &mut Buffer, x: u16, y: u16, folder_name: &str) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_calculate_chips_width() {
+        assert_eq!(calculate_chips_width(&[]), 0);
+        // "[📁 web]" is 7 columns, "[📁 api]" 7 more, plus a space after each
+        assert_eq!(calculate_chips_width(&["web", "api"]), 16);
+    }
+
     #[test]
     fn test_format_chip_folder_name_short() {
         let name = "project";
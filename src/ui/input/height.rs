@@ -6,8 +6,9 @@
 // Input Height Constants
 // ============================================================================
 
-/// Maximum number of visible lines in the input area
-pub const MAX_INPUT_LINES: u16 = 5;
+/// Default number of visible rows in the input area before it scrolls
+/// (`input_max_rows` in ~/.spoq/config.json overrides it)
+pub const MAX_INPUT_LINES: u16 = 8;
 
 // ============================================================================
 // Input Height Calculation
@@ -17,9 +18,14 @@ pub const MAX_INPUT_LINES: u16 = 5;
 ///
 /// Returns height in rows (including borders):
 /// - Min: 3 rows (border + 1 line + border)
-/// - Max: 7 rows (border + 5 lines + border)
+/// - Max: 10 rows (border + 8 lines + border)
 pub fn calculate_input_box_height(line_count: usize) -> u16 {
-    let content_lines = (line_count as u16).clamp(1, MAX_INPUT_LINES);
+    calculate_input_box_height_with_max(line_count, MAX_INPUT_LINES)
+}
+
+/// Calculate the input box height, growing up to `max_lines` rows of text.
+pub fn calculate_input_box_height_with_max(line_count: usize, max_lines: u16) -> u16 {
+    let content_lines = line_count.clamp(1, max_lines.max(1) as usize) as u16;
     content_lines + 2 // +2 for top/bottom borders
 }
 
@@ -27,7 +33,13 @@ pub fn calculate_input_box_height(line_count: usize) -> u16 {
 ///
 /// When `has_images` is true, adds 1 row for the image chip line above the input box.
 pub fn calculate_input_area_height(line_count: usize) -> u16 {
-    calculate_input_box_height(line_count) + 1 + 2 // +1 keybinds, +2 for top/bottom padding
+    calculate_input_area_height_with_max(line_count, MAX_INPUT_LINES)
+}
+
+/// Calculate the total input area height, growing up to `max_lines` rows of text.
+pub fn calculate_input_area_height_with_max(line_count: usize, max_lines: u16) -> u16 {
+    // +1 keybinds, +2 for top/bottom padding
+    calculate_input_box_height_with_max(line_count, max_lines) + 1 + 2
 }
 
 /// Calculate the total input area height with optional image chip row.
@@ -71,27 +83,39 @@ mod tests {
             7,
             "5 lines: 5 + 2 borders = 7"
         );
+        assert_eq!(
+            calculate_input_box_height(8),
+            10,
+            "8 lines: 8 + 2 borders = 10"
+        );
     }
 
     #[test]
     fn test_calculate_input_box_height_clamped_max() {
         assert_eq!(
-            calculate_input_box_height(6),
-            7,
-            "Max 5 lines + 2 borders = 7"
+            calculate_input_box_height(9),
+            10,
+            "Max 8 lines + 2 borders = 10"
         );
         assert_eq!(
             calculate_input_box_height(10),
-            7,
-            "Max 5 lines + 2 borders = 7"
+            10,
+            "Max 8 lines + 2 borders = 10"
         );
         assert_eq!(
             calculate_input_box_height(100),
-            7,
-            "Max 5 lines + 2 borders = 7"
+            10,
+            "Max 8 lines + 2 borders = 10"
         );
     }
 
+    #[test]
+    fn test_calculate_input_box_height_configured_max() {
+        assert_eq!(calculate_input_box_height_with_max(4, 3), 5);
+        assert_eq!(calculate_input_box_height_with_max(2, 3), 4);
+        assert_eq!(calculate_input_area_height_with_max(20, 12), 17);
+    }
+
     #[test]
     fn test_calculate_input_box_height_clamped_min() {
         assert_eq!(
@@ -1,13 +1,18 @@
 //! Image chip rendering for input area.
 //!
-//! Provides visual chips that display attached image indicators above the text area.
+//! Provides visual chips that display attached image indicators above the text area
+//! (`[image 1280x720 1.2MB png]`); sent messages show `[Image #1 a3f2b1c0]` chips instead.
+//! The newest attachment also gets a half-block thumbnail below the chips so
+//! a wrong screenshot can be spotted (and discarded) before sending.
 //! Follows the same pattern as `folder_chip.rs`.
 
 use ratatui::{
     buffer::Buffer,
     style::{Color, Style},
+    text::{Line, Span},
 };
 
+use super::super::theme::COLOR_DIM;
 use crate::clipboard::ImageAttachment;
 
 // ============================================================================
@@ -24,7 +29,7 @@ pub const COLOR_IMAGE_CHIP_TEXT: Color = Color::White;
 // Image Chip Rendering
 // ============================================================================
 
-/// Format the display text for a single image chip.
+/// Format the display text for an image chip on a sent message.
 ///
 /// Returns a string like `[Image #1 a3f2b1c0]`.
 pub fn format_image_chip_text(index: usize, hash: &str) -> String {
@@ -32,6 +37,82 @@ pub fn format_image_chip_text(index: usize, hash: &str) -> String {
     format!("[Image #{} {}]", index + 1, short_hash)
 }
 
+/// Format the display text for a pending attachment in the input area.
+///
+/// Returns a string like `[image 1280x720 1.2MB png]` (attachments are sent
+/// as PNG); the dimensions are left out when the image couldn't be decoded.
+pub fn format_pending_image_chip_text(image: &ImageAttachment) -> String {
+    if image.width == 0 || image.height == 0 {
+        return format!("[image {} png]", format_byte_size(image.byte_size));
+    }
+    format!(
+        "[image {}x{} {} png]",
+        image.width,
+        image.height,
+        format_byte_size(image.byte_size)
+    )
+}
+
+/// Thumbnail rows for the newest pending image, indented like the chips.
+///
+/// The first row is followed by the image's dimensions and size and a hint
+/// for `discard_key`. Empty when the image has no preview.
+pub fn build_image_preview_lines(image: &ImageAttachment, discard_key: &str) -> Vec<Line<'static>> {
+    let Some(preview) = &image.preview else {
+        return Vec::new();
+    };
+
+    preview
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut spans = vec![Span::raw("  ")];
+            spans.extend(row.iter().map(|&([tr, tg, tb], [br, bg, bb])| {
+                Span::styled(
+                    "\u{2580}",
+                    Style::default()
+                        .fg(Color::Rgb(tr, tg, tb))
+                        .bg(Color::Rgb(br, bg, bb)),
+                )
+            }));
+            if i == 0 {
+                spans.push(Span::styled(
+                    format!(
+                        "  {}x{} \u{b7} {} \u{b7} {} to discard",
+                        image.width,
+                        image.height,
+                        format_byte_size(image.byte_size),
+                        discard_key
+                    ),
+                    Style::default().fg(COLOR_DIM),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Rows the image tray takes above the input: the chip row plus the
+/// thumbnail of the newest image.
+pub fn image_tray_height(images: &[ImageAttachment]) -> u16 {
+    match images.last() {
+        None => 0,
+        Some(image) => 1 + image.preview.as_ref().map_or(0, |p| p.rows.len() as u16),
+    }
+}
+
+/// Compact byte size: `512B`, `340KB`, `1.2MB`
+fn format_byte_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{}B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{}KB", bytes / 1024)
+    } else {
+        format!("{:.1}MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
 /// Calculate the total width of all image chips with spacing.
 ///
 /// Each chip is separated by 1 space. Leading indent of 2 spaces.
@@ -44,7 +125,7 @@ pub fn calculate_image_chips_width(images: &[ImageAttachment]) -> u16 {
         if i > 0 {
             width += 1; // space between chips
         }
-        width += format_image_chip_text(i, &img.hash).len() as u16;
+        width += format_pending_image_chip_text(img).len() as u16;
     }
     width
 }
@@ -66,7 +147,7 @@ pub fn render_image_chips(buf: &mut Buffer, x: u16, y: u16, images: &[ImageAttac
         if i > 0 {
             offset += 1; // space between chips
         }
-        let chip_text = format_image_chip_text(i, &img.hash);
+        let chip_text = format_pending_image_chip_text(img);
         let chip_len = chip_text.len() as u16;
 
         // Only render if there's room in the buffer
@@ -80,12 +161,16 @@ pub fn render_image_chips(buf: &mut Buffer, x: u16, y: u16, images: &[ImageAttac
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clipboard::ImagePreview;
 
     fn make_attachment(hash: &str) -> ImageAttachment {
         ImageAttachment {
             hash: hash.to_string(),
             base64_png: String::new(),
-            byte_size: 0,
+            byte_size: 1_258_291,
+            width: 0,
+            height: 0,
+            preview: None,
         }
     }
 
@@ -102,6 +187,52 @@ mod tests {
         assert_eq!(text, "[Image #1 a3f2b1c0]"); // Only first 8 chars
     }
 
+    #[test]
+    fn test_format_pending_image_chip_text() {
+        let mut image = make_attachment("a3f2b1c0");
+        assert_eq!(format_pending_image_chip_text(&image), "[image 1.2MB png]");
+        image.byte_size = 348_160;
+        assert_eq!(format_pending_image_chip_text(&image), "[image 340KB png]");
+        image.byte_size = 512;
+        assert_eq!(format_pending_image_chip_text(&image), "[image 512B png]");
+    }
+
+    #[test]
+    fn test_format_pending_image_chip_text_with_dimensions() {
+        let mut image = make_attachment("a3f2b1c0");
+        image.width = 1280;
+        image.height = 720;
+        assert_eq!(
+            format_pending_image_chip_text(&image),
+            "[image 1280x720 1.2MB png]"
+        );
+    }
+
+    #[test]
+    fn test_image_preview_lines_and_tray_height() {
+        let mut image = make_attachment("a3f2b1c0");
+        assert!(build_image_preview_lines(&image, "ctrl+x").is_empty());
+        assert_eq!(image_tray_height(&[]), 0);
+        assert_eq!(image_tray_height(&[image.clone()]), 1);
+
+        image.width = 2;
+        image.height = 4;
+        image.preview = Some(ImagePreview {
+            rows: vec![vec![([255, 0, 0], [0, 0, 255]); 2]; 2],
+        });
+        let lines = build_image_preview_lines(&image, "ctrl+x");
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].spans[1].content, "\u{2580}");
+        assert_eq!(lines[0].spans[1].style.fg, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(lines[0].spans[1].style.bg, Some(Color::Rgb(0, 0, 255)));
+        assert_eq!(
+            lines[0].spans.last().unwrap().content,
+            "  2x4 \u{b7} 1.2MB \u{b7} ctrl+x to discard"
+        );
+        assert_eq!(lines[1].spans.len(), 3);
+        assert_eq!(image_tray_height(&[image]), 3);
+    }
+
     #[test]
     fn test_calculate_image_chips_width_empty() {
         assert_eq!(calculate_image_chips_width(&[]), 0);
@@ -111,8 +242,8 @@ mod tests {
     fn test_calculate_image_chips_width_one() {
         let images = vec![make_attachment("a3f2b1c0")];
         let width = calculate_image_chips_width(&images);
-        // 2 (indent) + "[Image #1 a3f2b1c0]".len() = 2 + 19 = 21
-        assert_eq!(width, 21);
+        // 2 (indent) + "[image 1.2MB png]".len() = 2 + 17 = 19
+        assert_eq!(width, 19);
     }
 
     #[test]
@@ -123,7 +254,7 @@ mod tests {
             make_attachment("2c8a4e61"),
         ];
         let width = calculate_image_chips_width(&images);
-        // 2 + 19 + 1 + 19 + 1 + 19 = 61
-        assert_eq!(width, 61);
+        // 2 + 17 + 1 + 17 + 1 + 17 = 55
+        assert_eq!(width, 55);
     }
 }
@@ -3,14 +3,14 @@
 //! Provides responsive keybind hints that adapt to terminal dimensions.
 
 use ratatui::{
-    style::Style,
+    style::{Color, Style},
     text::{Line, Span},
 };
 
 use crate::app::{App, Screen};
 
 use super::super::layout::LayoutContext;
-use super::super::theme::{COLOR_ACCENT, COLOR_DIM};
+use super::super::theme::{COLOR_ACCENT, COLOR_DIM, COLOR_TOOL_SUCCESS};
 
 // ============================================================================
 // Keybind Hints
@@ -38,6 +38,26 @@ pub fn build_contextual_keybinds(app: &App) -> Line<'static> {
 pub fn build_responsive_keybinds(app: &App, ctx: &LayoutContext) -> Line<'static> {
     let mut spans = vec![Span::raw(" ")];
 
+    // Transient confirmation (e.g., "Copied 42 chars") leads the line
+    if let Some(notice) = &app.status_notice {
+        spans.push(Span::styled(
+            notice.clone(),
+            Style::default().fg(COLOR_TOOL_SUCCESS),
+        ));
+        spans.push(Span::raw(" | "));
+    }
+
+    // Actions the server hasn't confirmed yet (being resent)
+    if app.ws_unacked_messages > 0 {
+        let count = app.ws_unacked_messages;
+        let label = if count == 1 { "action" } else { "actions" };
+        spans.push(Span::styled(
+            format!("{} unsent {}", count, label),
+            Style::default().fg(Color::Yellow),
+        ));
+        spans.push(Span::raw(" | "));
+    }
+
     // Check for visible elements that need special keybinds
     let has_error = app.stream_error.is_some();
     let has_links = app.has_visible_links;
@@ -68,6 +88,8 @@ pub fn build_responsive_keybinds(app: &App, ctx: &LayoutContext) -> Line<'static
             } else {
                 spans.push(Span::raw(": dismiss error | "));
             }
+            spans.push(Span::styled("r", Style::default().fg(COLOR_ACCENT)));
+            spans.push(Span::raw(": retry | "));
         }
 
         // Newline hint (skip on extra small)
@@ -97,7 +119,7 @@ pub fn build_responsive_keybinds(app: &App, ctx: &LayoutContext) -> Line<'static
                 spans.push(Span::styled("[Cmd] links", Style::default().fg(COLOR_DIM)));
             } else {
                 spans.push(Span::styled(
-                    "[Cmd+click] open links",
+                    "[Cmd+click] open links, [Ctrl+O] by number",
                     Style::default().fg(COLOR_DIM),
                 ));
             }
@@ -257,6 +279,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         });
         app.active_thread_id = Some("prog-thread".to_string());
 
@@ -343,6 +370,10 @@ mod tests {
         // Should show full link hint on normal width
         assert!(content.contains("[Cmd+click]"), "Should show [Cmd+click]");
         assert!(content.contains("open links"), "Should show 'open links'");
+        assert!(
+            content.contains("[Ctrl+O]"),
+            "Should show the link hints key"
+        );
     }
 
     #[test]
@@ -497,4 +528,45 @@ mod tests {
             "Link hint should appear after 'back' hint"
         );
     }
+
+    // ========================================================================
+    // Status Notice Tests
+    // ========================================================================
+
+    #[test]
+    fn test_status_notice_leads_keybinds() {
+        let mut app = create_test_app();
+        app.screen = Screen::Conversation;
+        app.status_notice = Some("Copied 42 chars".to_string());
+
+        let keybinds = build_responsive_keybinds(&app, &LayoutContext::new(120, 40));
+
+        assert_eq!(keybinds.spans[1].content, "Copied 42 chars");
+    }
+
+    #[test]
+    fn test_unsent_actions_shown() {
+        let mut app = create_test_app();
+        app.ws_unacked_messages = 1;
+
+        let keybinds = build_responsive_keybinds(&app, &LayoutContext::new(120, 40));
+
+        assert_eq!(keybinds.spans[1].content, "1 unsent action");
+    }
+
+    #[test]
+    fn test_retry_hint_shown_with_error() {
+        let mut app = create_test_app();
+        app.screen = Screen::Conversation;
+        app.stream_error = Some("Server error".to_string());
+
+        let keybinds = build_responsive_keybinds(&app, &LayoutContext::new(120, 40));
+        let content: String = keybinds
+            .spans
+            .iter()
+            .map(|s| s.content.to_string())
+            .collect();
+
+        assert!(content.contains("r: retry"), "Should show retry hint");
+    }
 }
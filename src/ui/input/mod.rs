@@ -11,16 +11,17 @@ mod permission;
 
 // Re-export public APIs to maintain backwards compatibility
 pub use folder_chip::{
-    calculate_chip_width, format_chip_folder_name, render_folder_chip, COLOR_CHIP_BG,
-    COLOR_CHIP_TEXT, MAX_CHIP_FOLDER_NAME_LEN,
+    calculate_chip_width, calculate_chips_width, format_chip_folder_name, render_folder_chip,
+    COLOR_CHIP_BG, COLOR_CHIP_TEXT, MAX_CHIP_FOLDER_NAME_LEN,
 };
 pub use height::{
     calculate_input_area_height, calculate_input_area_height_with_images,
-    calculate_input_box_height, MAX_INPUT_LINES,
+    calculate_input_area_height_with_max, calculate_input_box_height,
+    calculate_input_box_height_with_max, MAX_INPUT_LINES,
 };
 pub use image_chip::{
-    calculate_image_chips_width, format_image_chip_text, render_image_chips,
-    COLOR_IMAGE_CHIP_BG, COLOR_IMAGE_CHIP_TEXT,
+    build_image_preview_lines, calculate_image_chips_width, format_pending_image_chip_text,
+    image_tray_height, render_image_chips, COLOR_IMAGE_CHIP_BG, COLOR_IMAGE_CHIP_TEXT,
 };
 pub use keybinds::{build_contextual_keybinds, build_responsive_keybinds};
 pub use permission::{
@@ -37,11 +38,12 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{App, Screen};
+use crate::input::Action;
 use crate::models::PermissionMode;
 
 use super::layout::LayoutContext;
-use super::theme::{COLOR_ACCENT, COLOR_DIM};
+use super::theme::{COLOR_ACCENT, COLOR_BYPASS_BG, COLOR_DIM};
 
 // ============================================================================
 // Input Area
@@ -82,32 +84,24 @@ pub fn render_input_area_with_blink(
         height: area.height.saturating_sub(2),
     };
 
-    // Calculate chip width if a folder is selected
-    let chip_width = app
-        .selected_folder
-        .as_ref()
-        .map(|f| calculate_chip_width(&f.name) + 1) // +1 for space after chip
-        .unwrap_or(0);
+    // Soft-wrap to the content width; the text itself keeps its own lines
+    let content_width = input_text_width(app, area.width);
+    app.textarea.set_display_width(content_width);
 
-    // Calculate content width (accounting for input box borders and chip)
-    let content_width = inner.width.saturating_sub(2).saturating_sub(chip_width);
-
-    // Set hard wrap width so auto-newlines are inserted during typing
-    app.textarea.set_wrap_width(Some(content_width));
-
-    // Calculate dynamic input box height based on line count
-    let line_count = app.textarea.line_count();
-    let input_box_height = calculate_input_box_height(line_count);
+    // Calculate dynamic input box height based on wrapped row count
+    let row_count = app.textarea.visual_line_count(content_width);
+    let input_box_height = calculate_input_box_height_with_max(row_count, app.input_max_rows);
 
     let has_images = !app.pending_images.is_empty();
 
     let (input_area, keybinds_area) = if has_images {
+        let tray_height = image_tray_height(&app.pending_images);
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(1),                // Image chips row (above border)
+                Constraint::Length(tray_height), // Image chips + preview (above border)
                 Constraint::Length(input_box_height), // Input box
-                Constraint::Length(1),                // Keybinds
+                Constraint::Length(1),           // Keybinds
             ])
             .split(inner);
 
@@ -117,7 +111,7 @@ pub fn render_input_area_with_blink(
             if i > 0 {
                 spans.push(Span::raw(" "));
             }
-            let chip_text = format_image_chip_text(i, &img.hash);
+            let chip_text = format_pending_image_chip_text(img);
             spans.push(Span::styled(
                 chip_text,
                 Style::default()
@@ -125,8 +119,11 @@ pub fn render_input_area_with_blink(
                     .bg(COLOR_IMAGE_CHIP_BG),
             ));
         }
-        let image_line = Paragraph::new(Line::from(spans));
-        frame.render_widget(image_line, chunks[0]);
+        let mut tray_lines = vec![Line::from(spans)];
+        if let Some(newest) = app.pending_images.last() {
+            tray_lines.extend(build_image_preview_lines(newest, &discard_image_key(app)));
+        }
+        frame.render_widget(Paragraph::new(tray_lines), chunks[0]);
 
         (chunks[1], chunks[2])
     } else {
@@ -145,7 +142,7 @@ pub fn render_input_area_with_blink(
         textarea_input: &mut app.textarea,
         focused: input_focused,
         cursor_visible,
-        selected_folder: app.selected_folder.as_ref(),
+        selected_folders: &app.selected_folders,
     };
     frame.render_widget(input_with_chip, input_area);
 
@@ -157,6 +154,37 @@ pub fn render_input_area_with_blink(
     frame.render_widget(keybinds_widget, keybinds_area);
 }
 
+/// Width of the input text for an input area `area_width` wide, inside the
+/// padding, the input box borders and the folder chips.
+pub fn input_text_width(app: &App, area_width: u16) -> u16 {
+    let folder_names: Vec<&str> = app
+        .selected_folders
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect();
+    let chip_width = calculate_chips_width(&folder_names);
+    // 2 for the padding, 2 for the input box borders
+    area_width.saturating_sub(4).saturating_sub(chip_width)
+}
+
+/// Total height of an input area `area_width` wide, with the text soft-wrapped.
+pub fn input_area_height(app: &App, area_width: u16) -> u16 {
+    let row_count = app
+        .textarea
+        .visual_line_count(input_text_width(app, area_width));
+    calculate_input_area_height_with_max(row_count, app.input_max_rows)
+        + image_tray_height(&app.pending_images)
+}
+
+/// Key that discards the newest image, for the preview hint
+fn discard_image_key(app: &App) -> String {
+    app.keymap
+        .chords_for(Action::DiscardImage)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| "backspace".to_string())
+}
+
 /// Render the input area with static (non-blinking) cursor.
 ///
 /// This is the default rendering mode used by the command deck (dashboard).
@@ -166,10 +194,10 @@ pub fn render_input_area(frame: &mut Frame, area: Rect, app: &mut App) {
     render_input_area_with_blink(frame, area, app, false);
 }
 
-/// Widget that renders a folder chip followed by the TextArea input.
+/// Widget that renders the folder chips followed by the TextArea input.
 ///
 /// This composite widget handles:
-/// - Rendering the folder chip at the start (if selected)
+/// - Rendering the folder chips at the start (if any are attached)
 /// - Rendering the TextArea input in the remaining space
 /// - Supporting cursor blinking via `cursor_visible` flag
 struct InputWithChipWidget<'a, 'b> {
@@ -177,7 +205,7 @@ struct InputWithChipWidget<'a, 'b> {
     focused: bool,
     /// Whether the cursor should be visible (for blinking support)
     cursor_visible: bool,
-    selected_folder: Option<&'b crate::models::Folder>,
+    selected_folders: &'b [crate::models::Folder],
 }
 
 impl Widget for InputWithChipWidget<'_, '_> {
@@ -194,17 +222,24 @@ impl Widget for InputWithChipWidget<'_, '_> {
         let inner_area = block.inner(area);
         block.render(area, buf);
 
-        // If a folder is selected, render the chip at the start of the input
-        let textarea_area = if let Some(folder) = self.selected_folder {
-            let chip_width = calculate_chip_width(&folder.name);
-            let spacing = 1u16; // Space after chip
-
-            // Render the folder chip at the start of the inner area (top-left)
-            render_folder_chip(buf, inner_area.x, inner_area.y, &folder.name);
+        // If folders are attached, render their chips at the start of the input
+        let textarea_area = if !self.selected_folders.is_empty() {
+            let spacing = 1u16; // Space after each chip
+
+            // Render the folder chips side by side from the top-left of the inner area
+            let mut chip_x = inner_area.x;
+            for folder in self.selected_folders {
+                let chip_width = calculate_chip_width(&folder.name);
+                if chip_x + chip_width > inner_area.right() {
+                    break;
+                }
+                render_folder_chip(buf, chip_x, inner_area.y, &folder.name);
+                chip_x += chip_width + spacing;
+            }
 
             // Calculate remaining area for textarea
-            let chip_total_width = chip_width + spacing;
-            let textarea_x = inner_area.x + chip_total_width;
+            let chip_total_width = chip_x - inner_area.x;
+            let textarea_x = chip_x;
             let textarea_width = inner_area.width.saturating_sub(chip_total_width);
 
             Rect {
@@ -260,12 +295,16 @@ pub fn build_input_section_with_cursor(
             )]));
         }
         PermissionMode::Execution => {
-            lines.push(Line::from(vec![Span::styled(
-                "  [EXECUTE]",
-                Style::default()
-                    .fg(Color::Rgb(255, 140, 0))
-                    .add_modifier(Modifier::BOLD),
-            )]));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    " [EXECUTE] no tool prompts ",
+                    Style::default()
+                        .fg(Color::White)
+                        .bg(COLOR_BYPASS_BG)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
         }
     };
 
@@ -276,7 +315,7 @@ pub fn build_input_section_with_cursor(
             if i > 0 {
                 spans.push(Span::raw(" "));
             }
-            let chip_text = format_image_chip_text(i, &img.hash);
+            let chip_text = format_pending_image_chip_text(img);
             spans.push(Span::styled(
                 chip_text,
                 Style::default()
@@ -285,6 +324,22 @@ pub fn build_input_section_with_cursor(
             ));
         }
         lines.push(Line::from(spans));
+        if let Some(newest) = app.pending_images.last() {
+            lines.extend(build_image_preview_lines(newest, &discard_image_key(app)));
+        }
+    }
+
+    // 1.6. Reply reference chip
+    if let Some(reply) = app.active_pending_reply() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  \u{21aa} Replying to \u{201c}{}\u{201d}", reply.excerpt),
+                Style::default()
+                    .fg(COLOR_ACCENT)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+            Span::styled("  Ctrl+C to cancel", Style::default().fg(COLOR_DIM)),
+        ]));
     }
 
     // 2. Input top border (full-width horizontal line)
@@ -293,43 +348,17 @@ pub fn build_input_section_with_cursor(
         Style::default().fg(COLOR_ACCENT),
     )));
 
-    // 3. Input content lines with optional blinking cursor
-    let (cursor_row, cursor_col) = app.textarea.cursor();
-    for (line_idx, text_line) in app.textarea.lines().iter().enumerate() {
-        if cursor_visible && line_idx == cursor_row {
-            // Insert cursor at the cursor position
-            // cursor_col is a character index, but string slicing requires byte indices
-            let text = text_line.as_str();
-            let char_count = text.chars().count();
-            let col = cursor_col.min(char_count);
-
-            // Find byte index for the character position
-            let byte_idx = text.char_indices()
-                .nth(col)
-                .map(|(i, _)| i)
-                .unwrap_or(text.len());
-
-            let before_cursor = text[..byte_idx].to_string();
-            let (cursor_char, cursor_char_len) = if col < char_count {
-                let c = text.chars().nth(col).unwrap();
-                (c.to_string(), c.len_utf8())
-            } else {
-                (" ".to_string(), 1)
-            };
-            let after_cursor = if byte_idx + cursor_char_len <= text.len() && col < char_count {
-                text[byte_idx + cursor_char_len..].to_string()
-            } else {
-                String::new()
-            };
-
-            lines.push(Line::from(vec![
-                Span::raw(format!("  {}", before_cursor)),
-                Span::styled(cursor_char, Style::default().fg(Color::Black).bg(Color::White)),
-                Span::raw(after_cursor),
-            ]));
-        } else {
-            lines.push(Line::from(format!("  {}", text_line)));
-        }
+    // 3. Input content, soft-wrapped after the indent, with optional blinking cursor
+    app.textarea
+        .set_display_width(viewport_width.saturating_sub(2));
+    let cursor_style = cursor_visible.then_some(Style::default().fg(Color::Black).bg(Color::White));
+    for row in app
+        .textarea
+        .wrapped_lines(app.input_max_rows as usize, cursor_style)
+    {
+        let mut spans = vec![Span::raw("  ")];
+        spans.extend(row.spans);
+        lines.push(Line::from(spans));
     }
 
     // 4. Input bottom border (full-width horizontal line)
@@ -338,17 +367,44 @@ pub fn build_input_section_with_cursor(
         Style::default().fg(COLOR_ACCENT),
     )));
 
-    // 5. Keybind hints
-    lines.push(Line::from(vec![
-        Span::styled("  Enter", Style::default().fg(COLOR_DIM)),
-        Span::styled(" send ", Style::default().fg(COLOR_DIM)),
-        Span::styled("|", Style::default().fg(COLOR_DIM)),
-        Span::styled(" Shift+Enter", Style::default().fg(COLOR_DIM)),
-        Span::styled(" newline ", Style::default().fg(COLOR_DIM)),
-        Span::styled("|", Style::default().fg(COLOR_DIM)),
-        Span::styled(" Esc", Style::default().fg(COLOR_DIM)),
-        Span::styled(" menu", Style::default().fg(COLOR_DIM)),
-    ]));
+    // 5. Keybind hints, led by the vim navigation mode when it's enabled
+    if app.is_navigating() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                "  NAVIGATE ",
+                Style::default()
+                    .fg(COLOR_ACCENT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " j/k scroll | ^d/^u half page | gg/G top/bottom | {/} messages | i insert",
+                Style::default().fg(COLOR_DIM),
+            ),
+        ]));
+    } else {
+        let vim_insert = app.vim_navigation && app.screen == Screen::Conversation;
+        let mut spans = Vec::new();
+        if vim_insert {
+            spans.push(Span::styled(
+                "  INSERT",
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+        }
+        spans.extend([
+            Span::styled("  Enter", Style::default().fg(COLOR_DIM)),
+            Span::styled(" send ", Style::default().fg(COLOR_DIM)),
+            Span::styled("|", Style::default().fg(COLOR_DIM)),
+            Span::styled(" Shift+Enter", Style::default().fg(COLOR_DIM)),
+            Span::styled(" newline ", Style::default().fg(COLOR_DIM)),
+            Span::styled("|", Style::default().fg(COLOR_DIM)),
+            Span::styled(" Esc", Style::default().fg(COLOR_DIM)),
+            Span::styled(
+                if vim_insert { " navigate" } else { " menu" },
+                Style::default().fg(COLOR_DIM),
+            ),
+        ]);
+        lines.push(Line::from(spans));
+    }
 
     lines
 }
@@ -356,11 +412,15 @@ pub fn build_input_section_with_cursor(
 /// Build the input section as content lines for unified scroll.
 ///
 /// This is a convenience wrapper around `build_input_section_with_cursor` that
-/// uses `app.cursor_blink.is_visible()` for cursor visibility.
+/// uses `app.cursor_blink.is_visible()` for cursor visibility (hidden in navigate mode).
 ///
 /// Returns lines for: top border, input content (with blinking cursor), bottom border, keybinds.
 pub fn build_input_section(app: &App, viewport_width: u16) -> Vec<Line<'static>> {
-    build_input_section_with_cursor(app, viewport_width, app.cursor_blink.is_visible())
+    build_input_section_with_cursor(
+        app,
+        viewport_width,
+        app.cursor_blink.is_visible() && !app.is_navigating(),
+    )
 }
 
 #[cfg(test)]
@@ -406,6 +466,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_input_section_shows_reply_chip() {
+        let app = App {
+            active_thread_id: Some("thread-1".to_string()),
+            pending_reply: Some(crate::app::PendingReply {
+                thread_id: "thread-1".to_string(),
+                message_id: 4,
+                excerpt: "Use the retry queue".to_string(),
+            }),
+            ..Default::default()
+        };
+
+        let lines = build_input_section(&app, 80);
+
+        // Reply chip, top border, content, bottom border, keybinds = 5 lines
+        assert_eq!(lines.len(), 5);
+        let chip_text: String = lines[0].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(chip_text.contains("Replying to \u{201c}Use the retry queue\u{201d}"));
+    }
+
     #[test]
     fn test_build_input_section_plan_mode_indicator() {
         let app = App {
@@ -466,23 +546,52 @@ mod tests {
             "Execution mode should display '[EXECUTE]'"
         );
 
-        // Verify orange color styling (RGB 255, 140, 0)
-        if let Some(span) = mode_line.spans.first() {
-            assert_eq!(
-                span.style.fg,
-                Some(Color::Rgb(255, 140, 0)),
-                "Execute mode indicator should be orange"
-            );
+        // Verify the warning styling (white on red, unlike Plan's magenta text)
+        let span = mode_line.spans.last().unwrap();
+        assert_eq!(
+            span.style.bg,
+            Some(COLOR_BYPASS_BG),
+            "Execute mode indicator should be on a red background"
+        );
+    }
+
+    #[test]
+    fn test_input_renders_a_chip_per_folder() {
+        let mut textarea = crate::widgets::textarea_input::TextAreaInput::new();
+        let folders = vec![
+            Folder {
+                name: "web".to_string(),
+                path: "/code/web".to_string(),
+            },
+            Folder {
+                name: "api".to_string(),
+                path: "/code/api".to_string(),
+            },
+        ];
+        let area = Rect::new(0, 0, 40, 3);
+        let mut buf = Buffer::empty(area);
+        InputWithChipWidget {
+            textarea_input: &mut textarea,
+            focused: false,
+            cursor_visible: false,
+            selected_folders: &folders,
         }
+        .render(area, &mut buf);
+
+        let row: String = (0..area.width)
+            .map(|x| buf[(x, 1)].symbol().to_string())
+            .collect();
+        assert!(row.contains("web]"), "row: {}", row);
+        assert!(row.contains("api]"), "row: {}", row);
     }
 
     #[test]
     fn test_build_input_section_folder_does_not_affect_structure() {
         let app = App {
-            selected_folder: Some(Folder {
+            selected_folders: vec![Folder {
                 name: "my-project".to_string(),
                 path: "/path/to/project".to_string(),
-            }),
+            }],
             ..Default::default()
         };
 
@@ -500,10 +609,10 @@ mod tests {
     fn test_build_input_section_plan_mode_with_folder() {
         let app = App {
             permission_mode: PermissionMode::Plan,
-            selected_folder: Some(Folder {
+            selected_folders: vec![Folder {
                 name: "my-project".to_string(),
                 path: "/path/to/project".to_string(),
-            }),
+            }],
             ..Default::default()
         };
 
@@ -651,4 +760,28 @@ mod tests {
         assert_eq!(lines.len(), 4);
     }
 
+    #[test]
+    fn test_build_input_section_vim_mode_hints() {
+        let mut app = App::default();
+        app.vim_navigation = true;
+        app.screen = Screen::Conversation;
+        let hints = |app: &App| -> String {
+            let lines = build_input_section(app, 80);
+            lines[lines.len() - 1]
+                .spans
+                .iter()
+                .map(|s| s.content.as_ref())
+                .collect()
+        };
+
+        let insert = hints(&app);
+        assert!(insert.starts_with("  INSERT"));
+        assert!(insert.contains("Esc navigate"));
+
+        assert!(app.enter_navigate_mode());
+        let navigate = hints(&app);
+        assert!(navigate.starts_with("  NAVIGATE"));
+        assert!(navigate.contains("gg/G"));
+    }
+
 }
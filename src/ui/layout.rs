@@ -21,6 +21,8 @@ pub mod breakpoints {
     /// Large terminal (< 160 columns)
     #[allow(dead_code)]
     pub const LG_WIDTH: u16 = 160;
+    /// Narrowest terminal that fits the conversation needs-action sidebar
+    pub const SIDEBAR_MIN_WIDTH: u16 = 140;
 
     /// Extra small terminal height (< 16 rows)
     pub const XS_HEIGHT: u16 = 16;
@@ -167,6 +169,20 @@ impl LayoutContext {
         self.width.saturating_sub(border_width)
     }
 
+    /// Whether the terminal is wide enough for the conversation sidebar.
+    pub fn fits_sidebar(&self) -> bool {
+        self.width >= breakpoints::SIDEBAR_MIN_WIDTH
+    }
+
+    /// Context for the main column next to a sidebar of `sidebar_width`
+    /// columns.
+    ///
+    /// Widths (and so message wrapping and cached heights) then follow the
+    /// narrower column instead of the whole terminal.
+    pub fn main_column(&self, sidebar_width: u16) -> Self {
+        Self::new(self.width.saturating_sub(sidebar_width), self.height)
+    }
+
     /// Get available content height after accounting for header/footer/chrome.
     ///
     /// # Arguments
@@ -496,6 +512,18 @@ mod tests {
         assert_eq!(ctx.available_content_width(20), 0);
     }
 
+    #[test]
+    fn test_main_column_narrows_content_width() {
+        let ctx = LayoutContext::new(160, 40);
+        assert!(ctx.fits_sidebar());
+        assert!(!LayoutContext::new(120, 40).fits_sidebar());
+
+        let main = ctx.main_column(36);
+        assert_eq!(main.width, 124);
+        assert_eq!(main.available_content_width(4), 120);
+        assert_eq!(main.height, 40);
+    }
+
     #[test]
     fn test_available_content_height() {
         let ctx = LayoutContext::new(100, 40);
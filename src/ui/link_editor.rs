@@ -0,0 +1,125 @@
+//! Thread link editor dialog rendering
+//!
+//! Shown by `/link`: lists the other threads with their current relation to
+//! the active thread. Enter links/unlinks the selected thread with the
+//! relation shown in the title; Tab switches the relation.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::helpers::truncate_string;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Maximum threads listed at once
+const MAX_VISIBLE_THREADS: usize = 10;
+
+/// Render the link editor as a centered overlay
+pub fn render_link_editor(frame: &mut Frame, app: &App) {
+    let Some(ref editor) = app.link_editor else {
+        return;
+    };
+
+    let candidates = app.link_candidates(&editor.thread_id);
+    let links = app
+        .cache
+        .get_thread(&editor.thread_id)
+        .map(|t| t.links.as_slice())
+        .unwrap_or_default();
+
+    let area = frame.area();
+    let visible_count = candidates.len().clamp(1, MAX_VISIBLE_THREADS);
+    let dialog_width = 60u16.min(area.width.saturating_sub(4));
+    // Borders (2) + padding (2) + threads + blank line + hint line
+    let dialog_height = (visible_count as u16 + 6).min(area.height.saturating_sub(2));
+
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            format!(" Link thread: {} ", editor.kind.label()),
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 2,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(4),
+    };
+
+    // Keep the selection in view
+    let offset = editor
+        .selected_index
+        .saturating_sub(MAX_VISIBLE_THREADS - 1);
+    let relation_width = 14;
+    let max_title_width = (inner.width as usize).saturating_sub(relation_width + 2);
+
+    let mut lines: Vec<Line> = candidates
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(MAX_VISIBLE_THREADS)
+        .map(|(index, thread)| {
+            let is_selected = index == editor.selected_index;
+            let marker = if is_selected { "▶ " } else { "  " };
+            let title_style = if is_selected {
+                Style::default()
+                    .fg(COLOR_ACCENT)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(COLOR_DIM)
+            };
+            let relation = links
+                .iter()
+                .find(|l| l.thread_id == thread.id)
+                .map(|l| format!("{} {}", l.kind.icon(), l.kind.label()))
+                .unwrap_or_default();
+
+            Line::from(vec![
+                Span::styled(marker, title_style),
+                Span::styled(
+                    format!(
+                        "{:<width$}",
+                        truncate_string(&thread.title, max_title_width),
+                        width = max_title_width
+                    ),
+                    title_style,
+                ),
+                Span::styled(format!(" {}", relation), Style::default().fg(Color::Yellow)),
+            ])
+        })
+        .collect();
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("[Enter] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("link/unlink  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[Tab] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("relation  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("done", Style::default().fg(COLOR_DIM)),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
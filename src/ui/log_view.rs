@@ -0,0 +1,161 @@
+//! Log viewer rendering
+//!
+//! Shown by `/log`: the end of the log file, newest line at the bottom,
+//! coloured by level, with the active level filter and follow mode in the
+//! title.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::logging::LogLevel;
+
+use super::helpers::truncate_string;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER, COLOR_TOOL_ERROR};
+
+/// Style of a log line by its level (continuation lines take `level`)
+fn line_style(level: LogLevel) -> Style {
+    match level {
+        LogLevel::Error => Style::default().fg(COLOR_TOOL_ERROR),
+        LogLevel::Warn => Style::default().fg(Color::Yellow),
+        LogLevel::Info => Style::default(),
+        LogLevel::Debug | LogLevel::Trace => Style::default().fg(COLOR_DIM),
+    }
+}
+
+/// " Log: /tmp/spoq_debug.log · warn+ · following "
+fn title_text(path: &str, min_level: LogLevel, follow: bool) -> String {
+    let mut title = format!(" Log: {}", path);
+    if min_level != LogLevel::Trace {
+        title.push_str(&format!(" \u{00b7} {}+", min_level.as_str()));
+    }
+    if follow {
+        title.push_str(" \u{00b7} following");
+    }
+    title.push(' ');
+    title
+}
+
+/// Render the `/log` overlay as a centered dialog
+pub fn render_log_view(frame: &mut Frame, app: &App) {
+    let Some(ref view) = app.log_view else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = 140u16.min(area.width.saturating_sub(4));
+    let dialog_height = area.height.saturating_sub(4).max(6);
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height.min(area.height),
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let path = view.path.display().to_string();
+    let block = Block::default()
+        .title(Span::styled(
+            title_text(&truncate_string(&path, 60), view.min_level, view.follow),
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(2),
+    };
+    // Leave room for the blank line and the hint line
+    let visible_rows = (inner.height as usize).saturating_sub(2);
+    let width = inner.width as usize;
+
+    let visible = view.visible_lines();
+    let mut lines: Vec<Line> = if let Some(ref error) = view.error {
+        vec![Line::from(Span::styled(
+            format!("  Can't read the log file: {}", error),
+            Style::default().fg(COLOR_TOOL_ERROR),
+        ))]
+    } else if visible.is_empty() {
+        vec![Line::from(Span::styled(
+            "  No log lines at this level",
+            Style::default().fg(COLOR_DIM),
+        ))]
+    } else {
+        let end = visible.len().saturating_sub(view.scroll);
+        let start = end.saturating_sub(visible_rows);
+        let mut level = LogLevel::Info;
+        visible[..end]
+            .iter()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                // Track the level of continuation lines above the window too
+                if let Some(line_level) = LogLevel::of_line(line) {
+                    level = line_level;
+                }
+                (index >= start).then(|| {
+                    Line::from(Span::styled(
+                        truncate_string(line, width),
+                        line_style(level),
+                    ))
+                })
+            })
+            .collect()
+    };
+    // Keep the newest lines at the bottom when the file is short
+    if lines.len() < visible_rows {
+        let mut padded = vec![Line::from(""); visible_rows - lines.len()];
+        padded.append(&mut lines);
+        lines = padded;
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("[↑↓] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("scroll  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[e/w/i/d] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("level  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[f] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled(
+            if view.follow {
+                "stop following  "
+            } else {
+                "follow  "
+            },
+            Style::default().fg(COLOR_DIM),
+        ),
+        Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("close", Style::default().fg(COLOR_DIM)),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_text() {
+        assert_eq!(
+            title_text("/tmp/spoq_debug.log", LogLevel::Trace, true),
+            " Log: /tmp/spoq_debug.log \u{00b7} following "
+        );
+        assert_eq!(
+            title_text("/tmp/spoq_debug.log", LogLevel::Warn, false),
+            " Log: /tmp/spoq_debug.log \u{00b7} warn+ "
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! Follow-up list rendering
+//!
+//! Renders the TODO/FIXME markers the latest turn added, below the messages.
+//! Collapsed it is a single row; expanded it lists every marker with the
+//! selected one highlighted and a key hint underneath.
+
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::app::App;
+
+use super::super::helpers::truncate_string;
+use super::super::layout::LayoutContext;
+use super::super::theme::{COLOR_ACCENT, COLOR_DIM, COLOR_TOOL_SUCCESS};
+
+/// Render the follow-ups of the thread on screen.
+///
+/// Returns the lines to be added to the messages area
+pub fn render_followups(app: &App, ctx: &LayoutContext) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = Vec::new();
+    let Some(followups) = app.active_followups() else {
+        return lines;
+    };
+
+    let count = followups.items.len();
+    let noun = if count == 1 {
+        "follow-up"
+    } else {
+        "follow-ups"
+    };
+    if !followups.expanded {
+        lines.push(Line::from(vec![
+            Span::styled("\u{25B8} ", Style::default().fg(COLOR_DIM)),
+            Span::styled(
+                format!("{} {}", count, noun),
+                Style::default().fg(COLOR_ACCENT),
+            ),
+            Span::styled(" (f to expand)", Style::default().fg(COLOR_DIM)),
+        ]));
+        lines.push(Line::from(""));
+        return lines;
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("\u{25BE} ", Style::default().fg(COLOR_DIM)),
+        Span::styled(
+            format!("{} {}", count, noun),
+            Style::default()
+                .fg(COLOR_ACCENT)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+
+    // Leave room for the pointer and the tracked mark
+    let text_width = (ctx.text_wrap_width(2) as usize).saturating_sub(10);
+    for (i, item) in followups.items.iter().enumerate() {
+        let selected = i == followups.selected;
+        let pointer = if selected { "  \u{203A} " } else { "    " };
+        let style = if selected {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let mut spans = vec![
+            Span::styled(pointer, Style::default().fg(COLOR_ACCENT)),
+            Span::styled(truncate_string(&item.summary(), text_width), style),
+        ];
+        if followups.tracked.contains(&i) {
+            spans.push(Span::styled(
+                " \u{2713} tracked",
+                Style::default().fg(COLOR_TOOL_SUCCESS),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("    [t] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("track  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[Enter] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("follow-up prompt  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[f] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("collapse", Style::default().fg(COLOR_DIM)),
+    ]));
+    lines.push(Line::from(""));
+    lines
+}
@@ -4,7 +4,10 @@
 //! virtualization. Height calculations are performed in the prepare phase
 //! rather than during rendering.
 
-use crate::models::{Message, MessageSegment};
+use crate::markdown::table_height;
+use crate::models::{Message, MessageSegment, SubagentEvent, SubagentEventStatus};
+
+use super::super::helpers::display_width;
 
 /// Estimate the height of a message in visual lines.
 ///
@@ -43,16 +46,52 @@ pub fn estimate_text_height(text: &str, viewport_width: usize) -> usize {
     }
 
     // Estimate wrapped lines for each paragraph
-    text.lines()
-        .map(|line| {
-            if line.is_empty() {
-                1
-            } else {
-                // Simple estimation: divide by effective width, round up
-                line.len().div_ceil(effective_width)
+    let mut height = 0;
+    let mut lines = text.lines().peekable();
+    while let Some(line) = lines.next() {
+        // Tables are laid out to fit (see `markdown::table_height`), two
+        // columns narrower than the text for the message area's margins
+        if is_table_row(line) && lines.peek().is_some_and(|next| is_table_delimiter(next)) {
+            lines.next();
+            let mut rows = vec![table_cells(line)];
+            while let Some(row) = lines.next_if(|l| is_table_row(l)) {
+                rows.push(table_cells(row));
             }
-        })
-        .sum()
+            height += table_height(&rows, Some(effective_width.saturating_sub(2)));
+        } else if line.is_empty() {
+            height += 1;
+        } else {
+            // Display columns (not bytes or chars), rounded up to rows
+            height += display_width(line).div_ceil(effective_width).max(1);
+        }
+    }
+    height
+}
+
+/// Whether a line of markdown looks like a table row (`| a | b |`)
+fn is_table_row(line: &str) -> bool {
+    let line = line.trim();
+    line.starts_with('|') && line.len() > 1
+}
+
+/// Whether a line is a table's header delimiter (`|---|:--:|`)
+fn is_table_delimiter(line: &str) -> bool {
+    is_table_row(line)
+        && line.contains('-')
+        && line
+            .trim()
+            .chars()
+            .all(|c| matches!(c, '|' | '-' | ':' | ' '))
+}
+
+/// Cells of a table row
+fn table_cells(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = line.strip_suffix('|').unwrap_or(line);
+    line.split('|')
+        .map(|cell| cell.trim().to_string())
+        .collect()
 }
 
 /// Estimate the height contribution of tool events in a message.
@@ -74,19 +113,43 @@ pub fn estimate_tool_events_height(segments: &[MessageSegment]) -> usize {
 
 /// Estimate the height contribution of subagent events in a message.
 ///
-/// Subagent events are typically 1-2 lines each.
-pub fn estimate_subagent_events_height(segments: &[MessageSegment]) -> usize {
-    segments
-        .iter()
-        .filter_map(|seg| {
-            if let MessageSegment::SubagentEvent(_event) = seg {
-                // Each subagent event is approximately 1 line
-                Some(1)
-            } else {
-                None
-            }
-        })
-        .sum()
+/// Each subagent takes a line, plus one for its progress while running.
+/// Once the message is done, a block of several subagents is a summary line,
+/// followed by the tree when expanded (see `render_subagent_tree`).
+pub fn estimate_subagent_events_height(segments: &[MessageSegment], is_streaming: bool) -> usize {
+    let event_lines = |event: &SubagentEvent| {
+        let progress =
+            event.status == SubagentEventStatus::Running && event.progress_message.is_some();
+        1 + usize::from(progress)
+    };
+
+    let mut height = 0;
+    let mut i = 0;
+    while i < segments.len() {
+        // Consecutive subagent events render as one block
+        let block: Vec<&SubagentEvent> = segments[i..]
+            .iter()
+            .map_while(|seg| match seg {
+                MessageSegment::SubagentEvent(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+        if block.is_empty() {
+            i += 1;
+            continue;
+        }
+        i += block.len();
+
+        let tree: usize = block.iter().map(|&event| event_lines(event)).sum();
+        height += if is_streaming || block.len() < 2 {
+            tree
+        } else if block.iter().any(|event| event.expanded) {
+            1 + tree
+        } else {
+            1
+        };
+    }
+    height
 }
 
 #[cfg(test)]
@@ -117,6 +180,29 @@ mod tests {
         assert_eq!(estimate_text_height(&text, 80), 2);
     }
 
+    #[test]
+    fn test_estimate_text_height_counts_display_width() {
+        // 6 wide characters are 12 columns: two rows at effective width 6
+        assert_eq!(estimate_text_height("日本語日本語", 8), 2);
+        // Combining accents take no columns of their own
+        assert_eq!(
+            estimate_text_height("e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}", 8),
+            1
+        );
+    }
+
+    #[test]
+    fn test_estimate_text_height_counts_rendered_table() {
+        let text = format!(
+            "Results:\n| Name | Notes |\n|---|---|\n| a | {} |\n| b | short |",
+            "x".repeat(200)
+        );
+        // Fitted grid at 100 columns: borders, header, separator, two rows, blank
+        assert_eq!(estimate_text_height(&text, 100), 1 + 7);
+        // Stacked at 40 columns: two lines per row, a rule between, blank
+        assert_eq!(estimate_text_height(&text, 40), 1 + 6);
+    }
+
     #[test]
     fn test_estimate_tool_events_height_empty() {
         let segments: Vec<MessageSegment> = vec![];
@@ -126,6 +212,32 @@ mod tests {
     #[test]
     fn test_estimate_subagent_events_height_empty() {
         let segments: Vec<MessageSegment> = vec![];
-        assert_eq!(estimate_subagent_events_height(&segments), 0);
+        assert_eq!(estimate_subagent_events_height(&segments, false), 0);
+    }
+
+    #[test]
+    fn test_estimate_subagent_events_height_follows_the_tree() {
+        let subagent = |id: &str| {
+            let mut event =
+                SubagentEvent::new(id.to_string(), "Search".to_string(), "Explore".to_string());
+            event.progress_message = Some("Reading".to_string());
+            MessageSegment::SubagentEvent(event)
+        };
+        let mut segments = vec![
+            subagent("task-1"),
+            subagent("task-2"),
+            MessageSegment::Text("Found it.".to_string()),
+            subagent("task-3"),
+        ];
+        // Running with progress: two lines each
+        assert_eq!(estimate_subagent_events_height(&segments, true), 6);
+        // Done: the pair is a summary line, the single one stays as is
+        assert_eq!(estimate_subagent_events_height(&segments, false), 1 + 2);
+
+        if let MessageSegment::SubagentEvent(event) = &mut segments[0] {
+            event.expanded = true;
+            event.complete(None);
+        }
+        assert_eq!(estimate_subagent_events_height(&segments, false), 1 + 3 + 2);
     }
 }
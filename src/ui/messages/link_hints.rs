@@ -0,0 +1,179 @@
+//! Link hint labels and picker
+//!
+//! While link hints are shown (Ctrl+O), each OSC 8 link in the viewport gets
+//! its number in front of it, and a picker at the bottom of the messages area
+//! lists the numbered URLs (the only place links show a number when the
+//! terminal has no hyperlinks, or for links in code).
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::LinkHints;
+use crate::markdown::osc8_url;
+
+use super::super::helpers::truncate_string;
+use super::super::theme::{COLOR_BORDER, COLOR_DIM, COLOR_HEADER, COLOR_SEARCH_CURRENT_BG};
+
+/// Style of a link's number
+fn label_style() -> Style {
+    Style::default()
+        .fg(Color::Black)
+        .bg(COLOR_SEARCH_CURRENT_BG)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Put the number of each link that can still be picked in front of it.
+///
+/// A link wrapped over several spans is numbered once per line.
+pub fn label_link_spans(lines: &mut [Line<'_>], hints: &LinkHints) {
+    for line in lines.iter_mut() {
+        let mut previous_url: Option<String> = None;
+        let mut new_spans = Vec::with_capacity(line.spans.len());
+        for span in line.spans.drain(..) {
+            let url = osc8_url(&span.content).map(str::to_string);
+            if let Some(label) = url
+                .as_deref()
+                .filter(|url| previous_url.as_deref() != Some(*url))
+                .and_then(|url| hints.label_for(url))
+                .filter(|label| hints.is_candidate(*label))
+            {
+                new_spans.push(Span::styled(format!("[{}]", label), label_style()));
+            }
+            previous_url = url;
+            new_spans.push(span);
+        }
+        line.spans = new_spans;
+    }
+}
+
+/// Render the numbered links at the bottom of the messages area
+pub fn render_link_picker(frame: &mut Frame, area: Rect, hints: &LinkHints) {
+    let entries: Vec<(usize, &String)> = hints
+        .urls
+        .iter()
+        .enumerate()
+        .map(|(i, url)| (i + 1, url))
+        .filter(|(label, _)| hints.is_candidate(*label))
+        .collect();
+    let rows = entries.len().min((area.height / 2) as usize);
+    if rows == 0 || area.width < 10 {
+        return;
+    }
+
+    let height = rows as u16 + 2;
+    let picker_area = Rect {
+        x: area.x,
+        y: area.y + area.height - height,
+        width: area.width,
+        height,
+    };
+    frame.render_widget(Clear, picker_area);
+
+    let title = if hints.typed.is_empty() {
+        " Open link: type its number, Esc to cancel ".to_string()
+    } else {
+        format!(" Open link: {}_ ", hints.typed)
+    };
+    let block = Block::default()
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+
+    let url_width = (area.width as usize).saturating_sub(10);
+    let lines: Vec<Line> = entries
+        .into_iter()
+        .take(rows)
+        .map(|(label, url)| {
+            Line::from(vec![
+                Span::styled(format!("{:>3}", label), label_style()),
+                Span::raw(" "),
+                Span::styled(
+                    truncate_string(url, url_width),
+                    Style::default().fg(COLOR_DIM),
+                ),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block), picker_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hints(urls: &[&str], typed: &str) -> LinkHints {
+        LinkHints {
+            urls: urls.iter().map(|u| u.to_string()).collect(),
+            typed: typed.to_string(),
+        }
+    }
+
+    fn link(url: &str, text: &str) -> Span<'static> {
+        Span::raw(format!("\x1b]8;;{}\x07{}\x1b]8;;\x07", url, text))
+    }
+
+    fn text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .filter(|s| osc8_url(&s.content).is_none())
+            .map(|s| s.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_links_get_their_number() {
+        let mut lines = vec![
+            Line::from(vec![
+                Span::raw("see "),
+                link("https://a.test", "docs"),
+                Span::raw(" and "),
+                link("https://b.test", "https://b.test"),
+            ]),
+            Line::from(vec![link("https://a.test", "again")]),
+        ];
+        label_link_spans(
+            &mut lines,
+            &hints(&["https://a.test", "https://b.test"], ""),
+        );
+        assert_eq!(text(&lines[0]), "see [1] and [2]");
+        // Duplicate URLs share the label
+        assert_eq!(text(&lines[1]), "[1]");
+    }
+
+    #[test]
+    fn test_typed_digits_hide_other_labels() {
+        let urls: Vec<String> = (1..=12).map(|i| format!("https://{}.test", i)).collect();
+        let mut lines = vec![Line::from(vec![
+            link(&urls[1], "two"),
+            Span::raw(" "),
+            link(&urls[11], "twelve"),
+        ])];
+        let hints = LinkHints {
+            urls,
+            typed: "1".to_string(),
+        };
+        label_link_spans(&mut lines, &hints);
+        assert_eq!(text(&lines[0]), " [12]");
+    }
+
+    #[test]
+    fn test_wrapped_link_numbered_once_per_line() {
+        let mut lines = vec![Line::from(vec![
+            link("https://a.test", "long "),
+            link("https://a.test", "link"),
+        ])];
+        label_link_spans(&mut lines, &hints(&["https://a.test"], ""));
+        assert_eq!(text(&lines[0]), "[1]");
+    }
+}
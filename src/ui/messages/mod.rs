@@ -4,19 +4,29 @@
 //! Uses `LayoutContext` for responsive layout calculations.
 
 mod errors;
+mod followups;
 pub mod height;
+mod link_hints;
+mod permission_diff;
 mod permission_inline;
+mod permission_input;
 mod plan_events;
+mod search_highlight;
 mod segments;
 mod subagent_events;
 mod text_wrapping;
 mod thinking;
+pub mod timestamps;
+mod tool_diff;
 mod tool_events;
 pub mod virtualization;
+pub mod zoom;
 
 // Re-export public APIs at crate::ui::messages::*
 // Note: Some exports are only used in tests
+pub use permission_diff::{diff_lines, DiffLine};
 pub use permission_inline::build_permission_lines;
+pub use permission_input::permission_input_rows;
 #[allow(unused_imports)]
 pub use subagent_events::{render_subagent_event, render_subagent_events_block, TreeConnector};
 pub use text_wrapping::{apply_background_to_line, estimate_wrapped_line_count, wrap_line_with_prefix};
@@ -25,10 +35,20 @@ pub use tool_events::{render_tool_event, truncate_preview};
 
 // Used by this module's main functions
 use errors::render_inline_error_banners;
+use followups::render_followups;
+use link_hints::{label_link_spans, render_link_picker};
+use search_highlight::highlight_search_matches;
 use segments::render_message_segments;
 use text_wrapping::wrap_lines_with_prefix;
 use thinking::render_thinking_block;
-use virtualization::{estimate_message_height_fast, MessageHeight};
+use timestamps::add_timestamps;
+use virtualization::{
+    calculate_visible_range, estimate_message_height_fast, recalculate_offsets,
+    total_message_lines, MessageHeight,
+};
+use zoom::zoomed_message;
+
+use std::collections::HashMap;
 
 use ratatui::{
     layout::Rect,
@@ -37,16 +57,24 @@ use ratatui::{
     widgets::{Paragraph, Wrap},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
-use crate::app::App;
+use crate::app::{App, MessageViewport, ScrollAnchor, REPLY_EXCERPT_CHARS};
+use crate::cache::MSG_ID_USER_CANCELLED;
+use crate::markdown::MarkdownCache;
 use crate::models::{Message, MessageRole};
+use crate::rendered_lines_cache::RenderedLinesCache;
 use crate::ui::input::image_chip::{format_image_chip_text, COLOR_IMAGE_CHIP_BG, COLOR_IMAGE_CHIP_TEXT};
 
 use super::helpers::inner_rect;
 use super::layout::LayoutContext;
+use super::queued_prompts::build_queued_prompt_lines;
 use super::steering::build_steering_lines;
 use super::theme::{COLOR_ACCENT, COLOR_DIM, COLOR_HUMAN_BG};
 
+/// Upper bound on layout passes per frame while exact heights replace estimates
+const MAX_LAYOUT_PASSES: usize = 4;
+
 /// Extract @path file references from message content.
 ///
 /// Returns (file_paths, remaining_content) where file_paths are the paths
@@ -125,6 +153,31 @@ fn build_image_chips_line(hashes: &[String], label: &str, label_style: Style) ->
     Line::from(spans)
 }
 
+/// Start of the message a user message replies to, if it is still loaded
+fn reply_excerpt(message: &Message, thread_messages: &[Message]) -> Option<String> {
+    if message.role != MessageRole::User {
+        return None;
+    }
+    let reply_to = message.reply_to?;
+    thread_messages
+        .iter()
+        .find(|m| m.id == reply_to)
+        .map(|m| m.excerpt(REPLY_EXCERPT_CHARS))
+}
+
+/// Build a line quoting the message a user message replies to.
+fn build_reply_chip_line(excerpt: &str, label: &str, label_style: Style) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(label.to_string(), label_style),
+        Span::styled(
+            format!("\u{21aa} \u{201c}{}\u{201d}", excerpt),
+            Style::default()
+                .fg(COLOR_DIM)
+                .add_modifier(Modifier::ITALIC),
+        ),
+    ])
+}
+
 /// Check if the input section should be shown in conversation view.
 ///
 /// Returns false if the active thread has a pending permission or plan approval,
@@ -139,6 +192,18 @@ fn should_show_input_section(app: &App) -> bool {
         .unwrap_or(true)
 }
 
+/// What rendering a message needs besides the message itself
+pub struct MessageRenderState<'a> {
+    /// Animation tick, for spinners of running tools
+    pub tick_count: u64,
+    /// Parsed markdown of message text
+    pub markdown_cache: &'a mut MarkdownCache,
+    /// Rendered lines of completed messages and tool diffs
+    pub rendered_lines_cache: &'a mut RenderedLinesCache,
+    /// Messages of the thread, for quoting the message a reply refers to
+    pub thread_messages: &'a [Message],
+}
+
 /// Render a single message and return its lines.
 ///
 /// This is a helper function used by the virtualized message renderer.
@@ -149,6 +214,29 @@ pub fn render_single_message(
     message: &Message,
     app: &mut App,
     ctx: &LayoutContext,
+) -> Vec<Line<'static>> {
+    let mut state = MessageRenderState {
+        tick_count: app.tick_count,
+        markdown_cache: &mut app.markdown_cache,
+        rendered_lines_cache: &mut app.rendered_lines_cache,
+        thread_messages: app
+            .cache
+            .get_messages(thread_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[]),
+    };
+    render_message_lines(thread_id, message, &mut state, ctx)
+}
+
+/// Render a message with the given caches and return its lines.
+///
+/// Completed messages are cached under `thread_id`, their ID and render
+/// version.
+pub fn render_message_lines(
+    thread_id: &str,
+    message: &Message,
+    state: &mut MessageRenderState,
+    ctx: &LayoutContext,
 ) -> Vec<Line<'static>> {
     let mut lines: Vec<Line<'static>> = Vec::new();
 
@@ -157,7 +245,7 @@ pub fn render_single_message(
 
     // Render thinking/reasoning block for assistant messages (before content)
     if message.role == MessageRole::Assistant {
-        lines.extend(render_thinking_block(message, app.tick_count, ctx));
+        lines.extend(render_thinking_block(message, state.tick_count, ctx));
     }
 
     // Use vertical bar prefix for all messages (user and assistant)
@@ -165,6 +253,8 @@ pub fn render_single_message(
 
     // Calculate max width for wrapping
     let max_width = ctx.text_wrap_width(0) as usize;
+    // Width left for markdown after the label (tables are fitted to it)
+    let content_width = max_width.saturating_sub(label.width());
 
     // Handle streaming vs completed messages
     if message.is_streaming {
@@ -172,12 +262,15 @@ pub fn render_single_message(
         // This shows text, tool events, and subagent events in the order they occurred
         if message.role == MessageRole::Assistant && !message.segments.is_empty() {
             let (segment_lines, is_first_line) = render_message_segments(
+                message.id,
                 &message.segments,
-                app.tick_count,
+                state.tick_count,
+                true,
                 label,
                 label_style,
                 ctx,
-                &mut app.markdown_cache,
+                state.markdown_cache,
+                state.rendered_lines_cache,
             );
             lines.extend(segment_lines);
 
@@ -189,7 +282,14 @@ pub fn render_single_message(
             // Fall back to partial_content for backward compatibility
             // (non-assistant messages or when segments is empty)
 
-            let content_lines = (*app.markdown_cache.render(&message.partial_content)).clone();
+            // Incremental: only the text after the last completed block is re-parsed
+            let content_lines = (*state.markdown_cache.render_streaming(
+                message.id,
+                0,
+                &message.partial_content,
+                content_width,
+            ))
+            .clone();
 
             // Wrap and prepend vertical bar to ALL lines
             if content_lines.is_empty() {
@@ -213,7 +313,7 @@ pub fn render_single_message(
     } else {
         // Display completed message - try cache first
         if let Some(cached_lines) =
-            app.rendered_lines_cache
+            state.rendered_lines_cache
                 .get(thread_id, message.id, message.render_version)
         {
             // Use iter().cloned() to avoid cloning the entire Vec; we only clone each Line as needed
@@ -233,12 +333,15 @@ pub fn render_single_message(
         // For assistant messages with segments, render segments in order
         if message.role == MessageRole::Assistant && !message.segments.is_empty() {
             let (segment_lines, is_first_line) = render_message_segments(
+                message.id,
                 &message.segments,
-                app.tick_count,
+                state.tick_count,
+                false,
                 label,
                 label_style,
                 ctx,
-                &mut app.markdown_cache,
+                state.markdown_cache,
+                state.rendered_lines_cache,
             );
             message_lines.extend(segment_lines);
 
@@ -262,6 +365,13 @@ pub fn render_single_message(
                 message_lines.push(chips_line);
             }
 
+            // Render the quoted message this one replies to (for user messages)
+            if let Some(excerpt) = reply_excerpt(message, state.thread_messages) {
+                let mut reply_line = build_reply_chip_line(&excerpt, label, label_style);
+                apply_background_to_line(&mut reply_line, COLOR_HUMAN_BG, max_width);
+                message_lines.push(reply_line);
+            }
+
             // Render image attachment chips if present (for user messages)
             if message.role == MessageRole::User && !message.image_hashes.is_empty() {
                 let mut img_line = build_image_chips_line(&message.image_hashes, label, label_style);
@@ -269,7 +379,7 @@ pub fn render_single_message(
                 message_lines.push(img_line);
             }
 
-            let content_lines_arc = app.markdown_cache.render(&display_content);
+            let content_lines_arc = state.markdown_cache.render_width(&display_content, content_width);
             let content_lines = (*content_lines_arc).clone();
 
             if content_lines.is_empty() {
@@ -298,8 +408,21 @@ pub fn render_single_message(
             }
         }
 
+        // Responses stopped with Ctrl+C end with a dim marker
+        if message.role == MessageRole::Assistant && message.id == MSG_ID_USER_CANCELLED {
+            message_lines.push(Line::from(vec![
+                Span::styled(label, label_style),
+                Span::styled(
+                    "Cancelled by user",
+                    Style::default()
+                        .fg(COLOR_DIM)
+                        .add_modifier(Modifier::ITALIC),
+                ),
+            ]));
+        }
+
         // Cache and add to output
-        app.rendered_lines_cache.insert(
+        state.rendered_lines_cache.insert(
             thread_id,
             message.id,
             message.render_version,
@@ -327,8 +450,8 @@ pub fn render_single_message(
 /// - Error banners adapt to terminal size
 ///
 /// Implements message virtualization to only render messages within the
-/// visible viewport plus a small buffer, significantly improving performance
-/// for long conversation threads.
+/// visible viewport, so markdown for off-screen messages is never parsed and
+/// long conversation threads stay responsive.
 pub fn render_messages_area(frame: &mut Frame, area: Rect, app: &mut App, ctx: &LayoutContext) {
     // Note: has_visible_links is reset in prepare_render()
     // Note: rendered_lines_cache invalidation happens in prepare_render()
@@ -337,9 +460,40 @@ pub fn render_messages_area(frame: &mut Frame, area: Rect, app: &mut App, ctx: &
     let viewport_height = inner.height as usize;
     let viewport_width = inner.width as usize;
 
-    // Collect header lines (stream errors only - error banners are shown at bottom)
+    // Collect header lines (stream errors and the older-page loading row -
+    // error banners are shown at bottom)
     let mut header_lines: Vec<Line> = Vec::new();
 
+    // An older page of a long thread is being fetched
+    if app
+        .active_thread_id
+        .as_deref()
+        .is_some_and(|id| app.is_loading_older_messages(id))
+    {
+        header_lines.push(Line::from(Span::styled(
+            "  Loading earlier messages\u{2026}",
+            Style::default().fg(COLOR_DIM),
+        )));
+    }
+
+    // The thread is archived: say how to bring it back
+    if app.active_thread_archived() {
+        header_lines.push(Line::from(Span::styled(
+            "  Archived \u{2014} press U to unarchive",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    // The thread is muted: its prompts are only answerable from here
+    if app.active_thread_muted() {
+        header_lines.push(Line::from(Span::styled(
+            "  \u{1F515} Muted \u{2014} no notifications; answer its prompts here",
+            Style::default().fg(COLOR_DIM),
+        )));
+    }
+
     // Show stream error banner if there's a stream error (legacy, for non-thread errors)
     if let Some(error) = &app.stream_error {
         // Truncate error message based on available width
@@ -369,7 +523,8 @@ pub fn render_messages_area(frame: &mut Frame, area: Rect, app: &mut App, ctx: &
     // Phase 1: Get heights from pre-computed cache (prepared in prepare_render)
     // The height cache is updated in prepare_render(), we just read from it here
     let current_thread_id = app.active_thread_id.clone();
-    let (_message_heights, _total_visual_lines, message_count) = {
+    let zoom = app.zoom_level();
+    let (mut message_heights, _total_visual_lines, message_count) = {
         let cached_messages = current_thread_id.as_ref().and_then(|id| {
             crate::app::log_thread_update(&format!(
                 "RENDER: Looking for messages for thread_id: {}",
@@ -428,7 +583,8 @@ pub fn render_messages_area(frame: &mut Frame, area: Rect, app: &mut App, ctx: &
                     let heights: Vec<MessageHeight> = messages
                         .iter()
                         .scan(0usize, |offset, msg| {
-                            let height = estimate_message_height_fast(msg, viewport_width);
+                            let height =
+                                estimate_message_height_fast(&zoomed_message(msg, zoom), viewport_width);
                             let result = MessageHeight {
                                 visual_lines: height,
                                 cumulative_offset: *offset,
@@ -493,6 +649,9 @@ pub fn render_messages_area(frame: &mut Frame, area: Rect, app: &mut App, ctx: &
         let steering_lines = build_steering_lines(app, viewport_width);
         lines.extend(steering_lines);
 
+        // === UNIFIED SCROLL: Append prompts queued behind the stream ===
+        lines.extend(build_queued_prompt_lines(app, viewport_width));
+
         // === UNIFIED SCROLL: Append input section (if no pending permission) ===
         if should_show_input_section(app) {
             app.input_section_start = lines.len();
@@ -516,45 +675,33 @@ pub fn render_messages_area(frame: &mut Frame, area: Rect, app: &mut App, ctx: &
         return;
     }
 
-    // SIMPLE: Render ALL messages (no virtualization)
-    // This ensures smooth 1-line-at-a-time scrolling
-    let all_messages: Vec<Message> = app
-        .active_thread_id
-        .as_ref()
-        .and_then(|id| app.cache.get_messages(id))
-        .map(|msgs| msgs.to_vec())
-        .unwrap_or_default();
-
-    let mut lines: Vec<Line> = header_lines;
-
-    // Render ALL messages
-    let thread_id = app.active_thread_id.clone().unwrap_or_default();
-    for message in all_messages.iter() {
-        let message_lines = render_single_message(&thread_id, message, app, ctx);
-        lines.extend(message_lines);
-    }
-
-    // Detect if any visible lines contain hyperlinks (OSC 8 escape sequences)
-    // OSC 8 format starts with: \x1b]8;;
-    for line in &lines {
-        for span in &line.spans {
-            if span.content.contains("\x1b]8;;") {
-                app.has_visible_links = true;
-                break;
-            }
-        }
-        if app.has_visible_links {
-            break;
-        }
-    }
-
-    // Show inline error banners for the thread (at bottom where user is looking)
-    lines.extend(render_inline_error_banners(app, ctx));
+    let thread_id = current_thread_id.clone().unwrap_or_default();
+
+    // Message search: highlight matches and locate the current one
+    app.refresh_message_search();
+    let search_query = app
+        .message_search
+        .is_highlighting()
+        .then(|| app.message_search.query.clone());
+    // (message index, occurrence within that message) of the current match
+    let current_search_match = app.message_search.current_match().map(|current| {
+        let occurrence = app
+            .message_search
+            .matches
+            .iter()
+            .filter(|m| m.message_index == current.message_index && m.start < current.start)
+            .count();
+        (current.message_index, occurrence)
+    });
+
+    // Everything below the messages is always laid out so the total height is known
+    let mut footer_lines: Vec<Line> = render_inline_error_banners(app, ctx);
+    footer_lines.extend(render_followups(app, ctx));
 
     // Add permission lines if pending for this thread
     if let Some(perm) = app.dashboard.get_pending_permission(&thread_id) {
         let perm_lines = build_permission_lines(perm, &app.question_state, ctx, app.tick_count);
-        lines.extend(perm_lines);
+        footer_lines.extend(perm_lines);
     }
 
     // Show plan approval UI if a plan is pending approval
@@ -562,7 +709,7 @@ pub fn render_messages_area(frame: &mut Frame, area: Rect, app: &mut App, ctx: &
         let (selected_action, feedback_active, feedback_text) = app.dashboard.get_plan_approval_state(&thread_id)
             .map(|s| (s.selected_action, s.feedback_active, s.feedback_text.clone()))
             .unwrap_or((0, false, String::new()));
-        lines.extend(plan_events::render_plan_approval(
+        footer_lines.extend(plan_events::render_plan_approval(
             &plan_request.summary,
             ctx,
             &mut app.markdown_cache,
@@ -574,31 +721,355 @@ pub fn render_messages_area(frame: &mut Frame, area: Rect, app: &mut App, ctx: &
 
     // Append steering section (if active)
     let steering_lines = build_steering_lines(app, viewport_width);
-    lines.extend(steering_lines);
+    footer_lines.extend(steering_lines);
+
+    // Append prompts queued behind the stream
+    footer_lines.extend(build_queued_prompt_lines(app, viewport_width));
 
     // Append input section (if no pending permission)
-    if should_show_input_section(app) {
-        app.input_section_start = lines.len();
-        let input_lines = super::input::build_input_section(app, inner.width);
-        lines.extend(input_lines);
+    let input_offset = should_show_input_section(app).then(|| {
+        let offset = footer_lines.len();
+        footer_lines.extend(super::input::build_input_section(app, inner.width));
+        offset
+    });
+
+    let header_len = header_lines.len();
+    let footer_len = footer_lines.len();
+    let mut rendered: HashMap<usize, Vec<Line<'static>>> = HashMap::new();
+
+    // Render the current search match up front so the jump uses its exact position
+    let mut search_jump: Option<(usize, usize)> = None;
+    if app.message_search.pending_jump {
+        if let Some((index, _)) = current_search_match.filter(|(i, _)| *i < message_heights.len()) {
+            let (message_lines, jump_line) = render_message_at(
+                app,
+                ctx,
+                &thread_id,
+                index,
+                search_query.as_deref(),
+                current_search_match,
+            );
+            message_heights[index].visual_lines = message_lines.len();
+            recalculate_offsets(&mut message_heights, index);
+            // Rendered markdown can drop characters from the raw text, so fall
+            // back to the message start
+            search_jump = Some((index, jump_line.unwrap_or(0)));
+            rendered.insert(index, message_lines);
+        }
     }
 
-    // SIMPLE SCROLL CALCULATION:
-    // total_lines = everything we rendered
-    // max_scroll = how far we can scroll (total - viewport)
-    // scroll_from_top = max_scroll - unified_scroll (converts from "from bottom" to "from top")
-    let total_lines = lines.len();
-    app.total_content_lines = total_lines;
+    // Opening a notification scrolls to the message it refers to
+    let mut message_jumped = false;
+    if let Some(message_id) = app.pending_message_jump.filter(|_| search_jump.is_none()) {
+        let index = app
+            .cache
+            .get_messages(&thread_id)
+            .and_then(|messages| messages.iter().position(|m| m.id == message_id))
+            .filter(|i| *i < message_heights.len());
+        if let Some(index) = index {
+            let (message_lines, _) = render_message_at(app, ctx, &thread_id, index, None, None);
+            message_heights[index].visual_lines = message_lines.len();
+            recalculate_offsets(&mut message_heights, index);
+            search_jump = Some((index, 0));
+            rendered.insert(index, message_lines);
+        }
+        // Messages are loaded by now; an unknown ID isn't going to show up
+        app.pending_message_jump = None;
+        message_jumped = index.is_some();
+    }
+
+    // After a resize or an older page was prepended, a scrolled-up reader
+    // gets the same message back at the top
+    let restore_anchor = app
+        .pending_scroll_anchor
+        .take()
+        .filter(|_| search_jump.is_none())
+        .and_then(|anchor| {
+            let messages = app.cache.get_messages(&thread_id)?;
+            let index = messages.iter().position(|m| m.id == anchor.message_id)?;
+            (index < message_heights.len()).then_some((index, anchor.fraction))
+        });
+
+    // VIRTUALIZED LAYOUT:
+    // Only messages intersecting the viewport are rendered. Heights of the
+    // others come from the height cache and may be estimates; rendering a
+    // message replaces its estimate with the exact height, which can pull a
+    // new message into view, so repeat until the visible set is stable.
+    // Scroll is anchored to the bottom, so estimates above the viewport never
+    // move what is on screen and auto-scroll always lands exactly at the end.
+    let mut unified_scroll = app.unified_scroll as usize;
+    // Scrolled all the way up stays at the top as heights above are refined
+    let pinned_to_top = unified_scroll > 0 && unified_scroll >= app.max_scroll as usize;
+    // Otherwise a scrolled-up reader keeps the same top line while content
+    // streams in below (anchoring to the bottom would drag the text upwards)
+    let anchored_top = (app.user_has_scrolled && unified_scroll > 0 && !pinned_to_top)
+        .then(|| (app.max_scroll as usize).saturating_sub(unified_scroll));
+    let mut total_lines = 0;
+    let mut max_scroll = 0;
+    let mut scroll_from_top = 0;
+    let mut visible = (0, 0);
+    for pass in 1..=MAX_LAYOUT_PASSES {
+        total_lines = header_len + total_message_lines(&message_heights) + footer_len;
+        max_scroll = total_lines.saturating_sub(viewport_height);
+        if pinned_to_top {
+            unified_scroll = max_scroll;
+        } else if let Some(top) = anchored_top {
+            unified_scroll = max_scroll.saturating_sub(top);
+        }
+
+        // Scroll the current search match into view (about a third from the top)
+        if let Some((index, line_in_message)) = search_jump {
+            let target_line = header_len + message_heights[index].cumulative_offset + line_in_message;
+            let desired_top = target_line.saturating_sub(viewport_height / 3).min(max_scroll);
+            unified_scroll = max_scroll - desired_top;
+        } else if let Some((index, fraction)) = restore_anchor {
+            let height = message_heights[index].visual_lines;
+            let target_line = header_len
+                + message_heights[index].cumulative_offset
+                + (fraction * height as f32) as usize;
+            unified_scroll = max_scroll - target_line.min(max_scroll);
+        }
+
+        scroll_from_top = max_scroll.saturating_sub(unified_scroll);
+        let window_start = scroll_from_top.saturating_sub(header_len);
+        let window_end = (scroll_from_top + viewport_height).saturating_sub(header_len);
+        let (start, end, _) =
+            calculate_visible_range(&message_heights, window_start, window_end - window_start);
+        visible = (start, end);
+
+        let mut first_changed: Option<usize> = None;
+        for (index, height) in message_heights.iter_mut().enumerate().take(end).skip(start) {
+            if rendered.contains_key(&index) {
+                continue;
+            }
+            let (message_lines, _) = render_message_at(
+                app,
+                ctx,
+                &thread_id,
+                index,
+                search_query.as_deref(),
+                current_search_match,
+            );
+            if height.visual_lines != message_lines.len() {
+                height.visual_lines = message_lines.len();
+                first_changed.get_or_insert(index);
+            }
+            rendered.insert(index, message_lines);
+        }
+
+        match first_changed {
+            // The last pass keeps its layout; the next frame starts from the exact heights
+            Some(index) if pass < MAX_LAYOUT_PASSES => {
+                recalculate_offsets(&mut message_heights, index)
+            }
+            _ => break,
+        }
+    }
+
+    app.message_viewport = MessageViewport {
+        top: scroll_from_top.saturating_sub(header_len),
+        height: viewport_height,
+    };
+
+    // Keep exact heights so the next frame starts from them
+    if let Some(cache) = app.height_cache.as_mut() {
+        if cache.is_valid_for(&thread_id, viewport_width) && cache.heights.len() == message_heights.len() {
+            let first_changed = cache
+                .heights
+                .iter()
+                .zip(&message_heights)
+                .position(|(cached, exact)| cached.visual_lines != exact.visual_lines);
+            if let Some(index) = first_changed {
+                for (cached, exact) in cache.heights.iter_mut().zip(&message_heights).skip(index) {
+                    cached.visual_lines = exact.visual_lines;
+                }
+                cache.recalculate_offsets_from(index);
+            }
+        }
+    }
+
+    if pinned_to_top && search_jump.is_none() {
+        app.unified_scroll = unified_scroll.min(u16::MAX as usize) as u16;
+        app.scroll_position = app.unified_scroll as f32;
+    }
+    if restore_anchor.is_some() {
+        app.unified_scroll = unified_scroll.min(u16::MAX as usize) as u16;
+        app.scroll_position = app.unified_scroll as f32;
+        app.user_has_scrolled = app.unified_scroll > 0;
+    } else if anchored_top.is_some() && search_jump.is_none() {
+        let grown = max_scroll.saturating_sub(app.max_scroll as usize);
+        if grown > 0 && app.cache.is_thread_streaming(&thread_id) {
+            app.new_content_lines += grown;
+        }
+        let previous = app.unified_scroll;
+        app.unified_scroll = unified_scroll.min(u16::MAX as usize) as u16;
+        app.scroll_position += app.unified_scroll as f32 - previous as f32;
+    }
+    if !app.user_has_scrolled || app.unified_scroll == 0 {
+        app.new_content_lines = 0;
+    }
+    if app.message_search.pending_jump || message_jumped {
+        if search_jump.is_some() {
+            app.unified_scroll = unified_scroll as u16;
+            app.scroll_position = app.unified_scroll as f32;
+            app.scroll_velocity = 0.0;
+            app.user_has_scrolled = app.unified_scroll > 0;
+        }
+        app.message_search.pending_jump = false;
+    }
 
-    let max_scroll = total_lines.saturating_sub(viewport_height);
-    app.max_scroll = max_scroll as u16;
+    // Remember the top message so a resize or prepended page can bring it back
+    let (start, end) = visible;
+    let top_message_id = app
+        .cache
+        .get_messages(&thread_id)
+        .and_then(|messages| messages.get(start))
+        .map(|m| m.id);
+    app.scroll_anchor = top_message_id
+        .zip(message_heights.get(start))
+        .filter(|_| app.user_has_scrolled && app.unified_scroll > 0)
+        .map(|(message_id, height)| {
+            let into_message = scroll_from_top
+                .saturating_sub(header_len)
+                .saturating_sub(height.cumulative_offset);
+            ScrollAnchor {
+                message_id,
+                fraction: into_message as f32 / height.visual_lines.max(1) as f32,
+            }
+        });
+
+    // Assemble only the visible slice of the content
+    let mut lines: Vec<Line> = Vec::new();
+    let content_start = if start == 0 {
+        lines.extend(header_lines);
+        0
+    } else {
+        header_len
+            + message_heights
+                .get(start)
+                .map(|h| h.cumulative_offset)
+                .unwrap_or_else(|| total_message_lines(&message_heights))
+    };
+    for index in start..end {
+        lines.extend(rendered.remove(&index).unwrap_or_default());
+    }
+
+    // Detect if any visible lines contain hyperlinks (OSC 8 escape sequences)
+    // OSC 8 format starts with: \x1b]8;;
+    for line in &lines {
+        for span in &line.spans {
+            if span.content.contains("\x1b]8;;") {
+                app.has_visible_links = true;
+                break;
+            }
+        }
+        if app.has_visible_links {
+            break;
+        }
+    }
+    if let Some(hints) = &app.link_hints {
+        label_link_spans(&mut lines, hints);
+    }
+
+    let footer_start = total_lines - footer_len;
+    if let Some(offset) = input_offset {
+        app.input_section_start = footer_start + offset;
+    }
+    if end == message_heights.len() {
+        lines.extend(footer_lines);
+    }
 
-    let scroll_from_top = max_scroll.saturating_sub(app.unified_scroll as usize);
+    // SCROLL CALCULATION:
+    // total_lines = header + message heights + footer
+    // max_scroll = how far we can scroll (total - viewport)
+    // scroll_from_top = max_scroll - unified_scroll (converts from "from bottom" to "from top"),
+    // made relative to the first assembled line
+    app.total_content_lines = total_lines;
+    app.max_scroll = max_scroll.min(u16::MAX as usize) as u16;
 
     let messages_widget = Paragraph::new(lines)
         .wrap(Wrap { trim: false })
-        .scroll((scroll_from_top as u16, 0));
+        .scroll((scroll_from_top.saturating_sub(content_start) as u16, 0));
     frame.render_widget(messages_widget, inner);
+
+    if app.new_content_lines > 0 {
+        render_new_lines_badge(frame, inner, app.new_content_lines);
+    }
+    if let Some(hints) = &app.link_hints {
+        render_link_picker(frame, inner, hints);
+    }
+}
+
+/// Render the "↓ N new lines" badge centered on the bottom edge of the viewport.
+fn render_new_lines_badge(frame: &mut Frame, area: Rect, count: usize) {
+    let label = format!(
+        " \u{2193} {} new line{} ",
+        count,
+        if count == 1 { "" } else { "s" }
+    );
+    let width = (label.chars().count() as u16).min(area.width);
+    if width == 0 || area.height == 0 {
+        return;
+    }
+    let badge_area = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + area.height - 1,
+        width,
+        height: 1,
+    };
+    let badge = Paragraph::new(Line::from(Span::styled(
+        label,
+        Style::default()
+            .fg(Color::Black)
+            .bg(COLOR_ACCENT)
+            .add_modifier(Modifier::BOLD),
+    )));
+    frame.render_widget(badge, badge_area);
+}
+
+/// Render the message at `index` of a thread, highlighting search matches.
+///
+/// Returns the rendered lines and, if the current search match is in this
+/// message, the line it is on.
+fn render_message_at(
+    app: &mut App,
+    ctx: &LayoutContext,
+    thread_id: &str,
+    index: usize,
+    search_query: Option<&str>,
+    current_search_match: Option<(usize, usize)>,
+) -> (Vec<Line<'static>>, Option<usize>) {
+    let zoom = app.zoom_level();
+    let Some(message) = app
+        .cache
+        .get_messages(thread_id)
+        .and_then(|messages| messages.get(index))
+        .map(|message| zoomed_message(message, zoom).into_owned())
+    else {
+        return (Vec::new(), None);
+    };
+
+    let mut lines = render_single_message(thread_id, &message, app, ctx);
+    // Added after the cache so relative times stay current
+    if app.show_timestamps {
+        if let Some(messages) = app.cache.get_messages(thread_id) {
+            add_timestamps(&mut lines, messages, index, ctx, chrono::Utc::now());
+        }
+    }
+    let mut jump_line = None;
+    if let Some(query) = search_query {
+        let current = current_search_match
+            .filter(|(message_index, _)| *message_index == index)
+            .map(|(_, occurrence)| occurrence);
+        let occurrence_lines = highlight_search_matches(&mut lines, query, current);
+        if let Some(occurrence) = current {
+            jump_line = occurrence_lines
+                .get(occurrence)
+                .or(occurrence_lines.last())
+                .copied();
+        }
+    }
+    (lines, jump_line)
 }
 
 // ============================================================================
@@ -607,7 +1078,10 @@ pub fn render_messages_area(frame: &mut Frame, area: Rect, app: &mut App, ctx: &
 
 #[cfg(test)]
 mod tests {
+    use crate::app::{App, Screen};
+    use crate::models::{MessageRole, Thread, ThreadMode, ThreadType};
     use crate::ui::LayoutContext;
+    use ratatui::{backend::TestBackend, Terminal};
 
     // ========================================================================
     // Responsive Layout Tests
@@ -742,4 +1216,228 @@ mod tests {
         assert!(paths.is_empty());
         assert_eq!(remaining, "@username mentions are not files");
     }
+
+    // ========================================================================
+    // Virtualization Tests
+    // ========================================================================
+
+    const LONG_THREAD: &str = "long-thread";
+
+    fn app_with_long_thread(message_count: usize) -> App {
+        let mut app = App::default();
+        app.screen = Screen::Conversation;
+        app.active_thread_id = Some(LONG_THREAD.to_string());
+        // The thread itself must exist for streaming into it
+        app.cache.upsert_thread(Thread {
+            id: LONG_THREAD.to_string(),
+            title: "Long thread".to_string(),
+            description: None,
+            preview: String::new(),
+            updated_at: chrono::Utc::now(),
+            thread_type: ThreadType::Conversation,
+            mode: ThreadMode::default(),
+            model: None,
+            permission_mode: None,
+            message_count: 0,
+            created_at: chrono::Utc::now(),
+            working_directory: None,
+            status: None,
+            verified: None,
+            verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
+        });
+        for i in 0..message_count {
+            let role = if i % 2 == 0 {
+                MessageRole::User
+            } else {
+                MessageRole::Assistant
+            };
+            app.cache.add_message_simple(
+                LONG_THREAD,
+                role,
+                format!("Message {}\n\n```rust\nfn step_{}() {{}}\n```", i, i),
+            );
+        }
+        app
+    }
+
+    /// Draw one frame and return the screen contents
+    fn draw(terminal: &mut Terminal<TestBackend>, app: &mut App) -> String {
+        terminal.draw(|f| crate::ui::render(f, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_virtualized_render_only_renders_visible_messages() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        let mut app = app_with_long_thread(5000);
+
+        let screen = draw(&mut terminal, &mut app);
+
+        assert!(screen.contains("Message 4999"));
+        assert!(!screen.contains("Message 0 "));
+        // Only on-screen messages were rendered (and cached)
+        assert!(app.rendered_lines_cache.len() < 20);
+        assert!(app.total_content_lines > 5000);
+    }
+
+    #[test]
+    fn test_virtualized_render_scrolled_to_top() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        let mut app = app_with_long_thread(5000);
+        draw(&mut terminal, &mut app);
+
+        app.unified_scroll = app.max_scroll;
+        let screen = draw(&mut terminal, &mut app);
+
+        assert!(screen.contains("Message 0"));
+        assert!(!screen.contains("Message 4999"));
+    }
+
+    #[test]
+    fn test_resize_keeps_top_message_in_place() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        let mut app = app_with_long_thread(200);
+        app.update_terminal_dimensions(100, 30);
+        draw(&mut terminal, &mut app);
+        app.unified_scroll = app.max_scroll / 2;
+        app.user_has_scrolled = true;
+        draw(&mut terminal, &mut app);
+        let anchor = app.scroll_anchor.expect("anchor recorded while scrolled up");
+
+        terminal.backend_mut().resize(50, 30);
+        app.queue_resize(50, 30);
+        let at = app.pending_resize.unwrap().at;
+        assert!(app.apply_settled_resize_at(at + crate::app::RESIZE_SETTLE));
+        draw(&mut terminal, &mut app);
+
+        // The same message is at the top of the viewport at the new width
+        assert_eq!(
+            app.scroll_anchor.map(|a| a.message_id),
+            Some(anchor.message_id)
+        );
+        assert!(app.pending_scroll_anchor.is_none());
+    }
+
+    #[test]
+    fn test_streaming_auto_scroll_lands_at_bottom() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        let mut app = app_with_long_thread(500);
+        app.cache
+            .add_streaming_message(LONG_THREAD, "Keep going".to_string(), Vec::new());
+
+        for i in 0..40 {
+            app.cache
+                .append_to_message(LONG_THREAD, &format!("streamed line {}\n\n", i));
+            let screen = draw(&mut terminal, &mut app);
+            assert!(
+                screen.contains(&format!("streamed line {}", i)),
+                "latest streamed line {} should be on screen",
+                i
+            );
+        }
+        assert_eq!(app.unified_scroll, 0);
+    }
+
+    #[test]
+    fn test_scrolled_up_reader_stays_put_and_sees_new_lines_badge() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        let mut app = app_with_long_thread(50);
+        app.cache
+            .add_streaming_message(LONG_THREAD, "Keep going".to_string(), Vec::new());
+        draw(&mut terminal, &mut app);
+
+        // Scroll up a screen so the top line is fixed while streaming
+        app.unified_scroll = 40;
+        app.user_has_scrolled = true;
+        let before = draw(&mut terminal, &mut app);
+        assert!(!before.contains("new line"));
+
+        for i in 0..5 {
+            app.handle_message(crate::app::AppMessage::StreamToken {
+                thread_id: LONG_THREAD.to_string(),
+                token: format!("streamed line {}\n\n", i),
+            });
+        }
+        // Tokens coalesce until the frame is drawn (as the main loop does)
+        app.flush_stream_tokens();
+        let after = draw(&mut terminal, &mut app);
+
+        assert!(app.user_has_scrolled);
+        assert!(app.new_content_lines > 0);
+        assert!(after.contains(&format!("{} new lines", app.new_content_lines)));
+        // The text being read hasn't moved
+        assert_eq!(before.lines().nth(10), after.lines().nth(10));
+
+        app.scroll_to_bottom();
+        let bottom = draw(&mut terminal, &mut app);
+        assert!(!bottom.contains("new line"));
+        assert!(bottom.contains("streamed line 4"));
+    }
+
+    #[test]
+    fn test_reply_renders_quoted_chip_above_user_message() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        let mut app = app_with_long_thread(4);
+        // Message 2 (user, id 3) replies to Message 1 (assistant, id 2)
+        app.cache.get_messages_mut(LONG_THREAD).unwrap()[2].reply_to = Some(2);
+
+        let screen = draw(&mut terminal, &mut app);
+
+        let chip = screen
+            .lines()
+            .position(|l| l.contains("\u{21aa} \u{201c}Message 1\u{201d}"))
+            .expect("reply chip should be on screen");
+        let quoted = screen.lines().position(|l| l.contains("Message 2")).unwrap();
+        assert_eq!(quoted, chip + 1);
+    }
+
+    #[test]
+    fn test_expanding_tool_output_grows_conversation() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        let mut app = app_with_long_thread(4);
+        let messages = app.cache.get_messages_mut(LONG_THREAD).unwrap();
+        let last = messages.last_mut().unwrap();
+        last.start_tool_event("tool-1".to_string(), "Bash".to_string());
+        last.complete_tool_event("tool-1");
+        let stderr: Vec<String> = (0..250).map(|i| format!("stderr {}", i)).collect();
+        last.set_tool_result("tool-1", &stderr.join("\n"), true);
+
+        let collapsed = draw(&mut terminal, &mut app);
+        let collapsed_lines = app.total_content_lines;
+        assert!(!collapsed.contains("stderr 0"));
+
+        assert!(app.toggle_tool_output());
+        let expanded = draw(&mut terminal, &mut app);
+
+        // Cached heights were invalidated, so the new lines are counted
+        assert!(app.total_content_lines >= collapsed_lines + 201);
+        assert!(expanded.contains("50 more lines (press O to copy)"));
+        assert!(expanded.contains("stderr 199"));
+        assert!(!expanded.contains("stderr 200"));
+    }
+
+    #[test]
+    fn test_virtualized_render_benchmark_5000_messages() {
+        let message_count = 5000;
+        let mut app = app_with_long_thread(message_count);
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+
+        draw(&mut terminal, &mut app);
+
+        // Only messages intersecting the viewport were rendered
+        let rendered = app.rendered_lines_cache.thread_entries(LONG_THREAD).len();
+        assert!(rendered > 0);
+        assert!(rendered < 30, "rendered {} of {} messages", rendered, message_count);
+    }
 }
@@ -0,0 +1,240 @@
+//! Diff preview for Edit/Write permission prompts.
+//!
+//! Builds the proposed change from the request's `tool_input` so a file edit
+//! can be approved without opening anything else: Edit and MultiEdit show a
+//! line diff of `old_string` -> `new_string`, Write shows the new content as
+//! additions. Rows past the caller's limit are summarized in one line.
+
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
+
+use crate::state::session::PermissionRequest;
+use crate::ui::helpers::truncate_string;
+
+/// Above this many line pairs the LCS is skipped and the old text is shown
+/// as removed, the new text as added
+const MAX_LCS_CELLS: usize = 250_000;
+
+/// One line of a diff preview
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Context(String),
+    Removed(String),
+    Added(String),
+    /// Separator between MultiEdit hunks
+    Break,
+}
+
+/// Diff preview for an Edit/MultiEdit/Write request, None for other tools
+/// or when the tool input doesn't carry the change
+pub fn permission_diff(perm: &PermissionRequest) -> Option<Vec<DiffLine>> {
+    let input = perm.tool_input.as_ref()?;
+    let str_field = |value: &serde_json::Value, key: &str| {
+        value.get(key).and_then(|v| v.as_str()).map(str::to_string)
+    };
+
+    match perm.tool_name.as_str() {
+        "Edit" => Some(diff_lines(
+            &str_field(input, "old_string")?,
+            &str_field(input, "new_string")?,
+        )),
+        "MultiEdit" => {
+            let edits = input.get("edits")?.as_array()?;
+            let mut lines = Vec::new();
+            for edit in edits {
+                let (Some(old), Some(new)) =
+                    (str_field(edit, "old_string"), str_field(edit, "new_string"))
+                else {
+                    continue;
+                };
+                if !lines.is_empty() {
+                    lines.push(DiffLine::Break);
+                }
+                lines.extend(diff_lines(&old, &new));
+            }
+            (!lines.is_empty()).then_some(lines)
+        }
+        "Write" => Some(
+            str_field(input, "content")?
+                .lines()
+                .map(|l| DiffLine::Added(l.to_string()))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Line diff of two texts (longest common subsequence)
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    if old.len().saturating_mul(new.len()) > MAX_LCS_CELLS {
+        return old
+            .iter()
+            .map(|l| DiffLine::Removed(l.to_string()))
+            .chain(new.iter().map(|l| DiffLine::Added(l.to_string())))
+            .collect();
+    }
+
+    // lcs[i][j] = common lines of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(DiffLine::Context(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|l| DiffLine::Removed(l.to_string())));
+    lines.extend(new[j..].iter().map(|l| DiffLine::Added(l.to_string())));
+    lines
+}
+
+/// Styled spans for the diff, one `Vec` per row, cut to `max_width` columns.
+///
+/// Rows past `max_lines` are summarized in a final "... N more lines" row.
+pub fn diff_rows(
+    lines: &[DiffLine],
+    max_width: usize,
+    max_lines: usize,
+) -> Vec<Vec<Span<'static>>> {
+    let added = lines
+        .iter()
+        .filter(|l| matches!(l, DiffLine::Added(_)))
+        .count();
+    let removed = lines
+        .iter()
+        .filter(|l| matches!(l, DiffLine::Removed(_)))
+        .count();
+
+    let mut rows = vec![vec![
+        Span::styled(format!("+{}", added), Style::default().fg(Color::Green)),
+        Span::raw(" "),
+        Span::styled(format!("-{}", removed), Style::default().fg(Color::Red)),
+    ]];
+
+    let text_width = max_width.saturating_sub(2);
+    for line in lines.iter().take(max_lines) {
+        let (marker, text, color) = match line {
+            DiffLine::Context(text) => (" ", text.as_str(), Color::DarkGray),
+            DiffLine::Removed(text) => ("-", text.as_str(), Color::Red),
+            DiffLine::Added(text) => ("+", text.as_str(), Color::Green),
+            DiffLine::Break => ("", "\u{22EF}", Color::DarkGray),
+        };
+        let text = truncate_string(&text.replace('\t', "    "), text_width);
+        rows.push(vec![Span::styled(
+            format!("{} {}", marker, text),
+            Style::default().fg(color),
+        )]);
+    }
+
+    if lines.len() > max_lines {
+        rows.push(vec![Span::styled(
+            format!("\u{2026} {} more lines", lines.len() - max_lines),
+            Style::default().fg(Color::DarkGray),
+        )]);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn make_permission(tool_name: &str, tool_input: serde_json::Value) -> PermissionRequest {
+        PermissionRequest {
+            permission_id: "perm-1".to_string(),
+            thread_id: None,
+            tool_name: tool_name.to_string(),
+            description: String::new(),
+            context: None,
+            tool_input: Some(tool_input),
+            received_at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn test_diff_lines_keeps_common_lines_as_context() {
+        let lines = diff_lines("a\nb\nc", "a\nB\nc\nd");
+        assert_eq!(
+            lines,
+            vec![
+                DiffLine::Context("a".into()),
+                DiffLine::Removed("b".into()),
+                DiffLine::Added("B".into()),
+                DiffLine::Context("c".into()),
+                DiffLine::Added("d".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_permission_diff_for_edit_write_and_multi_edit() {
+        let edit = make_permission(
+            "Edit",
+            serde_json::json!({"file_path": "/a.rs", "old_string": "x", "new_string": "y"}),
+        );
+        assert_eq!(
+            permission_diff(&edit),
+            Some(vec![
+                DiffLine::Removed("x".into()),
+                DiffLine::Added("y".into())
+            ])
+        );
+
+        let write = make_permission(
+            "Write",
+            serde_json::json!({"file_path": "/a.rs", "content": "one\ntwo"}),
+        );
+        assert_eq!(permission_diff(&write).unwrap().len(), 2);
+
+        let multi = make_permission(
+            "MultiEdit",
+            serde_json::json!({"file_path": "/a.rs", "edits": [
+                {"old_string": "a", "new_string": "b"},
+                {"old_string": "c", "new_string": "c"}
+            ]}),
+        );
+        let lines = permission_diff(&multi).unwrap();
+        assert_eq!(lines[2], DiffLine::Break);
+        assert_eq!(lines[3], DiffLine::Context("c".into()));
+
+        let bash = make_permission("Bash", serde_json::json!({"command": "ls"}));
+        assert!(permission_diff(&bash).is_none());
+    }
+
+    #[test]
+    fn test_diff_rows_summarize_and_cap() {
+        let content: Vec<String> = (0..30).map(|i| format!("line {}", i)).collect();
+        let lines = diff_lines("", &content.join("\n"));
+        let rows = diff_rows(&lines, 80, 20);
+
+        let text = |row: &Vec<Span>| row.iter().map(|s| s.content.as_ref()).collect::<String>();
+        assert_eq!(text(&rows[0]), "+30 -0");
+        assert_eq!(rows.len(), 1 + 20 + 1);
+        assert_eq!(text(&rows[1]), "+ line 0");
+        assert_eq!(text(rows.last().unwrap()), "\u{2026} 10 more lines");
+    }
+}
@@ -9,7 +9,9 @@ use ratatui::{
     text::{Line, Span},
 };
 
+use super::permission_input::prompt_input_rows;
 use crate::state::session::{AskUserQuestionData, AskUserQuestionState, PermissionRequest};
+use crate::ui::dashboard::question_card::number_prefix;
 use crate::ui::input::parse_ask_user_question;
 use crate::ui::layout::LayoutContext;
 
@@ -99,14 +101,26 @@ fn build_standard_permission_lines(
         ),
     ]));
 
-    // Description or preview
-    let preview = get_preview_text(perm, ctx);
-    if !preview.is_empty() {
-        lines.push(Line::from(vec![
-            bar.clone(),
-            Span::styled("   ", Style::default()), // indent
-            Span::styled(preview, Style::default().fg(Color::DarkGray)),
-        ]));
+    // Command, path, URL or diff the tool would act on (`e` shows all of it)
+    let width = (ctx.text_wrap_width(0) as usize).saturating_sub(6); // bar + indent
+    let input_rows = prompt_input_rows(perm, width);
+
+    // Description, or the preview when the input has nothing to show
+    if perm.context.is_some() || input_rows.is_empty() {
+        let preview = get_preview_text(perm, ctx);
+        if !preview.is_empty() {
+            lines.push(Line::from(vec![
+                bar.clone(),
+                Span::styled("   ", Style::default()), // indent
+                Span::styled(preview, Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+    }
+
+    for row in input_rows {
+        let mut spans = vec![bar.clone(), Span::styled("   ", Style::default())];
+        spans.extend(row);
+        lines.push(Line::from(spans));
     }
 
     // Empty line
@@ -319,6 +333,7 @@ fn build_ask_user_question_lines(
                 Span::styled("   ", Style::default()),
                 Span::styled(marker, option_style),
                 Span::styled(format!("{} ", checkbox), option_style),
+                Span::styled(number_prefix(i), option_style),
                 Span::styled(opt.label.clone(), option_style),
             ]));
         } else {
@@ -340,6 +355,7 @@ fn build_ask_user_question_lines(
                 bar.clone(),
                 Span::styled("   ", Style::default()),
                 Span::styled(marker, marker_style),
+                Span::styled(number_prefix(i), label_style),
                 Span::styled(opt.label.clone(), label_style),
             ]));
         }
@@ -359,28 +375,31 @@ fn build_ask_user_question_lines(
         lines.push(Line::from(vec![bar.clone()])); // Spacing between options
     }
 
-    // "Other..." option
+    // "Other..." option (unless the question rules it out)
+    let constraints = state.current_constraints();
     let is_other_selected = current_selection.is_none();
-    let other_marker = if is_other_selected { "\u{203A} " } else { "  " };
-    let other_style = if is_other_selected {
-        Style::default()
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD)
-    } else {
-        Style::default().fg(Color::DarkGray)
-    };
-
-    lines.push(Line::from(vec![
-        bar.clone(),
-        Span::styled("   ", Style::default()),
-        Span::styled(
-            other_marker,
+    if constraints.allow_other {
+        let other_marker = if is_other_selected { "\u{203A} " } else { "  " };
+        let other_style = if is_other_selected {
             Style::default()
                 .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::styled("Other...", other_style),
-    ]));
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        lines.push(Line::from(vec![
+            bar.clone(),
+            Span::styled("   ", Style::default()),
+            Span::styled(
+                other_marker,
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("Other...", other_style),
+        ]));
+    }
 
     // "Other" text input (if active)
     if state.other_active && is_other_selected {
@@ -400,13 +419,26 @@ fn build_ask_user_question_lines(
             Span::styled(rule.clone(), Style::default().fg(Color::DarkGray)),
         ]));
 
-        // Input text with cursor
-        lines.push(Line::from(vec![
+        // Input text with cursor, and a length counter when limited
+        let mut input_spans = vec![
             bar.clone(),
             Span::styled("     ", Style::default()),
             Span::styled(display_text, Style::default().fg(Color::White)),
             Span::styled("\u{2588}", Style::default().fg(Color::White)), // cursor block
-        ]));
+        ];
+        if let Some(max) = constraints.other_max_length {
+            let len = other_text.chars().count();
+            let counter_color = if len >= max {
+                Color::Red
+            } else {
+                Color::DarkGray
+            };
+            input_spans.push(Span::styled(
+                format!("  {}/{}", len, max),
+                Style::default().fg(counter_color),
+            ));
+        }
+        lines.push(Line::from(input_spans));
 
         // Horizontal rule
         lines.push(Line::from(vec![
@@ -418,6 +450,15 @@ fn build_ask_user_question_lines(
 
     lines.push(Line::from(vec![bar.clone()])); // Empty line before help
 
+    // Why the last confirm was refused
+    if let Some(ref error) = state.validation_error {
+        lines.push(Line::from(vec![
+            bar.clone(),
+            Span::styled("   ", Style::default()),
+            Span::styled(error.clone(), Style::default().fg(Color::Red)),
+        ]));
+    }
+
     // Help line with countdown
     let help_spans = build_ask_user_help_spans(
         state,
@@ -474,11 +515,20 @@ fn build_ask_user_help_spans(
 
         spans.push(Span::styled("\u{2191}\u{2193}", help_style)); // up/down arrows
         spans.push(Span::styled(" navigate    ", help_style));
+        spans.push(Span::styled("1-9", help_style));
+        spans.push(Span::styled(" pick    ", help_style));
         spans.push(Span::styled("enter", help_style));
         spans.push(Span::styled(
             if multi_select { " submit" } else { " confirm" },
             help_style,
         ));
+
+        if multi_select {
+            spans.push(Span::styled(
+                format!("    {} selected", state.selected_count()),
+                Style::default().fg(Color::White),
+            ));
+        }
     }
 
     spans
@@ -538,6 +588,49 @@ mod tests {
         assert!(all_text.contains("Write"));
     }
 
+    #[test]
+    fn test_build_permission_lines_shows_edit_diff() {
+        let mut perm = make_permission("Edit", "Edit file");
+        perm.tool_input = Some(serde_json::json!({
+            "file_path": "/src/main.rs",
+            "old_string": "let x = 1;",
+            "new_string": "let x = 2;"
+        }));
+        let state = AskUserQuestionState::default();
+        let ctx = LayoutContext::new(100, 40);
+
+        let lines = build_permission_lines(&perm, &state, &ctx, 0);
+        let rows: Vec<String> = lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+
+        assert!(rows.iter().any(|r| r.contains("/src/main.rs")));
+        assert!(rows.iter().any(|r| r.ends_with("- let x = 1;")));
+        assert!(rows.iter().any(|r| r.ends_with("+ let x = 2;")));
+    }
+
+    #[test]
+    fn test_build_permission_lines_shows_full_command() {
+        let mut perm = make_permission("Bash", "Run command");
+        perm.tool_input = Some(serde_json::json!({
+            "command": "cargo build --release && rm -rf ./target/debug/incremental"
+        }));
+        let state = AskUserQuestionState::default();
+        let ctx = LayoutContext::new(100, 40);
+
+        let lines = build_permission_lines(&perm, &state, &ctx, 0);
+        let rows: Vec<String> = lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+
+        assert!(rows.iter().any(|r| r.ends_with("runs 2 commands")));
+        assert!(rows
+            .iter()
+            .any(|r| r.ends_with("$ cargo build --release && rm -rf ./target/debug/incremental")));
+    }
+
     #[test]
     fn test_countdown_normal() {
         let span = build_countdown_span(45);
@@ -638,6 +731,35 @@ mod tests {
         assert!(text.contains("space"));
         assert!(text.contains("toggle"));
         assert!(text.contains("submit")); // multi-select uses submit
+        assert!(text.contains("0 selected"));
+    }
+
+    #[test]
+    fn test_build_ask_user_question_lines_numbers_options() {
+        let data: AskUserQuestionData = serde_json::from_value(serde_json::json!({
+            "questions": [{
+                "question": "Which checks?",
+                "header": "Checks",
+                "options": [
+                    {"label": "Lint", "description": ""},
+                    {"label": "Tests", "description": ""}
+                ],
+                "multiSelect": true
+            }]
+        }))
+        .unwrap();
+        let mut state = AskUserQuestionState::from_data(&data);
+        state.select_number(2, 2, true);
+
+        let lines = build_ask_user_question_lines(&data, &state, &LayoutContext::new(100, 40), 60);
+        let text: Vec<String> = lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+
+        assert!(text.iter().any(|l| l.contains("1. Lint")));
+        assert!(text.iter().any(|l| l.contains("[\u{00D7}] 2. Tests")));
+        assert!(text.iter().any(|l| l.contains("1 selected")));
     }
 
     #[test]
@@ -0,0 +1,318 @@
+//! Tool input details for permission prompts.
+//!
+//! Shows what a permission would actually allow: the command, file path, URL
+//! or search pattern from the request's `tool_input`, wrapped to the prompt
+//! width rather than cut to one line. The `&&`, `||` and `;` of a chained
+//! command are picked out, with a note of how many commands it runs.
+//! Edit/Write requests add the diff preview. Tools with none of these fields
+//! show their input as pretty-printed JSON. The prompt shows the first
+//! `MAX_PROMPT_INPUT_ROWS` rows; `e` opens all of them in an overlay.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+use serde_json::Value;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::permission_diff::{diff_rows, permission_diff};
+use crate::state::session::PermissionRequest;
+use crate::ui::helpers::display_width;
+
+/// Rows of tool input shown in the prompt before `e` is needed
+pub const MAX_PROMPT_INPUT_ROWS: usize = 15;
+
+/// `tool_input` fields shown, with their labels, in order
+const INPUT_FIELDS: &[(&str, &str)] = &[
+    ("command", "$"),
+    ("file_path", "file"),
+    ("notebook_path", "notebook"),
+    ("path", "path"),
+    ("url", "url"),
+    ("pattern", "pattern"),
+    ("query", "query"),
+];
+
+/// Operators that chain commands
+const COMMAND_SEPARATORS: &[&str] = &["&&", "||", ";"];
+
+/// All rows of the request's tool input, wrapped to `width` columns
+pub fn permission_input_rows(perm: &PermissionRequest, width: usize) -> Vec<Vec<Span<'static>>> {
+    let mut rows = Vec::new();
+    let input = perm.tool_input.as_ref();
+
+    for (key, label) in INPUT_FIELDS {
+        let Some(value) = input
+            .and_then(|input| input.get(*key))
+            .and_then(Value::as_str)
+        else {
+            continue;
+        };
+        if *key == "command" {
+            rows.extend(command_rows(value, width));
+        } else {
+            let segments = [(value.to_string(), Style::default().fg(Color::White))];
+            rows.extend(wrap_segments(label, &segments, width));
+        }
+    }
+
+    let diff = permission_diff(perm);
+    if rows.is_empty() && diff.is_none() {
+        // Unknown tool: its whole input
+        if let Some(input) = input.filter(|input| !is_empty_input(input)) {
+            let pretty = serde_json::to_string_pretty(input).unwrap_or_default();
+            for line in pretty.lines() {
+                let segments = [(line.to_string(), Style::default().fg(Color::DarkGray))];
+                rows.extend(wrap_segments("", &segments, width));
+            }
+        }
+    }
+    if let Some(diff) = diff {
+        rows.extend(diff_rows(&diff, width, usize::MAX));
+    }
+    rows
+}
+
+/// The rows shown in the prompt: the first `MAX_PROMPT_INPUT_ROWS`, then a
+/// note of how many more `e` shows
+pub fn prompt_input_rows(perm: &PermissionRequest, width: usize) -> Vec<Vec<Span<'static>>> {
+    let mut rows = permission_input_rows(perm, width);
+    if rows.len() > MAX_PROMPT_INPUT_ROWS {
+        let hidden = rows.len() - MAX_PROMPT_INPUT_ROWS;
+        rows.truncate(MAX_PROMPT_INPUT_ROWS);
+        rows.push(vec![
+            Span::styled(
+                format!("\u{2026} {} more lines  ", hidden),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(
+                "[e]",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" show all", Style::default().fg(Color::DarkGray)),
+        ]);
+    }
+    rows
+}
+
+/// Rows of a shell command, one or more per line, with a note first when it
+/// chains several commands
+fn command_rows(command: &str, width: usize) -> Vec<Vec<Span<'static>>> {
+    let mut rows = Vec::new();
+    let mut count = 0;
+    for (index, line) in command.lines().enumerate() {
+        let (segments, commands) = command_segments(line);
+        count += commands;
+        let label = if index == 0 { "$" } else { "" };
+        rows.extend(wrap_segments(label, &segments, width));
+    }
+    if count > 1 {
+        rows.insert(
+            0,
+            vec![Span::styled(
+                format!("\u{26A0} runs {} commands", count),
+                Style::default().fg(Color::Yellow),
+            )],
+        );
+    }
+    rows
+}
+
+/// One line of a command split into plain text and chaining operators
+/// (outside quotes), with the number of commands on the line
+fn command_segments(line: &str) -> (Vec<(String, Style)>, usize) {
+    let plain = Style::default().fg(Color::White);
+    let operator = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+
+    let mut segments = Vec::new();
+    let mut commands = 0;
+    let mut text = String::new();
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut rest = line;
+
+    let mut flush = |text: &mut String, segments: &mut Vec<(String, Style)>| {
+        if !text.trim().is_empty() {
+            commands += 1;
+        }
+        if !text.is_empty() {
+            segments.push((std::mem::take(text), plain));
+        }
+    };
+
+    while let Some(ch) = rest.chars().next() {
+        if quote.is_none() && !escaped {
+            if let Some(op) = COMMAND_SEPARATORS.iter().find(|op| rest.starts_with(**op)) {
+                flush(&mut text, &mut segments);
+                segments.push((op.to_string(), operator));
+                rest = &rest[op.len()..];
+                continue;
+            }
+        }
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' if quote != Some('\'') => escaped = true,
+            '\'' | '"' if quote.is_none() => quote = Some(ch),
+            _ if quote == Some(ch) => quote = None,
+            _ => {}
+        }
+        text.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    flush(&mut text, &mut segments);
+    (segments, commands)
+}
+
+/// Wrap styled text to `width` columns after a `label` column; wrapped rows
+/// are indented to line up with the first
+fn wrap_segments(
+    label: &str,
+    segments: &[(String, Style)],
+    width: usize,
+) -> Vec<Vec<Span<'static>>> {
+    let label = if label.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", label)
+    };
+    let indent = " ".repeat(display_width(&label));
+    let text_width = width.saturating_sub(indent.len()).max(1);
+
+    let mut rows = Vec::new();
+    let mut row = vec![Span::styled(label, Style::default().fg(Color::DarkGray))];
+    let mut used = 0;
+    for (text, style) in segments {
+        let text = text.replace('\t', "    ").replace('\r', "");
+        let mut chunk = String::new();
+        for grapheme in text.graphemes(true) {
+            let grapheme_width = display_width(grapheme);
+            if used > 0 && used + grapheme_width > text_width {
+                if !chunk.is_empty() {
+                    row.push(Span::styled(std::mem::take(&mut chunk), *style));
+                }
+                rows.push(std::mem::replace(&mut row, vec![Span::raw(indent.clone())]));
+                used = 0;
+            }
+            chunk.push_str(grapheme);
+            used += grapheme_width;
+        }
+        if !chunk.is_empty() {
+            row.push(Span::styled(chunk, *style));
+        }
+    }
+    rows.push(row);
+    rows
+}
+
+/// Whether tool input carries nothing worth showing (`null`, `{}`)
+fn is_empty_input(input: &Value) -> bool {
+    match input {
+        Value::Null => true,
+        Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn make_permission(tool_name: &str, tool_input: Value) -> PermissionRequest {
+        PermissionRequest {
+            permission_id: "perm-1".to_string(),
+            thread_id: None,
+            tool_name: tool_name.to_string(),
+            description: String::new(),
+            context: None,
+            tool_input: Some(tool_input),
+            received_at: Instant::now(),
+        }
+    }
+
+    fn text(row: &[Span]) -> String {
+        row.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_command_is_shown_in_full_and_wrapped() {
+        let perm = make_permission(
+            "Bash",
+            serde_json::json!({"command": "rm -rf ./build/output/directory"}),
+        );
+        let rows = permission_input_rows(&perm, 20);
+        let texts: Vec<String> = rows.iter().map(|row| text(row)).collect();
+        assert_eq!(texts, vec!["$ rm -rf ./build/out", "  put/directory"]);
+    }
+
+    #[test]
+    fn test_chained_commands_are_highlighted() {
+        let (segments, commands) = command_segments("cd /tmp && echo 'a; b' ; rm -rf x");
+        assert_eq!(commands, 3);
+        let operators: Vec<&str> = segments
+            .iter()
+            .filter(|(_, style)| style.fg == Some(Color::Yellow))
+            .map(|(text, _)| text.as_str())
+            .collect();
+        assert_eq!(operators, vec!["&&", ";"]);
+
+        let perm = make_permission(
+            "Bash",
+            serde_json::json!({"command": "make && make install"}),
+        );
+        let rows = permission_input_rows(&perm, 80);
+        assert_eq!(text(&rows[0]), "\u{26A0} runs 2 commands");
+    }
+
+    #[test]
+    fn test_file_and_url_fields() {
+        let perm = make_permission(
+            "WebFetch",
+            serde_json::json!({"url": "https://example.com", "prompt": "summarize"}),
+        );
+        let rows = permission_input_rows(&perm, 80);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(text(&rows[0]), "url https://example.com");
+    }
+
+    #[test]
+    fn test_unknown_tool_shows_pretty_json() {
+        let perm = make_permission("mcp__db__query", serde_json::json!({"sql": "select 1"}));
+        let texts: Vec<String> = permission_input_rows(&perm, 80)
+            .iter()
+            .map(|row| text(row))
+            .collect();
+        assert_eq!(texts, vec!["{", "  \"sql\": \"select 1\"", "}"]);
+    }
+
+    #[test]
+    fn test_prompt_rows_are_capped() {
+        let command: Vec<String> = (0..30).map(|i| format!("echo {}", i)).collect();
+        let perm = make_permission("Bash", serde_json::json!({"command": command.join("\n")}));
+
+        let rows = prompt_input_rows(&perm, 80);
+        assert_eq!(rows.len(), MAX_PROMPT_INPUT_ROWS + 1);
+        // 30 lines plus the "runs 30 commands" note
+        assert_eq!(
+            text(rows.last().unwrap()),
+            "\u{2026} 16 more lines  [e] show all"
+        );
+    }
+
+    #[test]
+    fn test_edit_shows_path_and_diff() {
+        let perm = make_permission(
+            "Edit",
+            serde_json::json!({"file_path": "/a.rs", "old_string": "x", "new_string": "y"}),
+        );
+        let texts: Vec<String> = permission_input_rows(&perm, 80)
+            .iter()
+            .map(|row| text(row))
+            .collect();
+        assert_eq!(texts, vec!["file /a.rs", "+1 -1", "- x", "+ y"]);
+    }
+}
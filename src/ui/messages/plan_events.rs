@@ -81,7 +81,7 @@ pub fn render_plan_approval(
     // Plan content (markdown rendered) or fallback
     if let Some(content) = &summary.plan_content {
         if !content.trim().is_empty() {
-            let rendered = markdown_cache.render(content);
+            let rendered = markdown_cache.render_width(content, separator_width.saturating_sub(2));
             for line in rendered.iter() {
                 // Prefix each line with │
                 let mut prefixed = vec![Span::styled("│ ", Style::default().fg(Color::DarkGray))];
@@ -0,0 +1,111 @@
+//! Message search highlighting
+//!
+//! Splits rendered spans around search query occurrences so matches can be
+//! styled without touching the cached message lines.
+
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+use crate::cache::find_matches;
+
+use super::super::theme::{COLOR_SEARCH_CURRENT_BG, COLOR_SEARCH_MATCH_BG};
+
+/// Highlight occurrences of `query` in rendered lines.
+///
+/// Occurrences are counted in order across all lines; the occurrence at
+/// `current` (if any) gets the stronger "current match" style. Matches that
+/// span across two spans are not highlighted.
+///
+/// Returns the line index of each highlighted occurrence, in order.
+pub fn highlight_search_matches(
+    lines: &mut [Line<'static>],
+    query: &str,
+    current: Option<usize>,
+) -> Vec<usize> {
+    let mut occurrence_lines = Vec::new();
+    if query.is_empty() {
+        return occurrence_lines;
+    }
+
+    for (line_index, line) in lines.iter_mut().enumerate() {
+        let mut new_spans: Vec<Span<'static>> = Vec::with_capacity(line.spans.len());
+
+        for span in line.spans.drain(..) {
+            let matches = find_matches(&span.content, query);
+            if matches.is_empty() {
+                new_spans.push(span);
+                continue;
+            }
+
+            let text = span.content.as_ref();
+            let mut cursor = 0;
+            for (start, end) in matches {
+                if start > cursor {
+                    new_spans.push(Span::styled(text[cursor..start].to_string(), span.style));
+                }
+                let bg = if current == Some(occurrence_lines.len()) {
+                    COLOR_SEARCH_CURRENT_BG
+                } else {
+                    COLOR_SEARCH_MATCH_BG
+                };
+                new_spans.push(Span::styled(
+                    text[start..end].to_string(),
+                    span.style.patch(Style::default().fg(Color::Black).bg(bg)),
+                ));
+                occurrence_lines.push(line_index);
+                cursor = end;
+            }
+            if cursor < text.len() {
+                new_spans.push(Span::styled(text[cursor..].to_string(), span.style));
+            }
+        }
+
+        line.spans = new_spans;
+    }
+
+    occurrence_lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_highlight_splits_span_and_preserves_text() {
+        let mut lines = vec![Line::from("use Tokio for async")];
+        let occurrences = highlight_search_matches(&mut lines, "tokio", None);
+
+        assert_eq!(occurrences, vec![0]);
+        assert_eq!(line_text(&lines[0]), "use Tokio for async");
+        assert_eq!(lines[0].spans.len(), 3);
+        assert_eq!(lines[0].spans[1].content, "Tokio");
+        assert_eq!(lines[0].spans[1].style.bg, Some(COLOR_SEARCH_MATCH_BG));
+    }
+
+    #[test]
+    fn test_highlight_marks_current_occurrence() {
+        let mut lines = vec![Line::from("a match"), Line::from("another match")];
+        let occurrences = highlight_search_matches(&mut lines, "match", Some(1));
+
+        assert_eq!(occurrences, vec![0, 1]);
+        let current = lines[1]
+            .spans
+            .iter()
+            .find(|s| s.content == "match")
+            .unwrap();
+        assert_eq!(current.style.bg, Some(COLOR_SEARCH_CURRENT_BG));
+    }
+
+    #[test]
+    fn test_highlight_empty_query_is_noop() {
+        let mut lines = vec![Line::from("unchanged")];
+        assert!(highlight_search_matches(&mut lines, "", None).is_empty());
+        assert_eq!(lines[0].spans.len(), 1);
+    }
+}
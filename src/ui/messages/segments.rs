@@ -4,14 +4,17 @@
 //! grouping and tree connectors.
 
 use ratatui::{style::Style, text::Line};
+use unicode_width::UnicodeWidthStr;
 
 use crate::markdown::MarkdownCache;
 use crate::models::MessageSegment;
+use crate::rendered_lines_cache::RenderedLinesCache;
 
 use super::super::layout::LayoutContext;
-use super::subagent_events::render_subagent_events_block;
+use super::subagent_events::render_subagent_tree;
 use super::text_wrapping::{wrap_line_with_prefix, wrap_lines_with_prefix};
-use super::tool_events::render_tool_event;
+use super::tool_diff::render_tool_diff;
+use super::tool_events::{render_tool_event, render_tool_output};
 
 /// Render message segments, grouping consecutive subagent events for proper tree connectors
 ///
@@ -21,19 +24,26 @@ use super::tool_events::render_tool_event;
 /// Uses `LayoutContext` for responsive text truncation across all segment types.
 ///
 /// # Arguments
+/// * `message_id` - ID of the message, keying its incremental renders
 /// * `segments` - The message segments to render
 /// * `tick_count` - Current tick for animations
+/// * `is_streaming` - Whether the message is still streaming (finished subagent trees collapse)
 /// * `label` - Label prefix (e.g., "| " for user messages)
 /// * `label_style` - Style for the label
 /// * `ctx` - Layout context for responsive sizing
-/// * `markdown_cache` - Cache for markdown rendering
+/// * `markdown_cache` - Cache for markdown rendering (incremental while streaming)
+/// * `lines_cache` - Cache holding the diffs of file edit tool events
+#[allow(clippy::too_many_arguments)]
 pub fn render_message_segments(
+    message_id: i64,
     segments: &[MessageSegment],
     tick_count: u64,
+    is_streaming: bool,
     label: &'static str,
     label_style: Style,
     ctx: &LayoutContext,
     markdown_cache: &mut MarkdownCache,
+    lines_cache: &mut RenderedLinesCache,
 ) -> (Vec<Line<'static>>, bool) {
     let mut lines: Vec<Line<'static>> = Vec::new();
     let mut is_first_line = true;
@@ -42,11 +52,17 @@ pub fn render_message_segments(
     // Calculate max width for wrapping (text_wrap_width accounts for borders/margins)
     // We use indent_level=0 since the prefix handles indentation
     let max_width = ctx.text_wrap_width(0) as usize;
+    // Width left for markdown after the prefix (tables are fitted to it)
+    let content_width = max_width.saturating_sub(label.width());
 
     while i < segments.len() {
         match &segments[i] {
             MessageSegment::Text(text) => {
-                let segment_lines = markdown_cache.render(text);
+                let segment_lines = if is_streaming {
+                    markdown_cache.render_streaming(message_id, i, text, content_width)
+                } else {
+                    markdown_cache.render_width(text, content_width)
+                };
                 // Wrap and prepend vertical bar to ALL text lines
                 // This ensures wrapped continuations also get the prefix
                 lines.extend(wrap_lines_with_prefix(
@@ -71,6 +87,22 @@ pub fn render_message_segments(
                     max_width,
                     None,
                 ));
+                // Diff of a file edit, wrapped under the tool line
+                lines.extend(wrap_lines_with_prefix(
+                    render_tool_diff(event, lines_cache),
+                    label,
+                    label_style,
+                    max_width,
+                    None,
+                ));
+                // Expanded output block, wrapped under the tool line
+                lines.extend(wrap_lines_with_prefix(
+                    render_tool_output(event),
+                    label,
+                    label_style,
+                    max_width,
+                    None,
+                ));
                 is_first_line = false;
                 i += 1;
             }
@@ -87,7 +119,7 @@ pub fn render_message_segments(
                 }
 
                 // Render the block with tree connectors, wrap if needed
-                for line in render_subagent_events_block(&subagent_events, tick_count, ctx) {
+                for line in render_subagent_tree(&subagent_events, tick_count, is_streaming, ctx) {
                     lines.extend(wrap_line_with_prefix(
                         line,
                         label,
@@ -1,7 +1,10 @@
 //! Subagent event rendering
 //!
 //! Renders subagent execution status with tree connectors, icons, and progress indicators.
+//! Each subagent keeps its own timer. Once the response is done, a tree of several
+//! subagents collapses to one summary line (`s` expands it again).
 
+use chrono::Utc;
 use ratatui::{
     style::{Color, Style},
     text::{Line, Span},
@@ -37,6 +40,26 @@ impl TreeConnector {
     }
 }
 
+/// Elapsed time of a subagent: "12s", "1m 05s", "1h 02m"
+fn format_elapsed(secs: i64) -> String {
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+/// "1 tool use" / "N tool uses"
+fn tool_uses(count: usize) -> String {
+    if count == 1 {
+        "1 tool use".to_string()
+    } else {
+        format!("{} tool uses", count)
+    }
+}
+
 /// Render a single subagent event as a Line with optional tree connector
 ///
 /// Uses subagent-specific icons, color-coded status indicators, and tree connectors
@@ -45,8 +68,8 @@ impl TreeConnector {
 /// Uses `LayoutContext` for responsive description and summary truncation.
 ///
 /// # Display format
-/// - Running:  `[connector] [spinner] Task(description)` (cyan)
-/// - Complete: `[connector] Done (N tool uses - summary)` (green)
+/// - Running:  `[connector] [spinner] Task(description) type · 12s` (cyan)
+/// - Complete: `[connector] Done (N tool uses · 1m 05s · summary)` (green)
 pub fn render_subagent_event(
     event: &SubagentEvent,
     tick_count: u64,
@@ -96,6 +119,14 @@ pub fn render_subagent_event(
                 ),
                 Span::styled(description, Style::default().fg(COLOR_SUBAGENT_RUNNING)),
                 Span::styled(")", Style::default().fg(COLOR_SUBAGENT_RUNNING)),
+                Span::styled(
+                    format!(
+                        " {} \u{00B7} {}",
+                        event.subagent_type,
+                        format_elapsed(event.elapsed_secs())
+                    ),
+                    Style::default().fg(COLOR_DIM),
+                ),
             ]));
 
             // Progress line if available
@@ -121,12 +152,12 @@ pub fn render_subagent_event(
             }
         }
         SubagentEventStatus::Complete => {
-            // Format: connector + Done (N tool uses - summary) or just (N tool uses)
-            let tool_count_str = if event.tool_call_count == 1 {
-                "1 tool use".to_string()
-            } else {
-                format!("{} tool uses", event.tool_call_count)
-            };
+            // Format: connector + Done (N tool uses · duration · summary)
+            let tool_count_str = format!(
+                "{} \u{00B7} {}",
+                tool_uses(event.tool_call_count),
+                format_elapsed(event.elapsed_secs())
+            );
 
             let display_text = if let Some(ref summary) = event.summary {
                 // Truncate summary using responsive max length
@@ -191,6 +222,73 @@ pub fn render_subagent_events_block(
     lines
 }
 
+/// Render a block of consecutive subagent events under an assistant message
+///
+/// While the response streams, the block is the live tree. Once it's done, a
+/// tree of several subagents becomes a summary line (count, total tool uses,
+/// wall-clock duration), followed by the tree itself when expanded.
+pub fn render_subagent_tree(
+    events: &[&SubagentEvent],
+    tick_count: u64,
+    is_streaming: bool,
+    ctx: &LayoutContext,
+) -> Vec<Line<'static>> {
+    if is_streaming || events.len() < 2 {
+        return render_subagent_events_block(events, tick_count, ctx);
+    }
+
+    let expanded = events.iter().any(|event| event.expanded);
+    let mut lines = vec![render_subagent_tree_summary(events, expanded, ctx)];
+    if expanded {
+        lines.extend(render_subagent_events_block(events, tick_count, ctx));
+    }
+    lines
+}
+
+/// Summary line of a finished subagent tree:
+/// `▸ 3 subagents (14 tool uses · 1m 05s)  [s] toggle`
+fn render_subagent_tree_summary(
+    events: &[&SubagentEvent],
+    expanded: bool,
+    ctx: &LayoutContext,
+) -> Line<'static> {
+    let arrow = if expanded { "\u{25BE}" } else { "\u{25B8}" };
+    let tool_calls: usize = events.iter().map(|event| event.tool_call_count).sum();
+    // Parallel subagents overlap, so the duration is first start to last finish
+    let started = events.iter().map(|event| event.started_at).min();
+    let finished = events
+        .iter()
+        .map(|event| event.completed_at.unwrap_or_else(Utc::now))
+        .max();
+    let wall_secs = match (started, finished) {
+        (Some(started), Some(finished)) => (finished - started).num_seconds().max(0),
+        _ => 0,
+    };
+    let toggle_hint = if ctx.is_extra_small() {
+        " [s]"
+    } else {
+        "  [s] toggle"
+    };
+
+    Line::from(vec![
+        Span::styled("  ", Style::default()),
+        Span::styled(
+            format!("{} ", arrow),
+            Style::default().fg(COLOR_SUBAGENT_COMPLETE),
+        ),
+        Span::styled(
+            format!(
+                "{} subagents ({} \u{00B7} {})",
+                events.len(),
+                tool_uses(tool_calls),
+                format_elapsed(wall_secs)
+            ),
+            Style::default().fg(COLOR_SUBAGENT_COMPLETE),
+        ),
+        Span::styled(toggle_hint, Style::default().fg(COLOR_DIM)),
+    ])
+}
+
 /// Render subagent status with spinner and progress (LEGACY - kept for potential future use)
 /// UI design:
 /// ```text
@@ -322,6 +420,76 @@ mod tests {
         assert!(line_text.contains("Found relevant configuration files"));
     }
 
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(7), "7s");
+        assert_eq!(format_elapsed(65), "1m 05s");
+        assert_eq!(format_elapsed(3720), "1h 02m");
+    }
+
+    #[test]
+    fn test_parallel_subagents_keep_their_own_timers() {
+        let mut first = SubagentEvent::new(
+            "task-1".to_string(),
+            "Search".to_string(),
+            "Explore".to_string(),
+        );
+        first.started_at = Utc::now() - chrono::Duration::seconds(75);
+        let mut second =
+            SubagentEvent::new("task-2".to_string(), "Plan".to_string(), "Plan".to_string());
+        second.started_at = Utc::now() - chrono::Duration::seconds(4);
+
+        let ctx = LayoutContext::new(120, 40);
+        let lines = render_subagent_events_block(&[&first, &second], 0, &ctx);
+        assert!(line_text(&lines[0]).ends_with("Explore \u{00B7} 1m 15s"));
+        assert!(line_text(&lines[1]).ends_with("Plan \u{00B7} 4s"));
+
+        second.tool_call_count = 2;
+        second.complete(Some("Planned".to_string()));
+        let lines = render_subagent_event(&second, 0, TreeConnector::LastBranch, &ctx);
+        assert!(line_text(&lines[0]).contains("Done (2 tool uses \u{00B7} 4s \u{00B7} Planned)"));
+    }
+
+    #[test]
+    fn test_finished_tree_collapses_to_summary_line() {
+        let mut events = Vec::new();
+        for (i, count) in [3, 4].into_iter().enumerate() {
+            let mut event = SubagentEvent::new(
+                format!("task-{}", i),
+                "Search".to_string(),
+                "Explore".to_string(),
+            );
+            event.progress_message = Some("Reading".to_string());
+            event.tool_call_count = count;
+            event.complete(Some("Found it".to_string()));
+            events.push(event);
+        }
+        let ctx = LayoutContext::new(120, 40);
+
+        // Live tree while streaming
+        let refs: Vec<&SubagentEvent> = events.iter().collect();
+        let streaming = render_subagent_tree(&refs, 0, true, &ctx);
+        assert_eq!(streaming.len(), 2);
+
+        let collapsed = render_subagent_tree(&refs, 0, false, &ctx);
+        assert_eq!(collapsed.len(), 1);
+        let summary = line_text(&collapsed[0]);
+        assert!(summary.contains("\u{25B8} 2 subagents (7 tool uses"));
+        assert!(summary.ends_with("[s] toggle"));
+
+        for event in &mut events {
+            event.expanded = true;
+        }
+        let refs: Vec<&SubagentEvent> = events.iter().collect();
+        let expanded = render_subagent_tree(&refs, 0, false, &ctx);
+        assert_eq!(expanded.len(), 3);
+        assert!(line_text(&expanded[0]).contains("\u{25BE} 2 subagents"));
+    }
+
     #[test]
     fn test_render_subagent_event_truncates_description_on_narrow_terminal() {
         // Create event with a very long description
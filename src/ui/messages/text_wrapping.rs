@@ -3,11 +3,14 @@
 //! Provides functions to wrap styled text lines while maintaining prefixes
 //! and optional background colors for visual continuity.
 
-use crate::markdown::{contains_osc8_sequence, display_width_ignoring_escapes};
+use crate::markdown::{
+    contains_osc8_sequence, display_width_ignoring_escapes, strip_osc8_sequences,
+};
 use ratatui::{
     style::{Color, Style},
     text::{Line, Span},
 };
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 /// Apply full-width background color to a single line.
@@ -151,24 +154,23 @@ pub fn wrap_line_with_prefix(
                     current_line_spans.push(Span::styled(std::mem::take(word), word_style));
                     *current_line_width = word_width; // Will exceed content_width, but that's OK
                 } else {
-                    // For non-OSC8 content, use character-by-character breaking
+                    // For non-OSC8 content, break between grapheme clusters so
+                    // emoji sequences and combining marks stay intact
                     let mut remaining = std::mem::take(word);
                     while !remaining.is_empty() {
-                        let mut chunk = String::new();
+                        let mut split = remaining.len();
                         let mut chunk_width = 0;
-                        let mut chars = remaining.chars().peekable();
-
-                        while let Some(c) = chars.peek() {
-                            let c_width =
-                                unicode_width::UnicodeWidthChar::width(*c).unwrap_or(1);
-                            if chunk_width + c_width > content_width && !chunk.is_empty() {
+                        for (index, grapheme) in remaining.grapheme_indices(true) {
+                            let grapheme_width = grapheme.width();
+                            if chunk_width + grapheme_width > content_width && index > 0 {
+                                split = index;
                                 break;
                             }
-                            chunk.push(chars.next().unwrap());
-                            chunk_width += c_width;
+                            chunk_width += grapheme_width;
                         }
 
-                        remaining = chars.collect();
+                        let rest = remaining.split_off(split);
+                        let chunk = std::mem::replace(&mut remaining, rest);
 
                         if !remaining.is_empty() {
                             // More to come, finish this line
@@ -178,8 +180,8 @@ pub fn wrap_line_with_prefix(
                             *current_line_width = 0;
                         } else {
                             // Last chunk
-                            current_line_spans.push(Span::styled(chunk.clone(), word_style));
-                            *current_line_width = display_width_ignoring_escapes(&chunk);
+                            current_line_spans.push(Span::styled(chunk, word_style));
+                            *current_line_width = chunk_width;
                         }
                     }
                 }
@@ -192,8 +194,8 @@ pub fn wrap_line_with_prefix(
     };
 
     for (text, style) in segments {
-        for c in text.chars() {
-            if c == ' ' || c == '\t' {
+        for grapheme in text.graphemes(true) {
+            if grapheme == " " || grapheme == "\t" {
                 // Flush current word
                 if let Some(ws) = current_word_style {
                     flush_word(
@@ -231,7 +233,7 @@ pub fn wrap_line_with_prefix(
                     }
                     current_word_style = Some(style);
                 }
-                current_word.push(c);
+                current_word.push_str(grapheme);
             }
         }
     }
@@ -291,12 +293,14 @@ pub fn wrap_lines_with_prefix(
 /// This function calculates how many visual lines a set of logical lines will
 /// occupy when rendered with word wrapping enabled, given a specific viewport width.
 ///
-/// Each logical line wraps to ceil(char_count / viewport_width) visual lines.
+/// Each logical line is packed grapheme by grapheme into rows of
+/// `viewport_width` columns; a wide grapheme (CJK, emoji) that doesn't fit in
+/// the space left on a row moves to the next one, as it does in the terminal.
 /// Empty lines count as 1 visual line.
 ///
 /// # Arguments
 /// * `lines` - The logical lines to estimate
-/// * `viewport_width` - The width of the viewport in characters
+/// * `viewport_width` - The width of the viewport in columns
 ///
 /// # Returns
 /// The estimated number of visual lines after wrapping
@@ -308,13 +312,19 @@ pub fn estimate_wrapped_line_count(lines: &[Line], viewport_width: usize) -> usi
     lines
         .iter()
         .map(|line| {
-            let char_count: usize = line.spans.iter().map(|s| s.content.chars().count()).sum();
-            if char_count == 0 {
-                1 // Empty line still takes 1 row
-            } else {
-                // Ceiling division: (char_count + viewport_width - 1) / viewport_width
-                char_count.div_ceil(viewport_width)
+            let mut rows = 1; // Empty line still takes 1 row
+            let mut row_width = 0;
+            for span in &line.spans {
+                for grapheme in strip_osc8_sequences(&span.content).graphemes(true) {
+                    let grapheme_width = grapheme.width();
+                    if row_width + grapheme_width > viewport_width && row_width > 0 {
+                        rows += 1;
+                        row_width = 0;
+                    }
+                    row_width += grapheme_width;
+                }
             }
+            rows
         })
         .sum()
 }
@@ -642,4 +652,79 @@ mod tests {
         // Should be a single line since display width is small
         assert_eq!(result.len(), 1, "Short display text should fit on one line");
     }
+
+    /// Rows ratatui fills when it wraps `line` into a `width`-column area
+    fn rendered_rows(line: Line<'static>, width: u16) -> usize {
+        use ratatui::{
+            backend::TestBackend,
+            widgets::{Paragraph, Wrap},
+            Terminal,
+        };
+
+        let height = 40;
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|f| {
+                f.render_widget(Paragraph::new(line).wrap(Wrap { trim: false }), f.area());
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .rev()
+            .find(|&y| (0..width).any(|x| !buffer[(x, y)].symbol().trim().is_empty()))
+            .map_or(0, |y| y as usize + 1)
+    }
+
+    #[test]
+    fn test_estimate_wrapped_line_count_matches_ratatui_for_mixed_width() {
+        // CJK (width 2), an emoji with skin-tone modifier (one grapheme) and
+        // combining accents (zero-width marks on the previous character)
+        let samples = ["日本語テスト👍🏽👍🏽abc", "e\u{301}e\u{301}漢字x👍🏽y日本"];
+
+        for text in samples {
+            // Narrower than two wide characters, ratatui overlaps them in
+            // the buffer, so there is no row count to match
+            for width in [4u16, 5, 7, 10, 16, 40] {
+                let line = Line::from(text);
+                assert_eq!(
+                    estimate_wrapped_line_count(std::slice::from_ref(&line), width as usize),
+                    rendered_rows(line, width),
+                    "{:?} at width {}",
+                    text,
+                    width
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrap_line_with_prefix_mixed_width_fits_and_keeps_graphemes() {
+        let text = "日本語 test 👍🏽";
+
+        for width in [8usize, 9, 10, 13, 40] {
+            let wrapped =
+                wrap_line_with_prefix(Line::from(text), "| ", Style::default(), width, None);
+
+            // Every wrapped row fits, so the estimate for the wrapped output
+            // is exactly one row per line - what the renderer draws
+            for line in &wrapped {
+                assert!(
+                    line.width() <= width,
+                    "{:?} overflows width {}",
+                    line,
+                    width
+                );
+            }
+            assert_eq!(estimate_wrapped_line_count(&wrapped, width), wrapped.len());
+
+            // No grapheme was split across rows
+            let joined: String = wrapped
+                .iter()
+                .flat_map(|line| line.spans.iter().skip(1))
+                .map(|span| span.content.as_ref())
+                .collect();
+            assert!(joined.contains("👍🏽"), "emoji split at width {}", width);
+            assert_eq!(joined.replace(' ', ""), text.replace(' ', ""));
+        }
+    }
 }
@@ -0,0 +1,174 @@
+//! Message timestamp rendering
+//!
+//! With timestamps on (`T` or `/timestamps`), each message's leading blank
+//! line carries its relative time ("3m", "2h", "yesterday"), right-aligned,
+//! and a header row ("— Tuesday, Jan 14 —") starts every day. Narrow
+//! terminals only get the day headers. Both are added around the cached
+//! message lines, so relative times stay current; the day header rows are
+//! counted in the height estimates (see `day_separator_rows`).
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+
+use crate::models::Message;
+
+use super::super::helpers::{display_width, format_compact_age};
+use super::super::layout::LayoutContext;
+use super::super::theme::COLOR_DIM;
+
+/// Local calendar day of a message
+fn local_day(message: &Message) -> NaiveDate {
+    message.created_at.with_timezone(&Local).date_naive()
+}
+
+/// Whether the message at `index` starts a new day (and so gets a header
+/// row): the first message, or one on a different day than the one before
+pub fn starts_new_day(messages: &[Message], index: usize) -> bool {
+    match (
+        index.checked_sub(1).and_then(|i| messages.get(i)),
+        messages.get(index),
+    ) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(previous), Some(message)) => local_day(previous) != local_day(message),
+    }
+}
+
+/// Rows the timestamps add above the message at `index`
+pub fn day_separator_rows(messages: &[Message], index: usize) -> usize {
+    usize::from(starts_new_day(messages, index))
+}
+
+/// Relative time of `at` as of `now`: "now", "3m", "2h", "yesterday",
+/// a weekday within the week, then the date
+pub fn relative_time(at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let age = now - at;
+    if age.num_hours() < 24 {
+        return format_compact_age(age);
+    }
+    let day = at.with_timezone(&Local).date_naive();
+    let today = now.with_timezone(&Local).date_naive();
+    if today.pred_opt() == Some(day) {
+        "yesterday".to_string()
+    } else if age.num_days() < 7 {
+        day.format("%a").to_string()
+    } else {
+        day.format("%b %-d").to_string()
+    }
+}
+
+/// Header row text for a day ("— Tuesday, Jan 14 —")
+pub fn day_header(day: NaiveDate) -> String {
+    format!("\u{2014} {} \u{2014}", day.format("%A, %b %-d"))
+}
+
+/// Add the day header and the relative time to the rendered lines of the
+/// message at `index`
+pub fn add_timestamps(
+    lines: &mut Vec<Line<'static>>,
+    messages: &[Message],
+    index: usize,
+    ctx: &LayoutContext,
+    now: DateTime<Utc>,
+) {
+    let Some(message) = messages.get(index) else {
+        return;
+    };
+    let style = Style::default().fg(COLOR_DIM);
+
+    // The leading blank line gets the time, right-aligned with the text
+    if !ctx.is_narrow() {
+        if let Some(first) = lines.first_mut().filter(|line| line.width() == 0) {
+            let time = relative_time(message.created_at, now);
+            let width = ctx.text_wrap_width(0) as usize;
+            let padding = width.saturating_sub(display_width(&time));
+            *first = Line::from(vec![
+                Span::raw(" ".repeat(padding)),
+                Span::styled(time, style),
+            ]);
+        }
+    }
+
+    if starts_new_day(messages, index) {
+        lines.insert(
+            0,
+            Line::from(Span::styled(day_header(local_day(message)), style)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+    use crate::models::MessageRole;
+    use chrono::{Duration, TimeZone};
+
+    fn messages_at(times: &[DateTime<Utc>]) -> Vec<Message> {
+        let mut cache = ThreadCache::new();
+        for _ in times {
+            cache.add_message_simple("t", MessageRole::Assistant, "hi".to_string());
+        }
+        let mut messages = cache.get_messages("t").unwrap().clone();
+        for (message, time) in messages.iter_mut().zip(times) {
+            message.created_at = *time;
+        }
+        messages
+    }
+
+    fn local(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Local
+            .with_ymd_and_hms(y, m, d, h, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = local(2025, 1, 15, 12);
+        assert_eq!(relative_time(now - Duration::seconds(20), now), "now");
+        assert_eq!(relative_time(now - Duration::minutes(3), now), "3m");
+        assert_eq!(relative_time(now - Duration::hours(2), now), "2h");
+        assert_eq!(relative_time(local(2025, 1, 14, 9), now), "yesterday");
+        assert_eq!(relative_time(local(2025, 1, 12, 9), now), "Sun");
+        assert_eq!(relative_time(local(2024, 12, 25, 9), now), "Dec 25");
+    }
+
+    #[test]
+    fn test_day_headers_start_each_day() {
+        let messages = messages_at(&[
+            local(2025, 1, 14, 9),
+            local(2025, 1, 14, 18),
+            local(2025, 1, 15, 8),
+        ]);
+        assert_eq!(day_separator_rows(&messages, 0), 1);
+        assert_eq!(day_separator_rows(&messages, 1), 0);
+        assert_eq!(day_separator_rows(&messages, 2), 1);
+        assert_eq!(
+            day_header(local_day(&messages[0])),
+            "\u{2014} Tuesday, Jan 14 \u{2014}"
+        );
+    }
+
+    #[test]
+    fn test_add_timestamps_on_wide_and_narrow_terminals() {
+        let now = local(2025, 1, 14, 12);
+        let messages = messages_at(&[local(2025, 1, 14, 9)]);
+        let render = || vec![Line::from(""), Line::from("│ hi")];
+
+        let mut wide = render();
+        add_timestamps(&mut wide, &messages, 0, &LayoutContext::new(100, 40), now);
+        assert_eq!(wide.len(), 3);
+        assert_eq!(wide[0].to_string(), "\u{2014} Tuesday, Jan 14 \u{2014}");
+        assert!(wide[1].to_string().ends_with(" 3h"));
+        assert_eq!(wide[1].width(), 96);
+
+        let mut narrow = render();
+        add_timestamps(&mut narrow, &messages, 0, &LayoutContext::new(60, 40), now);
+        assert_eq!(narrow.len(), 3);
+        assert_eq!(narrow[1].width(), 0);
+    }
+}
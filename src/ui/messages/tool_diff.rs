@@ -0,0 +1,339 @@
+//! Inline diffs for Edit/Write tool events
+//!
+//! File edits show the change under the tool line: removed lines red, added
+//! lines green, context dim, with comments and keywords of the file's language
+//! picked out. Collapsed diffs stop after `MAX_TOOL_DIFF_LINES`; `o` expands
+//! them together with the tool output. Hunks that only change whitespace are
+//! collapsed to a note, and payloads over `MAX_DIFF_PAYLOAD_BYTES` get a
+//! summary line instead of a diff.
+//!
+//! Diffs are computed on first render and cached in `RenderedLinesCache` by
+//! tool call ID, so re-rendering a message doesn't diff the edit again.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use serde_json::Value;
+
+use crate::models::{ToolEvent, ToolEventStatus};
+use crate::rendered_lines_cache::RenderedLinesCache;
+
+use super::super::theme::COLOR_DIM;
+use super::permission_diff::{diff_lines, DiffLine};
+
+/// Diff lines shown while the diff is collapsed
+pub const MAX_TOOL_DIFF_LINES: usize = 80;
+
+/// Tool arguments above this size get a summary line instead of a diff
+const MAX_DIFF_PAYLOAD_BYTES: usize = 100 * 1024;
+
+/// Indent of diff lines under the tool line
+const DIFF_INDENT: &str = "    ";
+
+/// Keywords shown bold in diff lines
+const KEYWORDS: &[&str] = &[
+    "async", "await", "class", "const", "def", "else", "enum", "export", "fn", "for", "from",
+    "func", "function", "if", "impl", "import", "let", "match", "mod", "pub", "return", "self",
+    "static", "struct", "trait", "type", "use", "var", "while",
+];
+
+/// Whether a tool's input carries a file change
+fn is_file_edit_tool(name: &str) -> bool {
+    matches!(name, "Edit" | "MultiEdit" | "Write")
+}
+
+/// Diff lines of an Edit/MultiEdit/Write event, cached by tool call ID.
+///
+/// Empty for other tools and while the arguments are still streaming in.
+pub fn render_tool_diff(event: &ToolEvent, cache: &mut RenderedLinesCache) -> Vec<Line<'static>> {
+    if !is_file_edit_tool(&event.function_name) {
+        return Vec::new();
+    }
+    if let Some(lines) = cache.get_tool_diff(&event.tool_call_id, event.output_expanded) {
+        return lines.clone();
+    }
+
+    let Some(lines) = build_tool_diff_lines(event) else {
+        return Vec::new();
+    };
+    // Arguments of a running tool may still change
+    if event.status != ToolEventStatus::Running {
+        cache.insert_tool_diff(&event.tool_call_id, event.output_expanded, lines.clone());
+    }
+    lines
+}
+
+/// Build the diff lines, None if the arguments aren't complete JSON yet
+fn build_tool_diff_lines(event: &ToolEvent) -> Option<Vec<Line<'static>>> {
+    if event.args_json.len() > MAX_DIFF_PAYLOAD_BYTES {
+        return Some(vec![note_line(format!(
+            "(diff skipped: {}KB change)",
+            event.args_json.len() / 1024
+        ))]);
+    }
+
+    let input: Value = serde_json::from_str(&event.args_json).ok()?;
+    let hunks = edit_hunks(&event.function_name, &input)?;
+    let comment_prefix = input
+        .get("file_path")
+        .and_then(Value::as_str)
+        .and_then(comment_prefix_for);
+
+    let mut diff: Vec<DiffRow> = Vec::new();
+    for (i, (old, new)) in hunks.iter().enumerate() {
+        if i > 0 {
+            diff.push(DiffRow::Line(DiffLine::Break));
+        }
+        if old != new && old.split_whitespace().eq(new.split_whitespace()) {
+            diff.push(DiffRow::WhitespaceOnly);
+        } else {
+            diff.extend(diff_lines(old, new).into_iter().map(DiffRow::Line));
+        }
+    }
+
+    let shown = if event.output_expanded {
+        diff.len()
+    } else {
+        diff.len().min(MAX_TOOL_DIFF_LINES)
+    };
+    let mut lines: Vec<Line<'static>> = diff[..shown]
+        .iter()
+        .map(|row| match row {
+            DiffRow::Line(line) => diff_line(line, comment_prefix),
+            DiffRow::WhitespaceOnly => note_line("(whitespace changes)".to_string()),
+        })
+        .collect();
+
+    let hidden = diff.len() - shown;
+    if hidden > 0 {
+        lines.push(note_line(format!(
+            "\u{2026} {} more lines (press o to expand)",
+            hidden
+        )));
+    }
+    Some(lines)
+}
+
+/// A row of the rendered diff
+enum DiffRow {
+    Line(DiffLine),
+    /// A hunk that only changes whitespace
+    WhitespaceOnly,
+}
+
+/// (old, new) text pairs of a file edit
+fn edit_hunks(tool_name: &str, input: &Value) -> Option<Vec<(String, String)>> {
+    let field =
+        |value: &Value, key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+    match tool_name {
+        "Edit" => Some(vec![(
+            field(input, "old_string")?,
+            field(input, "new_string")?,
+        )]),
+        "MultiEdit" => input
+            .get("edits")?
+            .as_array()?
+            .iter()
+            .map(|edit| Some((field(edit, "old_string")?, field(edit, "new_string")?)))
+            .collect(),
+        "Write" => Some(vec![(String::new(), field(input, "content")?)]),
+        _ => None,
+    }
+}
+
+/// Line comment marker for a file, by extension
+fn comment_prefix_for(path: &str) -> Option<&'static str> {
+    let extension = path.rsplit_once('.')?.1;
+    match extension {
+        "rs" | "js" | "jsx" | "ts" | "tsx" | "go" | "c" | "h" | "cpp" | "java" | "kt" | "swift"
+        | "cs" | "scala" => Some("//"),
+        "py" | "sh" | "bash" | "rb" | "toml" | "yaml" | "yml" | "pl" | "r" => Some("#"),
+        "sql" | "lua" | "hs" => Some("--"),
+        _ => None,
+    }
+}
+
+/// One styled diff line
+fn diff_line(line: &DiffLine, comment_prefix: Option<&str>) -> Line<'static> {
+    let (marker, text, color) = match line {
+        DiffLine::Context(text) => (" ", text.as_str(), COLOR_DIM),
+        DiffLine::Removed(text) => ("-", text.as_str(), Color::Red),
+        DiffLine::Added(text) => ("+", text.as_str(), Color::Green),
+        DiffLine::Break => return note_line("\u{22EF}".to_string()),
+    };
+    let style = Style::default().fg(color);
+    let text = text.trim_end_matches('\r').replace('\t', "    ");
+
+    let mut spans = vec![
+        Span::raw(DIFF_INDENT),
+        Span::styled(format!("{} ", marker), style),
+    ];
+    if comment_prefix.is_some_and(|prefix| text.trim_start().starts_with(prefix)) {
+        spans.push(Span::styled(text, style.add_modifier(Modifier::ITALIC)));
+    } else {
+        spans.extend(keyword_spans(&text, style));
+    }
+    Line::from(spans)
+}
+
+/// Split code into spans, keywords in bold
+fn keyword_spans(text: &str, style: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut word = String::new();
+
+    for c in text.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            word.push(c);
+        } else {
+            flush_word(&mut word, &mut plain, &mut spans, style);
+            plain.push(c);
+        }
+    }
+    flush_word(&mut word, &mut plain, &mut spans, style);
+    if !plain.is_empty() {
+        spans.push(Span::styled(plain, style));
+    }
+    spans
+}
+
+/// End the current word: keywords become their own bold span, anything else
+/// joins the plain text
+fn flush_word(word: &mut String, plain: &mut String, spans: &mut Vec<Span<'static>>, style: Style) {
+    if KEYWORDS.contains(&word.as_str()) {
+        if !plain.is_empty() {
+            spans.push(Span::styled(std::mem::take(plain), style));
+        }
+        spans.push(Span::styled(
+            std::mem::take(word),
+            style.add_modifier(Modifier::BOLD),
+        ));
+    } else {
+        plain.push_str(word);
+        word.clear();
+    }
+}
+
+/// Dim italic note under the tool line
+fn note_line(text: String) -> Line<'static> {
+    Line::from(vec![
+        Span::raw(DIFF_INDENT),
+        Span::styled(
+            text,
+            Style::default()
+                .fg(COLOR_DIM)
+                .add_modifier(Modifier::ITALIC),
+        ),
+    ])
+}
+
+/// Estimated number of diff lines, for virtualized height estimates
+pub fn estimate_tool_diff_lines(event: &ToolEvent) -> usize {
+    if !is_file_edit_tool(&event.function_name) {
+        return 0;
+    }
+    if event.args_json.len() > MAX_DIFF_PAYLOAD_BYTES {
+        return 1;
+    }
+    // Every line of the arguments is at most one diff line
+    let lines = event.args_json.matches("\\n").count() + 2;
+    if event.output_expanded {
+        lines
+    } else {
+        lines.min(MAX_TOOL_DIFF_LINES + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit_event(name: &str, input: Value) -> ToolEvent {
+        let mut event = ToolEvent::new("call-1".to_string(), name.to_string());
+        event.args_json = input.to_string();
+        event.status = ToolEventStatus::Complete;
+        event
+    }
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_edit_diff_colors_and_caches() {
+        let event = edit_event(
+            "Edit",
+            serde_json::json!({
+                "file_path": "/src/lib.rs",
+                "old_string": "let a = 1;\n// old",
+                "new_string": "let a = 2;\n// old",
+            }),
+        );
+        let mut cache = RenderedLinesCache::new();
+        let lines = render_tool_diff(&event, &mut cache);
+
+        let texts: Vec<String> = lines.iter().map(text).collect();
+        assert_eq!(
+            texts,
+            vec!["    - let a = 1;", "    + let a = 2;", "      // old"]
+        );
+        assert_eq!(lines[0].spans[1].style.fg, Some(Color::Red));
+        assert_eq!(lines[1].spans[1].style.fg, Some(Color::Green));
+        // `let` is a keyword, the comment line is italic
+        assert!(lines[1].spans[2]
+            .style
+            .add_modifier
+            .contains(Modifier::BOLD));
+        assert!(lines[2].spans[2]
+            .style
+            .add_modifier
+            .contains(Modifier::ITALIC));
+
+        assert!(cache.get_tool_diff("call-1", false).is_some());
+    }
+
+    #[test]
+    fn test_whitespace_only_hunk_is_collapsed() {
+        let event = edit_event(
+            "Edit",
+            serde_json::json!({"old_string": "a  b", "new_string": "a b"}),
+        );
+        let lines = build_tool_diff_lines(&event).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(text(&lines[0]), "    (whitespace changes)");
+    }
+
+    #[test]
+    fn test_long_write_is_capped_until_expanded() {
+        let content: Vec<String> = (0..100).map(|i| format!("line {}", i)).collect();
+        let mut event = edit_event(
+            "Write",
+            serde_json::json!({"file_path": "/notes.md", "content": content.join("\n")}),
+        );
+        let lines = build_tool_diff_lines(&event).unwrap();
+        assert_eq!(lines.len(), MAX_TOOL_DIFF_LINES + 1);
+        assert_eq!(
+            text(lines.last().unwrap()),
+            "    \u{2026} 20 more lines (press o to expand)"
+        );
+
+        event.output_expanded = true;
+        assert_eq!(build_tool_diff_lines(&event).unwrap().len(), 100);
+    }
+
+    #[test]
+    fn test_large_payload_and_partial_args() {
+        let big = "x".repeat(MAX_DIFF_PAYLOAD_BYTES + 1);
+        let event = edit_event("Write", serde_json::json!({"content": big}));
+        let lines = build_tool_diff_lines(&event).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert!(text(&lines[0]).contains("diff skipped"));
+
+        let mut running = ToolEvent::new("call-2".to_string(), "Edit".to_string());
+        running.args_json = r#"{"old_string": "a", "new_"#.to_string();
+        let mut cache = RenderedLinesCache::new();
+        assert!(render_tool_diff(&running, &mut cache).is_empty());
+        assert!(cache.get_tool_diff("call-2", false).is_none());
+    }
+}
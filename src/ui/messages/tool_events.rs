@@ -2,10 +2,13 @@
 //!
 //! Renders tool execution status with icons, spinners, and color-coded indicators.
 
+use chrono::Utc;
 use ratatui::{
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
 };
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 use crate::app::App;
 use crate::models::{ToolEvent, ToolEventStatus};
@@ -17,6 +20,12 @@ use super::super::theme::{
     COLOR_DIM, COLOR_TOOL_ERROR, COLOR_TOOL_ICON, COLOR_TOOL_RUNNING, COLOR_TOOL_SUCCESS,
 };
 
+/// Most lines of tool output shown in an expanded output block
+pub const MAX_TOOL_OUTPUT_LINES: usize = 200;
+
+/// Running tools show their elapsed time once they've run this long
+const ELAPSED_THRESHOLD_SECS: i64 = 3;
+
 /// Render a single tool event as a Line
 ///
 /// Uses tool-specific icons, color-coded status indicators, and formatted arguments
@@ -25,7 +34,7 @@ use super::super::theme::{
 /// Uses `LayoutContext` for responsive args display truncation.
 ///
 /// # Display format
-/// - Running:  `[icon] [spinner] [tool_name]: [args_display]` (gray)
+/// - Running:  `[icon] [spinner] [tool_name]: [args_display] (elapsed)` (gray)
 /// - Complete: `[icon] checkmark [tool_name]: [args_display] (duration)` (green)
 /// - Failed:   `[icon] x [tool_name]: [args_display]` (red)
 pub fn render_tool_event(event: &ToolEvent, tick_count: u64, ctx: &LayoutContext) -> Line<'static> {
@@ -65,6 +74,7 @@ pub fn render_tool_event(event: &ToolEvent, tick_count: u64, ctx: &LayoutContext
                     Style::default().fg(COLOR_TOOL_RUNNING),
                 ),
                 Span::styled(args_display, Style::default().fg(COLOR_TOOL_RUNNING)),
+                Span::styled(elapsed_text(event), Style::default().fg(COLOR_DIM)),
             ])
         }
         ToolEventStatus::Complete => {
@@ -114,18 +124,74 @@ pub fn render_tool_event(event: &ToolEvent, tick_count: u64, ctx: &LayoutContext
     }
 }
 
+/// Elapsed time of a long-running tool, e.g. " (12s)"; empty for quick ones
+fn elapsed_text(event: &ToolEvent) -> String {
+    let elapsed = (Utc::now() - event.started_at).num_seconds();
+    if elapsed < ELAPSED_THRESHOLD_SECS {
+        return String::new();
+    }
+    format!(" ({}s)", elapsed)
+}
+
+/// Render the expanded output block of a tool event
+///
+/// Output lines are dimmed and indented under the tool line; the caller wraps
+/// them. At most [`MAX_TOOL_OUTPUT_LINES`] are shown, followed by a footer
+/// with the number of hidden lines. Empty when the block is collapsed.
+pub fn render_tool_output(event: &ToolEvent) -> Vec<Line<'static>> {
+    let output = match event.output.as_deref() {
+        Some(output) if event.output_expanded && event.has_output() => output,
+        _ => return Vec::new(),
+    };
+
+    let style = if event.result_is_error {
+        Style::default()
+            .fg(COLOR_TOOL_ERROR)
+            .add_modifier(Modifier::DIM)
+    } else {
+        Style::default().fg(COLOR_DIM)
+    };
+    let output_lines: Vec<&str> = output.trim_end().lines().collect();
+
+    let mut lines: Vec<Line<'static>> = output_lines
+        .iter()
+        .take(MAX_TOOL_OUTPUT_LINES)
+        .map(|line| {
+            Line::from(vec![
+                Span::styled("    ", Style::default()),
+                Span::styled(line.trim_end_matches('\r').replace('\t', "    "), style),
+            ])
+        })
+        .collect();
+
+    let hidden = output_lines.len().saturating_sub(MAX_TOOL_OUTPUT_LINES);
+    if hidden > 0 {
+        lines.push(Line::from(vec![
+            Span::styled("    ", Style::default()),
+            Span::styled(
+                format!("\u{2026} {} more lines (press O to copy)", hidden),
+                Style::default()
+                    .fg(COLOR_DIM)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+        ]));
+    }
+    lines
+}
+
 /// Truncate a preview string to fit display constraints
 ///
-/// Limits output to `max_chars` characters or `max_lines` newlines, whichever is reached first.
+/// Limits output to `max_chars` display columns or `max_lines` newlines, whichever is reached first.
 /// Replaces newlines with spaces for single-line display and appends "..." if truncated.
+/// Stops between grapheme clusters, so emoji and combining sequences are never split.
 pub fn truncate_preview(text: &str, max_chars: usize, max_lines: usize) -> String {
     let mut result = String::new();
-    let mut char_count = 0;
+    let mut width = 0;
     let mut line_count = 0;
     let mut truncated = false;
 
-    for ch in text.chars() {
-        if ch == '\n' {
+    for grapheme in text.graphemes(true) {
+        if grapheme == "\n" || grapheme == "\r\n" {
             line_count += 1;
             if line_count >= max_lines {
                 truncated = true;
@@ -133,23 +199,23 @@ pub fn truncate_preview(text: &str, max_chars: usize, max_lines: usize) -> Strin
             }
             // Replace newline with space for single-line display
             result.push(' ');
-            char_count += 1;
+            width += 1;
         } else {
-            result.push(ch);
-            char_count += 1;
+            let grapheme_width = grapheme.width();
+            if width + grapheme_width > max_chars {
+                truncated = true;
+                break;
+            }
+            result.push_str(grapheme);
+            width += grapheme_width;
         }
 
-        if char_count >= max_chars {
+        if width >= max_chars {
             truncated = true;
             break;
         }
     }
 
-    // Check if there's more content after where we stopped
-    if !truncated && char_count < text.chars().count() {
-        truncated = true;
-    }
-
     if truncated {
         // Trim trailing whitespace before adding ellipsis
         let trimmed = result.trim_end();
@@ -265,4 +331,52 @@ mod tests {
         // Should NOT be truncated
         assert!(!line_text.contains("...") || line_text.contains("Reading /path/to/file.rs"));
     }
+
+    #[test]
+    fn test_render_tool_event_shows_elapsed_time_while_running() {
+        let mut tool = ToolEvent::new("tool_123".to_string(), "Bash".to_string());
+        tool.args_display = Some("cargo build".to_string());
+        let ctx = LayoutContext::new(120, 40);
+
+        let text = |tool: &ToolEvent| -> String {
+            let line = render_tool_event(tool, 0, &ctx);
+            line.spans.iter().map(|s| s.content.as_ref()).collect()
+        };
+        assert!(text(&tool).ends_with("cargo build"));
+
+        tool.started_at = Utc::now() - chrono::Duration::seconds(42);
+        assert!(text(&tool).ends_with("cargo build (42s)"));
+    }
+
+    #[test]
+    fn test_render_tool_output_is_collapsed_by_default() {
+        let mut tool = ToolEvent::new("tool_123".to_string(), "Bash".to_string());
+        tool.set_result("error: no such file\n", true);
+        assert!(render_tool_output(&tool).is_empty());
+
+        tool.output_expanded = true;
+        let lines = render_tool_output(&tool);
+        assert_eq!(lines.len(), 1);
+        let text: String = lines[0].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "    error: no such file");
+    }
+
+    #[test]
+    fn test_render_tool_output_caps_lines_with_footer() {
+        let mut tool = ToolEvent::new("tool_123".to_string(), "Bash".to_string());
+        let output: Vec<String> = (0..250).map(|i| format!("line {}", i)).collect();
+        tool.set_result(&output.join("\n"), false);
+        tool.output_expanded = true;
+
+        let lines = render_tool_output(&tool);
+        assert_eq!(lines.len(), MAX_TOOL_OUTPUT_LINES + 1);
+        let footer: String = lines
+            .last()
+            .unwrap()
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert_eq!(footer, "    \u{2026} 50 more lines (press O to copy)");
+    }
 }
@@ -5,6 +5,10 @@
 
 use crate::models::{Message, MessageRole, MessageSegment};
 
+use super::height::{estimate_subagent_events_height, estimate_text_height};
+use super::tool_diff::estimate_tool_diff_lines;
+use super::tool_events::MAX_TOOL_OUTPUT_LINES;
+
 /// Represents the height in visual lines of a single message.
 /// Used for virtualization to determine which messages are visible.
 #[derive(Debug, Clone)]
@@ -69,6 +73,26 @@ pub fn calculate_visible_range(
     (start_index, end_index, first_message_line_offset)
 }
 
+/// Total visual lines across all messages.
+pub fn total_message_lines(message_heights: &[MessageHeight]) -> usize {
+    message_heights
+        .last()
+        .map(|h| h.cumulative_offset + h.visual_lines)
+        .unwrap_or(0)
+}
+
+/// Recalculate cumulative offsets after the height at `from` changed.
+pub fn recalculate_offsets(message_heights: &mut [MessageHeight], from: usize) {
+    let mut cumulative = match from.checked_sub(1).and_then(|i| message_heights.get(i)) {
+        Some(previous) => previous.cumulative_offset + previous.visual_lines,
+        None => 0,
+    };
+    for height in message_heights.iter_mut().skip(from) {
+        height.cumulative_offset = cumulative;
+        cumulative += height.visual_lines;
+    }
+}
+
 /// Fast height estimation for virtualization - takes only &Message, no mutable App access.
 ///
 /// This enables reference-based iteration over messages without cloning the entire Vec.
@@ -106,37 +130,51 @@ pub fn estimate_message_height_fast(message: &Message, viewport_width: usize) ->
         }
     }
 
+    // Add 1 line for the quoted reply chip if present
+    if message.role == MessageRole::User && message.reply_to.is_some() {
+        estimated_lines += 1;
+    }
+
     // Add 1 line for image attachment chips if present
     if message.role == MessageRole::User && !message.image_hashes.is_empty() {
         estimated_lines += 1;
     }
 
-    let char_count = content.chars().count();
-    let logical_lines = if char_count == 0 {
-        1
-    } else {
-        (char_count / 60).max(1)
-    };
-    let wrap_factor = if viewport_width > 0 {
-        60_usize.div_ceil(viewport_width)
-    } else {
-        1
-    };
-    estimated_lines += logical_lines * wrap_factor;
+    // Wrapped rows by display width, so CJK and emoji (2 columns) and
+    // combining marks (0 columns) don't skew the estimate
+    estimated_lines += estimate_text_height(content, viewport_width).max(1);
 
     // Add lines for tool events in segments
     if message.role == MessageRole::Assistant {
         let tool_count = message
             .segments
             .iter()
-            .filter(|s| {
-                matches!(
-                    s,
-                    MessageSegment::ToolEvent(_) | MessageSegment::SubagentEvent(_)
-                )
-            })
+            .filter(|s| matches!(s, MessageSegment::ToolEvent(_)))
             .count();
         estimated_lines += tool_count * 2;
+        // Subagent trees, which grow with progress lines and collapse once done
+        estimated_lines += estimate_subagent_events_height(&message.segments, message.is_streaming);
+
+        // File edit diffs, and expanded tool output: capped lines plus the
+        // "more lines" footer
+        for segment in &message.segments {
+            if let MessageSegment::ToolEvent(event) = segment {
+                estimated_lines += estimate_tool_diff_lines(event);
+                if let Some(output) = event.output.as_deref().filter(|_| event.output_expanded) {
+                    let total = output.trim_end().lines().count();
+                    let shown: String = output
+                        .trim_end()
+                        .lines()
+                        .take(MAX_TOOL_OUTPUT_LINES)
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    estimated_lines += estimate_text_height(&shown, viewport_width);
+                    if total > MAX_TOOL_OUTPUT_LINES {
+                        estimated_lines += 1;
+                    }
+                }
+            }
+        }
     }
 
     estimated_lines
@@ -273,6 +311,24 @@ mod tests {
         assert_eq!(offset, 0);
     }
 
+    #[test]
+    fn test_recalculate_offsets() {
+        let mut heights: Vec<MessageHeight> = (0..4)
+            .map(|i| MessageHeight {
+                visual_lines: 10,
+                cumulative_offset: i * 10,
+            })
+            .collect();
+
+        heights[1].visual_lines = 3;
+        recalculate_offsets(&mut heights, 1);
+
+        let offsets: Vec<usize> = heights.iter().map(|h| h.cumulative_offset).collect();
+        assert_eq!(offsets, vec![0, 10, 13, 23]);
+        assert_eq!(total_message_lines(&heights), 33);
+        assert_eq!(total_message_lines(&[]), 0);
+    }
+
     #[test]
     fn test_visible_range_consistency() {
         // Verify that for any scroll position, the visible range includes
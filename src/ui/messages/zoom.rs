@@ -0,0 +1,114 @@
+//! Zoom filter for conversation content
+//!
+//! Applied to each message before height estimation and rendering, so the
+//! virtualized layout only ever sees what the current zoom level shows:
+//! - [`ZoomLevel::Answers`]: user prompts and answer text only
+//! - [`ZoomLevel::ToolSummaries`]: adds one line per tool call and subagent
+//! - [`ZoomLevel::Full`]: everything, including reasoning and subagent progress
+
+use std::borrow::Cow;
+
+use crate::app::ZoomLevel;
+use crate::models::{Message, MessageRole, MessageSegment};
+
+/// The message as shown at `level` (borrowed unchanged at full zoom)
+pub fn zoomed_message(message: &Message, level: ZoomLevel) -> Cow<'_, Message> {
+    if level == ZoomLevel::Full || message.role != MessageRole::Assistant {
+        return Cow::Borrowed(message);
+    }
+
+    let mut zoomed = message.clone();
+    zoomed.reasoning_content.clear();
+    match level {
+        ZoomLevel::Answers => {
+            zoomed
+                .segments
+                .retain(|segment| matches!(segment, MessageSegment::Text(_)));
+        }
+        ZoomLevel::ToolSummaries => {
+            for segment in &mut zoomed.segments {
+                if let MessageSegment::SubagentEvent(event) = segment {
+                    event.progress_message = None;
+                    event.summary = None;
+                }
+            }
+        }
+        ZoomLevel::Full => {}
+    }
+    Cow::Owned(zoomed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SubagentEvent, ToolEvent};
+
+    fn assistant_message() -> Message {
+        let mut message = Message {
+            id: 1,
+            thread_id: "thread-1".to_string(),
+            role: MessageRole::Assistant,
+            content: "Done.".to_string(),
+            created_at: chrono::Utc::now(),
+            is_streaming: false,
+            partial_content: String::new(),
+            reasoning_content: "Let me think".to_string(),
+            reasoning_collapsed: true,
+            segments: Vec::new(),
+            render_version: 0,
+            image_hashes: Vec::new(),
+            reply_to: None,
+        };
+        let mut subagent = SubagentEvent::new(
+            "task-1".to_string(),
+            "Explore".to_string(),
+            "general".to_string(),
+        );
+        subagent.summary = Some("Found it".to_string());
+        message.segments = vec![
+            MessageSegment::Text("Looking.".to_string()),
+            MessageSegment::ToolEvent(ToolEvent::new("call-1".to_string(), "Read".to_string())),
+            MessageSegment::SubagentEvent(subagent),
+            MessageSegment::Text("Done.".to_string()),
+        ];
+        message
+    }
+
+    #[test]
+    fn test_answers_zoom_keeps_only_text() {
+        let message = assistant_message();
+
+        let zoomed = zoomed_message(&message, ZoomLevel::Answers);
+
+        assert_eq!(zoomed.segments.len(), 2);
+        assert!(zoomed
+            .segments
+            .iter()
+            .all(|s| matches!(s, MessageSegment::Text(_))));
+        assert!(zoomed.reasoning_content.is_empty());
+    }
+
+    #[test]
+    fn test_tool_summaries_zoom_trims_subagent_detail() {
+        let message = assistant_message();
+
+        let zoomed = zoomed_message(&message, ZoomLevel::ToolSummaries);
+
+        assert_eq!(zoomed.segments.len(), 4);
+        match &zoomed.segments[2] {
+            MessageSegment::SubagentEvent(event) => assert!(event.summary.is_none()),
+            other => panic!("Expected SubagentEvent, got {:?}", other),
+        }
+        assert!(zoomed.reasoning_content.is_empty());
+    }
+
+    #[test]
+    fn test_full_zoom_borrows_message() {
+        let message = assistant_message();
+
+        assert!(matches!(
+            zoomed_message(&message, ZoomLevel::Full),
+            Cow::Borrowed(_)
+        ));
+    }
+}
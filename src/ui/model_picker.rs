@@ -0,0 +1,130 @@
+//! Model picker dialog rendering
+//!
+//! Shown by `/model`: lists the models the backend offers, marking the one in
+//! use. Enter applies the selected model to the active thread (or to the next
+//! new thread on the CommandDeck).
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, Screen};
+
+use super::helpers::truncate_string;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Maximum models listed at once
+const MAX_VISIBLE_MODELS: usize = 10;
+
+/// Render the model picker as a centered overlay
+pub fn render_model_picker(frame: &mut Frame, app: &App) {
+    let Some(ref picker) = app.model_picker else {
+        return;
+    };
+
+    let current = app.selected_model();
+
+    let area = frame.area();
+    let visible_count = app.models.len().clamp(1, MAX_VISIBLE_MODELS);
+    let dialog_width = 56u16.min(area.width.saturating_sub(4));
+    // Borders (2) + padding (2) + models + blank line + hint line
+    let dialog_height = (visible_count as u16 + 6).min(area.height.saturating_sub(2));
+
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = if app.screen == Screen::Conversation {
+        " Model "
+    } else {
+        " Model for new threads "
+    };
+    let block = Block::default()
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 2,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(4),
+    };
+
+    let mut lines: Vec<Line> = if app.models_loading {
+        vec![Line::from(Span::styled(
+            "  Loading models...",
+            Style::default().fg(COLOR_DIM),
+        ))]
+    } else if let Some(ref error) = app.models_error {
+        vec![Line::from(Span::styled(
+            format!("  {}", truncate_string(error, inner.width as usize)),
+            Style::default().fg(Color::Red),
+        ))]
+    } else if app.models.is_empty() {
+        vec![Line::from(Span::styled(
+            "  No models available",
+            Style::default().fg(COLOR_DIM),
+        ))]
+    } else {
+        // Keep the selection in view
+        let offset = picker.selected_index.saturating_sub(MAX_VISIBLE_MODELS - 1);
+        let max_label_width = (inner.width as usize).saturating_sub(4);
+
+        app.models
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(MAX_VISIBLE_MODELS)
+            .map(|(index, model)| {
+                let is_selected = index == picker.selected_index;
+                let marker = if is_selected { "▶ " } else { "  " };
+                let label_style = if is_selected {
+                    Style::default()
+                        .fg(COLOR_ACCENT)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(COLOR_DIM)
+                };
+                let in_use = if current == Some(model.id.as_str()) {
+                    " ✓"
+                } else {
+                    ""
+                };
+
+                Line::from(vec![
+                    Span::styled(marker, label_style),
+                    Span::styled(truncate_string(model.label(), max_label_width), label_style),
+                    Span::styled(in_use, Style::default().fg(Color::Magenta)),
+                ])
+            })
+            .collect()
+    };
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("[Enter] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("use  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("cancel", Style::default().fg(COLOR_DIM)),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
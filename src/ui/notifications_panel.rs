@@ -0,0 +1,144 @@
+//! Notifications panel rendering
+//!
+//! Shown by `/notifications`: lists thread events newest first, with unread
+//! entries marked. Enter opens the source thread, `d` dismisses the selected
+//! entry and `D` clears them all.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::helpers::truncate_string;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Maximum notifications listed at once
+const MAX_VISIBLE_NOTIFICATIONS: usize = 10;
+
+/// Render the notifications panel as a centered overlay
+pub fn render_notifications_panel(frame: &mut Frame, app: &App) {
+    let Some(ref panel) = app.notifications_panel else {
+        return;
+    };
+
+    let notifications = app.dashboard.notifications();
+
+    let area = frame.area();
+    let visible_count = notifications.len().clamp(1, MAX_VISIBLE_NOTIFICATIONS);
+    let dialog_width = 70u16.min(area.width.saturating_sub(4));
+    // Borders (2) + padding (2) + entries + blank line + hint line
+    let dialog_height = (visible_count as u16 + 6).min(area.height.saturating_sub(2));
+
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let unread = app.dashboard.unread_notification_count();
+    let title = if unread > 0 {
+        format!(" Notifications ({} unread) ", unread)
+    } else {
+        " Notifications ".to_string()
+    };
+    let block = Block::default()
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 2,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(4),
+    };
+
+    let mut lines: Vec<Line> = if notifications.is_empty() {
+        vec![Line::from(Span::styled(
+            "  No notifications",
+            Style::default().fg(COLOR_DIM),
+        ))]
+    } else {
+        // Keep the selection in view
+        let offset = panel
+            .selected_index
+            .saturating_sub(MAX_VISIBLE_NOTIFICATIONS - 1);
+        // Marker (2) + unread dot (2) + time (6)
+        let max_text_width = (inner.width as usize).saturating_sub(10);
+
+        notifications
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(MAX_VISIBLE_NOTIFICATIONS)
+            .map(|(index, notification)| {
+                let is_selected = index == panel.selected_index;
+                let marker = if is_selected { "▶ " } else { "  " };
+                let text_style = if is_selected {
+                    Style::default()
+                        .fg(COLOR_ACCENT)
+                        .add_modifier(Modifier::BOLD)
+                } else if notification.read {
+                    Style::default().fg(COLOR_DIM)
+                } else {
+                    Style::default()
+                };
+                let thread_title = app
+                    .cache
+                    .get_thread(&notification.thread_id)
+                    .map(|t| t.title.as_str())
+                    .unwrap_or(notification.thread_id.as_str());
+                let time = notification
+                    .timestamp
+                    .with_timezone(&chrono::Local)
+                    .format("%H:%M");
+
+                Line::from(vec![
+                    Span::styled(marker, text_style),
+                    Span::styled(
+                        if notification.read { "  " } else { "● " },
+                        Style::default().fg(COLOR_ACCENT),
+                    ),
+                    Span::styled(format!("{} ", time), Style::default().fg(COLOR_DIM)),
+                    Span::styled(
+                        truncate_string(
+                            &format!("{}: {}", thread_title, notification.message),
+                            max_text_width,
+                        ),
+                        text_style,
+                    ),
+                ])
+            })
+            .collect()
+    };
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("[Enter] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("open  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[d] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("dismiss  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[D] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("clear all  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("close", Style::default().fg(COLOR_DIM)),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
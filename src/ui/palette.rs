@@ -0,0 +1,99 @@
+//! 256-color fallback for terminals without truecolor.
+//!
+//! Terminals that don't understand 24-bit color sequences either ignore them
+//! or pick an arbitrary color, so RGB colors are mapped to the nearest entry
+//! of xterm's 256-color palette after each frame is rendered.
+
+use ratatui::buffer::Buffer;
+use ratatui::style::Color;
+
+/// Channel levels of the 6x6x6 color cube (palette entries 16-231)
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Nearest xterm 256-color palette index for an RGB color.
+///
+/// Considers the color cube and the grayscale ramp (232-255); the 16 base
+/// colors are skipped because terminals commonly remap them.
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    fn nearest_level(c: u8) -> usize {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (c as i32 - **level as i32).abs())
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+    fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> i32 {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r1, r2) + d(g1, g2) + d(b1, b2)
+    }
+
+    let (ri, gi, bi) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube_index = 16 + 36 * ri + 6 * gi + bi;
+    let cube_rgb = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_level = 8 + 10 * gray_step;
+    let gray_index = 232 + gray_step as usize;
+
+    if distance((r, g, b), (gray_level, gray_level, gray_level)) < distance((r, g, b), cube_rgb) {
+        gray_index as u8
+    } else {
+        cube_index as u8
+    }
+}
+
+/// Map an RGB color to the 256-color palette; other colors are unchanged.
+pub fn downgrade_color(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        other => other,
+    }
+}
+
+/// Replace every RGB color in a rendered frame with its 256-color equivalent.
+pub fn downgrade_buffer_colors(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        cell.fg = downgrade_color(cell.fg);
+        cell.bg = downgrade_color(cell.bg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn test_rgb_to_ansi256_exact_entries() {
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(95, 135, 175), 67);
+    }
+
+    #[test]
+    fn test_rgb_to_ansi256_prefers_gray_ramp() {
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+        assert_eq!(rgb_to_ansi256(30, 30, 40), 235);
+    }
+
+    #[test]
+    fn test_downgrade_buffer_colors() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buffer.set_string(
+            0,
+            0,
+            "ab",
+            Style::default().fg(Color::Rgb(4, 181, 117)).bg(Color::Blue),
+        );
+
+        downgrade_buffer_colors(&mut buffer);
+
+        let cell = &buffer.content[0];
+        assert_eq!(cell.fg, Color::Indexed(36));
+        assert_eq!(cell.bg, Color::Blue);
+    }
+}
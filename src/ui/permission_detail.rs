@@ -0,0 +1,121 @@
+//! Full tool input overlay rendering
+//!
+//! Opened by `e` on a permission prompt: every row of the request's tool
+//! input (the whole command, every diff line) in a scrollable dialog, with
+//! the permission keys still available.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::messages::permission_input_rows;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Widest the dialog gets
+const MAX_DIALOG_WIDTH: u16 = 120;
+
+/// Dialog size for a terminal of `area`
+fn dialog_rect(area: Rect) -> Rect {
+    let width = MAX_DIALOG_WIDTH.min(area.width.saturating_sub(4));
+    let height = area.height.saturating_sub(4).max(6).min(area.height);
+    Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    }
+}
+
+/// Columns the tool input is wrapped to in a terminal `terminal_width` wide
+pub fn permission_detail_width(terminal_width: u16) -> usize {
+    MAX_DIALOG_WIDTH
+        .min(terminal_width.saturating_sub(4))
+        .saturating_sub(4) as usize
+}
+
+/// Render the full tool input of the permission opened with `e`
+pub fn render_permission_detail(frame: &mut Frame, app: &App) {
+    let (Some(view), Some(perm)) = (&app.permission_detail, app.permission_detail_request()) else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_area = dialog_rect(area);
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            format!(" {} ", perm.tool_name),
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(2),
+    };
+    // Leave room for the blank line and the hint line
+    let visible_rows = (inner.height as usize).saturating_sub(2);
+
+    let rows = permission_input_rows(perm, permission_detail_width(area.width));
+    let scroll = view.scroll.min(rows.len().saturating_sub(visible_rows));
+    let mut lines: Vec<Line> = if rows.is_empty() {
+        vec![Line::from(Span::styled(
+            "  No tool input sent with this request",
+            Style::default().fg(COLOR_DIM),
+        ))]
+    } else {
+        rows.into_iter()
+            .skip(scroll)
+            .take(visible_rows)
+            .map(Line::from)
+            .collect()
+    };
+    lines.resize(visible_rows.max(lines.len()), Line::from(""));
+
+    let key_style = Style::default().fg(COLOR_ACCENT);
+    let label_style = Style::default().fg(COLOR_DIM);
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("[y] ", key_style),
+        Span::styled("yes  ", label_style),
+        Span::styled("[a] ", key_style),
+        Span::styled("always  ", label_style),
+        Span::styled("[n] ", key_style),
+        Span::styled("no  ", label_style),
+        Span::styled("[↑↓] ", key_style),
+        Span::styled("scroll  ", label_style),
+        Span::styled("[Esc] ", key_style),
+        Span::styled("close", label_style),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detail_width_matches_dialog_inner_width() {
+        let area = Rect::new(0, 0, 80, 24);
+        assert_eq!(
+            permission_detail_width(80),
+            dialog_rect(area).width as usize - 4
+        );
+        assert_eq!(permission_detail_width(300), 116);
+    }
+}
@@ -23,6 +23,7 @@ use crate::ui::context::{MessageHeightInfo, RenderOutputs};
 /// - Clears hit registry
 /// - Invalidates caches if viewport width changed
 /// - Updates height cache for message virtualization
+/// - Refreshes thread message previews
 ///
 /// # Arguments
 /// * `app` - Mutable reference to app state
@@ -32,13 +33,24 @@ pub fn prepare_render(app: &mut App, viewport_width: u16) {
     app.rendered_lines_cache
         .invalidate_if_width_changed(viewport_width);
 
+    // Seed the caches from a thread's snapshot now that the width is known
+    app.apply_render_snapshot();
+
     // Reset link visibility flag
     app.has_visible_links = false;
 
+    // Rebuild previews of threads with new messages (thread lists only)
+    app.refresh_thread_previews();
+
     // Prepare height cache if we're on the conversation screen
     if app.screen == crate::app::Screen::Conversation {
-        let message_width = viewport_width.saturating_sub(4) as usize;
+        // Same width as the messages area (1-cell margin each side), so the
+        // renderer can reuse and refine these heights
+        let message_width = viewport_width.saturating_sub(2) as usize;
         prepare_message_heights(app, message_width);
+
+        // A resumed session's scroll needs the heights built above
+        app.apply_pending_scroll_restore();
     }
 }
 
@@ -46,10 +58,14 @@ pub fn prepare_render(app: &mut App, viewport_width: u16) {
 ///
 /// This function updates the height cache for message virtualization.
 /// It handles cache invalidation, incremental updates, and cache rebuilds.
-fn prepare_message_heights(app: &mut App, viewport_width: usize) {
+pub(crate) fn prepare_message_heights(app: &mut App, viewport_width: usize) {
+    use super::messages::timestamps::{day_separator_rows, starts_new_day};
     use super::messages::virtualization::estimate_message_height_fast;
+    use super::messages::zoom::zoomed_message;
 
     let current_thread_id = app.active_thread_id.clone();
+    // Heights are for the messages as the thread's zoom level shows them
+    let zoom = app.zoom_level();
 
     let cached_messages = current_thread_id.as_ref().and_then(|id| {
         crate::app::log_thread_update(&format!(
@@ -64,6 +80,29 @@ fn prepare_message_heights(app: &mut App, viewport_width: usize) {
         msgs
     });
 
+    // Day header rows above a message when timestamps are shown
+    let show_timestamps = app.show_timestamps;
+    let separator_rows = |messages: &[crate::models::Message], index: usize| {
+        if show_timestamps {
+            day_separator_rows(messages, index)
+        } else {
+            0
+        }
+    };
+    let estimate = |messages: &[crate::models::Message], index: usize| {
+        estimate_message_height_fast(&zoomed_message(&messages[index], zoom), viewport_width)
+            + separator_rows(messages, index)
+    };
+
+    // Heights measured in an earlier session, where still valid
+    let restored = &app.restored_heights;
+    let height_of = |thread_id: &str, messages: &[crate::models::Message], index: usize| {
+        let message = &messages[index];
+        restored
+            .get(thread_id, viewport_width, message.id, message.render_version)
+            .unwrap_or_else(|| estimate(messages, index))
+    };
+
     match (&current_thread_id, cached_messages) {
         (_, None) => {}
         (_, Some(messages)) if messages.is_empty() => {}
@@ -78,6 +117,35 @@ fn prepare_message_heights(app: &mut App, viewport_width: usize) {
             if cache_valid {
                 // Incremental update
                 let cache = app.height_cache.as_mut().unwrap();
+
+                // Older messages prepended (paginated loading): estimate only
+                // those and keep the heights already measured
+                let prepended = cache
+                    .heights
+                    .first()
+                    .and_then(|first| messages.iter().position(|m| m.id == first.message_id))
+                    .unwrap_or(0);
+                if prepended > 0 {
+                    // The old first message loses its day header if the
+                    // messages before it are from the same day
+                    if show_timestamps && !starts_new_day(messages, prepended) {
+                        if let Some(first) = cache.heights.first_mut() {
+                            first.visual_lines = first.visual_lines.saturating_sub(1);
+                        }
+                    }
+                    let entries = messages[..prepended]
+                        .iter()
+                        .enumerate()
+                        .map(|(i, message)| crate::app::CachedMessageHeight {
+                            message_id: message.id,
+                            render_version: message.render_version,
+                            visual_lines: height_of(thread_id, messages, i),
+                            cumulative_offset: 0,
+                        })
+                        .collect();
+                    cache.prepend(entries);
+                }
+
                 let cached_len = cache.heights.len();
                 let msg_len = messages.len();
 
@@ -95,7 +163,7 @@ fn prepare_message_heights(app: &mut App, viewport_width: usize) {
                     if cached_entry.message_id != message.id
                         || cached_entry.render_version != message.render_version
                     {
-                        let new_height = estimate_message_height_fast(message, viewport_width);
+                        let new_height = estimate(messages, i);
                         cache.heights[i].message_id = message.id;
                         cache.heights[i].render_version = message.render_version;
                         if cache.heights[i].visual_lines != new_height {
@@ -108,8 +176,8 @@ fn prepare_message_heights(app: &mut App, viewport_width: usize) {
                 }
 
                 // Append new messages
-                for message in messages.iter().skip(cache.heights.len()) {
-                    let height = estimate_message_height_fast(message, viewport_width);
+                for (i, message) in messages.iter().enumerate().skip(cache.heights.len()) {
+                    let height = height_of(thread_id, messages, i);
                     cache.append(message.id, message.render_version, height);
                 }
 
@@ -122,8 +190,8 @@ fn prepare_message_heights(app: &mut App, viewport_width: usize) {
                 let thread_id_arc = std::sync::Arc::new(thread_id.clone());
                 let mut cache = crate::app::CachedHeights::new(thread_id_arc, viewport_width);
 
-                for message in messages.iter() {
-                    let height = estimate_message_height_fast(message, viewport_width);
+                for (i, message) in messages.iter().enumerate() {
+                    let height = height_of(thread_id, messages, i);
                     cache.append(message.id, message.render_version, height);
                 }
 
@@ -213,4 +281,44 @@ mod tests {
 
         assert_eq!(total, 0);
     }
+
+    fn streaming_message<'a>(app: &'a mut App, thread_id: &str) -> &'a mut crate::models::Message {
+        app.cache
+            .get_messages_mut(thread_id)
+            .and_then(|messages| messages.iter_mut().find(|m| m.is_streaming))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_subagent_progress_updates_heights_incrementally() {
+        let mut app = App::default();
+        app.screen = crate::app::Screen::Conversation;
+        let thread_id = app.cache.create_streaming_thread("Look around".to_string());
+        app.active_thread_id = Some(thread_id.clone());
+        streaming_message(&mut app, &thread_id).start_subagent_event(
+            "task-1".to_string(),
+            "Search".to_string(),
+            "Explore".to_string(),
+        );
+        prepare_render(&mut app, 80);
+        let before = app.height_cache.clone().unwrap();
+
+        streaming_message(&mut app, &thread_id)
+            .update_subagent_progress("task-1", "Reading src/main.rs".to_string());
+        prepare_render(&mut app, 80);
+        let after = app.height_cache.as_ref().unwrap();
+
+        // Only the streaming message grew, by its progress line
+        let last = before.heights.len() - 1;
+        assert_eq!(after.heights.len(), before.heights.len());
+        assert_eq!(
+            after.heights[last].visual_lines,
+            before.heights[last].visual_lines + 1
+        );
+        assert_eq!(after.total_lines, before.total_lines + 1);
+        for (a, b) in after.heights[..last].iter().zip(&before.heights[..last]) {
+            assert_eq!(a.render_version, b.render_version);
+            assert_eq!(a.cumulative_offset, b.cumulative_offset);
+        }
+    }
 }
@@ -0,0 +1,85 @@
+//! Rendering for prompts queued while a response streams
+//!
+//! Queued prompts sit dimmed below the streaming message, in send order,
+//! using the same indent as steering messages.
+
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use super::messages::wrap_line_with_prefix;
+use super::theme::COLOR_DIM;
+use crate::app::App;
+
+/// Left indent for queued prompts (matches steering messages)
+const QUEUED_INDENT: &str = "        ";
+
+/// Build lines for the active thread's queued prompts (if any)
+pub fn build_queued_prompt_lines(app: &App, max_width: usize) -> Vec<Line<'static>> {
+    let Some(queue) = app
+        .active_thread_id
+        .as_deref()
+        .and_then(|id| app.queued_outgoing(id))
+    else {
+        return Vec::new();
+    };
+
+    let label_style = Style::default().fg(COLOR_DIM).add_modifier(Modifier::BOLD);
+    let text_style = Style::default().fg(COLOR_DIM).add_modifier(Modifier::DIM);
+    let mut lines = Vec::new();
+
+    for (index, prompt) in queue.iter().enumerate() {
+        for (line_index, raw_line) in prompt.content.split('\n').enumerate() {
+            let mut spans = Vec::new();
+            if line_index == 0 {
+                spans.push(Span::styled(
+                    format!("queued {} · ", index + 1),
+                    label_style,
+                ));
+            }
+            spans.push(Span::styled(raw_line.to_string(), text_style));
+            lines.extend(wrap_line_with_prefix(
+                Line::from(spans),
+                QUEUED_INDENT,
+                Style::default(),
+                max_width,
+                None,
+            ));
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::QueuedPrompt;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_queued_prompt_lines_for_active_thread_only() {
+        let mut app = App::default();
+        app.outgoing_queue.insert(
+            "thread-1".to_string(),
+            ["first", "second\nline"]
+                .map(|p| QueuedPrompt::text(p.to_string()))
+                .into(),
+        );
+        assert!(build_queued_prompt_lines(&app, 80).is_empty());
+
+        app.active_thread_id = Some("thread-1".to_string());
+        let lines: Vec<String> = build_queued_prompt_lines(&app, 80)
+            .iter()
+            .map(line_text)
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("queued 1 · first"));
+        assert!(lines[1].contains("queued 2 · second"));
+        assert!(lines[2].ends_with("line"));
+    }
+}
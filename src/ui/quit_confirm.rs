@@ -0,0 +1,104 @@
+//! Quit confirmation rendering
+//!
+//! Shown when quitting would abandon work in flight: one line per kind of
+//! work (streaming responses, queued prompts, pending permissions) and the
+//! ways out.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::helpers::truncate_string;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Longest busy thread title shown in the hint
+const JUMP_TITLE_WIDTH: usize = 24;
+
+/// Render the quit confirmation as a centered dialog
+pub fn render_quit_confirm(frame: &mut Frame, app: &App) {
+    let Some(ref confirm) = app.quit_confirm else {
+        return;
+    };
+    let lines: Vec<Line> = app
+        .background_work_lines(&confirm.work)
+        .into_iter()
+        .map(|line| Line::from(Span::raw(line)))
+        .collect();
+    let busy_title = confirm
+        .work
+        .busy_thread()
+        .map(|thread_id| {
+            app.cache
+                .get_thread(thread_id)
+                .map(|thread| thread.title.clone())
+                .unwrap_or_else(|| thread_id.to_string())
+        })
+        .unwrap_or_default();
+
+    let area = frame.area();
+    let dialog_width = 84u16.min(area.width.saturating_sub(4));
+    let dialog_height = (lines.len() as u16 + 5).min(area.height.saturating_sub(2));
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            " Quit spoq? ",
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    frame.render_widget(block, dialog_area);
+
+    let rows_area = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(3),
+    };
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), rows_area);
+
+    let hint_area = Rect {
+        x: rows_area.x,
+        y: dialog_area.y + dialog_area.height.saturating_sub(2),
+        width: rows_area.width,
+        height: 1,
+    };
+    let mut hint = vec![
+        Span::styled("[q] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("quit anyway  ", Style::default().fg(COLOR_DIM)),
+    ];
+    if !confirm.work.streaming.is_empty() {
+        hint.push(Span::styled("[w] ", Style::default().fg(COLOR_ACCENT)));
+        hint.push(Span::styled(
+            "wait for responses  ",
+            Style::default().fg(COLOR_DIM),
+        ));
+    }
+    hint.push(Span::styled("[j] ", Style::default().fg(COLOR_ACCENT)));
+    hint.push(Span::styled(
+        format!(
+            "jump to {}  ",
+            truncate_string(&busy_title, JUMP_TITLE_WIDTH)
+        ),
+        Style::default().fg(COLOR_DIM),
+    ));
+    hint.push(Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)));
+    hint.push(Span::styled("stay", Style::default().fg(COLOR_DIM)));
+    frame.render_widget(Paragraph::new(Line::from(hint)), hint_area);
+}
@@ -0,0 +1,438 @@
+//! First-run setup wizard screen
+//!
+//! A centered card with a step bar (sign in, VPS, credentials, summary), the
+//! current step's content and a key hint. The VPS config dialog opened from
+//! the VPS step is drawn on top.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{App, CredentialChecks, SetupStep, SetupWizard, StepStatus};
+use crate::view_state::OverlayState;
+
+use super::dashboard::vps_config_card;
+use super::helpers::SPINNER_FRAMES;
+use super::theme::{
+    COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER, COLOR_TOOL_ERROR, COLOR_TOOL_SUCCESS,
+};
+
+/// Render the setup wizard
+pub fn render_setup(frame: &mut Frame, app: &App) {
+    let Some(ref wizard) = app.setup_wizard else {
+        return;
+    };
+
+    let area = frame.area();
+    let card_area = centered(area, 72, 20);
+    frame.render_widget(Clear, card_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            " Set up SPOQ ",
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    frame.render_widget(block, card_area);
+
+    let inner = Rect {
+        x: card_area.x + 2,
+        y: card_area.y + 1,
+        width: card_area.width.saturating_sub(4),
+        height: card_area.height.saturating_sub(2),
+    };
+    let body_area = Rect {
+        y: inner.y + 2,
+        height: inner.height.saturating_sub(4),
+        ..inner
+    };
+    let hint_area = Rect {
+        y: inner.y + inner.height.saturating_sub(1),
+        height: 1,
+        ..inner
+    };
+
+    frame.render_widget(Paragraph::new(step_bar(wizard)), inner);
+
+    let spinner = SPINNER_FRAMES[(app.tick_count as usize) % SPINNER_FRAMES.len()];
+    frame.render_widget(
+        Paragraph::new(step_lines(wizard, spinner)).wrap(Wrap { trim: false }),
+        body_area,
+    );
+
+    // Errors from the wizard's keys (e.g. no browser) replace the hint
+    let hint = match app.stream_error {
+        Some(ref error) => Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(COLOR_TOOL_ERROR),
+        )),
+        None => hint_line(wizard),
+    };
+    frame.render_widget(Paragraph::new(hint), hint_area);
+
+    if let Some(OverlayState::VpsConfig { ref state, .. }) = app.dashboard.overlay() {
+        let dialog_area = centered(area, 60, vps_config_card::calculate_height(state) + 2);
+        frame.render_widget(Clear, dialog_area);
+        frame.render_widget(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(COLOR_BORDER)),
+            dialog_area,
+        );
+        let dialog_inner = Rect {
+            x: dialog_area.x + 2,
+            y: dialog_area.y + 1,
+            width: dialog_area.width.saturating_sub(4),
+            height: dialog_area.height.saturating_sub(2),
+        };
+        vps_config_card::render(frame, dialog_inner, state);
+    }
+}
+
+/// A `width` x `height` rect centered in `area`, shrunk to fit
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width.saturating_sub(4));
+    let height = height.min(area.height.saturating_sub(2));
+    Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    }
+}
+
+/// "✓ Sign in › ● VPS › ○ Credentials › ○ Summary"
+fn step_bar(wizard: &SetupWizard) -> Line<'static> {
+    let mut spans = Vec::new();
+    for step in SetupStep::ALL {
+        if !spans.is_empty() {
+            spans.push(Span::styled("  \u{203a}  ", Style::default().fg(COLOR_DIM)));
+        }
+        let (glyph, color) = match wizard.status(step) {
+            StepStatus::Done => ("\u{2713}", COLOR_TOOL_SUCCESS),
+            StepStatus::Failed(_) => ("\u{2717}", COLOR_TOOL_ERROR),
+            _ if step == wizard.step => ("\u{25cf}", COLOR_ACCENT),
+            _ => ("\u{25cb}", COLOR_DIM),
+        };
+        let mut label = Style::default().fg(if step == wizard.step {
+            COLOR_ACCENT
+        } else {
+            COLOR_DIM
+        });
+        if step == wizard.step {
+            label = label.add_modifier(Modifier::BOLD);
+        }
+        spans.push(Span::styled(
+            format!("{} ", glyph),
+            Style::default().fg(color),
+        ));
+        spans.push(Span::styled(step.label(), label));
+    }
+    Line::from(spans)
+}
+
+/// Content of the current step
+fn step_lines(wizard: &SetupWizard, spinner: &str) -> Vec<Line<'static>> {
+    let waiting = |text: String| {
+        Line::from(vec![
+            Span::styled(format!("{} ", spinner), Style::default().fg(COLOR_ACCENT)),
+            Span::raw(text),
+        ])
+    };
+    let done = |text: String| {
+        Line::from(Span::styled(
+            format!("\u{2713} {}", text),
+            Style::default().fg(COLOR_TOOL_SUCCESS),
+        ))
+    };
+
+    let status = wizard.status(wizard.step);
+    if let StepStatus::Failed(error) = status {
+        if wizard.vps_missing {
+            return vec![
+                Line::from("No VPS is set up yet."),
+                Line::from(""),
+                Line::from("Press Enter to connect your own server or run a local conductor."),
+            ];
+        }
+        return vec![
+            Line::from(Span::styled(
+                format!("\u{2717} {}", error),
+                Style::default().fg(COLOR_TOOL_ERROR),
+            )),
+            Line::from(""),
+            Line::from("Press Enter to try again."),
+        ];
+    }
+
+    match (wizard.step, status) {
+        (SetupStep::Auth, StepStatus::Running) => match wizard.verification_url {
+            Some(ref url) => {
+                let mut lines = vec![
+                    Line::from("Open this URL in your browser and approve the sign-in:"),
+                    Line::from(""),
+                    Line::from(Span::styled(
+                        format!("  {}", url),
+                        Style::default()
+                            .fg(COLOR_ACCENT)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+                ];
+                if let Some(code) = wizard.user_code.as_ref().filter(|c| !c.is_empty()) {
+                    lines.push(Line::from(""));
+                    lines.push(Line::from(vec![
+                        Span::raw("  Code: "),
+                        Span::styled(code.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                    ]));
+                }
+                lines.push(Line::from(""));
+                lines.push(waiting("Waiting for authorization...".to_string()));
+                lines
+            }
+            None => vec![waiting("Requesting a sign-in code...".to_string())],
+        },
+        (SetupStep::Auth, StepStatus::Done) => vec![done("Signed in".to_string())],
+        (SetupStep::Auth, _) => vec![Line::from("Sign in to your SPOQ account.")],
+
+        (SetupStep::Vps, StepStatus::Running) => vec![waiting(
+            wizard
+                .vps_progress
+                .clone()
+                .unwrap_or_else(|| "Looking up your VPS...".to_string()),
+        )],
+        (SetupStep::Vps, StepStatus::Done) => {
+            let mut lines = vec![done(format!(
+                "VPS ready: {}",
+                wizard.vps_hostname.as_deref().unwrap_or("unknown")
+            ))];
+            if let Some(ref url) = wizard.vps_url {
+                lines.push(Line::from(Span::styled(
+                    format!("  {}", url),
+                    Style::default().fg(COLOR_DIM),
+                )));
+            }
+            lines
+        }
+        (SetupStep::Vps, _) => vec![Line::from("Find the VPS that runs your conductor.")],
+
+        (SetupStep::Credentials, StepStatus::Running) => {
+            vec![waiting("Checking local credentials...".to_string())]
+        }
+        (SetupStep::Credentials, _) => match wizard.credentials {
+            Some(checks) => credential_lines(&checks),
+            None => vec![Line::from("Check the Claude and GitHub CLI credentials.")],
+        },
+
+        (SetupStep::Summary, _) => summary_lines(wizard),
+    }
+}
+
+/// One line per local credential, with how to fix a missing one
+fn credential_lines(checks: &CredentialChecks) -> Vec<Line<'static>> {
+    let rows = [
+        (
+            checks.claude_json,
+            "Claude credentials (~/.claude.json)",
+            "run `claude` and sign in",
+        ),
+        (
+            checks.gh_installed,
+            "GitHub CLI installed",
+            "install from https://cli.github.com/",
+        ),
+        (
+            checks.gh_authenticated,
+            "GitHub CLI signed in",
+            "run `gh auth login`",
+        ),
+        (
+            checks.gh_hosts,
+            "GitHub CLI credentials (~/.config/gh/hosts.yml)",
+            "created by `gh auth login`",
+        ),
+    ];
+
+    let mut lines: Vec<Line<'static>> = rows
+        .iter()
+        .map(|(found, label, fix)| {
+            if *found {
+                Line::from(Span::styled(
+                    format!("\u{2713} {}", label),
+                    Style::default().fg(COLOR_TOOL_SUCCESS),
+                ))
+            } else {
+                Line::from(vec![
+                    Span::styled(
+                        format!("\u{2717} {}", label),
+                        Style::default().fg(COLOR_TOOL_ERROR),
+                    ),
+                    Span::styled(format!(" \u{2014} {}", fix), Style::default().fg(COLOR_DIM)),
+                ])
+            }
+        })
+        .collect();
+
+    if rows.iter().any(|(found, _, _)| !found) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Missing credentials can be added later; they sync to the VPS once found.",
+            Style::default().fg(COLOR_DIM),
+        )));
+    }
+    lines
+}
+
+/// What setup found, before finishing
+fn summary_lines(wizard: &SetupWizard) -> Vec<Line<'static>> {
+    let row = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<10}", label), Style::default().fg(COLOR_DIM)),
+            Span::raw(value),
+        ])
+    };
+    let found = |ok: bool, yes: &str, no: &str| (if ok { yes } else { no }).to_string();
+    let checks = wizard.credentials.unwrap_or_default();
+
+    vec![
+        row("Account", "signed in".to_string()),
+        row(
+            "VPS",
+            wizard
+                .vps_hostname
+                .clone()
+                .unwrap_or_else(|| "not set up".to_string()),
+        ),
+        row(
+            "Claude",
+            found(checks.claude_json, "credentials found", "not signed in"),
+        ),
+        row(
+            "GitHub",
+            found(checks.gh_authenticated, "signed in", "not signed in"),
+        ),
+        Line::from(""),
+        Line::from("Press Enter to start using SPOQ."),
+    ]
+}
+
+/// Keys for the current step
+fn hint_line(wizard: &SetupWizard) -> Line<'static> {
+    let enter = match wizard.status(wizard.step) {
+        StepStatus::Running => None,
+        StepStatus::Failed(_) if wizard.vps_missing => Some("set up VPS"),
+        StepStatus::Failed(_) => Some("retry"),
+        StepStatus::Pending => Some("start"),
+        StepStatus::Done if wizard.step == SetupStep::Summary => Some("finish"),
+        StepStatus::Done => Some("continue"),
+    };
+
+    let mut spans = Vec::new();
+    let mut push = |key: &str, action: &str| {
+        spans.push(Span::styled(
+            format!("[{}] ", key),
+            Style::default().fg(COLOR_ACCENT),
+        ));
+        spans.push(Span::styled(
+            format!("{}  ", action),
+            Style::default().fg(COLOR_DIM),
+        ));
+    };
+    if let Some(action) = enter {
+        push("Enter", action);
+    }
+    if wizard.step == SetupStep::Auth && wizard.verification_url.is_some() {
+        push("o", "open in browser");
+    }
+    if wizard.step != SetupStep::Auth && !wizard.is_running() {
+        push("Esc", "back");
+    }
+    push("Ctrl+C", "quit");
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn render_text(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(90, 30)).unwrap();
+        terminal.draw(|f| render_setup(f, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|c| c.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_auth_step_shows_url_code_and_open_hint() {
+        let mut app = App::default();
+        let mut wizard = SetupWizard::default();
+        wizard.set_status(SetupStep::Auth, StepStatus::Running);
+        wizard.verification_url = Some("https://spoq.dev/device".to_string());
+        wizard.user_code = Some("ABCD-1234".to_string());
+        app.setup_wizard = Some(wizard);
+
+        let text = render_text(&app);
+
+        assert!(text.contains("https://spoq.dev/device"));
+        assert!(text.contains("ABCD-1234"));
+        assert!(text.contains("[o] open in browser"));
+        assert!(!text.contains("[Enter]"));
+    }
+
+    #[test]
+    fn test_failed_step_offers_retry_and_back() {
+        let mut wizard = SetupWizard::default();
+        wizard.set_status(SetupStep::Auth, StepStatus::Done);
+        wizard.step = SetupStep::Vps;
+        wizard.set_status(
+            SetupStep::Vps,
+            StepStatus::Failed("VPS is in failed state".to_string()),
+        );
+
+        let body: Vec<String> = step_lines(&wizard, "-").iter().map(line_text).collect();
+        assert_eq!(body[0], "\u{2717} VPS is in failed state");
+        let hint = line_text(&hint_line(&wizard));
+        assert!(hint.contains("[Enter] retry"));
+        assert!(hint.contains("[Esc] back"));
+
+        wizard.vps_missing = true;
+        assert!(line_text(&hint_line(&wizard)).contains("[Enter] set up VPS"));
+    }
+
+    #[test]
+    fn test_credential_lines_explain_missing_ones() {
+        let checks = CredentialChecks {
+            claude_json: true,
+            gh_hosts: false,
+            gh_installed: true,
+            gh_authenticated: false,
+        };
+
+        let lines: Vec<String> = credential_lines(&checks).iter().map(line_text).collect();
+
+        assert_eq!(lines[0], "\u{2713} Claude credentials (~/.claude.json)");
+        assert_eq!(
+            lines[2],
+            "\u{2717} GitHub CLI signed in \u{2014} run `gh auth login`"
+        );
+        assert!(lines.last().unwrap().contains("added later"));
+    }
+}
@@ -0,0 +1,132 @@
+//! Thread share dialog rendering
+//!
+//! Shown by `/share` (or `s` on a thread card): the share options for a
+//! thread not shared yet, or the link of one already shared, with the keys
+//! to copy or revoke it.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::helpers::truncate_string;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Render the share dialog as a centered overlay
+pub fn render_share_dialog(frame: &mut Frame, app: &App) {
+    let Some(ref dialog) = app.share_dialog else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = 60u16.min(area.width.saturating_sub(4));
+    // Borders (2) + padding (2) + three rows + blank line + hint line
+    let dialog_height = 9u16.min(area.height.saturating_sub(2));
+
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let title = app
+        .cache
+        .get_thread(&dialog.thread_id)
+        .map(|t| t.title.as_str())
+        .unwrap_or("thread");
+    let block = Block::default()
+        .title(Span::styled(
+            format!(" Share: {} ", truncate_string(title, 40)),
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 2,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(4),
+    };
+
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(COLOR_ACCENT));
+    let hint = |h: &'static str| Span::styled(h, Style::default().fg(COLOR_DIM));
+
+    let mut lines: Vec<Line> = match &dialog.existing {
+        Some(share) => vec![
+            Line::from(hint("Shared with:")),
+            Line::from(Span::raw(format!("  {}", share.visibility.label()))),
+            Line::from(Span::styled(
+                truncate_string(&share.url, inner.width as usize),
+                Style::default()
+                    .fg(COLOR_ACCENT)
+                    .add_modifier(Modifier::UNDERLINED),
+            )),
+        ],
+        None => {
+            let options = &dialog.options;
+            let check = |on: bool| if on { "[x]" } else { "[ ]" };
+            [
+                format!("Visible to:   {}", options.visibility.label()),
+                format!(
+                    "{} Include tool outputs",
+                    check(options.include_tool_outputs)
+                ),
+                format!("{} Include reasoning", check(options.include_reasoning)),
+            ]
+            .into_iter()
+            .enumerate()
+            .map(|(row, text)| {
+                let is_selected = row == dialog.selected_row;
+                let marker = if is_selected { "▶ " } else { "  " };
+                let style = if is_selected {
+                    Style::default()
+                        .fg(COLOR_ACCENT)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(COLOR_DIM)
+                };
+                Line::from(vec![Span::styled(marker, style), Span::styled(text, style)])
+            })
+            .collect()
+        }
+    };
+
+    lines.push(Line::from(""));
+    lines.push(if dialog.in_progress {
+        Line::from(hint("Working…"))
+    } else if dialog.existing.is_some() {
+        Line::from(vec![
+            key("[Enter] "),
+            hint("copy link  "),
+            key("[r] "),
+            hint("revoke  "),
+            key("[Esc] "),
+            hint("close"),
+        ])
+    } else {
+        Line::from(vec![
+            key("[Enter] "),
+            hint("share  "),
+            key("[Space] "),
+            hint("change  "),
+            key("[Esc] "),
+            hint("cancel"),
+        ])
+    });
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
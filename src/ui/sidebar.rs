@@ -0,0 +1,176 @@
+//! Needs-action sidebar for the conversation screen
+//!
+//! Lists the dashboard threads waiting on the user (title, what they wait
+//! for, how long) in a column on the right. Entries are numbered for
+//! Alt+number, and their areas are recorded in `app.sidebar_layout` for
+//! mouse clicks.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::models::dashboard::WaitingFor;
+use crate::view_state::{SidebarLayout, ThreadView};
+
+use super::dashboard::thread_row::truncate;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Sidebar width in columns, including its left border
+pub const SIDEBAR_WIDTH: u16 = 36;
+
+/// Rows per entry: title line and waiting/age line
+const ENTRY_HEIGHT: u16 = 2;
+
+/// Short label for what a thread is waiting on
+fn waiting_label(waiting_for: Option<&WaitingFor>) -> String {
+    match waiting_for {
+        Some(WaitingFor::Permission { tool_name, .. }) => format!("Permission: {}", tool_name),
+        Some(WaitingFor::PlanApproval { .. }) => "Plan approval".to_string(),
+        Some(WaitingFor::UserInput) | None => "Question".to_string(),
+    }
+}
+
+/// The two lines of one entry
+fn entry_lines(number: usize, view: &ThreadView, width: usize) -> [Line<'static>; 2] {
+    let prefix = if number <= 9 {
+        format!("{} ", number)
+    } else {
+        "  ".to_string()
+    };
+    let title_width = width.saturating_sub(prefix.len());
+    let detail = format!(
+        "{} \u{b7} {}",
+        waiting_label(view.waiting_for.as_ref()),
+        view.duration
+    );
+
+    [
+        Line::from(vec![
+            Span::styled(prefix, Style::default().fg(COLOR_ACCENT)),
+            Span::styled(
+                truncate(&view.title, title_width),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(Span::styled(
+            format!("  {}", truncate(&detail, width.saturating_sub(2))),
+            Style::default().fg(COLOR_DIM),
+        )),
+    ]
+}
+
+/// Render the sidebar into `area` and record the entry areas
+pub fn render_needs_action_sidebar(frame: &mut Frame, area: Rect, app: &mut App) {
+    let views: Vec<ThreadView> = app
+        .dashboard
+        .compute_thread_views()
+        .iter()
+        .filter(|v| v.needs_action)
+        .cloned()
+        .collect();
+
+    let block = Block::default()
+        .title(Span::styled(
+            format!(" Needs action ({}) ", views.len()),
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::LEFT)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let inner = Rect {
+        x: inner.x + 1,
+        y: inner.y + 1,
+        width: inner.width.saturating_sub(2),
+        height: inner.height.saturating_sub(1),
+    };
+
+    let mut layout = SidebarLayout::default();
+    if views.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "Nothing needs you",
+                Style::default().fg(COLOR_DIM),
+            )),
+            inner,
+        );
+    }
+
+    let width = inner.width as usize;
+    let mut y = inner.y;
+    for (i, view) in views.iter().enumerate() {
+        if y + ENTRY_HEIGHT > inner.bottom() {
+            break;
+        }
+        let entry_area = Rect::new(inner.x, y, inner.width, ENTRY_HEIGHT);
+        frame.render_widget(
+            Paragraph::new(entry_lines(i + 1, view, width).to_vec()),
+            entry_area,
+        );
+        layout.entries.push((entry_area, view.id.clone()));
+        // One blank row between entries
+        y += ENTRY_HEIGHT + 1;
+    }
+
+    app.sidebar_layout = layout;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::dashboard::ThreadStatus;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn test_entry_lines() {
+        let mut view = ThreadView::new("t1".into(), "Fix auth".into(), "repo".into());
+        view.waiting_for = Some(WaitingFor::Permission {
+            request_id: "p1".into(),
+            tool_name: "Bash".into(),
+        });
+        view.duration = "3m".into();
+
+        let text = |line: &Line| {
+            line.spans
+                .iter()
+                .map(|s| s.content.as_ref())
+                .collect::<String>()
+        };
+        let [title, detail] = entry_lines(2, &view, 30);
+        assert_eq!(text(&title), "2 Fix auth");
+        assert_eq!(text(&detail), "  Permission: Bash \u{b7} 3m");
+    }
+
+    #[test]
+    fn test_render_records_entry_areas() {
+        let mut app = App::default();
+        for (id, title) in [("t1", "Fix auth"), ("t2", "Done thing")] {
+            let thread = serde_json::from_value(serde_json::json!({"id": id, "title": title}))
+                .expect("thread");
+            app.dashboard.add_thread(thread);
+        }
+        app.dashboard.update_thread_status(
+            "t1",
+            ThreadStatus::Waiting,
+            Some(WaitingFor::UserInput),
+        );
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 20)).unwrap();
+        terminal
+            .draw(|f| render_needs_action_sidebar(f, Rect::new(4, 0, 36, 20), &mut app))
+            .unwrap();
+
+        assert_eq!(app.sidebar_layout.entries.len(), 1);
+        assert_eq!(app.sidebar_layout.thread_for_number(1), Some("t1"));
+        let (area, _) = &app.sidebar_layout.entries[0];
+        assert_eq!(app.sidebar_layout.thread_at(area.x, area.y + 1), Some("t1"));
+    }
+}
@@ -76,3 +76,21 @@ pub const COLOR_DIALOG_BG: Color = Color::Rgb(10, 15, 35);
 
 /// Background color for human/user messages - subtle blue-gray tint
 pub const COLOR_HUMAN_BG: Color = Color::Rgb(35, 40, 48);
+
+// ============================================================================
+// Search Highlight Colors
+// ============================================================================
+
+/// Background for message search matches
+pub const COLOR_SEARCH_MATCH_BG: Color = Color::Rgb(90, 80, 20);
+
+/// Background for the currently selected message search match
+pub const COLOR_SEARCH_CURRENT_BG: Color = Color::Rgb(200, 160, 40);
+
+// ============================================================================
+// Permission Mode Colors
+// ============================================================================
+
+/// Background of the Execute (bypass permissions) mode indicator, loud on
+/// purpose: no tool call is prompted in that mode
+pub const COLOR_BYPASS_BG: Color = Color::Red;
@@ -0,0 +1,90 @@
+//! Thread metrics rendering
+//!
+//! Shown by `/metrics`: the thread's turn outcomes, permission answers,
+//! errors and reverted edits, its model and mode, and the averages it can be
+//! compared with.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Width of the label column
+const LABEL_WIDTH: usize = 16;
+
+/// Render the `/metrics` pane as a centered dialog
+pub fn render_thread_metrics(frame: &mut Frame, app: &App) {
+    let Some(ref thread_id) = app.thread_metrics_view else {
+        return;
+    };
+    let rows = app.thread_metrics_rows(thread_id);
+    let title = app
+        .cache
+        .get_thread(thread_id)
+        .map(|thread| format!(" Metrics: {} ", thread.title))
+        .unwrap_or_else(|| " Metrics ".to_string());
+
+    let area = frame.area();
+    let dialog_width = 84u16.min(area.width.saturating_sub(4));
+    let dialog_height = (rows.len() as u16 + 6).min(area.height.saturating_sub(2));
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    frame.render_widget(block, dialog_area);
+
+    let rows_area = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(3),
+    };
+    // Comparison rows can be long; they wrap under the value column
+    let lines: Vec<Line> = rows
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", label, width = LABEL_WIDTH),
+                    Style::default().fg(COLOR_DIM),
+                ),
+                Span::raw(value),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), rows_area);
+
+    let hint_area = Rect {
+        x: rows_area.x,
+        y: dialog_area.y + dialog_area.height.saturating_sub(2),
+        width: rows_area.width,
+        height: 1,
+    };
+    let hint = Line::from(vec![
+        Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("close", Style::default().fg(COLOR_DIM)),
+    ]);
+    frame.render_widget(Paragraph::new(hint), hint_area);
+}
@@ -0,0 +1,107 @@
+//! Inline thread title editor rendering
+//!
+//! Shown by `/rename` (or `r` on a thread card): a one-line field prefilled
+//! with the thread's current title.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthChar;
+
+use crate::app::App;
+
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Render the title editor as a small centered dialog
+pub fn render_thread_rename(frame: &mut Frame, app: &App) {
+    let Some(ref editor) = app.thread_rename else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = 60u16.min(area.width.saturating_sub(4));
+    // Borders (2) + title field + blank line + hint line
+    let dialog_height = 5u16.min(area.height.saturating_sub(2));
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            " Rename thread ",
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(2),
+    };
+
+    // Keep the end of the title (where typing happens) in view
+    let field_width = (inner.width as usize).saturating_sub(1);
+    let visible_title = title_tail(&editor.title, field_width);
+
+    let lines = vec![
+        Line::from(vec![
+            Span::raw(visible_title),
+            Span::styled(
+                "_",
+                Style::default()
+                    .fg(COLOR_ACCENT)
+                    .add_modifier(Modifier::SLOW_BLINK),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("[Enter] ", Style::default().fg(COLOR_ACCENT)),
+            Span::styled("save  ", Style::default().fg(COLOR_DIM)),
+            Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)),
+            Span::styled("cancel", Style::default().fg(COLOR_DIM)),
+        ]),
+    ];
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// The end of `title` that fits in `max_width` columns
+fn title_tail(title: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    let mut start = title.len();
+    for (index, c) in title.char_indices().rev() {
+        width += c.width().unwrap_or(0);
+        if width > max_width {
+            break;
+        }
+        start = index;
+    }
+    &title[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_tail_keeps_end_in_view() {
+        assert_eq!(title_tail("Fix the login flow", 40), "Fix the login flow");
+        assert_eq!(title_tail("Fix the login flow", 4), "flow");
+        assert_eq!(title_tail("日本語", 4), "本語");
+        assert_eq!(title_tail("", 4), "");
+    }
+}
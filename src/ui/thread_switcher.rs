@@ -1,7 +1,11 @@
 //! Thread Switcher Dialog rendering
 //!
 //! Implements the Ctrl+Tab thread switcher overlay similar to macOS app switcher.
-//! Shows threads in MRU (Most Recently Used) order with keyboard navigation.
+//! Shows pinned threads first (marked with a pin), then the rest in MRU
+//! (Most Recently Used) order, with keyboard navigation. Typing filters the
+//! list by title and preview. Each row shows the thread type, how long ago it
+//! was updated and a badge when it is streaming or needs action. Muted
+//! threads are dimmed and marked with a bell glyph.
 //!
 //! The overlay adapts to terminal size:
 //! - On extra-small screens (< 60 cols): Uses compact layout with abbreviated hints
@@ -17,12 +21,29 @@ use ratatui::{
 };
 
 use crate::app::App;
-use crate::models::ThreadType;
+use crate::models::{Thread, ThreadStatus, ThreadType};
 
-use super::helpers::extract_short_model_name;
+use super::helpers::{extract_short_model_name, format_compact_age, SPINNER_FRAMES};
 use super::layout::LayoutContext;
 use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
 
+/// Narrowest room worth showing a message preview in
+const MIN_PREVIEW_WIDTH: usize = 12;
+
+/// Activity badge of a switcher row: spinner while streaming, `!` when the
+/// thread waits for a permission or answer
+fn activity_badge(app: &App, thread: &Thread) -> Option<(&'static str, Color)> {
+    if app.dashboard.get_waiting_for(&thread.id).is_some() {
+        return Some(("!", Color::Yellow));
+    }
+    let running = app.dashboard.thread_status(&thread.id) == Some(ThreadStatus::Running);
+    if running || app.cache.is_thread_streaming(&thread.id) {
+        let frame = SPINNER_FRAMES[(app.tick_count as usize) % SPINNER_FRAMES.len()];
+        return Some((frame, Color::Green));
+    }
+    None
+}
+
 /// Calculate maximum visible threads based on terminal dimensions
 fn calculate_max_visible_threads(ctx: &LayoutContext) -> usize {
     // Reserve space for: borders (2) + padding (2) + hint line (1) = 5 minimum
@@ -84,9 +105,12 @@ pub fn render_thread_switcher(frame: &mut Frame, app: &App) {
     let ctx = LayoutContext::new(app.terminal_width, app.terminal_height);
 
     // Calculate responsive dimensions
+    let entries = app.switcher_entries();
+    let query = &app.thread_switcher.query;
     let max_visible_threads = calculate_max_visible_threads(&ctx);
     let dialog_width = calculate_dialog_width(&ctx, area.width);
-    let visible_count = threads.len().min(max_visible_threads);
+    // An empty result still takes a row for "No matching threads"
+    let visible_count = entries.len().clamp(1, max_visible_threads);
     let dialog_height = calculate_dialog_height(&ctx, visible_count, area.height);
 
     // Center the dialog
@@ -134,8 +158,15 @@ pub fn render_thread_switcher(frame: &mut Frame, app: &App) {
     // Build content lines
     let mut lines: Vec<Line> = Vec::new();
 
-    // Skip top padding on extra-small screens to save space
-    if !ctx.is_extra_small() {
+    // The filter takes the top padding row while typing; extra-small
+    // screens skip the padding to save space
+    if !query.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("  / ", Style::default().fg(COLOR_ACCENT)),
+            Span::styled(query.as_str(), Style::default().fg(COLOR_HEADER)),
+            Span::styled("▏", Style::default().fg(COLOR_ACCENT)),
+        ]));
+    } else if !ctx.is_extra_small() {
         lines.push(Line::from("")); // Top padding
     }
 
@@ -163,16 +194,24 @@ pub fn render_thread_switcher(frame: &mut Frame, app: &App) {
         )]));
     }
 
+    if entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No matching threads",
+            Style::default().fg(COLOR_DIM),
+        )));
+    }
+
     // Iterate through visible threads starting from scroll_offset
-    let visible_threads: Vec<_> = threads
+    let visible_threads: Vec<_> = entries
         .iter()
         .enumerate()
         .skip(scroll_offset)
         .take(max_visible_threads)
+        .filter_map(|(position, &index)| threads.get(index).map(|thread| (position, thread)))
         .collect();
 
-    for (idx, thread) in visible_threads {
-        let is_selected = idx == selected_idx;
+    for (position, thread) in visible_threads {
+        let is_selected = position == selected_idx;
 
         // Selection marker
         let marker = if is_selected { "▶ " } else { "  " };
@@ -213,6 +252,22 @@ pub fn render_thread_switcher(frame: &mut Frame, app: &App) {
             ""
         };
 
+        let badge = activity_badge(app, thread);
+        let age = format_compact_age(chrono::Utc::now().signed_duration_since(thread.updated_at));
+
+        // Pinned and muted threads lose room for their glyph (two columns) and
+        // a space, badges for the glyph and a space, every row for the age
+        let mut max_title_width = max_title_width.saturating_sub(age.len() + 2);
+        if thread.pinned {
+            max_title_width = max_title_width.saturating_sub(3);
+        }
+        if thread.muted {
+            max_title_width = max_title_width.saturating_sub(3);
+        }
+        if badge.is_some() {
+            max_title_width = max_title_width.saturating_sub(2);
+        }
+
         // Truncate thread title if needed (respecting UTF-8 boundaries)
         let title = if thread.title.len() > max_title_width {
             let end = max_title_width.saturating_sub(3);
@@ -233,6 +288,8 @@ pub fn render_thread_switcher(frame: &mut Frame, app: &App) {
             Style::default()
                 .fg(COLOR_HEADER)
                 .add_modifier(Modifier::BOLD)
+        } else if thread.muted {
+            Style::default().fg(Color::DarkGray)
         } else {
             Style::default().fg(COLOR_DIM)
         };
@@ -257,13 +314,42 @@ pub fn render_thread_switcher(frame: &mut Frame, app: &App) {
             spans.push(Span::raw(" "));
         }
 
+        if let Some((glyph, color)) = badge {
+            spans.push(Span::styled(glyph, Style::default().fg(color)));
+            spans.push(Span::raw(" "));
+        }
+        if thread.pinned {
+            spans.push(Span::raw("📌 "));
+        }
+        if thread.muted {
+            spans.push(Span::raw("🔕 "));
+        }
         spans.push(Span::styled(title, title_style));
+        spans.push(Span::styled(
+            format!("  {}", age),
+            Style::default().fg(Color::DarkGray),
+        ));
+
+        // Latest message preview in the room left after the title
+        let used: usize = spans.iter().map(|span| span.width()).sum();
+        let available = (inner.width as usize).saturating_sub(used + 2);
+        if available >= MIN_PREVIEW_WIDTH {
+            if let Some(preview) = app.thread_previews.truncated(&thread.id, available) {
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled(
+                    preview,
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::ITALIC),
+                ));
+            }
+        }
 
         lines.push(Line::from(spans));
     }
 
     // Show scroll down indicator if there are hidden threads below
-    let threads_below = threads
+    let threads_below = entries
         .len()
         .saturating_sub(scroll_offset + max_visible_threads);
     if threads_below > 0 {
@@ -307,6 +393,8 @@ pub fn render_thread_switcher(frame: &mut Frame, app: &App) {
             Span::styled("  ", Style::default()),
             Span::styled("Tab/↓↑", Style::default().fg(COLOR_ACCENT)),
             Span::styled(": navigate  ", Style::default().fg(COLOR_DIM)),
+            Span::styled("type", Style::default().fg(COLOR_ACCENT)),
+            Span::styled(": filter  ", Style::default().fg(COLOR_DIM)),
             Span::styled("Esc", Style::default().fg(COLOR_ACCENT)),
             Span::styled(": cancel", Style::default().fg(COLOR_DIM)),
         ]));
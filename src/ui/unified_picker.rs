@@ -15,14 +15,19 @@ use ratatui::{
     Frame,
 };
 
+use std::time::Duration;
+
 use crate::models::picker::{PickerItem, PickerSection};
-use crate::state::UnifiedPickerState;
+use crate::state::{SectionState, UnifiedPickerState};
 
 use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIALOG_BG, COLOR_DIM, COLOR_HEADER};
 
 /// Maximum visible rows in the picker (across all sections)
 const MAX_VISIBLE_ROWS: usize = 10;
 
+/// Shown after folders already attached to the message
+const ATTACHED_LABEL: &str = " \u{2713} attached";
+
 /// Section header style
 const SECTION_HEADER_STYLE: Style = Style::new();
 
@@ -55,6 +60,34 @@ fn section_header(section: PickerSection) -> &'static str {
     }
 }
 
+/// Compact age of cached data (`45s`, `12m`, `2h`, `3d`)
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else if secs < 86400 {
+        format!("{}h", secs / 3600)
+    } else {
+        format!("{}d", secs / 86400)
+    }
+}
+
+/// Staleness note for a section header ("updated 2h ago – refreshing…"),
+/// None while its items are fresh
+fn freshness_label(section: &SectionState) -> Option<String> {
+    if !section.stale && !section.refreshing {
+        return None;
+    }
+    let updated_at = section.updated_at?;
+    let mut label = format!("updated {} ago", format_age(updated_at.elapsed()));
+    if section.refreshing {
+        label.push_str(" \u{2013} refreshing\u{2026}");
+    }
+    Some(label)
+}
+
 /// Render the unified picker dialog as a bottom-anchored overlay
 ///
 /// # Arguments
@@ -104,7 +137,13 @@ pub fn render_unified_picker(frame: &mut Frame, state: &UnifiedPickerState, inpu
     frame.render_widget(Clear, dialog_area);
 
     // Create the dialog border with solid background
-    let title = if state.query.is_empty() {
+    let title = if state.changing_directory {
+        if state.query.is_empty() {
+            " Change working directory ".to_string()
+        } else {
+            format!(" cd {} ", state.query)
+        }
+    } else if state.query.is_empty() {
         " Select Project ".to_string()
     } else {
         format!(" @{} ", state.query)
@@ -214,24 +253,45 @@ fn build_picker_lines(state: &UnifiedPickerState, available_width: usize) -> (Ve
     ];
 
     for (section, section_state) in sections {
-        // Skip empty sections (unless loading)
-        if section_state.items.is_empty() && !section_state.loading {
+        // Skip empty sections (unless loading or browsing)
+        if !state.shows_section(section) {
             continue;
         }
 
+        // While browsing, the folders section lists a directory
+        let browse = state
+            .browse
+            .as_ref()
+            .filter(|_| section == PickerSection::Folders);
+
         // Section header
         let header_style = SECTION_HEADER_STYLE
             .fg(COLOR_DIM)
             .add_modifier(Modifier::BOLD);
 
+        let header_text = match browse {
+            Some(browse) => browse.display_path().to_string(),
+            None => section_header(section).to_string(),
+        };
         let mut header_spans = vec![
             Span::styled("  ", Style::default()),
-            Span::styled(section_header(section), header_style),
+            Span::styled(header_text, header_style),
         ];
 
         // Show loading indicator
-        if section_state.loading {
+        let loading = browse.map_or(section_state.loading, |b| b.loading);
+        // Age of stale or refreshing items (not for directory listings)
+        let freshness = browse
+            .is_none()
+            .then(|| freshness_label(section_state))
+            .flatten();
+        if loading {
             header_spans.push(Span::styled(" ...", Style::default().fg(COLOR_DIM)));
+        } else if let Some(label) = freshness {
+            header_spans.push(Span::styled(
+                format!("  {}", label),
+                Style::default().fg(COLOR_DIM),
+            ));
         }
 
         lines.push(Line::from(header_spans));
@@ -240,12 +300,17 @@ fn build_picker_lines(state: &UnifiedPickerState, available_width: usize) -> (Ve
         for (item_idx, item) in section_state.items.iter().enumerate() {
             let is_selected = state.selected_section == section && state.selected_index == item_idx;
 
-            let line = render_item_line(item, is_selected, available_width);
+            let attached = state.is_attached(item);
+            let line = render_item_line(item, is_selected, attached, available_width);
             lines.push(line);
         }
 
         // Show error if any
-        if let Some(ref error) = section_state.error {
+        let error = match browse {
+            Some(browse) => browse.error.as_ref(),
+            None => section_state.error.as_ref(),
+        };
+        if let Some(error) = error {
             lines.push(Line::from(vec![
                 Span::styled("    ", Style::default()),
                 Span::styled(
@@ -277,22 +342,39 @@ fn build_picker_lines(state: &UnifiedPickerState, available_width: usize) -> (Ve
     }
 
     // Hint line
-    lines.push(Line::from(vec![
+    let mut hint = vec![
         Span::styled("  ", Style::default()),
         Span::styled("↑↓", Style::default().fg(COLOR_ACCENT)),
         Span::styled(": navigate  ", Style::default().fg(COLOR_DIM)),
         Span::styled("Enter", Style::default().fg(COLOR_ACCENT)),
-        Span::styled(": select  ", Style::default().fg(COLOR_DIM)),
-        Span::styled("Esc", Style::default().fg(COLOR_ACCENT)),
-        Span::styled(": cancel", Style::default().fg(COLOR_DIM)),
-    ]));
+    ];
+    if state.is_browsing() {
+        hint.push(Span::styled(
+            ": open/select  ",
+            Style::default().fg(COLOR_DIM),
+        ));
+        hint.push(Span::styled("Bksp", Style::default().fg(COLOR_ACCENT)));
+        hint.push(Span::styled(": up  ", Style::default().fg(COLOR_DIM)));
+    } else {
+        hint.push(Span::styled(": select  ", Style::default().fg(COLOR_DIM)));
+    }
+    hint.push(Span::styled("^R", Style::default().fg(COLOR_ACCENT)));
+    hint.push(Span::styled(": refresh  ", Style::default().fg(COLOR_DIM)));
+    hint.push(Span::styled("Esc", Style::default().fg(COLOR_ACCENT)));
+    hint.push(Span::styled(": cancel", Style::default().fg(COLOR_DIM)));
+    lines.push(Line::from(hint));
 
     let total_items = state.total_items();
     (lines, total_items)
 }
 
-/// Render a single item line
-fn render_item_line(item: &PickerItem, is_selected: bool, available_width: usize) -> Line<'static> {
+/// Render a single item line (`attached` folders are marked as such)
+fn render_item_line(
+    item: &PickerItem,
+    is_selected: bool,
+    attached: bool,
+    available_width: usize,
+) -> Line<'static> {
     let icon = item_icon(item);
     let name = item.display_name().to_string();
 
@@ -320,7 +402,13 @@ fn render_item_line(item: &PickerItem, is_selected: bool, available_width: usize
     let icon_len = 2;
     let name_len = name.chars().count();
     let separator_len = 2;
-    let remaining = available_width.saturating_sub(marker_len + icon_len + name_len + separator_len + 4);
+    let attached_len = if attached {
+        ATTACHED_LABEL.chars().count()
+    } else {
+        0
+    };
+    let remaining = available_width
+        .saturating_sub(marker_len + icon_len + name_len + separator_len + attached_len + 4);
 
     let secondary_info = match item {
         PickerItem::Folder { path, .. } => Some(truncate_path(path, remaining)),
@@ -336,6 +424,10 @@ fn render_item_line(item: &PickerItem, is_selected: bool, available_width: usize
         Span::styled(name, name_style),
     ];
 
+    if attached {
+        spans.push(Span::styled(ATTACHED_LABEL, Style::default().fg(COLOR_ACCENT)));
+    }
+
     if let Some(info) = secondary_info {
         spans.push(Span::styled("  ", Style::default()));
         spans.push(Span::styled(info, Style::default().fg(COLOR_DIM)));
@@ -530,6 +622,26 @@ mod tests {
         assert_eq!(lines.len(), 1);
     }
 
+    #[test]
+    fn test_build_picker_lines_browse_error_inline() {
+        let mut state = UnifiedPickerState::new();
+        state.visible = true;
+        state.start_browse("/root".to_string());
+        state.set_directory_error("/root", "Permission denied: /root".to_string());
+
+        let (lines, _) = build_picker_lines(&state, 80);
+        let text: Vec<String> = lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+
+        assert!(text.iter().any(|l| l.contains("/root")));
+        assert!(text
+            .iter()
+            .any(|l| l.contains("Error: Permission denied: /root")));
+        assert!(text.last().unwrap().contains("up"));
+    }
+
     #[test]
     fn test_render_item_line_selected() {
         let item = PickerItem::Folder {
@@ -537,7 +649,7 @@ mod tests {
             path: "/home/user/my-project".to_string(),
         };
 
-        let line = render_item_line(&item, true, 80);
+        let line = render_item_line(&item, true, false, 80);
 
         // Should contain the item name
         let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
@@ -551,11 +663,25 @@ mod tests {
             path: "/home/user/my-project".to_string(),
         };
 
-        let line = render_item_line(&item, false, 80);
+        let line = render_item_line(&item, false, false, 80);
 
         // Should contain the item name
         let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
         assert!(text.contains("my-project"));
+        assert!(!text.contains(ATTACHED_LABEL));
+    }
+
+    #[test]
+    fn test_render_item_line_attached() {
+        let item = PickerItem::Folder {
+            name: "my-project".to_string(),
+            path: "/home/user/my-project".to_string(),
+        };
+
+        let line = render_item_line(&item, false, true, 80);
+
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.contains("my-project \u{2713} attached"));
     }
 
     #[test]
@@ -588,4 +714,24 @@ mod tests {
         let wrapped = wrap_text(text, 50);
         assert_eq!(wrapped, vec![""]);
     }
+
+    #[test]
+    fn test_freshness_label() {
+        let mut section = SectionState::new();
+        assert_eq!(freshness_label(&section), None);
+
+        section.updated_at = Some(std::time::Instant::now());
+        assert_eq!(freshness_label(&section), None);
+
+        section.stale = true;
+        assert_eq!(freshness_label(&section).as_deref(), Some("updated 0s ago"));
+
+        section.refreshing = true;
+        assert_eq!(
+            freshness_label(&section).as_deref(),
+            Some("updated 0s ago \u{2013} refreshing\u{2026}")
+        );
+        assert_eq!(format_age(Duration::from_secs(90)), "1m");
+        assert_eq!(format_age(Duration::from_secs(2 * 3600 + 5)), "2h");
+    }
 }
@@ -0,0 +1,202 @@
+//! Token usage overlay rendering
+//!
+//! Shown by `/usage`: a row per thread with its turns and prompt, completion
+//! and total tokens (and estimated cost when prices are configured), most
+//! used first, with the session totals below.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::cache::TokenUsage;
+
+use super::context_gauge::format_token_count;
+use super::helpers::truncate_string;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Widths of the number columns: turns, prompt, completion, total
+const NUMBER_WIDTHS: [usize; 4] = [7, 9, 12, 9];
+
+/// Width of the cost column
+const COST_WIDTH: usize = 10;
+
+/// One table line: the title padded to `title_width`, then the numbers
+fn table_line(
+    title: &str,
+    numbers: [String; 4],
+    cost: Option<String>,
+    title_width: usize,
+) -> String {
+    let mut line = format!(
+        "{:<width$}",
+        truncate_string(title, title_width.saturating_sub(1)),
+        width = title_width
+    );
+    for (number, width) in numbers.iter().zip(NUMBER_WIDTHS) {
+        line.push_str(&format!("{:>width$}", number, width = width));
+    }
+    if let Some(cost) = cost {
+        line.push_str(&format!("{:>width$}", cost, width = COST_WIDTH));
+    }
+    line
+}
+
+/// The number cells of a usage
+fn usage_numbers(usage: &TokenUsage) -> [String; 4] {
+    [
+        usage.turns.to_string(),
+        format_token_count(usage.prompt_tokens),
+        format_token_count(usage.completion()),
+        format_token_count(usage.total()),
+    ]
+}
+
+/// "$0.75", or "--" without a price
+fn format_cost(cost: Option<f64>) -> String {
+    cost.map_or("--".to_string(), |cost| format!("${:.2}", cost))
+}
+
+/// Render the `/usage` overlay as a centered dialog
+pub fn render_usage_view(frame: &mut Frame, app: &App) {
+    let Some(ref view) = app.usage_view else {
+        return;
+    };
+    let summary = app.usage_summary();
+    let show_cost = summary.total_cost.is_some();
+
+    let area = frame.area();
+    let dialog_width = 100u16.min(area.width.saturating_sub(4));
+    // Header, rows, blank, total, blank, hint and the borders
+    let dialog_height = (summary.rows.len() as u16 + 8)
+        .max(9)
+        .min(area.height.saturating_sub(2));
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            " Usage this session ",
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(2),
+    };
+    let numbers_width: usize =
+        NUMBER_WIDTHS.iter().sum::<usize>() + if show_cost { COST_WIDTH } else { 0 };
+    let title_width = (inner.width as usize).saturating_sub(numbers_width).max(8);
+    // Header, blank, total, blank and hint take 5 rows
+    let visible_rows = (inner.height as usize).saturating_sub(5);
+
+    let mut lines = vec![Line::from(Span::styled(
+        table_line(
+            "Thread",
+            [
+                "Turns".to_string(),
+                "Prompt".to_string(),
+                "Completion".to_string(),
+                "Total".to_string(),
+            ],
+            show_cost.then(|| "Cost".to_string()),
+            title_width,
+        ),
+        Style::default().fg(COLOR_DIM),
+    ))];
+
+    if summary.rows.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No tokens used yet this session",
+            Style::default().fg(COLOR_DIM),
+        )));
+    } else {
+        let scroll = view
+            .scroll
+            .min(summary.rows.len().saturating_sub(visible_rows));
+        lines.extend(
+            summary
+                .rows
+                .iter()
+                .skip(scroll)
+                .take(visible_rows)
+                .map(|row| {
+                    Line::from(table_line(
+                        &row.title,
+                        usage_numbers(&row.usage),
+                        show_cost.then(|| format_cost(row.cost)),
+                        title_width,
+                    ))
+                }),
+        );
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        table_line(
+            "Total",
+            usage_numbers(&summary.total),
+            show_cost.then(|| format_cost(summary.total_cost)),
+            title_width,
+        ),
+        Style::default().add_modifier(Modifier::BOLD),
+    )));
+    frame.render_widget(Paragraph::new(lines), inner);
+
+    let hint_area = Rect {
+        x: inner.x,
+        y: dialog_area.y + dialog_area.height.saturating_sub(2),
+        width: inner.width,
+        height: 1,
+    };
+    let hint = Line::from(vec![
+        Span::styled("[↑↓] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("scroll  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("close", Style::default().fg(COLOR_DIM)),
+    ]);
+    frame.render_widget(Paragraph::new(hint), hint_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_line_aligns_columns() {
+        let usage = TokenUsage {
+            prompt_tokens: 41_000,
+            completion_tokens: 2_200,
+            streamed_estimate: 0,
+            turns: 3,
+        };
+        let line = table_line(
+            "Fix the login flow",
+            usage_numbers(&usage),
+            Some(format_cost(Some(0.756))),
+            12,
+        );
+        assert_eq!(
+            line,
+            "Fix the ...       3      41k        2.2k    43.2k     $0.76"
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! "What's new" overlay rendering
+//!
+//! Shown once on the first launch after an update: the release notes fetched
+//! during the update check, rendered as markdown in a scrollable dialog.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::markdown::render_markdown;
+
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_DIM, COLOR_HEADER};
+
+/// Render the "What's new" overlay as a centered dialog
+pub fn render_whats_new(frame: &mut Frame, app: &App) {
+    let Some(ref whats_new) = app.whats_new else {
+        return;
+    };
+
+    let area = frame.area();
+    let dialog_width = 76u16.min(area.width.saturating_sub(4));
+    let dialog_height = 24u16.min(area.height.saturating_sub(2));
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(dialog_width)) / 2,
+        y: (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            format!(
+                " What's new in v{} ",
+                whats_new.version.trim_start_matches('v')
+            ),
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    frame.render_widget(block, dialog_area);
+
+    // Notes fill the dialog above a one-line hint
+    let notes_area = Rect {
+        x: dialog_area.x + 2,
+        y: dialog_area.y + 1,
+        width: dialog_area.width.saturating_sub(4),
+        height: dialog_area.height.saturating_sub(3),
+    };
+    let hint_area = Rect {
+        x: notes_area.x,
+        y: dialog_area.y + dialog_area.height.saturating_sub(2),
+        width: notes_area.width,
+        height: 1,
+    };
+
+    frame.render_widget(
+        Paragraph::new(render_markdown(&whats_new.notes))
+            .wrap(Wrap { trim: false })
+            .scroll((whats_new.scroll, 0)),
+        notes_area,
+    );
+
+    let hint = Line::from(vec![
+        Span::styled("[↑↓] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("scroll  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[o] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("full release notes  ", Style::default().fg(COLOR_DIM)),
+        Span::styled("[Esc] ", Style::default().fg(COLOR_ACCENT)),
+        Span::styled("close", Style::default().fg(COLOR_DIM)),
+    ]);
+    frame.render_widget(Paragraph::new(hint), hint_area);
+}
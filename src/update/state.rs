@@ -23,6 +23,12 @@ pub struct UpdateState {
     pub pending_update_path: Option<String>,
     /// Version of the available update, if any.
     pub available_version: Option<String>,
+    /// Release notes (markdown) fetched for `available_version`.
+    #[serde(default)]
+    pub release_notes: Option<String>,
+    /// Last version whose "What's new" overlay was shown.
+    #[serde(default)]
+    pub changelog_shown_version: Option<String>,
 }
 
 impl UpdateState {
@@ -41,6 +47,26 @@ impl UpdateState {
         self.pending_update_path = None;
         self.available_version = None;
     }
+
+    /// Release notes to show once when `running_version` starts.
+    ///
+    /// Only returns notes fetched for that exact version that haven't been
+    /// shown yet, i.e. on the first launch after the update installed.
+    pub fn unseen_release_notes(&self, running_version: &str) -> Option<&str> {
+        if self.available_version.as_deref() != Some(running_version)
+            || self.changelog_shown_version.as_deref() == Some(running_version)
+        {
+            return None;
+        }
+        self.release_notes
+            .as_deref()
+            .filter(|notes| !notes.trim().is_empty())
+    }
+
+    /// Record that the "What's new" overlay for `version` was shown.
+    pub fn mark_changelog_shown(&mut self, version: &str) {
+        self.changelog_shown_version = Some(version.to_string());
+    }
 }
 
 /// Manages update state storage and retrieval.
@@ -85,8 +111,13 @@ impl UpdateStateManager {
     /// Save update state to the state file.
     ///
     /// Creates the parent directory if it doesn't exist.
-    /// Returns `true` if successful, `false` otherwise.
+    /// Returns `true` if successful, `false` otherwise (always `false` while
+    /// state files are read-only).
     pub fn save(&self, state: &UpdateState) -> bool {
+        if crate::startup::integrity::is_read_only() {
+            return false;
+        }
+
         // Ensure the parent directory exists
         if let Some(parent) = self.state_path.parent() {
             if !parent.exists() && fs::create_dir_all(parent).is_err() {
@@ -166,6 +197,7 @@ mod tests {
             last_check: Some(1234567890),
             pending_update_path: Some("/tmp/spoq-update".to_string()),
             available_version: Some("1.2.3".to_string()),
+            ..Default::default()
         };
 
         state.clear_pending_update();
@@ -175,6 +207,26 @@ mod tests {
         assert!(state.available_version.is_none());
     }
 
+    #[test]
+    fn test_update_state_unseen_release_notes_shown_once() {
+        let mut state = UpdateState {
+            available_version: Some("1.2.3".to_string()),
+            release_notes: Some("## Fixes\n- Faster startup".to_string()),
+            ..Default::default()
+        };
+
+        // Not yet installed
+        assert_eq!(state.unseen_release_notes("1.2.2"), None);
+        // First launch of the new version
+        assert_eq!(
+            state.unseen_release_notes("1.2.3"),
+            Some("## Fixes\n- Faster startup")
+        );
+
+        state.mark_changelog_shown("1.2.3");
+        assert_eq!(state.unseen_release_notes("1.2.3"), None);
+    }
+
     #[test]
     fn test_update_state_manager_new() {
         // This test depends on having a home directory, which should be available
@@ -199,6 +251,7 @@ mod tests {
             last_check: Some(1234567890),
             pending_update_path: Some("/tmp/spoq-update".to_string()),
             available_version: Some("1.2.3".to_string()),
+            ..Default::default()
         };
 
         assert!(manager.save(&state));
@@ -262,6 +315,7 @@ mod tests {
             last_check: Some(1234567890),
             pending_update_path: Some("/tmp/spoq-update".to_string()),
             available_version: Some("1.2.3".to_string()),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&state).unwrap();
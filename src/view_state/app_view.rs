@@ -121,8 +121,8 @@ pub struct AppViewState<'a> {
     /// Available folders for @ mentions
     pub folders: &'a [Folder],
 
-    /// Currently selected folder
-    pub selected_folder: Option<&'a Folder>,
+    /// Folders attached to the next new thread
+    pub selected_folders: &'a [Folder],
 
     /// Folder picker visibility
     pub folder_picker_visible: bool,
@@ -87,6 +87,14 @@ pub struct ThreadView {
     /// - Error: "error"
     /// - Waiting: None (uses old layout with status column + actions)
     pub activity_text: Option<String>,
+    /// One-line outcome summary of the last long run (e.g., "✅ 14 tests fixed")
+    pub summary: Option<String>,
+    /// Grouping hint from thread links (e.g., "⛔ blocked by Fix auth")
+    pub link_hint: Option<String>,
+    /// Whether the thread is pinned to the top of the list
+    pub pinned: bool,
+    /// Whether the thread is muted (dimmed, never needs action)
+    pub muted: bool,
 }
 
 impl ThreadView {
@@ -104,6 +112,10 @@ impl ThreadView {
             needs_action: false,
             current_operation: None,
             activity_text: None,
+            summary: None,
+            link_hint: None,
+            pinned: false,
+            muted: false,
         }
     }
 
@@ -153,6 +165,30 @@ impl ThreadView {
         self
     }
 
+    /// Builder-style setter for summary
+    pub fn with_summary(mut self, summary: Option<String>) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    /// Builder-style setter for link_hint
+    pub fn with_link_hint(mut self, link_hint: Option<String>) -> Self {
+        self.link_hint = link_hint;
+        self
+    }
+
+    /// Builder-style setter for pinned
+    pub fn with_pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// Builder-style setter for muted
+    pub fn with_muted(mut self, muted: bool) -> Self {
+        self.muted = muted;
+        self
+    }
+
     /// Get the status line for display
     ///
     /// Priority:
@@ -571,6 +607,102 @@ impl Default for Theme {
     }
 }
 
+// ============================================================================
+// Thread List Scrolling
+// ============================================================================
+
+/// Scroll offsets (first visible row) for the two thread list sections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadListScroll {
+    /// Offset into the need-action section (threads waiting on the user)
+    pub need_action: usize,
+    /// Offset into the autonomous thread section
+    pub threads: usize,
+}
+
+/// Geometry of one scrollable thread list section from the last render
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadListPanel {
+    /// Screen area the section occupies
+    pub area: ratatui::layout::Rect,
+    /// Number of threads in the section
+    pub total: usize,
+    /// Number of thread rows that fit on screen
+    pub visible: usize,
+}
+
+impl ThreadListPanel {
+    /// Largest scroll offset that still fills the visible rows
+    pub fn max_offset(&self) -> usize {
+        self.total.saturating_sub(self.visible)
+    }
+
+    /// Whether a screen position falls inside the section
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        self.area
+            .contains(ratatui::layout::Position::new(column, row))
+    }
+}
+
+/// Thread list sections from the last CommandDeck render, for mouse hit-testing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadListLayout {
+    /// Need-action section (None when no thread needs action)
+    pub need_action: Option<ThreadListPanel>,
+    /// Autonomous thread section
+    pub threads: Option<ThreadListPanel>,
+}
+
+/// Needs-action sidebar entries from the last Conversation render, for
+/// Alt+number and mouse clicks
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SidebarLayout {
+    /// Screen area of each entry and the thread it opens, top to bottom
+    pub entries: Vec<(ratatui::layout::Rect, String)>,
+}
+
+impl SidebarLayout {
+    /// Thread of the entry under a screen position
+    pub fn thread_at(&self, column: u16, row: u16) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(area, _)| area.contains(ratatui::layout::Position::new(column, row)))
+            .map(|(_, id)| id.as_str())
+    }
+
+    /// Thread of the `number`th entry (1-based, as shown)
+    pub fn thread_for_number(&self, number: usize) -> Option<&str> {
+        number
+            .checked_sub(1)
+            .and_then(|index| self.entries.get(index))
+            .map(|(_, id)| id.as_str())
+    }
+}
+
+// ============================================================================
+// Startup Loading
+// ============================================================================
+
+/// CommandDeck data still loading at startup, shown as a hint per section
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SectionLoading {
+    /// Thread list
+    pub threads: bool,
+    /// GitHub repos of the empty state
+    pub repos: bool,
+    /// Folder picker
+    pub folders: bool,
+    /// @ picker cache
+    pub picker: bool,
+}
+
+impl SectionLoading {
+    /// Whether any section is still loading
+    pub fn any(&self) -> bool {
+        self.threads || self.repos || self.folders || self.picker
+    }
+}
+
 // ============================================================================
 // RenderContext
 // ============================================================================
@@ -597,6 +729,21 @@ pub struct RenderContext<'a> {
     pub question_timer_secs: Option<u32>,
     /// GitHub repos for empty state
     pub repos: &'a [crate::models::GitHubRepo],
+    /// Scroll offsets for the thread list sections
+    pub scroll: ThreadListScroll,
+    /// Number of unread notifications (shown in the header)
+    pub unread_notifications: usize,
+    /// Message previews shown on thread rows
+    pub previews: Option<&'a crate::markdown::PreviewCache>,
+    /// Messages per thread that arrived while it wasn't visible
+    pub unread: Option<&'a std::collections::HashMap<String, usize>>,
+    /// Token usage per thread this session
+    pub token_usage: Option<&'a std::collections::HashMap<String, crate::cache::TokenUsage>>,
+    /// Needs-action thread the y/a/n keys act on, highlighted when several
+    /// threads are waiting
+    pub selected_needs_action: Option<&'a str>,
+    /// Sections still waiting for their startup data
+    pub loading: SectionLoading,
 }
 
 impl<'a> RenderContext<'a> {
@@ -617,6 +764,13 @@ impl<'a> RenderContext<'a> {
             question_state: None,
             question_timer_secs: None,
             repos,
+            scroll: ThreadListScroll::default(),
+            unread_notifications: 0,
+            previews: None,
+            unread: None,
+            token_usage: None,
+            selected_needs_action: None,
+            loading: SectionLoading::default(),
         }
     }
 
@@ -638,6 +792,51 @@ impl<'a> RenderContext<'a> {
         self
     }
 
+    /// Set thread list scroll offsets
+    pub fn with_scroll(mut self, scroll: ThreadListScroll) -> Self {
+        self.scroll = scroll;
+        self
+    }
+
+    /// Set the unread notification count
+    pub fn with_unread_notifications(mut self, count: usize) -> Self {
+        self.unread_notifications = count;
+        self
+    }
+
+    /// Set the message previews shown on thread rows
+    pub fn with_previews(mut self, previews: &'a crate::markdown::PreviewCache) -> Self {
+        self.previews = Some(previews);
+        self
+    }
+
+    /// Set the unread message counts shown on thread rows
+    pub fn with_unread(mut self, unread: &'a std::collections::HashMap<String, usize>) -> Self {
+        self.unread = Some(unread);
+        self
+    }
+
+    /// Set the token usage shown on thread rows
+    pub fn with_token_usage(
+        mut self,
+        usage: &'a std::collections::HashMap<String, crate::cache::TokenUsage>,
+    ) -> Self {
+        self.token_usage = Some(usage);
+        self
+    }
+
+    /// Set the highlighted needs-action thread
+    pub fn with_selected_needs_action(mut self, thread_id: Option<&'a str>) -> Self {
+        self.selected_needs_action = thread_id;
+        self
+    }
+
+    /// Set the sections still loading
+    pub fn with_loading(mut self, loading: SectionLoading) -> Self {
+        self.loading = loading;
+        self
+    }
+
     /// Check if there's an active overlay
     pub fn has_overlay(&self) -> bool {
         self.overlay.is_some()
@@ -49,7 +49,8 @@ mod system_stats;
 pub use app_view::AppViewState;
 pub use dashboard_view::{
     ClaudeLoginState, DashboardViewState, FieldErrors, OverlayState, Progress, ProvisioningPhase,
-    RenderContext, Theme, ThreadView, VpsConfigMode, VpsConfigState, VpsError,
+    RenderContext, SectionLoading, SidebarLayout, Theme, ThreadListLayout, ThreadListPanel,
+    ThreadListScroll, ThreadView, VpsConfigMode, VpsConfigState, VpsError,
 };
 pub use scroll_state::ScrollState;
 pub use session_view::SessionViewState;
@@ -0,0 +1,404 @@
+//! Thread event delivery to external webhooks.
+//!
+//! Thread events (completed, needs action, failed) are POSTed to the URLs in
+//! `~/.spoq/webhooks.json`, either for every thread (`global`) or for
+//! specific thread IDs (`threads`). Payloads are Slack (`{"text": ...}`),
+//! Discord (`{"content": ...}`) or plain JSON compatible.
+//!
+//! ```json
+//! {
+//!   "global": [{ "url": "https://hooks.slack.com/services/...", "format": "slack" }],
+//!   "threads": {
+//!     "thread-id": [{ "url": "https://discord.com/api/webhooks/...", "format": "discord",
+//!                     "events": ["failed"] }]
+//!   }
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// File name of the webhook configuration inside `~/.spoq`
+const WEBHOOKS_FILE: &str = "webhooks.json";
+
+/// Delivery attempts per webhook before giving up
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry; doubles on each further retry
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Timeout for a single delivery attempt
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Thread events that can be routed to webhooks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// The thread finished its run
+    Completed,
+    /// The thread is waiting for the user (permission, question, plan)
+    NeedsAction,
+    /// The thread's run failed
+    Failed,
+}
+
+impl WebhookEvent {
+    /// Event name used in JSON payloads
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::Completed => "completed",
+            WebhookEvent::NeedsAction => "needs_action",
+            WebhookEvent::Failed => "failed",
+        }
+    }
+
+    fn emoji(&self) -> &'static str {
+        match self {
+            WebhookEvent::Completed => "✅",
+            WebhookEvent::NeedsAction => "⏳",
+            WebhookEvent::Failed => "❌",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            WebhookEvent::Completed => "completed",
+            WebhookEvent::NeedsAction => "needs your action",
+            WebhookEvent::Failed => "failed",
+        }
+    }
+}
+
+/// Payload template of a webhook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    /// Slack incoming webhook: `{"text": ...}`
+    Slack,
+    /// Discord webhook: `{"content": ...}`
+    Discord,
+    /// Structured JSON with event, thread and message fields
+    #[default]
+    Json,
+}
+
+/// A webhook URL and the events it receives
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookTarget {
+    /// URL to POST to
+    pub url: String,
+    /// Payload template
+    #[serde(default)]
+    pub format: WebhookFormat,
+    /// Events to deliver (all events when omitted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<WebhookEvent>>,
+}
+
+impl WebhookTarget {
+    /// Whether this target receives the given event
+    pub fn accepts(&self, event: WebhookEvent) -> bool {
+        match &self.events {
+            Some(events) => events.contains(&event),
+            None => true,
+        }
+    }
+}
+
+/// Webhook routing configuration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Webhooks that receive events from every thread
+    #[serde(default)]
+    pub global: Vec<WebhookTarget>,
+    /// Webhooks for specific threads, keyed by thread ID
+    #[serde(default)]
+    pub threads: HashMap<String, Vec<WebhookTarget>>,
+}
+
+impl WebhookConfig {
+    /// Path of the webhook configuration (`~/.spoq/webhooks.json`)
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".spoq").join(WEBHOOKS_FILE))
+    }
+
+    /// Load the webhook configuration; missing or invalid files yield no webhooks.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                tracing::warn!("Ignoring invalid {}: {}", path.display(), e);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Whether any webhook is configured
+    pub fn is_empty(&self) -> bool {
+        self.global.is_empty() && self.threads.values().all(|t| t.is_empty())
+    }
+
+    /// Webhooks that should receive `event` for `thread_id`.
+    ///
+    /// Thread-specific webhooks come first; a URL is only returned once.
+    pub fn targets_for(&self, thread_id: &str, event: WebhookEvent) -> Vec<&WebhookTarget> {
+        let mut targets: Vec<&WebhookTarget> = Vec::new();
+        let thread_targets = self.threads.get(thread_id).into_iter().flatten();
+        for target in thread_targets.chain(self.global.iter()) {
+            if target.accepts(event) && !targets.iter().any(|t| t.url == target.url) {
+                targets.push(target);
+            }
+        }
+        targets
+    }
+}
+
+/// Build the payload for a thread event in the target's format
+pub fn build_payload(
+    format: WebhookFormat,
+    event: WebhookEvent,
+    thread_id: &str,
+    thread_title: &str,
+    detail: Option<&str>,
+) -> serde_json::Value {
+    let mut text = format!("{} *{}* {}", event.emoji(), thread_title, event.label());
+    if let Some(detail) = detail {
+        text.push_str(": ");
+        text.push_str(detail);
+    }
+
+    match format {
+        WebhookFormat::Slack => serde_json::json!({ "text": text }),
+        WebhookFormat::Discord => serde_json::json!({ "content": text }),
+        WebhookFormat::Json => serde_json::json!({
+            "event": event.as_str(),
+            "thread_id": thread_id,
+            "thread_title": thread_title,
+            "detail": detail,
+            "text": text,
+        }),
+    }
+}
+
+/// Webhook delivery error
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The request could not be sent
+    Request(String),
+    /// The endpoint answered with a non-success status
+    Status(u16),
+}
+
+impl WebhookError {
+    /// Whether another attempt may succeed
+    fn is_retryable(&self) -> bool {
+        match self {
+            WebhookError::Request(_) => true,
+            WebhookError::Status(code) => *code == 429 || *code >= 500,
+        }
+    }
+}
+
+impl std::fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookError::Request(e) => write!(f, "request failed: {}", e),
+            WebhookError::Status(code) => write!(f, "HTTP {}", code),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {}
+
+/// POST a payload, retrying network errors, 429 and 5xx responses.
+///
+/// Makes up to [`MAX_DELIVERY_ATTEMPTS`] attempts with exponential backoff
+/// starting at `base_delay`.
+pub async fn deliver(
+    client: &reqwest::Client,
+    url: &str,
+    payload: &serde_json::Value,
+    base_delay: Duration,
+) -> Result<(), WebhookError> {
+    let mut delay = base_delay;
+    let mut attempt = 1;
+    loop {
+        let result = match client
+            .post(url)
+            .timeout(REQUEST_TIMEOUT)
+            .json(payload)
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) => Err(WebhookError::Status(response.status().as_u16())),
+            Err(e) => Err(WebhookError::Request(e.to_string())),
+        };
+
+        match result {
+            Err(e) if e.is_retryable() && attempt < MAX_DELIVERY_ATTEMPTS => {
+                tracing::debug!("Webhook attempt {} to {} failed: {}", attempt, url, e);
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                attempt += 1;
+            }
+            other => return other,
+        }
+    }
+}
+
+/// POST a payload with the default retry delay
+pub async fn deliver_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+    payload: &serde_json::Value,
+) -> Result<(), WebhookError> {
+    deliver(client, url, payload, RETRY_BASE_DELAY).await
+}
+
+/// Host part of a webhook URL, for error messages that should not leak tokens
+pub fn display_host(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    without_scheme.split('/').next().unwrap_or(without_scheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn target(url: &str, events: Option<Vec<WebhookEvent>>) -> WebhookTarget {
+        WebhookTarget {
+            url: url.to_string(),
+            format: WebhookFormat::Json,
+            events,
+        }
+    }
+
+    #[test]
+    fn test_targets_for_combines_thread_and_global() {
+        let mut config = WebhookConfig {
+            global: vec![target("https://global", None), target("https://shared", None)],
+            threads: HashMap::new(),
+        };
+        config.threads.insert(
+            "t-1".to_string(),
+            vec![
+                target("https://shared", None),
+                target("https://failures", Some(vec![WebhookEvent::Failed])),
+            ],
+        );
+
+        let urls = |event| {
+            config
+                .targets_for("t-1", event)
+                .iter()
+                .map(|t| t.url.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            urls(WebhookEvent::Completed),
+            vec!["https://shared", "https://global"]
+        );
+        assert_eq!(
+            urls(WebhookEvent::Failed),
+            vec!["https://shared", "https://failures", "https://global"]
+        );
+        assert_eq!(config.targets_for("other", WebhookEvent::Failed).len(), 2);
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let config: WebhookConfig = serde_json::from_str(
+            r#"{"global": [{"url": "https://x", "format": "slack", "events": ["needs_action"]}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.global[0].format, WebhookFormat::Slack);
+        assert!(config.global[0].accepts(WebhookEvent::NeedsAction));
+        assert!(!config.global[0].accepts(WebhookEvent::Completed));
+        assert!(!config.is_empty());
+        assert!(WebhookConfig::default().is_empty());
+    }
+
+    #[test]
+    fn test_build_payload_formats() {
+        let slack = build_payload(
+            WebhookFormat::Slack,
+            WebhookEvent::Failed,
+            "t-1",
+            "Deploy",
+            Some("boom"),
+        );
+        assert_eq!(slack["text"], "❌ *Deploy* failed: boom");
+
+        let discord = build_payload(
+            WebhookFormat::Discord,
+            WebhookEvent::Completed,
+            "t-1",
+            "Deploy",
+            None,
+        );
+        assert_eq!(discord["content"], "✅ *Deploy* completed");
+
+        let json = build_payload(
+            WebhookFormat::Json,
+            WebhookEvent::NeedsAction,
+            "t-1",
+            "Deploy",
+            None,
+        );
+        assert_eq!(json["event"], "needs_action");
+        assert_eq!(json["thread_id"], "t-1");
+    }
+
+    #[test]
+    fn test_display_host() {
+        assert_eq!(
+            display_host("https://hooks.slack.com/services/T/B/X"),
+            "hooks.slack.com"
+        );
+        assert_eq!(display_host("localhost:8080/hook"), "localhost:8080");
+    }
+
+    #[tokio::test]
+    async fn test_deliver_retries_server_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::new();
+        let result = deliver(&client, &server.uri(), &serde_json::json!({}), Duration::ZERO).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_deliver_gives_up_on_client_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::new();
+        let result = deliver(&client, &server.uri(), &serde_json::json!({}), Duration::ZERO).await;
+
+        assert!(matches!(result, Err(WebhookError::Status(404))));
+    }
+}
@@ -0,0 +1,326 @@
+//! Delivery acknowledgements for outgoing WebSocket messages.
+//!
+//! Writing to the socket is fire-and-forget: a frame sent just before the
+//! connection drops is lost, and an agent waiting on a permission answer
+//! would wait forever. Outgoing messages that matter are therefore stamped
+//! with a client-generated `message_id` and kept in an [`AckQueue`] until the
+//! server confirms them with `{"type": "ack", "message_id": ...}`.
+//! Unconfirmed messages are resent after a reconnect, or when their ack is
+//! overdue (with exponential backoff), until the attempt limit is reached.
+//!
+//! Permission responses and question answers are the priority class and are
+//! resent first. Presence updates are ephemeral and never tracked.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::messages::WsOutgoingMessage;
+
+/// How urgently an outgoing message needs to reach the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AckClass {
+    /// Permission responses, question answers, plan decisions and stream
+    /// cancels
+    Priority,
+    /// Everything else worth delivering
+    Normal,
+}
+
+impl AckClass {
+    /// Class of a message, or `None` if it is not tracked
+    pub fn of(message: &WsOutgoingMessage) -> Option<Self> {
+        match message {
+            WsOutgoingMessage::CommandResponse(_)
+            | WsOutgoingMessage::CancelPermission(_)
+            | WsOutgoingMessage::CancelStream(_)
+            | WsOutgoingMessage::PlanApprovalResponse(_) => Some(AckClass::Priority),
+            WsOutgoingMessage::Presence(_) => None,
+            _ => Some(AckClass::Normal),
+        }
+    }
+}
+
+/// Retransmission settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AckConfig {
+    /// How long to wait for an ack before the first resend
+    pub timeout: Duration,
+    /// Upper bound on the wait between resends
+    pub max_backoff: Duration,
+    /// Sends (including the first) before a message is given up on
+    pub max_attempts: u8,
+}
+
+impl Default for AckConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(5),
+            max_backoff: Duration::from_secs(60),
+            max_attempts: 5,
+        }
+    }
+}
+
+/// A message waiting for its ack
+#[derive(Debug, Clone)]
+struct PendingMessage {
+    class: AckClass,
+    /// Message `type`, for logs and error messages
+    kind: String,
+    /// Serialized message, including its `message_id`
+    payload: String,
+    queued_at: Instant,
+    last_sent: Instant,
+    attempts: u8,
+}
+
+/// A message to send again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resend {
+    pub message_id: String,
+    pub kind: String,
+    pub payload: String,
+    /// Which send this is (2 for the first resend)
+    pub attempt: u8,
+}
+
+/// A message given up on after `max_attempts` sends
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Undelivered {
+    pub message_id: String,
+    pub kind: String,
+}
+
+/// Result of checking the queue for work
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AckSweep {
+    /// Messages to send again, priority class first
+    pub resend: Vec<Resend>,
+    /// Messages dropped from the queue
+    pub undelivered: Vec<Undelivered>,
+}
+
+/// Outgoing messages sent but not yet acknowledged by the server
+#[derive(Debug, Clone, Default)]
+pub struct AckQueue {
+    config: AckConfig,
+    pending: HashMap<String, PendingMessage>,
+}
+
+impl AckQueue {
+    /// Create an empty queue
+    pub fn new(config: AckConfig) -> Self {
+        Self {
+            config,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Serialize a message for sending.
+    ///
+    /// Tracked messages get a fresh `message_id` and stay queued until
+    /// acknowledged; untracked ones are serialized as they are.
+    pub fn prepare(
+        &mut self,
+        message: &WsOutgoingMessage,
+        now: Instant,
+    ) -> Result<String, serde_json::Error> {
+        let Some(class) = AckClass::of(message) else {
+            return serde_json::to_string(message);
+        };
+
+        let mut value = serde_json::to_value(message)?;
+        let message_id = uuid::Uuid::new_v4().to_string();
+        let kind = value
+            .get("type")
+            .and_then(|t| t.as_str())
+            .unwrap_or("message")
+            .to_string();
+        if let Some(object) = value.as_object_mut() {
+            object.insert(
+                "message_id".to_string(),
+                serde_json::Value::String(message_id.clone()),
+            );
+        }
+        let payload = serde_json::to_string(&value)?;
+
+        self.pending.insert(
+            message_id,
+            PendingMessage {
+                class,
+                kind,
+                payload: payload.clone(),
+                queued_at: now,
+                last_sent: now,
+                attempts: 1,
+            },
+        );
+        Ok(payload)
+    }
+
+    /// Record the server's ack; returns false for unknown ids
+    pub fn acknowledge(&mut self, message_id: &str) -> bool {
+        self.pending.remove(message_id).is_some()
+    }
+
+    /// Messages whose ack is overdue
+    pub fn due(&mut self, now: Instant) -> AckSweep {
+        self.sweep(now, false)
+    }
+
+    /// Every pending message, to resend on a fresh connection
+    pub fn after_reconnect(&mut self, now: Instant) -> AckSweep {
+        self.sweep(now, true)
+    }
+
+    /// Number of messages waiting for an ack
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Whether every sent message has been acknowledged
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// When a message's ack counts as overdue: the timeout doubles with each
+    /// resend, capped at `max_backoff`
+    fn deadline(&self, message: &PendingMessage) -> Instant {
+        let exponent = u32::from(message.attempts.saturating_sub(1)).min(16);
+        let wait = self
+            .config
+            .timeout
+            .saturating_mul(1 << exponent)
+            .min(self.config.max_backoff);
+        message.last_sent + wait
+    }
+
+    fn sweep(&mut self, now: Instant, reconnected: bool) -> AckSweep {
+        let mut ready: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, message)| reconnected || now >= self.deadline(message))
+            .map(|(id, _)| id.clone())
+            .collect();
+        ready.sort_by_key(|id| {
+            let message = &self.pending[id];
+            (message.class, message.queued_at)
+        });
+
+        let mut sweep = AckSweep::default();
+        for message_id in ready {
+            let exhausted = self.pending[&message_id].attempts >= self.config.max_attempts;
+            if exhausted {
+                if let Some(message) = self.pending.remove(&message_id) {
+                    sweep.undelivered.push(Undelivered {
+                        message_id,
+                        kind: message.kind,
+                    });
+                }
+                continue;
+            }
+            if let Some(message) = self.pending.get_mut(&message_id) {
+                message.attempts += 1;
+                message.last_sent = now;
+                sweep.resend.push(Resend {
+                    message_id,
+                    kind: message.kind.clone(),
+                    payload: message.payload.clone(),
+                    attempt: message.attempts,
+                });
+            }
+        }
+        sweep
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::websocket::messages::{
+        WsCancelPermission, WsNotificationDismissed, WsPresence, WsPresenceState,
+    };
+
+    fn queue() -> AckQueue {
+        AckQueue::new(AckConfig {
+            timeout: Duration::from_secs(5),
+            max_backoff: Duration::from_secs(12),
+            max_attempts: 3,
+        })
+    }
+
+    fn message_id(payload: &str) -> String {
+        let value: serde_json::Value = serde_json::from_str(payload).unwrap();
+        value["message_id"].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_prepare_stamps_tracked_messages() {
+        let mut acks = queue();
+        let now = Instant::now();
+
+        let cancel = WsOutgoingMessage::CancelPermission(WsCancelPermission::new("p1".into()));
+        let payload = acks.prepare(&cancel, now).unwrap();
+        assert!(payload.contains("\"type\":\"cancel_permission\""));
+        let id = message_id(&payload);
+        assert_eq!(acks.len(), 1);
+
+        // Presence is never tracked
+        let presence = WsOutgoingMessage::Presence(WsPresence::new(
+            "t1".into(),
+            "c1".into(),
+            WsPresenceState::Typing,
+        ));
+        let payload = acks.prepare(&presence, now).unwrap();
+        assert!(!payload.contains("message_id"));
+        assert_eq!(acks.len(), 1);
+
+        assert!(acks.acknowledge(&id));
+        assert!(!acks.acknowledge(&id));
+        assert!(acks.is_empty());
+    }
+
+    #[test]
+    fn test_due_backs_off_and_gives_up() {
+        let mut acks = queue();
+        let start = Instant::now();
+        let cancel = WsOutgoingMessage::CancelPermission(WsCancelPermission::new("p1".into()));
+        acks.prepare(&cancel, start).unwrap();
+
+        assert!(acks.due(start + Duration::from_secs(4)).resend.is_empty());
+
+        let sweep = acks.due(start + Duration::from_secs(5));
+        assert_eq!(sweep.resend.len(), 1);
+        assert_eq!(sweep.resend[0].attempt, 2);
+        assert_eq!(sweep.resend[0].kind, "cancel_permission");
+
+        // Second wait doubles to 10s
+        assert!(acks.due(start + Duration::from_secs(14)).resend.is_empty());
+        let sweep = acks.due(start + Duration::from_secs(15));
+        assert_eq!(sweep.resend[0].attempt, 3);
+
+        // Attempt limit reached: the next overdue check drops it
+        let sweep = acks.due(start + Duration::from_secs(30));
+        assert!(sweep.resend.is_empty());
+        assert_eq!(sweep.undelivered.len(), 1);
+        assert!(acks.is_empty());
+    }
+
+    #[test]
+    fn test_after_reconnect_resends_priority_first() {
+        let mut acks = queue();
+        let now = Instant::now();
+        let dismissal =
+            WsOutgoingMessage::NotificationDismissed(WsNotificationDismissed::new(vec![
+                "n1".into()
+            ]));
+        acks.prepare(&dismissal, now).unwrap();
+        let cancel = WsOutgoingMessage::CancelPermission(WsCancelPermission::new("p1".into()));
+        acks.prepare(&cancel, now + Duration::from_millis(1))
+            .unwrap();
+
+        let sweep = acks.after_reconnect(now + Duration::from_secs(1));
+        let kinds: Vec<_> = sweep.resend.iter().map(|r| r.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["cancel_permission", "notification_dismissed"]);
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use futures_util::{SinkExt, StreamExt};
 use tokio::sync::{mpsc, watch};
@@ -9,8 +9,25 @@ use tokio_tungstenite::{
 };
 use tracing::{debug, error, info, warn};
 
+use crate::adapters::fault_injection;
+
+use super::ack::{AckConfig, AckQueue, AckSweep};
 use super::messages::{WsIncomingMessage, WsOutgoingMessage};
 
+/// How often unacknowledged outgoing messages are checked for resending
+const ACK_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Sink half of the WebSocket connection
+type WsSink = futures_util::stream::SplitSink<
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+    Message,
+>;
+
+/// Stream half of the WebSocket connection
+type WsSource = futures_util::stream::SplitStream<
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+>;
+
 /// WebSocket connection errors
 #[derive(Debug, Clone)]
 pub enum WsError {
@@ -41,6 +58,17 @@ pub enum WsConnectionState {
     Disconnected,
 }
 
+/// Requests from the app to the connection loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsControl {
+    /// Try to reconnect right away instead of waiting out the backoff.
+    /// Ignored while connected.
+    ReconnectNow,
+}
+
+/// How often a connection that gave up reconnecting checks for shutdown
+const GAVE_UP_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Configuration for WebSocket client
 #[derive(Debug, Clone)]
 pub struct WsClientConfig {
@@ -51,6 +79,10 @@ pub struct WsClientConfig {
     pub auth_token: Option<String>,
     /// Whether to use TLS (wss://) for the connection
     pub use_tls: bool,
+    /// Seconds to wait for the server's ack before resending a message
+    pub ack_timeout_secs: u64,
+    /// Sends of an unacknowledged message before giving up on it
+    pub ack_max_attempts: u8,
 }
 
 impl Default for WsClientConfig {
@@ -66,6 +98,8 @@ impl Default for WsClientConfig {
             max_backoff_secs: 30,
             auth_token,
             use_tls: false, // Default to non-TLS for local/IP connections
+            ack_timeout_secs: 5,
+            ack_max_attempts: 5,
         }
     }
 }
@@ -95,12 +129,23 @@ impl WsClientConfig {
         self.use_tls = use_tls;
         self
     }
+
+    /// Retransmission settings for unacknowledged outgoing messages
+    pub fn ack_config(&self) -> AckConfig {
+        AckConfig {
+            timeout: Duration::from_secs(self.ack_timeout_secs),
+            max_backoff: Duration::from_secs(self.max_backoff_secs),
+            max_attempts: self.ack_max_attempts,
+        }
+    }
 }
 
 /// WebSocket client for communicating with the Claude Code server
 pub struct WsClient {
     /// Channel to send outgoing messages to the server
     outgoing_tx: mpsc::Sender<WsOutgoingMessage>,
+    /// Channel to send control requests to the connection loop
+    control_tx: mpsc::Sender<WsControl>,
     /// Receiver for incoming messages from the server
     incoming_rx: mpsc::Receiver<WsIncomingMessage>,
     /// Watch receiver for connection state changes
@@ -151,6 +196,7 @@ impl WsClient {
         // Create channels
         let (incoming_tx, incoming_rx) = mpsc::channel::<WsIncomingMessage>(100);
         let (outgoing_tx, outgoing_rx) = mpsc::channel::<WsOutgoingMessage>(100);
+        let (control_tx, control_rx) = mpsc::channel::<WsControl>(4);
         let (state_tx, state_rx) = watch::channel(WsConnectionState::Connected);
 
         let shutdown = Arc::new(AtomicBool::new(false));
@@ -165,6 +211,7 @@ impl WsClient {
                 ws_stream,
                 incoming_tx,
                 outgoing_rx,
+                control_rx,
                 state_tx,
                 shutdown: shutdown_clone,
             })
@@ -173,6 +220,7 @@ impl WsClient {
 
         Ok(Self {
             outgoing_tx,
+            control_tx,
             incoming_rx,
             state_rx,
             shutdown,
@@ -184,6 +232,11 @@ impl WsClient {
         self.state_rx.clone()
     }
 
+    /// Sender for control requests such as "reconnect now"
+    pub fn control_sender(&self) -> mpsc::Sender<WsControl> {
+        self.control_tx.clone()
+    }
+
     /// Send a message to the server
     pub async fn send(&self, message: WsOutgoingMessage) -> Result<(), WsError> {
         self.outgoing_tx
@@ -214,12 +267,7 @@ impl Drop for WsClient {
 struct ConnectionLoopParams {
     url: String,
     config: WsClientConfig,
-    ws_sink: futures_util::stream::SplitSink<
-        tokio_tungstenite::WebSocketStream<
-            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-        >,
-        Message,
-    >,
+    ws_sink: WsSink,
     ws_stream: futures_util::stream::SplitStream<
         tokio_tungstenite::WebSocketStream<
             tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
@@ -227,6 +275,7 @@ struct ConnectionLoopParams {
     >,
     incoming_tx: mpsc::Sender<WsIncomingMessage>,
     outgoing_rx: mpsc::Receiver<WsOutgoingMessage>,
+    control_rx: mpsc::Receiver<WsControl>,
     state_tx: watch::Sender<WsConnectionState>,
     shutdown: Arc<AtomicBool>,
 }
@@ -240,9 +289,12 @@ async fn run_connection_loop(params: ConnectionLoopParams) {
         mut ws_stream,
         incoming_tx,
         mut outgoing_rx,
+        mut control_rx,
         state_tx,
         shutdown,
     } = params;
+    let mut acks = AckQueue::new(config.ack_config());
+    let mut ack_timer = tokio::time::interval(ACK_CHECK_INTERVAL);
     loop {
         if shutdown.load(Ordering::SeqCst) {
             debug!("Shutdown signal received, closing connection");
@@ -255,6 +307,11 @@ async fn run_connection_loop(params: ConnectionLoopParams) {
             msg = ws_stream.next() => {
                 match msg {
                     Some(Ok(Message::Text(text))) => {
+                        if fault_injection::active().is_some_and(|f| f.drop_ws_frame()) {
+                            debug!("Injected fault: dropping incoming frame");
+                            continue;
+                        }
+
                         // Send raw message for debugging (truncated to 200 chars)
                         let raw_preview = if text.len() > 200 {
                             format!("{}...", &text[..200])
@@ -264,6 +321,12 @@ async fn run_connection_loop(params: ConnectionLoopParams) {
                         let _ = incoming_tx.send(WsIncomingMessage::RawMessage(raw_preview)).await;
 
                         match serde_json::from_str::<WsIncomingMessage>(&text) {
+                            Ok(WsIncomingMessage::Ack(ack)) => {
+                                debug!("Received ack for {}", ack.message_id);
+                                if acks.acknowledge(&ack.message_id) {
+                                    let _ = incoming_tx.send(WsIncomingMessage::PendingAcks(acks.len())).await;
+                                }
+                            }
                             Ok(parsed) => {
                                 debug!("Received message: {:?}", parsed);
                                 if incoming_tx.send(parsed).await.is_err() {
@@ -281,19 +344,30 @@ async fn run_connection_loop(params: ConnectionLoopParams) {
                             }
                         }
                     }
-                    Some(Ok(Message::Close(_))) => {
+                    Some(Ok(Message::Close(frame))) => {
                         info!("Received close frame from server");
+                        let reason = match frame {
+                            Some(frame) if !frame.reason.is_empty() => {
+                                format!("Closed by server ({}: {})", frame.code, frame.reason)
+                            }
+                            Some(frame) => format!("Closed by server ({})", frame.code),
+                            None => "Closed by server".to_string(),
+                        };
+                        let _ = incoming_tx.send(WsIncomingMessage::Disconnected { reason }).await;
                         let _ = state_tx.send(WsConnectionState::Disconnected);
                         // Attempt reconnection
                         if let Some((new_sink, new_stream)) = attempt_reconnect(
                             &url,
                             &config,
                             &state_tx,
+                            &mut control_rx,
                             &shutdown,
                         ).await {
                             ws_sink = new_sink;
                             ws_stream = new_stream;
                             let _ = state_tx.send(WsConnectionState::Connected);
+                            let sweep = acks.after_reconnect(Instant::now());
+                            send_ack_sweep(&mut ws_sink, sweep, acks.len(), &incoming_tx).await;
                         } else {
                             break;
                         }
@@ -307,34 +381,44 @@ async fn run_connection_loop(params: ConnectionLoopParams) {
                     }
                     Some(Err(e)) => {
                         error!("WebSocket error: {}", e);
+                        let reason = e.to_string();
+                        let _ = incoming_tx.send(WsIncomingMessage::Disconnected { reason }).await;
                         let _ = state_tx.send(WsConnectionState::Disconnected);
                         // Attempt reconnection
                         if let Some((new_sink, new_stream)) = attempt_reconnect(
                             &url,
                             &config,
                             &state_tx,
+                            &mut control_rx,
                             &shutdown,
                         ).await {
                             ws_sink = new_sink;
                             ws_stream = new_stream;
                             let _ = state_tx.send(WsConnectionState::Connected);
+                            let sweep = acks.after_reconnect(Instant::now());
+                            send_ack_sweep(&mut ws_sink, sweep, acks.len(), &incoming_tx).await;
                         } else {
                             break;
                         }
                     }
                     None => {
                         info!("WebSocket stream ended");
+                        let reason = "Connection dropped".to_string();
+                        let _ = incoming_tx.send(WsIncomingMessage::Disconnected { reason }).await;
                         let _ = state_tx.send(WsConnectionState::Disconnected);
                         // Attempt reconnection
                         if let Some((new_sink, new_stream)) = attempt_reconnect(
                             &url,
                             &config,
                             &state_tx,
+                            &mut control_rx,
                             &shutdown,
                         ).await {
                             ws_sink = new_sink;
                             ws_stream = new_stream;
                             let _ = state_tx.send(WsConnectionState::Connected);
+                            let sweep = acks.after_reconnect(Instant::now());
+                            send_ack_sweep(&mut ws_sink, sweep, acks.len(), &incoming_tx).await;
                         } else {
                             break;
                         }
@@ -345,8 +429,16 @@ async fn run_connection_loop(params: ConnectionLoopParams) {
             outgoing = outgoing_rx.recv() => {
                 match outgoing {
                     Some(msg) => {
-                        match serde_json::to_string(&msg) {
+                        let pending_before = acks.len();
+                        match acks.prepare(&msg, Instant::now()) {
                             Ok(json) => {
+                                if acks.len() != pending_before {
+                                    let _ = incoming_tx.send(WsIncomingMessage::PendingAcks(acks.len())).await;
+                                }
+                                if fault_injection::active().is_some_and(|f| f.drop_ws_frame()) {
+                                    debug!("Injected fault: dropping outgoing message: {}", json);
+                                    continue;
+                                }
                                 debug!("Sending outgoing message: {}", json);
                                 if let Err(e) = ws_sink.send(Message::Text(json)).await {
                                     error!("Failed to send outgoing message: {}", e);
@@ -364,6 +456,17 @@ async fn run_connection_loop(params: ConnectionLoopParams) {
                     }
                 }
             }
+            // Nothing to do for "reconnect now" while connected
+            Some(control) = control_rx.recv() => {
+                debug!("Ignoring {:?}: already connected", control);
+            }
+            // Resend messages whose ack is overdue
+            _ = ack_timer.tick() => {
+                if !acks.is_empty() {
+                    let sweep = acks.due(Instant::now());
+                    send_ack_sweep(&mut ws_sink, sweep, acks.len(), &incoming_tx).await;
+                }
+            }
         }
     }
 
@@ -371,25 +474,117 @@ async fn run_connection_loop(params: ConnectionLoopParams) {
     let _ = state_tx.send(WsConnectionState::Disconnected);
 }
 
-/// Attempt to reconnect with exponential backoff
+/// Resend unacknowledged messages and report the outcome to the app
+async fn send_ack_sweep(
+    ws_sink: &mut WsSink,
+    sweep: AckSweep,
+    pending: usize,
+    incoming_tx: &mpsc::Sender<WsIncomingMessage>,
+) {
+    if sweep.resend.is_empty() && sweep.undelivered.is_empty() {
+        return;
+    }
+
+    for resend in sweep.resend {
+        info!(
+            "Resending unacknowledged {} {} (attempt {})",
+            resend.kind, resend.message_id, resend.attempt
+        );
+        if let Err(e) = ws_sink.send(Message::Text(resend.payload)).await {
+            warn!("Failed to resend {}: {}", resend.message_id, e);
+        }
+        let _ = incoming_tx
+            .send(WsIncomingMessage::Retransmitted {
+                message_id: resend.message_id,
+                kind: resend.kind,
+                attempt: resend.attempt,
+            })
+            .await;
+    }
+    for undelivered in sweep.undelivered {
+        error!(
+            "Giving up on {} {}: no ack from server",
+            undelivered.kind, undelivered.message_id
+        );
+        let _ = incoming_tx
+            .send(WsIncomingMessage::Undelivered {
+                message_id: undelivered.message_id,
+                kind: undelivered.kind,
+            })
+            .await;
+    }
+    let _ = incoming_tx
+        .send(WsIncomingMessage::PendingAcks(pending))
+        .await;
+}
+
+/// Attempt to reconnect with exponential backoff.
+///
+/// A `ReconnectNow` request cuts the current backoff short. After
+/// `max_retries` failed attempts the connection stays down until the next
+/// `ReconnectNow`, which starts a fresh round of attempts. Returns None on
+/// shutdown, or once nobody can ask to reconnect anymore.
 async fn attempt_reconnect(
     url: &str,
     config: &WsClientConfig,
     state_tx: &watch::Sender<WsConnectionState>,
+    control_rx: &mut mpsc::Receiver<WsControl>,
     shutdown: &Arc<AtomicBool>,
-) -> Option<(
-    futures_util::stream::SplitSink<
-        tokio_tungstenite::WebSocketStream<
-            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-        >,
-        Message,
-    >,
-    futures_util::stream::SplitStream<
-        tokio_tungstenite::WebSocketStream<
-            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-        >,
-    >,
-)> {
+) -> Option<(WsSink, WsSource)> {
+    loop {
+        if let Some(connection) = reconnect_round(url, config, state_tx, control_rx, shutdown).await
+        {
+            return Some(connection);
+        }
+        if shutdown.load(Ordering::SeqCst) {
+            return None;
+        }
+
+        error!(
+            "Failed to reconnect after {} attempts, waiting for a reconnect request",
+            config.max_retries
+        );
+        let _ = state_tx.send(WsConnectionState::Disconnected);
+        if !wait_for_reconnect_request(control_rx, shutdown).await {
+            return None;
+        }
+    }
+}
+
+/// Wait until the app asks to reconnect. Returns false on shutdown or when
+/// the control channel closes.
+async fn wait_for_reconnect_request(
+    control_rx: &mut mpsc::Receiver<WsControl>,
+    shutdown: &Arc<AtomicBool>,
+) -> bool {
+    loop {
+        if shutdown.load(Ordering::SeqCst) {
+            debug!("Shutdown requested while disconnected");
+            return false;
+        }
+        tokio::select! {
+            control = control_rx.recv() => {
+                match control {
+                    Some(WsControl::ReconnectNow) => {
+                        info!("Reconnect requested, retrying");
+                        return true;
+                    }
+                    None => return false,
+                }
+            }
+            _ = tokio::time::sleep(GAVE_UP_POLL_INTERVAL) => {}
+        }
+    }
+}
+
+/// One round of up to `max_retries` reconnection attempts
+async fn reconnect_round(
+    url: &str,
+    config: &WsClientConfig,
+    state_tx: &watch::Sender<WsConnectionState>,
+    control_rx: &mut mpsc::Receiver<WsControl>,
+    shutdown: &Arc<AtomicBool>,
+) -> Option<(WsSink, WsSource)> {
     for attempt in 1..=config.max_retries {
         if shutdown.load(Ordering::SeqCst) {
             debug!("Shutdown requested during reconnection");
@@ -405,7 +600,12 @@ async fn attempt_reconnect(
             attempt, config.max_retries, backoff_secs
         );
 
-        tokio::time::sleep(Duration::from_secs(backoff_secs)).await;
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_secs(backoff_secs)) => {}
+            Some(WsControl::ReconnectNow) = control_rx.recv() => {
+                info!("Reconnect requested, skipping the rest of the backoff");
+            }
+        }
 
         if shutdown.load(Ordering::SeqCst) {
             debug!("Shutdown requested during backoff");
@@ -433,10 +633,6 @@ async fn attempt_reconnect(
         }
     }
 
-    error!(
-        "Failed to reconnect after {} attempts, giving up",
-        config.max_retries
-    );
     None
 }
 
@@ -531,6 +727,8 @@ mod tests {
             max_backoff_secs: 1,
             auth_token: None,
             use_tls: false,
+            ack_timeout_secs: 5,
+            ack_max_attempts: 5,
         };
 
         let result = WsClient::connect(config).await;
@@ -543,6 +741,25 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_wait_for_reconnect_request() {
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let (control_tx, mut control_rx) = mpsc::channel(4);
+
+        control_tx.send(WsControl::ReconnectNow).await.unwrap();
+        assert!(wait_for_reconnect_request(&mut control_rx, &shutdown).await);
+
+        drop(control_tx);
+        assert!(!wait_for_reconnect_request(&mut control_rx, &shutdown).await);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_reconnect_request_stops_on_shutdown() {
+        let shutdown = Arc::new(AtomicBool::new(true));
+        let (_control_tx, mut control_rx) = mpsc::channel(4);
+        assert!(!wait_for_reconnect_request(&mut control_rx, &shutdown).await);
+    }
+
     #[test]
     fn test_ws_error_clone() {
         let err = WsError::ConnectionFailed("test".to_string());
@@ -580,6 +797,8 @@ mod tests {
             max_backoff_secs: 60,
             auth_token: None,
             use_tls: false,
+            ack_timeout_secs: 5,
+            ack_max_attempts: 5,
         };
 
         assert_eq!(config.host, "example.com:8080");
@@ -596,6 +815,8 @@ mod tests {
             max_backoff_secs: 15,
             auth_token: Some("test-token".to_string()),
             use_tls: false,
+            ack_timeout_secs: 5,
+            ack_max_attempts: 5,
         };
 
         let cloned = config.clone();
@@ -684,6 +905,8 @@ mod tests {
             max_backoff_secs: 30,
             auth_token: None,
             use_tls: false,
+            ack_timeout_secs: 5,
+            ack_max_attempts: 5,
         };
         let debug_str = format!("{:?}", config);
         assert!(debug_str.contains("test.example.com:8000"));
@@ -711,6 +934,8 @@ mod tests {
             max_backoff_secs: 10,
             auth_token: Some("secret-token".to_string()),
             use_tls: false,
+            ack_timeout_secs: 5,
+            ack_max_attempts: 5,
         };
         assert_eq!(config.host, "custom.example.com:9000");
         assert_eq!(config.auth_token, Some("secret-token".to_string()));
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::models::{PlanSummary, Thread, ThreadMode, ThreadStatus, WaitingFor};
+use crate::state::Task;
 
 /// Incoming WebSocket messages from the client
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -8,6 +9,12 @@ use crate::models::{PlanSummary, Thread, ThreadMode, ThreadStatus, WaitingFor};
 pub enum WsIncomingMessage {
     #[serde(rename = "permission_request")]
     PermissionRequest(WsPermissionRequest),
+    /// Permission answered or cancelled outside this client (e.g. the web UI)
+    #[serde(rename = "permission_resolved")]
+    PermissionResolved(WsPermissionResolved),
+    /// Another client viewing, typing in or posting to a thread
+    #[serde(rename = "presence_update")]
+    PresenceUpdate(WsPresenceUpdate),
     /// Agent status update (thinking, idle, streaming, tool_use)
     #[serde(rename = "agent_status")]
     AgentStatus(WsAgentStatus),
@@ -35,6 +42,15 @@ pub enum WsIncomingMessage {
     /// Thread metadata updated (title, description)
     #[serde(rename = "thread_updated")]
     ThreadUpdated(WsThreadUpdated),
+    /// Thread deleted (possibly by another client)
+    #[serde(rename = "thread_deleted")]
+    ThreadDeleted(WsThreadDeleted),
+    /// Edits made in a thread were reverted (web UI, git checkout)
+    #[serde(rename = "edit_reverted")]
+    EditReverted(WsEditReverted),
+    /// A task's status or progress changed
+    #[serde(rename = "task_updated")]
+    TaskUpdated(WsTaskUpdated),
     /// System metrics update (CPU, RAM usage)
     #[serde(rename = "system_metrics_update")]
     SystemMetricsUpdate(WsSystemMetricsUpdate),
@@ -74,14 +90,39 @@ pub enum WsIncomingMessage {
     /// Claude account status update (real-time)
     #[serde(rename = "claude_account_status_update")]
     ClaudeAccountStatusUpdate(WsClaudeAccountStatusUpdate),
+    /// Server received an outgoing message stamped with `message_id`
+    #[serde(rename = "ack")]
+    Ack(WsAck),
     /// Raw message received (for debugging - not deserialized from JSON)
     #[serde(skip)]
     RawMessage(String),
+    /// Number of outgoing messages waiting for an ack changed (not deserialized from JSON)
+    #[serde(skip)]
+    PendingAcks(usize),
+    /// Unacknowledged outgoing message sent again (not deserialized from JSON)
+    #[serde(skip)]
+    Retransmitted {
+        message_id: String,
+        kind: String,
+        attempt: u8,
+    },
+    /// Outgoing message given up on after its last resend (not deserialized from JSON)
+    #[serde(skip)]
+    Undelivered { message_id: String, kind: String },
+    /// Connection lost, with why (not deserialized from JSON)
+    #[serde(skip)]
+    Disconnected { reason: String },
     /// Parse error occurred (for debugging - not deserialized from JSON)
     #[serde(skip)]
     ParseError { error: String, raw: String },
 }
 
+/// Acknowledgement of an outgoing message
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WsAck {
+    pub message_id: String,
+}
+
 /// Connection confirmation from WebSocket server
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WsConnected {
@@ -124,6 +165,47 @@ pub struct WsPermissionRequest {
     pub timestamp: u64,
 }
 
+/// Permission resolved by any client (or timed out on the server)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WsPermissionResolved {
+    pub request_id: String,
+    #[serde(default)]
+    pub thread_id: Option<String>,
+    /// Whether the tool was allowed (absent when cancelled or timed out)
+    #[serde(default)]
+    pub allowed: Option<bool>,
+    #[serde(default)]
+    pub timestamp: u64,
+}
+
+/// What a client is doing on a thread
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WsPresenceState {
+    /// Thread is open in the client
+    Viewing,
+    /// User is composing a message (refreshed every few seconds)
+    Typing,
+    /// User just sent a message
+    MessageSent,
+    /// Client closed the thread
+    Left,
+}
+
+/// Presence of another client (web UI or another TUI) on a thread
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WsPresenceUpdate {
+    pub thread_id: String,
+    /// Stable ID of the sending client (used to ignore our own echoes)
+    pub client_id: String,
+    /// Kind of client ("web", "tui", ...)
+    #[serde(default)]
+    pub client_kind: String,
+    pub state: WsPresenceState,
+    #[serde(default)]
+    pub timestamp: u64,
+}
+
 /// Thread status update for dashboard view
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WsThreadStatusUpdate {
@@ -255,6 +337,48 @@ pub struct WsThreadUpdated {
     pub timestamp: u64,
 }
 
+/// Thread deletion notification
+///
+/// Sent when a thread has been deleted, so other clients can drop it
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WsThreadDeleted {
+    /// Thread ID that was deleted
+    pub thread_id: String,
+    /// When the deletion occurred (Unix milliseconds)
+    pub timestamp: u64,
+}
+
+/// Task update notification
+///
+/// Sent when a task starts, pauses, progresses or completes
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WsTaskUpdated {
+    /// The task's new state
+    pub task: Task,
+}
+
+/// Edit revert notification
+///
+/// Sent when changes the agent made in a thread are reverted
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WsEditReverted {
+    /// Thread whose edits were reverted
+    pub thread_id: String,
+    /// Number of edits reverted
+    #[serde(default = "default_reverted_edits")]
+    pub count: u32,
+    /// Files the reverted edits touched
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// When the revert occurred (Unix milliseconds)
+    #[serde(default)]
+    pub timestamp: u64,
+}
+
+fn default_reverted_edits() -> u32 {
+    1
+}
+
 /// System metrics update from backend
 ///
 /// Sent periodically by the backend with current system resource usage
@@ -313,6 +437,23 @@ impl WsCancelPermission {
     }
 }
 
+/// Cancel the running stream of a thread (user pressed Ctrl+C)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WsCancelStream {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub thread_id: String,
+}
+
+impl WsCancelStream {
+    pub fn new(thread_id: String) -> Self {
+        Self {
+            type_: "cancel_stream".to_string(),
+            thread_id,
+        }
+    }
+}
+
 /// Plan approval response (user approved or rejected plan)
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WsPlanApprovalResponse {
@@ -668,12 +809,57 @@ impl WsSteering {
     }
 }
 
+/// Notifications dismissed in the TUI (sent to server)
+///
+/// Keeps the backend's notification feed in sync with the panel.
+#[derive(Debug, Clone, Serialize)]
+pub struct WsNotificationDismissed {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub notification_ids: Vec<String>,
+}
+
+impl WsNotificationDismissed {
+    pub fn new(notification_ids: Vec<String>) -> Self {
+        Self {
+            type_: "notification_dismissed".to_string(),
+            notification_ids,
+        }
+    }
+}
+
+/// This client's presence on a thread (sent to server)
+///
+/// Relayed to other clients as `presence_update`.
+#[derive(Debug, Clone, Serialize)]
+pub struct WsPresence {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub thread_id: String,
+    pub client_id: String,
+    pub client_kind: String,
+    pub state: WsPresenceState,
+}
+
+impl WsPresence {
+    pub fn new(thread_id: String, client_id: String, state: WsPresenceState) -> Self {
+        Self {
+            type_: "presence".to_string(),
+            thread_id,
+            client_id,
+            client_kind: "tui".to_string(),
+            state,
+        }
+    }
+}
+
 /// Outgoing WebSocket messages (sent to server)
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum WsOutgoingMessage {
     CommandResponse(WsCommandResponse),
     CancelPermission(WsCancelPermission),
+    CancelStream(WsCancelStream),
     PlanApprovalResponse(WsPlanApprovalResponse),
     ClaudeLoginResponse(WsClaudeLoginResponse),
     ClaudeAuthTokenResponse(WsClaudeAuthTokenResponse),
@@ -682,12 +868,23 @@ pub enum WsOutgoingMessage {
     ClaudeAccountAddRequest(WsClaudeAccountAddRequest),
     ClaudeAccountRemoveRequest(WsClaudeAccountRemoveRequest),
     ClaudeAccountSelectRequest(WsClaudeAccountSelectRequest),
+    NotificationDismissed(WsNotificationDismissed),
+    Presence(WsPresence),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_deserialize_ack() {
+        let json = r#"{"type": "ack", "message_id": "msg-1"}"#;
+        match serde_json::from_str::<WsIncomingMessage>(json).unwrap() {
+            WsIncomingMessage::Ack(ack) => assert_eq!(ack.message_id, "msg-1"),
+            _ => panic!("Unexpected message type"),
+        }
+    }
+
     #[test]
     fn test_deserialize_permission_request() {
         let json = r#"{
@@ -754,6 +951,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_deserialize_permission_resolved() {
+        let json = r#"{
+            "type": "permission_resolved",
+            "request_id": "perm-uuid",
+            "thread_id": "thread-123",
+            "allowed": true,
+            "timestamp": 1234567890
+        }"#;
+
+        let msg: WsIncomingMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            WsIncomingMessage::PermissionResolved(resolved) => {
+                assert_eq!(resolved.request_id, "perm-uuid");
+                assert_eq!(resolved.thread_id, Some("thread-123".to_string()));
+                assert_eq!(resolved.allowed, Some(true));
+            }
+            _ => panic!("Unexpected message type"),
+        }
+
+        let minimal = r#"{"type": "permission_resolved", "request_id": "perm-2"}"#;
+        match serde_json::from_str::<WsIncomingMessage>(minimal).unwrap() {
+            WsIncomingMessage::PermissionResolved(resolved) => {
+                assert_eq!(resolved.allowed, None);
+                assert_eq!(resolved.thread_id, None);
+            }
+            _ => panic!("Unexpected message type"),
+        }
+    }
+
     #[test]
     fn test_deserialize_permission_request_with_null_thread_id() {
         // Test that null thread_id deserializes to None
@@ -1378,6 +1605,17 @@ mod tests {
         assert_eq!(parsed["approved"], true);
     }
 
+    #[test]
+    fn test_ws_outgoing_message_cancel_stream() {
+        let outgoing = WsOutgoingMessage::CancelStream(WsCancelStream::new("thread-1".to_string()));
+
+        let json = serde_json::to_string(&outgoing).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed["type"], "cancel_stream");
+        assert_eq!(parsed["thread_id"], "thread-1");
+    }
+
     #[test]
     fn test_ws_outgoing_message_plan_approval() {
         let response = WsPlanApprovalResponse::new("req-outgoing".to_string(), false);
@@ -1391,6 +1629,62 @@ mod tests {
         assert_eq!(parsed["approved"], false);
     }
 
+    #[test]
+    fn test_ws_outgoing_message_notification_dismissed() {
+        let outgoing =
+            WsOutgoingMessage::NotificationDismissed(WsNotificationDismissed::new(vec![
+                "n1".to_string(),
+                "n2".to_string(),
+            ]));
+
+        let json = serde_json::to_string(&outgoing).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed["type"], "notification_dismissed");
+        assert_eq!(parsed["notification_ids"], serde_json::json!(["n1", "n2"]));
+    }
+
+    #[test]
+    fn test_ws_outgoing_message_presence() {
+        let outgoing = WsOutgoingMessage::Presence(WsPresence::new(
+            "thread-1".to_string(),
+            "client-1".to_string(),
+            WsPresenceState::Typing,
+        ));
+
+        let json = serde_json::to_string(&outgoing).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed["type"], "presence");
+        assert_eq!(parsed["thread_id"], "thread-1");
+        assert_eq!(parsed["client_id"], "client-1");
+        assert_eq!(parsed["client_kind"], "tui");
+        assert_eq!(parsed["state"], "typing");
+    }
+
+    #[test]
+    fn test_deserialize_presence_update() {
+        let json = r#"{
+            "type": "presence_update",
+            "thread_id": "thread-1",
+            "client_id": "web-abc",
+            "client_kind": "web",
+            "state": "message_sent",
+            "timestamp": 1705315800000
+        }"#;
+
+        let msg: WsIncomingMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            WsIncomingMessage::PresenceUpdate(update) => {
+                assert_eq!(update.thread_id, "thread-1");
+                assert_eq!(update.client_id, "web-abc");
+                assert_eq!(update.client_kind, "web");
+                assert_eq!(update.state, WsPresenceState::MessageSent);
+            }
+            _ => panic!("Expected PresenceUpdate"),
+        }
+    }
+
     // -------------------- Thread Mode Update Tests --------------------
 
     #[test]
@@ -1877,6 +2171,11 @@ mod tests {
             status: Some(crate::models::ThreadStatus::Running),
             verified: Some(false),
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         let created = WsThreadCreated {
@@ -1915,6 +2214,11 @@ mod tests {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
 
         let original = WsIncomingMessage::ThreadCreated(WsThreadCreated {
@@ -2040,6 +2344,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_deserialize_thread_deleted() {
+        let json = r#"{
+            "type": "thread_deleted",
+            "thread_id": "thread-gone",
+            "timestamp": 1705315800000
+        }"#;
+
+        let msg: WsIncomingMessage = serde_json::from_str(json).unwrap();
+        match msg {
+            WsIncomingMessage::ThreadDeleted(deleted) => {
+                assert_eq!(deleted.thread_id, "thread-gone");
+                assert_eq!(deleted.timestamp, 1705315800000);
+            }
+            _ => panic!("Expected ThreadDeleted"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_edit_reverted() {
+        let json = r#"{
+            "type": "edit_reverted",
+            "thread_id": "thread-1",
+            "count": 3,
+            "paths": ["src/lib.rs"],
+            "timestamp": 1705315800000
+        }"#;
+        match serde_json::from_str::<WsIncomingMessage>(json).unwrap() {
+            WsIncomingMessage::EditReverted(reverted) => {
+                assert_eq!(reverted.thread_id, "thread-1");
+                assert_eq!(reverted.count, 3);
+                assert_eq!(reverted.paths, vec!["src/lib.rs".to_string()]);
+            }
+            _ => panic!("Expected EditReverted"),
+        }
+
+        let minimal = r#"{"type": "edit_reverted", "thread_id": "thread-2"}"#;
+        match serde_json::from_str::<WsIncomingMessage>(minimal).unwrap() {
+            WsIncomingMessage::EditReverted(reverted) => assert_eq!(reverted.count, 1),
+            _ => panic!("Expected EditReverted"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_task_updated() {
+        let json = r#"{
+            "type": "task_updated",
+            "task": {"id": "task-1", "name": "Build", "status": "Paused", "progress": 0.5}
+        }"#;
+        match serde_json::from_str::<WsIncomingMessage>(json).unwrap() {
+            WsIncomingMessage::TaskUpdated(update) => {
+                assert_eq!(update.task.id, "task-1");
+                assert_eq!(update.task.status, crate::state::TaskStatus::Paused);
+            }
+            _ => panic!("Expected TaskUpdated"),
+        }
+    }
+
     #[test]
     fn test_serialize_thread_updated() {
         let update = WsThreadUpdated {
@@ -4,15 +4,18 @@
 //! and integration with the permission system. It handles incoming permission
 //! requests from the server and sends responses back via WebSocket.
 
+pub mod ack;
 pub mod client;
 pub mod messages;
 
-pub use client::{WsClient, WsClientConfig, WsConnectionState, WsError};
+pub use ack::{AckClass, AckConfig, AckQueue};
+pub use client::{WsClient, WsClientConfig, WsConnectionState, WsControl, WsError};
 pub use messages::{
-    ClaudeLoginStatus, WsCancelPermission, WsClaudeAuthTokenRequest, WsClaudeAuthTokenResponse,
-    WsClaudeAuthTokenStored, WsClaudeLoginRequest, WsClaudeLoginResponse,
-    WsClaudeLoginVerificationResult, WsCommandResponse, WsCommandResult, WsIncomingMessage,
-    WsOutgoingMessage, WsPermissionData, WsPermissionRequest, WsPlanApprovalResponse,
-    WsSteering, WsSteeringCompleted, WsSteeringFailed, WsSteeringInterrupting,
+    ClaudeLoginStatus, WsAck, WsCancelPermission, WsCancelStream, WsClaudeAuthTokenRequest,
+    WsClaudeAuthTokenResponse, WsClaudeAuthTokenStored, WsClaudeLoginRequest,
+    WsClaudeLoginResponse, WsClaudeLoginVerificationResult, WsCommandResponse, WsCommandResult,
+    WsIncomingMessage, WsNotificationDismissed, WsOutgoingMessage, WsPermissionData,
+    WsPermissionRequest, WsPermissionResolved, WsPlanApprovalResponse, WsPresence, WsPresenceState,
+    WsPresenceUpdate, WsSteering, WsSteeringCompleted, WsSteeringFailed, WsSteeringInterrupting,
     WsSteeringQueued, WsSteeringResuming,
 };
@@ -0,0 +1,242 @@
+//! Conversation widget for embedding spoq's message rendering.
+//!
+//! [`ConversationView`] renders a list of [`Message`]s the way the spoq
+//! conversation screen does (markdown, streaming text, thinking blocks, tool
+//! and subagent events) without the rest of the application. It owns the
+//! render caches and the scroll position; the host owns the messages and the
+//! event loop.
+//!
+//! ```ignore
+//! use spoq::widgets::{ConversationView, ConversationViewWidget};
+//!
+//! let mut view = ConversationView::new();
+//! view.set_messages(messages);
+//!
+//! // On each token: view.messages_mut().last_mut().unwrap().append_token(token);
+//! // On each tick:  view.tick();
+//! // On PageUp:     view.scroll_up(10);
+//!
+//! terminal.draw(|frame| {
+//!     frame.render_widget(ConversationViewWidget::new(&mut view), frame.area());
+//! })?;
+//! ```
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::Line,
+    widgets::{Paragraph, Widget},
+};
+
+use crate::markdown::MarkdownCache;
+use crate::models::Message;
+use crate::rendered_lines_cache::RenderedLinesCache;
+use crate::ui::messages::{render_message_lines, MessageRenderState};
+use crate::ui::LayoutContext;
+
+/// Key the render caches file this view's messages under
+const CACHE_KEY: &str = "conversation-view";
+
+/// State of an embedded conversation: its messages, render caches and
+/// scroll position.
+///
+/// Completed messages are rendered once and cached by ID and render
+/// version, so change them through [`Message`]'s methods (which bump the
+/// version) or replace them with [`set_messages`](Self::set_messages).
+/// Streaming messages are rendered fresh every frame.
+#[derive(Default)]
+pub struct ConversationView {
+    messages: Vec<Message>,
+    markdown_cache: MarkdownCache,
+    rendered_lines_cache: RenderedLinesCache,
+    /// Animation tick for spinners of running tools
+    tick_count: u64,
+    /// Lines scrolled up from the bottom (0 = following the latest message)
+    scroll: usize,
+    /// Lines of content in the last render
+    total_lines: usize,
+    /// Height of the area in the last render
+    viewport_height: usize,
+}
+
+impl ConversationView {
+    /// Create an empty conversation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace all messages
+    pub fn set_messages(&mut self, messages: Vec<Message>) {
+        self.messages = messages;
+        self.rendered_lines_cache.clear();
+    }
+
+    /// Append a message
+    pub fn push(&mut self, message: Message) {
+        self.messages.push(message);
+    }
+
+    /// The messages shown
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Mutable access to the messages, e.g. to append streamed tokens
+    pub fn messages_mut(&mut self) -> &mut Vec<Message> {
+        &mut self.messages
+    }
+
+    /// Advance spinner animations by one frame
+    pub fn tick(&mut self) {
+        self.tick_count = self.tick_count.wrapping_add(1);
+    }
+
+    /// Scroll towards older messages by `lines`
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_add(lines).min(self.max_scroll());
+    }
+
+    /// Scroll towards newer messages by `lines`
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Jump to the latest message and follow new content again
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// Whether the view follows the latest message
+    pub fn is_at_bottom(&self) -> bool {
+        self.scroll == 0
+    }
+
+    /// Lines scrolled up from the bottom
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll
+    }
+
+    /// Highest scroll offset in the last render
+    fn max_scroll(&self) -> usize {
+        self.total_lines.saturating_sub(self.viewport_height)
+    }
+
+    /// Render every message for an area of the given size
+    fn render_lines(&mut self, width: u16, height: u16) -> Vec<Line<'static>> {
+        let ctx = LayoutContext::new(width, height);
+        self.rendered_lines_cache.invalidate_if_width_changed(width);
+
+        let mut state = MessageRenderState {
+            tick_count: self.tick_count,
+            markdown_cache: &mut self.markdown_cache,
+            rendered_lines_cache: &mut self.rendered_lines_cache,
+            thread_messages: &self.messages,
+        };
+        self.messages
+            .iter()
+            .flat_map(|message| render_message_lines(CACHE_KEY, message, &mut state, &ctx))
+            .collect()
+    }
+}
+
+/// Renders a [`ConversationView`], newest message at the bottom.
+///
+/// While scrolled up, the lines on screen stay put as messages grow below.
+pub struct ConversationViewWidget<'a> {
+    view: &'a mut ConversationView,
+}
+
+impl<'a> ConversationViewWidget<'a> {
+    pub fn new(view: &'a mut ConversationView) -> Self {
+        Self { view }
+    }
+}
+
+impl Widget for ConversationViewWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let view = self.view;
+        let lines = view.render_lines(area.width, area.height);
+
+        // Keep a scrolled-up reader on the same lines as content streams in
+        if view.scroll > 0 {
+            view.scroll += lines.len().saturating_sub(view.total_lines);
+        }
+        view.total_lines = lines.len();
+        view.viewport_height = area.height as usize;
+        view.scroll = view.scroll.min(view.max_scroll());
+
+        let top = view.max_scroll() - view.scroll;
+        let visible: Vec<Line<'static>> = lines
+            .into_iter()
+            .skip(top)
+            .take(area.height as usize)
+            .collect();
+        Paragraph::new(visible).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::ThreadCache;
+    use crate::models::MessageRole;
+
+    fn messages(count: usize) -> Vec<Message> {
+        let mut cache = ThreadCache::new();
+        for i in 0..count {
+            cache.add_message_simple("t", MessageRole::Assistant, format!("Message {}", i));
+        }
+        cache.get_messages("t").unwrap().clone()
+    }
+
+    fn draw(view: &mut ConversationView, width: u16, height: u16) -> String {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        ConversationViewWidget::new(view).render(area, &mut buf);
+        buf.content()
+            .chunks(width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_renders_latest_messages_and_scrolls() {
+        let mut view = ConversationView::new();
+        view.set_messages(messages(20));
+
+        let screen = draw(&mut view, 40, 10);
+        assert!(screen.contains("Message 19"));
+        assert!(!screen.contains("Message 0 "));
+
+        view.scroll_up(usize::MAX);
+        let screen = draw(&mut view, 40, 10);
+        assert!(screen.contains("Message 0"));
+        assert!(!screen.contains("Message 19"));
+
+        view.scroll_to_bottom();
+        assert!(draw(&mut view, 40, 10).contains("Message 19"));
+    }
+
+    #[test]
+    fn test_streaming_message_grows_without_moving_scrolled_view() {
+        let mut view = ConversationView::new();
+        let mut all = messages(10);
+        let mut streaming = all.pop().unwrap();
+        streaming.is_streaming = true;
+        streaming.append_token("Thinking about it");
+        all.push(streaming);
+        view.set_messages(all);
+
+        assert!(draw(&mut view, 40, 8).contains("Thinking about it"));
+
+        view.scroll_up(6);
+        let before = draw(&mut view, 40, 8);
+        let streaming = view.messages_mut().last_mut().unwrap();
+        streaming.append_token("\n\nmore\n\nand more");
+        assert_eq!(draw(&mut view, 40, 8), before);
+
+        view.scroll_to_bottom();
+        assert!(draw(&mut view, 40, 8).contains("and more"));
+    }
+}
@@ -1,5 +1,8 @@
+pub mod conversation;
 pub mod textarea;
 
+pub use conversation::{ConversationView, ConversationViewWidget};
+
 // Re-export for backwards compatibility
 pub use textarea::{TextAreaInput, TextAreaInputWidget};
 
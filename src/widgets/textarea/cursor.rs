@@ -18,14 +18,26 @@ impl<'a> TextAreaInput<'a> {
 
     /// Move cursor to the beginning of the current line
     /// Maps to: `move_cursor_home()` -> `move_cursor(CursorMove::Head)`
+    ///
+    /// When soft-wrapped, goes to the start of the visual row first; a second
+    /// press goes on to the start of the line.
     pub fn move_cursor_home(&mut self) {
-        self.textarea.move_cursor(CursorMove::Head);
+        match self.display_width() {
+            0 => self.textarea.move_cursor(CursorMove::Head),
+            width => self.move_cursor_visual_edge(width, false),
+        }
     }
 
     /// Move cursor to the end of the current line
     /// Maps to: `move_cursor_end()` -> `move_cursor(CursorMove::End)`
+    ///
+    /// When soft-wrapped, goes to the end of the visual row first; a second
+    /// press goes on to the end of the line.
     pub fn move_cursor_end(&mut self) {
-        self.textarea.move_cursor(CursorMove::End);
+        match self.display_width() {
+            0 => self.textarea.move_cursor(CursorMove::End),
+            width => self.move_cursor_visual_edge(width, true),
+        }
     }
 
     /// Move cursor one word to the left
@@ -40,16 +52,22 @@ impl<'a> TextAreaInput<'a> {
         self.textarea.move_cursor(CursorMove::WordForward);
     }
 
-    /// Move cursor up one line
+    /// Move cursor up one line (one visual row when soft-wrapped)
     /// New capability from tui-textarea
     pub fn move_cursor_up(&mut self) {
-        self.textarea.move_cursor(CursorMove::Up);
+        match self.display_width() {
+            0 => self.textarea.move_cursor(CursorMove::Up),
+            width => self.move_cursor_visual_row(width, true),
+        }
     }
 
-    /// Move cursor down one line
+    /// Move cursor down one line (one visual row when soft-wrapped)
     /// New capability from tui-textarea
     pub fn move_cursor_down(&mut self) {
-        self.textarea.move_cursor(CursorMove::Down);
+        match self.display_width() {
+            0 => self.textarea.move_cursor(CursorMove::Down),
+            width => self.move_cursor_visual_row(width, false),
+        }
     }
 
     /// Move cursor to the top of the document
@@ -1,5 +1,6 @@
 //! Editing operations for TextAreaInput.
 
+use super::history::TypingRun;
 use super::TextAreaInput;
 
 impl<'a> TextAreaInput<'a> {
@@ -14,56 +15,73 @@ impl<'a> TextAreaInput<'a> {
             return; // silently ignore
         }
 
-        self.textarea.insert_char(c);
-        self.update_token_positions(line, col, 1);
+        self.record_edit(Some(TypingRun::Insert), |input| {
+            input.textarea.insert_char(c);
+            input.update_token_positions(line, col, 1);
 
-        // Check if we need to hard wrap
-        if let Some(wrap_width) = self.wrap_width {
-            self.maybe_hard_wrap(wrap_width as usize);
-        }
+            // Check if we need to hard wrap
+            if let Some(wrap_width) = input.wrap_width {
+                input.maybe_hard_wrap(wrap_width as usize);
+            }
+        });
+    }
+
+    /// Insert pasted text character by character, as one undo unit
+    pub fn insert_text(&mut self, text: &str) {
+        self.record_edit(None, |input| {
+            for c in text.chars() {
+                input.insert_char(c);
+            }
+        });
     }
 
     /// Delete the character before the cursor (like Backspace key)
     /// Maps to: `backspace()` -> `delete_char()`
     pub fn backspace(&mut self) {
-        // Check if cursor is at or inside a token (backspace at token end = delete entire token)
-        if let Some(idx) = self.token_at_or_ending_at_cursor() {
-            self.remove_token(idx);
-            return;
-        }
+        self.record_edit(Some(TypingRun::Delete), |input| {
+            // Check if cursor is at or inside a token (backspace at token end = delete entire token)
+            if let Some(idx) = input.token_at_or_ending_at_cursor() {
+                input.remove_token(idx);
+                return;
+            }
 
-        // Normal backspace
-        let (line, col) = self.textarea.cursor();
-        if col > 0 {
-            self.textarea.delete_char();
-            // Update token positions (1 char deleted)
-            self.update_token_positions(line, col, -1);
-        } else {
-            // At start of line - just do normal backspace (may join lines)
-            self.textarea.delete_char();
-        }
+            // Normal backspace
+            let (line, col) = input.textarea.cursor();
+            if col > 0 {
+                input.textarea.delete_char();
+                // Update token positions (1 char deleted)
+                input.update_token_positions(line, col, -1);
+            } else {
+                // At start of line - just do normal backspace (may join lines)
+                input.textarea.delete_char();
+            }
+        });
     }
 
     /// Delete the character at the current cursor position (like Delete key)
     /// Maps to: `delete_char()` -> `delete_next_char()`
     pub fn delete_char(&mut self) {
-        // Check if cursor is at start of a token
-        if let Some(idx) = self.token_after_cursor() {
-            self.remove_token(idx);
-            return;
-        }
+        self.record_edit(Some(TypingRun::Delete), |input| {
+            // Check if cursor is at start of a token
+            if let Some(idx) = input.token_after_cursor() {
+                input.remove_token(idx);
+                return;
+            }
 
-        // Normal delete
-        let (line, col) = self.textarea.cursor();
-        self.textarea.delete_next_char();
-        // Update token positions
-        self.update_token_positions(line, col + 1, -1);
+            // Normal delete
+            let (line, col) = input.textarea.cursor();
+            input.textarea.delete_next_char();
+            // Update token positions
+            input.update_token_positions(line, col + 1, -1);
+        });
     }
 
     /// Delete from cursor position backward to the previous word boundary
     /// Maps to: `delete_word_backward()` -> `delete_word()`
     pub fn delete_word_backward(&mut self) {
-        self.textarea.delete_word();
+        self.record_edit(None, |input| {
+            input.textarea.delete_word();
+        });
     }
 
     /// Delete from cursor position back to the start of the current line.
@@ -71,35 +89,44 @@ impl<'a> TextAreaInput<'a> {
     /// shifts remaining token positions.
     /// Maps to: `delete_to_line_start()` -> `delete_line_by_head()`
     pub fn delete_to_line_start(&mut self) {
-        let (row, col) = self.textarea.cursor();
-        // Remove tokens entirely or partially before cursor on this line
-        self.paste_tokens
-            .retain(|t| !(t.line == row && t.col_start < col));
-        // After deletion, cursor at col 0 - shift remaining tokens on this line
-        for token in &mut self.paste_tokens {
-            if token.line == row {
-                token.col_start = token.col_start.saturating_sub(col);
-                token.col_end = token.col_end.saturating_sub(col);
+        self.record_edit(None, |input| {
+            let (row, col) = input.textarea.cursor();
+            // Remove tokens entirely or partially before cursor on this line
+            input
+                .paste_tokens
+                .retain(|t| !(t.line == row && t.col_start < col));
+            // After deletion, cursor at col 0 - shift remaining tokens on this line
+            for token in &mut input.paste_tokens {
+                if token.line == row {
+                    token.col_start = token.col_start.saturating_sub(col);
+                    token.col_end = token.col_end.saturating_sub(col);
+                }
             }
-        }
-        self.textarea.delete_line_by_head();
+            input.textarea.delete_line_by_head();
+        });
     }
 
     /// Delete from cursor to end of line
     pub fn delete_to_line_end(&mut self) {
-        self.textarea.delete_line_by_end();
+        self.record_edit(None, |input| {
+            input.textarea.delete_line_by_end();
+        });
     }
 
     /// Delete the entire current line
     pub fn delete_line(&mut self) {
-        self.textarea.delete_line_by_head();
-        self.textarea.delete_line_by_end();
+        self.record_edit(None, |input| {
+            input.textarea.delete_line_by_head();
+            input.textarea.delete_line_by_end();
+        });
     }
 
     /// Insert a newline at the current cursor position
     /// Maps to: `insert_char('\n')` -> `insert_newline()`
     pub fn insert_newline(&mut self) {
-        self.textarea.insert_newline();
+        self.record_edit(None, |input| {
+            input.textarea.insert_newline();
+        });
     }
 
     /// Check if there are yank (paste) contents available
@@ -109,7 +136,9 @@ impl<'a> TextAreaInput<'a> {
 
     /// Paste yanked text
     pub fn paste(&mut self) {
-        self.textarea.paste();
+        self.record_edit(None, |input| {
+            input.textarea.paste();
+        });
     }
 
     /// Copy the current selection (or line if no selection)
@@ -119,17 +148,9 @@ impl<'a> TextAreaInput<'a> {
 
     /// Cut the current selection (or line if no selection)
     pub fn cut(&mut self) {
-        self.textarea.cut();
-    }
-
-    /// Undo the last edit
-    pub fn undo(&mut self) -> bool {
-        self.textarea.undo()
-    }
-
-    /// Redo the last undone edit
-    pub fn redo(&mut self) -> bool {
-        self.textarea.redo()
+        self.record_edit(None, |input| {
+            input.textarea.cut();
+        });
     }
 
     /// Start or update selection
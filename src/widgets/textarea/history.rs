@@ -0,0 +1,262 @@
+//! Undo/redo history for TextAreaInput.
+//!
+//! tui-textarea keeps a history of its own, but it knows nothing about paste
+//! tokens: undoing the insertion of `[Pasted #1 ~5 lines]` one character at a
+//! time would leave the token half-removed and tracked at a stale position.
+//! Instead every edit snapshots the text, cursor and tokens it started from.
+//! Runs of typed characters (or of Backspace/Delete presses) collapse into a
+//! single undo unit.
+
+use std::collections::VecDeque;
+
+use super::paste::PasteToken;
+use super::TextAreaInput;
+
+/// Most undo units kept; the oldest are dropped first
+pub(super) const MAX_UNDO_UNITS: usize = 200;
+
+/// Kind of single-key edit that coalesces with the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TypingRun {
+    Insert,
+    Delete,
+}
+
+/// State of the input before an edit
+#[derive(Debug, Clone)]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: (usize, usize),
+    paste_tokens: Vec<PasteToken>,
+    paste_counter: u32,
+}
+
+/// Undo and redo stacks of a TextAreaInput
+#[derive(Debug, Clone, Default)]
+pub(super) struct EditHistory {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// Typing run in progress, and where its last edit left the cursor
+    run: Option<(TypingRun, (usize, usize))>,
+    /// Inside an edit being recorded, whose inner edits aren't units of their own
+    recording: bool,
+}
+
+impl EditHistory {
+    fn push(&mut self, snapshot: Snapshot) {
+        self.undo.push_back(snapshot);
+        if self.undo.len() > MAX_UNDO_UNITS {
+            self.undo.pop_front();
+        }
+    }
+
+    fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.run = None;
+    }
+}
+
+impl<'a> TextAreaInput<'a> {
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            lines: self.textarea.lines().to_vec(),
+            cursor: self.textarea.cursor(),
+            paste_tokens: self.paste_tokens.clone(),
+            paste_counter: self.paste_counter,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.textarea.select_all();
+        self.textarea.delete_char();
+        self.textarea.insert_str(snapshot.lines.join("\n"));
+        self.set_cursor(snapshot.cursor.0, snapshot.cursor.1);
+        self.paste_tokens = snapshot.paste_tokens;
+        // Never hand out a token number that's still on screen
+        self.paste_counter = self.paste_counter.max(snapshot.paste_counter);
+    }
+
+    /// Run `edit` as one undo unit, or as part of the typing run in progress
+    /// when `run` continues it. Edits that change nothing aren't recorded.
+    pub(super) fn record_edit(&mut self, run: Option<TypingRun>, edit: impl FnOnce(&mut Self)) {
+        if self.history.recording {
+            edit(self);
+            return;
+        }
+        let before = self.snapshot();
+        let continues_run =
+            run.is_some() && self.history.run == run.map(|kind| (kind, before.cursor));
+
+        self.history.recording = true;
+        edit(self);
+        self.history.recording = false;
+
+        let changed = before.lines != self.textarea.lines()
+            || before.paste_tokens.len() != self.paste_tokens.len();
+        if !changed {
+            return;
+        }
+        if !continues_run {
+            self.history.push(before);
+        }
+        self.history.redo.clear();
+        self.history.run = run.map(|kind| (kind, self.textarea.cursor()));
+    }
+
+    /// Forget all undo and redo history
+    pub(super) fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Undo the last edit. Returns false if there's nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.history.undo.pop_back() else {
+            return false;
+        };
+        let current = self.snapshot();
+        self.history.redo.push(current);
+        self.history.run = None;
+        self.restore(snapshot);
+        true
+    }
+
+    /// Redo the last undone edit. Returns false if there's nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.history.redo.pop() else {
+            return false;
+        };
+        let current = self.snapshot();
+        self.history.push(current);
+        self.history.run = None;
+        self.restore(snapshot);
+        true
+    }
+
+    /// Whether there's an edit to undo
+    pub fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    /// Whether there's an undone edit to redo
+    pub fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_str(input: &mut TextAreaInput, s: &str) {
+        for c in s.chars() {
+            input.insert_char(c);
+        }
+    }
+
+    #[test]
+    fn test_typing_run_is_one_undo_unit() {
+        let mut input = TextAreaInput::new();
+        type_str(&mut input, "hello");
+        input.insert_newline();
+        type_str(&mut input, "world");
+
+        assert!(input.undo());
+        assert_eq!(input.content(), "hello\n");
+        assert!(input.undo());
+        assert_eq!(input.content(), "hello");
+        assert!(input.undo());
+        assert_eq!(input.content(), "");
+        assert!(!input.undo());
+
+        assert!(input.redo());
+        assert_eq!(input.content(), "hello");
+        assert_eq!(input.cursor(), (0, 5));
+    }
+
+    #[test]
+    fn test_moving_the_cursor_ends_a_typing_run() {
+        let mut input = TextAreaInput::new();
+        type_str(&mut input, "ac");
+        input.move_cursor_left();
+        input.insert_char('b');
+        assert_eq!(input.content(), "abc");
+
+        input.undo();
+        assert_eq!(input.content(), "ac");
+        assert_eq!(input.cursor(), (0, 1));
+    }
+
+    #[test]
+    fn test_new_edit_clears_redo() {
+        let mut input = TextAreaInput::new();
+        type_str(&mut input, "one two");
+        input.delete_word_backward();
+        input.undo();
+        assert!(input.can_redo());
+
+        input.backspace();
+        assert!(!input.can_redo());
+        assert!(!input.redo());
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut input = TextAreaInput::new();
+        for _ in 0..MAX_UNDO_UNITS + 10 {
+            input.insert_char('x');
+            input.insert_newline();
+        }
+        let mut undone = 0;
+        while input.undo() {
+            undone += 1;
+        }
+        assert_eq!(undone, MAX_UNDO_UNITS);
+    }
+
+    #[test]
+    fn test_paste_token_undoes_atomically() {
+        let mut input = TextAreaInput::new();
+        type_str(&mut input, "see ");
+        input.insert_paste_token("line1\nline2".to_string());
+        input.backspace();
+        assert_eq!(input.content(), "see ");
+
+        // Undoing the deletion brings the token back, still expandable
+        input.undo();
+        assert_eq!(input.content_expanded(), "see line1\nline2");
+        input.undo();
+        assert_eq!(input.content(), "see ");
+        assert!(input.paste_tokens.is_empty());
+
+        // Redoing it doesn't reuse its number for the next paste
+        input.redo();
+        assert_eq!(input.insert_paste_token("more".to_string()), 2);
+    }
+
+    #[test]
+    fn test_pasted_text_is_one_undo_unit() {
+        let mut input = TextAreaInput::new();
+        type_str(&mut input, "a");
+        input.insert_text("bc\nd");
+        input.insert_char('e');
+        assert_eq!(input.content(), "abc\nde");
+
+        input.undo();
+        assert_eq!(input.content(), "abc\nd");
+        input.undo();
+        assert_eq!(input.content(), "a");
+    }
+
+    #[test]
+    fn test_set_content_clears_history_but_is_undoable() {
+        let mut input = TextAreaInput::new();
+        type_str(&mut input, "draft");
+        input.insert_newline();
+        input.set_content("from history");
+
+        assert!(input.undo());
+        assert_eq!(input.content(), "draft\n");
+        assert!(!input.undo());
+    }
+}
@@ -6,9 +6,14 @@
 
 mod cursor;
 mod editing;
+mod history;
 mod paste;
 mod wrapping;
 
+pub use wrapping::VisualRow;
+use wrapping::WrapView;
+
+use history::EditHistory;
 use paste::PasteToken;
 
 use ratatui::{
@@ -16,10 +21,9 @@ use ratatui::{
     layout::Rect,
     style::{Color, Style},
     text::Line,
-    widgets::{Block, Borders, Widget},
+    widgets::{Block, Borders, Paragraph, Widget},
 };
 use tui_textarea::{CursorMove, TextArea};
-use unicode_width::UnicodeWidthStr;
 
 /// A wrapper around tui-textarea that provides an API compatible with InputBox.
 ///
@@ -37,6 +41,10 @@ pub struct TextAreaInput<'a> {
     /// Width for hard wrap (auto-newline). When set, lines are automatically
     /// wrapped by inserting newlines when they exceed this width.
     pub(super) wrap_width: Option<u16>,
+    /// Undo/redo stacks (tui-textarea's own history is disabled)
+    history: EditHistory,
+    /// Soft-wrap width and scroll position, kept from the last render
+    wrap_view: WrapView,
 }
 
 impl Default for TextAreaInput<'_> {
@@ -59,12 +67,15 @@ impl<'a> TextAreaInput<'a> {
         textarea.set_tab_length(4); // 4 spaces per tab (matches common Rust convention)
         textarea.set_line_wrap(true); // Enable soft line wrapping for visual display
                                       // Line numbers are OFF by default in tui-textarea (no need to explicitly remove)
+        textarea.set_max_histories(0); // Undo/redo is tracked by EditHistory
 
         Self {
             textarea,
             paste_tokens: Vec::new(),
             paste_counter: 0,
             wrap_width: None,
+            history: EditHistory::default(),
+            wrap_view: WrapView::default(),
         }
     }
 
@@ -86,6 +97,7 @@ impl<'a> TextAreaInput<'a> {
         textarea.set_tab_length(4); // 4 spaces per tab (matches common Rust convention)
         textarea.set_line_wrap(true); // Enable soft line wrapping for visual display
                                       // Line numbers are OFF by default in tui-textarea (no need to explicitly remove)
+        textarea.set_max_histories(0); // Undo/redo is tracked by EditHistory
 
         // Move cursor to end
         textarea.move_cursor(CursorMove::Bottom);
@@ -95,6 +107,8 @@ impl<'a> TextAreaInput<'a> {
             paste_tokens: Vec::new(),
             paste_counter: 0,
             wrap_width: None,
+            history: EditHistory::default(),
+            wrap_view: WrapView::default(),
         }
     }
 
@@ -115,10 +129,12 @@ impl<'a> TextAreaInput<'a> {
     /// Clear all content and reset cursor
     /// Maps to: `clear()` -> select_all + delete_char or recreate
     pub fn clear(&mut self) {
-        self.textarea.select_all();
-        self.textarea.delete_char();
-        self.paste_tokens.clear();
-        self.paste_counter = 0;
+        self.record_edit(None, |input| {
+            input.textarea.select_all();
+            input.textarea.delete_char();
+            input.paste_tokens.clear();
+            input.paste_counter = 0;
+        });
     }
 
     /// Get the current content of the input as a single string
@@ -144,38 +160,30 @@ impl<'a> TextAreaInput<'a> {
     /// at the given width (accounting for borders).
     pub fn visual_line_count(&self, available_width: u16) -> usize {
         // Callers already account for borders, use the width directly
-        let content_width = available_width as usize;
-        if content_width == 0 {
+        if available_width == 0 {
             return self.line_count();
         }
-
-        let mut visual_lines = 0;
-        for line in self.textarea.lines() {
-            let line_width = line.width();
-            if line_width == 0 {
-                // Empty line still takes 1 visual line
-                visual_lines += 1;
-            } else {
-                // Calculate how many visual lines this logical line needs
-                visual_lines += line_width.div_ceil(content_width);
-            }
-        }
-        visual_lines.max(1)
+        self.visual_rows(available_width).len().max(1)
     }
 
-    /// Set the content of the textarea
+    /// Set the content of the textarea.
+    ///
+    /// Drops the undo history, but the replacement itself can be undone.
     pub fn set_content(&mut self, text: &str) {
-        // Clear existing content
-        self.textarea.select_all();
-        self.textarea.delete_char();
-        // Insert new content
-        for c in text.chars() {
-            if c == '\n' {
-                self.textarea.insert_newline();
-            } else {
-                self.textarea.insert_char(c);
+        self.clear_history();
+        self.record_edit(None, |input| {
+            // Clear existing content
+            input.textarea.select_all();
+            input.textarea.delete_char();
+            // Insert new content
+            for c in text.chars() {
+                if c == '\n' {
+                    input.textarea.insert_newline();
+                } else {
+                    input.textarea.insert_char(c);
+                }
             }
-        }
+        });
     }
 
     /// Get the lines as a slice
@@ -289,18 +297,15 @@ impl<'a> TextAreaInput<'a> {
         // - When focused AND cursor_visible: show white background cursor
         // - When focused but NOT cursor_visible: hide cursor (blink "off" phase)
         // - When not focused: always hide cursor
-        if focused && cursor_visible {
-            self.textarea
-                .set_cursor_style(Style::default().fg(Color::Black).bg(Color::White));
-        } else {
-            self.textarea.set_cursor_style(Style::default());
-        }
-
-        // Clear any existing block (no border)
-        self.textarea.set_block(Block::default());
-
-        // Render the textarea
-        (&self.textarea).render(area, buf);
+        let cursor_style = (focused && cursor_visible)
+            .then_some(Style::default().fg(Color::Black).bg(Color::White));
+
+        // Soft-wrap to the area, scrolling once the text is taller than it
+        self.set_display_width(area.width);
+        let lines = self.wrapped_lines(area.height as usize, cursor_style);
+        Paragraph::new(lines)
+            .style(self.textarea.style())
+            .render(area, buf);
     }
 }
 
@@ -110,20 +110,23 @@ impl<'a> TextAreaInput<'a> {
 
         let (line, col_start) = self.textarea.cursor();
 
-        // Insert the display text (bypass our insert_char to avoid token check)
-        for ch in display.chars() {
-            self.textarea.insert_char(ch);
-        }
+        // One undo unit, so undo never leaves half a token behind
+        self.record_edit(None, |input| {
+            // Insert the display text (bypass our insert_char to avoid token check)
+            for ch in display.chars() {
+                input.textarea.insert_char(ch);
+            }
 
-        // Update positions of any tokens after this insertion point
-        self.update_token_positions(line, col_start, display_len as isize);
+            // Update positions of any tokens after this insertion point
+            input.update_token_positions(line, col_start, display_len as isize);
 
-        // Track this token
-        self.paste_tokens.push(PasteToken {
-            line,
-            col_start,
-            col_end: col_start + display_len,
-            content,
+            // Track this token
+            input.paste_tokens.push(PasteToken {
+                line,
+                col_start,
+                col_end: col_start + display_len,
+                content,
+            });
         });
 
         id
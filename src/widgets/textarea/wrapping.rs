@@ -1,7 +1,52 @@
 //! Line wrapping functionality for TextAreaInput.
+//!
+//! Hard wrapping inserts newlines as you type. Soft wrapping only changes how
+//! the text is laid out on screen: long lines are split into rows at the
+//! input's width, and the content itself is left untouched.
+
+use std::sync::atomic::{AtomicU16, AtomicUsize, Ordering};
 
 use super::TextAreaInput;
-use unicode_width::UnicodeWidthStr;
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+use tui_textarea::CursorMove;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// One on-screen row of a soft-wrapped line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VisualRow {
+    /// Index of the logical line the row belongs to
+    pub line: usize,
+    /// Char index of the row's first char
+    pub start: usize,
+    /// Char index just past the row's last char
+    pub end: usize,
+    /// Whether this is the last row of its line
+    pub last: bool,
+}
+
+/// Soft-wrap width and scroll position.
+///
+/// Atomics so they can be updated while rendering through `&self`, the same
+/// way tui-textarea keeps its viewport.
+#[derive(Debug, Default)]
+pub(super) struct WrapView {
+    /// Width the text is wrapped to (0 = not wrapped)
+    width: AtomicU16,
+    /// First row shown when the text is taller than the input
+    top: AtomicUsize,
+}
+
+impl Clone for WrapView {
+    fn clone(&self) -> Self {
+        Self {
+            width: AtomicU16::new(self.width.load(Ordering::Relaxed)),
+            top: AtomicUsize::new(self.top.load(Ordering::Relaxed)),
+        }
+    }
+}
 
 impl<'a> TextAreaInput<'a> {
     /// Set the wrap width for hard wrapping. When set, inserting characters
@@ -19,8 +64,6 @@ impl<'a> TextAreaInput<'a> {
     /// Check if the current line exceeds the wrap width and insert a newline if needed.
     /// Wraps at word boundaries when possible for cleaner text.
     pub(super) fn maybe_hard_wrap(&mut self, wrap_width: usize) {
-        use tui_textarea::CursorMove;
-
         if wrap_width == 0 {
             return;
         }
@@ -123,9 +166,455 @@ impl<'a> TextAreaInput<'a> {
     }
 }
 
+impl<'a> TextAreaInput<'a> {
+    /// Set the width the text is soft-wrapped to. Renderers call this so
+    /// cursor movement follows the rows on screen; 0 turns soft wrap off.
+    pub fn set_display_width(&self, width: u16) {
+        self.wrap_view.width.store(width, Ordering::Relaxed);
+    }
+
+    /// The width the text was last soft-wrapped to (0 if not wrapped)
+    pub fn display_width(&self) -> u16 {
+        self.wrap_view.width.load(Ordering::Relaxed)
+    }
+
+    /// The rows the text takes up when soft-wrapped to `width` columns.
+    ///
+    /// Lines break after a space where possible; a word longer than the
+    /// width is split at the edge. A width of 0 gives one row per line.
+    pub fn visual_rows(&self, width: u16) -> Vec<VisualRow> {
+        let width = if width == 0 {
+            usize::MAX
+        } else {
+            width as usize
+        };
+        let tab_len = self.textarea.tab_length() as usize;
+
+        let mut rows = Vec::new();
+        for (line, text) in self.textarea.lines().iter().enumerate() {
+            let chars: Vec<char> = text.chars().collect();
+            let breaks = wrap_line(&chars, width, tab_len);
+            let count = breaks.len();
+            rows.extend(
+                breaks
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (start, end))| VisualRow {
+                        line,
+                        start,
+                        end,
+                        last: i + 1 == count,
+                    }),
+            );
+        }
+        rows
+    }
+
+    /// The cursor's (row, column) on screen when soft-wrapped to `width`
+    pub fn visual_cursor(&self, width: u16) -> (usize, usize) {
+        self.visual_cursor_in(&self.visual_rows(width), width)
+    }
+
+    /// Whether the cursor is on the top row of the soft-wrapped text
+    pub fn is_cursor_on_first_visual_row(&self) -> bool {
+        match self.display_width() {
+            0 => self.is_cursor_on_first_line(),
+            width => self.visual_cursor(width).0 == 0,
+        }
+    }
+
+    /// Whether the cursor is on the bottom row of the soft-wrapped text
+    pub fn is_cursor_on_last_visual_row(&self) -> bool {
+        match self.display_width() {
+            0 => self.is_cursor_on_last_line(),
+            width => {
+                let rows = self.visual_rows(width);
+                self.visual_cursor_in(&rows, width).0 + 1 >= rows.len()
+            }
+        }
+    }
+
+    /// The soft-wrapped rows to draw, at most `max_rows` of them.
+    ///
+    /// Scrolls so the cursor stays in view once the text is taller than
+    /// `max_rows`. The cursor cell gets `cursor_style`; pass None to hide it.
+    /// Text spans are unstyled so callers can apply their own base style.
+    pub fn wrapped_lines(
+        &self,
+        max_rows: usize,
+        cursor_style: Option<Style>,
+    ) -> Vec<Line<'static>> {
+        let width = self.display_width();
+        let rows = self.visual_rows(width);
+        let (cursor_row, _) = self.visual_cursor_in(&rows, width);
+        let height = max_rows.max(1);
+        let top = self.scroll_to(cursor_row, rows.len(), height);
+        let tab_len = self.textarea.tab_length() as usize;
+        let max_width = if width == 0 {
+            usize::MAX
+        } else {
+            width as usize
+        };
+
+        rows.iter()
+            .enumerate()
+            .skip(top)
+            .take(height)
+            .map(|(index, row)| {
+                let chars: Vec<char> = self.textarea.lines()[row.line]
+                    .chars()
+                    .skip(row.start)
+                    .take(row.end - row.start)
+                    .collect();
+                let cells = display_cells(&chars, tab_len);
+                let Some(style) = cursor_style.filter(|_| index == cursor_row) else {
+                    return Line::from(cells.concat());
+                };
+
+                // Keep the cursor on screen when it's past a full row's edge
+                let mut at = (self.textarea.cursor().1 - row.start).min(cells.len());
+                while at > 0 && cells[..at].concat().width() >= max_width {
+                    at -= 1;
+                }
+                let cursor = cells.get(at).cloned().unwrap_or_else(|| " ".to_string());
+                let after = cells.get(at + 1..).map(|c| c.concat()).unwrap_or_default();
+                Line::from(vec![
+                    Span::raw(cells[..at].concat()),
+                    Span::styled(cursor, style),
+                    Span::raw(after),
+                ])
+            })
+            .collect()
+    }
+
+    /// Cursor (row, column) in `rows`, the layout for `width`
+    pub(super) fn visual_cursor_in(&self, rows: &[VisualRow], width: u16) -> (usize, usize) {
+        let (line, col) = self.textarea.cursor();
+        let Some(index) = rows
+            .iter()
+            .position(|r| r.line == line && col >= r.start && (col < r.end || r.last))
+        else {
+            return (0, 0);
+        };
+        let row = rows[index];
+        let chars: Vec<char> = self.textarea.lines()[line]
+            .chars()
+            .skip(row.start)
+            .take(col - row.start)
+            .collect();
+        let column = cells_width(&chars, self.textarea.tab_length() as usize);
+        match width {
+            0 => (index, column),
+            width => (index, column.min(width as usize - 1)),
+        }
+    }
+
+    /// Move the cursor one soft-wrapped row up or down, keeping its column
+    pub(super) fn move_cursor_visual_row(&mut self, width: u16, up: bool) {
+        let rows = self.visual_rows(width);
+        let (index, column) = self.visual_cursor_in(&rows, width);
+        let target = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1).filter(|&i| i < rows.len())
+        };
+        let Some(row) = target.map(|i| rows[i]) else {
+            return;
+        };
+
+        let chars: Vec<char> = self.textarea.lines()[row.line].chars().collect();
+        let tab_len = self.textarea.tab_length() as usize;
+        // Stay before the break so the cursor doesn't land on the next row
+        let last = if row.last {
+            row.end
+        } else {
+            row.end.saturating_sub(1).max(row.start)
+        };
+        let mut col = row.start;
+        let mut used = 0;
+        while col < last {
+            let w = cell_width(chars[col], used, tab_len);
+            if used + w > column {
+                break;
+            }
+            used += w;
+            col += 1;
+        }
+        self.jump_cursor(row.line, col);
+    }
+
+    /// Move the cursor to the start (or end) of its soft-wrapped row, or of
+    /// the whole line if it's already there
+    pub(super) fn move_cursor_visual_edge(&mut self, width: u16, end: bool) {
+        let rows = self.visual_rows(width);
+        let (index, _) = self.visual_cursor_in(&rows, width);
+        let Some(row) = rows.get(index).copied() else {
+            return;
+        };
+        let (line, col) = self.textarea.cursor();
+        let target = if end {
+            let row_end = if row.last {
+                row.end
+            } else {
+                row.end.saturating_sub(1).max(row.start)
+            };
+            if col == row_end {
+                self.textarea.lines()[line].chars().count()
+            } else {
+                row_end
+            }
+        } else if col == row.start {
+            0
+        } else {
+            row.start
+        };
+        self.jump_cursor(line, target);
+    }
+
+    /// Put the cursor at (row, col) of the logical text
+    fn jump_cursor(&mut self, row: usize, col: usize) {
+        match (u16::try_from(row), u16::try_from(col)) {
+            (Ok(row), Ok(col)) => self.textarea.move_cursor(CursorMove::Jump(row, col)),
+            _ => self.set_cursor(row, col),
+        }
+    }
+
+    /// Scroll so `cursor_row` is within `height` rows, returning the top row
+    fn scroll_to(&self, cursor_row: usize, total: usize, height: usize) -> usize {
+        let mut top = self
+            .wrap_view
+            .top
+            .load(Ordering::Relaxed)
+            .min(total.saturating_sub(height));
+        if cursor_row < top {
+            top = cursor_row;
+        } else if cursor_row >= top + height {
+            top = cursor_row + 1 - height;
+        }
+        self.wrap_view.top.store(top, Ordering::Relaxed);
+        top
+    }
+}
+
+/// Split a line into rows of at most `width` cells, as (start, end) char
+/// ranges. Breaks after the last space that fits; a space may hang one cell
+/// past the edge so rows don't start with the space between two words.
+fn wrap_line(chars: &[char], width: usize, tab_len: usize) -> Vec<(usize, usize)> {
+    let mut rows = Vec::new();
+    let mut start = 0;
+    let mut used = 0;
+    let mut break_at = None;
+
+    for (i, &c) in chars.iter().enumerate() {
+        let limit = if c == ' ' {
+            width.saturating_add(1)
+        } else {
+            width
+        };
+        let mut w = cell_width(c, used, tab_len);
+        while used + w > limit && i > start {
+            let end = break_at.filter(|&b| b > start).unwrap_or(i);
+            rows.push((start, end));
+            start = end;
+            break_at = None;
+            used = cells_width(&chars[start..i], tab_len);
+            w = cell_width(c, used, tab_len);
+        }
+        used += w;
+        if c == ' ' {
+            break_at = Some(i + 1);
+        }
+    }
+    rows.push((start, chars.len()));
+    rows
+}
+
+/// Columns `c` takes when drawn `col` columns into a row
+fn cell_width(c: char, col: usize, tab_len: usize) -> usize {
+    if c == '\t' {
+        if tab_len == 0 {
+            0
+        } else {
+            tab_len - col % tab_len
+        }
+    } else {
+        c.width().unwrap_or(0)
+    }
+}
+
+/// Columns a row of `chars` takes
+fn cells_width(chars: &[char], tab_len: usize) -> usize {
+    chars
+        .iter()
+        .fold(0, |used, &c| used + cell_width(c, used, tab_len))
+}
+
+/// What each char draws as (tabs become spaces)
+fn display_cells(chars: &[char], tab_len: usize) -> Vec<String> {
+    let mut used = 0;
+    chars
+        .iter()
+        .map(|&c| {
+            let w = cell_width(c, used, tab_len);
+            used += w;
+            if c == '\t' {
+                " ".repeat(w)
+            } else {
+                c.to_string()
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::TextAreaInput;
+    use super::VisualRow;
+    use ratatui::style::Style;
+
+    fn row_ranges(input: &TextAreaInput, width: u16) -> Vec<(usize, usize)> {
+        input
+            .visual_rows(width)
+            .iter()
+            .map(|r| (r.start, r.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_soft_wrap_breaks_after_spaces() {
+        let input = TextAreaInput::with_content("hello world foo");
+        // "hello " / "world " / "foo"
+        assert_eq!(row_ranges(&input, 8), vec![(0, 6), (6, 12), (12, 15)]);
+        assert_eq!(input.visual_line_count(8), 3);
+    }
+
+    #[test]
+    fn test_soft_wrap_splits_long_words() {
+        let input = TextAreaInput::with_content("abcdefghij");
+        assert_eq!(row_ranges(&input, 4), vec![(0, 4), (4, 8), (8, 10)]);
+    }
+
+    #[test]
+    fn test_soft_wrap_rows_track_lines() {
+        let input = TextAreaInput::with_content("one two\nx");
+        assert_eq!(
+            input.visual_rows(4),
+            vec![
+                VisualRow {
+                    line: 0,
+                    start: 0,
+                    end: 4,
+                    last: false
+                },
+                VisualRow {
+                    line: 0,
+                    start: 4,
+                    end: 7,
+                    last: true
+                },
+                VisualRow {
+                    line: 1,
+                    start: 0,
+                    end: 1,
+                    last: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_soft_wrap_leaves_content_unchanged() {
+        let mut input = TextAreaInput::with_content("a fairly long prompt that wraps");
+        input.set_display_width(10);
+        let lines = input.wrapped_lines(8, None);
+        assert!(lines.len() > 1);
+        input.move_cursor_up();
+        input.move_cursor_home();
+        assert_eq!(input.line_count(), 1);
+        assert_eq!(input.content_expanded(), "a fairly long prompt that wraps");
+    }
+
+    #[test]
+    fn test_visual_cursor_maps_across_wraps() {
+        let mut input = TextAreaInput::with_content("hello world foo");
+        input.set_cursor(0, 8); // the "r" in "world"
+        assert_eq!(input.visual_cursor(8), (1, 2));
+        input.set_cursor(0, 15);
+        assert_eq!(input.visual_cursor(8), (2, 3));
+    }
+
+    #[test]
+    fn test_up_down_move_by_visual_row() {
+        let mut input = TextAreaInput::with_content("hello world foo");
+        input.set_display_width(8);
+        assert!(input.is_cursor_on_last_visual_row());
+        assert!(!input.is_cursor_on_first_visual_row());
+
+        input.move_cursor_up();
+        assert_eq!(input.cursor(), (0, 9));
+        input.move_cursor_up();
+        assert_eq!(input.cursor(), (0, 3));
+        assert!(input.is_cursor_on_first_visual_row());
+
+        // Top row: nothing above, the caller falls back to history
+        input.move_cursor_up();
+        assert_eq!(input.cursor(), (0, 3));
+
+        input.move_cursor_down();
+        assert_eq!(input.cursor(), (0, 9));
+    }
+
+    #[test]
+    fn test_up_stays_on_shorter_row() {
+        let mut input = TextAreaInput::with_content("ab cdefgh");
+        input.set_display_width(6);
+        // "ab " / "cdefgh": from the end of "cdefgh", up lands on the space
+        input.move_cursor_up();
+        assert_eq!(input.cursor(), (0, 2));
+    }
+
+    #[test]
+    fn test_home_end_double_press() {
+        let mut input = TextAreaInput::with_content("hello world foo");
+        input.set_display_width(8);
+        input.set_cursor(0, 8);
+
+        input.move_cursor_home();
+        assert_eq!(input.cursor(), (0, 6));
+        input.move_cursor_home();
+        assert_eq!(input.cursor(), (0, 0));
+
+        input.set_cursor(0, 8);
+        input.move_cursor_end();
+        assert_eq!(input.cursor(), (0, 11));
+        input.move_cursor_end();
+        assert_eq!(input.cursor(), (0, 15));
+    }
+
+    #[test]
+    fn test_wrapped_lines_scroll_to_cursor() {
+        let input = TextAreaInput::with_content("aaaa bbbb cccc dddd");
+        input.set_display_width(5);
+
+        // Cursor at the end, on the 4th row; only 2 rows fit
+        let lines = input.wrapped_lines(2, Some(Style::default()));
+        let text: Vec<String> = lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        // (the cursor cell adds a trailing space)
+        assert_eq!(text, vec!["cccc ".to_string(), "dddd ".to_string()]);
+    }
+
+    #[test]
+    fn test_wrapped_lines_cursor_stays_inside_full_row() {
+        let input = TextAreaInput::with_content("abcde");
+        input.set_display_width(5);
+        let lines = input.wrapped_lines(3, Some(Style::default()));
+        assert_eq!(lines.len(), 1);
+        // The cursor sits on the last cell instead of past the edge
+        assert_eq!(lines[0].spans[1].content, "e");
+    }
 
     #[test]
     fn test_hard_wrap_disabled_by_default() {
@@ -0,0 +1,69 @@
+//! Attachment upload API tests using wiremock.
+//!
+//! These tests verify that the ConductorClient uploads images to
+//! POST /v1/attachments and returns the attachment ID used in stream requests.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use spoq::models::ImageAttachmentPayload;
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to create a test token.
+fn test_token() -> String {
+    "test-auth-token".to_string()
+}
+
+fn test_image() -> ImageAttachmentPayload {
+    ImageAttachmentPayload {
+        hash: "a3f2b1c0".to_string(),
+        data: "iVBORw0KGgo=".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_upload_attachment_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/attachments"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .and(body_json(serde_json::json!({
+            "hash": "a3f2b1c0",
+            "media_type": "image/png",
+            "data": "iVBORw0KGgo="
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "att-123"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.upload_attachment(&test_image()).await;
+
+    assert_eq!(result.unwrap(), "att-123");
+}
+
+#[tokio::test]
+async fn test_upload_attachment_too_large() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/attachments"))
+        .respond_with(ResponseTemplate::new(413).set_body_string("Payload too large"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.upload_attachment(&test_image()).await;
+
+    match result {
+        Err(ConductorError::ServerError { status, message }) => {
+            assert_eq!(status, 413);
+            assert_eq!(message, "Payload too large");
+        }
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
@@ -0,0 +1,76 @@
+//! Context compaction API endpoint tests using wiremock.
+//!
+//! These tests verify that the ConductorClient correctly calls the
+//! POST /v1/threads/{id}/compact endpoint.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to create a test token.
+fn test_token() -> String {
+    "test-auth-token".to_string()
+}
+
+#[tokio::test]
+async fn test_compact_context_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/threads/thread-1/compact"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "tokens_before": 82000,
+            "tokens_after": 31000,
+            "token_limit": 200000
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let response = client.compact_context("thread-1").await.unwrap();
+
+    assert_eq!(response.tokens_before, 82_000);
+    assert_eq!(response.tokens_after, 31_000);
+    assert_eq!(response.token_limit, Some(200_000));
+}
+
+#[tokio::test]
+async fn test_compact_context_not_implemented() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/threads/thread-1/compact"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.compact_context("thread-1").await;
+
+    assert!(matches!(result, Err(ConductorError::NotImplemented(_))));
+}
+
+#[tokio::test]
+async fn test_compact_context_server_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/threads/thread-1/compact"))
+        .respond_with(ResponseTemplate::new(409).set_body_string("Thread is streaming"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    match client.compact_context("thread-1").await {
+        Err(ConductorError::ServerError { status, message }) => {
+            assert_eq!(status, 409);
+            assert!(message.contains("streaming"));
+        }
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
@@ -39,6 +39,11 @@ fn make_test_thread(id: &str, title: &str, status: Option<ThreadStatus>) -> Thre
         status,
         verified: None,
         verified_at: None,
+        links: Vec::new(),
+        pinned: false,
+        pin_order: 0,
+        archived: false,
+        muted: false,
     }
 }
 
@@ -91,6 +91,50 @@ async fn test_debug_server_state_endpoint() {
     handle.abort();
 }
 
+/// Test that the snapshot endpoint serves the app's published state dump.
+#[tokio::test]
+async fn test_debug_server_snapshot_endpoint() {
+    let (tx, _rx) = create_debug_channel(16);
+
+    let addr: SocketAddr = "127.0.0.1:13035".parse().unwrap();
+    let (handle, state_snapshot) = start_debug_server_on(addr, tx)
+        .await
+        .expect("Failed to start debug server");
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let client = reqwest::Client::new();
+
+    // Nothing published yet
+    let body: serde_json::Value = client
+        .get("http://127.0.0.1:13035/snapshot")
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    assert!(body.is_null());
+
+    state_snapshot.write().await.app = Some(serde_json::json!({
+        "screen": "Conversation",
+        "scroll": { "unified_scroll": 12 }
+    }));
+
+    let body: serde_json::Value = client
+        .get("http://127.0.0.1:13035/snapshot")
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse JSON");
+    assert_eq!(body["screen"], "Conversation");
+    assert_eq!(body["scroll"]["unified_scroll"], 12);
+
+    handle.abort();
+}
+
 /// Test that WebSocket endpoint upgrades and receives events.
 #[tokio::test]
 async fn test_debug_server_websocket_receives_events() {
@@ -0,0 +1,89 @@
+//! Directory listing API endpoint tests using wiremock.
+//!
+//! These tests verify that the ConductorClient correctly calls the
+//! GET /v1/fs/list endpoint used by the @ picker's directory browser.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to create a test token.
+fn test_token() -> String {
+    "test-auth-token".to_string()
+}
+
+#[tokio::test]
+async fn test_list_directory_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/fs/list"))
+        .and(query_param("path", "~"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "/home/me",
+            "parent": "/home",
+            "entries": [
+                { "name": "src", "path": "/home/me/src", "is_dir": true },
+                { "name": "notes.txt", "path": "/home/me/notes.txt", "is_dir": false }
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let listing = client.list_directory("~").await.expect("listing");
+
+    assert_eq!(listing.path, "/home/me");
+    assert_eq!(listing.parent.as_deref(), Some("/home"));
+    assert_eq!(listing.entries.len(), 2);
+    assert!(listing.entries[0].is_dir);
+    assert!(!listing.entries[1].is_dir);
+}
+
+#[tokio::test]
+async fn test_list_directory_root_has_no_parent() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/fs/list"))
+        .and(query_param("path", "/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "/",
+            "entries": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let listing = client.list_directory("/").await.expect("listing");
+
+    assert!(listing.parent.is_none());
+    assert!(listing.entries.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_directory_permission_denied() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/fs/list"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("Permission denied"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.list_directory("/root").await;
+
+    match result {
+        Err(ConductorError::ServerError { status, message }) => {
+            assert_eq!(status, 403);
+            assert!(message.contains("Permission denied"));
+        }
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
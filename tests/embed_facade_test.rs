@@ -0,0 +1,186 @@
+//! Integration tests for the stable embedding facade (`spoq::embed`).
+//!
+//! These tests only use items re-exported through the facade plus public
+//! model/adapter types, mirroring what an external embedder can reach.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use ratatui::{backend::TestBackend, Terminal};
+use spoq::adapters::MockHttpClient;
+use spoq::embed::{AppEvent, EmbedEvent, EmbeddedApp, EMBED_API_VERSION};
+use spoq::models::{Message, MessageRole, Thread, ThreadMode, ThreadType};
+
+fn test_thread(id: &str, title: &str) -> Thread {
+    Thread {
+        id: id.to_string(),
+        title: title.to_string(),
+        description: None,
+        preview: String::new(),
+        updated_at: Utc::now(),
+        thread_type: ThreadType::default(),
+        mode: ThreadMode::default(),
+        model: None,
+        permission_mode: None,
+        message_count: 0,
+        created_at: Utc::now(),
+        working_directory: None,
+        status: None,
+        verified: None,
+        verified_at: None,
+        links: Vec::new(),
+        pinned: false,
+        pin_order: 0,
+        archived: false,
+        muted: false,
+    }
+}
+
+fn test_message(thread_id: &str, id: i64, role: MessageRole, content: &str) -> Message {
+    Message {
+        id,
+        thread_id: thread_id.to_string(),
+        role,
+        content: content.to_string(),
+        created_at: Utc::now(),
+        is_streaming: false,
+        partial_content: String::new(),
+        reasoning_content: String::new(),
+        reasoning_collapsed: true,
+        segments: Vec::new(),
+        render_version: 0,
+        image_hashes: Vec::new(),
+        reply_to: None,
+    }
+}
+
+fn build_embedded() -> EmbeddedApp {
+    EmbeddedApp::builder()
+        .base_url("http://localhost:8000")
+        .auth_token("test-token")
+        .http_client(Arc::new(MockHttpClient::new()))
+        .viewport(100, 30)
+        .build()
+        .expect("facade should build with injected HTTP client")
+}
+
+fn buffer_text(terminal: &Terminal<TestBackend>) -> String {
+    terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect()
+}
+
+#[test]
+fn test_embed_api_version_is_stable() {
+    assert_eq!(EMBED_API_VERSION, 1);
+}
+
+#[test]
+fn test_build_with_injected_http_client() {
+    let embedded = build_embedded();
+    assert!(embedded.threads().is_empty());
+    assert!(embedded.active_thread_id().is_none());
+    assert!(embedded.needs_redraw());
+}
+
+#[test]
+fn test_render_clears_redraw_flag() {
+    let mut embedded = build_embedded();
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+
+    terminal.draw(|f| embedded.render(f)).unwrap();
+    assert!(!embedded.needs_redraw());
+
+    embedded.handle_event(EmbedEvent::Resize { width: 120, height: 40 });
+    assert!(embedded.needs_redraw());
+}
+
+#[test]
+fn test_open_thread_renders_conversation() {
+    let mut embedded = build_embedded();
+    embedded.upsert_thread(test_thread("t-1", "Embedded Thread"));
+    embedded.set_messages(
+        "t-1",
+        vec![test_message("t-1", 1, MessageRole::User, "hello from host")],
+    );
+    embedded.open_thread("t-1");
+    assert_eq!(embedded.active_thread_id(), Some("t-1"));
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| embedded.render(f)).unwrap();
+
+    let text = buffer_text(&terminal);
+    assert!(text.contains("Embedded Thread"));
+    assert!(text.contains("hello from host"));
+
+    embedded.show_overview();
+    assert!(embedded.active_thread_id().is_none());
+}
+
+#[test]
+fn test_message_events_update_thread_state() {
+    let mut embedded = build_embedded();
+    embedded.upsert_thread(test_thread("t-1", "Original"));
+
+    embedded.handle_event(EmbedEvent::ThreadUpdated {
+        thread_id: "t-1".to_string(),
+        title: Some("Renamed".to_string()),
+        description: None,
+    });
+
+    assert_eq!(embedded.threads()[0].title, "Renamed");
+}
+
+#[test]
+fn test_process_pending_drains_sender() {
+    let mut embedded = build_embedded();
+    embedded.upsert_thread(test_thread("t-1", "Original"));
+
+    let tx = embedded.sender();
+    tx.send(EmbedEvent::ThreadUpdated {
+        thread_id: "t-1".to_string(),
+        title: Some("From task".to_string()),
+        description: None,
+    })
+    .unwrap();
+
+    assert_eq!(embedded.process_pending(), 1);
+    assert_eq!(embedded.process_pending(), 0);
+    assert_eq!(embedded.threads()[0].title, "From task");
+}
+
+#[test]
+fn test_focus_and_tick_events() {
+    let mut embedded = build_embedded();
+    embedded.handle_event(EmbedEvent::FocusChanged(false));
+    embedded.handle_event(EmbedEvent::Tick);
+    assert!(embedded.messages("missing").is_empty());
+}
+
+#[test]
+fn test_subscribers_see_events_fed_by_the_host() {
+    let mut embedded = build_embedded();
+    let mut events = embedded.subscribe_channel();
+    let seen = Arc::new(std::sync::Mutex::new(0));
+    let seen_by_callback = Arc::clone(&seen);
+    let id = embedded.subscribe(move |_| *seen_by_callback.lock().unwrap() += 1);
+
+    embedded.handle_event(EmbedEvent::StreamComplete {
+        thread_id: "t-1".to_string(),
+        message_id: 7,
+    });
+
+    assert_eq!(
+        events.try_recv().unwrap(),
+        AppEvent::MessageFinalized {
+            thread_id: "t-1".to_string(),
+            message_id: 7,
+        }
+    );
+    assert_eq!(*seen.lock().unwrap(), 1);
+    assert!(embedded.unsubscribe(id));
+}
@@ -1424,8 +1424,8 @@ async fn test_multiline_input_newline_insertion() {
     );
 }
 
-/// Test Case 3: Max 5 lines → verify line count doesn't exceed natural limit
-/// - Add more than 5 lines
+/// Test Case 3: Max 8 lines → verify line count doesn't exceed natural limit
+/// - Add more than 8 lines
 /// - Verify textarea accepts all lines (max height is for display, not content)
 #[tokio::test]
 async fn test_multiline_input_max_lines_height_calculation() {
@@ -1444,44 +1444,45 @@ async fn test_multiline_input_max_lines_height_calculation() {
     );
     assert_eq!(calculate_input_box_height(3), 5, "3 lines + 2 borders = 5");
     assert_eq!(calculate_input_box_height(4), 6, "4 lines + 2 borders = 6");
+    assert_eq!(calculate_input_box_height(5), 7, "5 lines + 2 borders = 7");
     assert_eq!(
-        calculate_input_box_height(5),
-        7,
-        "5 lines + 2 borders = 7 (max)"
+        calculate_input_box_height(8),
+        10,
+        "8 lines + 2 borders = 10 (max)"
     );
     assert_eq!(
-        calculate_input_box_height(6),
-        7,
-        "6 lines clamped to 5 + 2 = 7"
+        calculate_input_box_height(9),
+        10,
+        "9 lines clamped to 8 + 2 = 10"
     );
     assert_eq!(
         calculate_input_box_height(10),
-        7,
-        "10 lines clamped to 5 + 2 = 7"
+        10,
+        "10 lines clamped to 8 + 2 = 10"
     );
 
     // But textarea should still accept all lines
     let mut app = create_test_app();
 
-    // Create 7 lines
-    for i in 1..=7 {
+    // Create 10 lines
+    for i in 1..=10 {
         for c in format!("Line {}", i).chars() {
             app.textarea.insert_char(c);
         }
-        if i < 7 {
+        if i < 10 {
             app.textarea.insert_newline();
         }
     }
 
-    // All 7 lines should be stored (height clamping is only for display)
-    assert_eq!(app.textarea.line_count(), 7);
+    // All 10 lines should be stored (height clamping is only for display)
+    assert_eq!(app.textarea.line_count(), 10);
 
     // Content should have all lines
     let content = app.textarea.content();
     let lines: Vec<&str> = content.lines().collect();
-    assert_eq!(lines.len(), 7);
+    assert_eq!(lines.len(), 10);
     assert_eq!(lines[0], "Line 1");
-    assert_eq!(lines[6], "Line 7");
+    assert_eq!(lines[9], "Line 10");
 }
 
 /// Test Case 4: Up/Down cursor navigation between lines
@@ -1725,12 +1726,17 @@ async fn test_auto_grow_behavior() {
     assert_eq!(
         calculate_input_area_height(5),
         10,
-        "5 lines: box(7) + keybinds(1) + padding(2) = 10 (max)"
+        "5 lines: box(7) + keybinds(1) + padding(2) = 10"
     );
     assert_eq!(
-        calculate_input_area_height(6),
-        10,
-        "6 lines: clamped to box(7) + keybinds(1) + padding(2) = 10"
+        calculate_input_area_height(8),
+        13,
+        "8 lines: box(10) + keybinds(1) + padding(2) = 13 (max)"
+    );
+    assert_eq!(
+        calculate_input_area_height(9),
+        13,
+        "9 lines: clamped to box(10) + keybinds(1) + padding(2) = 13"
     );
 
     // Also verify with actual App
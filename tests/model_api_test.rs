@@ -0,0 +1,104 @@
+//! Model selection API endpoint tests using wiremock.
+//!
+//! These tests verify that the ConductorClient correctly calls the
+//! GET /v1/models and PUT /v1/threads/{id}/model endpoints.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to create a test token.
+fn test_token() -> String {
+    "test-auth-token".to_string()
+}
+
+#[tokio::test]
+async fn test_list_models_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/models"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "models": [
+                {"id": "claude-opus-4-5", "name": "Claude Opus 4.5"},
+                {"id": "claude-sonnet-4-5"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let models = client.list_models().await.expect("list_models failed");
+
+    assert_eq!(models.len(), 2);
+    assert_eq!(models[0].id, "claude-opus-4-5");
+    assert_eq!(models[0].label(), "Claude Opus 4.5");
+    assert_eq!(models[1].label(), "claude-sonnet-4-5");
+}
+
+#[tokio::test]
+async fn test_list_models_server_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/models"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("Internal error"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    match client.list_models().await {
+        Err(ConductorError::ServerError { status, .. }) => assert_eq!(status, 500),
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_update_thread_model_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/threads/thread-1/model"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .and(body_json(serde_json::json!({"model": "claude-opus-4-5"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client
+        .update_thread_model("thread-1", "claude-opus-4-5")
+        .await;
+
+    assert!(result.is_ok(), "Expected Ok, got Err: {:?}", result);
+}
+
+#[tokio::test]
+async fn test_update_thread_model_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/threads/missing/model"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Thread not found"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    match client
+        .update_thread_model("missing", "claude-opus-4-5")
+        .await
+    {
+        Err(ConductorError::ServerError { status, message }) => {
+            assert_eq!(status, 404);
+            assert!(message.contains("not found"));
+        }
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
@@ -0,0 +1,78 @@
+//! Polling transport tests using wiremock.
+//!
+//! These tests verify that ConductorClient::stream_polling submits the
+//! request to POST /v1/stream/poll and turns GET /v1/stream/events results
+//! into the same SseEvent stream as the SSE endpoint.
+
+use std::sync::Arc;
+
+use futures_util::StreamExt;
+use spoq::conductor::{ConductorClient, ConductorError};
+use spoq::events::SseEvent;
+use spoq::models::StreamRequest;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_stream_polling_yields_events_until_done() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/stream/poll"))
+        .respond_with(ResponseTemplate::new(202))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/stream/events"))
+        .and(query_param("thread_id", "thread-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "events": [
+                {"event": "content", "data": "{\"text\": \"Hello\"}", "seq": 1},
+                {"event": "content", "data": {"text": " there"}, "seq": 2},
+                {"event": "done", "data": {"message_id": "42"}, "seq": 3}
+            ],
+            "done": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = Arc::new(ConductorClient::with_url(&mock_server.uri()));
+    let request = StreamRequest::with_thread("Hi".to_string(), "thread-1".to_string());
+
+    let stream = client.stream_polling(&request).await.unwrap();
+    let events: Vec<SseEvent> = stream.map(|e| e.unwrap()).collect().await;
+
+    assert_eq!(events.len(), 3);
+    match (&events[0], &events[1]) {
+        (SseEvent::Content(a), SseEvent::Content(b)) => {
+            assert_eq!(a.text, "Hello");
+            assert_eq!(b.text, " there");
+        }
+        other => panic!("Expected content events, got {:?}", other),
+    }
+    match &events[2] {
+        SseEvent::Done(done) => assert_eq!(done.message_id, "42"),
+        other => panic!("Expected Done, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_stream_polling_submit_rejected() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/stream/poll"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Not found"))
+        .mount(&mock_server)
+        .await;
+
+    let client = Arc::new(ConductorClient::with_url(&mock_server.uri()));
+    let request = StreamRequest::with_thread("Hi".to_string(), "thread-1".to_string());
+
+    match client.stream_polling(&request).await {
+        Err(ConductorError::ServerError { status, .. }) => assert_eq!(status, 404),
+        Err(e) => panic!("Expected ServerError, got {}", e),
+        Ok(_) => panic!("Expected ServerError, got a stream"),
+    }
+}
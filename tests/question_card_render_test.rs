@@ -34,6 +34,9 @@ fn test_question_render_config_construction_single_select() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: Some(120), // 2 minutes
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     assert_eq!(config.question, "Choose one option:");
@@ -61,6 +64,9 @@ fn test_question_render_config_construction_multi_select() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     assert!(config.multi_select);
@@ -83,6 +89,9 @@ fn test_question_render_config_with_other_input() {
         other_input: "Maybe later",
         other_selected: true,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: Some(30), // 30 seconds
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     assert!(config.other_selected);
@@ -128,6 +137,9 @@ fn test_render_single_select_first_option_selected() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -171,6 +183,9 @@ fn test_render_single_select_last_option_selected() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -210,6 +225,9 @@ fn test_render_single_select_other_selected() {
         other_input: "Custom option",
         other_selected: true,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -258,6 +276,9 @@ fn test_render_multi_select_no_selections() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -302,6 +323,9 @@ fn test_render_multi_select_some_selections() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -346,6 +370,9 @@ fn test_render_multi_select_all_selections() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -386,6 +413,9 @@ fn test_render_multi_select_with_other_input() {
         other_input: "Custom feature",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -429,6 +459,9 @@ fn test_render_timer_normal_time() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: Some(272), // 4:32 - normal time
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -467,6 +500,9 @@ fn test_render_timer_urgent_time() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: Some(5), // 5 seconds - urgent (< 10)
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -505,6 +541,9 @@ fn test_render_no_timer() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None, // No timer
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -543,6 +582,9 @@ fn test_render_timer_zero_seconds() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: Some(0), // Zero seconds
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -585,6 +627,9 @@ fn test_render_many_options() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -626,6 +671,9 @@ fn test_render_long_question_text() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -664,6 +712,9 @@ fn test_render_empty_options() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -709,6 +760,9 @@ fn test_render_mismatched_selections_length() {
         other_input: "",
         other_selected: false,
         option_descriptions: &[], tab_headers: &[], current_tab: 0, tabs_answered: &[], scroll_offset: 0, needs_scroll: false, timer_seconds: None,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     
@@ -42,6 +42,11 @@ fn make_test_thread(id: &str, title: &str) -> Thread {
         status: Some(ThreadStatus::Waiting),
         verified: None,
         verified_at: None,
+        links: Vec::new(),
+        pinned: false,
+        pin_order: 0,
+        archived: false,
+        muted: false,
     }
 }
 
@@ -65,6 +70,8 @@ fn make_simple_question() -> AskUserQuestionData {
                 },
             ],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     }
@@ -90,6 +97,8 @@ fn make_multi_select_question() -> AskUserQuestionData {
                 },
             ],
             multi_select: true,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     }
@@ -112,6 +121,8 @@ fn make_multi_question_data() -> AskUserQuestionData {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             },
             Question {
                 question: "Which database?".to_string(),
@@ -127,6 +138,8 @@ fn make_multi_question_data() -> AskUserQuestionData {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             },
             Question {
                 question: "Select build tools:".to_string(),
@@ -142,6 +155,8 @@ fn make_multi_question_data() -> AskUserQuestionData {
                     },
                 ],
                 multi_select: true,
+                constraints: Default::default(),
+                default: None,
             },
         ],
         answers: HashMap::new(),
@@ -721,6 +736,8 @@ fn test_empty_options_list() {
             header: "Custom".to_string(),
             options: vec![], // Empty options
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     };
@@ -752,6 +769,8 @@ fn test_very_long_question_text() {
                 description: "Confirm".to_string(),
             }],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     };
@@ -787,6 +806,8 @@ fn test_very_long_option_label() {
                 },
             ],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     };
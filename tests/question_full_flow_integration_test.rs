@@ -44,6 +44,11 @@ fn make_test_thread(id: &str, title: &str) -> Thread {
         status: Some(ThreadStatus::Waiting),
         verified: None,
         verified_at: None,
+        links: Vec::new(),
+        pinned: false,
+        pin_order: 0,
+        archived: false,
+        muted: false,
     }
 }
 
@@ -70,6 +75,8 @@ fn make_multi_question_payload() -> AskUserQuestionData {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             },
             Question {
                 question: "Which database do you prefer?".to_string(),
@@ -89,6 +96,8 @@ fn make_multi_question_payload() -> AskUserQuestionData {
                     },
                 ],
                 multi_select: false,
+                constraints: Default::default(),
+                default: None,
             },
             Question {
                 question: "Select build tools to enable:".to_string(),
@@ -108,6 +117,8 @@ fn make_multi_question_payload() -> AskUserQuestionData {
                     },
                 ],
                 multi_select: true,
+                constraints: Default::default(),
+                default: None,
             },
         ],
         answers: HashMap::new(),
@@ -441,6 +452,8 @@ fn test_response_payload_matches_conductor_expectations() {
                 },
             ],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     };
@@ -536,6 +549,9 @@ fn test_question_card_renders_options_correctly() {
         timer_seconds: None,
         scroll_offset: 0,
         needs_scroll: false,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     terminal
@@ -589,6 +605,9 @@ fn test_question_card_renders_multi_select_with_checkboxes() {
         timer_seconds: None,
         scroll_offset: 0,
         needs_scroll: false,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     terminal
@@ -639,6 +658,8 @@ fn test_full_flow_with_other_text_input() {
                 },
             ],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     };
@@ -704,6 +725,8 @@ fn test_full_flow_single_question_immediate_submit() {
                 },
             ],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     };
@@ -750,6 +773,8 @@ fn test_navigation_wraps_around_correctly() {
                 },
             ],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     };
@@ -920,6 +945,8 @@ fn test_multi_select_with_other_text_appended() {
                 },
             ],
             multi_select: true,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     };
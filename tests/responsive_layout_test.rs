@@ -1788,6 +1788,11 @@ mod ui_rendering_integration {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread.clone());
 
@@ -1837,6 +1842,11 @@ mod ui_rendering_integration {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread.clone());
 
@@ -1886,6 +1896,11 @@ mod ui_rendering_integration {
             status: None,
             verified: None,
             verified_at: None,
+            links: Vec::new(),
+            pinned: false,
+            pin_order: 0,
+            archived: false,
+            muted: false,
         };
         app.cache.upsert_thread(thread.clone());
 
@@ -328,6 +328,7 @@ fn test_set_messages_preserves_streaming_messages() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
     cache.add_message(streaming_msg);
 
@@ -345,6 +346,7 @@ fn test_set_messages_preserves_streaming_messages() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
     let backend_msg2 = Message {
         id: 2,
@@ -359,6 +361,7 @@ fn test_set_messages_preserves_streaming_messages() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
 
     cache.set_messages(thread_id.clone(), vec![backend_msg1, backend_msg2]);
@@ -396,6 +399,7 @@ fn test_set_messages_preserves_temporary_id_messages() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
     cache.add_message(temp_msg);
 
@@ -413,6 +417,7 @@ fn test_set_messages_preserves_temporary_id_messages() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
 
     cache.set_messages(thread_id.clone(), vec![backend_msg]);
@@ -446,6 +451,7 @@ fn test_set_messages_preserves_higher_id_messages() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
     cache.add_message(existing_msg);
 
@@ -464,6 +470,7 @@ fn test_set_messages_preserves_higher_id_messages() {
             segments: Vec::new(),
             render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
         },
         Message {
             id: 3,
@@ -478,6 +485,7 @@ fn test_set_messages_preserves_higher_id_messages() {
             segments: Vec::new(),
             render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
         },
     ];
 
@@ -515,6 +523,7 @@ fn test_set_messages_replaces_all_when_no_local_messages() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
     cache.add_message(msg1);
 
@@ -533,6 +542,7 @@ fn test_set_messages_replaces_all_when_no_local_messages() {
             segments: Vec::new(),
             render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
         },
         Message {
             id: 2,
@@ -547,6 +557,7 @@ fn test_set_messages_replaces_all_when_no_local_messages() {
             segments: Vec::new(),
             render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
         },
     ];
 
@@ -580,6 +591,7 @@ fn test_set_messages_merge_order_backend_then_local() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
     let temp_msg = Message {
         id: 0,
@@ -594,6 +606,7 @@ fn test_set_messages_merge_order_backend_then_local() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
     cache.add_message(temp_msg);
     cache.add_message(streaming_msg);
@@ -612,6 +625,7 @@ fn test_set_messages_merge_order_backend_then_local() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     }];
 
     cache.set_messages(thread_id.clone(), backend_msgs);
@@ -645,6 +659,7 @@ fn test_set_messages_empty_backend_preserves_local() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
     cache.add_message(streaming_msg);
 
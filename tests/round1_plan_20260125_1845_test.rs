@@ -45,6 +45,11 @@ fn create_test_thread(id: &str, title: &str) -> Thread {
         status: None,
         verified: None,
         verified_at: None,
+        links: Vec::new(),
+        pinned: false,
+        pin_order: 0,
+        archived: false,
+        muted: false,
     }
 }
 
@@ -70,6 +75,8 @@ fn make_test_question() -> AskUserQuestionData {
                 description: "Confirm".to_string(),
             }],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     }
@@ -41,6 +41,11 @@ fn make_test_thread(id: &str, title: &str) -> Thread {
         status: Some(ThreadStatus::Waiting),
         verified: None,
         verified_at: None,
+        links: Vec::new(),
+        pinned: false,
+        pin_order: 0,
+        archived: false,
+        muted: false,
     }
 }
 
@@ -58,6 +63,8 @@ fn make_question_with_many_options(count: usize) -> AskUserQuestionData {
             header: "Choice".to_string(),
             options,
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     }
@@ -79,6 +86,8 @@ fn make_question_with_long_text() -> AskUserQuestionData {
                 },
             ],
             multi_select: false,
+            constraints: Default::default(),
+            default: None,
         }],
         answers: HashMap::new(),
     }
@@ -223,6 +232,9 @@ fn test_render_with_scroll_indicators() {
         tabs_answered: &[],
         scroll_offset: 2,
         needs_scroll: true,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     terminal
@@ -262,6 +274,9 @@ fn test_render_no_scroll_when_all_fit() {
         tabs_answered: &[],
         scroll_offset: 0,
         needs_scroll: false,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     terminal
@@ -409,6 +424,13 @@ fn test_adaptive_width_for_question_overlay() {
         question_state: None,
         question_timer_secs: None,
         repos: &repos,
+        scroll: Default::default(),
+        unread_notifications: 0,
+        previews: None,
+        unread: None,
+        token_usage: None,
+        selected_needs_action: None,
+        loading: Default::default(),
     };
 
     terminal
@@ -489,6 +511,9 @@ fn test_render_with_dynamic_question_wrapping() {
         tabs_answered: &[],
         scroll_offset: 0,
         needs_scroll: false,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     terminal
@@ -530,6 +555,9 @@ fn test_scroll_offset_skips_options_before_offset() {
         tabs_answered: &[],
         scroll_offset: 2,
         needs_scroll: true,
+        allow_other: true,
+        other_max_length: None,
+        validation_error: None,
     };
 
     terminal
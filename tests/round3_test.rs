@@ -90,6 +90,7 @@ fn test_reasoning_token_count() {
         segments: Vec::new(),
         render_version: 0,
         image_hashes: Vec::new(),
+        reply_to: None,
     };
 
     // "Let me think about this step by step carefully" = 9 words
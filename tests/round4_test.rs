@@ -33,6 +33,11 @@ fn setup_thread(app: &mut App, thread_id: &str) {
         status: Some(ThreadStatus::Waiting),
         verified: None,
         verified_at: None,
+        links: Vec::new(),
+        pinned: false,
+        pin_order: 0,
+        archived: false,
+        muted: false,
     };
     app.dashboard.add_thread(thread);
 }
@@ -28,6 +28,11 @@ fn create_test_thread(id: &str, title: &str) -> Thread {
         status: None,
         verified: None,
         verified_at: None,
+        links: Vec::new(),
+        pinned: false,
+        pin_order: 0,
+        archived: false,
+        muted: false,
     }
 }
 
@@ -0,0 +1,97 @@
+//! Startup loading against a `FakeConductor` with one slow endpoint.
+//!
+//! The CommandDeck should show threads as soon as they arrive, while the
+//! slowest loader (folders) is still pending.
+
+use std::time::Duration;
+
+use ratatui::{backend::TestBackend, Terminal};
+use serde_json::json;
+use spoq::app::App;
+use spoq::models::dashboard::ThreadStatus;
+use spoq::models::{Thread, ThreadMode, ThreadType};
+use spoq::testing::{pump_until, FakeConductor};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+/// Well past the time the other loaders need
+const SLOW_FOLDERS: Duration = Duration::from_secs(2);
+
+fn make_thread(id: &str, title: &str) -> Thread {
+    Thread {
+        id: id.to_string(),
+        title: title.to_string(),
+        description: None,
+        preview: String::new(),
+        updated_at: chrono::Utc::now(),
+        thread_type: ThreadType::Programming,
+        mode: ThreadMode::default(),
+        model: None,
+        permission_mode: None,
+        message_count: 2,
+        created_at: chrono::Utc::now(),
+        working_directory: Some("/home/dev/spoq".to_string()),
+        status: Some(ThreadStatus::Waiting),
+        verified: None,
+        verified_at: None,
+        links: Vec::new(),
+        pinned: false,
+        pin_order: 0,
+        archived: false,
+        muted: false,
+    }
+}
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| spoq::ui::render(f, app)).unwrap();
+    terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_command_deck_renders_before_the_slowest_loader() {
+    let fake = FakeConductor::start().await.unwrap();
+    fake.add_thread(make_thread("t-1", "Fix login redirect"));
+    fake.respond(
+        "GET",
+        "/v1/folders",
+        200,
+        json!([{ "name": "spoq", "path": "/home/dev/spoq" }]),
+    );
+    fake.delay("GET", "/v1/folders", SLOW_FOLDERS);
+
+    let mut app = App::with_client(fake.client()).unwrap();
+    // WebSocket against the fake too, so the test stays off the network
+    app.vps_url = Some(fake.base_url().to_string());
+    app.update_terminal_dimensions(100, 30);
+    let mut rx = app.message_rx.take().unwrap();
+
+    app.start_startup_loads(true);
+    assert!(app.section_loading().threads);
+    assert!(render(&mut app).contains("Loading threads..."));
+
+    assert!(
+        pump_until(&mut app, &mut rx, TIMEOUT, |app| !app.threads_loading).await,
+        "threads should load"
+    );
+    assert!(app.folders_loading, "folders should still be loading");
+    let screen = render(&mut app);
+    assert!(screen.contains("Fix login redirect"));
+    assert!(screen.contains("loading folders"));
+
+    // Repos have no fixture: their failure leaves the other sections alone
+    assert!(
+        pump_until(&mut app, &mut rx, TIMEOUT, |app| {
+            !app.folders_loading && !app.repos_loading
+        })
+        .await,
+        "folders should load after the delay"
+    );
+    assert_eq!(app.folders.len(), 1);
+    assert!(app.dashboard.thread_count() > 0);
+}
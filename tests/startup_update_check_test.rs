@@ -46,6 +46,7 @@ fn test_update_state_persistence() {
         last_check: Some(now),
         pending_update_path: Some("/tmp/spoq-0.2.0".to_string()),
         available_version: Some("0.2.0".to_string()),
+        ..Default::default()
     };
 
     assert!(manager.save(&state), "State should be saved successfully");
@@ -121,6 +122,7 @@ fn test_clear_pending_update() {
         last_check: Some(now),
         pending_update_path: Some("/tmp/spoq-update".to_string()),
         available_version: Some("0.2.0".to_string()),
+        ..Default::default()
     };
 
     assert!(state.has_pending_update());
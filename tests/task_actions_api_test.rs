@@ -0,0 +1,102 @@
+//! Task action API endpoint tests using wiremock.
+//!
+//! These tests verify that the ConductorClient correctly calls the
+//! POST /v1/tasks/{id}/start and POST /v1/tasks/{id}/pause endpoints.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use spoq::state::TaskStatus;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to create a test token.
+fn test_token() -> String {
+    "test-auth-token".to_string()
+}
+
+#[tokio::test]
+async fn test_start_task_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/tasks/task-1/start"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "task-1",
+            "name": "Build",
+            "status": "InProgress",
+            "progress": 0.25
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let task = client.start_task("task-1").await.unwrap();
+
+    assert_eq!(task.id, "task-1");
+    assert_eq!(task.status, TaskStatus::InProgress);
+}
+
+#[tokio::test]
+async fn test_pause_task_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/tasks/task-1/pause"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "task-1",
+            "name": "Build",
+            "status": "Paused",
+            "progress": 0.25
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let task = client.pause_task("task-1").await.unwrap();
+
+    assert_eq!(task.status, TaskStatus::Paused);
+}
+
+#[tokio::test]
+async fn test_task_action_not_implemented() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/tasks/task-1/pause"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.pause_task("task-1").await;
+
+    assert!(matches!(result, Err(ConductorError::NotImplemented(_))));
+}
+
+#[tokio::test]
+async fn test_task_action_server_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/tasks/task-1/start"))
+        .respond_with(ResponseTemplate::new(409).set_body_string("already completed"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.start_task("task-1").await;
+
+    match result {
+        Err(ConductorError::ServerError { status, message }) => {
+            assert_eq!(status, 409);
+            assert_eq!(message, "already completed");
+        }
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
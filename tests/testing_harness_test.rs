@@ -0,0 +1,159 @@
+//! End-to-end tests for the integration-test harness (`spoq::testing`).
+//!
+//! A real `App` talks to a `FakeConductor` through its normal client, and
+//! is rendered against a `TestBackend`.
+
+use std::time::Duration;
+
+use ratatui::{backend::TestBackend, Terminal};
+use spoq::app::{App, AppMessage};
+use spoq::models::{MessageRole, ThreadType};
+use spoq::testing::{pump_until, FakeConductor, ScriptedEventFeed, StreamStep};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// Long enough for the permission answer's HTTP fallback (sent after 500ms)
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+async fn start_app(fake: &FakeConductor) -> (App, UnboundedReceiver<AppMessage>) {
+    let mut app = App::with_client(fake.client()).expect("app should build with the fake");
+    app.update_terminal_dimensions(100, 30);
+    let rx = app.message_rx.take().expect("message channel");
+    (app, rx)
+}
+
+fn submit(app: &mut App, text: &str) -> String {
+    for c in text.chars() {
+        app.textarea.insert_char(c);
+    }
+    app.submit_input(ThreadType::Conversation);
+    app.active_thread_id
+        .clone()
+        .expect("submit should open a thread")
+}
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    app.flush_stream_tokens();
+    terminal.draw(|f| spoq::ui::render(f, app)).unwrap();
+    terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect()
+}
+
+fn assistant_text(app: &App, thread_id: &str) -> String {
+    let messages = app.cache.get_messages(thread_id).expect("thread messages");
+    let reply = messages
+        .iter()
+        .rev()
+        .find(|m| m.role == MessageRole::Assistant)
+        .expect("assistant reply");
+    if reply.is_streaming {
+        reply.partial_content.clone()
+    } else {
+        reply.content.clone()
+    }
+}
+
+#[tokio::test]
+async fn test_submit_stream_permission_approve_complete() {
+    let fake = FakeConductor::start().await.unwrap();
+    fake.script_stream(vec![
+        StreamStep::content("Let me look."),
+        StreamStep::permission_request("perm-1", "Bash", "Run ls"),
+        StreamStep::AwaitPermission("perm-1".to_string()),
+        StreamStep::content(" Found 3 files."),
+        StreamStep::done(42),
+    ]);
+    let (mut app, mut rx) = start_app(&fake).await;
+
+    let thread_id = submit(&mut app, "list the files");
+    assert!(
+        pump_until(&mut app, &mut rx, TIMEOUT, |app| {
+            app.dashboard.get_pending_permission(&thread_id).is_some()
+        })
+        .await,
+        "permission prompt should arrive"
+    );
+    assert_eq!(assistant_text(&app, &thread_id), "Let me look.");
+    assert!(render(&mut app).contains("Let me look."));
+
+    app.approve_permission("perm-1");
+    assert!(
+        pump_until(&mut app, &mut rx, TIMEOUT, |app| {
+            !app.cache.is_thread_streaming(&thread_id)
+        })
+        .await,
+        "stream should complete after the approval"
+    );
+
+    assert_eq!(fake.permission_answer("perm-1"), Some(true));
+    assert_eq!(
+        assistant_text(&app, &thread_id),
+        "Let me look. Found 3 files."
+    );
+    assert!(render(&mut app).contains("Found 3 files."));
+    assert!(fake.requests().iter().any(|r| r.method == "POST"
+        && r.path == "/v1/stream"
+        && r.body.contains("list the files")));
+}
+
+#[tokio::test]
+async fn test_scripted_feed_delivers_websocket_events() {
+    let fake = FakeConductor::start().await.unwrap();
+    // The backend holds the stream open until the prompt is answered
+    fake.script_stream(vec![
+        StreamStep::AwaitPermission("perm-2".to_string()),
+        StreamStep::done(7),
+    ]);
+    let (mut app, mut rx) = start_app(&fake).await;
+    let thread_id = submit(&mut app, "explore the repo");
+
+    ScriptedEventFeed::new()
+        .stream_token(&thread_id, "Exploring")
+        .subagent_started("task-1", "Map the modules", "Explore")
+        .after(
+            Duration::from_millis(20),
+            AppMessage::StreamToken {
+                thread_id: thread_id.clone(),
+                token: " now.".to_string(),
+            },
+        )
+        .permission_requested("perm-2", &thread_id, "Edit", "Edit src/main.rs")
+        .play(app.message_sender());
+
+    assert!(
+        pump_until(&mut app, &mut rx, TIMEOUT, |app| {
+            app.dashboard.get_pending_permission(&thread_id).is_some()
+        })
+        .await,
+        "scripted permission prompt should arrive"
+    );
+    assert_eq!(assistant_text(&app, &thread_id), "Exploring now.");
+    let messages = app.cache.get_messages(&thread_id).unwrap();
+    let reply = messages.last().unwrap();
+    assert_eq!(
+        reply
+            .get_subagent_event("task-1")
+            .map(|e| e.description.as_str()),
+        Some("Map the modules")
+    );
+    // The subagent started between the two tokens, so its row splits the text
+    let screen = render(&mut app);
+    assert!(screen.contains("Exploring"));
+    assert!(screen.contains("Task(Map the modules)"));
+    assert!(screen.contains("now."));
+
+    app.approve_permission("perm-2");
+    assert!(
+        pump_until(&mut app, &mut rx, TIMEOUT, |app| {
+            !app.cache.is_thread_streaming(&thread_id)
+        })
+        .await,
+        "stream should complete after the approval"
+    );
+    assert_eq!(fake.permission_answer("perm-2"), Some(true));
+}
@@ -0,0 +1,83 @@
+//! Thread archive API endpoint tests using wiremock.
+//!
+//! These tests verify that the ConductorClient correctly calls the
+//! PUT /v1/threads/{id}/archive endpoint and that the archived flag
+//! round-trips through the thread list.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to create a test token.
+fn test_token() -> String {
+    "test-auth-token".to_string()
+}
+
+#[tokio::test]
+async fn test_archive_thread_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/threads/thread-1/archive"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .and(body_json(serde_json::json!({ "archived": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.archive_thread("thread-1", true).await;
+
+    assert!(result.is_ok(), "Expected Ok, got Err: {:?}", result);
+}
+
+#[tokio::test]
+async fn test_unarchive_thread_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/threads/missing/archive"))
+        .and(body_json(serde_json::json!({ "archived": false })))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Thread not found"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.archive_thread("missing", false).await;
+
+    match result {
+        Err(ConductorError::ServerError { status, message }) => {
+            assert_eq!(status, 404);
+            assert!(message.contains("not found"));
+        }
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_fetch_threads_includes_archived() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/threads"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "threads": [
+                {"id": "thread-2", "name": "Old release notes", "archived": true},
+                {"id": "thread-1", "name": "Design the API"}
+            ],
+            "total": 2
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let threads = client.fetch_threads().await.expect("fetch_threads failed");
+
+    assert!(threads[0].archived);
+    assert!(!threads[1].archived);
+}
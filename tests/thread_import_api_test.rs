@@ -0,0 +1,68 @@
+//! Thread import API endpoint tests using wiremock.
+//!
+//! These tests verify that the ConductorClient correctly calls the
+//! POST /v1/threads/import batch endpoint.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use spoq::models::{ImportedMessage, ImportedThread, MessageRole};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn imported_thread() -> ImportedThread {
+    ImportedThread {
+        title: "Fix flaky test".to_string(),
+        working_directory: Some("/home/me/project".to_string()),
+        source: "claude-code".to_string(),
+        messages: vec![
+            ImportedMessage {
+                role: MessageRole::User,
+                content: "Why does this fail?".to_string(),
+                created_at: None,
+            },
+            ImportedMessage {
+                role: MessageRole::Assistant,
+                content: "```rust\nassert!(true);\n```".to_string(),
+                created_at: None,
+            },
+        ],
+    }
+}
+
+#[tokio::test]
+async fn test_import_threads_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/threads/import"))
+        .and(header("Authorization", "Bearer test-auth-token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "thread_ids": ["new-thread-1"] })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth("test-auth-token");
+
+    let result = client.import_threads(&[imported_thread()]).await;
+
+    assert_eq!(result.unwrap(), vec!["new-thread-1".to_string()]);
+}
+
+#[tokio::test]
+async fn test_import_threads_not_implemented() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/threads/import"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri());
+
+    let result = client.import_threads(&[imported_thread()]).await;
+
+    assert!(matches!(result, Err(ConductorError::NotImplemented(_))));
+}
@@ -0,0 +1,60 @@
+//! Thread rename API endpoint tests using wiremock.
+//!
+//! These tests verify that the ConductorClient correctly calls the
+//! PUT /v1/threads/{id}/title endpoint.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to create a test token.
+fn test_token() -> String {
+    "test-auth-token".to_string()
+}
+
+#[tokio::test]
+async fn test_rename_thread_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/threads/thread-1/title"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .and(body_json(serde_json::json!({
+            "title": "Fix the login flow"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.rename_thread("thread-1", "Fix the login flow").await;
+
+    assert!(result.is_ok(), "Expected Ok, got Err: {:?}", result);
+}
+
+#[tokio::test]
+async fn test_rename_thread_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/threads/missing/title"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Thread not found"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client.rename_thread("missing", "New title").await;
+
+    match result {
+        Err(ConductorError::ServerError { status, message }) => {
+            assert_eq!(status, 404);
+            assert!(message.contains("not found"));
+        }
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
@@ -0,0 +1,114 @@
+//! Thread share API endpoint tests using wiremock.
+//!
+//! These tests verify that the ConductorClient correctly calls the
+//! POST and DELETE /v1/threads/{id}/share endpoints.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use spoq::models::{ShareOptions, ShareVisibility};
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to create a test token.
+fn test_token() -> String {
+    "test-auth-token".to_string()
+}
+
+#[tokio::test]
+async fn test_share_thread_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/threads/thread-1/share"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .and(body_json(serde_json::json!({
+            "visibility": "org",
+            "include_tool_outputs": false,
+            "include_reasoning": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "url": "https://spoq.dev/s/abc123",
+            "visibility": "org"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let options = ShareOptions {
+        visibility: ShareVisibility::Org,
+        include_tool_outputs: false,
+        include_reasoning: true,
+    };
+    let share = client
+        .share_thread("thread-1", &options)
+        .await
+        .expect("share should succeed");
+
+    assert_eq!(share.url, "https://spoq.dev/s/abc123");
+    assert_eq!(share.visibility, ShareVisibility::Org);
+}
+
+#[tokio::test]
+async fn test_share_thread_forbidden() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/threads/thread-1/share"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("Sharing is disabled"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client
+        .share_thread("thread-1", &ShareOptions::default())
+        .await;
+
+    match result {
+        Err(ConductorError::ServerError { status, message }) => {
+            assert_eq!(status, 403);
+            assert!(message.contains("disabled"));
+        }
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_unshare_thread_success_and_not_shared() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/threads/thread-1/share"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v1/threads/thread-2/share"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    assert!(client.unshare_thread("thread-1").await.is_ok());
+    // Not shared: nothing to revoke
+    assert!(client.unshare_thread("thread-2").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_unshare_thread_server_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/threads/thread-1/share"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    match client.unshare_thread("thread-1").await {
+        Err(ConductorError::ServerError { status, .. }) => assert_eq!(status, 500),
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}
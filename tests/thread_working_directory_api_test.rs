@@ -0,0 +1,63 @@
+//! Thread working directory API endpoint tests using wiremock.
+//!
+//! These tests verify that the ConductorClient correctly calls the
+//! PUT /v1/threads/{id}/working-directory endpoint.
+
+use spoq::conductor::{ConductorClient, ConductorError};
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Helper to create a test token.
+fn test_token() -> String {
+    "test-auth-token".to_string()
+}
+
+#[tokio::test]
+async fn test_update_thread_working_directory_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/threads/thread-1/working-directory"))
+        .and(header("Authorization", format!("Bearer {}", test_token())))
+        .and(body_json(serde_json::json!({
+            "working_directory": "/home/me/code/spoq"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client
+        .update_thread_working_directory("thread-1", "/home/me/code/spoq")
+        .await;
+
+    assert!(result.is_ok(), "Expected Ok, got Err: {:?}", result);
+}
+
+#[tokio::test]
+async fn test_update_thread_working_directory_rejected() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/threads/thread-1/working-directory"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Directory does not exist"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ConductorClient::with_url(&mock_server.uri()).with_auth(&test_token());
+
+    let result = client
+        .update_thread_working_directory("thread-1", "/nope")
+        .await;
+
+    match result {
+        Err(ConductorError::ServerError { status, message }) => {
+            assert_eq!(status, 400);
+            assert!(message.contains("does not exist"));
+        }
+        other => panic!("Expected ServerError, got {:?}", other),
+    }
+}